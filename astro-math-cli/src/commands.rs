@@ -0,0 +1,140 @@
+//! The subcommands, each turning a site and time into records to print.
+
+use crate::output::{Record, Value};
+use crate::site::CliResult;
+use astro_math::almanac::{apparent_place, AlmanacBody};
+use astro_math::moon::{moon_illumination_checked, moon_phase_angle_checked, moon_phase_name};
+use astro_math::night::{Night, Twilight};
+use astro_math::rise_set::{body_rise_set, body_transit, moon_rise_set, rise_transit_set};
+use astro_math::sidereal::{apparent_sidereal_time_checked, gmst_checked, local_mean_sidereal_time_checked};
+use astro_math::transforms::body_alt_az;
+use astro_math::{airmass_kasten_young, julian_date, ra_dec_to_alt_az, Location};
+use chrono::{DateTime, Utc};
+
+/// Kilometres per astronomical unit
+const AU_KM: f64 = 149_597_870.7;
+
+/// What `riseset` is computed for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Target {
+    /// The Sun, Moon or a planet
+    Body(AlmanacBody),
+    /// A fixed position, RA and Dec in degrees
+    Fixed { ra: f64, dec: f64 },
+}
+
+/// Parses a Sun, Moon or planet name, ignoring case.
+pub fn parse_body(name: &str) -> Result<AlmanacBody, String> {
+    AlmanacBody::ALL.into_iter().find(|body| body.name().eq_ignore_ascii_case(name.trim())).ok_or_else(|| {
+        let names: Vec<_> = AlmanacBody::ALL.iter().map(|body| body.name().to_ascii_lowercase()).collect();
+        format!("unknown body '{}' (expected one of: {})", name, names.join(", "))
+    })
+}
+
+/// Greenwich and local sidereal times.
+pub fn lst(time: DateTime<Utc>, location: &Location) -> CliResult<Vec<Record>> {
+    let jd = julian_date(time);
+    Ok(vec![Record(vec![
+        ("time", Value::Time(Some(time))),
+        ("julian_date", Value::Number(jd, 6)),
+        ("gmst", Value::Hours(gmst_checked(jd)?)),
+        ("lmst", Value::Hours(local_mean_sidereal_time_checked(jd, location.longitude_deg)?)),
+        ("last", Value::Hours(apparent_sidereal_time_checked(jd, location.longitude_deg)?)),
+    ])])
+}
+
+/// Altitude, azimuth, hour angle and airmass of a position.
+pub fn altaz(ra: f64, dec: f64, time: DateTime<Utc>, location: &Location) -> CliResult<Vec<Record>> {
+    let (alt, az) = ra_dec_to_alt_az(ra, dec, time, location)?;
+    let last = apparent_sidereal_time_checked(julian_date(time), location.longitude_deg)?;
+    let hour_angle = (last - ra / 15.0 + 12.0).rem_euclid(24.0) - 12.0;
+    // Airmass only means something above the horizon
+    let airmass = if alt > 0.0 { airmass_kasten_young(alt)? } else { f64::NAN };
+    Ok(vec![Record(vec![
+        ("time", Value::Time(Some(time))),
+        ("altitude", Value::Degrees(alt)),
+        ("azimuth", Value::Degrees(az)),
+        ("hour_angle", Value::Number(hour_angle, 4)),
+        ("airmass", Value::Number(airmass, 3)),
+    ])])
+}
+
+/// Rise, transit and set on the UTC day of `time`.
+pub fn riseset(target: Target, time: DateTime<Utc>, location: &Location) -> CliResult<Vec<Record>> {
+    let (name, rise, transit, set) = match target {
+        Target::Body(body) => {
+            let (rise, set) = body_rise_set(&body, time, location)?;
+            (body.name().to_string(), rise, body_transit(&body, time, location)?, set)
+        }
+        Target::Fixed { ra, dec } => {
+            let name = format!("{:.4} {:+.4}", ra, dec);
+            match rise_transit_set(ra, dec, time, location, None)? {
+                Some((rise, transit, set)) => (name, Some(rise), Some(transit), Some(set)),
+                None => (name, None, None, None),
+            }
+        }
+    };
+    Ok(vec![Record(vec![
+        ("target", Value::Text(name)),
+        ("rise", Value::Time(rise)),
+        ("transit", Value::Time(transit)),
+        ("set", Value::Time(set)),
+    ])])
+}
+
+/// Phase, position and rise and set of the Moon.
+pub fn moon(time: DateTime<Utc>, location: &Location) -> CliResult<Vec<Record>> {
+    let place = apparent_place(AlmanacBody::Moon, time)?;
+    let (alt, az) = body_alt_az(&AlmanacBody::Moon, time, location)?;
+    let (rise, set) = moon_rise_set(time, location)?;
+    Ok(vec![Record(vec![
+        ("time", Value::Time(Some(time))),
+        ("phase", Value::Text(moon_phase_name(time).to_string())),
+        ("phase_angle", Value::Degrees(moon_phase_angle_checked(time)?)),
+        ("illumination", Value::Number(moon_illumination_checked(time)?, 1)),
+        ("ra", Value::RightAscension(place.ra)),
+        ("dec", Value::Degrees(place.dec)),
+        ("distance_km", Value::Number(place.distance_au * AU_KM, 0)),
+        ("altitude", Value::Degrees(alt)),
+        ("azimuth", Value::Degrees(az)),
+        ("rise", Value::Time(rise)),
+        ("set", Value::Time(set)),
+    ])])
+}
+
+/// Apparent place, altitude and azimuth of each planet.
+pub fn planets(time: DateTime<Utc>, location: &Location) -> CliResult<Vec<Record>> {
+    AlmanacBody::ALL
+        .into_iter()
+        .filter(|body| !matches!(body, AlmanacBody::Sun | AlmanacBody::Moon))
+        .map(|body| {
+            let place = apparent_place(body, time)?;
+            let (alt, az) = body_alt_az(&body, time, location)?;
+            Ok(Record(vec![
+                ("planet", Value::Text(body.name().to_string())),
+                ("ra", Value::RightAscension(place.ra)),
+                ("dec", Value::Degrees(place.dec)),
+                ("altitude", Value::Degrees(alt)),
+                ("azimuth", Value::Degrees(az)),
+                ("magnitude", Value::Number(place.magnitude, 1)),
+                ("distance_au", Value::Number(place.distance_au, 3)),
+            ]))
+        })
+        .collect()
+}
+
+/// Sunset, the three twilights and sunrise of the night containing `time`.
+pub fn twilight(time: DateTime<Utc>, location: &Location) -> CliResult<Vec<Record>> {
+    let night = Night::containing(time, location)?;
+    Ok(vec![Record(vec![
+        ("night", Value::Text(night.date().to_string())),
+        ("sunset", Value::Time(night.sunset()?)),
+        ("civil_dusk", Value::Time(night.dusk(Twilight::Civil)?)),
+        ("nautical_dusk", Value::Time(night.dusk(Twilight::Nautical)?)),
+        ("astronomical_dusk", Value::Time(night.dusk(Twilight::Astronomical)?)),
+        ("astronomical_dawn", Value::Time(night.dawn(Twilight::Astronomical)?)),
+        ("nautical_dawn", Value::Time(night.dawn(Twilight::Nautical)?)),
+        ("civil_dawn", Value::Time(night.dawn(Twilight::Civil)?)),
+        ("sunrise", Value::Time(night.sunrise()?)),
+    ])])
+}
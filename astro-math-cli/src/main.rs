@@ -0,0 +1,122 @@
+//! `astro-math`: the core computations of astro-math from the shell.
+//!
+//! For checking observatory numbers and simple scripted pipelines without
+//! writing Rust or Python. The site comes from `--lat`/`--lon`/`--height`,
+//! a JSON site file given with `--site` or the `ASTRO_MATH_SITE` variable,
+//! or both (flags override the file); the time from `--time`, defaulting
+//! to now.
+//!
+//! ```text
+//! $ astro-math --lat 19.8207 --lon -155.468 --height 4205 lst --time 2024-08-04T06:00:00Z
+//! time         2024-08-04 06:00:00
+//! julian date  2460526.750000
+//! gmst         02h53m11.73s
+//! lmst         16h31m19.41s
+//! last         16h31m19.33s
+//!
+//! $ export ASTRO_MATH_SITE=~/mauna-kea.json
+//! $ astro-math altaz --ra 279.2347 --dec 38.7837
+//! $ astro-math riseset moon --time 2024-03-10
+//! $ astro-math planets --json | jq '.[] | select(.altitude > 20) | .planet'
+//! $ astro-math twilight
+//! ```
+//!
+//! Angles are in degrees, right ascension shown as hours, minutes and
+//! seconds; `--json` prints angles in degrees, sidereal times in hours and
+//! times in RFC 3339 UTC.
+
+mod commands;
+mod output;
+mod site;
+#[cfg(test)]
+mod tests;
+
+use clap::{Parser, Subcommand};
+use commands::Target;
+use site::{CliResult, SiteArgs};
+use std::process::ExitCode;
+
+/// Astronomical computations for an observing site.
+#[derive(Debug, Parser)]
+#[command(name = "astro-math", version, about)]
+struct Cli {
+    #[command(flatten)]
+    site: SiteArgs,
+    /// Time in ISO 8601, e.g. 2024-08-04T06:00:00Z, 2024-08-04, or
+    /// "2024-08-04 06:01:09 TT" [default: now]
+    #[arg(long, short, global = true)]
+    time: Option<String>,
+    /// Print JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Altitude, azimuth, hour angle (hours) and airmass of a position
+    Altaz {
+        /// Right ascension in degrees, of date
+        #[arg(long, allow_hyphen_values = true)]
+        ra: f64,
+        /// Declination in degrees, of date
+        #[arg(long, allow_hyphen_values = true)]
+        dec: f64,
+    },
+    /// Rise, transit and set times on the UTC day of --time
+    Riseset {
+        /// sun, moon or a planet; or give --ra and --dec
+        #[arg(value_parser = commands::parse_body, required_unless_present_all = ["ra", "dec"])]
+        body: Option<astro_math::almanac::AlmanacBody>,
+        /// Right ascension in degrees of a fixed target
+        #[arg(long, allow_hyphen_values = true, requires = "dec", conflicts_with = "body")]
+        ra: Option<f64>,
+        /// Declination in degrees of a fixed target
+        #[arg(long, allow_hyphen_values = true, requires = "ra", conflicts_with = "body")]
+        dec: Option<f64>,
+    },
+    /// Greenwich mean and local mean and apparent sidereal time
+    Lst,
+    /// Moon phase, illumination, position and rise and set
+    Moon,
+    /// Position, altitude and azimuth of the planets
+    Planets,
+    /// Sunset, civil, nautical and astronomical twilight and sunrise of the night
+    Twilight,
+}
+
+fn run(cli: &Cli) -> CliResult<String> {
+    let location = cli.site.location()?;
+    let time = site::parse_time(cli.time.as_deref())?;
+    let records = match &cli.command {
+        Command::Altaz { ra, dec } => commands::altaz(*ra, *dec, time, &location)?,
+        Command::Riseset { body, ra, dec } => {
+            let target = match (body, ra, dec) {
+                (Some(body), _, _) => Target::Body(*body),
+                (None, Some(ra), Some(dec)) => Target::Fixed { ra: *ra, dec: *dec },
+                _ => unreachable!("clap requires a body or both --ra and --dec"),
+            };
+            commands::riseset(target, time, &location)?
+        }
+        Command::Lst => commands::lst(time, &location)?,
+        Command::Moon => commands::moon(time, &location)?,
+        Command::Planets => commands::planets(time, &location)?,
+        Command::Twilight => commands::twilight(time, &location)?,
+    };
+    Ok(output::render(&records, cli.json))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(text) => {
+            println!("{}", text);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
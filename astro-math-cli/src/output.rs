@@ -0,0 +1,127 @@
+//! Text and JSON rendering of command results.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::json;
+
+/// One value of a result, rendered for people or for scripts.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// An angle in degrees
+    Degrees(f64),
+    /// A right ascension in degrees, shown as hours, minutes and seconds
+    RightAscension(f64),
+    /// A time of day or sidereal time in hours, shown as hours, minutes
+    /// and seconds
+    Hours(f64),
+    /// A plain number with the given number of decimals in text output;
+    /// NaN for no value
+    Number(f64, usize),
+    /// An event time; `None` when the event does not happen
+    Time(Option<DateTime<Utc>>),
+    /// Free text
+    Text(String),
+}
+
+impl Value {
+    /// Renders the value for a terminal.
+    pub fn to_text(&self) -> String {
+        match self {
+            Value::Degrees(deg) => format!("{:.4}°", deg),
+            Value::RightAscension(deg) => format_hms(deg / 15.0),
+            Value::Hours(hours) => format_hms(*hours),
+            Value::Number(value, _) if value.is_nan() => "-".to_string(),
+            Value::Number(value, decimals) => format!("{:.*}", decimals, value),
+            Value::Time(Some(time)) => time.format("%Y-%m-%d %H:%M:%S").to_string(),
+            Value::Time(None) => "-".to_string(),
+            Value::Text(text) => text.clone(),
+        }
+    }
+
+    /// Renders the value for JSON output: angles in degrees, sidereal times
+    /// in hours and event times in RFC 3339, with `null` for no event or
+    /// value.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Value::Degrees(value) | Value::RightAscension(value) | Value::Hours(value) | Value::Number(value, _) => {
+                json!(value)
+            }
+            Value::Time(time) => json!(time.map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true))),
+            Value::Text(text) => json!(text),
+        }
+    }
+}
+
+/// Named values in display order, e.g. one planet or one night.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record(pub Vec<(&'static str, Value)>);
+
+/// Renders the output of a command.
+///
+/// A single record prints as `name value` lines and several as a table in
+/// text mode; in JSON mode a single record is an object and several are an
+/// array of objects.
+pub fn render(records: &[Record], as_json: bool) -> String {
+    if as_json {
+        let objects: Vec<serde_json::Value> = records
+            .iter()
+            .map(|record| {
+                let fields = record.0.iter().map(|(name, value)| (name.to_string(), value.to_json()));
+                serde_json::Value::Object(fields.collect())
+            })
+            .collect();
+        return match <[_; 1]>::try_from(objects) {
+            Ok([object]) => object.to_string(),
+            Err(objects) => serde_json::Value::Array(objects).to_string(),
+        };
+    }
+
+    match records {
+        [record] => {
+            let width = record.0.iter().map(|(name, _)| label(name).chars().count()).max().unwrap_or(0);
+            record
+                .0
+                .iter()
+                .map(|(name, value)| format!("{:<width$}  {}", label(name), value.to_text()))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        _ => table(records),
+    }
+}
+
+/// Lays several records out in columns under a header of their field names.
+fn table(records: &[Record]) -> String {
+    let Some(first) = records.first() else {
+        return String::new();
+    };
+    let header: Vec<String> = first.0.iter().map(|(name, _)| label(name)).collect();
+    let rows: Vec<Vec<String>> =
+        records.iter().map(|record| record.0.iter().map(|(_, value)| value.to_text()).collect()).collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| rows.iter().map(|row| row[i].chars().count()).chain([header[i].chars().count()]).max().unwrap_or(0))
+        .collect();
+    let line = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    std::iter::once(line(&header)).chain(rows.iter().map(|row| line(row))).collect::<Vec<_>>().join("\n")
+}
+
+/// Field name as shown in text output: `civil_dusk` → `civil dusk`.
+fn label(name: &str) -> String {
+    name.replace('_', " ")
+}
+
+/// Formats hours as `HHhMMmSS.SSs`, wrapped into [0, 24).
+pub fn format_hms(hours: f64) -> String {
+    let centiseconds = (hours.rem_euclid(24.0) * 360_000.0).round() as i64 % (24 * 360_000);
+    let (h, rest) = (centiseconds / 360_000, centiseconds % 360_000);
+    let (m, cs) = (rest / 6_000, rest % 6_000);
+    format!("{:02}h{:02}m{:02}.{:02}s", h, m, cs / 100, cs % 100)
+}
@@ -0,0 +1,81 @@
+//! Observer site and time from the command line or a site file.
+
+use astro_math::astro_time::AstroTime;
+use astro_math::Location;
+use chrono::{DateTime, Utc};
+use clap::Args;
+use std::error::Error;
+use std::path::PathBuf;
+
+/// Result of the command-line front end; library errors are boxed as they are.
+pub type CliResult<T> = Result<T, Box<dyn Error>>;
+
+/// Where the observer is.
+///
+/// A site file holds a serialized [`Location`]; `--lat`, `--lon` and
+/// `--height` override its fields, or give the whole site without a file.
+#[derive(Debug, Clone, Default, Args)]
+pub struct SiteArgs {
+    /// Latitude, in any format `Location::parse` reads, e.g. 19.8207 or "19 49 14.5 N"
+    #[arg(long, global = true, allow_hyphen_values = true)]
+    pub lat: Option<String>,
+    /// Longitude, east positive, e.g. -155.468 or "155 28 05 W"
+    #[arg(long, global = true, allow_hyphen_values = true)]
+    pub lon: Option<String>,
+    /// Height above sea level in metres [default: 0, or the site file's]
+    #[arg(long, global = true, allow_hyphen_values = true)]
+    pub height: Option<f64>,
+    /// JSON site file: {"latitude_deg": .., "longitude_deg": .., "altitude_m": ..}
+    #[arg(long, global = true, env = "ASTRO_MATH_SITE")]
+    pub site: Option<PathBuf>,
+}
+
+impl SiteArgs {
+    /// Resolves the observer location from the flags and site file.
+    ///
+    /// # Errors
+    /// If the site file cannot be read or parsed, a coordinate cannot be
+    /// parsed, or neither a site file nor both `--lat` and `--lon` are given.
+    pub fn location(&self) -> CliResult<Location> {
+        let file = match &self.site {
+            Some(path) => {
+                let text = std::fs::read_to_string(path)
+                    .map_err(|e| format!("cannot read site file {}: {}", path.display(), e))?;
+                let location: Location = serde_json::from_str(&text)
+                    .map_err(|e| format!("invalid site file {}: {}", path.display(), e))?;
+                Some(location)
+            }
+            None => None,
+        };
+
+        let latitude_deg = match (&self.lat, &file) {
+            (Some(lat), _) => Location::parse(lat, "0", 0.0)?.latitude_deg,
+            (None, Some(file)) => file.latitude_deg,
+            (None, None) => return Err(MISSING_SITE.into()),
+        };
+        let longitude_deg = match (&self.lon, &file) {
+            (Some(lon), _) => Location::parse("0", lon, 0.0)?.longitude_deg,
+            (None, Some(file)) => file.longitude_deg,
+            (None, None) => return Err(MISSING_SITE.into()),
+        };
+        let altitude_m = self.height.or(file.map(|f| f.altitude_m)).unwrap_or(0.0);
+        Ok(Location { latitude_deg, longitude_deg, altitude_m })
+    }
+}
+
+const MISSING_SITE: &str = "no observer site: give --lat and --lon, or --site FILE (or set ASTRO_MATH_SITE)";
+
+/// Parses `--time`, or returns the current time.
+///
+/// Accepts what [`AstroTime::from_iso`] does, e.g. `2024-08-04T06:00:00Z`,
+/// `2024-08-04 08:00:00+02:00`, a bare date, or a time on another scale such as
+/// `2024-08-04T06:01:09.184 TT`.
+///
+/// # Errors
+/// If the text is not an ISO 8601 date and time.
+pub fn parse_time(text: Option<&str>) -> CliResult<DateTime<Utc>> {
+    match text {
+        Some(text) => Ok(AstroTime::from_iso(text)?.to_utc().to_datetime()),
+        None => Ok(Utc::now()),
+    }
+}
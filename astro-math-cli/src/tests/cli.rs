@@ -0,0 +1,32 @@
+use crate::{Cli, Command};
+use astro_math::almanac::AlmanacBody;
+use clap::{CommandFactory, Parser};
+
+#[test]
+fn test_cli_definition_is_valid() {
+    Cli::command().debug_assert();
+}
+
+#[test]
+fn test_global_flags_after_subcommand() {
+    let cli = Cli::try_parse_from(["astro-math", "lst", "--lat", "-33.9", "--lon", "18.4", "--json", "-t", "2024-08-04"])
+        .unwrap();
+    assert!(matches!(cli.command, Command::Lst));
+    assert_eq!(cli.site.lat.as_deref(), Some("-33.9"));
+    assert_eq!(cli.time.as_deref(), Some("2024-08-04"));
+    assert!(cli.json);
+}
+
+#[test]
+fn test_riseset_takes_a_body_or_a_position() {
+    let cli = Cli::try_parse_from(["astro-math", "riseset", "Jupiter"]).unwrap();
+    assert!(matches!(cli.command, Command::Riseset { body: Some(AlmanacBody::Jupiter), .. }));
+
+    let cli = Cli::try_parse_from(["astro-math", "riseset", "--ra", "83.82", "--dec", "-5.39"]).unwrap();
+    assert!(matches!(cli.command, Command::Riseset { body: None, ra: Some(_), dec: Some(_) }));
+
+    assert!(Cli::try_parse_from(["astro-math", "riseset"]).is_err());
+    assert!(Cli::try_parse_from(["astro-math", "riseset", "--ra", "83.82"]).is_err());
+    assert!(Cli::try_parse_from(["astro-math", "riseset", "pluto"]).is_err());
+    assert!(Cli::try_parse_from(["astro-math", "riseset", "moon", "--ra", "1", "--dec", "2"]).is_err());
+}
@@ -0,0 +1,66 @@
+use crate::commands::{altaz, lst, parse_body, planets, riseset, twilight, Target};
+use crate::output::Value;
+use astro_math::almanac::AlmanacBody;
+use astro_math::Location;
+use chrono::{TimeZone, Utc};
+
+const GREENWICH: Location = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+
+#[test]
+fn test_parse_body() {
+    assert_eq!(parse_body("sun"), Ok(AlmanacBody::Sun));
+    assert_eq!(parse_body(" NEPTUNE "), Ok(AlmanacBody::Neptune));
+    assert!(parse_body("pluto").unwrap_err().contains("mercury"));
+}
+
+#[test]
+fn test_lst_matches_meeus() {
+    // Meeus Example 12.b: 1987 April 10, 19h21m UT, GMST 8h34m57.09s
+    let time = Utc.with_ymd_and_hms(1987, 4, 10, 19, 21, 0).unwrap();
+    let records = lst(time, &GREENWICH).unwrap();
+    match records[0].0[2] {
+        ("gmst", Value::Hours(gmst)) => assert!((gmst * 3600.0 - (8.0 * 3600.0 + 34.0 * 60.0 + 57.09)).abs() < 0.1),
+        ref other => panic!("unexpected field {:?}", other),
+    }
+}
+
+#[test]
+fn test_altaz_airmass_only_above_horizon() {
+    let time = Utc.with_ymd_and_hms(2024, 8, 4, 22, 0, 0).unwrap();
+    let airmass = |dec| match altaz(279.2347, dec, time, &GREENWICH).unwrap()[0].0[4].1 {
+        Value::Number(airmass, _) => airmass,
+        ref other => panic!("unexpected value {:?}", other),
+    };
+    // Vega high in the evening sky; a star near the south celestial pole never up
+    assert!((1.0..1.2).contains(&airmass(38.7837)));
+    assert!(airmass(-80.0).is_nan());
+    assert!(altaz(400.0, 0.0, time, &GREENWICH).is_err());
+}
+
+#[test]
+fn test_riseset_and_twilight_are_ordered() {
+    let date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+    let times = |records: Vec<crate::output::Record>| -> Vec<_> {
+        records[0].0.iter().filter_map(|(_, value)| match value {
+            Value::Time(time) => Some(time.unwrap()),
+            _ => None,
+        }).collect()
+    };
+
+    // Rigel rises, transits and sets at Greenwich
+    let events = times(riseset(Target::Fixed { ra: 78.6345, dec: -8.2016 }, date, &GREENWICH).unwrap());
+    assert!(events[0] < events[1] && events[1] < events[2]);
+    assert!(riseset(Target::Body(AlmanacBody::Jupiter), date, &GREENWICH).is_ok());
+
+    let night = times(twilight(Utc.with_ymd_and_hms(2024, 11, 3, 22, 0, 0).unwrap(), &GREENWICH).unwrap());
+    assert_eq!(night.len(), 8);
+    assert!(night.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_planets_lists_mercury_to_neptune() {
+    let time = Utc.with_ymd_and_hms(2024, 12, 7, 0, 0, 0).unwrap();
+    let records = planets(time, &GREENWICH).unwrap();
+    let names: Vec<_> = records.iter().map(|record| record.0[0].1.to_text()).collect();
+    assert_eq!(names, ["Mercury", "Venus", "Mars", "Jupiter", "Saturn", "Uranus", "Neptune"]);
+}
@@ -0,0 +1,40 @@
+use crate::output::{format_hms, render, Record, Value};
+use chrono::{TimeZone, Utc};
+
+#[test]
+fn test_format_hms() {
+    assert_eq!(format_hms(0.0), "00h00m00.00s");
+    assert_eq!(format_hms(13.781), "13h46m51.60s");
+    assert_eq!(format_hms(-1.5), "22h30m00.00s");
+    // Rounds up into the next day rather than printing 24h
+    assert_eq!(format_hms(23.999_999_9), "00h00m00.00s");
+}
+
+#[test]
+fn test_render_single_record() {
+    let time = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let record = Record(vec![
+        ("rise", Value::Time(Some(time))),
+        ("set", Value::Time(None)),
+        ("hour_angle", Value::Number(-1.25, 2)),
+        ("airmass", Value::Number(f64::NAN, 3)),
+    ]);
+    assert_eq!(
+        render(std::slice::from_ref(&record), false),
+        "rise        2024-08-04 06:00:00\nset         -\nhour angle  -1.25\nairmass     -"
+    );
+    assert_eq!(
+        render(&[record], true),
+        r#"{"rise":"2024-08-04T06:00:00Z","set":null,"hour_angle":-1.25,"airmass":null}"#
+    );
+}
+
+#[test]
+fn test_render_table() {
+    let records = [
+        Record(vec![("planet", Value::Text("Mars".into())), ("ra", Value::RightAscension(129.3))]),
+        Record(vec![("planet", Value::Text("Jupiter".into())), ("ra", Value::RightAscension(75.3))]),
+    ];
+    assert_eq!(render(&records, false), "planet   ra\nMars     08h37m12.00s\nJupiter  05h01m12.00s");
+    assert_eq!(render(&records, true), r#"[{"planet":"Mars","ra":129.3},{"planet":"Jupiter","ra":75.3}]"#);
+}
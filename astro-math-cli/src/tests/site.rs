@@ -0,0 +1,42 @@
+use crate::site::{parse_time, SiteArgs};
+use chrono::{TimeZone, Utc};
+
+#[test]
+fn test_location_from_flags() {
+    let args = SiteArgs { lat: Some("19 49 14.5 N".into()), lon: Some("-155.468".into()), height: None, site: None };
+    let location = args.location().unwrap();
+    assert!((location.latitude_deg - 19.8207).abs() < 1e-4);
+    assert_eq!(location.longitude_deg, -155.468);
+    assert_eq!(location.altitude_m, 0.0);
+
+    assert!(SiteArgs { lon: None, ..args.clone() }.location().is_err());
+    assert!(SiteArgs { lat: Some("north".into()), ..args }.location().is_err());
+}
+
+#[test]
+fn test_flags_override_site_file() {
+    let path = std::env::temp_dir().join(format!("astro-math-cli-site-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{"latitude_deg": -30.2407, "longitude_deg": -70.7366, "altitude_m": 2715.0}"#).unwrap();
+
+    let args = SiteArgs { site: Some(path.clone()), ..Default::default() };
+    let location = args.location().unwrap();
+    assert_eq!((location.latitude_deg, location.longitude_deg, location.altitude_m), (-30.2407, -70.7366, 2715.0));
+
+    let location = SiteArgs { height: Some(2650.0), ..args.clone() }.location().unwrap();
+    assert_eq!((location.latitude_deg, location.altitude_m), (-30.2407, 2650.0));
+
+    std::fs::write(&path, "latitude = -30").unwrap();
+    assert!(args.location().is_err());
+    std::fs::remove_file(&path).unwrap();
+    assert!(args.location().is_err());
+}
+
+#[test]
+fn test_parse_time() {
+    let expected = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    assert_eq!(parse_time(Some("2024-08-04T06:00:00Z")).unwrap(), expected);
+    assert_eq!(parse_time(Some("2024-08-04 08:00:00+02:00")).unwrap(), expected);
+    assert_eq!(parse_time(Some("2024-08-04T06:01:09.184 TT")).unwrap(), expected);
+    assert!(parse_time(Some("yesterday")).is_err());
+    assert!(parse_time(None).is_ok());
+}
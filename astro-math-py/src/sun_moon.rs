@@ -1,7 +1,7 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDateTime, PyDateAccess, PyTimeAccess};
 use astro_math::{sun, moon};
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 
 /// Calculate the Sun's equatorial position (RA, Dec).
 ///
@@ -83,6 +83,44 @@ fn moon_equatorial(datetime: &Bound<'_, PyDateTime>) -> PyResult<(f64, f64)> {
     Ok(moon::moon_equatorial(dt))
 }
 
+/// Sample the Sun's position from start to end at a uniform step.
+///
+/// One Rust-side sweep, much faster than calling sun_ra_dec in a Python loop.
+/// Returns a list of (datetime, ra, dec, distance_au) tuples, with RA/Dec in
+/// degrees and timezone-aware UTC datetimes.
+#[pyfunction]
+#[pyo3(signature = (start, end, step_seconds))]
+fn sun_positions(
+    start: &Bound<'_, PyDateTime>,
+    end: &Bound<'_, PyDateTime>,
+    step_seconds: f64,
+) -> PyResult<Vec<(DateTime<Utc>, f64, f64, f64)>> {
+    sun::sun_positions(datetime_from_py(start)?, datetime_from_py(end)?, step_from_seconds(step_seconds))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Sample the Moon's position from start to end at a uniform step.
+///
+/// One Rust-side sweep, much faster than calling moon_equatorial and
+/// moon_distance in a Python loop. Returns a list of
+/// (datetime, ra, dec, distance_km) tuples, with RA/Dec in degrees and
+/// timezone-aware UTC datetimes.
+#[pyfunction]
+#[pyo3(signature = (start, end, step_seconds))]
+fn moon_positions(
+    start: &Bound<'_, PyDateTime>,
+    end: &Bound<'_, PyDateTime>,
+    step_seconds: f64,
+) -> PyResult<Vec<(DateTime<Utc>, f64, f64, f64)>> {
+    moon::moon_positions(datetime_from_py(start)?, datetime_from_py(end)?, step_from_seconds(step_seconds))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+// Steps are rounded to the microsecond; NaN becomes zero and is rejected
+fn step_from_seconds(seconds: f64) -> Duration {
+    Duration::microseconds((seconds * 1e6).round() as i64)
+}
+
 // Helper function to parse datetime from Python
 fn datetime_from_py(dt: &Bound<'_, PyDateTime>) -> PyResult<DateTime<Utc>> {
     let year = dt.get_year();
@@ -117,5 +155,7 @@ pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(moon_phase_name, m)?)?;
     m.add_function(wrap_pyfunction!(moon_distance, m)?)?;
     m.add_function(wrap_pyfunction!(moon_equatorial, m)?)?;
+    m.add_function(wrap_pyfunction!(sun_positions, m)?)?;
+    m.add_function(wrap_pyfunction!(moon_positions, m)?)?;
     Ok(())
 }
\ No newline at end of file
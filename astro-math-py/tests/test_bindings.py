@@ -250,6 +250,27 @@ class TestSunMoon:
         assert 0 <= ra <= 360
         assert -30 <= dec <= 30  # Moon stays near ecliptic
 
+    def test_moon_positions(self):
+        """Test batch Moon sampling matches the scalar functions."""
+        start = datetime(2024, 1, 1, 0, 0, 0)
+        track = astro_math.sun_moon.moon_positions(start, datetime(2024, 1, 2, 0, 0, 0), 3600.0)
+
+        assert len(track) == 25
+        t, ra, dec, distance_km = track[12]
+        assert t.hour == 12
+        assert (ra, dec) == astro_math.sun_moon.moon_equatorial(datetime(2024, 1, 1, 12, 0, 0))
+        assert 356000 < distance_km < 407000
+
+    def test_sun_positions(self):
+        """Test batch Sun sampling."""
+        start = datetime(2024, 1, 1, 0, 0, 0)
+        track = astro_math.sun_moon.sun_positions(start, datetime(2024, 1, 11, 0, 0, 0), 86400.0)
+
+        assert len(track) == 11
+        assert all(0.98 < distance_au < 0.99 for _, _, _, distance_au in track)
+        with pytest.raises(ValueError):
+            astro_math.sun_moon.sun_positions(start, start, 0.0)
+
 
 class TestRefraction:
     """Test atmospheric refraction."""
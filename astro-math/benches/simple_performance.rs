@@ -1,5 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId, Throughput};
-use astro_math::{Location, ra_dec_to_alt_az, ra_dec_to_alt_az_batch_parallel};
+use astro_math::{Location, ra_dec_to_alt_az, ra_dec_to_alt_az_batch_parallel, ra_dec_to_alt_az_erfa};
+use astro_math::astrometric_context::AstrometricContext;
+use astro_math::fixed_site::FixedSite;
+use astro_math::refraction::{AtmosphericConditions, RefractionModel, RefractionTable};
 use chrono::{Utc, TimeZone};
 
 /// Benchmark coordinate transformation functions
@@ -20,6 +23,14 @@ fn bench_coordinate_transforms(c: &mut Criterion) {
         })
     });
     
+    // Same transformation with the site precomputed
+    let site = FixedSite::new(location).unwrap();
+    group.bench_function("fixed_site_single", |b| {
+        b.iter(|| {
+            site.ra_dec_to_alt_az(black_box(279.23), black_box(38.78), datetime)
+        })
+    });
+    
     // Batch processing performance
     for size in [10, 100, 1000].iter() {
         let coords: Vec<(f64, f64)> = (0..*size)
@@ -32,6 +43,40 @@ fn bench_coordinate_transforms(c: &mut Criterion) {
                 ra_dec_to_alt_az_batch_parallel(black_box(coords), datetime, &location, None, None, None)
             })
         });
+        // The batch shares one ERFA context; per-star Atco13 for comparison
+        group.bench_with_input(BenchmarkId::new("erfa_per_star", size), &coords, |b, coords| {
+            b.iter(|| {
+                coords
+                    .iter()
+                    .map(|&(ra, dec)| ra_dec_to_alt_az_erfa(black_box(ra), black_box(dec), datetime, &location, None, None, None))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+        });
+        let context = AstrometricContext::new(datetime, &location, None).unwrap();
+        group.bench_with_input(BenchmarkId::new("astrometric_context_serial", size), &coords, |b, coords| {
+            b.iter(|| {
+                coords
+                    .iter()
+                    .map(|&(ra, dec)| context.observed_from_icrs(black_box(ra), black_box(dec)))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("fixed_site_batch", size), &coords, |b, coords| {
+            b.iter(|| {
+                site.ra_dec_to_alt_az_batch(black_box(coords), datetime)
+            })
+        });
+        
+        // Same batch for callers whose data are already in radians
+        let coords_rad: Vec<(f64, f64)> = coords
+            .iter()
+            .map(|&(ra, dec)| (ra.to_radians(), dec.to_radians()))
+            .collect();
+        group.bench_with_input(BenchmarkId::new("fixed_site_batch_rad", size), &coords_rad, |b, coords| {
+            b.iter(|| {
+                site.ra_dec_to_alt_az_batch_rad(black_box(coords), datetime)
+            })
+        });
     }
     
     group.finish();
@@ -63,5 +108,19 @@ fn bench_location_parsing(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_coordinate_transforms, bench_location_parsing);
+/// Benchmark tabulated refraction against the formulas it replaces in servo loops
+fn bench_refraction_table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("refraction_table");
+    let conditions = AtmosphericConditions::standard();
+    let table = RefractionTable::build(RefractionModel::Saemundsson, &conditions, 0.0..=90.0, 0.05).unwrap();
+
+    group.bench_function("lookup", |b| b.iter(|| table.lookup(black_box(23.4))));
+    group.bench_function("saemundsson", |b| {
+        b.iter(|| RefractionModel::Saemundsson.refraction(black_box(23.4), 1013.25, 10.0))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_coordinate_transforms, bench_location_parsing, bench_refraction_table);
 criterion_main!(benches);
\ No newline at end of file
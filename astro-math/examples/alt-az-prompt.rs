@@ -1,5 +1,4 @@
-use astro_math::{Location, julian_date, ra_dec_to_alt_az};
-use chrono::Utc;
+use astro_math::prelude::*;
 use std::io::{self, Write};
 
 fn prompt_f64(prompt: &str) -> f64 {
@@ -1,8 +1,5 @@
-use astro_math::{
-    Location, moon_equatorial,
-    rise_set::{rise_transit_set, next_rise, next_set, sun_rise_set}
-};
-use chrono::{Datelike, TimeZone, Utc};
+use astro_math::prelude::*;
+use chrono::Datelike;
 
 fn main() {
     println!("=== Rise, Set, and Transit Times Example ===\n");
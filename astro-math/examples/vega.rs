@@ -1,5 +1,4 @@
-use astro_math::{Location, julian_date, ra_dec_to_alt_az};
-use chrono::{TimeZone, Utc};
+use astro_math::prelude::*;
 
 fn main() {
     let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
@@ -118,7 +118,7 @@ pub fn apply_aberration(
     let rv = 0.0;  // radial velocity (km/s)
     
     // Transform ICRS to CIRS - this includes aberration, precession, and nutation
-    let (ra_cirs, dec_cirs, _eo) = erfars::astrometry::Atci13(
+    let (ra_cirs, dec_cirs, _eo) = crate::backend::astrometry::Atci13(
         ra_rad, dec_rad, pr, pd, px, rv, jd_tt, 0.0,
     );
     
@@ -191,7 +191,7 @@ pub fn remove_aberration(
     
     // Use ERFA's inverse transformation (CIRS to ICRS)
     // This is the inverse of Atci13 - we use Atic13
-    let (ra_icrs, dec_icrs, _eo) = erfars::astrometry::Atic13(
+    let (ra_icrs, dec_icrs, _eo) = crate::backend::astrometry::Atic13(
         ra_rad, dec_rad, jd_tt, 0.0,
     );
     
@@ -0,0 +1,146 @@
+//! Error budgets for the main position routines.
+//!
+//! The `_with_accuracy` variants of the position functions
+//! ([`ra_dec_to_alt_az_with_accuracy`](crate::transforms::ra_dec_to_alt_az_with_accuracy),
+//! [`ra_dec_to_alt_az_erfa_with_accuracy`](crate::transforms::ra_dec_to_alt_az_erfa_with_accuracy),
+//! [`sun_ra_dec_with_accuracy`](crate::sun::sun_ra_dec_with_accuracy) and
+//! [`moon_equatorial_with_accuracy`](crate::moon::moon_equatorial_with_accuracy))
+//! return their usual result together with an estimated 1-sigma on-sky
+//! error in arcseconds. The estimate combines, in quadrature:
+//!
+//! - **Input uncertainty** — quantities the crate does not know, such as
+//!   UT1−UTC (DUT1) and polar motion, which are taken as zero
+//! - **Model simplifications** — effects a given path leaves out, such as
+//!   precession, nutation and aberration in the Meeus path
+//! - **Model truncation** — the published accuracy of the ephemerides, and
+//!   the pure-Rust backend's difference from ERFA when that backend is used
+//!
+//! The figures are meant to help choose between the fast Meeus path and
+//! the ERFA path at runtime, not to replace a full error analysis.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::accuracy::combine_errors;
+//!
+//! // Independent 3″ and 4″ errors combine to 5″
+//! assert!((combine_errors(&[3.0, 4.0]) - 5.0).abs() < 1e-12);
+//! ```
+
+use crate::time_scales::utc_to_tt_jd_for_date;
+
+/// 1-sigma of UT1−UTC when it is assumed to be zero, in seconds.
+///
+/// UTC is kept within 0.9 s of UT1; a uniform distribution over ±0.9 s
+/// has a standard deviation of 0.9/√3 s.
+pub const DUT1_SIGMA_SECONDS: f64 = 0.52;
+
+/// 1-sigma of the pole offset when polar motion is ignored, in arcseconds.
+pub const POLAR_MOTION_SIGMA_ARCSEC: f64 = 0.25;
+
+/// 1-sigma of a refraction model as a fraction of the refraction itself,
+/// covering the usual mismatch between assumed and actual weather.
+pub const REFRACTION_SIGMA_FRACTION: f64 = 0.02;
+
+/// RMS geocentric direction error of ERFA `Moon98` against ELP/MPP02 over
+/// 1950–2100, in arcseconds.
+pub const MOON98_SIGMA_ARCSEC: f64 = 2.9;
+
+/// Maximum diurnal aberration at the equator, in arcseconds.
+const DIURNAL_ABERRATION_ARCSEC: f64 = 0.32;
+
+/// Earth rotation in arcseconds per second of UT1.
+const EARTH_ROTATION_ARCSEC_PER_SECOND: f64 = 15.041;
+
+/// 1-sigma of the astrometry chain (`Atci13`, `Atco13`) relative to ERFA.
+#[cfg(feature = "pure-rust")]
+const BACKEND_ASTROMETRY_SIGMA_ARCSEC: f64 = 0.003;
+#[cfg(not(feature = "pure-rust"))]
+const BACKEND_ASTROMETRY_SIGMA_ARCSEC: f64 = 0.0;
+
+/// 1-sigma of the Earth ephemeris (`Epv00`) as a geocentric Sun direction:
+/// ERFA's 4.6 km RMS, or the truncated pure-Rust series.
+#[cfg(feature = "pure-rust")]
+const EARTH_EPHEMERIS_SIGMA_ARCSEC: f64 = 0.05;
+#[cfg(not(feature = "pure-rust"))]
+const EARTH_EPHEMERIS_SIGMA_ARCSEC: f64 = 0.006;
+
+/// Combines independent 1-sigma errors in quadrature (root sum of squares).
+///
+/// # Arguments
+/// * `errors` - Individual 1-sigma errors, all in the same unit
+///
+/// # Returns
+/// The combined 1-sigma error
+pub fn combine_errors(errors: &[f64]) -> f64 {
+    errors.iter().map(|e| e * e).sum::<f64>().sqrt()
+}
+
+/// On-sky error from assuming UT1 = UTC for a target at `dec_deg`.
+pub(crate) fn earth_rotation_sigma_arcsec(dec_deg: f64) -> f64 {
+    DUT1_SIGMA_SECONDS * EARTH_ROTATION_ARCSEC_PER_SECOND * dec_deg.to_radians().cos()
+}
+
+/// Diurnal aberration left out of a calculation, for an observer at `latitude_deg`.
+pub(crate) fn diurnal_aberration_arcsec(latitude_deg: f64) -> f64 {
+    DIURNAL_ABERRATION_ARCSEC * latitude_deg.to_radians().cos()
+}
+
+/// Polar motion plus the backend's astrometry error, shared by both alt/az paths.
+pub(crate) fn terrestrial_sigma_arcsec() -> f64 {
+    combine_errors(&[POLAR_MOTION_SIGMA_ARCSEC, BACKEND_ASTROMETRY_SIGMA_ARCSEC])
+}
+
+/// Angle between an ICRS catalog place and the apparent place of date
+/// (precession, nutation, annual aberration and light deflection).
+///
+/// This is the error made by treating catalog coordinates as coordinates
+/// of date, as the Meeus path does.
+pub(crate) fn catalog_to_apparent_arcsec(ra_deg: f64, dec_deg: f64, tt1: f64, tt2: f64) -> f64 {
+    let (rc, dc) = (ra_deg.to_radians(), dec_deg.to_radians());
+    let (ri, di, eo) = crate::backend::astrometry::Atci13(rc, dc, 0.0, 0.0, 0.0, 0.0, tt1, tt2);
+    separation_arcsec(rc, dc, ri - eo, di)
+}
+
+/// Ephemeris error from evaluating at UTC-derived TT while the true TT−UTC
+/// differs, for a body moving at `rate_arcsec_per_second` on the sky.
+///
+/// `tt_used` is the TT Julian Date actually passed to the ephemeris.
+pub(crate) fn time_scale_sigma_arcsec(jd_utc: f64, tt_used: f64, rate_arcsec_per_second: f64) -> f64 {
+    (utc_to_tt_jd_for_date(jd_utc) - tt_used).abs() * 86400.0 * rate_arcsec_per_second
+}
+
+/// Scales an ephemeris error outside 1900–2100, where the published
+/// comparisons stop: doubling per century beyond either end.
+pub(crate) fn ephemeris_epoch_factor(jd_tt: f64) -> f64 {
+    let year = 2000.0 + (jd_tt - 2451545.0) / 365.25;
+    let outside = (1900.0 - year).max(year - 2100.0).max(0.0);
+    2f64.powf(outside / 100.0)
+}
+
+/// 1-sigma of the geocentric Sun direction from the Earth ephemeris.
+pub(crate) fn earth_ephemeris_sigma_arcsec(jd_tt: f64) -> f64 {
+    EARTH_EPHEMERIS_SIGMA_ARCSEC * ephemeris_epoch_factor(jd_tt)
+}
+
+/// Angular rate (arcsec per second of time) of a body from its
+/// position-velocity vector in AU and AU/day.
+pub(crate) fn angular_rate_arcsec_per_second(pv: &[f64; 6]) -> f64 {
+    let (p, v) = ([pv[0], pv[1], pv[2]], [pv[3], pv[4], pv[5]]);
+    let cross = [
+        p[1] * v[2] - p[2] * v[1],
+        p[2] * v[0] - p[0] * v[2],
+        p[0] * v[1] - p[1] * v[0],
+    ];
+    let r2 = p.iter().map(|x| x * x).sum::<f64>();
+    let w = cross.iter().map(|x| x * x).sum::<f64>().sqrt() / r2;
+    w.to_degrees() * 3600.0 / 86400.0
+}
+
+/// Angular separation of two directions given in radians, in arcseconds.
+fn separation_arcsec(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> f64 {
+    let dra = ra2 - ra1;
+    let a = (dec2.cos() * dra.sin()).hypot(dec1.cos() * dec2.sin() - dec1.sin() * dec2.cos() * dra.cos());
+    let b = dec1.sin() * dec2.sin() + dec1.cos() * dec2.cos() * dra.cos();
+    a.atan2(b).to_degrees() * 3600.0
+}
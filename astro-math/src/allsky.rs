@@ -0,0 +1,415 @@
+//! All-sky (fisheye) camera projection and astrometric calibration.
+//!
+//! All-sky cameras used by meteor and cloud-monitoring networks image the
+//! whole hemisphere through a fisheye lens. Their geometry is well described by
+//! a radially symmetric model around the zenith:
+//!
+//! ```text
+//! z = 90° - altitude                       (zenith distance)
+//! r = k1·z + k2·z² + k3·z³                 (radial distance in pixels)
+//! θ = azimuth - rotation
+//! x = x0 + parity·r·sin(θ)
+//! y = y0 + r·cos(θ)
+//! ```
+//!
+//! where `(x0, y0)` is the zenith pixel and `parity` is `-1` for the usual
+//! mirrored view of a camera looking up at the sky.
+//!
+//! [`fit_all_sky_model`] solves for the zenith point, rotation and lens
+//! polynomial from a list of identified stars using Levenberg-Marquardt
+//! least squares.
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for altitudes outside [-90, 90] or pixels beyond the model
+//! - `AstroError::CalculationError` when a fit has too few or degenerate stars or fails to converge
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::allsky::{AllSkyModel, AllSkyStar, fit_all_sky_model};
+//!
+//! let truth = AllSkyModel::new(960.0, 540.0, 10.5).with_rotation(12.0);
+//! let stars: Vec<AllSkyStar> = [(70.0, 10.0), (45.0, 100.0), (30.0, 200.0), (20.0, 300.0), (60.0, 250.0)]
+//!     .iter()
+//!     .map(|&(alt, az)| {
+//!         let (x, y) = truth.alt_az_to_pixel(alt, az).unwrap();
+//!         AllSkyStar { x, y, alt_deg: alt, az_deg: az }
+//!     })
+//!     .collect();
+//!
+//! let fit = fit_all_sky_model(&stars, &AllSkyModel::new(950.0, 550.0, 10.0)).unwrap();
+//! assert!(fit.rms_px < 1e-3);
+//! assert!((fit.model.rotation_deg - 12.0).abs() < 1e-3);
+//! ```
+
+use crate::error::{AstroError, Result};
+use crate::location::Location;
+use crate::transforms::ra_dec_to_alt_az;
+use chrono::{DateTime, Utc};
+use nalgebra::{DMatrix, DVector};
+
+/// Geometric model of a zenith-pointing fisheye camera.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AllSkyModel {
+    /// Zenith X pixel coordinate
+    pub x0: f64,
+    /// Zenith Y pixel coordinate
+    pub y0: f64,
+    /// Azimuth of the image +Y direction in degrees
+    pub rotation_deg: f64,
+    /// Linear lens coefficient (pixels per degree of zenith distance)
+    pub k1: f64,
+    /// Quadratic lens coefficient (pixels per degree²)
+    pub k2: f64,
+    /// Cubic lens coefficient (pixels per degree³)
+    pub k3: f64,
+    /// `-1.0` for a mirrored (looking-up) view, `1.0` otherwise
+    pub parity: f64,
+}
+
+/// A star identified in an all-sky image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AllSkyStar {
+    /// Measured X pixel coordinate
+    pub x: f64,
+    /// Measured Y pixel coordinate
+    pub y: f64,
+    /// Catalog altitude in degrees
+    pub alt_deg: f64,
+    /// Catalog azimuth in degrees
+    pub az_deg: f64,
+}
+
+impl AllSkyStar {
+    /// Builds a star from its catalog RA/Dec at the time of the exposure.
+    ///
+    /// # Errors
+    /// Returns `AstroError::InvalidCoordinate` if RA or Dec is out of range.
+    pub fn from_ra_dec(
+        x: f64,
+        y: f64,
+        ra_deg: f64,
+        dec_deg: f64,
+        datetime: DateTime<Utc>,
+        location: &Location,
+    ) -> Result<Self> {
+        let (alt_deg, az_deg) = ra_dec_to_alt_az(ra_deg, dec_deg, datetime, location)?;
+        Ok(Self { x, y, alt_deg, az_deg })
+    }
+}
+
+/// Result of an all-sky calibration fit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllSkyFit {
+    /// Best-fit camera model
+    pub model: AllSkyModel,
+    /// RMS of the pixel residuals
+    pub rms_px: f64,
+    /// Per-star `(dx, dy)` residuals (measured - model) in pixels
+    pub residuals: Vec<(f64, f64)>,
+}
+
+impl AllSkyModel {
+    /// Creates an equidistant model (`r = k1·z`) with no rotation and a
+    /// mirrored view.
+    ///
+    /// # Arguments
+    /// * `x0`, `y0` - Zenith pixel
+    /// * `pixels_per_degree` - Plate scale near the zenith
+    pub fn new(x0: f64, y0: f64, pixels_per_degree: f64) -> Self {
+        Self {
+            x0,
+            y0,
+            rotation_deg: 0.0,
+            k1: pixels_per_degree,
+            k2: 0.0,
+            k3: 0.0,
+            parity: -1.0,
+        }
+    }
+
+    /// Set the rotation (azimuth of the image +Y direction) in degrees
+    pub fn with_rotation(mut self, rotation_deg: f64) -> Self {
+        self.rotation_deg = rotation_deg;
+        self
+    }
+
+    /// Set the higher-order lens coefficients
+    pub fn with_distortion(mut self, k2: f64, k3: f64) -> Self {
+        self.k2 = k2;
+        self.k3 = k3;
+        self
+    }
+
+    /// Set whether the image is mirrored (the usual case for a sky camera)
+    pub fn with_mirrored(mut self, mirrored: bool) -> Self {
+        self.parity = if mirrored { -1.0 } else { 1.0 };
+        self
+    }
+
+    /// Radial pixel distance for a zenith distance in degrees.
+    #[inline]
+    fn radius(&self, z: f64) -> f64 {
+        z * (self.k1 + z * (self.k2 + z * self.k3))
+    }
+
+    /// Projects horizontal coordinates to pixel coordinates.
+    ///
+    /// # Errors
+    /// Returns `AstroError::OutOfRange` if altitude is outside [-90, 90].
+    pub fn alt_az_to_pixel(&self, alt_deg: f64, az_deg: f64) -> Result<(f64, f64)> {
+        if !(-90.0..=90.0).contains(&alt_deg) {
+            return Err(AstroError::OutOfRange {
+                parameter: "altitude",
+                value: alt_deg,
+                min: -90.0,
+                max: 90.0,
+            });
+        }
+        Ok(self.project(alt_deg, az_deg))
+    }
+
+    fn project(&self, alt_deg: f64, az_deg: f64) -> (f64, f64) {
+        let r = self.radius(90.0 - alt_deg);
+        let theta = (az_deg - self.rotation_deg).to_radians();
+        (
+            self.x0 + self.parity * r * theta.sin(),
+            self.y0 + r * theta.cos(),
+        )
+    }
+
+    /// Converts pixel coordinates to horizontal coordinates.
+    ///
+    /// The lens polynomial is inverted with Newton's method.
+    ///
+    /// # Returns
+    /// `(altitude_deg, azimuth_deg)`
+    ///
+    /// # Errors
+    /// Returns `AstroError::OutOfRange` if the pixel lies beyond the point
+    /// where the lens polynomial stops increasing.
+    pub fn pixel_to_alt_az(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        let dx = self.parity * (x - self.x0);
+        let dy = y - self.y0;
+        let r = dx.hypot(dy);
+
+        let mut z = r / self.k1;
+        for _ in 0..50 {
+            let f = self.radius(z) - r;
+            let df = self.k1 + z * (2.0 * self.k2 + 3.0 * self.k3 * z);
+            if df <= 0.0 {
+                break;
+            }
+            let step = f / df;
+            z -= step;
+            if step.abs() < 1e-12 {
+                break;
+            }
+        }
+        if !z.is_finite() || (self.radius(z) - r).abs() > 1e-6 || z > 180.0 {
+            return Err(AstroError::OutOfRange {
+                parameter: "radial distance",
+                value: r,
+                min: 0.0,
+                max: self.radius(90.0),
+            });
+        }
+
+        let az = (dx.atan2(dy).to_degrees() + self.rotation_deg).rem_euclid(360.0);
+        Ok((90.0 - z, az))
+    }
+
+    fn to_params(self) -> [f64; 6] {
+        [self.x0, self.y0, self.rotation_deg, self.k1, self.k2, self.k3]
+    }
+
+    fn from_params(p: &[f64], parity: f64) -> Self {
+        Self {
+            x0: p[0],
+            y0: p[1],
+            rotation_deg: p[2].rem_euclid(360.0),
+            k1: p[3],
+            k2: p[4],
+            k3: p[5],
+            parity,
+        }
+    }
+}
+
+/// Fits an all-sky camera model to identified stars.
+///
+/// Both image parities are tried and the better solution is returned. The
+/// initial rotation is estimated from the stars themselves, so only a rough
+/// zenith position and plate scale are needed in `initial`.
+///
+/// # Arguments
+/// * `stars` - Identified stars with measured pixels and catalog alt/az
+/// * `initial` - Starting guess (zenith pixel and `k1` matter most)
+///
+/// # Errors
+/// Returns `AstroError::CalculationError` if fewer than 4 stars are given,
+/// the stars lie on a line in the image, they leave some model parameter
+/// undetermined (e.g. all at one altitude), or the solution does not
+/// converge within 200 iterations.
+pub fn fit_all_sky_model(stars: &[AllSkyStar], initial: &AllSkyModel) -> Result<AllSkyFit> {
+    if stars.len() < 4 {
+        return Err(AstroError::CalculationError {
+            calculation: "all-sky calibration",
+            reason: format!("need at least 4 stars, got {}", stars.len()),
+        });
+    }
+    if is_collinear(stars) {
+        return Err(AstroError::CalculationError {
+            calculation: "all-sky calibration",
+            reason: "stars lie on a line in the image".to_string(),
+        });
+    }
+
+    let fits = [-1.0, 1.0].map(|parity| {
+        let mut start = *initial;
+        start.parity = parity;
+        start.rotation_deg = estimate_rotation(stars, &start);
+        levenberg_marquardt(stars, start)
+    });
+    match fits {
+        [Ok(a), Ok(b)] => Ok(if b.rms_px < a.rms_px { b } else { a }),
+        [Ok(fit), Err(_)] | [Err(_), Ok(fit)] => Ok(fit),
+        // Report the error for the default (mirrored) parity
+        [Err(e), Err(_)] => Err(e),
+    }
+}
+
+/// Whether the star pixels lie along a line: the minor axis of their
+/// scatter is a negligible fraction of the major one.
+fn is_collinear(stars: &[AllSkyStar]) -> bool {
+    let n = stars.len() as f64;
+    let mx = stars.iter().map(|s| s.x).sum::<f64>() / n;
+    let my = stars.iter().map(|s| s.y).sum::<f64>() / n;
+    let (sxx, syy, sxy) = stars.iter().fold((0.0, 0.0, 0.0), |(xx, yy, xy), s| {
+        let (dx, dy) = (s.x - mx, s.y - my);
+        (xx + dx * dx, yy + dy * dy, xy + dx * dy)
+    });
+    let mean = (sxx + syy) / 2.0;
+    let spread = ((sxx - syy).powi(2) / 4.0 + sxy * sxy).sqrt();
+    mean - spread <= 1e-6 * (mean + spread)
+}
+
+/// Circular mean of the rotation implied by each star.
+fn estimate_rotation(stars: &[AllSkyStar], model: &AllSkyModel) -> f64 {
+    let (s, c) = stars.iter().fold((0.0, 0.0), |(s, c), star| {
+        let dx = model.parity * (star.x - model.x0);
+        let dy = star.y - model.y0;
+        let rot = (star.az_deg - dx.atan2(dy).to_degrees()).to_radians();
+        (s + rot.sin(), c + rot.cos())
+    });
+    s.atan2(c).to_degrees().rem_euclid(360.0)
+}
+
+fn residuals(stars: &[AllSkyStar], model: &AllSkyModel) -> DVector<f64> {
+    DVector::from_iterator(
+        stars.len() * 2,
+        stars.iter().flat_map(|star| {
+            let (x, y) = model.project(star.alt_deg, star.az_deg);
+            [star.x - x, star.y - y]
+        }),
+    )
+}
+
+/// Forward-difference Jacobian of the residuals with respect to the parameters.
+fn jacobian(stars: &[AllSkyStar], params: &[f64; 6], parity: f64, res: &DVector<f64>) -> DMatrix<f64> {
+    const STEPS: [f64; 6] = [1e-3, 1e-3, 1e-5, 1e-6, 1e-8, 1e-10];
+    let mut jac = DMatrix::zeros(res.len(), 6);
+    for (j, &h) in STEPS.iter().enumerate() {
+        let mut p = *params;
+        p[j] += h;
+        let shifted = residuals(stars, &AllSkyModel::from_params(&p, parity));
+        jac.set_column(j, &((shifted - res) / h));
+    }
+    jac
+}
+
+fn levenberg_marquardt(stars: &[AllSkyStar], start: AllSkyModel) -> Result<AllSkyFit> {
+    let parity = start.parity;
+    let mut params = start.to_params();
+    let mut res = residuals(stars, &start);
+    let mut cost = res.norm_squared();
+    let mut lambda = 1e-3;
+    let mut converged = false;
+
+    for _ in 0..200 {
+        let jac = jacobian(stars, &params, parity, &res);
+        let jtj = jac.transpose() * &jac;
+        let jtr = jac.transpose() * &res;
+
+        let mut improved = false;
+        while lambda < 1e12 {
+            let mut a = jtj.clone();
+            for i in 0..6 {
+                a[(i, i)] += lambda * jtj[(i, i)].max(1e-12);
+            }
+            let Some(delta) = a.lu().solve(&(-&jtr)) else {
+                lambda *= 10.0;
+                continue;
+            };
+            let scale = params.iter().map(|p| p * p).sum::<f64>().sqrt();
+            if delta.norm() <= 1e-12 * (scale + 1e-12) {
+                converged = true;
+                break;
+            }
+            let mut trial = params;
+            for (t, d) in trial.iter_mut().zip(delta.iter()) {
+                *t += d;
+            }
+            let trial_res = residuals(stars, &AllSkyModel::from_params(&trial, parity));
+            let trial_cost = trial_res.norm_squared();
+            if trial_cost.is_finite() && trial_cost < cost {
+                converged = (cost - trial_cost) <= 1e-14 * cost.max(1.0);
+                params = trial;
+                res = trial_res;
+                cost = trial_cost;
+                lambda = (lambda / 10.0).max(1e-12);
+                improved = !converged;
+                break;
+            }
+            lambda *= 10.0;
+        }
+
+        if !improved {
+            break;
+        }
+    }
+
+    if !converged {
+        return Err(AstroError::CalculationError {
+            calculation: "all-sky calibration",
+            reason: "fit did not converge".to_string(),
+        });
+    }
+
+    // Correlation matrix of the parameters: a near-zero eigenvalue means
+    // some combination of them is not constrained by the stars
+    let jtj = {
+        let jac = jacobian(stars, &params, parity, &res);
+        jac.transpose() * jac
+    };
+    let corr = DMatrix::from_fn(6, 6, |i, j| jtj[(i, j)] / (jtj[(i, i)] * jtj[(j, j)]).sqrt());
+    if !corr.symmetric_eigenvalues().iter().all(|&e| e > 1e-10) {
+        return Err(AstroError::CalculationError {
+            calculation: "all-sky calibration",
+            reason: "stars do not constrain every model parameter".to_string(),
+        });
+    }
+
+    let model = AllSkyModel::from_params(&params, parity);
+    let residuals = res
+        .as_slice()
+        .chunks(2)
+        .map(|c| (c[0], c[1]))
+        .collect::<Vec<_>>();
+    Ok(AllSkyFit {
+        model,
+        rms_px: (cost / stars.len() as f64).sqrt(),
+        residuals,
+    })
+}
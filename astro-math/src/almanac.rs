@@ -0,0 +1,490 @@
+//! Monthly almanac tables for the Sun, Moon and planets.
+//!
+//! [`monthly_table`] produces the classic almanac page for one body and
+//! observer: for each day of a month, the times of rise, meridian transit
+//! and set, with the body's apparent position, distance and visual
+//! magnitude. The rows render as CSV or JSON with
+//! [`almanac_to_csv`](crate::export::almanac_to_csv) and
+//! [`almanac_to_json`](crate::export::almanac_to_json).
+//!
+//! Positions come from the crate's ephemerides (`Epv00` for the Earth and
+//! Sun, `Moon98` for the Moon, `Plan94` for the planets) and are apparent,
+//! referred to the true equator and equinox of date: light time, annual
+//! aberration, precession and nutation are applied. Rise and set come from
+//! [`body_rise_set`]: the topocentric upper limb crossing -34′ of
+//! refraction on a sea-level horizon, as in
+//! [`moon_rise_set`](crate::rise_set::moon_rise_set).
+//!
+//! Days are UTC days, and each event is the first of its kind on the day,
+//! so a Moon or planet rising ~50 minutes later each day has one day a
+//! month without a rise. UT1 is taken equal to UTC, as elsewhere in the
+//! crate.
+//!
+//! [`sky_snapshot`] gives every body at once as seen from a site: position,
+//! altitude, magnitude, apparent diameter and the separation of each pair,
+//! for "tonight's sky" displays and conjunction finders.
+//!
+//! Magnitudes use the formulae of the *Astronomical Almanac* as given by
+//! Meeus (*Astronomical Algorithms*, 2nd ed., ch. 41), including the tilt of
+//! Saturn's rings, and Allen's phase law for the Moon. They are good to
+//! about 0.1 mag.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::almanac::{monthly_table, AlmanacBody};
+//! use astro_math::export::almanac_to_csv;
+//! use astro_math::Location;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let location = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+//! let december = Utc.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap();
+//!
+//! // Jupiter around its opposition of 2024 December 7
+//! let table = monthly_table(AlmanacBody::Jupiter, december, &location).unwrap();
+//! assert_eq!(table.len(), 31);
+//! let opposition = &table[6];
+//! assert!((opposition.magnitude + 2.8).abs() < 0.1);
+//! // Transiting near local midnight
+//! let transit = opposition.transit.unwrap();
+//! assert!(transit.format("%H").to_string() == "23" || transit.format("%H").to_string() == "00");
+//!
+//! let csv = almanac_to_csv(&table);
+//! assert_eq!(csv.lines().count(), 32);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range latitude or longitude
+//! - `AstroError::CalculationError` if the planetary ephemeris rejects the
+//!   date
+
+use crate::ephemeris::topocentric_place;
+use crate::erfa::bias_precession_nutation_matrix;
+use crate::error::{validate_latitude, validate_longitude, Result};
+use crate::events::{find_zeros, Crossing};
+use crate::location::Location;
+use crate::nutation::true_obliquity;
+use crate::propagator::Planet;
+use crate::rise_set::body_rise_set;
+use crate::sidereal::apparent_sidereal_time;
+use crate::time::julian_date;
+use crate::time_scales::utc_to_tt_jd;
+use crate::transforms::ra_dec_to_alt_az;
+use crate::vec3::{angle_between, dot, norm, normalize, radec_to_unit_vector, unit_vector_to_radec, Vec3};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+
+/// Astronomical unit in kilometers
+const AU_KM: f64 = 149_597_870.7;
+
+/// Speed of light in AU per day
+const C_AU_PER_DAY: f64 = 173.1446326846693;
+
+/// ICRS direction of the north pole of Saturn's rings (IAU 2009), RA and
+/// Dec in degrees
+const SATURN_POLE: (f64, f64) = (40.589, 83.537);
+
+/// A body that [`monthly_table`] can tabulate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlmanacBody {
+    Sun,
+    Moon,
+    Mercury,
+    Venus,
+    Mars,
+    Jupiter,
+    Saturn,
+    Uranus,
+    Neptune,
+}
+
+impl AlmanacBody {
+    /// All bodies, in almanac order.
+    pub const ALL: [AlmanacBody; 9] = [
+        AlmanacBody::Sun,
+        AlmanacBody::Moon,
+        AlmanacBody::Mercury,
+        AlmanacBody::Venus,
+        AlmanacBody::Mars,
+        AlmanacBody::Jupiter,
+        AlmanacBody::Saturn,
+        AlmanacBody::Uranus,
+        AlmanacBody::Neptune,
+    ];
+
+    /// English name, e.g. for event summaries.
+    pub fn name(self) -> &'static str {
+        match self {
+            AlmanacBody::Sun => "Sun",
+            AlmanacBody::Moon => "Moon",
+            AlmanacBody::Mercury => "Mercury",
+            AlmanacBody::Venus => "Venus",
+            AlmanacBody::Mars => "Mars",
+            AlmanacBody::Jupiter => "Jupiter",
+            AlmanacBody::Saturn => "Saturn",
+            AlmanacBody::Uranus => "Uranus",
+            AlmanacBody::Neptune => "Neptune",
+        }
+    }
+
+    /// Equatorial radius in km; Saturn's is the globe's, without the rings.
+    pub fn equatorial_radius_km(self) -> f64 {
+        match self {
+            AlmanacBody::Sun => 696_000.0,
+            AlmanacBody::Moon => 1737.4,
+            AlmanacBody::Mercury => 2440.5,
+            AlmanacBody::Venus => 6051.8,
+            AlmanacBody::Mars => 3396.2,
+            AlmanacBody::Jupiter => 71_492.0,
+            AlmanacBody::Saturn => 60_268.0,
+            AlmanacBody::Uranus => 25_559.0,
+            AlmanacBody::Neptune => 24_764.0,
+        }
+    }
+
+    fn planet(self) -> Option<Planet> {
+        match self {
+            AlmanacBody::Sun | AlmanacBody::Moon => None,
+            AlmanacBody::Mercury => Some(Planet::Mercury),
+            AlmanacBody::Venus => Some(Planet::Venus),
+            AlmanacBody::Mars => Some(Planet::Mars),
+            AlmanacBody::Jupiter => Some(Planet::Jupiter),
+            AlmanacBody::Saturn => Some(Planet::Saturn),
+            AlmanacBody::Uranus => Some(Planet::Uranus),
+            AlmanacBody::Neptune => Some(Planet::Neptune),
+        }
+    }
+}
+
+/// Apparent place of a body at one instant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApparentPlace {
+    /// Right ascension in degrees, true equator and equinox of date
+    pub ra: f64,
+    /// Declination in degrees, true equator and equinox of date
+    pub dec: f64,
+    /// Geocentric distance in AU
+    pub distance_au: f64,
+    /// Visual magnitude
+    pub magnitude: f64,
+}
+
+/// One day of a [`monthly_table`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlmanacRow {
+    /// Day of the row (00:00 UTC)
+    pub date: DateTime<Utc>,
+    /// Rise time, `None` if the body does not rise on this day
+    pub rise: Option<DateTime<Utc>>,
+    /// Upper meridian transit, `None` if there is none on this day
+    pub transit: Option<DateTime<Utc>>,
+    /// Set time, `None` if the body does not set on this day
+    pub set: Option<DateTime<Utc>>,
+    /// Apparent right ascension at 00:00 UTC in degrees
+    pub ra: f64,
+    /// Apparent declination at 00:00 UTC in degrees
+    pub dec: f64,
+    /// Geocentric distance at 00:00 UTC in AU
+    pub distance_au: f64,
+    /// Visual magnitude at 00:00 UTC
+    pub magnitude: f64,
+}
+
+/// Computes the apparent place and magnitude of a body.
+///
+/// # Arguments
+/// * `body` - Sun, Moon or planet
+/// * `datetime` - Time of interest
+///
+/// # Returns
+/// Geocentric apparent RA/Dec of date, distance and visual magnitude
+///
+/// # Errors
+/// Returns `Err(AstroError::CalculationError)` if the planetary ephemeris
+/// rejects the date.
+///
+/// # Example
+/// ```
+/// use astro_math::almanac::{apparent_place, AlmanacBody};
+/// use chrono::{TimeZone, Utc};
+///
+/// // Venus near greatest brilliancy
+/// let t = Utc.with_ymd_and_hms(2025, 2, 16, 0, 0, 0).unwrap();
+/// let venus = apparent_place(AlmanacBody::Venus, t).unwrap();
+/// assert!((venus.magnitude + 4.8).abs() < 0.2);
+/// assert!(venus.distance_au < 0.5);
+/// ```
+pub fn apparent_place(body: AlmanacBody, datetime: DateTime<Utc>) -> Result<ApparentPlace> {
+    let tt = utc_to_tt_jd(julian_date(datetime));
+    let (earth_h, earth_b) = crate::backend::ephemerides::Epv00(tt, 0.0);
+    let earth = [earth_h[0], earth_h[1], earth_h[2]];
+
+    // Geometric heliocentric position of the body and its position relative
+    // to the Earth when the light left it
+    let (heliocentric, geocentric) = match body {
+        AlmanacBody::Sun => ([0.0; 3], earth.map(|c| -c)),
+        AlmanacBody::Moon => {
+            let moon = |dt: f64| {
+                let pv = crate::backend::ephemerides::Moon98(tt - dt, 0.0);
+                [pv[0], pv[1], pv[2]]
+            };
+            let mut geocentric = moon(0.0);
+            for _ in 0..2 {
+                geocentric = moon(norm(geocentric) / C_AU_PER_DAY);
+            }
+            (add(earth, geocentric), geocentric)
+        }
+        _ => {
+            let planet = body.planet().expect("Sun and Moon handled above");
+            let mut heliocentric = planet.heliocentric_state(tt)?.position;
+            for _ in 0..2 {
+                let light_time = norm(sub(heliocentric, earth)) / C_AU_PER_DAY;
+                heliocentric = planet.heliocentric_state(tt - light_time)?.position;
+            }
+            (heliocentric, sub(heliocentric, earth))
+        }
+    };
+
+    // The Moon moves with the Earth, so its light-time displacement already
+    // cancels the annual aberration
+    let direction = normalize(geocentric);
+    let apparent = match body {
+        AlmanacBody::Moon => direction,
+        _ => normalize(add(direction, [earth_b[3], earth_b[4], earth_b[5]].map(|v| v / C_AU_PER_DAY))),
+    };
+    let npb = bias_precession_nutation_matrix(tt, 0.0);
+    let (ra, dec) = unit_vector_to_radec([dot(npb[0], apparent), dot(npb[1], apparent), dot(npb[2], apparent)]);
+
+    Ok(ApparentPlace {
+        ra,
+        dec,
+        distance_au: norm(geocentric),
+        magnitude: magnitude(body, heliocentric, geocentric),
+    })
+}
+
+/// Tabulates rise, transit, set, position and magnitude for each day of a
+/// month.
+///
+/// # Arguments
+/// * `body` - Sun, Moon or planet
+/// * `month` - Any time in the month; the table covers its UTC days
+/// * `location` - Observer's location
+///
+/// # Returns
+/// One [`AlmanacRow`] per day, in date order
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - `AstroError::CalculationError` if the planetary ephemeris rejects the
+///   date
+pub fn monthly_table(body: AlmanacBody, month: DateTime<Utc>, location: &Location) -> Result<Vec<AlmanacRow>> {
+    validate_latitude(location.latitude_deg)?;
+    validate_longitude(location.longitude_deg)?;
+
+    let first = Utc.with_ymd_and_hms(month.year(), month.month(), 1, 0, 0, 0).unwrap();
+    let next = match month.month() {
+        12 => Utc.with_ymd_and_hms(month.year() + 1, 1, 1, 0, 0, 0).unwrap(),
+        m => Utc.with_ymd_and_hms(month.year(), m + 1, 1, 0, 0, 0).unwrap(),
+    };
+
+    let mut rows = Vec::new();
+    let mut date = first;
+    while date < next {
+        // Evaluating the midnight place first surfaces any ephemeris error;
+        // within the day the searches can then treat failures as NaN
+        let place = apparent_place(body, date)?;
+        let hour_angle_sine = |t| {
+            apparent_place(body, t)
+                .map(|p| {
+                    let last_deg = apparent_sidereal_time(julian_date(t), location.longitude_deg) * 15.0;
+                    (last_deg - p.ra).to_radians().sin()
+                })
+                .unwrap_or(f64::NAN)
+        };
+
+        let end = date + Duration::days(1);
+        let step = Duration::minutes(10);
+        let tolerance = Duration::seconds(1);
+        let (rise, set) = body_rise_set(&body, date, location)?;
+        // sin(HA) rises through zero at upper transit and falls at lower
+        let transit = find_zeros(hour_angle_sine, date, end, step, tolerance)?
+            .into_iter()
+            .find(|c| c.1 == Crossing::Rising)
+            .map(|c| c.0);
+
+        rows.push(AlmanacRow {
+            date,
+            rise,
+            transit,
+            set,
+            ra: place.ra,
+            dec: place.dec,
+            distance_au: place.distance_au,
+            magnitude: place.magnitude,
+        });
+        date = end;
+    }
+    Ok(rows)
+}
+
+/// One body in a [`Snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapshotBody {
+    /// The body
+    pub body: AlmanacBody,
+    /// Topocentric right ascension in degrees, true equator and equinox of
+    /// date
+    pub ra: f64,
+    /// Topocentric declination in degrees, true equator and equinox of date
+    pub dec: f64,
+    /// Geometric altitude in degrees, without refraction
+    pub altitude: f64,
+    /// Azimuth in degrees, north through east
+    pub azimuth: f64,
+    /// Distance from the observer in AU
+    pub distance_au: f64,
+    /// Visual magnitude
+    pub magnitude: f64,
+    /// Apparent equatorial diameter in arcseconds
+    pub diameter_arcsec: f64,
+}
+
+/// The Sun, Moon and planets as seen from one place at one instant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    /// Time of the snapshot
+    pub time: DateTime<Utc>,
+    /// One entry per body, in the order of [`AlmanacBody::ALL`]
+    pub bodies: Vec<SnapshotBody>,
+    /// Topocentric angular separations in degrees: `separations[i][j]` is
+    /// between `bodies[i]` and `bodies[j]`
+    pub separations: Vec<Vec<f64>>,
+}
+
+impl Snapshot {
+    /// The entry for a body.
+    pub fn body(&self, body: AlmanacBody) -> &SnapshotBody {
+        &self.bodies[Self::index(body)]
+    }
+
+    /// Topocentric angular separation of two bodies in degrees.
+    pub fn separation(&self, a: AlmanacBody, b: AlmanacBody) -> f64 {
+        self.separations[Self::index(a)][Self::index(b)]
+    }
+
+    fn index(body: AlmanacBody) -> usize {
+        AlmanacBody::ALL.iter().position(|&b| b == body).expect("ALL lists every body")
+    }
+}
+
+/// Computes where the Sun, Moon and planets are in the sky, in one call.
+///
+/// Positions are topocentric, so the Moon's parallax (up to 1°) is
+/// included in its altitude, its separations from the other bodies and its
+/// diameter. Magnitudes are geocentric, as in [`apparent_place`].
+///
+/// # Arguments
+/// * `datetime` - Time of observation
+/// * `location` - Observer's location
+///
+/// # Returns
+/// A [`Snapshot`] with every body in [`AlmanacBody::ALL`] and the matrix of
+/// their separations
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - `AstroError::CalculationError` if the planetary ephemeris rejects the
+///   date
+///
+/// # Example
+/// ```
+/// use astro_math::almanac::{sky_snapshot, AlmanacBody};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// // Venus and Jupiter half a degree apart on the evening of 2023 March 1
+/// let site = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let dusk = Utc.with_ymd_and_hms(2023, 3, 1, 18, 30, 0).unwrap();
+/// let sky = sky_snapshot(dusk, &site).unwrap();
+/// assert!(sky.separation(AlmanacBody::Venus, AlmanacBody::Jupiter) < 0.8);
+/// assert!(sky.body(AlmanacBody::Venus).altitude > 10.0);
+/// assert!((sky.body(AlmanacBody::Moon).diameter_arcsec - 1800.0).abs() < 200.0);
+/// ```
+pub fn sky_snapshot(datetime: DateTime<Utc>, location: &Location) -> Result<Snapshot> {
+    let mut bodies = Vec::with_capacity(AlmanacBody::ALL.len());
+    for body in AlmanacBody::ALL {
+        let (ra, dec, distance_km) = topocentric_place(&body, datetime, location)?;
+        let distance_km = distance_km.expect("the Sun, Moon and planets have distances");
+        let (altitude, azimuth) = ra_dec_to_alt_az(ra, dec, datetime, location)?;
+        let diameter = 2.0 * (body.equatorial_radius_km() / distance_km).asin();
+        bodies.push(SnapshotBody {
+            body,
+            ra,
+            dec,
+            altitude,
+            azimuth,
+            distance_au: distance_km / AU_KM,
+            magnitude: apparent_place(body, datetime)?.magnitude,
+            diameter_arcsec: diameter.to_degrees() * 3600.0,
+        });
+    }
+
+    let directions: Vec<Vec3> = bodies.iter().map(|b| radec_to_unit_vector(b.ra, b.dec)).collect();
+    let separations = directions
+        .iter()
+        .map(|&a| directions.iter().map(|&b| angle_between(a, b).to_degrees()).collect())
+        .collect();
+    Ok(Snapshot { time: datetime, bodies, separations })
+}
+
+/// Visual magnitude from the heliocentric and geocentric positions in AU.
+fn magnitude(body: AlmanacBody, heliocentric: Vec3, geocentric: Vec3) -> f64 {
+    let delta = norm(geocentric);
+    if body == AlmanacBody::Sun {
+        return -26.74 + 5.0 * delta.log10();
+    }
+    let r = norm(heliocentric);
+    let distance_term = 5.0 * (r * delta).log10();
+    // Phase angle: Sun–body–Earth
+    let i = angle_between(heliocentric.map(|c| -c), geocentric.map(|c| -c)).to_degrees();
+    match body {
+        AlmanacBody::Moon => 0.21 + distance_term + 0.026 * i + 4.0e-9 * i.powi(4),
+        AlmanacBody::Mercury => -0.42 + distance_term + 0.0380 * i - 0.000273 * i * i + 0.000002 * i.powi(3),
+        AlmanacBody::Venus => -4.40 + distance_term + 0.0009 * i + 0.000239 * i * i - 0.00000065 * i.powi(3),
+        AlmanacBody::Mars => -1.52 + distance_term + 0.016 * i,
+        AlmanacBody::Jupiter => -9.40 + distance_term + 0.005 * i,
+        AlmanacBody::Saturn => {
+            // Saturnicentric latitude of the Earth above the ring plane, and
+            // the difference of the Sun's and Earth's longitudes in it
+            let pole = radec_to_unit_vector(SATURN_POLE.0, SATURN_POLE.1);
+            let to_earth = normalize(geocentric.map(|c| -c));
+            let to_sun = normalize(heliocentric.map(|c| -c));
+            let b = dot(pole, to_earth).asin();
+            let in_plane = |v: Vec3| sub(v, pole.map(|c| c * dot(pole, v)));
+            let delta_u = angle_between(in_plane(to_earth), in_plane(to_sun)).to_degrees();
+            -8.88 + distance_term + 0.044 * delta_u - 2.60 * b.abs().sin() + 1.25 * b.sin().powi(2)
+        }
+        AlmanacBody::Uranus => -7.19 + distance_term,
+        AlmanacBody::Neptune => -6.87 + distance_term,
+        AlmanacBody::Sun => unreachable!("handled above"),
+    }
+}
+
+/// Ecliptic longitude of date, in degrees in [0, 360), of a direction on
+/// the true equator and equinox of date.
+pub(crate) fn ecliptic_longitude_of_date(ra: f64, dec: f64, datetime: DateTime<Utc>) -> f64 {
+    let epsilon = true_obliquity(utc_to_tt_jd(julian_date(datetime))).to_radians();
+    let (ra, dec) = (ra.to_radians(), dec.to_radians());
+    (ra.sin() * epsilon.cos() + dec.tan() * epsilon.sin()).atan2(ra.cos()).to_degrees().rem_euclid(360.0)
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
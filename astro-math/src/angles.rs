@@ -0,0 +1,179 @@
+//! Angular separation, position angle and offsets between sky positions.
+//!
+//! The basic measurements between two RA/Dec positions, as astropy's
+//! `SkyCoord.separation`, `position_angle` and `directional_offset_by`:
+//!
+//! - [`angular_separation`] — great-circle distance, with the Vincenty
+//!   formula, which keeps full precision for separations near 0° and 180°
+//!   where the cosine formula (and the haversine near 180°) lose digits
+//! - [`position_angle`] — direction of one position from another, east of
+//!   north
+//! - [`offset_by`] — the position at a given position angle and separation,
+//!   the inverse of the two above
+//!
+//! All three work at the celestial poles, where north is taken along the
+//! meridian of the given RA: from the north pole, north points towards
+//! RA + 180°.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::angles::{angular_separation, offset_by, position_angle};
+//!
+//! // Mizar and Alcor, 11.8′ apart
+//! let (mizar, alcor) = ((200.981429, 54.925362), (201.306403, 54.987954));
+//! let sep = angular_separation(mizar.0, mizar.1, alcor.0, alcor.1).unwrap();
+//! let pa = position_angle(mizar.0, mizar.1, alcor.0, alcor.1).unwrap();
+//! assert!((sep * 60.0 - 11.8).abs() < 0.05);
+//! assert!((pa - 71.0).abs() < 0.5);
+//!
+//! // Walking back out along the same great circle lands on Alcor
+//! let (ra, dec) = offset_by(mizar.0, mizar.1, pa, sep).unwrap();
+//! assert!((ra - alcor.0).abs() < 1e-9 && (dec - alcor.1).abs() < 1e-9);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::CalculationError` for non-finite inputs
+
+use crate::error::{validate_dec, validate_finite, validate_ra, Result};
+use crate::vec3::{radec_to_unit_vector, unit_vector_to_radec};
+
+/// Calculates the angular separation between two positions.
+///
+/// # Arguments
+/// * `ra1`, `dec1` - First position in degrees
+/// * `ra2`, `dec2` - Second position in degrees
+///
+/// # Returns
+/// Separation in degrees, in [0, 180]
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if an RA is outside [0, 360) or a Dec
+///   outside [-90, 90]
+/// - `AstroError::CalculationError` if any input is not finite
+///
+/// # Example
+/// ```
+/// use astro_math::angles::angular_separation;
+///
+/// // A milliarcsecond is resolved, where acos(cos θ) would return 0
+/// let sep = angular_separation(10.0, 0.0, 10.0, 1e-3 / 3600.0).unwrap();
+/// assert!((sep * 3600.0 - 1e-3).abs() < 1e-15);
+///
+/// // ...and so is a near-antipode
+/// let sep = angular_separation(0.0, 0.0, 180.0, 1e-3 / 3600.0).unwrap();
+/// assert!(((180.0 - sep) * 3600.0 - 1e-3).abs() < 1e-9);
+/// ```
+pub fn angular_separation(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> Result<f64> {
+    validate_ra(ra1)?;
+    validate_dec(dec1)?;
+    validate_ra(ra2)?;
+    validate_dec(dec2)?;
+    Ok(separation(ra1, dec1, ra2, dec2))
+}
+
+/// Calculates the position angle of one position as seen from another.
+///
+/// The angle at the first position from the direction of the north
+/// celestial pole to the great circle towards the second, increasing
+/// through east.
+///
+/// # Arguments
+/// * `ra1`, `dec1` - Position the angle is measured at, in degrees
+/// * `ra2`, `dec2` - Position the angle is measured to, in degrees
+///
+/// # Returns
+/// Position angle in degrees, in [0, 360); 0 for coincident positions
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if an RA is outside [0, 360) or a Dec
+///   outside [-90, 90]
+/// - `AstroError::CalculationError` if any input is not finite
+///
+/// # Example
+/// ```
+/// use astro_math::angles::position_angle;
+///
+/// // Due north, and due east along the equator
+/// assert!(position_angle(50.0, 10.0, 50.0, 11.0).unwrap().abs() < 1e-9);
+/// assert!((position_angle(50.0, 0.0, 51.0, 0.0).unwrap() - 90.0).abs() < 1e-9);
+/// ```
+pub fn position_angle(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> Result<f64> {
+    validate_ra(ra1)?;
+    validate_dec(dec1)?;
+    validate_ra(ra2)?;
+    validate_dec(dec2)?;
+    Ok(bearing(ra1, dec1, ra2, dec2).rem_euclid(360.0) % 360.0)
+}
+
+/// Finds the position a given separation away in a given direction.
+///
+/// Moves along the great circle leaving `(ra, dec)` at `position_angle`,
+/// the inverse of [`angular_separation`] and [`position_angle`].
+///
+/// # Arguments
+/// * `ra`, `dec` - Starting position in degrees
+/// * `position_angle` - Direction to move in, degrees east of north
+/// * `separation` - Distance to move in degrees; a negative separation
+///   moves the opposite way
+///
+/// # Returns
+/// `(ra, dec)` of the new position in degrees, RA in [0, 360)
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if `ra` is outside [0, 360) or `dec`
+///   outside [-90, 90]
+/// - `AstroError::CalculationError` if any input is not finite
+///
+/// # Example
+/// ```
+/// use astro_math::angles::offset_by;
+///
+/// // 10° east of a point on the equator
+/// let (ra, dec) = offset_by(355.0, 0.0, 90.0, 10.0).unwrap();
+/// assert!((ra - 5.0).abs() < 1e-9 && dec.abs() < 1e-9);
+///
+/// // Over the north pole and down the far side
+/// let (ra, dec) = offset_by(30.0, 80.0, 0.0, 20.0).unwrap();
+/// assert!((ra - 210.0).abs() < 1e-9 && (dec - 80.0).abs() < 1e-9);
+/// ```
+pub fn offset_by(ra: f64, dec: f64, position_angle: f64, separation: f64) -> Result<(f64, f64)> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    validate_finite(position_angle, "position_angle")?;
+    validate_finite(separation, "separation")?;
+
+    let (sin_ra, cos_ra) = ra.to_radians().sin_cos();
+    let (sin_dec, cos_dec) = dec.to_radians().sin_cos();
+    let (sin_pa, cos_pa) = position_angle.to_radians().sin_cos();
+    let (sin_sep, cos_sep) = separation.to_radians().sin_cos();
+
+    // Local north and east at the start; at a pole these follow the
+    // meridian of `ra`
+    let start = radec_to_unit_vector(ra, dec);
+    let north = [-sin_dec * cos_ra, -sin_dec * sin_ra, cos_dec];
+    let east = [-sin_ra, cos_ra, 0.0];
+    let end = [0, 1, 2].map(|i| start[i] * cos_sep + (north[i] * cos_pa + east[i] * sin_pa) * sin_sep);
+    Ok(unit_vector_to_radec(end))
+}
+
+/// Separation in degrees, without validation.
+pub(crate) fn separation(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> f64 {
+    let (sin_d1, cos_d1) = dec1.to_radians().sin_cos();
+    let (sin_d2, cos_d2) = dec2.to_radians().sin_cos();
+    let (sin_dra, cos_dra) = (ra2 - ra1).to_radians().sin_cos();
+    // Vincenty: atan2 of |a × b| and a · b
+    let y = (cos_d2 * sin_dra).hypot(cos_d1 * sin_d2 - sin_d1 * cos_d2 * cos_dra);
+    let x = sin_d1 * sin_d2 + cos_d1 * cos_d2 * cos_dra;
+    y.atan2(x).to_degrees()
+}
+
+/// Position angle in degrees, in (-180, 180], without validation.
+pub(crate) fn bearing(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> f64 {
+    let (sin_d1, cos_d1) = dec1.to_radians().sin_cos();
+    let (sin_d2, cos_d2) = dec2.to_radians().sin_cos();
+    let (sin_dra, cos_dra) = (ra2 - ra1).to_radians().sin_cos();
+    (sin_dra * cos_d2).atan2(cos_d1 * sin_d2 - sin_d1 * cos_d2 * cos_dra).to_degrees()
+}
@@ -0,0 +1,144 @@
+//! Apparent places of date and how fast they drift.
+//!
+//! The apparent place is where a star appears on the true equator and
+//! equinox of date, as seen from the geocentre: the ICRS position with
+//! light deflection, annual aberration, precession and nutation applied.
+//! It is the "JNow" that most mount controllers point in.
+//!
+//! Because all of those effects change with time, apparent coordinates
+//! drift even for a star with no proper motion. [`apparent_place_drift`]
+//! gives the rate, typically a few hundred milliarcseconds per day:
+//! precession contributes up to ~140 mas/day, the annual cycle of aberration
+//! up to ~350 mas/day and nutation up to ~100 mas/day. Over a long unguided
+//! survey this decides how often a field must be re-solved, and it
+//! separates genuine slow drifts of a mount from the sky's own motion.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::apparent::{apparent_place, apparent_place_drift};
+//! use chrono::{Duration, TimeZone, Utc};
+//!
+//! let dt = Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap();
+//! let (dra, ddec) = apparent_place_drift(83.82, -5.39, dt).unwrap();
+//! assert!(dra.hypot(ddec) < 600.0);
+//!
+//! // An hour later the apparent place has moved by a 24th of the daily rate
+//! let (_, dec0) = apparent_place(83.82, -5.39, dt).unwrap();
+//! let (_, dec1) = apparent_place(83.82, -5.39, dt + Duration::hours(1)).unwrap();
+//! assert!(((dec1 - dec0) * 3.6e6 - ddec / 24.0).abs() < 0.1);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+
+use crate::error::{validate_dec, validate_ra, Result};
+use crate::time::julian_date_two_part;
+use crate::time_scales::utc_to_tt_jd_two_part;
+use chrono::{DateTime, Utc};
+use std::f64::consts::TAU;
+
+/// Milliarcseconds per radian
+const MAS_PER_RAD: f64 = 206264806.24709636;
+
+/// Half the interval of the central difference in [`apparent_place_drift`], in days
+const DRIFT_STEP_DAYS: f64 = 1.0 / 24.0;
+
+/// Converts ICRS coordinates to the apparent place of date.
+///
+/// Applies light deflection by the Sun, annual aberration, frame bias,
+/// precession and nutation (IAU 2006/2000A), referring the result to the
+/// true equator and equinox of date. The observer is at the geocentre, so
+/// diurnal aberration and parallax are not included. The time is converted
+/// from UTC to TT.
+///
+/// # Arguments
+/// * `ra` - ICRS right ascension in degrees
+/// * `dec` - ICRS declination in degrees
+/// * `datetime` - Time of observation (UTC)
+///
+/// # Returns
+/// Tuple of apparent (ra, dec) in degrees, RA in [0, 360)
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+/// ```
+/// use astro_math::apparent::apparent_place;
+/// use astro_math::precession::icrs_to_mean_of_date;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let apparent = apparent_place(279.23473479, 38.78368896, dt).unwrap();
+/// let mean = icrs_to_mean_of_date(279.23473479, 38.78368896, dt).unwrap();
+/// // Nutation and aberration: tens of arcseconds at most
+/// assert!((apparent.1 - mean.1).abs() * 3600.0 < 40.0);
+/// ```
+pub fn apparent_place(ra: f64, dec: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+    let (ra_rad, dec_rad) = apparent_radians(ra.to_radians(), dec.to_radians(), tt1, tt2);
+    Ok((ra_rad.to_degrees(), dec_rad.to_degrees()))
+}
+
+/// Calculates how fast the apparent place of a fixed ICRS position drifts.
+///
+/// The rate is the time derivative of [`apparent_place`], from a central
+/// difference over ±1 hour. It covers precession, nutation, annual
+/// aberration and light deflection; the ICRS position itself is taken as
+/// fixed, so add any proper motion separately. Within a few degrees of the
+/// Sun, the changing light deflection dominates the rate.
+///
+/// # Arguments
+/// * `ra` - ICRS right ascension in degrees
+/// * `dec` - ICRS declination in degrees
+/// * `datetime` - Time at which to evaluate the rate (UTC)
+///
+/// # Returns
+/// `(dra_dt_mas_per_day, ddec_dt_mas_per_day)`. The RA rate is an on-sky
+/// angle, i.e. ΔRA·cos(Dec), so the two components can be combined with
+/// `hypot` and do not blow up near the poles.
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+/// ```
+/// use astro_math::apparent::apparent_place_drift;
+/// use chrono::{TimeZone, Utc};
+///
+/// // Precession carries a star at RA 0h north by about 20″ a year
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// let mut total = 0.0;
+/// for day in 0..365 {
+///     total += apparent_place_drift(0.0, 30.0, dt + chrono::Duration::days(day)).unwrap().1;
+/// }
+/// assert!((total / 1000.0 - 20.0).abs() < 3.0);
+/// ```
+pub fn apparent_place_drift(ra: f64, dec: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+    let (ra_rad, dec_rad) = (ra.to_radians(), dec.to_radians());
+
+    let (ra0, dec0) = apparent_radians(ra_rad, dec_rad, tt1, tt2 - DRIFT_STEP_DAYS);
+    let (ra1, dec1) = apparent_radians(ra_rad, dec_rad, tt1, tt2 + DRIFT_STEP_DAYS);
+    let dra = (ra1 - ra0 + TAU / 2.0).rem_euclid(TAU) - TAU / 2.0;
+    let mid_dec = (dec0 + dec1) / 2.0;
+
+    let scale = MAS_PER_RAD / (2.0 * DRIFT_STEP_DAYS);
+    Ok((dra * mid_dec.cos() * scale, (dec1 - dec0) * scale))
+}
+
+/// Equinox-based apparent `(ra, dec)` in radians for a TT Julian Date.
+fn apparent_radians(ra: f64, dec: f64, tt1: f64, tt2: f64) -> (f64, f64) {
+    // CIRS right ascension is measured from the CIO; subtracting the
+    // equation of the origins refers it to the true equinox
+    let (ri, di, eo) = crate::backend::astrometry::Atci13(ra, dec, 0.0, 0.0, 0.0, 0.0, tt1, tt2);
+    ((ri - eo).rem_euclid(TAU), di)
+}
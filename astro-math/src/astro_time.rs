@@ -0,0 +1,386 @@
+//! A time instant that knows its time scale.
+//!
+//! The free functions of [`time`](crate::time) and
+//! [`time_scales`](crate::time_scales) pass Julian Dates around as bare
+//! `f64`s, and nothing stops a UTC date from reaching a function that
+//! expects TT, an error of about 69 seconds. [`AstroTime`] keeps the Julian
+//! Date together with its [`TimeScale`] and converts between scales on
+//! request:
+//!
+//! - **UTC ↔ TAI** with the leap second in force on the date (see
+//!   [`tai_utc_offset_for_date`])
+//! - **TAI ↔ TT**, TT = TAI + 32.184 s exactly
+//! - **TT ↔ TDB** with [`tdb_tt_offset_seconds`] (±1.7 ms)
+//! - **UTC ↔ UT1** with a UT1−UTC (DUT1) value supplied by the caller, for
+//!   example from an [`EopTable`](crate::eop::EopTable)
+//!
+//! The date is held as two parts, `jd1 + jd2`, in the ERFA convention:
+//! offsets are applied to `jd2` only, so a time made from a
+//! [`DateTime`] keeps nanosecond resolution through any number of
+//! conversions. Pass the parts of the right scale to the `*_two_part`
+//! functions, e.g. the UTC parts to
+//! [`ra_dec_to_alt_az_two_part`](crate::transforms::ra_dec_to_alt_az_two_part).
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::astro_time::{AstroTime, TimeScale};
+//!
+//! let t: AstroTime = "2024-08-04T06:00:00Z".parse().unwrap();
+//! assert_eq!(t.scale(), TimeScale::Utc);
+//!
+//! // TT runs 69.184 s ahead of UTC since 2017
+//! let tt = t.to_tt();
+//! assert_eq!(tt.jd1(), t.jd1());
+//! assert!(((tt.jd2() - t.jd2()) * 86400.0 - 69.184).abs() < 1e-9);
+//! assert_eq!(tt.to_string(), "2024-08-04T06:01:09.184 TT");
+//!
+//! // And back, exactly
+//! assert_eq!(tt.to_utc().to_datetime(), t.to_datetime());
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidFormat` for a string that is not an ISO 8601 date
+//!   and time, or names an unknown time scale
+
+use crate::error::{AstroError, Result};
+use crate::time::julian_date_two_part;
+use crate::time_scales::{tai_utc_offset_for_date, tdb_tt_offset_seconds};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::fmt;
+use std::str::FromStr;
+
+/// TT−TAI in seconds
+const TT_TAI_SECONDS: f64 = 32.184;
+
+/// Seconds per day
+const DAY_SECONDS: f64 = 86400.0;
+
+/// Julian Date of 0000-12-31 00:00, day 0 of chrono's day count
+const JD_CE_EPOCH: f64 = 1721424.5;
+
+/// A time scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    /// Coordinated Universal Time, civil time with leap seconds
+    Utc,
+    /// International Atomic Time
+    Tai,
+    /// Terrestrial Time, TAI + 32.184 s
+    Tt,
+    /// Universal Time, following the Earth's rotation
+    Ut1,
+    /// Barycentric Dynamical Time, the time argument of ephemerides
+    Tdb,
+}
+
+impl TimeScale {
+    /// The scale's abbreviation, e.g. `"TT"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeScale::Utc => "UTC",
+            TimeScale::Tai => "TAI",
+            TimeScale::Tt => "TT",
+            TimeScale::Ut1 => "UT1",
+            TimeScale::Tdb => "TDB",
+        }
+    }
+}
+
+impl fmt::Display for TimeScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for TimeScale {
+    type Err = AstroError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "UTC" | "Z" => Ok(TimeScale::Utc),
+            "TAI" => Ok(TimeScale::Tai),
+            "TT" | "TDT" => Ok(TimeScale::Tt),
+            "UT1" => Ok(TimeScale::Ut1),
+            "TDB" => Ok(TimeScale::Tdb),
+            _ => Err(AstroError::InvalidFormat {
+                format: "time scale",
+                input: s.to_string(),
+                reason: "expected UTC, TAI, TT, UT1 or TDB".to_string(),
+            }),
+        }
+    }
+}
+
+/// An instant as a two-part Julian Date on a given time scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AstroTime {
+    jd1: f64,
+    jd2: f64,
+    scale: TimeScale,
+    /// UT1−UTC in seconds, used to convert a UT1 time back to UTC
+    dut1: f64,
+}
+
+impl AstroTime {
+    /// Creates a time from a two-part Julian Date on the given scale.
+    ///
+    /// A UT1 time created this way takes UT1−UTC as zero, as the rest of
+    /// the crate does; use [`AstroTime::from_ut1`] when DUT1 is known.
+    ///
+    /// # Arguments
+    /// * `jd1`, `jd2` - Julian Date split into two parts, in any proportion
+    /// * `scale` - Time scale of the date
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::astro_time::{AstroTime, TimeScale};
+    ///
+    /// let j2000 = AstroTime::new(2451545.0, 0.0, TimeScale::Tt);
+    /// assert_eq!(j2000.to_string(), "2000-01-01T12:00:00.000 TT");
+    /// ```
+    pub fn new(jd1: f64, jd2: f64, scale: TimeScale) -> Self {
+        Self { jd1, jd2, scale, dut1: 0.0 }
+    }
+
+    /// Creates a UT1 time from a two-part Julian Date and the UT1−UTC
+    /// difference that relates it to UTC.
+    ///
+    /// # Arguments
+    /// * `jd1`, `jd2` - UT1 Julian Date split into two parts
+    /// * `dut1` - UT1−UTC in seconds
+    pub fn from_ut1(jd1: f64, jd2: f64, dut1: f64) -> Self {
+        Self { jd1, jd2, scale: TimeScale::Ut1, dut1 }
+    }
+
+    /// Creates a UTC time from a chrono datetime, to the nanosecond.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::astro_time::{AstroTime, TimeScale};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    /// let t = AstroTime::from_datetime(dt);
+    /// assert_eq!((t.jd1(), t.jd2(), t.scale()), (2460526.5, 0.25, TimeScale::Utc));
+    /// ```
+    pub fn from_datetime(datetime: DateTime<Utc>) -> Self {
+        let (jd1, jd2) = julian_date_two_part(datetime);
+        Self::new(jd1, jd2, TimeScale::Utc)
+    }
+
+    /// Parses an ISO 8601 date and time, optionally followed by a time
+    /// scale.
+    ///
+    /// Accepts `2024-08-04T06:00:00`, a space instead of the `T`, fractional
+    /// seconds, a bare date (midnight), and a trailing `Z` or UTC offset
+    /// such as `+02:00`. Without a scale the time is UTC; a scale after a
+    /// space, as in `2024-08-04T06:00:00 TT`, reads the calendar date and
+    /// time on that scale. A UTC leap second is written `23:59:60`.
+    ///
+    /// # Arguments
+    /// * `text` - The date and time
+    ///
+    /// # Errors
+    /// `AstroError::InvalidFormat` if the text is not a date and time in
+    /// one of these forms, or the scale is unknown
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::astro_time::{AstroTime, TimeScale};
+    ///
+    /// let t = AstroTime::from_iso("2024-08-04 06:00:00.5 TDB").unwrap();
+    /// assert_eq!(t.scale(), TimeScale::Tdb);
+    /// assert!((t.jd() - 2460526.750005787).abs() < 1e-9);
+    ///
+    /// let cest = AstroTime::from_iso("2024-08-04T08:00:00+02:00").unwrap();
+    /// assert_eq!(cest, AstroTime::from_iso("2024-08-04T06:00:00Z").unwrap());
+    /// ```
+    pub fn from_iso(text: &str) -> Result<Self> {
+        let invalid = |reason: &str| AstroError::InvalidFormat {
+            format: "ISO 8601",
+            input: text.to_string(),
+            reason: reason.to_string(),
+        };
+        let trimmed = text.trim();
+        let (stamp, scale) = match trimmed.rsplit_once(char::is_whitespace) {
+            Some((stamp, scale)) if scale.chars().all(|c| c.is_ascii_alphanumeric()) && scale.len() <= 3 => {
+                (stamp.trim(), scale.parse::<TimeScale>().map_err(|_| invalid("unknown time scale"))?)
+            }
+            _ => (trimmed, TimeScale::Utc),
+        };
+
+        if let Ok(with_offset) = DateTime::parse_from_rfc3339(&stamp.replacen(' ', "T", 1)) {
+            if scale != TimeScale::Utc {
+                return Err(invalid("a UTC offset only applies to UTC"));
+            }
+            return Ok(Self::from_datetime(with_offset.with_timezone(&Utc)));
+        }
+        let stamp = stamp.strip_suffix('Z').unwrap_or(stamp);
+        let naive = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"]
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(stamp, format).ok())
+            .or_else(|| NaiveDate::parse_from_str(stamp, "%Y-%m-%d").ok().map(|d| d.and_hms_opt(0, 0, 0).unwrap()))
+            .ok_or_else(|| invalid("expected YYYY-MM-DD[THH:MM[:SS[.fff]]]"))?;
+
+        let (jd1, jd2) = julian_date_two_part(Utc.from_utc_datetime(&naive));
+        Ok(Self::new(jd1, jd2, scale))
+    }
+
+    /// First part of the Julian Date.
+    pub fn jd1(&self) -> f64 {
+        self.jd1
+    }
+
+    /// Second part of the Julian Date.
+    pub fn jd2(&self) -> f64 {
+        self.jd2
+    }
+
+    /// The Julian Date as a single number, good to about 20 µs.
+    pub fn jd(&self) -> f64 {
+        self.jd1 + self.jd2
+    }
+
+    /// The Modified Julian Date, JD − 2400000.5.
+    pub fn mjd(&self) -> f64 {
+        (self.jd1 - 2400000.5) + self.jd2
+    }
+
+    /// The time scale of the date.
+    pub fn scale(&self) -> TimeScale {
+        self.scale
+    }
+
+    /// Converts to UTC.
+    pub fn to_utc(&self) -> Self {
+        match self.scale {
+            TimeScale::Utc => *self,
+            TimeScale::Ut1 => self.shifted(-self.dut1, TimeScale::Utc),
+            _ => {
+                let tai = self.to_tai();
+                // Look the offset up on the UTC date, which the first guess
+                // finds except within a leap second
+                let guess = tai.shifted(-tai_utc_offset(tai.jd1, tai.jd2), TimeScale::Utc);
+                tai.shifted(-tai_utc_offset(guess.jd1, guess.jd2), TimeScale::Utc)
+            }
+        }
+    }
+
+    /// Converts to TAI.
+    pub fn to_tai(&self) -> Self {
+        match self.scale {
+            TimeScale::Tai => *self,
+            TimeScale::Utc => self.shifted(tai_utc_offset(self.jd1, self.jd2), TimeScale::Tai),
+            TimeScale::Ut1 => self.to_utc().to_tai(),
+            TimeScale::Tt | TimeScale::Tdb => self.to_tt().shifted(-TT_TAI_SECONDS, TimeScale::Tai),
+        }
+    }
+
+    /// Converts to TT.
+    pub fn to_tt(&self) -> Self {
+        match self.scale {
+            TimeScale::Tt => *self,
+            TimeScale::Tdb => self.shifted(-tdb_tt_offset_seconds(self.jd()), TimeScale::Tt),
+            _ => self.to_tai().shifted(TT_TAI_SECONDS, TimeScale::Tt),
+        }
+    }
+
+    /// Converts to TDB at the geocentre.
+    pub fn to_tdb(&self) -> Self {
+        match self.scale {
+            TimeScale::Tdb => *self,
+            _ => {
+                let tt = self.to_tt();
+                tt.shifted(tdb_tt_offset_seconds(tt.jd()), TimeScale::Tdb)
+            }
+        }
+    }
+
+    /// Converts to UT1.
+    ///
+    /// # Arguments
+    /// * `dut1` - UT1−UTC in seconds at this time, e.g. from
+    ///   [`Dut1Table::dut1`](crate::eop::Dut1Table::dut1)
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::astro_time::AstroTime;
+    ///
+    /// let utc = AstroTime::from_iso("2024-08-04T06:00:00").unwrap();
+    /// let ut1 = utc.to_ut1(0.0147);
+    /// assert!(((ut1.jd2() - utc.jd2()) * 86400.0 - 0.0147).abs() < 1e-9);
+    /// assert_eq!(ut1.to_utc().to_datetime(), utc.to_datetime());
+    /// ```
+    pub fn to_ut1(&self, dut1: f64) -> Self {
+        let utc = self.to_utc();
+        Self { dut1, ..utc.shifted(dut1, TimeScale::Ut1) }
+    }
+
+    /// Converts to a chrono UTC datetime, rounded to the nanosecond.
+    ///
+    /// A time inside a leap second comes out as the first instant of the
+    /// next day.
+    pub fn to_datetime(&self) -> DateTime<Utc> {
+        let utc = self.to_utc();
+        Utc.from_utc_datetime(&calendar(utc.jd1, utc.jd2))
+    }
+
+    /// The same instant with `seconds` added to the date on a new scale.
+    fn shifted(&self, seconds: f64, scale: TimeScale) -> Self {
+        let dut1 = if scale == TimeScale::Ut1 { self.dut1 } else { 0.0 };
+        Self { jd1: self.jd1, jd2: self.jd2 + seconds / DAY_SECONDS, scale, dut1 }
+    }
+}
+
+impl From<DateTime<Utc>> for AstroTime {
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Self::from_datetime(datetime)
+    }
+}
+
+impl FromStr for AstroTime {
+    type Err = AstroError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_iso(s)
+    }
+}
+
+impl fmt::Display for AstroTime {
+    /// Formats as ISO 8601 on the time's own scale, to the millisecond,
+    /// e.g. `2024-08-04T06:01:09.184 TT`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", calendar(self.jd1, self.jd2).format("%Y-%m-%dT%H:%M:%S%.3f"), self.scale)
+    }
+}
+
+/// TAI−UTC in seconds for a two-part UTC Julian Date.
+///
+/// A date split at 0h, as [`julian_date_two_part`] does it, is looked up on
+/// the day of `jd1` even when `jd2` reaches 1.0, so 23:59:60 on the day of a
+/// leap second keeps that day's offset.
+fn tai_utc_offset(jd1: f64, jd2: f64) -> f64 {
+    let day_start = if (jd1 - 0.5).fract() == 0.0 && (0.0..=1.0).contains(&jd2) {
+        jd1
+    } else {
+        (jd1 + jd2 - 0.5).floor() + 0.5
+    };
+    let days = (day_start - JD_CE_EPOCH) as i64;
+    let date = NaiveDate::from_num_days_from_ce_opt(days as i32).unwrap_or(NaiveDate::MIN);
+    tai_utc_offset_for_date(date)
+}
+
+/// Calendar date and time of a two-part Julian Date, to the nanosecond.
+fn calendar(jd1: f64, jd2: f64) -> NaiveDateTime {
+    let day_start = (jd1 - 0.5).floor() + 0.5;
+    let fraction = (jd1 - day_start) + jd2;
+    let days = (day_start - JD_CE_EPOCH) as i64 + fraction.floor() as i64;
+    let nanos = (fraction.rem_euclid(1.0) * DAY_SECONDS * 1e9).round() as i64;
+    let midnight = NaiveDate::from_num_days_from_ce_opt(days as i32)
+        .unwrap_or(NaiveDate::MIN)
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    midnight + Duration::nanoseconds(nanos)
+}
@@ -0,0 +1,202 @@
+//! Precomputed ICRS → observed astrometry for one time and site.
+//!
+//! ERFA's `Atco13`, behind
+//! [`ra_dec_to_alt_az_erfa`](crate::transforms::ra_dec_to_alt_az_erfa), rebuilds the whole
+//! reduction for every star: the Earth's barycentric position and velocity,
+//! the precession-nutation matrix, the Earth rotation angle, polar motion
+//! and the refraction constants. None of it depends on the star. An
+//! [`AstrometricContext`] computes it once for a time, site and weather
+//! (ERFA `Apco13`), leaving only the per-star part (`Atciq` and `Atioq`):
+//! light deflection, aberration, one rotation and refraction. For a large
+//! catalog this is about two orders of magnitude faster, with the same
+//! result.
+//!
+//! [`ra_dec_to_alt_az_batch_parallel`](crate::transforms::ra_dec_to_alt_az_batch_parallel)
+//! builds a context internally; build one directly to reuse it across
+//! calls, or to apply measured Earth orientation parameters.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::{ra_dec_to_alt_az_erfa, Location};
+//! use astro_math::astrometric_context::AstrometricContext;
+//! use astro_math::refraction::AtmosphericConditions;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let site = Location { latitude_deg: -30.2407, longitude_deg: -70.7366, altitude_m: 2715.0 };
+//! let weather = AtmosphericConditions { pressure_hpa: 743.0, temperature_c: 10.0, relative_humidity: 0.2 };
+//! let dt = Utc.with_ymd_and_hms(2024, 6, 1, 3, 0, 0).unwrap();
+//!
+//! let context = AstrometricContext::new(dt, &site, Some(weather)).unwrap();
+//! let catalog: Vec<(f64, f64)> = (0..10_000).map(|i| ((i as f64 * 0.036) % 360.0, -60.0 + (i % 100) as f64 * 0.5)).collect();
+//! let alt_az = context.observed_from_icrs_many(&catalog).unwrap();
+//!
+//! let (ra, dec) = catalog[1234];
+//! let expected = ra_dec_to_alt_az_erfa(ra, dec, dt, &site, Some(743.0), Some(10.0), Some(0.2)).unwrap();
+//! assert!((alt_az[1234].0 - expected.0).abs() < 1e-9 && (alt_az[1234].1 - expected.1).abs() < 1e-9);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for weather or Earth orientation values
+//!   outside their accepted ranges
+//! - `AstroError::CalculationError` if ERFA rejects the date
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+
+use crate::backend::Astrom;
+use crate::eop::EarthOrientation;
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::location::Location;
+use crate::refraction::AtmosphericConditions;
+use crate::time::julian_date_two_part;
+use crate::transforms::{alt_az_from_observed, apply_pole_offsets, mas_to_rad, ERFA_WAVELENGTH_UM};
+use chrono::{DateTime, Utc};
+use rayon::prelude::*;
+
+/// Star-independent ICRS → observed parameters for one time, site and
+/// weather.
+#[derive(Debug)]
+pub struct AstrometricContext {
+    astrom: Astrom,
+    datetime: DateTime<Utc>,
+    location: Location,
+    /// Celestial pole offsets dX, dY in radians, if supplied
+    pole_offsets: Option<(f64, f64)>,
+}
+
+impl AstrometricContext {
+    /// Builds a context with UT1 = UTC and no polar motion, as
+    /// [`ra_dec_to_alt_az_erfa`](crate::transforms::ra_dec_to_alt_az_erfa).
+    ///
+    /// # Arguments
+    /// * `datetime` - Time of observation (UTC)
+    /// * `observer` - Observer's location
+    /// * `conditions` - Weather for refraction; `None` for the
+    ///   [default](crate::config) weather, no refraction unless set
+    ///
+    /// # Errors
+    /// - `AstroError::OutOfRange` if a weather value is out of range
+    /// - `AstroError::CalculationError` if ERFA rejects the date
+    pub fn new(
+        datetime: DateTime<Utc>,
+        observer: &Location,
+        conditions: Option<AtmosphericConditions>,
+    ) -> Result<Self> {
+        Self::with_eop(datetime, observer, conditions, &EarthOrientation::default())
+    }
+
+    /// Builds a context with measured Earth orientation parameters, as
+    /// [`ra_dec_to_alt_az_erfa_with_eop`](crate::transforms::ra_dec_to_alt_az_erfa_with_eop).
+    ///
+    /// # Arguments
+    /// * `datetime` - Time of observation (UTC)
+    /// * `observer` - Observer's location
+    /// * `conditions` - Weather for refraction; `None` for the
+    ///   [default](crate::config) weather, no refraction unless set
+    /// * `eop` - Earth orientation parameters for `datetime`
+    ///
+    /// # Errors
+    /// - `AstroError::OutOfRange` if a weather or Earth orientation value is
+    ///   out of range
+    /// - `AstroError::CalculationError` if ERFA rejects the date
+    pub fn with_eop(
+        datetime: DateTime<Utc>,
+        observer: &Location,
+        conditions: Option<AtmosphericConditions>,
+        eop: &EarthOrientation,
+    ) -> Result<Self> {
+        let conditions = crate::config::conditions_or_default(conditions);
+        if let Some(conditions) = &conditions {
+            conditions.validate()?;
+        }
+        eop.validate()?;
+        let weather = conditions.map_or((0.0, 0.0, 0.0), |c| (c.pressure_hpa, c.temperature_c, c.relative_humidity));
+        Self::build(datetime, observer, weather, eop)
+    }
+
+    /// Builds a context from unvalidated weather `(pressure_hpa,
+    /// temperature_c, humidity)`, zero pressure for no refraction.
+    pub(crate) fn build(
+        datetime: DateTime<Utc>,
+        observer: &Location,
+        (phpa, tc, rh): (f64, f64, f64),
+        eop: &EarthOrientation,
+    ) -> Result<Self> {
+        let (jd1, jd2) = julian_date_two_part(datetime);
+        let mut astrom = Astrom::default();
+        crate::backend::astrometry::Apco13(
+            jd1,
+            jd2,
+            eop.dut1,
+            observer.longitude_deg.to_radians(),
+            observer.latitude_deg.to_radians(),
+            observer.altitude_m,
+            mas_to_rad(eop.xp_arcsec * 1000.0),
+            mas_to_rad(eop.yp_arcsec * 1000.0),
+            phpa,
+            tc,
+            rh,
+            ERFA_WAVELENGTH_UM,
+            &mut astrom,
+        )
+        .map_err(|_| AstroError::CalculationError {
+            calculation: "ERFA Apco13",
+            reason: format!("cannot prepare astrometry parameters for {}", datetime),
+        })?;
+        let pole_offsets = match (eop.dx_mas, eop.dy_mas) {
+            (Some(dx), Some(dy)) => Some((mas_to_rad(dx), mas_to_rad(dy))),
+            _ => None,
+        };
+        Ok(Self { astrom, datetime, location: *observer, pole_offsets })
+    }
+
+    /// Time the context was built for.
+    pub fn datetime(&self) -> DateTime<Utc> {
+        self.datetime
+    }
+
+    /// Observer the context was built for.
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    /// Converts one ICRS position to observed altitude and azimuth.
+    ///
+    /// # Arguments
+    /// * `ra_icrs`, `dec_icrs` - ICRS coordinates in degrees
+    ///
+    /// # Returns
+    /// `(altitude_deg, azimuth_deg)`, azimuth from North through East
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of
+    /// range.
+    pub fn observed_from_icrs(&self, ra_icrs: f64, dec_icrs: f64) -> Result<(f64, f64)> {
+        validate_ra(ra_icrs)?;
+        validate_dec(dec_icrs)?;
+        let (mut ra_rad, mut dec_rad) = (ra_icrs.to_radians(), dec_icrs.to_radians());
+        if let Some((dx, dy)) = self.pole_offsets {
+            (ra_rad, dec_rad) = apply_pole_offsets(ra_rad, dec_rad, dx, dy);
+        }
+        let (ri, di) = crate::backend::astrometry::Atciq(ra_rad, dec_rad, 0.0, 0.0, 0.0, 0.0, &self.astrom);
+        let (aob, zob, _, _, _) = crate::backend::astrometry::Atioq(ri, di, &self.astrom);
+        alt_az_from_observed(aob, zob)
+    }
+
+    /// Converts many ICRS positions to observed altitude and azimuth, in
+    /// parallel.
+    ///
+    /// # Arguments
+    /// * `ra_dec_pairs` - ICRS `(ra, dec)` pairs in degrees
+    ///
+    /// # Returns
+    /// One `(altitude_deg, azimuth_deg)` tuple per position, in input order
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if any RA or Dec is out
+    /// of range.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(count = ra_dec_pairs.len())))]
+    pub fn observed_from_icrs_many(&self, ra_dec_pairs: &[(f64, f64)]) -> Result<Vec<(f64, f64)>> {
+        ra_dec_pairs.par_iter().map(|&(ra, dec)| self.observed_from_icrs(ra, dec)).collect()
+    }
+}
@@ -0,0 +1,33 @@
+//! Selection of the implementation behind the ERFA routines used by the crate.
+//!
+//! By default (`erfa` feature) the crate calls the C ERFA library through the
+//! `erfars` bindings. Building with the `pure-rust` feature swaps in Rust
+//! ports of the same routines, which removes the C dependency for targets
+//! where it is awkward to cross-compile (WASM, Android/Termux, static musl).
+//!
+//! If both features are enabled, `pure-rust` takes precedence. The accuracy
+//! of each pure-Rust routine relative to ERFA is tabulated in the crate
+//! documentation and checked in `tests/backend.rs`.
+
+#[cfg(not(any(feature = "erfa", feature = "pure-rust")))]
+compile_error!("astro-math needs either the `erfa` (default) or the `pure-rust` feature");
+
+#[cfg_attr(not(feature = "pure-rust"), allow(dead_code))]
+pub(crate) mod pure;
+
+#[cfg(all(feature = "erfa", not(feature = "pure-rust")))]
+pub(crate) use erfars::{astrometry, ephemerides, galacticcoordinates, gnomonic, precnutpolar, rotationtime};
+#[cfg(all(feature = "erfa", not(feature = "pure-rust")))]
+pub(crate) use erfars::Astrom;
+
+#[cfg(feature = "pure-rust")]
+pub(crate) use pure::{astrometry, ephemerides, galacticcoordinates, gnomonic, precnutpolar, rotationtime};
+#[cfg(feature = "pure-rust")]
+pub(crate) use pure::astrometry::Astrom;
+
+/// Name of the backend in use, `"erfa"` or `"pure-rust"`.
+pub(crate) const NAME: &str = if cfg!(feature = "pure-rust") { "pure-rust" } else { "erfa" };
+
+/// Precession-nutation model behind `Pnm06a` and `Nut00a` for the backend in use.
+pub(crate) const PRECESSION_NUTATION: &str =
+    if cfg!(feature = "pure-rust") { "IAU 2006/2000B" } else { "IAU 2006/2000A" };
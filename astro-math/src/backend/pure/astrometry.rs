@@ -0,0 +1,512 @@
+//! ICRS ↔ CIRS ↔ observed transformations.
+//!
+//! **Differs from ERFA:** the chain follows `eraApci13`/`eraApio13` step by
+//! step, but uses the pure-Rust [`Epv00`] and [`Pnm06a`](super::precnutpolar::Pnm06a)
+//! approximations, forms CIRS from the equinox-based matrix plus the
+//! equation of the origins, and neglects the CIO locator `s` (< 1 mas
+//! within a century of J2000.0).
+
+use super::ephemerides::Epv00;
+use super::precnutpolar::{ee06a, pnm06a};
+use super::{
+    anp, anpm, c2s, centuries, identity, pdp, pn, pxp, rx, rxp, ry, rz, s2c, trxp, BackendError,
+    CMPS, DAS2R, DAU, DAYSEC, DJ00, DJY, SRS,
+};
+use super::rotationtime::Era00;
+use crate::time_scales::utc_to_tt_jd_for_date;
+use std::f64::consts::TAU;
+
+/// Star-independent parameters for the ICRS → CIRS step.
+#[derive(Debug, Default)]
+struct CelestialParams {
+    /// Proper motion time interval (Julian years)
+    pmt: f64,
+    /// SSB to observer (AU)
+    eb: [f64; 3],
+    /// Sun to observer (unit vector)
+    eh: [f64; 3],
+    /// Sun to observer distance (AU)
+    em: f64,
+    /// Observer barycentric velocity (units of c)
+    v: [f64; 3],
+    /// sqrt(1 − |v|²)
+    bm1: f64,
+    /// Bias-precession-nutation matrix
+    bpn: [[f64; 3]; 3],
+    /// Equation of the origins (ERA − GST)
+    eo: f64,
+}
+
+fn celestial_params(date1: f64, date2: f64) -> CelestialParams {
+    let (pvh, pvb) = Epv00(date1, date2);
+    let (em, eh) = pn(&[pvh[0], pvh[1], pvh[2]]);
+
+    // Velocity in units of c
+    let c_au_per_day = DAYSEC * CMPS / DAU;
+    let v = [pvb[3] / c_au_per_day, pvb[4] / c_au_per_day, pvb[5] / c_au_per_day];
+
+    let t = centuries(date1, date2);
+    let precession = (0.014506
+        + (4612.156534 + (1.3915817 + (-0.00000044 + (-0.000029956 + (-0.0000000368) * t) * t) * t) * t) * t)
+        * DAS2R;
+
+    CelestialParams {
+        pmt: ((date1 - DJ00) + date2) / DJY,
+        eb: [pvb[0], pvb[1], pvb[2]],
+        eh,
+        em,
+        v,
+        bm1: (1.0 - pdp(&v, &v)).sqrt(),
+        bpn: pnm06a(date1, date2),
+        eo: anpm(-(precession + ee06a(date1, date2))),
+    }
+}
+
+/// Proper motion and parallax (ERFA `eraPmpx`).
+#[allow(clippy::too_many_arguments)]
+fn pmpx(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, pmt: f64, pob: &[f64; 3]) -> [f64; 3] {
+    // km/s to AU/year, and light time for 1 AU in Julian years
+    let vf = DAYSEC * 365250.0 / DAU;
+    let aulty = DAU / CMPS / DAYSEC / DJY;
+
+    let (sr, cr) = rc.sin_cos();
+    let (sd, cd) = dc.sin_cos();
+    let p = [cr * cd, sr * cd, sd];
+
+    let dt = pmt + pdp(&p, pob) * aulty;
+    let pxr = px * DAS2R;
+    let w = vf * rv * pxr;
+    let pdz = pd * p[2];
+    let pm = [-pr * p[1] - pdz * cr + w * p[0], pr * p[0] - pdz * sr + w * p[1], pd * cd + w * p[2]];
+
+    let q: [f64; 3] = std::array::from_fn(|i| p[i] + dt * pm[i] - pxr * pob[i]);
+    pn(&q).1
+}
+
+/// Light deflection by the Sun (ERFA `eraLdsun`).
+fn ldsun(p: &[f64; 3], e: &[f64; 3], em: f64) -> [f64; 3] {
+    let dlim = 1e-6 / (em * em).max(1.0);
+    let qpe = [p[0] + e[0], p[1] + e[1], p[2] + e[2]];
+    let w = SRS / em / pdp(p, &qpe).max(dlim);
+    let peq = pxp(p, &pxp(e, p));
+    [p[0] + w * peq[0], p[1] + w * peq[1], p[2] + w * peq[2]]
+}
+
+/// Stellar aberration (ERFA `eraAb`).
+fn ab(pnat: &[f64; 3], v: &[f64; 3], s: f64, bm1: f64) -> [f64; 3] {
+    let pdv = pdp(pnat, v);
+    let w1 = 1.0 + pdv / (1.0 + bm1);
+    let w2 = SRS / s;
+    let p: [f64; 3] = std::array::from_fn(|i| pnat[i] * bm1 + w1 * v[i] + w2 * (v[i] - pdv * pnat[i]));
+    pn(&p).1
+}
+
+/// Inverts a direction-to-direction map by fixed-point iteration.
+fn invert<F: Fn(&[f64; 3]) -> [f64; 3]>(target: &[f64; 3], forward: F) -> [f64; 3] {
+    let mut p = *target;
+    for _ in 0..5 {
+        let mapped = forward(&p);
+        p = pn(&[
+            p[0] + target[0] - mapped[0],
+            p[1] + target[1] - mapped[1],
+            p[2] + target[2] - mapped[2],
+        ])
+        .1;
+    }
+    p
+}
+
+/// ICRS to CIRS for a star, geocentric observer.
+///
+/// Returns `(ri, di, eo)` in radians, where `eo` is the equation of the
+/// origins (ERA − GST).
+#[allow(non_snake_case)]
+#[allow(clippy::too_many_arguments)]
+pub fn Atci13(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, date1: f64, date2: f64) -> (f64, f64, f64) {
+    let astrom = celestial_params(date1, date2);
+    let (ri, di) = atciq(rc, dc, pr, pd, px, rv, &astrom);
+    (ri, di, astrom.eo)
+}
+
+/// ICRS to CIRS for a star with precomputed parameters (ERFA `eraAtciq`).
+#[allow(clippy::too_many_arguments)]
+fn atciq(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, astrom: &CelestialParams) -> (f64, f64) {
+    let pco = pmpx(rc, dc, pr, pd, px, rv, astrom.pmt, &astrom.eb);
+    let pnat = ldsun(&pco, &astrom.eh, astrom.em);
+    let ppr = ab(&pnat, &astrom.v, astrom.em, astrom.bm1);
+
+    // True equator and equinox of date, then shift RA to the CIO
+    let (ra, di) = c2s(&rxp(&astrom.bpn, &ppr));
+    (anp(ra + astrom.eo), di)
+}
+
+/// CIRS to ICRS for a star, geocentric observer (the inverse of [`Atci13`]
+/// for zero proper motion and parallax).
+///
+/// Returns `(rc, dc, eo)` in radians.
+#[allow(non_snake_case)]
+pub fn Atic13(ri: f64, di: f64, date1: f64, date2: f64) -> (f64, f64, f64) {
+    let astrom = celestial_params(date1, date2);
+    let (rc, dc) = aticq(ri, di, &astrom);
+    (rc, dc, astrom.eo)
+}
+
+/// CIRS to ICRS for a star with precomputed parameters (ERFA `eraAticq`).
+fn aticq(ri: f64, di: f64, astrom: &CelestialParams) -> (f64, f64) {
+    let ppr = trxp(&astrom.bpn, &s2c(ri - astrom.eo, di));
+    let pnat = invert(&ppr, |p| ab(p, &astrom.v, astrom.em, astrom.bm1));
+    let pco = invert(&pnat, |p| ldsun(p, &astrom.eh, astrom.em));
+
+    let (rc, dc) = c2s(&pco);
+    (anp(rc), dc)
+}
+
+/// Refraction constants A and B for the A tan z + B tan³ z model
+/// (ERFA `eraRefco`).
+fn refco(phpa: f64, tc: f64, rh: f64, wl: f64) -> (f64, f64) {
+    let optic = wl <= 100.0;
+    let t = tc.clamp(-150.0, 200.0);
+    let p = phpa.clamp(0.0, 10000.0);
+    let r = rh.clamp(0.0, 1.0);
+    let w = wl.clamp(0.1, 1e6);
+
+    // Water vapour pressure at the observer
+    let pw = if p > 0.0 {
+        let ps = 10f64.powf((0.7859 + 0.03477 * t) / (1.0 + 0.00412 * t)) * (1.0 + p * (4.5e-6 + 6e-10 * t * t));
+        r * ps / (1.0 - (1.0 - r) * ps / p)
+    } else {
+        0.0
+    };
+
+    // Refractive index minus 1 at the observer
+    let tk = t + 273.15;
+    let gamma = if optic {
+        let wlsq = w * w;
+        ((77.53484e-6 + (4.39108e-7 + 3.666e-9 / wlsq) / wlsq) * p - 11.2684e-6 * pw) / tk
+    } else {
+        (77.6890e-6 * p - (6.3938e-6 - 0.375463 / tk) * pw) / tk
+    };
+
+    // Stone's formula for beta, with empirical adjustments
+    let mut beta = 4.4474e-6 * tk;
+    if !optic {
+        beta -= 0.0074 * pw * beta;
+    }
+
+    (gamma * (1.0 - beta), -gamma * (beta - gamma / 2.0))
+}
+
+/// UTC (two-part quasi-JD) to TT.
+fn utc_to_tt(utc1: f64, utc2: f64) -> (f64, f64) {
+    let jd = utc1 + utc2;
+    (utc1, utc2 + (utc_to_tt_jd_for_date(jd) - jd))
+}
+
+/// Star-independent parameters for the CIRS → observed step.
+#[derive(Debug, Default)]
+struct ObserverParams {
+    /// Longitude + s' + ERA, with polar motion (radians)
+    eral: f64,
+    /// Polar motion with respect to the local meridian (radians)
+    xpl: f64,
+    ypl: f64,
+    /// Sine and cosine of the geodetic latitude
+    sphi: f64,
+    cphi: f64,
+    /// Magnitude of the diurnal aberration vector
+    diurab: f64,
+    /// Refraction constants A and B
+    refa: f64,
+    refb: f64,
+}
+
+/// Observer parameters for the CIRS → observed step (ERFA `eraApio13`).
+#[allow(clippy::too_many_arguments)]
+fn observer_params(
+    utc1: f64,
+    utc2: f64,
+    dut1: f64,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    phpa: f64,
+    tc: f64,
+    rh: f64,
+    wl: f64,
+) -> ObserverParams {
+    let (tt1, tt2) = utc_to_tt(utc1, utc2);
+    let sp = -47e-6 * centuries(tt1, tt2) * DAS2R;
+    let theta = Era00(utc1, utc2 + dut1 / DAYSEC);
+
+    // CIRS to apparent [HA, Dec] rotation (ERFA eraApio)
+    let mut r = identity();
+    rz(theta + sp, &mut r);
+    ry(-xp, &mut r);
+    rx(-yp, &mut r);
+    rz(elong, &mut r);
+    let eral = if r[0][0] != 0.0 || r[0][1] != 0.0 { r[0][1].atan2(r[0][0]) } else { 0.0 };
+    let xpl = r[0][2].atan2((r[0][0] * r[0][0] + r[0][1] * r[0][1]).sqrt());
+    let ypl = if r[1][2] != 0.0 || r[2][2] != 0.0 { -r[1][2].atan2(r[2][2]) } else { 0.0 };
+    let (sphi, cphi) = phi.sin_cos();
+
+    // Diurnal aberration from the WGS84 distance to the spin axis
+    let flattening = 1.0 / 298.257223563;
+    let w = (1.0 - flattening) * (1.0 - flattening);
+    let ac = 6378137.0 / (cphi * cphi + w * sphi * sphi).sqrt();
+    let omega = 1.002737811911354 * TAU / DAYSEC;
+    let diurab = omega * (ac + hm) * cphi / CMPS;
+
+    let (refa, refb) = refco(phpa, tc, rh, wl);
+    ObserverParams { eral, xpl, ypl, sphi, cphi, diurab, refa, refb }
+}
+
+/// CIRS to observed place with precomputed parameters (ERFA `eraAtioq`).
+fn atioq(ri: f64, di: f64, astrom: &ObserverParams) -> (f64, f64, f64, f64, f64) {
+    // Minimum cos(alt) and sin(alt) for the refraction model
+    const CELMIN: f64 = 1e-6;
+    const SELMIN: f64 = 0.05;
+
+    let ObserverParams { eral, xpl, ypl, sphi, cphi, diurab, refa, refb } = *astrom;
+
+    // CIRS RA,Dec to Cartesian −HA,Dec, with polar motion
+    let v = s2c(ri - eral, di);
+    let (sx, cx) = xpl.sin_cos();
+    let (sy, cy) = ypl.sin_cos();
+    let xhd = cx * v[0] + sx * v[2];
+    let yhd = sx * sy * v[0] + cy * v[1] - cx * sy * v[2];
+    let zhd = -sx * cy * v[0] + sy * v[1] + cx * cy * v[2];
+
+    // Diurnal aberration
+    let f = 1.0 - diurab * yhd;
+    let (xhdt, yhdt, zhdt) = (f * xhd, f * (yhd + diurab), f * zhd);
+
+    // To Cartesian Az,El (S = 0, E = 90°)
+    let xaet = sphi * xhdt - cphi * zhdt;
+    let yaet = yhdt;
+    let zaet = cphi * xhdt + sphi * zhdt;
+    let azobs = if xaet != 0.0 || yaet != 0.0 { yaet.atan2(-xaet) } else { 0.0 };
+
+    // Refraction: A tan z + B tan³ z with a Newton-Raphson correction
+    let r = (xaet * xaet + yaet * yaet).sqrt().max(CELMIN);
+    let z = zaet.max(SELMIN);
+    let tz = r / z;
+    let w = refb * tz * tz;
+    let del = (refa + w) * tz / (1.0 + (refa + 3.0 * w) / (z * z));
+    let cosdel = 1.0 - del * del / 2.0;
+    let f = cosdel - del * z / r;
+    let (xaeo, yaeo, zaeo) = (xaet * f, yaet * f, cosdel * zaet + del * r);
+
+    let zdobs = (xaeo * xaeo + yaeo * yaeo).sqrt().atan2(zaeo);
+
+    // Az/El vector back to HA,Dec
+    let (hmobs, dcobs) = c2s(&[sphi * xaeo + cphi * zaeo, yaeo, -cphi * xaeo + sphi * zaeo]);
+
+    (anp(azobs), zdobs, -hmobs, dcobs, anp(eral + hmobs))
+}
+
+/// Observed place to CIRS with precomputed parameters (ERFA `eraAtoiq`).
+///
+/// `ctype` selects the observed coordinates: `'A'` for azimuth (N = 0,
+/// E = 90°) and zenith distance, `'H'` for hour angle and declination, `'R'`
+/// for CIO-based right ascension and declination.
+fn atoiq(ctype: char, ob1: f64, ob2: f64, astrom: &ObserverParams) -> Result<(f64, f64), BackendError> {
+    // Minimum sin(alt) for the refraction model
+    const SELMIN: f64 = 0.05;
+
+    let ObserverParams { eral, xpl, ypl, sphi, cphi, diurab, refa, refb } = *astrom;
+
+    // Observed coordinates to Cartesian Az,El (S = 0, E = 90°)
+    let (xaeo, yaeo, zaeo) = match ctype.to_ascii_uppercase() {
+        'A' => {
+            let ce = ob2.sin();
+            (-ob1.cos() * ce, ob1.sin() * ce, ob2.cos())
+        }
+        c @ ('H' | 'R') => {
+            let ha = if c == 'R' { eral - ob1 } else { ob1 };
+            let v = s2c(-ha, ob2);
+            (sphi * v[0] - cphi * v[2], v[1], cphi * v[0] + sphi * v[2])
+        }
+        _ => return Err(BackendError::BadInputValue),
+    };
+    let az = if xaeo != 0.0 || yaeo != 0.0 { yaeo.atan2(xaeo) } else { 0.0 };
+
+    // Remove refraction, to first order in the A tan z + B tan³ z model
+    let sz = (xaeo * xaeo + yaeo * yaeo).sqrt();
+    let zdo = sz.atan2(zaeo);
+    let tz = sz / zaeo.max(SELMIN);
+    let zdt = zdo + (refa + refb * tz * tz) * tz;
+
+    // Topocentric Az,El back to −HA,Dec
+    let (sz, cz) = zdt.sin_cos();
+    let (xaet, yaet, zaet) = (az.cos() * sz, az.sin() * sz, cz);
+    let (xmhda, ymhda, zmhda) = (sphi * xaet + cphi * zaet, yaet, -cphi * xaet + sphi * zaet);
+
+    // Diurnal aberration
+    let f = 1.0 + diurab * ymhda;
+    let (xhd, yhd, zhd) = (f * xmhda, f * (ymhda - diurab), f * zmhda);
+
+    // Polar motion
+    let (sx, cx) = xpl.sin_cos();
+    let (sy, cy) = ypl.sin_cos();
+    let v = [
+        cx * xhd + sx * sy * yhd - sx * cy * zhd,
+        cy * yhd + sy * zhd,
+        sx * xhd - cx * sy * yhd + cx * cy * zhd,
+    ];
+
+    let (hma, di) = c2s(&v);
+    Ok((anp(eral + hma), di))
+}
+
+/// CIRS to observed place.
+///
+/// Returns `(aob, zob, hob, dob, rob)`: observed azimuth (N = 0, E = 90°),
+/// zenith distance, hour angle, declination and CIO-based right ascension,
+/// all in radians.
+///
+/// # Errors
+/// Never fails; the `Result` mirrors the `erfars` signature.
+#[allow(non_snake_case)]
+#[allow(clippy::too_many_arguments)]
+pub fn Atio13(
+    ri: f64,
+    di: f64,
+    utc1: f64,
+    utc2: f64,
+    dut1: f64,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    phpa: f64,
+    tc: f64,
+    rh: f64,
+    wl: f64,
+) -> Result<(f64, f64, f64, f64, f64), BackendError> {
+    Ok(atioq(ri, di, &observer_params(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)))
+}
+
+/// ICRS to observed place for a star.
+///
+/// Returns `(aob, zob, hob, dob, rob, eo)` in radians; see [`Atio13`] and
+/// [`Atci13`].
+///
+/// # Errors
+/// Never fails; the `Result` mirrors the `erfars` signature.
+#[allow(non_snake_case)]
+#[allow(clippy::too_many_arguments)]
+pub fn Atco13(
+    rc: f64,
+    dc: f64,
+    pr: f64,
+    pd: f64,
+    px: f64,
+    rv: f64,
+    utc1: f64,
+    utc2: f64,
+    dut1: f64,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    phpa: f64,
+    tc: f64,
+    rh: f64,
+    wl: f64,
+) -> Result<(f64, f64, f64, f64, f64, f64), BackendError> {
+    let (tt1, tt2) = utc_to_tt(utc1, utc2);
+    let (ri, di, eo) = Atci13(rc, dc, pr, pd, px, rv, tt1, tt2);
+    let (aob, zob, hob, dob, rob) = Atio13(ri, di, utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl)?;
+    Ok((aob, zob, hob, dob, rob, eo))
+}
+
+/// Observed place to ICRS for a star, the inverse of [`Atco13`] for zero
+/// proper motion and parallax.
+///
+/// `ctype` is `'A'`, `'H'` or `'R'` (either case) for observed azimuth and
+/// zenith distance, hour angle and declination, or CIO-based right
+/// ascension and declination. Returns `(rc, dc)` in radians.
+///
+/// # Errors
+/// `BackendError::BadInputValue` for any other `ctype`.
+#[allow(non_snake_case)]
+#[allow(clippy::too_many_arguments)]
+pub fn Atoc13(
+    ctype: char,
+    ob1: f64,
+    ob2: f64,
+    utc1: f64,
+    utc2: f64,
+    dut1: f64,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    phpa: f64,
+    tc: f64,
+    rh: f64,
+    wl: f64,
+) -> Result<(f64, f64), BackendError> {
+    let observer = observer_params(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl);
+    let (ri, di) = atoiq(ctype, ob1, ob2, &observer)?;
+    let (tt1, tt2) = utc_to_tt(utc1, utc2);
+    Ok(aticq(ri, di, &celestial_params(tt1, tt2)))
+}
+
+/// Star-independent parameters of the ICRS → observed chain, the
+/// counterpart of ERFA's `eraASTROM` for [`Apco13`], [`Atciq`] and [`Atioq`].
+#[derive(Debug, Default)]
+pub struct Astrom {
+    celestial: CelestialParams,
+    observer: ObserverParams,
+}
+
+/// Prepares the star-independent parameters for ICRS to observed place,
+/// for a terrestrial observer.
+///
+/// Returns the equation of the origins in radians.
+///
+/// # Errors
+/// Never fails; the `Result` mirrors the `erfars` signature.
+#[allow(non_snake_case)]
+#[allow(clippy::too_many_arguments)]
+pub fn Apco13(
+    utc1: f64,
+    utc2: f64,
+    dut1: f64,
+    elong: f64,
+    phi: f64,
+    hm: f64,
+    xp: f64,
+    yp: f64,
+    phpa: f64,
+    tc: f64,
+    rh: f64,
+    wl: f64,
+    astrom: &mut Astrom,
+) -> Result<f64, BackendError> {
+    let (tt1, tt2) = utc_to_tt(utc1, utc2);
+    astrom.celestial = celestial_params(tt1, tt2);
+    astrom.observer = observer_params(utc1, utc2, dut1, elong, phi, hm, xp, yp, phpa, tc, rh, wl);
+    Ok(astrom.celestial.eo)
+}
+
+/// ICRS to CIRS for a star, with parameters from [`Apco13`].
+#[allow(non_snake_case)]
+#[allow(clippy::too_many_arguments)]
+pub fn Atciq(rc: f64, dc: f64, pr: f64, pd: f64, px: f64, rv: f64, astrom: &Astrom) -> (f64, f64) {
+    atciq(rc, dc, pr, pd, px, rv, &astrom.celestial)
+}
+
+/// CIRS to observed place, with parameters from [`Apco13`].
+///
+/// Returns `(aob, zob, hob, dob, rob)` as [`Atio13`].
+#[allow(non_snake_case)]
+pub fn Atioq(ri: f64, di: f64, astrom: &Astrom) -> (f64, f64, f64, f64, f64) {
+    atioq(ri, di, &astrom.observer)
+}
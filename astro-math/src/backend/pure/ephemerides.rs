@@ -0,0 +1,1477 @@
+//! Solar-system ephemerides: planets, Moon and Earth.
+
+use super::precnutpolar::pfw06;
+use super::{anpm, centuries, identity, rx, rxp, rz, BackendError, DAS2R, DAU, DJ00, DJC, DJY};
+use std::f64::consts::TAU;
+
+/// Gaussian gravitational constant
+const GK: f64 = 0.017202098950;
+
+/// sin and cos of the J2000.0 mean obliquity (IAU 1976)
+const SINEPS: f64 = 0.3977771559319137;
+const COSEPS: f64 = 0.9174820620691818;
+
+/// Planetary inverse masses
+const AMAS: [f64; 8] = [6023600.0, 408523.5, 328900.5, 3098710.0, 1047.355, 3498.5, 22869.0, 19314.0];
+
+// Plan94 mean elements: semi-major axis (AU), mean longitude, eccentricity,
+// longitude of perihelion, inclination and node (degrees and arcsec/millennium)
+#[rustfmt::skip]
+const A: [[f64; 3]; 8] = [
+    [0.3870983098, 0.0, 0.0],
+    [0.72332982, 0.0, 0.0],
+    [1.0000010178, 0.0, 0.0],
+    [1.5236793419, 3e-10, 0.0],
+    [5.2026032092, 1.9132e-06, -3.9e-09],
+    [9.5549091915, -2.13896e-05, 4.44e-08],
+    [19.2184460618, -3.716e-07, 9.79e-08],
+    [30.1103868694, -1.6635e-06, 6.86e-08],
+];
+
+const DLM: [[f64; 3]; 8] = [
+    [252.25090552, 5381016286.88982, -1.92789],
+    [181.97980085, 2106641364.33548, 0.59381],
+    [100.46645683, 1295977422.83429, -2.04411],
+    [355.43299958, 689050774.93988, 0.94264],
+    [34.35151874, 109256603.77991, -30.60378],
+    [50.0774443, 43996098.55732, 75.61614],
+    [314.05500511, 15424811.93933, -1.75083],
+    [304.34866548, 7865503.20744, 0.21103],
+];
+
+const E: [[f64; 3]; 8] = [
+    [0.2056317526, 0.0002040653, -2.8349e-06],
+    [0.0067719164, -0.0004776521, 9.8127e-06],
+    [0.0167086342, -0.0004203654, -1.26734e-05],
+    [0.0934006477, 0.0009048438, -8.0641e-06],
+    [0.0484979255, 0.0016322542, -4.71366e-05],
+    [0.0555481426, -0.0034664062, -6.43639e-05],
+    [0.0463812221, -0.0002729293, 7.8913e-06],
+    [0.009455747, 6.03263e-05, 0.0],
+];
+
+const PI: [[f64; 3]; 8] = [
+    [77.45611904, 5719.1159, -4.83016],
+    [131.563703, 175.4864, -498.48184],
+    [102.93734808, 11612.3529, 53.27577],
+    [336.06023395, 15980.45908, -62.328],
+    [14.33120687, 7758.75163, 259.95938],
+    [93.05723748, 20395.49439, 190.25952],
+    [173.00529106, 3215.56238, -34.09288],
+    [48.12027554, 1050.71912, 27.39717],
+];
+
+const DINC: [[f64; 3]; 8] = [
+    [7.00498625, -214.25629, 0.28977],
+    [3.39466189, -30.84437, -11.67836],
+    [0.0, 469.97289, -3.35053],
+    [1.84972648, -293.31722, -8.1183],
+    [1.30326698, -71.5589, 11.95297],
+    [2.48887878, 91.85195, -17.66225],
+    [0.77319689, -60.72723, 1.25759],
+    [1.76995259, 8.12333, 0.08135],
+];
+
+const OMEGA: [[f64; 3]; 8] = [
+    [48.33089304, -4515.21727, -31.79892],
+    [76.67992019, -10008.48154, -51.32614],
+    [174.87317577, -8679.27034, 15.34191],
+    [49.55809321, -10620.90088, -230.57416],
+    [100.46440702, 6362.03561, 326.52178],
+    [113.66550252, -9240.19942, -66.23743],
+    [74.00595701, 2669.15033, 145.93964],
+    [131.78405702, -221.94322, -0.78728],
+];
+
+const KP: [[f64; 9]; 8] = [
+    [69613.0, 75645.0, 88306.0, 59899.0, 15746.0, 71087.0, 142173.0, 3086.0, 0.0],
+    [21863.0, 32794.0, 26934.0, 10931.0, 26250.0, 43725.0, 53867.0, 28939.0, 0.0],
+    [16002.0, 21863.0, 32004.0, 10931.0, 14529.0, 16368.0, 15318.0, 32794.0, 0.0],
+    [6345.0, 7818.0, 15636.0, 7077.0, 8184.0, 14163.0, 1107.0, 4872.0, 0.0],
+    [1760.0, 1454.0, 1167.0, 880.0, 287.0, 2640.0, 19.0, 2047.0, 1454.0],
+    [574.0, 0.0, 880.0, 287.0, 19.0, 1760.0, 1167.0, 306.0, 574.0],
+    [204.0, 0.0, 177.0, 1265.0, 4.0, 385.0, 200.0, 208.0, 204.0],
+    [0.0, 102.0, 106.0, 4.0, 98.0, 1367.0, 487.0, 204.0, 0.0],
+];
+
+const CA: [[f64; 9]; 8] = [
+    [4.0, -13.0, 11.0, -9.0, -9.0, -3.0, -1.0, 4.0, 0.0],
+    [-156.0, 59.0, -42.0, 6.0, 19.0, -20.0, -10.0, -12.0, 0.0],
+    [64.0, -152.0, 62.0, -8.0, 32.0, -41.0, 19.0, -11.0, 0.0],
+    [124.0, 621.0, -145.0, 208.0, 54.0, -57.0, 30.0, 15.0, 0.0],
+    [-23437.0, -2634.0, 6601.0, 6259.0, -1507.0, -1821.0, 2620.0, -2115.0, -1489.0],
+    [62911.0, -119919.0, 79336.0, 17814.0, -24241.0, 12068.0, 8306.0, -4893.0, 8902.0],
+    [389061.0, -262125.0, -44088.0, 8387.0, -22976.0, -2093.0, -615.0, -9720.0, 6633.0],
+    [-412235.0, -157046.0, -31430.0, 37817.0, -9740.0, -13.0, -7449.0, 9644.0, 0.0],
+];
+
+const SA: [[f64; 9]; 8] = [
+    [-29.0, -1.0, 9.0, 6.0, -6.0, 5.0, 4.0, 0.0, 0.0],
+    [-48.0, -125.0, -26.0, -37.0, 18.0, -13.0, -20.0, -2.0, 0.0],
+    [-150.0, -46.0, 68.0, 54.0, 14.0, 24.0, -28.0, 22.0, 0.0],
+    [-621.0, 532.0, -694.0, -20.0, 192.0, -94.0, 71.0, -73.0, 0.0],
+    [-14614.0, -19828.0, -5869.0, 1881.0, -4372.0, -2255.0, 782.0, 930.0, 913.0],
+    [139737.0, 0.0, 24667.0, 51123.0, -5102.0, 7429.0, -4095.0, -1976.0, -9566.0],
+    [-138081.0, 0.0, 37205.0, -49039.0, -41901.0, -33872.0, -27037.0, -12474.0, 18797.0],
+    [0.0, 28492.0, 133236.0, 69654.0, 52322.0, -49577.0, -26430.0, -3593.0, 0.0],
+];
+
+const KQ: [[f64; 10]; 8] = [
+    [3086.0, 15746.0, 69613.0, 59899.0, 75645.0, 88306.0, 12661.0, 2658.0, 0.0, 0.0],
+    [21863.0, 32794.0, 10931.0, 73.0, 4387.0, 26934.0, 1473.0, 2157.0, 0.0, 0.0],
+    [10.0, 16002.0, 21863.0, 10931.0, 1473.0, 32004.0, 4387.0, 73.0, 0.0, 0.0],
+    [10.0, 6345.0, 7818.0, 1107.0, 15636.0, 7077.0, 8184.0, 532.0, 10.0, 0.0],
+    [19.0, 1760.0, 1454.0, 287.0, 1167.0, 880.0, 574.0, 2640.0, 19.0, 1454.0],
+    [19.0, 574.0, 287.0, 306.0, 1760.0, 12.0, 31.0, 38.0, 19.0, 574.0],
+    [4.0, 204.0, 177.0, 8.0, 31.0, 200.0, 1265.0, 102.0, 4.0, 204.0],
+    [4.0, 102.0, 106.0, 8.0, 98.0, 1367.0, 487.0, 204.0, 4.0, 102.0],
+];
+
+const CL: [[f64; 10]; 8] = [
+    [21.0, -95.0, -157.0, 41.0, -5.0, 42.0, 23.0, 30.0, 0.0, 0.0],
+    [-160.0, -313.0, -235.0, 60.0, -74.0, -76.0, -27.0, 34.0, 0.0, 0.0],
+    [-325.0, -322.0, -79.0, 232.0, -52.0, 97.0, 55.0, -41.0, 0.0, 0.0],
+    [2268.0, -979.0, 802.0, 602.0, -668.0, -33.0, 345.0, 201.0, -55.0, 0.0],
+    [7610.0, -4997.0, -7689.0, -5841.0, -2617.0, 1115.0, -748.0, -607.0, 6074.0, 354.0],
+    [-18549.0, 30125.0, 20012.0, -730.0, 824.0, 23.0, 1289.0, -352.0, -14767.0, -2062.0],
+    [-135245.0, -14594.0, 4197.0, -4030.0, -5630.0, -2898.0, 2540.0, -306.0, 2939.0, 1986.0],
+    [89948.0, 2103.0, 8963.0, 2695.0, 3682.0, 1648.0, 866.0, -154.0, -1963.0, -283.0],
+];
+
+const SL: [[f64; 10]; 8] = [
+    [-342.0, 136.0, -23.0, 62.0, 66.0, -52.0, -33.0, 17.0, 0.0, 0.0],
+    [524.0, -149.0, -35.0, 117.0, 151.0, 122.0, -71.0, -62.0, 0.0, 0.0],
+    [-105.0, -137.0, 258.0, 35.0, -116.0, -88.0, -112.0, -80.0, 0.0, 0.0],
+    [854.0, -205.0, -936.0, -240.0, 140.0, -341.0, -97.0, -232.0, 536.0, 0.0],
+    [-56980.0, 8016.0, 1012.0, 1448.0, -3024.0, -3710.0, 318.0, 503.0, 3767.0, 577.0],
+    [138606.0, -13478.0, -4964.0, 1441.0, -1319.0, -1482.0, 427.0, 1236.0, -9167.0, -1918.0],
+    [71234.0, -41116.0, 5334.0, -4935.0, -1848.0, 66.0, 434.0, -1748.0, 3780.0, -701.0],
+    [-47645.0, 11647.0, 2166.0, 3194.0, 679.0, 0.0, -244.0, -419.0, -2531.0, 48.0],
+];
+
+/// Longitude and distance series: multiples of (D, M, M', F), L sine coefficient (deg),
+/// R cosine coefficient (m)
+#[rustfmt::skip]
+const TLR: [([i32; 4], f64, f64); 60] = [
+    ([0, 0, 1, 0], 6.288774, -20905355.0),
+    ([2, 0, -1, 0], 1.274027, -3699111.0),
+    ([2, 0, 0, 0], 0.658314, -2955968.0),
+    ([0, 0, 2, 0], 0.213618, -569925.0),
+    ([0, 1, 0, 0], -0.185116, 48888.0),
+    ([0, 0, 0, 2], -0.114332, -3149.0),
+    ([2, 0, -2, 0], 0.058793, 246158.0),
+    ([2, -1, -1, 0], 0.057066, -152138.0),
+    ([2, 0, 1, 0], 0.053322, -170733.0),
+    ([2, -1, 0, 0], 0.045758, -204586.0),
+    ([0, 1, -1, 0], -0.040923, -129620.0),
+    ([1, 0, 0, 0], -0.03472, 108743.0),
+    ([0, 1, 1, 0], -0.030383, 104755.0),
+    ([2, 0, 0, -2], 0.015327, 10321.0),
+    ([0, 0, 1, 2], -0.012528, 0.0),
+    ([0, 0, 1, -2], 0.01098, 79661.0),
+    ([4, 0, -1, 0], 0.010675, -34782.0),
+    ([0, 0, 3, 0], 0.010034, -23210.0),
+    ([4, 0, -2, 0], 0.008548, -21636.0),
+    ([2, 1, -1, 0], -0.007888, 24208.0),
+    ([2, 1, 0, 0], -0.006766, 30824.0),
+    ([1, 0, -1, 0], -0.005163, -8379.0),
+    ([1, 1, 0, 0], 0.004987, -16675.0),
+    ([2, -1, 1, 0], 0.004036, -12831.0),
+    ([2, 0, 2, 0], 0.003994, -10445.0),
+    ([4, 0, 0, 0], 0.003861, -11650.0),
+    ([2, 0, -3, 0], 0.003665, 14403.0),
+    ([0, 1, -2, 0], -0.002689, -7003.0),
+    ([2, 0, -1, 2], -0.002602, 0.0),
+    ([2, -1, -2, 0], 0.00239, 10056.0),
+    ([1, 0, 1, 0], -0.002348, 6322.0),
+    ([2, -2, 0, 0], 0.002236, -9884.0),
+    ([0, 1, 2, 0], -0.00212, 5751.0),
+    ([0, 2, 0, 0], -0.002069, 0.0),
+    ([2, -2, -1, 0], 0.002048, -4950.0),
+    ([2, 0, 1, -2], -0.001773, 4130.0),
+    ([2, 0, 0, 2], -0.001595, 0.0),
+    ([4, -1, -1, 0], 0.001215, -3958.0),
+    ([0, 0, 2, 2], -0.00111, 0.0),
+    ([3, 0, -1, 0], -0.000892, 3258.0),
+    ([2, 1, 1, 0], -0.00081, 2616.0),
+    ([4, -1, -2, 0], 0.000759, -1897.0),
+    ([0, 2, -1, 0], -0.000713, -2117.0),
+    ([2, 2, -1, 0], -0.0007, 2354.0),
+    ([2, 1, -2, 0], 0.000691, 0.0),
+    ([2, -1, 0, -2], 0.000596, 0.0),
+    ([4, 0, 1, 0], 0.000549, -1423.0),
+    ([0, 0, 4, 0], 0.000537, -1117.0),
+    ([4, -1, 0, 0], 0.00052, -1571.0),
+    ([1, 0, -2, 0], -0.000487, -1739.0),
+    ([2, 1, 0, -2], -0.000399, 0.0),
+    ([0, 0, 2, -2], -0.000381, -4421.0),
+    ([1, 1, 1, 0], 0.000351, 0.0),
+    ([3, 0, -2, 0], -0.00034, 0.0),
+    ([4, 0, -3, 0], 0.00033, 0.0),
+    ([2, -1, 2, 0], 0.000327, 0.0),
+    ([0, 2, 1, 0], -0.000323, 1165.0),
+    ([1, 1, -1, 0], 0.000299, 0.0),
+    ([2, 0, 3, 0], 0.000294, 0.0),
+    ([2, 0, -1, -2], 0.0, 8752.0),
+];
+
+/// Latitude series: multiples of (D, M, M', F), B sine coefficient (deg)
+#[rustfmt::skip]
+const TB: [([i32; 4], f64); 60] = [
+    ([0, 0, 0, 1], 5.128122),
+    ([0, 0, 1, 1], 0.280602),
+    ([0, 0, 1, -1], 0.277693),
+    ([2, 0, 0, -1], 0.173237),
+    ([2, 0, -1, 1], 0.055413),
+    ([2, 0, -1, -1], 0.046271),
+    ([2, 0, 0, 1], 0.032573),
+    ([0, 0, 2, 1], 0.017198),
+    ([2, 0, 1, -1], 0.009266),
+    ([0, 0, 2, -1], 0.008822),
+    ([2, -1, 0, -1], 0.008216),
+    ([2, 0, -2, -1], 0.004324),
+    ([2, 0, 1, 1], 0.0042),
+    ([2, 1, 0, -1], -0.003359),
+    ([2, -1, -1, 1], 0.002463),
+    ([2, -1, 0, 1], 0.002211),
+    ([2, -1, -1, -1], 0.002065),
+    ([0, 1, -1, -1], -0.00187),
+    ([4, 0, -1, -1], 0.001828),
+    ([0, 1, 0, 1], -0.001794),
+    ([0, 0, 0, 3], -0.001749),
+    ([0, 1, -1, 1], -0.001565),
+    ([1, 0, 0, 1], -0.001491),
+    ([0, 1, 1, 1], -0.001475),
+    ([0, 1, 1, -1], -0.00141),
+    ([0, 1, 0, -1], -0.001344),
+    ([1, 0, 0, -1], -0.001335),
+    ([0, 0, 3, 1], 0.001107),
+    ([4, 0, 0, -1], 0.001021),
+    ([4, 0, -1, 1], 0.000833),
+    ([0, 0, 1, -3], 0.000777),
+    ([4, 0, -2, 1], 0.000671),
+    ([2, 0, 0, -3], 0.000607),
+    ([2, 0, 2, -1], 0.000596),
+    ([2, -1, 1, -1], 0.000491),
+    ([2, 0, -2, 1], -0.000451),
+    ([0, 0, 3, -1], 0.000439),
+    ([2, 0, 2, 1], 0.000422),
+    ([2, 0, -3, -1], 0.000421),
+    ([2, 1, -1, 1], -0.000366),
+    ([2, 1, 0, 1], -0.000351),
+    ([4, 0, 0, 1], 0.000331),
+    ([2, -1, 1, 1], 0.000315),
+    ([2, -2, 0, -1], 0.000302),
+    ([0, 0, 1, 3], -0.000283),
+    ([2, 1, 1, -1], -0.000229),
+    ([1, 1, 0, -1], 0.000223),
+    ([1, 1, 0, 1], 0.000223),
+    ([0, 1, -2, -1], -0.00022),
+    ([2, 1, -1, -1], -0.00022),
+    ([1, 0, 1, 1], -0.000185),
+    ([2, -1, -2, -1], 0.000181),
+    ([0, 1, 2, 1], -0.000177),
+    ([4, 0, -2, -1], 0.000176),
+    ([4, -1, -1, -1], 0.000166),
+    ([1, 0, 1, -1], -0.000164),
+    ([4, 0, 1, -1], 0.000132),
+    ([1, 0, -1, -1], -0.000119),
+    ([4, -1, 0, -1], 0.000115),
+    ([2, -2, 0, 1], 0.000107),
+];
+
+/// Heliocentric position and velocity of a planet (approximate).
+///
+/// `np` is 1 = Mercury, 2 = Venus, 3 = Earth-Moon barycentre, 4 = Mars,
+/// ..., 8 = Neptune. Returns `[x, y, z, vx, vy, vz]` in AU and AU/day,
+/// J2000.0 mean equator and equinox.
+///
+/// # Errors
+/// Returns `BackendError::BadInputValue` for an invalid planet number.
+#[allow(non_snake_case)]
+pub fn Plan94(date1: f64, date2: f64, np: i32) -> Result<[f64; 6], BackendError> {
+    if !(1..=8).contains(&np) {
+        return Err(BackendError::BadInputValue);
+    }
+    let np = (np - 1) as usize;
+
+    // Julian millennia since J2000.0
+    let t = ((date1 - DJ00) + date2) / 365250.0;
+
+    // Mean elements
+    let mut da = A[np][0] + (A[np][1] + A[np][2] * t) * t;
+    let mut dl = (3600.0 * DLM[np][0] + (DLM[np][1] + DLM[np][2] * t) * t) * DAS2R;
+    let de = E[np][0] + (E[np][1] + E[np][2] * t) * t;
+    let dp = anpm((3600.0 * PI[np][0] + (PI[np][1] + PI[np][2] * t) * t) * DAS2R);
+    let di = (3600.0 * DINC[np][0] + (DINC[np][1] + DINC[np][2] * t) * t) * DAS2R;
+    let dom = anpm((3600.0 * OMEGA[np][0] + (OMEGA[np][1] + OMEGA[np][2] * t) * t) * DAS2R);
+
+    // Trigonometric terms
+    let dmu = 0.35953620 * t;
+    for k in 0..8 {
+        let arga = KP[np][k] * dmu;
+        let argl = KQ[np][k] * dmu;
+        da += (CA[np][k] * arga.cos() + SA[np][k] * arga.sin()) * 1e-7;
+        dl += (CL[np][k] * argl.cos() + SL[np][k] * argl.sin()) * 1e-7;
+    }
+    let arga = KP[np][8] * dmu;
+    da += t * (CA[np][8] * arga.cos() + SA[np][8] * arga.sin()) * 1e-7;
+    for k in 8..10 {
+        let argl = KQ[np][k] * dmu;
+        dl += t * (CL[np][k] * argl.cos() + SL[np][k] * argl.sin()) * 1e-7;
+    }
+    dl %= TAU;
+
+    // Kepler's equation
+    let am = dl - dp;
+    let mut ae = am + de * am.sin();
+    for _ in 0..10 {
+        let dae = (am - ae + de * ae.sin()) / (1.0 - de * ae.cos());
+        ae += dae;
+        if dae.abs() <= 1e-12 {
+            break;
+        }
+    }
+
+    // True anomaly, distance (AU) and speed (radians per day)
+    let ae2 = ae / 2.0;
+    let at = 2.0 * (((1.0 + de) / (1.0 - de)).sqrt() * ae2.sin()).atan2(ae2.cos());
+    let r = da * (1.0 - de * ae.cos());
+    let v = GK * ((1.0 + 1.0 / AMAS[np]) / (da * da * da)).sqrt();
+
+    let si2 = (di / 2.0).sin();
+    let xq = si2 * dom.cos();
+    let xp = si2 * dom.sin();
+    let tl = at + dp;
+    let (xsw, xcw) = tl.sin_cos();
+    let xm2 = 2.0 * (xp * xcw - xq * xsw);
+    let xf = da / (1.0 - de * de).sqrt();
+    let ci2 = (di / 2.0).cos();
+    let xms = (de * dp.sin() + xsw) * xf;
+    let xmc = (de * dp.cos() + xcw) * xf;
+    let xpxq2 = 2.0 * xp * xq;
+
+    // Ecliptic position and velocity, rotated to equatorial
+    let x = r * (xcw - xm2 * xp);
+    let y = r * (xsw + xm2 * xq);
+    let z = r * (-xm2 * ci2);
+    let vx = v * ((-1.0 + 2.0 * xp * xp) * xms + xpxq2 * xmc);
+    let vy = v * ((1.0 - 2.0 * xq * xq) * xmc - xpxq2 * xms);
+    let vz = v * (2.0 * ci2 * (xp * xms + xq * xmc));
+
+    Ok([
+        x,
+        y * COSEPS - z * SINEPS,
+        y * SINEPS + z * COSEPS,
+        vx,
+        vy * COSEPS - vz * SINEPS,
+        vy * SINEPS + vz * COSEPS,
+    ])
+}
+
+/// Geocentric position and velocity of the Moon (GCRS, AU and AU/day),
+/// after Meeus's simplification of ELP2000-82.
+#[allow(non_snake_case)]
+pub fn Moon98(date1: f64, date2: f64) -> [f64; 6] {
+    let t = centuries(date1, date2);
+    let dd2r = TAU / 360.0;
+
+    // Fundamental argument and its derivative (radians, radians/century)
+    let fundamental = |c: [f64; 5]| {
+        let value = dd2r * ((c[0] + (c[1] + (c[2] + (c[3] + c[4] * t) * t) * t) * t) % 360.0);
+        let rate = dd2r * (c[1] + (c[2] * 2.0 + (c[3] * 3.0 + c[4] * 4.0 * t) * t) * t);
+        (value, rate)
+    };
+    let (elp, delp) = fundamental([218.31665436, 481267.88123421, -0.0015786, 1.0 / 538841.0, -1.0 / 65194000.0]);
+    let (d, dd) = fundamental([297.8501921, 445267.1114034, -0.0018819, 1.0 / 545868.0, 1.0 / 113065000.0]);
+    let (em, dem) = fundamental([357.5291092, 35999.0502909, -0.0001536, 1.0 / 24490000.0, 0.0]);
+    let (emp, demp) = fundamental([134.9633964, 477198.8675055, 0.0087414, 1.0 / 69699.0, -1.0 / 14712000.0]);
+    let (f, df) = fundamental([93.2720950, 483202.0175233, -0.0036539, 1.0 / 3526000.0, 1.0 / 863310000.0]);
+
+    // Meeus further arguments
+    const AL1: f64 = 0.003958;
+    const AL2: f64 = 0.001962;
+    const AL3: f64 = 0.000318;
+    const AB: [f64; 6] = [-0.002235, 0.000382, 0.000175, 0.000175, 0.000127, -0.000115];
+    let a1 = dd2r * (119.75 + 131.849 * t);
+    // As in ERFA, which uses al1 (not a11) for this rate
+    let da1 = dd2r * AL1;
+    let a2 = dd2r * (53.09 + 479264.290 * t);
+    let da2 = dd2r * 479264.290;
+    let a3 = dd2r * (313.45 + 481266.484 * t);
+    let da3 = dd2r * 481266.484;
+
+    // E-factor and its square
+    let e = 1.0 + (-0.002516 - 0.0000074 * t) * t;
+    let de = -0.002516 - 2.0 * 0.0000074 * t;
+    let esq = e * e;
+    let desq = 2.0 * e * de;
+
+    // Meeus additive terms start off the summations
+    let elpmf = elp - f;
+    let delpmf = delp - df;
+    let mut vel = AL1 * a1.sin() + AL2 * elpmf.sin() + AL3 * a2.sin();
+    let mut vdel = AL1 * a1.cos() * da1 + AL2 * elpmf.cos() * delpmf + AL3 * a2.cos() * da2;
+    let mut vr = 0.0;
+    let mut vdr = 0.0;
+
+    let (a1mf, da1mf) = (a1 - f, da1 - df);
+    let (a1pf, da1pf) = (a1 + f, da1 + df);
+    let (dlpmp, slpmp) = (elp - emp, elp + emp);
+    let mut vb = AB[0] * elp.sin()
+        + AB[1] * a3.sin()
+        + AB[2] * a1mf.sin()
+        + AB[3] * a1pf.sin()
+        + AB[4] * dlpmp.sin()
+        + AB[5] * slpmp.sin();
+    let mut vdb = AB[0] * elp.cos() * delp
+        + AB[1] * a3.cos() * da3
+        + AB[2] * a1mf.cos() * da1mf
+        + AB[3] * a1pf.cos() * da1pf
+        + AB[4] * dlpmp.cos() * (delp - demp)
+        + AB[5] * slpmp.cos() * (delp + demp);
+
+    // Argument, its rate, and the eccentricity factor for one series term
+    let term = |n: &[i32; 4]| {
+        let (en, den) = match n[1].abs() {
+            1 => (e, de),
+            2 => (esq, desq),
+            _ => (1.0, 0.0),
+        };
+        let (nd, nem, nemp, nf) = (n[0] as f64, n[1] as f64, n[2] as f64, n[3] as f64);
+        let arg = nd * d + nem * em + nemp * emp + nf * f;
+        let darg = nd * dd + nem * dem + nemp * demp + nf * df;
+        (arg, darg, en, den)
+    };
+
+    // Longitude and distance plus derivatives
+    for (n, coefl, coefr) in TLR.iter().rev() {
+        let (arg, darg, en, den) = term(n);
+        let (s, c) = arg.sin_cos();
+        vel += coefl * s * en;
+        vdel += coefl * (c * darg * en + s * den);
+        vr += coefr * c * en;
+        vdr += coefr * (-s * darg * en + c * den);
+    }
+    let el = elp + dd2r * vel;
+    let del = (delp + dd2r * vdel) / DJC;
+    let r = (vr + 385000560.0) / DAU;
+    let dr = vdr / DAU / DJC;
+
+    // Latitude plus derivative
+    for (n, coefb) in TB.iter().rev() {
+        let (arg, darg, en, den) = term(n);
+        let (s, c) = arg.sin_cos();
+        vb += coefb * s * en;
+        vdb += coefb * (c * darg * en + s * den);
+    }
+    let b = vb * dd2r;
+    let db = vdb * dd2r / DJC;
+
+    // Spherical to position/velocity (mean ecliptic of date)
+    let (sl, cl) = el.sin_cos();
+    let (sb, cb) = b.sin_cos();
+    let rcb = r * cb;
+    let x = rcb * cl;
+    let y = rcb * sl;
+    let p = [x, y, r * sb];
+    let rdb = r * db;
+    let w = rdb * sb - cb * dr;
+    let v = [-y * del - w * cl, x * del - w * sl, rdb * cb + sb * dr];
+
+    // Mean ecliptic of date to GCRS
+    let (gamb, phib, psib, _) = pfw06(date1, date2);
+    let mut rm = identity();
+    rz(psib, &mut rm);
+    rx(-phib, &mut rm);
+    rz(-gamb, &mut rm);
+    let p = rxp(&rm, &p);
+    let v = rxp(&rm, &v);
+    [p[0], p[1], p[2], v[0], v[1], v[2]]
+}
+
+/// Earth position and velocity, heliocentric and barycentric (BCRS, AU and
+/// AU/day).
+///
+/// **Differs from ERFA:** evaluates the `eraEpv00` harmonic series truncated
+/// to the terms whose position or velocity contribution can reach 1e-7 AU
+/// (848 of 1951 terms), which keeps it within 1e-6 AU and 0.02 m/s of
+/// ERFA over 1900–2100.
+///
+/// Returns `(pvh, pvb)`, each `[x, y, z, vx, vy, vz]`.
+#[allow(non_snake_case)]
+pub fn Epv00(date1: f64, date2: f64) -> ([f64; 6], [f64; 6]) {
+    // Julian years since J2000.0
+    let t = ((date1 - DJ00) + date2) / DJY;
+
+    let sun_to_earth: [[&[[f64; 3]]; 3]; 3] = [
+        [&E0X, &E1X, &E2X],
+        [&E0Y, &E1Y, &E2Y],
+        [&E0Z, &E1Z, &E2Z],
+    ];
+    let ssb_to_sun: [[&[[f64; 3]]; 3]; 3] = [
+        [&S0X, &S1X, &S2X],
+        [&S0Y, &S1Y, &S2Y],
+        [&S0Z, &S1Z, &S2Z],
+    ];
+
+    // Sum of a t^0, t^1, t^2 Poisson series and its time derivative
+    let series = |groups: &[&[[f64; 3]]; 3]| {
+        let mut xyz = 0.0;
+        let mut xyzd = 0.0;
+        for (power, terms) in groups.iter().enumerate() {
+            let tp = t.powi(power as i32);
+            for &[a, b, c] in terms.iter() {
+                let p = b + c * t;
+                let (sp, cp) = p.sin_cos();
+                xyz += a * tp * cp;
+                xyzd += match power {
+                    0 => -a * c * sp,
+                    1 => a * (cp - c * t * sp),
+                    _ => a * t * (2.0 * cp - c * t * sp),
+                };
+            }
+        }
+        (xyz, xyzd)
+    };
+
+    let mut ph = [0.0; 3];
+    let mut vh = [0.0; 3];
+    let mut pb = [0.0; 3];
+    let mut vb = [0.0; 3];
+    for i in 0..3 {
+        let (x, xd) = series(&sun_to_earth[i]);
+        let (bx, bxd) = series(&ssb_to_sun[i]);
+        ph[i] = x;
+        vh[i] = xd / DJY;
+        pb[i] = x + bx;
+        vb[i] = (xd + bxd) / DJY;
+    }
+
+    // Ecliptic to BCRS, oriented to DE405
+    let orient = |v: [f64; 3]| {
+        [
+            v[0] + AM12 * v[1] + AM13 * v[2],
+            AM21 * v[0] + AM22 * v[1] + AM23 * v[2],
+            AM32 * v[1] + AM33 * v[2],
+        ]
+    };
+    let (ph, vh, pb, vb) = (orient(ph), orient(vh), orient(pb), orient(vb));
+    (
+        [ph[0], ph[1], ph[2], vh[0], vh[1], vh[2]],
+        [pb[0], pb[1], pb[2], vb[0], vb[1], vb[2]],
+    )
+}
+
+// Matrix elements orienting the Epv00 analytical model to DE405
+const AM12: f64 = 0.000000211284;
+const AM13: f64 = -0.000000091603;
+const AM21: f64 = -0.000000230286;
+const AM22: f64 = 0.917482137087;
+const AM23: f64 = -0.397776982902;
+const AM32: f64 = 0.397776982902;
+const AM33: f64 = 0.917482137087;
+
+// Truncated Epv00 series: (amplitude AU, phase rad, frequency rad/year).
+// E*: Sun to Earth; S*: SSB to Sun; digit: power of t.
+const E0X: [[f64; 3]; 274] = [
+    [0.9998292878132, 1.753485171504, 6.283075850446],
+    [0.008352579567414, 1.710344404582, 12.56615170089],
+    [0.005611445335148, 0.0, 0.0],
+    [0.0001046664295572, 1.66722541677, 18.84922755134],
+    [3.110842534677e-05, 0.6687513390251, 83.99684731857],
+    [2.55241350355e-05, 0.5830637358413, 0.5296909721118],
+    [2.137207845781e-05, 1.092330954011, 1.577343543434],
+    [1.680240182951e-05, 0.4955366134987, 6.279552690824],
+    [1.679012370795e-05, 6.153014091901, 6.286599010068],
+    [1.445526946777e-05, 3.472744100492, 2.352866153506],
+    [1.091038246184e-05, 3.689845786119, 5.223693906222],
+    [9.344399733932e-06, 6.073934645672, 12.03646072878],
+    [8.993182910652e-06, 3.175705249069, 10.21328554739],
+    [5.665546034116e-06, 2.152484672246, 1.059381944224],
+    [6.844146703035e-06, 1.30696409975, 5.753384878334],
+    [7.346610905565e-06, 4.354980070466, 0.3981490189893],
+    [6.815396474414e-06, 2.218229211267, 4.705732307012],
+    [6.112787253053e-06, 5.384788425458, 6.812766822558],
+    [4.518120711239e-06, 6.087604012291, 5.884926831456],
+    [4.521963430706e-06, 1.279424524906, 6.256777527156],
+    [4.497426764085e-06, 5.369129144266, 6.309374173736],
+    [4.062190566959e-06, 0.5436473303367, 6.681224869435],
+    [5.412193480192e-06, 0.7867838528395, 0.775522610072],
+    [5.469839049386e-06, 1.461440311134, 14.14349524433],
+    [5.205264083477e-06, 4.432944696116, 7.86041939388],
+    [2.149759935455e-06, 4.502237496846, 11.50676975667],
+    [2.279109618501e-06, 1.239441308815, 7.058598460518],
+    [2.259282939683e-06, 3.272430985331, 4.69400293411],
+    [2.558950271319e-06, 2.265471086404, 12.1680026819],
+    [2.561581447555e-06, 1.454740653245, 0.7099330490126],
+    [1.78144111544e-06, 2.962068630206, 0.7962980379786],
+    [1.612005874644e-06, 1.473255041006, 5.486777812467],
+    [1.818630667105e-06, 0.3743903293447, 6.283008715021],
+    [1.818601377529e-06, 6.274174354554, 6.28314298587],
+    [1.554475925257e-06, 1.624110906816, 25.13230340178],
+    [2.090948029241e-06, 5.852052276256, 11.79062909082],
+    [2.00017634546e-06, 4.072093298513, 17.78984560711],
+    [1.289535917759e-06, 5.217019331069, 7.079373888424],
+    [1.281135307881e-06, 4.802054538934, 3.738761453707],
+    [1.518229005692e-06, 0.8691914742502, 0.2132990797783],
+    [9.450128579027e-07, 4.60185952995, 10.97707878456],
+    [7.781119494996e-07, 1.844352816694, 8.827390247185],
+    [7.733407759912e-07, 3.58279015475, 5.507553240374],
+    [7.35064431812e-07, 2.69527778823, 1.589072916335],
+    [6.535928827023e-07, 3.651327986142, 11.76985366291],
+    [6.324624183656e-07, 2.241302375862, 6.262300422539],
+    [6.298565300557e-07, 4.407122406081, 6.303851278352],
+    [8.587037089179e-07, 3.024307223119, 167.2837615881],
+    [8.299954491035e-07, 6.192539428237, 3.340612434717],
+    [6.311263503401e-07, 2.014758795416, 0.0071134546679],
+    [6.005646745452e-07, 3.399500503397, 4.136910472696],
+    [7.917715109929e-07, 2.493386877837, 6.069776770667],
+    [7.556958099685e-07, 4.159491740143, 6.496374930224],
+    [6.773228244949e-07, 4.03416293423, 9.437762937313],
+    [5.370708577847e-07, 1.562219163734, 1.194447056968],
+    [5.710804266203e-07, 2.662730803386, 6.282095334605],
+    [5.709824583726e-07, 3.985828430833, 6.284056366286],
+    [5.143950896447e-07, 1.308144688689, 6.290189305114],
+    [5.088010604546e-07, 5.352817214804, 6.275962395778],
+    [4.960369085172e-07, 2.644267922349, 6.127655567643],
+    [4.803137891183e-07, 4.00884419208, 6.438496133249],
+    [5.731747768225e-07, 3.794550174597, 3.154687086868],
+    [4.735947960579e-07, 6.107118308982, 3.128388763578],
+    [4.808348796625e-07, 4.771458618163, 0.8018209333619],
+    [4.115073743137e-07, 3.327111335159, 8.429241228195],
+    [5.230575889287e-07, 5.305708551694, 13.36797263425],
+    [5.133977889215e-07, 5.784230738814, 12.35285262111],
+    [5.065815825327e-07, 2.052064793679, 11.85621865188],
+    [4.339831593868e-07, 3.64499419583, 17.260154635],
+    [3.952928638953e-07, 4.930376436758, 5.481254917084],
+    [4.898498111942e-07, 0.4542084219731, 9.225539266174],
+    [4.757490209328e-07, 3.161126388878, 5.856477690889],
+    [4.727701669749e-07, 0.6214993845446, 2.544314396739],
+    [3.800966681863e-07, 3.040132339297, 0.4265981595566],
+    [3.257301077939e-07, 0.8064977360087, 3.93020969694],
+    [3.255810528674e-07, 1.974147981034, 2.14616537775],
+    [3.252029748187e-07, 2.845924913135, 4.164311961999],
+    [3.255505635308e-07, 3.01790082412, 5.088628793478],
+    [2.80134521199e-07, 6.109717793179, 12.56967486051],
+    [3.68898774097e-07, 2.911550235289, 18.07370494127],
+    [2.475153429458e-07, 2.179146025856, 0.0262983232899],
+    [3.03345774915e-07, 1.994161050744, 4.535059491685],
+    [2.18674376311e-07, 5.125687237936, 11.37170464392],
+    [2.764777032774e-07, 0.4822646860252, 12.56262854127],
+    [2.199028768592e-07, 4.637633293831, 12.55903824622],
+    [2.04648282476e-07, 1.467038733093, 7.084896783808],
+    [2.611209147507e-07, 0.3044718783485, 71.43069561767],
+    [2.286079656818e-07, 4.764220356805, 8.031092209206],
+    [1.855071202587e-07, 3.383637774428, 1.74801635876],
+    [2.324669506784e-07, 6.189088449251, 18.31953657923],
+    [1.709528015688e-07, 0.5874966729774, 4.933208510675],
+    [2.168156875828e-07, 4.302994009132, 10.44738781244],
+    [2.106675556535e-07, 3.800475419891, 7.477522907414],
+    [1.430213830465e-07, 1.294660846502, 2.942463415728],
+    [1.388396901944e-07, 4.594797202114, 8.635942003952],
+    [1.92225884419e-07, 0.4943044543591, 17.29818233119],
+    [1.888460058292e-07, 2.426943912028, 156.1374759853],
+    [1.789449386107e-07, 0.1582973303499, 1.592596075957],
+    [1.360803685374e-07, 5.197240440504, 13.095842673],
+    [1.504038014709e-07, 3.120360916217, 16.49636139783],
+    [1.382769533389e-07, 6.164702888205, 7.632943190217],
+    [1.438059769079e-07, 1.437423770979, 20.42657109477],
+    [1.326303260037e-07, 3.609688799679, 12.13955354133],
+    [1.15924495054e-07, 5.463018167225, 5.331357529664],
+    [1.433118149136e-07, 6.028909912097, 7.342457794669],
+    [1.234623148594e-07, 3.109645574997, 6.2794855554],
+    [1.233949875344e-07, 3.539359332866, 6.286666145492],
+    [9.927196061299e-08, 1.259321569772, 7.234794171227],
+    [1.242302191316e-07, 1.065949392609, 15.11046609763],
+    [1.098402195201e-07, 2.192508743837, 10.98880815746],
+    [1.158191395315e-07, 4.05441127865, 5.729506548653],
+    [9.048475596241e-08, 5.429764748518, 9.623688285163],
+    [8.889853269023e-08, 5.046586206575, 6.148010737701],
+    [1.048694242164e-07, 2.628858030806, 6.836645152238],
+    [1.112308378646e-07, 4.177292719907, 15.72083878776],
+    [8.631729709901e-08, 1.601345232557, 6.41814096319],
+    [8.527816951664e-08, 2.463888997513, 14.71231707864],
+    [7.892139456991e-08, 3.154022088718, 2.118763888447],
+    [1.051782905236e-07, 4.795035816088, 1.349867339771],
+    [1.048219943164e-07, 2.95298339523, 5.999216516294],
+    [7.435760775143e-08, 5.420547991464, 6.04034711426],
+    [9.869574106949e-08, 3.695646753667, 6.566935184597],
+    [9.156886364226e-08, 3.922675306609, 5.643178611111],
+    [7.006834356188e-08, 1.233968624861, 6.525804586632],
+    [9.806170182601e-08, 1.919542280684, 21.22839202813],
+    [9.052289673607e-08, 4.615902724369, 4.690479774488],
+    [7.554200867893e-08, 1.236863719072, 12.5398533776],
+    [8.215741286498e-08, 0.3286800101559, 10.97355562493],
+    [7.185178575397e-08, 5.880942158367, 6.245048154254],
+    [7.13072647618e-08, 0.7674871987661, 6.321103546637],
+    [6.650894461162e-08, 0.6987129150116, 5.327476111629],
+    [7.420588884775e-08, 5.033615245369, 23.54323048545],
+    [6.141181642908e-08, 0.9449927045673, 12.96430071988],
+    [6.359474329261e-08, 5.036079095757, 1.990745094947],
+    [6.398054487042e-08, 3.976367969666, 24.07292145756],
+    [7.797092650498e-08, 4.305423910623, 22.0039146382],
+    [6.4667600009e-08, 3.5001368252, 5.23080736089],
+    [7.52941704389e-08, 3.5147792461, 18.42262939178],
+    [6.220798650222e-08, 2.242598118209, 18.45107853235],
+    [6.263953473888e-08, 2.191105358956, 6.277552955062],
+    [6.257781390012e-08, 4.457559396698, 6.288598745829],
+    [6.335438746791e-08, 0.6441691079251, 5.216580451554],
+    [6.377258441152e-08, 2.252599151092, 5.650292065779],
+    [4.73555148525e-08, 3.744672082942, 14.31416805965],
+    [6.258152336933e-08, 4.395836159154, 26.0879031406],
+    [6.196171366594e-08, 2.587043007997, 84.67247584405],
+    [6.159556952126e-08, 4.782499769128, 239.4243902548],
+    [4.987741172394e-08, 0.7312257619924, 77.71377146812],
+    [5.459280703142e-08, 3.001376372532, 6.17998303789],
+    [4.863461189999e-08, 3.767222128541, 90.27992316901],
+    [5.349912093158e-08, 3.663594450273, 6.386168663001],
+    [5.673725607806e-08, 4.331187919049, 6.915859635113],
+    [4.745485060512e-08, 5.816195745518, 6.282970628506],
+    [4.745379005326e-08, 0.8323672435672, 6.283181072386],
+    [4.049002796321e-08, 3.785023976293, 6.254626709878],
+    [4.247084014515e-08, 2.378220728783, 7.875671926403],
+    [4.026912363055e-08, 2.864103423269, 6.311524991013],
+    [4.062935011774e-08, 2.415408595975, 3.634620989887],
+    [5.347771048509e-08, 3.343479309801, 25.15860172507],
+    [4.829494136505e-08, 2.821742398262, 5.760498333002],
+    [4.342554404599e-08, 5.624662458712, 7.238675589263],
+    [4.021599184361e-08, 0.5557250275009, 11.01510648075],
+    [4.104900474558e-08, 3.296691780005, 6.709674010002],
+    [4.376532905131e-08, 3.814443999443, 6.80565336789],
+    [3.31459048065e-08, 3.56022918925, 12.59245002418],
+    [3.541176318876e-08, 3.921381909679, 9.917696840332],
+    [3.689831242681e-08, 4.190658955386, 11.92625446156],
+    [3.03855933978e-08, 6.231032794494, 12.56621883632],
+    [3.137083969782e-08, 6.20706341919, 4.292330755499],
+    [4.024004081854e-08, 1.195257375713, 13.34167431096],
+    [3.300234879283e-08, 1.804694240998, 10.57540660594],
+    [3.635399155575e-08, 5.5978113435, 6.208294184755],
+    [3.032668691356e-08, 3.19105936653, 18.05292951336],
+    [3.696955383823e-08, 5.219282738794, 5.966683958112],
+    [3.562894142503e-08, 1.037247544554, 6.357857516136],
+    [3.510598524148e-08, 1.430020816116, 6.599467742779],
+    [3.617736142953e-08, 3.002911403677, 6.019991944201],
+    [2.62452491073e-08, 2.437046757292, 6.702560555334],
+    [2.53582420449e-08, 1.581594689647, 31.41537925223],
+    [3.519787226257e-08, 5.379863121521, 250.5706758577],
+    [2.578406709982e-08, 4.904222639329, 16.73046366289],
+    [3.423887981473e-08, 3.646448997315, 6.546159756691],
+    [2.776083886467e-08, 3.307829300144, 12.72157198369],
+    [3.379592818379e-08, 1.747541251125, 14.94531617769],
+    [3.050255426284e-08, 0.01784689432607, 4.732030630302],
+    [2.652378350236e-08, 4.42005527626, 5.863591145557],
+    [2.71645125514e-08, 3.07962370678, 12.02934727411],
+    [3.038583699229e-08, 0.3312487903507, 12.56608456547],
+    [2.22068122876e-08, 5.265520401774, 13.36244973887],
+    [3.044156540912e-08, 4.76666408125, 29.08881142201],
+    [2.731859923561e-08, 5.069146530691, 13.91601904066],
+    [2.285603018171e-08, 5.954935112271, 6.076890225335],
+    [2.012597519804e-08, 2.485047705241, 6.262720680387],
+    [2.003406962258e-08, 4.16377920932, 6.303431020504],
+    [2.207863441371e-08, 0.6923839133828, 6.489261475556],
+    [2.481374305624e-08, 5.944173595676, 12.04357418345],
+    [2.13092328887e-08, 4.641013671967, 5.746271423666],
+    [1.932492759052e-08, 0.2234572324504, 13.52175143971],
+    [2.600122568049e-08, 4.28101240544, 4.590910121555],
+    [1.875902869209e-08, 0.9781803816948, 6.27919443241],
+    [1.874381139426e-08, 5.670368130173, 6.286957268481],
+    [2.156696047173e-08, 2.008985006833, 18.13929450232],
+    [2.334816372359e-08, 4.408121891493, 10.02183730415],
+    [2.436236460883e-08, 4.407720479029, 95.14313292143],
+    [1.761365216611e-08, 0.1943892315074, 13.51787002167],
+    [2.156289480503e-08, 1.418570924545, 6.037244212485],
+    [2.164748979255e-08, 4.72460343943, 23.01353951334],
+    [2.222286670853e-08, 2.400266874598, 12.66924451345],
+    [2.070901414929e-08, 5.230348028732, 6.528907488406],
+    [1.79274517702e-08, 2.099190328945, 6.819880277225],
+    [1.841802068445e-08, 0.3467527844848, 65.14761976723],
+    [1.737356469576e-08, 4.487064760345, 17.65478049437],
+    [1.424812827089e-08, 1.682114725827, 13.11972100268],
+    [1.380282448623e-08, 3.262668602579, 10.17725758696],
+    [1.811481244566e-08, 3.187771221777, 18.87552587463],
+    [1.740776154137e-08, 5.487068607507, 19.6510484847],
+    [1.761377477704e-08, 5.748060203659, 25.93412433514],
+    [1.535138225795e-08, 6.22684850579, 9.411464614024],
+    [1.788140543676e-08, 6.189318878563, 33.01902111895],
+    [1.450977333938e-08, 4.143836662127, 12.77945078067],
+    [1.462667934821e-08, 5.760505536428, 18.63592847156],
+    [1.682333169307e-08, 5.409870870133, 16.20077269078],
+    [1.190812918837e-08, 1.397205174601, 11.499656302],
+    [1.221434762106e-08, 0.9001804809095, 12.57326515556],
+    [1.54993464486e-08, 4.262528275544, 18.209330312],
+    [1.237078905387e-08, 2.844472403615, 24.35678079171],
+    [1.297768238261e-08, 1.074509953328, 12.4913700352],
+    [1.018926508678e-08, 2.2166078543, 12.54537627298],
+    [9.500087869225e-09, 2.625116459733, 12.56517118505],
+    [1.117645675413e-08, 3.932148831189, 17.79695906178],
+    [1.15886405216e-08, 0.9995605521691, 17.78273215245],
+    [1.211463487798e-08, 4.041544938511, 82.57698122054],
+    [1.038720703636e-08, 0.4594249718112, 15.50861511662],
+    [9.168973650819e-09, 2.470150501679, 149.8544001348],
+    [1.075444949238e-08, 1.32860616123, 36.94923081589],
+    [9.541469226356e-09, 3.942568967039, 12.56713221673],
+    [9.821910122027e-09, 0.2360246287233, 11.40367694411],
+    [9.897822023777e-09, 4.61980563428, 22.80573557157],
+    [7.147219933778e-09, 5.516616675856, 17.25663147538],
+    [8.298582787358e-09, 0.5236413127363, 12.41658836951],
+    [7.232154664726e-09, 3.067548981535, 161.0006857377],
+    [5.466993627395e-09, 4.588357817278, 18.84211409667],
+    [6.34051209098e-09, 1.164543038893, 52.1758062812],
+    [6.589828273941e-09, 2.750967106776, 40.87944051283],
+    [5.383376567189e-09, 0.6325947523578, 22.48384854122],
+    [5.353772620129e-09, 6.12277496824, 173.5668374386],
+    [5.972838885276e-09, 6.044489493203, 26.73594526851],
+    [5.210772682858e-09, 6.220111376901, 24.60261242967],
+    [6.029425105059e-09, 0.8548704071116, 322.7113045244],
+    [5.836024505068e-09, 0.07135651752625, 72.8505617157],
+    [5.694429833732e-09, 2.952369582215, 97.23862754494],
+    [4.784939596873e-09, 6.196709413181, 29.29661536378],
+    [4.387748764954e-09, 4.825580552819, 233.1413144044],
+    [4.949443923785e-09, 2.725622229926, 161.7106187867],
+    [4.207206893193e-09, 0.9494780468236, 29.86433403208],
+    [4.099438144212e-09, 4.173244670532, 44.80965020977],
+    [3.720398002179e-09, 1.184933429829, 30.66615496545],
+    [3.187610710605e-09, 4.710624424816, 169.3792562116],
+    [2.687593060336e-09, 1.526689456959, 70.75506709219],
+    [2.582135006946e-09, 5.595464352926, 48.73985990671],
+    [1.72001791628e-09, 4.942488551129, 167.9593901136],
+    [1.702427665131e-09, 1.452233856386, 333.8575901272],
+    [1.414032510054e-09, 5.525357721439, 162.4205518357],
+    [1.652626045364e-09, 4.108794283624, 89.56999012],
+    [1.593193738177e-09, 4.185136203609, 228.2781046519],
+    [1.258433517061e-09, 0.2575068876639, 311.5650189215],
+    [1.240303229539e-09, 0.4800844956756, 178.430047191],
+    [7.217398104321e-10, 1.597772562175, 245.7074661053],
+    [6.912033134447e-10, 5.824090621461, 167.9936946371],
+    [5.173709754788e-10, 5.422427507933, 244.2876000072],
+    [4.7127233654e-10, 0.8979003224474, 316.4282286739],
+    [4.909967465112e-10, 3.210426725516, 405.9982187939],
+    [3.943451445989e-10, 2.195145341074, 256.8537517081],
+    [3.738330190479e-10, 2.613062847997, 394.851933191],
+];
+
+const E0Y: [[f64; 3]; 274] = [
+    [0.9998921098898, 0.1826583913846, 6.283075850446],
+    [-0.02442700893735, 0.0, 0.0],
+    [0.008352929742915, 0.139527799868, 12.56615170089],
+    [0.0001046697300177, 0.09641423109763, 18.84922755134],
+    [3.110841876663e-05, 5.381140401712, 83.99684731857],
+    [2.570269094593e-05, 5.301016407128, 0.5296909721118],
+    [2.14738962361e-05, 2.66251086985, 1.577343543434],
+    [1.68034438405e-05, 5.207904119704, 6.279552690824],
+    [1.679117312193e-05, 4.582187486968, 6.286599010068],
+    [1.44051206844e-05, 1.900688517726, 2.352866153506],
+    [1.135139664999e-05, 5.273108538556, 5.223693906222],
+    [9.345482571018e-06, 4.503047687738, 12.03646072878],
+    [9.007418719568e-06, 1.605621059637, 10.21328554739],
+    [5.671536712314e-06, 0.5812849070861, 1.059381944224],
+    [7.451401861666e-06, 2.807346794836, 0.3981490189893],
+    [6.393470057114e-06, 6.029224133855, 5.753384878334],
+    [6.814275881697e-06, 0.6472990145974, 4.705732307012],
+    [6.113705628887e-06, 3.8138434197, 6.812766822558],
+    [4.503851367273e-06, 4.527804370996, 5.884926831456],
+    [4.522249141926e-06, 5.991783029224, 6.256777527156],
+    [4.501794307018e-06, 3.798703844397, 6.309374173736],
+    [5.51492748018e-06, 3.961257833388, 5.507553240374],
+    [4.062862799995e-06, 5.256247296369, 6.681224869435],
+    [5.414900429712e-06, 5.499032014097, 0.775522610072],
+    [5.463153987424e-06, 6.173092454097, 14.14349524433],
+    [5.071611859329e-06, 2.870244247651, 7.86041939388],
+    [2.195112094455e-06, 2.952338617201, 11.50676975667],
+    [2.279139233919e-06, 5.951775132933, 7.058598460518],
+    [2.278386100876e-06, 4.845456398785, 4.69400293411],
+    [2.559088003308e-06, 0.6945321117311, 12.1680026819],
+    [2.561079286856e-06, 6.167224608301, 0.7099330490126],
+    [1.792755796387e-06, 1.400122509632, 0.7962980379786],
+    [1.818715656502e-06, 4.70334761183, 6.28314298587],
+    [1.818744924791e-06, 5.086748900237, 6.283008715021],
+    [1.55451879139e-06, 0.05331008042713, 25.13230340178],
+    [2.063265737239e-06, 4.283680484178, 11.79062909082],
+    [1.497613520041e-06, 6.074207826073, 5.486777812467],
+    [2.000617940427e-06, 2.50142628145, 17.78984560711],
+    [1.28973119558e-06, 3.646340599536, 7.079373888424],
+    [1.282657998934e-06, 3.232864804902, 3.738761453707],
+    [1.528915968658e-06, 5.581433416669, 0.2132990797783],
+    [1.187304098432e-06, 5.453576453694, 9.437762937313],
+    [7.842782928118e-07, 0.2823953922273, 8.827390247185],
+    [7.352892280868e-07, 1.124369580175, 1.589072916335],
+    [6.570189360797e-07, 2.08915404284, 11.76985366291],
+    [6.32496759041e-07, 0.670485558123, 6.262300422539],
+    [6.298289872283e-07, 2.83641485584, 6.303851278352],
+    [6.476686465855e-07, 0.4852433866467, 0.0071134546679],
+    [8.587034651234e-07, 1.453511005668, 167.2837615881],
+    [8.068948788113e-07, 0.9224087798609, 6.069776770667],
+    [8.353786011661e-07, 4.631707184895, 3.340612434717],
+    [6.009324532132e-07, 1.829498827726, 4.136910472696],
+    [7.558158559566e-07, 2.588596800317, 6.496374930224],
+    [5.809279504503e-07, 0.5516818853476, 10.97707878456],
+    [5.374131950254e-07, 6.27567473496, 1.194447056968],
+    [5.711160507326e-07, 1.091905956872, 6.282095334605],
+    [5.710183170746e-07, 2.41500163509, 6.284056366286],
+    [5.14437359061e-07, 6.020336443438, 6.290189305114],
+    [5.103108927267e-07, 3.775634564605, 6.275962395778],
+    [4.960654697891e-07, 1.073450946756, 6.127655567643],
+    [4.78638568928e-07, 2.43117801231, 6.438496133249],
+    [6.109911263665e-07, 5.343356157914, 3.154687086868],
+    [4.839898944024e-07, 0.05830833594047, 0.8018209333619],
+    [4.734822623919e-07, 4.536080134821, 3.128388763578],
+    [4.83474147329e-07, 0.2585090489754, 7.084896783808],
+    [5.134858581156e-07, 4.213317172603, 12.35285262111],
+    [5.064004264978e-07, 0.4814418806478, 11.85621865188],
+    [3.753476772761e-07, 1.599953399788, 8.429241228195],
+    [4.935264014283e-07, 2.157417556873, 2.544314396739],
+    [3.950929600897e-07, 3.359394184254, 5.481254917084],
+    [4.895849789777e-07, 5.165704376558, 9.225539266174],
+    [4.215241688886e-07, 2.065368800993, 17.260154635],
+    [3.796773731132e-07, 1.468606346612, 0.4265981595566],
+    [3.114178142515e-07, 3.615638079474, 2.14616537775],
+    [3.260664220838e-07, 4.417134922435, 4.164311961999],
+    [3.976996123008e-07, 4.700866883004, 5.856477690889],
+    [2.801459672924e-07, 4.538902060922, 12.56967486051],
+    [3.638931868861e-07, 1.334197991475, 18.07370494127],
+    [2.487013269476e-07, 3.749275558275, 0.0262983232899],
+    [3.034165481994e-07, 0.4236622030873, 4.535059491685],
+    [2.676278825586e-07, 5.970848007811, 3.93020969694],
+    [2.764903818918e-07, 5.194636754501, 12.56262854127],
+    [2.485149930507e-07, 1.002434207846, 5.088628793478],
+    [2.199305540941e-07, 3.066773098403, 12.55903824622],
+    [2.571106500435e-07, 0.7588312459063, 13.36797263425],
+    [2.049751817158e-07, 3.444977434856, 11.37170464392],
+    [2.599707296297e-07, 1.873128542205, 71.43069561767],
+    [1.785018072217e-07, 5.015891306615, 1.74801635876],
+    [2.324833891115e-07, 4.61827123973, 18.31953657923],
+    [1.709711119545e-07, 5.300003455669, 4.933208510675],
+    [2.107159351716e-07, 2.229819815115, 7.477522907414],
+    [1.750333080295e-07, 6.161485880008, 10.44738781244],
+    [2.000598210339e-07, 2.967357299999, 8.031092209206],
+    [1.380920248681e-07, 3.027007923917, 8.635942003952],
+    [1.412460470299e-07, 6.037597163798, 2.942463415728],
+    [1.888459803001e-07, 0.8561476243374, 156.1374759853],
+    [1.788370542585e-07, 4.869736290209, 1.592596075957],
+    [1.360893296167e-07, 3.626411886436, 13.095842673],
+    [1.50684653016e-07, 1.550975377427, 16.49636139783],
+    [1.800913376176e-07, 2.07582603319, 17.29818233119],
+    [1.436261390649e-07, 6.148876420255, 20.42657109477],
+    [1.220227114151e-07, 4.382583879906, 7.632943190217],
+    [1.337883603592e-07, 2.036644327361, 12.13955354133],
+    [1.159326650738e-07, 3.892276994687, 5.331357529664],
+    [1.352853128569e-07, 1.447950649744, 16.73046366289],
+    [1.433408296083e-07, 4.457854692961, 7.342457794669],
+    [1.234701666518e-07, 1.538818147151, 6.2794855554],
+    [1.234027192007e-07, 1.96852322076, 6.286666145492],
+    [1.244024091797e-07, 5.779803499985, 15.11046609763],
+    [1.097934945516e-07, 0.6210975221388, 10.98880815746],
+    [1.254611329856e-07, 2.591963807998, 15.72083878776],
+    [1.158247286784e-07, 2.48361281267, 5.729506548653],
+    [9.03907825296e-08, 3.857554579796, 9.623688285163],
+    [9.108024978836e-08, 5.826368512984, 7.234794171227],
+    [8.887068108436e-08, 3.475694573987, 6.148010737701],
+    [8.632374035438e-08, 0.03059070488983, 6.41814096319],
+    [7.893186992967e-08, 1.583194837728, 2.118763888447],
+    [8.297650201172e-08, 0.8519770534637, 14.71231707864],
+    [1.019759578988e-07, 0.1319598738732, 1.349867339771],
+    [1.010037696236e-07, 0.9937860115618, 6.836645152238],
+    [1.047727548266e-07, 1.382138405399, 5.999216516294],
+    [7.351993881086e-08, 3.833397851735, 6.04034711426],
+    [9.868771092341e-08, 2.12491381439, 6.566935184597],
+    [7.00732195939e-08, 5.946305343763, 6.525804586632],
+    [6.861411679709e-08, 4.574654977089, 7.238675589263],
+    [7.554519809614e-08, 5.949232686844, 12.5398533776],
+    [9.541880448335e-08, 3.495242990564, 21.22839202813],
+    [7.185606722155e-08, 4.310113471661, 6.245048154254],
+    [7.13136087171e-08, 5.48030932365, 6.321103546637],
+    [6.651142021039e-08, 5.411097713654, 5.327476111629],
+    [8.538618213667e-08, 1.827849973951, 11.01510648075],
+    [8.634954288044e-08, 5.443584943349, 5.643178611111],
+    [7.421047599169e-08, 3.464562529249, 23.54323048545],
+    [6.140694354424e-08, 5.657556228815, 12.96430071988],
+    [6.353525143033e-08, 3.463816593821, 1.990745094947],
+    [6.398637498911e-08, 2.405645801972, 24.07292145756],
+    [6.977997694382e-08, 4.762347105419, 10.97355562493],
+    [7.460629558396e-08, 2.711944692164, 22.0039146382],
+    [5.376577536101e-08, 2.352980430239, 14.31416805965],
+    [7.530607893556e-08, 1.943940180699, 18.42262939178],
+    [6.220772380094e-08, 0.6716871369278, 18.45107853235],
+    [6.586950799043e-08, 2.229714460505, 5.216580451554],
+    [6.264346929745e-08, 0.6202785478961, 6.277552955062],
+    [6.257929115669e-08, 2.886775596668, 6.288598745829],
+    [5.343536033409e-08, 1.977241012051, 4.690479774488],
+    [6.178957066649e-08, 5.197558947765, 5.23080736089],
+    [6.187270224331e-08, 0.8193497368922, 5.650292065779],
+    [5.385664291426e-08, 5.406336665586, 77.71377146812],
+    [6.329363917926e-08, 2.837760654536, 26.0879031406],
+    [6.196091049375e-08, 4.157871494377, 84.67247584405],
+    [6.159555108218e-08, 3.211703561703, 239.4243902548],
+    [4.995340539317e-08, 1.459098102922, 4.732030630302],
+    [5.457031243572e-08, 1.430457676136, 6.17998303789],
+    [4.863461418397e-08, 2.19642591673, 90.27992316901],
+    [5.34294762687e-08, 2.086612890268, 6.386168663001],
+    [5.674296648439e-08, 2.760204966535, 6.915859635113],
+    [4.745783120161e-08, 4.245368971862, 6.282970628506],
+    [4.745676961198e-08, 5.544725787016, 6.283181072386],
+    [4.049796869973e-08, 2.213984363586, 6.254626709878],
+    [4.24833359694e-08, 0.8075781952896, 7.875671926403],
+    [4.027178070205e-08, 1.293268540378, 6.311524991013],
+    [4.066543943476e-08, 3.986141175804, 3.634620989887],
+    [4.85886378788e-08, 1.276112738231, 5.760498333002],
+    [5.27739826353e-08, 4.916111741527, 25.15860172507],
+    [4.105635656559e-08, 1.725805864426, 6.709674010002],
+    [4.376781925772e-08, 2.243642442106, 6.80565336789],
+    [3.073244740308e-08, 2.46087339346, 5.863591145557],
+    [3.088609271373e-08, 5.67843177179, 9.917696840332],
+    [3.393022279836e-08, 3.814017477291, 13.91601904066],
+    [3.038686508802e-08, 4.660216229171, 12.56621883632],
+    [4.019677752497e-08, 5.906906243735, 13.34167431096],
+    [3.288834998232e-08, 0.9536146445882, 16.20077269078],
+    [3.050438987141e-08, 1.624810271286, 18.05292951336],
+    [3.601142564638e-08, 4.030467142575, 6.208294184755],
+    [3.689015557141e-08, 3.648878818694, 5.966683958112],
+    [3.563471893565e-08, 5.749584017096, 6.357857516136],
+    [2.922350530341e-08, 1.790346403629, 12.72157198369],
+    [3.511076917302e-08, 6.142198301611, 6.599467742779],
+    [3.619351007632e-08, 1.432421386492, 6.019991944201],
+    [2.561254711098e-08, 2.302822475792, 12.59245002418],
+    [2.62690394292e-08, 0.8660470994571, 6.702560555334],
+    [2.550187397083e-08, 6.069721995383, 10.57540660594],
+    [2.535873526138e-08, 0.01079020331795, 31.41537925223],
+    [3.519786153847e-08, 3.809066902283, 250.5706758577],
+    [3.424651492873e-08, 2.075435114417, 6.546159756691],
+    [2.710980779541e-08, 1.51006848801, 12.02934727411],
+    [3.038710889704e-08, 5.043617528901, 12.56608456547],
+    [2.220364130585e-08, 3.694793218205, 13.36244973887],
+    [3.02588082546e-08, 0.05450618999049, 29.08881142201],
+    [2.784493486864e-08, 3.381164084502, 14.94531617769],
+    [2.294414142438e-08, 4.38230902521, 6.076890225335],
+    [2.012723294724e-08, 0.9142212256518, 6.262720680387],
+    [2.003474823288e-08, 2.592767977625, 6.303431020504],
+    [2.207144900109e-08, 5.40497627118, 6.489261475556],
+    [2.481664905135e-08, 4.373284587027, 12.04357418345],
+    [2.674949182295e-08, 5.859182188482, 4.590910121555],
+    [2.601975986457e-08, 3.933165584959, 19.6510484847],
+    [2.199860022848e-08, 5.227977189087, 13.51787002167],
+    [1.876014864049e-08, 5.690546553605, 6.27919443241],
+    [1.874513219396e-08, 4.099539297446, 6.286957268481],
+    [2.156380842559e-08, 0.4382594769913, 18.13929450232],
+    [2.329992648539e-08, 2.836254278973, 10.02183730415],
+    [2.436368366085e-08, 2.836897959677, 95.14313292143],
+    [2.164089203889e-08, 6.127522446024, 6.037244212485],
+    [1.674798769966e-08, 0.3316993867246, 13.11972100268],
+    [2.222542124356e-08, 0.829409780548, 12.66924451345],
+    [2.071074505925e-08, 3.659492220261, 6.528907488406],
+    [1.608224471835e-08, 4.774492067182, 13.52175143971],
+    [1.857583439071e-08, 2.873120597682, 8.662240327241],
+    [1.793018836159e-08, 0.5282441177929, 6.819880277225],
+    [1.840132009557e-08, 1.917110916256, 65.14761976723],
+    [1.760917288281e-08, 2.972635937132, 5.746271423666],
+    [1.951359382579e-08, 3.094448898752, 23.01353951334],
+    [1.569144275614e-08, 2.802103689808, 17.65478049437],
+    [1.297967708237e-08, 6.26963712284, 11.499656302],
+    [1.783812325219e-08, 1.47654054756, 33.01902111895],
+    [1.525884228756e-08, 4.653477715241, 9.411464614024],
+    [1.451067396763e-08, 2.573001128225, 12.77945078067],
+    [1.46278401282e-08, 4.18966162387, 18.63592847156],
+    [1.221497599801e-08, 5.612515760138, 12.57326515556],
+    [1.560574525896e-08, 4.783414317919, 18.87552587463],
+    [1.544598372036e-08, 2.694431138063, 18.209330312],
+    [1.531678928696e-08, 4.105103489666, 25.93412433514],
+    [1.226103625262e-08, 1.267127706817, 24.35678079171],
+    [1.019449641504e-08, 4.367790112269, 17.25663147538],
+    [1.297929434405e-08, 5.786874896426, 12.4913700352],
+    [1.165184404862e-08, 5.768367239093, 17.78273215245],
+    [1.018948635601e-08, 0.6458292350865, 12.54537627298],
+    [9.500383606676e-09, 1.054306140741, 12.56517118505],
+    [1.227512202906e-08, 2.505278379114, 22.48384854122],
+    [1.117906736211e-08, 2.361405953468, 17.79695906178],
+    [1.215539124483e-08, 5.613969479755, 82.57698122054],
+    [9.932460955209e-09, 1.106124877015, 10.17725758696],
+    [1.039063219067e-08, 5.171080641327, 15.50861511662],
+    [9.171858254191e-09, 0.8992015524177, 149.8544001348],
+    [1.075327150242e-08, 2.898669963648, 36.94923081589],
+    [9.884866689828e-09, 4.946715904478, 11.40367694411],
+    [9.541835576677e-09, 2.371787888469, 12.56713221673],
+    [9.724904869624e-09, 6.195878564404, 22.80573557157],
+    [8.288040568796e-09, 5.234914433867, 12.41658836951],
+    [6.456797542736e-09, 5.919285089994, 16.85848245639],
+    [7.235671196168e-09, 1.496516557134, 161.0006857377],
+    [5.467593991798e-09, 3.017561234194, 18.84211409667],
+    [6.388519802999e-09, 5.887386712935, 52.1758062812],
+    [7.383420275489e-09, 5.417387056707, 23.58125818164],
+    [6.599951172637e-09, 4.320826409528, 40.87944051283],
+    [5.353771071862e-09, 4.551978748683, 173.5668374386],
+    [5.926120403383e-09, 1.333998428358, 26.73594526851],
+    [5.211016176149e-09, 4.64931536076, 24.60261242967],
+    [6.029426018652e-09, 5.567259412084, 322.7113045244],
+    [5.83526827742e-09, 4.783808492071, 72.8505617157],
+    [5.693571465184e-09, 1.381646203111, 97.23862754494],
+    [4.781323427824e-09, 4.633332586423, 29.29661536378],
+    [4.387283718538e-09, 3.254859566869, 233.1413144044],
+    [4.94934013024e-09, 1.154832815171, 161.7106187867],
+    [4.110846382042e-09, 5.742978187327, 44.80965020977],
+    [3.708099772977e-09, 2.756168198616, 30.66615496545],
+    [3.187949696649e-09, 3.139776445735, 169.3792562116],
+    [2.687502389925e-09, 6.239078264579, 70.75506709219],
+    [2.591951887361e-09, 0.8819350522008, 48.73985990671],
+    [1.72059877545e-09, 0.2303524214705, 167.9593901136],
+    [1.702429015449e-09, 6.164622655048, 333.8575901272],
+    [1.414033197685e-09, 3.95456118558, 162.4205518357],
+    [1.651087618855e-09, 2.53846105728, 89.56999012],
+    [1.593191980553e-09, 2.614340453411, 228.2781046519],
+    [1.258432887565e-09, 4.969896184844, 311.5650189215],
+    [1.240336343282e-09, 5.192460776926, 178.430047191],
+    [7.217424756199e-10, 0.02697449980577, 245.7074661053],
+    [6.912003846756e-10, 4.253296276335, 167.9936946371],
+    [5.161802866314e-10, 3.852750634351, 244.2876000072],
+    [4.710812608586e-10, 5.610486976767, 316.4282286739],
+    [4.909977500324e-10, 1.639629524123, 405.9982187939],
+    [3.943441230497e-10, 0.6243502862796, 256.8537517081],
+    [3.738329328831e-10, 1.042266763456, 394.851933191],
+];
+
+const E0Z: [[f64; 3]; 57] = [
+    [2.796207639075e-06, 3.198701560209, 84.33466158131],
+    [1.016042198142e-06, 5.422360395913, 5.507553240374],
+    [8.044305033647e-07, 3.880222866652, 5.223693906222],
+    [4.385347909274e-07, 3.704369937468, 2.352866153506],
+    [3.186156414906e-07, 3.999639363235, 1.577343543434],
+    [2.272412285792e-07, 3.984738315952, 1.047747311755],
+    [1.645620103007e-07, 3.565412516841, 5.856477690889],
+    [1.815836921166e-07, 4.98450705902, 6.283075850446],
+    [1.447461676364e-07, 3.702753570108, 9.437762937313],
+    [1.430760876382e-07, 3.409658712357, 10.21328554739],
+    [1.120445753226e-07, 4.829561570246, 14.14349524433],
+    [1.090232840797e-07, 2.080729178066, 6.812766822558],
+    [9.715727346551e-08, 3.476295881948, 4.69400293411],
+    [1.036267136217e-07, 4.056639536648, 71.09288135493],
+    [8.75266527134e-08, 4.448159519911, 5.753384878334],
+    [8.331864956004e-08, 4.991704044208, 7.084896783808],
+    [6.901658670245e-08, 4.325358994219, 6.275962395778],
+    [9.144536848998e-08, 1.141826375363, 6.620890113188],
+    [7.697874654176e-08, 5.554257458998, 167.6215758509],
+    [5.197545738384e-08, 6.251760961735, 18.07370494127],
+    [5.031345378608e-08, 2.497341091913, 4.705732307012],
+    [4.52711020584e-08, 2.335079920992, 6.309374173736],
+    [4.753355798089e-08, 0.7094148987474, 5.884926831456],
+    [4.296951977516e-08, 1.101916352091, 6.681224869435],
+    [3.855341568387e-08, 1.825495405486, 5.486777812467],
+    [5.25393097099e-08, 4.424740687208, 7.86041939388],
+    [4.024630496471e-08, 5.120498157053, 13.36797263425],
+    [4.061069791453e-08, 6.029771435451, 3.93020969694],
+    [3.797883804205e-08, 0.4435193600836, 3.154687086868],
+    [3.503000930426e-08, 5.421830162065, 6.069776770667],
+    [3.67009621405e-08, 4.582101667297, 12.19403291462],
+    [2.905609437008e-08, 1.926566420072, 10.97707878456],
+    [2.466827821713e-08, 0.6090174539834, 6.496374930224],
+    [2.691647295332e-08, 1.393432595077, 22.0039146382],
+    [2.150554667946e-08, 4.308671715951, 5.643178611111],
+    [2.23748192268e-08, 0.8133968269414, 8.635942003952],
+    [2.227820762132e-08, 2.759558596664, 12.03646072878],
+    [1.944713772307e-08, 5.699645869121, 11.79062909082],
+    [1.616563121701e-08, 0.2601671259394, 17.29818233119],
+    [1.488726974214e-08, 2.815862451372, 25.93412433514],
+    [1.692626442388e-08, 4.956894109797, 156.475290248],
+    [7.432912038789e-09, 1.991086893337, 26.0879031406],
+    [8.586233727285e-09, 4.238357924414, 29.86433403208],
+    [7.690770957702e-09, 1.680504249084, 21.22839202813],
+    [5.150246069997e-09, 4.099769855122, 64.80980550449],
+    [5.102434319633e-09, 5.660834602509, 33.79454372902],
+    [5.52402981528e-09, 1.029264714351, 239.7622045175],
+    [3.992493949002e-09, 3.100307589464, 25.15860172507],
+    [3.101209215259e-09, 0.8000833804348, 37.72475342596],
+    [3.156632236269e-09, 1.626628050682, 250.9084901204],
+    [2.774031674807e-09, 4.654002897158, 82.2391669578],
+    [2.492901628386e-09, 0.1290101424052, 154.3797956245],
+    [1.916351061607e-09, 0.8719067257774, 77.37595720538],
+    [1.477304050539e-09, 0.6544722606797, 95.48094718417],
+    [1.264316431249e-09, 2.059072853236, 83.99684731857],
+    [7.983392077387e-10, 5.000024502753, 150.1922143975],
+    [5.407629837898e-10, 3.384820609076, 323.0491187871],
+];
+
+const E1X: [[f64; 3]; 25] = [
+    [1.234046326004e-06, 0.0, 0.0],
+    [5.150068824701e-07, 6.002664557501, 12.56615170089],
+    [1.290743923245e-08, 5.959437664199, 18.84922755134],
+    [1.068615564952e-08, 2.015529654209, 6.283075850446],
+    [2.079619142538e-09, 1.732960531432, 6.279552690824],
+    [2.078009243969e-09, 4.915604476996, 6.286599010068],
+    [6.206330058856e-10, 0.3616457953824, 4.705732307012],
+    [5.989335313746e-10, 3.802607304474, 6.256777527156],
+    [5.95849566384e-10, 2.845866560031, 6.309374173736],
+    [4.610675141648e-10, 0.01837249181372, 7.86041939388],
+    [3.626989993973e-10, 2.161590545326, 5.753384878334],
+    [3.563071194389e-10, 1.452631954746, 5.884926831456],
+    [3.557015642807e-10, 4.470593393054, 6.812766822558],
+    [3.210412089122e-10, 5.195926078314, 6.681224869435],
+    [2.875473577986e-10, 5.916256610193, 25.13230340178],
+    [2.842913681629e-10, 1.149902426047, 6.127655567643],
+    [2.751248215916e-10, 5.502088574662, 6.438496133249],
+    [2.481432881127e-10, 2.921989846637, 5.486777812467],
+    [2.05988597656e-10, 3.718070376585, 7.079373888424],
+    [2.015522342591e-10, 5.97939525974, 6.290189305114],
+    [1.995364084253e-10, 0.6772087985494, 6.275962395778],
+    [1.957436436943e-10, 2.899210654665, 5.507553240374],
+    [1.651609818948e-10, 6.228206482192, 11.50676975667],
+    [1.822980550699e-10, 1.469348746179, 11.79062909082],
+    [1.67522315976e-10, 3.813910555688, 7.058598460518],
+];
+
+const E1Y: [[f64; 3]; 25] = [
+    [9.304690546528e-07, 0.0, 0.0],
+    [5.150715570663e-07, 4.431807116294, 12.56615170089],
+    [1.290825411056e-08, 4.388610039678, 18.84922755134],
+    [4.645466665386e-09, 5.827263376034, 6.283075850446],
+    [2.079625310718e-09, 0.1621698662282, 6.279552690824],
+    [2.078189850907e-09, 3.34471343514, 6.286599010068],
+    [6.207190138027e-10, 5.074049319576, 4.705732307012],
+    [5.989826532569e-10, 2.23184221662, 6.256777527156],
+    [5.961360812618e-10, 1.274975769045, 6.309374173736],
+    [4.652389287529e-10, 4.715794792175, 7.86041939388],
+    [3.751707476401e-10, 0.6617207370325, 5.753384878334],
+    [3.559998806198e-10, 6.155548875404, 5.884926831456],
+    [3.558447558857e-10, 2.898827297664, 6.812766822558],
+    [3.211116927106e-10, 3.625813502509, 6.681224869435],
+    [2.875609914672e-10, 4.345435813134, 25.13230340178],
+    [2.843109704069e-10, 5.862263940038, 6.127655567643],
+    [2.744676468427e-10, 3.926419475089, 6.438496133249],
+    [2.481285237789e-10, 1.351976572828, 5.486777812467],
+    [2.060338481033e-10, 2.147556998591, 7.079373888424],
+    [2.015822358331e-10, 4.408358972216, 6.290189305114],
+    [2.001195944195e-10, 5.385829822531, 6.275962395778],
+    [1.953667642377e-10, 1.30493374612, 5.507553240374],
+    [1.839744078713e-10, 6.173567228835, 11.79062909082],
+    [1.643334294845e-10, 4.635942997523, 11.50676975667],
+    [1.674874205489e-10, 2.243332137241, 7.058598460518],
+];
+
+const E1Z: [[f64; 3]; 5] = [
+    [2.278290449966e-06, 3.413716033863, 6.283075850446],
+    [5.42945820983e-08, 0.0, 0.0],
+    [1.903240492525e-08, 3.370592358297, 12.56615170089],
+    [2.385409276743e-10, 3.327914718416, 18.84922755134],
+    [7.092175288657e-11, 2.333246960021, 83.99684731857],
+];
+
+const E2X: [[f64; 3]; 4] = [
+    [-4.143818297913e-11, 0.0, 0.0],
+    [2.171497694435e-11, 4.398225628264, 12.56615170089],
+    [9.845398442516e-12, 0.2079720838384, 6.283075850446],
+    [9.256833552682e-13, 4.191264694361, 18.84922755134],
+];
+
+const E2Y: [[f64; 3]; 4] = [
+    [5.063375872532e-11, 0.0, 0.0],
+    [2.17381578598e-11, 2.827805833053, 12.56615170089],
+    [1.01023199992e-11, 4.634612377133, 6.283075850446],
+    [9.259745317636e-13, 2.620612076189, 18.84922755134],
+];
+
+const E2Z: [[f64; 3]; 1] = [
+    [9.722666114891e-11, 5.152219582658, 6.283075850446],
+];
+
+const S0X: [[f64; 3]; 69] = [
+    [0.00495675753641, 3.741073751789, 0.5296909721118],
+    [0.002718490072522, 4.016011511425, 0.2132990797783],
+    [0.001546493974344, 2.170528330642, 0.0381329181312],
+    [0.0008366855276341, 2.339614075294, 0.0747816656905],
+    [0.0002936777942117, 0.0, 0.0],
+    [0.0001201317439469, 4.090736353305, 1.059381944224],
+    [7.57855088723e-05, 3.24151808814, 0.4265981595566],
+    [1.941787367773e-05, 1.01220206433, 0.2061856251104],
+    [1.889227765991e-05, 3.89252041644, 0.2204125344462],
+    [1.937896968613e-05, 4.797779441161, 0.149563331381],
+    [1.434506110873e-05, 3.868960697933, 0.5225775174439],
+    [1.40665991158e-05, 0.4759766557397, 0.5368044267797],
+    [1.179022300202e-05, 0.7774961520598, 0.0762658362624],
+    [8.085864460959e-06, 3.254654471465, 0.0366487475593],
+    [7.622752967615e-06, 4.227633103489, 0.0396170887031],
+    [6.209171139066e-06, 0.2791828325711, 0.0732974951186],
+    [4.36643563397e-06, 4.440454875925, 1.589072916335],
+    [3.792124889348e-06, 5.156393842356, 0.0071134546679],
+    [3.154548963402e-06, 6.157005730093, 0.4194847048887],
+    [3.088359882942e-06, 2.494567553163, 0.6398972393349],
+    [2.788440902136e-06, 4.934318747989, 0.1102062672231],
+    [3.039928456376e-06, 4.89507770264, 6.283075850446],
+    [2.272258457679e-06, 5.278394064764, 0.1030928125552],
+    [2.162007057957e-06, 5.802978019099, 0.3163918923335],
+    [1.767632855737e-06, 0.03415346595193, 10.21328554739],
+    [1.349413459362e-06, 2.001643230755, 0.0014841705719],
+    [1.170141900476e-06, 2.42475049162, 0.632783784667],
+    [1.05435526682e-06, 3.123311487576, 0.4337116142245],
+    [9.80082246161e-07, 3.02625808813, 1.052268489556],
+    [1.091203749931e-06, 3.157811670347, 1.162474756779],
+    [6.960236715913e-07, 0.8219570542313, 1.066495398892],
+    [5.689257296909e-07, 1.323052375236, 0.9491756770005],
+    [6.613172135802e-07, 0.2765348881598, 0.8460828644453],
+    [6.277702517571e-07, 5.794064466382, 0.1480791608091],
+    [6.304884066699e-07, 0.7323555380787, 0.2243449970715],
+    [4.897850467382e-07, 3.062464235399, 3.340612434717],
+    [3.759148598786e-07, 4.588290469664, 0.0351645769874],
+    [3.110520548195e-07, 1.374299536572, 0.0637357483973],
+    [3.06470835978e-07, 4.222267485047, 0.0110459172932],
+    [2.856347168241e-07, 3.714202944973, 0.1510475019529],
+    [2.840945514288e-07, 2.847972875882, 0.041101259275],
+    [2.378951599405e-07, 3.762072563388, 0.2275259891141],
+    [2.714229481417e-07, 1.036049980031, 0.025350505],
+    [2.323551717307e-07, 0.4682388599076, 0.0858275829837],
+    [1.881790512219e-07, 4.790565425418, 2.118763888447],
+    [2.261353968371e-07, 1.669144912212, 0.0718133245467],
+    [2.214546389848e-07, 3.937717281614, 0.0029683411438],
+    [2.184915594933e-07, 0.1129169845099, 0.0777500068343],
+    [2.000164937936e-07, 4.030009638488, 0.209366617153],
+    [1.966105136719e-07, 0.8745955786834, 0.2172315424036],
+    [1.904742332624e-07, 5.919743598964, 0.2022531624851],
+    [1.657399705031e-07, 2.549141484884, 0.7358765972222],
+    [1.574070533987e-07, 5.27753302023, 0.7429900518901],
+    [1.832261651039e-07, 3.064688127777, 0.3235053470014],
+    [1.733615346569e-07, 3.011432799094, 0.1385174140878],
+    [1.549124014496e-07, 4.005569132359, 0.515464062776],
+    [1.637044713838e-07, 1.831375966632, 0.8531963191132],
+    [1.123420082383e-07, 1.180270407578, 0.1990721704425],
+    [1.08375416574e-07, 0.3414101320863, 0.5439178814476],
+    [1.156638012655e-07, 0.6130479452594, 0.5257585094865],
+    [1.142548785134e-07, 3.724761948846, 0.5336234347371],
+    [7.921463895965e-08, 2.435425589361, 1.478866649112],
+    [8.323211246747e-08, 3.525058072354, 1.692165728891],
+    [7.381147293385e-08, 3.272990384244, 1.581959461667],
+    [6.233195669151e-08, 1.254922242403, 26.0879031406],
+    [2.539523734781e-08, 4.851947722567, 12.56615170089],
+    [2.283087914139e-08, 3.400498595496, 6.681224869435],
+    [5.984021492007e-09, 0.9138753105829, 20.42657109477],
+    [6.351223158474e-09, 4.306447410369, 52.1758062812],
+];
+
+const S0Y: [[f64; 3]; 69] = [
+    [0.004955392320126, 2.170467313679, 0.5296909721118],
+    [0.002722325167392, 2.444433682196, 0.2132990797783],
+    [0.001546579925346, 0.5992779281546, 0.0381329181312],
+    [0.0008363140252966, 0.7687356310801, 0.0747816656905],
+    [0.0003385792683603, 0.0, 0.0],
+    [0.0001201192221613, 2.520035601514, 1.059381944224],
+    [7.587125720554e-05, 1.669954006449, 0.4265981595566],
+    [1.96415536125e-05, 5.707743963343, 0.2061856251104],
+    [1.891900364909e-05, 2.320960679937, 0.2204125344462],
+    [1.937373433356e-05, 3.226940689555, 0.149563331381],
+    [1.437139941351e-05, 2.301626908096, 0.5225775174439],
+    [1.406267683099e-05, 5.188579265542, 0.5368044267797],
+    [1.178703080346e-05, 5.489483248476, 0.0762658362624],
+    [8.079835186041e-06, 1.683751835264, 0.0366487475593],
+    [7.623253594652e-06, 2.656400462961, 0.0396170887031],
+    [6.248667483971e-06, 4.992775362055, 0.0732974951186],
+    [4.366353695038e-06, 2.869706279678, 1.589072916335],
+    [3.829101568895e-06, 3.57213135995, 0.0071134546679],
+    [3.175733773908e-06, 4.535372530045, 0.4194847048887],
+    [3.092437902159e-06, 0.9230153317909, 0.6398972393349],
+    [2.874168812154e-06, 3.363143761101, 0.1102062672231],
+    [3.040119321826e-06, 3.324250895675, 6.283075850446],
+    [2.699723308006e-06, 0.2917882441928, 0.1030928125552],
+    [2.134832683534e-06, 4.220997202487, 0.3163918923335],
+    [1.770412139433e-06, 4.747318496462, 10.21328554739],
+    [1.377264209373e-06, 0.4305058462401, 0.0014841705719],
+    [1.12781453896e-06, 0.853817724074, 0.632783784667],
+    [1.05560809013e-06, 1.55180074258, 0.4337116142245],
+    [9.80267386142e-07, 1.459646735377, 1.052268489556],
+    [1.090329461951e-06, 1.587351228711, 1.162474756779],
+    [6.95959002509e-07, 5.534442628766, 1.066495398892],
+    [5.664914529542e-07, 6.030673003297, 0.9491756770005],
+    [6.607787763599e-07, 4.989507233927, 0.8460828644453],
+    [6.269725742838e-07, 4.222951804572, 0.1480791608091],
+    [6.301889697863e-07, 5.444316669126, 0.2243449970715],
+    [4.891042662861e-07, 1.490552839784, 3.340612434717],
+    [3.45708312329e-07, 3.030475486049, 0.0351645769874],
+    [3.032559967314e-07, 2.652038793632, 0.0110459172932],
+    [2.841133988903e-07, 1.276744786829, 0.041101259275],
+    [2.855564444432e-07, 2.143368674733, 0.1510475019529],
+    [2.765157135038e-07, 5.444186109077, 0.0637357483973],
+    [2.382312465034e-07, 2.190521137593, 0.2275259891141],
+    [2.808060365077e-07, 5.735195064841, 0.025350505],
+    [2.332175234405e-07, 0.09481985524859, 0.0718133245467],
+    [2.322488199659e-07, 5.180499361533, 0.0858275829837],
+    [1.881850258423e-07, 3.219788273885, 2.118763888447],
+    [2.196111392808e-07, 2.366941159761, 0.0029683411438],
+    [2.183810335519e-07, 4.825445110915, 0.0777500068343],
+    [2.002733093326e-07, 2.457148995307, 0.209366617153],
+    [1.967111767229e-07, 5.586291545459, 0.2172315424036],
+    [1.568473250543e-07, 3.70800312332, 0.7429900518901],
+    [1.8525283143e-07, 4.31063815156, 0.2022531624851],
+    [1.832111226447e-07, 1.494665322656, 0.3235053470014],
+    [1.74680550231e-07, 1.451378500784, 0.1385174140878],
+    [1.55573096665e-07, 1.068040418198, 0.7358765972222],
+    [1.554883462559e-07, 2.442579035461, 0.515464062776],
+    [1.638380568746e-07, 0.2597913420625, 0.8531963191132],
+    [1.15993859364e-07, 5.83451202128, 0.1990721704425],
+    [1.083427965695e-07, 5.05403317795, 0.5439178814476],
+    [1.156480369431e-07, 5.325677432457, 0.5257585094865],
+    [1.141308860095e-07, 2.153403923857, 0.5336234347371],
+    [7.913146470946e-08, 0.8642846847027, 1.478866649112],
+    [8.319567719136e-08, 1.954371928334, 1.692165728891],
+    [7.375477357248e-08, 1.705076390088, 1.581959461667],
+    [6.300822573871e-08, 5.979398788281, 26.0879031406],
+    [2.539620831872e-08, 3.281126083375, 12.56615170089],
+    [2.281017868007e-08, 1.829122133165, 6.681224869435],
+    [5.993635744494e-09, 5.627122113596, 20.42657109477],
+    [6.399244436159e-09, 2.746214421532, 52.1758062812],
+];
+
+const S0Z: [[f64; 3]; 23] = [
+    [0.0001181255122986, 0.4607918989164, 0.2132990797783],
+    [0.0001127777651095, 0.4169146331296, 0.5296909721118],
+    [4.777754401806e-05, 4.58265700713, 0.0381329181312],
+    [1.129354285772e-05, 5.75873514248, 0.0747816656905],
+    [-1.149543637123e-05, 0.0, 0.0],
+    [3.298730512306e-06, 5.978801994625, 0.4265981595566],
+    [2.733376706079e-06, 0.766541369104, 1.059381944224],
+    [9.42638965727e-07, 3.710201265838, 0.2061856251104],
+    [8.187517749552e-07, 0.3390675605802, 0.2204125344462],
+    [4.080447871819e-07, 0.4552296640088, 0.5225775174439],
+    [3.169973017028e-07, 3.445455899321, 0.5368044267797],
+    [2.438098615549e-07, 5.664675150648, 0.0366487475593],
+    [2.601897517235e-07, 1.931894095697, 0.149563331381],
+    [2.314558080079e-07, 0.3666319115574, 0.0396170887031],
+    [1.962549548002e-07, 3.16741169902, 0.0762658362624],
+    [2.180518287925e-07, 1.54442074658, 0.0071134546679],
+    [1.451382442868e-07, 1.58375674007, 0.1102062672231],
+    [1.358439007389e-07, 5.23994175828, 0.6398972393349],
+    [1.050585898028e-07, 2.266958352859, 0.3163918923335],
+    [1.050029870186e-07, 2.711495250354, 0.4194847048887],
+    [9.9349206798e-08, 1.116208151396, 1.589072916335],
+    [1.04839533156e-07, 3.408619600206, 10.21328554739],
+    [7.649332643544e-09, 5.134543417106, 26.0879031406],
+];
+
+const S1X: [[f64; 3]; 8] = [
+    [-1.29631036152e-08, 0.0, 0.0],
+    [8.975769009438e-09, 1.12889160925, 0.4265981595566],
+    [7.771113441307e-09, 2.706039877077, 0.2061856251104],
+    [7.538303866642e-09, 2.191281289498, 0.2204125344462],
+    [6.061384579336e-09, 3.248167319958, 1.059381944224],
+    [5.726994235594e-09, 5.56998139861, 0.5225775174439],
+    [5.616492836424e-09, 5.057386614909, 0.5368044267797],
+    [1.010881584769e-09, 3.473577116095, 0.0071134546679],
+];
+
+const S1Y: [[f64; 3]; 7] = [
+    [8.989047573576e-09, 5.840593672122, 0.4265981595566],
+    [7.815938401048e-09, 1.129664707133, 0.2061856251104],
+    [7.55092671328e-09, 0.6196589104845, 0.2204125344462],
+    [6.056556925895e-09, 1.677494667846, 1.059381944224],
+    [5.734142698204e-09, 4.000920852962, 0.5225775174439],
+    [5.614341822459e-09, 3.486722577328, 0.5368044267797],
+    [1.028678147656e-09, 1.877141024787, 0.0071134546679],
+];
+
+const S1Z: [[f64; 3]; 3] = [
+    [5.444220475678e-09, 1.80382550931, 0.2132990797783],
+    [3.883412695596e-09, 4.668616389392, 0.5296909721118],
+    [1.334341434551e-09, 0.0, 0.0],
+];
+
+const S2X: [[f64; 3]; 0] = [
+];
+
+const S2Y: [[f64; 3]; 0] = [
+];
+
+const S2Z: [[f64; 3]; 0] = [
+];
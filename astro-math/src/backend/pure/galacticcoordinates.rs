@@ -0,0 +1,26 @@
+//! Galactic coordinates (Hipparcos L2, B2 system).
+
+use super::{anp, anpm, c2s, rxp, s2c, trxp};
+
+/// ICRS to Galactic rotation matrix: R3(−R) R1(π/2−Q) R3(π/2+P) with
+/// P = 192.85948°, Q = 27.12825°, R = 32.93192°.
+#[allow(clippy::excessive_precision)]
+const R: [[f64; 3]; 3] = [
+    [-0.054875560416215368492398900454, -0.873437090234885048760383168409, -0.483835015548713226831774175116],
+    [0.494109427875583673525222371358, -0.444829629960011178146614061616, 0.746982244497218890527388004556],
+    [-0.867666149019004701181616534570, -0.198076373431201528180486091412, 0.455983776175066922272100478348],
+];
+
+/// Galactic to ICRS coordinates (radians).
+#[allow(non_snake_case)]
+pub fn G2icrs(dl: f64, db: f64) -> (f64, f64) {
+    let (dr, dd) = c2s(&trxp(&R, &s2c(dl, db)));
+    (anp(dr), anpm(dd))
+}
+
+/// ICRS to Galactic coordinates (radians).
+#[allow(non_snake_case)]
+pub fn Icrs2g(dr: f64, dd: f64) -> (f64, f64) {
+    let (dl, db) = c2s(&rxp(&R, &s2c(dr, dd)));
+    (anp(dl), anpm(db))
+}
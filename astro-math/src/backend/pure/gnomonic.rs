@@ -0,0 +1,33 @@
+//! Gnomonic (tangent plane) projection.
+
+use super::{anp, BackendError};
+
+/// Projects spherical coordinates onto the tangent plane at `(a0, b0)`.
+///
+/// Returns the standard coordinates `(xi, eta)` in radians, or an error if
+/// the star is too far from the tangent point (on or beyond the horizon of
+/// the projection).
+#[allow(non_snake_case)]
+pub fn Tpxes(a: f64, b: f64, a0: f64, b0: f64) -> Result<(f64, f64), BackendError> {
+    const TINY: f64 = 1e-6;
+
+    let (sb0, cb0) = b0.sin_cos();
+    let (sb, cb) = b.sin_cos();
+    let (sda, cda) = (a - a0).sin_cos();
+
+    let d = sb * sb0 + cb * cb0 * cda;
+    if d <= TINY {
+        return Err(BackendError::BadInputValue);
+    }
+
+    Ok((cb * sda / d, (sb * cb0 - cb * sb0 * cda) / d))
+}
+
+/// Deprojects tangent plane coordinates `(xi, eta)` at `(a0, b0)` to
+/// spherical coordinates (radians).
+#[allow(non_snake_case)]
+pub fn Tpsts(xi: f64, eta: f64, a0: f64, b0: f64) -> (f64, f64) {
+    let (sb0, cb0) = b0.sin_cos();
+    let d = cb0 - eta * sb0;
+    (anp(xi.atan2(d) + a0), (sb0 + eta * cb0).atan2((xi * xi + d * d).sqrt()))
+}
@@ -0,0 +1,178 @@
+//! Pure-Rust ports of the ERFA routines used by this crate.
+//!
+//! Function names and signatures mirror the `erfars` bindings so the
+//! [`backend`](crate::backend) facade can swap implementations without
+//! touching call sites. The accuracy of each routine relative to ERFA is
+//! tabulated in the crate documentation.
+//!
+//! The algorithms and coefficient tables are derived from ERFA
+//! (Copyright (C) 2013-2023, NumFOCUS Foundation, BSD-3-Clause), itself
+//! derived with permission from the IAU SOFA library. Routines that differ
+//! from the ERFA originals are marked as such in their documentation.
+
+pub mod astrometry;
+pub mod ephemerides;
+pub mod galacticcoordinates;
+pub mod gnomonic;
+pub mod precnutpolar;
+pub mod rotationtime;
+
+use std::f64::consts::{PI, TAU};
+use std::fmt;
+
+/// Reference epoch J2000.0 as a Julian Date
+pub(crate) const DJ00: f64 = 2451545.0;
+
+/// Days per Julian century
+pub(crate) const DJC: f64 = 36525.0;
+
+/// Days per Julian year
+pub(crate) const DJY: f64 = 365.25;
+
+/// Seconds per day
+pub(crate) const DAYSEC: f64 = 86400.0;
+
+/// Arcseconds to radians
+pub(crate) const DAS2R: f64 = PI / 648000.0;
+
+/// Astronomical unit in meters
+pub(crate) const DAU: f64 = 149597870.7e3;
+
+/// Speed of light in m/s
+pub(crate) const CMPS: f64 = 299792458.0;
+
+/// Schwarzschild radius of the Sun in AU
+pub(crate) const SRS: f64 = 1.97412574336e-8;
+
+/// Error returned by the fallible pure-Rust routines.
+///
+/// Mirrors the role of `erfars::ERFAError`: callers only need to know that
+/// the routine rejected its inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendError {
+    /// An argument was outside the range the routine accepts
+    BadInputValue,
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::BadInputValue => write!(f, "bad input value"),
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
+
+/// Normalizes an angle into [0, 2π).
+pub(crate) fn anp(a: f64) -> f64 {
+    let w = a % TAU;
+    if w < 0.0 {
+        w + TAU
+    } else {
+        w
+    }
+}
+
+/// Normalizes an angle into [−π, π).
+pub(crate) fn anpm(a: f64) -> f64 {
+    let mut w = a % TAU;
+    if w.abs() >= PI {
+        w -= TAU.copysign(a);
+    }
+    w
+}
+
+/// Spherical coordinates to unit vector.
+pub(crate) fn s2c(theta: f64, phi: f64) -> [f64; 3] {
+    let cp = phi.cos();
+    [theta.cos() * cp, theta.sin() * cp, phi.sin()]
+}
+
+/// Vector to spherical coordinates.
+pub(crate) fn c2s(p: &[f64; 3]) -> (f64, f64) {
+    let d2 = p[0] * p[0] + p[1] * p[1];
+    let theta = if d2 == 0.0 { 0.0 } else { p[1].atan2(p[0]) };
+    let phi = if p[2] == 0.0 { 0.0 } else { p[2].atan2(d2.sqrt()) };
+    (theta, phi)
+}
+
+pub(crate) fn pdp(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn pxp(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Splits a vector into modulus and unit direction.
+pub(crate) fn pn(p: &[f64; 3]) -> (f64, [f64; 3]) {
+    let m = pdp(p, p).sqrt();
+    if m == 0.0 {
+        (0.0, [0.0; 3])
+    } else {
+        (m, [p[0] / m, p[1] / m, p[2] / m])
+    }
+}
+
+/// r-matrix times vector.
+pub(crate) fn rxp(r: &[[f64; 3]; 3], p: &[f64; 3]) -> [f64; 3] {
+    [pdp(&r[0], p), pdp(&r[1], p), pdp(&r[2], p)]
+}
+
+/// Transpose of r-matrix times vector.
+pub(crate) fn trxp(r: &[[f64; 3]; 3], p: &[f64; 3]) -> [f64; 3] {
+    [
+        r[0][0] * p[0] + r[1][0] * p[1] + r[2][0] * p[2],
+        r[0][1] * p[0] + r[1][1] * p[1] + r[2][1] * p[2],
+        r[0][2] * p[0] + r[1][2] * p[1] + r[2][2] * p[2],
+    ]
+}
+
+pub(crate) fn identity() -> [[f64; 3]; 3] {
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+}
+
+fn rxr(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut out = [[0.0; 3]; 3];
+    for (i, row) in out.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    out
+}
+
+/// Rotates an r-matrix about the x-axis (ERFA `eraRx`).
+pub(crate) fn rx(phi: f64, r: &mut [[f64; 3]; 3]) {
+    let (s, c) = phi.sin_cos();
+    *r = rxr(&[[1.0, 0.0, 0.0], [0.0, c, s], [0.0, -s, c]], r);
+}
+
+/// Rotates an r-matrix about the z-axis (ERFA `eraRz`).
+pub(crate) fn rz(psi: f64, r: &mut [[f64; 3]; 3]) {
+    let (s, c) = psi.sin_cos();
+    *r = rxr(&[[c, s, 0.0], [-s, c, 0.0], [0.0, 0.0, 1.0]], r);
+}
+
+/// Flattens an r-matrix into the row-major layout used by `erfars`.
+pub(crate) fn flatten(r: &[[f64; 3]; 3]) -> [f64; 9] {
+    [
+        r[0][0], r[0][1], r[0][2], r[1][0], r[1][1], r[1][2], r[2][0], r[2][1], r[2][2],
+    ]
+}
+
+/// Julian centuries of TT (or TDB) since J2000.0.
+pub(crate) fn centuries(date1: f64, date2: f64) -> f64 {
+    ((date1 - DJ00) + date2) / DJC
+}
+
+/// Rotates an r-matrix about the y-axis (ERFA `eraRy`).
+pub(crate) fn ry(theta: f64, r: &mut [[f64; 3]; 3]) {
+    let (s, c) = theta.sin_cos();
+    *r = rxr(&[[c, 0.0, -s], [0.0, 1.0, 0.0], [s, 0.0, c]], r);
+}
@@ -0,0 +1,379 @@
+//! Precession, nutation and obliquity (IAU 2006/2000).
+
+use super::{anpm, centuries, flatten, identity, pn, pxp, rx, rz, DAS2R};
+use std::f64::consts::TAU;
+
+/// Arcseconds in a full circle
+const TURNAS: f64 = 1296000.0;
+
+/// Units of 0.1 microarcsecond to radians
+const U2R: f64 = DAS2R / 1e7;
+
+/// Milliarcseconds to radians
+const DMAS2R: f64 = DAS2R / 1e3;
+
+/// Fixed offsets standing in for the planetary nutation terms (IAU 2000B)
+const DPPLAN: f64 = -0.135 * DMAS2R;
+const DEPLAN: f64 = 0.388 * DMAS2R;
+
+/// IAU 2000B luni-solar nutation series: multiples of (l, l', F, D, Ω) and
+/// coefficients (ψ sin, ψ sin·t, ψ cos, ε cos, ε cos·t, ε sin) in 0.1 µas.
+#[rustfmt::skip]
+const NUT00B: [([i32; 5], [f64; 6]); 77] = [
+    ([0, 0, 0, 0, 1], [-172064161.0, -174666.0, 33386.0, 92052331.0, 9086.0, 15377.0]),
+    ([0, 0, 2, -2, 2], [-13170906.0, -1675.0, -13696.0, 5730336.0, -3015.0, -4587.0]),
+    ([0, 0, 2, 0, 2], [-2276413.0, -234.0, 2796.0, 978459.0, -485.0, 1374.0]),
+    ([0, 0, 0, 0, 2], [2074554.0, 207.0, -698.0, -897492.0, 470.0, -291.0]),
+    ([0, 1, 0, 0, 0], [1475877.0, -3633.0, 11817.0, 73871.0, -184.0, -1924.0]),
+    ([0, 1, 2, -2, 2], [-516821.0, 1226.0, -524.0, 224386.0, -677.0, -174.0]),
+    ([1, 0, 0, 0, 0], [711159.0, 73.0, -872.0, -6750.0, 0.0, 358.0]),
+    ([0, 0, 2, 0, 1], [-387298.0, -367.0, 380.0, 200728.0, 18.0, 318.0]),
+    ([1, 0, 2, 0, 2], [-301461.0, -36.0, 816.0, 129025.0, -63.0, 367.0]),
+    ([0, -1, 2, -2, 2], [215829.0, -494.0, 111.0, -95929.0, 299.0, 132.0]),
+    ([0, 0, 2, -2, 1], [128227.0, 137.0, 181.0, -68982.0, -9.0, 39.0]),
+    ([-1, 0, 2, 0, 2], [123457.0, 11.0, 19.0, -53311.0, 32.0, -4.0]),
+    ([-1, 0, 0, 2, 0], [156994.0, 10.0, -168.0, -1235.0, 0.0, 82.0]),
+    ([1, 0, 0, 0, 1], [63110.0, 63.0, 27.0, -33228.0, 0.0, -9.0]),
+    ([-1, 0, 0, 0, 1], [-57976.0, -63.0, -189.0, 31429.0, 0.0, -75.0]),
+    ([-1, 0, 2, 2, 2], [-59641.0, -11.0, 149.0, 25543.0, -11.0, 66.0]),
+    ([1, 0, 2, 0, 1], [-51613.0, -42.0, 129.0, 26366.0, 0.0, 78.0]),
+    ([-2, 0, 2, 0, 1], [45893.0, 50.0, 31.0, -24236.0, -10.0, 20.0]),
+    ([0, 0, 0, 2, 0], [63384.0, 11.0, -150.0, -1220.0, 0.0, 29.0]),
+    ([0, 0, 2, 2, 2], [-38571.0, -1.0, 158.0, 16452.0, -11.0, 68.0]),
+    ([0, -2, 2, -2, 2], [32481.0, 0.0, 0.0, -13870.0, 0.0, 0.0]),
+    ([-2, 0, 0, 2, 0], [-47722.0, 0.0, -18.0, 477.0, 0.0, -25.0]),
+    ([2, 0, 2, 0, 2], [-31046.0, -1.0, 131.0, 13238.0, -11.0, 59.0]),
+    ([1, 0, 2, -2, 2], [28593.0, 0.0, -1.0, -12338.0, 10.0, -3.0]),
+    ([-1, 0, 2, 0, 1], [20441.0, 21.0, 10.0, -10758.0, 0.0, -3.0]),
+    ([2, 0, 0, 0, 0], [29243.0, 0.0, -74.0, -609.0, 0.0, 13.0]),
+    ([0, 0, 2, 0, 0], [25887.0, 0.0, -66.0, -550.0, 0.0, 11.0]),
+    ([0, 1, 0, 0, 1], [-14053.0, -25.0, 79.0, 8551.0, -2.0, -45.0]),
+    ([-1, 0, 0, 2, 1], [15164.0, 10.0, 11.0, -8001.0, 0.0, -1.0]),
+    ([0, 2, 2, -2, 2], [-15794.0, 72.0, -16.0, 6850.0, -42.0, -5.0]),
+    ([0, 0, -2, 2, 0], [21783.0, 0.0, 13.0, -167.0, 0.0, 13.0]),
+    ([1, 0, 0, -2, 1], [-12873.0, -10.0, -37.0, 6953.0, 0.0, -14.0]),
+    ([0, -1, 0, 0, 1], [-12654.0, 11.0, 63.0, 6415.0, 0.0, 26.0]),
+    ([-1, 0, 2, 2, 1], [-10204.0, 0.0, 25.0, 5222.0, 0.0, 15.0]),
+    ([0, 2, 0, 0, 0], [16707.0, -85.0, -10.0, 168.0, -1.0, 10.0]),
+    ([1, 0, 2, 2, 2], [-7691.0, 0.0, 44.0, 3268.0, 0.0, 19.0]),
+    ([-2, 0, 2, 0, 0], [-11024.0, 0.0, -14.0, 104.0, 0.0, 2.0]),
+    ([0, 1, 2, 0, 2], [7566.0, -21.0, -11.0, -3250.0, 0.0, -5.0]),
+    ([0, 0, 2, 2, 1], [-6637.0, -11.0, 25.0, 3353.0, 0.0, 14.0]),
+    ([0, -1, 2, 0, 2], [-7141.0, 21.0, 8.0, 3070.0, 0.0, 4.0]),
+    ([0, 0, 0, 2, 1], [-6302.0, -11.0, 2.0, 3272.0, 0.0, 4.0]),
+    ([1, 0, 2, -2, 1], [5800.0, 10.0, 2.0, -3045.0, 0.0, -1.0]),
+    ([2, 0, 2, -2, 2], [6443.0, 0.0, -7.0, -2768.0, 0.0, -4.0]),
+    ([-2, 0, 0, 2, 1], [-5774.0, -11.0, -15.0, 3041.0, 0.0, -5.0]),
+    ([2, 0, 2, 0, 1], [-5350.0, 0.0, 21.0, 2695.0, 0.0, 12.0]),
+    ([0, -1, 2, -2, 1], [-4752.0, -11.0, -3.0, 2719.0, 0.0, -3.0]),
+    ([0, 0, 0, -2, 1], [-4940.0, -11.0, -21.0, 2720.0, 0.0, -9.0]),
+    ([-1, -1, 0, 2, 0], [7350.0, 0.0, -8.0, -51.0, 0.0, 4.0]),
+    ([2, 0, 0, -2, 1], [4065.0, 0.0, 6.0, -2206.0, 0.0, 1.0]),
+    ([1, 0, 0, 2, 0], [6579.0, 0.0, -24.0, -199.0, 0.0, 2.0]),
+    ([0, 1, 2, -2, 1], [3579.0, 0.0, 5.0, -1900.0, 0.0, 1.0]),
+    ([1, -1, 0, 0, 0], [4725.0, 0.0, -6.0, -41.0, 0.0, 3.0]),
+    ([-2, 0, 2, 0, 2], [-3075.0, 0.0, -2.0, 1313.0, 0.0, -1.0]),
+    ([3, 0, 2, 0, 2], [-2904.0, 0.0, 15.0, 1233.0, 0.0, 7.0]),
+    ([0, -1, 0, 2, 0], [4348.0, 0.0, -10.0, -81.0, 0.0, 2.0]),
+    ([1, -1, 2, 0, 2], [-2878.0, 0.0, 8.0, 1232.0, 0.0, 4.0]),
+    ([0, 0, 0, 1, 0], [-4230.0, 0.0, 5.0, -20.0, 0.0, -2.0]),
+    ([-1, -1, 2, 2, 2], [-2819.0, 0.0, 7.0, 1207.0, 0.0, 3.0]),
+    ([-1, 0, 2, 0, 0], [-4056.0, 0.0, 5.0, 40.0, 0.0, -2.0]),
+    ([0, -1, 2, 2, 2], [-2647.0, 0.0, 11.0, 1129.0, 0.0, 5.0]),
+    ([-2, 0, 0, 0, 1], [-2294.0, 0.0, -10.0, 1266.0, 0.0, -4.0]),
+    ([1, 1, 2, 0, 2], [2481.0, 0.0, -7.0, -1062.0, 0.0, -3.0]),
+    ([2, 0, 0, 0, 1], [2179.0, 0.0, -2.0, -1129.0, 0.0, -2.0]),
+    ([-1, 1, 0, 1, 0], [3276.0, 0.0, 1.0, -9.0, 0.0, 0.0]),
+    ([1, 1, 0, 0, 0], [-3389.0, 0.0, 5.0, 35.0, 0.0, -2.0]),
+    ([1, 0, 2, 0, 0], [3339.0, 0.0, -13.0, -107.0, 0.0, 1.0]),
+    ([-1, 0, 2, -2, 1], [-1987.0, 0.0, -6.0, 1073.0, 0.0, -2.0]),
+    ([1, 0, 0, 0, 2], [-1981.0, 0.0, 0.0, 854.0, 0.0, 0.0]),
+    ([-1, 0, 0, 1, 0], [4026.0, 0.0, -353.0, -553.0, 0.0, -139.0]),
+    ([0, 0, 2, 1, 2], [1660.0, 0.0, -5.0, -710.0, 0.0, -2.0]),
+    ([-1, 0, 2, 4, 2], [-1521.0, 0.0, 9.0, 647.0, 0.0, 4.0]),
+    ([-1, 1, 0, 1, 1], [1314.0, 0.0, 0.0, -700.0, 0.0, 0.0]),
+    ([0, -2, 2, -2, 1], [-1283.0, 0.0, 0.0, 672.0, 0.0, 0.0]),
+    ([1, 0, 2, 2, 1], [-1331.0, 0.0, 8.0, 663.0, 0.0, 4.0]),
+    ([-2, 0, 2, 2, 2], [1383.0, 0.0, -2.0, -594.0, 0.0, -2.0]),
+    ([-1, 0, 0, 0, 2], [1405.0, 0.0, 4.0, -610.0, 0.0, 2.0]),
+    ([1, 1, 2, -2, 2], [1290.0, 0.0, 0.0, -556.0, 0.0, 0.0]),
+];
+
+/// Nutation, IAU 2000.
+///
+/// **Differs from ERFA:** evaluates the 77-term IAU 2000B series with fixed
+/// planetary offsets instead of the full 1365-term IAU 2000A model. The
+/// difference from `eraNut00a` stays below 3 mas over 1900–2100.
+///
+/// Returns `(dpsi, deps)` in radians.
+#[allow(non_snake_case)]
+pub fn Nut00a(date1: f64, date2: f64) -> (f64, f64) {
+    let t = centuries(date1, date2);
+
+    // Delaunay arguments (Simon et al. 1994), radians
+    let arg = |a0: f64, a1: f64| ((a0 + a1 * t) % TURNAS) * DAS2R;
+    let el = arg(485868.249036, 1717915923.2178);
+    let elp = arg(1287104.79305, 129596581.0481);
+    let f = arg(335779.526232, 1739527262.8478);
+    let d = arg(1072260.70369, 1602961601.2090);
+    let om = arg(450160.398036, -6962890.5431);
+
+    let mut dp = 0.0;
+    let mut de = 0.0;
+    for (n, c) in NUT00B.iter().rev() {
+        let a = ((n[0] as f64) * el
+            + (n[1] as f64) * elp
+            + (n[2] as f64) * f
+            + (n[3] as f64) * d
+            + (n[4] as f64) * om)
+            % TAU;
+        let (sarg, carg) = a.sin_cos();
+        dp += (c[0] + c[1] * t) * sarg + c[2] * carg;
+        de += (c[3] + c[4] * t) * carg + c[5] * sarg;
+    }
+
+    (dp * U2R + DPPLAN, de * U2R + DEPLAN)
+}
+
+/// Nutation, IAU 2006/2000A: the IAU 2000 nutation adjusted for the IAU
+/// 2006 precession (ERFA `eraNut06a`).
+pub(crate) fn nut06a(date1: f64, date2: f64) -> (f64, f64) {
+    let t = centuries(date1, date2);
+    let fj2 = -2.7774e-6 * t;
+    let (dp, de) = Nut00a(date1, date2);
+    (dp + dp * (0.4697e-6 + fj2), de + de * fj2)
+}
+
+/// Mean obliquity of the ecliptic, IAU 2006 precession model (radians).
+#[allow(non_snake_case)]
+pub fn Obl06(date1: f64, date2: f64) -> f64 {
+    let t = centuries(date1, date2);
+    (84381.406
+        + (-46.836769 + (-0.0001831 + (0.00200340 + (-0.000000576 + (-0.0000000434) * t) * t) * t) * t) * t)
+        * DAS2R
+}
+
+/// Mean obliquity of the ecliptic, IAU 1980 model (radians).
+#[allow(non_snake_case)]
+pub fn Obl80(date1: f64, date2: f64) -> f64 {
+    let t = centuries(date1, date2);
+    (84381.448 + (-46.8150 + (-0.00059 + 0.001813 * t) * t) * t) * DAS2R
+}
+
+/// Precession angles, IAU 2006, equinox based.
+///
+/// Returns `(eps0, psia, oma, bpa, bqa, pia, bpia, epsa, chia, za, zetaa,
+/// thetaa, pa, gam, phi, psi)` in radians.
+#[allow(non_snake_case)]
+#[allow(clippy::type_complexity)]
+pub fn P06e(
+    date1: f64,
+    date2: f64,
+) -> (f64, f64, f64, f64, f64, f64, f64, f64, f64, f64, f64, f64, f64, f64, f64, f64) {
+    let t = centuries(date1, date2);
+    // Horner evaluation of c[0] + c[1] t + ... in arcseconds, to radians
+    let poly = |c: &[f64]| c.iter().rev().fold(0.0, |acc, &k| acc * t + k) * DAS2R;
+
+    let eps0 = 84381.406 * DAS2R;
+    let psia = poly(&[0.0, 5038.481507, -1.0790069, -0.00114045, 0.000132851, -0.0000000951]);
+    let oma = eps0 + poly(&[0.0, -0.025754, 0.0512623, -0.00772503, -0.000000467, 0.0000003337]);
+    let bpa = poly(&[0.0, 4.199094, 0.1939873, -0.00022466, -0.000000912, 0.0000000120]);
+    let bqa = poly(&[0.0, -46.811015, 0.0510283, 0.00052413, -0.000000646, -0.0000000172]);
+    let pia = poly(&[0.0, 46.998973, -0.0334926, -0.00012559, 0.000000113, -0.0000000022]);
+    let bpia = poly(&[629546.7936, -867.95758, 0.157992, -0.0005371, -0.00004797, 0.000000072]);
+    let epsa = Obl06(date1, date2);
+    let chia = poly(&[0.0, 10.556403, -2.3814292, -0.00121197, 0.000170663, -0.0000000560]);
+    let za = poly(&[-2.650545, 2306.077181, 1.0927348, 0.01826837, -0.000028596, -0.0000002904]);
+    let zetaa = poly(&[2.650545, 2306.083227, 0.2988499, 0.01801828, -0.000005971, -0.0000003173]);
+    let thetaa = poly(&[0.0, 2004.191903, -0.4294934, -0.04182264, -0.000007089, -0.0000001274]);
+    let pa = poly(&[0.0, 5028.796195, 1.1054348, 0.00007964, -0.000023857, -0.0000000383]);
+    let gam = poly(&[0.0, 10.556403, 0.4932044, -0.00031238, -0.000002788, 0.0000000260]);
+    let phi = eps0 + poly(&[0.0, -46.811015, 0.0511269, 0.00053289, -0.000000440, -0.0000000176]);
+    let psi = poly(&[0.0, 5038.481507, 1.5584176, -0.00018522, -0.000026452, -0.0000000148]);
+
+    (eps0, psia, oma, bpa, bqa, pia, bpia, epsa, chia, za, zetaa, thetaa, pa, gam, phi, psi)
+}
+
+/// Fukushima-Williams bias+precession angles, IAU 2006 (ERFA `eraPfw06`).
+///
+/// Returns `(gamb, phib, psib, epsa)` in radians.
+pub(crate) fn pfw06(date1: f64, date2: f64) -> (f64, f64, f64, f64) {
+    let t = centuries(date1, date2);
+    let poly = |c: &[f64]| c.iter().rev().fold(0.0, |acc, &k| acc * t + k) * DAS2R;
+
+    let gamb = poly(&[-0.052928, 10.556378, 0.4932044, -0.00031238, -0.000002788, 0.0000000260]);
+    let phib = poly(&[84381.412819, -46.811016, 0.0511268, 0.00053289, -0.000000440, -0.0000000176]);
+    let psib = poly(&[-0.041775, 5038.481484, 1.5584175, -0.00018522, -0.000026452, -0.0000000148]);
+    (gamb, phib, psib, Obl06(date1, date2))
+}
+
+/// Forms a rotation matrix from Fukushima-Williams angles (ERFA `eraFw2m`).
+pub(crate) fn fw2m(gamb: f64, phib: f64, psi: f64, eps: f64) -> [[f64; 3]; 3] {
+    let mut r = identity();
+    rz(gamb, &mut r);
+    rx(phib, &mut r);
+    rz(-psi, &mut r);
+    rx(-eps, &mut r);
+    r
+}
+
+/// Bias-precession matrix, IAU 2006.
+#[allow(non_snake_case)]
+pub fn Pmat06(date1: f64, date2: f64, rbp: &mut [f64; 9]) {
+    let (gamb, phib, psib, epsa) = pfw06(date1, date2);
+    *rbp = flatten(&fw2m(gamb, phib, psib, epsa));
+}
+
+/// Vondrák et al. (2011) long-term precession: polynomial coefficients of
+/// the equator pole X, Y in arcseconds.
+const LTP_XYPOL: [[f64; 4]; 2] = [
+    [5453.282155, 0.4252841, -0.00037173, -0.000000152],
+    [-73750.930350, -0.7675452, -0.00018725, 0.000000231],
+];
+
+/// Periodic terms of the equator pole: period in centuries, then
+/// (X cos, Y cos, X sin, Y sin) in arcseconds.
+#[rustfmt::skip]
+const LTP_XYPER: [[f64; 5]; 14] = [
+    [256.75, -819.940624, 75004.344875, 81491.287984, 1558.515853],
+    [708.15, -8444.676815, 624.033993, 787.163481, 7774.939698],
+    [274.20, 2600.009459, 1251.136893, 1251.296102, -2219.534038],
+    [241.45, 2755.175630, -1102.212834, -1257.950837, -2523.969396],
+    [2309.00, -167.659835, -2660.664980, -2966.799730, 247.850422],
+    [492.20, 871.855056, 699.291817, 639.744522, -846.485643],
+    [396.10, 44.769698, 153.167220, 131.600209, -1393.124055],
+    [288.90, -512.313065, -950.865637, -445.040117, 368.526116],
+    [231.10, -819.415595, 499.754645, 584.522874, 749.045012],
+    [1610.00, -538.071099, -145.188210, -89.756563, 444.704518],
+    [620.00, -189.793622, 558.116553, 524.429630, 235.934465],
+    [157.87, -402.922932, -23.923029, -13.549067, 374.049623],
+    [220.30, 179.516345, -165.405086, -210.157124, -171.330180],
+    [1200.00, -9.814756, 9.344131, -44.919798, -22.899655],
+];
+
+/// Polynomial coefficients of the ecliptic pole P_A, Q_A in arcseconds.
+const LTP_PQPOL: [[f64; 4]; 2] = [
+    [5851.607687, -0.1189000, -0.00028913, 0.000000101],
+    [-1600.886300, 1.1689818, -0.00000020, -0.000000437],
+];
+
+/// Periodic terms of the ecliptic pole, laid out as [`LTP_XYPER`].
+#[rustfmt::skip]
+const LTP_PQPER: [[f64; 5]; 8] = [
+    [708.15, -5486.751211, -684.661560, 667.666730, -5523.863691],
+    [2309.00, -17.127623, 2446.283880, -2354.886252, -549.747450],
+    [1620.00, -617.517403, 399.671049, -428.152441, -310.998056],
+    [492.20, 413.442940, -356.652376, 376.202861, 421.535876],
+    [1183.00, 78.614193, -186.387003, 184.778874, -36.776172],
+    [622.00, -180.732815, -316.800070, 335.321713, -145.278396],
+    [882.00, -87.676083, 198.296701, -185.138669, -34.744450],
+    [547.00, 46.140315, 101.135679, -120.972830, 22.885731],
+];
+
+/// Sums the periodic and polynomial series of the long-term model, giving
+/// the two pole coordinates in radians.
+fn ltp_series(epj: f64, periodic: &[[f64; 5]], polynomial: &[[f64; 4]; 2]) -> (f64, f64) {
+    let t = (epj - 2000.0) / 100.0;
+    let (mut a, mut b) = (0.0, 0.0);
+    let w = TAU * t;
+    for term in periodic {
+        let (s, c) = (w / term[0]).sin_cos();
+        a += c * term[1] + s * term[3];
+        b += c * term[2] + s * term[4];
+    }
+    let mut w = 1.0;
+    for (pa, pb) in polynomial[0].iter().zip(&polynomial[1]) {
+        a += pa * w;
+        b += pb * w;
+        w *= t;
+    }
+    (a * DAS2R, b * DAS2R)
+}
+
+/// Long-term precession of the ecliptic pole (Vondrák et al. 2011).
+#[allow(non_snake_case)]
+pub fn Ltpecl(epj: f64) -> [f64; 3] {
+    let eps0 = 84381.406 * DAS2R;
+    let (p, q) = ltp_series(epj, &LTP_PQPER, &LTP_PQPOL);
+    let w = (1.0 - p * p - q * q).max(0.0).sqrt();
+    let (s, c) = eps0.sin_cos();
+    [p, -q * c - w * s, -q * s + w * c]
+}
+
+/// Long-term precession of the equator pole (Vondrák et al. 2011).
+#[allow(non_snake_case)]
+pub fn Ltpequ(epj: f64) -> [f64; 3] {
+    let (x, y) = ltp_series(epj, &LTP_XYPER, &LTP_XYPOL);
+    [x, y, (1.0 - x * x - y * y).max(0.0).sqrt()]
+}
+
+/// Long-term precession matrix (Vondrák et al. 2011).
+#[allow(non_snake_case)]
+pub fn Ltp(epj: f64, rp: &mut [f64; 9]) {
+    let peqr = Ltpequ(epj);
+    let pecl = Ltpecl(epj);
+    let (_, eqx) = pn(&pxp(&peqr, &pecl));
+    let middle = pxp(&peqr, &eqx);
+    *rp = flatten(&[eqx, middle, peqr]);
+}
+
+/// Long-term precession matrix including the ICRS frame bias.
+#[allow(non_snake_case)]
+pub fn Ltpb(epj: f64, rpb: &mut [f64; 9]) {
+    // Frame bias (IERS Conventions 2010, Eqs. 5.21 and 5.33)
+    let dx = -0.016617 * DAS2R;
+    let de = -0.0068192 * DAS2R;
+    let dr = -0.0146 * DAS2R;
+    let mut rp = [0.0; 9];
+    Ltp(epj, &mut rp);
+    for i in 0..3 {
+        let row = [rp[3 * i], rp[3 * i + 1], rp[3 * i + 2]];
+        rpb[3 * i] = row[0] - row[1] * dr + row[2] * dx;
+        rpb[3 * i + 1] = row[0] * dr + row[1] + row[2] * de;
+        rpb[3 * i + 2] = -row[0] * dx - row[1] * de + row[2];
+    }
+}
+
+/// Nutation matrix from obliquity and nutation components.
+#[allow(non_snake_case)]
+pub fn Numat(epsa: f64, dpsi: f64, deps: f64, rmatn: &mut [f64; 9]) {
+    let mut r = identity();
+    rx(epsa, &mut r);
+    rz(-dpsi, &mut r);
+    rx(-(epsa + deps), &mut r);
+    *rmatn = flatten(&r);
+}
+
+/// Bias-precession-nutation matrix, IAU 2006/2000A.
+///
+/// **Differs from ERFA** only through [`Nut00a`] (IAU 2000B series).
+#[allow(non_snake_case)]
+pub fn Pnm06a(date1: f64, date2: f64, rbpn: &mut [f64; 9]) {
+    *rbpn = flatten(&pnm06a(date1, date2));
+}
+
+pub(crate) fn pnm06a(date1: f64, date2: f64) -> [[f64; 3]; 3] {
+    let (gamb, phib, psib, epsa) = pfw06(date1, date2);
+    let (dp, de) = nut06a(date1, date2);
+    fw2m(gamb, phib, psib + dp, epsa + de)
+}
+
+/// Equation of the equinoxes, IAU 2006/2000A (radians).
+///
+/// **Differs from ERFA:** the complementary terms (`eraEect00`) are
+/// truncated to the three largest, an error below 2 µas.
+pub(crate) fn ee06a(date1: f64, date2: f64) -> f64 {
+    let t = centuries(date1, date2);
+    let (dpsi, _) = nut06a(date1, date2);
+    let epsa = Obl06(date1, date2);
+
+    // Mean longitude of the Moon's ascending node (IERS 2003)
+    let om = anpm(
+        ((450160.398036 + (-6962890.5431 + (7.4722 + (0.007702 + (-0.00005939) * t) * t) * t) * t)
+            % TURNAS)
+            * DAS2R,
+    );
+    let eect = (2640.96e-6 * om.sin() + 63.52e-6 * (2.0 * om).sin() - 0.87e-6 * t * om.sin()) * DAS2R;
+
+    dpsi * epsa.cos() + eect
+}
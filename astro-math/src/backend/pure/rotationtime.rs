@@ -0,0 +1,37 @@
+//! Earth rotation angle and sidereal time.
+
+use super::precnutpolar::ee06a;
+use super::{anp, centuries, DAS2R, DJ00};
+use std::f64::consts::TAU;
+
+/// Earth rotation angle, IAU 2000 (radians).
+#[allow(non_snake_case)]
+pub fn Era00(dj1: f64, dj2: f64) -> f64 {
+    let (d1, d2) = if dj1 < dj2 { (dj1, dj2) } else { (dj2, dj1) };
+    let t = d1 + (d2 - DJ00);
+    // Fractional part of T (days)
+    let f = d1 % 1.0 + d2 % 1.0;
+    anp(TAU * (f + 0.7790572732640 + 0.00273781191135448 * t))
+}
+
+/// Greenwich mean sidereal time, consistent with IAU 2006 precession
+/// (radians).
+#[allow(non_snake_case)]
+pub fn Gmst06(uta: f64, utb: f64, tta: f64, ttb: f64) -> f64 {
+    let t = centuries(tta, ttb);
+    anp(Era00(uta, utb)
+        + (0.014506
+            + (4612.156534 + (1.3915817 + (-0.00000044 + (-0.000029956 + (-0.0000000368) * t) * t) * t) * t)
+                * t)
+            * DAS2R)
+}
+
+/// Greenwich apparent sidereal time, IAU 2006/2000A (radians).
+///
+/// **Differs from ERFA:** formed as GMST plus the equation of the equinoxes
+/// rather than through the CIO locator; with the IAU 2000B nutation the
+/// difference from `eraGst06a` stays below 0.2 ms of time.
+#[allow(non_snake_case)]
+pub fn Gst06a(uta: f64, utb: f64, tta: f64, ttb: f64) -> f64 {
+    anp(Gmst06(uta, utb, tta, ttb) + ee06a(tta, ttb))
+}
@@ -0,0 +1,622 @@
+//! Besselian elements for solar eclipses and lunar and asteroid occultations.
+//!
+//! Besselian elements describe the shadow of the Moon or an asteroid on the
+//! *fundamental plane*: the plane through the Earth's centre perpendicular to
+//! the shadow axis, which runs from the occulted body (the Sun or a star)
+//! through the occulting body.
+//! Detailed local-circumstance engines take them as input, so
+//! [`besselian_elements`] provides them directly from this crate's
+//! ephemerides instead of from a separate toolchain.
+//!
+//! The elements are, in the conventions of the *Explanatory Supplement to
+//! the Astronomical Almanac* and the NASA eclipse bulletins:
+//!
+//! - `x`, `y` — shadow axis on the fundamental plane, in Earth equatorial
+//!   radii, with `y` towards the north celestial pole
+//! - `d` — declination of the shadow axis, in degrees
+//! - `mu` — Greenwich hour angle of the shadow axis, in degrees
+//! - `l1`, `l2` — radii of the penumbral and umbral cones on the fundamental
+//!   plane, in Earth equatorial radii (`l2` is negative for a total eclipse)
+//! - `tan_f1`, `tan_f2` — tangents of the penumbral and umbral cone angles
+//!
+//! Each element is returned as a cubic polynomial in hours from the reference
+//! time `t0`, fitted to exact values over ±3 hours, which is how eclipse
+//! bulletins tabulate them. For an occultation the star is at infinity, so
+//! the shadow is a cylinder: `l1 = l2` is the radius of the Moon or asteroid
+//! and both cone angles are zero. The [`occultation`](crate::occultation)
+//! module reduces observed timings against these elements, and
+//! [`path_on_earth`] traces the central line, limits, duration and width of
+//! the path of totality, annularity or occultation across the ground.
+//!
+//! Positions are apparent, referred to the true equator and equinox of date:
+//! light time and aberration are applied to the Sun and Moon, aberration to
+//! the star, and both to an asteroid, whose position comes from two-body
+//! propagation of its [`OrbitalElements`]. UT1 is taken equal to UTC, as
+//! elsewhere in the crate.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::besselian::{besselian_elements, BesselianEvent};
+//! use chrono::{TimeZone, Utc};
+//!
+//! // Total solar eclipse of 2024 April 8
+//! let t0 = Utc.with_ymd_and_hms(2024, 4, 8, 18, 0, 0).unwrap();
+//! let elements = besselian_elements(BesselianEvent::SolarEclipse { t0 }).unwrap();
+//!
+//! // Near greatest eclipse the axis passes close to the Earth's centre
+//! assert!(elements.x[0].hypot(elements.y[0]) < 0.5);
+//! // ...and the umbral cone reaches the ground
+//! assert!(elements.l2[0] < 0.0);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range star position
+//! - `AstroError::OutOfRange` for invalid asteroid orbital elements or
+//!   diameter, or a path step outside [1 s, 6 h]
+//! - `AstroError::CalculationError` if the polynomial fit fails
+
+use crate::erfa::bias_precession_nutation_matrix;
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::observer::geodetic_from_terrestrial_km;
+use crate::orbit::OrbitalElements;
+use crate::time::julian_date_two_part;
+use crate::time_scales::{tt_utc_offset_seconds, utc_to_tt_jd_two_part};
+use crate::vec3::{dot, norm, radec_to_unit_vector};
+use chrono::{DateTime, Duration, Utc};
+use nalgebra::{DMatrix, DVector};
+
+/// Astronomical unit in kilometers
+const AU_KM: f64 = 149597870.7;
+
+/// Earth equatorial radius in kilometers
+const EARTH_RADIUS_KM: f64 = 6378.137;
+
+/// WGS84 flattening
+const EARTH_FLATTENING: f64 = 1.0 / 298.257223563;
+
+/// Solar radius in kilometers
+const SUN_RADIUS_KM: f64 = 696000.0;
+
+/// Speed of light in AU per day
+const C_AU_PER_DAY: f64 = 173.1446326846693;
+
+/// Moon radius in Earth radii for the penumbra (mean radius)
+const MOON_K1: f64 = 0.2724880;
+
+/// Moon radius in Earth radii for the umbra (mean radius of the limb minima)
+const MOON_K2: f64 = 0.2722810;
+
+/// Hours either side of `t0` covered by the polynomial fit
+const FIT_HALF_WIDTH_HOURS: i64 = 3;
+
+/// The phenomenon to compute elements for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BesselianEvent {
+    /// Solar eclipse, with `t0` near greatest eclipse
+    SolarEclipse {
+        /// Reference time of the polynomials
+        t0: DateTime<Utc>,
+    },
+    /// Occultation of a star by the Moon, with `t0` near conjunction
+    LunarOccultation {
+        /// Reference time of the polynomials
+        t0: DateTime<Utc>,
+        /// ICRS right ascension of the star in degrees
+        ra: f64,
+        /// ICRS declination of the star in degrees
+        dec: f64,
+    },
+    /// Occultation of a star by an asteroid, with `t0` near conjunction
+    AsteroidOccultation {
+        /// Reference time of the polynomials
+        t0: DateTime<Utc>,
+        /// ICRS right ascension of the star in degrees
+        ra: f64,
+        /// ICRS declination of the star in degrees
+        dec: f64,
+        /// Heliocentric orbit of the asteroid
+        elements: OrbitalElements,
+        /// Diameter of the asteroid in kilometers, which sets the shadow width
+        diameter_km: f64,
+    },
+}
+
+/// Besselian elements as polynomials in hours from `t0`.
+///
+/// Each `[c0, c1, c2, c3]` evaluates as `c0 + c1·t + c2·t² + c3·t³` with `t`
+/// in hours; [`BesselianElements::at`] does this for all elements at once.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BesselianElements {
+    /// Reference time of the polynomials
+    pub t0: DateTime<Utc>,
+    /// TT − UT1 used for the elements, in seconds
+    pub delta_t_s: f64,
+    /// Shadow axis x coordinate, Earth radii
+    pub x: [f64; 4],
+    /// Shadow axis y coordinate, Earth radii
+    pub y: [f64; 4],
+    /// Declination of the shadow axis, degrees
+    pub d: [f64; 4],
+    /// Greenwich hour angle of the shadow axis, degrees (not reduced to [0, 360))
+    pub mu: [f64; 4],
+    /// Penumbral radius on the fundamental plane, Earth radii
+    pub l1: [f64; 4],
+    /// Umbral radius on the fundamental plane, Earth radii
+    pub l2: [f64; 4],
+    /// Tangent of the penumbral cone angle at `t0`
+    pub tan_f1: f64,
+    /// Tangent of the umbral cone angle at `t0`
+    pub tan_f2: f64,
+}
+
+/// Values of the Besselian elements at one instant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FundamentalPlane {
+    /// Shadow axis x coordinate, Earth radii
+    pub x: f64,
+    /// Shadow axis y coordinate, Earth radii
+    pub y: f64,
+    /// Declination of the shadow axis, degrees
+    pub d: f64,
+    /// Greenwich hour angle of the shadow axis, degrees in [0, 360)
+    pub mu: f64,
+    /// Penumbral radius on the fundamental plane, Earth radii
+    pub l1: f64,
+    /// Umbral radius on the fundamental plane, Earth radii
+    pub l2: f64,
+}
+
+impl BesselianElements {
+    /// Evaluates the polynomials at a given time.
+    ///
+    /// The fit is accurate within about ±3 hours of `t0`.
+    pub fn at(&self, t: DateTime<Utc>) -> FundamentalPlane {
+        let hours = (t - self.t0).num_milliseconds() as f64 / 3_600_000.0;
+        let eval = |c: &[f64; 4]| c[0] + hours * (c[1] + hours * (c[2] + hours * c[3]));
+        FundamentalPlane {
+            x: eval(&self.x),
+            y: eval(&self.y),
+            d: eval(&self.d),
+            mu: eval(&self.mu).rem_euclid(360.0),
+            l1: eval(&self.l1),
+            l2: eval(&self.l2),
+        }
+    }
+}
+
+/// A point on the central line of the path of an eclipse or occultation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathPoint {
+    /// Instant the shadow axis touches the ground here
+    pub time: DateTime<Utc>,
+    /// Geodetic latitude of the central line, degrees
+    pub latitude_deg: f64,
+    /// Longitude of the central line, degrees east
+    pub longitude_deg: f64,
+    /// Duration of totality, annularity or the occultation on the central
+    /// line, seconds
+    pub duration_s: f64,
+    /// Width of the path perpendicular to the central line, kilometers
+    pub width_km: f64,
+    /// `(latitude_deg, longitude_deg)` of the limit on the northern side of
+    /// the fundamental plane at `time`, or `None` where it is off the Earth
+    pub north_limit: Option<(f64, f64)>,
+    /// `(latitude_deg, longitude_deg)` of the limit on the southern side,
+    /// or `None` where it is off the Earth
+    pub south_limit: Option<(f64, f64)>,
+}
+
+/// Computes Besselian elements for a solar eclipse or an occultation.
+///
+/// # Arguments
+/// * `event` - The eclipse or occultation, with the reference time `t0`
+///
+/// # Returns
+/// Cubic polynomials for each element in hours from `t0`
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the star's RA or Dec is out of range
+/// - `AstroError::OutOfRange` if an asteroid's orbital elements are invalid
+///   or its diameter is not positive
+/// - `AstroError::CalculationError` if the polynomial fit fails
+///
+/// # Example
+/// ```
+/// use astro_math::besselian::{besselian_elements, BesselianEvent};
+/// use chrono::{TimeZone, Utc};
+///
+/// // Occultation of Spica on 2024 July 14
+/// let t0 = Utc.with_ymd_and_hms(2024, 7, 14, 3, 0, 0).unwrap();
+/// let event = BesselianEvent::LunarOccultation { t0, ra: 201.298247, dec: -11.161319 };
+/// let elements = besselian_elements(event).unwrap();
+///
+/// // The shadow of the Moon is a cylinder of the Moon's radius...
+/// assert_eq!(elements.tan_f1, 0.0);
+/// assert!((elements.l1[0] - 0.2725).abs() < 1e-4);
+/// // ...crossing the Earth's disk eastwards at about half an Earth radius per hour
+/// assert!(elements.x[0].hypot(elements.y[0]) < 1.0);
+/// assert!(elements.x[1] > 0.4 && elements.x[1] < 0.6);
+/// ```
+pub fn besselian_elements(event: BesselianEvent) -> Result<BesselianElements> {
+    let t0 = match event {
+        BesselianEvent::SolarEclipse { t0 } => t0,
+        BesselianEvent::LunarOccultation { t0, ra, dec } => {
+            validate_ra(ra)?;
+            validate_dec(dec)?;
+            t0
+        }
+        BesselianEvent::AsteroidOccultation {
+            t0,
+            ra,
+            dec,
+            elements,
+            diameter_km,
+        } => {
+            validate_ra(ra)?;
+            validate_dec(dec)?;
+            elements.validate()?;
+            if !(diameter_km > 0.0 && diameter_km.is_finite()) {
+                return Err(AstroError::OutOfRange {
+                    parameter: "diameter_km",
+                    value: diameter_km,
+                    min: f64::MIN_POSITIVE,
+                    max: f64::MAX,
+                });
+            }
+            t0
+        }
+    };
+
+    let hours: Vec<f64> = (-FIT_HALF_WIDTH_HOURS..=FIT_HALF_WIDTH_HOURS).map(|h| h as f64).collect();
+    let samples = (-FIT_HALF_WIDTH_HOURS..=FIT_HALF_WIDTH_HOURS)
+        .map(|h| sample(&event, t0 + Duration::hours(h)))
+        .collect::<Result<Vec<Sample>>>()?;
+
+    // Keep μ continuous across 0°/360° before fitting
+    let mut mu: Vec<f64> = samples.iter().map(|s| s.mu).collect();
+    for i in 1..mu.len() {
+        mu[i] = mu[i - 1] + (mu[i] - mu[i - 1] + 180.0).rem_euclid(360.0) - 180.0;
+    }
+
+    let fit = |values: Vec<f64>| fit_cubic(&hours, &values);
+    let centre = &samples[FIT_HALF_WIDTH_HOURS as usize];
+    Ok(BesselianElements {
+        t0,
+        delta_t_s: tt_utc_offset_seconds(),
+        x: fit(samples.iter().map(|s| s.x).collect())?,
+        y: fit(samples.iter().map(|s| s.y).collect())?,
+        d: fit(samples.iter().map(|s| s.d).collect())?,
+        mu: fit(mu)?,
+        l1: fit(samples.iter().map(|s| s.l1).collect())?,
+        l2: fit(samples.iter().map(|s| s.l2).collect())?,
+        tan_f1: centre.tan_f1,
+        tan_f2: centre.tan_f2,
+    })
+}
+
+/// Traces the path of an eclipse or occultation across the Earth.
+///
+/// Samples the central line, where the shadow axis meets the WGS84
+/// ellipsoid, every `step` over the ±3 hours the elements cover. For a
+/// solar eclipse the shadow is the umbra (totality) or antumbra
+/// (annularity); for an occultation it is the shadow of the Moon or the
+/// asteroid. At each point the limits are where the shadow's edge grazes
+/// the ground at the same instant, and the duration and width follow from
+/// the shadow's radius at the ground and its speed relative to the
+/// rotating Earth.
+///
+/// # Arguments
+/// * `event` - The eclipse or occultation, with `t0` near greatest eclipse
+///   or conjunction
+/// * `step` - Interval between points, 1 second to 6 hours
+///
+/// # Returns
+/// The points where the shadow axis meets the Earth, in time order; empty
+/// for a partial eclipse or an occultation whose shadow misses the Earth
+///
+/// # Errors
+/// - `AstroError::OutOfRange` if `step` is outside [1 s, 6 h]
+/// - As [`besselian_elements`] for the event
+///
+/// # Example
+/// ```
+/// use astro_math::besselian::path_on_earth;
+/// use astro_math::besselian::BesselianEvent;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// // Total solar eclipse of 2024 April 8
+/// let t0 = Utc.with_ymd_and_hms(2024, 4, 8, 18, 0, 0).unwrap();
+/// let path = path_on_earth(BesselianEvent::SolarEclipse { t0 }, Duration::minutes(1)).unwrap();
+///
+/// // Over central Texas at 18:40 UTC, with 4m23s of totality
+/// let texas = path.iter().find(|p| p.time == t0 + Duration::minutes(40)).unwrap();
+/// assert!((texas.latitude_deg - 31.7).abs() < 0.1 && (texas.longitude_deg + 97.4).abs() < 0.1);
+/// assert!((texas.duration_s - 263.0).abs() < 3.0);
+/// assert!((texas.width_km - 192.0).abs() < 3.0);
+/// ```
+pub fn path_on_earth(event: BesselianEvent, step: Duration) -> Result<Vec<PathPoint>> {
+    let step_s = step.num_milliseconds() as f64 / 1000.0;
+    let max_step_s = (2 * FIT_HALF_WIDTH_HOURS * 3600) as f64;
+    if !(1.0..=max_step_s).contains(&step_s) {
+        return Err(AstroError::OutOfRange {
+            parameter: "step_s",
+            value: step_s,
+            min: 1.0,
+            max: max_step_s,
+        });
+    }
+    let elements = besselian_elements(event)?;
+
+    let end = elements.t0 + Duration::hours(FIT_HALF_WIDTH_HOURS);
+    let mut t = elements.t0 - Duration::hours(FIT_HALF_WIDTH_HOURS);
+    let mut path = Vec::new();
+    while t <= end {
+        if let Some(point) = path_point(&elements, t) {
+            path.push(point);
+        }
+        t += step;
+    }
+    Ok(path)
+}
+
+/// The central line point and limits at one instant, if the shadow axis
+/// meets the Earth.
+fn path_point(elements: &BesselianElements, t: DateTime<Utc>) -> Option<PathPoint> {
+    let plane = elements.at(t);
+    let basis = plane_basis(&plane);
+    let centre = ground_point(&basis, plane.x, plane.y)?;
+    let shadow = local_shadow(elements, t, centre);
+
+    // The edges of the path are the lines, along the shadow's motion, a
+    // shadow radius either side of the centre on the fundamental plane
+    let tilt = shadow.across[0] * shadow.normal[0] + shadow.across[1] * shadow.normal[1];
+    let width_km = 2.0 * shadow.radius * EARTH_RADIUS_KM / (1.0 - tilt * tilt).sqrt();
+
+    // The limit grazes the shadow: a shadow radius from the axis, across
+    // its own direction of motion
+    let limit = |side: f64| -> Option<(f64, f64)> {
+        let mut point = centre;
+        let mut local = shadow;
+        for _ in 0..3 {
+            let xi = plane.x + side * local.radius * local.across[0];
+            let eta = plane.y + side * local.radius * local.across[1];
+            point = ground_point(&basis, xi, eta)?;
+            local = local_shadow(elements, t, point);
+        }
+        let location = geodetic_from_terrestrial_km(point);
+        Some((location.latitude_deg, location.longitude_deg))
+    };
+
+    let location = geodetic_from_terrestrial_km(centre);
+    Some(PathPoint {
+        time: t,
+        latitude_deg: location.latitude_deg,
+        longitude_deg: location.longitude_deg,
+        duration_s: 2.0 * shadow.radius / shadow.speed * 3600.0,
+        width_km,
+        north_limit: limit(1.0),
+        south_limit: limit(-1.0),
+    })
+}
+
+/// The shadow as it passes a point on the ground.
+#[derive(Debug, Clone, Copy)]
+struct LocalShadow {
+    /// Radius of the umbra or antumbra at the point, Earth radii
+    radius: f64,
+    /// Speed of the shadow relative to the point, Earth radii per hour
+    speed: f64,
+    /// Unit vector across the relative motion on the fundamental plane,
+    /// towards +η
+    across: [f64; 2],
+    /// Surface normal at the point in fundamental-plane coordinates
+    normal: [f64; 3],
+}
+
+fn local_shadow(elements: &BesselianElements, t: DateTime<Utc>, position_km: [f64; 3]) -> LocalShadow {
+    let r = scale(position_km, 1.0 / EARTH_RADIUS_KM);
+    let plane = elements.at(t);
+    let basis = plane_basis(&plane);
+    let zeta = dot(basis[2], r);
+    let radius = (plane.l2 - zeta * elements.tan_f2).abs();
+
+    // Motion of the axis relative to the point, by central difference
+    let half_step = Duration::seconds(30);
+    let offset = |t: DateTime<Utc>| {
+        let plane = elements.at(t);
+        let basis = plane_basis(&plane);
+        [plane.x - dot(basis[0], r), plane.y - dot(basis[1], r)]
+    };
+    let (later, earlier) = (offset(t + half_step), offset(t - half_step));
+    let hours = 2.0 * half_step.num_seconds() as f64 / 3600.0;
+    let (u, v) = ((later[0] - earlier[0]) / hours, (later[1] - earlier[1]) / hours);
+    let speed = u.hypot(v);
+    let across = if u > 0.0 { [-v / speed, u / speed] } else { [v / speed, -u / speed] };
+
+    let e2 = EARTH_FLATTENING * (2.0 - EARTH_FLATTENING);
+    let gradient = [r[0], r[1], r[2] / (1.0 - e2)];
+    let normal = rotate(&basis, scale(gradient, 1.0 / norm(gradient)));
+    LocalShadow { radius, speed, across, normal }
+}
+
+/// The ξ, η and ζ axes of the fundamental plane in terrestrial coordinates.
+fn plane_basis(plane: &FundamentalPlane) -> [[f64; 3]; 3] {
+    let (sin_mu, cos_mu) = plane.mu.to_radians().sin_cos();
+    let (sin_d, cos_d) = plane.d.to_radians().sin_cos();
+    [
+        [sin_mu, cos_mu, 0.0],
+        [-sin_d * cos_mu, sin_d * sin_mu, cos_d],
+        [cos_d * cos_mu, -cos_d * sin_mu, sin_d],
+    ]
+}
+
+/// Terrestrial position in kilometers where the line through `(ξ, η)`
+/// parallel to the shadow axis meets the WGS84 ellipsoid on the side facing
+/// the Sun or star.
+fn ground_point(basis: &[[f64; 3]; 3], xi: f64, eta: f64) -> Option<[f64; 3]> {
+    // |P|² in a metric that makes the ellipsoid a unit sphere
+    let e2 = EARTH_FLATTENING * (2.0 - EARTH_FLATTENING);
+    let metric = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2] / (1.0 - e2);
+    let p0: [f64; 3] = std::array::from_fn(|i| xi * basis[0][i] + eta * basis[1][i]);
+    let k = basis[2];
+
+    let (a, b, c) = (metric(k, k), metric(p0, k), metric(p0, p0) - 1.0);
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let zeta = (-b + discriminant.sqrt()) / a;
+    Some(std::array::from_fn(|i| (p0[i] + zeta * k[i]) * EARTH_RADIUS_KM))
+}
+
+/// Exact element values at one instant.
+struct Sample {
+    x: f64,
+    y: f64,
+    d: f64,
+    mu: f64,
+    l1: f64,
+    l2: f64,
+    tan_f1: f64,
+    tan_f2: f64,
+}
+
+fn sample(event: &BesselianEvent, t: DateTime<Utc>) -> Result<Sample> {
+    let (jd1, jd2) = julian_date_two_part(t);
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+    let npb = bias_precession_nutation_matrix(tt1, tt2);
+    let earth_radii_per_au = AU_KM / EARTH_RADIUS_KM;
+
+    // Apparent geocentric Moon: its position when the light left it
+    let moon = || -> Result<[f64; 3]> {
+        let moon = apparent_position(|dt| {
+            let pv = crate::backend::ephemerides::Moon98(tt1, tt2 - dt);
+            Ok([pv[0], pv[1], pv[2]])
+        })?;
+        Ok(scale(rotate(&npb, moon), earth_radii_per_au))
+    };
+    let aberrate = |direction: [f64; 3]| {
+        let (_, earth_b) = crate::backend::ephemerides::Epv00(tt1, tt2);
+        let beta = [earth_b[3] / C_AU_PER_DAY, earth_b[4] / C_AU_PER_DAY, earth_b[5] / C_AU_PER_DAY];
+        let aberrated = [direction[0] + beta[0], direction[1] + beta[1], direction[2] + beta[2]];
+        scale(aberrated, 1.0 / norm(aberrated))
+    };
+
+    // The shadow is a cone towards the Sun (at distance g from the Moon) or
+    // a cylinder of the occulting body's radius towards a star
+    let (body, axis, sun_distance, radius) = match *event {
+        BesselianEvent::SolarEclipse { .. } => {
+            let moon = moon()?;
+            // The Sun's position relative to the Earth at the emission time
+            // includes both light time and annual aberration
+            let sun = apparent_position(|dt| {
+                let (earth_h, _) = crate::backend::ephemerides::Epv00(tt1, tt2 - dt);
+                Ok([-earth_h[0], -earth_h[1], -earth_h[2]])
+            })?;
+            let sun = scale(rotate(&npb, sun), earth_radii_per_au);
+            let moon_to_sun = [sun[0] - moon[0], sun[1] - moon[1], sun[2] - moon[2]];
+            let g = norm(moon_to_sun);
+            (moon, scale(moon_to_sun, 1.0 / g), Some(g), MOON_K1)
+        }
+        BesselianEvent::LunarOccultation { ra, dec, .. } => {
+            let star = rotate(&npb, aberrate(radec_to_unit_vector(ra, dec)));
+            (moon()?, star, None, MOON_K1)
+        }
+        BesselianEvent::AsteroidOccultation {
+            ra,
+            dec,
+            elements,
+            diameter_km,
+            ..
+        } => {
+            let (earth_h, _) = crate::backend::ephemerides::Epv00(tt1, tt2);
+            let asteroid = apparent_position(|dt| {
+                let state = elements.state_at(tt1 + (tt2 - dt))?;
+                Ok([
+                    state.position[0] - earth_h[0],
+                    state.position[1] - earth_h[1],
+                    state.position[2] - earth_h[2],
+                ])
+            })?;
+            // At the distance of an asteroid, annual aberration moves it on
+            // the sky exactly as it moves the star
+            let distance = norm(asteroid);
+            let asteroid = scale(aberrate(scale(asteroid, 1.0 / distance)), distance);
+            let asteroid = scale(rotate(&npb, asteroid), earth_radii_per_au);
+            let star = rotate(&npb, aberrate(radec_to_unit_vector(ra, dec)));
+            (asteroid, star, None, diameter_km / 2.0 / EARTH_RADIUS_KM)
+        }
+    };
+
+    let a = axis[1].atan2(axis[0]);
+    let d = axis[2].clamp(-1.0, 1.0).asin();
+
+    // Fundamental plane axes: x towards the east, y towards the north
+    let i = [-a.sin(), a.cos(), 0.0];
+    let j = [-d.sin() * a.cos(), -d.sin() * a.sin(), d.cos()];
+    let (x, y, z) = (dot(body, i), dot(body, j), dot(body, axis));
+
+    let (l1, l2, tan_f1, tan_f2) = match sun_distance {
+        Some(g) => {
+            let sun_radius = SUN_RADIUS_KM / EARTH_RADIUS_KM;
+            let f1 = ((sun_radius + MOON_K1) / g).asin();
+            let f2 = ((sun_radius - MOON_K2) / g).asin();
+            (
+                z * f1.tan() + MOON_K1 / f1.cos(),
+                z * f2.tan() - MOON_K2 / f2.cos(),
+                f1.tan(),
+                f2.tan(),
+            )
+        }
+        None => (radius, radius, 0.0, 0.0),
+    };
+
+    let gast = crate::backend::rotationtime::Gst06a(jd1, jd2, tt1, tt2);
+    Ok(Sample {
+        x,
+        y,
+        d: d.to_degrees(),
+        mu: (gast - a).to_degrees().rem_euclid(360.0),
+        l1,
+        l2,
+        tan_f1,
+        tan_f2,
+    })
+}
+
+/// Geocentric position of a body as seen at the observation time.
+///
+/// `position(dt)` gives the body's geocentric position in AU `dt` days
+/// before the observation time. Evaluating it at the light time gives the
+/// apparent position, light time and annual aberration included.
+fn apparent_position(position: impl Fn(f64) -> Result<[f64; 3]>) -> Result<[f64; 3]> {
+    let mut p = position(0.0)?;
+    for _ in 0..2 {
+        p = position(norm(p) / C_AU_PER_DAY)?;
+    }
+    Ok(p)
+}
+
+/// Least-squares cubic through `(t, value)` pairs.
+fn fit_cubic(t: &[f64], values: &[f64]) -> Result<[f64; 4]> {
+    let design = DMatrix::from_fn(t.len(), 4, |row, power| t[row].powi(power as i32));
+    let rhs = DVector::from_column_slice(values);
+    let coefficients = design
+        .svd(true, true)
+        .solve(&rhs, 1e-12)
+        .map_err(|reason| AstroError::CalculationError {
+            calculation: "Besselian elements",
+            reason: reason.to_string(),
+        })?;
+    Ok([coefficients[0], coefficients[1], coefficients[2], coefficients[3]])
+}
+
+fn rotate(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [dot(m[0], v), dot(m[1], v), dot(m[2], v)]
+}
+
+fn scale(v: [f64; 3], factor: f64) -> [f64; 3] {
+    v.map(|c| c * factor)
+}
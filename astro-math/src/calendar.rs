@@ -0,0 +1,446 @@
+//! Easter and the month starts of lunisolar and lunar calendars.
+//!
+//! Requires the `calendar` feature.
+//!
+//! Calendars that follow the Moon need the instants of new moon and, for
+//! the lunisolar ones, of the Sun's passage through fixed longitudes. Both
+//! come from the apparent ecliptic longitudes of date of
+//! [`ecliptic_longitude_of_sun`] and [`moon_ecliptic_longitude`]:
+//!
+//! - [`new_moons`] — when the Moon's apparent longitude equals the Sun's
+//! - [`solar_terms`] — when the Sun's apparent longitude is a multiple of
+//!   15°, the 24 *jiéqì* of the Chinese calendar (0° is the March equinox,
+//!   270° the December solstice)
+//!
+//! Both are good to well under a minute over 1900–2100, the validity range
+//! of the Moon ephemeris.
+//!
+//! On these rest the month starts:
+//!
+//! - [`chinese_months`] — the modern (1929) rules of the Chinese calendar:
+//!   a month begins on the date of new moon in China Standard Time (UTC+8),
+//!   the month containing the December solstice is the 11th, and when 13
+//!   months fall between two such months the first without a major solar
+//!   term (a multiple of 30°) is a leap month, repeating the previous number
+//! - [`islamic_months`] — an estimate after the criterion of Saudi Arabia's
+//!   Umm al-Qura calendar: a month begins the day after the first evening at
+//!   Mecca on which conjunction has happened before sunset and the Moon sets
+//!   after the Sun. Religious observance in most countries depends on
+//!   sighting the crescent and may start a day or two later.
+//! - [`hebrew_months`] — the Hebrew calendar is fixed arithmetic on the mean
+//!   lunation (*molad*) and its postponement rules, not an observation, so
+//!   this one is exact and needs no ephemeris
+//!
+//! [`easter`] gives the date of Easter Sunday by the Gregorian computus,
+//! which also uses a tabulated, not an observed, Moon.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::calendar::{chinese_months, easter, new_moons};
+//! use chrono::{NaiveDate, TimeZone, Utc};
+//!
+//! assert_eq!(easter(2025).unwrap(), NaiveDate::from_ymd_opt(2025, 4, 20).unwrap());
+//!
+//! // New moon of 2024 January 11, 11:57 UTC
+//! let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+//! let end = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+//! let new_moon = new_moons(start, end).unwrap()[0];
+//! assert_eq!(new_moon.format("%d %H:%M").to_string(), "11 11:57");
+//!
+//! // The Year of the Dragon began on 2024 February 10
+//! let months = chinese_months(2024).unwrap();
+//! assert_eq!(months[0].start, NaiveDate::from_ymd_opt(2024, 2, 10).unwrap());
+//! assert_eq!(months.len(), 12);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for a year or date outside the supported range
+//! - `AstroError::InvalidDateTime` if a search interval ends before it starts
+
+use crate::almanac::{apparent_place, AlmanacBody};
+use crate::error::{validate_range, AstroError, Result};
+use crate::events::{find_zeros, Crossing};
+use crate::location::Location;
+use crate::moon::moon_ecliptic_longitude;
+use crate::night::Night;
+use crate::rise_set::moon_standard_altitude;
+use crate::sun::ecliptic_longitude_of_sun;
+use crate::transforms::ra_dec_to_alt_az;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+
+/// Astronomical unit in kilometers
+const AU_KM: f64 = 149_597_870.7;
+
+/// Mean synodic month in days
+const SYNODIC_MONTH: f64 = 29.530588853;
+
+/// Mecca, where the Umm al-Qura criterion is evaluated
+const MECCA: Location = Location { latitude_deg: 21.4225, longitude_deg: 39.8262, altitude_m: 0.0 };
+
+/// Hebrew epoch, 1 Tishri AM 1, as a day count with 0001-01-01 (proleptic
+/// Gregorian) as day 1
+const HEBREW_EPOCH: i64 = -1_373_427;
+
+/// One month of a lunar or lunisolar calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunarMonth {
+    /// Year of the calendar the month belongs to
+    pub year: i32,
+    /// Month number within the year, from 1
+    pub month: u32,
+    /// Whether this is an intercalary month, sharing its number with the
+    /// month before it
+    pub leap: bool,
+    /// Civil date of the first day
+    pub start: NaiveDate,
+}
+
+/// Computes the date of Easter Sunday in the Gregorian calendar.
+///
+/// Uses the anonymous Gregorian algorithm (Meeus, *Astronomical
+/// Algorithms*, ch. 8): Easter is the first Sunday after the ecclesiastical
+/// full moon on or after March 21.
+///
+/// # Arguments
+/// * `year` - Gregorian year, 1583 or later
+///
+/// # Returns
+/// The date of Easter Sunday, between March 22 and April 25
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` for a year before 1583 or after
+/// 9999.
+///
+/// # Example
+/// ```
+/// use astro_math::calendar::easter;
+/// use chrono::NaiveDate;
+///
+/// assert_eq!(easter(2024).unwrap(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+/// assert_eq!(easter(2285).unwrap(), NaiveDate::from_ymd_opt(2285, 3, 22).unwrap());
+/// ```
+pub fn easter(year: i32) -> Result<NaiveDate> {
+    validate_range(year as f64, 1583.0, 9999.0, "year")?;
+    let a = year % 19;
+    let (b, c) = (year / 100, year % 100);
+    let (d, e) = (b / 4, b % 4);
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    Ok(NaiveDate::from_ymd_opt(year, month as u32, day as u32).expect("computus gives a valid date"))
+}
+
+/// Finds the instants of new moon in a time range.
+///
+/// New moon is taken as the instant the Moon's apparent geocentric
+/// ecliptic longitude equals the Sun's.
+///
+/// # Arguments
+/// * `start` - Start of the search
+/// * `end` - End of the search
+///
+/// # Returns
+/// Every new moon in the range, in order
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidDateTime)` if `end` is not after
+/// `start`.
+///
+/// # Example
+/// ```
+/// use astro_math::calendar::new_moons;
+/// use chrono::{TimeZone, Utc};
+///
+/// let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+/// assert_eq!(new_moons(start, end).unwrap().len(), 12);
+/// ```
+pub fn new_moons(start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<DateTime<Utc>>> {
+    // sin(λ☾ − λ☉) rises through zero at new moon and falls at full moon
+    let elongation = |t| (moon_ecliptic_longitude(t) - ecliptic_longitude_of_sun(t)).to_radians().sin();
+    let crossings = find_zeros(elongation, start, end, Duration::days(1), Duration::seconds(1))?;
+    Ok(crossings.into_iter().filter(|c| c.1 == Crossing::Rising).map(|c| c.0).collect())
+}
+
+/// Finds the instants the Sun's apparent longitude passes a multiple of
+/// 15°, the 24 solar terms.
+///
+/// # Arguments
+/// * `start` - Start of the search
+/// * `end` - End of the search
+///
+/// # Returns
+/// Each solar term in the range with its longitude in degrees (0, 15, …,
+/// 345), in order. Longitudes 0, 90, 180 and 270 are the March equinox,
+/// June solstice, September equinox and December solstice.
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidDateTime)` if `end` is not after
+/// `start`.
+///
+/// # Example
+/// ```
+/// use astro_math::calendar::solar_terms;
+/// use chrono::{TimeZone, Utc};
+///
+/// // The March equinox of 2024 was at 03:06 UTC on March 20
+/// let start = Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2024, 3, 25, 0, 0, 0).unwrap();
+/// let (equinox, longitude) = solar_terms(start, end).unwrap()[0];
+/// assert_eq!(longitude, 0);
+/// assert_eq!(equinox.format("%d %H:%M").to_string(), "20 03:06");
+/// ```
+pub fn solar_terms(start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<(DateTime<Utc>, u32)>> {
+    // sin(12λ) is zero at every multiple of 15°
+    let term = |t| (12.0 * ecliptic_longitude_of_sun(t).to_radians()).sin();
+    let crossings = find_zeros(term, start, end, Duration::days(1), Duration::seconds(1))?;
+    Ok(crossings
+        .into_iter()
+        .map(|(t, _)| {
+            let longitude = (ecliptic_longitude_of_sun(t) / 15.0).round() as u32 * 15;
+            (t, longitude % 360)
+        })
+        .collect())
+}
+
+/// Computes the months of a year of the Chinese calendar.
+///
+/// # Arguments
+/// * `year` - Gregorian year in which the Chinese year begins, 1901–2099
+///
+/// # Returns
+/// The year's 12 or 13 months, from the first day of the year (the Spring
+/// Festival) to the last month before the next one. A leap month has the
+/// number of the month before it and `leap` set.
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` for a year outside 1901–2099.
+///
+/// # Example
+/// ```
+/// use astro_math::calendar::chinese_months;
+/// use chrono::NaiveDate;
+///
+/// // 2023 had a leap second month
+/// let months = chinese_months(2023).unwrap();
+/// assert_eq!(months.len(), 13);
+/// let leap = months.iter().find(|m| m.leap).unwrap();
+/// assert_eq!(leap.month, 2);
+/// assert_eq!(leap.start, NaiveDate::from_ymd_opt(2023, 3, 22).unwrap());
+/// ```
+pub fn chinese_months(year: i32) -> Result<Vec<LunarMonth>> {
+    validate_range(year as f64, 1901.0, 2099.0, "year")?;
+    // From before the December solstice that precedes the year to after the
+    // one that ends the following winter
+    let start = Utc.with_ymd_and_hms(year - 1, 11, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(year + 2, 2, 1, 0, 0, 0).unwrap();
+    let china_date = |t: DateTime<Utc>| (t + Duration::hours(8)).date_naive();
+
+    let month_starts: Vec<NaiveDate> = new_moons(start, end)?.into_iter().map(china_date).collect();
+    let terms = solar_terms(start, end)?;
+    let major_terms: Vec<NaiveDate> =
+        terms.iter().filter(|(_, longitude)| longitude % 30 == 0).map(|&(t, _)| china_date(t)).collect();
+    let solstices: Vec<NaiveDate> =
+        terms.iter().filter(|(_, longitude)| *longitude == 270).map(|&(t, _)| china_date(t)).collect();
+
+    // Number the months between successive 11th months
+    let containing = |date: NaiveDate| month_starts.iter().rposition(|&s| s <= date).expect("window starts before");
+    let mut labels = vec![None; month_starts.len()];
+    for pair in solstices.windows(2) {
+        let (first, next) = (containing(pair[0]), containing(pair[1]));
+        let mut leap_pending = next - first == 13;
+        let mut number = 11;
+        labels[first] = Some((11, false));
+        for i in first + 1..next {
+            let has_major_term = major_terms.iter().any(|&d| month_starts[i] <= d && d < month_starts[i + 1]);
+            if leap_pending && !has_major_term {
+                leap_pending = false;
+                labels[i] = Some((number, true));
+            } else {
+                number = number % 12 + 1;
+                labels[i] = Some((number, false));
+            }
+        }
+    }
+
+    let is_first_month = |label: &Option<(u32, bool)>| *label == Some((1, false));
+    let first = labels.iter().position(is_first_month).expect("month 1 follows the first solstice");
+    let next = first + 1 + labels[first + 1..].iter().position(is_first_month).expect("and the second");
+    Ok((first..next)
+        .map(|i| {
+            let (month, leap) = labels[i].expect("labelled between the solstices");
+            LunarMonth { year, month, leap, start: month_starts[i] }
+        })
+        .collect())
+}
+
+/// Estimates the months of the Islamic calendar beginning in a date range.
+///
+/// A month begins the day after the first evening, on or after the day of
+/// conjunction, on which at Mecca the conjunction precedes sunset and the
+/// Moon sets after the Sun, the criterion of the Umm al-Qura calendar.
+///
+/// # Arguments
+/// * `start` - First date of the range
+/// * `end` - Last date of the range, inclusive
+///
+/// # Returns
+/// Each month whose first day falls in the range, numbered by Hijri year
+/// and month (1 = Muharram, 9 = Ramadan), in order
+///
+/// # Errors
+/// - `AstroError::OutOfRange` if either date is outside 1900–2100
+/// - `AstroError::InvalidDateTime` if `end` is before `start`
+///
+/// # Example
+/// ```
+/// use astro_math::calendar::islamic_months;
+/// use chrono::NaiveDate;
+///
+/// // Ramadan 1446 began on 2025 March 1
+/// let start = NaiveDate::from_ymd_opt(2025, 2, 15).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2025, 3, 15).unwrap();
+/// let ramadan = islamic_months(start, end).unwrap()[0];
+/// assert_eq!((ramadan.year, ramadan.month), (1446, 9));
+/// assert_eq!(ramadan.start, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+/// ```
+pub fn islamic_months(start: NaiveDate, end: NaiveDate) -> Result<Vec<LunarMonth>> {
+    validate_range(start.year() as f64, 1900.0, 2100.0, "year")?;
+    validate_range(end.year() as f64, 1900.0, 2100.0, "year")?;
+    if end < start {
+        return Err(AstroError::InvalidDateTime {
+            reason: format!("range end {} is before start {}", end, start),
+        });
+    }
+
+    // 1 Muharram 1446 followed the conjunction of 2024 July 5, 22:57 UTC
+    let reference = Utc.with_ymd_and_hms(2024, 7, 5, 22, 57, 0).unwrap();
+    let from = Utc.from_utc_datetime(&start.and_hms_opt(0, 0, 0).unwrap()) - Duration::days(3);
+    let to = Utc.from_utc_datetime(&end.and_hms_opt(0, 0, 0).unwrap());
+
+    let mut months = Vec::new();
+    for conjunction in new_moons(from, to)? {
+        // Mecca keeps UTC+3
+        let day = (conjunction + Duration::hours(3)).date_naive();
+        let first_day = if crescent_sets_after_sun(day, conjunction)? {
+            day + Duration::days(1)
+        } else {
+            day + Duration::days(2)
+        };
+        if first_day < start || first_day > end {
+            continue;
+        }
+        let lunations = ((conjunction - reference).num_seconds() as f64 / 86400.0 / SYNODIC_MONTH).round() as i32;
+        let index = 1445 * 12 + lunations;
+        months.push(LunarMonth {
+            year: index.div_euclid(12) + 1,
+            month: index.rem_euclid(12) as u32 + 1,
+            leap: false,
+            start: first_day,
+        });
+    }
+    Ok(months)
+}
+
+/// Computes the months of a year of the Hebrew calendar.
+///
+/// # Arguments
+/// * `year` - Year *anno mundi*, 1–9999 (5785 began in 2024)
+///
+/// # Returns
+/// The year's 12 or 13 months in order, numbered from Tishri = 1 to
+/// Elul = 12. In a leap year Adar I is the leap month, numbered 6 like the
+/// Adar (II) that follows it.
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` for a year outside 1–9999.
+///
+/// # Example
+/// ```
+/// use astro_math::calendar::hebrew_months;
+/// use chrono::NaiveDate;
+///
+/// // Rosh Hashanah 5785 and 1 Nisan, two weeks before Passover
+/// let months = hebrew_months(5784).unwrap();
+/// assert_eq!(months.len(), 13);
+/// assert_eq!(months[7].start, NaiveDate::from_ymd_opt(2024, 4, 9).unwrap());
+/// assert_eq!(hebrew_months(5785).unwrap()[0].start, NaiveDate::from_ymd_opt(2024, 10, 3).unwrap());
+/// ```
+pub fn hebrew_months(year: i32) -> Result<Vec<LunarMonth>> {
+    validate_range(year as f64, 1.0, 9999.0, "year")?;
+    let new_year = hebrew_new_year(year as i64);
+    let days = hebrew_new_year(year as i64 + 1) - new_year;
+    let leap_year = (7 * year as i64 + 1).rem_euclid(19) < 7;
+
+    // Heshvan and Kislev absorb the postponements: a 355- or 385-day year
+    // has a long Heshvan, a 353- or 383-day year a short Kislev
+    let heshvan = if days % 10 == 5 { 30 } else { 29 };
+    let kislev = if days % 10 == 3 { 29 } else { 30 };
+    let mut lengths = vec![(1, false, 30), (2, false, heshvan), (3, false, kislev), (4, false, 29), (5, false, 30)];
+    if leap_year {
+        lengths.push((6, true, 30));
+    }
+    lengths.extend([(6, false, 29), (7, false, 30), (8, false, 29), (9, false, 30)]);
+    lengths.extend([(10, false, 29), (11, false, 30), (12, false, 29)]);
+
+    let mut start = new_year;
+    Ok(lengths
+        .into_iter()
+        .map(|(month, leap, length)| {
+            let date = NaiveDate::from_num_days_from_ce_opt(start as i32).expect("year range is within chrono's");
+            start += length;
+            LunarMonth { year, month, leap, start: date }
+        })
+        .collect())
+}
+
+/// Day number of 1 Tishri of a Hebrew year, with 0001-01-01 as day 1
+/// (Reingold & Dershowitz, *Calendrical Calculations*).
+fn hebrew_new_year(year: i64) -> i64 {
+    let elapsed = hebrew_elapsed_days(year);
+    let correction = if hebrew_elapsed_days(year + 1) - elapsed == 356 {
+        2
+    } else if elapsed - hebrew_elapsed_days(year - 1) == 382 {
+        1
+    } else {
+        0
+    };
+    HEBREW_EPOCH + elapsed + correction
+}
+
+/// Days from the epoch to the molad of Tishri, postponed off Sunday,
+/// Wednesday and Friday.
+fn hebrew_elapsed_days(year: i64) -> i64 {
+    let months = (235 * year - 234).div_euclid(19);
+    // 1080 parts to the hour; the molad of AM 1 and the mean lunation of
+    // 29d 12h 793p
+    let parts = 12_084 + 13_753 * months;
+    let days = 29 * months + parts.div_euclid(25_920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+/// Whether, at sunset at Mecca on `day`, the conjunction has passed and the
+/// Moon is still above the horizon.
+fn crescent_sets_after_sun(day: NaiveDate, conjunction: DateTime<Utc>) -> Result<bool> {
+    let sunset = Night::starting_on(day, &MECCA)?.sunset()?.ok_or_else(|| AstroError::CalculationError {
+        calculation: "islamic_months",
+        reason: "no sunset at Mecca".to_string(),
+    })?;
+    if conjunction >= sunset {
+        return Ok(false);
+    }
+    let moon = apparent_place(AlmanacBody::Moon, sunset)?;
+    let (altitude, _) = ra_dec_to_alt_az(moon.ra, moon.dec, sunset, &MECCA)?;
+    Ok(altitude > moon_standard_altitude(moon.distance_au * AU_KM)?)
+}
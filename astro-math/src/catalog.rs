@@ -0,0 +1,465 @@
+//! Star catalogues behind one cone-search interface.
+//!
+//! Pointing-model fitting, plate solving and occultation prediction all
+//! start from "the stars near here, brighter than this". [`StarCatalog`]
+//! is that query, so the same code can run against any source:
+//!
+//! - [`BrightStars`] — the 58 navigational stars plus Mimosa and Castor,
+//!   embedded in the crate: Hipparcos (van Leeuwen 2007) positions at
+//!   J2000.0 and proper motions, to about an arcsecond. Enough to pick
+//!   pointing-model stars across the sky without any data files.
+//! - `GaiaCatalog` (feature `gaia`) — an extract of Gaia DR3
+//!   loaded from the archive's CSV output, or from a Parquet file with
+//!   feature `gaia-parquet`, for fields down to whatever magnitude the
+//!   extract goes.
+//! - A slice of [`Star`]s, for a list built in code.
+//!
+//! Queries are made on the catalogue positions at the catalogue epoch;
+//! [`Star::position_at`] applies the proper motion to another date.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::catalog::{BrightStars, StarCatalog};
+//!
+//! // Bright stars within 12° of the belt of Orion
+//! let stars = BrightStars.query_cone(83.0, -1.0, 12.0, 2.0).unwrap();
+//! let names: Vec<_> = stars.iter().map(|star| star.id.as_str()).collect();
+//! assert_eq!(names, ["Rigel", "Betelgeuse", "Bellatrix", "Alnilam"]);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range cone centre
+//! - `AstroError::OutOfRange` for a cone radius outside [0, 180] degrees
+//! - `AstroError::CalculationError` for a magnitude limit that is not finite
+//! - `AstroError::InvalidFormat` for a Gaia CSV or Parquet file that cannot
+//!   be read
+
+use crate::angles::separation;
+#[cfg(feature = "gaia")]
+use crate::error::AstroError;
+use crate::error::{validate_coordinate_safe, validate_dec, validate_finite, validate_ra, Result};
+use crate::simulate::CatalogStar;
+use crate::time::{julian_date, julian_epoch};
+use chrono::{DateTime, Utc};
+
+/// Milliarcseconds per degree
+const MAS_PER_DEG: f64 = 3_600_000.0;
+
+/// A catalogue star.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Star {
+    /// Name, or catalogue number such as a Gaia `source_id`
+    pub id: String,
+    /// ICRS right ascension in degrees at `epoch`
+    pub ra: f64,
+    /// ICRS declination in degrees at `epoch`
+    pub dec: f64,
+    /// Julian epoch of the position, e.g. 2000.0 or 2016.0 for Gaia DR3
+    pub epoch: f64,
+    /// Proper motion in RA × cos(Dec), mas/yr
+    pub pm_ra_cosdec: f64,
+    /// Proper motion in Dec, mas/yr
+    pub pm_dec: f64,
+    /// Magnitude: V for [`BrightStars`], G for Gaia
+    pub magnitude: f64,
+}
+
+impl Star {
+    /// Position at another date, moving the star along its proper motion.
+    ///
+    /// The motion is applied linearly, which is good to a milliarcsecond
+    /// over a few decades except very close to the poles.
+    ///
+    /// # Arguments
+    /// * `datetime` - Date of the position wanted
+    ///
+    /// # Returns
+    /// `(ra, dec)` in degrees, ICRS
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::catalog::{BrightStars, StarCatalog};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// // Arcturus moves 2.3″ a year
+    /// let arcturus = &BrightStars.query_cone(213.9, 19.2, 0.1, 1.0).unwrap()[0];
+    /// let (ra, dec) = arcturus.position_at(Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap());
+    /// assert!(((dec - arcturus.dec) * 3600.0 + 100.0).abs() < 0.1);
+    /// assert!(ra < arcturus.ra);
+    /// ```
+    pub fn position_at(&self, datetime: DateTime<Utc>) -> (f64, f64) {
+        let years = julian_epoch(julian_date(datetime)) - self.epoch;
+        let dec = self.dec + self.pm_dec * years / MAS_PER_DEG;
+        let ra = self.ra + self.pm_ra_cosdec * years / MAS_PER_DEG / self.dec.to_radians().cos();
+        (ra.rem_euclid(360.0), dec.clamp(-90.0, 90.0))
+    }
+}
+
+impl From<&Star> for CatalogStar {
+    fn from(star: &Star) -> Self {
+        CatalogStar { ra: star.ra, dec: star.dec, magnitude: star.magnitude }
+    }
+}
+
+/// A source of stars that can be searched by position and brightness.
+pub trait StarCatalog {
+    /// Returns the stars within a cone, brightest first.
+    ///
+    /// # Arguments
+    /// * `ra` - Right ascension of the cone centre in degrees (ICRS)
+    /// * `dec` - Declination of the cone centre in degrees (ICRS)
+    /// * `radius` - Cone radius in degrees, [0, 180]
+    /// * `mag_limit` - Faintest magnitude to return
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if the centre is out of range
+    /// - `AstroError::OutOfRange` if the radius is outside [0, 180]
+    /// - `AstroError::CalculationError` if `mag_limit` is not finite
+    fn query_cone(&self, ra: f64, dec: f64, radius: f64, mag_limit: f64) -> Result<Vec<Star>>;
+}
+
+/// Checks the arguments of [`StarCatalog::query_cone`].
+fn validate_cone(ra: f64, dec: f64, radius: f64, mag_limit: f64) -> Result<()> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    validate_coordinate_safe(radius, 0.0, 180.0, "cone radius")?;
+    validate_finite(mag_limit, "magnitude limit")
+}
+
+/// Keeps the stars inside the cone and sorts them brightest first.
+fn select<'a>(
+    stars: impl Iterator<Item = &'a Star>,
+    ra: f64,
+    dec: f64,
+    radius: f64,
+    mag_limit: f64,
+) -> Vec<Star> {
+    let mut found: Vec<Star> = stars
+        .filter(|star| star.magnitude <= mag_limit && separation(ra, dec, star.ra, star.dec) <= radius)
+        .cloned()
+        .collect();
+    found.sort_by(|a, b| a.magnitude.total_cmp(&b.magnitude));
+    found
+}
+
+impl StarCatalog for [Star] {
+    fn query_cone(&self, ra: f64, dec: f64, radius: f64, mag_limit: f64) -> Result<Vec<Star>> {
+        validate_cone(ra, dec, radius, mag_limit)?;
+        Ok(select(self.iter(), ra, dec, radius, mag_limit))
+    }
+}
+
+/// The bright stars embedded in the crate; see the [module documentation](self).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BrightStars;
+
+impl BrightStars {
+    /// All the embedded stars, in order of right ascension.
+    pub fn all() -> Vec<Star> {
+        BRIGHT_STARS
+            .iter()
+            .map(|&(id, ra, dec, pm_ra_cosdec, pm_dec, magnitude)| Star {
+                id: id.to_string(),
+                ra,
+                dec,
+                epoch: 2000.0,
+                pm_ra_cosdec,
+                pm_dec,
+                magnitude,
+            })
+            .collect()
+    }
+}
+
+impl StarCatalog for BrightStars {
+    fn query_cone(&self, ra: f64, dec: f64, radius: f64, mag_limit: f64) -> Result<Vec<Star>> {
+        validate_cone(ra, dec, radius, mag_limit)?;
+        Ok(select(BrightStars::all().iter(), ra, dec, radius, mag_limit))
+    }
+}
+
+/// Name, RA and Dec (J2000.0, degrees), proper motion in RA × cos(Dec) and
+/// Dec (mas/yr) and V magnitude of the navigational stars, Mimosa and Castor.
+#[rustfmt::skip]
+const BRIGHT_STARS: [(&str, f64, f64, f64, f64, f64); 60] = [
+    ("Alpheratz",        2.09652,  29.09043,   137.46,  -163.44,  2.06),
+    ("Ankaa",            6.57105, -42.30599,   232.76,  -353.64,  2.40),
+    ("Schedar",         10.12684,  56.53733,    50.88,   -32.13,  2.24),
+    ("Diphda",          10.89738, -17.98661,   232.55,    31.99,  2.04),
+    ("Achernar",        24.42852, -57.23675,    87.00,   -38.24,  0.46),
+    ("Hamal",           31.79336,  23.46242,   188.55,  -148.08,  2.01),
+    ("Polaris",         37.95456,  89.26411,    44.48,   -11.85,  1.98),
+    ("Acamar",          44.56531, -40.30468,   -52.89,    21.98,  2.88),
+    ("Menkar",          45.56989,   4.08974,   -10.41,   -76.85,  2.54),
+    ("Mirfak",          51.08071,  49.86118,    23.75,   -26.23,  1.79),
+    ("Aldebaran",       68.98016,  16.50930,    63.45,  -188.94,  0.86),
+    ("Rigel",           78.63447,  -8.20164,     1.31,     0.50,  0.13),
+    ("Capella",         79.17233,  45.99799,    75.25,  -426.89,  0.08),
+    ("Bellatrix",       81.28276,   6.34970,    -8.11,   -12.88,  1.64),
+    ("Elnath",          81.57297,  28.60745,    22.76,  -173.58,  1.65),
+    ("Alnilam",         84.05339,  -1.20192,     1.44,    -0.78,  1.69),
+    ("Betelgeuse",      88.79294,   7.40706,    27.54,    11.30,  0.42),
+    ("Canopus",         95.98796, -52.69566,    19.93,    23.24, -0.74),
+    ("Sirius",         101.28716, -16.71612,  -546.01, -1223.07, -1.46),
+    ("Adhara",         104.65645, -28.97209,     3.24,     1.33,  1.50),
+    ("Castor",         113.64947,  31.88828,  -191.45,  -145.19,  1.58),
+    ("Procyon",        114.82550,   5.22499,  -714.59, -1036.80,  0.37),
+    ("Pollux",         116.32896,  28.02620,  -626.55,   -45.80,  1.14),
+    ("Avior",          125.62848, -59.50948,   -25.52,    22.72,  1.86),
+    ("Suhail",         136.99899, -43.43259,   -23.21,    14.28,  2.21),
+    ("Miaplacidus",    138.29991, -69.71721,  -156.47,   108.95,  1.68),
+    ("Alphard",        141.89685,  -8.65860,   -15.23,    34.37,  1.98),
+    ("Regulus",        152.09296,  11.96721,  -248.73,     5.59,  1.40),
+    ("Dubhe",          165.93196,  61.75103,  -134.11,   -34.70,  1.79),
+    ("Denebola",       177.26491,  14.57206,  -497.68,  -114.67,  2.13),
+    ("Gienah",         183.95155, -17.54193,  -158.61,    21.86,  2.59),
+    ("Acrux",          186.64956, -63.09909,   -35.83,   -14.86,  0.76),
+    ("Gacrux",         187.79150, -57.11321,    28.23,  -265.08,  1.64),
+    ("Mimosa",         191.93029, -59.68877,   -42.97,   -16.18,  1.25),
+    ("Alioth",         193.50729,  55.95982,   111.91,    -8.24,  1.77),
+    ("Spica",          201.29825, -11.16132,   -42.35,   -30.67,  0.97),
+    ("Alkaid",         206.88516,  49.31327,  -121.17,   -14.91,  1.86),
+    ("Hadar",          210.95586, -60.37304,   -33.27,   -23.16,  0.61),
+    ("Menkent",        211.67061, -36.36996,  -519.29,  -517.87,  2.06),
+    ("Arcturus",       213.91530,  19.18241, -1093.39, -1999.40, -0.05),
+    ("Rigil Kentaurus", 219.90206, -60.83399, -3679.25,   473.67,  0.01),
+    ("Kochab",         222.67636,  74.15550,   -32.61,    11.42,  2.08),
+    ("Zubenelgenubi",  222.71964, -16.04178,  -105.68,   -68.40,  2.75),
+    ("Alphecca",       233.67195,  26.71469,   120.27,   -89.58,  2.23),
+    ("Antares",        247.35192, -26.43200,   -12.11,   -23.30,  1.06),
+    ("Atria",          252.16623, -69.02771,    17.99,   -31.58,  1.91),
+    ("Sabik",          257.59453, -15.72491,    40.13,    99.17,  2.43),
+    ("Shaula",         263.40217, -37.10382,    -8.53,   -30.80,  1.62),
+    ("Rasalhague",     263.73362,  12.56004,   108.07,  -221.57,  2.07),
+    ("Eltanin",        269.15154,  51.48890,    -8.48,   -22.79,  2.23),
+    ("Kaus Australis", 276.04299, -34.38462,   -39.42,  -124.20,  1.85),
+    ("Vega",           279.23473,  38.78369,   200.94,   286.23,  0.03),
+    ("Nunki",          283.81636, -26.29672,    15.14,   -53.43,  2.05),
+    ("Altair",         297.69583,   8.86832,   536.23,   385.29,  0.76),
+    ("Peacock",        306.41190, -56.73509,     6.90,   -86.02,  1.94),
+    ("Deneb",          310.35798,  45.28034,     2.01,     1.85,  1.25),
+    ("Enif",           326.04648,   9.87501,    26.92,     0.44,  2.38),
+    ("Alnair",         332.05827, -46.96097,   126.69,  -147.47,  1.74),
+    ("Fomalhaut",      344.41269, -29.62224,   328.95,  -164.67,  1.16),
+    ("Markab",         346.19022,  15.20527,    60.40,   -41.30,  2.48),
+];
+
+/// Stars from a Gaia DR3 extract, indexed by declination for cone searches.
+///
+/// Read from the CSV the Gaia archive returns for a query such as
+///
+/// ```sql
+/// SELECT source_id, ra, dec, pmra, pmdec, phot_g_mean_mag
+/// FROM gaiadr3.gaia_source WHERE phot_g_mean_mag < 12
+/// ```
+///
+/// or from a Parquet file with the same columns (feature `gaia-parquet`).
+/// Positions are at the DR3 epoch, J2016.0, and magnitudes are G.
+#[cfg(feature = "gaia")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GaiaCatalog {
+    /// Sorted by declination
+    stars: Vec<Star>,
+}
+
+#[cfg(feature = "gaia")]
+impl GaiaCatalog {
+    /// Reads a Gaia archive CSV export.
+    ///
+    /// The header row names the columns, which may come in any order and
+    /// alongside others: `source_id`, `ra`, `dec`, `pmra`, `pmdec` and
+    /// `phot_g_mean_mag` are used. Lines starting with `#` are comments.
+    /// Stars without proper motions (two-parameter solutions) get zero,
+    /// and stars without a G magnitude are skipped.
+    ///
+    /// # Arguments
+    /// * `text` - Contents of the CSV file
+    ///
+    /// # Errors
+    /// `AstroError::InvalidFormat` if a column is missing, or a row has the
+    /// wrong number of fields or a value that is not a number.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::catalog::{GaiaCatalog, StarCatalog};
+    ///
+    /// let csv = "source_id,ra,dec,pmra,pmdec,phot_g_mean_mag\n\
+    ///            2947050466531873024,101.28662,-16.72087,-546.05,-1223.14,8.52\n\
+    ///            2947050466531872896,101.30158,-16.73519,,,17.95\n";
+    /// let catalog = GaiaCatalog::from_csv_str(csv).unwrap();
+    /// assert_eq!(catalog.len(), 2);
+    /// assert_eq!(catalog.query_cone(101.29, -16.72, 0.05, 12.0).unwrap().len(), 1);
+    /// ```
+    pub fn from_csv_str(text: &str) -> Result<Self> {
+        let invalid = |input: &str, reason: String| AstroError::InvalidFormat {
+            format: "Gaia CSV",
+            input: input.to_string(),
+            reason,
+        };
+
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'));
+        let header = lines.next().ok_or_else(|| invalid("", "no header row".to_string()))?;
+        let names: Vec<&str> = header.split(',').map(|name| name.trim().trim_matches('"')).collect();
+        let column = |name: &str| {
+            names
+                .iter()
+                .position(|&n| n == name)
+                .ok_or_else(|| invalid(header, format!("no `{}` column", name)))
+        };
+        let [id, ra, dec, pmra, pmdec, mag] =
+            ["source_id", "ra", "dec", "pmra", "pmdec", "phot_g_mean_mag"].map(column);
+        let (id, ra, dec, pmra, pmdec, mag) = (id?, ra?, dec?, pmra?, pmdec?, mag?);
+
+        let mut stars = Vec::new();
+        for line in lines {
+            let fields: Vec<&str> = line.split(',').map(|field| field.trim().trim_matches('"')).collect();
+            if fields.len() != names.len() {
+                return Err(invalid(line, format!("{} fields, header has {}", fields.len(), names.len())));
+            }
+            let number = |index: usize| -> Result<Option<f64>> {
+                match fields[index] {
+                    "" => Ok(None),
+                    field => field
+                        .parse()
+                        .map(Some)
+                        .map_err(|_| invalid(line, format!("{} `{}` is not a number", names[index], field))),
+                }
+            };
+            let values = [number(ra)?, number(dec)?, number(pmra)?, number(pmdec)?, number(mag)?];
+            stars.extend(gaia_star(fields[id].to_string(), values).map_err(|reason| invalid(line, reason))?);
+        }
+        Ok(GaiaCatalog::from_stars(stars))
+    }
+
+    /// Reads a Parquet file of Gaia sources.
+    ///
+    /// The columns are those of [`from_csv_str`](Self::from_csv_str), found
+    /// by name among any others, with the same handling of missing values.
+    /// Positions and proper motions may be stored as doubles or floats,
+    /// `source_id` as an integer or a string. Only these columns are
+    /// decoded. Files compressed with Snappy, Zstandard, gzip or LZ4 are
+    /// read.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the Parquet file
+    ///
+    /// # Errors
+    /// - `AstroError::CalculationError` if the file cannot be opened
+    /// - `AstroError::InvalidFormat` if it is not a Parquet file, a column is
+    ///   missing or a value is not a number
+    ///
+    /// # Example
+    /// ```no_run
+    /// use astro_math::catalog::{GaiaCatalog, StarCatalog};
+    ///
+    /// let catalog = GaiaCatalog::from_parquet_file("gaia_dr3_g12.parquet").unwrap();
+    /// let near_sirius = catalog.query_cone(101.29, -16.72, 0.5, 12.0).unwrap();
+    /// ```
+    #[cfg(feature = "gaia-parquet")]
+    pub fn from_parquet_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+        use parquet::record::Field;
+        use parquet::schema::types::Type;
+
+        let path = path.as_ref();
+        let invalid = |reason: String| AstroError::InvalidFormat {
+            format: "Gaia Parquet",
+            input: path.display().to_string(),
+            reason,
+        };
+
+        let file = std::fs::File::open(path).map_err(|e| AstroError::CalculationError {
+            calculation: "Gaia catalogue",
+            reason: format!("cannot read {}: {}", path.display(), e),
+        })?;
+        let reader = SerializedFileReader::new(file).map_err(|e| invalid(e.to_string()))?;
+
+        // Decode only the columns that are used
+        const COLUMNS: [&str; 6] = ["source_id", "ra", "dec", "pmra", "pmdec", "phot_g_mean_mag"];
+        let root = reader.metadata().file_metadata().schema_descr().root_schema();
+        let fields = COLUMNS.map(|name| root.get_fields().iter().find(|field| field.name() == name).cloned());
+        if let Some(missing) = COLUMNS.iter().zip(&fields).find_map(|(name, field)| field.is_none().then_some(name)) {
+            return Err(invalid(format!("no `{}` column", missing)));
+        }
+        let projection = Type::group_type_builder(root.name())
+            .with_fields(fields.into_iter().flatten().collect())
+            .build()
+            .map_err(|e| invalid(e.to_string()))?;
+
+        let mut stars = Vec::new();
+        for row in reader.get_row_iter(Some(projection)).map_err(|e| invalid(e.to_string()))? {
+            let row = row.map_err(|e| invalid(e.to_string()))?;
+            let mut id = String::new();
+            let mut values = [None; 5];
+            for (name, field) in row.get_column_iter() {
+                let Some(index) = COLUMNS.iter().position(|column| column == name) else {
+                    continue;
+                };
+                if index == 0 {
+                    id = match field {
+                        Field::Str(text) => text.clone(),
+                        other => other.to_string(),
+                    };
+                    continue;
+                }
+                values[index - 1] = match field {
+                    Field::Null => None,
+                    Field::Double(x) => Some(*x),
+                    Field::Float(x) => Some(*x as f64),
+                    Field::Long(x) => Some(*x as f64),
+                    Field::Int(x) => Some(*x as f64),
+                    other => return Err(invalid(format!("{} `{}` is not a number", name, other))),
+                };
+            }
+            stars.extend(gaia_star(id, values).map_err(invalid)?);
+        }
+        Ok(GaiaCatalog::from_stars(stars))
+    }
+
+    fn from_stars(mut stars: Vec<Star>) -> Self {
+        stars.sort_by(|a, b| a.dec.total_cmp(&b.dec));
+        GaiaCatalog { stars }
+    }
+
+    /// Number of stars in the catalogue.
+    pub fn len(&self) -> usize {
+        self.stars.len()
+    }
+
+    /// Whether the catalogue has no stars.
+    pub fn is_empty(&self) -> bool {
+        self.stars.is_empty()
+    }
+}
+
+/// A Gaia source from its `ra`, `dec`, `pmra`, `pmdec` and
+/// `phot_g_mean_mag`: `None` without a G magnitude, zero proper motion
+/// without an astrometric solution, and an error without a position.
+#[cfg(feature = "gaia")]
+fn gaia_star(id: String, [ra, dec, pmra, pmdec, mag]: [Option<f64>; 5]) -> std::result::Result<Option<Star>, String> {
+    let (Some(ra), Some(dec)) = (ra, dec) else {
+        return Err("no position".to_string());
+    };
+    Ok(mag.map(|magnitude| Star {
+        id,
+        ra,
+        dec,
+        epoch: 2016.0,
+        pm_ra_cosdec: pmra.unwrap_or(0.0),
+        pm_dec: pmdec.unwrap_or(0.0),
+        magnitude,
+    }))
+}
+
+#[cfg(feature = "gaia")]
+impl StarCatalog for GaiaCatalog {
+    fn query_cone(&self, ra: f64, dec: f64, radius: f64, mag_limit: f64) -> Result<Vec<Star>> {
+        validate_cone(ra, dec, radius, mag_limit)?;
+        // Only the declination band the cone spans needs a separation test
+        let start = self.stars.partition_point(|star| star.dec < dec - radius);
+        let end = self.stars.partition_point(|star| star.dec <= dec + radius);
+        Ok(select(self.stars[start..end].iter(), ra, dec, radius, mag_limit))
+    }
+}
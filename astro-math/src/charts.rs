@@ -0,0 +1,200 @@
+//! Text-art sky charts for terminals and logs.
+//!
+//! Console observatory controllers and CLI tools often have no graphics, but
+//! a glance at where things are in the sky is still useful. [`horizon_view`]
+//! draws the sky above the horizon as a grid of characters, with azimuth
+//! across (north at both edges, east a quarter of the way along) and
+//! altitude up, followed by a legend of positions:
+//!
+//! ```text
+//! 90│                                     │
+//!   │      V                              │
+//!   │                                     │
+//! 45│                                     │
+//!   │                   A                 │
+//!   │                                     │
+//!  0└─────────────────────────────────────┘
+//!    N        E        S        W        N
+//! V Vega     alt  74.1  az  59.6
+//! A Antares  alt  30.0  az 194.8
+//! ```
+//!
+//! Each object is marked by the first character of its label, or `+` where
+//! two objects share a cell. Altitudes are geometric (no refraction). Use
+//! [`ChartStyle::Ascii`] where the output may not be shown in UTF-8.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::charts::horizon_view;
+//! use astro_math::Location;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let site = Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 };
+//! let dt = Utc.with_ymd_and_hms(2024, 8, 4, 4, 0, 0).unwrap();
+//! let objects = [("Vega", 279.2347, 38.7837), ("Antares", 247.3519, -26.4320)];
+//! let chart = horizon_view(&objects, dt, &site, 37, 7).unwrap();
+//! println!("{}", chart);
+//! assert_eq!(chart.lines().count(), 7 + 1 + objects.len());
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::OutOfRange` for an out-of-range latitude or longitude, or
+//!   a chart narrower than 9 or shorter than 3 characters
+
+use crate::error::{validate_range, AstroError, Result};
+use crate::transforms::ra_dec_to_alt_az;
+use crate::Location;
+use chrono::{DateTime, Utc};
+use std::fmt::Write;
+
+/// Smallest plot width that fits the compass labels
+const MIN_WIDTH: usize = 9;
+
+/// Smallest plot height that fits the altitude labels
+const MIN_HEIGHT: usize = 3;
+
+/// Characters used to draw a chart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartStyle {
+    /// Box-drawing characters (`│`, `└`, `─`, `┘`)
+    #[default]
+    Unicode,
+    /// Plain ASCII (`|`, `+`, `-`), safe for any terminal or log
+    Ascii,
+}
+
+impl ChartStyle {
+    /// (vertical, bottom left, horizontal, bottom right)
+    fn frame(self) -> (char, char, char, char) {
+        match self {
+            ChartStyle::Unicode => ('│', '└', '─', '┘'),
+            ChartStyle::Ascii => ('|', '+', '-', '+'),
+        }
+    }
+}
+
+/// Draws a horizon chart of objects in Unicode.
+///
+/// # Arguments
+/// * `objects` - `(label, ra, dec)` of each object, coordinates in degrees
+/// * `datetime` - Time of observation (UTC)
+/// * `location` - Observer's location
+/// * `width` - Columns of the plot area, at least 9
+/// * `height` - Rows of the plot area, at least 3
+///
+/// # Returns
+/// The chart, `height + 1` lines of plot and compass labels followed by one
+/// legend line per object, each line ending in `\n`
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if any RA or Dec is out of range
+/// - `AstroError::OutOfRange` if the latitude or longitude is out of range,
+///   or the chart is smaller than 9 × 3
+pub fn horizon_view(
+    objects: &[(&str, f64, f64)],
+    datetime: DateTime<Utc>,
+    location: &Location,
+    width: usize,
+    height: usize,
+) -> Result<String> {
+    horizon_view_styled(objects, datetime, location, width, height, ChartStyle::Unicode)
+}
+
+/// Draws a horizon chart of objects in the given style.
+///
+/// See [`horizon_view`] for the arguments, layout and errors.
+///
+/// # Example
+/// ```
+/// use astro_math::charts::{horizon_view_styled, ChartStyle};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: 52.0, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// // Polaris sits due north at the observer's latitude
+/// let chart = horizon_view_styled(&[("Polaris", 37.95, 89.26)], dt, &site, 9, 3, ChartStyle::Ascii).unwrap();
+/// assert!(chart.is_ascii());
+/// let middle = chart.lines().nth(1).unwrap();
+/// assert!(middle.starts_with("  |P") || middle.ends_with("P|"));
+/// ```
+pub fn horizon_view_styled(
+    objects: &[(&str, f64, f64)],
+    datetime: DateTime<Utc>,
+    location: &Location,
+    width: usize,
+    height: usize,
+    style: ChartStyle,
+) -> Result<String> {
+    validate_range(location.latitude_deg, -90.0, 90.0, "latitude")?;
+    validate_range(location.longitude_deg, -180.0, 180.0, "longitude")?;
+    if width < MIN_WIDTH {
+        return Err(AstroError::OutOfRange {
+            parameter: "width",
+            value: width as f64,
+            min: MIN_WIDTH as f64,
+            max: f64::MAX,
+        });
+    }
+    if height < MIN_HEIGHT {
+        return Err(AstroError::OutOfRange {
+            parameter: "height",
+            value: height as f64,
+            min: MIN_HEIGHT as f64,
+            max: f64::MAX,
+        });
+    }
+
+    let mut grid = vec![vec![' '; width]; height];
+    let mut legend = Vec::with_capacity(objects.len());
+    for &(label, ra, dec) in objects {
+        let (alt, az) = ra_dec_to_alt_az(ra, dec, datetime, location)?;
+        let marker = label.chars().next().unwrap_or('*');
+        if alt >= 0.0 {
+            let row = ((90.0 - alt) / 90.0 * (height - 1) as f64).round() as usize;
+            let column = (az.rem_euclid(360.0) / 360.0 * (width - 1) as f64).round() as usize;
+            let cell = &mut grid[row.min(height - 1)][column.min(width - 1)];
+            *cell = if *cell == ' ' || *cell == marker { marker } else { '+' };
+        }
+        legend.push((marker, label, alt, az));
+    }
+
+    let (vertical, bottom_left, horizontal, bottom_right) = style.frame();
+    let mut chart = String::new();
+    for (row, cells) in grid.iter().enumerate() {
+        let axis = if row == 0 {
+            "90"
+        } else if row == (height - 1) / 2 && (height - 1).is_multiple_of(2) {
+            "45"
+        } else {
+            "  "
+        };
+        let line: String = cells.iter().collect();
+        // The bottom row is the horizon itself, drawn as the frame
+        if row == height - 1 {
+            let horizon: String = cells.iter().map(|&c| if c == ' ' { horizontal } else { c }).collect();
+            let _ = writeln!(chart, " 0{}{}{}", bottom_left, horizon, bottom_right);
+        } else {
+            let _ = writeln!(chart, "{}{}{}{}", axis, vertical, line, vertical);
+        }
+    }
+
+    let mut compass = vec![' '; width];
+    for (k, point) in ['N', 'E', 'S', 'W', 'N'].into_iter().enumerate() {
+        compass[(k * (width - 1) + 2) / 4] = point;
+    }
+    let _ = writeln!(chart, "   {}", compass.iter().collect::<String>().trim_end());
+
+    let label_width = legend.iter().map(|(_, label, _, _)| label.chars().count()).max().unwrap_or(0);
+    for (marker, label, alt, az) in legend {
+        let _ = write!(chart, "{} {:<w$}  alt {:5.1}  az {:5.1}", marker, label, alt, az, w = label_width);
+        if alt < 0.0 {
+            chart.push_str("  (below horizon)");
+        }
+        chart.push('\n');
+    }
+    Ok(chart)
+}
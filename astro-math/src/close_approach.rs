@@ -0,0 +1,320 @@
+//! Minimum orbit intersection distance (MOID) and close-approach scanning.
+//!
+//! Two complementary measures of how near a small body comes to the Earth
+//! (or to another body):
+//!
+//! - [`moid`] — the purely geometric minimum distance between two orbits,
+//!   independent of where the bodies actually are. An Earth MOID below
+//!   0.05 AU is one criterion for a Potentially Hazardous Asteroid.
+//! - [`find_close_approaches`] — actual encounters with the Earth over a time
+//!   range, found by propagating the body with an [`NBodyPropagator`].
+//!
+//! # Method
+//!
+//! The MOID is found by a global grid search over the eccentric anomalies of
+//! both orbits, followed by local refinement of every grid minimum. This is
+//! robust for the near-tangent and nearly-coplanar geometries where analytic
+//! root-finding methods are fragile.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::close_approach::moid;
+//! use astro_math::orbit::OrbitalElements;
+//!
+//! let circular = |a: f64, inc: f64| OrbitalElements {
+//!     epoch_jd_tdb: 2451545.0,
+//!     semi_major_axis_au: a,
+//!     eccentricity: 0.0,
+//!     inclination_deg: inc,
+//!     ascending_node_deg: 0.0,
+//!     argument_of_perihelion_deg: 0.0,
+//!     mean_anomaly_deg: 0.0,
+//! };
+//!
+//! let result = moid(&circular(1.0, 0.0), &circular(1.3, 0.0)).unwrap();
+//! assert!((result.distance_au - 0.3).abs() < 1e-9);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for invalid orbital elements, a non-positive
+//!   scan step, or a non-positive distance threshold
+//! - `AstroError::CalculationError` if orbit propagation fails
+
+use crate::error::{AstroError, Result};
+use crate::orbit::{true_anomaly_from_eccentric, OrbitalElements, StateVector};
+use crate::vec3::norm;
+use crate::propagator::NBodyPropagator;
+use std::f64::consts::TAU;
+
+/// Astronomical Unit in kilometers
+const AU_KM: f64 = 149597870.7;
+
+/// Grid resolution per orbit for the global MOID search.
+const MOID_GRID: usize = 180;
+
+/// Result of a MOID computation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Moid {
+    /// Minimum distance between the two orbits in AU
+    pub distance_au: f64,
+    /// True anomaly on the first orbit at the closest point, in degrees [0, 360)
+    pub true_anomaly_a_deg: f64,
+    /// True anomaly on the second orbit at the closest point, in degrees [0, 360)
+    pub true_anomaly_b_deg: f64,
+}
+
+/// A close approach between a propagated body and the Earth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CloseApproach {
+    /// Time of minimum distance as a TDB Julian Date
+    pub jd_tdb: f64,
+    /// Geocentric distance at closest approach in AU
+    pub distance_au: f64,
+    /// Speed relative to the Earth at closest approach in km/s
+    pub relative_speed_km_s: f64,
+}
+
+/// Computes the minimum orbit intersection distance between two orbits.
+///
+/// # Arguments
+/// * `elements_a`, `elements_b` - Heliocentric elliptical orbits
+///
+/// # Returns
+/// [`Moid`] with the distance and the true anomalies of the closest points
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if either set of elements is invalid.
+pub fn moid(elements_a: &OrbitalElements, elements_b: &OrbitalElements) -> Result<Moid> {
+    elements_a.validate()?;
+    elements_b.validate()?;
+
+    let distance = |ea: f64, eb: f64| {
+        let pa = elements_a.position_at_eccentric_anomaly(ea);
+        let pb = elements_b.position_at_eccentric_anomaly(eb);
+        norm([pa[0] - pb[0], pa[1] - pb[1], pa[2] - pb[2]])
+    };
+
+    // Global grid over both eccentric anomalies
+    let step = TAU / MOID_GRID as f64;
+    let points_a: Vec<[f64; 3]> =
+        (0..MOID_GRID).map(|i| elements_a.position_at_eccentric_anomaly(i as f64 * step)).collect();
+    let points_b: Vec<[f64; 3]> =
+        (0..MOID_GRID).map(|j| elements_b.position_at_eccentric_anomaly(j as f64 * step)).collect();
+    let grid: Vec<Vec<f64>> = points_a
+        .iter()
+        .map(|pa| {
+            points_b
+                .iter()
+                .map(|pb| norm([pa[0] - pb[0], pa[1] - pb[1], pa[2] - pb[2]]))
+                .collect()
+        })
+        .collect();
+
+    // Refine every local minimum of the periodic grid
+    let mut best = (f64::INFINITY, 0.0, 0.0);
+    for i in 0..MOID_GRID {
+        for j in 0..MOID_GRID {
+            let d = grid[i][j];
+            let is_minimum = (-1i32..=1).all(|di| {
+                (-1i32..=1).all(|dj| {
+                    let ni = (i as i32 + di).rem_euclid(MOID_GRID as i32) as usize;
+                    let nj = (j as i32 + dj).rem_euclid(MOID_GRID as i32) as usize;
+                    (di == 0 && dj == 0) || d <= grid[ni][nj]
+                })
+            });
+            if !is_minimum {
+                continue;
+            }
+
+            let refined = refine_minimum(&distance, i as f64 * step, j as f64 * step, step);
+            if refined.0 < best.0 {
+                best = refined;
+            }
+        }
+    }
+
+    let (distance_au, ea, eb) = best;
+    Ok(Moid {
+        distance_au,
+        true_anomaly_a_deg: true_anomaly_from_eccentric(ea, elements_a.eccentricity)
+            .to_degrees()
+            .rem_euclid(360.0),
+        true_anomaly_b_deg: true_anomaly_from_eccentric(eb, elements_b.eccentricity)
+            .to_degrees()
+            .rem_euclid(360.0),
+    })
+}
+
+/// Computes the MOID between an orbit and the Earth's osculating orbit at
+/// the orbit's epoch.
+///
+/// # Arguments
+/// * `elements` - Heliocentric elliptical orbit
+///
+/// # Returns
+/// [`Moid`] where orbit "a" is `elements` and orbit "b" is the Earth
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if the elements are invalid.
+pub fn earth_moid(elements: &OrbitalElements) -> Result<Moid> {
+    let earth = OrbitalElements::from_state_vector(&earth_state(elements.epoch_jd_tdb))?;
+    moid(elements, &earth)
+}
+
+/// Finds close approaches of a body to the Earth over a time range.
+///
+/// The body is propagated from `initial` and sampled every `step_days`;
+/// every local minimum of the geocentric distance that falls below
+/// `threshold_au` is then refined to the exact time of closest approach.
+/// The step should be short compared with the encounter duration (a day or
+/// less for fast NEO flybys).
+///
+/// # Arguments
+/// * `initial` - Heliocentric state of the body (ICRS axes)
+/// * `propagator` - Propagator used to advance the body
+/// * `start_jd`, `end_jd` - Scan range as TDB Julian Dates
+/// * `step_days` - Sampling step in days
+/// * `threshold_au` - Report only approaches closer than this distance
+///
+/// # Returns
+/// Close approaches in time order
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if `step_days` or `threshold_au` is
+/// not positive, or `Err(AstroError::CalculationError)` if propagation fails.
+pub fn find_close_approaches(
+    initial: &StateVector,
+    propagator: &NBodyPropagator,
+    start_jd: f64,
+    end_jd: f64,
+    step_days: f64,
+    threshold_au: f64,
+) -> Result<Vec<CloseApproach>> {
+    if !(threshold_au > 0.0 && threshold_au.is_finite()) {
+        return Err(AstroError::OutOfRange {
+            parameter: "threshold_au",
+            value: threshold_au,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+
+    let (t0, t1) = if start_jd <= end_jd { (start_jd, end_jd) } else { (end_jd, start_jd) };
+    let start = propagator.propagate(initial, t0)?;
+    let samples = propagator.sample(&start, t0, t1, step_days)?;
+    let distances: Vec<f64> = samples.iter().map(|s| geocentric(s).0).collect();
+
+    let mut approaches = Vec::new();
+    for k in 0..samples.len() {
+        let d = distances[k];
+        let before = if k > 0 { distances[k - 1] } else { f64::INFINITY };
+        let after = distances.get(k + 1).copied().unwrap_or(f64::INFINITY);
+        if !(d <= before && d < after) {
+            continue;
+        }
+
+        // Golden-section search on the bracketing interval
+        let lo = if k > 0 { samples[k - 1].jd_tdb } else { samples[k].jd_tdb };
+        let hi = samples.get(k + 1).map_or(samples[k].jd_tdb, |s| s.jd_tdb);
+        let at = |jd: f64| propagator.propagate(&samples[k], jd);
+
+        let inv_phi = (5.0_f64.sqrt() - 1.0) / 2.0;
+        let (mut a, mut b) = (lo, hi);
+        let mut c = b - inv_phi * (b - a);
+        let mut e = a + inv_phi * (b - a);
+        let mut fc = geocentric(&at(c)?).0;
+        let mut fe = geocentric(&at(e)?).0;
+        // ~1 second in time
+        while b - a > 1e-5 {
+            if fc < fe {
+                b = e;
+                e = c;
+                fe = fc;
+                c = b - inv_phi * (b - a);
+                fc = geocentric(&at(c)?).0;
+            } else {
+                a = c;
+                c = e;
+                fc = fe;
+                e = a + inv_phi * (b - a);
+                fe = geocentric(&at(e)?).0;
+            }
+        }
+
+        let state = at((a + b) / 2.0)?;
+        let (distance_au, relative_speed) = geocentric(&state);
+        if distance_au < threshold_au {
+            approaches.push(CloseApproach {
+                jd_tdb: state.jd_tdb,
+                distance_au,
+                relative_speed_km_s: relative_speed * AU_KM / 86400.0,
+            });
+        }
+    }
+    Ok(approaches)
+}
+
+/// Heliocentric state of the Earth (ICRS axes) from ERFA.
+fn earth_state(jd_tdb: f64) -> StateVector {
+    let (earth_h, _) = crate::backend::ephemerides::Epv00(jd_tdb, 0.0);
+    StateVector {
+        jd_tdb,
+        position: [earth_h[0], earth_h[1], earth_h[2]],
+        velocity: [earth_h[3], earth_h[4], earth_h[5]],
+    }
+}
+
+/// Geocentric distance (AU) and relative speed (AU/day) of a heliocentric state.
+fn geocentric(state: &StateVector) -> (f64, f64) {
+    let earth = earth_state(state.jd_tdb);
+    let dp = [
+        state.position[0] - earth.position[0],
+        state.position[1] - earth.position[1],
+        state.position[2] - earth.position[2],
+    ];
+    let dv = [
+        state.velocity[0] - earth.velocity[0],
+        state.velocity[1] - earth.velocity[1],
+        state.velocity[2] - earth.velocity[2],
+    ];
+    (norm(dp), norm(dv))
+}
+
+/// Pattern search for a local minimum of `f` starting from `(x, y)`.
+fn refine_minimum<F>(f: &F, mut x: f64, mut y: f64, initial_step: f64) -> (f64, f64, f64)
+where
+    F: Fn(f64, f64) -> f64,
+{
+    const DIRECTIONS: [(f64, f64); 8] = [
+        (1.0, 0.0),
+        (-1.0, 0.0),
+        (0.0, 1.0),
+        (0.0, -1.0),
+        (1.0, 1.0),
+        (1.0, -1.0),
+        (-1.0, 1.0),
+        (-1.0, -1.0),
+    ];
+
+    let mut value = f(x, y);
+    let mut step = initial_step;
+    while step > 1e-12 {
+        let mut improved = false;
+        for (dx, dy) in DIRECTIONS {
+            let candidate = f(x + dx * step, y + dy * step);
+            if candidate < value {
+                value = candidate;
+                x += dx * step;
+                y += dy * step;
+                improved = true;
+                break;
+            }
+        }
+        if !improved {
+            step /= 2.0;
+        }
+    }
+    (value, x, y)
+}
@@ -0,0 +1,196 @@
+//! Crate-wide defaults, set once by the embedding application.
+//!
+//! Some conventions are the same for every call an application makes: the
+//! weather at its one site, the obliquity model its other software uses,
+//! whether an RA of 360° is a bug or just a wrapped angle. [`set_defaults`]
+//! stores a [`Config`] for the whole process instead of threading those
+//! choices through every call site:
+//!
+//! - [`Config::conditions`] — weather used for refraction when a call
+//!   passes none: `None` for the weather arguments of the `_erfa`
+//!   transforms, [`observed_place`](crate::observer::observed_place),
+//!   [`AstrometricContext::new`](crate::astrometric_context::AstrometricContext::new) and the
+//!   [`polar_alignment`](crate::polar_alignment) functions
+//! - [`Config::obliquity`] — model behind
+//!   [`mean_obliquity`](crate::nutation::mean_obliquity), and so the
+//!   [`ecliptic`](crate::ecliptic) conversions
+//! - [`Config::validation`] — whether RA and azimuth outside [0°, 360°) are
+//!   rejected or taken modulo 360°
+//!
+//! Arguments given to a call always win over the defaults. To change the
+//! defaults for a block of code on one thread only, as a test or a request
+//! handler might, use [`with_defaults`].
+//!
+//! The storage is a lock read on each use, so setting the defaults from
+//! one thread while others compute is safe; a calculation running at that
+//! moment sees either the old or the new defaults.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::config::{self, Config, ValidationPolicy};
+//! use astro_math::error::validate_ra;
+//! use astro_math::refraction::AtmosphericConditions;
+//!
+//! let site_weather = AtmosphericConditions { pressure_hpa: 780.0, temperature_c: 5.0, relative_humidity: 0.4 };
+//! let lenient = Config { conditions: Some(site_weather), validation: ValidationPolicy::Wrap, ..Config::default() };
+//!
+//! config::with_defaults(lenient, || {
+//!     assert_eq!(config::defaults().conditions, Some(site_weather));
+//!     assert!(validate_ra(365.0).is_ok());
+//! });
+//! assert!(validate_ra(365.0).is_err());
+//! ```
+//!
+//! # Error Handling
+//!
+//! Setting and reading the defaults never fails. [`ValidationPolicy::Wrap`]
+//! still rejects NaN and infinite angles.
+
+use crate::refraction::AtmosphericConditions;
+use std::cell::Cell;
+use std::sync::RwLock;
+
+/// Model of the mean obliquity of the ecliptic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObliquityModel {
+    /// IAU 2006 (Capitaine et al. 2003), ERFA `Obl06`
+    #[default]
+    Iau2006,
+    /// IAU 1976/1980 (Lieske et al. 1977), ERFA `Obl80`, for agreement with
+    /// older software and FK5-era catalogs
+    Iau1980,
+}
+
+/// How out-of-range angles are treated on input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValidationPolicy {
+    /// RA and azimuth outside [0°, 360°) are rejected with
+    /// `AstroError::InvalidCoordinate`
+    #[default]
+    Strict,
+    /// RA and azimuth of any finite value are accepted and taken modulo
+    /// 360°; declination, altitude and latitude are still checked
+    Wrap,
+}
+
+/// Crate-wide defaults; see the [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    /// Weather for refraction when a call gives none; `None` for no
+    /// refraction
+    pub conditions: Option<AtmosphericConditions>,
+    /// Model of the mean obliquity of the ecliptic
+    pub obliquity: ObliquityModel,
+    /// Treatment of out-of-range RA and azimuth
+    pub validation: ValidationPolicy,
+}
+
+impl Config {
+    /// The defaults the crate starts with: no refraction, IAU 2006
+    /// obliquity and strict validation.
+    pub const DEFAULT: Config =
+        Config { conditions: None, obliquity: ObliquityModel::Iau2006, validation: ValidationPolicy::Strict };
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+static DEFAULTS: RwLock<Config> = RwLock::new(Config::DEFAULT);
+
+thread_local! {
+    static THREAD_DEFAULTS: Cell<Option<Config>> = const { Cell::new(None) };
+}
+
+/// Sets the defaults for the whole process.
+///
+/// # Arguments
+/// * `config` - The new defaults
+///
+/// # Example
+/// ```
+/// use astro_math::config::{self, Config, ObliquityModel};
+///
+/// config::set_defaults(Config { obliquity: ObliquityModel::Iau1980, ..Config::default() });
+/// assert_eq!(config::defaults().obliquity, ObliquityModel::Iau1980);
+/// ```
+pub fn set_defaults(config: Config) {
+    // A panic while holding the lock cannot leave a `Copy` value half-written
+    *DEFAULTS.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = config;
+}
+
+/// The defaults in effect on this thread: those of an enclosing
+/// [`with_defaults`], otherwise those last passed to [`set_defaults`].
+pub fn defaults() -> Config {
+    THREAD_DEFAULTS
+        .with(Cell::get)
+        .unwrap_or_else(|| *DEFAULTS.read().unwrap_or_else(|poisoned| poisoned.into_inner()))
+}
+
+/// Runs `f` with `config` as the defaults on the calling thread.
+///
+/// Other threads, including the Rayon workers behind the crate's parallel
+/// batch functions, keep the process defaults. The previous defaults are
+/// restored when `f` returns or panics, and calls may be nested.
+///
+/// # Arguments
+/// * `config` - Defaults for the duration of `f`
+/// * `f` - Code to run
+///
+/// # Returns
+/// The value returned by `f`
+///
+/// # Example
+/// ```
+/// use astro_math::config::{self, Config, ObliquityModel};
+/// use astro_math::nutation::mean_obliquity;
+///
+/// let iau1980 = Config { obliquity: ObliquityModel::Iau1980, ..Config::default() };
+/// let eps80 = config::with_defaults(iau1980, || mean_obliquity(2460000.5));
+/// let eps06 = config::with_defaults(Config::default(), || mean_obliquity(2460000.5));
+/// // The two models differ by about 0.04″ today
+/// assert!((eps80 - eps06).abs() * 3600.0 < 0.1);
+/// ```
+pub fn with_defaults<R>(config: Config, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Config>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            THREAD_DEFAULTS.with(|cell| cell.set(self.0));
+        }
+    }
+
+    let _restore = Restore(THREAD_DEFAULTS.with(|cell| cell.replace(Some(config))));
+    f()
+}
+
+/// `conditions`, or the default weather if there are none.
+pub(crate) fn conditions_or_default(conditions: Option<AtmosphericConditions>) -> Option<AtmosphericConditions> {
+    conditions.or_else(|| defaults().conditions)
+}
+
+/// Weather given as separate optional values, with the default weather
+/// filled in when none of them is given.
+pub(crate) fn weather_or_default(
+    pressure_hpa: Option<f64>,
+    temperature_c: Option<f64>,
+    humidity: Option<f64>,
+) -> (Option<f64>, Option<f64>, Option<f64>) {
+    if pressure_hpa.is_some() || temperature_c.is_some() || humidity.is_some() {
+        return (pressure_hpa, temperature_c, humidity);
+    }
+    match defaults().conditions {
+        Some(c) => (Some(c.pressure_hpa), Some(c.temperature_c), Some(c.relative_humidity)),
+        None => (None, None, None),
+    }
+}
+
+/// Whether an out-of-range RA or azimuth is to be accepted.
+pub(crate) fn wraps_angles() -> bool {
+    defaults().validation == ValidationPolicy::Wrap
+}
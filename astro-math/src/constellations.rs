@@ -0,0 +1,930 @@
+//! Constellation lookup from equatorial coordinates.
+//!
+//! The IAU constellation boundaries were drawn by Delporte (1930) along
+//! lines of constant right ascension and declination of the B1875.0
+//! equator. [`constellation_at`] precesses a J2000.0 (ICRS) position to
+//! B1875.0 and finds the boundary zone containing it with the method of
+//! Roman (1987, PASP 99, 695): the zones are scanned from north to south
+//! and the first one whose lower declination limit is below the point and
+//! whose right ascension range contains it names the constellation. The
+//! zones are Roman's table as distributed by the CDS (catalogue VI/42). Every
+//! point on the sky falls in exactly one zone, so the lookup cannot fail
+//! for valid coordinates.
+//!
+//! Precession is the IAU 2006 model of [`crate::precession`]. Near a
+//! boundary, proper motion between the epoch of the coordinates and J2000.0
+//! can matter as much as the model; apply it first with
+//! [`crate::proper_motion`] for fast-moving stars.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::constellations::{constellation_at, Constellation};
+//!
+//! // Betelgeuse and Rigel
+//! assert_eq!(constellation_at(88.7929, 7.4071).unwrap(), Constellation::Orion);
+//! assert_eq!(constellation_at(78.6345, -8.2016).unwrap().abbreviation(), "Ori");
+//!
+//! // Plate-solve labels
+//! let m31 = constellation_at(10.6847, 41.2690).unwrap();
+//! assert_eq!(format!("M31 in {m31}"), "M31 in Andromeda");
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for RA outside [0, 360) or Dec outside [-90, 90]
+
+use crate::error::Result;
+use crate::precession::precess_from_j2000_two_part;
+use std::fmt;
+
+/// Julian Date of the B1875.0 epoch on which the boundaries are defined.
+pub const B1875_JD: f64 = 2_405_889.258_550_475;
+
+/// The 88 IAU constellations, in alphabetical order of their Latin names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Constellation {
+    /// And
+    Andromeda,
+    /// Ant
+    Antlia,
+    /// Aps
+    Apus,
+    /// Aqr
+    Aquarius,
+    /// Aql
+    Aquila,
+    /// Ara
+    Ara,
+    /// Ari
+    Aries,
+    /// Aur
+    Auriga,
+    /// Boo
+    Bootes,
+    /// Cae
+    Caelum,
+    /// Cam
+    Camelopardalis,
+    /// Cnc
+    Cancer,
+    /// CVn
+    CanesVenatici,
+    /// CMa
+    CanisMajor,
+    /// CMi
+    CanisMinor,
+    /// Cap
+    Capricornus,
+    /// Car
+    Carina,
+    /// Cas
+    Cassiopeia,
+    /// Cen
+    Centaurus,
+    /// Cep
+    Cepheus,
+    /// Cet
+    Cetus,
+    /// Cha
+    Chamaeleon,
+    /// Cir
+    Circinus,
+    /// Col
+    Columba,
+    /// Com
+    ComaBerenices,
+    /// CrA
+    CoronaAustralis,
+    /// CrB
+    CoronaBorealis,
+    /// Crv
+    Corvus,
+    /// Crt
+    Crater,
+    /// Cru
+    Crux,
+    /// Cyg
+    Cygnus,
+    /// Del
+    Delphinus,
+    /// Dor
+    Dorado,
+    /// Dra
+    Draco,
+    /// Equ
+    Equuleus,
+    /// Eri
+    Eridanus,
+    /// For
+    Fornax,
+    /// Gem
+    Gemini,
+    /// Gru
+    Grus,
+    /// Her
+    Hercules,
+    /// Hor
+    Horologium,
+    /// Hya
+    Hydra,
+    /// Hyi
+    Hydrus,
+    /// Ind
+    Indus,
+    /// Lac
+    Lacerta,
+    /// Leo
+    Leo,
+    /// LMi
+    LeoMinor,
+    /// Lep
+    Lepus,
+    /// Lib
+    Libra,
+    /// Lup
+    Lupus,
+    /// Lyn
+    Lynx,
+    /// Lyr
+    Lyra,
+    /// Men
+    Mensa,
+    /// Mic
+    Microscopium,
+    /// Mon
+    Monoceros,
+    /// Mus
+    Musca,
+    /// Nor
+    Norma,
+    /// Oct
+    Octans,
+    /// Oph
+    Ophiuchus,
+    /// Ori
+    Orion,
+    /// Pav
+    Pavo,
+    /// Peg
+    Pegasus,
+    /// Per
+    Perseus,
+    /// Phe
+    Phoenix,
+    /// Pic
+    Pictor,
+    /// Psc
+    Pisces,
+    /// PsA
+    PiscisAustrinus,
+    /// Pup
+    Puppis,
+    /// Pyx
+    Pyxis,
+    /// Ret
+    Reticulum,
+    /// Sge
+    Sagitta,
+    /// Sgr
+    Sagittarius,
+    /// Sco
+    Scorpius,
+    /// Scl
+    Sculptor,
+    /// Sct
+    Scutum,
+    /// Ser
+    Serpens,
+    /// Sex
+    Sextans,
+    /// Tau
+    Taurus,
+    /// Tel
+    Telescopium,
+    /// Tri
+    Triangulum,
+    /// TrA
+    TriangulumAustrale,
+    /// Tuc
+    Tucana,
+    /// UMa
+    UrsaMajor,
+    /// UMi
+    UrsaMinor,
+    /// Vel
+    Vela,
+    /// Vir
+    Virgo,
+    /// Vol
+    Volans,
+    /// Vul
+    Vulpecula,
+}
+
+impl Constellation {
+    /// Every constellation, in alphabetical order.
+    pub const ALL: [Constellation; 88] = [
+        Constellation::Andromeda,
+        Constellation::Antlia,
+        Constellation::Apus,
+        Constellation::Aquarius,
+        Constellation::Aquila,
+        Constellation::Ara,
+        Constellation::Aries,
+        Constellation::Auriga,
+        Constellation::Bootes,
+        Constellation::Caelum,
+        Constellation::Camelopardalis,
+        Constellation::Cancer,
+        Constellation::CanesVenatici,
+        Constellation::CanisMajor,
+        Constellation::CanisMinor,
+        Constellation::Capricornus,
+        Constellation::Carina,
+        Constellation::Cassiopeia,
+        Constellation::Centaurus,
+        Constellation::Cepheus,
+        Constellation::Cetus,
+        Constellation::Chamaeleon,
+        Constellation::Circinus,
+        Constellation::Columba,
+        Constellation::ComaBerenices,
+        Constellation::CoronaAustralis,
+        Constellation::CoronaBorealis,
+        Constellation::Corvus,
+        Constellation::Crater,
+        Constellation::Crux,
+        Constellation::Cygnus,
+        Constellation::Delphinus,
+        Constellation::Dorado,
+        Constellation::Draco,
+        Constellation::Equuleus,
+        Constellation::Eridanus,
+        Constellation::Fornax,
+        Constellation::Gemini,
+        Constellation::Grus,
+        Constellation::Hercules,
+        Constellation::Horologium,
+        Constellation::Hydra,
+        Constellation::Hydrus,
+        Constellation::Indus,
+        Constellation::Lacerta,
+        Constellation::Leo,
+        Constellation::LeoMinor,
+        Constellation::Lepus,
+        Constellation::Libra,
+        Constellation::Lupus,
+        Constellation::Lynx,
+        Constellation::Lyra,
+        Constellation::Mensa,
+        Constellation::Microscopium,
+        Constellation::Monoceros,
+        Constellation::Musca,
+        Constellation::Norma,
+        Constellation::Octans,
+        Constellation::Ophiuchus,
+        Constellation::Orion,
+        Constellation::Pavo,
+        Constellation::Pegasus,
+        Constellation::Perseus,
+        Constellation::Phoenix,
+        Constellation::Pictor,
+        Constellation::Pisces,
+        Constellation::PiscisAustrinus,
+        Constellation::Puppis,
+        Constellation::Pyxis,
+        Constellation::Reticulum,
+        Constellation::Sagitta,
+        Constellation::Sagittarius,
+        Constellation::Scorpius,
+        Constellation::Sculptor,
+        Constellation::Scutum,
+        Constellation::Serpens,
+        Constellation::Sextans,
+        Constellation::Taurus,
+        Constellation::Telescopium,
+        Constellation::Triangulum,
+        Constellation::TriangulumAustrale,
+        Constellation::Tucana,
+        Constellation::UrsaMajor,
+        Constellation::UrsaMinor,
+        Constellation::Vela,
+        Constellation::Virgo,
+        Constellation::Volans,
+        Constellation::Vulpecula,
+    ];
+
+    /// Three-letter IAU abbreviation, e.g. "UMa".
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Constellation::Andromeda => "And",
+            Constellation::Antlia => "Ant",
+            Constellation::Apus => "Aps",
+            Constellation::Aquarius => "Aqr",
+            Constellation::Aquila => "Aql",
+            Constellation::Ara => "Ara",
+            Constellation::Aries => "Ari",
+            Constellation::Auriga => "Aur",
+            Constellation::Bootes => "Boo",
+            Constellation::Caelum => "Cae",
+            Constellation::Camelopardalis => "Cam",
+            Constellation::Cancer => "Cnc",
+            Constellation::CanesVenatici => "CVn",
+            Constellation::CanisMajor => "CMa",
+            Constellation::CanisMinor => "CMi",
+            Constellation::Capricornus => "Cap",
+            Constellation::Carina => "Car",
+            Constellation::Cassiopeia => "Cas",
+            Constellation::Centaurus => "Cen",
+            Constellation::Cepheus => "Cep",
+            Constellation::Cetus => "Cet",
+            Constellation::Chamaeleon => "Cha",
+            Constellation::Circinus => "Cir",
+            Constellation::Columba => "Col",
+            Constellation::ComaBerenices => "Com",
+            Constellation::CoronaAustralis => "CrA",
+            Constellation::CoronaBorealis => "CrB",
+            Constellation::Corvus => "Crv",
+            Constellation::Crater => "Crt",
+            Constellation::Crux => "Cru",
+            Constellation::Cygnus => "Cyg",
+            Constellation::Delphinus => "Del",
+            Constellation::Dorado => "Dor",
+            Constellation::Draco => "Dra",
+            Constellation::Equuleus => "Equ",
+            Constellation::Eridanus => "Eri",
+            Constellation::Fornax => "For",
+            Constellation::Gemini => "Gem",
+            Constellation::Grus => "Gru",
+            Constellation::Hercules => "Her",
+            Constellation::Horologium => "Hor",
+            Constellation::Hydra => "Hya",
+            Constellation::Hydrus => "Hyi",
+            Constellation::Indus => "Ind",
+            Constellation::Lacerta => "Lac",
+            Constellation::Leo => "Leo",
+            Constellation::LeoMinor => "LMi",
+            Constellation::Lepus => "Lep",
+            Constellation::Libra => "Lib",
+            Constellation::Lupus => "Lup",
+            Constellation::Lynx => "Lyn",
+            Constellation::Lyra => "Lyr",
+            Constellation::Mensa => "Men",
+            Constellation::Microscopium => "Mic",
+            Constellation::Monoceros => "Mon",
+            Constellation::Musca => "Mus",
+            Constellation::Norma => "Nor",
+            Constellation::Octans => "Oct",
+            Constellation::Ophiuchus => "Oph",
+            Constellation::Orion => "Ori",
+            Constellation::Pavo => "Pav",
+            Constellation::Pegasus => "Peg",
+            Constellation::Perseus => "Per",
+            Constellation::Phoenix => "Phe",
+            Constellation::Pictor => "Pic",
+            Constellation::Pisces => "Psc",
+            Constellation::PiscisAustrinus => "PsA",
+            Constellation::Puppis => "Pup",
+            Constellation::Pyxis => "Pyx",
+            Constellation::Reticulum => "Ret",
+            Constellation::Sagitta => "Sge",
+            Constellation::Sagittarius => "Sgr",
+            Constellation::Scorpius => "Sco",
+            Constellation::Sculptor => "Scl",
+            Constellation::Scutum => "Sct",
+            Constellation::Serpens => "Ser",
+            Constellation::Sextans => "Sex",
+            Constellation::Taurus => "Tau",
+            Constellation::Telescopium => "Tel",
+            Constellation::Triangulum => "Tri",
+            Constellation::TriangulumAustrale => "TrA",
+            Constellation::Tucana => "Tuc",
+            Constellation::UrsaMajor => "UMa",
+            Constellation::UrsaMinor => "UMi",
+            Constellation::Vela => "Vel",
+            Constellation::Virgo => "Vir",
+            Constellation::Volans => "Vol",
+            Constellation::Vulpecula => "Vul",
+        }
+    }
+
+    /// Latin name, e.g. "Ursa Major".
+    pub fn name(self) -> &'static str {
+        match self {
+            Constellation::Andromeda => "Andromeda",
+            Constellation::Antlia => "Antlia",
+            Constellation::Apus => "Apus",
+            Constellation::Aquarius => "Aquarius",
+            Constellation::Aquila => "Aquila",
+            Constellation::Ara => "Ara",
+            Constellation::Aries => "Aries",
+            Constellation::Auriga => "Auriga",
+            Constellation::Bootes => "Boötes",
+            Constellation::Caelum => "Caelum",
+            Constellation::Camelopardalis => "Camelopardalis",
+            Constellation::Cancer => "Cancer",
+            Constellation::CanesVenatici => "Canes Venatici",
+            Constellation::CanisMajor => "Canis Major",
+            Constellation::CanisMinor => "Canis Minor",
+            Constellation::Capricornus => "Capricornus",
+            Constellation::Carina => "Carina",
+            Constellation::Cassiopeia => "Cassiopeia",
+            Constellation::Centaurus => "Centaurus",
+            Constellation::Cepheus => "Cepheus",
+            Constellation::Cetus => "Cetus",
+            Constellation::Chamaeleon => "Chamaeleon",
+            Constellation::Circinus => "Circinus",
+            Constellation::Columba => "Columba",
+            Constellation::ComaBerenices => "Coma Berenices",
+            Constellation::CoronaAustralis => "Corona Australis",
+            Constellation::CoronaBorealis => "Corona Borealis",
+            Constellation::Corvus => "Corvus",
+            Constellation::Crater => "Crater",
+            Constellation::Crux => "Crux",
+            Constellation::Cygnus => "Cygnus",
+            Constellation::Delphinus => "Delphinus",
+            Constellation::Dorado => "Dorado",
+            Constellation::Draco => "Draco",
+            Constellation::Equuleus => "Equuleus",
+            Constellation::Eridanus => "Eridanus",
+            Constellation::Fornax => "Fornax",
+            Constellation::Gemini => "Gemini",
+            Constellation::Grus => "Grus",
+            Constellation::Hercules => "Hercules",
+            Constellation::Horologium => "Horologium",
+            Constellation::Hydra => "Hydra",
+            Constellation::Hydrus => "Hydrus",
+            Constellation::Indus => "Indus",
+            Constellation::Lacerta => "Lacerta",
+            Constellation::Leo => "Leo",
+            Constellation::LeoMinor => "Leo Minor",
+            Constellation::Lepus => "Lepus",
+            Constellation::Libra => "Libra",
+            Constellation::Lupus => "Lupus",
+            Constellation::Lynx => "Lynx",
+            Constellation::Lyra => "Lyra",
+            Constellation::Mensa => "Mensa",
+            Constellation::Microscopium => "Microscopium",
+            Constellation::Monoceros => "Monoceros",
+            Constellation::Musca => "Musca",
+            Constellation::Norma => "Norma",
+            Constellation::Octans => "Octans",
+            Constellation::Ophiuchus => "Ophiuchus",
+            Constellation::Orion => "Orion",
+            Constellation::Pavo => "Pavo",
+            Constellation::Pegasus => "Pegasus",
+            Constellation::Perseus => "Perseus",
+            Constellation::Phoenix => "Phoenix",
+            Constellation::Pictor => "Pictor",
+            Constellation::Pisces => "Pisces",
+            Constellation::PiscisAustrinus => "Piscis Austrinus",
+            Constellation::Puppis => "Puppis",
+            Constellation::Pyxis => "Pyxis",
+            Constellation::Reticulum => "Reticulum",
+            Constellation::Sagitta => "Sagitta",
+            Constellation::Sagittarius => "Sagittarius",
+            Constellation::Scorpius => "Scorpius",
+            Constellation::Sculptor => "Sculptor",
+            Constellation::Scutum => "Scutum",
+            Constellation::Serpens => "Serpens",
+            Constellation::Sextans => "Sextans",
+            Constellation::Taurus => "Taurus",
+            Constellation::Telescopium => "Telescopium",
+            Constellation::Triangulum => "Triangulum",
+            Constellation::TriangulumAustrale => "Triangulum Australe",
+            Constellation::Tucana => "Tucana",
+            Constellation::UrsaMajor => "Ursa Major",
+            Constellation::UrsaMinor => "Ursa Minor",
+            Constellation::Vela => "Vela",
+            Constellation::Virgo => "Virgo",
+            Constellation::Volans => "Volans",
+            Constellation::Vulpecula => "Vulpecula",
+        }
+    }
+
+    /// Looks a constellation up by its IAU abbreviation, ignoring case.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::constellations::Constellation;
+    ///
+    /// assert_eq!(Constellation::from_abbreviation("cma"), Some(Constellation::CanisMajor));
+    /// assert_eq!(Constellation::from_abbreviation("Xyz"), None);
+    /// ```
+    pub fn from_abbreviation(abbreviation: &str) -> Option<Constellation> {
+        Constellation::ALL
+            .into_iter()
+            .find(|c| c.abbreviation().eq_ignore_ascii_case(abbreviation))
+    }
+}
+
+impl fmt::Display for Constellation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Returns the constellation containing a J2000.0 (ICRS) position.
+///
+/// # Arguments
+/// * `ra` - Right ascension in degrees (J2000.0)
+/// * `dec` - Declination in degrees (J2000.0)
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if:
+/// - `ra` is outside [0, 360)
+/// - `dec` is outside [-90, 90]
+///
+/// # Example
+/// ```
+/// use astro_math::constellations::{constellation_at, Constellation};
+///
+/// // Polaris and the south celestial pole
+/// assert_eq!(constellation_at(37.9546, 89.2641).unwrap(), Constellation::UrsaMinor);
+/// assert_eq!(constellation_at(0.0, -90.0).unwrap(), Constellation::Octans);
+/// ```
+pub fn constellation_at(ra: f64, dec: f64) -> Result<Constellation> {
+    let (ra_1875, dec_1875) = precess_from_j2000_two_part(ra, dec, B1875_JD, 0.0)?;
+    Ok(constellation_at_b1875(ra_1875, dec_1875))
+}
+
+/// Finds the boundary zone containing a B1875.0 position, in degrees.
+fn constellation_at_b1875(ra: f64, dec: f64) -> Constellation {
+    let ra_hours = (ra / 15.0).rem_euclid(24.0);
+    BOUNDARIES
+        .iter()
+        .find(|&&(ra_lo, ra_hi, dec_lo, _)| dec >= dec_lo && ra_hours >= ra_lo && ra_hours < ra_hi)
+        // Only a declination rounded below -90° misses the last zone
+        .map_or(Constellation::Octans, |zone| zone.3)
+}
+
+/// Boundary zones on the B1875.0 equator as (RA low, RA high, Dec low,
+/// constellation), RA in hours and Dec in degrees, sorted by decreasing
+/// Dec low. A point belongs to the first zone that contains it.
+///
+/// The 357 zones of Roman (1987), CDS catalogue VI/42 (`data.dat`).
+#[rustfmt::skip]
+pub(crate) const BOUNDARIES: [(f64, f64, f64, Constellation); 357] = {
+    use Constellation::*;
+    [
+        (0.0000, 24.0000, 88.0000, UrsaMinor),
+        (8.0000, 14.5000, 86.5000, UrsaMinor),
+        (21.0000, 23.0000, 86.1667, UrsaMinor),
+        (18.0000, 21.0000, 86.0000, UrsaMinor),
+        (0.0000, 8.0000, 85.0000, Cepheus),
+        (9.1667, 10.6667, 82.0000, Camelopardalis),
+        (0.0000, 5.0000, 80.0000, Cepheus),
+        (10.6667, 14.5000, 80.0000, Camelopardalis),
+        (17.5000, 18.0000, 80.0000, UrsaMinor),
+        (20.1667, 21.0000, 80.0000, Draco),
+        (0.0000, 3.5083, 77.0000, Cepheus),
+        (11.5000, 13.5833, 77.0000, Camelopardalis),
+        (16.5333, 17.5000, 75.0000, UrsaMinor),
+        (20.1667, 20.6667, 75.0000, Cepheus),
+        (7.9667, 9.1667, 73.5000, Camelopardalis),
+        (9.1667, 11.3333, 73.5000, Draco),
+        (13.0000, 16.5333, 70.0000, UrsaMinor),
+        (3.1000, 3.4167, 68.0000, Cassiopeia),
+        (20.4167, 20.6667, 67.0000, Draco),
+        (11.3333, 12.0000, 66.5000, Draco),
+        (0.0000, 0.3333, 66.0000, Cepheus),
+        (14.0000, 15.6667, 66.0000, UrsaMinor),
+        (23.5833, 24.0000, 66.0000, Cepheus),
+        (12.0000, 13.5000, 64.0000, Draco),
+        (13.5000, 14.4167, 63.0000, Draco),
+        (23.1667, 23.5833, 63.0000, Cepheus),
+        (6.1000, 7.0000, 62.0000, Camelopardalis),
+        (20.0000, 20.4167, 61.5000, Draco),
+        (20.5367, 20.6000, 60.9167, Cepheus),
+        (7.0000, 7.9667, 60.0000, Camelopardalis),
+        (7.9667, 8.4167, 60.0000, UrsaMajor),
+        (19.7667, 20.0000, 59.5000, Draco),
+        (20.0000, 20.5367, 59.5000, Cepheus),
+        (22.8667, 23.1667, 59.0833, Cepheus),
+        (0.0000, 2.4333, 58.5000, Cassiopeia),
+        (19.4167, 19.7667, 58.0000, Draco),
+        (1.7000, 1.9083, 57.5000, Cassiopeia),
+        (2.4333, 3.1000, 57.0000, Cassiopeia),
+        (3.1000, 3.1667, 57.0000, Camelopardalis),
+        (22.3167, 22.8667, 56.2500, Cepheus),
+        (5.0000, 6.1000, 56.0000, Camelopardalis),
+        (14.0333, 14.4167, 55.5000, UrsaMajor),
+        (14.4167, 19.4167, 55.5000, Draco),
+        (3.1667, 3.3333, 55.0000, Camelopardalis),
+        (22.1333, 22.3167, 55.0000, Cepheus),
+        (20.6000, 21.9667, 54.8333, Cepheus),
+        (0.0000, 1.7000, 54.0000, Cassiopeia),
+        (6.1000, 6.5000, 54.0000, Lynx),
+        (12.0833, 13.5000, 53.0000, UrsaMajor),
+        (15.2500, 15.7500, 53.0000, Draco),
+        (21.9667, 22.1333, 52.7500, Cepheus),
+        (3.3333, 5.0000, 52.5000, Camelopardalis),
+        (22.8667, 23.3333, 52.5000, Cassiopeia),
+        (15.7500, 17.0000, 51.5000, Draco),
+        (2.0417, 2.5167, 50.5000, Perseus),
+        (17.0000, 18.2333, 50.5000, Draco),
+        (0.0000, 1.3667, 50.0000, Cassiopeia),
+        (1.3667, 1.6667, 50.0000, Perseus),
+        (6.5000, 6.8000, 50.0000, Lynx),
+        (23.3333, 24.0000, 50.0000, Cassiopeia),
+        (13.5000, 14.0333, 48.5000, UrsaMajor),
+        (0.0000, 1.1167, 48.0000, Cassiopeia),
+        (23.5833, 24.0000, 48.0000, Cassiopeia),
+        (18.1750, 18.2333, 47.5000, Hercules),
+        (18.2333, 19.0833, 47.5000, Draco),
+        (19.0833, 19.1667, 47.5000, Cygnus),
+        (1.6667, 2.0417, 47.0000, Perseus),
+        (8.4167, 9.1667, 47.0000, UrsaMajor),
+        (0.1667, 0.8667, 46.0000, Cassiopeia),
+        (12.0000, 12.0833, 45.0000, UrsaMajor),
+        (6.8000, 7.3667, 44.5000, Lynx),
+        (21.9083, 21.9667, 44.0000, Cygnus),
+        (21.8750, 21.9083, 43.7500, Cygnus),
+        (19.1667, 19.4000, 43.5000, Cygnus),
+        (9.1667, 10.1667, 42.0000, UrsaMajor),
+        (10.1667, 10.7833, 40.0000, UrsaMajor),
+        (15.4333, 15.7500, 40.0000, Bootes),
+        (15.7500, 16.3333, 40.0000, Hercules),
+        (9.2500, 9.5833, 39.7500, Lynx),
+        (0.0000, 2.5167, 36.7500, Andromeda),
+        (2.5167, 2.5667, 36.7500, Perseus),
+        (19.3583, 19.4000, 36.5000, Lyra),
+        (4.5000, 4.6917, 36.0000, Perseus),
+        (21.7333, 21.8750, 36.0000, Cygnus),
+        (21.8750, 22.0000, 36.0000, Lacerta),
+        (6.5333, 7.3667, 35.5000, Auriga),
+        (7.3667, 7.7500, 35.5000, Lynx),
+        (0.0000, 2.0000, 35.0000, Andromeda),
+        (22.0000, 22.8167, 35.0000, Lacerta),
+        (22.8167, 22.8667, 34.5000, Lacerta),
+        (22.8667, 23.5000, 34.5000, Andromeda),
+        (2.5667, 2.7167, 34.0000, Perseus),
+        (10.7833, 11.0000, 34.0000, UrsaMajor),
+        (12.0000, 12.3333, 34.0000, CanesVenatici),
+        (7.7500, 9.2500, 33.5000, Lynx),
+        (9.2500, 9.8833, 33.5000, LeoMinor),
+        (0.7167, 1.4083, 33.0000, Andromeda),
+        (15.1833, 15.4333, 33.0000, Bootes),
+        (23.5000, 23.7500, 32.0833, Andromeda),
+        (12.3333, 13.2500, 32.0000, CanesVenatici),
+        (23.7500, 24.0000, 31.3333, Andromeda),
+        (13.9583, 14.0333, 30.7500, CanesVenatici),
+        (2.4167, 2.7167, 30.6667, Triangulum),
+        (2.7167, 4.5000, 30.6667, Perseus),
+        (4.5000, 4.7500, 30.0000, Auriga),
+        (18.1750, 19.3583, 30.0000, Lyra),
+        (11.0000, 12.0000, 29.0000, UrsaMajor),
+        (19.6667, 20.9167, 29.0000, Cygnus),
+        (4.7500, 5.8833, 28.5000, Auriga),
+        (9.8833, 10.5000, 28.5000, LeoMinor),
+        (13.2500, 13.9583, 28.5000, CanesVenatici),
+        (0.0000, 0.0667, 28.0000, Andromeda),
+        (1.4083, 1.6667, 28.0000, Triangulum),
+        (5.8833, 6.5333, 28.0000, Auriga),
+        (7.8833, 8.0000, 28.0000, Gemini),
+        (20.9167, 21.7333, 28.0000, Cygnus),
+        (19.2583, 19.6667, 27.5000, Cygnus),
+        (1.9167, 2.4167, 27.2500, Triangulum),
+        (16.1667, 16.3333, 27.0000, CoronaBorealis),
+        (15.0833, 15.1833, 26.0000, Bootes),
+        (15.1833, 16.1667, 26.0000, CoronaBorealis),
+        (18.3667, 18.8667, 26.0000, Lyra),
+        (10.7500, 11.0000, 25.5000, LeoMinor),
+        (18.8667, 19.2583, 25.5000, Lyra),
+        (1.6667, 1.9167, 25.0000, Triangulum),
+        (0.7167, 0.8500, 23.7500, Pisces),
+        (10.5000, 10.7500, 23.5000, LeoMinor),
+        (21.2500, 21.4167, 23.5000, Vulpecula),
+        (5.7000, 5.8833, 22.8333, Taurus),
+        (0.0667, 0.1417, 22.0000, Andromeda),
+        (15.9167, 16.0333, 22.0000, Serpens),
+        (5.8833, 6.2167, 21.5000, Gemini),
+        (19.8333, 20.2500, 21.2500, Vulpecula),
+        (18.8667, 19.2500, 21.0833, Vulpecula),
+        (0.1417, 0.8500, 21.0000, Andromeda),
+        (20.2500, 20.5667, 20.5000, Vulpecula),
+        (7.8083, 7.8833, 20.0000, Gemini),
+        (20.5667, 21.2500, 19.5000, Vulpecula),
+        (19.2500, 19.8333, 19.1667, Vulpecula),
+        (3.2833, 3.3667, 19.0000, Aries),
+        (18.8667, 19.0000, 18.5000, Sagitta),
+        (5.7000, 5.7667, 18.0000, Orion),
+        (6.2167, 6.3083, 17.5000, Gemini),
+        (19.0000, 19.8333, 16.1667, Sagitta),
+        (4.9667, 5.3333, 16.0000, Taurus),
+        (15.9167, 16.0833, 16.0000, Hercules),
+        (19.8333, 20.2500, 15.7500, Sagitta),
+        (4.6167, 4.9667, 15.5000, Taurus),
+        (5.3333, 5.6000, 15.5000, Taurus),
+        (12.8333, 13.5000, 15.0000, ComaBerenices),
+        (17.2500, 18.2500, 14.3333, Hercules),
+        (11.8667, 12.8333, 14.0000, ComaBerenices),
+        (7.5000, 7.8083, 13.5000, Gemini),
+        (16.7500, 17.2500, 12.8333, Hercules),
+        (0.0000, 0.1417, 12.5000, Pegasus),
+        (5.6000, 5.7667, 12.5000, Taurus),
+        (7.0000, 7.5000, 12.5000, Gemini),
+        (21.1167, 21.3333, 12.5000, Pegasus),
+        (6.3083, 6.9333, 12.0000, Gemini),
+        (18.2500, 18.8667, 12.0000, Hercules),
+        (20.8750, 21.0500, 11.8333, Delphinus),
+        (21.0500, 21.1167, 11.8333, Pegasus),
+        (11.5167, 11.8667, 11.0000, Leo),
+        (6.2417, 6.3083, 10.0000, Orion),
+        (6.9333, 7.0000, 10.0000, Gemini),
+        (7.8083, 7.9250, 10.0000, Cancer),
+        (23.8333, 24.0000, 10.0000, Pegasus),
+        (1.6667, 3.2833, 9.9167, Aries),
+        (20.1417, 20.3000, 8.5000, Delphinus),
+        (13.5000, 15.0833, 8.0000, Bootes),
+        (22.7500, 23.8333, 7.5000, Pegasus),
+        (7.9250, 9.2500, 7.0000, Cancer),
+        (9.2500, 10.7500, 7.0000, Leo),
+        (18.2500, 18.6622, 6.2500, Ophiuchus),
+        (18.6622, 18.8667, 6.2500, Aquila),
+        (20.8333, 20.8750, 6.0000, Delphinus),
+        (7.0000, 7.0167, 5.5000, CanisMinor),
+        (18.2500, 18.4250, 4.5000, Serpens),
+        (16.0833, 16.7500, 4.0000, Hercules),
+        (18.2500, 18.4250, 3.0000, Ophiuchus),
+        (21.4667, 21.6667, 2.7500, Pegasus),
+        (0.0000, 2.0000, 2.0000, Pisces),
+        (18.5833, 18.8667, 2.0000, Serpens),
+        (20.3000, 20.8333, 2.0000, Delphinus),
+        (20.8333, 21.3333, 2.0000, Equuleus),
+        (21.3333, 21.4667, 2.0000, Pegasus),
+        (22.0000, 22.7500, 2.0000, Pegasus),
+        (21.6667, 22.0000, 1.7500, Pegasus),
+        (7.0167, 7.2000, 1.5000, CanisMinor),
+        (3.5833, 4.6167, 0.0000, Taurus),
+        (4.6167, 4.6667, 0.0000, Orion),
+        (7.2000, 8.0833, 0.0000, CanisMinor),
+        (14.6667, 15.0833, 0.0000, Virgo),
+        (17.8333, 18.2500, 0.0000, Ophiuchus),
+        (2.6500, 3.2833, -1.7500, Cetus),
+        (3.2833, 3.5833, -1.7500, Taurus),
+        (15.0833, 16.2667, -3.2500, Serpens),
+        (4.6667, 5.0833, -4.0000, Orion),
+        (5.8333, 6.2417, -4.0000, Orion),
+        (17.8333, 17.9667, -4.0000, Serpens),
+        (18.2500, 18.5833, -4.0000, Serpens),
+        (18.5833, 18.8667, -4.0000, Aquila),
+        (22.7500, 23.8333, -4.0000, Pisces),
+        (10.7500, 11.5167, -6.0000, Leo),
+        (11.5167, 11.8333, -6.0000, Virgo),
+        (0.0000, 0.3333, -7.0000, Pisces),
+        (23.8333, 24.0000, -7.0000, Pisces),
+        (14.2500, 14.6667, -8.0000, Virgo),
+        (15.9167, 16.2667, -8.0000, Ophiuchus),
+        (20.0000, 20.5333, -9.0000, Aquila),
+        (21.3333, 21.8667, -9.0000, Aquarius),
+        (17.1667, 17.9667, -10.0000, Ophiuchus),
+        (5.8333, 8.0833, -11.0000, Monoceros),
+        (4.9167, 5.0833, -11.0000, Eridanus),
+        (5.0833, 5.8333, -11.0000, Orion),
+        (8.0833, 8.3667, -11.0000, Hydra),
+        (9.5833, 10.7500, -11.0000, Sextans),
+        (11.8333, 12.8333, -11.0000, Virgo),
+        (17.5833, 17.6667, -11.6667, Ophiuchus),
+        (18.8667, 20.0000, -12.0333, Aquila),
+        (4.8333, 4.9167, -14.5000, Eridanus),
+        (20.5333, 21.3333, -15.0000, Aquarius),
+        (17.1667, 18.2500, -16.0000, Serpens),
+        (18.2500, 18.8667, -16.0000, Scutum),
+        (8.3667, 8.5833, -17.0000, Hydra),
+        (16.2667, 16.3750, -18.2500, Ophiuchus),
+        (8.5833, 9.0833, -19.0000, Hydra),
+        (10.7500, 10.8333, -19.0000, Crater),
+        (16.2667, 16.3750, -19.2500, Scorpius),
+        (15.6667, 15.9167, -20.0000, Libra),
+        (12.5833, 12.8333, -22.0000, Corvus),
+        (12.8333, 14.2500, -22.0000, Virgo),
+        (9.0833, 9.7500, -24.0000, Hydra),
+        (1.6667, 2.6500, -24.3833, Cetus),
+        (2.6500, 3.7500, -24.3833, Eridanus),
+        (10.8333, 11.8333, -24.5000, Crater),
+        (11.8333, 12.5833, -24.5000, Corvus),
+        (14.2500, 14.9167, -24.5000, Libra),
+        (16.2667, 16.7500, -24.5833, Ophiuchus),
+        (0.0000, 1.6667, -25.5000, Cetus),
+        (21.3333, 21.8667, -25.5000, Capricornus),
+        (21.8667, 23.8333, -25.5000, Aquarius),
+        (23.8333, 24.0000, -25.5000, Cetus),
+        (9.7500, 10.2500, -26.5000, Hydra),
+        (4.7000, 4.8333, -27.2500, Eridanus),
+        (4.8333, 6.1167, -27.2500, Lepus),
+        (20.0000, 21.3333, -28.0000, Capricornus),
+        (10.2500, 10.5833, -29.1667, Hydra),
+        (12.5833, 14.9167, -29.5000, Hydra),
+        (14.9167, 15.6667, -29.5000, Libra),
+        (15.6667, 16.0000, -29.5000, Scorpius),
+        (4.5833, 4.7000, -30.0000, Eridanus),
+        (16.7500, 17.6000, -30.0000, Ophiuchus),
+        (17.6000, 17.8333, -30.0000, Sagittarius),
+        (10.5833, 10.8333, -31.1667, Hydra),
+        (6.1167, 7.3667, -33.0000, CanisMajor),
+        (12.2500, 12.5833, -33.0000, Hydra),
+        (10.8333, 12.2500, -35.0000, Hydra),
+        (3.5000, 3.7500, -36.0000, Fornax),
+        (8.3667, 9.3667, -36.7500, Pyxis),
+        (4.2667, 4.5833, -37.0000, Eridanus),
+        (17.8333, 19.1667, -37.0000, Sagittarius),
+        (21.3333, 23.0000, -37.0000, PiscisAustrinus),
+        (23.0000, 23.3333, -37.0000, Sculptor),
+        (3.0000, 3.5000, -39.5833, Fornax),
+        (9.3667, 11.0000, -39.7500, Antlia),
+        (0.0000, 1.6667, -40.0000, Sculptor),
+        (1.6667, 3.0000, -40.0000, Fornax),
+        (3.8667, 4.2667, -40.0000, Eridanus),
+        (23.3333, 24.0000, -40.0000, Sculptor),
+        (14.1667, 14.9167, -42.0000, Centaurus),
+        (15.6667, 16.0000, -42.0000, Lupus),
+        (16.0000, 16.4208, -42.0000, Scorpius),
+        (4.8333, 5.0000, -43.0000, Caelum),
+        (5.0000, 6.5833, -43.0000, Columba),
+        (8.0000, 8.3667, -43.0000, Puppis),
+        (3.4167, 3.8667, -44.0000, Eridanus),
+        (16.4208, 17.8333, -45.5000, Scorpius),
+        (17.8333, 19.1667, -45.5000, CoronaAustralis),
+        (19.1667, 20.3333, -45.5000, Sagittarius),
+        (20.3333, 21.3333, -45.5000, Microscopium),
+        (3.0000, 3.4167, -46.0000, Eridanus),
+        (4.5000, 4.8333, -46.5000, Caelum),
+        (15.3333, 15.6667, -48.0000, Lupus),
+        (0.0000, 2.3333, -48.1667, Phoenix),
+        (2.6667, 3.0000, -49.0000, Eridanus),
+        (4.0833, 4.2667, -49.0000, Horologium),
+        (4.2667, 4.5000, -49.0000, Caelum),
+        (21.3333, 22.0000, -50.0000, Grus),
+        (6.0000, 8.0000, -50.7500, Puppis),
+        (8.0000, 8.1667, -50.7500, Vela),
+        (2.4167, 2.6667, -51.0000, Eridanus),
+        (3.8333, 4.0833, -51.0000, Horologium),
+        (0.0000, 1.8333, -51.5000, Phoenix),
+        (6.0000, 6.1667, -52.5000, Carina),
+        (8.1667, 8.4500, -53.0000, Vela),
+        (3.5000, 3.8333, -53.1667, Horologium),
+        (3.8333, 4.0000, -53.1667, Dorado),
+        (0.0000, 1.5833, -53.5000, Phoenix),
+        (2.1667, 2.4167, -54.0000, Eridanus),
+        (4.5000, 5.0000, -54.0000, Pictor),
+        (15.0500, 15.3333, -54.0000, Lupus),
+        (8.4500, 8.8333, -54.5000, Vela),
+        (6.1667, 6.5000, -55.0000, Carina),
+        (11.8333, 12.8333, -55.0000, Centaurus),
+        (14.1667, 15.0500, -55.0000, Lupus),
+        (15.0500, 15.3333, -55.0000, Norma),
+        (4.0000, 4.3333, -56.5000, Dorado),
+        (8.8333, 11.0000, -56.5000, Vela),
+        (11.0000, 11.2500, -56.5000, Centaurus),
+        (17.5000, 18.0000, -57.0000, Ara),
+        (18.0000, 20.3333, -57.0000, Telescopium),
+        (22.0000, 23.3333, -57.0000, Grus),
+        (3.2000, 3.5000, -57.5000, Horologium),
+        (5.0000, 5.5000, -57.5000, Pictor),
+        (6.5000, 6.8333, -58.0000, Carina),
+        (0.0000, 1.3333, -58.5000, Phoenix),
+        (1.3333, 2.1667, -58.5000, Eridanus),
+        (23.3333, 24.0000, -58.5000, Phoenix),
+        (4.3333, 4.5833, -59.0000, Dorado),
+        (15.3333, 16.4208, -60.0000, Norma),
+        (20.3333, 21.3333, -60.0000, Indus),
+        (5.5000, 6.0000, -61.0000, Pictor),
+        (15.1667, 15.3333, -61.0000, Circinus),
+        (16.4208, 16.5833, -61.0000, Ara),
+        (14.9167, 15.1667, -63.5833, Circinus),
+        (16.5833, 16.7500, -63.5833, Ara),
+        (6.0000, 6.8333, -64.0000, Pictor),
+        (6.8333, 9.0333, -64.0000, Carina),
+        (11.2500, 11.8333, -64.0000, Centaurus),
+        (11.8333, 12.8333, -64.0000, Crux),
+        (12.8333, 14.5333, -64.0000, Centaurus),
+        (13.5000, 13.6667, -65.0000, Circinus),
+        (16.7500, 16.8333, -65.0000, Ara),
+        (2.1667, 3.2000, -67.5000, Horologium),
+        (3.2000, 4.5833, -67.5000, Reticulum),
+        (14.7500, 14.9167, -67.5000, Circinus),
+        (16.8333, 17.5000, -67.5000, Ara),
+        (17.5000, 18.0000, -67.5000, Pavo),
+        (22.0000, 23.3333, -67.5000, Tucana),
+        (4.5833, 6.5833, -70.0000, Dorado),
+        (13.6667, 14.7500, -70.0000, Circinus),
+        (14.7500, 17.0000, -70.0000, TriangulumAustrale),
+        (0.0000, 1.3333, -75.0000, Tucana),
+        (3.5000, 4.5833, -75.0000, Hydrus),
+        (6.5833, 9.0333, -75.0000, Volans),
+        (9.0333, 11.2500, -75.0000, Carina),
+        (11.2500, 13.6667, -75.0000, Musca),
+        (18.0000, 21.3333, -75.0000, Pavo),
+        (21.3333, 23.3333, -75.0000, Indus),
+        (23.3333, 24.0000, -75.0000, Tucana),
+        (0.7500, 1.3333, -76.0000, Tucana),
+        (0.0000, 3.5000, -82.5000, Hydrus),
+        (7.6667, 13.6667, -82.5000, Chamaeleon),
+        (13.6667, 18.0000, -82.5000, Apus),
+        (3.5000, 7.6667, -85.0000, Mensa),
+        (0.0000, 24.0000, -90.0000, Octans),
+    ]
+};
@@ -0,0 +1,525 @@
+//! Minor planet and comet designations, unpacked and MPC packed.
+//!
+//! The Minor Planet Center writes designations in two forms. The
+//! unpacked form is for people (`(433)`, `2024 AB1`, `C/1995 O1`). The
+//! packed form is a fixed-width code, used in the MPC's orbit files and
+//! 80-column observation records (`00433`, `K24A01B`, `CJ95O010`).
+//! [`Designation`] parses either form, prints the unpacked form through
+//! `Display`, and packs with [`Designation::pack`]. For one-off conversions
+//! between strings, use [`pack`] and [`unpack`].
+//!
+//! Supported designations, as described in the MPC's documentation of
+//! packed designations:
+//!
+//! | Kind | Unpacked | Packed |
+//! |------|----------|--------|
+//! | Numbered minor planet | `(433)`, `(100345)`, `(620000)` | `00433`, `A0345`, `~0000` |
+//! | Provisional minor planet | `2024 AB1`, `1995 XA` | `K24A01B`, `J95X00A` |
+//! | Palomar–Leiden and Trojan surveys | `2040 P-L`, `3138 T-1` | `PLS2040`, `T1S3138` |
+//! | Numbered comet | `1P`, `2I` | `0001P`, `0002I` |
+//! | Provisional comet | `C/1995 O1`, `P/1993 F2-B`, `P/2019 LD2` | `CJ95O010`, `PJ93F02b`, `PK19L02D` |
+//!
+//! Numbered comets may carry a name (`1P/Halley`), which is accepted and
+//! dropped. Packed numbers run to 15,396,335, the limit of the four-digit
+//! base-62 `~` form; provisional subscripts run to 619. The MPC's extended
+//! form for subscripts beyond 619 is not supported.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::designation::{pack, unpack, Designation};
+//!
+//! assert_eq!(pack("2024 AB1").unwrap(), "K24A01B");
+//! assert_eq!(unpack("K24A01B").unwrap(), "2024 AB1");
+//! assert_eq!(pack("(433)").unwrap(), "00433");
+//! assert_eq!(unpack("CJ95O010").unwrap(), "C/1995 O1");
+//!
+//! // Either form parses to the same designation
+//! let eros: Designation = "433".parse().unwrap();
+//! assert_eq!(eros, Designation::Numbered(433));
+//! assert_eq!(Designation::unpack("00433").unwrap(), eros);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidFormat` for a string that is not a designation
+//! - `AstroError::OutOfRange` for a number or year too large to pack
+
+use crate::error::{AstroError, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// First number written with a `~` and four base-62 digits
+const TILDE_BASE: u32 = 620_000;
+
+/// Largest number the packed form can hold
+const MAX_NUMBER: u32 = TILDE_BASE + 62 * 62 * 62 * 62 - 1;
+
+/// Largest provisional subscript the packed form can hold
+const MAX_SUBSCRIPT: u32 = 619;
+
+/// One of the Palomar–Leiden and Trojan surveys, whose designations carry
+/// the survey name instead of a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Survey {
+    /// Palomar–Leiden survey (1960), `P-L`
+    PalomarLeiden,
+    /// First Trojan survey (1971), `T-1`
+    Trojan1,
+    /// Second Trojan survey (1973), `T-2`
+    Trojan2,
+    /// Third Trojan survey (1977), `T-3`
+    Trojan3,
+}
+
+impl Survey {
+    const ALL: [Survey; 4] = [Survey::PalomarLeiden, Survey::Trojan1, Survey::Trojan2, Survey::Trojan3];
+
+    fn unpacked(self) -> &'static str {
+        match self {
+            Survey::PalomarLeiden => "P-L",
+            Survey::Trojan1 => "T-1",
+            Survey::Trojan2 => "T-2",
+            Survey::Trojan3 => "T-3",
+        }
+    }
+
+    fn packed(self) -> &'static str {
+        match self {
+            Survey::PalomarLeiden => "PLS",
+            Survey::Trojan1 => "T1S",
+            Survey::Trojan2 => "T2S",
+            Survey::Trojan3 => "T3S",
+        }
+    }
+}
+
+/// Orbit type of a comet, the letter before its designation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CometKind {
+    /// `P`: periodic, period under 200 years or observed at more than one
+    /// perihelion
+    Periodic,
+    /// `C`: non-periodic
+    NonPeriodic,
+    /// `D`: defunct or disappeared
+    Defunct,
+    /// `X`: no meaningful orbit
+    Uncertain,
+    /// `A`: first designated as a comet, found to be asteroidal
+    Asteroidal,
+    /// `I`: interstellar
+    Interstellar,
+}
+
+impl CometKind {
+    /// The letter of this kind, e.g. `'P'`.
+    pub fn letter(self) -> char {
+        match self {
+            CometKind::Periodic => 'P',
+            CometKind::NonPeriodic => 'C',
+            CometKind::Defunct => 'D',
+            CometKind::Uncertain => 'X',
+            CometKind::Asteroidal => 'A',
+            CometKind::Interstellar => 'I',
+        }
+    }
+
+    fn from_letter(letter: char) -> Option<CometKind> {
+        match letter {
+            'P' => Some(CometKind::Periodic),
+            'C' => Some(CometKind::NonPeriodic),
+            'D' => Some(CometKind::Defunct),
+            'X' => Some(CometKind::Uncertain),
+            'A' => Some(CometKind::Asteroidal),
+            'I' => Some(CometKind::Interstellar),
+            _ => None,
+        }
+    }
+}
+
+/// The date-based part of a provisional designation, e.g. `2024 AB1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProvisionalDesignation {
+    /// Year of discovery
+    pub year: u32,
+    /// Half-month of discovery, `A` (January 1–15) to `Y` (December
+    /// 16–31), skipping `I`
+    pub half_month: char,
+    /// Order within the half-month, `A` to `Z` skipping `I`; always present
+    /// for minor planets, usually absent for comets
+    pub letter: Option<char>,
+    /// Subscript: the number of times the letters have cycled for a minor
+    /// planet, or the order within the half-month for a comet
+    pub number: u32,
+}
+
+/// A minor planet or comet designation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Designation {
+    /// Numbered minor planet, e.g. `(433)`
+    Numbered(u32),
+    /// Provisional minor planet designation, e.g. `2024 AB1`
+    Provisional(ProvisionalDesignation),
+    /// Survey designation, e.g. `2040 P-L`
+    Survey {
+        /// Number within the survey
+        number: u32,
+        /// The survey
+        survey: Survey,
+    },
+    /// Numbered periodic or interstellar comet, e.g. `1P`
+    NumberedComet {
+        /// Periodic comet number
+        number: u32,
+        /// Orbit type: periodic, defunct or interstellar
+        kind: CometKind,
+    },
+    /// Provisional comet designation, e.g. `C/1995 O1` or `P/1993 F2-B`
+    ProvisionalComet {
+        /// Orbit type
+        kind: CometKind,
+        /// Date-based designation
+        designation: ProvisionalDesignation,
+        /// Fragment letter of a split comet, uppercase
+        fragment: Option<char>,
+    },
+}
+
+impl Designation {
+    /// Parses an MPC packed designation.
+    ///
+    /// # Arguments
+    /// * `packed` - Packed designation, e.g. `K24A01B`; surrounding spaces,
+    ///   as in fixed-width columns, are ignored
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidFormat)` if `packed` is not a packed
+    /// designation.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::designation::Designation;
+    ///
+    /// let ceres = Designation::unpack("00001").unwrap();
+    /// assert_eq!(ceres.to_string(), "(1)");
+    /// let comet = Designation::unpack("PJ93F02b").unwrap();
+    /// assert_eq!(comet.to_string(), "P/1993 F2-B");
+    /// ```
+    pub fn unpack(packed: &str) -> Result<Designation> {
+        let text = packed.trim();
+        let invalid = |reason: &str| invalid("packed designation", packed, reason);
+        let chars: Vec<char> = text.chars().collect();
+        match chars.len() {
+            5 if chars[4] == 'P' || chars[4] == 'D' || chars[4] == 'I' => {
+                let number = decimal(&chars[..4]).filter(|&n| n > 0).ok_or_else(|| invalid("bad comet number"))?;
+                let kind = CometKind::from_letter(chars[4]).unwrap();
+                Ok(Designation::NumberedComet { number, kind })
+            }
+            5 => {
+                let number = if chars[0] == '~' {
+                    chars[1..].iter().try_fold(0, |n, &c| base62(c).map(|d| n * 62 + d)).map(|n| n + TILDE_BASE)
+                } else {
+                    base62(chars[0]).zip(decimal(&chars[1..])).map(|(high, low)| high * 10_000 + low)
+                };
+                let number = number.filter(|&n| n > 0).ok_or_else(|| invalid("bad minor planet number"))?;
+                Ok(Designation::Numbered(number))
+            }
+            7 => {
+                let prefix: String = chars[..3].iter().collect();
+                if let Some(survey) = Survey::ALL.into_iter().find(|s| s.packed() == prefix) {
+                    let number = decimal(&chars[3..]).filter(|&n| n > 0).ok_or_else(|| invalid("bad survey number"))?;
+                    return Ok(Designation::Survey { number, survey });
+                }
+                let designation = unpack_provisional(&chars).ok_or_else(|| invalid("bad provisional designation"))?;
+                if designation.letter.is_none() {
+                    return Err(invalid("minor planet designation lacks its second letter"));
+                }
+                Ok(Designation::Provisional(designation))
+            }
+            8 => {
+                let kind = CometKind::from_letter(chars[0]).ok_or_else(|| invalid("unknown comet type"))?;
+                let last = chars[7];
+                let fragment = last.is_ascii_lowercase().then(|| last.to_ascii_uppercase());
+                let mut body = chars[1..].to_vec();
+                if fragment.is_some() {
+                    body[6] = '0';
+                }
+                let designation = unpack_provisional(&body).ok_or_else(|| invalid("bad provisional designation"))?;
+                Ok(Designation::ProvisionalComet { kind, designation, fragment })
+            }
+            _ => Err(invalid("expected 5, 7 or 8 characters")),
+        }
+    }
+
+    /// Writes the designation in MPC packed form.
+    ///
+    /// # Errors
+    /// - `AstroError::OutOfRange` if a number, subscript or year cannot be
+    ///   packed
+    /// - `AstroError::InvalidFormat` if a letter is not a valid half-month,
+    ///   order or fragment letter
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::designation::Designation;
+    ///
+    /// assert_eq!(Designation::Numbered(3_140_113).pack().unwrap(), "~AZaz");
+    /// ```
+    pub fn pack(&self) -> Result<String> {
+        match *self {
+            Designation::Numbered(number) => {
+                if !(1..=MAX_NUMBER).contains(&number) {
+                    return Err(out_of_range("number", number, 1, MAX_NUMBER));
+                }
+                Ok(if number >= TILDE_BASE {
+                    let n = number - TILDE_BASE;
+                    let digits: String = [n / 238_328, n / 3844 % 62, n / 62 % 62, n % 62].map(base62_char).iter().collect();
+                    format!("~{}", digits)
+                } else {
+                    format!("{}{:04}", base62_char(number / 10_000), number % 10_000)
+                })
+            }
+            Designation::Provisional(designation) => {
+                if designation.letter.is_none() {
+                    return Err(invalid("designation", &self.to_string(), "minor planet designation lacks its second letter"));
+                }
+                pack_provisional(&designation, self)
+            }
+            Designation::Survey { number, survey } => {
+                if !(1..=9999).contains(&number) {
+                    return Err(out_of_range("number", number, 1, 9999));
+                }
+                Ok(format!("{}{:04}", survey.packed(), number))
+            }
+            Designation::NumberedComet { number, kind } => {
+                if !(1..=9999).contains(&number) {
+                    return Err(out_of_range("number", number, 1, 9999));
+                }
+                if !matches!(kind, CometKind::Periodic | CometKind::Defunct | CometKind::Interstellar) {
+                    return Err(invalid("designation", &self.to_string(), "only P, D and I comets are numbered"));
+                }
+                Ok(format!("{:04}{}", number, kind.letter()))
+            }
+            Designation::ProvisionalComet { kind, designation, fragment } => {
+                let mut packed = pack_provisional(&designation, self)?;
+                if let Some(fragment) = fragment {
+                    if !fragment.is_ascii_uppercase() || designation.letter.is_some() {
+                        return Err(invalid("designation", &self.to_string(), "bad fragment letter"));
+                    }
+                    packed.pop();
+                    packed.push(fragment.to_ascii_lowercase());
+                }
+                Ok(format!("{}{}", kind.letter(), packed))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Designation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Designation::Numbered(number) => write!(f, "({})", number),
+            Designation::Provisional(designation) => write!(f, "{}", designation),
+            Designation::Survey { number, survey } => write!(f, "{} {}", number, survey.unpacked()),
+            Designation::NumberedComet { number, kind } => write!(f, "{}{}", number, kind.letter()),
+            Designation::ProvisionalComet { kind, designation, fragment } => {
+                write!(f, "{}/{}", kind.letter(), designation)?;
+                match fragment {
+                    Some(fragment) => write!(f, "-{}", fragment),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for ProvisionalDesignation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.year, self.half_month)?;
+        if let Some(letter) = self.letter {
+            write!(f, "{}", letter)?;
+        }
+        // A minor planet's subscript 0 is not written
+        if self.number > 0 || self.letter.is_none() {
+            write!(f, "{}", self.number)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Designation {
+    type Err = AstroError;
+
+    /// Parses an unpacked designation such as `(433)`, `2024 AB1`,
+    /// `2040 P-L`, `1P/Halley` or `C/1995 O1`.
+    fn from_str(s: &str) -> Result<Self> {
+        let text = s.trim();
+        let invalid = |reason: &str| invalid("designation", s, reason);
+
+        // Comets: "1P", "1P/Halley", "C/1995 O1", "P/1993 F2-B"
+        let (prefix, rest) = match text.split_once('/') {
+            Some((prefix, rest)) => (prefix.trim(), rest.trim()),
+            None => (text, ""),
+        };
+        if let Some(kind) = prefix.chars().last().and_then(CometKind::from_letter) {
+            let digits = &prefix[..prefix.len() - 1];
+            if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                if !matches!(kind, CometKind::Periodic | CometKind::Defunct | CometKind::Interstellar) {
+                    return Err(invalid("only P, D and I comets are numbered"));
+                }
+                let number = digits.parse().ok().filter(|&n| n > 0).ok_or_else(|| invalid("bad comet number"))?;
+                return Ok(Designation::NumberedComet { number, kind });
+            }
+            if digits.is_empty() && !rest.is_empty() {
+                let (body, fragment) = match rest.split_once('-') {
+                    Some((body, fragment)) => {
+                        let mut letters = fragment.chars();
+                        match (letters.next(), letters.next()) {
+                            (Some(c), None) if c.is_ascii_uppercase() => (body, Some(c)),
+                            _ => return Err(invalid("bad fragment letter")),
+                        }
+                    }
+                    None => (rest, None),
+                };
+                let designation = parse_provisional(body).ok_or_else(|| invalid("bad provisional designation"))?;
+                return Ok(Designation::ProvisionalComet { kind, designation, fragment });
+            }
+        }
+
+        // Numbered minor planets, with or without parentheses
+        let bare = text.strip_prefix('(').and_then(|t| t.strip_suffix(')')).unwrap_or(text);
+        if !bare.is_empty() && bare.chars().all(|c| c.is_ascii_digit()) {
+            let number = bare.parse().ok().filter(|&n| n > 0).ok_or_else(|| invalid("bad minor planet number"))?;
+            return Ok(Designation::Numbered(number));
+        }
+
+        // Surveys and provisional designations
+        if let Some((number, name)) = text.split_once(' ') {
+            if let Some(survey) = Survey::ALL.into_iter().find(|s| s.unpacked() == name.trim()) {
+                let number = number.parse().ok().filter(|&n| n > 0).ok_or_else(|| invalid("bad survey number"))?;
+                return Ok(Designation::Survey { number, survey });
+            }
+        }
+        match parse_provisional(text) {
+            Some(designation) if designation.letter.is_some() => Ok(Designation::Provisional(designation)),
+            _ => Err(invalid("not a recognised designation")),
+        }
+    }
+}
+
+/// Converts an unpacked designation to MPC packed form.
+///
+/// # Arguments
+/// * `designation` - Unpacked designation, e.g. `2024 AB1`
+///
+/// # Errors
+/// - `AstroError::InvalidFormat` if `designation` does not parse
+/// - `AstroError::OutOfRange` if it is too large to pack
+pub fn pack(designation: &str) -> Result<String> {
+    designation.parse::<Designation>()?.pack()
+}
+
+/// Converts an MPC packed designation to unpacked form.
+///
+/// # Arguments
+/// * `packed` - Packed designation, e.g. `K24A01B`
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidFormat)` if `packed` does not parse.
+pub fn unpack(packed: &str) -> Result<String> {
+    Ok(Designation::unpack(packed)?.to_string())
+}
+
+/// Parses the unpacked date-based part, e.g. `2024 AB1` or `1995 O1`.
+fn parse_provisional(text: &str) -> Option<ProvisionalDesignation> {
+    let (year, rest) = text.trim().split_once(' ')?;
+    if year.len() != 4 || !year.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut chars = rest.chars().peekable();
+    let half_month = chars.next().filter(|&c| is_half_month(c))?;
+    let letter = chars.next_if(|c| c.is_ascii_alphabetic());
+    if letter.is_some_and(|c| !is_order_letter(c)) {
+        return None;
+    }
+    let digits: String = chars.collect();
+    if !digits.chars().all(|c| c.is_ascii_digit()) || (digits.is_empty() && letter.is_none()) {
+        return None;
+    }
+    let number = if digits.is_empty() { 0 } else { digits.parse().ok()? };
+    Some(ProvisionalDesignation { year: year.parse().ok()?, half_month, letter, number })
+}
+
+/// Parses the seven packed characters of a provisional designation, with
+/// `'0'` in the last place when there is no second letter.
+fn unpack_provisional(chars: &[char]) -> Option<ProvisionalDesignation> {
+    let century = base62(chars[0]).filter(|c| (10..=35).contains(c))?;
+    let year = century * 100 + decimal(&chars[1..3])?;
+    let half_month = Some(chars[3]).filter(|&c| is_half_month(c))?;
+    let number = base62(chars[4])? * 10 + decimal(&chars[5..6])?;
+    let letter = match chars[6] {
+        '0' => None,
+        c if is_order_letter(c) => Some(c),
+        _ => return None,
+    };
+    Some(ProvisionalDesignation { year, half_month, letter, number })
+}
+
+fn pack_provisional(designation: &ProvisionalDesignation, whole: &Designation) -> Result<String> {
+    let ProvisionalDesignation { year, half_month, letter, number } = *designation;
+    if !(1000..=3599).contains(&year) {
+        return Err(out_of_range("year", year, 1000, 3599));
+    }
+    if number > MAX_SUBSCRIPT {
+        return Err(out_of_range("number", number, 0, MAX_SUBSCRIPT));
+    }
+    if !is_half_month(half_month) || letter.is_some_and(|c| !is_order_letter(c)) {
+        return Err(invalid("designation", &whole.to_string(), "bad half-month or order letter"));
+    }
+    Ok(format!(
+        "{}{:02}{}{}{}{}",
+        base62_char(year / 100),
+        year % 100,
+        half_month,
+        base62_char(number / 10),
+        number % 10,
+        letter.unwrap_or('0')
+    ))
+}
+
+/// Half-month letters run `A` to `Y` without `I`
+fn is_half_month(c: char) -> bool {
+    c.is_ascii_uppercase() && c != 'I' && c != 'Z'
+}
+
+/// Order letters run `A` to `Z` without `I`
+fn is_order_letter(c: char) -> bool {
+    c.is_ascii_uppercase() && c != 'I'
+}
+
+fn decimal(chars: &[char]) -> Option<u32> {
+    chars.iter().try_fold(0, |n, c| c.to_digit(10).map(|d| n * 10 + d))
+}
+
+/// Value of an MPC base-62 digit: `0`–`9`, then `A`–`Z`, then `a`–`z`
+fn base62(c: char) -> Option<u32> {
+    match c {
+        '0'..='9' => Some(c as u32 - '0' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32 + 10),
+        'a'..='z' => Some(c as u32 - 'a' as u32 + 36),
+        _ => None,
+    }
+}
+
+fn base62_char(value: u32) -> char {
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"[value as usize] as char
+}
+
+fn invalid(format: &'static str, input: &str, reason: &str) -> AstroError {
+    AstroError::InvalidFormat { format, input: input.to_string(), reason: reason.to_string() }
+}
+
+fn out_of_range(parameter: &'static str, value: u32, min: u32, max: u32) -> AstroError {
+    AstroError::OutOfRange { parameter, value: value as f64, min: min as f64, max: max as f64 }
+}
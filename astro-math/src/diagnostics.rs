@@ -0,0 +1,375 @@
+//! Self-validation for deployments.
+//!
+//! [`self_check`] runs a short battery of internal cross-validations and
+//! returns a [`Report`] that an application can log or display at startup.
+//! It is meant as a confidence check after installing on new hardware, a new
+//! compiler or a different backend feature: the checks take a fraction of
+//! a second and catch broken builds, miscompiled floating point and stale
+//! data rather than small model differences.
+//!
+//! The battery covers:
+//!
+//! - **Round trips** — RA/Dec ↔ Alt/Az, J2000 ↔ date precession,
+//!   equatorial ↔ galactic and sky ↔ pixel, each of which should return
+//!   its input to well under a milliarcsecond
+//! - **ERFA vs Meeus** — the IAU 2006 sidereal time against Meeus'
+//!   polynomial, the Meeus Alt/Az path against the full ERFA pipeline
+//!   (scaled by the error the crate itself estimates for that path), and
+//!   Bennett's refraction against Saemundsson's
+//! - **Leap seconds** — whether the built-in table is still known to be
+//!   complete; see [`leap_second_table_expires`]
+//! - **Earth orientation** — whether UT1−UTC and polar motion are
+//!   available. The crate takes both as zero, which is reported as a
+//!   warning with the resulting error.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::diagnostics::{self_check, Status};
+//!
+//! let report = self_check();
+//! println!("{}", report);
+//! assert!(report.status() != Status::Fail);
+//! ```
+//!
+//! # Error Handling
+//!
+//! [`self_check`] never fails. An error from a function under test is
+//! recorded as a failed check, with the error in its detail.
+
+use crate::accuracy::DUT1_SIGMA_SECONDS;
+use crate::angles::separation;
+use crate::error::Result;
+use crate::galactic::{equatorial_to_galactic, galactic_to_equatorial};
+use crate::location::Location;
+use crate::precession::{precess_from_j2000, precess_to_j2000};
+use crate::projection::TangentPlane;
+use crate::refraction::{refraction_bennett, refraction_saemundsson};
+use crate::sidereal::gmst_two_part;
+use crate::time::julian_date_two_part;
+use crate::time_scales::leap_second_table_expires;
+use crate::transforms::{alt_az_to_ra_dec, ra_dec_to_alt_az, ra_dec_to_alt_az_erfa, ra_dec_to_alt_az_with_accuracy};
+use chrono::{DateTime, Duration, Utc};
+use std::fmt;
+
+/// Largest acceptable round-trip error, in arcseconds
+const ROUND_TRIP_LIMIT_ARCSEC: f64 = 1e-3;
+
+/// Largest acceptable IAU 2006 vs Meeus sidereal time difference, in seconds
+const GMST_LIMIT_SECONDS: f64 = 0.05;
+
+/// Largest acceptable Meeus vs ERFA Alt/Az difference, in units of the
+/// 1-sigma error estimated for the Meeus path
+const ALT_AZ_LIMIT_SIGMA: f64 = 3.0;
+
+/// Meeus' stated agreement between Bennett's and Saemundsson's formulas
+/// above 5° altitude, in arcseconds
+const REFRACTION_LIMIT_ARCSEC: f64 = 4.0;
+
+/// Days before the leap-second table expires at which to start warning
+const LEAP_SECOND_WARNING_DAYS: i64 = 30;
+
+/// Outcome of a single check, ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Status {
+    /// Within limits
+    Pass,
+    /// Usable, but with reduced accuracy or soon-to-expire data
+    Warn,
+    /// Outside limits; results from the affected routines should not be trusted
+    Fail,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Pass => "PASS",
+            Status::Warn => "WARN",
+            Status::Fail => "FAIL",
+        })
+    }
+}
+
+/// Result of a single check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    /// Short name of what was checked
+    pub name: &'static str,
+    /// Outcome
+    pub status: Status,
+    /// Largest measured deviation, in `unit`; NaN if the check could not run
+    pub value: f64,
+    /// Threshold `value` is compared against, in `unit`
+    pub limit: f64,
+    /// Unit of `value` and `limit`
+    pub unit: &'static str,
+    /// Explanation, or the error if a function under test failed
+    pub detail: String,
+}
+
+/// Results of [`self_check`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// Time the checks were evaluated for
+    pub checked_at: DateTime<Utc>,
+    /// Crate version
+    pub version: &'static str,
+    /// Astrometry backend in use, `"erfa"` or `"pure-rust"`
+    pub backend: &'static str,
+    /// Individual checks, in the order they were run
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    /// Worst status of any check.
+    pub fn status(&self) -> Status {
+        self.checks.iter().map(|c| c.status).max().unwrap_or(Status::Pass)
+    }
+
+    /// Checks that did not pass.
+    pub fn problems(&self) -> impl Iterator<Item = &Check> {
+        self.checks.iter().filter(|c| c.status != Status::Pass)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "astro-math {} ({} backend) self-check at {}: {}",
+            self.version,
+            self.backend,
+            self.checked_at.format("%Y-%m-%d %H:%M:%S UTC"),
+            self.status()
+        )?;
+        for check in &self.checks {
+            writeln!(
+                f,
+                "  {} {:<32} {:>10.3e} {} (limit {:.3e}) {}",
+                check.status, check.name, check.value, check.unit, check.limit, check.detail
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs the self-check battery for the current time.
+///
+/// Equivalent to [`self_check_at`] with `Utc::now()`.
+///
+/// # Returns
+/// A report with one entry per check
+///
+/// # Example
+/// ```
+/// use astro_math::diagnostics::{self_check, Status};
+///
+/// let report = self_check();
+/// for check in report.problems() {
+///     eprintln!("{}: {} {}", check.status, check.name, check.detail);
+/// }
+/// assert!(report.checks.iter().all(|c| c.name != "GMST: IAU 2006 vs Meeus" || c.status == Status::Pass));
+/// ```
+pub fn self_check() -> Report {
+    self_check_at(Utc::now())
+}
+
+/// Runs the self-check battery for a given time.
+///
+/// The model comparisons are sampled over a few days around `datetime`,
+/// and the leap-second table is judged against its date.
+///
+/// # Arguments
+/// * `datetime` - Time to evaluate the checks for (UTC)
+///
+/// # Returns
+/// A report with one entry per check
+///
+/// # Example
+/// ```
+/// use astro_math::diagnostics::{self_check_at, Status};
+/// use chrono::{TimeZone, Utc};
+///
+/// let report = self_check_at(Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap());
+/// let leap = report.checks.iter().find(|c| c.name == "Leap-second table").unwrap();
+/// assert_eq!(leap.status, Status::Pass);
+/// ```
+pub fn self_check_at(datetime: DateTime<Utc>) -> Report {
+    let epochs: Vec<DateTime<Utc>> = (0..4).map(|k| datetime + Duration::hours(k * 31)).collect();
+    let checks = vec![
+        measure("RA/Dec <-> Alt/Az round trip", "arcsec", ROUND_TRIP_LIMIT_ARCSEC, || alt_az_round_trip(&epochs)),
+        measure("Precession round trip", "arcsec", ROUND_TRIP_LIMIT_ARCSEC, || precession_round_trip(&epochs)),
+        measure("Galactic round trip", "arcsec", ROUND_TRIP_LIMIT_ARCSEC, galactic_round_trip),
+        measure("Tangent-plane round trip", "arcsec", ROUND_TRIP_LIMIT_ARCSEC, projection_round_trip),
+        measure("GMST: IAU 2006 vs Meeus", "s", GMST_LIMIT_SECONDS, || gmst_difference(&epochs)),
+        measure("Alt/Az: Meeus vs ERFA", "sigma", ALT_AZ_LIMIT_SIGMA, || alt_az_difference(&epochs)),
+        measure("Refraction: Bennett vs Saemundsson", "arcsec", REFRACTION_LIMIT_ARCSEC, refraction_difference),
+        leap_second_check(datetime),
+        earth_orientation_check(),
+    ];
+    Report {
+        checked_at: datetime,
+        version: env!("CARGO_PKG_VERSION"),
+        backend: crate::backend::NAME,
+        checks,
+    }
+}
+
+/// Runs a numerical check, failing it if it exceeds `limit` or errors.
+fn measure<F>(name: &'static str, unit: &'static str, limit: f64, check: F) -> Check
+where
+    F: FnOnce() -> Result<f64>,
+{
+    let (status, value, detail) = match check() {
+        Ok(value) if value <= limit => (Status::Pass, value, String::new()),
+        Ok(value) => (Status::Fail, value, "exceeds limit".to_string()),
+        Err(e) => (Status::Fail, f64::NAN, e.to_string()),
+    };
+    Check { name, status, value, limit, unit, detail }
+}
+
+fn sites() -> [Location; 4] {
+    [
+        Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 },
+        Location { latitude_deg: -30.2407, longitude_deg: -70.7366, altitude_m: 2200.0 },
+        Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 46.0 },
+        Location { latitude_deg: 69.6, longitude_deg: 18.9, altitude_m: 0.0 },
+    ]
+}
+
+fn targets() -> Vec<(f64, f64)> {
+    let mut targets: Vec<(f64, f64)> =
+        (0..8).flat_map(|i| [-60.0, -30.0, 0.0, 30.0, 60.0].map(|dec| (i as f64 * 45.0 + 7.5, dec))).collect();
+    targets.extend([(37.95, 89.26), (317.2, -88.9)]);
+    targets
+}
+
+fn alt_az_round_trip(epochs: &[DateTime<Utc>]) -> Result<f64> {
+    let mut worst: f64 = 0.0;
+    for &dt in epochs {
+        for site in &sites() {
+            for &(ra, dec) in &targets() {
+                let (alt, az) = ra_dec_to_alt_az(ra, dec, dt, site)?;
+                // Azimuth is undefined at the zenith and nadir
+                if alt.abs() > 89.5 {
+                    continue;
+                }
+                let back = alt_az_to_ra_dec(alt, az, dt, site)?;
+                worst = worst.max(separation_arcsec((ra, dec), back));
+            }
+        }
+    }
+    Ok(worst)
+}
+
+fn precession_round_trip(epochs: &[DateTime<Utc>]) -> Result<f64> {
+    let mut worst: f64 = 0.0;
+    for &dt in epochs {
+        for &(ra, dec) in &targets() {
+            let (ra_date, dec_date) = precess_from_j2000(ra, dec, dt)?;
+            worst = worst.max(separation_arcsec((ra, dec), precess_to_j2000(ra_date, dec_date, dt)?));
+        }
+    }
+    Ok(worst)
+}
+
+fn galactic_round_trip() -> Result<f64> {
+    let mut worst: f64 = 0.0;
+    for &(ra, dec) in &targets() {
+        let (l, b) = equatorial_to_galactic(ra, dec)?;
+        worst = worst.max(separation_arcsec((ra, dec), galactic_to_equatorial(l, b)?));
+    }
+    Ok(worst)
+}
+
+fn projection_round_trip() -> Result<f64> {
+    let mut worst: f64 = 0.0;
+    for &(ra0, dec0) in &targets() {
+        let plane = TangentPlane::new(ra0, dec0, 1.5)?.with_reference_pixel(2048.0, 1536.0).with_rotation(23.0);
+        for (dx, dy) in [(0.3, 0.2), (-0.4, 0.1), (0.1, -0.5)] {
+            let ra = (ra0 + dx / dec0.to_radians().cos()).rem_euclid(360.0);
+            let dec = (dec0 + dy).clamp(-90.0, 90.0);
+            let (x, y) = plane.ra_dec_to_pixel(ra, dec)?;
+            worst = worst.max(separation_arcsec((ra, dec), plane.pixel_to_ra_dec(x, y)?));
+        }
+    }
+    Ok(worst)
+}
+
+/// Largest difference between the crate's IAU 2006 GMST and Meeus (12.4), in seconds of time
+fn gmst_difference(epochs: &[DateTime<Utc>]) -> Result<f64> {
+    let mut worst: f64 = 0.0;
+    for &dt in epochs {
+        let (jd1, jd2) = julian_date_two_part(dt);
+        let days = (jd1 - 2451545.0) + jd2;
+        let t = days / 36525.0;
+        let meeus_deg = 280.46061837 + 360.98564736629 * days + t * t * (0.000387933 - t / 38710000.0);
+        let difference_deg = (gmst_two_part(jd1, jd2) * 15.0 - meeus_deg + 180.0).rem_euclid(360.0) - 180.0;
+        worst = worst.max(difference_deg.abs() * 240.0);
+    }
+    Ok(worst)
+}
+
+/// Largest Meeus vs ERFA Alt/Az difference relative to the Meeus path's own error estimate
+fn alt_az_difference(epochs: &[DateTime<Utc>]) -> Result<f64> {
+    let mut worst: f64 = 0.0;
+    for &dt in epochs {
+        for site in &sites() {
+            for &(ra, dec) in &targets() {
+                let (alt, az, sigma) = ra_dec_to_alt_az_with_accuracy(ra, dec, dt, site)?;
+                let (erfa_alt, erfa_az) = ra_dec_to_alt_az_erfa(ra, dec, dt, site, None, None, None)?;
+                worst = worst.max(separation_arcsec((az, alt), (erfa_az, erfa_alt)) / sigma);
+            }
+        }
+    }
+    Ok(worst)
+}
+
+/// Largest difference between Bennett and Saemundsson refraction from 5° to 85°, in arcseconds
+fn refraction_difference() -> Result<f64> {
+    let mut worst: f64 = 0.0;
+    for step in 0..=80 {
+        let apparent = 5.0 + step as f64;
+        let bennett = refraction_bennett(apparent)?;
+        // Bennett takes the apparent altitude, Saemundsson the true one
+        let saemundsson = refraction_saemundsson(apparent - bennett, 1010.0, 10.0)?;
+        worst = worst.max((bennett - saemundsson).abs() * 3600.0);
+    }
+    Ok(worst)
+}
+
+fn leap_second_check(datetime: DateTime<Utc>) -> Check {
+    let expires = leap_second_table_expires();
+    let days_left = (expires - datetime.date_naive()).num_days();
+    let (status, detail) = if days_left < 0 {
+        (Status::Warn, format!("table expired {}; TAI-UTC may be off by a leap second", expires))
+    } else if days_left < LEAP_SECOND_WARNING_DAYS {
+        (Status::Warn, format!("table expires {}", expires))
+    } else {
+        (Status::Pass, format!("table valid until {}", expires))
+    };
+    Check {
+        name: "Leap-second table",
+        status,
+        value: days_left as f64,
+        limit: LEAP_SECOND_WARNING_DAYS as f64,
+        unit: "days left",
+        detail,
+    }
+}
+
+fn earth_orientation_check() -> Check {
+    Check {
+        name: "Earth orientation parameters",
+        status: Status::Warn,
+        value: DUT1_SIGMA_SECONDS,
+        limit: 0.0,
+        unit: "s",
+        detail: "not available; UT1-UTC and polar motion taken as zero (up to 13.5\" of hour angle)".to_string(),
+    }
+}
+
+/// On-sky separation of two (longitude, latitude) pairs in degrees, in arcseconds
+fn separation_arcsec(a: (f64, f64), b: (f64, f64)) -> f64 {
+    separation(a.0, a.1, b.0, b.1) * 3600.0
+}
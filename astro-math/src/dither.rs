@@ -0,0 +1,168 @@
+//! Dither patterns for image sequences.
+//!
+//! Dithering moves the telescope by a small amount between exposures so that
+//! hot pixels, column defects and fixed-pattern noise land on different parts
+//! of the sky and can be rejected when the frames are stacked. [`pattern`]
+//! generates the offsets and [`apply`] turns them into pointings.
+//!
+//! Offsets are `(ΔRA·cos(Dec), ΔDec)` in arcseconds, i.e. true angular
+//! displacements east and north, so a pattern has the same size on the sky
+//! at any declination. [`apply`] uses the tangent-plane offsets of
+//! [`offsets`](crate::offsets), which remain correct near the poles where a
+//! naive `ΔRA / cos(Dec)` blows up.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::dither::{self, DitherKind};
+//!
+//! // Eight 10″ spiral dithers around a target at Dec +75°
+//! let offsets = dither::pattern(DitherKind::Spiral, 10.0, 8).unwrap();
+//! let pointings = dither::apply(150.0, 75.0, &offsets).unwrap();
+//!
+//! assert!((pointings[0].0 - 150.0).abs() < 1e-9 && (pointings[0].1 - 75.0).abs() < 1e-9);
+//! // 10″ east at Dec +75° is 38.6″ of RA
+//! assert!(((pointings[1].0 - 150.0) * 3600.0 - 38.64).abs() < 0.01);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for a non-positive or non-finite scale
+//! - `AstroError::InvalidCoordinate` for an out-of-range base pointing
+
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::offsets::offset_position;
+
+/// Shape of a dither pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DitherKind {
+    /// Square spiral outward from the base pointing, one `scale` per step.
+    /// Every offset is distinct and consecutive offsets are one step apart.
+    Spiral,
+    /// Square grid with spacing `scale`, centred on the base pointing and
+    /// traversed row by row in alternating directions
+    Grid,
+    /// Uniformly distributed within ±`scale` on each axis. The same seed
+    /// always gives the same sequence.
+    Random {
+        /// Seed of the pseudo-random sequence
+        seed: u64,
+    },
+}
+
+/// Generates dither offsets.
+///
+/// # Arguments
+/// * `kind` - Pattern shape
+/// * `scale_arcsec` - Step size (spiral, grid) or maximum offset (random) in arcseconds
+/// * `n` - Number of offsets
+///
+/// # Returns
+/// `n` offsets `(ΔRA·cos(Dec), ΔDec)` in arcseconds. Spiral patterns start
+/// at (0, 0); grid patterns include it when `n` fills an odd-sided grid.
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if `scale_arcsec` is not positive and finite.
+///
+/// # Example
+/// ```
+/// use astro_math::dither::{pattern, DitherKind};
+///
+/// let grid = pattern(DitherKind::Grid, 5.0, 9).unwrap();
+/// assert_eq!(grid[0], (-5.0, -5.0));
+/// assert_eq!(grid[4], (0.0, 0.0));
+/// assert_eq!(grid[8], (5.0, 5.0));
+/// ```
+pub fn pattern(kind: DitherKind, scale_arcsec: f64, n: usize) -> Result<Vec<(f64, f64)>> {
+    if !(scale_arcsec > 0.0 && scale_arcsec.is_finite()) {
+        return Err(AstroError::OutOfRange {
+            parameter: "scale_arcsec",
+            value: scale_arcsec,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+
+    let offsets = match kind {
+        DitherKind::Spiral => spiral(n),
+        DitherKind::Grid => grid(n),
+        DitherKind::Random { seed } => {
+            let mut state = seed;
+            (0..n)
+                .map(|_| (2.0 * unit_random(&mut state) - 1.0, 2.0 * unit_random(&mut state) - 1.0))
+                .collect()
+        }
+    };
+    Ok(offsets.into_iter().map(|(x, y)| (x * scale_arcsec, y * scale_arcsec)).collect())
+}
+
+/// Applies dither offsets to a base pointing.
+///
+/// # Arguments
+/// * `ra`, `dec` - Base pointing in degrees
+/// * `offsets` - Offsets `(ΔRA·cos(Dec), ΔDec)` in arcseconds, e.g. from [`pattern`]
+///
+/// # Returns
+/// One `(ra, dec)` pointing in degrees per offset, RA in [0, 360)
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if `ra` or `dec` is out of range
+/// - `AstroError::OutOfRange` if an offset is not finite
+pub fn apply(ra: f64, dec: f64, offsets: &[(f64, f64)]) -> Result<Vec<(f64, f64)>> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    offsets
+        .iter()
+        .map(|&(dra, ddec)| offset_position(ra, dec, dra, ddec))
+        .collect()
+}
+
+/// Unit-step square spiral: (0,0), (1,0), (1,1), (0,1), (-1,1), (-1,0), ...
+fn spiral(n: usize) -> Vec<(f64, f64)> {
+    let mut points = Vec::with_capacity(n);
+    let (mut x, mut y) = (0i64, 0i64);
+    let (mut dx, mut dy) = (1i64, 0i64);
+    let mut leg_length = 1;
+    'outer: loop {
+        // Two legs of each length, turning left after each
+        for _ in 0..2 {
+            for _ in 0..leg_length {
+                if points.len() == n {
+                    break 'outer;
+                }
+                points.push((x as f64, y as f64));
+                x += dx;
+                y += dy;
+            }
+            (dx, dy) = (-dy, dx);
+        }
+        leg_length += 1;
+    }
+    points
+}
+
+/// Unit-spacing grid, centred on the origin, in serpentine order.
+fn grid(n: usize) -> Vec<(f64, f64)> {
+    let side = (n as f64).sqrt().ceil() as usize;
+    let centre = (side as f64 - 1.0) / 2.0;
+    let mut points = Vec::with_capacity(n);
+    for row in 0..side {
+        for k in 0..side {
+            let column = if row % 2 == 0 { k } else { side - 1 - k };
+            if points.len() < n {
+                points.push((column as f64 - centre, row as f64 - centre));
+            }
+        }
+    }
+    points
+}
+
+/// SplitMix64 mapped to [0, 1).
+fn unit_random(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
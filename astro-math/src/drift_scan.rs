@@ -0,0 +1,224 @@
+//! Geometry of drift scans with a fixed alt/az pointing.
+//!
+//! Transit radio telescopes, meridian cameras and drift-scanning CCDs keep
+//! still and let the sky carry objects through the beam. A fixed altitude
+//! and azimuth always looks at the same declination, while right ascension
+//! advances at the sidereal rate, so the sky covered over a time range is a
+//! strip along one declination. [`drift_scan_strip`] works out that strip:
+//! its declination, RA range, length on the sky and drift rate, and the
+//! pointing sampled through the scan. The strip's width in declination is
+//! the beam or detector width across the drift direction
+//! ([`DriftScanStrip::dec_range`]); its length on the sky shrinks as
+//! cos(Dec) towards the poles, and so does the drift rate.
+//!
+//! Positions are geometric (no refraction), as from [`alt_az_to_ra_dec`].
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::drift_scan::drift_scan_strip;
+//! use astro_math::Location;
+//! use chrono::{Duration, TimeZone, Utc};
+//!
+//! // A transit instrument pointing 20° south of the zenith on the meridian
+//! let site = Location { latitude_deg: 52.0, longitude_deg: 0.0, altitude_m: 0.0 };
+//! let t0 = Utc.with_ymd_and_hms(2024, 3, 1, 20, 0, 0).unwrap();
+//! let strip = drift_scan_strip(70.0, 180.0, t0, t0 + Duration::hours(2), &site, Duration::minutes(10)).unwrap();
+//!
+//! assert!((strip.dec - 32.0).abs() < 1e-9);
+//! assert!((strip.ra_span - 30.08).abs() < 0.01); // two sidereal hours
+//! assert_eq!(strip.track.len(), 13);
+//! // A 1° wide beam covers Dec 31.5° to 32.5°
+//! let (south, north) = strip.dec_range(1.0).unwrap();
+//! assert!((south - 31.5).abs() < 1e-9 && (north - 32.5).abs() < 1e-9);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range altitude or azimuth
+//! - `AstroError::OutOfRange` for a scan that ends before it starts, a
+//!   non-positive step or a negative or non-finite beam width
+
+use crate::error::{AstroError, Result};
+use crate::transforms::alt_az_to_ra_dec;
+use crate::Location;
+use chrono::{DateTime, Duration, Utc};
+
+/// Ratio of a mean solar day to a sidereal day
+const SIDEREAL_RATE: f64 = 1.002_737_909_35;
+
+/// Sidereal drift at the celestial equator, in arcseconds per second of time
+const EQUATORIAL_DRIFT_ARCSEC_PER_S: f64 = 15.0 * SIDEREAL_RATE;
+
+/// The strip of sky swept by a fixed pointing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriftScanStrip {
+    /// Declination of the strip's center line in degrees
+    pub dec: f64,
+    /// RA at the start of the scan in degrees, in [0, 360)
+    pub ra_start: f64,
+    /// RA at the end of the scan in degrees, in [0, 360)
+    pub ra_end: f64,
+    /// RA swept from start to end in degrees; over 360 for scans longer
+    /// than a sidereal day
+    pub ra_span: f64,
+    /// Length of the center line on the sky in degrees, `ra_span·cos(Dec)`
+    pub length: f64,
+    /// Rate at which the sky drifts through the pointing, in arcseconds of
+    /// great circle per second of time
+    pub drift_rate: f64,
+    /// `(time, ra, dec)` of the pointing at each step from the start, and at
+    /// the end of the scan
+    pub track: Vec<(DateTime<Utc>, f64, f64)>,
+}
+
+impl DriftScanStrip {
+    /// Declination limits of the strip for a beam or detector width.
+    ///
+    /// # Arguments
+    /// * `width_deg` - Full width across the drift direction, in degrees
+    ///
+    /// # Returns
+    /// `(south, north)` declination edges in degrees, clamped to ±90
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if `width_deg` is negative or
+    /// not finite.
+    pub fn dec_range(&self, width_deg: f64) -> Result<(f64, f64)> {
+        validate_width(width_deg)?;
+        Ok(((self.dec - width_deg / 2.0).max(-90.0), (self.dec + width_deg / 2.0).min(90.0)))
+    }
+
+    /// Whether a position passes through the beam during the scan.
+    ///
+    /// # Arguments
+    /// * `ra`, `dec` - Position in degrees
+    /// * `width_deg` - Full beam width across the drift direction, in degrees
+    ///
+    /// # Returns
+    /// `true` if `dec` is within the strip's [`dec_range`](Self::dec_range)
+    /// and `ra` is within the RA swept
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if `width_deg` is negative or
+    /// not finite.
+    pub fn contains(&self, ra: f64, dec: f64, width_deg: f64) -> Result<bool> {
+        let (south, north) = self.dec_range(width_deg)?;
+        let swept = self.ra_span >= 360.0 || (ra - self.ra_start).rem_euclid(360.0) <= self.ra_span;
+        Ok(swept && (south..=north).contains(&dec))
+    }
+
+    /// Time the strip's center line takes to drift through a beam.
+    ///
+    /// # Arguments
+    /// * `width_deg` - Beam width along the drift direction, in degrees
+    ///
+    /// # Returns
+    /// Seconds of time, or `None` at the poles where the sky does not drift
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if `width_deg` is negative or
+    /// not finite.
+    pub fn transit_duration(&self, width_deg: f64) -> Result<Option<f64>> {
+        validate_width(width_deg)?;
+        Ok((self.drift_rate > 0.0).then(|| width_deg * 3600.0 / self.drift_rate))
+    }
+}
+
+/// Computes the strip of sky a fixed alt/az pointing sweeps over a time
+/// range.
+///
+/// # Arguments
+/// * `altitude_deg` - Altitude of the pointing in degrees
+/// * `azimuth_deg` - Azimuth of the pointing in degrees, from north through
+///   east
+/// * `start`, `end` - Time range of the scan (UTC)
+/// * `location` - Observer's location
+/// * `step` - Interval between samples of the [`track`](DriftScanStrip::track)
+///
+/// # Returns
+/// The [`DriftScanStrip`]; `track` holds the pointing at `start`, every
+/// `step` after it and at `end`
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the altitude or azimuth is out of
+///   range
+/// - `AstroError::OutOfRange` if `end` is before `start` or `step` is not
+///   positive
+///
+/// # Example
+/// ```
+/// use astro_math::drift_scan::drift_scan_strip;
+/// use astro_math::Location;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// // Pointing at the zenith scans the declination equal to the latitude
+/// let site = Location { latitude_deg: -30.7, longitude_deg: 21.4, altitude_m: 1000.0 };
+/// let t0 = Utc.with_ymd_and_hms(2024, 5, 1, 18, 0, 0).unwrap();
+/// let strip = drift_scan_strip(90.0, 0.0, t0, t0 + Duration::minutes(30), &site, Duration::minutes(1)).unwrap();
+/// assert!((strip.dec + 30.7).abs() < 1e-9);
+/// // Sources at Dec −30.7° cross a 2° beam in about 9 minutes
+/// let seconds = strip.transit_duration(2.0).unwrap().unwrap();
+/// assert!((seconds / 60.0 - 9.3).abs() < 0.05);
+/// ```
+pub fn drift_scan_strip(
+    altitude_deg: f64,
+    azimuth_deg: f64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    location: &Location,
+    step: Duration,
+) -> Result<DriftScanStrip> {
+    if step <= Duration::zero() {
+        return Err(AstroError::OutOfRange {
+            parameter: "step",
+            value: step.num_milliseconds() as f64 / 1000.0,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+    let seconds = (end - start).num_milliseconds() as f64 / 1000.0;
+    if seconds < 0.0 {
+        return Err(AstroError::OutOfRange {
+            parameter: "scan duration",
+            value: seconds,
+            min: 0.0,
+            max: f64::MAX,
+        });
+    }
+
+    let mut track = Vec::new();
+    let mut time = start;
+    while time < end {
+        let (ra, dec) = alt_az_to_ra_dec(altitude_deg, azimuth_deg, time, location)?;
+        track.push((time, ra, dec));
+        time += step;
+    }
+    let (ra_end, dec) = alt_az_to_ra_dec(altitude_deg, azimuth_deg, end, location)?;
+    track.push((end, ra_end, dec));
+
+    let ra_span = seconds * EQUATORIAL_DRIFT_ARCSEC_PER_S / 3600.0;
+    // cos(±90°) is not exactly zero in floating point
+    let cos_dec = if dec.abs() >= 90.0 { 0.0 } else { dec.to_radians().cos() };
+    Ok(DriftScanStrip {
+        dec,
+        ra_start: track[0].1,
+        ra_end,
+        ra_span,
+        length: ra_span * cos_dec,
+        drift_rate: EQUATORIAL_DRIFT_ARCSEC_PER_S * cos_dec,
+        track,
+    })
+}
+
+fn validate_width(width_deg: f64) -> Result<()> {
+    if !(width_deg >= 0.0 && width_deg.is_finite()) {
+        return Err(AstroError::OutOfRange {
+            parameter: "width_deg",
+            value: width_deg,
+            min: 0.0,
+            max: f64::MAX,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,222 @@
+//! Earth's orbital state: distance, velocity and anomalies.
+//!
+//! Several corrections in the crate depend on where Earth is in its orbit:
+//! aberration and barycentric radial velocities on its velocity, solar
+//! irradiance and radiometric calibration on its distance from the Sun, and
+//! annual parallax on the Sun's longitude. This module exposes those
+//! quantities directly.
+//!
+//! Positions and velocities come from ERFA's `eraEpv00` (the pure-Rust port
+//! with the `pure-rust` feature), which is accurate to a few kilometers.
+//! They are available relative to the Sun (heliocentric) and to the Solar
+//! System barycentre, which the Sun itself orbits at up to ~2 solar radii.
+//! The anomalies use the mean elements of Earth's orbit from Meeus
+//! (*Astronomical Algorithms*, ch. 25) and are good to about 0.01°.
+//!
+//! All functions take a TT Julian Date; TDB may be used interchangeably.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::earth::{earth_heliocentric_distance, earth_orbital_velocity};
+//!
+//! // Perihelion in early January: closest and fastest
+//! let january = 2460314.5; // 2024-01-03
+//! let july = 2460496.5;    // 2024-07-03
+//! assert!(earth_heliocentric_distance(january) < 0.984);
+//! assert!(earth_heliocentric_distance(july) > 1.016);
+//! assert!(earth_orbital_velocity(january) > earth_orbital_velocity(july));
+//! ```
+
+use crate::orbit::StateVector;
+
+/// Astronomical Unit in kilometers
+const AU_KM: f64 = 149597870.7;
+
+/// Seconds per day
+const DAY_SECONDS: f64 = 86400.0;
+
+/// Julian centuries since J2000.0
+fn centuries(jd: f64) -> f64 {
+    (jd - 2451545.0) / 36525.0
+}
+
+/// Earth's heliocentric position and velocity.
+///
+/// # Arguments
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+/// [`StateVector`] in AU and AU/day with ICRS axes, relative to the Sun's centre
+pub fn earth_heliocentric_state(jd: f64) -> StateVector {
+    let (heliocentric, _) = crate::backend::ephemerides::Epv00(jd, 0.0);
+    state_vector(jd, &heliocentric)
+}
+
+/// Earth's barycentric position and velocity.
+///
+/// # Arguments
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+/// [`StateVector`] in AU and AU/day with ICRS (BCRS) axes, relative to the
+/// Solar System barycentre
+pub fn earth_barycentric_state(jd: f64) -> StateVector {
+    let (_, barycentric) = crate::backend::ephemerides::Epv00(jd, 0.0);
+    state_vector(jd, &barycentric)
+}
+
+/// Distance from the Sun's centre to Earth's centre.
+///
+/// # Arguments
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+/// Distance in AU, between about 0.983 (January) and 1.017 (July)
+///
+/// # Example
+/// ```
+/// use astro_math::earth::earth_heliocentric_distance;
+///
+/// // Solar irradiance scales as 1/r²: about 3.4% above the mean at perihelion
+/// let r = earth_heliocentric_distance(2460314.5);
+/// assert!((1.0 / (r * r) - 1.034).abs() < 0.002);
+/// ```
+pub fn earth_heliocentric_distance(jd: f64) -> f64 {
+    earth_heliocentric_state(jd).distance_au()
+}
+
+/// Distance from the Solar System barycentre to Earth's centre.
+///
+/// # Arguments
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+/// Distance in AU; differs from [`earth_heliocentric_distance`] by up to
+/// ~0.01 AU, mostly because of Jupiter's pull on the Sun
+pub fn earth_barycentric_distance(jd: f64) -> f64 {
+    earth_barycentric_state(jd).distance_au()
+}
+
+/// Earth's orbital speed relative to the Sun.
+///
+/// # Arguments
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+/// Speed in km/s, between about 29.3 (July) and 30.3 (January)
+///
+/// # Example
+/// ```
+/// use astro_math::earth::earth_orbital_velocity;
+///
+/// let v = earth_orbital_velocity(2451545.0);
+/// assert!(v > 29.2 && v < 30.4);
+/// ```
+pub fn earth_orbital_velocity(jd: f64) -> f64 {
+    earth_heliocentric_state(jd).speed_au_per_day() * AU_KM / DAY_SECONDS
+}
+
+/// Earth's velocity relative to the Solar System barycentre.
+///
+/// This is the velocity behind annual aberration and barycentric radial
+/// velocity corrections.
+///
+/// # Arguments
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+/// `[vx, vy, vz]` in km/s with ICRS (BCRS) axes
+///
+/// # Example
+/// ```
+/// use astro_math::earth::earth_barycentric_velocity;
+///
+/// // Annual aberration: v/c ≈ 20.5″
+/// let v = earth_barycentric_velocity(2451545.0);
+/// let speed = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+/// assert!((speed / 299792.458 * 206264.8 - 20.5).abs() < 0.5);
+/// ```
+pub fn earth_barycentric_velocity(jd: f64) -> [f64; 3] {
+    earth_barycentric_state(jd).velocity.map(|v| v * AU_KM / DAY_SECONDS)
+}
+
+/// Eccentricity of Earth's orbit (Meeus 25.4).
+///
+/// # Arguments
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+/// Eccentricity, 0.0167 at J2000.0 and slowly decreasing
+pub fn earth_orbit_eccentricity(jd: f64) -> f64 {
+    let t = centuries(jd);
+    0.016708634 - t * (0.000042037 + 0.0000001267 * t)
+}
+
+/// Earth's mean anomaly (Meeus 25.3).
+///
+/// The angle from perihelion Earth would have reached moving at a uniform
+/// rate. It equals the Sun's mean anomaly in geocentric terms.
+///
+/// # Arguments
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+/// Mean anomaly in degrees, in [0, 360)
+///
+/// # Example
+/// ```
+/// use astro_math::earth::earth_mean_anomaly;
+///
+/// // Meeus Example 25.a: 1992 October 13.0 TD
+/// assert!((earth_mean_anomaly(2448908.5) - 278.99397).abs() < 1e-4);
+/// ```
+pub fn earth_mean_anomaly(jd: f64) -> f64 {
+    mean_anomaly_unwrapped(jd).rem_euclid(360.0)
+}
+
+/// Earth's true anomaly.
+///
+/// The actual angle from perihelion, the mean anomaly plus the equation of
+/// the centre (Meeus ch. 25).
+///
+/// # Arguments
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+/// True anomaly in degrees, in [0, 360)
+///
+/// # Example
+/// ```
+/// use astro_math::earth::{earth_mean_anomaly, earth_true_anomaly};
+///
+/// // Meeus Example 25.a: C = −1.89732°
+/// let jd = 2448908.5;
+/// assert!((earth_true_anomaly(jd) - earth_mean_anomaly(jd) + 1.89732).abs() < 1e-4);
+/// ```
+pub fn earth_true_anomaly(jd: f64) -> f64 {
+    (mean_anomaly_unwrapped(jd) + equation_of_center(jd)).rem_euclid(360.0)
+}
+
+/// Mean anomaly in degrees, not reduced to [0, 360)
+fn mean_anomaly_unwrapped(jd: f64) -> f64 {
+    let t = centuries(jd);
+    357.52911 + t * (35999.05029 - 0.0001537 * t)
+}
+
+/// Equation of the centre, true minus mean anomaly, in degrees
+pub(crate) fn equation_of_center(jd: f64) -> f64 {
+    let t = centuries(jd);
+    let m = mean_anomaly_unwrapped(jd).to_radians();
+    (1.914602 - t * (0.004817 + 0.000014 * t)) * m.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * m).sin()
+        + 0.000289 * (3.0 * m).sin()
+}
+
+fn state_vector(jd: f64, pv: &[f64; 6]) -> StateVector {
+    StateVector {
+        jd_tdb: jd,
+        position: [pv[0], pv[1], pv[2]],
+        velocity: [pv[3], pv[4], pv[5]],
+    }
+}
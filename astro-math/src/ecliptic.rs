@@ -0,0 +1,205 @@
+//! Ecliptic coordinate system conversions.
+//!
+//! Ecliptic longitude λ is measured eastward along the ecliptic from the
+//! vernal equinox and latitude β north of it. The frame is the equatorial
+//! one rotated about the equinox direction by the obliquity of the
+//! ecliptic ε, taken from [`mean_obliquity`] (IAU 2006):
+//!
+//! - [`equatorial_to_ecliptic`] / [`ecliptic_to_equatorial`] — mean
+//!   equator, ecliptic and equinox of date, for positions precessed to the
+//!   date with [`precession`](crate::precession)
+//! - [`equatorial_to_ecliptic_j2000`] / [`ecliptic_to_equatorial_j2000`] —
+//!   mean ecliptic and equinox of J2000.0, for catalogue (ICRS) positions
+//!   and orbital elements
+//!
+//! # Coordinate Systems
+//!
+//! - **Equatorial**:
+//!   - Right Ascension (RA): 0° to 360°
+//!   - Declination (Dec): -90° to +90°
+//!
+//! - **Ecliptic**:
+//!   - Longitude (λ): 0° to 360° (measured from the vernal equinox)
+//!   - Latitude (β): -90° to +90° (measured from the ecliptic plane)
+//!
+//! # Example
+//!
+//! Solar elongation follows from the ecliptic coordinates alone,
+//! cos E = cos β cos(λ − λ☉):
+//!
+//! ```
+//! use astro_math::ecliptic::equatorial_to_ecliptic;
+//! use astro_math::planets::{planet_position, Planet};
+//! use astro_math::sun::ecliptic_longitude_of_sun;
+//! use astro_math::time::julian_date;
+//! use chrono::{TimeZone, Utc};
+//!
+//! // Venus at greatest eastern elongation, 2025 January 10
+//! let dt = Utc.with_ymd_and_hms(2025, 1, 10, 4, 0, 0).unwrap();
+//! let venus = planet_position(Planet::Venus, dt).unwrap();
+//! let (lambda, beta) = equatorial_to_ecliptic(venus.ra, venus.dec, julian_date(dt)).unwrap();
+//! let sun = ecliptic_longitude_of_sun(dt);
+//!
+//! let elongation = (beta.to_radians().cos() * (lambda - sun).to_radians().cos()).acos().to_degrees();
+//! assert!((elongation - 47.2).abs() < 0.1);
+//! ```
+//!
+//! # Error Handling
+//!
+//! All functions validate their inputs and return `Result<T>` types:
+//! - `AstroError::InvalidCoordinate` for out-of-range values
+//!
+//! # References
+//!
+//! - Meeus, *Astronomical Algorithms*, 2nd ed., eqs. 13.1–13.4
+//! - IAU 2006 precession (Capitaine et al. 2003) for the obliquity
+
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::nutation::mean_obliquity;
+use crate::time::JD2000;
+
+/// Converts equatorial coordinates to ecliptic coordinates of date.
+///
+/// # Arguments
+/// * `ra` - Right ascension in degrees (mean equator and equinox of date)
+/// * `dec` - Declination in degrees (mean equator and equinox of date)
+/// * `jd` - Julian Date (TT) of the equinox
+///
+/// # Returns
+/// Tuple of (λ, β) in degrees, λ in [0, 360)
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if:
+/// - `ra` is outside [0, 360)
+/// - `dec` is outside [-90, 90]
+///
+/// # Example
+/// ```
+/// use astro_math::ecliptic::equatorial_to_ecliptic;
+/// use astro_math::nutation::mean_obliquity;
+///
+/// // The June solstice point of 2025 lies on the ecliptic of 2025 ...
+/// let jd = 2460676.5;
+/// let (lambda, beta) = equatorial_to_ecliptic(90.0, mean_obliquity(jd), jd).unwrap();
+/// assert!((lambda - 90.0).abs() < 1e-9 && beta.abs() < 1e-9);
+///
+/// // ... but 35" south of that of 1950, as the obliquity slowly decreases
+/// let (_, beta) = equatorial_to_ecliptic(90.0, mean_obliquity(jd), 2433282.4235).unwrap();
+/// assert!((beta * 3600.0 + 35.1).abs() < 0.5);
+/// ```
+pub fn equatorial_to_ecliptic(ra: f64, dec: f64, jd: f64) -> Result<(f64, f64)> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    Ok(rotate(ra, dec, -mean_obliquity(jd)))
+}
+
+/// Converts ecliptic coordinates of date to equatorial coordinates.
+///
+/// # Arguments
+/// * `lambda` - Ecliptic longitude in degrees (any value, normalized to [0, 360))
+/// * `beta` - Ecliptic latitude in degrees
+/// * `jd` - Julian Date (TT) of the equinox
+///
+/// # Returns
+/// Tuple of (ra, dec) in degrees on the mean equator and equinox of date
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if `beta` is outside [-90, 90]
+///
+/// # Example
+/// ```
+/// use astro_math::ecliptic::{ecliptic_to_equatorial, equatorial_to_ecliptic};
+///
+/// let jd = 2460676.5; // 2025 January 1
+/// let (ra, dec) = ecliptic_to_equatorial(90.0, 0.0, jd).unwrap();
+/// // The June solstice point lies at RA 6h, Dec +ε
+/// assert!((ra - 90.0).abs() < 1e-9);
+/// assert!((dec - 23.436).abs() < 0.001);
+///
+/// let (lambda, beta) = equatorial_to_ecliptic(ra, dec, jd).unwrap();
+/// assert!((lambda - 90.0).abs() < 1e-9 && beta.abs() < 1e-9);
+/// ```
+pub fn ecliptic_to_equatorial(lambda: f64, beta: f64, jd: f64) -> Result<(f64, f64)> {
+    validate_ecliptic_latitude(beta)?;
+    Ok(rotate(lambda, beta, mean_obliquity(jd)))
+}
+
+/// Converts J2000.0 equatorial coordinates to J2000.0 ecliptic coordinates.
+///
+/// # Arguments
+/// * `ra` - Right ascension in degrees (J2000.0)
+/// * `dec` - Declination in degrees (J2000.0)
+///
+/// # Returns
+/// Tuple of (λ, β) in degrees on the mean ecliptic and equinox of J2000.0,
+/// λ in [0, 360)
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if:
+/// - `ra` is outside [0, 360)
+/// - `dec` is outside [-90, 90]
+///
+/// # Example
+/// ```
+/// use astro_math::ecliptic::equatorial_to_ecliptic_j2000;
+///
+/// // Pollux (Meeus example 13.a, which uses the IAU 1980 obliquity)
+/// let (lambda, beta) = equatorial_to_ecliptic_j2000(116.328942, 28.026183).unwrap();
+/// assert!((lambda - 113.215630).abs() < 0.0001);
+/// assert!((beta - 6.684170).abs() < 0.0001);
+/// ```
+pub fn equatorial_to_ecliptic_j2000(ra: f64, dec: f64) -> Result<(f64, f64)> {
+    equatorial_to_ecliptic(ra, dec, JD2000)
+}
+
+/// Converts J2000.0 ecliptic coordinates to J2000.0 equatorial coordinates.
+///
+/// # Arguments
+/// * `lambda` - Ecliptic longitude in degrees (any value, normalized to [0, 360))
+/// * `beta` - Ecliptic latitude in degrees
+///
+/// # Returns
+/// Tuple of (ra, dec) in degrees (J2000.0)
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if `beta` is outside [-90, 90]
+///
+/// # Example
+/// ```
+/// use astro_math::ecliptic::ecliptic_to_equatorial_j2000;
+///
+/// // The north ecliptic pole, in Draco
+/// let (ra, dec) = ecliptic_to_equatorial_j2000(0.0, 90.0).unwrap();
+/// assert!((ra - 270.0).abs() < 1e-9);
+/// assert!((dec - 66.560721).abs() < 1e-6);
+/// ```
+pub fn ecliptic_to_equatorial_j2000(lambda: f64, beta: f64) -> Result<(f64, f64)> {
+    ecliptic_to_equatorial(lambda, beta, JD2000)
+}
+
+fn validate_ecliptic_latitude(beta: f64) -> Result<()> {
+    if !(-90.0..=90.0).contains(&beta) {
+        return Err(AstroError::InvalidCoordinate {
+            coord_type: "Ecliptic latitude",
+            value: beta,
+            valid_range: "[-90, 90]",
+        });
+    }
+    Ok(())
+}
+
+/// Rotates spherical coordinates about the equinox direction by `angle_deg`:
+/// ecliptic → equatorial for +ε, equatorial → ecliptic for −ε.
+fn rotate(lon: f64, lat: f64, angle_deg: f64) -> (f64, f64) {
+    let (sin_e, cos_e) = angle_deg.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = lon.to_radians().sin_cos();
+    let (sin_lat, cos_lat) = lat.to_radians().sin_cos();
+
+    let x = cos_lat * cos_lon;
+    let y = cos_lat * sin_lon * cos_e - sin_lat * sin_e;
+    let z = cos_lat * sin_lon * sin_e + sin_lat * cos_e;
+
+    let lon_out = y.atan2(x).to_degrees().rem_euclid(360.0);
+    let lat_out = z.clamp(-1.0, 1.0).asin().to_degrees();
+    (lon_out, lat_out)
+}
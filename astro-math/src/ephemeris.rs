@@ -0,0 +1,350 @@
+//! One interface to every moving target: Sun, Moon, planets, satellites and
+//! tabulated ephemerides.
+//!
+//! Anything that can say where it is at a given time implements
+//! [`Ephemeris`]: its geocentric apparent RA/Dec of date and, where it is
+//! near enough for parallax to matter, its distance. The generic functions
+//! built on it then work for every kind of target alike:
+//!
+//! - [`topocentric_radec`] — the position seen from a site, with parallax
+//! - [`body_alt_az`](crate::transforms::body_alt_az) — altitude and azimuth
+//! - [`body_rise_set`](crate::rise_set::body_rise_set),
+//!   [`body_altitude_crossings`](crate::rise_set::body_altitude_crossings) and
+//!   [`rise_set_events`](crate::rise_set::rise_set_events) — rise, transit,
+//!   set and altitude limits, following the target's motion
+//!
+//! Implementations are provided for [`AlmanacBody`] (the Sun, Moon and
+//! planets), [`Tle`] (Earth satellites), [`FixedPosition`] (stars and other
+//! fixed targets) and [`TabulatedEphemeris`], which interpolates a table
+//! such as a JPL Horizons or MPC ephemeris of a comet, asteroid or
+//! spacecraft. Implement the trait for your own targets to use
+//! the same functions.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::almanac::AlmanacBody;
+//! use astro_math::ephemeris::{TabulatedEphemeris, TabulatedPosition};
+//! use astro_math::rise_set::body_rise_set;
+//! use astro_math::transforms::body_alt_az;
+//! use astro_math::Location;
+//! use chrono::{Duration, TimeZone, Utc};
+//!
+//! let site = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+//! let date = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+//!
+//! // The same call for the Sun...
+//! let (sunrise, _) = body_rise_set(&AlmanacBody::Sun, date, &site).unwrap();
+//! assert_eq!(sunrise.unwrap().format("%H:%M").to_string(), "06:44");
+//!
+//! // ...and for a comet from a daily table
+//! let t0 = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+//! let comet = TabulatedEphemeris::new(vec![
+//!     TabulatedPosition { time: t0, ra: 40.0, dec: 45.0, distance_au: Some(1.9) },
+//!     TabulatedPosition { time: t0 + Duration::days(1), ra: 40.5, dec: 45.2, distance_au: Some(1.9) },
+//! ])
+//! .unwrap();
+//! let (alt, _az) = body_alt_az(&comet, t0 + Duration::hours(12), &site).unwrap();
+//! assert!(alt > 0.0);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range latitude,
+//!   longitude, RA or Dec
+//! - `AstroError::OutOfRange` for a time outside a table or too far from a
+//!   satellite's element epoch, or a non-positive tabulated distance
+//! - `AstroError::InvalidDateTime` for a table whose times do not increase
+//! - `AstroError::CalculationError` if an ephemeris rejects the date
+
+use crate::almanac::{apparent_place, AlmanacBody};
+use crate::error::{validate_dec, validate_latitude, validate_longitude, validate_ra, AstroError, Result};
+use crate::great_circle::interpolate;
+use crate::location::Location;
+use crate::observer::terrestrial_position_km;
+use crate::satellite::Tle;
+use crate::sidereal::apparent_sidereal_time;
+use crate::time::julian_date;
+use crate::vec3::{norm, radec_to_unit_vector, unit_vector_to_radec};
+use chrono::{DateTime, Duration, Utc};
+
+/// Astronomical unit in kilometers
+const AU_KM: f64 = 149_597_870.7;
+
+/// A target whose position on the sky is known at any time.
+pub trait Ephemeris {
+    /// Geocentric apparent right ascension and declination in degrees,
+    /// referred to the true equator and equinox of date.
+    ///
+    /// # Errors
+    /// Whatever the underlying ephemeris returns for a time it cannot
+    /// cover.
+    fn radec(&self, datetime: DateTime<Utc>) -> Result<(f64, f64)>;
+
+    /// Geocentric distance in AU, `None` if unknown or too far for
+    /// parallax to matter.
+    ///
+    /// # Errors
+    /// As [`Ephemeris::radec`].
+    fn distance(&self, datetime: DateTime<Utc>) -> Result<Option<f64>>;
+
+    /// Mean radius of the body in km, which sets its apparent
+    /// semi-diameter; `None` (the default) treats it as a point.
+    fn radius_km(&self) -> Option<f64> {
+        None
+    }
+
+    /// Sampling interval for event searches, short enough that the target
+    /// cannot rise and set again within it. Ten minutes by default.
+    fn search_step(&self) -> Duration {
+        Duration::minutes(10)
+    }
+}
+
+impl Ephemeris for AlmanacBody {
+    fn radec(&self, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+        let place = apparent_place(*self, datetime)?;
+        Ok((place.ra, place.dec))
+    }
+
+    fn distance(&self, datetime: DateTime<Utc>) -> Result<Option<f64>> {
+        Ok(Some(apparent_place(*self, datetime)?.distance_au))
+    }
+
+    /// The Sun's and Moon's radii; the planets' disks are too small to move
+    /// their rise and set
+    fn radius_km(&self) -> Option<f64> {
+        match self {
+            AlmanacBody::Sun | AlmanacBody::Moon => Some(self.equatorial_radius_km()),
+            _ => None,
+        }
+    }
+}
+
+/// TEME, the frame of TLE theory, is taken as the true equator and equinox
+/// of date; they differ by the equation of the equinoxes, at most about 1
+/// second of RA, far below the propagator's accuracy.
+impl Ephemeris for Tle {
+    fn radec(&self, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+        Ok(unit_vector_to_radec(self.position_teme(datetime)?))
+    }
+
+    fn distance(&self, datetime: DateTime<Utc>) -> Result<Option<f64>> {
+        Ok(Some(norm(self.position_teme(datetime)?) / AU_KM))
+    }
+
+    /// A pass can last only a few minutes
+    fn search_step(&self) -> Duration {
+        Duration::minutes(1)
+    }
+}
+
+/// A target fixed on the sky, such as a star or galaxy, at its apparent
+/// place of date.
+///
+/// # Example
+/// ```
+/// use astro_math::ephemeris::{Ephemeris, FixedPosition};
+/// use chrono::Utc;
+///
+/// let vega = FixedPosition { ra: 279.2347, dec: 38.7837 };
+/// assert_eq!(vega.radec(Utc::now()).unwrap(), (279.2347, 38.7837));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedPosition {
+    /// Right ascension in degrees
+    pub ra: f64,
+    /// Declination in degrees
+    pub dec: f64,
+}
+
+impl Ephemeris for FixedPosition {
+    fn radec(&self, _datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+        validate_ra(self.ra)?;
+        validate_dec(self.dec)?;
+        Ok((self.ra, self.dec))
+    }
+
+    fn distance(&self, _datetime: DateTime<Utc>) -> Result<Option<f64>> {
+        Ok(None)
+    }
+}
+
+/// One row of a [`TabulatedEphemeris`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TabulatedPosition {
+    /// Time of the row (UTC)
+    pub time: DateTime<Utc>,
+    /// Geocentric apparent right ascension in degrees
+    pub ra: f64,
+    /// Geocentric apparent declination in degrees
+    pub dec: f64,
+    /// Geocentric distance in AU, if tabulated
+    pub distance_au: Option<f64>,
+}
+
+/// A target's positions tabulated at times, interpolated between them.
+///
+/// Positions are interpolated along the great circle between neighboring
+/// rows, and distances linearly, so the table needs rows close enough that
+/// the motion between them is nearly uniform: daily for a main-belt
+/// asteroid, hourly or closer for a near-Earth object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabulatedEphemeris {
+    rows: Vec<TabulatedPosition>,
+}
+
+impl TabulatedEphemeris {
+    /// Builds an ephemeris from tabulated positions.
+    ///
+    /// # Arguments
+    /// * `rows` - At least two positions in increasing time order
+    ///
+    /// # Errors
+    /// - `AstroError::OutOfRange` if there are fewer than two rows, or a
+    ///   distance is not positive and finite
+    /// - `AstroError::InvalidDateTime` if the times do not strictly increase
+    /// - `AstroError::InvalidCoordinate` if an RA or Dec is out of range
+    pub fn new(rows: Vec<TabulatedPosition>) -> Result<Self> {
+        if rows.len() < 2 {
+            return Err(AstroError::OutOfRange {
+                parameter: "ephemeris rows",
+                value: rows.len() as f64,
+                min: 2.0,
+                max: f64::INFINITY,
+            });
+        }
+        for row in &rows {
+            validate_ra(row.ra)?;
+            validate_dec(row.dec)?;
+            if let Some(distance) = row.distance_au {
+                if !(distance > 0.0 && distance.is_finite()) {
+                    return Err(AstroError::OutOfRange {
+                        parameter: "distance_au",
+                        value: distance,
+                        min: 0.0,
+                        max: f64::INFINITY,
+                    });
+                }
+            }
+        }
+        if let Some(pair) = rows.windows(2).find(|pair| pair[1].time <= pair[0].time) {
+            return Err(AstroError::InvalidDateTime {
+                reason: format!("ephemeris time {} does not follow {}", pair[1].time, pair[0].time),
+            });
+        }
+        Ok(TabulatedEphemeris { rows })
+    }
+
+    /// The tabulated positions.
+    pub fn rows(&self) -> &[TabulatedPosition] {
+        &self.rows
+    }
+
+    /// The two rows around `datetime` and the fraction of the way from the
+    /// first to the second.
+    fn bracket(&self, datetime: DateTime<Utc>) -> Result<(&TabulatedPosition, &TabulatedPosition, f64)> {
+        let (first, last) = (self.rows[0].time, self.rows[self.rows.len() - 1].time);
+        if datetime < first || datetime > last {
+            return Err(AstroError::OutOfRange {
+                parameter: "days from ephemeris start",
+                value: seconds(datetime - first) / 86400.0,
+                min: 0.0,
+                max: seconds(last - first) / 86400.0,
+            });
+        }
+        let i = self.rows.partition_point(|row| row.time <= datetime).clamp(1, self.rows.len() - 1);
+        let (a, b) = (&self.rows[i - 1], &self.rows[i]);
+        Ok((a, b, seconds(datetime - a.time) / seconds(b.time - a.time)))
+    }
+}
+
+impl Ephemeris for TabulatedEphemeris {
+    fn radec(&self, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+        let (a, b, fraction) = self.bracket(datetime)?;
+        interpolate((a.ra, a.dec), (b.ra, b.dec), fraction)
+    }
+
+    fn distance(&self, datetime: DateTime<Utc>) -> Result<Option<f64>> {
+        let (a, b, fraction) = self.bracket(datetime)?;
+        Ok(match (a.distance_au, b.distance_au) {
+            (Some(da), Some(db)) => Some(da + (db - da) * fraction),
+            _ => None,
+        })
+    }
+}
+
+/// Computes a target's position as seen from a site on the Earth.
+///
+/// The geocentric place is shifted by the observer's displacement from the
+/// Earth's center on the WGS84 ellipsoid (diurnal parallax): nearly 1° for
+/// the Moon, tens of degrees for a low satellite, nothing for a target with
+/// no distance. No refraction is applied. UT1 is taken equal to UTC.
+///
+/// # Arguments
+/// * `body` - The target
+/// * `datetime` - Time of observation
+/// * `location` - Observer's location
+///
+/// # Returns
+/// Topocentric `(ra, dec)` in degrees, true equator and equinox of date
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - Whatever the target's ephemeris returns for `datetime`
+///
+/// # Example
+/// ```
+/// use astro_math::almanac::AlmanacBody;
+/// use astro_math::ephemeris::{topocentric_radec, Ephemeris};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// // The Moon is displaced by up to its horizontal parallax, about 1°
+/// let site = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+/// let (ra, dec) = AlmanacBody::Moon.radec(dt).unwrap();
+/// let (topo_ra, topo_dec) = topocentric_radec(&AlmanacBody::Moon, dt, &site).unwrap();
+/// let shift = ((topo_ra - ra) * dec.to_radians().cos()).hypot(topo_dec - dec);
+/// assert!(shift > 0.1 && shift < 1.0);
+/// ```
+pub fn topocentric_radec<E: Ephemeris + ?Sized>(
+    body: &E,
+    datetime: DateTime<Utc>,
+    location: &Location,
+) -> Result<(f64, f64)> {
+    let (ra, dec, _) = topocentric_place(body, datetime, location)?;
+    Ok((ra, dec))
+}
+
+/// Topocentric `(ra, dec)` in degrees and distance in km, `None` if the
+/// target has no distance.
+pub(crate) fn topocentric_place<E: Ephemeris + ?Sized>(
+    body: &E,
+    datetime: DateTime<Utc>,
+    location: &Location,
+) -> Result<(f64, f64, Option<f64>)> {
+    validate_latitude(location.latitude_deg)?;
+    validate_longitude(location.longitude_deg)?;
+    let (ra, dec) = body.radec(datetime)?;
+    let Some(distance_au) = body.distance(datetime)? else {
+        return Ok((ra, dec, None));
+    };
+
+    // The site in the true equator and equinox of date: its Earth-fixed
+    // position turned by Greenwich apparent sidereal time
+    let site = terrestrial_position_km(location);
+    let gast = (apparent_sidereal_time(julian_date(datetime), 0.0) * 15.0).to_radians();
+    let (sin_g, cos_g) = gast.sin_cos();
+    let site = [cos_g * site[0] - sin_g * site[1], sin_g * site[0] + cos_g * site[1], site[2]];
+
+    let target = radec_to_unit_vector(ra, dec).map(|c| c * distance_au * AU_KM);
+    let topocentric = [0, 1, 2].map(|k| target[k] - site[k]);
+    let (ra, dec) = unit_vector_to_radec(topocentric);
+    Ok((ra, dec, Some(norm(topocentric))))
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.num_milliseconds() as f64 / 1000.0
+}
@@ -4,6 +4,10 @@
 //! Fundamental Astronomy) C library functions, ensuring compatibility with
 //! astropy and other professional astronomy software.
 //!
+//! With the `pure-rust` feature the same functions are backed by Rust ports
+//! of the ERFA routines; see the crate-level Cargo Features section for
+//! their accuracy.
+//!
 //! # Function Naming
 //!
 //! We use descriptive names instead of ERFA's cryptic abbreviations:
@@ -50,6 +54,7 @@ use crate::error::{AstroError, Result};
 ///
 /// * `Result<(f64, f64, f64, f64, f64, f64)>` - (azimuth, zenith distance, hour angle, declination, RA, declination) all in radians
 #[allow(clippy::too_many_arguments)]
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
 pub fn icrs_to_observed(
     ra_icrs: f64,
     dec_icrs: f64,
@@ -70,7 +75,7 @@ pub fn icrs_to_observed(
     rh: f64,
     wl: f64,
 ) -> Result<(f64, f64, f64, f64, f64, f64)> {
-    match erfars::astrometry::Atco13(
+    match crate::backend::astrometry::Atco13(
         ra_icrs, dec_icrs, pr, pd, px, rv,
         utc1, utc2, dut1, elong, phi, hm,
         xp, yp, phpa, tc, rh, wl,
@@ -102,6 +107,7 @@ pub fn icrs_to_observed(
 ///
 /// * `Result<(f64, f64, f64)>` - (RA, Dec, equation of origins) in radians
 #[allow(clippy::too_many_arguments)]
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
 pub fn icrs_to_cirs(
     ra_icrs: f64,
     dec_icrs: f64,
@@ -112,7 +118,7 @@ pub fn icrs_to_cirs(
     date1: f64,
     date2: f64,
 ) -> Result<(f64, f64, f64)> {
-    let (ri, di, eo) = erfars::astrometry::Atci13(
+    let (ri, di, eo) = crate::backend::astrometry::Atci13(
         ra_icrs, dec_icrs, pr, pd, px, rv, date1, date2,
     );
     Ok((ri, di, eo))
@@ -143,6 +149,7 @@ pub fn icrs_to_cirs(
 ///
 /// * `Result<(f64, f64, f64, f64, f64, f64)>` - (azimuth, zenith distance, hour angle, declination, RA, declination) in radians
 #[allow(clippy::too_many_arguments)]
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
 pub fn cirs_to_observed(
     ri: f64,
     di: f64,
@@ -159,7 +166,7 @@ pub fn cirs_to_observed(
     rh: f64,
     wl: f64,
 ) -> Result<(f64, f64, f64, f64, f64, f64)> {
-    match erfars::astrometry::Atio13(
+    match crate::backend::astrometry::Atio13(
         ri, di, utc1, utc2, dut1, elong, phi, hm,
         xp, yp, phpa, tc, rh, wl,
     ) {
@@ -184,7 +191,7 @@ pub fn cirs_to_observed(
 ///
 /// GMST in radians
 pub fn greenwich_mean_sidereal_time(ut11: f64, ut12: f64, tt1: f64, tt2: f64) -> f64 {
-    erfars::rotationtime::Gmst06(ut11, ut12, tt1, tt2)
+    crate::backend::rotationtime::Gmst06(ut11, ut12, tt1, tt2)
 }
 
 /// Calculate Greenwich Apparent Sidereal Time using ERFA IAU 2006 model.
@@ -202,7 +209,7 @@ pub fn greenwich_mean_sidereal_time(ut11: f64, ut12: f64, tt1: f64, tt2: f64) ->
 ///
 /// GAST in radians
 pub fn greenwich_apparent_sidereal_time(ut11: f64, ut12: f64, tt1: f64, tt2: f64) -> f64 {
-    erfars::rotationtime::Gst06a(ut11, ut12, tt1, tt2)
+    crate::backend::rotationtime::Gst06a(ut11, ut12, tt1, tt2)
 }
 
 /// Calculate Earth Rotation Angle.
@@ -218,7 +225,7 @@ pub fn greenwich_apparent_sidereal_time(ut11: f64, ut12: f64, tt1: f64, tt2: f64
 ///
 /// ERA in radians
 pub fn earth_rotation_angle(ut11: f64, ut12: f64) -> f64 {
-    erfars::rotationtime::Era00(ut11, ut12)
+    crate::backend::rotationtime::Era00(ut11, ut12)
 }
 
 /// Get precession matrix at a given epoch.
@@ -233,7 +240,7 @@ pub fn earth_rotation_angle(ut11: f64, ut12: f64) -> f64 {
 /// 3x3 precession matrix from J2000 to date
 pub fn precession_matrix(date1: f64, date2: f64) -> [[f64; 3]; 3] {
     let mut rbp = [0.0; 9];
-    erfars::precnutpolar::Pmat06(date1, date2, &mut rbp);
+    crate::backend::precnutpolar::Pmat06(date1, date2, &mut rbp);
     
     // Convert from flat array to 3x3 matrix
     [
@@ -255,7 +262,7 @@ pub fn precession_matrix(date1: f64, date2: f64) -> [[f64; 3]; 3] {
 /// 3x3 BPN matrix
 pub fn bias_precession_nutation_matrix(date1: f64, date2: f64) -> [[f64; 3]; 3] {
     let mut rbpn = [0.0; 9];
-    erfars::precnutpolar::Pnm06a(date1, date2, &mut rbpn);
+    crate::backend::precnutpolar::Pnm06a(date1, date2, &mut rbpn);
     
     // Convert from flat array to 3x3 matrix
     [
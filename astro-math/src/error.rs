@@ -12,6 +12,7 @@
 //! - **Format errors**: Invalid string formats (e.g., DMS parsing)
 //! - **Calculation errors**: Mathematical failures or edge cases
 //! - **Projection errors**: Points that cannot be projected
+//! - **File format errors**: Malformed model files and other structured text
 //!
 //! # Examples
 //!
@@ -110,6 +111,17 @@ pub enum AstroError {
         /// Description of the issue
         reason: String,
     },
+
+    /// Invalid input for a structured text format (model files, catalogs, etc.)
+    #[error("Invalid {format} input '{input}': {reason}")]
+    InvalidFormat {
+        /// Name of the format being parsed
+        format: &'static str,
+        /// The offending input (line or token)
+        input: String,
+        /// Why it could not be parsed
+        reason: String,
+    },
 }
 
 /// Type alias for Results in this crate.
@@ -146,7 +158,8 @@ pub fn validate_range(value: f64, min: f64, max: f64, parameter: &'static str) -
 
 /// Validate right ascension (0 <= RA < 360).
 ///
-/// Right ascension must be in the range [0, 360) degrees.
+/// Right ascension must be in the range [0, 360) degrees, unless the
+/// [validation policy](crate::config::ValidationPolicy) is `Wrap`.
 ///
 /// # Errors
 /// Returns `AstroError::InvalidCoordinate` if RA is outside the valid range.
@@ -163,7 +176,7 @@ pub fn validate_range(value: f64, min: f64, max: f64, parameter: &'static str) -
 #[inline]
 pub fn validate_ra(ra: f64) -> Result<()> {
     validate_finite(ra, "RA")?;
-    if !(0.0..360.0).contains(&ra) {
+    if !(0.0..360.0).contains(&ra) && !crate::config::wraps_angles() {
         Err(AstroError::InvalidCoordinate {
             coord_type: "RA",
             value: ra,
@@ -259,6 +272,36 @@ pub fn validate_longitude(lon: f64) -> Result<()> {
     }
 }
 
+/// Earliest Julian Date accepted by [`validate_jd`]: 4713 BC January 1, 12h (JD 0).
+pub const JD_MIN: f64 = 0.0;
+
+/// Latest Julian Date accepted by [`validate_jd`]: AD 9999 December 31, 0h.
+pub const JD_MAX: f64 = 5_373_484.5;
+
+/// Validate a Julian Date (finite and within [`JD_MIN`], [`JD_MAX`]).
+///
+/// This rejects NaN, infinities and dates so far from the present that the
+/// nutation, sidereal time and lunar series return meaningless numbers. It
+/// does not promise full accuracy over the whole span: the series are fitted
+/// to observations of the last few millennia.
+///
+/// # Errors
+/// Returns `AstroError::CalculationError` for NaN or infinite input and
+/// `AstroError::OutOfRange` for a finite date outside the range.
+///
+/// # Example
+/// ```
+/// use astro_math::error::validate_jd;
+///
+/// assert!(validate_jd(2451545.0).is_ok());
+/// assert!(validate_jd(f64::NAN).is_err());
+/// assert!(validate_jd(-1.0e9).is_err());
+/// ```
+#[inline]
+pub fn validate_jd(jd: f64) -> Result<()> {
+    validate_coordinate_safe(jd, JD_MIN, JD_MAX, "Julian Date")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,394 @@
+//! Generic event search over time.
+//!
+//! Many astronomical questions reduce to finding when some function of time
+//! crosses zero ("when does the target reach 40° altitude?") or reaches an
+//! extremum ("when is the Moon highest tonight?"). This module provides the
+//! robust solvers for these searches so they can be applied to any
+//! user-defined function of [`DateTime<Utc>`].
+//!
+//! # Solvers
+//!
+//! - [`find_zero`] — Brent's method on a bracketing interval
+//! - [`find_zeros`] — Scans an interval with a fixed step and refines every sign change
+//! - [`find_extremum`] — Golden-section search for a minimum or maximum
+//!
+//! Time is handled internally as seconds from the interval start, so results
+//! are accurate to well below a millisecond.
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidDateTime` if the interval is empty or reversed
+//! - `AstroError::OutOfRange` for non-positive tolerances or scan steps
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::events::find_zero;
+//! use astro_math::{Location, ra_dec_to_alt_az};
+//! use chrono::{Duration, TimeZone, Utc};
+//!
+//! let loc = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+//! let t0 = Utc.with_ymd_and_hms(2024, 8, 4, 20, 0, 0).unwrap();
+//! let t1 = t0 + Duration::hours(6);
+//!
+//! // When does Vega pass 80° altitude?
+//! let altitude = |t| ra_dec_to_alt_az(279.23, 38.78, t, &loc).unwrap().0 - 80.0;
+//! if let Some(t) = find_zero(altitude, t0, t1, Duration::seconds(1)).unwrap() {
+//!     println!("Vega reaches 80° at {}", t);
+//! }
+//! ```
+
+use crate::error::{AstroError, Result};
+use chrono::{DateTime, Duration, Utc};
+
+/// Direction in which a function crosses zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crossing {
+    /// Function goes from negative to positive (e.g. a rise)
+    Rising,
+    /// Function goes from positive to negative (e.g. a set)
+    Falling,
+}
+
+/// Kind of extremum to search for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extremum {
+    /// Search for the smallest value
+    Minimum,
+    /// Search for the largest value
+    Maximum,
+}
+
+fn validate_interval(t0: DateTime<Utc>, t1: DateTime<Utc>) -> Result<f64> {
+    let span = seconds_between(t0, t1);
+    if span <= 0.0 {
+        return Err(AstroError::InvalidDateTime {
+            reason: format!("search interval end {} is not after start {}", t1, t0),
+        });
+    }
+    Ok(span)
+}
+
+fn validate_positive(value: Duration, parameter: &'static str) -> Result<f64> {
+    let seconds = duration_seconds(value);
+    if seconds <= 0.0 {
+        return Err(AstroError::OutOfRange {
+            parameter,
+            value: seconds,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+    Ok(seconds)
+}
+
+#[inline]
+fn duration_seconds(d: Duration) -> f64 {
+    d.num_microseconds()
+        .map(|us| us as f64 / 1e6)
+        .unwrap_or(d.num_seconds() as f64)
+}
+
+#[inline]
+fn seconds_between(t0: DateTime<Utc>, t1: DateTime<Utc>) -> f64 {
+    duration_seconds(t1 - t0)
+}
+
+#[inline]
+fn offset(t0: DateTime<Utc>, seconds: f64) -> DateTime<Utc> {
+    t0 + Duration::microseconds((seconds * 1e6).round() as i64)
+}
+
+/// Evaluates `sample` at `start`, `start + step`, … up to `end`.
+pub(crate) fn sample_uniformly<T>(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: Duration,
+    mut sample: impl FnMut(DateTime<Utc>) -> T,
+) -> Result<Vec<T>> {
+    validate_positive(step, "step")?;
+    let mut samples = Vec::new();
+    let mut time = start;
+    while time <= end {
+        samples.push(sample(time));
+        time += step;
+    }
+    Ok(samples)
+}
+
+/// Brent's method on `g(s)` for `s` in `[a, b]` with `g(a)` and `g(b)` of opposite sign.
+fn brent<G: FnMut(f64) -> f64>(mut g: G, mut a: f64, mut b: f64, mut fa: f64, mut fb: f64, tol: f64) -> f64 {
+    if fa.abs() < fb.abs() {
+        std::mem::swap(&mut a, &mut b);
+        std::mem::swap(&mut fa, &mut fb);
+    }
+    let mut c = a;
+    let mut fc = fa;
+    let mut d = b - a;
+    let mut bisected = true;
+
+    for _ in 0..200 {
+        if fb == 0.0 || (b - a).abs() <= tol {
+            break;
+        }
+        let mut s = if fa != fc && fb != fc {
+            // Inverse quadratic interpolation
+            a * fb * fc / ((fa - fb) * (fa - fc))
+                + b * fa * fc / ((fb - fa) * (fb - fc))
+                + c * fa * fb / ((fc - fa) * (fc - fb))
+        } else {
+            // Secant
+            b - fb * (b - a) / (fb - fa)
+        };
+
+        let lo = (3.0 * a + b) / 4.0;
+        let outside = !((lo < s && s < b) || (b < s && s < lo));
+        if outside
+            || (bisected && (s - b).abs() >= (b - c).abs() / 2.0)
+            || (!bisected && (s - b).abs() >= (c - d).abs() / 2.0)
+            || (bisected && (b - c).abs() < tol)
+            || (!bisected && (c - d).abs() < tol)
+        {
+            s = (a + b) / 2.0;
+            bisected = true;
+        } else {
+            bisected = false;
+        }
+
+        let fs = g(s);
+        d = c;
+        c = b;
+        fc = fb;
+        if fa * fs < 0.0 {
+            b = s;
+            fb = fs;
+        } else {
+            a = s;
+            fa = fs;
+        }
+        if fa.abs() < fb.abs() {
+            std::mem::swap(&mut a, &mut b);
+            std::mem::swap(&mut fa, &mut fb);
+        }
+    }
+    b
+}
+
+/// Finds a time in `[t0, t1]` where `f` crosses zero.
+///
+/// Uses Brent's method, which combines the guaranteed convergence of
+/// bisection with the speed of interpolation. The function must have opposite
+/// signs at the two ends of the interval; if it does not, `Ok(None)` is
+/// returned. Use [`find_zeros`] when the interval may contain several roots.
+///
+/// # Arguments
+/// * `f` - Function of time whose root is sought
+/// * `t0` - Start of the search interval
+/// * `t1` - End of the search interval
+/// * `tol` - Time tolerance of the result
+///
+/// # Returns
+/// - `Ok(Some(t))` - Time of the zero crossing
+/// - `Ok(None)` - No sign change between `t0` and `t1`
+///
+/// # Errors
+/// - `AstroError::InvalidDateTime` if `t1` is not after `t0`
+/// - `AstroError::OutOfRange` if `tol` is not positive
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(%t0, %t1)))]
+pub fn find_zero<F>(
+    mut f: F,
+    t0: DateTime<Utc>,
+    t1: DateTime<Utc>,
+    tol: Duration,
+) -> Result<Option<DateTime<Utc>>>
+where
+    F: FnMut(DateTime<Utc>) -> f64,
+{
+    let span = validate_interval(t0, t1)?;
+    let tol = validate_positive(tol, "tolerance")?;
+
+    let fa = f(t0);
+    let fb = f(t1);
+    if fa == 0.0 {
+        return Ok(Some(t0));
+    }
+    if fb == 0.0 {
+        return Ok(Some(t1));
+    }
+    // Same sign at both ends (or NaN): nothing bracketed
+    if fa * fb >= 0.0 || fa.is_nan() || fb.is_nan() {
+        return Ok(None);
+    }
+
+    let s = brent(|s| f(offset(t0, s)), 0.0, span, fa, fb, tol);
+    Ok(Some(offset(t0, s)))
+}
+
+/// Finds all zero crossings of `f` in `[t0, t1]`.
+///
+/// The interval is sampled every `step` and each sign change is refined with
+/// Brent's method. Roots closer together than `step` may be missed, so the
+/// step should be shorter than the fastest expected variation (e.g. 10-30
+/// minutes for altitude crossings).
+///
+/// # Arguments
+/// * `f` - Function of time whose roots are sought
+/// * `t0` - Start of the search interval
+/// * `t1` - End of the search interval
+/// * `step` - Sampling step
+/// * `tol` - Time tolerance of each result
+///
+/// # Returns
+/// Chronologically ordered `(time, direction)` pairs
+///
+/// # Errors
+/// - `AstroError::InvalidDateTime` if `t1` is not after `t0`
+/// - `AstroError::OutOfRange` if `step` or `tol` is not positive
+///
+/// # Example
+/// ```
+/// use astro_math::events::{find_zeros, Crossing};
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// // A function with a 12-hour period
+/// let f = |t: chrono::DateTime<Utc>| {
+///     let hours = (t - t0).num_seconds() as f64 / 3600.0;
+///     (hours * std::f64::consts::PI / 6.0).sin()
+/// };
+/// let roots = find_zeros(f, t0 + Duration::minutes(1), t0 + Duration::hours(23),
+///                        Duration::minutes(30), Duration::seconds(1)).unwrap();
+/// assert_eq!(roots.len(), 3);
+/// assert_eq!(roots[0].1, Crossing::Falling);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(%t0, %t1)))]
+pub fn find_zeros<F>(
+    mut f: F,
+    t0: DateTime<Utc>,
+    t1: DateTime<Utc>,
+    step: Duration,
+    tol: Duration,
+) -> Result<Vec<(DateTime<Utc>, Crossing)>>
+where
+    F: FnMut(DateTime<Utc>) -> f64,
+{
+    let span = validate_interval(t0, t1)?;
+    let step = validate_positive(step, "step")?;
+    let tol = validate_positive(tol, "tolerance")?;
+
+    let mut roots = Vec::new();
+    let mut a = 0.0;
+    let mut fa = f(t0);
+
+    while a < span {
+        let b = (a + step).min(span);
+        let fb = f(offset(t0, b));
+
+        if fb == 0.0 && fa != 0.0 {
+            // Exact hit on a sample
+            let dir = if fa < 0.0 { Crossing::Rising } else { Crossing::Falling };
+            roots.push((offset(t0, b), dir));
+        } else if fa * fb < 0.0 {
+            let s = brent(|s| f(offset(t0, s)), a, b, fa, fb, tol);
+            let dir = if fa < 0.0 { Crossing::Rising } else { Crossing::Falling };
+            roots.push((offset(t0, s), dir));
+        }
+
+        a = b;
+        fa = fb;
+    }
+
+    trace_event!(debug, count = roots.len(), "zero crossings found");
+    Ok(roots)
+}
+
+/// Finds the time of the minimum or maximum of `f` in `[t0, t1]`.
+///
+/// Uses golden-section search, which assumes `f` is unimodal on the interval.
+/// For functions with several extrema, narrow the interval first (for example
+/// around a transit time) or scan with [`find_zeros`] on a finite difference.
+///
+/// # Arguments
+/// * `f` - Function of time to optimize
+/// * `t0` - Start of the search interval
+/// * `t1` - End of the search interval
+/// * `tol` - Time tolerance of the result
+/// * `kind` - Whether to search for a minimum or a maximum
+///
+/// # Returns
+/// `(time, value)` of the extremum
+///
+/// # Errors
+/// - `AstroError::InvalidDateTime` if `t1` is not after `t0`
+/// - `AstroError::OutOfRange` if `tol` is not positive
+///
+/// # Example
+/// ```
+/// use astro_math::events::{find_extremum, Extremum};
+/// use astro_math::{Location, ra_dec_to_alt_az};
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let loc = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+/// let t0 = Utc.with_ymd_and_hms(2024, 8, 4, 22, 0, 0).unwrap();
+/// let altitude = |t| ra_dec_to_alt_az(279.23, 38.78, t, &loc).unwrap().0;
+///
+/// let (_, max_alt) = find_extremum(altitude, t0, t0 + Duration::hours(8),
+///                                  Duration::seconds(1), Extremum::Maximum).unwrap();
+/// // Culmination altitude is 90° - |lat - dec|
+/// assert!((max_alt - (90.0 - (40.0f64 - 38.78).abs())).abs() < 0.1);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(%t0, %t1, ?kind)))]
+pub fn find_extremum<F>(
+    mut f: F,
+    t0: DateTime<Utc>,
+    t1: DateTime<Utc>,
+    tol: Duration,
+    kind: Extremum,
+) -> Result<(DateTime<Utc>, f64)>
+where
+    F: FnMut(DateTime<Utc>) -> f64,
+{
+    let span = validate_interval(t0, t1)?;
+    let tol = validate_positive(tol, "tolerance")?;
+
+    let sign = match kind {
+        Extremum::Minimum => 1.0,
+        Extremum::Maximum => -1.0,
+    };
+    let mut g = |s: f64| sign * f(offset(t0, s));
+
+    let inv_phi = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b) = (0.0, span);
+    let mut c = b - inv_phi * (b - a);
+    let mut d = a + inv_phi * (b - a);
+    let mut fc = g(c);
+    let mut fd = g(d);
+
+    while (b - a).abs() > tol {
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - inv_phi * (b - a);
+            fc = g(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + inv_phi * (b - a);
+            fd = g(d);
+        }
+    }
+
+    // The extremum may sit on an interval boundary
+    let mid = (a + b) / 2.0;
+    let candidates = [(0.0, g(0.0)), (mid, g(mid)), (span, g(span))];
+    let (s, value) = candidates
+        .iter()
+        .copied()
+        .min_by(|x, y| x.1.total_cmp(&y.1))
+        .unwrap();
+
+    trace_event!(debug, value = sign * value, "extremum found");
+    Ok((offset(t0, s), sign * value))
+}
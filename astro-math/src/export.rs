@@ -0,0 +1,515 @@
+//! Export of visibility curves, rise/set and almanac tables and event lists.
+//!
+//! Observation planning results usually end up in a spreadsheet, a web page
+//! or a calendar. This module renders the crate's results in the common
+//! interchange formats so that every application does not need its own
+//! exporter:
+//!
+//! - **CSV** (RFC 4180) with a header row, for spreadsheets and plotting
+//! - **JSON** arrays of flat objects, for web front ends
+//! - **iCalendar** (RFC 5545) for events such as rises, transits, meridian
+//!   flips and twilight, for import into scheduling calendars
+//!
+//! All times are written in UTC as ISO 8601 (`2024-08-04T06:00:00Z`).
+//! Missing values (a set time for a circumpolar object, the airmass of an
+//! object below the horizon) are empty in CSV and `null` in JSON.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::export::{rise_set_table, rise_set_to_csv};
+//! use astro_math::Location;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+//! let start = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+//!
+//! // A week of rise/transit/set times for M31
+//! let table = rise_set_table(10.6847, 41.2689, start, 7, &location).unwrap();
+//! let csv = rise_set_to_csv(&table);
+//! assert!(csv.starts_with("date,rise,transit,set\r\n"));
+//! assert_eq!(csv.lines().count(), 8);
+//! ```
+//!
+//! # Error Handling
+//!
+//! Rendering never fails. The table builders return the errors of the
+//! calculations they wrap:
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::OutOfRange` for a non-positive sampling step
+
+use crate::airmass::airmass_kasten_young;
+use crate::almanac::AlmanacRow;
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::events::Crossing;
+use crate::rise_set::rise_transit_set;
+use crate::transforms::ra_dec_to_alt_az;
+use crate::Location;
+use chrono::{DateTime, Duration, Utc};
+
+/// One point of a visibility curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VisibilitySample {
+    /// Time of the sample
+    pub time: DateTime<Utc>,
+    /// Altitude in degrees
+    pub altitude_deg: f64,
+    /// Azimuth in degrees
+    pub azimuth_deg: f64,
+    /// Kasten & Young airmass, `None` below the horizon
+    pub airmass: Option<f64>,
+}
+
+/// Rise, transit and set times for one day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RiseSetRow {
+    /// Day the times were calculated for (00:00 UTC)
+    pub date: DateTime<Utc>,
+    /// Rise time, `None` if the object is circumpolar or never rises
+    pub rise: Option<DateTime<Utc>>,
+    /// Meridian transit time, `None` in the same cases as `rise`
+    pub transit: Option<DateTime<Utc>>,
+    /// Set time, `None` if the object is circumpolar or never rises
+    pub set: Option<DateTime<Utc>>,
+}
+
+/// Kind of an [`AstroEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    /// Object rises above the horizon or a chosen altitude
+    Rise,
+    /// Object sets below the horizon or a chosen altitude
+    Set,
+    /// Object crosses the meridian
+    Transit,
+    /// German equatorial mount must flip sides of the pier
+    MeridianFlip,
+    /// Start or end of civil, nautical or astronomical twilight
+    Twilight,
+    /// Anything else
+    Other,
+}
+
+impl EventKind {
+    /// Name used in CSV/JSON output and as the iCalendar category.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventKind::Rise => "rise",
+            EventKind::Set => "set",
+            EventKind::Transit => "transit",
+            EventKind::MeridianFlip => "meridian_flip",
+            EventKind::Twilight => "twilight",
+            EventKind::Other => "other",
+        }
+    }
+}
+
+/// A timed astronomical event, e.g. for a calendar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AstroEvent {
+    /// What kind of event this is
+    pub kind: EventKind,
+    /// Start of the event
+    pub start: DateTime<Utc>,
+    /// End of the event, `None` for an instant
+    pub end: Option<DateTime<Utc>>,
+    /// Short description, e.g. `"M31 transit"` or `"Astronomical dusk"`
+    pub summary: String,
+}
+
+impl AstroEvent {
+    /// Creates an instantaneous event.
+    pub fn new(kind: EventKind, start: DateTime<Utc>, summary: impl Into<String>) -> Self {
+        Self { kind, start, end: None, summary: summary.into() }
+    }
+}
+
+impl RiseSetRow {
+    /// Converts the row to rise, transit and set events for `name`.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::export::{rise_set_table, EventKind};
+    /// use astro_math::Location;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    /// let start = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+    /// let table = rise_set_table(10.6847, 41.2689, start, 1, &location).unwrap();
+    ///
+    /// let events = table[0].events("M31");
+    /// assert_eq!(events[1].kind, EventKind::Transit);
+    /// assert_eq!(events[1].summary, "M31 transit");
+    /// ```
+    pub fn events(&self, name: &str) -> Vec<AstroEvent> {
+        [(EventKind::Rise, self.rise), (EventKind::Transit, self.transit), (EventKind::Set, self.set)]
+            .into_iter()
+            .filter_map(|(kind, time)| time.map(|t| AstroEvent::new(kind, t, format!("{} {}", name, kind.name()))))
+            .collect()
+    }
+}
+
+/// Converts altitude crossings (e.g. from
+/// [`altitude_crossings`](crate::rise_set::altitude_crossings)) to rise and
+/// set events for `name`.
+pub fn events_from_crossings(name: &str, crossings: &[(DateTime<Utc>, Crossing)]) -> Vec<AstroEvent> {
+    crossings
+        .iter()
+        .map(|&(time, direction)| {
+            let kind = match direction {
+                Crossing::Rising => EventKind::Rise,
+                Crossing::Falling => EventKind::Set,
+            };
+            AstroEvent::new(kind, time, format!("{} {}", name, kind.name()))
+        })
+        .collect()
+}
+
+/// Samples the altitude, azimuth and airmass of an object over a time span.
+///
+/// # Arguments
+/// * `ra`, `dec` - Object position in degrees
+/// * `start`, `end` - Time span; both ends are included
+/// * `step` - Sampling interval
+/// * `location` - Observer's location
+///
+/// # Returns
+/// Samples in time order
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if `ra` or `dec` is out of range
+/// - `AstroError::OutOfRange` if `step` is not positive
+///
+/// # Example
+/// ```
+/// use astro_math::export::visibility_curve;
+/// use astro_math::Location;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+/// let start = Utc.with_ymd_and_hms(2024, 10, 1, 22, 0, 0).unwrap();
+/// let curve = visibility_curve(10.6847, 41.2689, start, start + Duration::hours(8),
+///                              Duration::minutes(30), &location).unwrap();
+/// assert_eq!(curve.len(), 17);
+/// ```
+pub fn visibility_curve(
+    ra: f64,
+    dec: f64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: Duration,
+    location: &Location,
+) -> Result<Vec<VisibilitySample>> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    if step <= Duration::zero() {
+        return Err(AstroError::OutOfRange {
+            parameter: "step",
+            value: step.num_milliseconds() as f64 / 1000.0,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+
+    let mut samples = Vec::new();
+    let mut time = start;
+    while time <= end {
+        let (altitude_deg, azimuth_deg) = ra_dec_to_alt_az(ra, dec, time, location)?;
+        let airmass = if altitude_deg > 0.0 { airmass_kasten_young(altitude_deg).ok() } else { None };
+        samples.push(VisibilitySample { time, altitude_deg, azimuth_deg, airmass });
+        time += step;
+    }
+    Ok(samples)
+}
+
+/// Calculates rise, transit and set times for consecutive days.
+///
+/// # Arguments
+/// * `ra`, `dec` - Object position in degrees
+/// * `start` - First day (the time of day is ignored)
+/// * `days` - Number of days
+/// * `location` - Observer's location
+///
+/// # Returns
+/// One [`RiseSetRow`] per day, as from
+/// [`rise_transit_set`] with the
+/// default horizon altitude
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if `ra` or `dec` is out of range.
+pub fn rise_set_table(
+    ra: f64,
+    dec: f64,
+    start: DateTime<Utc>,
+    days: u32,
+    location: &Location,
+) -> Result<Vec<RiseSetRow>> {
+    let first = start.date_naive().and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+    (0..days)
+        .map(|day| {
+            let date = first + Duration::days(day as i64);
+            let times = rise_transit_set(ra, dec, date, location, None)?;
+            Ok(RiseSetRow {
+                date,
+                rise: times.map(|t| t.0),
+                transit: times.map(|t| t.1),
+                set: times.map(|t| t.2),
+            })
+        })
+        .collect()
+}
+
+/// Renders a visibility curve as CSV.
+///
+/// Columns: `time,altitude_deg,azimuth_deg,airmass`.
+pub fn visibility_to_csv(samples: &[VisibilitySample]) -> String {
+    let mut out = String::from("time,altitude_deg,azimuth_deg,airmass\r\n");
+    for s in samples {
+        out.push_str(&format!(
+            "{},{:.4},{:.4},{}\r\n",
+            iso(s.time),
+            s.altitude_deg,
+            s.azimuth_deg,
+            s.airmass.map(|x| format!("{:.4}", x)).unwrap_or_default()
+        ));
+    }
+    out
+}
+
+/// Renders a visibility curve as a JSON array.
+pub fn visibility_to_json(samples: &[VisibilitySample]) -> String {
+    json_array(samples.iter().map(|s| {
+        format!(
+            "{{\"time\":\"{}\",\"altitude_deg\":{},\"azimuth_deg\":{},\"airmass\":{}}}",
+            iso(s.time),
+            json_number(Some(s.altitude_deg)),
+            json_number(Some(s.azimuth_deg)),
+            json_number(s.airmass)
+        )
+    }))
+}
+
+/// Renders a rise/set table as CSV.
+///
+/// Columns: `date,rise,transit,set`, with the date as `YYYY-MM-DD`.
+pub fn rise_set_to_csv(rows: &[RiseSetRow]) -> String {
+    let mut out = String::from("date,rise,transit,set\r\n");
+    for row in rows {
+        out.push_str(&format!(
+            "{},{},{},{}\r\n",
+            row.date.format("%Y-%m-%d"),
+            row.rise.map(iso).unwrap_or_default(),
+            row.transit.map(iso).unwrap_or_default(),
+            row.set.map(iso).unwrap_or_default()
+        ));
+    }
+    out
+}
+
+/// Renders a rise/set table as a JSON array.
+pub fn rise_set_to_json(rows: &[RiseSetRow]) -> String {
+    json_array(rows.iter().map(|row| {
+        format!(
+            "{{\"date\":\"{}\",\"rise\":{},\"transit\":{},\"set\":{}}}",
+            row.date.format("%Y-%m-%d"),
+            json_time(row.rise),
+            json_time(row.transit),
+            json_time(row.set)
+        )
+    }))
+}
+
+/// Renders an almanac table as CSV.
+///
+/// Columns: `date,rise,transit,set,ra_deg,dec_deg,distance_au,magnitude`,
+/// with the date as `YYYY-MM-DD`.
+pub fn almanac_to_csv(rows: &[AlmanacRow]) -> String {
+    let mut out = String::from("date,rise,transit,set,ra_deg,dec_deg,distance_au,magnitude\r\n");
+    for row in rows {
+        out.push_str(&format!(
+            "{},{},{},{},{:.4},{:.4},{:.6},{:.2}\r\n",
+            row.date.format("%Y-%m-%d"),
+            row.rise.map(iso).unwrap_or_default(),
+            row.transit.map(iso).unwrap_or_default(),
+            row.set.map(iso).unwrap_or_default(),
+            row.ra,
+            row.dec,
+            row.distance_au,
+            row.magnitude
+        ));
+    }
+    out
+}
+
+/// Renders an almanac table as a JSON array.
+pub fn almanac_to_json(rows: &[AlmanacRow]) -> String {
+    json_array(rows.iter().map(|row| {
+        format!(
+            "{{\"date\":\"{}\",\"rise\":{},\"transit\":{},\"set\":{},\"ra_deg\":{},\"dec_deg\":{},\"distance_au\":{},\"magnitude\":{}}}",
+            row.date.format("%Y-%m-%d"),
+            json_time(row.rise),
+            json_time(row.transit),
+            json_time(row.set),
+            json_number(Some(row.ra)),
+            json_number(Some(row.dec)),
+            json_number(Some(row.distance_au)),
+            json_number(Some(row.magnitude))
+        )
+    }))
+}
+
+/// Renders an event list as CSV.
+///
+/// Columns: `kind,start,end,summary`.
+pub fn events_to_csv(events: &[AstroEvent]) -> String {
+    let mut out = String::from("kind,start,end,summary\r\n");
+    for event in events {
+        out.push_str(&format!(
+            "{},{},{},{}\r\n",
+            event.kind.name(),
+            iso(event.start),
+            event.end.map(iso).unwrap_or_default(),
+            csv_field(&event.summary)
+        ));
+    }
+    out
+}
+
+/// Renders an event list as a JSON array.
+pub fn events_to_json(events: &[AstroEvent]) -> String {
+    json_array(events.iter().map(|event| {
+        format!(
+            "{{\"kind\":\"{}\",\"start\":\"{}\",\"end\":{},\"summary\":{}}}",
+            event.kind.name(),
+            iso(event.start),
+            json_time(event.end),
+            json_string(&event.summary)
+        )
+    }))
+}
+
+/// Renders an event list as an iCalendar (`.ics`) file.
+///
+/// Each event becomes a `VEVENT` with the kind as its category. Instants
+/// are written with `DTEND` equal to `DTSTART`. UIDs are derived from the
+/// event time and position in the list, so re-exporting the same list
+/// updates rather than duplicates the events in a calendar.
+///
+/// # Arguments
+/// * `events` - Events to export
+/// * `calendar_name` - Calendar name shown by calendar applications
+///
+/// # Example
+/// ```
+/// use astro_math::export::{events_to_ics, AstroEvent, EventKind};
+/// use chrono::{TimeZone, Utc};
+///
+/// let dusk = Utc.with_ymd_and_hms(2024, 10, 1, 23, 35, 0).unwrap();
+/// let events = [AstroEvent::new(EventKind::Twilight, dusk, "Astronomical dusk")];
+///
+/// let ics = events_to_ics(&events, "Observing plan");
+/// assert!(ics.contains("DTSTART:20241001T233500Z\r\n"));
+/// assert!(ics.contains("SUMMARY:Astronomical dusk\r\n"));
+/// ```
+pub fn events_to_ics(events: &[AstroEvent], calendar_name: &str) -> String {
+    let stamp = ics_time(Utc::now());
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//astro-math//astro-math export//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", ics_text(calendar_name)),
+    ];
+    for (i, event) in events.iter().enumerate() {
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}-{}-{}@astro-math", ics_time(event.start), i, event.kind.name()),
+            format!("DTSTAMP:{}", stamp),
+            format!("DTSTART:{}", ics_time(event.start)),
+            format!("DTEND:{}", ics_time(event.end.unwrap_or(event.start))),
+            format!("SUMMARY:{}", ics_text(&event.summary)),
+            format!("CATEGORIES:{}", event.kind.name().to_uppercase()),
+            "TRANSP:TRANSPARENT".to_string(),
+            "END:VEVENT".to_string(),
+        ]);
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold_ics_line(line)).collect()
+}
+
+fn iso(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+fn ics_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn json_array(items: impl Iterator<Item = String>) -> String {
+    format!("[{}]", items.collect::<Vec<_>>().join(","))
+}
+
+/// JSON has no NaN or infinity, so non-finite values become `null`.
+fn json_number(value: Option<f64>) -> String {
+    match value {
+        Some(x) if x.is_finite() => format!("{}", x),
+        _ => "null".to_string(),
+    }
+}
+
+fn json_time(time: Option<DateTime<Utc>>) -> String {
+    time.map(|t| format!("\"{}\"", iso(t))).unwrap_or_else(|| "null".to_string())
+}
+
+fn json_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Escapes an iCalendar TEXT value (RFC 5545 §3.3.11).
+fn ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line at 75 octets without splitting a UTF-8 character
+/// and terminates it with CRLF (RFC 5545 §3.1).
+fn fold_ics_line(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+    out
+}
@@ -0,0 +1,361 @@
+//! Precomputed observer geometry for a fixed site.
+//!
+//! Services that answer many requests for a single observatory (pointing
+//! servers, schedulers, alert brokers) call the transforms millions of
+//! times with the same [`Location`]. A [`FixedSite`] computes everything
+//! that depends only on the site once: the sine and cosine of the latitude,
+//! the geocentric factors ρ·sin φ′ and ρ·cos φ′ used for parallax, and the
+//! pressure and temperature scale of the refraction formula. Its methods
+//! run the same math as the free functions and return the same results.
+//!
+//! For many targets at one instant, [`FixedSite::ra_dec_to_alt_az_batch`]
+//! also computes the local sidereal time only once, and
+//! [`FixedSite::ra_dec_to_alt_az_batch_rad`] does the same for positions
+//! already in radians.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::{ra_dec_to_alt_az, Location};
+//! use astro_math::refraction::AtmosphericConditions;
+//! use astro_math::fixed_site::FixedSite;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let kitt_peak = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+//! let site = FixedSite::new(kitt_peak)
+//!     .unwrap()
+//!     .with_conditions(AtmosphericConditions { pressure_hpa: 790.0, temperature_c: 5.0, relative_humidity: 0.2 })
+//!     .unwrap();
+//!
+//! let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+//! let (alt, az) = site.ra_dec_to_alt_az(279.23473479, 38.78368896, dt).unwrap();
+//! assert_eq!((alt, az), ra_dec_to_alt_az(279.23473479, 38.78368896, dt, &kitt_peak).unwrap());
+//!
+//! let apparent = site.apparent_altitude(alt).unwrap();
+//! assert!(apparent > alt);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for an out-of-range latitude, longitude or
+//!   altitude, weather outside the ranges accepted by the refraction models,
+//!   or an altitude outside [-90, 90] degrees passed to the refraction methods
+//! - `AstroError::InvalidCoordinate` for out-of-range RA, Dec, altitude or
+//!   azimuth passed to the transforms
+
+use crate::error::{validate_dec, validate_ra, validate_range, AstroError, Result};
+use crate::location::Location;
+use crate::parallax::{diurnal_parallax_from_lst, geocentric_factors};
+use crate::refraction::{saemundsson_scale, saemundsson_scaled, AtmosphericConditions};
+use crate::transforms::{
+    alt_az_from_lst, alt_az_from_lst_rad, ra_dec_from_lst, ra_dec_from_lst_rad, validate_alt_az,
+    validate_alt_az_rad, validate_ra_dec_rad, HOURS_TO_RAD,
+};
+use chrono::{DateTime, Utc};
+
+/// An observer location with its site-dependent quantities precomputed.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedSite {
+    location: Location,
+    conditions: AtmosphericConditions,
+    sin_lat: f64,
+    cos_lat: f64,
+    rho_sin_phi: f64,
+    rho_cos_phi: f64,
+    refraction_scale: f64,
+}
+
+impl FixedSite {
+    /// Creates a fixed site under [`AtmosphericConditions::standard`].
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if the latitude is outside
+    /// [-90, 90], the longitude outside [-180, 180], or the altitude is not
+    /// finite.
+    pub fn new(location: Location) -> Result<Self> {
+        validate_range(location.latitude_deg, -90.0, 90.0, "latitude")?;
+        validate_range(location.longitude_deg, -180.0, 180.0, "longitude")?;
+        if !location.altitude_m.is_finite() {
+            return Err(AstroError::OutOfRange {
+                parameter: "altitude_m",
+                value: location.altitude_m,
+                min: f64::MIN,
+                max: f64::MAX,
+            });
+        }
+
+        let (sin_lat, cos_lat) = location.latitude_deg.to_radians().sin_cos();
+        let (rho_sin_phi, rho_cos_phi) = geocentric_factors(&location);
+        let conditions = AtmosphericConditions::standard();
+        Ok(FixedSite {
+            location,
+            conditions,
+            sin_lat,
+            cos_lat,
+            rho_sin_phi,
+            rho_cos_phi,
+            refraction_scale: saemundsson_scale(conditions.pressure_hpa, conditions.temperature_c),
+        })
+    }
+
+    /// Replaces the weather used by the refraction methods.
+    ///
+    /// Refraction uses Saemundsson's formula, which depends on pressure and
+    /// temperature only; humidity is kept for reference.
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if a weather value is out of range.
+    pub fn with_conditions(mut self, conditions: AtmosphericConditions) -> Result<Self> {
+        conditions.validate()?;
+        self.conditions = conditions;
+        self.refraction_scale = saemundsson_scale(conditions.pressure_hpa, conditions.temperature_c);
+        Ok(self)
+    }
+
+    /// The site's location.
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    /// The weather used by the refraction methods.
+    pub fn conditions(&self) -> &AtmosphericConditions {
+        &self.conditions
+    }
+
+    /// Sine of the geodetic latitude.
+    pub fn sin_latitude(&self) -> f64 {
+        self.sin_lat
+    }
+
+    /// Cosine of the geodetic latitude.
+    pub fn cos_latitude(&self) -> f64 {
+        self.cos_lat
+    }
+
+    /// ρ·sin φ′ in Earth radii, where φ′ is the geocentric latitude.
+    pub fn rho_sin_phi(&self) -> f64 {
+        self.rho_sin_phi
+    }
+
+    /// ρ·cos φ′ in Earth radii, where φ′ is the geocentric latitude.
+    pub fn rho_cos_phi(&self) -> f64 {
+        self.rho_cos_phi
+    }
+
+    /// Distance from the Earth's centre in Earth radii, as
+    /// [`geocentric_distance`](crate::parallax::geocentric_distance).
+    pub fn geocentric_distance(&self) -> f64 {
+        self.rho_sin_phi.hypot(self.rho_cos_phi)
+    }
+
+    /// Local apparent sidereal time in hours, as [`Location::local_sidereal_time`].
+    pub fn local_sidereal_time(&self, datetime: DateTime<Utc>) -> f64 {
+        self.location.local_sidereal_time(datetime)
+    }
+
+    /// Converts RA/Dec to Alt/Az, as [`ra_dec_to_alt_az`](crate::transforms::ra_dec_to_alt_az).
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+    pub fn ra_dec_to_alt_az(&self, ra_deg: f64, dec_deg: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+        validate_ra(ra_deg)?;
+        validate_dec(dec_deg)?;
+        let lst_hours = self.location.local_sidereal_time(datetime);
+        alt_az_from_lst(ra_deg, dec_deg, lst_hours, self.sin_lat, self.cos_lat)
+    }
+
+    /// Converts RA/Dec to Alt/Az for a two-part UTC Julian Date, as
+    /// [`ra_dec_to_alt_az_two_part`](crate::transforms::ra_dec_to_alt_az_two_part).
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+    pub fn ra_dec_to_alt_az_two_part(&self, ra_deg: f64, dec_deg: f64, jd1: f64, jd2: f64) -> Result<(f64, f64)> {
+        validate_ra(ra_deg)?;
+        validate_dec(dec_deg)?;
+        let lst_hours = self.location.local_sidereal_time_two_part(jd1, jd2);
+        alt_az_from_lst(ra_deg, dec_deg, lst_hours, self.sin_lat, self.cos_lat)
+    }
+
+    /// Converts many RA/Dec positions to Alt/Az at one instant.
+    ///
+    /// The local sidereal time is computed once for the whole batch.
+    ///
+    /// # Arguments
+    /// * `coords` - `(ra, dec)` pairs in degrees
+    /// * `datetime` - Time of observation (UTC)
+    ///
+    /// # Returns
+    /// One `(altitude, azimuth)` per input, in the same order
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` for the first RA or Dec
+    /// that is out of range.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::Location;
+    /// use astro_math::fixed_site::FixedSite;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let site = FixedSite::new(Location { latitude_deg: -30.24, longitude_deg: -70.74, altitude_m: 2715.0 }).unwrap();
+    /// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    /// let targets = [(83.82, -5.39), (201.37, -11.16), (279.23, 38.78)];
+    ///
+    /// let batch = site.ra_dec_to_alt_az_batch(&targets, dt).unwrap();
+    /// for (&(ra, dec), &alt_az) in targets.iter().zip(&batch) {
+    ///     assert_eq!(alt_az, site.ra_dec_to_alt_az(ra, dec, dt).unwrap());
+    /// }
+    /// ```
+    pub fn ra_dec_to_alt_az_batch(&self, coords: &[(f64, f64)], datetime: DateTime<Utc>) -> Result<Vec<(f64, f64)>> {
+        let lst_hours = self.location.local_sidereal_time(datetime);
+        coords
+            .iter()
+            .map(|&(ra_deg, dec_deg)| {
+                validate_ra(ra_deg)?;
+                validate_dec(dec_deg)?;
+                alt_az_from_lst(ra_deg, dec_deg, lst_hours, self.sin_lat, self.cos_lat)
+            })
+            .collect()
+    }
+
+    /// Converts RA/Dec to Alt/Az in radians, as
+    /// [`ra_dec_to_alt_az_rad`](crate::transforms::ra_dec_to_alt_az_rad).
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+    pub fn ra_dec_to_alt_az_rad(&self, ra_rad: f64, dec_rad: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+        validate_ra_dec_rad(ra_rad, dec_rad)?;
+        let lst_rad = self.location.local_sidereal_time(datetime) * HOURS_TO_RAD;
+        alt_az_from_lst_rad(ra_rad, dec_rad, lst_rad, self.sin_lat, self.cos_lat)
+    }
+
+    /// Converts many RA/Dec positions to Alt/Az at one instant, in radians.
+    ///
+    /// The radian counterpart of [`ra_dec_to_alt_az_batch`](Self::ra_dec_to_alt_az_batch),
+    /// for inputs that are already in radians.
+    ///
+    /// # Arguments
+    /// * `coords` - `(ra, dec)` pairs in radians
+    /// * `datetime` - Time of observation (UTC)
+    ///
+    /// # Returns
+    /// One `(altitude, azimuth)` in radians per input, in the same order
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` for the first RA or Dec
+    /// that is out of range.
+    pub fn ra_dec_to_alt_az_batch_rad(&self, coords: &[(f64, f64)], datetime: DateTime<Utc>) -> Result<Vec<(f64, f64)>> {
+        let lst_rad = self.location.local_sidereal_time(datetime) * HOURS_TO_RAD;
+        coords
+            .iter()
+            .map(|&(ra_rad, dec_rad)| {
+                validate_ra_dec_rad(ra_rad, dec_rad)?;
+                alt_az_from_lst_rad(ra_rad, dec_rad, lst_rad, self.sin_lat, self.cos_lat)
+            })
+            .collect()
+    }
+
+    /// Converts Alt/Az to RA/Dec, as [`alt_az_to_ra_dec`](crate::transforms::alt_az_to_ra_dec).
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if altitude or azimuth is out of range.
+    pub fn alt_az_to_ra_dec(&self, altitude_deg: f64, azimuth_deg: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+        validate_alt_az(altitude_deg, azimuth_deg)?;
+        let lst_hours = self.location.local_sidereal_time(datetime);
+        ra_dec_from_lst(altitude_deg, azimuth_deg, lst_hours, self.sin_lat, self.cos_lat)
+    }
+
+    /// Converts Alt/Az to RA/Dec in radians, as
+    /// [`alt_az_to_ra_dec_rad`](crate::transforms::alt_az_to_ra_dec_rad).
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if altitude or azimuth is out of range.
+    pub fn alt_az_to_ra_dec_rad(&self, altitude_rad: f64, azimuth_rad: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+        validate_alt_az_rad(altitude_rad, azimuth_rad)?;
+        let lst_rad = self.location.local_sidereal_time(datetime) * HOURS_TO_RAD;
+        ra_dec_from_lst_rad(altitude_rad, azimuth_rad, lst_rad, self.sin_lat, self.cos_lat)
+    }
+
+    /// Applies diurnal parallax, as [`diurnal_parallax`](crate::parallax::diurnal_parallax).
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+    /// - `AstroError::OutOfRange` if `distance_au` is not positive
+    pub fn diurnal_parallax(
+        &self,
+        ra: f64,
+        dec: f64,
+        distance_au: f64,
+        datetime: DateTime<Utc>,
+    ) -> Result<(f64, f64)> {
+        validate_ra(ra)?;
+        validate_dec(dec)?;
+        if distance_au <= 0.0 {
+            return Err(AstroError::OutOfRange {
+                parameter: "distance_au",
+                value: distance_au,
+                min: f64::MIN_POSITIVE,
+                max: f64::MAX,
+            });
+        }
+        let lst_hours = self.location.local_sidereal_time(datetime);
+        Ok(diurnal_parallax_from_lst(ra, dec, distance_au, lst_hours, self.rho_sin_phi, self.rho_cos_phi))
+    }
+
+    /// Refraction at an apparent altitude under the site's conditions, as
+    /// [`refraction_saemundsson`](crate::refraction::refraction_saemundsson).
+    ///
+    /// # Returns
+    /// Refraction correction in degrees
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if the altitude is outside [-90, 90] degrees.
+    pub fn refraction(&self, apparent_altitude_deg: f64) -> Result<f64> {
+        validate_altitude(apparent_altitude_deg)?;
+        Ok(self.refraction_unchecked(apparent_altitude_deg))
+    }
+
+    /// True altitude for an apparent altitude, as
+    /// [`apparent_to_true_altitude`](crate::refraction::apparent_to_true_altitude).
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if the altitude is outside [-90, 90] degrees.
+    pub fn true_altitude(&self, apparent_altitude_deg: f64) -> Result<f64> {
+        Ok(apparent_altitude_deg - self.refraction(apparent_altitude_deg)?)
+    }
+
+    /// Apparent altitude for a true altitude, as
+    /// [`true_to_apparent_altitude`](crate::refraction::true_to_apparent_altitude).
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if the altitude is outside [-90, 90] degrees.
+    pub fn apparent_altitude(&self, true_altitude_deg: f64) -> Result<f64> {
+        validate_altitude(true_altitude_deg)?;
+        let mut apparent = true_altitude_deg;
+        for _ in 0..5 {
+            validate_altitude(apparent)?;
+            apparent = true_altitude_deg + self.refraction_unchecked(apparent);
+        }
+        Ok(apparent)
+    }
+
+    fn refraction_unchecked(&self, altitude_deg: f64) -> f64 {
+        if altitude_deg < -1.0 {
+            0.0
+        } else {
+            saemundsson_scaled(altitude_deg, self.refraction_scale)
+        }
+    }
+}
+
+fn validate_altitude(altitude_deg: f64) -> Result<()> {
+    if !(-90.0..=90.0).contains(&altitude_deg) {
+        return Err(AstroError::OutOfRange {
+            parameter: "altitude",
+            value: altitude_deg,
+            min: -90.0,
+            max: 90.0,
+        });
+    }
+    Ok(())
+}
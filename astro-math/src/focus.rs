@@ -0,0 +1,185 @@
+//! Temperature compensation of telescope focus.
+//!
+//! Tubes and mirrors change length with temperature, so best focus drifts
+//! through the night. Most observatories log the focuser position found by
+//! each autofocus run alongside the tube temperature, and move the focuser
+//! between runs by a model fitted to that log. [`FocusModel::fit`] fits a
+//! linear or quadratic model of focus position against temperature by least
+//! squares, and [`FocusModel::offset`] gives the move for a change in
+//! temperature.
+//!
+//! Positions are in whatever units the focuser uses (usually steps);
+//! temperatures are in °C.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::focus::{FocusModel, FocusModelKind};
+//!
+//! // (temperature °C, best focus in steps) from autofocus runs
+//! let log = [(14.2, 12_180.0), (11.8, 12_231.0), (9.5, 12_279.0), (7.1, 12_332.0), (4.9, 12_378.0)];
+//! let model = FocusModel::fit(&log, FocusModelKind::Linear).unwrap();
+//!
+//! // About −21 steps per degree
+//! assert!((model.slope_at(10.0) + 21.4).abs() < 0.5);
+//! // Tube cooled from 9.5 °C to 8.0 °C since the last autofocus
+//! let correction = model.offset(9.5, 8.0);
+//! assert!((correction - 32.0).abs() < 1.0);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::CalculationError` for too few samples or distinct
+//!   temperatures to fit the model, or values that are not finite
+
+use crate::error::{AstroError, Result};
+use nalgebra::{DMatrix, DVector};
+
+/// Shape of a focus-versus-temperature model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FocusModelKind {
+    /// Position changes at a constant rate with temperature
+    #[default]
+    Linear,
+    /// Adds a curvature term, for tubes of mixed materials or wide
+    /// temperature ranges
+    Quadratic,
+}
+
+impl FocusModelKind {
+    /// Number of fitted coefficients
+    fn terms(self) -> usize {
+        match self {
+            FocusModelKind::Linear => 2,
+            FocusModelKind::Quadratic => 3,
+        }
+    }
+}
+
+/// A fitted focus position model.
+///
+/// The position at temperature `T` is
+/// `c0 + c1·(T − T0) + c2·(T − T0)²`, with `T0` the mean temperature of the
+/// samples (which keeps the fit well conditioned) and `c2 = 0` for a linear
+/// model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusModel {
+    kind: FocusModelKind,
+    reference_temperature_c: f64,
+    coefficients: [f64; 3],
+    rms: f64,
+    sample_count: usize,
+}
+
+impl FocusModel {
+    /// Fits a model to logged focus positions.
+    ///
+    /// # Arguments
+    /// * `samples` - `(temperature_c, position)` pairs, in any order
+    /// * `kind` - Linear or quadratic
+    ///
+    /// # Returns
+    /// The least-squares model
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::CalculationError)` if any value is not
+    /// finite, or there are fewer distinct temperatures than the model has
+    /// coefficients (two for linear, three for quadratic).
+    pub fn fit(samples: &[(f64, f64)], kind: FocusModelKind) -> Result<Self> {
+        if let Some(&(t, p)) = samples.iter().find(|(t, p)| !t.is_finite() || !p.is_finite()) {
+            return Err(AstroError::CalculationError {
+                calculation: "focus model fit",
+                reason: format!("sample ({}, {}) is not finite", t, p),
+            });
+        }
+        let mut temperatures: Vec<f64> = samples.iter().map(|s| s.0).collect();
+        temperatures.sort_by(f64::total_cmp);
+        temperatures.dedup();
+        if temperatures.len() < kind.terms() {
+            return Err(AstroError::CalculationError {
+                calculation: "focus model fit",
+                reason: format!(
+                    "need at least {} distinct temperatures, got {}",
+                    kind.terms(),
+                    temperatures.len()
+                ),
+            });
+        }
+
+        let reference = samples.iter().map(|s| s.0).sum::<f64>() / samples.len() as f64;
+        let design = DMatrix::from_fn(samples.len(), kind.terms(), |row, power| {
+            (samples[row].0 - reference).powi(power as i32)
+        });
+        let positions = DVector::from_iterator(samples.len(), samples.iter().map(|s| s.1));
+        let solution = design
+            .svd(true, true)
+            .solve(&positions, 1e-12)
+            .map_err(|reason| AstroError::CalculationError {
+                calculation: "focus model fit",
+                reason: reason.to_string(),
+            })?;
+        let mut coefficients = [0.0; 3];
+        coefficients[..kind.terms()].copy_from_slice(solution.as_slice());
+
+        let mut model = FocusModel {
+            kind,
+            reference_temperature_c: reference,
+            coefficients,
+            rms: 0.0,
+            sample_count: samples.len(),
+        };
+        let sum_sq: f64 = samples.iter().map(|&(t, p)| (p - model.position_at(t)).powi(2)).sum();
+        model.rms = (sum_sq / samples.len() as f64).sqrt();
+        Ok(model)
+    }
+
+    /// Focus position predicted for a temperature.
+    pub fn position_at(&self, temperature_c: f64) -> f64 {
+        let dt = temperature_c - self.reference_temperature_c;
+        let [c0, c1, c2] = self.coefficients;
+        c0 + dt * (c1 + dt * c2)
+    }
+
+    /// Focuser move for a change in temperature.
+    ///
+    /// Only the change matters, so this can be applied on top of a position
+    /// found by autofocus at `from_c` even if the model's absolute positions
+    /// have drifted (e.g. after a filter or camera change).
+    ///
+    /// # Returns
+    /// `position_at(to_c) − position_at(from_c)`
+    pub fn offset(&self, from_c: f64, to_c: f64) -> f64 {
+        self.position_at(to_c) - self.position_at(from_c)
+    }
+
+    /// Rate of change of focus position with temperature, per °C.
+    pub fn slope_at(&self, temperature_c: f64) -> f64 {
+        let [_, c1, c2] = self.coefficients;
+        c1 + 2.0 * c2 * (temperature_c - self.reference_temperature_c)
+    }
+
+    /// Shape of the model.
+    pub fn kind(&self) -> FocusModelKind {
+        self.kind
+    }
+
+    /// The reference temperature `T0` in °C.
+    pub fn reference_temperature(&self) -> f64 {
+        self.reference_temperature_c
+    }
+
+    /// Coefficients `[c0, c1, c2]` about the reference temperature.
+    pub fn coefficients(&self) -> [f64; 3] {
+        self.coefficients
+    }
+
+    /// Root-mean-square residual of the fitted samples, in position units.
+    pub fn rms(&self) -> f64 {
+        self.rms
+    }
+
+    /// Number of samples fitted.
+    pub fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+}
@@ -75,7 +75,7 @@ pub fn equatorial_to_galactic(ra: f64, dec: f64) -> Result<(f64, f64)> {
     
     // Use ERFA's ICRS to Galactic transformation
     // This implements the IAU standard galactic coordinate system
-    let (l_rad, b_rad) = erfars::galacticcoordinates::Icrs2g(ra_rad, dec_rad);
+    let (l_rad, b_rad) = crate::backend::galacticcoordinates::Icrs2g(ra_rad, dec_rad);
     
     // Convert to degrees and normalize longitude
     let mut l_deg = l_rad.to_degrees();
@@ -144,7 +144,7 @@ pub fn galactic_to_equatorial(l: f64, b: f64) -> Result<(f64, f64)> {
     let b_rad = b.to_radians();
     
     // Use ERFA's Galactic to ICRS transformation
-    let (ra_rad, dec_rad) = erfars::galacticcoordinates::G2icrs(l_rad, b_rad);
+    let (ra_rad, dec_rad) = crate::backend::galacticcoordinates::G2icrs(l_rad, b_rad);
     
     // Convert to degrees and normalize RA
     let mut ra_deg = ra_rad.to_degrees();
@@ -0,0 +1,156 @@
+//! Positions along the great circle between two points on the sky.
+//!
+//! The shortest path between two positions on the sphere is the arc of the
+//! great circle through them. Interpolating RA and Dec linearly instead
+//! bends the path away from it, badly so near the poles or across RA = 0°.
+//! These functions interpolate on unit vectors (spherical linear
+//! interpolation, "slerp"), so points are spaced evenly in angle along the
+//! true arc wherever the endpoints are — for smooth slew trajectories, or
+//! to draw the arc between two objects on a chart.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::great_circle::waypoints;
+//!
+//! // Across the north celestial pole: from RA 0° to RA 180° at Dec +80°
+//! let path = waypoints((0.0, 80.0), (180.0, 80.0), 3).unwrap();
+//! assert!((path[1].1 - 90.0).abs() < 1e-9);
+//! // Straight-line interpolation would have given (90, 80)
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::OutOfRange` for a non-finite fraction or fewer than two
+//!   waypoints
+//! - `AstroError::CalculationError` for antipodal endpoints, between which
+//!   the great circle is not unique
+
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::vec3::{angle_between, cross, norm, radec_to_unit_vector, unit_vector_to_radec, Vec3};
+
+/// Separations closer than this to 180°, in radians, count as antipodal
+const ANTIPODAL_TOLERANCE: f64 = 1e-9;
+
+/// Finds the position a fraction of the way from `a` to `b` along the
+/// great circle.
+///
+/// # Arguments
+/// * `a` - `(ra, dec)` of the start in degrees
+/// * `b` - `(ra, dec)` of the end in degrees
+/// * `fraction` - 0 at `a`, 1 at `b`; values outside [0, 1] continue along
+///   the same great circle
+///
+/// # Returns
+/// `(ra, dec)` in degrees with RA in [0, 360)
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if either position is out of range
+/// - `AstroError::OutOfRange` if `fraction` is not finite
+/// - `AstroError::CalculationError` if `a` and `b` are antipodal
+///
+/// # Example
+/// ```
+/// use astro_math::great_circle::interpolate;
+///
+/// // Across RA = 0°
+/// let (ra, dec) = interpolate((350.0, 0.0), (10.0, 0.0), 0.75).unwrap();
+/// assert!((ra - 5.0).abs() < 1e-9 && dec.abs() < 1e-9);
+/// ```
+pub fn interpolate(a: (f64, f64), b: (f64, f64), fraction: f64) -> Result<(f64, f64)> {
+    if !fraction.is_finite() {
+        return Err(AstroError::OutOfRange {
+            parameter: "fraction",
+            value: fraction,
+            min: f64::MIN,
+            max: f64::MAX,
+        });
+    }
+    let arc = Arc::new(a, b)?;
+    Ok(unit_vector_to_radec(arc.at(fraction)))
+}
+
+/// Divides the great circle from `a` to `b` into evenly spaced points.
+///
+/// # Arguments
+/// * `a` - `(ra, dec)` of the start in degrees
+/// * `b` - `(ra, dec)` of the end in degrees
+/// * `n` - Number of points, including both ends; at least 2
+///
+/// # Returns
+/// `n` positions as `(ra, dec)` in degrees, starting with `a` and ending
+/// with `b` exactly as given, equally spaced in angle between them
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if either position is out of range
+/// - `AstroError::OutOfRange` if `n` is less than 2
+/// - `AstroError::CalculationError` if `a` and `b` are antipodal
+///
+/// # Example
+/// ```
+/// use astro_math::great_circle::waypoints;
+///
+/// // A slew from Vega to Altair in 1° steps
+/// let (vega, altair) = ((279.2347, 38.7837), (297.6958, 8.8683));
+/// let path = waypoints(vega, altair, 36).unwrap();
+/// assert_eq!(path.len(), 36);
+/// assert_eq!(path[0], vega);
+/// assert_eq!(path[35], altair);
+/// ```
+pub fn waypoints(a: (f64, f64), b: (f64, f64), n: usize) -> Result<Vec<(f64, f64)>> {
+    if n < 2 {
+        return Err(AstroError::OutOfRange {
+            parameter: "n",
+            value: n as f64,
+            min: 2.0,
+            max: f64::MAX,
+        });
+    }
+    let arc = Arc::new(a, b)?;
+    let mut points = Vec::with_capacity(n);
+    points.push(a);
+    for k in 1..n - 1 {
+        points.push(unit_vector_to_radec(arc.at(k as f64 / (n - 1) as f64)));
+    }
+    points.push(b);
+    Ok(points)
+}
+
+/// A validated great-circle arc between two unit vectors.
+struct Arc {
+    a: Vec3,
+    b: Vec3,
+    /// Angle between the ends in radians
+    angle: f64,
+}
+
+impl Arc {
+    fn new(a: (f64, f64), b: (f64, f64)) -> Result<Self> {
+        for (ra, dec) in [a, b] {
+            validate_ra(ra)?;
+            validate_dec(dec)?;
+        }
+        let (pa, pb) = (radec_to_unit_vector(a.0, a.1), radec_to_unit_vector(b.0, b.1));
+        let angle = angle_between(pa, pb);
+        if std::f64::consts::PI - angle < ANTIPODAL_TOLERANCE {
+            return Err(AstroError::CalculationError {
+                calculation: "great-circle interpolation",
+                reason: format!("({}, {}) and ({}, {}) are antipodal", a.0, a.1, b.0, b.1),
+            });
+        }
+        Ok(Arc { a: pa, b: pb, angle })
+    }
+
+    /// Unit vector a fraction of the way along the arc
+    fn at(&self, fraction: f64) -> Vec3 {
+        if norm(cross(self.a, self.b)) < 1e-15 {
+            // Coincident ends
+            return self.a;
+        }
+        let sin = self.angle.sin();
+        let wa = ((1.0 - fraction) * self.angle).sin() / sin;
+        let wb = (fraction * self.angle).sin() / sin;
+        [0, 1, 2].map(|i| wa * self.a[i] + wb * self.b[i])
+    }
+}
@@ -0,0 +1,346 @@
+//! Local horizon profiles: trees, buildings and hills as an altitude mask.
+//!
+//! A real horizon is rarely flat. A [`Horizon`] holds the altitude of the
+//! visible horizon at a set of azimuths and interpolates linearly between
+//! them (wrapping through north), so that a target can be checked against
+//! the obstructions at the site rather than against 0°.
+//!
+//! Most observers have already measured their horizon for other software.
+//! It can be read from:
+//!
+//! - **CSV** with `azimuth,altitude` per line, optionally after a header
+//! - **Cartes du Ciel** `.hrz` files: `azimuth altitude` per line
+//! - **Stellarium** polygonal landscapes: the horizon list file, with the
+//!   angle units and rotation taken from the landscape's `landscape.ini`
+//!
+//! Azimuths are measured from north through east in all of these. Blank
+//! lines and lines starting with `#` or `;` are ignored.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::horizon::Horizon;
+//!
+//! // A tree line to the south-east
+//! let hrz = "\
+//! ; azimuth altitude
+//! 0 5
+//! 120 5
+//! 140 25
+//! 170 25
+//! 190 5
+//! ";
+//! let horizon = Horizon::from_hrz_str(hrz).unwrap();
+//! assert_eq!(horizon.altitude_at(155.0), 25.0);
+//! assert_eq!(horizon.altitude_at(130.0), 15.0);
+//! assert!(!horizon.is_visible(20.0, 150.0));
+//! assert!(horizon.is_visible(20.0, 300.0));
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidFormat` for lines that cannot be parsed, with the
+//!   offending line
+//! - `AstroError::OutOfRange` for an altitude outside [-90, 90] degrees or a
+//!   non-finite azimuth
+//! - `AstroError::CalculationError` for a profile with no points
+
+use crate::error::{AstroError, Result};
+use std::f64::consts::PI;
+
+/// An azimuth-dependent horizon altitude.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Horizon {
+    /// `(azimuth, altitude)` in degrees, azimuth in [0, 360) and non-decreasing
+    points: Vec<(f64, f64)>,
+}
+
+/// Angle units of a Stellarium polygonal horizon list.
+///
+/// Named after the values of `polygonal_horizon_list_mode` in
+/// `landscape.ini`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StellariumHorizonMode {
+    /// Azimuth and altitude in degrees (`azDeg_altDeg`, the default)
+    #[default]
+    AzDegAltDeg,
+    /// Azimuth and zenith distance in degrees (`azDeg_zdDeg`)
+    AzDegZdDeg,
+    /// Azimuth and altitude in radians (`azRad_altRad`)
+    AzRadAltRad,
+    /// Azimuth and zenith distance in radians (`azRad_zdRad`)
+    AzRadZdRad,
+    /// Azimuth and altitude in gradians (`azGrad_altGrad`)
+    AzGradAltGrad,
+    /// Azimuth and zenith distance in gradians (`azGrad_zdGrad`)
+    AzGradZdGrad,
+}
+
+impl StellariumHorizonMode {
+    /// Converts an `(azimuth, second)` pair to `(azimuth, altitude)` in degrees
+    fn to_degrees(self, az: f64, value: f64) -> (f64, f64) {
+        use StellariumHorizonMode::*;
+        let scale = match self {
+            AzDegAltDeg | AzDegZdDeg => 1.0,
+            AzRadAltRad | AzRadZdRad => 180.0 / PI,
+            AzGradAltGrad | AzGradZdGrad => 0.9,
+        };
+        let (az, value) = (az * scale, value * scale);
+        match self {
+            AzDegZdDeg | AzRadZdRad | AzGradZdGrad => (az, 90.0 - value),
+            _ => (az, value),
+        }
+    }
+}
+
+impl std::str::FromStr for StellariumHorizonMode {
+    type Err = AstroError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        use StellariumHorizonMode::*;
+        match s.trim() {
+            "azDeg_altDeg" => Ok(AzDegAltDeg),
+            "azDeg_zdDeg" => Ok(AzDegZdDeg),
+            "azRad_altRad" => Ok(AzRadAltRad),
+            "azRad_zdRad" => Ok(AzRadZdRad),
+            "azGrad_altGrad" => Ok(AzGradAltGrad),
+            "azGrad_zdGrad" => Ok(AzGradZdGrad),
+            other => Err(AstroError::InvalidFormat {
+                format: "Stellarium landscape",
+                input: other.to_string(),
+                reason: "unknown polygonal_horizon_list_mode".to_string(),
+            }),
+        }
+    }
+}
+
+impl Horizon {
+    /// Creates a horizon from `(azimuth, altitude)` points.
+    ///
+    /// Points may be in any order and azimuths outside [0, 360) are wrapped.
+    /// Two points at the same azimuth make a vertical step, such as the edge
+    /// of a building; they keep their input order.
+    ///
+    /// # Arguments
+    /// * `points` - `(azimuth, altitude)` pairs in degrees
+    ///
+    /// # Errors
+    /// - `AstroError::CalculationError` if `points` is empty
+    /// - `AstroError::OutOfRange` if an altitude is outside [-90, 90] or an
+    ///   azimuth is not finite
+    pub fn new(points: &[(f64, f64)]) -> Result<Self> {
+        if points.is_empty() {
+            return Err(AstroError::CalculationError {
+                calculation: "horizon profile",
+                reason: "no points".to_string(),
+            });
+        }
+        let mut wrapped = Vec::with_capacity(points.len());
+        for &(az, alt) in points {
+            if !az.is_finite() {
+                return Err(AstroError::OutOfRange {
+                    parameter: "azimuth",
+                    value: az,
+                    min: f64::MIN,
+                    max: f64::MAX,
+                });
+            }
+            if !(-90.0..=90.0).contains(&alt) {
+                return Err(AstroError::OutOfRange {
+                    parameter: "altitude",
+                    value: alt,
+                    min: -90.0,
+                    max: 90.0,
+                });
+            }
+            let az = az.rem_euclid(360.0);
+            wrapped.push((if az >= 360.0 { 0.0 } else { az }, alt));
+        }
+        wrapped.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(Horizon { points: wrapped })
+    }
+
+    /// A horizon at the same altitude in every direction.
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if `altitude` is outside [-90, 90].
+    pub fn flat(altitude: f64) -> Result<Self> {
+        Horizon::new(&[(0.0, altitude)])
+    }
+
+    /// The profile's points as `(azimuth, altitude)` in degrees, in order of
+    /// azimuth from 0 to 360.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Altitude of the horizon in a given direction.
+    ///
+    /// # Arguments
+    /// * `azimuth` - Azimuth in degrees; any value, wrapped to [0, 360)
+    ///
+    /// # Returns
+    /// Altitude in degrees, interpolated linearly in azimuth between the
+    /// neighbouring points, across north if need be
+    pub fn altitude_at(&self, azimuth: f64) -> f64 {
+        let n = self.points.len();
+        let az = azimuth.rem_euclid(360.0);
+        let k = self.points.partition_point(|p| p.0 <= az);
+        let (a0, h0) = if k == 0 { (self.points[n - 1].0 - 360.0, self.points[n - 1].1) } else { self.points[k - 1] };
+        let (a1, h1) = if k == n { (self.points[0].0 + 360.0, self.points[0].1) } else { self.points[k] };
+        h0 + (az - a0) / (a1 - a0) * (h1 - h0)
+    }
+
+    /// Tests whether a direction is above the horizon.
+    ///
+    /// # Arguments
+    /// * `altitude` - Altitude in degrees, apparent (refracted) to compare
+    ///   with a measured horizon
+    /// * `azimuth` - Azimuth in degrees
+    ///
+    /// # Returns
+    /// `true` if `altitude` is above the horizon at `azimuth`
+    pub fn is_visible(&self, altitude: f64, azimuth: f64) -> bool {
+        altitude > self.altitude_at(azimuth)
+    }
+
+    /// Reads a horizon from CSV.
+    ///
+    /// Each line holds `azimuth,altitude` in degrees; further columns are
+    /// ignored. The first line may be a header.
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidFormat` for a line without two numbers
+    /// - `AstroError::OutOfRange` for an out-of-range altitude
+    /// - `AstroError::CalculationError` if there are no points
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::horizon::Horizon;
+    ///
+    /// let horizon = Horizon::from_csv_str("azimuth,altitude\n0,10\n180,20\n").unwrap();
+    /// assert_eq!(horizon.altitude_at(90.0), 15.0);
+    /// assert_eq!(horizon.altitude_at(270.0), 15.0);
+    /// ```
+    pub fn from_csv_str(text: &str) -> Result<Self> {
+        let mut points = Vec::new();
+        for (i, line) in data_lines(text).enumerate() {
+            let mut fields = line.split(',').map(str::trim);
+            match number_pair(&mut fields) {
+                Some(point) => points.push(point),
+                // Header
+                None if i == 0 => continue,
+                None => return Err(bad_line("CSV horizon", line, "expected azimuth,altitude")),
+            }
+        }
+        Horizon::new(&points)
+    }
+
+    /// Reads a Cartes du Ciel horizon (`.hrz`) file.
+    ///
+    /// Each line holds an azimuth and an altitude in degrees separated by
+    /// spaces or tabs. The same layout is used by several other planetarium
+    /// and imaging programs.
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidFormat` for a line without two numbers
+    /// - `AstroError::OutOfRange` for an out-of-range altitude
+    /// - `AstroError::CalculationError` if there are no points
+    pub fn from_hrz_str(text: &str) -> Result<Self> {
+        let mut points = Vec::new();
+        for line in data_lines(text) {
+            let point = number_pair(&mut line.split_whitespace())
+                .ok_or_else(|| bad_line("Cartes du Ciel horizon", line, "expected azimuth and altitude"))?;
+            points.push(point);
+        }
+        Horizon::new(&points)
+    }
+
+    /// Reads a Stellarium polygonal landscape horizon.
+    ///
+    /// # Arguments
+    /// * `horizon_list` - Contents of the file named by
+    ///   `polygonal_horizon_list`: two numbers per line, separated by spaces,
+    ///   tabs or a comma
+    /// * `landscape_ini` - Contents of `landscape.ini`, for
+    ///   `polygonal_horizon_list_mode` (default `azDeg_altDeg`) and
+    ///   `polygonal_angle_rotatez` (default 0°, added to every azimuth);
+    ///   `None` to use the defaults
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidFormat` for a line without two numbers, or an
+    ///   unknown mode or non-numeric rotation in `landscape.ini`
+    /// - `AstroError::OutOfRange` for an out-of-range altitude
+    /// - `AstroError::CalculationError` if there are no points
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::horizon::Horizon;
+    ///
+    /// let ini = "\
+    /// [landscape]
+    /// name = Backyard
+    /// type = polygonal
+    /// polygonal_horizon_list = horizon.txt
+    /// polygonal_horizon_list_mode = azDeg_zdDeg
+    /// polygonal_angle_rotatez = 10
+    /// ";
+    /// let list = "0 80\n90 70\n180 85\n270 80\n";
+    /// let horizon = Horizon::from_stellarium_str(list, Some(ini)).unwrap();
+    /// // 90° in the file, rotated by 10°; zenith distance 70° is altitude 20°
+    /// assert_eq!(horizon.altitude_at(100.0), 20.0);
+    /// ```
+    pub fn from_stellarium_str(horizon_list: &str, landscape_ini: Option<&str>) -> Result<Self> {
+        let mut mode = StellariumHorizonMode::default();
+        let mut rotation = 0.0;
+        if let Some(ini) = landscape_ini {
+            let mut in_landscape = false;
+            for line in data_lines(ini) {
+                if line.starts_with('[') {
+                    in_landscape = line.eq_ignore_ascii_case("[landscape]");
+                    continue;
+                }
+                let Some((key, value)) = line.split_once('=') else { continue };
+                match key.trim() {
+                    "polygonal_horizon_list_mode" if in_landscape => mode = value.parse()?,
+                    "polygonal_angle_rotatez" if in_landscape => {
+                        rotation = value.trim().parse().map_err(|_| {
+                            bad_line("Stellarium landscape", line, "polygonal_angle_rotatez is not a number")
+                        })?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut points = Vec::new();
+        for line in data_lines(horizon_list) {
+            let mut fields = line.split(|c: char| c == ',' || c.is_whitespace()).filter(|f| !f.is_empty());
+            let (az, value) = number_pair(&mut fields)
+                .ok_or_else(|| bad_line("Stellarium horizon", line, "expected two numbers"))?;
+            let (az, alt) = mode.to_degrees(az, value);
+            points.push((az + rotation, alt));
+        }
+        Horizon::new(&points)
+    }
+}
+
+/// Non-blank, non-comment lines, trimmed
+fn data_lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#') && !l.starts_with(';'))
+}
+
+/// The first two fields as numbers
+fn number_pair<'a>(fields: &mut impl Iterator<Item = &'a str>) -> Option<(f64, f64)> {
+    let az = fields.next()?.parse().ok()?;
+    let alt = fields.next()?.parse().ok()?;
+    Some((az, alt))
+}
+
+fn bad_line(format: &'static str, line: &str, reason: &str) -> AstroError {
+    AstroError::InvalidFormat {
+        format,
+        input: line.to_string(),
+        reason: reason.to_string(),
+    }
+}
@@ -0,0 +1,331 @@
+//! Clear-sky solar irradiance and daily insolation.
+//!
+//! Requires the `irradiance` feature.
+//!
+//! [`clear_sky_irradiance`] estimates the sunlight reaching a horizontal
+//! surface under a cloudless sky with the simple broadband model of Bird
+//! and Hulstrom (1981), as distributed by NREL. The Sun's elevation comes
+//! from [`solar_azimuth_elevation`], the airmass from
+//! [`airmass_kasten_young`], and the irradiance at the top of the
+//! atmosphere from the solar constant scaled by the Earth–Sun distance of
+//! [`earth_heliocentric_distance`]. The model attenuates the direct beam by
+//! Rayleigh scattering, ozone, mixed gases, water vapour and aerosols, and
+//! adds the forward-scattered sky light and the light reflected back down
+//! between the ground and the sky.
+//!
+//! Against measured clear-sky data the model is good to about 5% for
+//! elevations above 10°; near the horizon it is only indicative. Clouds
+//! are not modelled: the results are an upper envelope for a real day.
+//!
+//! [`daily_insolation`] integrates the global horizontal irradiance over
+//! the 24 hours centred on solar noon, so the day is not cut in two for
+//! sites far from Greenwich.
+//!
+//! The atmosphere defaults to the values NREL suggests for a clear,
+//! mid-latitude sky ([`ClearSkyAtmosphere::default`]); the surface
+//! pressure follows the site's altitude unless given explicitly.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::irradiance::{clear_sky_irradiance, daily_insolation};
+//! use astro_math::Location;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let boulder = Location { latitude_deg: 40.0, longitude_deg: -105.25, altitude_m: 1650.0 };
+//!
+//! // Summer noon: about 1 kW/m² on the ground, most of it direct
+//! let noon = Utc.with_ymd_and_hms(2024, 6, 21, 19, 0, 0).unwrap();
+//! let sky = clear_sky_irradiance(noon, &boulder).unwrap();
+//! assert!(sky.ghi > 950.0 && sky.ghi < 1100.0);
+//! assert!(sky.dhi < 0.15 * sky.ghi);
+//!
+//! // A clear midsummer day gives three times the energy of midwinter
+//! let june = daily_insolation(noon, &boulder).unwrap();
+//! let december = daily_insolation(Utc.with_ymd_and_hms(2024, 12, 21, 0, 0, 0).unwrap(), &boulder).unwrap();
+//! assert!(june > 8.0 && june < 10.0);
+//! assert!(june / december > 2.5);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range latitude or longitude
+//! - `AstroError::OutOfRange` for atmospheric parameters outside their
+//!   physical range
+
+use crate::airmass::airmass_kasten_young;
+use crate::earth::earth_heliocentric_distance;
+use crate::error::{validate_latitude, validate_longitude, validate_range, Result};
+use crate::location::Location;
+use crate::sun::{solar_azimuth_elevation, solar_noon};
+use crate::time::julian_date;
+use crate::time_scales::utc_to_tt_jd;
+use chrono::{DateTime, Duration, Utc};
+
+/// Total solar irradiance at 1 AU in W/m² (Kopp & Lean 2011).
+pub const SOLAR_CONSTANT_W_M2: f64 = 1361.0;
+
+/// Sampling interval for [`daily_insolation`], in minutes.
+const INSOLATION_STEP_MINUTES: i64 = 5;
+
+/// Atmospheric state for the clear-sky model.
+///
+/// The defaults are NREL's suggestions for a clear mid-latitude sky.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClearSkyAtmosphere {
+    /// Surface pressure in hPa; `None` derives it from the site's altitude
+    /// with the standard atmosphere
+    pub pressure_hpa: Option<f64>,
+    /// Total column ozone in atm-cm (0.3 = 300 Dobson units)
+    pub ozone_cm: f64,
+    /// Precipitable water in cm
+    pub precipitable_water_cm: f64,
+    /// Aerosol optical depth at 380 nm
+    pub aod_380nm: f64,
+    /// Aerosol optical depth at 500 nm
+    pub aod_500nm: f64,
+    /// Fraction of aerosol scattering directed forward, 0–1
+    pub aerosol_forward_scatter: f64,
+    /// Ground albedo, 0–1
+    pub albedo: f64,
+}
+
+impl Default for ClearSkyAtmosphere {
+    fn default() -> Self {
+        Self {
+            pressure_hpa: None,
+            ozone_cm: 0.3,
+            precipitable_water_cm: 1.5,
+            aod_380nm: 0.15,
+            aod_500nm: 0.1,
+            aerosol_forward_scatter: 0.85,
+            albedo: 0.2,
+        }
+    }
+}
+
+impl ClearSkyAtmosphere {
+    fn validate(&self) -> Result<()> {
+        if let Some(pressure) = self.pressure_hpa {
+            validate_range(pressure, 1.0, 1100.0, "pressure_hpa")?;
+        }
+        validate_range(self.ozone_cm, 0.0, 1.0, "ozone_cm")?;
+        validate_range(self.precipitable_water_cm, 0.0, 10.0, "precipitable_water_cm")?;
+        validate_range(self.aod_380nm, 0.0, 5.0, "aod_380nm")?;
+        validate_range(self.aod_500nm, 0.0, 5.0, "aod_500nm")?;
+        validate_range(self.aerosol_forward_scatter, 0.0, 1.0, "aerosol_forward_scatter")?;
+        validate_range(self.albedo, 0.0, 1.0, "albedo")
+    }
+}
+
+/// Clear-sky irradiance on the ground, all in W/m².
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClearSkyIrradiance {
+    /// Global horizontal irradiance: direct plus diffuse on a level surface
+    pub ghi: f64,
+    /// Direct normal irradiance: the beam on a surface facing the Sun
+    pub dni: f64,
+    /// Diffuse horizontal irradiance: sky light on a level surface
+    pub dhi: f64,
+    /// Irradiance on a level surface at the top of the atmosphere
+    pub extraterrestrial: f64,
+    /// Geometric elevation of the Sun's centre in degrees
+    pub elevation_deg: f64,
+}
+
+/// Estimates the clear-sky irradiance at a site with the default
+/// atmosphere.
+///
+/// # Arguments
+/// * `datetime` - Time of interest
+/// * `location` - Observer's location; its altitude sets the surface pressure
+///
+/// # Returns
+/// Global, direct normal and diffuse irradiance in W/m², all zero while the
+/// Sun is below the horizon
+///
+/// # Errors
+/// `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+/// range
+///
+/// # Example
+/// ```
+/// use astro_math::irradiance::clear_sky_irradiance;
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: 52.0, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let night = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+/// assert_eq!(clear_sky_irradiance(night, &site).unwrap().ghi, 0.0);
+/// ```
+pub fn clear_sky_irradiance(datetime: DateTime<Utc>, location: &Location) -> Result<ClearSkyIrradiance> {
+    clear_sky_irradiance_with_atmosphere(datetime, location, &ClearSkyAtmosphere::default())
+}
+
+/// Estimates the clear-sky irradiance at a site for a given atmosphere.
+///
+/// # Arguments
+/// * `datetime` - Time of interest
+/// * `location` - Observer's location
+/// * `atmosphere` - Ozone, water vapour, aerosols, albedo and pressure
+///
+/// # Returns
+/// Global, direct normal and diffuse irradiance in W/m², all zero while the
+/// Sun is below the horizon
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - `AstroError::OutOfRange` if an atmospheric parameter is negative or
+///   implausibly large, or the albedo or forward-scatter fraction is
+///   outside 0–1
+///
+/// # Example
+/// ```
+/// use astro_math::irradiance::{clear_sky_irradiance_with_atmosphere, ClearSkyAtmosphere};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: 33.0, longitude_deg: -112.0, altitude_m: 340.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 6, 21, 19, 30, 0).unwrap();
+/// let clean = clear_sky_irradiance_with_atmosphere(dt, &site, &ClearSkyAtmosphere::default()).unwrap();
+/// let dusty = ClearSkyAtmosphere { aod_380nm: 0.6, aod_500nm: 0.4, ..Default::default() };
+/// let dusty = clear_sky_irradiance_with_atmosphere(dt, &site, &dusty).unwrap();
+///
+/// // Haze takes from the beam and gives some of it back as sky light
+/// assert!(dusty.dni < 0.85 * clean.dni);
+/// assert!(dusty.dhi > clean.dhi);
+/// ```
+pub fn clear_sky_irradiance_with_atmosphere(
+    datetime: DateTime<Utc>,
+    location: &Location,
+    atmosphere: &ClearSkyAtmosphere,
+) -> Result<ClearSkyIrradiance> {
+    validate_latitude(location.latitude_deg)?;
+    validate_longitude(location.longitude_deg)?;
+    atmosphere.validate()?;
+
+    let (_, elevation_deg) = solar_azimuth_elevation(datetime, location)?;
+    if elevation_deg <= 0.0 {
+        return Ok(ClearSkyIrradiance {
+            ghi: 0.0,
+            dni: 0.0,
+            dhi: 0.0,
+            extraterrestrial: 0.0,
+            elevation_deg,
+        });
+    }
+
+    let r_au = earth_heliocentric_distance(utc_to_tt_jd(julian_date(datetime)));
+    let normal = SOLAR_CONSTANT_W_M2 / (r_au * r_au);
+    let cos_z = elevation_deg.to_radians().sin();
+    let pressure = atmosphere
+        .pressure_hpa
+        .unwrap_or_else(|| standard_pressure_hpa(location.altitude_m));
+    let am = airmass_kasten_young(elevation_deg)?;
+    let am_p = am * pressure / 1013.25;
+
+    // Transmittances of Bird & Hulstrom (1981)
+    let t_rayleigh = (-0.0903 * am_p.powf(0.84) * (1.0 + am_p - am_p.powf(1.01))).exp();
+    let ozone = atmosphere.ozone_cm * am;
+    let t_ozone = 1.0
+        - 0.1611 * ozone * (1.0 + 139.48 * ozone).powf(-0.3034)
+        - 0.002715 * ozone / (1.0 + 0.044 * ozone + 0.0003 * ozone * ozone);
+    let t_gases = (-0.0127 * am_p.powf(0.26)).exp();
+    let water = atmosphere.precipitable_water_cm * am;
+    let t_water = 1.0 - 2.4959 * water / ((1.0 + 79.034 * water).powf(0.6828) + 6.385 * water);
+    let tau_a = 0.2758 * atmosphere.aod_380nm + 0.35 * atmosphere.aod_500nm;
+    let t_aerosol = (-tau_a.powf(0.873) * (1.0 + tau_a - tau_a.powf(0.7088)) * am.powf(0.9108)).exp();
+    let t_absorb = 1.0 - 0.1 * (1.0 - am + am.powf(1.06)) * (1.0 - t_aerosol);
+    let sky_albedo = 0.0685 + (1.0 - atmosphere.aerosol_forward_scatter) * (1.0 - t_aerosol / t_absorb);
+
+    let dni = 0.9662 * normal * t_aerosol * t_water * t_gases * t_ozone * t_rayleigh;
+    let direct_horizontal = dni * cos_z;
+    let scattered = normal * cos_z * 0.79 * t_ozone * t_gases * t_water * t_absorb
+        * (0.5 * (1.0 - t_rayleigh) + atmosphere.aerosol_forward_scatter * (1.0 - t_aerosol / t_absorb))
+        / (1.0 - am + am.powf(1.02));
+    let ghi = (direct_horizontal + scattered) / (1.0 - atmosphere.albedo * sky_albedo);
+
+    Ok(ClearSkyIrradiance {
+        ghi,
+        dni,
+        dhi: ghi - direct_horizontal,
+        extraterrestrial: normal * cos_z,
+        elevation_deg,
+    })
+}
+
+/// Integrates the clear-sky global horizontal irradiance over a day.
+///
+/// Samples [`clear_sky_irradiance`] every five minutes across the 24 hours
+/// centred on solar noon of the given date and sums with the trapezoidal
+/// rule.
+///
+/// # Arguments
+/// * `date` - Date to integrate (only the UTC calendar date is used)
+/// * `location` - Observer's location
+///
+/// # Returns
+/// Daily insolation on a level surface in kWh/m²
+///
+/// # Errors
+/// `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+/// range
+///
+/// # Example
+/// ```
+/// use astro_math::irradiance::daily_insolation;
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// // No sunlight at all in the Antarctic midwinter
+/// let south_pole = Location { latitude_deg: -89.9, longitude_deg: 0.0, altitude_m: 2835.0 };
+/// let june = Utc.with_ymd_and_hms(2024, 6, 21, 0, 0, 0).unwrap();
+/// assert_eq!(daily_insolation(june, &south_pole).unwrap(), 0.0);
+/// ```
+pub fn daily_insolation(date: DateTime<Utc>, location: &Location) -> Result<f64> {
+    daily_insolation_with_atmosphere(date, location, &ClearSkyAtmosphere::default())
+}
+
+/// Integrates the clear-sky global horizontal irradiance over a day for a
+/// given atmosphere.
+///
+/// # Arguments
+/// * `date` - Date to integrate (only the UTC calendar date is used)
+/// * `location` - Observer's location
+/// * `atmosphere` - Ozone, water vapour, aerosols, albedo and pressure
+///
+/// # Returns
+/// Daily insolation on a level surface in kWh/m²
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - `AstroError::OutOfRange` if an atmospheric parameter is out of range
+pub fn daily_insolation_with_atmosphere(
+    date: DateTime<Utc>,
+    location: &Location,
+    atmosphere: &ClearSkyAtmosphere,
+) -> Result<f64> {
+    validate_latitude(location.latitude_deg)?;
+    validate_longitude(location.longitude_deg)?;
+    atmosphere.validate()?;
+
+    let step = Duration::minutes(INSOLATION_STEP_MINUTES);
+    let samples = 24 * 60 / INSOLATION_STEP_MINUTES;
+    let start = solar_noon(date, location) - Duration::hours(12);
+
+    let mut total_w_hours = 0.0;
+    let mut previous = clear_sky_irradiance_with_atmosphere(start, location, atmosphere)?.ghi;
+    for i in 1..=samples {
+        let ghi = clear_sky_irradiance_with_atmosphere(start + step * i as i32, location, atmosphere)?.ghi;
+        total_w_hours += 0.5 * (previous + ghi) * INSOLATION_STEP_MINUTES as f64 / 60.0;
+        previous = ghi;
+    }
+    Ok(total_w_hours / 1000.0)
+}
+
+/// Surface pressure of the standard atmosphere at a given height, in hPa.
+fn standard_pressure_hpa(altitude_m: f64) -> f64 {
+    1013.25 * (1.0 - 2.25577e-5 * altitude_m.max(0.0)).max(0.0).powf(5.25588)
+}
@@ -8,39 +8,102 @@
 //! This library provides everything needed for astronomical calculations:
 //!
 //! ### Time Systems
-//! - [`time`] — Julian Date conversions, J2000 epoch calculations  
-//! - [`time_scales`] — UTC ↔ TT conversions with proper leap second handling
+//! - [`time`] — Julian Date conversions, J2000 epoch calculations, Julian and Besselian epochs  
+//! - [`time_scales`] — UTC ↔ TT conversions with proper leap second handling, plus TDB, TCG and TCB
+//! - [`astro_time`] — `AstroTime`, a two-part Julian Date that knows its scale (UTC, TAI, TT, UT1, TDB)
 //! - [`sidereal`] — Greenwich Mean Sidereal Time (GMST), Local Mean/Apparent Sidereal Time
+//! - [`events`] — Root-finding and extremum search over time for custom events
+//! - [`eop`] — UT1−UTC tables from IERS data with Bulletin A-style prediction past the end of the data
+//! - [`timing`] — Exposure midpoints in UTC, TT, TDB and BJD, and coordinate smearing across an exposure
+//! - `calendar` — Easter, new moons, solar terms, and Chinese, Islamic and Hebrew month starts (feature `calendar`)
 //!
 //! ### Observer Location  
-//! - [`location`] — Earth coordinates with flexible parsing (27+ formats)
+//! - [`location`] — Earth coordinates with flexible parsing (27+ formats), and ellipsoidal distances and bearings between sites
 //! - Support for decimal degrees, DMS, HMS, aviation formats, Unicode symbols
+//! - [`observer`] — Ground and atmosphere-free space observers with a common apparent-place pipeline
 //!
 //! ### Coordinate Transformations
 //! - [`transforms`] — RA/Dec ↔ Alt/Az conversions with spherical trigonometry
 //! - [`galactic`] — Equatorial ↔ Galactic coordinate system conversions
-//! - [`projection`] — Gnomonic/TAN projection for astrometry and plate solving
+//! - [`constellations`] — IAU constellation containing a position, from the B1875.0 boundaries
+//! - [`ecliptic`] — Equatorial ↔ Ecliptic conversions, of date and J2000.0
+//! - [`projection`] — Gnomonic/TAN projection for astrometry and plate solving, and SIN, ARC, STG and AIT for wide fields
+//! - [`plate_solve`] — Quad-hash matching of detections to a catalogue, seeding blind plate solves
+//! - [`angles`] — Vincenty angular separation, position angle and offsets by position angle and separation
+//! - [`offsets`] — Offset-star and blind-offset differential coordinates, valid near the poles
+//! - [`great_circle`] — Pole-safe interpolation and evenly spaced waypoints along great-circle arcs
+//! - [`sky_polygon`] — Spherical polygon area, point-in-polygon and cone overlap for footprints
+//! - [`allsky`] — Fisheye all-sky camera projection and calibration fitting
+//! - `units` — Typed `uom` angles, lengths and times over the transform, parallax and location functions (feature `units`)
+//! - [`vec3`] — Unit-vector ↔ RA/Dec conversions and dot/cross helpers shared by the transforms
 //!
 //! ### Precision Corrections 
 //! - [`precession`] — Convert coordinates between epochs (J2000 ↔ current date)
 //! - [`nutation`] — Earth's axis wobble corrections (±18.6" longitude, ±9.2" obliquity)
+//! - [`apparent`] — Apparent places of date and their drift rate from precession, nutation and aberration
 //! - [`aberration`] — Annual stellar aberration corrections (±20.5 arcseconds)
 //! - [`proper_motion`] — Linear and rigorous 3D space motion calculations
 //! - [`parallax`] — Diurnal and annual parallax corrections
+//! - [`radial_velocity`] — Barycentric and Local Standard of Rest velocity corrections
 //!
 //! ### Solar System Objects
-//! - [`moon`] — Lunar position, phase, illumination, distance calculations
-//! - [`sun`] — Solar position and rise/set calculations
-//! - [`rise_set`] — Rise, set, and meridian transit times for any object
+//! - [`moon`] — Lunar position, phase, illumination, distance calculations, and uniform-step sampling
+//! - [`minor_planet`] — Asteroid apparent magnitude with the IAU H-G system
+//! - [`designation`] — Minor planet and comet designations in unpacked and MPC packed form
+//! - [`orbit`] — Keplerian elements, state vectors and two-body propagation
+//! - [`propagator`] — Numerical orbit integration with planetary perturbations
+//! - [`close_approach`] — Minimum orbit intersection distance and Earth close-approach scanning
+//! - [`meteor`] — Meteor trajectory, heights, speed and radiant triangulated from two stations
+//! - [`earth`] — Earth's heliocentric and barycentric distance and velocity, mean and true anomaly
+//! - [`planets`] — Geocentric and topocentric apparent places of Mercury through Neptune for pointing
+//! - [`sun`] — Solar position and uniform-step sampling, solar noon, subsolar point, azimuth/elevation and the P, B0, L0 disk orientation
+//! - `irradiance` — Clear-sky solar irradiance and daily insolation for solar-energy use (feature `irradiance`)
+//! - [`zodiac`] — Tropical zodiac signs and equal ecliptic segments for the Sun's and Moon's longitudes
+//! - [`rise_set`] — Rise, set, and meridian transit times for any object, with per-body standard altitudes and moonrise/moonset
+//! - [`almanac`] — Monthly almanac tables of rise, transit, set and magnitude for the Sun, Moon and planets, and a snapshot of them all with diameters and separations
+//! - [`satellite`] — ISS and other Earth satellites in the sky from two-line elements, propagated with SGP4
+//! - [`ephemeris`] — One `Ephemeris` trait for the Sun, Moon, planets, satellites and tabulated targets, with generic alt/az and rise/set
+//! - [`besselian`] — Besselian elements for solar eclipses and lunar and asteroid occultations, and their paths on the Earth
+//! - [`occultation`] — Asteroid occultation timings reduced to chords, with circular and elliptical profile fits
 //!
 //! ### Atmospheric Effects
-//! - [`refraction`] — Multiple atmospheric refraction models (Bennett, Saemundsson, radio)
+//! - [`refraction`] — Multiple atmospheric refraction models (Bennett, Saemundsson, radio, ray trace)
 //! - [`airmass`] — Various airmass formulas for extinction calculations
+//! - [`weather`] — Weather-station providers of live pressure, temperature and humidity for refraction
+//!
+//! ### Telescope Control
+//! - [`pointing_model`] — TPoint-style mount pointing models with TPoint and 10Micron file interchange
+//! - [`polar_alignment`] — Refracted celestial pole and pole-star clock position for polar alignment
+//! - [`mount`] — German equatorial mount pier side and tube–pier collision prediction
+//! - [`focus`] — Linear and quadratic focus-versus-temperature models fitted from autofocus logs
+//!
+//! ### Observation Planning
+//! - [`mosaic`] — Mosaic tiling with per-tile rotation, correct near the poles
+//! - [`dither`] — Spiral, grid and random dither offsets applied in true on-sky arcseconds
+//! - [`drift_scan`] — RA/Dec strip, length and drift rate swept by a fixed alt/az pointing, for transit instruments
+//! - [`catalog`] — One cone-search interface over the embedded bright stars and Gaia DR3 extracts (feature `gaia`)
+//! - [`simulate`] — Synthetic star fields through the production transforms, for hardware-in-the-loop tests
+//! - [`zenith`] — Zenith and nadir RA/Dec and the cone of sky overhead, for "what's up now" and flat fields
+//! - [`horizon`] — Azimuth-dependent horizon masks, read from CSV, Cartes du Ciel and Stellarium files
+//! - [`night`] — Observing nights from local noon to noon, with their sunset, sunrise and twilight
+//! - [`interference`] — Hour-by-hour Sun and Moon interference and sky brightness for a target through the night
+//! - [`schedule`] — Clustering of targets by overlapping transit windows for queue scheduling, and when whole survey fields are observable
+//! - [`charts`] — Text-art horizon charts of alt/az positions for consoles and logs
+//! - [`export`] — Visibility curves, rise/set tables and event lists as CSV, JSON and iCalendar
 //!
 //! ### High Performance
-//! - Parallel batch processing with Rayon for coordinate transformations
-//! - ERFA (Essential Routines for Fundamental Astronomy) integration
+//! - Parallel batch processing with Rayon for coordinate transformations,
+//!   [`visible_mask`](transforms::visible_mask) for pruning large catalogs to what is above the horizon,
+//!   and [`alt_az_for_sites`](transforms::alt_az_for_sites) for one target from many stations
+//! - [`astrometric_context`] — ERFA astrometry parameters computed once per time and site for million-star catalogs
+//! - [`fixed_site`] — Site trigonometry, parallax factors and refraction scale precomputed for single-site services
+//! - Radian-native `_rad` transforms ([`ra_dec_to_alt_az_rad`](transforms::ra_dec_to_alt_az_rad), [`alt_az_to_ra_dec_rad`](transforms::alt_az_to_ra_dec_rad)) for data already in radians
+//! - ERFA (Essential Routines for Fundamental Astronomy) integration, with an
+//!   optional pure-Rust backend (see [Cargo Features](#cargo-features))
 //! - Input validation and clear error messages
+//! - [`config`] — Crate-wide default weather, obliquity model and RA/azimuth validation policy, set once at startup
+//! - [`diagnostics`] — Startup self-check of round trips, ERFA-vs-Meeus deltas, leap-second table and EOP availability
+//! - [`provenance`] — The models, corrections, EOP source and leap-second table behind a result, to store with pipeline output
 //!
 //! ## Architecture Overview
 //!
@@ -58,16 +121,20 @@
 //! Earth-based observer coordinates with flexible coordinate parsing supporting
 //! 27+ formats (decimal degrees, DMS, HMS, aviation, Unicode symbols).
 //! Handles local sidereal time calculations and coordinate validation.
+//! Aircraft and ships use a [`MovingObserver`](moving_observer::MovingObserver) built from a GPS track instead,
+//! and balloon or orbiting telescopes an [`ObserverKind::Space`](observer::ObserverKind::Space).
 //!
 //! ### 2. Time Systems ([`time`], [`time_scales`], [`sidereal`])
 //! - Julian Date conversions and J2000 epoch calculations
 //! - UTC ↔ TT conversions with proper leap second handling  
+//! - TT ↔ TDB/TCG and TDB ↔ TCB conversions, and clock rates relative to TT
 //! - Greenwich Mean Sidereal Time (GMST) and Local Sidereal Time (LST)
 //!
 //! ### 3. Coordinate Systems ([`transforms`], [`galactic`], [`projection`])
 //! - RA/Dec ↔ Alt/Az transformations using spherical trigonometry
 //! - Equatorial ↔ Galactic coordinate system conversions
-//! - Gnomonic/TAN projection for astrometry and plate solving
+//! - Gnomonic/TAN projection for astrometry and plate solving, and SIN, ARC, STG
+//!   and AIT projections for wide-field and all-sky images
 //!
 //! ### 4. Astrometric Corrections
 //! - [`precession`] — Convert coordinates between epochs (J2000 ↔ current date)
@@ -75,7 +142,8 @@
 //! - [`aberration`] — Annual stellar aberration (±20.5 arcseconds)
 //! - [`proper_motion`] — Linear and rigorous 3D space motion calculations
 //! - [`parallax`] — Diurnal and annual parallax corrections
-//! - [`refraction`] — Atmospheric refraction (Bennett, Saemundsson, radio models)
+//! - [`radial_velocity`] — Barycentric and Local Standard of Rest velocity corrections
+//! - [`refraction`] — Atmospheric refraction (Bennett, Saemundsson, radio and ray-traced models)
 //!
 //! ### 5. Solar System Objects ([`sun`], [`moon`], [`rise_set`])
 //! - Solar and lunar position calculations
@@ -90,11 +158,78 @@
 //! - **ERFA**: Essential Routines for Fundamental Astronomy  
 //! - **USNO**: US Naval Observatory references
 //!
+//! The main position functions have `_with_accuracy` variants that return an
+//! estimated 1-sigma error alongside the result (see [`accuracy`]).
+//!
+//! ## Cargo Features
+//!
+//! - `erfa` (default) — call the C ERFA library through the `erfars` bindings
+//! - `pure-rust` — use Rust ports of the ERFA routines instead, removing the C
+//!   dependency for targets where it is awkward to cross-compile (WASM,
+//!   Android/Termux, static musl). Takes precedence if both are enabled.
+//! - `generator` — random test data generation
+//! - `tracing` — emit [`tracing`](https://docs.rs/tracing) spans around
+//!   expensive operations (ERFA transformations, batch transforms, event
+//!   searches) and debug-level events with intermediate values (sidereal
+//!   time, hour angle, refraction applied). Install a subscriber such as
+//!   `tracing-subscriber` to see them; without the feature the
+//!   instrumentation compiles away.
+//! - `calendar` — the `calendar` module: Easter, new moons and solar terms,
+//!   and month starts of the Chinese, Islamic and Hebrew calendars. Off by
+//!   default, as astronomy-only builds have no use for it.
+//! - `irradiance` — the `irradiance` module: clear-sky global, direct and
+//!   diffuse irradiance (Bird & Hulstrom) and daily insolation, for
+//!   solar-energy work. Off by default.
+//! - `units` — the `units` module: wrappers over the coordinate, parallax
+//!   and location functions taking and returning
+//!   [`uom`](https://docs.rs/uom) `Angle`, `Length` and `Time` quantities
+//!   instead of `f64`s in implied degrees, hours, metres or AU. Off by
+//!   default; pulls in `uom`.
+//! - `serde` — `Serialize` and `Deserialize` for the types worth keeping in
+//!   configuration files: [`Location`], [`TangentPlane`], [`SipDistortion`](projection::SipDistortion),
+//!   [`Nutation`](nutation::Nutation), [`EarthOrientation`](eop::EarthOrientation)
+//!   and [`ExtrapolationPolicy`](eop::ExtrapolationPolicy),
+//!   [`PointingModel`](pointing_model::PointingModel), [`AtmosphericConditions`](refraction::AtmosphericConditions),
+//!   [`RefractionModel`](refraction::RefractionModel) and [`Config`](config::Config). The EOP and DUT1
+//!   tables are not included; store the IERS file and parse it again. Off
+//!   by default; pulls in `serde`.
+//! - `gaia` — `catalog::GaiaCatalog`, a star catalogue read from a Gaia DR3
+//!   CSV extract from the Gaia archive. Off by default.
+//! - `gaia-parquet` — adds `GaiaCatalog::from_parquet_file` for extracts
+//!   kept as Parquet. Off by default; enables `gaia` and pulls in `parquet`
+//!   (without its Arrow support).
+//!
+//! ```toml
+//! astro-math = { version = "*", default-features = false, features = ["pure-rust"] }
+//! ```
+//!
+//! Differences of the pure-Rust backend from ERFA over 1900–2100:
+//!
+//! | ERFA routine | Pure-Rust implementation | Difference |
+//! |--------------|--------------------------|------------|
+//! | `Obl06`, `Obl80`, `P06e`, `Pmat06`, `Ltpb`, `Numat`, `Era00`, `Gmst06` | exact port | rounding only |
+//! | `G2icrs`, `Icrs2g`, `Tpxes`, `Tpsts`, `Plan94`, `Moon98` | exact port | rounding only |
+//! | `Nut00a`, `Pnm06a` | IAU 2000B nutation (77 terms) | < 3 mas |
+//! | `Gst06a` | GMST + truncated equation of the equinoxes | < 0.2 ms |
+//! | `Epv00` | truncated `Epv00` series (848 of 1951 terms) | < 1e-6 AU, < 0.02 m/s |
+//! | `Atci13`, `Atic13`, `Atio13`, `Atco13`, `Atoc13` | ERFA chain built on the above | < 5 mas |
+//!
+//! For the arcsecond-level work this crate targets the two backends are
+//! interchangeable.
+//!
+//! ## Imports
+//!
+//! [`prelude`] gathers the most-used types and functions for a glob
+//! import, `use astro_math::prelude::*;`. Everything else is reached by its
+//! module path, e.g. `astro_math::mount::collision_check`. The crate root
+//! still re-exports what it offered before the prelude existed, for
+//! existing code, but nothing more: newer modules, and newer items in the
+//! old ones, are reached by module path or through the prelude.
+//!
 //! ## Quick Example: Compute LST and Alt/Az for Vega
 //!
 //! ```
-//! use chrono::{Utc, TimeZone};
-//! use astro_math::{julian_date, Location, ra_dec_to_alt_az};
+//! use astro_math::prelude::*;
 //!
 //! let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
 //! let loc = Location {
@@ -128,42 +263,145 @@
 //! print(altaz.alt.deg, altaz.az.deg)
 //! ```
 
+/// Emits a `tracing` event at the given level when the `tracing` feature is
+/// enabled, and nothing otherwise.
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+    };
+}
+
 pub mod aberration;
+pub mod accuracy;
 pub mod airmass;
+pub mod allsky;
+pub mod almanac;
+pub mod angles;
+pub mod apparent;
+pub mod astro_time;
+pub mod astrometric_context;
+pub(crate) mod backend;
+pub mod besselian;
+#[cfg(feature = "calendar")]
+pub mod calendar;
+pub mod catalog;
+pub mod charts;
+pub mod close_approach;
+pub mod config;
+pub mod constellations;
+pub mod designation;
+pub mod diagnostics;
+pub mod dither;
+pub mod drift_scan;
+pub mod earth;
+pub mod ecliptic;
+pub mod eop;
+pub mod ephemeris;
 pub mod erfa;
 pub mod error;
+pub mod events;
+pub mod export;
+pub mod fixed_site;
+pub mod focus;
 pub mod galactic;
+pub mod great_circle;
+pub mod horizon;
+pub mod interference;
+#[cfg(feature = "irradiance")]
+pub mod irradiance;
 pub mod location;
+pub mod meteor;
+pub mod minor_planet;
 pub mod moon;
+pub mod mosaic;
+pub mod mount;
+pub mod moving_observer;
+pub mod night;
 pub mod nutation;
+pub mod observer;
+pub mod occultation;
+pub mod offsets;
+pub mod orbit;
 pub mod parallax;
+pub mod planets;
+pub mod plate_solve;
+pub mod pointing_model;
+pub mod polar_alignment;
 pub mod precession;
+pub mod prelude;
 pub mod projection;
+pub mod propagator;
 pub mod proper_motion;
+pub mod provenance;
+pub mod radial_velocity;
 pub mod refraction;
 pub mod rise_set;
+pub mod satellite;
+pub mod schedule;
 pub mod sidereal;
+pub mod simulate;
+pub mod sky_polygon;
 pub mod sun;
 pub mod time;
 pub mod time_scales;
+pub mod timing;
 pub mod transforms;
+#[cfg(feature = "units")]
+pub mod units;
+pub mod vec3;
+pub mod weather;
+pub mod zenith;
+pub mod zodiac;
 
-pub use aberration::*;
-pub use airmass::*;
+// Root re-exports are frozen at what the root offered before the prelude,
+// listed item by item so that new modules, and new items in these ones, do
+// not land in the crate root and collide there. Do not add to these lists;
+// new items are reached by module path or through `astro_math::prelude`.
+pub use aberration::{
+    aberration_magnitude, apply_aberration, remove_aberration, ABERRATION_CONSTANT,
+};
+pub use airmass::{
+    airmass_kasten_young, airmass_pickering, airmass_plane_parallel, airmass_young,
+    extinction_coefficient_estimate, extinction_magnitudes,
+};
 pub use error::{AstroError, Result};
-pub use galactic::*;
-pub use location::*;
-pub use moon::*;
-pub use parallax::*;
-pub use precession::*;
-pub use projection::*;
-pub use proper_motion::*;
-pub use refraction::*;
-pub use rise_set::*;
-pub use sidereal::*;
-pub use time::*;
-pub use time_scales::*;
-pub use transforms::*;
+pub use galactic::{
+    equatorial_to_galactic, galactic_landmarks, galactic_to_equatorial, GC_DEC, GC_RA, NGP_DEC,
+    NGP_RA,
+};
+pub use location::Location;
+pub use moon::{
+    moon_distance, moon_equatorial, moon_illumination, moon_phase_angle, moon_phase_name,
+    moon_position,
+};
+pub use parallax::{annual_parallax, diurnal_parallax, geocentric_distance};
+pub use precession::{
+    get_precession_angles, get_precession_matrix, precess_from_j2000, precess_to_j2000,
+};
+pub use projection::TangentPlane;
+pub use proper_motion::{
+    apply_proper_motion, apply_proper_motion_rigorous, pm_ra_cosdec_to_pm_ra,
+    pm_ra_to_pm_ra_cosdec, proper_motion_position_angle, total_proper_motion,
+};
+pub use refraction::{
+    apparent_to_true_altitude, refraction_bennett, refraction_radio, refraction_saemundsson,
+    true_to_apparent_altitude,
+};
+pub use rise_set::{
+    next_rise, next_set, rise_transit_set, sun_rise_set, RiseTransitSetResult, RISE_SET_ALTITUDE,
+    SUN_SEMI_DIAMETER,
+};
+pub use sidereal::{apparent_sidereal_time, gmst, local_mean_sidereal_time};
+pub use time::{j2000_days, julian_date, JD2000};
+pub use time_scales::{
+    check_time_offset_accuracy, split_jd_for_erfa, tai_utc_offset, tai_utc_offset_for_date,
+    tai_utc_offset_for_datetime, tt_to_utc_jd, tt_utc_offset_jd, tt_utc_offset_seconds,
+    utc_to_tt_jd, utc_to_tt_jd_for_date,
+};
+pub use transforms::{
+    alt_az_to_ra_dec, ra_dec_to_alt_az, ra_dec_to_alt_az_batch_parallel, ra_dec_to_alt_az_erfa,
+};
 
 #[cfg(test)]
 pub mod tests;
@@ -27,20 +27,51 @@
 //! - Mixed formats: `"40° 42.767'"` (degrees and decimal minutes)
 //! - Fuzzy matching: handles typos, extra spaces, mixed separators
 //! - Case insensitive: `"40D42M46S"` or `"n40.7128"`
+//! - Geohashes: [`Location::from_geohash`] and [`Location::to_geohash`]
+//! - Ambiguous strings such as `"404246"`: [`Location::parse_with_diagnostics`]
+//!   reports the reading chosen, a confidence and the alternatives
+//!
+//! # Distances Between Sites
+//!
+//! [`Location::distance_to`], [`Location::bearing_to`] and
+//! [`Location::final_bearing_to`] measure baselines between sites on the
+//! WGS84 ellipsoid, for networks such as occultation chords and meteor
+//! triangulation; [`Location::geodesic_to`] returns all three at once.
 //!
 //! # Error Handling
 //!
 //! Parsing returns `Result<Location>` with detailed error messages:
 //! - `AstroError::InvalidDmsFormat` with suggestions for fixing common issues
+//! - `AstroError::InvalidFormat` for malformed geohashes
+//! - `AstroError::InvalidCoordinate` for out-of-range coordinates passed to
+//!   the distance and bearing methods
+//! - `AstroError::CalculationError` for nearly antipodal sites, where the
+//!   geodesic does not converge
 
-use crate::time::julian_date;
-use crate::{local_mean_sidereal_time, sidereal::apparent_sidereal_time};
+use crate::time::{julian_date, julian_date_two_part};
+use crate::{local_mean_sidereal_time, sidereal::apparent_sidereal_time_two_part};
 use crate::error::{AstroError, Result};
 use chrono::{DateTime, Utc};
 use std::str::FromStr;
 use regex::{Regex, RegexBuilder};
 use lazy_static::lazy_static;
 
+/// Base-32 alphabet of geohashes
+const GEOHASH_ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Longest geohash accepted, about 3.7 cm × 1.9 cm
+const MAX_GEOHASH_LENGTH: usize = 12;
+
+/// WGS84 equatorial radius in meters
+const WGS84_A: f64 = 6378137.0;
+
+/// WGS84 flattening
+const WGS84_F: f64 = 1.0 / 298.257223563;
+
+/// Iteration limit of Vincenty's inverse formula; only nearly antipodal
+/// points need more than a handful
+const VINCENTY_MAX_ITERATIONS: usize = 200;
+
 // Pre-compiled regex patterns for performance
 lazy_static! {
     /// HMS pattern with DoS protection
@@ -84,6 +115,7 @@ lazy_static! {
 /// Used for computing local sidereal time, converting celestial coordinates,
 /// and modeling telescope geometry.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Location {
     /// Latitude in degrees (+N, -S)
     pub latitude_deg: f64,
@@ -173,6 +205,57 @@ impl Location {
         })
     }
 
+    /// Parses a location like [`parse`](Self::parse), and reports how sure
+    /// the reading is.
+    ///
+    /// Some strings can be read more than one way: `"404246"` is 40°42′46″
+    /// as compact DDMMSS, but may be 40.4246 with the decimal point lost,
+    /// and a longitude of `"4:56:27"` is taken as degrees but may be hours.
+    /// [`parse`](Self::parse) silently picks the first reading; this returns
+    /// the same choice along with the other readings and a
+    /// [`ParseConfidence`], so a user interface can ask for confirmation
+    /// instead of storing a wrong site position.
+    ///
+    /// # Arguments
+    /// - `lat_str`: Latitude string in any format accepted by [`parse`](Self::parse)
+    /// - `lon_str`: Longitude string in any format accepted by [`parse`](Self::parse)
+    /// - `alt_m`: Altitude in meters
+    ///
+    /// # Returns
+    /// A [`LocationDiagnostics`] whose `location` equals what
+    /// [`parse`](Self::parse) returns
+    ///
+    /// # Errors
+    /// The same as [`parse`](Self::parse): `Err(AstroError::InvalidDmsFormat)`
+    /// for unreadable strings and `Err(AstroError::InvalidCoordinate)` for
+    /// values out of range
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::location::{CoordinateFormat, Location, ParseConfidence};
+    ///
+    /// let report = Location::parse_with_diagnostics("404246", "74.0060 W", 10.0).unwrap();
+    /// assert!((report.location.latitude_deg - 40.7128).abs() < 1e-4);
+    /// assert_eq!(report.latitude.format, CoordinateFormat::CompactDms);
+    /// assert_eq!(report.latitude.confidence, ParseConfidence::Low);
+    /// assert!(report.latitude.alternatives.iter().any(|a| (a.value_deg - 40.4246).abs() < 1e-9));
+    /// assert_eq!(report.longitude.confidence, ParseConfidence::High);
+    /// assert_eq!(report.confidence(), ParseConfidence::Low);
+    /// ```
+    pub fn parse_with_diagnostics(lat_str: &str, lon_str: &str, alt_m: f64) -> Result<LocationDiagnostics> {
+        let latitude = diagnose_coordinate(lat_str, true)?;
+        let longitude = diagnose_coordinate(lon_str, false)?;
+        Ok(LocationDiagnostics {
+            location: Location {
+                latitude_deg: latitude.value_deg,
+                longitude_deg: longitude.value_deg,
+                altitude_m: alt_m,
+            },
+            latitude,
+            longitude,
+        })
+    }
+
     /// Parses a `Location` from sexagesimal (DMS) strings for latitude and longitude.
     ///
     /// Supports a wide range of common DMS formats:
@@ -270,8 +353,22 @@ impl Location {
     /// assert!((lst - 4.3157).abs() < 1e-3);
     /// ```
     pub fn local_sidereal_time(&self, datetime: DateTime<Utc>) -> f64 {
-        let jd = julian_date(datetime);
-        apparent_sidereal_time(jd, self.longitude_deg)
+        let (jd1, jd2) = julian_date_two_part(datetime);
+        self.local_sidereal_time_two_part(jd1, jd2)
+    }
+
+    /// Local Apparent Sidereal Time from a two-part UTC Julian Date.
+    ///
+    /// Use this when the observation time is already available as a split
+    /// Julian Date and sub-millisecond precision matters.
+    ///
+    /// # Arguments
+    /// - `jd1`, `jd2`: UTC Julian Date split into two parts
+    ///
+    /// # Returns
+    /// Local Sidereal Time in fractional hours
+    pub fn local_sidereal_time_two_part(&self, jd1: f64, jd2: f64) -> f64 {
+        apparent_sidereal_time_two_part(jd1, jd2, self.longitude_deg)
     }
 
     /// Local Mean Sidreal Time (LMST) is calculated using the
@@ -311,6 +408,334 @@ impl Location {
     pub fn longitude_dms(&self) -> String {
         format_dms(self.longitude_deg, false)
     }
+
+    /// Decodes a location from a geohash.
+    ///
+    /// Geohashes encode a latitude/longitude cell as a base-32 string, each
+    /// character narrowing the cell; weather and seeing services often key
+    /// sites by them. Eight characters give a cell of about 38 m × 19 m.
+    ///
+    /// # Arguments
+    /// - `hash`: Geohash of 1 to 12 characters, in either case
+    ///
+    /// # Returns
+    /// The center of the cell, at altitude 0
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidFormat)` if `hash` is empty, longer
+    /// than 12 characters or contains a character outside the geohash
+    /// alphabet (which omits `a`, `i`, `l` and `o`)
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::location::Location;
+    ///
+    /// let loc = Location::from_geohash("dr5regw3").unwrap();
+    /// assert!((loc.latitude_deg - 40.7128).abs() < 1e-3);
+    /// assert!((loc.longitude_deg + 74.0060).abs() < 1e-3);
+    /// assert_eq!(loc.to_geohash(8).unwrap(), "dr5regw3");
+    /// ```
+    pub fn from_geohash(hash: &str) -> Result<Self> {
+        let invalid = |reason: String| AstroError::InvalidFormat { format: "geohash", input: hash.to_string(), reason };
+        if hash.is_empty() || hash.len() > MAX_GEOHASH_LENGTH {
+            return Err(invalid(format!("expected 1 to {} characters", MAX_GEOHASH_LENGTH)));
+        }
+        let (mut lat, mut lon) = ((-90.0, 90.0), (-180.0, 180.0));
+        let mut is_longitude = true;
+        for c in hash.chars() {
+            let value = GEOHASH_ALPHABET
+                .iter()
+                .position(|&b| b as char == c.to_ascii_lowercase())
+                .ok_or_else(|| invalid(format!("'{}' is not a geohash character", c)))?;
+            for bit in (0..5).rev() {
+                let range: &mut (f64, f64) = if is_longitude { &mut lon } else { &mut lat };
+                let middle = (range.0 + range.1) / 2.0;
+                if value >> bit & 1 == 1 {
+                    range.0 = middle;
+                } else {
+                    range.1 = middle;
+                }
+                is_longitude = !is_longitude;
+            }
+        }
+        Ok(Location {
+            latitude_deg: (lat.0 + lat.1) / 2.0,
+            longitude_deg: (lon.0 + lon.1) / 2.0,
+            altitude_m: 0.0,
+        })
+    }
+
+    /// Encodes this location as a geohash.
+    ///
+    /// # Arguments
+    /// - `precision`: Number of characters, 1 to 12
+    ///
+    /// # Returns
+    /// The geohash of the cell containing the location, in lower case
+    ///
+    /// # Errors
+    /// - `AstroError::OutOfRange` if `precision` is outside 1–12
+    /// - `AstroError::InvalidCoordinate` if the latitude or longitude is out
+    ///   of range
+    pub fn to_geohash(&self, precision: usize) -> Result<String> {
+        if !(1..=MAX_GEOHASH_LENGTH).contains(&precision) {
+            return Err(AstroError::OutOfRange {
+                parameter: "precision",
+                value: precision as f64,
+                min: 1.0,
+                max: MAX_GEOHASH_LENGTH as f64,
+            });
+        }
+        crate::error::validate_latitude(self.latitude_deg)?;
+        crate::error::validate_longitude(self.longitude_deg)?;
+        let (mut lat, mut lon) = ((-90.0, 90.0), (-180.0, 180.0));
+        let mut is_longitude = true;
+        let mut hash = String::with_capacity(precision);
+        for _ in 0..precision {
+            let mut value = 0;
+            for _ in 0..5 {
+                let (range, coordinate): (&mut (f64, f64), f64) = if is_longitude {
+                    (&mut lon, self.longitude_deg)
+                } else {
+                    (&mut lat, self.latitude_deg)
+                };
+                let middle = (range.0 + range.1) / 2.0;
+                value <<= 1;
+                if coordinate >= middle {
+                    value |= 1;
+                    range.0 = middle;
+                } else {
+                    range.1 = middle;
+                }
+                is_longitude = !is_longitude;
+            }
+            hash.push(GEOHASH_ALPHABET[value] as char);
+        }
+        Ok(hash)
+    }
+
+    /// Solves the geodesic from this location to another on the WGS84
+    /// ellipsoid.
+    ///
+    /// Uses Vincenty's inverse formula, accurate to well under a millimeter.
+    /// Altitudes are ignored: the distance is measured along the ellipsoid
+    /// surface, which for baselines between observatories differs from the
+    /// straight-line distance by at most a few parts per million.
+    ///
+    /// # Arguments
+    /// - `other`: The far end of the baseline
+    ///
+    /// # Returns
+    /// The [`Geodesic`]'s length and its bearings at each end; bearings are
+    /// 0 for coincident points
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if either latitude or longitude is
+    ///   out of range
+    /// - `AstroError::CalculationError` if the points are so nearly
+    ///   antipodal that the iteration does not converge
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::location::Location;
+    ///
+    /// // Flinders Peak to Buninyong, Vincenty's (1975) test line
+    /// let flinders = Location::parse("37 57 03.72030 S", "144 25 29.52440 E", 0.0).unwrap();
+    /// let buninyong = Location::parse("37 39 10.15610 S", "143 55 35.38390 E", 0.0).unwrap();
+    /// let line = flinders.geodesic_to(&buninyong).unwrap();
+    /// assert!((line.distance_m - 54_972.271).abs() < 0.001);
+    /// assert!((line.initial_bearing_deg - (306.0 + 52.0 / 60.0 + 5.37 / 3600.0)).abs() < 0.01 / 3600.0);
+    /// ```
+    pub fn geodesic_to(&self, other: &Location) -> Result<Geodesic> {
+        for location in [self, other] {
+            crate::error::validate_latitude(location.latitude_deg)?;
+            crate::error::validate_longitude(location.longitude_deg)?;
+        }
+        let b = WGS84_A * (1.0 - WGS84_F);
+        let l = (other.longitude_deg - self.longitude_deg).to_radians();
+        let (sin_u1, cos_u1) = ((1.0 - WGS84_F) * self.latitude_deg.to_radians().tan()).atan().sin_cos();
+        let (sin_u2, cos_u2) = ((1.0 - WGS84_F) * other.latitude_deg.to_radians().tan()).atan().sin_cos();
+
+        let mut lambda = l;
+        let mut converged = false;
+        let (mut sin_lambda, mut cos_lambda) = (0.0, 0.0);
+        let (mut sin_sigma, mut cos_sigma, mut sigma) = (0.0, 0.0, 0.0);
+        let (mut cos_sq_alpha, mut cos_2sigma_m) = (0.0, 0.0);
+        for _ in 0..VINCENTY_MAX_ITERATIONS {
+            (sin_lambda, cos_lambda) = lambda.sin_cos();
+            sin_sigma = (cos_u2 * sin_lambda).hypot(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+            if sin_sigma == 0.0 {
+                return Ok(Geodesic { distance_m: 0.0, initial_bearing_deg: 0.0, final_bearing_deg: 0.0 });
+            }
+            cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+            // Both points on the equator
+            cos_2sigma_m = if cos_sq_alpha == 0.0 { 0.0 } else { cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha };
+            let c = WGS84_F / 16.0 * cos_sq_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos_sq_alpha));
+            let previous = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * WGS84_F
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+            if (lambda - previous).abs() < 1e-12 {
+                converged = true;
+                break;
+            }
+        }
+        if !converged || !lambda.is_finite() {
+            return Err(AstroError::CalculationError {
+                calculation: "geodesic",
+                reason: "Vincenty iteration did not converge for nearly antipodal points".to_string(),
+            });
+        }
+
+        let u_sq = cos_sq_alpha * (WGS84_A * WGS84_A - b * b) / (b * b);
+        let a_coef = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let b_coef = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+        let delta_sigma = b_coef
+            * sin_sigma
+            * (cos_2sigma_m
+                + b_coef / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                        - b_coef / 6.0
+                            * cos_2sigma_m
+                            * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                            * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+        let initial = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+        let final_ = (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+        Ok(Geodesic {
+            distance_m: b * a_coef * (sigma - delta_sigma),
+            initial_bearing_deg: initial.to_degrees().rem_euclid(360.0),
+            final_bearing_deg: final_.to_degrees().rem_euclid(360.0),
+        })
+    }
+
+    /// Distance to another location along the WGS84 ellipsoid, in meters.
+    ///
+    /// See [`geodesic_to`](Self::geodesic_to) for the method and errors.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::location::Location;
+    ///
+    /// // Mauna Kea to Kitt Peak
+    /// let mauna_kea = Location { latitude_deg: 19.8207, longitude_deg: -155.4681, altitude_m: 4205.0 };
+    /// let kitt_peak = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+    /// let km = mauna_kea.distance_to(&kitt_peak).unwrap() / 1000.0;
+    /// assert!((km - 4563.0).abs() < 1.0);
+    /// ```
+    pub fn distance_to(&self, other: &Location) -> Result<f64> {
+        Ok(self.geodesic_to(other)?.distance_m)
+    }
+
+    /// Initial bearing of the geodesic to another location, in degrees
+    /// clockwise from north in [0, 360).
+    ///
+    /// See [`geodesic_to`](Self::geodesic_to) for the method and errors.
+    pub fn bearing_to(&self, other: &Location) -> Result<f64> {
+        Ok(self.geodesic_to(other)?.initial_bearing_deg)
+    }
+
+    /// Bearing of the geodesic on arrival at another location, in degrees
+    /// clockwise from north in [0, 360).
+    ///
+    /// See [`geodesic_to`](Self::geodesic_to) for the method and errors.
+    pub fn final_bearing_to(&self, other: &Location) -> Result<f64> {
+        Ok(self.geodesic_to(other)?.final_bearing_deg)
+    }
+}
+
+/// The shortest path between two locations on the WGS84 ellipsoid, from
+/// [`Location::geodesic_to`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geodesic {
+    /// Length along the ellipsoid in meters
+    pub distance_m: f64,
+    /// Bearing at the start, in degrees clockwise from north in [0, 360)
+    pub initial_bearing_deg: f64,
+    /// Bearing on arrival at the end, in degrees clockwise from north in
+    /// [0, 360); the reverse bearing is this ± 180°
+    pub final_bearing_deg: f64,
+}
+
+/// How sure a coordinate parse is of its reading.
+///
+/// Ordered from least to most sure, so the weaker of two is their `min`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ParseConfidence {
+    /// The string is a bare run of digits that reads sensibly more than one
+    /// way; confirm with the user
+    Low,
+    /// Other readings exist but the chosen one follows the usual convention,
+    /// e.g. unitless sexagesimal longitude taken as degrees rather than hours
+    Medium,
+    /// No other reading gives a valid coordinate
+    High,
+}
+
+/// Format a coordinate string was read as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoordinateFormat {
+    /// Decimal degrees, e.g. `40.7128`
+    Decimal,
+    /// Degrees, minutes and seconds, e.g. `40 42 46`
+    Dms,
+    /// Degrees and decimal minutes, e.g. `40 42.767`
+    Dm,
+    /// Hours, minutes and seconds of longitude, e.g. `4h56m27s`
+    Hms,
+    /// Compact DDMMSS or DDDMMSS, e.g. `404246`
+    CompactDms,
+    /// Compact DDMM.mmm as used in aviation, e.g. `4042.767`
+    CompactDm,
+    /// Decimal degrees with the decimal point missing, e.g. `404246` for
+    /// `40.4246`
+    MissingDecimalPoint,
+}
+
+/// One reading of a coordinate string.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoordinateReading {
+    /// Value in degrees, with any compass direction applied
+    pub value_deg: f64,
+    /// Format the string was read as
+    pub format: CoordinateFormat,
+}
+
+/// The reading chosen for a coordinate string, and the alternatives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoordinateDiagnostics {
+    /// Chosen value in degrees, as [`Location::parse`] returns it
+    pub value_deg: f64,
+    /// Format of the chosen reading
+    pub format: CoordinateFormat,
+    /// How sure the choice is
+    pub confidence: ParseConfidence,
+    /// Other valid readings with different values, most likely first
+    pub alternatives: Vec<CoordinateReading>,
+}
+
+/// Result of [`Location::parse_with_diagnostics`].
+#[derive(Debug, Clone)]
+pub struct LocationDiagnostics {
+    /// The parsed location
+    pub location: Location,
+    /// How the latitude string was read
+    pub latitude: CoordinateDiagnostics,
+    /// How the longitude string was read
+    pub longitude: CoordinateDiagnostics,
+}
+
+impl LocationDiagnostics {
+    /// The lower of the latitude and longitude confidences.
+    pub fn confidence(&self) -> ParseConfidence {
+        self.latitude.confidence.min(self.longitude.confidence)
+    }
 }
 
 /// Converts decimal degrees to DMS string format:
@@ -372,38 +797,47 @@ fn parse_dms(s: &str) -> Result<f64> {
 
 /// Parse coordinate from various input formats
 fn parse_coordinate(input: &str, is_latitude: bool) -> Result<f64> {
+    parse_coordinate_reading(input, is_latitude).map(|reading| reading.value_deg)
+}
+
+/// Parse coordinate from various input formats, reporting which format matched
+fn parse_coordinate_reading(input: &str, is_latitude: bool) -> Result<CoordinateReading> {
     let s = input.trim();
     
     // Extract compass direction if present
     let (value_str, compass_dir) = extract_compass_direction(s);
+    let reading = |deg: f64, format: CoordinateFormat| -> Result<CoordinateReading> {
+        Ok(CoordinateReading { value_deg: apply_compass_direction(deg, compass_dir, is_latitude)?, format })
+    };
     
     // Try various parsing strategies in order of likelihood
     
     // 1. Try compact formats first (specific patterns)
     if let Ok(deg) = try_parse_compact(&value_str) {
-        return apply_compass_direction(deg, compass_dir, is_latitude);
+        let format = if value_str.contains('.') { CoordinateFormat::CompactDm } else { CoordinateFormat::CompactDms };
+        return reading(deg, format);
     }
     
     // 2. Try decimal degrees (most common)
     if let Ok(deg) = try_parse_decimal_degrees(&value_str) {
-        return apply_compass_direction(deg, compass_dir, is_latitude);
+        return reading(deg, CoordinateFormat::Decimal);
     }
     
     // 3. Try HMS format (for longitude)
     if !is_latitude {
         if let Ok(deg) = try_parse_hms(&value_str) {
-            return apply_compass_direction(deg, compass_dir, is_latitude);
+            return reading(deg, CoordinateFormat::Hms);
         }
     }
     
     // 4. Try DMS format
     if let Ok(deg) = try_parse_dms(&value_str) {
-        return apply_compass_direction(deg, compass_dir, is_latitude);
+        return reading(deg, CoordinateFormat::Dms);
     }
     
     // 5. Try degrees + decimal minutes
     if let Ok(deg) = try_parse_dm(&value_str) {
-        return apply_compass_direction(deg, compass_dir, is_latitude);
+        return reading(deg, CoordinateFormat::Dm);
     }
     
     // If all parsing fails, provide helpful error message
@@ -417,6 +851,65 @@ fn parse_coordinate(input: &str, is_latitude: bool) -> Result<f64> {
     })
 }
 
+/// Parse a coordinate and list the other plausible readings of the same string
+fn diagnose_coordinate(input: &str, is_latitude: bool) -> Result<CoordinateDiagnostics> {
+    let chosen = parse_coordinate_reading(input, is_latitude)?;
+    let (value_str, compass_dir) = extract_compass_direction(input.trim());
+    let value_str = value_str.trim();
+    let unsigned = value_str.trim_start_matches(['+', '-']);
+    let sign = if value_str.starts_with('-') { -1.0 } else { 1.0 };
+
+    let mut candidates = Vec::new();
+    if chosen.format != CoordinateFormat::Decimal
+        && !unsigned.is_empty()
+        && unsigned.chars().all(|c| c.is_ascii_digit())
+    {
+        // A bare run of digits too large for decimal degrees: compact
+        // DDMMSS, or decimal degrees with the decimal point lost
+        if let Ok(deg) = try_parse_compact(value_str) {
+            candidates.push((deg, CoordinateFormat::CompactDms));
+        }
+        for split in 1..=3.min(unsigned.len() - 1) {
+            if let Ok(deg) = f64::from_str(&format!("{}.{}", &unsigned[..split], &unsigned[split..])) {
+                candidates.push((sign * deg, CoordinateFormat::MissingDecimalPoint));
+            }
+        }
+    } else if !is_latitude
+        && matches!(chosen.format, CoordinateFormat::Dms | CoordinateFormat::Dm)
+        && value_str.chars().all(|c| c.is_ascii_digit() || " :.+-".contains(c))
+    {
+        // Sexagesimal longitude with no units could be hours rather than degrees
+        let degrees = try_parse_dms(value_str).or_else(|_| try_parse_dm(value_str));
+        if let Ok(deg) = degrees {
+            if deg.abs() < 24.0 {
+                candidates.push((deg * 15.0, CoordinateFormat::Hms));
+            }
+        }
+    }
+
+    let mut alternatives: Vec<CoordinateReading> = Vec::new();
+    for (deg, format) in candidates {
+        let Ok(value_deg) = apply_compass_direction(deg, compass_dir, is_latitude) else {
+            continue;
+        };
+        let seen = |value: f64| (value - value_deg).abs() < 1e-9;
+        if !seen(chosen.value_deg) && !alternatives.iter().any(|a| seen(a.value_deg)) {
+            alternatives.push(CoordinateReading { value_deg, format });
+        }
+    }
+
+    let confidence = if alternatives.is_empty() {
+        ParseConfidence::High
+    } else if alternatives.iter().any(|a| a.format == CoordinateFormat::MissingDecimalPoint)
+        || chosen.format == CoordinateFormat::CompactDms
+    {
+        ParseConfidence::Low
+    } else {
+        ParseConfidence::Medium
+    };
+    Ok(CoordinateDiagnostics { value_deg: chosen.value_deg, format: chosen.format, confidence, alternatives })
+}
+
 /// Extract compass direction from string and return cleaned value
 fn extract_compass_direction(s: &str) -> (String, Option<char>) {
     let upper = s.to_uppercase();
@@ -0,0 +1,286 @@
+//! Meteor trajectories from two-station observations.
+//!
+//! Meteor networks record each meteor as a track of `(altitude, azimuth,
+//! time)` points from two or more cameras, such as all-sky cameras
+//! calibrated with [`allsky`](crate::allsky). [`triangulate_meteor`] turns the
+//! tracks of two stations into a straight-line trajectory in space by the
+//! method of intersecting planes (Ceplecha 1987):
+//!
+//! 1. Each station's lines of sight to the meteor lie, up to measurement
+//!    noise, in a plane through the station; it is fitted by least squares.
+//! 2. The two planes meet in the trajectory line.
+//! 3. Each observed point is placed on the trajectory where it passes
+//!    closest to that line of sight, which gives its height and its
+//!    distance along the track, and from the times, the velocity.
+//!
+//! The radiant, the point on the sky the meteor came from, is the reverse
+//! of the direction of motion. It is returned as geocentric RA/Dec of date,
+//! uncorrected for the Earth's rotation and gravity (zenith attraction),
+//! which change it by up to a few degrees for slow meteors.
+//!
+//! Positions are on the WGS84 ellipsoid; altitudes should be geometric
+//! (corrected for refraction), which at meteor heights and elevations above
+//! 10° makes little difference.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::meteor::triangulate_meteor;
+//! use astro_math::Location;
+//! use chrono::{Duration, TimeZone, Utc};
+//!
+//! // A meteor seen from two stations 60 km apart
+//! let west = Location { latitude_deg: 45.0, longitude_deg: 15.0, altitude_m: 300.0 };
+//! let east = Location { latitude_deg: 45.0, longitude_deg: 15.76, altitude_m: 250.0 };
+//! let t0 = Utc.with_ymd_and_hms(2024, 8, 12, 23, 14, 5).unwrap();
+//! let at = |ms| t0 + Duration::milliseconds(ms);
+//! let track_west = [(65.34, 51.72, at(0)), (67.46, 55.13, at(200)), (69.93, 60.20, at(400))];
+//! let track_east = [(69.35, 318.83, at(0)), (68.36, 306.68, at(200)), (66.23, 294.39, at(400))];
+//!
+//! let trajectory = triangulate_meteor((&west, &track_west), (&east, &track_east)).unwrap();
+//! assert!((trajectory.begin().location.altitude_m / 1000.0 - 100.0).abs() < 0.1);
+//! assert!((trajectory.velocity_km_s - 55.0).abs() < 0.5);
+//! assert!(trajectory.end().location.altitude_m < trajectory.begin().location.altitude_m);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range altitude or
+//!   azimuth, or station latitude or longitude
+//! - `AstroError::CalculationError` for fewer than two points from a
+//!   station, tracks that span no time, planes too close to parallel to
+//!   intersect reliably, or a trajectory behind a station
+
+use crate::error::{validate_latitude, validate_longitude, AstroError, Result};
+use crate::location::Location;
+use crate::observer::{geodetic_from_terrestrial_km, terrestrial_position_km};
+use crate::transforms::validate_alt_az;
+use crate::vec3::{cross, dot, norm, normalize, unit_vector_to_radec, Vec3};
+use chrono::{DateTime, Utc};
+use nalgebra::Matrix3;
+
+/// Smallest angle between the two stations' planes, in degrees, below which
+/// the intersection is too poorly determined to return
+const MIN_CONVERGENCE_ANGLE_DEG: f64 = 0.1;
+
+/// One station's observations: its location and `(altitude_deg,
+/// azimuth_deg, time)` points along the meteor's track.
+pub type StationTrack<'a> = (&'a Location, &'a [(f64, f64, DateTime<Utc>)]);
+
+/// An observed point placed on the trajectory.
+#[derive(Debug, Clone, Copy)]
+pub struct TrajectoryPoint {
+    /// Time of the observation
+    pub time: DateTime<Utc>,
+    /// Position on the trajectory; `altitude_m` is the height above the
+    /// WGS84 ellipsoid
+    pub location: Location,
+    /// Distance along the trajectory from the earliest point, in kilometers
+    pub distance_km: f64,
+    /// Station that made the observation: 0 for the first, 1 for the second
+    pub station: usize,
+    /// Closest distance between the line of sight and the trajectory, in
+    /// kilometers; a measure of the observation's error
+    pub miss_distance_km: f64,
+}
+
+/// A meteor's straight-line trajectory, from [`triangulate_meteor`].
+#[derive(Debug, Clone)]
+pub struct Trajectory {
+    /// Points from both stations in time order
+    pub points: Vec<TrajectoryPoint>,
+    /// Right ascension of the radiant in degrees, geocentric and of date
+    pub radiant_ra: f64,
+    /// Declination of the radiant in degrees, geocentric and of date
+    pub radiant_dec: f64,
+    /// Mean speed along the trajectory in km/s, from a least-squares fit of
+    /// distance against time
+    pub velocity_km_s: f64,
+    /// Angle between the two stations' planes in degrees, from 0 to 90;
+    /// intersections below about 5° are poorly determined
+    pub convergence_angle_deg: f64,
+}
+
+impl Trajectory {
+    /// The earliest observed point.
+    pub fn begin(&self) -> &TrajectoryPoint {
+        &self.points[0]
+    }
+
+    /// The latest observed point.
+    pub fn end(&self) -> &TrajectoryPoint {
+        &self.points[self.points.len() - 1]
+    }
+}
+
+/// Triangulates a meteor's trajectory from two stations' tracks.
+///
+/// # Arguments
+/// * `station_a`, `station_b` - Each station's location and its
+///   `(altitude_deg, azimuth_deg, time)` observations, with azimuth from
+///   north through east; at least two points per station
+///
+/// # Returns
+/// The [`Trajectory`], with every observed point placed on it
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if an altitude, azimuth, station
+///   latitude or station longitude is out of range
+/// - `AstroError::CalculationError` if a station has fewer than two points,
+///   all points share one time, the planes meet at less than 0.1°, or the
+///   trajectory lies behind a station's line of sight
+pub fn triangulate_meteor(station_a: StationTrack, station_b: StationTrack) -> Result<Trajectory> {
+    let stations = [station_a, station_b];
+    let mut origins = [[0.0; 3]; 2];
+    let mut sightlines: [Vec<(Vec3, DateTime<Utc>)>; 2] = [Vec::new(), Vec::new()];
+    let mut normals = [[0.0; 3]; 2];
+    for (index, &(location, track)) in stations.iter().enumerate() {
+        validate_latitude(location.latitude_deg)?;
+        validate_longitude(location.longitude_deg)?;
+        if track.len() < 2 {
+            return Err(failure(format!("station {} has {} points; at least 2 are needed", index, track.len())));
+        }
+        origins[index] = terrestrial_position_km(location);
+        for &(altitude_deg, azimuth_deg, time) in track {
+            validate_alt_az(altitude_deg, azimuth_deg)?;
+            sightlines[index].push((direction(location, altitude_deg, azimuth_deg), time));
+        }
+        normals[index] = plane_normal(&sightlines[index]);
+    }
+
+    let along = cross(normals[0], normals[1]);
+    let sin_convergence = norm(along).min(1.0);
+    let convergence_angle_deg = sin_convergence.asin().to_degrees();
+    if convergence_angle_deg < MIN_CONVERGENCE_ANGLE_DEG {
+        return Err(failure(format!(
+            "the stations' planes meet at {:.3}°, too close to parallel",
+            convergence_angle_deg
+        )));
+    }
+    let mut motion = normalize(along);
+
+    // A point on both planes, the one nearest the first station
+    let offsets = [dot(normals[0], origins[0]), dot(normals[1], origins[1]), dot(motion, origins[0])];
+    let start = solve_planes([normals[0], normals[1], motion], offsets);
+
+    let mut points = Vec::new();
+    for (station, lines) in sightlines.iter().enumerate() {
+        for &(sight, time) in lines {
+            let (distance, range, miss) = closest_approach(start, motion, origins[station], sight);
+            if range <= 0.0 {
+                return Err(failure(format!("the trajectory lies behind station {}", station)));
+            }
+            points.push((time, distance, station, miss));
+        }
+    }
+
+    // Orient the track with time, and measure it from the earliest point
+    if fit_velocity(&points)? < 0.0 {
+        motion = motion.map(|c| -c);
+        points.iter_mut().for_each(|p| p.1 = -p.1);
+    }
+    points.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    let first = points[0].1;
+    let velocity_km_s = fit_velocity(&points)?;
+
+    let (ra_itrs, radiant_dec) = unit_vector_to_radec(motion.map(|c| -c));
+    let gast_deg = crate::sidereal::apparent_sidereal_time(crate::julian_date(points[0].0), 0.0) * 15.0;
+    Ok(Trajectory {
+        points: points
+            .into_iter()
+            .map(|(time, distance, station, miss)| {
+                let position = [0, 1, 2].map(|i| start[i] + distance * motion[i]);
+                TrajectoryPoint {
+                    time,
+                    location: geodetic_from_terrestrial_km(position),
+                    distance_km: distance - first,
+                    station,
+                    miss_distance_km: miss,
+                }
+            })
+            .collect(),
+        radiant_ra: (ra_itrs + gast_deg).rem_euclid(360.0),
+        radiant_dec,
+        velocity_km_s,
+        convergence_angle_deg,
+    })
+}
+
+fn failure(reason: String) -> AstroError {
+    AstroError::CalculationError { calculation: "meteor triangulation", reason }
+}
+
+/// ITRS unit vector along a line of sight.
+fn direction(location: &Location, altitude_deg: f64, azimuth_deg: f64) -> Vec3 {
+    let (sin_lat, cos_lat) = location.latitude_deg.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = location.longitude_deg.to_radians().sin_cos();
+    let (sin_alt, cos_alt) = altitude_deg.to_radians().sin_cos();
+    let (sin_az, cos_az) = azimuth_deg.to_radians().sin_cos();
+    let (east, north, up) = (cos_alt * sin_az, cos_alt * cos_az, sin_alt);
+    [
+        -sin_lon * east - sin_lat * cos_lon * north + cos_lat * cos_lon * up,
+        cos_lon * east - sin_lat * sin_lon * north + cos_lat * sin_lon * up,
+        cos_lat * north + sin_lat * up,
+    ]
+}
+
+/// Unit normal of the plane through the origin that best contains the
+/// directions: the eigenvector of `Σ d·dᵀ` with the smallest eigenvalue.
+fn plane_normal(sightlines: &[(Vec3, DateTime<Utc>)]) -> Vec3 {
+    let mut scatter = Matrix3::zeros();
+    for (d, _) in sightlines {
+        for i in 0..3 {
+            for j in 0..3 {
+                scatter[(i, j)] += d[i] * d[j];
+            }
+        }
+    }
+    let eigen = scatter.symmetric_eigen();
+    let smallest = eigen.eigenvalues.imin();
+    let n = eigen.eigenvectors.column(smallest);
+    normalize([n[0], n[1], n[2]])
+}
+
+/// Solves `rows[k]·x = offsets[k]` for three independent rows.
+fn solve_planes(rows: [Vec3; 3], offsets: [f64; 3]) -> Vec3 {
+    let [a, b, c] = rows;
+    let (bc, ca, ab) = (cross(b, c), cross(c, a), cross(a, b));
+    let det = dot(a, bc);
+    [0, 1, 2].map(|i| (offsets[0] * bc[i] + offsets[1] * ca[i] + offsets[2] * ab[i]) / det)
+}
+
+/// Distance along the trajectory `start + s·motion` of its closest approach
+/// to the line of sight `origin + r·sight`, with the range `r` and the miss
+/// distance between the two lines.
+fn closest_approach(start: Vec3, motion: Vec3, origin: Vec3, sight: Vec3) -> (f64, f64, f64) {
+    let w = [0, 1, 2].map(|i| start[i] - origin[i]);
+    let b = dot(motion, sight);
+    let (d, e) = (dot(motion, w), dot(sight, w));
+    let denominator = 1.0 - b * b;
+    let distance = (b * e - d) / denominator;
+    let range = (e - b * d) / denominator;
+    let gap = [0, 1, 2].map(|i| w[i] + distance * motion[i] - range * sight[i]);
+    (distance, range, norm(gap))
+}
+
+/// Least-squares slope of distance against time, in km/s.
+fn fit_velocity(points: &[(DateTime<Utc>, f64, usize, f64)]) -> Result<f64> {
+    let t0 = points[0].0;
+    let seconds: Vec<f64> = points
+        .iter()
+        .map(|p| (p.0 - t0).num_microseconds().unwrap_or(i64::MAX) as f64 / 1e6)
+        .collect();
+    let n = points.len() as f64;
+    let mean_t = seconds.iter().sum::<f64>() / n;
+    let mean_d = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let mut sum_tt = 0.0;
+    let mut sum_td = 0.0;
+    for (t, p) in seconds.iter().zip(points) {
+        sum_tt += (t - mean_t) * (t - mean_t);
+        sum_td += (t - mean_t) * (p.1 - mean_d);
+    }
+    if sum_tt == 0.0 {
+        return Err(failure("all observations have the same time".to_string()));
+    }
+    Ok(sum_td / sum_tt)
+}
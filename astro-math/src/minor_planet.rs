@@ -0,0 +1,159 @@
+//! Minor planet brightness using the IAU H-G magnitude system.
+//!
+//! The H-G system (Bowell et al. 1989, adopted by the IAU in 1985) describes
+//! the apparent brightness of an asteroid from two parameters:
+//!
+//! - `H` — absolute magnitude: V magnitude at 1 AU from both Sun and
+//!   observer, at zero phase angle
+//! - `G` — slope parameter describing the opposition surge and the
+//!   fall-off of brightness with phase angle (typically 0.15)
+//!
+//! # Overview
+//!
+//! The apparent magnitude is
+//!
+//! ```text
+//! V = H + 5 log10(r Δ) − 2.5 log10[(1 − G) Φ1(α) + G Φ2(α)]
+//! ```
+//!
+//! where `r` is the heliocentric distance, `Δ` the observer distance and `α`
+//! the Sun–asteroid–observer phase angle. The phase functions Φ1 and Φ2 are
+//! evaluated with the exact blended form (smooth low-phase and linear
+//! high-phase components weighted by `W = exp(−90.56 tan²(α/2))`), not the
+//! simplified exponential approximation, so results remain accurate at
+//! large phase angles.
+//!
+//! # Applications
+//!
+//! - Exposure planning for asteroid photometry and astrometry
+//! - Filtering MPC orbit catalogs by detectability limit
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for non-positive distances, phase angles
+//!   outside [0, 180] degrees, or non-finite magnitude parameters
+
+use crate::error::{validate_range, AstroError, Result};
+
+// Bowell et al. (1989) phase function coefficients
+const A1: f64 = 3.332;
+const A2: f64 = 1.862;
+const B1: f64 = 0.631;
+const B2: f64 = 1.218;
+const C1: f64 = 0.986;
+const C2: f64 = 0.238;
+
+/// Evaluates the H-G phase functions Φ1 and Φ2.
+///
+/// Both functions equal 1.0 at zero phase and decrease towards zero as the
+/// phase angle approaches 180°.
+///
+/// # Arguments
+/// * `phase_angle_deg` - Sun–asteroid–observer angle in degrees [0, 180]
+///
+/// # Returns
+/// Tuple `(phi1, phi2)`
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if the phase angle is outside [0, 180].
+///
+/// # Example
+/// ```
+/// use astro_math::minor_planet::hg_phase_functions;
+///
+/// let (phi1, phi2) = hg_phase_functions(0.0).unwrap();
+/// assert!((phi1 - 1.0).abs() < 1e-12 && (phi2 - 1.0).abs() < 1e-12);
+/// ```
+pub fn hg_phase_functions(phase_angle_deg: f64) -> Result<(f64, f64)> {
+    if phase_angle_deg.is_nan() {
+        return Err(AstroError::OutOfRange {
+            parameter: "phase_angle",
+            value: phase_angle_deg,
+            min: 0.0,
+            max: 180.0,
+        });
+    }
+    validate_range(phase_angle_deg, 0.0, 180.0, "phase_angle")?;
+
+    let alpha = phase_angle_deg.to_radians();
+    let sin_a = alpha.sin();
+    let tan_half = (alpha / 2.0).tan();
+
+    // At exactly 180° tan(α/2) is infinite and both components vanish
+    if !tan_half.is_finite() {
+        return Ok((0.0, 0.0));
+    }
+
+    let w = (-90.56 * tan_half * tan_half).exp();
+    let smooth_denom = 0.119 + 1.341 * sin_a - 0.754 * sin_a * sin_a;
+
+    let phi = |a: f64, b: f64, c: f64| {
+        let smooth = 1.0 - c * sin_a / smooth_denom;
+        let linear = (-a * tan_half.powf(b)).exp();
+        w * smooth + (1.0 - w) * linear
+    };
+
+    Ok((phi(A1, B1, C1), phi(A2, B2, C2)))
+}
+
+/// Calculates the apparent V magnitude of an asteroid using the H-G system.
+///
+/// # Arguments
+/// * `h` - Absolute magnitude H
+/// * `g` - Slope parameter G (typically 0.15 when unknown)
+/// * `r_au` - Heliocentric distance in AU
+/// * `delta_au` - Observer–asteroid distance in AU
+/// * `phase_angle_deg` - Sun–asteroid–observer angle in degrees [0, 180]
+///
+/// # Returns
+/// Apparent V magnitude. Returns `f64::INFINITY` at 180° phase, where the
+/// illuminated fraction seen by the observer vanishes.
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if either distance is not positive,
+/// the phase angle is outside [0, 180], or `h`/`g` are not finite.
+///
+/// # Example
+/// ```
+/// use astro_math::minor_planet::asteroid_magnitude_hg;
+///
+/// // (1) Ceres near opposition: H = 3.34, G = 0.12
+/// let v = asteroid_magnitude_hg(3.34, 0.12, 2.55, 1.57, 5.0).unwrap();
+/// assert!(v > 6.0 && v < 7.0);
+/// ```
+pub fn asteroid_magnitude_hg(
+    h: f64,
+    g: f64,
+    r_au: f64,
+    delta_au: f64,
+    phase_angle_deg: f64,
+) -> Result<f64> {
+    for (parameter, value) in [("h", h), ("g", g)] {
+        if !value.is_finite() {
+            return Err(AstroError::OutOfRange {
+                parameter,
+                value,
+                min: f64::MIN,
+                max: f64::MAX,
+            });
+        }
+    }
+    for (parameter, value) in [("r_au", r_au), ("delta_au", delta_au)] {
+        if !(value > 0.0 && value.is_finite()) {
+            return Err(AstroError::OutOfRange {
+                parameter,
+                value,
+                min: f64::MIN_POSITIVE,
+                max: f64::MAX,
+            });
+        }
+    }
+
+    let (phi1, phi2) = hg_phase_functions(phase_angle_deg)?;
+    let reduced = (1.0 - g) * phi1 + g * phi2;
+    if reduced <= 0.0 {
+        return Ok(f64::INFINITY);
+    }
+
+    Ok(h + 5.0 * (r_au * delta_au).log10() - 2.5 * reduced.log10())
+}
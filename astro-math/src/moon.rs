@@ -2,9 +2,26 @@
 //!
 //! Uses ERFA's high-precision Moon98 function based on the ELP2000-82 lunar theory
 //! for professional-grade accuracy.
+//!
+//! [`moon_libration`] and [`moon_sub_observer_point`] give which part of the
+//! lunar surface faces the observer, for planning observations of features
+//! near the limb.
 
+use crate::accuracy;
+use crate::almanac::{apparent_place, ecliptic_longitude_of_date, AlmanacBody};
+use crate::ephemeris::topocentric_radec;
+use crate::error::{validate_jd, Result};
+use crate::events::sample_uniformly;
 use crate::julian_date;
-use chrono::{DateTime, Utc};
+use crate::location::Location;
+use crate::nutation::{nutation_in_longitude, true_obliquity};
+use chrono::{DateTime, Duration, Utc};
+
+/// Astronomical unit in kilometers
+const AU_KM: f64 = 149_597_870.7;
+
+/// Inclination of the Moon's mean equator to the ecliptic (IAU), in degrees
+const LUNAR_EQUATOR_INCLINATION_DEG: f64 = 1.54242;
 
 /// Calculates the Moon's ecliptic longitude and latitude using ERFA's high-precision Moon98.
 ///
@@ -21,7 +38,7 @@ pub fn moon_position(datetime: DateTime<Utc>) -> (f64, f64) {
     let tt = utc_to_tt_jd(jd);
     
     // Get Moon position-velocity using ERFA Moon98 (GCRS coordinates)
-    let pv = erfars::ephemerides::Moon98(tt, 0.0);
+    let pv = crate::backend::ephemerides::Moon98(tt, 0.0);
     
     // Extract position (AU)
     let x = pv[0];
@@ -30,7 +47,7 @@ pub fn moon_position(datetime: DateTime<Utc>) -> (f64, f64) {
     
     // Convert to ecliptic coordinates
     // First get obliquity of ecliptic
-    let eps_rad = erfars::precnutpolar::Obl06(tt, 0.0);
+    let eps_rad = crate::backend::precnutpolar::Obl06(tt, 0.0);
     let cos_eps = eps_rad.cos();
     let sin_eps = eps_rad.sin();
     
@@ -57,6 +74,35 @@ pub fn moon_position(datetime: DateTime<Utc>) -> (f64, f64) {
     (longitude, latitude)
 }
 
+/// Calculates the Moon's apparent ecliptic longitude of date.
+///
+/// The geocentric longitude measured along the ecliptic from the true
+/// equinox of date, with light time applied, on the same footing as
+/// [`ecliptic_longitude_of_sun`](crate::sun::ecliptic_longitude_of_sun):
+/// the two are equal at new moon. [`moon_position`] instead refers the
+/// longitude to the ecliptic of J2000.
+///
+/// # Arguments
+/// * `datetime` - Observation time
+///
+/// # Returns
+/// Ecliptic longitude in degrees, in [0, 360)
+///
+/// # Example
+/// ```
+/// use astro_math::moon::moon_ecliptic_longitude;
+/// use astro_math::sun::ecliptic_longitude_of_sun;
+/// use chrono::{TimeZone, Utc};
+///
+/// // New moon of 2024 January 11, 11:57 UTC
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 11, 11, 57, 0).unwrap();
+/// assert!((moon_ecliptic_longitude(dt) - ecliptic_longitude_of_sun(dt)).abs() < 0.02);
+/// ```
+pub fn moon_ecliptic_longitude(datetime: DateTime<Utc>) -> f64 {
+    let place = apparent_place(AlmanacBody::Moon, datetime).expect("the Moon's ephemeris accepts any date");
+    ecliptic_longitude_of_date(place.ra, place.dec, datetime)
+}
+
 /// Calculates the Moon's phase angle using ERFA's high-precision ephemerides.
 ///
 /// # Arguments
@@ -74,7 +120,7 @@ pub fn moon_phase_angle(datetime: DateTime<Utc>) -> f64 {
     let tt = utc_to_tt_jd(jd);
     
     // Get Earth position relative to Sun (heliocentric)
-    let (earth_h, _earth_b) = erfars::ephemerides::Epv00(tt, 0.0);
+    let (earth_h, _earth_b) = crate::backend::ephemerides::Epv00(tt, 0.0);
     // Sun position relative to Earth is negative of Earth's heliocentric position
     let sun_x = -earth_h[0];
     let sun_y = -earth_h[1];
@@ -82,7 +128,7 @@ pub fn moon_phase_angle(datetime: DateTime<Utc>) -> f64 {
     
     // Convert Sun position to ecliptic longitude
     // First get obliquity
-    let eps_rad = erfars::precnutpolar::Obl06(tt, 0.0);
+    let eps_rad = crate::backend::precnutpolar::Obl06(tt, 0.0);
     let cos_eps = eps_rad.cos();
     let sin_eps = eps_rad.sin();
     
@@ -127,6 +173,56 @@ pub fn moon_illumination(datetime: DateTime<Utc>) -> f64 {
     illumination.clamp(0.0, 100.0)
 }
 
+/// Calculates the Moon's phase angle, rejecting a date outside the supported range.
+///
+/// Same as [`moon_phase_angle`], which extrapolates the lunar theory to any
+/// date `chrono` can represent and returns meaningless values far from the present.
+///
+/// # Arguments
+/// * `datetime` - Observation time
+///
+/// # Returns
+/// Phase angle in degrees (0° = New Moon, 180° = Full Moon)
+///
+/// # Errors
+/// Returns `AstroError::OutOfRange` if the Julian Date of `datetime` is outside
+/// [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX).
+///
+/// # Example
+/// ```
+/// use astro_math::moon::moon_phase_angle_checked;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 25, 17, 54, 0).unwrap(); // full moon
+/// assert!((moon_phase_angle_checked(dt).unwrap() - 180.0).abs() < 1.0);
+///
+/// let far_future = Utc.with_ymd_and_hms(20000, 1, 1, 0, 0, 0).unwrap();
+/// assert!(moon_phase_angle_checked(far_future).is_err());
+/// ```
+pub fn moon_phase_angle_checked(datetime: DateTime<Utc>) -> Result<f64> {
+    validate_jd(julian_date(datetime))?;
+    Ok(moon_phase_angle(datetime))
+}
+
+/// Calculates the Moon's illumination percentage, rejecting a date outside
+/// the supported range.
+///
+/// Same as [`moon_illumination`] apart from the validation.
+///
+/// # Arguments
+/// * `datetime` - Observation time
+///
+/// # Returns
+/// Illumination percentage (0-100)
+///
+/// # Errors
+/// Returns `AstroError::OutOfRange` if the Julian Date of `datetime` is outside
+/// [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX).
+pub fn moon_illumination_checked(datetime: DateTime<Utc>) -> Result<f64> {
+    validate_jd(julian_date(datetime))?;
+    Ok(moon_illumination(datetime))
+}
+
 /// Returns a descriptive name for the Moon's phase.
 ///
 /// # Arguments
@@ -165,7 +261,7 @@ pub fn moon_distance(datetime: DateTime<Utc>) -> f64 {
     let tt = utc_to_tt_jd(jd);
     
     // Get Moon position-velocity using ERFA Moon98
-    let pv = erfars::ephemerides::Moon98(tt, 0.0);
+    let pv = crate::backend::ephemerides::Moon98(tt, 0.0);
     
     // Calculate distance from position vector (in AU)
     let x = pv[0];
@@ -192,7 +288,7 @@ pub fn moon_equatorial(datetime: DateTime<Utc>) -> (f64, f64) {
     let tt = utc_to_tt_jd(jd);
     
     // Get Moon position-velocity using ERFA Moon98 (already in GCRS equatorial)
-    let pv = erfars::ephemerides::Moon98(tt, 0.0);
+    let pv = crate::backend::ephemerides::Moon98(tt, 0.0);
     
     // Extract position and convert to spherical coordinates
     let x = pv[0];
@@ -214,6 +310,220 @@ pub fn moon_equatorial(datetime: DateTime<Utc>) -> (f64, f64) {
     (ra_deg, dec_rad.to_degrees())
 }
 
+/// Calculates the Moon's equatorial coordinates with an error estimate.
+///
+/// Returns the same position as [`moon_equatorial`] together with an
+/// estimated 1-sigma error: the published accuracy of `Moon98` (2.9″ RMS
+/// over 1950–2100, growing outside 1900–2100), plus the Moon's motion over
+/// any difference between the current TT−UTC used by [`moon_equatorial`]
+/// and the value in force on the date, which matters for dates before the
+/// most recent leap second. See [`accuracy`] for how the
+/// terms are combined.
+///
+/// # Arguments
+/// * `datetime` - Observation time
+///
+/// # Returns
+/// Tuple of (right_ascension, declination, error_arcsec), coordinates in degrees (GCRS)
+///
+/// # Example
+/// ```
+/// use astro_math::moon::moon_equatorial_with_accuracy;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 25, 17, 54, 0).unwrap();
+/// let (_ra, _dec, err) = moon_equatorial_with_accuracy(dt);
+/// assert!(err >= 2.9 && err < 5.0);
+/// ```
+pub fn moon_equatorial_with_accuracy(datetime: DateTime<Utc>) -> (f64, f64, f64) {
+    let (ra, dec) = moon_equatorial(datetime);
+    let jd = julian_date(datetime);
+    let tt = crate::time_scales::utc_to_tt_jd(jd);
+    let pv = crate::backend::ephemerides::Moon98(tt, 0.0);
+    let rate = accuracy::angular_rate_arcsec_per_second(&pv);
+    let error = accuracy::combine_errors(&[
+        accuracy::MOON98_SIGMA_ARCSEC * accuracy::ephemeris_epoch_factor(tt),
+        accuracy::time_scale_sigma_arcsec(jd, tt, rate),
+    ]);
+    (ra, dec, error)
+}
+
+/// One sample from [`moon_positions`]: `(time, ra, dec, distance_km)`, coordinates in
+/// degrees.
+pub type MoonPositionSample = (DateTime<Utc>, f64, f64, f64);
+
+/// Samples the Moon's equatorial coordinates and distance at a uniform step.
+///
+/// Gives the same values as calling [`moon_equatorial`] and
+/// [`moon_distance`] at each time, but evaluates the lunar theory once per
+/// sample instead of twice, so a sweep for a plot or planner costs half as
+/// much (and one call from Python instead of a loop of them).
+///
+/// # Arguments
+/// * `start`, `end` - Time span; both ends are included when `end` falls on
+///   a step
+/// * `step` - Sampling interval
+///
+/// # Returns
+/// `(time, ra, dec, distance_km)` in time order, coordinates in degrees
+/// (GCRS); empty if `end` is before `start`
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if `step` is not positive.
+///
+/// # Example
+/// ```
+/// use astro_math::moon::{moon_distance, moon_positions};
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let start = Utc.with_ymd_and_hms(2024, 8, 4, 0, 0, 0).unwrap();
+/// let track = moon_positions(start, start + Duration::days(1), Duration::hours(1)).unwrap();
+/// assert_eq!(track.len(), 25);
+///
+/// // About 13° a day eastwards
+/// let (_, ra0, _, _) = track[0];
+/// let (t, ra1, _, distance_km) = track[24];
+/// assert!((ra1 - ra0).rem_euclid(360.0) > 10.0 && (ra1 - ra0).rem_euclid(360.0) < 16.0);
+/// assert_eq!(distance_km, moon_distance(t));
+/// ```
+pub fn moon_positions(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: Duration,
+) -> Result<Vec<MoonPositionSample>> {
+    sample_uniformly(start, end, step, |time| {
+        let tt = crate::time_scales::utc_to_tt_jd(julian_date(time));
+        let pv = crate::backend::ephemerides::Moon98(tt, 0.0);
+        let (x, y, z) = (pv[0], pv[1], pv[2]);
+        let mut ra = y.atan2(x).to_degrees();
+        if ra < 0.0 {
+            ra += 360.0;
+        }
+        let dec = z.atan2((x * x + y * y).sqrt()).to_degrees();
+        (time, ra, dec, (x * x + y * y + z * z).sqrt() * AU_KM)
+    })
+}
+
+/// The Moon's optical libration and the orientation of its axis on the sky.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Libration {
+    /// Libration in longitude in degrees, in [-180, 180): the selenographic
+    /// longitude of the sub-observer point. Positive when the east limb
+    /// (Mare Crisium) is turned towards the observer.
+    pub longitude: f64,
+    /// Libration in latitude in degrees: the selenographic latitude of the
+    /// sub-observer point. Positive when the north polar region is turned
+    /// towards the observer.
+    pub latitude: f64,
+    /// Position angle of the Moon's north pole in degrees, measured from
+    /// celestial north through east, in [0, 360)
+    pub position_angle: f64,
+}
+
+/// Calculates the Moon's geocentric optical libration.
+///
+/// Follows Meeus, *Astronomical Algorithms*, chapter 53, from the apparent
+/// place of Moon98. The physical libration, under 0.04°, is not included.
+///
+/// # Arguments
+/// * `datetime` - Observation time
+///
+/// # Returns
+/// Libration in longitude and latitude (each within about ±8°) and the
+/// position angle of the axis, as seen from the Earth's centre
+///
+/// # Example
+/// ```
+/// use astro_math::moon::moon_libration;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 3, 25, 7, 0, 0).unwrap();
+/// let libration = moon_libration(dt);
+/// assert!(libration.longitude.abs() < 8.5 && libration.latitude.abs() < 7.0);
+/// ```
+pub fn moon_libration(datetime: DateTime<Utc>) -> Libration {
+    let place = apparent_place(AlmanacBody::Moon, datetime).expect("the Moon's ephemeris accepts any date");
+    let tt = crate::time_scales::utc_to_tt_jd(julian_date(datetime));
+    optical_libration(place.ra, place.dec, tt)
+}
+
+/// Calculates the point on the Moon at the centre of its disk for an
+/// observer.
+///
+/// The topocentric libration: from the surface of the Earth the Moon is
+/// seen up to about 1° from where the geocentric [`moon_libration`] puts
+/// it, which shifts the sub-observer point by as much.
+///
+/// # Arguments
+/// * `datetime` - Observation time
+/// * `location` - Observer's location
+///
+/// # Returns
+/// `(longitude, latitude)` of the sub-observer point in selenographic
+/// degrees, longitude in [-180, 180) and positive towards Mare Crisium
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if the latitude or longitude
+/// is out of range.
+///
+/// # Example
+/// ```
+/// use astro_math::moon::{moon_libration, moon_sub_observer_point};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 3, 25, 7, 0, 0).unwrap();
+/// let site = Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2096.0 };
+/// let (longitude, latitude) = moon_sub_observer_point(dt, &site).unwrap();
+/// let geocentric = moon_libration(dt);
+/// assert!((longitude - geocentric.longitude).abs() < 1.1);
+/// assert!((latitude - geocentric.latitude).abs() < 1.1);
+/// ```
+pub fn moon_sub_observer_point(datetime: DateTime<Utc>, location: &Location) -> Result<(f64, f64)> {
+    let (ra, dec) = topocentric_radec(&AlmanacBody::Moon, datetime, location)?;
+    let libration = optical_libration(ra, dec, crate::time_scales::utc_to_tt_jd(julian_date(datetime)));
+    Ok((libration.longitude, libration.latitude))
+}
+
+/// Optical libration for the Moon seen at an apparent RA/Dec of date.
+fn optical_libration(ra: f64, dec: f64, jd_tt: f64) -> Libration {
+    let t = (jd_tt - 2451545.0) / 36525.0;
+    let epsilon = true_obliquity(jd_tt).to_radians();
+    let (ra, dec) = (ra.to_radians(), dec.to_radians());
+    let incl = LUNAR_EQUATOR_INCLINATION_DEG.to_radians();
+
+    // Apparent ecliptic longitude and latitude of date
+    let lambda = (ra.sin() * epsilon.cos() + dec.tan() * epsilon.sin()).atan2(ra.cos());
+    let beta = (dec.sin() * epsilon.cos() - dec.cos() * epsilon.sin() * ra.sin()).asin();
+
+    // Mean longitude of the ascending node and argument of latitude
+    let node = (125.0445479 - 1934.1362891 * t + 0.0020754 * t * t + t.powi(3) / 467441.0
+        - t.powi(4) / 60616000.0)
+        .to_radians();
+    let f = (93.2720950 + 483202.0175233 * t - 0.0036539 * t * t - t.powi(3) / 3526000.0
+        + t.powi(4) / 863310000.0)
+        .to_radians();
+    let dpsi = (nutation_in_longitude(jd_tt) / 3600.0).to_radians();
+
+    let w = lambda - dpsi - node;
+    let a = (w.sin() * beta.cos() * incl.cos() - beta.sin() * incl.sin()).atan2(w.cos() * beta.cos());
+    let longitude = ((a - f).to_degrees() + 180.0).rem_euclid(360.0) - 180.0;
+    let latitude = (-w.sin() * beta.cos() * incl.sin() - beta.sin() * incl.cos()).asin();
+
+    // Direction of the pole of the lunar equator
+    let v = node + dpsi;
+    let x = incl.sin() * v.sin();
+    let y = incl.sin() * v.cos() * epsilon.cos() - incl.cos() * epsilon.sin();
+    let omega = x.atan2(y);
+    let sin_p = (x.hypot(y) * (ra - omega).cos() / latitude.cos()).clamp(-1.0, 1.0);
+
+    Libration {
+        longitude,
+        latitude: latitude.to_degrees(),
+        position_angle: sin_p.asin().to_degrees().rem_euclid(360.0),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,219 @@
+//! Mosaic tiling for regions larger than a camera's field of view.
+//!
+//! [`plan`] lays a rectangular grid of overlapping frames over a region of
+//! sky. The grid is laid out on the tangent plane at the mosaic centre, so
+//! rows and columns stay straight and evenly spaced on the sky wherever the
+//! mosaic is, including over a celestial pole. Spacing tiles by a fixed step
+//! in RA instead, which shrinks as cos(Dec), is the usual way to get gaps or
+//! wasted overlap near the poles.
+//!
+//! Because lines of constant RA converge, the camera angle that keeps a tile
+//! aligned with the grid changes across the mosaic. Each [`MosaicTile`]
+//! carries its own centre and rotation, in the same convention as
+//! [`TangentPlane::rotation`].
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::mosaic;
+//!
+//! // A 3°×2° mosaic of the Heart Nebula with a 1.5°×1° camera and 10% overlap
+//! let tiles = mosaic::plan((38.2, 61.45), 3.0, 2.0, (1.5, 1.0), 10.0, 0.0).unwrap();
+//! assert_eq!(tiles.len(), 3 * 3);
+//!
+//! // Tiles at the ends of a row are rotated by the convergence of the meridians
+//! let west = tiles.iter().find(|t| t.row == 1 && t.column == 2).unwrap();
+//! assert!(west.rotation.abs() > 1.0);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range centre
+//! - `AstroError::OutOfRange` for a non-positive or too large mosaic or
+//!   field of view, an overlap outside [0, 90] percent, or a non-finite rotation
+
+use crate::angles::bearing;
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::projection::TangentPlane;
+
+/// Largest mosaic or field side supported, in degrees.
+///
+/// The grid lives on a single tangent plane, which stops being useful well
+/// before a hemisphere.
+pub const MAX_MOSAIC_SIDE_DEG: f64 = 90.0;
+
+/// One frame of a mosaic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MosaicTile {
+    /// Row index, increasing along the mosaic's y axis (north at rotation 0)
+    pub row: usize,
+    /// Column index, increasing along the mosaic's x axis
+    pub column: usize,
+    /// Frame centre RA in degrees
+    pub ra: f64,
+    /// Frame centre Dec in degrees
+    pub dec: f64,
+    /// Camera rotation in degrees, as in [`TangentPlane::rotation`]
+    pub rotation: f64,
+    /// Field of view along x in degrees
+    pub width_deg: f64,
+    /// Field of view along y in degrees
+    pub height_deg: f64,
+}
+
+impl MosaicTile {
+    /// Tangent plane projection for this frame.
+    ///
+    /// The reference pixel is (0, 0), so pixel coordinates are offsets from
+    /// the frame centre; use [`TangentPlane::with_reference_pixel`] to move it.
+    ///
+    /// # Arguments
+    /// * `scale` - Pixel scale in arcseconds per pixel
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if `scale` is not positive.
+    pub fn tangent_plane(&self, scale: f64) -> Result<TangentPlane> {
+        Ok(TangentPlane::new(self.ra, self.dec, scale)?.with_rotation(self.rotation))
+    }
+
+    /// Corners of the frame on the sky, as `(ra, dec)` in degrees.
+    ///
+    /// The corners are in order around the frame, so they can be passed
+    /// directly to the [`sky_polygon`](crate::sky_polygon) functions.
+    pub fn corners(&self) -> Result<[(f64, f64); 4]> {
+        // One pixel per degree
+        let plane = self.tangent_plane(3600.0)?;
+        let (w, h) = (self.width_deg / 2.0, self.height_deg / 2.0);
+        Ok([
+            plane.pixel_to_ra_dec(-w, -h)?,
+            plane.pixel_to_ra_dec(w, -h)?,
+            plane.pixel_to_ra_dec(w, h)?,
+            plane.pixel_to_ra_dec(-w, h)?,
+        ])
+    }
+}
+
+/// Plans a mosaic covering a rectangular region.
+///
+/// Tiles are placed on a regular grid on the tangent plane at `center`,
+/// `camera_fov × (1 − overlap)` apart and centred on the region, with
+/// enough rows and columns to cover it. Far from the centre the tangent
+/// plane is stretched relative to the sky, so the actual overlap there is
+/// slightly larger than requested (never smaller).
+///
+/// # Arguments
+/// * `center` - Mosaic centre `(ra, dec)` in degrees
+/// * `width_deg`, `height_deg` - Size of the region along the mosaic's x and y axes
+/// * `camera_fov` - Camera field of view `(width, height)` in degrees
+/// * `overlap_pct` - Overlap between neighbouring frames, in percent of the field
+/// * `rotation` - Orientation of the mosaic grid in degrees, as in
+///   [`TangentPlane::rotation`]
+///
+/// # Returns
+/// Tiles ordered row by row, starting at row 0, column 0
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the centre is out of range
+/// - `AstroError::OutOfRange` if a size is not positive or exceeds
+///   [`MAX_MOSAIC_SIDE_DEG`], `overlap_pct` is outside [0, 90], or
+///   `rotation` is not finite
+///
+/// # Example
+/// ```
+/// use astro_math::mosaic;
+///
+/// // Around the north celestial pole the tiles fan out in RA
+/// let tiles = mosaic::plan((0.0, 90.0), 4.0, 4.0, (2.0, 2.0), 0.0, 0.0).unwrap();
+/// assert_eq!(tiles.len(), 4);
+/// for tile in &tiles {
+///     // Each tile centre is 1.41° from the pole
+///     assert!((90.0 - tile.dec - 1.414).abs() < 0.01);
+/// }
+/// ```
+pub fn plan(
+    center: (f64, f64),
+    width_deg: f64,
+    height_deg: f64,
+    camera_fov: (f64, f64),
+    overlap_pct: f64,
+    rotation: f64,
+) -> Result<Vec<MosaicTile>> {
+    let (ra0, dec0) = center;
+    validate_ra(ra0)?;
+    validate_dec(dec0)?;
+    for (parameter, value) in [
+        ("width_deg", width_deg),
+        ("height_deg", height_deg),
+        ("camera_fov.0", camera_fov.0),
+        ("camera_fov.1", camera_fov.1),
+    ] {
+        if !(value > 0.0 && value <= MAX_MOSAIC_SIDE_DEG) {
+            return Err(AstroError::OutOfRange {
+                parameter,
+                value,
+                min: f64::MIN_POSITIVE,
+                max: MAX_MOSAIC_SIDE_DEG,
+            });
+        }
+    }
+    if !(0.0..=90.0).contains(&overlap_pct) {
+        return Err(AstroError::OutOfRange {
+            parameter: "overlap_pct",
+            value: overlap_pct,
+            min: 0.0,
+            max: 90.0,
+        });
+    }
+    if !rotation.is_finite() {
+        return Err(AstroError::OutOfRange {
+            parameter: "rotation",
+            value: rotation,
+            min: f64::MIN,
+            max: f64::MAX,
+        });
+    }
+
+    let (fov_x, fov_y) = camera_fov;
+    let step_x = fov_x * (1.0 - overlap_pct / 100.0);
+    let step_y = fov_y * (1.0 - overlap_pct / 100.0);
+    let columns = tile_count(width_deg, fov_x, step_x);
+    let rows = tile_count(height_deg, fov_y, step_y);
+
+    // Mosaic plane at one pixel per degree
+    let plane = TangentPlane::new(ra0, dec0, 3600.0)?.with_rotation(rotation);
+
+    let mut tiles = Vec::with_capacity(rows * columns);
+    for row in 0..rows {
+        for column in 0..columns {
+            let x = (column as f64 - (columns - 1) as f64 / 2.0) * step_x;
+            let y = (row as f64 - (rows - 1) as f64 / 2.0) * step_y;
+            let (ra, dec) = plane.pixel_to_ra_dec(x, y)?;
+
+            // Keep the frame's y axis along the grid's y axis
+            let (ra_up, dec_up) = plane.pixel_to_ra_dec(x, y + 1e-4)?;
+            let up = bearing(ra, dec, ra_up, dec_up);
+            let tile_rotation = (180.0 - up).rem_euclid(360.0) - 180.0;
+
+            tiles.push(MosaicTile {
+                row,
+                column,
+                ra,
+                dec,
+                rotation: tile_rotation,
+                width_deg: fov_x,
+                height_deg: fov_y,
+            });
+        }
+    }
+    Ok(tiles)
+}
+
+/// Frames of size `fov` spaced by `step` needed to cover `extent`.
+fn tile_count(extent: f64, fov: f64, step: f64) -> usize {
+    if extent <= fov {
+        1
+    } else {
+        // Tolerate rounding when the extent is an exact multiple of the step
+        ((extent - fov) / step - 1e-9).ceil() as usize + 1
+    }
+}
@@ -0,0 +1,324 @@
+//! German equatorial mount geometry: pier side and tube–pier collisions.
+//!
+//! On a German equatorial mount (GEM) the tube sits off to one side of the
+//! polar axis, balanced by counterweights on the other. Every position of
+//! the sky can be reached from either side of the pier, and on the wrong
+//! side, or with the counterweights up, a long tube swings its rear end
+//! into the pier. [`collision_check`] predicts this from a simplified
+//! model of the mount, so slews that would hit the pier can be refused
+//! before they are sent.
+//!
+//! # Model
+//!
+//! Positions are in meters in a local frame centered on the intersection
+//! of the RA and Dec axes, with the RA axis parallel to the Earth's axis.
+//! The tube's optical axis is parallel to the pointing direction and
+//! `dec_axis_offset_m` from the RA axis along the Dec axis; its midpoint is
+//! `tube_center_offset_m` from the Dec axis, towards the sky. The pier is a
+//! vertical cylinder centered under the axes, its top `pier_top_m` below
+//! them. Tube and pier are modelled as capsules, cylinders with rounded
+//! ends, so the check errs on the safe side by up to a tube radius at the
+//! ends of the tube and a pier radius above the top of the pier.
+//!
+//! [`PierSide::East`] puts the tube on the east side of the pier, the
+//! normal side for targets west of the meridian; [`PierSide::West`] is the
+//! normal side for targets east of it. The counterweights are up when a
+//! target is tracked past the meridian without a flip.
+//!
+//! Mount flexure, the counterweight shaft and the mount head itself are
+//! not modelled; keep `clearance_m` generous enough to cover them.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::mount::{collision_check, MountGeometry, PierSide};
+//!
+//! // A 1.2 m refractor on a GEM at latitude 45° N
+//! let mount = MountGeometry {
+//!     latitude_deg: 45.0,
+//!     tube_length_m: 1.2,
+//!     tube_diameter_m: 0.2,
+//!     tube_center_offset_m: 0.1,
+//!     dec_axis_offset_m: 0.25,
+//!     pier_radius_m: 0.12,
+//!     pier_top_m: 0.3,
+//!     clearance_m: 0.02,
+//! };
+//!
+//! // On the normal side the tube is well clear of the pier
+//! assert!(!collision_check(&mount, 30.0, 20.0, PierSide::East).unwrap());
+//! // Six hours east of the meridian on the same side the counterweights
+//! // are up, and the rear of the tube swings into the pier
+//! assert!(collision_check(&mount, -90.0, 60.0, PierSide::East).unwrap());
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range latitude,
+//!   declination or altitude
+//! - `AstroError::OutOfRange` for a negative, zero or non-finite dimension
+//!   where a positive one is needed, or a non-finite hour angle or azimuth
+
+use crate::error::{validate_dec, validate_latitude, AstroError, Result};
+use crate::vec3::Vec3;
+
+/// Golden-section iterations; shrinks the search along the tube by 0.618^60
+const SEARCH_ITERATIONS: usize = 60;
+
+/// Side of the pier the tube is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PierSide {
+    /// Tube east of the pier, looking west: normal for hour angles 0 to 12h
+    East,
+    /// Tube west of the pier, looking east: normal for hour angles −12h to 0
+    West,
+}
+
+impl PierSide {
+    /// The side that keeps the counterweights below the tube at an hour
+    /// angle.
+    ///
+    /// # Arguments
+    /// * `ha_deg` - Hour angle in degrees, positive west of the meridian
+    pub fn counterweight_down(ha_deg: f64) -> PierSide {
+        if ha_deg.to_radians().sin() >= 0.0 {
+            PierSide::East
+        } else {
+            PierSide::West
+        }
+    }
+
+    fn sign(self) -> f64 {
+        match self {
+            PierSide::East => 1.0,
+            PierSide::West => -1.0,
+        }
+    }
+}
+
+/// Simplified geometry of a German equatorial mount, its tube and pier.
+///
+/// All lengths are in meters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MountGeometry {
+    /// Elevation of the polar axis, normally the site latitude, in degrees
+    pub latitude_deg: f64,
+    /// Length of the tube, including dew shield and camera
+    pub tube_length_m: f64,
+    /// Outer diameter of the tube
+    pub tube_diameter_m: f64,
+    /// Distance from the Dec axis to the tube's midpoint along the optical
+    /// axis, positive towards the sky end
+    pub tube_center_offset_m: f64,
+    /// Distance from the RA axis to the tube's optical axis along the Dec
+    /// axis: the saddle height plus half the tube diameter
+    pub dec_axis_offset_m: f64,
+    /// Radius of the pier
+    pub pier_radius_m: f64,
+    /// Height of the axes' intersection above the top of the pier
+    pub pier_top_m: f64,
+    /// Minimum gap to keep between tube and pier
+    pub clearance_m: f64,
+}
+
+impl MountGeometry {
+    /// Checks that the dimensions are physical.
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if the latitude is out of range
+    /// - `AstroError::OutOfRange` if the tube length or diameter or the
+    ///   pier radius is not positive, the clearance, pier top or Dec axis
+    ///   offset is negative, or any value is not finite
+    pub fn validate(&self) -> Result<()> {
+        validate_latitude(self.latitude_deg)?;
+        for (parameter, value) in [
+            ("tube_length_m", self.tube_length_m),
+            ("tube_diameter_m", self.tube_diameter_m),
+            ("pier_radius_m", self.pier_radius_m),
+        ] {
+            if !(value > 0.0 && value.is_finite()) {
+                return Err(AstroError::OutOfRange { parameter, value, min: f64::MIN_POSITIVE, max: f64::MAX });
+            }
+        }
+        for (parameter, value) in [
+            ("dec_axis_offset_m", self.dec_axis_offset_m),
+            ("pier_top_m", self.pier_top_m),
+            ("clearance_m", self.clearance_m),
+        ] {
+            if !(value >= 0.0 && value.is_finite()) {
+                return Err(AstroError::OutOfRange { parameter, value, min: 0.0, max: f64::MAX });
+            }
+        }
+        if !self.tube_center_offset_m.is_finite() {
+            return Err(AstroError::OutOfRange {
+                parameter: "tube_center_offset_m",
+                value: self.tube_center_offset_m,
+                min: f64::MIN,
+                max: f64::MAX,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Calculates the gap between the tube and the pier.
+///
+/// # Arguments
+/// * `geometry` - Mount, tube and pier dimensions
+/// * `ha_deg` - Hour angle of the pointing in degrees, positive west
+/// * `dec_deg` - Declination of the pointing in degrees
+/// * `pier_side` - Side of the pier the tube is on
+///
+/// # Returns
+/// The shortest distance between the surfaces of tube and pier in meters,
+/// negative if they intersect
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` for an out-of-range latitude or
+///   declination
+/// - `AstroError::OutOfRange` for invalid dimensions or a non-finite hour
+///   angle
+pub fn tube_pier_gap(geometry: &MountGeometry, ha_deg: f64, dec_deg: f64, pier_side: PierSide) -> Result<f64> {
+    geometry.validate()?;
+    validate_dec(dec_deg)?;
+    if !ha_deg.is_finite() {
+        return Err(AstroError::OutOfRange { parameter: "ha_deg", value: ha_deg, min: f64::MIN, max: f64::MAX });
+    }
+
+    // Local frame: x east, y north, z up
+    let (sin_lat, cos_lat) = geometry.latitude_deg.to_radians().sin_cos();
+    let (sin_h, cos_h) = ha_deg.to_radians().sin_cos();
+    let (sin_d, cos_d) = dec_deg.to_radians().sin_cos();
+    let pointing: Vec3 = [
+        -cos_d * sin_h,
+        cos_lat * sin_d - sin_lat * cos_d * cos_h,
+        sin_lat * sin_d + cos_lat * cos_d * cos_h,
+    ];
+    // Dec axis, perpendicular to the RA axis and the pointing, towards the
+    // tube on the east side of the pier
+    let dec_axis: Vec3 = [cos_h, -sin_lat * sin_h, cos_lat * sin_h];
+
+    let offset = pier_side.sign() * geometry.dec_axis_offset_m;
+    let axis_point = |t: f64| -> Vec3 {
+        [0, 1, 2].map(|k| offset * dec_axis[k] + (geometry.tube_center_offset_m + t) * pointing[k])
+    };
+    // Distance from a point to the pier's axis, a half-line down from its
+    // top; a convex function of the point, so also of t
+    let distance_to_pier_axis = |t: f64| {
+        let [x, y, z] = axis_point(t);
+        let horizontal = x.hypot(y);
+        let above_top = z + geometry.pier_top_m;
+        if above_top > 0.0 {
+            horizontal.hypot(above_top)
+        } else {
+            horizontal
+        }
+    };
+
+    let half = geometry.tube_length_m / 2.0;
+    let distance = golden_section_minimum(distance_to_pier_axis, -half, half);
+    Ok(distance - geometry.tube_diameter_m / 2.0 - geometry.pier_radius_m)
+}
+
+/// Predicts whether the tube hits the pier at an equatorial pointing.
+///
+/// # Arguments
+/// * `geometry` - Mount, tube and pier dimensions
+/// * `ha_deg` - Hour angle of the pointing in degrees, positive west
+/// * `dec_deg` - Declination of the pointing in degrees
+/// * `pier_side` - Side of the pier the tube is on
+///
+/// # Returns
+/// `true` if the tube comes closer to the pier than `clearance_m`
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` for an out-of-range latitude or
+///   declination
+/// - `AstroError::OutOfRange` for invalid dimensions or a non-finite hour
+///   angle
+pub fn collision_check(geometry: &MountGeometry, ha_deg: f64, dec_deg: f64, pier_side: PierSide) -> Result<bool> {
+    Ok(tube_pier_gap(geometry, ha_deg, dec_deg, pier_side)? < geometry.clearance_m)
+}
+
+/// Predicts whether the tube hits the pier at a horizontal pointing.
+///
+/// # Arguments
+/// * `geometry` - Mount, tube and pier dimensions
+/// * `alt_deg` - Altitude of the pointing in degrees
+/// * `az_deg` - Azimuth of the pointing in degrees, clockwise from north
+/// * `pier_side` - Side of the pier the tube is on
+///
+/// # Returns
+/// `true` if the tube comes closer to the pier than `clearance_m`
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` for an out-of-range latitude or
+///   altitude
+/// - `AstroError::OutOfRange` for invalid dimensions or a non-finite
+///   azimuth
+///
+/// # Example
+/// ```
+/// use astro_math::mount::{collision_check_alt_az, MountGeometry, PierSide};
+///
+/// let mount = MountGeometry {
+///     latitude_deg: -31.0,
+///     tube_length_m: 0.9,
+///     tube_diameter_m: 0.3,
+///     tube_center_offset_m: 0.2,
+///     dec_axis_offset_m: 0.35,
+///     pier_radius_m: 0.1,
+///     pier_top_m: 0.35,
+///     clearance_m: 0.05,
+/// };
+/// // The zenith is safe from either side
+/// assert!(!collision_check_alt_az(&mount, 90.0, 0.0, PierSide::East).unwrap());
+/// assert!(!collision_check_alt_az(&mount, 90.0, 0.0, PierSide::West).unwrap());
+/// ```
+pub fn collision_check_alt_az(geometry: &MountGeometry, alt_deg: f64, az_deg: f64, pier_side: PierSide) -> Result<bool> {
+    let (ha_deg, dec_deg) = alt_az_to_ha_dec(geometry.latitude_deg, alt_deg, az_deg)?;
+    collision_check(geometry, ha_deg, dec_deg, pier_side)
+}
+
+/// Converts a horizontal direction to hour angle and declination.
+fn alt_az_to_ha_dec(latitude_deg: f64, alt_deg: f64, az_deg: f64) -> Result<(f64, f64)> {
+    validate_latitude(latitude_deg)?;
+    if !(-90.0..=90.0).contains(&alt_deg) {
+        return Err(AstroError::InvalidCoordinate { coord_type: "Altitude", value: alt_deg, valid_range: "[-90, 90]" });
+    }
+    if !az_deg.is_finite() {
+        return Err(AstroError::OutOfRange { parameter: "az_deg", value: az_deg, min: f64::MIN, max: f64::MAX });
+    }
+    let (sin_lat, cos_lat) = latitude_deg.to_radians().sin_cos();
+    let (sin_alt, cos_alt) = alt_deg.to_radians().sin_cos();
+    let (sin_az, cos_az) = az_deg.to_radians().sin_cos();
+    let (east, north, up) = (cos_alt * sin_az, cos_alt * cos_az, sin_alt);
+    let dec = (sin_lat * up + cos_lat * north).clamp(-1.0, 1.0).asin();
+    let ha = (-east).atan2(cos_lat * up - sin_lat * north);
+    Ok((ha.to_degrees(), dec.to_degrees()))
+}
+
+/// Minimum of a convex function on `[a, b]` by golden-section search.
+fn golden_section_minimum(f: impl Fn(f64) -> f64, mut a: f64, mut b: f64) -> f64 {
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let (mut fc, mut fd) = (f(c), f(d));
+    for _ in 0..SEARCH_ITERATIONS {
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - ratio * (b - a);
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + ratio * (b - a);
+            fd = f(d);
+        }
+    }
+    // The minimum may sit at an end of the tube
+    fc.min(fd).min(f(a)).min(f(b))
+}
@@ -0,0 +1,206 @@
+//! Observers whose position changes with time.
+//!
+//! Airborne astronomy (eclipse and occultation flights, SOFIA-style
+//! observatories) and ship-based observing need the observer's geodetic
+//! position, and therefore the local sidereal time, recomputed for every
+//! timestamp. A [`MovingObserver`] supplies a [`Location`] for any time,
+//! either from a recorded track such as a GPS log or from a closure.
+//!
+//! The `_moving` transform variants
+//! ([`ra_dec_to_alt_az_moving`](crate::transforms::ra_dec_to_alt_az_moving),
+//! [`ra_dec_to_alt_az_erfa_moving`](crate::transforms::ra_dec_to_alt_az_erfa_moving)
+//! and [`alt_az_to_ra_dec_moving`](crate::transforms::alt_az_to_ra_dec_moving))
+//! look the position up at the requested time and then behave like their
+//! fixed-location counterparts. The observer's own velocity (at most ~0.2″
+//! of aberration for an airliner) is not modelled.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::Location;
+//! use astro_math::moving_observer::MovingObserver;
+//! use chrono::{Duration, TimeZone, Utc};
+//!
+//! let t0 = Utc.with_ymd_and_hms(2024, 4, 8, 18, 0, 0).unwrap();
+//! let track = vec![
+//!     (t0, Location { latitude_deg: 30.0, longitude_deg: -100.0, altitude_m: 11000.0 }),
+//!     (t0 + Duration::hours(1), Location { latitude_deg: 32.0, longitude_deg: -96.0, altitude_m: 12000.0 }),
+//! ];
+//! let flight = MovingObserver::from_track(track).unwrap();
+//!
+//! let midway = flight.location_at(t0 + Duration::minutes(30)).unwrap();
+//! assert!((midway.latitude_deg - 31.0).abs() < 0.05);
+//! assert!((midway.altitude_m - 11500.0).abs() < 1e-6);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::CalculationError` for an empty or unordered track
+//! - `AstroError::OutOfRange` for times outside a track
+
+use crate::error::{AstroError, Result};
+use crate::location::Location;
+use crate::time::julian_date;
+use crate::vec3::{dot, radec_to_unit_vector};
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::sync::Arc;
+
+/// An observer whose location is a function of time.
+#[derive(Clone)]
+pub struct MovingObserver {
+    motion: Motion,
+}
+
+#[derive(Clone)]
+enum Motion {
+    /// Time-ordered fixes, interpolated between neighbours
+    Track(Vec<(DateTime<Utc>, Location)>),
+    /// Arbitrary position model
+    Function(Arc<dyn Fn(DateTime<Utc>) -> Location + Send + Sync>),
+}
+
+impl fmt::Debug for MovingObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.motion {
+            Motion::Track(track) => f.debug_struct("MovingObserver").field("track", track).finish(),
+            Motion::Function(_) => f.debug_struct("MovingObserver").field("function", &"..").finish(),
+        }
+    }
+}
+
+impl MovingObserver {
+    /// Creates an observer from a recorded track such as a GPS log.
+    ///
+    /// Between fixes the position is interpolated along the great circle
+    /// (so tracks may cross the antimeridian or pass near a pole) and the
+    /// altitude linearly. The track must be sampled densely enough for this
+    /// to follow the real path.
+    ///
+    /// # Arguments
+    /// * `track` - `(time, location)` fixes in strictly increasing time order
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::CalculationError)` if the track is empty or
+    /// its times are not strictly increasing.
+    pub fn from_track(track: Vec<(DateTime<Utc>, Location)>) -> Result<Self> {
+        if track.is_empty() {
+            return Err(AstroError::CalculationError {
+                calculation: "observer track",
+                reason: "track has no fixes".to_string(),
+            });
+        }
+        if let Some(pair) = track.windows(2).find(|pair| pair[1].0 <= pair[0].0) {
+            return Err(AstroError::CalculationError {
+                calculation: "observer track",
+                reason: format!("fix at {} does not follow {}", pair[1].0, pair[0].0),
+            });
+        }
+        Ok(Self { motion: Motion::Track(track) })
+    }
+
+    /// Creates an observer from a position model.
+    ///
+    /// Use this for dead reckoning, a planned flight path, or any other
+    /// analytic description of the motion.
+    ///
+    /// # Arguments
+    /// * `position` - Returns the observer's location at a given time
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::Location;
+    /// use astro_math::moving_observer::MovingObserver;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// // A ship steaming due east along the equator at 0.25° per hour
+    /// let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let ship = MovingObserver::from_fn(move |t| Location {
+    ///     latitude_deg: 0.0,
+    ///     longitude_deg: 0.25 * (t - t0).num_seconds() as f64 / 3600.0,
+    ///     altitude_m: 15.0,
+    /// });
+    ///
+    /// let later = ship.location_at(t0 + chrono::Duration::hours(4)).unwrap();
+    /// assert_eq!(later.longitude_deg, 1.0);
+    /// ```
+    pub fn from_fn<F>(position: F) -> Self
+    where
+        F: Fn(DateTime<Utc>) -> Location + Send + Sync + 'static,
+    {
+        Self { motion: Motion::Function(Arc::new(position)) }
+    }
+
+    /// Creates an observer that stays at one location.
+    pub fn stationary(location: Location) -> Self {
+        Self::from_fn(move |_| location)
+    }
+
+    /// Time span covered by a track, or `None` for a position model.
+    pub fn time_range(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        match &self.motion {
+            Motion::Track(track) => Some((track[0].0, track[track.len() - 1].0)),
+            Motion::Function(_) => None,
+        }
+    }
+
+    /// Returns the observer's location at a given time.
+    ///
+    /// # Arguments
+    /// * `datetime` - UTC time
+    ///
+    /// # Returns
+    /// The interpolated or modelled [`Location`]
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if the observer was created from
+    /// a track and `datetime` lies outside it (values are Julian Dates).
+    pub fn location_at(&self, datetime: DateTime<Utc>) -> Result<Location> {
+        let track = match &self.motion {
+            Motion::Function(position) => return Ok(position(datetime)),
+            Motion::Track(track) => track,
+        };
+
+        let (first, last) = (track[0].0, track[track.len() - 1].0);
+        if datetime < first || datetime > last {
+            return Err(AstroError::OutOfRange {
+                parameter: "datetime",
+                value: julian_date(datetime),
+                min: julian_date(first),
+                max: julian_date(last),
+            });
+        }
+
+        // First fix at or after the requested time
+        let k = track.partition_point(|(t, _)| *t < datetime);
+        if track[k].0 == datetime {
+            return Ok(track[k].1);
+        }
+        let (t0, a) = track[k - 1];
+        let (t1, b) = track[k];
+        let f = (datetime - t0).num_microseconds().unwrap_or(i64::MAX) as f64
+            / (t1 - t0).num_microseconds().unwrap_or(i64::MAX) as f64;
+        Ok(interpolate(&a, &b, f))
+    }
+}
+
+/// Great-circle interpolation of the horizontal position, linear in altitude.
+fn interpolate(a: &Location, b: &Location, f: f64) -> Location {
+    let pa = radec_to_unit_vector(a.longitude_deg, a.latitude_deg);
+    let pb = radec_to_unit_vector(b.longitude_deg, b.latitude_deg);
+    let cos_d = dot(pa, pb).clamp(-1.0, 1.0);
+    let d = cos_d.acos();
+
+    let (wa, wb) = if d < 1e-12 {
+        (1.0 - f, f)
+    } else {
+        (((1.0 - f) * d).sin() / d.sin(), (f * d).sin() / d.sin())
+    };
+    let p: [f64; 3] = std::array::from_fn(|i| wa * pa[i] + wb * pb[i]);
+
+    Location {
+        latitude_deg: p[2].atan2(p[0].hypot(p[1])).to_degrees(),
+        longitude_deg: p[1].atan2(p[0]).to_degrees(),
+        altitude_m: a.altitude_m + f * (b.altitude_m - a.altitude_m),
+    }
+}
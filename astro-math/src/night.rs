@@ -0,0 +1,237 @@
+//! Observing nights: noon-to-noon spans named by their evening date.
+//!
+//! An observing night crosses midnight, and at most longitudes it also
+//! crosses a UTC date boundary, so planning by UTC date splits one night
+//! across two dates or counts the wrong evening. [`Night`] fixes the span
+//! instead: it runs from local mean noon to the next local mean noon at the
+//! observer's longitude, and is named by the local date on which it begins,
+//! the convention of observing logs ("the night of 3 November"). Every
+//! instant belongs to exactly one night, found with [`Night::containing`].
+//!
+//! Local mean noon depends only on the longitude, so the boundaries are
+//! fixed times of day; the Sun is near its highest at either end, well away
+//! from any sunset, sunrise or twilight. The night's Sun events are searched
+//! for across the whole span:
+//!
+//! - [`Night::sunset`] and [`Night::sunrise`] use the Sun's upper limb at
+//!   the standard altitude on a sea-level horizon, as
+//!   [`sun_rise_set`](crate::rise_set::sun_rise_set) does
+//! - [`Night::dusk`] and [`Night::dawn`] are when the Sun's center is 6°,
+//!   12° or 18° below the geometric horizon for civil, nautical and
+//!   astronomical [`Twilight`]
+//!
+//! Each is `None` when the event does not happen, e.g. during polar day, or
+//! in summer at high latitudes where astronomical twilight lasts all night.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::night::{Night, Twilight};
+//! use astro_math::Location;
+//! use chrono::{NaiveDate, TimeZone, Utc};
+//!
+//! // Kitt Peak is 7h 27m west of Greenwich
+//! let kitt_peak = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+//!
+//! // 03:00 UTC on November 4 is still the evening of November 3 locally
+//! let t = Utc.with_ymd_and_hms(2024, 11, 4, 3, 0, 0).unwrap();
+//! let night = Night::containing(t, &kitt_peak).unwrap();
+//! assert_eq!(night.date(), NaiveDate::from_ymd_opt(2024, 11, 3).unwrap());
+//!
+//! let dusk = night.dusk(Twilight::Astronomical).unwrap().unwrap();
+//! let dawn = night.dawn(Twilight::Astronomical).unwrap().unwrap();
+//! assert!(night.sunset().unwrap().unwrap() < dusk && dawn < night.sunrise().unwrap().unwrap());
+//! assert!((dawn - dusk).num_hours() == 10);
+//!
+//! // Hourly planning grid across the night
+//! assert_eq!(night.hours().count(), 24);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range latitude or longitude
+
+use crate::error::{validate_latitude, validate_longitude, Result};
+use crate::events::{find_zeros, Crossing};
+use crate::location::Location;
+use crate::rise_set::BodyProfile;
+use crate::sun::solar_azimuth_elevation;
+use chrono::{DateTime, Duration, NaiveDate, Timelike, Utc};
+
+/// Twilight, by how far the Sun's center is below the horizon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Twilight {
+    /// Sun 6° below the horizon; the brightest stars are visible
+    Civil,
+    /// Sun 12° below the horizon; the sea horizon is no longer visible
+    Nautical,
+    /// Sun 18° below the horizon; the sky is fully dark
+    Astronomical,
+}
+
+impl Twilight {
+    /// Altitude of the Sun's center at the start and end of this twilight,
+    /// in degrees.
+    pub fn sun_altitude(self) -> f64 {
+        match self {
+            Twilight::Civil => -6.0,
+            Twilight::Nautical => -12.0,
+            Twilight::Astronomical => -18.0,
+        }
+    }
+}
+
+/// One observing night at a location, from local mean noon to the next.
+#[derive(Debug, Clone, Copy)]
+pub struct Night {
+    date: NaiveDate,
+    location: Location,
+}
+
+impl Night {
+    /// Returns the night that `datetime` falls in.
+    ///
+    /// # Arguments
+    /// * `datetime` - Any instant; instants at or after local mean noon
+    ///   belong to that evening's night
+    /// * `location` - Observer's location
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if the latitude or
+    /// longitude is out of range.
+    pub fn containing(datetime: DateTime<Utc>, location: &Location) -> Result<Night> {
+        validate_latitude(location.latitude_deg)?;
+        validate_longitude(location.longitude_deg)?;
+        // Local mean time is twelve hours ahead of the night's clock
+        let local = datetime.naive_utc() + longitude_offset(location.longitude_deg) - Duration::hours(12);
+        Ok(Night { date: local.date(), location: *location })
+    }
+
+    /// Returns the night that begins on the local evening of `date`.
+    ///
+    /// # Arguments
+    /// * `date` - Local date of the evening
+    /// * `location` - Observer's location
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if the latitude or
+    /// longitude is out of range.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::night::Night;
+    /// use astro_math::Location;
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    ///
+    /// // Akashi, on the meridian of Japan Standard Time
+    /// let akashi = Location { latitude_deg: 34.65, longitude_deg: 135.0, altitude_m: 0.0 };
+    /// let night = Night::starting_on(NaiveDate::from_ymd_opt(2024, 11, 3).unwrap(), &akashi).unwrap();
+    /// assert_eq!(night.start(), Utc.with_ymd_and_hms(2024, 11, 3, 3, 0, 0).unwrap());
+    /// assert_eq!(night.end(), Utc.with_ymd_and_hms(2024, 11, 4, 3, 0, 0).unwrap());
+    /// ```
+    pub fn starting_on(date: NaiveDate, location: &Location) -> Result<Night> {
+        validate_latitude(location.latitude_deg)?;
+        validate_longitude(location.longitude_deg)?;
+        Ok(Night { date, location: *location })
+    }
+
+    /// Local date of the evening on which the night begins.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Observer's location.
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    /// Local mean noon at which the night begins.
+    pub fn start(&self) -> DateTime<Utc> {
+        let noon = self.date.and_hms_opt(12, 0, 0).unwrap() - longitude_offset(self.location.longitude_deg);
+        DateTime::from_naive_utc_and_offset(noon, Utc)
+    }
+
+    /// Local mean noon at which the night ends, exclusive.
+    pub fn end(&self) -> DateTime<Utc> {
+        self.start() + Duration::days(1)
+    }
+
+    /// Whether `datetime` falls within the night.
+    pub fn contains(&self, datetime: DateTime<Utc>) -> bool {
+        self.start() <= datetime && datetime < self.end()
+    }
+
+    /// The following night.
+    pub fn next(&self) -> Night {
+        Night { date: self.date.succ_opt().unwrap(), location: self.location }
+    }
+
+    /// The preceding night.
+    pub fn previous(&self) -> Night {
+        Night { date: self.date.pred_opt().unwrap(), location: self.location }
+    }
+
+    /// Every whole UTC hour within the night, in order.
+    pub fn hours(&self) -> impl Iterator<Item = DateTime<Utc>> {
+        let start = self.start();
+        let end = self.end();
+        let past_hour = Duration::seconds(start.minute() as i64 * 60 + start.second() as i64)
+            + Duration::nanoseconds(start.nanosecond() as i64);
+        let first = if past_hour.is_zero() { start } else { start - past_hour + Duration::hours(1) };
+        (0..).map(move |h| first + Duration::hours(h)).take_while(move |&t| t < end)
+    }
+
+    /// Sunset at the start of the night.
+    ///
+    /// # Returns
+    /// The time the Sun's upper limb sets, `None` if it does not set
+    pub fn sunset(&self) -> Result<Option<DateTime<Utc>>> {
+        self.sun_crossing(BodyProfile::SunUpperLimb.standard_altitude(), Crossing::Falling)
+    }
+
+    /// Sunrise at the end of the night.
+    ///
+    /// # Returns
+    /// The time the Sun's upper limb rises, `None` if it does not rise
+    pub fn sunrise(&self) -> Result<Option<DateTime<Utc>>> {
+        self.sun_crossing(BodyProfile::SunUpperLimb.standard_altitude(), Crossing::Rising)
+    }
+
+    /// End of evening twilight, when the night proper begins.
+    ///
+    /// # Arguments
+    /// * `twilight` - Civil, nautical or astronomical
+    ///
+    /// # Returns
+    /// The time the Sun sinks to the twilight's altitude, `None` if it
+    /// stays above or below it all night
+    pub fn dusk(&self, twilight: Twilight) -> Result<Option<DateTime<Utc>>> {
+        self.sun_crossing(twilight.sun_altitude(), Crossing::Falling)
+    }
+
+    /// Start of morning twilight.
+    ///
+    /// # Arguments
+    /// * `twilight` - Civil, nautical or astronomical
+    ///
+    /// # Returns
+    /// The time the Sun climbs to the twilight's altitude, `None` if it
+    /// stays above or below it all night
+    pub fn dawn(&self, twilight: Twilight) -> Result<Option<DateTime<Utc>>> {
+        self.sun_crossing(twilight.sun_altitude(), Crossing::Rising)
+    }
+
+    /// First time in the night the Sun's center crosses `altitude` in the
+    /// given direction.
+    fn sun_crossing(&self, altitude: f64, direction: Crossing) -> Result<Option<DateTime<Utc>>> {
+        // The location is validated, so the Sun's elevation is always finite
+        let above = |t| solar_azimuth_elevation(t, &self.location).map(|(_, el)| el - altitude).unwrap_or(f64::NAN);
+        let crossings = find_zeros(above, self.start(), self.end(), Duration::minutes(10), Duration::seconds(1))?;
+        Ok(crossings.into_iter().find(|c| c.1 == direction).map(|c| c.0))
+    }
+}
+
+/// Local mean time minus UTC at a longitude, to the millisecond.
+fn longitude_offset(longitude_deg: f64) -> Duration {
+    Duration::milliseconds((longitude_deg * 240_000.0).round() as i64)
+}
@@ -32,7 +32,24 @@
 //! println!("Nutation in obliquity: {:.2}\"", deps);
 //! println!("True obliquity: {:.6}°", true_obliquity);
 //! ```
+//!
+//! # Error Handling
+//!
+//! The plain functions take any Julian Date and return NaN for NaN input.
+//! The `_checked` variants validate the date first with
+//! [`validate_jd`]:
+//!
+//! - `AstroError::CalculationError` for a NaN or infinite Julian Date
+//! - `AstroError::OutOfRange` for a Julian Date outside
+//!   [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX)
+//! - `AstroError::InvalidCoordinate` for an out-of-range RA or Dec in the
+//!   coordinate functions
 
+use chrono::{DateTime, Utc};
+use crate::config::ObliquityModel;
+use crate::error::{Result, validate_dec, validate_jd, validate_ra};
+use crate::precession::{rotate_batch, rotate_ra_dec};
+use crate::time::julian_date;
 
 /// Calculates nutation in longitude (Δψ) in arcseconds using ERFA.
 ///
@@ -61,7 +78,7 @@ pub fn nutation_in_longitude(jd: f64) -> f64 {
     let jd2 = 0.0;
     
     // Get nutation using IAU 2000A model
-    let (dpsi, _deps) = erfars::precnutpolar::Nut00a(jd1, jd2);
+    let (dpsi, _deps) = crate::backend::precnutpolar::Nut00a(jd1, jd2);
     
     // Convert from radians to arcseconds using exact mathematical constant
     dpsi * (180.0 * 3600.0 / std::f64::consts::PI)
@@ -94,7 +111,7 @@ pub fn nutation_in_obliquity(jd: f64) -> f64 {
     let jd2 = 0.0;
     
     // Get nutation using IAU 2000A model
-    let (_dpsi, deps) = erfars::precnutpolar::Nut00a(jd1, jd2);
+    let (_dpsi, deps) = crate::backend::precnutpolar::Nut00a(jd1, jd2);
     
     // Convert from radians to arcseconds using exact mathematical constant
     deps * (180.0 * 3600.0 / std::f64::consts::PI)
@@ -102,7 +119,8 @@ pub fn nutation_in_obliquity(jd: f64) -> f64 {
 
 /// Calculates the mean obliquity of the ecliptic (ε₀) in degrees using ERFA.
 ///
-/// Uses the IAU 2006 precession model.
+/// Uses the IAU 2006 precession model, or the IAU 1980 model if that is the
+/// [configured](crate::config::ObliquityModel) default.
 ///
 /// # Arguments
 ///
@@ -126,8 +144,10 @@ pub fn mean_obliquity(jd: f64) -> f64 {
     let jd1 = jd;
     let jd2 = 0.0;
     
-    // Get mean obliquity using IAU 2006 model
-    let eps_rad = erfars::precnutpolar::Obl06(jd1, jd2);
+    let eps_rad = match crate::config::defaults().obliquity {
+        ObliquityModel::Iau2006 => crate::backend::precnutpolar::Obl06(jd1, jd2),
+        ObliquityModel::Iau1980 => crate::backend::precnutpolar::Obl80(jd1, jd2),
+    };
     
     // Convert from radians to degrees
     eps_rad.to_degrees()
@@ -164,6 +184,7 @@ pub fn true_obliquity(jd: f64) -> f64 {
 /// This is convenient when you need both values and want to avoid
 /// duplicate calculations of the fundamental arguments.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nutation {
     /// Nutation in longitude (Δψ) in arcseconds
     pub longitude: f64,
@@ -193,12 +214,116 @@ pub struct Nutation {
 /// println!("Δψ = {:.2}\", Δε = {:.2}\"", nut.longitude, nut.obliquity);
 /// ```
 pub fn nutation(jd: f64) -> Nutation {
-    // Split JD for better precision
-    let jd1 = jd;
-    let jd2 = 0.0;
-    
+    nutation_two_part(jd, 0.0)
+}
+
+/// Calculates nutation in longitude (Δψ), rejecting an invalid Julian Date.
+///
+/// Same as [`nutation_in_longitude`], which returns NaN for NaN input and
+/// meaningless values for dates far outside the range of the series.
+///
+/// # Arguments
+///
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+///
+/// Nutation in longitude in arcseconds.
+///
+/// # Errors
+///
+/// Returns an error if `jd` is not finite or is outside
+/// [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX).
+///
+/// # Example
+///
+/// ```
+/// use astro_math::nutation::{nutation_in_longitude, nutation_in_longitude_checked};
+///
+/// assert_eq!(nutation_in_longitude_checked(2451545.0).unwrap(), nutation_in_longitude(2451545.0));
+/// assert!(nutation_in_longitude_checked(f64::NAN).is_err());
+/// ```
+pub fn nutation_in_longitude_checked(jd: f64) -> Result<f64> {
+    validate_jd(jd)?;
+    Ok(nutation_in_longitude(jd))
+}
+
+/// Calculates nutation in obliquity (Δε), rejecting an invalid Julian Date.
+///
+/// Same as [`nutation_in_obliquity`] apart from the validation.
+///
+/// # Arguments
+///
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+///
+/// Nutation in obliquity in arcseconds.
+///
+/// # Errors
+///
+/// Returns an error if `jd` is not finite or is outside
+/// [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX).
+pub fn nutation_in_obliquity_checked(jd: f64) -> Result<f64> {
+    validate_jd(jd)?;
+    Ok(nutation_in_obliquity(jd))
+}
+
+/// Calculates both nutation components, rejecting an invalid Julian Date.
+///
+/// Same as [`nutation`] apart from the validation.
+///
+/// # Arguments
+///
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+///
+/// A `Nutation` struct containing both components in arcseconds.
+///
+/// # Errors
+///
+/// Returns an error if `jd` is not finite or is outside
+/// [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX).
+///
+/// # Example
+///
+/// ```
+/// use astro_math::nutation::nutation_checked;
+///
+/// let nut = nutation_checked(2451545.0).unwrap();
+/// assert!(nut.longitude.abs() < 20.0);
+/// assert!(nutation_checked(1.0e12).is_err());
+/// ```
+pub fn nutation_checked(jd: f64) -> Result<Nutation> {
+    validate_jd(jd)?;
+    Ok(nutation(jd))
+}
+
+/// Calculates both nutation components from a two-part Julian Date (TT).
+///
+/// Identical to [`nutation`] but keeps the full precision of a split Julian Date.
+///
+/// # Arguments
+///
+/// * `jd1`, `jd2` - Julian Date (TT) split into two parts
+///
+/// # Returns
+///
+/// A `Nutation` struct containing both components in arcseconds.
+///
+/// # Example
+///
+/// ```
+/// use astro_math::nutation::{nutation, nutation_two_part};
+/// 
+/// let a = nutation(2460526.75);
+/// let b = nutation_two_part(2460526.5, 0.25);
+/// assert!((a.longitude - b.longitude).abs() < 1e-9);
+/// ```
+pub fn nutation_two_part(jd1: f64, jd2: f64) -> Nutation {
     // Get nutation using IAU 2000A model
-    let (dpsi, deps) = erfars::precnutpolar::Nut00a(jd1, jd2);
+    let (dpsi, deps) = crate::backend::precnutpolar::Nut00a(jd1, jd2);
     
     // Convert from radians to arcseconds using exact mathematical constant  
     let rad_to_arcsec = 180.0 * 3600.0 / std::f64::consts::PI;
@@ -208,6 +333,89 @@ pub fn nutation(jd: f64) -> Nutation {
     }
 }
 
+/// Returns the nutation matrix (mean equator and equinox of date → true
+/// equator and equinox of date) for a Julian Date (TT).
+///
+/// Built from the IAU 2000A nutation components and IAU 2006 mean obliquity.
+///
+/// # Arguments
+///
+/// * `jd` - Julian Date (TT)
+///
+/// # Returns
+///
+/// 3×3 rotation matrix
+pub fn nutation_matrix(jd: f64) -> [[f64; 3]; 3] {
+    let (dpsi, deps) = crate::backend::precnutpolar::Nut00a(jd, 0.0);
+    let epsa = crate::backend::precnutpolar::Obl06(jd, 0.0);
+    let mut rmatn = [0.0; 9];
+    crate::backend::precnutpolar::Numat(epsa, dpsi, deps, &mut rmatn);
+    [
+        [rmatn[0], rmatn[1], rmatn[2]],
+        [rmatn[3], rmatn[4], rmatn[5]],
+        [rmatn[6], rmatn[7], rmatn[8]],
+    ]
+}
+
+/// Applies nutation to a mean-of-date position, giving the true-of-date position.
+///
+/// Typically used after [`precess_from_j2000`](crate::precess_from_j2000) to
+/// obtain apparent-place coordinates (before aberration).
+///
+/// # Arguments
+///
+/// * `ra` - Mean right ascension of date in degrees
+/// * `dec` - Mean declination of date in degrees
+/// * `datetime` - Date/time of the equinox
+///
+/// # Returns
+///
+/// Tuple of true (ra, dec) of date in degrees.
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+///
+/// ```
+/// use astro_math::nutation::apply_nutation;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let (ra, dec) = apply_nutation(279.5, 38.8, dt).unwrap();
+/// // Nutation shifts positions by at most ~20 arcseconds
+/// assert!((ra - 279.5).abs() < 0.01 && (dec - 38.8).abs() < 0.01);
+/// ```
+pub fn apply_nutation(ra: f64, dec: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    let m = nutation_matrix(julian_date(datetime));
+    Ok(rotate_ra_dec(&m, ra, dec, false))
+}
+
+/// Applies nutation to many mean-of-date positions in parallel.
+///
+/// The nutation matrix is computed once and applied to every coordinate with
+/// Rayon, instead of re-evaluating the IAU 2000A series per point.
+///
+/// # Arguments
+///
+/// * `coords` - Slice of mean (ra, dec) of date pairs in degrees
+/// * `datetime` - Date/time of the equinox
+///
+/// # Returns
+///
+/// Vector of true (ra, dec) of date in degrees, in input order.
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if any RA or Dec is out of range.
+pub fn apply_nutation_batch(coords: &[(f64, f64)], datetime: DateTime<Utc>) -> Result<Vec<(f64, f64)>> {
+    let m = nutation_matrix(julian_date(datetime));
+    rotate_batch(&m, coords, false)
+}
+
 // Keep the old functions for backwards compatibility with internal use
 #[doc(hidden)]
 pub fn nutation_in_longitude_arcsec(jd: f64) -> f64 {
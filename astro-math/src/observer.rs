@@ -0,0 +1,419 @@
+//! Ground-based and space-based observers.
+//!
+//! Most of the crate describes the observer with a [`Location`] on the
+//! Earth, which brings an atmosphere, a horizon and the Earth's rotation
+//! with it. Balloon, airborne-above-the-atmosphere and orbiting telescopes
+//! have none of these: they see the sky from a point given in Earth-centred
+//! inertial coordinates, move with their own platform velocity and are never
+//! refracted. [`ObserverKind`] makes the distinction explicit, and
+//! [`observed_place`] runs the appropriate pipeline for either kind
+//! ([`observed_place_with_provenance`] also records which corrections it
+//! applied):
+//!
+//! | | `Ground` | `Space` |
+//! |---|---|---|
+//! | Parallax (for a finite distance) | from the site | from the platform position |
+//! | Aberration | annual + diurnal | annual + platform velocity |
+//! | Earth rotation, polar motion | applied | not applicable |
+//! | Refraction | when conditions are given | never |
+//! | Altitude/azimuth | yes | no |
+//!
+//! Space positions and velocities are geocentric, in the GCRS, whose axes
+//! are those of the ICRS (the "J2000 ECI" frame of most orbit products).
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::Location;
+//! use astro_math::observer::{observed_place, ObserverKind};
+//! use chrono::{TimeZone, Utc};
+//!
+//! let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+//! let ground = ObserverKind::Ground(Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 });
+//! // Low Earth orbit, 7.6 km/s
+//! let leo = ObserverKind::space([6778.0, 0.0, 0.0], [0.0, 7.6, 0.0]).unwrap();
+//!
+//! let from_ground = observed_place(279.23473479, 38.78368896, None, dt, &ground, None).unwrap();
+//! let from_orbit = observed_place(279.23473479, 38.78368896, None, dt, &leo, None).unwrap();
+//!
+//! assert!(from_ground.alt_az.is_some());
+//! assert!(from_orbit.alt_az.is_none());
+//! // The platform velocity shifts Vega by a few arcseconds of aberration
+//! let shift = ((from_orbit.ra - from_ground.ra) * 38.78f64.to_radians().cos()).hypot(from_orbit.dec - from_ground.dec);
+//! assert!(shift * 3600.0 > 1.0 && shift * 3600.0 < 6.0);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::OutOfRange` for a non-positive distance, a space platform
+//!   inside the Earth or moving faster than light, or out-of-range weather
+//! - `AstroError::CalculationError` if the ERFA transformation fails
+
+use crate::erfa::bias_precession_nutation_matrix;
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::location::Location;
+use crate::provenance::{Correction, EopSource, Provenance, RefractionApplied};
+use crate::refraction::AtmosphericConditions;
+use crate::time::julian_date_two_part;
+use crate::time_scales::utc_to_tt_jd_two_part;
+use crate::vec3::{dot, norm, normalize, radec_to_unit_vector, unit_vector_to_radec};
+use chrono::{DateTime, Utc};
+use std::f64::consts::FRAC_PI_2;
+
+/// Earth's equatorial radius in kilometers
+const EARTH_RADIUS_KM: f64 = 6378.137;
+
+/// Earth's polar radius in kilometers
+const EARTH_POLAR_RADIUS_KM: f64 = 6356.752;
+
+/// Earth's flattening factor
+const EARTH_FLATTENING: f64 = 1.0 / 298.257223563;
+
+/// Astronomical Unit in kilometers
+const AU_KM: f64 = 149597870.7;
+
+/// Speed of light in km/s
+const C_KM_S: f64 = 299792.458;
+
+/// Effective wavelength of visual observation, in micrometers
+const VISUAL_WAVELENGTH_UM: f64 = 0.574;
+
+/// Where an observer is, and therefore which effects apply.
+#[derive(Debug, Clone, Copy)]
+pub enum ObserverKind {
+    /// On or near the ground, under the atmosphere and rotating with the Earth
+    Ground(Location),
+    /// Above the atmosphere, at a geocentric GCRS position. Construct with
+    /// [`ObserverKind::space`] to validate the state vector.
+    Space {
+        /// Geocentric position in kilometers
+        position_km: [f64; 3],
+        /// Geocentric velocity in km/s
+        velocity_km_s: [f64; 3],
+    },
+}
+
+impl ObserverKind {
+    /// Creates a space observer from a GCRS state vector.
+    ///
+    /// # Arguments
+    /// * `position_km` - Geocentric position in kilometers
+    /// * `velocity_km_s` - Geocentric velocity in km/s
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if the position is inside the
+    /// Earth, the speed is not below the speed of light, or any component
+    /// is not finite.
+    pub fn space(position_km: [f64; 3], velocity_km_s: [f64; 3]) -> Result<Self> {
+        let radius = norm(position_km);
+        if !(radius >= EARTH_POLAR_RADIUS_KM && radius.is_finite()) {
+            return Err(AstroError::OutOfRange {
+                parameter: "position_km",
+                value: radius,
+                min: EARTH_POLAR_RADIUS_KM,
+                max: f64::MAX,
+            });
+        }
+        let speed = norm(velocity_km_s);
+        if speed.is_nan() || speed >= C_KM_S {
+            return Err(AstroError::OutOfRange {
+                parameter: "velocity_km_s",
+                value: speed,
+                min: 0.0,
+                max: C_KM_S,
+            });
+        }
+        Ok(ObserverKind::Space { position_km, velocity_km_s })
+    }
+
+    /// Whether observations from here pass through the atmosphere.
+    pub fn has_atmosphere(&self) -> bool {
+        matches!(self, ObserverKind::Ground(_))
+    }
+
+    /// Geocentric GCRS position of the observer in kilometers.
+    ///
+    /// For a ground observer the site is rotated from the terrestrial frame
+    /// with the Greenwich apparent sidereal time, taking UT1 = UTC and no
+    /// polar motion.
+    pub fn gcrs_position_km(&self, datetime: DateTime<Utc>) -> [f64; 3] {
+        match *self {
+            ObserverKind::Ground(location) => {
+                let (jd1, jd2) = julian_date_two_part(datetime);
+                let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+                let gast = crate::backend::rotationtime::Gst06a(jd1, jd2, tt1, tt2);
+                let itrs = terrestrial_position_km(&location);
+
+                // Earth rotation to the true equator and equinox of date...
+                let (s, c) = gast.sin_cos();
+                let true_of_date = [c * itrs[0] - s * itrs[1], s * itrs[0] + c * itrs[1], itrs[2]];
+                // ...then back to the GCRS through the transposed NPB matrix
+                let npb = bias_precession_nutation_matrix(tt1, tt2);
+                [0, 1, 2].map(|j| (0..3).map(|i| npb[i][j] * true_of_date[i]).sum())
+            }
+            ObserverKind::Space { position_km, .. } => position_km,
+        }
+    }
+}
+
+/// An observer on the ground or in space, as taken by [`observed_place`].
+pub type Observer = ObserverKind;
+
+/// Apparent position of a target as seen by an observer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObservedPlace {
+    /// Right ascension in degrees, referred to the CIRS (CIO-based) of date
+    pub ra: f64,
+    /// Declination in degrees, referred to the CIRS of date
+    pub dec: f64,
+    /// `(altitude, azimuth)` in degrees for ground observers; `None` in space
+    pub alt_az: Option<(f64, f64)>,
+}
+
+/// Calculates where a target appears to an observer on the ground or in space.
+///
+/// # Arguments
+/// * `ra`, `dec` - ICRS position in degrees; geocentric astrometric for
+///   solar-system targets
+/// * `distance_au` - Geocentric distance for solar-system targets, so that
+///   parallax is applied; `None` for stars and other distant targets
+/// * `datetime` - Time of observation (UTC)
+/// * `observer` - Ground or space observer
+/// * `conditions` - Weather for refraction at a ground observer; `None`
+///   for the [default](crate::config) weather, no refraction unless set.
+///   Ignored in space, where there is no atmosphere.
+///
+/// # Returns
+/// The apparent position. For a ground observer with `conditions`, RA/Dec
+/// and altitude include refraction.
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if `distance_au` is not positive or a weather
+///   value is out of range
+/// - `AstroError::CalculationError` if the ERFA transformation fails
+///
+/// # Example
+/// ```
+/// use astro_math::observer::{observed_place, ObserverKind};
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// // A geostationary platform sees the Moon shifted by up to ~9° of parallax
+/// let geo = ObserverKind::space([42164.0, 0.0, 0.0], [0.0, 3.07, 0.0]).unwrap();
+/// let centre = ObserverKind::space([6378.137, 0.0, 0.0], [0.0; 3]).unwrap();
+///
+/// let a = observed_place(90.0, 0.0, Some(0.00257), dt, &geo, None).unwrap();
+/// let b = observed_place(90.0, 0.0, Some(0.00257), dt, &centre, None).unwrap();
+/// assert!((a.ra - b.ra).abs() > 4.0);
+/// ```
+pub fn observed_place(
+    ra: f64,
+    dec: f64,
+    distance_au: Option<f64>,
+    datetime: DateTime<Utc>,
+    observer: &ObserverKind,
+    conditions: Option<AtmosphericConditions>,
+) -> Result<ObservedPlace> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+
+    // Topocentric astrometric direction, in the ICRS axes
+    let mut direction = radec_to_unit_vector(ra, dec);
+    if let Some(distance_au) = distance_au {
+        if !(distance_au > 0.0 && distance_au.is_finite()) {
+            return Err(AstroError::OutOfRange {
+                parameter: "distance_au",
+                value: distance_au,
+                min: f64::MIN_POSITIVE,
+                max: f64::MAX,
+            });
+        }
+        let observer_km = observer.gcrs_position_km(datetime);
+        let target_km = direction.map(|c| c * distance_au * AU_KM);
+        direction = normalize([0, 1, 2].map(|i| target_km[i] - observer_km[i]));
+    }
+
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+    let (ra_rad, dec_rad) = spherical(direction);
+
+    match *observer {
+        ObserverKind::Ground(location) => {
+            let conditions = match crate::config::conditions_or_default(conditions) {
+                Some(c) => {
+                    c.validate()?;
+                    c
+                }
+                None => AtmosphericConditions { pressure_hpa: 0.0, temperature_c: 0.0, relative_humidity: 0.0 },
+            };
+            // Atco13 adds annual and diurnal aberration, Earth rotation and refraction
+            let (aob, zob, _, dob, rob, _) = crate::backend::astrometry::Atco13(
+                ra_rad,
+                dec_rad,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                jd1,
+                jd2,
+                0.0,
+                location.longitude_deg.to_radians(),
+                location.latitude_deg.to_radians(),
+                location.altitude_m,
+                0.0,
+                0.0,
+                conditions.pressure_hpa,
+                conditions.temperature_c,
+                conditions.relative_humidity,
+                VISUAL_WAVELENGTH_UM,
+            )
+            .map_err(|_| AstroError::CalculationError {
+                calculation: "ERFA Atco13",
+                reason: "Failed to transform ICRS to observed coordinates".to_string(),
+            })?;
+            Ok(ObservedPlace {
+                ra: rob.to_degrees().rem_euclid(360.0),
+                dec: dob.to_degrees(),
+                alt_az: Some(((FRAC_PI_2 - zob).to_degrees(), aob.to_degrees().rem_euclid(360.0))),
+            })
+        }
+        ObserverKind::Space { velocity_km_s, .. } => {
+            // Aberration from the platform's motion relative to the geocentre
+            // (first order; the second-order cross term with the Earth's
+            // velocity is below 2 mas in low Earth orbit)
+            let beta = velocity_km_s.map(|v| v / C_KM_S);
+            let aberrated = normalize([0, 1, 2].map(|i| direction[i] + beta[i] - dot(direction, beta) * direction[i]));
+            let (ra_rad, dec_rad) = spherical(aberrated);
+
+            // Atci13 adds light deflection, annual aberration, precession and nutation
+            let (ri, di, _) = crate::backend::astrometry::Atci13(ra_rad, dec_rad, 0.0, 0.0, 0.0, 0.0, tt1, tt2);
+            Ok(ObservedPlace { ra: ri.to_degrees().rem_euclid(360.0), dec: di.to_degrees(), alt_az: None })
+        }
+    }
+}
+
+/// Calculates where a target appears to an observer, with the provenance
+/// of the result.
+///
+/// The same calculation as [`observed_place`], returning a [`Provenance`]
+/// that records the corrections applied for this kind of observer, the
+/// refraction model and weather, and the leap-second table.
+///
+/// # Arguments
+/// As for [`observed_place`]
+///
+/// # Returns
+/// The apparent position and its provenance
+///
+/// # Errors
+/// As for [`observed_place`]
+///
+/// # Example
+/// ```
+/// use astro_math::observer::{observed_place, observed_place_with_provenance, ObserverKind};
+/// use astro_math::provenance::Correction;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let leo = ObserverKind::space([6778.0, 0.0, 0.0], [0.0, 7.6, 0.0]).unwrap();
+///
+/// let (place, provenance) = observed_place_with_provenance(279.2347, 38.7837, None, dt, &leo, None).unwrap();
+/// assert_eq!(place, observed_place(279.2347, 38.7837, None, dt, &leo, None).unwrap());
+/// assert!(provenance.applied(Correction::PlatformAberration));
+/// assert!(!provenance.applied(Correction::Refraction));
+/// assert!(provenance.eop.is_none());
+/// ```
+pub fn observed_place_with_provenance(
+    ra: f64,
+    dec: f64,
+    distance_au: Option<f64>,
+    datetime: DateTime<Utc>,
+    observer: &ObserverKind,
+    conditions: Option<AtmosphericConditions>,
+) -> Result<(ObservedPlace, Provenance)> {
+    let conditions = crate::config::conditions_or_default(conditions);
+    let place = observed_place(ra, dec, distance_au, datetime, observer, conditions)?;
+
+    let mut corrections = Vec::new();
+    if distance_au.is_some() {
+        corrections.push(Correction::Parallax);
+    }
+    let provenance = match observer {
+        ObserverKind::Ground(_) => {
+            corrections.extend([
+                Correction::FrameBias,
+                Correction::Precession,
+                Correction::Nutation,
+                Correction::LightDeflection,
+                Correction::AnnualAberration,
+                Correction::DiurnalAberration,
+                Correction::EarthRotation,
+            ]);
+            let refraction = conditions.filter(|c| c.pressure_hpa > 0.0).map(|conditions| RefractionApplied {
+                model: "ERFA Refco (A tan z + B tan^3 z)",
+                conditions,
+                wavelength_um: VISUAL_WAVELENGTH_UM,
+            });
+            if refraction.is_some() {
+                corrections.push(Correction::Refraction);
+            }
+            Provenance::new(datetime, "ERFA Atco13", corrections, refraction, Some(EopSource::Zero))
+        }
+        ObserverKind::Space { .. } => {
+            corrections.extend([
+                Correction::PlatformAberration,
+                Correction::FrameBias,
+                Correction::Precession,
+                Correction::Nutation,
+                Correction::LightDeflection,
+                Correction::AnnualAberration,
+            ]);
+            Provenance::new(datetime, "ERFA Atci13", corrections, None, None)
+        }
+    };
+    Ok((place, provenance))
+}
+
+/// ITRS position of a site on the WGS84 ellipsoid, in kilometers.
+pub(crate) fn terrestrial_position_km(location: &Location) -> [f64; 3] {
+    let (lat, lon) = (location.latitude_deg.to_radians(), location.longitude_deg.to_radians());
+    let e2 = EARTH_FLATTENING * (2.0 - EARTH_FLATTENING);
+    let n = EARTH_RADIUS_KM / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+    let h = location.altitude_m / 1000.0;
+    [
+        (n + h) * lat.cos() * lon.cos(),
+        (n + h) * lat.cos() * lon.sin(),
+        (n * (1.0 - e2) + h) * lat.sin(),
+    ]
+}
+
+/// Geodetic location of an ITRS position in kilometers on the WGS84
+/// ellipsoid, the inverse of [`terrestrial_position_km`].
+pub(crate) fn geodetic_from_terrestrial_km(position: [f64; 3]) -> Location {
+    let e2 = EARTH_FLATTENING * (2.0 - EARTH_FLATTENING);
+    let p = position[0].hypot(position[1]);
+    let mut lat = position[2].atan2(p * (1.0 - e2));
+    let mut h = 0.0;
+    // Converges to well under a millimeter within a few iterations for
+    // heights up to thousands of kilometers
+    for _ in 0..6 {
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let n = EARTH_RADIUS_KM / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        // Stable at the poles, unlike p / cos(lat) - n
+        h = p * cos_lat + position[2] * sin_lat - EARTH_RADIUS_KM * EARTH_RADIUS_KM / n;
+        lat = position[2].atan2(p * (1.0 - e2 * n / (n + h)));
+    }
+    Location {
+        latitude_deg: lat.to_degrees(),
+        longitude_deg: position[1].atan2(position[0]).to_degrees(),
+        altitude_m: h * 1000.0,
+    }
+}
+
+/// `(ra, dec)` in radians of a direction.
+fn spherical(v: [f64; 3]) -> (f64, f64) {
+    let (ra, dec) = unit_vector_to_radec(v);
+    (ra.to_radians(), dec.to_radians())
+}
@@ -0,0 +1,415 @@
+//! Chord reduction and profile fitting for asteroid occultations.
+//!
+//! When an asteroid passes in front of a star, observers spread across its
+//! shadow path each time the star's disappearance (D) and reappearance (R).
+//! Seen from the star, each observer's line of sight crosses the asteroid
+//! along a straight *chord*; together the chords outline the asteroid's
+//! silhouette to a few hundred metres, far better than any telescope can
+//! resolve it.
+//!
+//! The reduction works on the fundamental plane of the event's
+//! [`BesselianElements`], computed with
+//! [`BesselianEvent::AsteroidOccultation`](crate::besselian::BesselianEvent::AsteroidOccultation):
+//!
+//! 1. [`fundamental_plane_coordinates`] projects an observer onto the plane
+//!    at an instant, as the standard coordinates (ξ, η).
+//! 2. [`shadow_offset_km`] subtracts the shadow axis, giving the observer's
+//!    position relative to the asteroid's predicted centre.
+//! 3. [`reduce_chords`] does this at the D and R times of each
+//!    [`OccultationTiming`], turning them into a [`Chord`].
+//! 4. [`fit_circle`] and [`fit_ellipse`] fit a profile to the chord ends.
+//!
+//! Offsets are in kilometres, with `x` towards the east and `y` towards the
+//! north celestial pole, as seen from the Earth looking at the star. An
+//! error in the predicted asteroid position shifts all chords together, so
+//! the fitted centre measures the prediction's miss while the shape is
+//! unaffected.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::besselian::BesselianElements;
+//! use astro_math::occultation::{fit_circle, reduce_chords, OccultationTiming};
+//! use astro_math::Location;
+//! use chrono::{DateTime, TimeZone, Utc};
+//!
+//! // Shadow of a 60 km asteroid crossing Europe eastwards at 5.3 km/s
+//! let t0 = Utc.with_ymd_and_hms(2024, 11, 3, 21, 30, 0).unwrap();
+//! let elements = BesselianElements {
+//!     t0,
+//!     delta_t_s: 69.184,
+//!     x: [-0.2245, 3.0, 0.0, 0.0],
+//!     y: [0.4935, 0.0, 0.0, 0.0],
+//!     d: [20.0, 0.0, 0.0, 0.0],
+//!     mu: [330.0, 15.041, 0.0, 0.0],
+//!     l1: [0.0047, 0.0, 0.0, 0.0],
+//!     l2: [0.0047, 0.0, 0.0, 0.0],
+//!     tan_f1: 0.0,
+//!     tan_f2: 0.0,
+//! };
+//!
+//! let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+//! let site = |latitude_deg| Location { latitude_deg, longitude_deg: 10.0, altitude_m: 0.0 };
+//! let timings = [
+//!     OccultationTiming {
+//!         location: site(48.85),
+//!         disappearance: at("2024-11-03T21:29:52.280Z"),
+//!         reappearance: at("2024-11-03T21:30:04.002Z"),
+//!     },
+//!     OccultationTiming {
+//!         location: site(49.0),
+//!         disappearance: at("2024-11-03T21:29:53.303Z"),
+//!         reappearance: at("2024-11-03T21:30:04.730Z"),
+//!     },
+//!     OccultationTiming {
+//!         location: site(49.1),
+//!         disappearance: at("2024-11-03T21:29:54.856Z"),
+//!         reappearance: at("2024-11-03T21:30:04.347Z"),
+//!     },
+//! ];
+//!
+//! let chords = reduce_chords(&elements, &timings).unwrap();
+//! assert!((chords[1].length_km() - 57.4).abs() < 0.1);
+//!
+//! // The asteroid passed 3 km east and 2 km south of its predicted centre
+//! let profile = fit_circle(&chords).unwrap();
+//! assert!((profile.semi_major_km - 30.0).abs() < 0.01);
+//! assert!((profile.center_km.0 - 3.0).abs() < 0.01);
+//! assert!((profile.center_km.1 + 2.0).abs() < 0.01);
+//! assert!(profile.rms_km < 0.01);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range observer latitude
+//!   or longitude
+//! - `AstroError::InvalidDateTime` for a reappearance before its
+//!   disappearance
+//! - `AstroError::CalculationError` for too few chords, or chord ends that
+//!   do not determine the profile
+
+use crate::besselian::BesselianElements;
+use crate::error::{validate_latitude, validate_longitude, AstroError, Result};
+use crate::location::Location;
+use crate::observer::terrestrial_position_km;
+use chrono::{DateTime, Utc};
+use nalgebra::{DMatrix, DVector, Matrix2};
+
+/// Earth equatorial radius in kilometers, the unit of the Besselian elements
+const EARTH_RADIUS_KM: f64 = 6378.137;
+
+/// Smallest ratio of singular values of a fit's design matrix, below which
+/// the chord ends do not determine the profile
+const MIN_CONDITION: f64 = 1e-9;
+
+/// The timed disappearance and reappearance of the star at one site.
+#[derive(Debug, Clone, Copy)]
+pub struct OccultationTiming {
+    /// Observer's location
+    pub location: Location,
+    /// Time the star disappeared (D)
+    pub disappearance: DateTime<Utc>,
+    /// Time the star reappeared (R)
+    pub reappearance: DateTime<Utc>,
+}
+
+/// One observer's chord across the asteroid's shadow.
+#[derive(Debug, Clone, Copy)]
+pub struct Chord {
+    /// Observer's location
+    pub location: Location,
+    /// Offset from the shadow axis at disappearance, `(x, y)` in kilometers
+    pub disappearance: (f64, f64),
+    /// Offset from the shadow axis at reappearance, `(x, y)` in kilometers
+    pub reappearance: (f64, f64),
+}
+
+impl Chord {
+    /// Length of the chord in kilometers.
+    pub fn length_km(&self) -> f64 {
+        (self.reappearance.0 - self.disappearance.0).hypot(self.reappearance.1 - self.disappearance.1)
+    }
+
+    /// Midpoint of the chord, `(x, y)` in kilometers.
+    pub fn midpoint(&self) -> (f64, f64) {
+        (
+            (self.disappearance.0 + self.reappearance.0) / 2.0,
+            (self.disappearance.1 + self.reappearance.1) / 2.0,
+        )
+    }
+}
+
+/// A circular or elliptical profile fitted to chord ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProfileFit {
+    /// Centre of the profile relative to the predicted shadow axis, `(x, y)`
+    /// in kilometers
+    pub center_km: (f64, f64),
+    /// Semi-major axis in kilometers (the radius for a circle)
+    pub semi_major_km: f64,
+    /// Semi-minor axis in kilometers (the radius for a circle)
+    pub semi_minor_km: f64,
+    /// Position angle of the major axis in degrees, from north through east,
+    /// in [0, 180); zero for a circle
+    pub position_angle_deg: f64,
+    /// Root-mean-square radial distance of the chord ends from the profile,
+    /// in kilometers
+    pub rms_km: f64,
+}
+
+impl ProfileFit {
+    /// Diameter of the circle with the same area as the profile, in
+    /// kilometers.
+    pub fn equivalent_diameter_km(&self) -> f64 {
+        2.0 * (self.semi_major_km * self.semi_minor_km).sqrt()
+    }
+}
+
+/// Position of an observer on the fundamental plane.
+///
+/// # Arguments
+/// * `elements` - Besselian elements of the event
+/// * `location` - Observer's location
+/// * `time` - Instant of interest, within about ±3 hours of `elements.t0`
+///
+/// # Returns
+/// The standard coordinates `(ξ, η)` in Earth equatorial radii
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if the latitude or longitude
+/// is out of range.
+pub fn fundamental_plane_coordinates(
+    elements: &BesselianElements,
+    location: &Location,
+    time: DateTime<Utc>,
+) -> Result<(f64, f64)> {
+    validate_latitude(location.latitude_deg)?;
+    validate_longitude(location.longitude_deg)?;
+
+    let [x, y, z] = terrestrial_position_km(location);
+    let rho_cos_phi = x.hypot(y) / EARTH_RADIUS_KM;
+    let rho_sin_phi = z / EARTH_RADIUS_KM;
+
+    let plane = elements.at(time);
+    let hour_angle = plane.mu.to_radians() + y.atan2(x);
+    let (sin_d, cos_d) = plane.d.to_radians().sin_cos();
+    Ok((
+        rho_cos_phi * hour_angle.sin(),
+        rho_sin_phi * cos_d - rho_cos_phi * sin_d * hour_angle.cos(),
+    ))
+}
+
+/// Offset of an observer from the shadow axis.
+///
+/// # Arguments
+/// * `elements` - Besselian elements of the event
+/// * `location` - Observer's location
+/// * `time` - Instant of interest, within about ±3 hours of `elements.t0`
+///
+/// # Returns
+/// `(ξ − x, η − y)` in kilometers
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if the latitude or longitude
+/// is out of range.
+pub fn shadow_offset_km(elements: &BesselianElements, location: &Location, time: DateTime<Utc>) -> Result<(f64, f64)> {
+    let (xi, eta) = fundamental_plane_coordinates(elements, location, time)?;
+    let plane = elements.at(time);
+    Ok(((xi - plane.x) * EARTH_RADIUS_KM, (eta - plane.y) * EARTH_RADIUS_KM))
+}
+
+/// Reduces observed timings to chords.
+///
+/// # Arguments
+/// * `elements` - Besselian elements of the event
+/// * `timings` - One entry per observer who saw the star disappear
+///
+/// # Returns
+/// The chords, in the order of `timings`
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if a latitude or longitude is out of
+///   range
+/// - `AstroError::InvalidDateTime` if a reappearance is before its
+///   disappearance
+pub fn reduce_chords(elements: &BesselianElements, timings: &[OccultationTiming]) -> Result<Vec<Chord>> {
+    timings
+        .iter()
+        .map(|timing| {
+            if timing.reappearance < timing.disappearance {
+                return Err(AstroError::InvalidDateTime {
+                    reason: format!(
+                        "reappearance {} is before disappearance {}",
+                        timing.reappearance, timing.disappearance
+                    ),
+                });
+            }
+            Ok(Chord {
+                location: timing.location,
+                disappearance: shadow_offset_km(elements, &timing.location, timing.disappearance)?,
+                reappearance: shadow_offset_km(elements, &timing.location, timing.reappearance)?,
+            })
+        })
+        .collect()
+}
+
+/// Fits a circle to the chord ends by least squares.
+///
+/// # Arguments
+/// * `chords` - At least two chords that are not all on one line
+///
+/// # Returns
+/// The fitted profile, with equal semi-axes
+///
+/// # Errors
+/// Returns `Err(AstroError::CalculationError)` if the chord ends are fewer
+/// than three, all on one line, or not finite.
+pub fn fit_circle(chords: &[Chord]) -> Result<ProfileFit> {
+    let NormalizedEnds { origin, scale, points } = normalized_ends(chords, 3, "occultation circle fit")?;
+
+    // x² + y² + D·x + E·y + F = 0
+    let design = DMatrix::from_fn(points.len(), 3, |row, column| match column {
+        0 => points[row].0,
+        1 => points[row].1,
+        _ => 1.0,
+    });
+    let rhs = DVector::from_iterator(points.len(), points.iter().map(|&(x, y)| -(x * x + y * y)));
+    let c = solve(design, rhs, "occultation circle fit")?;
+
+    let center = (-c[0] / 2.0, -c[1] / 2.0);
+    let radius_sq = center.0 * center.0 + center.1 * center.1 - c[2];
+    if !(radius_sq > 0.0 && radius_sq.is_finite()) {
+        return Err(failure("occultation circle fit", "chord ends do not lie on a circle".to_string()));
+    }
+    let radius = radius_sq.sqrt();
+    let sum_sq: f64 = points
+        .iter()
+        .map(|&(x, y)| ((x - center.0).hypot(y - center.1) - radius).powi(2))
+        .sum();
+
+    Ok(ProfileFit {
+        center_km: (origin.0 + scale * center.0, origin.1 + scale * center.1),
+        semi_major_km: scale * radius,
+        semi_minor_km: scale * radius,
+        position_angle_deg: 0.0,
+        rms_km: scale * (sum_sq / points.len() as f64).sqrt(),
+    })
+}
+
+/// Fits an ellipse to the chord ends by least squares.
+///
+/// The fit is algebraic (a general conic through the points), which is
+/// exact for exact data and close to the best geometric fit when the
+/// chords cover the profile well. With only three chords it passes
+/// through five of the six ends and is poorly constrained; fix the shape
+/// with [`fit_circle`] unless the chords are well spread.
+///
+/// # Arguments
+/// * `chords` - At least three chords
+///
+/// # Returns
+/// The fitted profile
+///
+/// # Errors
+/// Returns `Err(AstroError::CalculationError)` if the chord ends are fewer
+/// than five, do not determine a conic, or the best-fitting conic is not an
+/// ellipse.
+pub fn fit_ellipse(chords: &[Chord]) -> Result<ProfileFit> {
+    const CALCULATION: &str = "occultation ellipse fit";
+    let NormalizedEnds { origin, scale, points } = normalized_ends(chords, 5, CALCULATION)?;
+
+    // A·x² + B·xy + C·y² + D·x + E·y = 1, which cannot pass through the
+    // origin; the centroid of the ends is inside the ellipse
+    let design = DMatrix::from_fn(points.len(), 5, |row, column| {
+        let (x, y) = points[row];
+        [x * x, x * y, y * y, x, y][column]
+    });
+    let rhs = DVector::from_element(points.len(), 1.0);
+    let c = solve(design, rhs, CALCULATION)?;
+    let (a, b, cc, d, e) = (c[0], c[1], c[2], c[3], c[4]);
+
+    let not_ellipse = || failure(CALCULATION, "chord ends do not lie on an ellipse".to_string());
+    let det = 4.0 * a * cc - b * b;
+    if !(det > 0.0 && det.is_finite()) {
+        return Err(not_ellipse());
+    }
+    let center = ((b * e - 2.0 * cc * d) / det, (b * d - 2.0 * a * e) / det);
+    // About its centre the conic is A·u² + B·uv + C·v² = k
+    let k = 1.0 - (a * center.0 * center.0 + b * center.0 * center.1 + cc * center.1 * center.1)
+        - d * center.0
+        - e * center.1;
+    let quadratic = Matrix2::new(a, b / 2.0, b / 2.0, cc) / k;
+    let eigen = quadratic.symmetric_eigen();
+    let (minor, major) = if eigen.eigenvalues[0] > eigen.eigenvalues[1] { (0, 1) } else { (1, 0) };
+    if !(eigen.eigenvalues[major] > 0.0 && eigen.eigenvalues[minor].is_finite()) {
+        return Err(not_ellipse());
+    }
+    let axis = eigen.eigenvectors.column(major);
+
+    let sum_sq: f64 = points
+        .iter()
+        .map(|&(x, y)| {
+            let (u, v) = (x - center.0, y - center.1);
+            let r = u.hypot(v);
+            let level = (a * u * u + b * u * v + cc * v * v) / k;
+            (r - r / level.sqrt()).powi(2)
+        })
+        .sum();
+
+    Ok(ProfileFit {
+        center_km: (origin.0 + scale * center.0, origin.1 + scale * center.1),
+        semi_major_km: scale / eigen.eigenvalues[major].sqrt(),
+        semi_minor_km: scale / eigen.eigenvalues[minor].sqrt(),
+        position_angle_deg: axis[0].atan2(axis[1]).to_degrees().rem_euclid(180.0),
+        rms_km: scale * (sum_sq / points.len() as f64).sqrt(),
+    })
+}
+
+fn failure(calculation: &'static str, reason: String) -> AstroError {
+    AstroError::CalculationError { calculation, reason }
+}
+
+/// Chord ends relative to their centroid and divided by their RMS distance
+/// from it, which keeps the fits well conditioned.
+struct NormalizedEnds {
+    origin: (f64, f64),
+    scale: f64,
+    points: Vec<(f64, f64)>,
+}
+
+fn normalized_ends(chords: &[Chord], min_points: usize, calculation: &'static str) -> Result<NormalizedEnds> {
+    let ends: Vec<(f64, f64)> = chords.iter().flat_map(|c| [c.disappearance, c.reappearance]).collect();
+    if ends.len() < min_points {
+        return Err(failure(
+            calculation,
+            format!("need at least {} chord ends, got {}", min_points, ends.len()),
+        ));
+    }
+    if ends.iter().any(|(x, y)| !x.is_finite() || !y.is_finite()) {
+        return Err(failure(calculation, "chord ends are not finite".to_string()));
+    }
+    let n = ends.len() as f64;
+    let origin = (
+        ends.iter().map(|e| e.0).sum::<f64>() / n,
+        ends.iter().map(|e| e.1).sum::<f64>() / n,
+    );
+    let scale = (ends.iter().map(|e| (e.0 - origin.0).powi(2) + (e.1 - origin.1).powi(2)).sum::<f64>() / n).sqrt();
+    if !(scale > 0.0 && scale.is_finite()) {
+        return Err(failure(calculation, "chord ends coincide".to_string()));
+    }
+    let points = ends
+        .iter()
+        .map(|e| ((e.0 - origin.0) / scale, (e.1 - origin.1) / scale))
+        .collect();
+    Ok(NormalizedEnds { origin, scale, points })
+}
+
+/// Least-squares solution, rejecting rank-deficient designs.
+fn solve(design: DMatrix<f64>, rhs: DVector<f64>, calculation: &'static str) -> Result<DVector<f64>> {
+    let svd = design.svd(true, true);
+    let largest = svd.singular_values.max();
+    if !(svd.singular_values.min() > MIN_CONDITION * largest && largest.is_finite()) {
+        return Err(failure(calculation, "chord ends do not determine the profile".to_string()));
+    }
+    svd.solve(&rhs, 1e-12).map_err(|reason| failure(calculation, reason.to_string()))
+}
@@ -0,0 +1,123 @@
+//! Differential coordinates for offset-star and blind-offset work.
+//!
+//! Telescope offsets are given as an east–west and a north–south displacement
+//! on the sky, in arcseconds: `(ΔRA·cos(Dec), ΔDec)`. Naively multiplying a
+//! difference in RA by cos(Dec) breaks down near the celestial poles, where
+//! lines of constant RA converge, and when the two positions straddle
+//! RA = 0°. This module defines the offsets as standard coordinates on the
+//! tangent plane at the reference position (the same gnomonic projection
+//! as [`TangentPlane`](crate::projection::TangentPlane)), which:
+//!
+//! - reduces to `(ΔRA·cos(Dec), ΔDec)` for small offsets away from the pole
+//! - stays well defined up to and across the pole
+//! - makes [`offset_position`] the exact inverse of [`offset_between`]
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::offsets::{offset_between, offset_position};
+//!
+//! // Blind offset from a bright star to a faint target
+//! let star = (83.8221, -5.3911);
+//! let target = (83.8187, -5.3875);
+//! let (east, north) = offset_between(star.0, star.1, target.0, target.1).unwrap();
+//!
+//! let (ra, dec) = offset_position(star.0, star.1, east, north).unwrap();
+//! assert!((ra - target.0).abs() < 1e-9 && (dec - target.1).abs() < 1e-9);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::ProjectionError` when the two positions are 90° or more apart
+//! - `AstroError::OutOfRange` for non-finite offsets
+
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+
+/// Arcseconds per radian
+const ARCSEC_PER_RAD: f64 = 206264.80624709636;
+
+/// Applies a tangent-plane offset to a position.
+///
+/// # Arguments
+/// * `ra`, `dec` - Reference position in degrees
+/// * `dra_arcsec` - Offset towards the east, `ΔRA·cos(Dec)`, in arcseconds
+/// * `ddec_arcsec` - Offset towards the north, `ΔDec`, in arcseconds
+///
+/// # Returns
+/// The offset position `(ra, dec)` in degrees, RA in [0, 360)
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if `ra` or `dec` is out of range
+/// - `AstroError::OutOfRange` if either offset is not finite
+///
+/// # Example
+/// ```
+/// use astro_math::offsets::offset_position;
+///
+/// // 60″ east of a star at Dec +60° is 120″ (8 s) of RA away
+/// let (ra, dec) = offset_position(150.0, 60.0, 60.0, 0.0).unwrap();
+/// assert!(((ra - 150.0) * 3600.0 - 120.0).abs() < 0.01);
+/// assert!((dec - 60.0).abs() < 1e-5);
+///
+/// // Offsets carry straight across the pole
+/// let (ra, dec) = offset_position(0.0, 89.99, 0.0, 72.0).unwrap();
+/// assert!((ra - 180.0).abs() < 1e-9 && (dec - 89.99).abs() < 1e-6);
+/// ```
+pub fn offset_position(ra: f64, dec: f64, dra_arcsec: f64, ddec_arcsec: f64) -> Result<(f64, f64)> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    for (parameter, value) in [("dra_arcsec", dra_arcsec), ("ddec_arcsec", ddec_arcsec)] {
+        if !value.is_finite() {
+            return Err(AstroError::OutOfRange { parameter, value, min: f64::MIN, max: f64::MAX });
+        }
+    }
+
+    let (ra_rad, dec_rad) = crate::backend::gnomonic::Tpsts(
+        dra_arcsec / ARCSEC_PER_RAD,
+        ddec_arcsec / ARCSEC_PER_RAD,
+        ra.to_radians(),
+        dec.to_radians(),
+    );
+    Ok((ra_rad.to_degrees().rem_euclid(360.0), dec_rad.to_degrees()))
+}
+
+/// Computes the tangent-plane offset from one position to another.
+///
+/// # Arguments
+/// * `ra1`, `dec1` - Reference position (e.g. the offset star) in degrees
+/// * `ra2`, `dec2` - Target position in degrees
+///
+/// # Returns
+/// `(delta_ra_cosdec, delta_dec)` in arcseconds: the east and north offsets
+/// that take the reference position to the target
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if any coordinate is out of range
+/// - `AstroError::ProjectionError` if the positions are 90° or more apart
+///
+/// # Example
+/// ```
+/// use astro_math::offsets::offset_between;
+///
+/// // Across RA = 0° the offset is small, not ~360°
+/// let (east, north) = offset_between(359.99, 0.0, 0.01, 0.0).unwrap();
+/// assert!((east - 72.0).abs() < 1e-4 && north.abs() < 1e-9);
+/// ```
+pub fn offset_between(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> Result<(f64, f64)> {
+    validate_ra(ra1)?;
+    validate_dec(dec1)?;
+    validate_ra(ra2)?;
+    validate_dec(dec2)?;
+
+    let (xi, eta) = crate::backend::gnomonic::Tpxes(
+        ra2.to_radians(),
+        dec2.to_radians(),
+        ra1.to_radians(),
+        dec1.to_radians(),
+    )
+    .map_err(|_| AstroError::ProjectionError {
+        reason: "Positions are 90° or more apart".to_string(),
+    })?;
+    Ok((xi * ARCSEC_PER_RAD, eta * ARCSEC_PER_RAD))
+}
@@ -0,0 +1,364 @@
+//! Heliocentric orbital elements, state vectors and two-body propagation.
+//!
+//! This module provides the basic building blocks for minor-planet and comet
+//! orbit work: Keplerian elements as published by the MPC, Cartesian state
+//! vectors, and analytic two-body propagation.
+//!
+//! # Conventions
+//!
+//! - Orbital elements are referred to the **ecliptic and equinox of J2000.0**,
+//!   matching MPC and JPL small-body element sets
+//! - State vectors are heliocentric with **ICRS (equatorial J2000) axes**,
+//!   matching ERFA's planetary ephemerides
+//! - Positions in AU, velocities in AU/day, times as TDB Julian Dates
+//!
+//! Only bound (elliptical, `e < 1`) orbits are supported.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::orbit::OrbitalElements;
+//!
+//! // (1) Ceres, MPC elements at epoch 2460600.5 TDB
+//! let ceres = OrbitalElements {
+//!     epoch_jd_tdb: 2460600.5,
+//!     semi_major_axis_au: 2.7656,
+//!     eccentricity: 0.0796,
+//!     inclination_deg: 10.588,
+//!     ascending_node_deg: 80.25,
+//!     argument_of_perihelion_deg: 73.30,
+//!     mean_anomaly_deg: 145.84,
+//! };
+//!
+//! let state = ceres.state_at(2460700.5).unwrap();
+//! let r = state.distance_au();
+//! assert!(r > 2.5 && r < 3.0);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for invalid element values (non-positive
+//!   semi-major axis, unbound eccentricity)
+//! - `AstroError::CalculationError` when a state vector does not describe a
+//!   bound orbit
+
+use crate::error::{AstroError, Result};
+use crate::vec3::{cross, dot, norm};
+use std::f64::consts::TAU;
+
+/// Gaussian gravitational constant k (AU^(3/2) / day / solar mass^(1/2))
+pub const GAUSSIAN_GRAVITATIONAL_CONSTANT: f64 = 0.01720209895;
+
+/// Heliocentric gravitational parameter GM☉ = k² in AU³/day²
+pub const GM_SUN: f64 = GAUSSIAN_GRAVITATIONAL_CONSTANT * GAUSSIAN_GRAVITATIONAL_CONSTANT;
+
+/// Obliquity of the ecliptic at J2000.0 (IAU 2006) in radians
+const OBLIQUITY_J2000: f64 = 84381.406 / 3600.0 * std::f64::consts::PI / 180.0;
+
+/// Cartesian position and velocity at an instant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StateVector {
+    /// Epoch as a TDB Julian Date
+    pub jd_tdb: f64,
+    /// Position (x, y, z) in AU, ICRS axes
+    pub position: [f64; 3],
+    /// Velocity (vx, vy, vz) in AU/day, ICRS axes
+    pub velocity: [f64; 3],
+}
+
+impl StateVector {
+    /// Distance from the origin in AU.
+    pub fn distance_au(&self) -> f64 {
+        norm(self.position)
+    }
+
+    /// Speed in AU/day.
+    pub fn speed_au_per_day(&self) -> f64 {
+        norm(self.velocity)
+    }
+}
+
+/// Classical Keplerian elements of a heliocentric elliptical orbit.
+///
+/// Angles are referred to the ecliptic and equinox of J2000.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitalElements {
+    /// Epoch of osculation as a TDB Julian Date
+    pub epoch_jd_tdb: f64,
+    /// Semi-major axis a in AU
+    pub semi_major_axis_au: f64,
+    /// Eccentricity e (0 ≤ e < 1)
+    pub eccentricity: f64,
+    /// Inclination i in degrees
+    pub inclination_deg: f64,
+    /// Longitude of the ascending node Ω in degrees
+    pub ascending_node_deg: f64,
+    /// Argument of perihelion ω in degrees
+    pub argument_of_perihelion_deg: f64,
+    /// Mean anomaly M at epoch in degrees
+    pub mean_anomaly_deg: f64,
+}
+
+impl OrbitalElements {
+    /// Checks that the elements describe a bound orbit.
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if `a ≤ 0` or `e` is outside [0, 1).
+    pub fn validate(&self) -> Result<()> {
+        if !(self.semi_major_axis_au > 0.0 && self.semi_major_axis_au.is_finite()) {
+            return Err(AstroError::OutOfRange {
+                parameter: "semi_major_axis_au",
+                value: self.semi_major_axis_au,
+                min: f64::MIN_POSITIVE,
+                max: f64::MAX,
+            });
+        }
+        if !(0.0..1.0).contains(&self.eccentricity) {
+            return Err(AstroError::OutOfRange {
+                parameter: "eccentricity",
+                value: self.eccentricity,
+                min: 0.0,
+                max: 1.0,
+            });
+        }
+        Ok(())
+    }
+
+    /// Mean motion n in degrees per day.
+    pub fn mean_motion_deg_per_day(&self) -> f64 {
+        (GM_SUN / self.semi_major_axis_au.powi(3)).sqrt().to_degrees()
+    }
+
+    /// Orbital period in days.
+    pub fn period_days(&self) -> f64 {
+        360.0 / self.mean_motion_deg_per_day()
+    }
+
+    /// Perihelion distance q = a(1 − e) in AU.
+    pub fn perihelion_au(&self) -> f64 {
+        self.semi_major_axis_au * (1.0 - self.eccentricity)
+    }
+
+    /// Aphelion distance Q = a(1 + e) in AU.
+    pub fn aphelion_au(&self) -> f64 {
+        self.semi_major_axis_au * (1.0 + self.eccentricity)
+    }
+
+    /// Returns the heliocentric state vector at the element epoch.
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if the elements are invalid.
+    pub fn to_state_vector(&self) -> Result<StateVector> {
+        self.state_at(self.epoch_jd_tdb)
+    }
+
+    /// Propagates the elements to `jd_tdb` with two-body motion and returns
+    /// the heliocentric state vector.
+    ///
+    /// # Arguments
+    /// * `jd_tdb` - Target time as a TDB Julian Date
+    ///
+    /// # Returns
+    /// Heliocentric [`StateVector`] with ICRS axes
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if the elements are invalid.
+    pub fn state_at(&self, jd_tdb: f64) -> Result<StateVector> {
+        self.validate()?;
+
+        let a = self.semi_major_axis_au;
+        let e = self.eccentricity;
+        let n = (GM_SUN / a.powi(3)).sqrt();
+        let m = self.mean_anomaly_deg.to_radians() + n * (jd_tdb - self.epoch_jd_tdb);
+        let ea = solve_kepler(m, e);
+
+        // Perifocal position and velocity
+        let (sin_e, cos_e) = ea.sin_cos();
+        let b = a * (1.0 - e * e).sqrt();
+        let r = a * (1.0 - e * cos_e);
+        let p = [a * (cos_e - e), b * sin_e, 0.0];
+        let v = [-a * n * sin_e * a / r, b * n * cos_e * a / r, 0.0];
+
+        let rot = perifocal_to_icrs(
+            self.inclination_deg.to_radians(),
+            self.ascending_node_deg.to_radians(),
+            self.argument_of_perihelion_deg.to_radians(),
+        );
+
+        Ok(StateVector {
+            jd_tdb,
+            position: mat_vec(&rot, &p),
+            velocity: mat_vec(&rot, &v),
+        })
+    }
+
+    /// Heliocentric position (AU, ICRS axes) at a given eccentric anomaly.
+    ///
+    /// Traces the orbit geometrically without reference to time; the
+    /// elements are assumed valid.
+    pub fn position_at_eccentric_anomaly(&self, eccentric_anomaly_rad: f64) -> [f64; 3] {
+        let a = self.semi_major_axis_au;
+        let e = self.eccentricity;
+        let (sin_e, cos_e) = eccentric_anomaly_rad.sin_cos();
+        let p = [a * (cos_e - e), a * (1.0 - e * e).sqrt() * sin_e, 0.0];
+        let rot = perifocal_to_icrs(
+            self.inclination_deg.to_radians(),
+            self.ascending_node_deg.to_radians(),
+            self.argument_of_perihelion_deg.to_radians(),
+        );
+        mat_vec(&rot, &p)
+    }
+
+    /// Computes osculating elements from a heliocentric state vector.
+    ///
+    /// # Arguments
+    /// * `state` - Heliocentric state with ICRS axes
+    ///
+    /// # Returns
+    /// Elements referred to the ecliptic J2000.0 with epoch `state.jd_tdb`
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::CalculationError)` if the state does not
+    /// describe a bound orbit.
+    pub fn from_state_vector(state: &StateVector) -> Result<Self> {
+        let r = icrs_to_ecliptic(&state.position);
+        let v = icrs_to_ecliptic(&state.velocity);
+        let r_mag = norm(r);
+        let v2 = dot(v, v);
+
+        let energy = v2 / 2.0 - GM_SUN / r_mag;
+        if energy >= 0.0 || energy.is_nan() {
+            return Err(AstroError::CalculationError {
+                calculation: "orbital elements",
+                reason: "state vector does not describe a bound orbit".to_string(),
+            });
+        }
+        let a = -GM_SUN / (2.0 * energy);
+
+        let h = cross(r, v);
+        let h_mag = norm(h);
+        let rv = dot(r, v);
+        let e_vec = [
+            (v2 - GM_SUN / r_mag) * r[0] / GM_SUN - rv * v[0] / GM_SUN,
+            (v2 - GM_SUN / r_mag) * r[1] / GM_SUN - rv * v[1] / GM_SUN,
+            (v2 - GM_SUN / r_mag) * r[2] / GM_SUN - rv * v[2] / GM_SUN,
+        ];
+        let e = norm(e_vec);
+
+        let inc = (h[2] / h_mag).clamp(-1.0, 1.0).acos();
+        let node_vec = [-h[1], h[0], 0.0];
+        let node_mag = norm(node_vec);
+
+        // Equatorial (i = 0) orbits: put the node on the x axis
+        let node = if node_mag > 1e-14 { node_vec[1].atan2(node_vec[0]) } else { 0.0 };
+        let (sin_node, cos_node) = node.sin_cos();
+
+        // Argument of latitude of the position and of perihelion, measured in
+        // the orbital plane from the ascending node
+        let in_plane = |x: &[f64; 3]| {
+            let along_node = x[0] * cos_node + x[1] * sin_node;
+            let across = (-x[0] * sin_node + x[1] * cos_node) * inc.cos() + x[2] * inc.sin();
+            across.atan2(along_node)
+        };
+        let u = in_plane(&r);
+
+        // Circular orbits: measure from the node
+        let (peri, true_anomaly) = if e > 1e-12 {
+            let w = in_plane(&e_vec);
+            (w, u - w)
+        } else {
+            (0.0, u)
+        };
+
+        let ea = 2.0 * (((1.0 - e) / (1.0 + e)).sqrt() * (true_anomaly / 2.0).tan()).atan();
+        let m = ea - e * ea.sin();
+
+        Ok(OrbitalElements {
+            epoch_jd_tdb: state.jd_tdb,
+            semi_major_axis_au: a,
+            eccentricity: e,
+            inclination_deg: inc.to_degrees(),
+            ascending_node_deg: node.to_degrees().rem_euclid(360.0),
+            argument_of_perihelion_deg: peri.to_degrees().rem_euclid(360.0),
+            mean_anomaly_deg: m.to_degrees().rem_euclid(360.0),
+        })
+    }
+}
+
+/// Solves Kepler's equation M = E − e·sin(E) for the eccentric anomaly.
+///
+/// # Arguments
+/// * `mean_anomaly_rad` - Mean anomaly M in radians (any value)
+/// * `eccentricity` - Eccentricity (0 ≤ e < 1)
+///
+/// # Returns
+/// Eccentric anomaly E in radians, in the same revolution as M
+///
+/// # Example
+/// ```
+/// use astro_math::orbit::solve_kepler;
+///
+/// let e = 0.5;
+/// let ea = solve_kepler(1.0, e);
+/// assert!((ea - e * ea.sin() - 1.0).abs() < 1e-14);
+/// ```
+pub fn solve_kepler(mean_anomaly_rad: f64, eccentricity: f64) -> f64 {
+    let revolutions = (mean_anomaly_rad / TAU).floor();
+    let m = mean_anomaly_rad - revolutions * TAU;
+
+    let mut ea = if eccentricity > 0.8 { std::f64::consts::PI } else { m };
+    for _ in 0..50 {
+        let f = ea - eccentricity * ea.sin() - m;
+        let delta = f / (1.0 - eccentricity * ea.cos());
+        ea -= delta;
+        if delta.abs() < 1e-15 {
+            break;
+        }
+    }
+    ea + revolutions * TAU
+}
+
+/// Converts an eccentric anomaly to the true anomaly (both in radians).
+pub fn true_anomaly_from_eccentric(eccentric_anomaly_rad: f64, eccentricity: f64) -> f64 {
+    let (sin_e, cos_e) = eccentric_anomaly_rad.sin_cos();
+    ((1.0 - eccentricity * eccentricity).sqrt() * sin_e).atan2(cos_e - eccentricity)
+}
+
+/// Rotation matrix from the perifocal frame to ICRS axes.
+fn perifocal_to_icrs(inc: f64, node: f64, peri: f64) -> [[f64; 3]; 3] {
+    let (so, co) = node.sin_cos();
+    let (sw, cw) = peri.sin_cos();
+    let (si, ci) = inc.sin_cos();
+    let (se, ce) = OBLIQUITY_J2000.sin_cos();
+
+    // Perifocal → ecliptic J2000
+    let ecl = [
+        [co * cw - so * sw * ci, -co * sw - so * cw * ci, so * si],
+        [so * cw + co * sw * ci, -so * sw + co * cw * ci, -co * si],
+        [sw * si, cw * si, ci],
+    ];
+
+    // Ecliptic → equatorial: rotate about x by +ε
+    let mut m = [[0.0; 3]; 3];
+    for col in 0..3 {
+        m[0][col] = ecl[0][col];
+        m[1][col] = ce * ecl[1][col] - se * ecl[2][col];
+        m[2][col] = se * ecl[1][col] + ce * ecl[2][col];
+    }
+    m
+}
+
+/// Rotates an ICRS-axis vector into ecliptic J2000 axes.
+pub(crate) fn icrs_to_ecliptic(v: &[f64; 3]) -> [f64; 3] {
+    let (se, ce) = OBLIQUITY_J2000.sin_cos();
+    [v[0], ce * v[1] + se * v[2], -se * v[1] + ce * v[2]]
+}
+
+fn mat_vec(m: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
@@ -18,11 +18,12 @@
 //! - `AstroError::OutOfRange` for invalid distance values
 
 use crate::{Location, julian_date};
+use crate::earth::equation_of_center;
 use crate::error::{Result, validate_ra, validate_dec};
 use chrono::{DateTime, Utc};
 
 /// Earth's equatorial radius in kilometers
-const EARTH_RADIUS_KM: f64 = 6378.137;
+pub(crate) const EARTH_RADIUS_KM: f64 = 6378.137;
 
 /// Earth's flattening factor
 const EARTH_FLATTENING: f64 = 1.0 / 298.257223563;
@@ -46,7 +47,7 @@ pub fn geocentric_distance(location: &Location) -> f64 {
     
     // rho * cos(phi') and rho * sin(phi')
     let rho_cos_phi = u.cos() + (alt_km / EARTH_RADIUS_KM) * lat_rad.cos();
-    let rho_sin_phi = (1.0 - EARTH_FLATTENING).powi(2) * u.sin() + (alt_km / EARTH_RADIUS_KM) * lat_rad.sin();
+    let rho_sin_phi = (1.0 - EARTH_FLATTENING) * u.sin() + (alt_km / EARTH_RADIUS_KM) * lat_rad.sin();
     
     (rho_cos_phi.powi(2) + rho_sin_phi.powi(2)).sqrt()
 }
@@ -118,6 +119,31 @@ pub fn diurnal_parallax(
         });
     }
     let lst_hours = location.local_sidereal_time(datetime);
+    let (rho_sin, rho_cos) = geocentric_factors(location);
+    Ok(diurnal_parallax_from_lst(ra, dec, distance_au, lst_hours, rho_sin, rho_cos))
+}
+
+/// Observer's `(ρ·sin φ′, ρ·cos φ′)` in Earth radii, where φ′ is the
+/// geocentric latitude.
+pub(crate) fn geocentric_factors(location: &Location) -> (f64, f64) {
+    let lat_rad = location.latitude_deg.to_radians();
+    let u = ((1.0 - EARTH_FLATTENING) * lat_rad.tan()).atan();
+    let rho_cos = u.cos() + (location.altitude_m / 1000.0 / EARTH_RADIUS_KM) * lat_rad.cos();
+    let rho_sin = (1.0 - EARTH_FLATTENING) * u.sin() + 
+                  (location.altitude_m / 1000.0 / EARTH_RADIUS_KM) * lat_rad.sin();
+    (rho_sin, rho_cos)
+}
+
+/// Diurnal parallax from validated inputs, local apparent sidereal time in
+/// hours and the observer's [`geocentric_factors`].
+pub(crate) fn diurnal_parallax_from_lst(
+    ra: f64,
+    dec: f64,
+    distance_au: f64,
+    lst_hours: f64,
+    rho_sin: f64,
+    rho_cos: f64,
+) -> (f64, f64) {
     let lst_deg = lst_hours * 15.0;
     
     // Hour angle
@@ -125,16 +151,8 @@ pub fn diurnal_parallax(
     let ha_rad = ha.to_radians();
     let dec_rad = dec.to_radians();
     
-    // Observer's geocentric position
-    let lat_rad = location.latitude_deg.to_radians();
-    let u = ((1.0 - EARTH_FLATTENING) * lat_rad.tan()).atan();
-    let rho_cos = u.cos() + (location.altitude_m / 1000.0 / EARTH_RADIUS_KM) * lat_rad.cos();
-    let rho_sin = (1.0 - EARTH_FLATTENING).powi(2) * u.sin() + 
-                  (location.altitude_m / 1000.0 / EARTH_RADIUS_KM) * lat_rad.sin();
-    
-    // Parallax in arcseconds
-    let parallax_as = 8.794 / (distance_au * AU_KM / EARTH_RADIUS_KM);
-    let parallax_rad = (parallax_as / 3600.0).to_radians();
+    // Equatorial horizontal parallax
+    let parallax_rad = (EARTH_RADIUS_KM / (distance_au * AU_KM)).min(1.0).asin();
     
     // Calculate corrections
     let cos_dec = dec_rad.cos();
@@ -159,7 +177,7 @@ pub fn diurnal_parallax(
         ra_corrected
     };
     
-    Ok((ra_normalized, dec_corrected))
+    (ra_normalized, dec_corrected)
 }
 
 /// Calculates annual parallax for stars.
@@ -225,17 +243,8 @@ pub fn annual_parallax(
     // Mean longitude of the Sun
     let l = 280.46646 + 36000.76983 * t + 0.0003032 * t * t;
     
-    // Mean anomaly of the Sun
-    let m = 357.52911 + 35999.05029 * t - 0.0001537 * t * t;
-    let m_rad = m.to_radians();
-    
-    // Sun's equation of center
-    let c = (1.914602 - 0.004817 * t - 0.000014 * t * t) * m_rad.sin()
-          + (0.019993 - 0.000101 * t) * (2.0 * m_rad).sin()
-          + 0.000289 * (3.0 * m_rad).sin();
-    
-    // Sun's true longitude
-    let sun_lon = l + c;
+    // Sun's true longitude: the mean longitude plus the equation of center
+    let sun_lon = l + equation_of_center(jd);
     let sun_lon_rad = sun_lon.to_radians();
     
     // Parallax in radians
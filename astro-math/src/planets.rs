@@ -0,0 +1,142 @@
+//! Positions of the major planets for pointing and GoTo.
+//!
+//! [`planet_position`] gives the geocentric apparent place of Mercury
+//! through Neptune from ERFA's `Plan94` ephemeris, the same as
+//! [`almanac::apparent_place`](crate::almanac::apparent_place): light time,
+//! annual aberration, precession and nutation are applied, and the position
+//! is referred to the true equator and equinox of date, ready for
+//! [`ra_dec_to_alt_az`](crate::transforms::ra_dec_to_alt_az).
+//! [`topocentric_planet_position`] adds the diurnal parallax for the
+//! observer with [`diurnal_parallax`], up to half an arcminute for Venus
+//! or Mars at their closest.
+//!
+//! `Plan94` is good to a few arcseconds for the inner planets and about
+//! ten for Neptune between the years 1000 and 3000, well inside a GoTo
+//! mount's pointing error.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::planets::{topocentric_planet_position, Planet};
+//! use astro_math::{ra_dec_to_alt_az, Location};
+//! use chrono::{TimeZone, Utc};
+//!
+//! let site = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+//! let dt = Utc.with_ymd_and_hms(2024, 12, 7, 0, 0, 0).unwrap();
+//!
+//! // Jupiter at opposition, high in the south at midnight
+//! let jupiter = topocentric_planet_position(Planet::Jupiter, dt, &site).unwrap();
+//! let (alt, az) = ra_dec_to_alt_az(jupiter.ra, jupiter.dec, dt, &site).unwrap();
+//! assert!(alt > 55.0 && (az - 180.0).abs() < 10.0);
+//! assert!((jupiter.distance_au - 4.08).abs() < 0.01);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range latitude or longitude
+//! - `AstroError::CalculationError` for [`Planet::EarthMoon`], which is not
+//!   a target, or if the planetary ephemeris rejects the date
+
+use crate::almanac::{apparent_place, AlmanacBody, ApparentPlace};
+use crate::error::{validate_latitude, validate_longitude, AstroError, Result};
+use crate::location::Location;
+use crate::parallax::diurnal_parallax;
+use chrono::{DateTime, Utc};
+
+pub use crate::propagator::Planet;
+
+/// Computes the geocentric apparent place of a planet.
+///
+/// # Arguments
+/// * `planet` - Mercury through Neptune
+/// * `datetime` - Time of interest
+///
+/// # Returns
+/// RA/Dec in degrees (true equator and equinox of date), geocentric
+/// distance in AU and visual magnitude
+///
+/// # Errors
+/// `AstroError::CalculationError` for [`Planet::EarthMoon`], or if the
+/// planetary ephemeris rejects the date
+///
+/// # Example
+/// ```
+/// use astro_math::planets::{planet_position, Planet};
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2025, 1, 16, 0, 0, 0).unwrap();
+/// // Mars at opposition, bright and near
+/// let mars = planet_position(Planet::Mars, dt).unwrap();
+/// assert!(mars.distance_au < 0.65);
+/// assert!(mars.magnitude < -1.2);
+/// ```
+pub fn planet_position(planet: Planet, datetime: DateTime<Utc>) -> Result<ApparentPlace> {
+    apparent_place(almanac_body(planet)?, datetime)
+}
+
+/// Computes the apparent place of a planet as seen from a site on the
+/// Earth.
+///
+/// [`planet_position`] corrected for diurnal parallax with
+/// [`diurnal_parallax`]. The distance and magnitude are left geocentric;
+/// they differ from the topocentric values by less than one part in 10⁴.
+///
+/// # Arguments
+/// * `planet` - Mercury through Neptune
+/// * `datetime` - Time of observation
+/// * `location` - Observer's location
+///
+/// # Returns
+/// Topocentric RA/Dec in degrees (true equator and equinox of date),
+/// geocentric distance in AU and visual magnitude
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - `AstroError::CalculationError` for [`Planet::EarthMoon`], or if the
+///   planetary ephemeris rejects the date
+///
+/// # Example
+/// ```
+/// use astro_math::planets::{planet_position, topocentric_planet_position, Planet};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: -30.2, longitude_deg: -70.7, altitude_m: 2200.0 };
+/// let dt = Utc.with_ymd_and_hms(2026, 10, 24, 3, 0, 0).unwrap();
+/// let geocentric = planet_position(Planet::Venus, dt).unwrap();
+/// let topocentric = topocentric_planet_position(Planet::Venus, dt, &site).unwrap();
+///
+/// // Venus near inferior conjunction: parallax of tens of arcseconds
+/// let shift_arcsec = (topocentric.dec - geocentric.dec).abs() * 3600.0;
+/// assert!(shift_arcsec > 5.0 && shift_arcsec < 35.0);
+/// ```
+pub fn topocentric_planet_position(
+    planet: Planet,
+    datetime: DateTime<Utc>,
+    location: &Location,
+) -> Result<ApparentPlace> {
+    validate_latitude(location.latitude_deg)?;
+    validate_longitude(location.longitude_deg)?;
+    let place = planet_position(planet, datetime)?;
+    let (ra, dec) = diurnal_parallax(place.ra, place.dec, place.distance_au, datetime, location)?;
+    Ok(ApparentPlace { ra, dec, ..place })
+}
+
+fn almanac_body(planet: Planet) -> Result<AlmanacBody> {
+    Ok(match planet {
+        Planet::Mercury => AlmanacBody::Mercury,
+        Planet::Venus => AlmanacBody::Venus,
+        Planet::Mars => AlmanacBody::Mars,
+        Planet::Jupiter => AlmanacBody::Jupiter,
+        Planet::Saturn => AlmanacBody::Saturn,
+        Planet::Uranus => AlmanacBody::Uranus,
+        Planet::Neptune => AlmanacBody::Neptune,
+        Planet::EarthMoon => {
+            return Err(AstroError::CalculationError {
+                calculation: "planet position",
+                reason: "the Earth–Moon barycenter is the observer's own position".to_string(),
+            })
+        }
+    })
+}
@@ -0,0 +1,353 @@
+//! Blind plate-solve seeding by geometric hashing of star quads.
+//!
+//! [`match_quads`] finds where a frame points without any starting guess
+//! beyond the catalogue region, the way astrometry.net does: every four
+//! stars, in the image and in the catalogue, are reduced to a code that
+//! does not change with position, rotation or scale, and a frame quad whose
+//! code matches a catalogue quad gives a candidate [`TangentPlane`]. The
+//! candidates are checked against the rest of the stars, so the best one
+//! can seed a full fit.
+//!
+//! For each quad the two stars furthest apart, A and B, are mapped to (0, 0)
+//! and (1, 1); the positions of the other two, C and D, in that frame are the
+//! code. Quads with C or D outside the circle through A and B are dropped,
+//! and A/B and C/D are ordered so each quad has one code.
+//!
+//! The catalogue should cover about the frame, e.g. a
+//! [`StarCatalog::query_cone`](crate::catalog::StarCatalog::query_cone) a
+//! little wider than the field around a rough position; for a truly blind
+//! solve, run it over a grid of such cones. Only the brightest stars of each
+//! list are hashed, so put both lists brightest first.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::plate_solve::match_quads;
+//! use astro_math::projection::TangentPlane;
+//!
+//! // A field of stars, and a frame of it at 4″/px rotated by 20°
+//! let catalog: Vec<(f64, f64)> = (0..12)
+//!     .map(|i| {
+//!         let i = i as f64;
+//!         (150.0 + 0.3 * (i * 2.4).sin(), 30.0 + 0.3 * (i * 1.7).cos())
+//!     })
+//!     .collect();
+//! let truth = TangentPlane::new(150.0, 30.0, 4.0).unwrap()
+//!     .with_reference_pixel(500.0, 500.0)
+//!     .with_rotation(20.0);
+//! let detections: Vec<(f64, f64)> = catalog.iter().rev()
+//!     .map(|&(ra, dec)| truth.ra_dec_to_pixel(ra, dec).unwrap())
+//!     .collect();
+//!
+//! let candidates = match_quads(&detections, &catalog, 1.0..=10.0).unwrap();
+//! let best = &candidates[0];
+//! assert_eq!(best.matched, 12);
+//! assert!((best.plane.scale - 4.0).abs() < 1e-6);
+//!
+//! // Same sky at every pixel, though the reference pixel differs
+//! let (ra, dec) = best.plane.pixel_to_ra_dec(0.0, 0.0).unwrap();
+//! let (ra_true, dec_true) = truth.pixel_to_ra_dec(0.0, 0.0).unwrap();
+//! assert!((ra - ra_true).abs() < 1e-6 && (dec - dec_true).abs() < 1e-6);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for a catalogue position out of range
+//! - `AstroError::OutOfRange` for an empty or non-positive scale range
+//! - `AstroError::CalculationError` for fewer than four stars in either list
+//!   or a pixel position that is not finite
+//! - `AstroError::ProjectionError` for a catalogue spanning more than a
+//!   hemisphere
+
+use crate::angles::separation;
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::projection::TangentPlane;
+use crate::vec3::{normalize, radec_to_unit_vector, unit_vector_to_radec};
+use std::ops::RangeInclusive;
+
+/// Detections used to build quads; C(20, 4) = 4845 quads
+const MAX_DETECTIONS: usize = 20;
+
+/// Catalogue stars used to build quads; C(30, 4) = 27405 quads
+const MAX_CATALOG: usize = 30;
+
+/// Largest distance between matching codes, in units of the A–B frame
+const CODE_TOLERANCE: f64 = 0.01;
+
+/// Distance in pixels within which a detection matches a catalogue star
+const MATCH_RADIUS_PX: f64 = 3.0;
+
+/// Matched stars needed for a candidate: the quad and at least one more
+const MIN_MATCHED: usize = 5;
+
+/// A plate solution proposed by a quad match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolveCandidate {
+    /// The solution, with its reference pixel at the centroid of the
+    /// detections
+    pub plane: TangentPlane,
+    /// Detections within 3 pixels of a catalogue star under `plane`,
+    /// including the four of the quad
+    pub matched: usize,
+    /// Distance between the frame and catalogue quad codes
+    pub code_distance: f64,
+}
+
+/// Four stars, ordered A, B, C, D, and their code.
+#[derive(Debug, Clone, Copy)]
+struct Quad {
+    stars: [usize; 4],
+    code: [f64; 4],
+    /// Distance from A to B, in the units of the points
+    diameter: f64,
+}
+
+/// Position of `p` in the frame with `a` at (0, 0) and `b` at (1, 1).
+fn local(a: (f64, f64), b: (f64, f64), p: (f64, f64)) -> (f64, f64) {
+    let (bx, by) = (b.0 - a.0, b.1 - a.1);
+    let (px, py) = (p.0 - a.0, p.1 - a.1);
+    let norm = bx * bx + by * by;
+    let (u, v) = ((px * bx + py * by) / norm, (py * bx - px * by) / norm);
+    (u - v, u + v)
+}
+
+/// Orders four stars and computes their code, or `None` if C or D lies
+/// outside the circle on A–B or the stars coincide.
+fn quad(points: &[(f64, f64)], stars: [usize; 4]) -> Option<Quad> {
+    let mut widest = (0, 1, 0.0);
+    for i in 0..4 {
+        for j in i + 1..4 {
+            let (p, q) = (points[stars[i]], points[stars[j]]);
+            let distance = (p.0 - q.0).hypot(p.1 - q.1);
+            if distance > widest.2 {
+                widest = (i, j, distance);
+            }
+        }
+    }
+    let (i, j, diameter) = widest;
+    if diameter <= 0.0 {
+        return None;
+    }
+    let mut rest = (0..4).filter(|&k| k != i && k != j).map(|k| stars[k]);
+    let (mut a, mut b) = (stars[i], stars[j]);
+    let (mut c, mut d) = (rest.next()?, rest.next()?);
+
+    let mut code_c = local(points[a], points[b], points[c]);
+    let mut code_d = local(points[a], points[b], points[d]);
+    let inside = |(x, y): (f64, f64)| (x - 0.5).powi(2) + (y - 0.5).powi(2) <= 0.5;
+    if !inside(code_c) || !inside(code_d) {
+        return None;
+    }
+    if code_c.0 + code_d.0 > 1.0 {
+        std::mem::swap(&mut a, &mut b);
+        code_c = (1.0 - code_c.0, 1.0 - code_c.1);
+        code_d = (1.0 - code_d.0, 1.0 - code_d.1);
+    }
+    if code_c.0 > code_d.0 {
+        std::mem::swap(&mut c, &mut d);
+        std::mem::swap(&mut code_c, &mut code_d);
+    }
+    Some(Quad { stars: [a, b, c, d], code: [code_c.0, code_c.1, code_d.0, code_d.1], diameter })
+}
+
+/// All valid quads of the first `limit` points.
+fn quads(points: &[(f64, f64)], limit: usize) -> Vec<Quad> {
+    let n = points.len().min(limit);
+    let mut found = Vec::new();
+    for i in 0..n {
+        for j in i + 1..n {
+            for k in j + 1..n {
+                for l in k + 1..n {
+                    found.extend(quad(points, [i, j, k, l]));
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Least-squares similarity transform `to = a·from + b`, in complex numbers
+/// `(re, im)`.
+fn similarity(from: &[(f64, f64)], to: &[(f64, f64)]) -> ((f64, f64), (f64, f64)) {
+    let n = from.len() as f64;
+    let mean = |points: &[(f64, f64)]| {
+        let (x, y) = points.iter().fold((0.0, 0.0), |sum, p| (sum.0 + p.0, sum.1 + p.1));
+        (x / n, y / n)
+    };
+    let (mf, mt) = (mean(from), mean(to));
+    let (mut re, mut im, mut norm) = (0.0, 0.0, 0.0);
+    for (f, t) in from.iter().zip(to) {
+        let (fx, fy) = (f.0 - mf.0, f.1 - mf.1);
+        let (tx, ty) = (t.0 - mt.0, t.1 - mt.1);
+        // (t − mt)·conj(f − mf)
+        re += tx * fx + ty * fy;
+        im += ty * fx - tx * fy;
+        norm += fx * fx + fy * fy;
+    }
+    let a = (re / norm, im / norm);
+    let b = (mt.0 - (a.0 * mf.0 - a.1 * mf.1), mt.1 - (a.0 * mf.1 + a.1 * mf.0));
+    (a, b)
+}
+
+/// Fits the tangent plane mapping four detections onto four catalogue stars.
+fn fit(pixels: &[(f64, f64)], stars: &[(f64, f64)], reference: (f64, f64)) -> Result<TangentPlane> {
+    // Planes at one "pixel" per degree have the frame's parity, x to the west
+    let (mut ra0, mut dec0) = centre(stars);
+    let mut a = (0.0, 0.0);
+    for _ in 0..2 {
+        let plane = TangentPlane::new(ra0, dec0, 3600.0)?;
+        let projected = stars.iter().map(|&(ra, dec)| plane.ra_dec_to_pixel(ra, dec)).collect::<Result<Vec<_>>>()?;
+        let b;
+        (a, b) = similarity(pixels, &projected);
+        let x = a.0 * reference.0 - a.1 * reference.1 + b.0;
+        let y = a.0 * reference.1 + a.1 * reference.0 + b.1;
+        (ra0, dec0) = plane.pixel_to_ra_dec(x, y)?;
+    }
+    // Plane offset = scale·e^(−i·rotation)·pixel offset
+    let scale = a.0.hypot(a.1) * 3600.0;
+    let rotation = -a.1.atan2(a.0).to_degrees();
+    Ok(TangentPlane::new(ra0, dec0, scale)?.with_reference_pixel(reference.0, reference.1).with_rotation(rotation))
+}
+
+/// Direction of the mean of the unit vectors of the stars.
+fn centre(stars: &[(f64, f64)]) -> (f64, f64) {
+    let sum = stars.iter().map(|&(ra, dec)| radec_to_unit_vector(ra, dec)).fold([0.0; 3], |sum, v| {
+        [sum[0] + v[0], sum[1] + v[1], sum[2] + v[2]]
+    });
+    unit_vector_to_radec(normalize(sum))
+}
+
+/// Detections within [`MATCH_RADIUS_PX`] of a catalogue star under `plane`.
+fn count_matches(plane: &TangentPlane, detections: &[(f64, f64)], catalog: &[(f64, f64)]) -> usize {
+    let projected: Vec<(f64, f64)> =
+        catalog.iter().filter_map(|&(ra, dec)| plane.ra_dec_to_pixel(ra, dec).ok()).collect();
+    detections
+        .iter()
+        .filter(|d| projected.iter().any(|p| (p.0 - d.0).hypot(p.1 - d.1) <= MATCH_RADIUS_PX))
+        .count()
+}
+
+/// Proposes plate solutions for a frame by matching star quads against a
+/// catalogue.
+///
+/// Quads are built from the first 20 detections and first 30 catalogue
+/// stars. A frame quad matches a catalogue quad when their codes agree to
+/// 0.01 and the pixel scale they imply is in `scale_range`; each match is
+/// fitted and kept if at least one star beyond the quad falls within 3
+/// pixels of a catalogue star. Near-identical solutions from different
+/// quads are merged.
+///
+/// The frame must have sky parity, east to the left with north up, as
+/// [`TangentPlane`] assumes; negate the x coordinates of a mirrored frame
+/// first.
+///
+/// # Arguments
+/// * `detected_xy` - Star positions in pixels, brightest first, in no
+///   correspondence with the catalogue
+/// * `catalog_radec` - Catalogue RA and Dec in degrees, brightest first
+/// * `scale_range` - Plausible pixel scales in arcseconds per pixel
+///
+/// # Returns
+/// Candidates with the most matched stars first, then the closest codes;
+/// empty if no quad matched. The reference pixel of each plane is the
+/// centroid of the detections.
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if a catalogue position is out of
+///   range
+/// - `AstroError::OutOfRange` if the scale range is empty or not positive
+/// - `AstroError::CalculationError` if either list has fewer than four
+///   stars, or a detection is not finite
+/// - `AstroError::ProjectionError` if the catalogue spans more than a
+///   hemisphere
+pub fn match_quads(
+    detected_xy: &[(f64, f64)],
+    catalog_radec: &[(f64, f64)],
+    scale_range: RangeInclusive<f64>,
+) -> Result<Vec<SolveCandidate>> {
+    let (min_scale, max_scale) = (*scale_range.start(), *scale_range.end());
+    if !(min_scale > 0.0 && max_scale >= min_scale && max_scale.is_finite()) {
+        return Err(AstroError::OutOfRange {
+            parameter: "scale_range",
+            value: if min_scale > 0.0 { max_scale } else { min_scale },
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+    if detected_xy.len() < 4 || catalog_radec.len() < 4 {
+        return Err(AstroError::CalculationError {
+            calculation: "quad matching",
+            reason: format!(
+                "need at least four stars, got {} detections and {} catalogue stars",
+                detected_xy.len(),
+                catalog_radec.len()
+            ),
+        });
+    }
+    if detected_xy.iter().any(|p| !p.0.is_finite() || !p.1.is_finite()) {
+        return Err(AstroError::CalculationError {
+            calculation: "quad matching",
+            reason: "detection position is not finite".to_string(),
+        });
+    }
+    for &(ra, dec) in catalog_radec {
+        validate_ra(ra)?;
+        validate_dec(dec)?;
+    }
+
+    // Catalogue quads on a plane at one unit per degree, sorted by the
+    // first code component for a windowed search
+    let (ra_c, dec_c) = centre(catalog_radec);
+    let catalog_plane = TangentPlane::new(ra_c, dec_c, 3600.0)?;
+    let projected = catalog_radec
+        .iter()
+        .map(|&(ra, dec)| catalog_plane.ra_dec_to_pixel(ra, dec))
+        .collect::<Result<Vec<_>>>()?;
+    let mut catalog_quads = quads(&projected, MAX_CATALOG);
+    catalog_quads.sort_by(|a, b| a.code[0].total_cmp(&b.code[0]));
+
+    let n = detected_xy.len() as f64;
+    let (sx, sy) = detected_xy.iter().fold((0.0, 0.0), |sum, p| (sum.0 + p.0, sum.1 + p.1));
+    let reference = (sx / n, sy / n);
+
+    let mut candidates: Vec<SolveCandidate> = Vec::new();
+    for frame_quad in quads(detected_xy, MAX_DETECTIONS) {
+        let start = catalog_quads.partition_point(|q| q.code[0] < frame_quad.code[0] - CODE_TOLERANCE);
+        for catalog_quad in &catalog_quads[start..] {
+            if catalog_quad.code[0] > frame_quad.code[0] + CODE_TOLERANCE {
+                break;
+            }
+            let code_distance =
+                frame_quad.code.iter().zip(&catalog_quad.code).map(|(f, c)| (f - c).powi(2)).sum::<f64>().sqrt();
+            let scale = catalog_quad.diameter * 3600.0 / frame_quad.diameter;
+            if code_distance > CODE_TOLERANCE || !scale_range.contains(&scale) {
+                continue;
+            }
+
+            let pixels = frame_quad.stars.map(|i| detected_xy[i]);
+            let stars = catalog_quad.stars.map(|i| catalog_radec[i]);
+            let Ok(plane) = fit(&pixels, &stars, reference) else {
+                continue;
+            };
+            let duplicate = candidates.iter_mut().find(|other| {
+                let offset = separation(plane.ra0, plane.dec0, other.plane.ra0, other.plane.dec0) * 3600.0;
+                let turn = (plane.rotation - other.plane.rotation + 540.0).rem_euclid(360.0) - 180.0;
+                offset <= MATCH_RADIUS_PX * plane.scale
+                    && turn.abs() < 1.0
+                    && (plane.scale / other.plane.scale - 1.0).abs() < 0.01
+            });
+            match duplicate {
+                Some(other) => other.code_distance = other.code_distance.min(code_distance),
+                None => {
+                    let matched = count_matches(&plane, detected_xy, catalog_radec);
+                    if matched >= MIN_MATCHED {
+                        candidates.push(SolveCandidate { plane, matched, code_distance });
+                    }
+                }
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| b.matched.cmp(&a.matched).then(a.code_distance.total_cmp(&b.code_distance)));
+    Ok(candidates)
+}
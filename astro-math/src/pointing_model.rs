@@ -0,0 +1,432 @@
+//! Telescope pointing models and mount model file interchange.
+//!
+//! A pointing model describes the systematic differences between where a
+//! telescope is commanded to point and where it actually points. This module
+//! implements the classic TPoint geometric terms for equatorial mounts and
+//! reads/writes the model formats produced by common commercial tools, so
+//! calibrations can be carried over into software built on this crate.
+//!
+//! # Supported Terms
+//!
+//! | Term  | Meaning                                        |
+//! |-------|------------------------------------------------|
+//! | `IH`  | Index error in hour angle                      |
+//! | `ID`  | Index error in declination                     |
+//! | `CH`  | East-west collimation error                    |
+//! | `NP`  | Non-perpendicularity of HA and Dec axes        |
+//! | `MA`  | Polar axis misalignment in azimuth             |
+//! | `ME`  | Polar axis misalignment in elevation           |
+//! | `TF`  | Tube flexure (Hooke's law)                     |
+//! | `FO`  | Fork flexure                                   |
+//! | `DAF` | Declination axis flexure                       |
+//!
+//! All coefficients are in arcseconds and follow TPoint sign conventions:
+//! the correction is *added* to the ideal (catalog) position to obtain the
+//! position the mount must be commanded to.
+//!
+//! # Supported Formats
+//!
+//! - **TPoint** model files as written by `OUTMOD` (a caption line, one line per
+//!   term with name, value and optional sigma, terminated by `END`)
+//! - **10Micron** alignment info strings as returned by the `:getain#` command
+//!
+//! # Error Handling
+//!
+//! Parsing returns `AstroError::InvalidFormat` with the offending line.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::pointing_model::{PointingModel, PointingTerm};
+//!
+//! let model = PointingModel::new()
+//!     .with_term(PointingTerm::IH, -120.0)
+//!     .with_term(PointingTerm::ID, 35.0);
+//!
+//! let (ha, dec) = model.apply(15.0, 30.0, 40.0);
+//! assert!((ha - (15.0 + 120.0 / 3600.0)).abs() < 1e-9);
+//! assert!((dec - (30.0 - 35.0 / 3600.0)).abs() < 1e-9);
+//! ```
+
+use crate::error::{AstroError, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// A single geometric term of a pointing model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointingTerm {
+    /// Index error in hour angle
+    IH,
+    /// Index error in declination
+    ID,
+    /// East-west collimation error
+    CH,
+    /// Non-perpendicularity of HA and Dec axes
+    NP,
+    /// Polar axis misalignment in azimuth (positive = axis east of pole)
+    MA,
+    /// Polar axis misalignment in elevation (positive = axis above pole)
+    ME,
+    /// Tube flexure
+    TF,
+    /// Fork flexure
+    FO,
+    /// Declination axis flexure
+    DAF,
+}
+
+impl PointingTerm {
+    /// All supported terms in canonical TPoint order.
+    pub const ALL: [PointingTerm; 9] = [
+        PointingTerm::IH,
+        PointingTerm::ID,
+        PointingTerm::CH,
+        PointingTerm::NP,
+        PointingTerm::MA,
+        PointingTerm::ME,
+        PointingTerm::TF,
+        PointingTerm::FO,
+        PointingTerm::DAF,
+    ];
+
+    /// The TPoint mnemonic for this term.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PointingTerm::IH => "IH",
+            PointingTerm::ID => "ID",
+            PointingTerm::CH => "CH",
+            PointingTerm::NP => "NP",
+            PointingTerm::MA => "MA",
+            PointingTerm::ME => "ME",
+            PointingTerm::TF => "TF",
+            PointingTerm::FO => "FO",
+            PointingTerm::DAF => "DAF",
+        }
+    }
+
+    /// Correction `(ΔH, Δδ)` in arcseconds for a unit (1") coefficient.
+    ///
+    /// All angles are in radians.
+    fn unit_correction(&self, ha: f64, dec: f64, lat: f64) -> (f64, f64) {
+        let (sin_h, cos_h) = ha.sin_cos();
+        let (sin_d, cos_d) = dec.sin_cos();
+        let (sin_p, cos_p) = lat.sin_cos();
+        let tan_d = sin_d / cos_d;
+        match self {
+            PointingTerm::IH => (-1.0, 0.0),
+            PointingTerm::ID => (0.0, -1.0),
+            PointingTerm::CH => (-1.0 / cos_d, 0.0),
+            PointingTerm::NP => (-tan_d, 0.0),
+            PointingTerm::MA => (-cos_h * tan_d, sin_h),
+            PointingTerm::ME => (sin_h * tan_d, cos_h),
+            PointingTerm::TF => (
+                cos_p * sin_h / cos_d,
+                cos_p * cos_h * sin_d - sin_p * cos_d,
+            ),
+            PointingTerm::FO => (0.0, cos_h),
+            PointingTerm::DAF => (-(cos_p * cos_h + sin_p * tan_d), 0.0),
+        }
+    }
+}
+
+impl fmt::Display for PointingTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for PointingTerm {
+    type Err = AstroError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let upper = s.trim().to_ascii_uppercase();
+        PointingTerm::ALL
+            .iter()
+            .copied()
+            .find(|t| t.name() == upper)
+            .ok_or_else(|| AstroError::InvalidFormat {
+                format: "pointing term",
+                input: s.to_string(),
+                reason: "unknown term name".to_string(),
+            })
+    }
+}
+
+/// A pointing model made of TPoint-style geometric terms.
+///
+/// Coefficients are stored in arcseconds. Terms not present in the model are
+/// treated as zero.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointingModel {
+    /// Caption or description of the model (first line of a TPoint file)
+    pub caption: String,
+    /// Model terms and their coefficients in arcseconds
+    pub terms: Vec<(PointingTerm, f64)>,
+    /// Sky RMS of the fit in arcseconds, if known
+    pub rms_arcsec: Option<f64>,
+}
+
+impl PointingModel {
+    /// Create an empty pointing model.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a term coefficient (arcseconds), replacing any existing value.
+    pub fn with_term(mut self, term: PointingTerm, value_arcsec: f64) -> Self {
+        self.set_term(term, value_arcsec);
+        self
+    }
+
+    /// Set a term coefficient (arcseconds), replacing any existing value.
+    pub fn set_term(&mut self, term: PointingTerm, value_arcsec: f64) {
+        match self.terms.iter_mut().find(|(t, _)| *t == term) {
+            Some(entry) => entry.1 = value_arcsec,
+            None => self.terms.push((term, value_arcsec)),
+        }
+    }
+
+    /// Coefficient of a term in arcseconds (zero if not present).
+    pub fn term(&self, term: PointingTerm) -> f64 {
+        self.terms
+            .iter()
+            .find(|(t, _)| *t == term)
+            .map(|&(_, v)| v)
+            .unwrap_or(0.0)
+    }
+
+    /// Computes the pointing correction at a given position.
+    ///
+    /// # Arguments
+    /// * `ha_deg` - Hour angle in degrees
+    /// * `dec_deg` - Declination in degrees
+    /// * `latitude_deg` - Observer latitude in degrees
+    ///
+    /// # Returns
+    /// `(ΔH, Δδ)` in degrees, to be added to the ideal position
+    pub fn correction(&self, ha_deg: f64, dec_deg: f64, latitude_deg: f64) -> (f64, f64) {
+        let ha = ha_deg.to_radians();
+        let dec = dec_deg.to_radians();
+        let lat = latitude_deg.to_radians();
+
+        let (dh, dd) = self.terms.iter().fold((0.0, 0.0), |(dh, dd), (term, value)| {
+            let (uh, ud) = term.unit_correction(ha, dec, lat);
+            (dh + uh * value, dd + ud * value)
+        });
+
+        (dh / 3600.0, dd / 3600.0)
+    }
+
+    /// Converts an ideal (catalog) position to the commanded mount position.
+    ///
+    /// # Arguments
+    /// * `ha_deg` - Ideal hour angle in degrees
+    /// * `dec_deg` - Ideal declination in degrees
+    /// * `latitude_deg` - Observer latitude in degrees
+    ///
+    /// # Returns
+    /// `(ha_deg, dec_deg)` the mount should be driven to
+    pub fn apply(&self, ha_deg: f64, dec_deg: f64, latitude_deg: f64) -> (f64, f64) {
+        let (dh, dd) = self.correction(ha_deg, dec_deg, latitude_deg);
+        (ha_deg + dh, dec_deg + dd)
+    }
+
+    /// Converts a mount (encoder) position back to the ideal sky position.
+    ///
+    /// This is the inverse of [`apply`](Self::apply), solved by fixed-point
+    /// iteration. Converges to well below a milliarcsecond for realistic models.
+    ///
+    /// # Arguments
+    /// * `ha_deg` - Mount hour angle in degrees
+    /// * `dec_deg` - Mount declination in degrees
+    /// * `latitude_deg` - Observer latitude in degrees
+    pub fn remove(&self, ha_deg: f64, dec_deg: f64, latitude_deg: f64) -> (f64, f64) {
+        let (mut ha, mut dec) = (ha_deg, dec_deg);
+        for _ in 0..10 {
+            let (dh, dd) = self.correction(ha, dec, latitude_deg);
+            ha = ha_deg - dh;
+            dec = dec_deg - dd;
+        }
+        (ha, dec)
+    }
+
+    /// Parses a TPoint model file (as written by `OUTMOD`).
+    ///
+    /// The first non-blank line is taken as the caption. Each subsequent line
+    /// holds a term name, its value in arcseconds and optionally a sigma.
+    /// TPoint also writes a fit-status line (e.g. `T  0  ...`) after the caption
+    /// and an optional `RMS` line; both are accepted. Parsing stops at `END`.
+    /// Terms not supported by this crate are rejected rather than silently
+    /// dropped.
+    ///
+    /// # Errors
+    /// Returns `AstroError::InvalidFormat` for malformed lines or unknown terms.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::pointing_model::{PointingModel, PointingTerm};
+    ///
+    /// let text = "\
+    /// Observatory model 2024-08
+    /// T  0  21.40  0.0000  39.0005
+    ///      IH       -123.45      2.10
+    ///      ID         45.60      1.30
+    ///      NP         12.00
+    /// END
+    /// ";
+    /// let model = PointingModel::from_tpoint_str(text).unwrap();
+    /// assert_eq!(model.caption, "Observatory model 2024-08");
+    /// assert_eq!(model.term(PointingTerm::IH), -123.45);
+    /// assert_eq!(model.term(PointingTerm::NP), 12.0);
+    /// ```
+    pub fn from_tpoint_str(text: &str) -> Result<Self> {
+        let mut model = PointingModel::new();
+        let mut lines = text.lines().map(str::trim).filter(|l| !l.is_empty());
+
+        model.caption = lines.next().unwrap_or_default().to_string();
+
+        for line in lines {
+            let mut fields = line.split_whitespace();
+            let name = fields.next().unwrap_or_default();
+
+            if name.eq_ignore_ascii_case("END") {
+                break;
+            }
+            // Fit status line written after the caption
+            if name == "T" || name == "S" {
+                continue;
+            }
+
+            let value = fields
+                .next()
+                .and_then(|v| v.parse::<f64>().ok())
+                .ok_or_else(|| AstroError::InvalidFormat {
+                    format: "TPoint model",
+                    input: line.to_string(),
+                    reason: "expected a term name followed by a numeric value".to_string(),
+                })?;
+
+            if name.eq_ignore_ascii_case("RMS") {
+                model.rms_arcsec = Some(value);
+                continue;
+            }
+
+            let term = name.parse::<PointingTerm>().map_err(|_| AstroError::InvalidFormat {
+                format: "TPoint model",
+                input: line.to_string(),
+                reason: format!("unsupported term '{}'", name),
+            })?;
+            model.set_term(term, value);
+        }
+
+        Ok(model)
+    }
+
+    /// Writes the model in TPoint `OUTMOD` format.
+    ///
+    /// The output can be read back by [`from_tpoint_str`](Self::from_tpoint_str)
+    /// and loaded into TPoint with `INMOD`.
+    pub fn to_tpoint_string(&self) -> String {
+        let caption = if self.caption.is_empty() {
+            "astro-math pointing model"
+        } else {
+            self.caption.as_str()
+        };
+        let mut out = format!("{}\n", caption);
+        for (term, value) in &self.terms {
+            out.push_str(&format!("     {:<8}{:>12.4}\n", term.name(), value));
+        }
+        if let Some(rms) = self.rms_arcsec {
+            out.push_str(&format!("     {:<8}{:>12.4}\n", "RMS", rms));
+        }
+        out.push_str("END\n");
+        out
+    }
+
+    /// Builds a model from a 10Micron alignment info string (`:getain#` reply).
+    ///
+    /// The reply has the form
+    /// `ZZZ.ZZZZ,+AA.AAAA,EE.EEEE,PPP.PP,+OO.OOOO,+aa.aa,+bb.bb,NN,RRRRR.R#`
+    /// (RA axis azimuth and altitude, polar error, its position angle,
+    /// orthogonality error, knob turns, number of model terms and RMS). The RA axis
+    /// orientation is converted to `MA`/`ME` and the orthogonality error to `NP`.
+    ///
+    /// # Arguments
+    /// * `info` - Alignment info string
+    /// * `latitude_deg` - Observer latitude in degrees
+    ///
+    /// # Errors
+    /// Returns `AstroError::InvalidFormat` if the string has too few fields or a
+    /// field is not numeric.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::pointing_model::{PointingModel, PointingTerm};
+    ///
+    /// let info = "0.0100,+40.0050,0.0112,063.43,+0.0020,+00.10,-00.05,15,00012.3#";
+    /// let model = PointingModel::from_10micron_alignment(info, 40.0).unwrap();
+    /// assert!((model.term(PointingTerm::ME) - 18.0).abs() < 1e-6);
+    /// assert!((model.term(PointingTerm::NP) - 7.2).abs() < 1e-6);
+    /// assert_eq!(model.rms_arcsec, Some(12.3));
+    /// ```
+    pub fn from_10micron_alignment(info: &str, latitude_deg: f64) -> Result<Self> {
+        let trimmed = info.trim().trim_end_matches('#');
+        let fields = trimmed
+            .split(',')
+            .map(|f| f.trim().parse::<f64>())
+            .collect::<std::result::Result<Vec<f64>, _>>()
+            .map_err(|_| AstroError::InvalidFormat {
+                format: "10Micron alignment",
+                input: info.to_string(),
+                reason: "non-numeric field".to_string(),
+            })?;
+        if fields.len() < 5 {
+            return Err(AstroError::InvalidFormat {
+                format: "10Micron alignment",
+                input: info.to_string(),
+                reason: format!("expected at least 5 fields, found {}", fields.len()),
+            });
+        }
+
+        let (axis_az, axis_alt, ortho) = (fields[0], fields[1], fields[4]);
+        let pole_az = if latitude_deg >= 0.0 { 0.0 } else { 180.0 };
+        let d_az = (axis_az - pole_az + 180.0).rem_euclid(360.0) - 180.0;
+        let d_alt = axis_alt - latitude_deg.abs();
+
+        let mut model = PointingModel::new()
+            .with_term(PointingTerm::MA, d_az * latitude_deg.to_radians().cos() * 3600.0)
+            .with_term(PointingTerm::ME, d_alt * 3600.0)
+            .with_term(PointingTerm::NP, ortho * 3600.0);
+        model.caption = "10Micron alignment".to_string();
+        model.rms_arcsec = fields.get(8).copied();
+        Ok(model)
+    }
+
+    /// Writes the polar alignment part of the model as a 10Micron alignment
+    /// info string.
+    ///
+    /// Only `MA`, `ME` and `NP` can be represented; knob turns are written as
+    /// zero since they depend on the mount hardware.
+    pub fn to_10micron_alignment(&self, latitude_deg: f64) -> String {
+        let ma = self.term(PointingTerm::MA) / 3600.0;
+        let me = self.term(PointingTerm::ME) / 3600.0;
+        let np = self.term(PointingTerm::NP) / 3600.0;
+
+        let cos_lat = latitude_deg.to_radians().cos();
+        let d_az = if cos_lat.abs() > 1e-12 { ma / cos_lat } else { 0.0 };
+        let pole_az = if latitude_deg >= 0.0 { 0.0 } else { 180.0 };
+        let axis_az = (pole_az + d_az).rem_euclid(360.0);
+        let axis_alt = latitude_deg.abs() + me;
+        let polar_error = ma.hypot(me);
+        let position_angle = ma.atan2(me).to_degrees().rem_euclid(360.0);
+        let n_terms = self.terms.len();
+        let rms = self.rms_arcsec.unwrap_or(0.0);
+
+        format!(
+            "{:08.4},{:+08.4},{:07.4},{:06.2},{:+07.4},+00.00,+00.00,{:02},{:07.1}#",
+            axis_az, axis_alt, polar_error, position_angle, np, n_terms, rms
+        )
+    }
+}
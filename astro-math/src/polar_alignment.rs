@@ -0,0 +1,285 @@
+//! Polar alignment aids for equatorial mounts.
+//!
+//! An equatorial mount tracks correctly when its polar axis points at the
+//! *refracted* celestial pole: refraction lifts the pole above the observer's
+//! latitude by up to a few arcminutes, and a mount aligned on the true pole
+//! drifts in declination. [`refracted_pole_position`] gives the apparent
+//! alt/az of the pole, and [`pole_star_offset`] describes where the pole
+//! star (Polaris in the north, σ Octantis in the south) sits relative to it,
+//! in the clock-face form used by polar-scope reticles and alignment apps.
+//!
+//! The pole is the Celestial Intermediate Pole of date, so precession and
+//! nutation are included. UT1−UTC and polar motion are taken as zero.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::Location;
+//! use astro_math::polar_alignment::{pole_star_offset, refracted_pole_position};
+//! use astro_math::refraction::AtmosphericConditions;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let site = Location { latitude_deg: 40.0, longitude_deg: -105.0, altitude_m: 1600.0 };
+//! let dt = Utc.with_ymd_and_hms(2024, 10, 1, 4, 0, 0).unwrap();
+//! let weather = AtmosphericConditions { pressure_hpa: 840.0, temperature_c: 10.0, relative_humidity: 0.4 };
+//!
+//! // Refraction lifts the pole about 1′ above the latitude
+//! let (alt, az) = refracted_pole_position(dt, &site, Some(weather)).unwrap();
+//! assert!((alt - 40.0) * 60.0 > 0.5 && (alt - 40.0) * 60.0 < 1.5);
+//! assert!(az < 0.1 || az > 359.9);
+//!
+//! let polaris = pole_star_offset(dt, &site, Some(weather)).unwrap();
+//! assert_eq!(polaris.star, "Polaris");
+//! assert!((polaris.separation_deg - 0.63).abs() < 0.02);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for an out-of-range latitude or longitude, or
+//!   pressure, temperature or humidity outside the ranges accepted by the
+//!   refraction models
+//! - `AstroError::CalculationError` if the ERFA transformation fails
+
+use crate::error::{validate_range, AstroError, Result};
+use crate::location::Location;
+use crate::refraction::AtmosphericConditions;
+use crate::time::julian_date_two_part;
+use chrono::{DateTime, Utc};
+use std::f64::consts::FRAC_PI_2;
+
+/// Milliarcseconds per radian
+const MAS_PER_RAD: f64 = 206264806.24709636;
+
+/// Effective wavelength of visual observation, in micrometers
+const VISUAL_WAVELENGTH_UM: f64 = 0.574;
+
+/// ICRS catalogue entry for a pole star.
+struct PoleStar {
+    name: &'static str,
+    ra_deg: f64,
+    dec_deg: f64,
+    /// Proper motion in RA·cos(Dec), mas/yr
+    pm_ra_cosdec: f64,
+    /// Proper motion in Dec, mas/yr
+    pm_dec: f64,
+    /// Parallax, mas
+    parallax: f64,
+    /// Radial velocity, km/s
+    radial_velocity: f64,
+}
+
+/// α UMi (Hipparcos, epoch J2000)
+const POLARIS: PoleStar = PoleStar {
+    name: "Polaris",
+    ra_deg: 37.954560667,
+    dec_deg: 89.264109,
+    pm_ra_cosdec: 44.48,
+    pm_dec: -11.85,
+    parallax: 7.54,
+    radial_velocity: -17.4,
+};
+
+/// σ Oct (Hipparcos, epoch J2000)
+const SIGMA_OCTANTIS: PoleStar = PoleStar {
+    name: "σ Octantis",
+    ra_deg: 317.195264875,
+    dec_deg: -88.956499528,
+    pm_ra_cosdec: 25.96,
+    pm_dec: 5.02,
+    parallax: 11.38,
+    radial_velocity: 12.0,
+};
+
+/// Position of the pole star relative to the refracted celestial pole.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoleStarOffset {
+    /// Name of the pole star: "Polaris" or "σ Octantis"
+    pub star: &'static str,
+    /// Angular distance from the refracted pole, in degrees
+    pub separation_deg: f64,
+    /// Observed hour angle of the star in hours, in [0, 24)
+    pub hour_angle_hours: f64,
+    /// Direction from the pole to the star as a clock position in [0, 12),
+    /// seen with the naked eye facing the pole: 12 is towards the zenith,
+    /// 3 is to the right. For an inverting polar scope add 6 (mod 12).
+    pub clock_position: f64,
+    /// Altitude of the star minus altitude of the pole, in degrees
+    pub delta_alt_deg: f64,
+    /// Azimuth of the star minus azimuth of the pole, in degrees, in (-180, 180]
+    pub delta_az_deg: f64,
+}
+
+/// Calculates the apparent (refracted) position of the visible celestial pole.
+///
+/// The north pole is used for latitudes ≥ 0, the south pole otherwise.
+///
+/// # Arguments
+/// * `datetime` - Time of observation (UTC)
+/// * `location` - Observer location
+/// * `conditions` - Weather at the observer; `None` for the
+///   [default](crate::config) weather, the unrefracted pole unless set
+///
+/// # Returns
+/// `(altitude, azimuth)` in degrees, azimuth in [0, 360) measured from north through east
+///
+/// # Errors
+/// - `AstroError::OutOfRange` if the location or a weather value is out of range
+/// - `AstroError::CalculationError` if the ERFA transformation fails
+///
+/// # Example
+/// ```
+/// use astro_math::Location;
+/// use astro_math::polar_alignment::refracted_pole_position;
+/// use chrono::{TimeZone, Utc};
+///
+/// // Without refraction the pole sits at the latitude
+/// let site = Location { latitude_deg: -31.27, longitude_deg: 149.06, altitude_m: 1165.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+/// let (alt, az) = refracted_pole_position(dt, &site, None).unwrap();
+/// assert!((alt - 31.27).abs() < 0.01);
+/// assert!((az - 180.0).abs() < 0.1);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(location)))]
+pub fn refracted_pole_position(
+    datetime: DateTime<Utc>,
+    location: &Location,
+    conditions: Option<AtmosphericConditions>,
+) -> Result<(f64, f64)> {
+    validate_location(location)?;
+    let conditions = weather(conditions)?;
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let pole_dec = if location.latitude_deg >= 0.0 { FRAC_PI_2 } else { -FRAC_PI_2 };
+
+    let (aob, zob, _, _, _) = crate::backend::astrometry::Atio13(
+        0.0,
+        pole_dec,
+        jd1,
+        jd2,
+        0.0,
+        location.longitude_deg.to_radians(),
+        location.latitude_deg.to_radians(),
+        location.altitude_m,
+        0.0,
+        0.0,
+        conditions.pressure_hpa,
+        conditions.temperature_c,
+        conditions.relative_humidity,
+        VISUAL_WAVELENGTH_UM,
+    )
+    .map_err(|_| AstroError::CalculationError {
+        calculation: "ERFA Atio13",
+        reason: "Failed to transform the celestial pole to observed coordinates".to_string(),
+    })?;
+    let alt = 90.0 - zob.to_degrees();
+    trace_event!(debug, refraction_arcsec = (alt - location.latitude_deg.abs()) * 3600.0, "pole lifted by refraction");
+    Ok((alt, aob.to_degrees().rem_euclid(360.0)))
+}
+
+/// Calculates where the pole star is relative to the refracted celestial pole.
+///
+/// Uses Polaris for latitudes ≥ 0 and σ Octantis otherwise, with proper
+/// motion, parallax and radial velocity applied to the date of observation.
+///
+/// # Arguments
+/// * `datetime` - Time of observation (UTC)
+/// * `location` - Observer location
+/// * `conditions` - Weather at the observer; `None` for the
+///   [default](crate::config) weather, no refraction unless set
+///
+/// # Errors
+/// Same as [`refracted_pole_position`].
+///
+/// # Example
+/// ```
+/// use astro_math::Location;
+/// use astro_math::polar_alignment::pole_star_offset;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: -31.27, longitude_deg: 149.06, altitude_m: 1165.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+/// let offset = pole_star_offset(dt, &site, None).unwrap();
+/// assert_eq!(offset.star, "σ Octantis");
+/// assert!((0.0..12.0).contains(&offset.clock_position));
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(location)))]
+pub fn pole_star_offset(
+    datetime: DateTime<Utc>,
+    location: &Location,
+    conditions: Option<AtmosphericConditions>,
+) -> Result<PoleStarOffset> {
+    let (pole_alt, pole_az) = refracted_pole_position(datetime, location, conditions)?;
+    let conditions = weather(conditions)?;
+    let star = if location.latitude_deg >= 0.0 { &POLARIS } else { &SIGMA_OCTANTIS };
+    let (jd1, jd2) = julian_date_two_part(datetime);
+
+    let dec = star.dec_deg.to_radians();
+    let (aob, zob, hob, _, _, _) = crate::backend::astrometry::Atco13(
+        star.ra_deg.to_radians(),
+        dec,
+        star.pm_ra_cosdec / MAS_PER_RAD / dec.cos(),
+        star.pm_dec / MAS_PER_RAD,
+        star.parallax / 1000.0,
+        star.radial_velocity,
+        jd1,
+        jd2,
+        0.0,
+        location.longitude_deg.to_radians(),
+        location.latitude_deg.to_radians(),
+        location.altitude_m,
+        0.0,
+        0.0,
+        conditions.pressure_hpa,
+        conditions.temperature_c,
+        conditions.relative_humidity,
+        VISUAL_WAVELENGTH_UM,
+    )
+    .map_err(|_| AstroError::CalculationError {
+        calculation: "ERFA Atco13",
+        reason: format!("Failed to transform {} to observed coordinates", star.name),
+    })?;
+    let star_alt = 90.0 - zob.to_degrees();
+    let star_az = aob.to_degrees();
+
+    let delta_alt_deg = star_alt - pole_alt;
+    let delta_az_deg = 180.0 - (180.0 - (star_az - pole_az)).rem_euclid(360.0);
+
+    // Facing the pole, increasing azimuth is to the right in both hemispheres
+    let right = delta_az_deg.to_radians() * pole_alt.to_radians().cos();
+    let clock_position = (right.atan2(delta_alt_deg.to_radians()).to_degrees() / 30.0).rem_euclid(12.0);
+
+    Ok(PoleStarOffset {
+        star: star.name,
+        separation_deg: angular_distance(pole_alt, pole_az, star_alt, star_az),
+        hour_angle_hours: (hob.to_degrees() / 15.0).rem_euclid(24.0),
+        clock_position,
+        delta_alt_deg,
+        delta_az_deg,
+    })
+}
+
+fn validate_location(location: &Location) -> Result<()> {
+    validate_range(location.latitude_deg, -90.0, 90.0, "latitude")?;
+    validate_range(location.longitude_deg, -180.0, 180.0, "longitude")
+}
+
+/// Validated weather, with zero pressure (no refraction) when none is given
+/// or configured.
+fn weather(conditions: Option<AtmosphericConditions>) -> Result<AtmosphericConditions> {
+    match crate::config::conditions_or_default(conditions) {
+        Some(c) => {
+            c.validate()?;
+            Ok(c)
+        }
+        None => Ok(AtmosphericConditions { pressure_hpa: 0.0, temperature_c: 0.0, relative_humidity: 0.0 }),
+    }
+}
+
+/// Great-circle distance between two alt/az positions, in degrees.
+fn angular_distance(alt1: f64, az1: f64, alt2: f64, az2: f64) -> f64 {
+    let (a1, a2) = (alt1.to_radians(), alt2.to_radians());
+    let daz = (az2 - az1).to_radians();
+    let x = a1.cos() * a2.sin() - a1.sin() * a2.cos() * daz.cos();
+    let y = a2.cos() * daz.sin();
+    let z = a1.sin() * a2.sin() + a1.cos() * a2.cos() * daz.cos();
+    x.hypot(y).atan2(z).to_degrees()
+}
@@ -16,6 +16,32 @@
 //! This module uses ERFA (Essential Routines for Fundamental Astronomy) for
 //! maximum accuracy, implementing the IAU 2006 precession model.
 //!
+//! # Long-Term Precession
+//!
+//! The IAU 2006 polynomials are fitted to a few centuries and drift away
+//! from the true motion of the poles over millennia. For epochs more than
+//! [`LONG_TERM_PRECESSION_YEARS`] from J2000.0, [`precess_from_j2000`],
+//! [`precess_to_j2000`], their two-part and batch forms,
+//! [`icrs_to_mean_of_date`] and [`mean_of_date_to_icrs`] switch to the
+//! Vondrák et al. (2011) model, which is valid for ±200 000 years and agrees
+//! with IAU 2006 to a few tenths of an arcsecond at the switch. Use
+//! [`precess_from_j2000_with_model`] and [`precess_to_j2000_with_model`] to
+//! choose a [`PrecessionModel`] explicitly.
+//!
+//! # Frame Bias
+//!
+//! ICRS and the mean equator and equinox of J2000.0 differ by the *frame
+//! bias*, a fixed rotation of about 23 mas. [`precess_from_j2000`],
+//! [`precess_to_j2000`], their batch forms and [`icrs_to_mean_of_date`]
+//! include it, so their J2000 side is ICRS, which is what modern catalogues
+//! (Gaia, Hipparcos, UCAC) provide. [`precess_from_j2000_without_bias`]
+//! leaves it out and treats the input as mean J2000.0 (FK5), matching the
+//! rigorous method of Meeus, *Astronomical Algorithms*, ch. 21.
+//!
+//! None of these functions apply nutation or aberration: the results are
+//! *mean* places of date, as expected by mount firmwares that work in
+//! "JNow" without nutation. Apparent places are up to about 40″ away.
+//!
 //! # Error Handling
 //!
 //! All functions validate their inputs and return `Result<T>` types:
@@ -25,14 +51,21 @@
 //!
 //! - IAU 2006 precession model via ERFA
 //! - Capitaine et al. (2003), "Expressions for IAU 2000 precession quantities"
+//! - Vondrák, Capitaine & Wallace (2011), "New precession expressions, valid
+//!   for long time intervals", A&A 534, A22
 
 use chrono::{DateTime, Utc};
 use crate::error::{Result, validate_ra, validate_dec};
+use crate::time::{julian_date_two_part, julian_epoch};
+use crate::time_scales::utc_to_tt_jd_two_part;
+use crate::vec3::{radec_to_unit_vector, unit_vector_to_radec};
+use rayon::prelude::*;
 
 /// Calculates precession angles (ζ, z, θ) in degrees for converting from J2000.0 to a given date.
 ///
 /// Uses the IAU 2006 precession model via ERFA for maximum accuracy.
-/// Note: These angles include frame bias corrections.
+/// The angles describe precession alone, from the mean equator and equinox
+/// of J2000.0, and do not include the frame bias.
 ///
 /// # Arguments
 /// * `jd` - Julian Date of the target epoch (TT)
@@ -43,7 +76,7 @@ pub fn get_precession_angles(jd: f64) -> (f64, f64, f64) {
     // Use ERFA's IAU 2006 precession angles directly
     let (_eps0, _psia, _oma, _bpa, _bqa, _pia, _bpia, 
          _epsa, _chia, za, zetaa, thetaa, _pa, _gam, _phi, _psi) = 
-        erfars::precnutpolar::P06e(jd, 0.0);
+        crate::backend::precnutpolar::P06e(jd, 0.0);
     
     // Convert from radians to degrees
     // zetaa, za, and thetaa are the precession angles we need
@@ -52,8 +85,10 @@ pub fn get_precession_angles(jd: f64) -> (f64, f64, f64) {
 
 /// Returns the IAU 2006 precession matrix from J2000.0 to the given date.
 ///
-/// This matrix transforms mean J2000.0 coordinates to mean coordinates of date.
-/// Uses ERFA's Pmat06 function which implements the IAU 2006 precession model.
+/// This matrix transforms ICRS coordinates to mean coordinates of date: it
+/// includes the frame bias as well as precession. Uses ERFA's Pmat06
+/// function which implements the IAU 2006 precession model; see
+/// [`get_precession_matrix_without_bias`] for precession alone.
 ///
 /// # Arguments
 /// * `jd` - Julian Date of the target epoch (TT)
@@ -70,8 +105,22 @@ pub fn get_precession_angles(jd: f64) -> (f64, f64, f64) {
 /// // At J2000.0, matrix should be close to identity (with small frame bias)
 /// ```
 pub fn get_precession_matrix(jd: f64) -> [[f64; 3]; 3] {
+    get_precession_matrix_two_part(jd, 0.0)
+}
+
+/// Returns the IAU 2006 precession matrix for a two-part Julian Date (TT).
+///
+/// Identical to [`get_precession_matrix`] but keeps the full precision of a
+/// split Julian Date.
+///
+/// # Arguments
+/// * `jd1`, `jd2` - Julian Date (TT) split into two parts
+///
+/// # Returns
+/// 3x3 precession matrix as a nested array
+pub fn get_precession_matrix_two_part(jd1: f64, jd2: f64) -> [[f64; 3]; 3] {
     let mut rbp = [0.0; 9];
-    erfars::precnutpolar::Pmat06(jd, 0.0, &mut rbp);
+    crate::backend::precnutpolar::Pmat06(jd1, jd2, &mut rbp);
     
     // Convert flat array to 3x3 matrix
     [
@@ -81,8 +130,83 @@ pub fn get_precession_matrix(jd: f64) -> [[f64; 3]; 3] {
     ]
 }
 
+/// Years from J2000.0 beyond which the long-term model is used by default.
+pub const LONG_TERM_PRECESSION_YEARS: f64 = 1000.0;
+
+/// A model of the precession of the equator and ecliptic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrecessionModel {
+    /// IAU 2006 (Capitaine et al. 2003), accurate over a few centuries
+    Iau2006,
+    /// Vondrák et al. (2011), valid for ±200 000 years and within about
+    /// 0.1″ of IAU 2006 around the present
+    Vondrak2011,
+}
+
+impl PrecessionModel {
+    /// The model used by default for an epoch: [`Vondrak2011`](Self::Vondrak2011)
+    /// more than [`LONG_TERM_PRECESSION_YEARS`] from J2000.0, otherwise
+    /// [`Iau2006`](Self::Iau2006).
+    ///
+    /// # Arguments
+    /// * `jd` - Julian Date (TT)
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::precession::PrecessionModel;
+    ///
+    /// assert_eq!(PrecessionModel::for_epoch(2460000.5), PrecessionModel::Iau2006);
+    /// // 3000 BCE
+    /// assert_eq!(PrecessionModel::for_epoch(625307.5), PrecessionModel::Vondrak2011);
+    /// ```
+    pub fn for_epoch(jd: f64) -> Self {
+        if (julian_epoch(jd) - 2000.0).abs() > LONG_TERM_PRECESSION_YEARS {
+            PrecessionModel::Vondrak2011
+        } else {
+            PrecessionModel::Iau2006
+        }
+    }
+}
+
+/// Returns the bias-precession matrix from ICRS to the mean equator and
+/// equinox of a date, for a chosen model.
+///
+/// With [`PrecessionModel::Iau2006`] this is [`get_precession_matrix_two_part`].
+///
+/// # Arguments
+/// * `jd1`, `jd2` - Julian Date (TT) split into two parts
+/// * `model` - Precession model
+///
+/// # Returns
+/// 3x3 precession matrix as a nested array
+pub fn get_precession_matrix_with_model(jd1: f64, jd2: f64, model: PrecessionModel) -> [[f64; 3]; 3] {
+    match model {
+        PrecessionModel::Iau2006 => get_precession_matrix_two_part(jd1, jd2),
+        PrecessionModel::Vondrak2011 => {
+            let mut rbp = [0.0; 9];
+            crate::backend::precnutpolar::Ltpb(julian_epoch(jd1 + jd2), &mut rbp);
+            [
+                [rbp[0], rbp[1], rbp[2]],
+                [rbp[3], rbp[4], rbp[5]],
+                [rbp[6], rbp[7], rbp[8]],
+            ]
+        }
+    }
+}
+
+/// Bias-precession matrix with the model chosen by [`PrecessionModel::for_epoch`].
+fn bias_precession_matrix(jd1: f64, jd2: f64) -> [[f64; 3]; 3] {
+    get_precession_matrix_with_model(jd1, jd2, PrecessionModel::for_epoch(jd1 + jd2))
+}
+
 /// Applies precession from J2000.0 to a given date.
 ///
+/// The input is taken as ICRS and the frame bias is applied along with
+/// precession (see [Frame Bias](self#frame-bias)). The date is used as TT
+/// without conversion from UTC, a difference of about 1 mas. More than
+/// [`LONG_TERM_PRECESSION_YEARS`] from J2000.0 the long-term model is used
+/// (see [Long-Term Precession](self#long-term-precession)).
+///
 /// # Arguments
 /// * `ra_j2000` - Right ascension at J2000.0 in degrees
 /// * `dec_j2000` - Declination at J2000.0 in degrees
@@ -120,57 +244,43 @@ pub fn get_precession_matrix(jd: f64) -> [[f64; 3]; 3] {
 /// }
 /// ```
 pub fn precess_from_j2000(ra_j2000: f64, dec_j2000: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+    let jd = crate::julian_date(datetime);
+    precess_from_j2000_two_part(ra_j2000, dec_j2000, jd, 0.0)
+}
+
+/// Applies precession from J2000.0 to a date given as a two-part Julian Date.
+///
+/// Identical to [`precess_from_j2000`] but takes the target epoch as a split
+/// Julian Date to keep full precision.
+///
+/// # Arguments
+/// * `ra_j2000` - Right ascension at J2000.0 in degrees
+/// * `dec_j2000` - Declination at J2000.0 in degrees
+/// * `jd1`, `jd2` - Target epoch as a two-part Julian Date
+///
+/// # Returns
+/// Tuple of (ra, dec) at the target epoch in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if:
+/// - `ra_j2000` is outside [0, 360)
+/// - `dec_j2000` is outside [-90, 90]
+pub fn precess_from_j2000_two_part(ra_j2000: f64, dec_j2000: f64, jd1: f64, jd2: f64) -> Result<(f64, f64)> {
     // Validate inputs
     validate_ra(ra_j2000)?;
     validate_dec(dec_j2000)?;
-    let jd = crate::julian_date(datetime);
-    
-    // Use ERFA for accurate precession
-    let ra_rad = ra_j2000.to_radians();
-    let dec_rad = dec_j2000.to_radians();
     
     // Get precession matrix from J2000 to date
-    let mut rbp = [0.0; 9];
-    erfars::precnutpolar::Pmat06(jd, 0.0, &mut rbp);
-    
-    // Convert spherical to Cartesian
-    let cos_ra = ra_rad.cos();
-    let sin_ra = ra_rad.sin();
-    let cos_dec = dec_rad.cos();
-    let sin_dec = dec_rad.sin();
-    
-    let p = [
-        cos_dec * cos_ra,
-        cos_dec * sin_ra,
-        sin_dec,
-    ];
-    
-    // Apply precession matrix
-    let p_new = [
-        rbp[0] * p[0] + rbp[1] * p[1] + rbp[2] * p[2],
-        rbp[3] * p[0] + rbp[4] * p[1] + rbp[5] * p[2],
-        rbp[6] * p[0] + rbp[7] * p[1] + rbp[8] * p[2],
-    ];
-    
-    // Convert back to spherical
-    let ra_new = p_new[1].atan2(p_new[0]);
-    let dec_new = p_new[2].asin();
-    
-    // Convert to degrees and normalize RA
-    let mut ra_deg = ra_new.to_degrees();
-    if ra_deg < 0.0 {
-        ra_deg += 360.0;
-    } else if ra_deg >= 360.0 {
-        ra_deg -= 360.0;
-    }
-    
-    Ok((ra_deg, dec_new.to_degrees()))
+    let rbp = bias_precession_matrix(jd1, jd2);
+    Ok(rotate_ra_dec(&rbp, ra_j2000, dec_j2000, false))
 }
 
 /// Applies precession from a given date back to J2000.0.
 ///
 /// This is the inverse of [`precess_from_j2000`] and is useful for converting
-/// current epoch coordinates to the standard J2000.0 reference frame.
+/// current epoch coordinates to the standard J2000.0 reference frame. The
+/// result is ICRS: the frame bias is removed along with precession.
 ///
 /// # Arguments
 /// * `ra` - Right ascension at the given date in degrees
@@ -195,58 +305,333 @@ pub fn precess_from_j2000(ra_j2000: f64, dec_j2000: f64, datetime: DateTime<Utc>
 /// let (ra_j2000, dec_j2000) = precess_to_j2000(10.0, 20.0, dt).unwrap();
 /// ```
 pub fn precess_to_j2000(ra: f64, dec: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+    let jd = crate::julian_date(datetime);
+    precess_to_j2000_two_part(ra, dec, jd, 0.0)
+}
+
+/// Applies precession from a date given as a two-part Julian Date back to J2000.0.
+///
+/// Identical to [`precess_to_j2000`] but takes the epoch as a split Julian
+/// Date to keep full precision.
+///
+/// # Arguments
+/// * `ra` - Right ascension at the given date in degrees
+/// * `dec` - Declination at the given date in degrees
+/// * `jd1`, `jd2` - Epoch of the input coordinates as a two-part Julian Date
+///
+/// # Returns
+/// Tuple of (ra, dec) at J2000.0 in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if:
+/// - `ra` is outside [0, 360)
+/// - `dec` is outside [-90, 90]
+pub fn precess_to_j2000_two_part(ra: f64, dec: f64, jd1: f64, jd2: f64) -> Result<(f64, f64)> {
     // Validate inputs
     validate_ra(ra)?;
     validate_dec(dec)?;
-    let jd = crate::julian_date(datetime);
-    
-    // Use ERFA for accurate precession
-    let ra_rad = ra.to_radians();
-    let dec_rad = dec.to_radians();
-    
-    // Get precession matrix from J2000 to date
-    let mut rbp = [0.0; 9];
-    erfars::precnutpolar::Pmat06(jd, 0.0, &mut rbp);
-    
-    // For inverse, we need the transpose of the matrix
-    let rbp_t = [
-        rbp[0], rbp[3], rbp[6],
-        rbp[1], rbp[4], rbp[7],
-        rbp[2], rbp[5], rbp[8],
-    ];
-    
-    // Convert spherical to Cartesian
-    let cos_ra = ra_rad.cos();
-    let sin_ra = ra_rad.sin();
-    let cos_dec = dec_rad.cos();
-    let sin_dec = dec_rad.sin();
-    
-    let p = [
-        cos_dec * cos_ra,
-        cos_dec * sin_ra,
-        sin_dec,
-    ];
     
-    // Apply inverse precession matrix (transpose)
-    let p_j2000 = [
-        rbp_t[0] * p[0] + rbp_t[1] * p[1] + rbp_t[2] * p[2],
-        rbp_t[3] * p[0] + rbp_t[4] * p[1] + rbp_t[5] * p[2],
-        rbp_t[6] * p[0] + rbp_t[7] * p[1] + rbp_t[8] * p[2],
-    ];
-    
-    // Convert back to spherical
-    let ra_j2000 = p_j2000[1].atan2(p_j2000[0]);
-    let dec_j2000 = p_j2000[2].asin();
-    
-    // Convert to degrees and normalize RA
-    let mut ra_deg = ra_j2000.to_degrees();
-    if ra_deg < 0.0 {
-        ra_deg += 360.0;
-    } else if ra_deg >= 360.0 {
-        ra_deg -= 360.0;
+    // Inverse precession is the transpose of the J2000 → date matrix
+    let rbp = bias_precession_matrix(jd1, jd2);
+    Ok(rotate_ra_dec(&rbp, ra, dec, true))
+}
+
+/// Applies precession from J2000.0 to a given date with a chosen model.
+///
+/// Like [`precess_from_j2000`], but the model is given explicitly instead of
+/// being picked from the epoch.
+///
+/// # Arguments
+/// * `ra_j2000` - ICRS right ascension in degrees
+/// * `dec_j2000` - ICRS declination in degrees
+/// * `datetime` - Target date/time
+/// * `model` - Precession model
+///
+/// # Returns
+/// Tuple of (ra, dec) at the target epoch in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use astro_math::precession::{precess_from_j2000_with_model, PrecessionModel};
+///
+/// let dt = Utc.with_ymd_and_hms(2100, 1, 1, 0, 0, 0).unwrap();
+/// let (ra1, dec1) = precess_from_j2000_with_model(10.0, 20.0, dt, PrecessionModel::Iau2006).unwrap();
+/// let (ra2, dec2) = precess_from_j2000_with_model(10.0, 20.0, dt, PrecessionModel::Vondrak2011).unwrap();
+/// // The two models agree to well under an arcsecond near the present
+/// assert!((ra1 - ra2).abs() * 3600.0 < 1.0 && (dec1 - dec2).abs() * 3600.0 < 1.0);
+/// ```
+pub fn precess_from_j2000_with_model(
+    ra_j2000: f64,
+    dec_j2000: f64,
+    datetime: DateTime<Utc>,
+    model: PrecessionModel,
+) -> Result<(f64, f64)> {
+    validate_ra(ra_j2000)?;
+    validate_dec(dec_j2000)?;
+    let rbp = get_precession_matrix_with_model(crate::julian_date(datetime), 0.0, model);
+    Ok(rotate_ra_dec(&rbp, ra_j2000, dec_j2000, false))
+}
+
+/// Applies precession from a given date back to J2000.0 with a chosen model.
+///
+/// The inverse of [`precess_from_j2000_with_model`].
+///
+/// # Arguments
+/// * `ra` - Right ascension at the given date in degrees
+/// * `dec` - Declination at the given date in degrees
+/// * `datetime` - Epoch of the input coordinates
+/// * `model` - Precession model
+///
+/// # Returns
+/// Tuple of ICRS (ra, dec) in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+pub fn precess_to_j2000_with_model(
+    ra: f64,
+    dec: f64,
+    datetime: DateTime<Utc>,
+    model: PrecessionModel,
+) -> Result<(f64, f64)> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    let rbp = get_precession_matrix_with_model(crate::julian_date(datetime), 0.0, model);
+    Ok(rotate_ra_dec(&rbp, ra, dec, true))
+}
+
+/// Returns the IAU 2006 precession matrix from mean J2000.0 to the given
+/// date, without the frame bias.
+///
+/// This is [`get_precession_matrix`] with the ICRS → mean J2000.0 frame bias
+/// taken out, for coordinates that are already on the mean equator and
+/// equinox of J2000.0 (FK5).
+///
+/// # Arguments
+/// * `jd` - Julian Date of the target epoch (TT)
+///
+/// # Returns
+/// 3x3 precession matrix as a nested array
+///
+/// # Example
+/// ```
+/// use astro_math::precession::get_precession_matrix_without_bias;
+///
+/// // No frame bias, so exactly the identity at J2000.0
+/// let m = get_precession_matrix_without_bias(2451545.0);
+/// assert!((m[0][1]).abs() < 1e-15 && (m[0][0] - 1.0).abs() < 1e-15);
+/// ```
+pub fn get_precession_matrix_without_bias(jd: f64) -> [[f64; 3]; 3] {
+    let rbp = get_precession_matrix_two_part(jd, 0.0);
+    // Pmat06 at J2000.0 is the frame bias alone
+    let rb = get_precession_matrix_two_part(2451545.0, 0.0);
+    let mut rp = [[0.0; 3]; 3];
+    for (i, row) in rp.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| rbp[i][k] * rb[j][k]).sum();
+        }
     }
-    
-    Ok((ra_deg, dec_j2000.to_degrees()))
+    rp
+}
+
+/// Applies precession from mean J2000.0 to a given date, without frame bias.
+///
+/// Use this for coordinates already referred to the mean equator and
+/// equinox of J2000.0 (e.g. FK5), or to reproduce Meeus' rigorous method.
+/// For ICRS catalogue positions use [`icrs_to_mean_of_date`] instead; the
+/// two differ by the frame bias, up to about 23 mas.
+///
+/// # Arguments
+/// * `ra_j2000` - Mean right ascension at J2000.0 in degrees
+/// * `dec_j2000` - Mean declination at J2000.0 in degrees
+/// * `datetime` - Target date/time
+///
+/// # Returns
+/// Tuple of (ra, dec) on the mean equator and equinox of date in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use astro_math::precession::precess_from_j2000_without_bias;
+///
+/// // Meeus, Astronomical Algorithms, example 21.b: θ Persei to 2028 Nov 13.19 TD.
+/// // Meeus uses the IAU 1976 precession model; the results agree within 0.1″.
+/// let dt = Utc.with_ymd_and_hms(2028, 11, 13, 4, 33, 36).unwrap();
+/// let (ra, dec) = precess_from_j2000_without_bias(41.054063, 49.227750, dt).unwrap();
+/// assert!((ra - 41.547214).abs() * 3600.0 < 0.1);
+/// assert!((dec - 49.348483).abs() * 3600.0 < 0.1);
+/// ```
+pub fn precess_from_j2000_without_bias(ra_j2000: f64, dec_j2000: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+    validate_ra(ra_j2000)?;
+    validate_dec(dec_j2000)?;
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+    let rp = get_precession_matrix_without_bias(tt1 + tt2);
+    Ok(rotate_ra_dec(&rp, ra_j2000, dec_j2000, false))
+}
+
+/// Converts ICRS coordinates to the mean equator and equinox of date.
+///
+/// Applies the frame bias and IAU 2006 precession, but neither nutation nor
+/// aberration, giving the "mean of date" (JNow without nutation)
+/// coordinates some mount firmwares expect. The time is converted from UTC
+/// to TT.
+///
+/// # Arguments
+/// * `ra` - ICRS right ascension in degrees
+/// * `dec` - ICRS declination in degrees
+/// * `datetime` - Date of the mean equator and equinox
+///
+/// # Returns
+/// Tuple of (ra, dec) on the mean equator and equinox of date in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use astro_math::precession::{icrs_to_mean_of_date, mean_of_date_to_icrs};
+///
+/// let dt = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+/// let (ra, dec) = icrs_to_mean_of_date(279.23473479, 38.78368896, dt).unwrap();
+/// // A quarter century of precession moves Vega by about 5′ in RA
+/// assert!((ra - 279.23473479) * 60.0 > 4.0);
+///
+/// let (ra0, dec0) = mean_of_date_to_icrs(ra, dec, dt).unwrap();
+/// assert!((ra0 - 279.23473479).abs() < 1e-9 && (dec0 - 38.78368896).abs() < 1e-9);
+/// ```
+pub fn icrs_to_mean_of_date(ra: f64, dec: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+    let rbp = bias_precession_matrix(tt1, tt2);
+    Ok(rotate_ra_dec(&rbp, ra, dec, false))
+}
+
+/// Converts coordinates on the mean equator and equinox of date to ICRS.
+///
+/// The inverse of [`icrs_to_mean_of_date`].
+///
+/// # Arguments
+/// * `ra` - Mean right ascension of date in degrees
+/// * `dec` - Mean declination of date in degrees
+/// * `datetime` - Date of the mean equator and equinox
+///
+/// # Returns
+/// Tuple of ICRS (ra, dec) in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+pub fn mean_of_date_to_icrs(ra: f64, dec: f64, datetime: DateTime<Utc>) -> Result<(f64, f64)> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+    let rbp = bias_precession_matrix(tt1, tt2);
+    Ok(rotate_ra_dec(&rbp, ra, dec, true))
+}
+
+/// Precesses many J2000.0 positions to a target date in parallel.
+///
+/// The precession matrix is computed once and applied to every coordinate
+/// with Rayon, which is far cheaper than calling [`precess_from_j2000`] per
+/// point when precessing whole catalogs.
+///
+/// # Arguments
+/// * `coords` - Slice of (ra, dec) pairs at J2000.0 in degrees
+/// * `datetime` - Target date/time
+///
+/// # Returns
+/// Vector of (ra, dec) at the target epoch in degrees, in input order
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if any RA or Dec is out of range.
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use astro_math::precess_from_j2000;
+/// use astro_math::precession::precess_from_j2000_batch;
+///
+/// let dt = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+/// let coords = vec![(0.0, 0.0), (279.23473479, 38.78368896)];
+/// let batch = precess_from_j2000_batch(&coords, dt).unwrap();
+/// let single = precess_from_j2000(279.23473479, 38.78368896, dt).unwrap();
+/// assert!((batch[1].0 - single.0).abs() < 1e-12);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(count = coords.len())))]
+pub fn precess_from_j2000_batch(coords: &[(f64, f64)], datetime: DateTime<Utc>) -> Result<Vec<(f64, f64)>> {
+    let rbp = bias_precession_matrix(crate::julian_date(datetime), 0.0);
+    rotate_batch(&rbp, coords, false)
+}
+
+/// Precesses many positions from a given date back to J2000.0 in parallel.
+///
+/// Batch counterpart of [`precess_to_j2000`]; the matrix is computed once.
+///
+/// # Arguments
+/// * `coords` - Slice of (ra, dec) pairs at the given date in degrees
+/// * `datetime` - Epoch of the input coordinates
+///
+/// # Returns
+/// Vector of (ra, dec) at J2000.0 in degrees, in input order
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if any RA or Dec is out of range.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(count = coords.len())))]
+pub fn precess_to_j2000_batch(coords: &[(f64, f64)], datetime: DateTime<Utc>) -> Result<Vec<(f64, f64)>> {
+    let rbp = bias_precession_matrix(crate::julian_date(datetime), 0.0);
+    rotate_batch(&rbp, coords, true)
+}
+
+/// Validates and rotates a slice of coordinates by a fixed matrix using Rayon.
+pub(crate) fn rotate_batch(m: &[[f64; 3]; 3], coords: &[(f64, f64)], transpose: bool) -> Result<Vec<(f64, f64)>> {
+    coords
+        .par_iter()
+        .map(|&(ra, dec)| {
+            validate_ra(ra)?;
+            validate_dec(dec)?;
+            Ok(rotate_ra_dec(m, ra, dec, transpose))
+        })
+        .collect()
+}
+
+/// Rotates an (ra, dec) direction in degrees by a 3×3 matrix,
+/// or by its transpose when `transpose` is set.
+pub(crate) fn rotate_ra_dec(m: &[[f64; 3]; 3], ra: f64, dec: f64, transpose: bool) -> (f64, f64) {
+    let p = radec_to_unit_vector(ra, dec);
+
+    let p_new = if transpose {
+        [
+            m[0][0] * p[0] + m[1][0] * p[1] + m[2][0] * p[2],
+            m[0][1] * p[0] + m[1][1] * p[1] + m[2][1] * p[2],
+            m[0][2] * p[0] + m[1][2] * p[1] + m[2][2] * p[2],
+        ]
+    } else {
+        [
+            m[0][0] * p[0] + m[0][1] * p[1] + m[0][2] * p[2],
+            m[1][0] * p[0] + m[1][1] * p[1] + m[1][2] * p[2],
+            m[2][0] * p[0] + m[2][1] * p[1] + m[2][2] * p[2],
+        ]
+    };
+
+    unit_vector_to_radec(p_new)
 }
 
 #[cfg(test)]
@@ -0,0 +1,71 @@
+//! The most-used types and functions, for a single glob import.
+//!
+//! ```
+//! use astro_math::prelude::*;
+//! ```
+//!
+//! brings in what nearly every program needs: the observer types, weather
+//! conditions, errors, [`AstroTime`] and time conversions, the main coordinate transforms and
+//! rise/set searches, and chrono's [`DateTime`], [`TimeZone`] and [`Utc`],
+//! which every time argument uses. Everything else lives in its module, e.g.
+//! [`crate::mount`] or [`crate::almanac`].
+//!
+//! The prelude only grows deliberately: a new module does not add to it, so
+//! a glob import of it will not start clashing with names in your own code
+//! after an upgrade.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::prelude::*;
+//!
+//! let kitt_peak = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+//! let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+//!
+//! // Vega, refracted for the site's weather
+//! let weather = AtmosphericConditions { pressure_hpa: 790.0, temperature_c: 15.0, relative_humidity: 0.3 };
+//! let ground = Observer::Ground(kitt_peak);
+//! let vega = observed_place(279.23473479, 38.78368896, None, dt, &ground, Some(weather)).unwrap();
+//! let (alt, _az) = vega.alt_az.unwrap();
+//! assert!(alt > 70.0);
+//!
+//! let jd = julian_date(dt);
+//! assert!((julian_epoch(utc_to_tt_jd(jd)) - 2024.59).abs() < 0.01);
+//!
+//! let (sunrise, sunset) = sun_rise_set(dt, &kitt_peak).unwrap().unwrap();
+//! assert!(sunrise < sunset);
+//! ```
+
+pub use crate::error::{AstroError, Result};
+
+// Observers and their conditions
+pub use crate::fixed_site::FixedSite;
+pub use crate::location::Location;
+pub use crate::moving_observer::MovingObserver;
+pub use crate::observer::{observed_place, ObservedPlace, Observer, ObserverKind};
+pub use crate::refraction::{AtmosphericConditions, RefractionModel};
+pub use crate::weather::ConditionsProvider;
+
+// Time
+pub use crate::astro_time::{AstroTime, TimeScale};
+pub use crate::sidereal::{apparent_sidereal_time, local_mean_sidereal_time};
+pub use crate::time::{j2000_days, julian_date, julian_date_two_part, julian_epoch, JD2000};
+pub use crate::time_scales::{tt_to_utc_jd, utc_to_tt_jd};
+pub use chrono::{DateTime, TimeZone, Utc};
+
+// Transforms
+pub use crate::apparent::apparent_place;
+pub use crate::precession::{precess_from_j2000, precess_to_j2000};
+pub use crate::proper_motion::apply_proper_motion;
+pub use crate::refraction::{apparent_to_true_altitude, true_to_apparent_altitude};
+pub use crate::transforms::{alt_az_to_ra_dec, body_alt_az, ra_dec_to_alt_az, ra_dec_to_alt_az_batch_parallel};
+
+// Sun, Moon and rise/set
+pub use crate::airmass::airmass_kasten_young;
+pub use crate::ephemeris::Ephemeris;
+pub use crate::moon::{moon_distance, moon_equatorial, moon_illumination, moon_phase_name};
+pub use crate::night::{Night, Twilight};
+pub use crate::rise_set::{
+    body_rise_set, moon_rise_set, moon_transit, next_rise, next_set, rise_transit_set, sun_rise_set,
+};
+pub use crate::sun::{solar_azimuth_elevation, sun_ra_dec};
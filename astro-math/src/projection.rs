@@ -1,8 +1,9 @@
-//! Standard tangent plane projection for astronomical imaging.
+//! Sky projections for astronomical imaging.
 //!
 //! This module provides the tangent plane (gnomonic) projection commonly used in
 //! astronomical imaging to convert between celestial coordinates (RA/Dec) and 
-//! pixel coordinates (X/Y).
+//! pixel coordinates (X/Y), and the other FITS WCS projections needed for wide
+//! fields.
 //!
 //! # Overview
 //!
@@ -10,19 +11,48 @@
 //! is minimal. It's the standard projection used in FITS files and most astronomical
 //! CCD images.
 //!
+//! Wider fields need a projection that stays finite far from the centre.
+//! [`WcsProjection`] adds the FITS WCS (Calabretta & Greisen 2002)
+//! projections:
+//!
+//! - `SIN` (orthographic) — radio synthesis images; one hemisphere
+//! - `ARC` (zenithal equidistant) — all-sky cameras and Schmidt plates;
+//!   radius proportional to the angle from the centre
+//! - `STG` (stereographic) — conformal, shapes kept over wide fields
+//! - `AIT` (Hammer-Aitoff) — equal-area maps of the whole sky
+//!
+//! [`SkyProjection`] is implemented by [`TangentPlane`], [`WcsProjection`],
+//! [`RefractedTangentPlane`] and [`DistortedTangentPlane`], so code can take
+//! any of them. [`TangentPlane::with_refraction`] maps catalogue positions to
+//! where refraction puts them, for matching fields at low elevation, and
+//! [`TangentPlane::with_distortion`] adds the SIP polynomial distortion of a
+//! wide-field solution from astrometry.net.
+//!
+//! All projections share the same pixel convention: `scale` arcseconds per
+//! pixel at the reference point, `rotation` degrees, and x increasing to
+//! the west with north up.
+//!
 //! # Error Handling
 //!
 //! Functions return `Result<T>` types with these possible errors:
 //! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
-//! - `AstroError::ProjectionError` when a point cannot be projected (e.g., on opposite side of sky)
+//! - `AstroError::ProjectionError` when a point cannot be projected (e.g., on
+//!   opposite side of sky) or a pixel lies outside the projection's boundary
 //! - `AstroError::OutOfRange` for invalid scale values
+//! - `AstroError::InvalidFormat` for a FITS header with malformed SIP keywords
 
-use crate::error::{Result, AstroError, validate_ra, validate_dec};
+use crate::error::{Result, AstroError, validate_ra, validate_dec, validate_range};
+use crate::refraction::{apparent_to_true_altitude, true_to_apparent_altitude, AtmosphericConditions};
+use crate::transforms::AltAzRates;
+use crate::vec3::{dot, normalize, radec_to_unit_vector, unit_vector_to_radec, Vec3};
+use std::f64::consts::PI;
 
 /// Tangent plane (gnomonic) projection for converting RA/Dec to X/Y pixel coordinates.
 ///
 /// This is the standard projection used in most astronomical imaging. It provides
 /// accurate representation of small fields of view with minimal distortion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TangentPlane {
     /// Reference point RA in degrees
     pub ra0: f64,
@@ -89,7 +119,100 @@ impl TangentPlane {
         self.rotation = rotation;
         self
     }
+
+    /// Adds atmospheric refraction to the projection.
+    ///
+    /// The plane describes the refracted sky as the camera sees it; the
+    /// result takes catalogue (true) positions and raises each star towards
+    /// the zenith by the refraction at its own altitude before projecting,
+    /// so catalogue stars land where they appear. The refraction varies
+    /// across the field: 2° wide at 20° altitude, the bottom edge is lifted
+    /// about 14″ more than the top, and the field is squashed vertically.
+    ///
+    /// Refraction follows [`true_to_apparent_altitude`] for the conditions'
+    /// pressure and temperature.
+    ///
+    /// # Arguments
+    /// * `conditions` - Weather at the observer
+    /// * `alt_az_of_center` - Horizontal position of the plane's reference
+    ///   point, as from [`alt_az_rates`](crate::transforms::alt_az_rates);
+    ///   its unrefracted altitude and parallactic angle fix where the
+    ///   zenith is
+    ///
+    /// # Errors
+    /// `AstroError::OutOfRange` if the conditions are out of range or the
+    /// altitude is outside [-90, 90].
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::projection::TangentPlane;
+    /// use astro_math::refraction::AtmosphericConditions;
+    /// use astro_math::Location;
+    /// use astro_math::transforms::alt_az_rates;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let site = Location { latitude_deg: 19.8207, longitude_deg: -155.468, altitude_m: 4205.0 };
+    /// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 13, 0, 0).unwrap();
+    /// let (ra, dec) = (279.2347, 38.7837);
+    /// let center = alt_az_rates(ra, dec, dt, &site).unwrap();
+    /// assert!(center.alt < 25.0);
+    ///
+    /// let plane = TangentPlane::new(ra, dec, 1.0).unwrap();
+    /// let refracted = plane.with_refraction(&AtmosphericConditions::standard(), &center).unwrap();
+    /// // The whole field is lifted by about 2′, and a star a degree away
+    /// // along the vertical by several arcseconds more or less
+    /// let shift = |ra: f64| {
+    ///     let (x, y) = plane.ra_dec_to_pixel(ra, dec).unwrap();
+    ///     let (xr, yr) = refracted.ra_dec_to_pixel(ra, dec).unwrap();
+    ///     (xr - x, yr - y)
+    /// };
+    /// let (centre, lower) = (shift(ra), shift(ra + 1.2));
+    /// assert!((centre.0.hypot(centre.1) / 60.0 - 2.0).abs() < 0.2);
+    /// assert!((lower.0 - centre.0).hypot(lower.1 - centre.1) > 3.0);
+    /// ```
+    pub fn with_refraction(
+        self,
+        conditions: &AtmosphericConditions,
+        alt_az_of_center: &AltAzRates,
+    ) -> Result<RefractedTangentPlane> {
+        conditions.validate()?;
+        validate_range(alt_az_of_center.alt, -90.0, 90.0, "altitude")?;
+
+        // The zenith lies at position angle q from the centre, 90° − a away
+        let (alt, q) = (alt_az_of_center.alt.to_radians(), alt_az_of_center.parallactic_angle.to_radians());
+        let [centre, east, north] = frame(self.ra0, self.dec0);
+        let zenith =
+            std::array::from_fn(|i| alt.sin() * centre[i] + alt.cos() * (q.cos() * north[i] + q.sin() * east[i]));
+        Ok(RefractedTangentPlane { plane: self, conditions: *conditions, zenith })
+    }
     
+    /// Adds SIP polynomial distortion to the projection.
+    ///
+    /// The plane becomes the linear part of the solution, applied after the
+    /// distortion has been removed from the pixel offsets, as in a FITS
+    /// `RA---TAN-SIP` header.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::projection::{SipDistortion, TangentPlane};
+    ///
+    /// let plane = TangentPlane::new(150.0, 2.0, 1.5).unwrap().with_reference_pixel(2048.0, 2048.0);
+    /// // Radial distortion k·r² of 4 pixels along each axis at the corners
+    /// let k = 2.0 / 2048f64.powi(3);
+    /// let sip = SipDistortion::new(
+    ///     vec![vec![], vec![0.0, 0.0, k], vec![], vec![k]],  // k·u³ + k·u·v²
+    ///     vec![vec![0.0, 0.0, 0.0, k], vec![], vec![0.0, k]], // k·v³ + k·u²·v
+    /// );
+    /// let distorted = plane.with_distortion(sip);
+    ///
+    /// let (x, y) = distorted.ra_dec_to_pixel(150.5, 2.5).unwrap();
+    /// let (ra, dec) = distorted.pixel_to_ra_dec(x, y).unwrap();
+    /// assert!((ra - 150.5).abs() * 3.6e6 < 0.01 && (dec - 2.5).abs() * 3.6e6 < 0.01);
+    /// ```
+    pub fn with_distortion(self, sip: SipDistortion) -> DistortedTangentPlane {
+        DistortedTangentPlane { plane: self, sip }
+    }
+
     /// Project RA/Dec coordinates to pixel coordinates.
     ///
     /// # Arguments
@@ -125,7 +248,7 @@ impl TangentPlane {
         
         // Use ERFA's Tpxes for tangent plane projection
         // Returns standard coordinates (xi, eta) in radians
-        let result = erfars::gnomonic::Tpxes(ra_rad, dec_rad, ra0_rad, dec0_rad);
+        let result = crate::backend::gnomonic::Tpxes(ra_rad, dec_rad, ra0_rad, dec0_rad);
         
         let (xi, eta) = match result {
             Ok((xi, eta)) => (xi, eta),
@@ -198,7 +321,7 @@ impl TangentPlane {
         let dec0_rad = self.dec0.to_radians();
         
         // Use ERFA's Tpsts for inverse tangent plane projection
-        let (ra_rad, dec_rad) = erfars::gnomonic::Tpsts(xi, eta, ra0_rad, dec0_rad);
+        let (ra_rad, dec_rad) = crate::backend::gnomonic::Tpsts(xi, eta, ra0_rad, dec0_rad);
         
         // Convert to degrees and normalize
         let mut ra = ra_rad.to_degrees();
@@ -216,6 +339,601 @@ impl TangentPlane {
     }
 }
 
+/// Conversion between sky and pixel coordinates, whatever the projection.
+///
+/// # Example
+/// ```
+/// use astro_math::projection::{ProjectionKind, SkyProjection, TangentPlane, WcsProjection};
+///
+/// fn corner(projection: &dyn SkyProjection) -> (f64, f64) {
+///     projection.to_sky(0.0, 0.0).unwrap()
+/// }
+///
+/// let tan = TangentPlane::new(83.8, -5.4, 2.0).unwrap().with_reference_pixel(1024.0, 1024.0);
+/// let stg = WcsProjection::new(ProjectionKind::Stg, 83.8, -5.4, 2.0).unwrap()
+///     .with_reference_pixel(1024.0, 1024.0);
+/// let (a, b) = (corner(&tan), corner(&stg));
+/// // Half a degree from the centre the two differ by a fraction of an arcsecond
+/// assert!((a.1 - b.1).abs() * 3600.0 < 0.5);
+/// ```
+pub trait SkyProjection {
+    /// Projects RA/Dec in degrees to (x, y) pixel coordinates.
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+    /// - `AstroError::ProjectionError` if the point cannot be projected
+    fn to_pixel(&self, ra: f64, dec: f64) -> Result<(f64, f64)>;
+
+    /// Converts (x, y) pixel coordinates to RA/Dec in degrees.
+    ///
+    /// # Errors
+    /// `AstroError::ProjectionError` if the pixel is outside the
+    /// projection's boundary
+    fn to_sky(&self, x: f64, y: f64) -> Result<(f64, f64)>;
+}
+
+impl SkyProjection for TangentPlane {
+    fn to_pixel(&self, ra: f64, dec: f64) -> Result<(f64, f64)> {
+        self.ra_dec_to_pixel(ra, dec)
+    }
+
+    fn to_sky(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        self.pixel_to_ra_dec(x, y)
+    }
+}
+
+/// FITS WCS projection types, by their `CTYPE` code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProjectionKind {
+    /// Gnomonic: great circles are straight lines; less than a hemisphere
+    Tan,
+    /// Orthographic: the sky as seen from outside the sphere; one hemisphere
+    Sin,
+    /// Zenithal equidistant: radius proportional to the angle from the
+    /// centre; the whole sky
+    Arc,
+    /// Stereographic: conformal; all but the point opposite the centre
+    Stg,
+    /// Hammer-Aitoff: equal area; the whole sky in an ellipse
+    Ait,
+}
+
+impl ProjectionKind {
+    /// The three-letter FITS code, as in `CTYPE1 = 'RA---TAN'`.
+    pub fn code(self) -> &'static str {
+        match self {
+            ProjectionKind::Tan => "TAN",
+            ProjectionKind::Sin => "SIN",
+            ProjectionKind::Arc => "ARC",
+            ProjectionKind::Stg => "STG",
+            ProjectionKind::Ait => "AIT",
+        }
+    }
+}
+
+/// A FITS WCS projection of any [`ProjectionKind`].
+///
+/// Parameters are those of [`TangentPlane`]; the projection centre is the
+/// reference point, where the scale is `scale` arcseconds per pixel in every
+/// direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WcsProjection {
+    /// Projection type
+    pub kind: ProjectionKind,
+    /// Reference point RA in degrees
+    pub ra0: f64,
+    /// Reference point Dec in degrees
+    pub dec0: f64,
+    /// Pixel scale at the reference point in arcseconds per pixel
+    pub scale: f64,
+    /// Rotation angle in degrees (0 = North up)
+    pub rotation: f64,
+    /// Reference pixel X coordinate
+    pub crpix1: f64,
+    /// Reference pixel Y coordinate
+    pub crpix2: f64,
+}
+
+impl From<TangentPlane> for WcsProjection {
+    fn from(plane: TangentPlane) -> Self {
+        WcsProjection {
+            kind: ProjectionKind::Tan,
+            ra0: plane.ra0,
+            dec0: plane.dec0,
+            scale: plane.scale,
+            rotation: plane.rotation,
+            crpix1: plane.crpix1,
+            crpix2: plane.crpix2,
+        }
+    }
+}
+
+impl WcsProjection {
+    /// Create a new projection centred on a reference point.
+    ///
+    /// # Arguments
+    /// * `kind` - Projection type
+    /// * `ra0` - Reference RA in degrees (projection center)
+    /// * `dec0` - Reference Dec in degrees (projection center)
+    /// * `scale` - Pixel scale at the center in arcseconds per pixel (must
+    ///   be positive)
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if RA is outside [0, 360) or Dec outside [-90, 90]
+    /// - `AstroError::OutOfRange` if scale is not positive
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::projection::{ProjectionKind, WcsProjection};
+    ///
+    /// // All-sky camera: zenith at the centre of a 3000-pixel frame, 90° to the edge
+    /// let camera = WcsProjection::new(ProjectionKind::Arc, 0.0, 90.0, 90.0 * 3600.0 / 1500.0)
+    ///     .unwrap()
+    ///     .with_reference_pixel(1500.0, 1500.0);
+    /// let (_, y) = camera.ra_dec_to_pixel(180.0, 0.0).unwrap();
+    /// assert!((y - 3000.0).abs() < 1e-6);
+    /// ```
+    pub fn new(kind: ProjectionKind, ra0: f64, dec0: f64, scale: f64) -> Result<Self> {
+        let plane = TangentPlane::new(ra0, dec0, scale)?;
+        Ok(WcsProjection { kind, ..plane.into() })
+    }
+
+    /// Set the reference pixel (usually image center)
+    pub fn with_reference_pixel(mut self, x: f64, y: f64) -> Self {
+        self.crpix1 = x;
+        self.crpix2 = y;
+        self
+    }
+
+    /// Set the rotation angle in degrees
+    pub fn with_rotation(mut self, rotation: f64) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Project RA/Dec coordinates to pixel coordinates.
+    ///
+    /// # Arguments
+    /// * `ra` - Right ascension in degrees
+    /// * `dec` - Declination in degrees
+    ///
+    /// # Returns
+    /// (x, y) pixel coordinates
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+    /// - `AstroError::ProjectionError` if the point is outside the
+    ///   projection: on or beyond the horizon of the centre for TAN, beyond
+    ///   it for SIN, or opposite the centre for ARC and STG
+    pub fn ra_dec_to_pixel(&self, ra: f64, dec: f64) -> Result<(f64, f64)> {
+        validate_ra(ra)?;
+        validate_dec(dec)?;
+
+        // Direction cosines towards the centre, east and north
+        let v = radec_to_unit_vector(ra, dec);
+        let [x, y, z] = frame(self.ra0, self.dec0).map(|axis| axis[0] * v[0] + axis[1] * v[1] + axis[2] * v[2]);
+
+        let outside = |reason: &str| AstroError::ProjectionError {
+            reason: format!("{} ({} projection)", reason, self.kind.code()),
+        };
+        let (xi, eta) = match self.kind {
+            ProjectionKind::Ait => {
+                let (phi, theta) = (y.atan2(x), z.clamp(-1.0, 1.0).asin());
+                let gamma = (2.0 / (1.0 + theta.cos() * (phi / 2.0).cos())).sqrt();
+                (2.0 * gamma * theta.cos() * (phi / 2.0).sin(), gamma * theta.sin())
+            }
+            kind => {
+                let sin_c = y.hypot(z);
+                let c = sin_c.atan2(x);
+                if sin_c < 1e-12 && x < 0.0 {
+                    return Err(outside("Point is opposite the projection center"));
+                }
+                let r = match kind {
+                    ProjectionKind::Tan if x <= 0.0 => {
+                        return Err(outside("Point is on opposite side of sky from projection center"))
+                    }
+                    ProjectionKind::Sin if x < 0.0 => return Err(outside("Point is on the far hemisphere")),
+                    ProjectionKind::Tan => c.tan(),
+                    ProjectionKind::Sin => sin_c,
+                    ProjectionKind::Arc => c,
+                    _ => 2.0 * (c / 2.0).tan(),
+                };
+                if sin_c == 0.0 {
+                    (0.0, 0.0)
+                } else {
+                    (r * y / sin_c, r * z / sin_c)
+                }
+            }
+        };
+
+        let (xi_deg, eta_deg) = (xi.to_degrees(), eta.to_degrees());
+        let (sin_rot, cos_rot) = self.rotation.to_radians().sin_cos();
+        let xi_rot = xi_deg * cos_rot + eta_deg * sin_rot;
+        let eta_rot = -xi_deg * sin_rot + eta_deg * cos_rot;
+
+        // xi increases to the east, x to the west
+        Ok((self.crpix1 - xi_rot * 3600.0 / self.scale, self.crpix2 + eta_rot * 3600.0 / self.scale))
+    }
+
+    /// Inverse projection: pixel to RA/Dec.
+    ///
+    /// # Arguments
+    /// * `x` - X pixel coordinate
+    /// * `y` - Y pixel coordinate
+    ///
+    /// # Returns
+    /// (ra, dec) in degrees
+    ///
+    /// # Errors
+    /// `AstroError::ProjectionError` if the pixel is outside the boundary
+    /// of the projection: the circle of radius 1 radian for SIN, π for ARC,
+    /// or the ellipse of the whole sky for AIT
+    pub fn pixel_to_ra_dec(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        let xi_deg = -(x - self.crpix1) * self.scale / 3600.0;
+        let eta_deg = (y - self.crpix2) * self.scale / 3600.0;
+        let (sin_rot, cos_rot) = self.rotation.to_radians().sin_cos();
+        let xi = (xi_deg * cos_rot - eta_deg * sin_rot).to_radians();
+        let eta = (xi_deg * sin_rot + eta_deg * cos_rot).to_radians();
+
+        let outside = || AstroError::ProjectionError {
+            reason: format!("Pixel ({}, {}) is outside the {} projection", x, y, self.kind.code()),
+        };
+        let native = match self.kind {
+            ProjectionKind::Ait => {
+                let q = 1.0 - (xi / 4.0).powi(2) - (eta / 2.0).powi(2);
+                if q.is_nan() || q < 0.5 {
+                    return Err(outside());
+                }
+                let z = q.sqrt();
+                let phi = 2.0 * (z * xi / 2.0).atan2(2.0 * q - 1.0);
+                let theta = (eta * z).clamp(-1.0, 1.0).asin();
+                [theta.cos() * phi.cos(), theta.cos() * phi.sin(), theta.sin()]
+            }
+            kind => {
+                let r = xi.hypot(eta);
+                let c = match kind {
+                    ProjectionKind::Tan => r.atan(),
+                    ProjectionKind::Sin if r > 1.0 => return Err(outside()),
+                    ProjectionKind::Sin => r.asin(),
+                    ProjectionKind::Arc if r > PI => return Err(outside()),
+                    ProjectionKind::Arc => r,
+                    _ => 2.0 * (r / 2.0).atan(),
+                };
+                if !c.is_finite() {
+                    return Err(outside());
+                }
+                if r == 0.0 {
+                    [1.0, 0.0, 0.0]
+                } else {
+                    [c.cos(), c.sin() * xi / r, c.sin() * eta / r]
+                }
+            }
+        };
+
+        let [centre, east, north] = frame(self.ra0, self.dec0);
+        let v: [f64; 3] =
+            std::array::from_fn(|i| native[0] * centre[i] + native[1] * east[i] + native[2] * north[i]);
+        Ok(unit_vector_to_radec(v))
+    }
+}
+
+impl SkyProjection for WcsProjection {
+    fn to_pixel(&self, ra: f64, dec: f64) -> Result<(f64, f64)> {
+        self.ra_dec_to_pixel(ra, dec)
+    }
+
+    fn to_sky(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        self.pixel_to_ra_dec(x, y)
+    }
+}
+
+/// Unit vectors towards a point, and east and north at the point.
+fn frame(ra_deg: f64, dec_deg: f64) -> [[f64; 3]; 3] {
+    let (ra, dec) = (ra_deg.to_radians(), dec_deg.to_radians());
+    [
+        radec_to_unit_vector(ra_deg, dec_deg),
+        [-ra.sin(), ra.cos(), 0.0],
+        [-dec.sin() * ra.cos(), -dec.sin() * ra.sin(), dec.cos()],
+    ]
+}
+
+/// A [`TangentPlane`] of the refracted sky, taking true positions; see
+/// [`TangentPlane::with_refraction`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RefractedTangentPlane {
+    plane: TangentPlane,
+    conditions: AtmosphericConditions,
+    /// Unit vector towards the zenith
+    zenith: Vec3,
+}
+
+impl RefractedTangentPlane {
+    /// The projection of the refracted sky.
+    pub fn plane(&self) -> &TangentPlane {
+        &self.plane
+    }
+
+    /// Moves `v` by `angle` degrees along the great circle towards the zenith.
+    fn towards_zenith(&self, v: Vec3, angle: f64) -> Vec3 {
+        let along = dot(v, self.zenith);
+        let up = normalize(std::array::from_fn(|i| self.zenith[i] - along * v[i]));
+        let (sin, cos) = angle.to_radians().sin_cos();
+        std::array::from_fn(|i| cos * v[i] + sin * up[i])
+    }
+
+    /// Altitude of a direction in degrees.
+    fn altitude(&self, v: Vec3) -> f64 {
+        dot(v, self.zenith).clamp(-1.0, 1.0).asin().to_degrees()
+    }
+
+    /// Projects a catalogue (true) RA/Dec to the pixel where it appears.
+    ///
+    /// # Arguments
+    /// * `ra` - Right ascension in degrees
+    /// * `dec` - Declination in degrees
+    ///
+    /// # Returns
+    /// (x, y) pixel coordinates
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+    /// - `AstroError::ProjectionError` if point is on opposite side of sky
+    pub fn ra_dec_to_pixel(&self, ra: f64, dec: f64) -> Result<(f64, f64)> {
+        validate_ra(ra)?;
+        validate_dec(dec)?;
+        let v = radec_to_unit_vector(ra, dec);
+        let alt = self.altitude(v);
+        let apparent = true_to_apparent_altitude(alt, self.conditions.pressure_hpa, self.conditions.temperature_c)?;
+        let (ra, dec) = unit_vector_to_radec(self.towards_zenith(v, apparent - alt));
+        self.plane.ra_dec_to_pixel(ra, dec)
+    }
+
+    /// Inverse projection: pixel to catalogue (true) RA/Dec.
+    ///
+    /// # Arguments
+    /// * `x` - X pixel coordinate
+    /// * `y` - Y pixel coordinate
+    ///
+    /// # Returns
+    /// (ra, dec) in degrees
+    pub fn pixel_to_ra_dec(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        let (ra, dec) = self.plane.pixel_to_ra_dec(x, y)?;
+        let v = radec_to_unit_vector(ra, dec);
+        let alt = self.altitude(v);
+        let true_alt = apparent_to_true_altitude(alt, self.conditions.pressure_hpa, self.conditions.temperature_c)?;
+        Ok(unit_vector_to_radec(self.towards_zenith(v, true_alt - alt)))
+    }
+}
+
+impl SkyProjection for RefractedTangentPlane {
+    fn to_pixel(&self, ra: f64, dec: f64) -> Result<(f64, f64)> {
+        self.ra_dec_to_pixel(ra, dec)
+    }
+
+    fn to_sky(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        self.pixel_to_ra_dec(x, y)
+    }
+}
+
+/// SIP polynomial distortion (Shupe et al. 2005), as written by
+/// astrometry.net and SCAMP-to-SIP converters.
+///
+/// Coefficient matrices are indexed `[p][q]` for the term `u^p v^q`, where
+/// `u` and `v` are pixel offsets from the reference pixel; missing entries
+/// are zero. `a` and `b` map measured offsets to undistorted ones,
+/// `u' = u + Σ a[p][q] u^p v^q`, and `ap` and `bp` are the approximate
+/// inverse. When `ap` and `bp` are empty, the inverse is found by iteration.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SipDistortion {
+    /// `A_p_q`: correction to `u`
+    pub a: Vec<Vec<f64>>,
+    /// `B_p_q`: correction to `v`
+    pub b: Vec<Vec<f64>>,
+    /// `AP_p_q`: inverse correction to `u`
+    pub ap: Vec<Vec<f64>>,
+    /// `BP_p_q`: inverse correction to `v`
+    pub bp: Vec<Vec<f64>>,
+}
+
+/// Largest SIP order read from a header
+const MAX_SIP_ORDER: usize = 9;
+
+impl SipDistortion {
+    /// Forward coefficients only; the inverse is found by iteration.
+    pub fn new(a: Vec<Vec<f64>>, b: Vec<Vec<f64>>) -> Self {
+        SipDistortion { a, b, ap: Vec::new(), bp: Vec::new() }
+    }
+
+    /// Reads the `A_`, `B_`, `AP_` and `BP_` keywords of a FITS header,
+    /// such as astrometry.net's `.wcs` output.
+    ///
+    /// The header may be split into lines or be one string of 80-character
+    /// cards. Other keywords are ignored, and `AP_`/`BP_` are optional.
+    ///
+    /// # Errors
+    /// `AstroError::InvalidFormat` if `A_ORDER` or `B_ORDER` is missing or
+    /// above 9, or a coefficient is not a number.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::projection::SipDistortion;
+    ///
+    /// let header = "\
+    /// CTYPE1  = 'RA---TAN-SIP'
+    /// A_ORDER =                    2 / Polynomial order, axis 1
+    /// A_0_2   =   -1.35433043032E-06
+    /// A_1_1   =    5.04216436045E-07
+    /// A_2_0   =    8.72116311342E-07
+    /// B_ORDER =                    2
+    /// B_0_2   =    2.57436713458E-07
+    /// B_2_0   =   -6.12376011812E-07
+    /// ";
+    /// let sip = SipDistortion::from_fits_header(header).unwrap();
+    /// assert_eq!(sip.a[1][1], 5.04216436045E-07);
+    /// assert_eq!(sip.b[0][2], 2.57436713458E-07);
+    /// assert!(sip.ap.is_empty());
+    /// ```
+    pub fn from_fits_header(header: &str) -> Result<Self> {
+        let invalid = |input: &str, reason: String| AstroError::InvalidFormat {
+            format: "FITS SIP header",
+            input: input.trim_end().to_string(),
+            reason,
+        };
+        let cards: Vec<&str> = if header.contains('\n') {
+            header.lines().collect()
+        } else {
+            // A raw header: 80-character cards with no line breaks
+            header.as_bytes().chunks(80).filter_map(|card| std::str::from_utf8(card).ok()).collect()
+        };
+
+        let mut values = Vec::new();
+        for card in cards {
+            let Some((keyword, rest)) = card.split_once('=') else {
+                continue;
+            };
+            let keyword = keyword.trim();
+            let Some(prefix) = ["AP_", "BP_", "A_", "B_"].into_iter().find(|p| keyword.starts_with(p)) else {
+                continue;
+            };
+            let value = rest.split('/').next().unwrap_or("").trim().replace(['D', 'd'], "E");
+            let value: f64 = value.parse().map_err(|_| invalid(card, format!("{} is not a number", keyword)))?;
+            values.push((prefix, keyword[prefix.len()..].to_string(), value, card));
+        }
+
+        let order = |prefix: &str, required: bool| -> Result<Option<usize>> {
+            match values.iter().find(|(p, rest, _, _)| *p == prefix && rest == "ORDER") {
+                Some(&(_, _, order, _)) if order >= 0.0 && order <= MAX_SIP_ORDER as f64 && order.fract() == 0.0 => {
+                    Ok(Some(order as usize))
+                }
+                Some(&(_, _, _, card)) => Err(invalid(card, format!("{}ORDER must be 0 to {}", prefix, MAX_SIP_ORDER))),
+                None if required => Err(invalid("", format!("no {}ORDER keyword", prefix))),
+                None => Ok(None),
+            }
+        };
+        let matrix = |prefix: &str, order: Option<usize>| -> Result<Vec<Vec<f64>>> {
+            let Some(order) = order else {
+                return Ok(Vec::new());
+            };
+            let mut m = vec![vec![0.0; order + 1]; order + 1];
+            for (_, rest, value, card) in values.iter().filter(|(p, rest, _, _)| *p == prefix && rest != "ORDER") {
+                let indices = rest.split_once('_').and_then(|(p, q)| Some((p.parse::<usize>().ok()?, q.parse::<usize>().ok()?)));
+                match indices {
+                    Some((p, q)) if p + q <= order => m[p][q] = *value,
+                    _ => return Err(invalid(card, format!("{}{} is not a term of order {}", prefix, rest, order))),
+                }
+            }
+            Ok(m)
+        };
+
+        Ok(SipDistortion {
+            a: matrix("A_", order("A_", true)?)?,
+            b: matrix("B_", order("B_", true)?)?,
+            ap: matrix("AP_", order("AP_", false)?)?,
+            bp: matrix("BP_", order("BP_", false)?)?,
+        })
+    }
+
+    /// Applies the forward polynomials: measured offsets to undistorted.
+    pub fn undistort(&self, u: f64, v: f64) -> (f64, f64) {
+        (u + polynomial(&self.a, u, v), v + polynomial(&self.b, u, v))
+    }
+
+    /// Inverts [`undistort`](Self::undistort): undistorted offsets to
+    /// measured.
+    ///
+    /// Starts from `ap` and `bp` when present and refines by fixed-point
+    /// iteration to a millionth of a pixel, so the result inverts `a` and
+    /// `b` exactly rather than to the accuracy of the fitted inverse.
+    pub fn distort(&self, u: f64, v: f64) -> (f64, f64) {
+        let (mut du, mut dv) = if self.ap.is_empty() && self.bp.is_empty() {
+            (u, v)
+        } else {
+            (u + polynomial(&self.ap, u, v), v + polynomial(&self.bp, u, v))
+        };
+        for _ in 0..50 {
+            let next = (u - polynomial(&self.a, du, dv), v - polynomial(&self.b, du, dv));
+            let step = (next.0 - du).hypot(next.1 - dv);
+            (du, dv) = next;
+            if step < 1e-6 {
+                break;
+            }
+        }
+        (du, dv)
+    }
+}
+
+/// Evaluates `Σ m[p][q] u^p v^q`.
+fn polynomial(m: &[Vec<f64>], u: f64, v: f64) -> f64 {
+    let mut sum = 0.0;
+    let mut u_p = 1.0;
+    for row in m {
+        let mut v_q = 1.0;
+        for &coefficient in row {
+            sum += coefficient * u_p * v_q;
+            v_q *= v;
+        }
+        u_p *= u;
+    }
+    sum
+}
+
+/// A [`TangentPlane`] with SIP distortion; see
+/// [`TangentPlane::with_distortion`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistortedTangentPlane {
+    /// The linear part of the solution
+    pub plane: TangentPlane,
+    /// The distortion of pixel offsets from the reference pixel
+    pub sip: SipDistortion,
+}
+
+impl DistortedTangentPlane {
+    /// Project RA/Dec coordinates to distorted pixel coordinates.
+    ///
+    /// # Arguments
+    /// * `ra` - Right ascension in degrees
+    /// * `dec` - Declination in degrees
+    ///
+    /// # Returns
+    /// (x, y) pixel coordinates
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+    /// - `AstroError::ProjectionError` if point is on opposite side of sky
+    pub fn ra_dec_to_pixel(&self, ra: f64, dec: f64) -> Result<(f64, f64)> {
+        let (x, y) = self.plane.ra_dec_to_pixel(ra, dec)?;
+        let (u, v) = self.sip.distort(x - self.plane.crpix1, y - self.plane.crpix2);
+        Ok((self.plane.crpix1 + u, self.plane.crpix2 + v))
+    }
+
+    /// Inverse projection: distorted pixel to RA/Dec.
+    ///
+    /// # Arguments
+    /// * `x` - X pixel coordinate
+    /// * `y` - Y pixel coordinate
+    ///
+    /// # Returns
+    /// (ra, dec) in degrees
+    pub fn pixel_to_ra_dec(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        let (u, v) = self.sip.undistort(x - self.plane.crpix1, y - self.plane.crpix2);
+        self.plane.pixel_to_ra_dec(self.plane.crpix1 + u, self.plane.crpix2 + v)
+    }
+}
+
+impl SkyProjection for DistortedTangentPlane {
+    fn to_pixel(&self, ra: f64, dec: f64) -> Result<(f64, f64)> {
+        self.ra_dec_to_pixel(ra, dec)
+    }
+
+    fn to_sky(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        self.pixel_to_ra_dec(x, y)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,4 +1019,197 @@ mod tests {
         let (ra2, _) = tp2.pixel_to_ra_dec(100.0, 512.0).unwrap();
         assert!((0.0..360.0).contains(&ra2));
     }
-}
\ No newline at end of file
+
+    const KINDS: [ProjectionKind; 5] =
+        [ProjectionKind::Tan, ProjectionKind::Sin, ProjectionKind::Arc, ProjectionKind::Stg, ProjectionKind::Ait];
+
+    #[test]
+    fn test_wcs_tan_matches_tangent_plane() {
+        let tp = TangentPlane::new(83.8, -5.4, 2.0).unwrap()
+            .with_reference_pixel(1024.0, 1024.0)
+            .with_rotation(15.0);
+        let wcs = WcsProjection::from(tp);
+        for (ra, dec) in [(84.0, -5.5), (80.0, -1.0), (90.0, -12.0)] {
+            let (x1, y1) = tp.ra_dec_to_pixel(ra, dec).unwrap();
+            let (x2, y2) = wcs.to_pixel(ra, dec).unwrap();
+            assert!((x1 - x2).abs() < 1e-8 && (y1 - y2).abs() < 1e-8);
+            let (ra2, dec2) = wcs.to_sky(x1, y1).unwrap();
+            assert!((ra2 - ra).abs() < 1e-10 && (dec2 - dec).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_wcs_round_trips() {
+        for kind in KINDS {
+            let wcs = WcsProjection::new(kind, 350.0, 60.0, 36.0).unwrap()
+                .with_reference_pixel(500.0, 400.0)
+                .with_rotation(-30.0);
+            // Up to 80° from the centre, across RA 0h and over the pole
+            for (ra, dec) in [(350.0, 60.0), (10.0, 55.0), (170.0, 80.0), (300.0, 0.0), (355.0, -15.0)] {
+                let (x, y) = wcs.ra_dec_to_pixel(ra, dec).unwrap();
+                let (ra2, dec2) = wcs.pixel_to_ra_dec(x, y).unwrap();
+                let dra = ((ra2 - ra + 180.0).rem_euclid(360.0) - 180.0).abs();
+                assert!(dra < 1e-9 && (dec2 - dec).abs() < 1e-9, "{:?} {} {}", kind, ra, dec);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wcs_radial_functions() {
+        // 60° south of a centre on the north pole, 1°/pixel
+        let radius = |kind| {
+            let wcs = WcsProjection::new(kind, 0.0, 90.0, 3600.0).unwrap();
+            let (x, y) = wcs.ra_dec_to_pixel(0.0, 30.0).unwrap();
+            x.hypot(y)
+        };
+        let c = 60f64.to_radians();
+        assert!((radius(ProjectionKind::Tan) - c.tan().to_degrees()).abs() < 1e-9);
+        assert!((radius(ProjectionKind::Sin) - c.sin().to_degrees()).abs() < 1e-9);
+        assert!((radius(ProjectionKind::Arc) - 60.0).abs() < 1e-9);
+        assert!((radius(ProjectionKind::Stg) - (2.0 * (c / 2.0).tan()).to_degrees()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aitoff_whole_sky() {
+        let ait = WcsProjection::new(ProjectionKind::Ait, 0.0, 0.0, 3600.0).unwrap();
+        // Equator to ±180° at x = ∓2√2 rad, poles at y = ±√2 rad
+        let (x, y) = ait.ra_dec_to_pixel(180.0 - 1e-9, 0.0).unwrap();
+        assert!((x + 8f64.sqrt().to_degrees()).abs() < 1e-6 && y.abs() < 1e-9);
+        let (x, y) = ait.ra_dec_to_pixel(0.0, 90.0).unwrap();
+        assert!(x.abs() < 1e-9 && (y - 2f64.sqrt().to_degrees()).abs() < 1e-9);
+        for (ra, dec) in [(150.0, 40.0), (200.0, -70.0), (90.0, 0.0)] {
+            let (x, y) = ait.ra_dec_to_pixel(ra, dec).unwrap();
+            let (ra2, dec2) = ait.pixel_to_ra_dec(x, y).unwrap();
+            assert!((ra2 - ra).abs() < 1e-9 && (dec2 - dec).abs() < 1e-9);
+        }
+        // Beyond the ellipse
+        assert!(matches!(ait.pixel_to_ra_dec(0.0, 85.0), Err(AstroError::ProjectionError { .. })));
+    }
+
+    #[test]
+    fn test_wcs_boundaries() {
+        let at = |kind| WcsProjection::new(kind, 0.0, 0.0, 3600.0).unwrap();
+        // Far hemisphere
+        assert!(at(ProjectionKind::Tan).ra_dec_to_pixel(120.0, 0.0).is_err());
+        assert!(at(ProjectionKind::Sin).ra_dec_to_pixel(120.0, 0.0).is_err());
+        assert!(at(ProjectionKind::Arc).ra_dec_to_pixel(120.0, 0.0).is_ok());
+        assert!(at(ProjectionKind::Stg).ra_dec_to_pixel(120.0, 0.0).is_ok());
+        // Opposite the centre
+        for kind in [ProjectionKind::Arc, ProjectionKind::Stg] {
+            assert!(matches!(at(kind).ra_dec_to_pixel(180.0, 0.0), Err(AstroError::ProjectionError { .. })));
+        }
+        // Outside the projected disc
+        assert!(at(ProjectionKind::Sin).pixel_to_ra_dec(-58.0, 0.0).is_err());
+        assert!(at(ProjectionKind::Arc).pixel_to_ra_dec(-181.0, 0.0).is_err());
+        assert!(at(ProjectionKind::Stg).pixel_to_ra_dec(-10_000.0, 0.0).is_ok());
+        assert!(WcsProjection::new(ProjectionKind::Sin, 0.0, 0.0, 0.0).is_err());
+        assert_eq!(ProjectionKind::Ait.code(), "AIT");
+    }
+
+    #[test]
+    fn test_refraction_lifts_towards_the_zenith() {
+        use crate::location::Location;
+        use crate::refraction::AtmosphericConditions;
+        use crate::transforms::{alt_az_rates, ra_dec_to_alt_az};
+        use chrono::{TimeZone, Utc};
+
+        let site = Location { latitude_deg: -30.24, longitude_deg: -70.74, altitude_m: 2700.0 };
+        let dt = Utc.with_ymd_and_hms(2024, 3, 1, 1, 0, 0).unwrap();
+        let conditions = AtmosphericConditions { pressure_hpa: 740.0, temperature_c: 12.0, relative_humidity: 0.2 };
+        let (ra0, dec0) = (30.0, -10.0);
+        let center = alt_az_rates(ra0, dec0, dt, &site).unwrap();
+        assert!(center.alt > 10.0 && center.alt < 30.0, "{}", center.alt);
+
+        let plane = TangentPlane::new(ra0, dec0, 1.0).unwrap().with_reference_pixel(500.0, 500.0).with_rotation(40.0);
+        let refracted = plane.with_refraction(&conditions, &center).unwrap();
+        for (ra, dec) in [(ra0, dec0), (31.0, -10.5), (29.2, -9.3), (30.4, -11.0)] {
+            // Apparent place: same azimuth, altitude raised by the refraction
+            let (x, y) = refracted.to_pixel(ra, dec).unwrap();
+            let (ra_app, dec_app) = plane.pixel_to_ra_dec(x, y).unwrap();
+            let (alt, az) = ra_dec_to_alt_az(ra, dec, dt, &site).unwrap();
+            let (alt_app, az_app) = ra_dec_to_alt_az(ra_app, dec_app, dt, &site).unwrap();
+            let expected = crate::refraction::true_to_apparent_altitude(alt, 740.0, 12.0).unwrap();
+            assert!((alt_app - expected).abs() * 3600.0 < 0.1, "{} {}", alt_app, expected);
+            assert!((az_app - az).abs() * 3600.0 * alt.to_radians().cos() < 0.1);
+
+            let (ra_back, dec_back) = refracted.to_sky(x, y).unwrap();
+            assert!((ra_back - ra).abs() < 1e-9 && (dec_back - dec).abs() < 1e-9);
+        }
+        assert_eq!(refracted.plane(), &plane);
+
+        let bad = AtmosphericConditions { pressure_hpa: -1.0, ..conditions };
+        assert!(matches!(plane.with_refraction(&bad, &center), Err(AstroError::OutOfRange { .. })));
+    }
+
+    fn sip_example() -> SipDistortion {
+        // Quadratic and cubic terms of a few pixels over ±1000 pixels
+        SipDistortion::new(
+            vec![vec![0.0, 0.0, 2e-6], vec![0.0, -1e-6], vec![3e-6], vec![1e-9]],
+            vec![vec![0.0, 0.0, -1e-6, 1e-9], vec![0.0, 2e-6], vec![1.5e-6]],
+        )
+    }
+
+    #[test]
+    fn test_sip_distort_inverts_undistort() {
+        let sip = sip_example();
+        for (u, v) in [(0.0, 0.0), (1000.0, -1000.0), (-700.0, 300.0), (20.0, 950.0)] {
+            let (du, dv) = sip.undistort(u, v);
+            assert!((du - u).hypot(dv - v) > 0.0 || (u, v) == (0.0, 0.0));
+            let (u2, v2) = sip.distort(du, dv);
+            assert!((u2 - u).abs() < 1e-5 && (v2 - v).abs() < 1e-5, "{} {} {} {}", u, v, u2, v2);
+        }
+        assert_eq!(SipDistortion::default().undistort(12.0, -5.0), (12.0, -5.0));
+    }
+
+    #[test]
+    fn test_distorted_plane_round_trip() {
+        let plane = TangentPlane::new(210.0, -45.0, 2.0).unwrap()
+            .with_reference_pixel(1000.0, 1000.0)
+            .with_rotation(-70.0);
+        let distorted = plane.with_distortion(sip_example());
+        for (x, y) in [(1000.0, 1000.0), (0.0, 0.0), (2000.0, 150.0), (400.0, 1900.0)] {
+            let (ra, dec) = distorted.pixel_to_ra_dec(x, y).unwrap();
+            let (u, v) = distorted.sip.undistort(x - 1000.0, y - 1000.0);
+            assert_eq!((ra, dec), plane.pixel_to_ra_dec(1000.0 + u, 1000.0 + v).unwrap());
+            // Back to the same pixel, far below a milliarcsecond
+            let (x2, y2) = distorted.to_pixel(ra, dec).unwrap();
+            assert!((x2 - x).hypot(y2 - y) * 2.0 < 1e-4);
+        }
+        // Without distortion it is the plane itself
+        let flat = plane.with_distortion(SipDistortion::default());
+        assert_eq!(flat.to_sky(10.0, 20.0).unwrap(), plane.pixel_to_ra_dec(10.0, 20.0).unwrap());
+    }
+
+    #[test]
+    fn test_sip_from_raw_fits_header() {
+        let card = |text: &str| format!("{:<80}", text);
+        let header: String = [
+            "SIMPLE  =                    T",
+            "A_ORDER =                    2",
+            "A_2_0   =              1.0D-06 / Fortran exponent",
+            "B_ORDER =                    2",
+            "B_1_1   =   -2.0E-06",
+            "AP_ORDER=                    2",
+            "AP_2_0  =   -1.0E-06",
+            "BP_ORDER=                    2",
+            "COMMENT = not a SIP card",
+            "END",
+        ]
+        .into_iter()
+        .map(card)
+        .collect();
+        let sip = SipDistortion::from_fits_header(&header).unwrap();
+        assert_eq!(sip.a[2][0], 1e-6);
+        assert_eq!(sip.b[1][1], -2e-6);
+        assert_eq!(sip.ap[2][0], -1e-6);
+        assert_eq!(sip.bp, vec![vec![0.0; 3]; 3]);
+
+        let invalid = |text: &str| {
+            matches!(SipDistortion::from_fits_header(text), Err(AstroError::InvalidFormat { format: "FITS SIP header", .. }))
+        };
+        assert!(invalid("B_ORDER = 2\n"));
+        assert!(invalid("A_ORDER = 2\nB_ORDER = 2\nA_1_1 = abc\n"));
+        assert!(invalid("A_ORDER = 2\nB_ORDER = 2\nA_3_0 = 1e-9\n"));
+        assert!(invalid("A_ORDER = 12\nB_ORDER = 2\n"));
+    }
+}
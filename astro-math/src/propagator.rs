@@ -0,0 +1,418 @@
+//! Numerical orbit propagation with planetary perturbations.
+//!
+//! Two-body motion ([`OrbitalElements::state_at`](crate::orbit::OrbitalElements::state_at))
+//! drifts by thousands of kilometres per year for near-Earth objects. This
+//! module integrates the heliocentric equations of motion numerically, adding
+//! point-mass perturbations from the major planets.
+//!
+//! # Method
+//!
+//! - Adaptive Dormand–Prince 5(4) Runge–Kutta integrator
+//! - Planet positions from ERFA's `plan94` (Simon et al. 1994), with Earth and
+//!   Moon treated as a single body at their barycentre
+//! - The indirect term from the Sun's acceleration by the planets is included,
+//!   so states remain heliocentric throughout
+//!
+//! `plan94` is accurate to a few arcseconds for the inner planets between
+//! 1800 and 2050, which is ample for perturbation forces but means this is not
+//! a replacement for a full JPL DE integration.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::orbit::OrbitalElements;
+//! use astro_math::propagator::NBodyPropagator;
+//!
+//! let elements = OrbitalElements {
+//!     epoch_jd_tdb: 2460600.5,
+//!     semi_major_axis_au: 1.458,
+//!     eccentricity: 0.2227,
+//!     inclination_deg: 10.83,
+//!     ascending_node_deg: 304.3,
+//!     argument_of_perihelion_deg: 178.9,
+//!     mean_anomaly_deg: 310.5,
+//! };
+//!
+//! let start = elements.to_state_vector().unwrap();
+//! let propagator = NBodyPropagator::new();
+//! let samples = propagator.sample(&start, 2460600.5, 2460660.5, 10.0).unwrap();
+//! assert_eq!(samples.len(), 7);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` for non-positive sampling steps or tolerances
+//! - `AstroError::CalculationError` if the planetary ephemeris fails or the
+//!   integrator cannot meet the tolerance (e.g. a collision course)
+
+use crate::error::{AstroError, Result};
+use crate::orbit::{StateVector, GM_SUN};
+use crate::vec3::norm;
+
+/// Major planets available as perturbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Planet {
+    Mercury,
+    Venus,
+    /// Earth–Moon barycentre
+    EarthMoon,
+    Mars,
+    Jupiter,
+    Saturn,
+    Uranus,
+    Neptune,
+}
+
+impl Planet {
+    /// All eight perturbing bodies, in order from the Sun.
+    pub const ALL: [Planet; 8] = [
+        Planet::Mercury,
+        Planet::Venus,
+        Planet::EarthMoon,
+        Planet::Mars,
+        Planet::Jupiter,
+        Planet::Saturn,
+        Planet::Uranus,
+        Planet::Neptune,
+    ];
+
+    /// Sun/planet mass ratio (IAU 2009 / DE430 system values).
+    pub fn inverse_mass(&self) -> f64 {
+        match self {
+            Planet::Mercury => 6_023_597.4,
+            Planet::Venus => 408_523.72,
+            Planet::EarthMoon => 328_900.56,
+            Planet::Mars => 3_098_703.6,
+            Planet::Jupiter => 1047.3486,
+            Planet::Saturn => 3497.9018,
+            Planet::Uranus => 22_902.98,
+            Planet::Neptune => 19_412.26,
+        }
+    }
+
+    /// Gravitational parameter GM in AU³/day².
+    pub fn gm(&self) -> f64 {
+        GM_SUN / self.inverse_mass()
+    }
+
+    /// Heliocentric state vector of the planet (ICRS axes).
+    ///
+    /// # Arguments
+    /// * `jd_tdb` - TDB Julian Date
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::CalculationError)` if ERFA rejects the request.
+    pub fn heliocentric_state(&self, jd_tdb: f64) -> Result<StateVector> {
+        let index = match self {
+            Planet::Mercury => 1,
+            Planet::Venus => 2,
+            Planet::EarthMoon => 3,
+            Planet::Mars => 4,
+            Planet::Jupiter => 5,
+            Planet::Saturn => 6,
+            Planet::Uranus => 7,
+            Planet::Neptune => 8,
+        };
+        let pv = crate::backend::ephemerides::Plan94(jd_tdb, 0.0, index).map_err(|e| {
+            AstroError::CalculationError {
+                calculation: "planetary ephemeris",
+                reason: format!("{:?}", e),
+            }
+        })?;
+        Ok(StateVector {
+            jd_tdb,
+            position: [pv[0], pv[1], pv[2]],
+            velocity: [pv[3], pv[4], pv[5]],
+        })
+    }
+}
+
+/// Adaptive Runge–Kutta propagator for heliocentric orbits with planetary
+/// perturbations.
+///
+/// Build with [`NBodyPropagator::new`] and customize with the `with_*` methods.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NBodyPropagator {
+    /// Planets whose attraction is included
+    pub perturbers: Vec<Planet>,
+    /// Local error tolerance per step (relative to state magnitude)
+    pub tolerance: f64,
+    /// Upper limit on the step size in days
+    pub max_step_days: f64,
+}
+
+impl Default for NBodyPropagator {
+    fn default() -> Self {
+        NBodyPropagator::new()
+    }
+}
+
+/// Step limit per call, guarding against runaway step-size collapse.
+const MAX_STEPS: usize = 1_000_000;
+
+impl NBodyPropagator {
+    /// Creates a propagator with all eight planets, tolerance 1e-12 and a
+    /// 10-day maximum step.
+    pub fn new() -> Self {
+        NBodyPropagator {
+            perturbers: Planet::ALL.to_vec(),
+            tolerance: 1e-12,
+            max_step_days: 10.0,
+        }
+    }
+
+    /// Creates a propagator with no perturbers (pure two-body motion).
+    pub fn two_body() -> Self {
+        NBodyPropagator::new().with_perturbers(&[])
+    }
+
+    /// Sets the perturbing planets.
+    pub fn with_perturbers(mut self, perturbers: &[Planet]) -> Self {
+        self.perturbers = perturbers.to_vec();
+        self
+    }
+
+    /// Sets the per-step error tolerance.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the maximum step size in days.
+    pub fn with_max_step(mut self, max_step_days: f64) -> Self {
+        self.max_step_days = max_step_days;
+        self
+    }
+
+    /// Heliocentric acceleration (AU/day²) at a position and time.
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::CalculationError)` if a planet ephemeris fails.
+    pub fn acceleration(&self, jd_tdb: f64, position: &[f64; 3]) -> Result<[f64; 3]> {
+        let r = norm(*position);
+        let k = -GM_SUN / (r * r * r);
+        let mut acc = [k * position[0], k * position[1], k * position[2]];
+
+        for planet in &self.perturbers {
+            let p = planet.heliocentric_state(jd_tdb)?.position;
+            let d = [p[0] - position[0], p[1] - position[1], p[2] - position[2]];
+            let d3 = norm(d).powi(3);
+            let p3 = norm(p).powi(3);
+            let gm = planet.gm();
+            for i in 0..3 {
+                // Direct attraction minus the planet's pull on the Sun
+                acc[i] += gm * (d[i] / d3 - p[i] / p3);
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Propagates a heliocentric state to `jd_tdb`.
+    ///
+    /// Propagation may run forwards or backwards in time.
+    ///
+    /// # Arguments
+    /// * `initial` - Starting heliocentric state (ICRS axes)
+    /// * `jd_tdb` - Target TDB Julian Date
+    ///
+    /// # Returns
+    /// State vector at `jd_tdb`
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if the tolerance or maximum step is
+    /// not positive, or `Err(AstroError::CalculationError)` if the integration
+    /// fails.
+    pub fn propagate(&self, initial: &StateVector, jd_tdb: f64) -> Result<StateVector> {
+        self.validate()?;
+        let mut state = *initial;
+        let mut h = (jd_tdb - initial.jd_tdb).clamp(-1.0, 1.0);
+        self.integrate(&mut state, jd_tdb, &mut h)?;
+        Ok(state)
+    }
+
+    /// Propagates a state and samples it at a uniform step.
+    ///
+    /// The output starts at `start_jd` and includes every `start_jd + k·step`
+    /// up to and including `end_jd` (the final sample is clamped to `end_jd`).
+    /// `end_jd` may be earlier than `start_jd` for backward sampling.
+    ///
+    /// # Arguments
+    /// * `initial` - Starting heliocentric state (ICRS axes)
+    /// * `start_jd`, `end_jd` - Sampling interval as TDB Julian Dates
+    /// * `step_days` - Sample spacing in days (positive)
+    ///
+    /// # Returns
+    /// Vector of state vectors in time order of propagation
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if `step_days` is not positive, or
+    /// any error from [`propagate`](Self::propagate).
+    pub fn sample(
+        &self,
+        initial: &StateVector,
+        start_jd: f64,
+        end_jd: f64,
+        step_days: f64,
+    ) -> Result<Vec<StateVector>> {
+        if !(step_days > 0.0 && step_days.is_finite()) {
+            return Err(AstroError::OutOfRange {
+                parameter: "step_days",
+                value: step_days,
+                min: f64::MIN_POSITIVE,
+                max: f64::MAX,
+            });
+        }
+        self.validate()?;
+
+        let direction = if end_jd >= start_jd { 1.0 } else { -1.0 };
+        let count = ((end_jd - start_jd).abs() / step_days + 1e-9).floor() as usize;
+
+        let mut state = *initial;
+        let mut h = direction * step_days.min(1.0);
+        self.integrate(&mut state, start_jd, &mut h)?;
+
+        let mut samples = Vec::with_capacity(count + 2);
+        samples.push(state);
+        for k in 1..=count {
+            let target = start_jd + direction * k as f64 * step_days;
+            self.integrate(&mut state, target, &mut h)?;
+            samples.push(state);
+        }
+        if (state.jd_tdb - end_jd).abs() > 1e-9 {
+            self.integrate(&mut state, end_jd, &mut h)?;
+            samples.push(state);
+        }
+        Ok(samples)
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (parameter, value) in [("tolerance", self.tolerance), ("max_step_days", self.max_step_days)] {
+            if !(value > 0.0 && value.is_finite()) {
+                return Err(AstroError::OutOfRange {
+                    parameter,
+                    value,
+                    min: f64::MIN_POSITIVE,
+                    max: f64::MAX,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn derivative(&self, t: f64, y: &[f64; 6]) -> Result<[f64; 6]> {
+        let a = self.acceleration(t, &[y[0], y[1], y[2]])?;
+        Ok([y[3], y[4], y[5], a[0], a[1], a[2]])
+    }
+
+    /// Integrates `state` in place to `target`, carrying the step size `h`
+    /// between calls.
+    fn integrate(&self, state: &mut StateVector, target: f64, h: &mut f64) -> Result<()> {
+        // Dormand–Prince 5(4) tableau
+        const C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+        const A: [[f64; 6]; 7] = [
+            [0.0; 6],
+            [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+            [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+            [19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0, 0.0, 0.0],
+            [9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0, 0.0],
+            [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0],
+        ];
+        // Fifth-order weights (equal to the last row of A) and the differences
+        // to the embedded fourth-order solution
+        const B: [f64; 7] = [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0, 0.0];
+        const E: [f64; 7] = [
+            71.0 / 57600.0,
+            0.0,
+            -71.0 / 16695.0,
+            71.0 / 1920.0,
+            -17253.0 / 339200.0,
+            22.0 / 525.0,
+            -1.0 / 40.0,
+        ];
+
+        let mut t = state.jd_tdb;
+        let mut y = [
+            state.position[0],
+            state.position[1],
+            state.position[2],
+            state.velocity[0],
+            state.velocity[1],
+            state.velocity[2],
+        ];
+
+        let direction = if target >= t { 1.0 } else { -1.0 };
+        if *h == 0.0 || h.signum() != direction {
+            *h = direction * self.max_step_days.min(1.0);
+        }
+
+        let mut steps = 0;
+        while (target - t) * direction > 1e-12 {
+            steps += 1;
+            if steps > MAX_STEPS {
+                return Err(AstroError::CalculationError {
+                    calculation: "orbit propagation",
+                    reason: "maximum number of integration steps exceeded".to_string(),
+                });
+            }
+
+            let h_max = self.max_step_days.min((target - t).abs());
+            let step = direction * h.abs().min(h_max);
+
+            let mut k = [[0.0; 6]; 7];
+            k[0] = self.derivative(t, &y)?;
+            for stage in 1..7 {
+                let mut ys = y;
+                for (i, value) in ys.iter_mut().enumerate() {
+                    for (j, kj) in k.iter().enumerate().take(stage) {
+                        *value += step * A[stage][j] * kj[i];
+                    }
+                }
+                k[stage] = self.derivative(t + C[stage] * step, &ys)?;
+            }
+
+            let mut y_new = y;
+            let mut err: f64 = 0.0;
+            for i in 0..6 {
+                let mut increment = 0.0;
+                let mut error = 0.0;
+                for j in 0..7 {
+                    increment += B[j] * k[j][i];
+                    error += E[j] * k[j][i];
+                }
+                y_new[i] += step * increment;
+                let scale = self.tolerance * (1.0 + y[i].abs().max(y_new[i].abs()));
+                err = err.max((step * error).abs() / scale);
+            }
+
+            if !err.is_finite() {
+                return Err(AstroError::CalculationError {
+                    calculation: "orbit propagation",
+                    reason: "non-finite state during integration".to_string(),
+                });
+            }
+
+            if err <= 1.0 {
+                t += step;
+                y = y_new;
+            }
+
+            // Standard step-size controller with safety factor
+            let factor = if err == 0.0 { 5.0 } else { (0.9 * err.powf(-0.2)).clamp(0.2, 5.0) };
+            *h = step * factor;
+            if h.abs() < 1e-10 {
+                return Err(AstroError::CalculationError {
+                    calculation: "orbit propagation",
+                    reason: "step size underflow (close encounter or collision)".to_string(),
+                });
+            }
+        }
+
+        *state = StateVector {
+            jd_tdb: target,
+            position: [y[0], y[1], y[2]],
+            velocity: [y[3], y[4], y[5]],
+        };
+        Ok(())
+    }
+}
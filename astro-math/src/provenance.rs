@@ -0,0 +1,277 @@
+//! Provenance of a reduction: the models and corrections behind a result.
+//!
+//! A position is only reproducible if the way it was computed is known:
+//! which precession-nutation model, whether refraction was applied and for
+//! what weather, where UT1 and polar motion came from and how current the
+//! leap-second table was. The `_with_provenance` variants of the high-level
+//! transforms return a [`Provenance`] alongside the result recording
+//! exactly that, for pipelines to store with their output:
+//!
+//! - [`observed_place_with_provenance`](crate::observer::observed_place_with_provenance)
+//! - [`ra_dec_to_alt_az_erfa_with_provenance`](crate::transforms::ra_dec_to_alt_az_erfa_with_provenance)
+//!
+//! The [`Display`](fmt::Display) form is one `key: value` line per item,
+//! suitable for a log or FITS `HISTORY` cards.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::provenance::Correction;
+//! use astro_math::refraction::AtmosphericConditions;
+//! use astro_math::transforms::ra_dec_to_alt_az_erfa_with_provenance;
+//! use astro_math::Location;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+//! let site = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+//! let weather = AtmosphericConditions { pressure_hpa: 780.0, temperature_c: 12.0, relative_humidity: 0.2 };
+//!
+//! let (_alt, _az, provenance) =
+//!     ra_dec_to_alt_az_erfa_with_provenance(279.2347, 38.7837, dt, &site, Some(weather), None).unwrap();
+//! assert!(provenance.applied(Correction::Refraction));
+//! assert!(!provenance.applied(Correction::PolarMotion));
+//! println!("{provenance}");
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::OutOfRange` from [`EopSource::from_table`] for a time the
+//!   table does not cover
+
+use crate::eop::{datetime_to_mjd, EarthOrientation, EopTable};
+use crate::error::Result;
+use crate::refraction::AtmosphericConditions;
+use crate::time_scales::{leap_second_table_expires, tai_utc_offset_for_datetime};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::fmt;
+
+/// One step of an astrometric reduction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Correction {
+    /// ICRS to mean J2000 frame bias
+    FrameBias,
+    /// Precession of the equator and ecliptic
+    Precession,
+    /// Nutation
+    Nutation,
+    /// Gravitational light deflection by the Sun
+    LightDeflection,
+    /// Aberration from the Earth's orbital motion
+    AnnualAberration,
+    /// Aberration from the observer's motion with the Earth's rotation
+    DiurnalAberration,
+    /// Aberration from a space platform's motion about the geocentre
+    PlatformAberration,
+    /// Parallax of a solar-system target from the observer's position
+    Parallax,
+    /// Earth rotation angle, from UT1
+    EarthRotation,
+    /// Polar motion
+    PolarMotion,
+    /// Celestial pole offsets dX, dY
+    CelestialPoleOffsets,
+    /// Atmospheric refraction
+    Refraction,
+}
+
+impl Correction {
+    /// Human-readable name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Correction::FrameBias => "frame bias",
+            Correction::Precession => "precession",
+            Correction::Nutation => "nutation",
+            Correction::LightDeflection => "light deflection",
+            Correction::AnnualAberration => "annual aberration",
+            Correction::DiurnalAberration => "diurnal aberration",
+            Correction::PlatformAberration => "platform aberration",
+            Correction::Parallax => "parallax",
+            Correction::EarthRotation => "Earth rotation",
+            Correction::PolarMotion => "polar motion",
+            Correction::CelestialPoleOffsets => "celestial pole offsets",
+            Correction::Refraction => "refraction",
+        }
+    }
+}
+
+impl fmt::Display for Correction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Refraction as applied to a result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RefractionApplied {
+    /// Refraction model
+    pub model: &'static str,
+    /// Weather at the observer
+    pub conditions: AtmosphericConditions,
+    /// Effective wavelength in micrometers
+    pub wavelength_um: f64,
+}
+
+/// Where the Earth orientation parameters behind a result came from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EopSource {
+    /// None used: UT1 = UTC, no polar motion and the IAU 2006/2000A pole,
+    /// good to a few arcseconds
+    Zero,
+    /// Values passed in by the caller
+    Supplied(EarthOrientation),
+    /// Values looked up in an [`EopTable`]
+    Table {
+        /// The values used
+        values: EarthOrientation,
+        /// MJD of the first and last table entries
+        span_mjd: (f64, f64),
+        /// Whether the time was past the end of the table, so that DUT1
+        /// was predicted rather than interpolated
+        extrapolated: bool,
+    },
+}
+
+impl EopSource {
+    /// Looks up the Earth orientation parameters for a time in a table,
+    /// recording the table's coverage.
+    ///
+    /// # Arguments
+    /// * `table` - IERS Earth orientation data
+    /// * `datetime` - Time of the observation
+    ///
+    /// # Returns
+    /// [`EopSource::Table`] with the interpolated or predicted values
+    ///
+    /// # Errors
+    /// As [`EopTable::earth_orientation`].
+    pub fn from_table(table: &EopTable, datetime: DateTime<Utc>) -> Result<Self> {
+        let mjd = datetime_to_mjd(datetime);
+        let span_mjd = table.span();
+        Ok(EopSource::Table { values: table.earth_orientation(mjd)?, span_mjd, extrapolated: mjd > span_mjd.1 })
+    }
+
+    /// The values used, zero for [`EopSource::Zero`].
+    pub fn values(&self) -> EarthOrientation {
+        match *self {
+            EopSource::Zero => EarthOrientation::default(),
+            EopSource::Supplied(values) | EopSource::Table { values, .. } => values,
+        }
+    }
+}
+
+impl fmt::Display for EopSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values = self.values();
+        match *self {
+            EopSource::Zero => return f.write_str("none (UT1 = UTC, no polar motion)"),
+            EopSource::Supplied(_) => f.write_str("supplied")?,
+            EopSource::Table { span_mjd, extrapolated, .. } => write!(
+                f,
+                "table MJD {:.1}-{:.1}{}",
+                span_mjd.0,
+                span_mjd.1,
+                if extrapolated { " (extrapolated)" } else { "" }
+            )?,
+        }
+        write!(
+            f,
+            ", DUT1 {:+.4} s, xp {:.4}\", yp {:.4}\"",
+            values.dut1, values.xp_arcsec, values.yp_arcsec
+        )
+    }
+}
+
+/// Models and corrections behind one result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Provenance {
+    /// Time of the observation (UTC)
+    pub utc: DateTime<Utc>,
+    /// Crate version
+    pub version: &'static str,
+    /// Astrometry backend in use, `"erfa"` or `"pure-rust"`
+    pub backend: &'static str,
+    /// Precession-nutation model
+    pub precession_nutation: &'static str,
+    /// Routine that produced the result, e.g. `"ERFA Atco13"`
+    pub method: &'static str,
+    /// Corrections applied, in the order of the reduction
+    pub corrections: Vec<Correction>,
+    /// Refraction, if applied
+    pub refraction: Option<RefractionApplied>,
+    /// Earth orientation parameters; `None` where the Earth's orientation
+    /// does not enter (a space observer)
+    pub eop: Option<EopSource>,
+    /// TAI − UTC in seconds at `utc`
+    pub tai_utc_s: f64,
+    /// Expiry of the leap-second table, identifying its version (see
+    /// [`leap_second_table_expires`])
+    pub leap_second_table_expires: NaiveDate,
+}
+
+impl Provenance {
+    /// Provenance for a result from `method`, filling in the build and
+    /// leap-second details.
+    pub(crate) fn new(
+        utc: DateTime<Utc>,
+        method: &'static str,
+        corrections: Vec<Correction>,
+        refraction: Option<RefractionApplied>,
+        eop: Option<EopSource>,
+    ) -> Self {
+        Self {
+            utc,
+            version: env!("CARGO_PKG_VERSION"),
+            backend: crate::backend::NAME,
+            precession_nutation: crate::backend::PRECESSION_NUTATION,
+            method,
+            corrections,
+            refraction,
+            eop,
+            tai_utc_s: tai_utc_offset_for_datetime(utc),
+            leap_second_table_expires: leap_second_table_expires(),
+        }
+    }
+
+    /// Whether a correction was applied.
+    pub fn applied(&self, correction: Correction) -> bool {
+        self.corrections.contains(&correction)
+    }
+
+    /// Whether the observation is after the leap-second table's expiry, so
+    /// that an unannounced leap second could make UTC-based times a second out.
+    pub fn leap_seconds_may_be_stale(&self) -> bool {
+        self.utc.date_naive() > self.leap_second_table_expires
+    }
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "time: {}", self.utc.format("%Y-%m-%dT%H:%M:%S%.3f UTC"))?;
+        writeln!(f, "software: astro-math {} ({} backend)", self.version, self.backend)?;
+        writeln!(f, "method: {}", self.method)?;
+        writeln!(f, "precession-nutation: {}", self.precession_nutation)?;
+        let corrections: Vec<_> = self.corrections.iter().map(|c| c.name()).collect();
+        writeln!(f, "corrections: {}", corrections.join(", "))?;
+        match &self.refraction {
+            Some(r) => writeln!(
+                f,
+                "refraction: {}, {:.2} hPa, {:.1} C, {:.0}% RH, {} um",
+                r.model,
+                r.conditions.pressure_hpa,
+                r.conditions.temperature_c,
+                r.conditions.relative_humidity * 100.0,
+                r.wavelength_um
+            )?,
+            None => writeln!(f, "refraction: none")?,
+        }
+        match &self.eop {
+            Some(eop) => writeln!(f, "earth orientation: {}", eop)?,
+            None => writeln!(f, "earth orientation: not applicable")?,
+        }
+        writeln!(
+            f,
+            "leap seconds: TAI-UTC {} s, table valid to {}",
+            self.tai_utc_s, self.leap_second_table_expires
+        )
+    }
+}
@@ -0,0 +1,269 @@
+//! Radial velocity frame corrections: barycentric and Local Standard of Rest.
+//!
+//! Observed (topocentric) radial velocities include the observer's motion
+//! from Earth's rotation and orbit. Radio spectral-line work (HI, OH and
+//! water masers, CO) conventionally reports velocities relative to the
+//! Local Standard of Rest (LSR), which additionally removes the Sun's
+//! peculiar motion with respect to nearby stars.
+//!
+//! # Overview
+//!
+//! ```text
+//! v_bary = v_topo + barycentric_correction
+//! v_lsr  = v_bary + lsr_correction
+//!        = v_topo + vlsr_correction
+//! ```
+//!
+//! All velocities are in km/s, positive for recession.
+//!
+//! # Solar Motion
+//!
+//! The Sun's motion relative to the LSR is configurable through
+//! [`SolarMotion`]:
+//!
+//! - [`SolarMotion::KINEMATIC`] — the "standard" radio LSR: 20 km/s toward
+//!   RA 18h, Dec +30° (B1900), used by most radio observatories
+//! - [`SolarMotion::schoenrich_2010`] — the dynamical LSR of Schönrich,
+//!   Binney & Dehnen (2010): (U, V, W) = (11.1, 12.24, 7.25) km/s
+//!
+//! # Accuracy
+//!
+//! The Earth's barycentric velocity comes from ERFA's `epv00` (a few mm/s);
+//! Earth rotation uses the observer's geocentric distance from the spin axis.
+//! Relativistic terms (~m/s level) are neglected, which is well below the
+//! channel width of typical radio spectra.
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+
+use crate::earth::earth_barycentric_velocity;
+use crate::error::{validate_dec, validate_ra, Result};
+use crate::galactic::galactic_to_equatorial;
+use crate::time::julian_date;
+use crate::time_scales::utc_to_tt_jd;
+use crate::vec3::radec_to_unit_vector;
+use crate::Location;
+use chrono::{DateTime, Utc};
+
+/// Earth's equatorial radius in kilometers
+const EARTH_RADIUS_KM: f64 = 6378.137;
+
+/// Earth's flattening factor
+const EARTH_FLATTENING: f64 = 1.0 / 298.257223563;
+
+/// Earth's sidereal rotation rate in rad/s
+const EARTH_ROTATION_RAD_S: f64 = 7.292115e-5;
+
+/// The Sun's velocity relative to the Local Standard of Rest.
+///
+/// Expressed as a speed toward a solar apex given in J2000 equatorial
+/// coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarMotion {
+    /// Solar speed relative to the LSR in km/s
+    pub speed_kms: f64,
+    /// Right ascension of the solar apex (J2000) in degrees
+    pub apex_ra_deg: f64,
+    /// Declination of the solar apex (J2000) in degrees
+    pub apex_dec_deg: f64,
+}
+
+impl SolarMotion {
+    /// Standard kinematic LSR: 20 km/s toward RA 18h, Dec +30° (B1900),
+    /// precessed to J2000 (RA 18h03m50.29s, Dec +30°00′16.8″).
+    pub const KINEMATIC: SolarMotion = SolarMotion {
+        speed_kms: 20.0,
+        apex_ra_deg: 270.959_542,
+        apex_dec_deg: 30.004_667,
+    };
+
+    /// Creates a solar motion from Galactic velocity components.
+    ///
+    /// # Arguments
+    /// * `u` - Velocity toward the Galactic centre in km/s
+    /// * `v` - Velocity in the direction of Galactic rotation in km/s
+    /// * `w` - Velocity toward the North Galactic Pole in km/s
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::radial_velocity::SolarMotion;
+    ///
+    /// let m = SolarMotion::from_uvw(0.0, 0.0, 7.0);
+    /// assert!((m.speed_kms - 7.0).abs() < 1e-12);
+    /// assert!((m.apex_dec_deg - 27.128).abs() < 0.01); // North Galactic Pole
+    /// ```
+    pub fn from_uvw(u: f64, v: f64, w: f64) -> Self {
+        let speed_kms = (u * u + v * v + w * w).sqrt();
+        if speed_kms == 0.0 {
+            return SolarMotion { speed_kms, apex_ra_deg: 0.0, apex_dec_deg: 0.0 };
+        }
+
+        let l = v.atan2(u).to_degrees().rem_euclid(360.0);
+        let b = (w / speed_kms).asin().to_degrees();
+        // l is normalized and b comes from asin, so the conversion cannot fail
+        let (apex_ra_deg, apex_dec_deg) = galactic_to_equatorial(l, b).unwrap_or((0.0, 0.0));
+
+        SolarMotion { speed_kms, apex_ra_deg, apex_dec_deg }
+    }
+
+    /// Dynamical LSR of Schönrich, Binney & Dehnen (2010):
+    /// (U, V, W) = (11.1, 12.24, 7.25) km/s.
+    pub fn schoenrich_2010() -> Self {
+        SolarMotion::from_uvw(11.1, 12.24, 7.25)
+    }
+}
+
+impl Default for SolarMotion {
+    fn default() -> Self {
+        SolarMotion::KINEMATIC
+    }
+}
+
+/// Calculates the barycentric radial velocity correction for an observer.
+///
+/// This is the component of the observer's velocity relative to the Solar
+/// System barycentre (Earth orbit plus Earth rotation) toward the source.
+///
+/// # Arguments
+/// * `ra` - Right ascension (J2000) in degrees
+/// * `dec` - Declination (J2000) in degrees
+/// * `datetime` - Observation time
+/// * `location` - Observer's location
+///
+/// # Returns
+/// Correction in km/s to add to a topocentric radial velocity
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+/// ```
+/// use astro_math::{Location, radial_velocity::barycentric_correction};
+/// use chrono::{TimeZone, Utc};
+///
+/// let loc = Location { latitude_deg: 38.43, longitude_deg: -79.84, altitude_m: 807.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 3, 20, 4, 0, 0).unwrap();
+/// let v = barycentric_correction(83.82, -5.39, dt, &loc).unwrap();
+/// assert!(v.abs() < 31.0); // bounded by Earth's orbital + rotational speed
+/// ```
+pub fn barycentric_correction(
+    ra: f64,
+    dec: f64,
+    datetime: DateTime<Utc>,
+    location: &Location,
+) -> Result<f64> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+
+    let s = radec_to_unit_vector(ra, dec);
+
+    // Earth's barycentric velocity (km/s, BCRS axes)
+    let tt = utc_to_tt_jd(julian_date(datetime));
+    let earth_v = earth_barycentric_velocity(tt);
+    let v_orbit = earth_v[0] * s[0] + earth_v[1] * s[1] + earth_v[2] * s[2];
+
+    // Earth rotation: eastward velocity of the observer, projected on the source
+    let lat = location.latitude_deg.to_radians();
+    let u = ((1.0 - EARTH_FLATTENING) * lat.tan()).atan();
+    let rho_cos_phi = EARTH_RADIUS_KM * u.cos() + location.altitude_m / 1000.0 * lat.cos();
+    let hour_angle = (location.local_sidereal_time(datetime) * 15.0 - ra).to_radians();
+    let v_rotation = -EARTH_ROTATION_RAD_S * rho_cos_phi * dec.to_radians().cos() * hour_angle.sin();
+
+    Ok(v_orbit + v_rotation)
+}
+
+/// Calculates the correction from barycentric to LSR radial velocity.
+///
+/// This is the projection of the Sun's motion relative to the LSR onto the
+/// line of sight; it does not depend on time or observer location.
+///
+/// # Arguments
+/// * `ra` - Right ascension (J2000) in degrees
+/// * `dec` - Declination (J2000) in degrees
+/// * `solar_motion` - Adopted solar motion
+///
+/// # Returns
+/// Correction in km/s to add to a barycentric radial velocity
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+/// ```
+/// use astro_math::radial_velocity::{lsr_correction, SolarMotion};
+///
+/// // Toward the apex the full solar speed is added
+/// let m = SolarMotion::KINEMATIC;
+/// let v = lsr_correction(m.apex_ra_deg, m.apex_dec_deg, &m).unwrap();
+/// assert!((v - 20.0).abs() < 1e-9);
+/// ```
+pub fn lsr_correction(ra: f64, dec: f64, solar_motion: &SolarMotion) -> Result<f64> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+
+    let s = radec_to_unit_vector(ra, dec);
+    let apex = radec_to_unit_vector(solar_motion.apex_ra_deg, solar_motion.apex_dec_deg);
+    Ok(solar_motion.speed_kms * (s[0] * apex[0] + s[1] * apex[1] + s[2] * apex[2]))
+}
+
+/// Calculates the topocentric to LSR radial velocity correction using the
+/// standard kinematic LSR.
+///
+/// Equivalent to [`vlsr_correction_with`] with [`SolarMotion::KINEMATIC`].
+///
+/// # Arguments
+/// * `ra` - Right ascension (J2000) in degrees
+/// * `dec` - Declination (J2000) in degrees
+/// * `datetime` - Observation time
+/// * `location` - Observer's location
+///
+/// # Returns
+/// Correction in km/s to add to a topocentric radial velocity
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+/// ```
+/// use astro_math::{Location, radial_velocity::vlsr_correction};
+/// use chrono::{TimeZone, Utc};
+///
+/// let loc = Location { latitude_deg: 38.43, longitude_deg: -79.84, altitude_m: 807.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 3, 20, 4, 0, 0).unwrap();
+/// let v_topo = 12.5;
+/// let v_lsr = v_topo + vlsr_correction(83.82, -5.39, dt, &loc).unwrap();
+/// ```
+pub fn vlsr_correction(
+    ra: f64,
+    dec: f64,
+    datetime: DateTime<Utc>,
+    location: &Location,
+) -> Result<f64> {
+    vlsr_correction_with(ra, dec, datetime, location, &SolarMotion::KINEMATIC)
+}
+
+/// Calculates the topocentric to LSR radial velocity correction for a
+/// given solar motion.
+///
+/// # Arguments
+/// * `ra` - Right ascension (J2000) in degrees
+/// * `dec` - Declination (J2000) in degrees
+/// * `datetime` - Observation time
+/// * `location` - Observer's location
+/// * `solar_motion` - Adopted solar motion
+///
+/// # Returns
+/// Correction in km/s to add to a topocentric radial velocity
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+pub fn vlsr_correction_with(
+    ra: f64,
+    dec: f64,
+    datetime: DateTime<Utc>,
+    location: &Location,
+    solar_motion: &SolarMotion,
+) -> Result<f64> {
+    Ok(barycentric_correction(ra, dec, datetime, location)? + lsr_correction(ra, dec, solar_motion)?)
+}
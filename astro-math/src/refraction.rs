@@ -12,12 +12,29 @@
 //! - At 45° altitude: ~1 arcminute
 //! - Near zenith: essentially zero
 //!
+//! # Models
+//!
+//! - [`refraction_bennett`] and [`refraction_saemundsson`] — closed-form
+//!   fits for the lower atmosphere. Saemundsson scales with pressure and
+//!   temperature and remains good to a few percent under stratospheric
+//!   conditions above ~10° altitude
+//! - [`refraction_radio`] — refractivity including water vapour, for radio work
+//! - [`refraction_raytrace`] — numerical ray trace through a layered
+//!   atmosphere, for the horizon and for high-altitude (balloon, aircraft)
+//!   observers who see objects below the astronomical horizon
+//!
+//! [`RefractionModel`] selects between them at runtime, and
+//! [`RefractionTable`] tabulates one for fast lookup in servo loops.
+//!
 //! # Error Handling
 //!
 //! All functions validate altitude inputs and return `Result<T>` types:
 //! - `AstroError::OutOfRange` for altitudes outside [-90, 90] degrees
+//! - `AstroError::OutOfRange` for pressures outside [0, 1200] hPa or
+//!   temperatures outside [-150, 100] °C
 
-use crate::error::{Result, AstroError};
+use crate::error::{validate_range, Result, AstroError};
+use std::ops::RangeInclusive;
 
 /// Calculates atmospheric refraction using Bennett's formula.
 ///
@@ -90,7 +107,8 @@ pub fn refraction_bennett(altitude_deg: f64) -> Result<f64> {
 /// Refraction correction in degrees
 ///
 /// # Errors
-/// Returns `Err(AstroError::OutOfRange)` if altitude is outside [-90, 90] degrees.
+/// Returns `Err(AstroError::OutOfRange)` if altitude is outside [-90, 90] degrees,
+/// pressure is outside [0, 1200] hPa, or temperature is outside [-150, 100] °C.
 pub fn refraction_saemundsson(altitude_deg: f64, pressure_hpa: f64, temperature_c: f64) -> Result<f64> {
     if !(-90.0..=90.0).contains(&altitude_deg) {
         return Err(AstroError::OutOfRange {
@@ -100,20 +118,27 @@ pub fn refraction_saemundsson(altitude_deg: f64, pressure_hpa: f64, temperature_
             max: 90.0,
         });
     }
+    validate_conditions(pressure_hpa, temperature_c)?;
     
     if altitude_deg < -1.0 {
         return Ok(0.0);
     }
     
-    // Saemundsson's formula
-    let h = altitude_deg;
-    let r_arcmin = 1.02 / ((h + 10.3 / (h + 5.11)).to_radians().tan());
-    
-    // Pressure and temperature corrections
+    Ok(saemundsson_scaled(altitude_deg, saemundsson_scale(pressure_hpa, temperature_c)))
+}
+
+/// Pressure and temperature factor of Saemundsson's formula.
+pub(crate) fn saemundsson_scale(pressure_hpa: f64, temperature_c: f64) -> f64 {
     let p_factor = pressure_hpa / 1010.0;
     let t_factor = 283.0 / (273.0 + temperature_c);
-    
-    Ok(r_arcmin * p_factor * t_factor / 60.0)
+    p_factor * t_factor
+}
+
+/// Saemundsson's formula in degrees for a validated altitude above −1°.
+pub(crate) fn saemundsson_scaled(altitude_deg: f64, scale: f64) -> f64 {
+    let h = altitude_deg;
+    let r_arcmin = 1.02 / ((h + 10.3 / (h + 5.11)).to_radians().tan());
+    r_arcmin * scale / 60.0
 }
 
 /// Calculates atmospheric refraction for radio wavelengths.
@@ -133,6 +158,8 @@ pub fn refraction_saemundsson(altitude_deg: f64, pressure_hpa: f64, temperature_
 /// # Errors
 /// - `AstroError::OutOfRange` if altitude is outside [-90, 90] degrees
 /// - `AstroError::OutOfRange` if humidity is outside [0, 100]
+/// - `AstroError::OutOfRange` if pressure is outside [0, 1200] hPa or
+///   temperature is outside [-150, 100] °C
 pub fn refraction_radio(
     altitude_deg: f64,
     pressure_hpa: f64,
@@ -156,6 +183,7 @@ pub fn refraction_radio(
             max: 100.0,
         });
     }
+    validate_conditions(pressure_hpa, temperature_c)?;
     
     if altitude_deg < -1.0 {
         return Ok(0.0);
@@ -195,6 +223,7 @@ pub fn apparent_to_true_altitude(
     temperature_c: f64,
 ) -> Result<f64> {
     let refraction = refraction_saemundsson(apparent_altitude_deg, pressure_hpa, temperature_c)?;
+    trace_event!(debug, apparent_altitude_deg, refraction_arcsec = refraction * 3600.0, "refraction removed");
     Ok(apparent_altitude_deg - refraction)
 }
 
@@ -242,10 +271,528 @@ pub fn true_to_apparent_altitude(
         let refraction = refraction_saemundsson(apparent, pressure_hpa, temperature_c)?;
         apparent = true_altitude_deg + refraction;
     }
-    
+    trace_event!(debug, true_altitude_deg, refraction_arcsec = (apparent - true_altitude_deg) * 3600.0, "refraction applied");
+
     Ok(apparent)
 }
 
+/// Selects a refraction model for [`RefractionModel::refraction`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RefractionModel {
+    /// [`refraction_bennett`]; pressure and temperature are ignored
+    Bennett,
+    /// [`refraction_saemundsson`]
+    Saemundsson,
+    /// [`refraction_radio`] at the given relative humidity (0-100)
+    Radio { humidity_percent: f64 },
+    /// [`refraction_raytrace`] for an observer at the given height above
+    /// sea level in meters
+    Raytrace { height_m: f64 },
+}
+
+impl RefractionModel {
+    /// Calculates refraction with this model.
+    ///
+    /// # Arguments
+    /// * `altitude_deg` - Apparent altitude in degrees
+    /// * `pressure_hpa` - Atmospheric pressure at the observer in hectopascals
+    /// * `temperature_c` - Temperature at the observer in Celsius
+    ///
+    /// # Returns
+    /// Refraction correction in degrees
+    ///
+    /// # Errors
+    /// Whatever the underlying function returns for these inputs.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::refraction::RefractionModel;
+    ///
+    /// for model in [RefractionModel::Saemundsson, RefractionModel::Raytrace { height_m: 0.0 }] {
+    ///     // About 1.7′ at 30° altitude
+    ///     let r = model.refraction(30.0, 1013.25, 10.0).unwrap();
+    ///     assert!((r * 60.0 - 1.7).abs() < 0.1);
+    /// }
+    /// ```
+    pub fn refraction(&self, altitude_deg: f64, pressure_hpa: f64, temperature_c: f64) -> Result<f64> {
+        match *self {
+            RefractionModel::Bennett => refraction_bennett(altitude_deg),
+            RefractionModel::Saemundsson => refraction_saemundsson(altitude_deg, pressure_hpa, temperature_c),
+            RefractionModel::Radio { humidity_percent } => {
+                refraction_radio(altitude_deg, pressure_hpa, temperature_c, humidity_percent)
+            }
+            RefractionModel::Raytrace { height_m } => {
+                refraction_raytrace(altitude_deg, pressure_hpa, temperature_c, height_m)
+            }
+        }
+    }
+}
+
+/// Weather at the observer, for functions that apply refraction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AtmosphericConditions {
+    /// Atmospheric pressure in hectopascals
+    pub pressure_hpa: f64,
+    /// Temperature in Celsius
+    pub temperature_c: f64,
+    /// Relative humidity as a fraction (0-1)
+    pub relative_humidity: f64,
+}
+
+impl AtmosphericConditions {
+    /// Standard conditions at sea level: 1013.25 hPa, 10 °C, 50% humidity.
+    pub fn standard() -> Self {
+        Self { pressure_hpa: 1013.25, temperature_c: 10.0, relative_humidity: 0.5 }
+    }
+
+    /// Checks the fields against the ranges accepted by the refraction models.
+    pub(crate) fn validate(&self) -> Result<()> {
+        validate_conditions(self.pressure_hpa, self.temperature_c)?;
+        if !(0.0..=1.0).contains(&self.relative_humidity) {
+            return Err(AstroError::OutOfRange {
+                parameter: "relative_humidity",
+                value: self.relative_humidity,
+                min: 0.0,
+                max: 1.0,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Largest number of entries [`RefractionTable::build`] will allocate
+const MAX_TABLE_POINTS: usize = 1_000_000;
+
+/// Refraction precomputed on an altitude grid, for control loops that need
+/// it every cycle.
+///
+/// A mount servo running at 100 Hz or more cannot afford
+/// [`refraction_raytrace`] per iteration, and even the closed-form models
+/// cost a few transcendental calls. [`build`](Self::build) evaluates a
+/// [`RefractionModel`] once per grid point for fixed weather;
+/// [`lookup`](Self::lookup) then interpolates linearly between neighbours,
+/// a multiply, a truncation and two loads. The interpolation error against
+/// the model, measured between every pair of grid points when the table is
+/// built, is reported by [`max_error`](Self::max_error), so the step can be
+/// chosen for the accuracy needed; rebuild the table when the weather
+/// changes.
+///
+/// # Example
+/// ```
+/// use astro_math::refraction::{AtmosphericConditions, RefractionModel, RefractionTable};
+///
+/// let conditions = AtmosphericConditions::standard();
+/// let table = RefractionTable::build(RefractionModel::Saemundsson, &conditions, 5.0..=90.0, 0.05).unwrap();
+/// // Well under an arcsecond from the formula
+/// assert!(table.max_error() * 3600.0 < 0.1);
+///
+/// let exact = RefractionModel::Saemundsson.refraction(23.4, 1013.25, 10.0).unwrap();
+/// assert!((table.lookup(23.4) - exact).abs() <= table.max_error());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefractionTable {
+    min_altitude_deg: f64,
+    max_altitude_deg: f64,
+    step_deg: f64,
+    inverse_step: f64,
+    values: Vec<f64>,
+    max_error_deg: f64,
+}
+
+impl RefractionTable {
+    /// Tabulates a refraction model over a range of altitudes.
+    ///
+    /// The step is shortened if need be so that the grid ends exactly at
+    /// the top of the range.
+    ///
+    /// # Arguments
+    /// * `model` - Refraction model to tabulate
+    /// * `conditions` - Pressure and temperature at the observer; humidity
+    ///   is taken from the model ([`RefractionModel::Radio`])
+    /// * `alt_range` - Altitudes to cover, in degrees, as passed to
+    ///   [`RefractionModel::refraction`]
+    /// * `step` - Grid spacing in degrees
+    ///
+    /// # Returns
+    /// The table, with its interpolation error measured
+    ///
+    /// # Errors
+    /// - `AstroError::OutOfRange` if the range is empty or outside
+    ///   [-90, 90], if `step` is not positive or would need more than a
+    ///   million entries, or if the conditions are out of range
+    /// - Whatever the model returns anywhere in the range
+    pub fn build(
+        model: RefractionModel,
+        conditions: &AtmosphericConditions,
+        alt_range: RangeInclusive<f64>,
+        step: f64,
+    ) -> Result<Self> {
+        let (min, max) = (*alt_range.start(), *alt_range.end());
+        let check = |value: f64, low: f64, high: f64, parameter| {
+            if !(value > low && value <= high) {
+                return Err(AstroError::OutOfRange { parameter, value, min: low, max: high });
+            }
+            Ok(())
+        };
+        validate_range(min, -90.0, 90.0, "altitude")?;
+        check(max, min, 90.0, "altitude")?;
+        let span = max - min;
+        check(step, span / MAX_TABLE_POINTS as f64, span, "step")?;
+        conditions.validate()?;
+
+        let intervals = (span / step).ceil() as usize;
+        let step_deg = span / intervals as f64;
+        let refraction = |altitude| model.refraction(altitude, conditions.pressure_hpa, conditions.temperature_c);
+        let values = (0..=intervals)
+            .map(|i| refraction((min + i as f64 * step_deg).min(max)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut table = Self {
+            min_altitude_deg: min,
+            max_altitude_deg: max,
+            step_deg,
+            inverse_step: 1.0 / step_deg,
+            values,
+            max_error_deg: 0.0,
+        };
+        // Linear interpolation errs most near the middle of each interval
+        let mut max_error: f64 = 0.0;
+        for i in 0..intervals {
+            for fraction in [0.25, 0.5, 0.75] {
+                let altitude = min + (i as f64 + fraction) * step_deg;
+                max_error = max_error.max((table.lookup(altitude) - refraction(altitude)?).abs());
+            }
+        }
+        table.max_error_deg = max_error;
+        Ok(table)
+    }
+
+    /// Refraction at an altitude, interpolated from the table.
+    ///
+    /// Altitudes outside the table's range are clamped to its ends.
+    ///
+    /// # Arguments
+    /// * `altitude_deg` - Altitude in degrees
+    ///
+    /// # Returns
+    /// Refraction correction in degrees
+    #[inline]
+    pub fn lookup(&self, altitude_deg: f64) -> f64 {
+        let altitude = altitude_deg.clamp(self.min_altitude_deg, self.max_altitude_deg);
+        let position = (altitude - self.min_altitude_deg) * self.inverse_step;
+        let i = (position as usize).min(self.values.len() - 2);
+        let fraction = position - i as f64;
+        self.values[i] + fraction * (self.values[i + 1] - self.values[i])
+    }
+
+    /// Largest difference between [`lookup`](Self::lookup) and the model
+    /// found when the table was built, in degrees.
+    pub fn max_error(&self) -> f64 {
+        self.max_error_deg
+    }
+
+    /// Altitude range covered, in degrees.
+    pub fn range(&self) -> RangeInclusive<f64> {
+        self.min_altitude_deg..=self.max_altitude_deg
+    }
+
+    /// Grid spacing in degrees.
+    pub fn step(&self) -> f64 {
+        self.step_deg
+    }
+}
+
+/// Earth radius used for the ray trace, in meters
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+
+/// Optical refractivity of dry air per hPa/K at 574 nm
+const REFRACTIVITY_PER_HPA_K: f64 = 78.9e-6;
+
+/// g0·M/R* for dry air, in K/m: the hydrostatic pressure gradient
+const HYDROSTATIC_K_PER_M: f64 = 0.034163;
+
+/// US Standard Atmosphere 1976 layers: base geopotential height (m) and
+/// lapse rate (K/m), up to the top of the model at 84.852 km
+const STANDARD_LAYERS: [(f64, f64); 7] = [
+    (0.0, -0.0065),
+    (11_000.0, 0.0),
+    (20_000.0, 0.001),
+    (32_000.0, 0.0028),
+    (47_000.0, 0.0),
+    (51_000.0, -0.0028),
+    (71_000.0, -0.002),
+];
+const ATMOSPHERE_TOP_M: f64 = 84_852.0;
+
+/// Simpson intervals per atmospheric layer
+const RAYTRACE_STEPS: usize = 64;
+
+/// Calculates atmospheric refraction by tracing a ray through a layered
+/// atmosphere.
+///
+/// The atmosphere follows the US Standard Atmosphere 1976 lapse rates,
+/// offset so that the temperature and pressure at the observer's height
+/// match the values given, and is in hydrostatic equilibrium with
+/// inverse-square gravity. The refraction integral is evaluated in zenith
+/// distance along the ray (Auer & Standish 2000), layer by layer, for dry
+/// air at 574 nm.
+///
+/// Unlike the closed-form models this stays accurate at the horizon and
+/// under stratospheric conditions, and handles the negative apparent
+/// altitudes seen from high-altitude balloons and aircraft, where the ray
+/// dips below the observer before climbing out of the atmosphere. It costs
+/// a few thousand refractivity evaluations per call.
+///
+/// # Arguments
+/// * `altitude_deg` - Apparent altitude in degrees
+/// * `pressure_hpa` - Atmospheric pressure at the observer in hectopascals
+/// * `temperature_c` - Temperature at the observer in Celsius
+/// * `height_m` - Observer height above sea level in meters
+///
+/// # Returns
+/// Refraction correction in degrees
+///
+/// # Errors
+/// - `AstroError::OutOfRange` if altitude is outside [-90, 90] degrees,
+///   pressure outside [0, 1200] hPa, temperature outside [-150, 100] °C, or
+///   height outside [-500, 80000] m
+/// - `AstroError::CalculationError` if the ray meets the sea-level surface
+///   before leaving the atmosphere, or if the atmosphere is cold and dense
+///   enough to form a duct that traps horizontal rays
+///
+/// # Example
+/// ```
+/// use astro_math::refraction::refraction_raytrace;
+///
+/// // Balloon at 30 km: at the astronomical horizon refraction is under 1′...
+/// let horizon = refraction_raytrace(0.0, 11.97, -46.6, 30_000.0).unwrap();
+/// assert!(horizon * 60.0 > 0.4 && horizon * 60.0 < 0.8);
+///
+/// // ...but a ray skimming the lower atmosphere is bent by degrees
+/// let grazing = refraction_raytrace(-5.0, 11.97, -46.6, 30_000.0).unwrap();
+/// assert!(grazing > 0.5);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug"))]
+pub fn refraction_raytrace(altitude_deg: f64, pressure_hpa: f64, temperature_c: f64, height_m: f64) -> Result<f64> {
+    if !(-90.0..=90.0).contains(&altitude_deg) {
+        return Err(AstroError::OutOfRange {
+            parameter: "altitude",
+            value: altitude_deg,
+            min: -90.0,
+            max: 90.0,
+        });
+    }
+    validate_conditions(pressure_hpa, temperature_c)?;
+    if !(-500.0..=80_000.0).contains(&height_m) {
+        return Err(AstroError::OutOfRange {
+            parameter: "height_m",
+            value: height_m,
+            min: -500.0,
+            max: 80_000.0,
+        });
+    }
+    if pressure_hpa == 0.0 || altitude_deg == 90.0 {
+        return Ok(0.0);
+    }
+
+    let atmosphere = Atmosphere::new(pressure_hpa, temperature_c + 273.15, height_m);
+    let r0 = EARTH_RADIUS_M + height_m;
+    let (n0, _) = atmosphere.index(r0);
+    let z0 = (90.0 - altitude_deg).to_radians();
+    // Bouguer's invariant n·r·sin(z), conserved along the ray
+    let k = n0 * r0 * z0.sin();
+
+    if atmosphere.has_duct(r0.min(EARTH_RADIUS_M)) {
+        return Err(AstroError::CalculationError {
+            calculation: "refraction ray trace",
+            reason: "refractivity gradient traps horizontal rays (atmospheric duct)".to_string(),
+        });
+    }
+
+    let r_top = EARTH_RADIUS_M + geometric_height(ATMOSPHERE_TOP_M);
+    if altitude_deg >= 0.0 {
+        return Ok(atmosphere.bending(r0, r_top, k).to_degrees());
+    }
+
+    // The ray descends to a tangent point, then climbs back past the observer
+    let mut r_t = k;
+    for _ in 0..50 {
+        let (n, dn) = atmosphere.index(r_t);
+        let step = (n * r_t - k) / (n + r_t * dn);
+        r_t -= step;
+        if step.abs() < 1e-6 {
+            break;
+        }
+    }
+    if r_t < EARTH_RADIUS_M {
+        return Err(AstroError::CalculationError {
+            calculation: "refraction ray trace",
+            reason: format!("ray at {}° meets the surface {:.0} m below sea level", altitude_deg, EARTH_RADIUS_M - r_t),
+        });
+    }
+    Ok((2.0 * atmosphere.bending(r_t, r0, k) + atmosphere.bending(r0, r_top, k)).to_degrees())
+}
+
+fn validate_conditions(pressure_hpa: f64, temperature_c: f64) -> Result<()> {
+    if !(0.0..=1200.0).contains(&pressure_hpa) {
+        return Err(AstroError::OutOfRange {
+            parameter: "pressure_hpa",
+            value: pressure_hpa,
+            min: 0.0,
+            max: 1200.0,
+        });
+    }
+    if !(-150.0..=100.0).contains(&temperature_c) {
+        return Err(AstroError::OutOfRange {
+            parameter: "temperature_c",
+            value: temperature_c,
+            min: -150.0,
+            max: 100.0,
+        });
+    }
+    Ok(())
+}
+
+fn geopotential_height(h: f64) -> f64 {
+    EARTH_RADIUS_M * h / (EARTH_RADIUS_M + h)
+}
+
+fn geometric_height(h_geopotential: f64) -> f64 {
+    EARTH_RADIUS_M * h_geopotential / (EARTH_RADIUS_M - h_geopotential)
+}
+
+/// Layered model atmosphere anchored at the observer.
+struct Atmosphere {
+    /// Per layer: base geopotential height, base temperature (K), base
+    /// pressure (hPa) and lapse rate (K/m)
+    layers: Vec<(f64, f64, f64, f64)>,
+}
+
+impl Atmosphere {
+    fn new(pressure_hpa: f64, temperature_k: f64, height_m: f64) -> Self {
+        let h_obs = geopotential_height(height_m);
+        let observer = STANDARD_LAYERS.iter().rposition(|&(base, _)| base <= h_obs).unwrap_or(0);
+
+        // Base of the observer's layer, then the layers above and below it
+        let mut layers = vec![(0.0, 0.0, 0.0, 0.0); STANDARD_LAYERS.len()];
+        let (base, lapse) = STANDARD_LAYERS[observer];
+        let t_base = temperature_k - lapse * (h_obs - base);
+        let p_base = pressure_hpa / pressure_ratio(lapse, t_base, h_obs - base);
+        layers[observer] = (base, t_base, p_base, lapse);
+        for i in observer + 1..STANDARD_LAYERS.len() {
+            let (b, t, p, l) = layers[i - 1];
+            let (next_base, next_lapse) = STANDARD_LAYERS[i];
+            let dh = next_base - b;
+            layers[i] = (next_base, t + l * dh, p * pressure_ratio(l, t, dh), next_lapse);
+        }
+        for i in (0..observer).rev() {
+            let (next_base, ..) = layers[i + 1];
+            let (b, l) = STANDARD_LAYERS[i];
+            let dh = next_base - b;
+            let t = layers[i + 1].1 - l * dh;
+            let p = layers[i + 1].2 / pressure_ratio(l, t, dh);
+            layers[i] = (b, t, p, l);
+        }
+        Self { layers }
+    }
+
+    /// Refractive index and its radial derivative (per meter) at radius `r`.
+    fn index(&self, r: f64) -> (f64, f64) {
+        let h = geopotential_height(r - EARTH_RADIUS_M);
+        if h >= ATMOSPHERE_TOP_M {
+            return (1.0, 0.0);
+        }
+        let i = self.layers.iter().rposition(|&(base, ..)| base <= h).unwrap_or(0);
+        let (base, t_base, p_base, lapse) = self.layers[i];
+        let t = t_base + lapse * (h - base);
+        let refractivity = REFRACTIVITY_PER_HPA_K * p_base * pressure_ratio(lapse, t_base, h - base) / t;
+        // d/dh of P/T, converted from geopotential to geometric height
+        let dn_dh = -refractivity * (HYDROSTATIC_K_PER_M + lapse) / t;
+        let dh_dr = (EARTH_RADIUS_M / r).powi(2);
+        (1.0 + refractivity, dn_dh * dh_dr)
+    }
+
+    /// Whether n + r·n' drops to zero anywhere above `r_low`, so that rays
+    /// curve faster than the Earth and the ray trace has no solution.
+    ///
+    /// The gradient is steepest at the bottom of a layer, so only layer
+    /// bases need checking.
+    fn has_duct(&self, r_low: f64) -> bool {
+        std::iter::once(r_low)
+            .chain(self.layers.iter().map(|&(base, ..)| EARTH_RADIUS_M + geometric_height(base) + 1e-3))
+            .filter(|&r| r >= r_low)
+            .any(|r| {
+                let (n, dn) = self.index(r);
+                n + r * dn <= 0.0
+            })
+    }
+
+    /// Bending of the ray with invariant `k` while it climbs from radius
+    /// `r_low` to `r_high`, in radians.
+    fn bending(&self, r_low: f64, r_high: f64, k: f64) -> f64 {
+        let mut bounds = vec![r_low];
+        for &(base, ..) in &self.layers[1..] {
+            let r = EARTH_RADIUS_M + geometric_height(base);
+            if r > r_low && r < r_high {
+                bounds.push(r);
+            }
+        }
+        bounds.push(r_high);
+
+        let zenith = |r: f64| {
+            let (n, _) = self.index(r);
+            (k / (n * r)).min(1.0).asin()
+        };
+        let slope = |r: f64| {
+            let (n, dn) = self.index(r);
+            -r * dn / (n + r * dn)
+        };
+        // Solve n(r)·r·sin(z) = k for r, starting from a nearby radius
+        let radius = |z: f64, mut r: f64| {
+            let target = k / z.sin();
+            for _ in 0..20 {
+                let (n, dn) = self.index(r);
+                let step = (n * r - target) / (n + r * dn);
+                r -= step;
+                if step.abs() < 1e-7 {
+                    break;
+                }
+            }
+            r
+        };
+
+        let mut total = 0.0;
+        for pair in bounds.windows(2) {
+            let (z_low, z_high) = (zenith(pair[0]), zenith(pair[1]));
+            let step = (z_low - z_high) / RAYTRACE_STEPS as f64;
+            if step <= 0.0 {
+                continue;
+            }
+            // dR/dz = -r·n'/(n + r·n'); the end points are nudged inside the
+            // layer so n' comes from the right side of each boundary
+            let mut sum = slope(pair[0] + 1e-3) + slope(pair[1] - 1e-3);
+            let mut r = pair[0];
+            for j in 1..RAYTRACE_STEPS {
+                r = radius(z_low - j as f64 * step, r);
+                sum += if j % 2 == 1 { 4.0 } else { 2.0 } * slope(r);
+            }
+            total += sum * step / 3.0;
+        }
+        total
+    }
+}
+
+/// Ratio of pressure `dh` above a layer base to the pressure at the base.
+fn pressure_ratio(lapse: f64, t_base: f64, dh: f64) -> f64 {
+    if lapse == 0.0 {
+        (-HYDROSTATIC_K_PER_M * dh / t_base).exp()
+    } else {
+        (1.0 + lapse * dh / t_base).powf(-HYDROSTATIC_K_PER_M / lapse)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,6 +11,7 @@
 //! - **Set**: When an object crosses the horizon moving downward
 //! - **Circumpolar**: Objects that never set (always above horizon)
 //! - **Never rises**: Objects that never rise above the horizon
+//! - **Altitude crossing**: When an object passes any chosen altitude (see [`altitude_crossings`])
 //!
 //! # Standard Altitudes
 //!
@@ -19,26 +20,243 @@
 //! - Atmospheric refraction (~34')
 //! - Sun's semi-diameter (~16') for solar calculations
 //!
+//! [`BodyProfile`] gives the standard altitude for each kind of object,
+//! including the Moon's +0.125°, where its parallax outweighs refraction
+//! and semi-diameter; [`rise_transit_set_for_body`] applies it, and
+//! [`moon_rise_set`] finds moonrise and moonset following the Moon's motion
+//! and distance through the day.
+//!
+//! [`body_rise_set`], [`body_transit`] and [`rise_set_events`] work for any
+//! target with an [`Ephemeris`], following it through the day; the last
+//! yields every rise, transit and set over a date range. [`moon_transit`]
+//! is the Moon's meridian transit.
+//!
+//! [`sun_rise_set`] holds the Sun at a low-precision position for the whole
+//! day; [`sun_rise_set_with_model`] with [`SolarModel::Ephemeris`] follows
+//! its apparent place from the full ephemeris instead, for when the extra
+//! minutes matter.
+//!
+//! An elevated observer sees the horizon below the astronomical horizon by
+//! the [`horizon_dip`] (about 1.3° at 2000 m, bringing sunrise 6–8 minutes
+//! earlier). The rise/set functions assume a sea-level horizon; the dip
+//! depends on the height above the terrain that forms the horizon rather
+//! than on `altitude_m`, so it is applied only when given explicitly, through
+//! [`horizon_altitude`] as the altitude for [`rise_transit_set`], or through
+//! [`sun_rise_set_from_height`] and [`body_rise_set_from_height`].
+//!
 //! # Error Handling
 //!
 //! All functions validate their inputs and return `Result<T>` types:
 //! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
 
 use crate::{Location, julian_date, ra_dec_to_alt_az};
-use crate::error::{Result, validate_ra, validate_dec};
+use crate::almanac::{apparent_place, AlmanacBody};
+use crate::ephemeris::{topocentric_place, Ephemeris};
+use crate::error::{AstroError, Result, validate_ra, validate_dec, validate_range};
+use crate::events::{find_zeros, Crossing};
+use crate::transforms::body_alt_az;
 use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+use std::collections::VecDeque;
 
 /// Result type for rise, transit, and set times.
 /// Returns None if the object is circumpolar or never rises.
 /// Returns Some((rise, transit, set)) for normal objects.
 pub type RiseTransitSetResult = Result<Option<(DateTime<Utc>, DateTime<Utc>, DateTime<Utc>)>>;
 
+/// Result type for the rise and set times of a moving target.
+/// Returns `(rise, set)`, each None if it does not happen that day.
+pub type RiseSetResult = Result<(Option<DateTime<Utc>>, Option<DateTime<Utc>>)>;
+
+/// Result type for moonrise and moonset times.
+/// Returns `(moonrise, moonset)`, each None if it does not happen that day.
+pub type MoonRiseSetResult = Result<(Option<DateTime<Utc>>, Option<DateTime<Utc>>)>;
+
 /// Standard altitude for rise/set calculations (accounting for refraction and semi-diameter)
 pub const RISE_SET_ALTITUDE: f64 = -0.5667; // -34 arcminutes
 
 /// Sun's semi-diameter in degrees
 pub const SUN_SEMI_DIAMETER: f64 = 0.2667; // 16 arcminutes
 
+/// Standard altitude of the Moon's upper limb at its mean distance
+const MOON_MEAN_RISE_SET_ALTITUDE: f64 = 0.125;
+
+/// Equatorial radius of the Earth in kilometers
+const EARTH_RADIUS_KM: f64 = 6378.137;
+
+/// Dip of the horizon in arcminutes per square root of meter of height,
+/// including standard terrestrial refraction (Nautical Almanac)
+const DIP_ARCMIN_PER_SQRT_M: f64 = 1.76;
+
+/// Calculates the dip of the visible horizon below the astronomical horizon.
+///
+/// An observer above the surrounding terrain or sea sees the horizon below
+/// 0° altitude. Uses the navigator's formula `dip = 1.76′ × √h`, which
+/// includes standard terrestrial refraction.
+///
+/// # Arguments
+/// * `height_m` - Observer's height above the horizon-defining surface in meters
+///
+/// # Returns
+/// Dip in degrees (positive, below the astronomical horizon)
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if `height_m` is negative or not finite.
+///
+/// # Example
+/// ```
+/// use astro_math::rise_set::horizon_dip;
+///
+/// // From 2 m above the sea the horizon is about 2.5' low
+/// assert!((horizon_dip(2.0).unwrap() * 60.0 - 2.49).abs() < 0.01);
+/// // From an airliner at 10 km it is more than 2.9° low
+/// assert!(horizon_dip(10_000.0).unwrap() > 2.9);
+/// ```
+pub fn horizon_dip(height_m: f64) -> Result<f64> {
+    if !(height_m >= 0.0 && height_m.is_finite()) {
+        return Err(AstroError::OutOfRange { parameter: "height_m", value: height_m, min: 0.0, max: f64::MAX });
+    }
+    Ok(DIP_ARCMIN_PER_SQRT_M * height_m.sqrt() / 60.0)
+}
+
+/// Calculates the altitude of the visible horizon for rise/set calculations.
+///
+/// The true altitude of a point-like object on the visible horizon is
+/// `-dip - refraction`, that is [`RISE_SET_ALTITUDE`] lowered by
+/// [`horizon_dip`]. At sea level this equals [`RISE_SET_ALTITUDE`].
+///
+/// # Arguments
+/// * `height_m` - Observer's height above the horizon-defining surface in
+///   meters; negative heights (below sea level) give the sea-level horizon
+///
+/// # Returns
+/// Horizon altitude in degrees
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if `height_m` is not finite.
+///
+/// # Example
+/// ```
+/// use astro_math::RISE_SET_ALTITUDE;
+/// use astro_math::rise_set::horizon_altitude;
+///
+/// assert_eq!(horizon_altitude(0.0).unwrap(), RISE_SET_ALTITUDE);
+/// assert!(horizon_altitude(2000.0).unwrap() < -1.8);
+/// ```
+pub fn horizon_altitude(height_m: f64) -> Result<f64> {
+    let height = if height_m < 0.0 && height_m.is_finite() { 0.0 } else { height_m };
+    Ok(RISE_SET_ALTITUDE - horizon_dip(height)?)
+}
+
+/// Kind of object, for choosing the standard altitude of rise and set.
+///
+/// Rise and set are defined by the object's geometric center reaching a
+/// standard altitude `h0` that allows for refraction, the object's
+/// semi-diameter and, for the Moon, its horizontal parallax (Meeus,
+/// *Astronomical Algorithms*, ch. 15):
+///
+/// | Profile | `h0` | Event |
+/// |---------|------|-------|
+/// | [`Star`](Self::Star), [`Planet`](Self::Planet) | −0.5667° | Point on the horizon |
+/// | [`SunUpperLimb`](Self::SunUpperLimb) | −0.8333° | Sunrise and sunset |
+/// | [`SunCenter`](Self::SunCenter) | −0.5667° | Center of the disk on the horizon |
+/// | [`MoonUpperLimb`](Self::MoonUpperLimb) | +0.125° | Moonrise and moonset |
+///
+/// The Moon's value is positive because its parallax (about 0.95°) lowers
+/// it by more than refraction and its semi-diameter raise it. It is the
+/// value for the mean distance; use [`moon_standard_altitude`] with the
+/// actual distance for the best times, which can differ by about a minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BodyProfile {
+    /// A star or other point source
+    #[default]
+    Star,
+    /// The Sun's upper limb, for conventional sunrise and sunset
+    SunUpperLimb,
+    /// The center of the Sun's disk
+    SunCenter,
+    /// The Moon's upper limb, allowing for its parallax at mean distance
+    MoonUpperLimb,
+    /// A planet, whose disk and parallax are negligible at this precision
+    Planet,
+}
+
+impl BodyProfile {
+    /// Standard altitude `h0` for a flat horizon at sea level, in degrees.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::RISE_SET_ALTITUDE;
+    /// use astro_math::rise_set::BodyProfile;
+    ///
+    /// assert_eq!(BodyProfile::Star.standard_altitude(), RISE_SET_ALTITUDE);
+    /// assert!((BodyProfile::SunUpperLimb.standard_altitude() + 0.8333).abs() < 1e-3);
+    /// assert!((BodyProfile::MoonUpperLimb.standard_altitude() - 0.125).abs() < 1e-3);
+    /// ```
+    pub fn standard_altitude(self) -> f64 {
+        match self {
+            BodyProfile::Star | BodyProfile::SunCenter | BodyProfile::Planet => RISE_SET_ALTITUDE,
+            BodyProfile::SunUpperLimb => RISE_SET_ALTITUDE - SUN_SEMI_DIAMETER,
+            BodyProfile::MoonUpperLimb => MOON_MEAN_RISE_SET_ALTITUDE,
+        }
+    }
+
+    /// Standard altitude on the visible horizon of an elevated observer.
+    ///
+    /// [`standard_altitude`](Self::standard_altitude) lowered by the
+    /// [`horizon_dip`], as [`horizon_altitude`] does for point sources.
+    ///
+    /// # Arguments
+    /// * `height_m` - Observer's height above the horizon-defining surface in
+    ///   meters; negative heights give the sea-level horizon
+    ///
+    /// # Returns
+    /// Altitude in degrees
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if `height_m` is not finite.
+    pub fn horizon_altitude(self, height_m: f64) -> Result<f64> {
+        Ok(horizon_altitude(height_m)? - RISE_SET_ALTITUDE + self.standard_altitude())
+    }
+}
+
+/// Standard altitude for moonrise and moonset at a given Earth–Moon distance.
+///
+/// Meeus' `h0 = 0.7275·π − 0°34′`, where π is the Moon's horizontal
+/// parallax; the factor 0.7275 combines the parallax with the Moon's
+/// semi-diameter, which scales with it. Ranges from about +0.08° at apogee
+/// to +0.17° at perigee.
+///
+/// # Arguments
+/// * `distance_km` - Geocentric distance of the Moon in kilometers, e.g. from
+///   [`moon_distance`](crate::moon::moon_distance)
+///
+/// # Returns
+/// Standard altitude in degrees, for a flat horizon at sea level
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if `distance_km` is not finite or
+/// not greater than the Earth's radius.
+///
+/// # Example
+/// ```
+/// use astro_math::rise_set::moon_standard_altitude;
+///
+/// // At the mean distance this is the tabulated +0.125°
+/// assert!((moon_standard_altitude(385_000.0).unwrap() - 0.125).abs() < 0.002);
+/// ```
+pub fn moon_standard_altitude(distance_km: f64) -> Result<f64> {
+    if !(distance_km > EARTH_RADIUS_KM && distance_km.is_finite()) {
+        return Err(AstroError::OutOfRange {
+            parameter: "distance_km",
+            value: distance_km,
+            min: EARTH_RADIUS_KM,
+            max: f64::MAX,
+        });
+    }
+    let parallax = (EARTH_RADIUS_KM / distance_km).asin().to_degrees();
+    Ok(0.7275 * parallax + RISE_SET_ALTITUDE)
+}
+
 /// Calculates rise, transit, and set times for an object.
 ///
 /// # Arguments
@@ -46,7 +264,8 @@ pub const SUN_SEMI_DIAMETER: f64 = 0.2667; // 16 arcminutes
 /// * `dec` - Declination in degrees
 /// * `date` - Date to calculate for (uses noon UTC as reference)
 /// * `location` - Observer's location
-/// * `altitude_deg` - Altitude for rise/set (default: -0.5667° for refraction)
+/// * `altitude_deg` - Altitude for rise/set (default: [`RISE_SET_ALTITUDE`];
+///   pass [`horizon_altitude`] to include the dip of the horizon)
 ///
 /// # Returns
 /// - `Ok(Some((rise, transit, set)))` - Times in UTC
@@ -72,6 +291,7 @@ pub const SUN_SEMI_DIAMETER: f64 = 0.2667; // 16 arcminutes
 ///     None => println!("Object is circumpolar or never visible"),
 /// }
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(location)))]
 pub fn rise_transit_set(
     ra: f64,
     dec: f64,
@@ -86,7 +306,7 @@ pub fn rise_transit_set(
     let dec_rad = dec.to_radians();
     
     // Calculate hour angle at rise/set
-    let cos_h = -(target_alt.to_radians().sin() - lat_rad.sin() * dec_rad.sin()) 
+    let cos_h = (target_alt.to_radians().sin() - lat_rad.sin() * dec_rad.sin())
         / (lat_rad.cos() * dec_rad.cos());
     
     // Check if object is circumpolar or never rises
@@ -100,6 +320,7 @@ pub fn rise_transit_set(
     
     let h = cos_h.acos();
     let h_hours = h.to_degrees() / 15.0;
+    trace_event!(debug, target_alt, h_hours, "hour angle at rise/set");
     
     // Calculate transit time (when object crosses meridian)
     let noon = Utc.with_ymd_and_hms(date.year(), date.month(), date.day(), 12, 0, 0).unwrap();
@@ -128,6 +349,50 @@ pub fn rise_transit_set(
     Ok(Some((rise_time, transit_time, set_time)))
 }
 
+/// Calculates rise, transit, and set times using a body's standard altitude.
+///
+/// [`rise_transit_set`] with the altitude set by `profile` (see
+/// [`BodyProfile::standard_altitude`]). For an elevated observer, pass
+/// [`BodyProfile::horizon_altitude`] to [`rise_transit_set`] instead.
+///
+/// # Arguments
+/// * `ra` - Right ascension in degrees
+/// * `dec` - Declination in degrees
+/// * `date` - Date to calculate for (uses noon UTC as reference)
+/// * `location` - Observer's location
+/// * `profile` - Kind of object
+///
+/// # Returns
+/// - `Ok(Some((rise, transit, set)))` - Times in UTC
+/// - `Ok(None)` - Object is circumpolar or never rises
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if `ra` or `dec` is out of range.
+///
+/// # Example
+/// ```
+/// # use chrono::{TimeZone, Utc};
+/// # use astro_math::{Location, rise_transit_set};
+/// # use astro_math::rise_set::{BodyProfile, rise_transit_set_for_body};
+/// let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+/// let date = Utc.with_ymd_and_hms(2024, 8, 4, 12, 0, 0).unwrap();
+///
+/// // The Moon's upper limb appears several minutes later than a star
+/// // at the same position would
+/// let star = rise_transit_set(150.0, 10.0, date, &location, None).unwrap().unwrap();
+/// let moon = rise_transit_set_for_body(150.0, 10.0, date, &location, BodyProfile::MoonUpperLimb).unwrap().unwrap();
+/// assert!((moon.0 - star.0).num_seconds() > 180);
+/// ```
+pub fn rise_transit_set_for_body(
+    ra: f64,
+    dec: f64,
+    date: DateTime<Utc>,
+    location: &Location,
+    profile: BodyProfile,
+) -> RiseTransitSetResult {
+    rise_transit_set(ra, dec, date, location, Some(profile.standard_altitude()))
+}
+
 /// Calculates next rise time for an object.
 ///
 /// Searches forward from the given time to find when the object next
@@ -138,7 +403,7 @@ pub fn rise_transit_set(
 /// * `dec` - Declination in degrees
 /// * `start_time` - Time to start searching from
 /// * `location` - Observer's location
-/// * `altitude_deg` - Altitude for rise (default: -0.5667° for refraction)
+/// * `altitude_deg` - Altitude for rise (default: [`RISE_SET_ALTITUDE`])
 ///
 /// # Returns
 /// - `Ok(Some(rise_time))` - Next rise time in UTC
@@ -157,10 +422,6 @@ pub fn next_rise(
 ) -> Result<Option<DateTime<Utc>>> {
     validate_ra(ra)?;
     validate_dec(dec)?;
-    // Check current altitude
-    let (_current_alt, _) = ra_dec_to_alt_az(ra, dec, start_time, location)?;
-    let _target_alt = altitude_deg.unwrap_or(RISE_SET_ALTITUDE);
-    
     // Search for rise time over next 2 days
     let mut check_date = start_time.date_naive();
     for _ in 0..2 {
@@ -186,7 +447,7 @@ pub fn next_rise(
 /// * `dec` - Declination in degrees
 /// * `start_time` - Time to start searching from
 /// * `location` - Observer's location
-/// * `altitude_deg` - Altitude for set (default: -0.5667° for refraction)
+/// * `altitude_deg` - Altitude for set (default: [`RISE_SET_ALTITUDE`])
 ///
 /// # Returns
 /// - `Ok(Some(set_time))` - Next set time in UTC
@@ -220,11 +481,493 @@ pub fn next_set(
     Ok(None)
 }
 
+/// Finds every time an object crosses a given altitude within 24 hours.
+///
+/// Unlike [`rise_transit_set`], which uses a single-pass analytic
+/// approximation for the standard horizon, this samples the object's altitude
+/// every 10 minutes and refines each crossing with the [`events`](crate::events)
+/// solver to about a second. It answers questions like "when does M31 climb
+/// above 30°?" directly.
+///
+/// # Arguments
+/// * `ra` - Right ascension in degrees
+/// * `dec` - Declination in degrees
+/// * `start` - Start of the 24-hour search window
+/// * `location` - Observer's location
+/// * `threshold_alt` - Altitude to test against in degrees
+///
+/// # Returns
+/// Chronological `(time, direction)` pairs; [`Crossing::Rising`] means the
+/// object climbs above `threshold_alt`. Empty if the object stays on one side.
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if `ra` or `dec` is out of range
+/// - `AstroError::OutOfRange` if `threshold_alt` is outside [-90, 90]
+///
+/// # Example
+/// ```
+/// # use chrono::{TimeZone, Utc};
+/// # use astro_math::Location;
+/// # use astro_math::rise_set::altitude_crossings;
+/// use astro_math::events::Crossing;
+///
+/// let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+/// let start = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+///
+/// // M31 above 30°
+/// let crossings = altitude_crossings(10.6847, 41.2689, start, &location, 30.0).unwrap();
+/// assert_eq!(crossings.len(), 2);
+/// assert!(crossings.iter().any(|&(_, dir)| dir == Crossing::Rising));
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(location)))]
+pub fn altitude_crossings(
+    ra: f64,
+    dec: f64,
+    start: DateTime<Utc>,
+    location: &Location,
+    threshold_alt: f64,
+) -> Result<Vec<(DateTime<Utc>, Crossing)>> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    validate_range(threshold_alt, -90.0, 90.0, "threshold_alt")?;
+
+    // Inputs are validated, so altitude computation cannot fail
+    let altitude = |t| {
+        ra_dec_to_alt_az(ra, dec, t, location)
+            .map(|(alt, _)| alt - threshold_alt)
+            .unwrap_or(f64::NAN)
+    };
+    find_zeros(
+        altitude,
+        start,
+        start + Duration::hours(24),
+        Duration::minutes(10),
+        Duration::seconds(1),
+    )
+}
+
+/// Finds every time a moving target crosses a given altitude within 24
+/// hours.
+///
+/// The [`Ephemeris`] counterpart of [`altitude_crossings`]: the target's
+/// topocentric position ([`body_alt_az`]) is re-evaluated at every sample,
+/// every [`Ephemeris::search_step`], so this follows the Moon, a planet or
+/// a satellite pass as well as a star. The altitude is that of the
+/// target's center, unrefracted.
+///
+/// # Arguments
+/// * `body` - The target
+/// * `start` - Start of the 24-hour search window
+/// * `location` - Observer's location
+/// * `threshold_alt` - Altitude to test against in degrees
+///
+/// # Returns
+/// Chronological `(time, direction)` pairs, as for [`altitude_crossings`]
+///
+/// # Errors
+/// - `AstroError::OutOfRange` if `threshold_alt` is outside [-90, 90]
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - Whatever the target's ephemeris returns for `start`
+///
+/// # Example
+/// ```
+/// use astro_math::almanac::AlmanacBody;
+/// use astro_math::events::Crossing;
+/// use astro_math::rise_set::body_altitude_crossings;
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// // When is the Moon more than 20° up?
+/// let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+/// let start = Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap();
+/// let crossings = body_altitude_crossings(&AlmanacBody::Moon, start, &location, 20.0).unwrap();
+/// assert!(crossings.iter().any(|&(_, dir)| dir == Crossing::Rising));
+/// ```
+pub fn body_altitude_crossings<E: Ephemeris + ?Sized>(
+    body: &E,
+    start: DateTime<Utc>,
+    location: &Location,
+    threshold_alt: f64,
+) -> Result<Vec<(DateTime<Utc>, Crossing)>> {
+    validate_range(threshold_alt, -90.0, 90.0, "threshold_alt")?;
+    body_alt_az(body, start, location)?;
+
+    // Failures later in the window (e.g. past the end of a table) end the
+    // search there rather than failing it
+    let altitude = |t| {
+        body_alt_az(body, t, location)
+            .map(|(alt, _)| alt - threshold_alt)
+            .unwrap_or(f64::NAN)
+    };
+    find_zeros(
+        altitude,
+        start,
+        start + Duration::hours(24),
+        body.search_step(),
+        Duration::seconds(1),
+    )
+}
+
+/// Calculates the rise and set times of a moving target on a given date.
+///
+/// Works for anything implementing [`Ephemeris`], following the target
+/// through the day: the topocentric altitude of its upper limb (its center
+/// raised by the semi-diameter from [`Ephemeris::radius_km`] at its current
+/// distance) is compared with the standard refraction of -34′ on a
+/// sea-level horizon; [`body_rise_set_from_height`] lowers it by the dip.
+///
+/// For the Moon this is the geocentric altitude crossing
+/// [`moon_standard_altitude`] that [`moon_rise_set`] describes; for the Sun
+/// it agrees with [`sun_rise_set_with_model`] and [`SolarModel::Ephemeris`]
+/// to a few seconds.
+///
+/// # Arguments
+/// * `body` - The target
+/// * `date` - Date to calculate for; events are searched for over the UTC day
+/// * `location` - Observer's location
+///
+/// # Returns
+/// `(rise, set)` in UTC, each `None` if it does not happen on that day.
+/// A satellite can rise and set several times a day; this returns the
+/// first of each, and [`body_altitude_crossings`] returns them all.
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - Whatever the target's ephemeris returns at the start of the day
+///
+/// # Example
+/// ```
+/// use astro_math::almanac::AlmanacBody;
+/// use astro_math::rise_set::body_rise_set;
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let location = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+///
+/// let (rise, set) = body_rise_set(&AlmanacBody::Jupiter, date, &location).unwrap();
+/// assert!(rise.unwrap() < set.unwrap());
+/// ```
+pub fn body_rise_set<E: Ephemeris + ?Sized>(
+    body: &E,
+    date: DateTime<Utc>,
+    location: &Location,
+) -> RiseSetResult {
+    body_limb_crossings(body, date, location, RISE_SET_ALTITUDE)
+}
+
+/// Calculates the rise and set times of a moving target for an elevated observer.
+///
+/// As [`body_rise_set`], with the horizon lowered by the [`horizon_dip`]
+/// for an observer `observer_height_m` above the surface that forms the
+/// horizon, for instance the sea seen from a cliff or a ship's bridge.
+///
+/// # Arguments
+/// * `body` - The target
+/// * `date` - Date to calculate for; events are searched for over the UTC day
+/// * `location` - Observer's location
+/// * `observer_height_m` - Height above the horizon-defining surface in
+///   meters; negative heights give the sea-level horizon
+///
+/// # Returns
+/// `(rise, set)` in UTC, each `None` if it does not happen on that day
+///
+/// # Errors
+/// - `AstroError::OutOfRange` if `observer_height_m` is not finite
+/// - Whatever [`body_rise_set`] returns
+///
+/// # Example
+/// ```
+/// use astro_math::almanac::AlmanacBody;
+/// use astro_math::rise_set::{body_rise_set, body_rise_set_from_height};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let location = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+///
+/// // From 100 m up the Moon rises about two minutes earlier
+/// let (rise, _) = body_rise_set(&AlmanacBody::Moon, date, &location).unwrap();
+/// let (high_rise, _) = body_rise_set_from_height(&AlmanacBody::Moon, date, &location, 100.0).unwrap();
+/// assert!(high_rise.unwrap() < rise.unwrap());
+/// ```
+pub fn body_rise_set_from_height<E: Ephemeris + ?Sized>(
+    body: &E,
+    date: DateTime<Utc>,
+    location: &Location,
+    observer_height_m: f64,
+) -> RiseSetResult {
+    body_limb_crossings(body, date, location, horizon_altitude(observer_height_m)?)
+}
+
+/// First rise and set of the target's upper limb through `horizon` over the UTC day.
+fn body_limb_crossings<E: Ephemeris + ?Sized>(
+    body: &E,
+    date: DateTime<Utc>,
+    location: &Location,
+    horizon: f64,
+) -> RiseSetResult {
+    let midnight = Utc.with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0).unwrap();
+
+    let limb_altitude = |t| -> Result<f64> {
+        let (ra, dec, distance_km) = topocentric_place(body, t, location)?;
+        let (altitude, _) = ra_dec_to_alt_az(ra, dec, t, location)?;
+        let semi_diameter = match (body.radius_km(), distance_km) {
+            (Some(radius), Some(distance)) if distance > radius => (radius / distance).asin().to_degrees(),
+            _ => 0.0,
+        };
+        Ok(altitude + semi_diameter - horizon)
+    };
+    limb_altitude(midnight)?;
+
+    let crossings = find_zeros(
+        |t| limb_altitude(t).unwrap_or(f64::NAN),
+        midnight,
+        midnight + Duration::days(1),
+        body.search_step(),
+        Duration::seconds(1),
+    )?;
+    let first = |direction| crossings.iter().find(|c| c.1 == direction).map(|c| c.0);
+    Ok((first(Crossing::Rising), first(Crossing::Falling)))
+}
+
+/// Calculates the upper meridian transit of a moving target on a given date.
+///
+/// Works for anything implementing [`Ephemeris`], following the target
+/// through the day: the transit is where the hour angle of its topocentric
+/// place passes through zero. For the Moon, which moves about 13° a day
+/// eastward, this is about 50 minutes later each day, and once a month
+/// there is no transit on the date.
+///
+/// # Arguments
+/// * `body` - The target
+/// * `date` - Date to calculate for; the transit is searched for over the UTC day
+/// * `location` - Observer's location
+///
+/// # Returns
+/// The transit time in UTC, `None` if there is no upper transit on that
+/// day. The target may be below the horizon at transit; see
+/// [`body_alt_az`] for its altitude.
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - Whatever the target's ephemeris returns at the start of the day
+///
+/// # Example
+/// ```
+/// use astro_math::almanac::AlmanacBody;
+/// use astro_math::rise_set::{body_rise_set, body_transit};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let location = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+///
+/// let (rise, set) = body_rise_set(&AlmanacBody::Jupiter, date, &location).unwrap();
+/// let transit = body_transit(&AlmanacBody::Jupiter, date, &location).unwrap().unwrap();
+/// assert!(rise.unwrap() < transit && transit < set.unwrap());
+/// ```
+pub fn body_transit<E: Ephemeris + ?Sized>(
+    body: &E,
+    date: DateTime<Utc>,
+    location: &Location,
+) -> Result<Option<DateTime<Utc>>> {
+    let midnight = Utc.with_ymd_and_hms(date.year(), date.month(), date.day(), 0, 0, 0).unwrap();
+    topocentric_place(body, midnight, location)?;
+    Ok(upper_transits(body, midnight, midnight + Duration::days(1), location)?.into_iter().next())
+}
+
+/// Upper meridian transits in `[t0, t1]`, in time order.
+fn upper_transits<E: Ephemeris + ?Sized>(
+    body: &E,
+    t0: DateTime<Utc>,
+    t1: DateTime<Utc>,
+    location: &Location,
+) -> Result<Vec<DateTime<Utc>>> {
+    // sin(HA) rises through zero at upper transit and falls at lower
+    let hour_angle_sine = |t| {
+        topocentric_place(body, t, location)
+            .map(|(ra, _, _)| (location.local_sidereal_time(t) * 15.0 - ra).to_radians().sin())
+            .unwrap_or(f64::NAN)
+    };
+    let transits = find_zeros(hour_angle_sine, t0, t1, body.search_step(), Duration::seconds(1))?;
+    Ok(transits.into_iter().filter(|c| c.1 == Crossing::Rising).map(|c| c.0).collect())
+}
+
+/// Kind of a [`RiseSetEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RiseSetEventKind {
+    /// The target climbs through the chosen altitude
+    Rise,
+    /// Upper meridian transit; below the chosen altitude on days the target
+    /// does not rise
+    Transit,
+    /// The target sinks through the chosen altitude
+    Set,
+}
+
+/// One event from [`rise_set_events`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RiseSetEvent {
+    /// Time of the event (UTC)
+    pub time: DateTime<Utc>,
+    /// Rise, transit or set
+    pub kind: RiseSetEventKind,
+    /// Topocentric altitude of the target's center at the event, in degrees
+    pub altitude_deg: f64,
+}
+
+/// Lists every rise, transit and set of a target over a date range.
+///
+/// Rather than calling a one-day function for each date, this yields the
+/// events in time order as they are found, searching a day at a time, so
+/// a season-long range costs nothing until it is iterated. Rises and sets
+/// are where the topocentric altitude of the target's center crosses
+/// `altitude_deg` (see [`body_altitude_crossings`]); transits are upper
+/// meridian transits.
+///
+/// Polar day and night need no special handling: while the target stays on
+/// one side of `altitude_deg` only its transits are yielded, and rises and
+/// sets resume when it crosses again. Use
+/// [`FixedPosition`](crate::ephemeris::FixedPosition) for a star or other
+/// fixed RA/Dec.
+///
+/// # Arguments
+/// * `body` - The target
+/// * `start`, `end` - Range to search; events at or after `end` are not
+///   included
+/// * `location` - Observer's location
+/// * `altitude_deg` - Altitude of the target's center for rise and set, in
+///   degrees, e.g. [`RISE_SET_ALTITUDE`] for refraction at the horizon
+///
+/// # Returns
+/// An iterator over the events in time order
+///
+/// # Errors
+/// - `AstroError::InvalidDateTime` if `end` is not after `start`
+/// - `AstroError::OutOfRange` if `altitude_deg` is outside [-90, 90] or
+///   the target's [`Ephemeris::search_step`] is not positive
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - Whatever the target's ephemeris returns at `start` or `end`
+///
+/// # Example
+/// ```
+/// use astro_math::almanac::AlmanacBody;
+/// use astro_math::rise_set::{rise_set_events, RiseSetEventKind, RISE_SET_ALTITUDE, SUN_SEMI_DIAMETER};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// // The end of the polar night at Tromsø
+/// let tromso = Location { latitude_deg: 69.65, longitude_deg: 18.96, altitude_m: 0.0 };
+/// let start = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2024, 1, 20, 0, 0, 0).unwrap();
+/// let sunrise_altitude = RISE_SET_ALTITUDE - SUN_SEMI_DIAMETER;
+///
+/// let mut events = rise_set_events(&AlmanacBody::Sun, start, end, &tromso, sunrise_altitude).unwrap();
+/// // Days of noon transits below the horizon, then the first sunrise
+/// let first_sunrise = events.find(|e| e.kind == RiseSetEventKind::Rise).unwrap();
+/// assert_eq!(first_sunrise.time.format("%m-%d").to_string(), "01-15");
+/// ```
+pub fn rise_set_events<'a, E: Ephemeris + ?Sized>(
+    body: &'a E,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    location: &'a Location,
+    altitude_deg: f64,
+) -> Result<impl Iterator<Item = RiseSetEvent> + 'a> {
+    if end <= start {
+        return Err(AstroError::InvalidDateTime {
+            reason: format!("event range end {} is not after start {}", end, start),
+        });
+    }
+    validate_range(altitude_deg, -90.0, 90.0, "altitude_deg")?;
+    let step = body.search_step();
+    if step <= Duration::zero() {
+        return Err(AstroError::OutOfRange {
+            parameter: "search_step",
+            value: step.num_milliseconds() as f64 / 1000.0,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+    body_alt_az(body, start, location)?;
+    body_alt_az(body, end, location)?;
+
+    let mut day_start = start;
+    let mut pending = VecDeque::new();
+    Ok(std::iter::from_fn(move || {
+        while pending.is_empty() && day_start < end {
+            let day_end = (day_start + Duration::days(1)).min(end);
+            pending.extend(events_between(body, day_start, day_end, location, altitude_deg));
+            day_start = day_end;
+        }
+        pending.pop_front()
+    }))
+}
+
+/// Rises, transits and sets in `[t0, t1]`, in time order.
+fn events_between<E: Ephemeris + ?Sized>(
+    body: &E,
+    t0: DateTime<Utc>,
+    t1: DateTime<Utc>,
+    location: &Location,
+    altitude_deg: f64,
+) -> Vec<RiseSetEvent> {
+    let altitude = |t| body_alt_az(body, t, location).map(|(alt, _)| alt).unwrap_or(f64::NAN);
+
+    // The interval and step are validated, so the searches cannot fail
+    let step = body.search_step();
+    let tolerance = Duration::seconds(1);
+    let crossings = find_zeros(|t| altitude(t) - altitude_deg, t0, t1, step, tolerance).unwrap_or_default();
+    let transits = upper_transits(body, t0, t1, location).unwrap_or_default();
+
+    let mut events: Vec<RiseSetEvent> = crossings
+        .into_iter()
+        .map(|(time, direction)| RiseSetEvent {
+            time,
+            kind: match direction {
+                Crossing::Rising => RiseSetEventKind::Rise,
+                Crossing::Falling => RiseSetEventKind::Set,
+            },
+            altitude_deg,
+        })
+        .chain(transits.into_iter().map(|time| RiseSetEvent {
+            time,
+            kind: RiseSetEventKind::Transit,
+            altitude_deg: altitude(time),
+        }))
+        .collect();
+    events.sort_by_key(|event| event.time);
+    events
+}
+
+/// Solar position model for [`sun_rise_set_with_model`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SolarModel {
+    /// [`sun_position_low_precision`](crate::sun::sun_position_low_precision)
+    /// at the given time, held fixed through the day. A handful of
+    /// trigonometric calls; the Sun's motion in declination makes rise and
+    /// set a few minutes out near the equinoxes.
+    #[default]
+    LowPrecision,
+    /// The Sun's apparent place from the `Epv00` ephemeris (VSOP87-based),
+    /// as in [`almanac::apparent_place`](crate::almanac::apparent_place),
+    /// re-evaluated at each event until it converges. Good to a few seconds
+    /// against the topocentric event, at about ten times the cost.
+    Ephemeris,
+}
+
 /// Calculates sunrise and sunset times.
 ///
 /// Uses a low-precision solar position algorithm suitable for rise/set
-/// calculations. Automatically accounts for atmospheric refraction and
-/// the Sun's semi-diameter.
+/// calculations; [`sun_rise_set_with_model`] selects a more accurate one.
+/// Automatically accounts for atmospheric refraction and the Sun's
+/// semi-diameter on a sea-level horizon; [`sun_rise_set_from_height`] adds
+/// the dip of the horizon for an elevated observer.
 ///
 /// # Arguments
 /// * `date` - Date to calculate for
@@ -250,34 +993,212 @@ pub fn sun_rise_set(
     date: DateTime<Utc>,
     location: &Location,
 ) -> Result<Option<(DateTime<Utc>, DateTime<Utc>)>> {
-    // Approximate sun position (low precision)
-    let jd = julian_date(date);
-    let n = jd - 2451545.0;
-    let l = (280.460 + 0.9856474 * n) % 360.0;
-    let g = ((357.528 + 0.9856003 * n) % 360.0).to_radians();
-    let lambda = l + 1.915 * g.sin() + 0.020 * (2.0 * g).sin();
-    
-    // Sun's RA and Dec
-    let lambda_rad = lambda.to_radians();
-    let epsilon = 23.439_f64.to_radians();
-    let mut ra = lambda_rad.cos().atan2(epsilon.cos() * lambda_rad.sin()).to_degrees();
-    let dec = (epsilon.sin() * lambda_rad.sin()).asin().to_degrees();
-    
-    // Normalize RA to [0, 360)
-    if ra < 0.0 {
-        ra += 360.0;
-    }
-    
-    // Account for sun's semi-diameter
-    let sun_altitude = RISE_SET_ALTITUDE;
-    
-    if let Some((rise, _, set)) = rise_transit_set(ra, dec, date, location, Some(sun_altitude))? {
-        Ok(Some((rise, set)))
-    } else {
-        Ok(None)
+    sun_rise_set_with_model(date, location, SolarModel::LowPrecision)
+}
+
+/// Calculates sunrise and sunset times for an elevated observer.
+///
+/// As [`sun_rise_set`], with the Sun's upper limb on the visible horizon
+/// of an observer `observer_height_m` above the surface that forms it (see
+/// [`BodyProfile::horizon_altitude`]). This is the height above the
+/// surrounding terrain or sea, not the location's `altitude_m`: on a
+/// high plateau the horizon is not lowered at all.
+///
+/// # Arguments
+/// * `date` - Date to calculate for
+/// * `location` - Observer's location
+/// * `observer_height_m` - Height above the horizon-defining surface in
+///   meters; negative heights give the sea-level horizon
+///
+/// # Returns
+/// - `Ok(Some((sunrise, sunset)))` - Times in UTC
+/// - `Ok(None)` - Sun doesn't rise or set (polar day/night)
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if `observer_height_m` is not finite.
+///
+/// # Example
+/// ```
+/// # use chrono::{TimeZone, Utc};
+/// # use astro_math::Location;
+/// use astro_math::rise_set::{sun_rise_set, sun_rise_set_from_height};
+///
+/// // From Mauna Kea, 2000 m above the sea horizon, the day is about a
+/// // quarter of an hour longer
+/// let location = Location { latitude_deg: 19.82, longitude_deg: -155.47, altitude_m: 4200.0 };
+/// let date = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+/// let (rise, set) = sun_rise_set(date, &location).unwrap().unwrap();
+/// let (high_rise, high_set) = sun_rise_set_from_height(date, &location, 2000.0).unwrap().unwrap();
+/// assert!((high_set - high_rise) - (set - rise) > chrono::Duration::minutes(10));
+/// ```
+pub fn sun_rise_set_from_height(
+    date: DateTime<Utc>,
+    location: &Location,
+    observer_height_m: f64,
+) -> Result<Option<(DateTime<Utc>, DateTime<Utc>)>> {
+    let sun_altitude = BodyProfile::SunUpperLimb.horizon_altitude(observer_height_m)?;
+    sun_events(date, location, SolarModel::LowPrecision, sun_altitude)
+}
+
+/// Calculates sunrise and sunset times with a chosen solar position model.
+///
+/// As [`sun_rise_set`], which is this function with
+/// [`SolarModel::LowPrecision`]. The events pair the same way for both
+/// models: the sunrise before and the sunset after the solar transit
+/// nearest to noon UTC on the date.
+///
+/// # Arguments
+/// * `date` - Date to calculate for
+/// * `location` - Observer's location
+/// * `model` - Solar position model
+///
+/// # Returns
+/// - `Ok(Some((sunrise, sunset)))` - Times in UTC
+/// - `Ok(None)` - Sun doesn't rise or set (polar day/night)
+///
+/// # Errors
+/// With [`SolarModel::Ephemeris`], whatever
+/// [`almanac::apparent_place`](crate::almanac::apparent_place) returns.
+///
+/// # Example
+/// ```
+/// # use chrono::{TimeZone, Utc};
+/// # use astro_math::Location;
+/// use astro_math::rise_set::{sun_rise_set_with_model, SolarModel};
+///
+/// // USNO: sunrise 06:44, sunset 17:41 UTC at Greenwich on 2024 March 1
+/// let greenwich = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let date = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+/// let (rise, set) = sun_rise_set_with_model(date, &greenwich, SolarModel::Ephemeris).unwrap().unwrap();
+/// assert_eq!(rise.format("%H:%M").to_string(), "06:44");
+/// assert_eq!(set.format("%H:%M").to_string(), "17:41");
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(location)))]
+pub fn sun_rise_set_with_model(
+    date: DateTime<Utc>,
+    location: &Location,
+    model: SolarModel,
+) -> Result<Option<(DateTime<Utc>, DateTime<Utc>)>> {
+    sun_events(date, location, model, BodyProfile::SunUpperLimb.standard_altitude())
+}
+
+/// Sunrise and sunset with the Sun's center at `sun_altitude`.
+fn sun_events(
+    date: DateTime<Utc>,
+    location: &Location,
+    model: SolarModel,
+    sun_altitude: f64,
+) -> Result<Option<(DateTime<Utc>, DateTime<Utc>)>> {
+    let events_for = |(ra, dec): (f64, f64)| rise_transit_set(ra, dec, date, location, Some(sun_altitude));
+
+    match model {
+        SolarModel::LowPrecision => {
+            let position = crate::sun::sun_position_low_precision(julian_date(date));
+            Ok(events_for(position)?.map(|(rise, _, set)| (rise, set)))
+        }
+        SolarModel::Ephemeris => {
+            let apparent = |t| {
+                let place = apparent_place(AlmanacBody::Sun, t)?;
+                Ok::<_, AstroError>((place.ra, place.dec))
+            };
+            let Some((mut rise, _, mut set)) = events_for(apparent(date)?)? else {
+                return Ok(None);
+            };
+            // The Sun moves about 1° a day, so each event converges to the
+            // second within a few passes
+            for _ in 0..3 {
+                let (Some(at_rise), Some(at_set)) = (events_for(apparent(rise)?)?, events_for(apparent(set)?)?) else {
+                    return Ok(None);
+                };
+                (rise, set) = (at_rise.0, at_set.2);
+            }
+            Ok(Some((rise, set)))
+        }
     }
 }
 
+/// Calculates moonrise and moonset times for a given date.
+///
+/// The Moon moves about 13° a day and its distance changes through the
+/// month, so rather than fixing one position for the date this is
+/// [`body_rise_set`] for [`AlmanacBody::Moon`]: it follows the Moon's
+/// apparent place through the day and finds where its geocentric altitude
+/// crosses [`moon_standard_altitude`] for the current distance (computed as
+/// the topocentric altitude of its upper limb). [`body_rise_set_from_height`]
+/// gives the events for an elevated observer.
+///
+/// Moonrise is about 50 minutes later each day, so once a month there is no
+/// moonrise on the date, and once a month no moonset.
+///
+/// # Arguments
+/// * `date` - Date to calculate for; events are searched for over the UTC day
+/// * `location` - Observer's location
+///
+/// # Returns
+/// `(moonrise, moonset)` in UTC, each `None` if the event does not happen
+/// on that day
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if the location's latitude or
+/// longitude is out of range.
+///
+/// # Example
+/// ```
+/// # use chrono::{TimeZone, Utc};
+/// # use astro_math::Location;
+/// # use astro_math::rise_set::moon_rise_set;
+/// let location = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+///
+/// let (moonrise, moonset) = moon_rise_set(date, &location).unwrap();
+/// if let Some(moonrise) = moonrise {
+///     println!("Moonrise {}", moonrise);
+/// }
+/// # assert!(moonrise.is_some() && moonset.is_some());
+/// ```
+pub fn moon_rise_set(
+    date: DateTime<Utc>,
+    location: &Location,
+) -> MoonRiseSetResult {
+    body_rise_set(&AlmanacBody::Moon, date, location)
+}
+
+/// Calculates the Moon's upper meridian transit for a given date.
+///
+/// [`body_transit`] for [`AlmanacBody::Moon`], following the Moon's
+/// topocentric place (including its parallax of up to a degree) through
+/// the day. Like moonrise and moonset, the transit is about 50 minutes
+/// later each day, so once a month there is none on the date.
+///
+/// # Arguments
+/// * `date` - Date to calculate for; the transit is searched for over the UTC day
+/// * `location` - Observer's location
+///
+/// # Returns
+/// The transit time in UTC, `None` if there is no upper transit on that day
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if the latitude or
+/// longitude is out of range.
+///
+/// # Example
+/// ```
+/// # use chrono::{TimeZone, Utc};
+/// # use astro_math::Location;
+/// # use astro_math::rise_set::moon_transit;
+/// # use astro_math::rise_set::moon_rise_set;
+/// let location = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+/// // First quarter: the Moon rises before noon and sets after midnight
+/// let date = Utc.with_ymd_and_hms(2024, 3, 17, 0, 0, 0).unwrap();
+///
+/// let (moonrise, _) = moon_rise_set(date, &location).unwrap();
+/// let transit = moon_transit(date, &location).unwrap().unwrap();
+/// assert!(moonrise.unwrap() < transit);
+/// ```
+pub fn moon_transit(date: DateTime<Utc>, location: &Location) -> Result<Option<DateTime<Utc>>> {
+    body_transit(&AlmanacBody::Moon, date, location)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -356,4 +1277,4 @@ mod tests {
         let daylight_hours = (sunset - sunrise).num_hours();
         assert!(daylight_hours > 8 && daylight_hours < 18);
     }
-}
\ No newline at end of file
+}
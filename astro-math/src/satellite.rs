@@ -0,0 +1,854 @@
+//! Where an Earth satellite such as the ISS appears in the sky, from a
+//! two-line element set (TLE).
+//!
+//! A TLE gives a satellite's mean orbit at an epoch. [`Tle`] parses one
+//! (checking the line checksums), and [`satellite_alt_az`] propagates it to
+//! a time and returns the altitude and azimuth seen from a location, in the
+//! same form as [`solar_azimuth_elevation`](crate::sun::solar_azimuth_elevation)
+//! and [`ra_dec_to_alt_az`](crate::transforms::ra_dec_to_alt_az) give them
+//! for the Sun and stars. [`iss_alt_az`] does the same for ISS elements,
+//! picking them out of a multi-satellite TLE file by catalog number.
+//!
+//! No elements are built in: low orbits change too quickly for any copy in
+//! a library to stay useful. Fetch current elements (e.g. CelesTrak's
+//! `stations.txt`, which lists the ISS and the Chinese space station) and
+//! refresh them every day or two.
+//!
+//! Near-Earth orbits (periods under 225 minutes, which covers the ISS and
+//! the Starlink and OneWeb constellations) are propagated with [`Sgp4`], a
+//! pure-Rust SGP4 that reproduces the reference implementation. A TLE is
+//! itself only good to about a km at epoch, growing by a few km per day —
+//! a few arcminutes for a low satellite overhead, enough to predict which
+//! exposures a pass will cross. Deep-space orbits would need the lunar and
+//! solar terms of SDP4; they get a Keplerian orbit with the secular J2 drift
+//! and the TLE's mean-motion derivative instead, good to tens of km within
+//! a day of the epoch. Propagation more than [`MAX_PROPAGATION_DAYS`] from
+//! the epoch is refused.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::satellite::{satellite_alt_az, Tle};
+//! use astro_math::Location;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let iss = Tle::parse(
+//!     "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927",
+//!     "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+//! )
+//! .unwrap();
+//! let site = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+//! let dt = Utc.with_ymd_and_hms(2008, 9, 20, 18, 0, 0).unwrap();
+//! let (alt, az) = satellite_alt_az(&iss, dt, &site).unwrap();
+//! assert!((-90.0..=90.0).contains(&alt) && (0.0..360.0).contains(&az));
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidFormat` for malformed TLE lines or bad checksums
+//! - `AstroError::OutOfRange` for an out-of-range latitude or longitude, or a
+//!   time more than [`MAX_PROPAGATION_DAYS`] from the element epoch
+//! - `AstroError::CalculationError` for elements describing an orbit that
+//!   has decayed, a deep-space orbit given to [`Sgp4::new`], or no ISS entry
+//!   in a TLE file
+
+use crate::error::{validate_range, AstroError, Result};
+use crate::observer::terrestrial_position_km;
+use crate::orbit::solve_kepler;
+use crate::sidereal::gmst_two_part;
+use crate::time::julian_date_two_part;
+use crate::vec3::{dot, norm, Vec3};
+use crate::Location;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use std::f64::consts::TAU;
+
+/// NORAD catalog number of the International Space Station
+pub const ISS_NORAD_ID: u32 = 25544;
+
+/// NORAD catalog number of the Chinese space station's core module, Tianhe
+pub const CSS_NORAD_ID: u32 = 48274;
+
+/// Furthest from the element epoch, in days, that positions are computed
+pub const MAX_PROPAGATION_DAYS: f64 = 14.0;
+
+/// WGS-72 equatorial radius in km, the unit of length of TLE theory
+const EARTH_RADIUS_KM: f64 = 6378.135;
+
+/// WGS-72 √(GM) in Earth radii^1.5 per minute
+const KE: f64 = 0.07436691613317342;
+
+/// WGS-72 second zonal harmonic
+const J2: f64 = 1.082616e-3;
+
+/// WGS-72 J2/2
+const K2: f64 = J2 / 2.0;
+
+/// WGS-72 fourth zonal harmonic
+const J4: f64 = -1.65597e-6;
+
+/// WGS-72 third zonal harmonic divided by J2
+const J3OJ2: f64 = -2.53881e-6 / J2;
+
+/// Minutes per day
+const DAY_MINUTES: f64 = 1440.0;
+
+/// Mean orbital elements of one satellite from a two-line element set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tle {
+    /// Name from the title line of a three-line set, if there was one
+    pub name: Option<String>,
+    /// NORAD catalog number
+    pub norad_id: u32,
+    /// Epoch of the elements (UTC)
+    pub epoch: DateTime<Utc>,
+    /// First derivative of mean motion divided by two, in rev/day²
+    pub mean_motion_dot: f64,
+    /// SGP4 drag term B* in inverse Earth radii
+    pub bstar: f64,
+    /// Inclination in degrees
+    pub inclination_deg: f64,
+    /// Right ascension of the ascending node in degrees
+    pub raan_deg: f64,
+    /// Eccentricity
+    pub eccentricity: f64,
+    /// Argument of perigee in degrees
+    pub arg_perigee_deg: f64,
+    /// Mean anomaly at epoch in degrees
+    pub mean_anomaly_deg: f64,
+    /// Mean motion in revolutions per day
+    pub mean_motion: f64,
+}
+
+impl Tle {
+    /// Parses a two-line element set.
+    ///
+    /// # Arguments
+    /// * `line1` - First line, starting `1 `
+    /// * `line2` - Second line, starting `2 `
+    ///
+    /// # Returns
+    /// The elements, with no name
+    ///
+    /// # Errors
+    /// Returns `AstroError::InvalidFormat` if either line is short, has the
+    /// wrong line number or a bad checksum, the catalog numbers differ, or a
+    /// field does not parse.
+    pub fn parse(line1: &str, line2: &str) -> Result<Self> {
+        let (line1, line2) = (line1.trim_end(), line2.trim_end());
+        check_line(line1, '1')?;
+        check_line(line2, '2')?;
+
+        let norad_id = field(line1, 2..7, "catalog number")?;
+        let second_id: u32 = field(line2, 2..7, "catalog number")?;
+        if norad_id != second_id {
+            return Err(invalid(line2, format!("catalog number {} does not match line 1 ({})", second_id, norad_id)));
+        }
+
+        let year: i32 = field(line1, 18..20, "epoch year")?;
+        let day: f64 = field(line1, 20..32, "epoch day")?;
+        if !(1.0..367.0).contains(&day) {
+            return Err(invalid(line1, format!("epoch day {} is out of range", day)));
+        }
+        let year = if year < 57 { 2000 + year } else { 1900 + year };
+        let new_year = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+        let epoch = new_year + Duration::microseconds(((day - 1.0) * 86400e6).round() as i64);
+
+        Ok(Tle {
+            name: None,
+            norad_id,
+            epoch,
+            mean_motion_dot: field(line1, 33..43, "mean motion derivative")?,
+            bstar: implied_decimal(line1, 53..61, "B*")?,
+            inclination_deg: field(line2, 8..16, "inclination")?,
+            raan_deg: field(line2, 17..25, "right ascension of node")?,
+            eccentricity: field::<f64>(line2, 26..33, "eccentricity").map(|e| e * 1e-7)?,
+            arg_perigee_deg: field(line2, 34..42, "argument of perigee")?,
+            mean_anomaly_deg: field(line2, 43..51, "mean anomaly")?,
+            mean_motion: field(line2, 52..63, "mean motion")?,
+        })
+    }
+
+    /// Parses every element set in a TLE file.
+    ///
+    /// Accepts two-line sets and three-line sets with a title line (as
+    /// published by CelesTrak), mixed freely; blank lines are skipped.
+    ///
+    /// # Errors
+    /// Returns `AstroError::InvalidFormat` for the first set that does not
+    /// parse, or a first line with no second line after it.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::satellite::{Tle, ISS_NORAD_ID};
+    ///
+    /// let text = "ISS (ZARYA)
+    /// 1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+    /// 2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537
+    /// ";
+    /// let sets = Tle::parse_all(text).unwrap();
+    /// assert_eq!(sets[0].name.as_deref(), Some("ISS (ZARYA)"));
+    /// assert_eq!(sets[0].norad_id, ISS_NORAD_ID);
+    /// ```
+    pub fn parse_all(text: &str) -> Result<Vec<Self>> {
+        let mut lines = text.lines().map(str::trim_end).filter(|line| !line.trim().is_empty());
+        let mut sets = Vec::new();
+        while let Some(line) = lines.next() {
+            let (name, line1) = if line.starts_with("1 ") {
+                (None, line)
+            } else {
+                let line1 = lines.next().ok_or_else(|| invalid(line, "title line with no elements".to_string()))?;
+                // CelesTrak prefixes titles with "0 " in some formats
+                (Some(line.strip_prefix("0 ").unwrap_or(line).trim().to_string()), line1)
+            };
+            let line2 = lines.next().ok_or_else(|| invalid(line1, "line 1 with no line 2".to_string()))?;
+            let mut tle = Tle::parse(line1, line2)?;
+            tle.name = name;
+            sets.push(tle);
+        }
+        Ok(sets)
+    }
+
+    /// Orbital period from the mean motion, in minutes.
+    pub fn period_minutes(&self) -> f64 {
+        DAY_MINUTES / self.mean_motion
+    }
+
+    /// Position of the satellite in the TEME frame (true equator, mean
+    /// equinox of date), the frame of TLE theory.
+    ///
+    /// Near-Earth orbits are propagated with [`Sgp4`]. Deep-space orbits
+    /// (periods of 225 minutes or more) use a Keplerian orbit with the
+    /// secular J2 drift and the TLE's mean-motion derivative, good to tens
+    /// of km within a day of the epoch.
+    ///
+    /// # Arguments
+    /// * `datetime` - Time (UTC)
+    ///
+    /// # Returns
+    /// `[x, y, z]` in km from the Earth's center
+    ///
+    /// # Errors
+    /// - `AstroError::OutOfRange` if `datetime` is more than
+    ///   [`MAX_PROPAGATION_DAYS`] from the epoch
+    /// - `AstroError::CalculationError` if the elements do not describe a
+    ///   bound orbit above the Earth's surface at that time
+    pub fn position_teme(&self, datetime: DateTime<Utc>) -> Result<Vec3> {
+        match Sgp4::init(self)? {
+            Some(sgp4) => Ok(sgp4.position_velocity_teme(datetime)?.0),
+            None => self.secular_position_teme(datetime),
+        }
+    }
+
+    /// Propagates a deep-space orbit with its secular J2 and drag terms
+    /// only, in place of SDP4.
+    fn secular_position_teme(&self, datetime: DateTime<Utc>) -> Result<Vec3> {
+        let minutes = minutes_since(self.epoch, datetime);
+        let days = minutes / DAY_MINUTES;
+        if days.abs() > MAX_PROPAGATION_DAYS {
+            return Err(AstroError::OutOfRange {
+                parameter: "days from TLE epoch",
+                value: days,
+                min: -MAX_PROPAGATION_DAYS,
+                max: MAX_PROPAGATION_DAYS,
+            });
+        }
+        let e = self.eccentricity;
+        let i = self.inclination_deg.to_radians();
+        let cos_i = i.cos();
+
+        // Recover the Brouwer mean motion from the Kozai value in the TLE
+        let n_kozai = self.mean_motion * TAU / DAY_MINUTES;
+        let a1 = (KE / n_kozai).powf(2.0 / 3.0);
+        let beta2 = 1.0 - e * e;
+        let shape = 1.5 * K2 * (3.0 * cos_i * cos_i - 1.0) / beta2.powf(1.5);
+        let d1 = shape / (a1 * a1);
+        let a0 = a1 * (1.0 - d1 / 3.0 - d1 * d1 - 134.0 / 81.0 * d1 * d1 * d1);
+        let d0 = shape / (a0 * a0);
+        let n0 = n_kozai / (1.0 + d0);
+        let a0 = a0 / (1.0 - d0);
+
+        // Secular J2 rates, radians per minute
+        let p2 = (a0 * beta2).powi(2);
+        let raan_rate = -3.0 * K2 * n0 * cos_i / p2;
+        let perigee_rate = 1.5 * K2 * n0 * (5.0 * cos_i * cos_i - 1.0) / p2;
+        let anomaly_rate = n0 + 1.5 * K2 * n0 * beta2.sqrt() * (3.0 * cos_i * cos_i - 1.0) / p2;
+
+        // Drag: the mean motion changes linearly, so the orbit shrinks
+        let n_dot = self.mean_motion_dot * 2.0 * TAU / (DAY_MINUTES * DAY_MINUTES);
+        let n = n0 + n_dot * minutes;
+        if n <= 0.0 {
+            return Err(decayed(self));
+        }
+        let a = a0 * (n0 / n).powf(2.0 / 3.0);
+        if a * (1.0 - e) <= 1.0 || e >= 1.0 {
+            return Err(decayed(self));
+        }
+
+        let raan = self.raan_deg.to_radians() + raan_rate * minutes;
+        let perigee = self.arg_perigee_deg.to_radians() + perigee_rate * minutes;
+        let mean_anomaly = self.mean_anomaly_deg.to_radians() + anomaly_rate * minutes + 0.5 * n_dot * minutes * minutes;
+
+        let eccentric_anomaly = solve_kepler(mean_anomaly, e);
+        let (sin_e, cos_e) = eccentric_anomaly.sin_cos();
+        // Perifocal coordinates in Earth radii
+        let xp = a * (cos_e - e);
+        let yp = a * beta2.sqrt() * sin_e;
+
+        let (sin_w, cos_w) = perigee.sin_cos();
+        let (sin_o, cos_o) = raan.sin_cos();
+        let sin_i = i.sin();
+        let ux = [cos_o * cos_w - sin_o * sin_w * cos_i, sin_o * cos_w + cos_o * sin_w * cos_i, sin_w * sin_i];
+        let uy = [-cos_o * sin_w - sin_o * cos_w * cos_i, -sin_o * sin_w + cos_o * cos_w * cos_i, cos_w * sin_i];
+        Ok([0, 1, 2].map(|k| (xp * ux[k] + yp * uy[k]) * EARTH_RADIUS_KM))
+    }
+
+    /// Altitude and azimuth of the satellite from a location.
+    ///
+    /// See [`satellite_alt_az`].
+    pub fn alt_az(&self, datetime: DateTime<Utc>, location: &Location) -> Result<(f64, f64)> {
+        satellite_alt_az(self, datetime, location)
+    }
+}
+
+/// An SGP4 propagator initialised from one element set.
+///
+/// This is the near-Earth part of SGP4 as revised by Vallado et al.,
+/// "Revisiting Spacetrack Report #3" (2006), with WGS-72 constants: secular
+/// J2–J4 and drag, plus the long- and short-period J2 and J3 terms. Against
+/// the reference implementation it agrees to well under a metre; the TLE
+/// itself is the limit, typically about 1 km at epoch growing by a few km
+/// per day for a low orbit.
+///
+/// Orbits with a period of 225 minutes or more need the lunar and solar
+/// terms of SDP4, which are not implemented; [`Sgp4::new`] refuses them
+/// and [`Tle::position_teme`] falls back to a secular propagator for them.
+///
+/// # Example
+/// ```
+/// use astro_math::satellite::{Sgp4, Tle};
+///
+/// let tle = Tle::parse(
+///     "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753",
+///     "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+/// )
+/// .unwrap();
+/// let sgp4 = Sgp4::new(&tle).unwrap();
+/// let (position, _velocity) = sgp4.propagate(360.0).unwrap();
+/// // Vallado et al. (2006), test case 00005 at 360 minutes
+/// assert!((position[0] + 7154.03120202).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sgp4 {
+    norad_id: u32,
+    epoch: DateTime<Utc>,
+    bstar: f64,
+    ecco: f64,
+    inclo: f64,
+    nodeo: f64,
+    argpo: f64,
+    mo: f64,
+    /// Brouwer mean motion, radians per minute
+    no: f64,
+    /// Perigee below 220 km: drop the higher-order drag terms
+    simple: bool,
+    con41: f64,
+    x1mth2: f64,
+    x7thm1: f64,
+    eta: f64,
+    mdot: f64,
+    argpdot: f64,
+    nodedot: f64,
+    nodecf: f64,
+    omgcof: f64,
+    xmcof: f64,
+    delmo: f64,
+    sinmao: f64,
+    xlcof: f64,
+    aycof: f64,
+    cc1: f64,
+    cc4: f64,
+    cc5: f64,
+    t2cof: f64,
+    d2: f64,
+    d3: f64,
+    d4: f64,
+    t3cof: f64,
+    t4cof: f64,
+    t5cof: f64,
+}
+
+impl Sgp4 {
+    /// Initialises SGP4 for a satellite.
+    ///
+    /// # Arguments
+    /// * `tle` - The satellite's elements
+    ///
+    /// # Errors
+    /// Returns `AstroError::CalculationError` if the orbit has a period of
+    /// 225 minutes or more (a deep-space orbit), or the elements do not
+    /// describe a bound orbit.
+    pub fn new(tle: &Tle) -> Result<Self> {
+        Sgp4::init(tle)?.ok_or_else(|| AstroError::CalculationError {
+            calculation: "SGP4 initialisation",
+            reason: format!(
+                "catalog number {} has a period of {:.1} min; deep-space orbits need SDP4",
+                tle.norad_id,
+                tle.period_minutes()
+            ),
+        })
+    }
+
+    /// Initialises SGP4, or returns `None` for a deep-space orbit.
+    fn init(tle: &Tle) -> Result<Option<Self>> {
+        let ecco = tle.eccentricity;
+        let inclo = tle.inclination_deg.to_radians();
+        let argpo = tle.arg_perigee_deg.to_radians();
+        let mo = tle.mean_anomaly_deg.to_radians();
+        let n_kozai = tle.mean_motion * TAU / DAY_MINUTES;
+        if n_kozai.is_nan() || n_kozai <= 0.0 || !(0.0..1.0).contains(&ecco) {
+            return Err(decayed(tle));
+        }
+
+        // Recover the Brouwer mean motion and semi-major axis from the
+        // Kozai mean motion in the TLE
+        let eccsq = ecco * ecco;
+        let omeosq = 1.0 - eccsq;
+        let rteosq = omeosq.sqrt();
+        let (sinio, cosio) = inclo.sin_cos();
+        let cosio2 = cosio * cosio;
+        let ak = (KE / n_kozai).powf(2.0 / 3.0);
+        let d1 = 0.75 * J2 * (3.0 * cosio2 - 1.0) / (rteosq * omeosq);
+        let del = d1 / (ak * ak);
+        let adel = ak * (1.0 - del * del - del * (1.0 / 3.0 + 134.0 * del * del / 81.0));
+        let del = d1 / (adel * adel);
+        let no = n_kozai / (1.0 + del);
+        if TAU / no >= 225.0 {
+            return Ok(None);
+        }
+        let ao = (KE / no).powf(2.0 / 3.0);
+        let po = ao * omeosq;
+        let con42 = 1.0 - 5.0 * cosio2;
+        let con41 = 3.0 * cosio2 - 1.0;
+        let posq = po * po;
+        let rp = ao * (1.0 - ecco);
+
+        // Atmospheric density parameters, adjusted for a low perigee
+        let mut sfour = 78.0 / EARTH_RADIUS_KM + 1.0;
+        let mut qzms24 = ((120.0 - 78.0) / EARTH_RADIUS_KM).powi(4);
+        let perigee_km = (rp - 1.0) * EARTH_RADIUS_KM;
+        if perigee_km < 156.0 {
+            let s = if perigee_km < 98.0 { 20.0 } else { perigee_km - 78.0 };
+            qzms24 = ((120.0 - s) / EARTH_RADIUS_KM).powi(4);
+            sfour = s / EARTH_RADIUS_KM + 1.0;
+        }
+
+        let pinvsq = 1.0 / posq;
+        let tsi = 1.0 / (ao - sfour);
+        let eta = ao * ecco * tsi;
+        let etasq = eta * eta;
+        let eeta = ecco * eta;
+        let psisq = (1.0 - etasq).abs();
+        let coef = qzms24 * tsi.powi(4);
+        let coef1 = coef / psisq.powf(3.5);
+        let cc2 = coef1
+            * no
+            * (ao * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+                + 0.375 * J2 * tsi / psisq * con41 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+        let cc1 = tle.bstar * cc2;
+        let cc3 = if ecco > 1e-4 { -2.0 * coef * tsi * J3OJ2 * no * sinio / ecco } else { 0.0 };
+        let x1mth2 = 1.0 - cosio2;
+        let cc4 = 2.0
+            * no
+            * coef1
+            * ao
+            * omeosq
+            * (eta * (2.0 + 0.5 * etasq) + ecco * (0.5 + 2.0 * etasq)
+                - J2 * tsi / (ao * psisq)
+                    * (-3.0 * con41 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                        + 0.75 * x1mth2 * (2.0 * etasq - eeta * (1.0 + etasq)) * (2.0 * argpo).cos()));
+        let cc5 = 2.0 * coef1 * ao * omeosq * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+        // Secular rates of the mean anomaly, perigee and node
+        let cosio4 = cosio2 * cosio2;
+        let temp1 = 1.5 * J2 * pinvsq * no;
+        let temp2 = 0.5 * temp1 * J2 * pinvsq;
+        let temp3 = -0.46875 * J4 * pinvsq * pinvsq * no;
+        let mdot = no
+            + 0.5 * temp1 * rteosq * con41
+            + 0.0625 * temp2 * rteosq * (13.0 - 78.0 * cosio2 + 137.0 * cosio4);
+        let argpdot = -0.5 * temp1 * con42
+            + 0.0625 * temp2 * (7.0 - 114.0 * cosio2 + 395.0 * cosio4)
+            + temp3 * (3.0 - 36.0 * cosio2 + 49.0 * cosio4);
+        let xhdot1 = -temp1 * cosio;
+        let nodedot = xhdot1 + (0.5 * temp2 * (4.0 - 19.0 * cosio2) + 2.0 * temp3 * (3.0 - 7.0 * cosio2)) * cosio;
+
+        // Long-period J3 terms; guard the division for a retrograde
+        // equatorial orbit
+        let one_plus_cosio = if (cosio + 1.0).abs() > 1.5e-12 { 1.0 + cosio } else { 1.5e-12 };
+
+        let simple = rp < 220.0 / EARTH_RADIUS_KM + 1.0;
+        let (mut d2, mut d3, mut d4, mut t3cof, mut t4cof, mut t5cof) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        if !simple {
+            let cc1sq = cc1 * cc1;
+            d2 = 4.0 * ao * tsi * cc1sq;
+            let temp = d2 * tsi * cc1 / 3.0;
+            d3 = (17.0 * ao + sfour) * temp;
+            d4 = 0.5 * temp * ao * tsi * (221.0 * ao + 31.0 * sfour) * cc1;
+            t3cof = d2 + 2.0 * cc1sq;
+            t4cof = 0.25 * (3.0 * d3 + cc1 * (12.0 * d2 + 10.0 * cc1sq));
+            t5cof = 0.2 * (3.0 * d4 + 12.0 * cc1 * d3 + 6.0 * d2 * d2 + 15.0 * cc1sq * (2.0 * d2 + cc1sq));
+        }
+
+        Ok(Some(Sgp4 {
+            norad_id: tle.norad_id,
+            epoch: tle.epoch,
+            bstar: tle.bstar,
+            ecco,
+            inclo,
+            nodeo: tle.raan_deg.to_radians(),
+            argpo,
+            mo,
+            no,
+            simple,
+            con41,
+            x1mth2,
+            x7thm1: 7.0 * cosio2 - 1.0,
+            eta,
+            mdot,
+            argpdot,
+            nodedot,
+            nodecf: 3.5 * omeosq * xhdot1 * cc1,
+            omgcof: tle.bstar * cc3 * argpo.cos(),
+            xmcof: if ecco > 1e-4 { -2.0 / 3.0 * coef * tle.bstar / eeta } else { 0.0 },
+            delmo: (1.0 + eta * mo.cos()).powi(3),
+            sinmao: mo.sin(),
+            xlcof: -0.25 * J3OJ2 * sinio * (3.0 + 5.0 * cosio) / one_plus_cosio,
+            aycof: -0.5 * J3OJ2 * sinio,
+            cc1,
+            cc4,
+            cc5,
+            t2cof: 1.5 * cc1,
+            d2,
+            d3,
+            d4,
+            t3cof,
+            t4cof,
+            t5cof,
+        }))
+    }
+
+    /// Position and velocity a given time after the element epoch.
+    ///
+    /// # Arguments
+    /// * `minutes` - Time since the epoch of the elements, in minutes
+    ///
+    /// # Returns
+    /// `(position, velocity)` in the TEME frame, in km and km/s
+    ///
+    /// # Errors
+    /// - `AstroError::OutOfRange` if `minutes` is more than
+    ///   [`MAX_PROPAGATION_DAYS`] from the epoch
+    /// - `AstroError::CalculationError` if the orbit has decayed by then
+    pub fn propagate(&self, minutes: f64) -> Result<(Vec3, Vec3)> {
+        let days = minutes / DAY_MINUTES;
+        if days.is_nan() || days.abs() > MAX_PROPAGATION_DAYS {
+            return Err(AstroError::OutOfRange {
+                parameter: "days from TLE epoch",
+                value: days,
+                min: -MAX_PROPAGATION_DAYS,
+                max: MAX_PROPAGATION_DAYS,
+            });
+        }
+        let t = minutes;
+
+        // Secular gravity and drag
+        let xmdf = self.mo + self.mdot * t;
+        let argpdf = self.argpo + self.argpdot * t;
+        let nodedf = self.nodeo + self.nodedot * t;
+        let t2 = t * t;
+        let nodem = nodedf + self.nodecf * t2;
+        let mut argpm = argpdf;
+        let mut mm = xmdf;
+        let mut tempa = 1.0 - self.cc1 * t;
+        let mut tempe = self.bstar * self.cc4 * t;
+        let mut templ = self.t2cof * t2;
+        if !self.simple {
+            let delomg = self.omgcof * t;
+            let delm = self.xmcof * ((1.0 + self.eta * xmdf.cos()).powi(3) - self.delmo);
+            mm = xmdf + delomg + delm;
+            argpm = argpdf - delomg - delm;
+            let t3 = t2 * t;
+            let t4 = t3 * t;
+            tempa -= self.d2 * t2 + self.d3 * t3 + self.d4 * t4;
+            tempe += self.bstar * self.cc5 * (mm.sin() - self.sinmao);
+            templ += self.t3cof * t3 + t4 * (self.t4cof + t * self.t5cof);
+        }
+
+        let am = (KE / self.no).powf(2.0 / 3.0) * tempa * tempa;
+        let nm = KE / am.powf(1.5);
+        let em = self.ecco - tempe;
+        if am.is_nan() || am <= 0.0 || !(-0.001..1.0).contains(&em) {
+            return Err(self.decayed());
+        }
+        let em = em.max(1e-6);
+        mm += self.no * templ;
+        let xlm = mm + argpm + nodem;
+        let nodem = nodem % TAU;
+        let argpm = argpm % TAU;
+        let mm = (xlm % TAU - argpm - nodem) % TAU;
+        let (sinim, cosim) = self.inclo.sin_cos();
+
+        // Long-period periodics
+        let axnl = em * argpm.cos();
+        let temp = 1.0 / (am * (1.0 - em * em));
+        let aynl = em * argpm.sin() + temp * self.aycof;
+        let xl = mm + argpm + nodem + temp * self.xlcof * axnl;
+
+        // Kepler's equation in the equinoctial elements
+        let u = (xl - nodem) % TAU;
+        let mut eo1 = u;
+        let mut iterations = 0;
+        let (sineo1, coseo1) = loop {
+            let (sin_e, cos_e) = eo1.sin_cos();
+            let step = (u - aynl * cos_e + axnl * sin_e - eo1) / (1.0 - cos_e * axnl - sin_e * aynl);
+            eo1 += step.clamp(-0.95, 0.95);
+            iterations += 1;
+            if step.abs() < 1e-12 || iterations == 10 {
+                break (sin_e, cos_e);
+            }
+        };
+
+        // Short-period periodics
+        let ecose = axnl * coseo1 + aynl * sineo1;
+        let esine = axnl * sineo1 - aynl * coseo1;
+        let el2 = axnl * axnl + aynl * aynl;
+        let pl = am * (1.0 - el2);
+        if pl < 0.0 {
+            return Err(self.decayed());
+        }
+        let rl = am * (1.0 - ecose);
+        let rdotl = am.sqrt() * esine / rl;
+        let rvdotl = pl.sqrt() / rl;
+        let betal = (1.0 - el2).sqrt();
+        let temp = esine / (1.0 + betal);
+        let sinu = am / rl * (sineo1 - aynl - axnl * temp);
+        let cosu = am / rl * (coseo1 - axnl + aynl * temp);
+        let su = sinu.atan2(cosu);
+        let sin2u = 2.0 * cosu * sinu;
+        let cos2u = 1.0 - 2.0 * sinu * sinu;
+        let temp1 = 0.5 * J2 / pl;
+        let temp2 = temp1 / pl;
+
+        let mrt = rl * (1.0 - 1.5 * temp2 * betal * self.con41) + 0.5 * temp1 * self.x1mth2 * cos2u;
+        if mrt < 1.0 {
+            return Err(self.decayed());
+        }
+        let su = su - 0.25 * temp2 * self.x7thm1 * sin2u;
+        let xnode = nodem + 1.5 * temp2 * cosim * sin2u;
+        let xinc = self.inclo + 1.5 * temp2 * cosim * sinim * cos2u;
+        let mvt = rdotl - nm * temp1 * self.x1mth2 * sin2u / KE;
+        let rvdot = rvdotl + nm * temp1 * (self.x1mth2 * cos2u + 1.5 * self.con41) / KE;
+
+        // Orientation vectors
+        let (sinsu, cossu) = su.sin_cos();
+        let (snod, cnod) = xnode.sin_cos();
+        let (sini, cosi) = xinc.sin_cos();
+        let xmx = -snod * cosi;
+        let xmy = cnod * cosi;
+        let u_hat = [xmx * sinsu + cnod * cossu, xmy * sinsu + snod * cossu, sini * sinsu];
+        let v_hat = [xmx * cossu - cnod * sinsu, xmy * cossu - snod * sinsu, sini * cossu];
+        let km_per_s = EARTH_RADIUS_KM * KE / 60.0;
+        Ok((
+            u_hat.map(|c| mrt * c * EARTH_RADIUS_KM),
+            [0, 1, 2].map(|k| (mvt * u_hat[k] + rvdot * v_hat[k]) * km_per_s),
+        ))
+    }
+
+    /// Position and velocity at a time.
+    ///
+    /// # Arguments
+    /// * `datetime` - Time (UTC)
+    ///
+    /// # Returns
+    /// `(position, velocity)` in the TEME frame, in km and km/s
+    ///
+    /// # Errors
+    /// As [`propagate`](Self::propagate).
+    pub fn position_velocity_teme(&self, datetime: DateTime<Utc>) -> Result<(Vec3, Vec3)> {
+        self.propagate(minutes_since(self.epoch, datetime))
+    }
+
+    fn decayed(&self) -> AstroError {
+        AstroError::CalculationError {
+            calculation: "TLE propagation",
+            reason: format!("orbit of catalog number {} has decayed", self.norad_id),
+        }
+    }
+}
+
+/// Computes where a satellite appears in the sky.
+///
+/// The position is topocentric and geometric: no refraction, and no
+/// correction for light time (a few ms for a low orbit). UT1 is taken equal
+/// to UTC.
+///
+/// # Arguments
+/// * `tle` - The satellite's elements
+/// * `datetime` - Time of observation (UTC)
+/// * `location` - Observer's location
+///
+/// # Returns
+/// `(altitude, azimuth)` in degrees, azimuth measured from north through
+/// east in [0, 360). Negative altitudes are below the horizon.
+///
+/// # Errors
+/// - `AstroError::OutOfRange` if the latitude or longitude is out of range,
+///   or `datetime` is more than [`MAX_PROPAGATION_DAYS`] from the epoch
+/// - `AstroError::CalculationError` if the orbit has decayed by `datetime`
+///
+/// # Example
+/// ```
+/// use astro_math::satellite::{satellite_alt_az, Tle};
+/// use astro_math::Location;
+///
+/// let iss = Tle::parse(
+///     "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927",
+///     "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+/// )
+/// .unwrap();
+/// let site = Location { latitude_deg: 40.0, longitude_deg: -75.0, altitude_m: 0.0 };
+/// let (alt, _az) = satellite_alt_az(&iss, iss.epoch, &site).unwrap();
+/// if alt > 10.0 {
+///     println!("ISS is up");
+/// }
+/// ```
+pub fn satellite_alt_az(tle: &Tle, datetime: DateTime<Utc>, location: &Location) -> Result<(f64, f64)> {
+    validate_range(location.latitude_deg, -90.0, 90.0, "latitude")?;
+    validate_range(location.longitude_deg, -180.0, 180.0, "longitude")?;
+    let teme = tle.position_teme(datetime)?;
+
+    // TEME to Earth-fixed: rotate by Greenwich mean sidereal time
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (sin_g, cos_g) = (gmst_two_part(jd1, jd2) * 15.0).to_radians().sin_cos();
+    let satellite = [cos_g * teme[0] + sin_g * teme[1], -sin_g * teme[0] + cos_g * teme[1], teme[2]];
+
+    let site = terrestrial_position_km(location);
+    let range = [0, 1, 2].map(|k| satellite[k] - site[k]);
+    let (sin_lat, cos_lat) = location.latitude_deg.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = location.longitude_deg.to_radians().sin_cos();
+    let east = dot(range, [-sin_lon, cos_lon, 0.0]);
+    let north = dot(range, [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat]);
+    let up = dot(range, [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat]);
+
+    let alt = (up / norm(range)).clamp(-1.0, 1.0).asin().to_degrees();
+    let az = east.atan2(north).to_degrees().rem_euclid(360.0);
+    Ok((alt, if az >= 360.0 { 0.0 } else { az }))
+}
+
+/// Computes where the International Space Station appears in the sky.
+///
+/// Finds the ISS ([`ISS_NORAD_ID`]) among the element sets in `tle_text`,
+/// such as a downloaded copy of CelesTrak's `stations.txt`, and calls
+/// [`satellite_alt_az`] with it.
+///
+/// # Arguments
+/// * `datetime` - Time of observation (UTC)
+/// * `location` - Observer's location
+/// * `tle_text` - Two- or three-line element sets including the ISS
+///
+/// # Returns
+/// `(altitude, azimuth)` in degrees; see [`satellite_alt_az`]
+///
+/// # Errors
+/// - `AstroError::InvalidFormat` if `tle_text` does not parse
+/// - `AstroError::CalculationError` if it has no ISS entry
+/// - As [`satellite_alt_az`]
+///
+/// # Example
+/// ```
+/// use astro_math::satellite::iss_alt_az;
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let stations = "ISS (ZARYA)
+/// 1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+/// 2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537
+/// ";
+/// let site = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let dt = Utc.with_ymd_and_hms(2008, 9, 21, 19, 30, 0).unwrap();
+/// let (alt, az) = iss_alt_az(dt, &site, stations).unwrap();
+/// assert!(alt.abs() <= 90.0 && (0.0..360.0).contains(&az));
+/// ```
+pub fn iss_alt_az(datetime: DateTime<Utc>, location: &Location, tle_text: &str) -> Result<(f64, f64)> {
+    let iss = Tle::parse_all(tle_text)?
+        .into_iter()
+        .find(|tle| tle.norad_id == ISS_NORAD_ID)
+        .ok_or_else(|| AstroError::CalculationError {
+            calculation: "ISS position",
+            reason: format!("no elements for catalog number {}", ISS_NORAD_ID),
+        })?;
+    satellite_alt_az(&iss, datetime, location)
+}
+
+/// Checks a TLE line's length, line number and modulo-10 checksum.
+fn check_line(line: &str, number: char) -> Result<()> {
+    if !line.is_ascii() || line.len() < 69 {
+        return Err(invalid(line, "expected 69 ASCII characters".to_string()));
+    }
+    if !line.starts_with(number) {
+        return Err(invalid(line, format!("expected line {}", number)));
+    }
+    let sum: u32 = line[..68]
+        .chars()
+        .map(|c| match c {
+            '-' => 1,
+            _ => c.to_digit(10).unwrap_or(0),
+        })
+        .sum();
+    let expected = line[68..69].parse::<u32>().map_err(|_| invalid(line, "checksum is not a digit".to_string()))?;
+    if sum % 10 != expected {
+        return Err(invalid(line, format!("checksum {} does not match computed {}", expected, sum % 10)));
+    }
+    Ok(())
+}
+
+/// Parses the fixed columns `range` of a line.
+fn field<T: std::str::FromStr>(line: &str, range: std::ops::Range<usize>, name: &str) -> Result<T> {
+    let text = line[range].trim();
+    text.parse().map_err(|_| invalid(line, format!("{} '{}' is not a number", name, text)))
+}
+
+/// Parses a field in the TLE's implied-decimal exponent notation, e.g.
+/// `-11606-4` for -0.11606e-4.
+fn implied_decimal(line: &str, range: std::ops::Range<usize>, name: &str) -> Result<f64> {
+    let text = line[range].trim();
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let split = digits.rfind(['-', '+']).filter(|&k| k > 0);
+    let (mantissa, exponent) = match split {
+        Some(k) => (&digits[..k], &digits[k..]),
+        None => (digits, "0"),
+    };
+    match (format!("0.{}", mantissa).parse::<f64>(), exponent.parse::<i32>()) {
+        (Ok(m), Ok(x)) if mantissa.chars().all(|c| c.is_ascii_digit()) => Ok(sign * m * 10f64.powi(x)),
+        _ => Err(invalid(line, format!("{} '{}' is not a number", name, text))),
+    }
+}
+
+/// Minutes from `epoch` to `datetime`.
+fn minutes_since(epoch: DateTime<Utc>, datetime: DateTime<Utc>) -> f64 {
+    (datetime - epoch).num_milliseconds() as f64 / 60e3
+}
+
+fn invalid(line: &str, reason: String) -> AstroError {
+    AstroError::InvalidFormat { format: "TLE", input: line.to_string(), reason }
+}
+
+fn decayed(tle: &Tle) -> AstroError {
+    AstroError::CalculationError {
+        calculation: "TLE propagation",
+        reason: format!("orbit of catalog number {} has decayed", tle.norad_id),
+    }
+}
@@ -0,0 +1,408 @@
+//! Scheduling primitives for observing queues.
+//!
+//! A target is best observed around its meridian transit, where it is
+//! highest and the airmass lowest. [`group_by_transit`] finds each target's
+//! best time within a night (the transit when it falls inside the night,
+//! otherwise the end of the night nearer to it) and clusters targets whose
+//! windows around those times overlap. Each group is a block of targets
+//! that can share a slot in a queue, e.g. for one calibration or one
+//! slew-optimised sequence.
+//!
+//! A wide survey field is observable only when all of it is, not just its
+//! centre. [`field_observability`] finds when the corners of a footprint,
+//! or the rim of a circular field, meet altitude and airmass limits.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::{schedule, Location};
+//! use chrono::{TimeZone, Utc};
+//!
+//! let site = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+//! let night = (
+//!     Utc.with_ymd_and_hms(2024, 10, 1, 2, 0, 0).unwrap(),
+//!     Utc.with_ymd_and_hms(2024, 10, 1, 12, 0, 0).unwrap(),
+//! );
+//! // M31 and M33 transit 50 minutes apart; M1 transits hours later
+//! let targets = [(10.6847, 41.2689), (23.4621, 30.6602), (83.6331, 22.0145)];
+//!
+//! let groups = schedule::group_by_transit(&targets, night, &site, 60.0).unwrap();
+//! assert_eq!(groups.len(), 2);
+//! assert_eq!(groups[0].indices(), vec![0, 1]);
+//! assert_eq!(groups[1].indices(), vec![2]);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::OutOfRange` for a night that does not end after it starts,
+//!   a non-positive window, an out-of-range location, or out-of-range
+//!   field constraints
+//! - `AstroError::CalculationError` for a field polygon of fewer than
+//!   three vertices
+
+use crate::airmass::airmass_kasten_young;
+use crate::angles::offset_by;
+use crate::error::{validate_dec, validate_ra, validate_range, AstroError, Result};
+use crate::events::{find_zeros, Crossing};
+use crate::fixed_site::FixedSite;
+use crate::Location;
+use chrono::{DateTime, Duration, Utc};
+
+/// Ratio of a mean solar day to a sidereal day
+const SIDEREAL_RATE: f64 = 1.002_737_909_35;
+
+/// A target at its best time of the night.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScheduledTarget {
+    /// Index of the target in the input slice
+    pub index: usize,
+    /// Meridian transit if it falls within the night, otherwise the start
+    /// or end of the night, whichever is nearer the transit
+    pub optimal_time: DateTime<Utc>,
+    /// Altitude at `optimal_time` in degrees
+    pub altitude_deg: f64,
+    /// Whether `optimal_time` is the meridian transit
+    pub transits: bool,
+}
+
+/// Targets whose observation windows overlap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitGroup {
+    /// Members in order of optimal time
+    pub targets: Vec<ScheduledTarget>,
+    /// Start of the earliest member's window, no earlier than the night
+    pub start: DateTime<Utc>,
+    /// End of the latest member's window, no later than the night
+    pub end: DateTime<Utc>,
+}
+
+impl TransitGroup {
+    /// Indices of the members in the input slice, in order of optimal time.
+    pub fn indices(&self) -> Vec<usize> {
+        self.targets.iter().map(|t| t.index).collect()
+    }
+}
+
+/// Clusters targets whose optimal observation windows overlap.
+///
+/// Each target's window is `window_minutes` long, centred on its optimal
+/// time. Two windows overlap when the optimal times are less than
+/// `window_minutes` apart, and groups are formed transitively: a target
+/// joins a group if its window overlaps that of the previous member.
+/// Targets that stay below the horizon all night are left out.
+///
+/// # Arguments
+/// * `targets` - `(ra, dec)` positions in degrees
+/// * `night` - `(start, end)` of the observing night
+/// * `location` - Observer location
+/// * `window_minutes` - Length of each target's observation window
+///
+/// # Returns
+/// Groups in order of time, each with its members in order of optimal time
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if a target's RA or Dec is out of range
+/// - `AstroError::OutOfRange` if the night does not end after it starts,
+///   `window_minutes` is not positive and finite, or the location is out of range
+///
+/// # Example
+/// ```
+/// use astro_math::{schedule, Location};
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: -30.24, longitude_deg: -70.74, altitude_m: 2715.0 };
+/// let night = (
+///     Utc.with_ymd_and_hms(2024, 6, 1, 23, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2024, 6, 2, 10, 0, 0).unwrap(),
+/// );
+/// // Polaris never rises in Chile; Spica transits early in the night
+/// let groups = schedule::group_by_transit(&[(37.95, 89.26), (201.37, -11.16)], night, &site, 30.0).unwrap();
+/// assert_eq!(groups.len(), 1);
+/// assert_eq!(groups[0].indices(), vec![1]);
+/// ```
+pub fn group_by_transit(
+    targets: &[(f64, f64)],
+    night: (DateTime<Utc>, DateTime<Utc>),
+    location: &Location,
+    window_minutes: f64,
+) -> Result<Vec<TransitGroup>> {
+    let (night_start, night_end) = night;
+    if night_end <= night_start {
+        return Err(AstroError::OutOfRange {
+            parameter: "night",
+            value: (night_end - night_start).num_seconds() as f64 / 3600.0,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+    if !(window_minutes > 0.0 && window_minutes.is_finite()) {
+        return Err(AstroError::OutOfRange {
+            parameter: "window_minutes",
+            value: window_minutes,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+    let site = FixedSite::new(*location)?;
+
+    let lst_start = site.local_sidereal_time(night_start);
+    let mut scheduled = Vec::with_capacity(targets.len());
+    for (index, &(ra, dec)) in targets.iter().enumerate() {
+        // Hours after the start of the night until the next transit, and
+        // since the previous one
+        let until = (ra / 15.0 - lst_start).rem_euclid(24.0) / SIDEREAL_RATE;
+        let since = 24.0 / SIDEREAL_RATE - until;
+        let transit = night_start + hours(until);
+
+        let (optimal_time, transits) = if transit <= night_end {
+            (transit, true)
+        } else {
+            // Altitude falls with |hour angle|, so pick the nearer end
+            let hours_after_end = until - (night_end - night_start).num_milliseconds() as f64 / 3.6e6;
+            (if since < hours_after_end { night_start } else { night_end }, false)
+        };
+
+        let (altitude_deg, _) = site.ra_dec_to_alt_az(ra, dec, optimal_time)?;
+        if altitude_deg > 0.0 {
+            scheduled.push(ScheduledTarget { index, optimal_time, altitude_deg, transits });
+        }
+    }
+    scheduled.sort_by_key(|t| t.optimal_time);
+
+    let window = minutes(window_minutes);
+    let half_window = minutes(window_minutes / 2.0);
+    let mut groups: Vec<TransitGroup> = Vec::new();
+    for target in scheduled {
+        match groups.last_mut() {
+            Some(group) if target.optimal_time - group.targets[group.targets.len() - 1].optimal_time < window => {
+                group.end = (target.optimal_time + half_window).min(night_end);
+                group.targets.push(target);
+            }
+            _ => groups.push(TransitGroup {
+                start: (target.optimal_time - half_window).max(night_start),
+                end: (target.optimal_time + half_window).min(night_end),
+                targets: vec![target],
+            }),
+        }
+    }
+    Ok(groups)
+}
+
+/// Points around the rim of a circular field
+const RIM_POINTS: usize = 16;
+
+/// A survey field on the sky.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SurveyField {
+    /// A footprint polygon of `(ra, dec)` vertices in degrees, as in
+    /// [`sky_polygon`](crate::sky_polygon); its vertices are checked
+    Polygon(Vec<(f64, f64)>),
+    /// A circle of `radius` degrees around `(ra, dec)`; 16 points around
+    /// its rim are checked
+    Circle {
+        /// Right ascension of the centre in degrees
+        ra: f64,
+        /// Declination of the centre in degrees
+        dec: f64,
+        /// Radius in degrees
+        radius: f64,
+    },
+}
+
+impl SurveyField {
+    /// The points whose altitudes are checked: the polygon's corners, or
+    /// points every 22.5° of position angle around the circle.
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` for an out-of-range RA or Dec
+    /// - `AstroError::OutOfRange` for a radius outside [0, 90]
+    /// - `AstroError::CalculationError` for a polygon of fewer than three
+    ///   vertices
+    pub fn points(&self) -> Result<Vec<(f64, f64)>> {
+        match self {
+            SurveyField::Polygon(vertices) => {
+                if vertices.len() < 3 {
+                    return Err(AstroError::CalculationError {
+                        calculation: "field observability",
+                        reason: format!("polygon needs at least three vertices, got {}", vertices.len()),
+                    });
+                }
+                for &(ra, dec) in vertices {
+                    validate_ra(ra)?;
+                    validate_dec(dec)?;
+                }
+                Ok(vertices.clone())
+            }
+            &SurveyField::Circle { ra, dec, radius } => {
+                validate_range(radius, 0.0, 90.0, "radius")?;
+                (0..RIM_POINTS)
+                    .map(|i| offset_by(ra, dec, i as f64 * 360.0 / RIM_POINTS as f64, radius))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// What a field needs to be observable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldConstraints {
+    /// Lowest acceptable altitude in degrees
+    pub min_altitude_deg: f64,
+    /// Highest acceptable airmass (Kasten & Young), if any
+    pub max_airmass: Option<f64>,
+    /// Fraction of the field's points that must meet the constraints, in
+    /// (0, 1]; 1 for all of them
+    pub min_fraction: f64,
+}
+
+impl Default for FieldConstraints {
+    /// Every point above 30° altitude, with no airmass limit.
+    fn default() -> Self {
+        FieldConstraints { min_altitude_deg: 30.0, max_airmass: None, min_fraction: 1.0 }
+    }
+}
+
+/// Finds when a survey field is observable as a whole.
+///
+/// A single check at the field centre passes a wide field whose lower edge
+/// is still below the limit; this checks the field's corners (or rim) and
+/// requires all of them, or `min_fraction` of them, to be above
+/// `min_altitude_deg` and below `max_airmass`. An airmass limit is turned
+/// into the altitude where the airmass reaches it, so the tighter of the
+/// two applies.
+///
+/// The night is sampled every 10 minutes and each change refined to a
+/// second; a field observable for less than 10 minutes between two samples
+/// may be missed.
+///
+/// # Arguments
+/// * `field` - The field's footprint or circle
+/// * `night` - `(start, end)` of the time to search, e.g. astronomical dusk
+///   and dawn
+/// * `location` - Observer location
+/// * `constraints` - Altitude, airmass and fraction required
+///
+/// # Returns
+/// Time windows in order during which the constraints hold
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if a field position is out of range
+/// - `AstroError::OutOfRange` if the night does not end after it starts,
+///   the altitude limit is outside [-90, 90], the airmass limit is below 1,
+///   `min_fraction` is outside (0, 1], the circle radius is out of range,
+///   or the location is out of range
+/// - `AstroError::CalculationError` for a polygon of fewer than three
+///   vertices
+///
+/// # Example
+/// ```
+/// use astro_math::schedule::{field_observability, FieldConstraints, SurveyField};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: -30.24, longitude_deg: -70.74, altitude_m: 2715.0 };
+/// let night = (
+///     Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2024, 10, 1, 9, 0, 0).unwrap(),
+/// );
+/// let constraints = FieldConstraints { max_airmass: Some(1.5), ..Default::default() };
+///
+/// // A 7° field is observable for less time than its centre alone
+/// let wide = SurveyField::Circle { ra: 10.0, dec: -60.0, radius: 3.5 };
+/// let point = SurveyField::Circle { ra: 10.0, dec: -60.0, radius: 0.0 };
+/// let windows = field_observability(&wide, night, &site, &constraints).unwrap();
+/// let centre = field_observability(&point, night, &site, &constraints).unwrap();
+/// assert_eq!((windows.len(), centre.len()), (1, 1));
+/// assert!(windows[0].0 > centre[0].0 && windows[0].1 < centre[0].1);
+/// ```
+pub fn field_observability(
+    field: &SurveyField,
+    night: (DateTime<Utc>, DateTime<Utc>),
+    location: &Location,
+    constraints: &FieldConstraints,
+) -> Result<Vec<(DateTime<Utc>, DateTime<Utc>)>> {
+    let (night_start, night_end) = night;
+    if night_end <= night_start {
+        return Err(AstroError::OutOfRange {
+            parameter: "night",
+            value: (night_end - night_start).num_seconds() as f64 / 3600.0,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+    validate_range(constraints.min_altitude_deg, -90.0, 90.0, "min_altitude_deg")?;
+    if !(constraints.min_fraction > 0.0 && constraints.min_fraction <= 1.0) {
+        return Err(AstroError::OutOfRange {
+            parameter: "min_fraction",
+            value: constraints.min_fraction,
+            min: f64::MIN_POSITIVE,
+            max: 1.0,
+        });
+    }
+    let min_altitude = match constraints.max_airmass {
+        Some(airmass) => constraints.min_altitude_deg.max(airmass_altitude(airmass)?),
+        None => constraints.min_altitude_deg,
+    };
+    let points = field.points()?;
+    let site = FixedSite::new(*location)?;
+
+    // Altitude margin of the point ranked `needed` from the top: positive
+    // exactly when enough points are above the limit
+    let needed = ((constraints.min_fraction * points.len() as f64).ceil() as usize).clamp(1, points.len());
+    let margin = |t: DateTime<Utc>| {
+        let mut altitudes: Vec<f64> = match site.ra_dec_to_alt_az_batch(&points, t) {
+            Ok(alt_az) => alt_az.into_iter().map(|(alt, _)| alt).collect(),
+            Err(_) => return f64::NAN,
+        };
+        altitudes.sort_by(|a, b| b.total_cmp(a));
+        altitudes[needed - 1] - min_altitude
+    };
+
+    let crossings = find_zeros(margin, night_start, night_end, Duration::minutes(10), Duration::seconds(1))?;
+    let mut windows = Vec::new();
+    let mut open = (margin(night_start) >= 0.0).then_some(night_start);
+    for (time, direction) in crossings {
+        match (direction, open) {
+            (Crossing::Rising, None) => open = Some(time),
+            (Crossing::Falling, Some(start)) => {
+                windows.push((start, time));
+                open = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = open {
+        windows.push((start, night_end));
+    }
+    Ok(windows)
+}
+
+/// Altitude in degrees at which the Kasten & Young airmass reaches `airmass`.
+fn airmass_altitude(airmass: f64) -> Result<f64> {
+    if airmass.is_nan() || airmass < 1.0 {
+        return Err(AstroError::OutOfRange { parameter: "max_airmass", value: airmass, min: 1.0, max: f64::MAX });
+    }
+    // Airmass falls monotonically from the horizon to the zenith
+    let (mut low, mut high) = (0.0, 90.0);
+    if airmass_kasten_young(low)? <= airmass {
+        return Ok(low);
+    }
+    for _ in 0..60 {
+        let mid = 0.5 * (low + high);
+        if airmass_kasten_young(mid)? > airmass {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok(high)
+}
+
+fn hours(h: f64) -> Duration {
+    Duration::milliseconds((h * 3.6e6).round() as i64)
+}
+
+fn minutes(m: f64) -> Duration {
+    Duration::milliseconds((m * 6e4).round() as i64)
+}
@@ -40,8 +40,21 @@
 //! // Object at RA = LST is on the meridian (highest point)
 //! println!("Current LST: {:.2} hours", lst);
 //! ```
+//!
+//! # Error Handling
+//!
+//! The plain functions take any input and return NaN for NaN input. The
+//! `_checked` variants validate it first:
+//!
+//! - `AstroError::CalculationError` for a NaN or infinite Julian Date
+//! - `AstroError::OutOfRange` for a Julian Date outside
+//!   [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX)
+//! - `AstroError::InvalidCoordinate` for a longitude outside [-180, 180]
 
+use crate::eop::EarthOrientation;
 use crate::erfa;
+use crate::error::{validate_jd, validate_longitude, Result};
+use crate::time_scales::utc_to_tt_jd_two_part;
 
 /// Computes the Greenwich Mean Sidereal Time (GMST) in fractional hours (0.0–24.0)
 /// from a Julian Date (JD).
@@ -71,14 +84,59 @@ use crate::erfa;
 /// assert!((gmst - 8.5825).abs() < 1e-4);  // matches Meeus Example 11.a
 /// ```
 pub fn gmst(jd: f64) -> f64 {
-    // Split JD for better precision
-    let jd1 = jd;
-    let jd2 = 0.0;
-    
+    gmst_two_part(jd, 0.0)
+}
+
+/// Computes GMST in fractional hours, rejecting an invalid Julian Date.
+///
+/// Same as [`gmst`], which returns NaN for NaN input and meaningless values
+/// for dates far outside the range of the model.
+///
+/// # Arguments
+/// * `jd` - Julian Date (UT1)
+///
+/// # Returns
+/// GMST in fractional hours, normalized to `[0.0, 24.0)`
+///
+/// # Errors
+/// Returns an error if `jd` is not finite or is outside
+/// [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX).
+///
+/// # Example
+/// ```
+/// use astro_math::sidereal::{gmst, gmst_checked};
+///
+/// assert_eq!(gmst_checked(2446896.30625).unwrap(), gmst(2446896.30625));
+/// assert!(gmst_checked(f64::INFINITY).is_err());
+/// ```
+pub fn gmst_checked(jd: f64) -> Result<f64> {
+    validate_jd(jd)?;
+    Ok(gmst(jd))
+}
+
+/// Computes GMST in fractional hours from a two-part UT1 Julian Date.
+///
+/// Identical to [`gmst`] but keeps the full precision of a split Julian Date
+/// such as the one returned by [`julian_date_two_part`](crate::time::julian_date_two_part).
+///
+/// # Arguments
+/// * `jd1`, `jd2` - Julian Date split into two parts (sum is the date)
+///
+/// # Returns
+/// GMST in fractional hours, normalized to `[0.0, 24.0)`
+///
+/// # Example
+/// ```
+/// use astro_math::sidereal::{gmst, gmst_two_part};
+///
+/// let a = gmst(2446896.30625);
+/// let b = gmst_two_part(2446895.5, 0.80625);
+/// assert!((a - b).abs() < 1e-8);
+/// ```
+pub fn gmst_two_part(jd1: f64, jd2: f64) -> f64 {
     // Convert UTC to TT using proper time scale conversion
-    use crate::time_scales::{utc_to_tt_jd, split_jd_for_erfa};
-    let jd_tt = utc_to_tt_jd(jd);
-    let (tt1, tt2) = split_jd_for_erfa(jd_tt);
+    use crate::time_scales::utc_to_tt_jd_two_part;
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
     
     // Use ERFA's GMST function (IAU 2006)
     let gmst_rad = erfa::greenwich_mean_sidereal_time(jd1, jd2, tt1, tt2);
@@ -146,6 +204,31 @@ pub fn local_mean_sidereal_time(jd: f64, longitude_deg: f64) -> f64 {
     h
 }
 
+/// Computes Local Mean Sidereal Time, rejecting an invalid Julian Date or
+/// longitude.
+///
+/// Same as [`local_mean_sidereal_time`] apart from the validation.
+///
+/// # Arguments
+///
+/// - `jd` — Julian Date (e.g. from [`julian_date`](crate::time::julian_date))
+/// - `longitude_deg` — Observer’s longitude in degrees, positive east
+///
+/// # Returns
+///
+/// Local mean sidereal time in fractional hours, normalized to `[0.0, 24.0)`
+///
+/// # Errors
+///
+/// Returns an error if `jd` is not finite or is outside
+/// [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX), or
+/// `AstroError::InvalidCoordinate` if the longitude is outside [-180, 180].
+pub fn local_mean_sidereal_time_checked(jd: f64, longitude_deg: f64) -> Result<f64> {
+    validate_jd(jd)?;
+    validate_longitude(longitude_deg)?;
+    Ok(local_mean_sidereal_time(jd, longitude_deg))
+}
+
 /// Computes **Local Apparent Sidereal Time (LAST)** in fractional hours (0.0–24.0)
 /// from a Julian Date and geographic longitude.
 ///
@@ -185,14 +268,76 @@ pub fn local_mean_sidereal_time(jd: f64, longitude_deg: f64) -> f64 {
 /// assert!(last >= 0.0 && last < 24.0);
 /// ```
 pub fn apparent_sidereal_time(jd: f64, longitude_deg: f64) -> f64 {
-    // Split JD for better precision
-    let jd1 = jd;
-    let jd2 = 0.0;
-    
+    apparent_sidereal_time_two_part(jd, 0.0, longitude_deg)
+}
+
+/// Computes Local Apparent Sidereal Time, rejecting an invalid Julian Date
+/// or longitude.
+///
+/// Same as [`apparent_sidereal_time`] apart from the validation.
+///
+/// # Arguments
+///
+/// - `jd`: Julian Date (e.g. from [`julian_date`](crate::time::julian_date))
+/// - `longitude_deg`: Observer's longitude (degrees, east positive)
+///
+/// # Returns
+///
+/// Local apparent sidereal time in fractional hours, normalized to `[0.0, 24.0)`
+///
+/// # Errors
+///
+/// Returns an error if `jd` is not finite or is outside
+/// [`JD_MIN`](crate::error::JD_MIN)..=[`JD_MAX`](crate::error::JD_MAX), or
+/// `AstroError::InvalidCoordinate` if the longitude is outside [-180, 180].
+///
+/// # Example
+///
+/// ```
+/// use astro_math::sidereal::apparent_sidereal_time_checked;
+///
+/// let last = apparent_sidereal_time_checked(2460526.75, -111.6).unwrap();
+/// assert!((0.0..24.0).contains(&last));
+/// assert!(apparent_sidereal_time_checked(f64::NAN, -111.6).is_err());
+/// assert!(apparent_sidereal_time_checked(2460526.75, 248.4).is_err());
+/// ```
+pub fn apparent_sidereal_time_checked(jd: f64, longitude_deg: f64) -> Result<f64> {
+    validate_jd(jd)?;
+    validate_longitude(longitude_deg)?;
+    Ok(apparent_sidereal_time(jd, longitude_deg))
+}
+
+/// Computes Local Apparent Sidereal Time in fractional hours from a two-part
+/// Julian Date.
+///
+/// Identical to [`apparent_sidereal_time`] but keeps the full precision of a
+/// split Julian Date.
+///
+/// # Arguments
+///
+/// - `jd1`, `jd2`: Julian Date split into two parts (sum is the date)
+/// - `longitude_deg`: Observer's longitude (degrees, east positive)
+///
+/// # Returns
+///
+/// Local apparent sidereal time in fractional hours, normalized to `[0.0, 24.0)`
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::time::julian_date_two_part;
+/// use astro_math::sidereal::apparent_sidereal_time_two_part;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let (jd1, jd2) = julian_date_two_part(dt);
+/// let last = apparent_sidereal_time_two_part(jd1, jd2, -111.6);
+/// assert!(last >= 0.0 && last < 24.0);
+/// ```
+pub fn apparent_sidereal_time_two_part(jd1: f64, jd2: f64, longitude_deg: f64) -> f64 {
     // Convert UTC to TT using proper time scale conversion
-    use crate::time_scales::{utc_to_tt_jd, split_jd_for_erfa};
-    let jd_tt = utc_to_tt_jd(jd);
-    let (tt1, tt2) = split_jd_for_erfa(jd_tt);
+    use crate::time_scales::utc_to_tt_jd_two_part;
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
     
     // Use ERFA's Greenwich Apparent Sidereal Time (includes nutation)
     let gast_rad = erfa::greenwich_apparent_sidereal_time(jd1, jd2, tt1, tt2);
@@ -207,3 +352,40 @@ pub fn apparent_sidereal_time(jd: f64, longitude_deg: f64) -> f64 {
     }
     last
 }
+
+/// Computes Local Apparent Sidereal Time with a measured UT1−UTC.
+///
+/// The other sidereal time functions take UT1 = UTC, which is up to 0.9 s
+/// (13.5″ of hour angle) out. This adds the `dut1` of `eop`, e.g. from an
+/// [`EopTable`](crate::eop::EopTable). Polar motion does not change
+/// sidereal time; it moves the observer's meridian by milliarcseconds
+/// except very near the poles, and is applied by
+/// [`ra_dec_to_alt_az_erfa_with_eop`](crate::transforms::ra_dec_to_alt_az_erfa_with_eop).
+///
+/// # Arguments
+///
+/// - `jd`: Julian Date (UTC)
+/// - `longitude_deg`: Observer's longitude (degrees, east positive)
+/// - `eop`: Earth orientation parameters for the date
+///
+/// # Returns
+///
+/// Local apparent sidereal time in fractional hours, normalized to `[0.0, 24.0)`
+///
+/// # Example
+///
+/// ```
+/// use astro_math::eop::EarthOrientation;
+/// use astro_math::sidereal::{apparent_sidereal_time, apparent_sidereal_time_with_eop};
+///
+/// let eop = EarthOrientation { dut1: -0.2, ..Default::default() };
+/// let last = apparent_sidereal_time_with_eop(2460310.75, -111.6, &eop);
+/// // 0.2 s of UT1 is 0.2 sidereal seconds, to 0.3%
+/// let difference_s = (apparent_sidereal_time(2460310.75, -111.6) - last) * 3600.0;
+/// assert!((difference_s - 0.2).abs() < 0.001);
+/// ```
+pub fn apparent_sidereal_time_with_eop(jd: f64, longitude_deg: f64, eop: &EarthOrientation) -> f64 {
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd, 0.0);
+    let gast_rad = erfa::greenwich_apparent_sidereal_time(jd, eop.dut1 / 86400.0, tt1, tt2);
+    (gast_rad * 12.0 / std::f64::consts::PI + longitude_deg / 15.0).rem_euclid(24.0)
+}
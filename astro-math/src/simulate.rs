@@ -0,0 +1,209 @@
+//! Synthetic star fields for hardware-in-the-loop testing.
+//!
+//! Mount, guider and plate-solver software is easiest to test against
+//! frames whose content is known exactly. [`star_field`] places catalogue
+//! stars on a detector described by a [`TangentPlane`], running them through
+//! the same transforms the crate uses in production, so a test can check
+//! that software recovers the pointing, rotation and drift it was given.
+//!
+//! Each astrometric effect can be switched on separately in
+//! [`SimulationConditions`]:
+//!
+//! - **Apparent place** (`apparent_place`): precession, nutation, annual
+//!   aberration and light deflection, via [`apparent_place`]
+//! - **Refraction** (`refraction`): each star is lifted towards the zenith
+//!   by Saemundsson's formula for the given site and weather, via
+//!   [`FixedSite`]. Across a wide field at low altitude this stretches the
+//!   field vertically.
+//!
+//! The plane's reference point is the pointing in the frame the enabled
+//! effects produce: ICRS with no effects, the apparent place of date with
+//! `apparent_place`, and the refracted (observed) place with `refraction`.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::projection::TangentPlane;
+//! use astro_math::simulate::{star_field, CatalogStar, SimulationConditions};
+//! use chrono::{TimeZone, Utc};
+//!
+//! let wcs = TangentPlane::new(83.82, -5.39, 1.5).unwrap().with_reference_pixel(512.0, 512.0);
+//! let catalog = [
+//!     CatalogStar { ra: 83.82, dec: -5.39, magnitude: 5.0 },
+//!     CatalogStar { ra: 83.90, dec: -5.30, magnitude: 10.0 },
+//! ];
+//! let dt = Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap();
+//!
+//! let stars = star_field(&wcs, &catalog, dt, &SimulationConditions::default()).unwrap();
+//! assert!((stars[0].0 - 512.0).abs() < 1e-6 && (stars[0].1 - 512.0).abs() < 1e-6);
+//! // Five magnitudes fainter is a hundred times less flux
+//! assert!((stars[0].2 / stars[1].2 - 100.0).abs() < 1e-9);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for an out-of-range catalogue position
+//! - `AstroError::OutOfRange` for a non-finite magnitude, a non-positive
+//!   zero point or image size, or an out-of-range site or weather
+
+use crate::apparent::apparent_place;
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::fixed_site::FixedSite;
+use crate::location::Location;
+use crate::projection::TangentPlane;
+use crate::refraction::AtmosphericConditions;
+use chrono::{DateTime, Utc};
+
+/// A catalogue star to place in a synthetic field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CatalogStar {
+    /// ICRS right ascension in degrees
+    pub ra: f64,
+    /// ICRS declination in degrees
+    pub dec: f64,
+    /// Magnitude in the band being simulated
+    pub magnitude: f64,
+}
+
+/// Which effects to apply when simulating a field, and how to render it.
+#[derive(Debug, Clone, Copy)]
+pub struct SimulationConditions {
+    /// Convert catalogue positions to apparent places of date
+    pub apparent_place: bool,
+    /// Refract positions for this site and weather; `None` for none
+    pub refraction: Option<(Location, AtmosphericConditions)>,
+    /// Flux of a magnitude 0 star, in whatever units the caller renders
+    pub zero_point_flux: f64,
+    /// `(width, height)` in pixels; stars outside `[0, width) × [0, height)`
+    /// are dropped. `None` keeps every star that projects.
+    pub image_size: Option<(f64, f64)>,
+}
+
+impl Default for SimulationConditions {
+    /// No astrometric effects, unit zero point and no cropping.
+    fn default() -> Self {
+        SimulationConditions {
+            apparent_place: false,
+            refraction: None,
+            zero_point_flux: 1.0,
+            image_size: None,
+        }
+    }
+}
+
+/// Generates the pixel positions and fluxes of catalogue stars.
+///
+/// Stars that are more than 90° from the plane's reference point, below
+/// the horizon when refraction is enabled, or outside `image_size` are
+/// left out.
+///
+/// # Arguments
+/// * `wcs` - Projection of the detector
+/// * `catalog` - Stars to place
+/// * `datetime` - Time of the exposure (UTC)
+/// * `conditions` - Effects to apply and rendering options
+///
+/// # Returns
+/// `(x, y, flux)` per star that lands on the detector, in catalogue order,
+/// with `flux = zero_point_flux × 10^(−0.4 m)`
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if a catalogue position is out of range
+/// - `AstroError::OutOfRange` if a magnitude is not finite, the zero point
+///   or an image dimension is not positive, or the site or weather is out of range
+///
+/// # Example
+/// ```
+/// use astro_math::projection::TangentPlane;
+/// use astro_math::simulate::{star_field, CatalogStar, SimulationConditions};
+/// use astro_math::Location;
+/// use astro_math::refraction::AtmosphericConditions;
+/// use chrono::{TimeZone, Utc};
+///
+/// let catalog = [CatalogStar { ra: 83.82, dec: -5.39, magnitude: 5.0 }];
+/// let dt = Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap();
+/// let site = Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 };
+/// let wcs = TangentPlane::new(83.82, -5.39, 1.0).unwrap();
+///
+/// let conditions = SimulationConditions {
+///     apparent_place: true,
+///     refraction: Some((site, AtmosphericConditions::standard())),
+///     ..SimulationConditions::default()
+/// };
+/// let stars = star_field(&wcs, &catalog, dt, &conditions).unwrap();
+/// // Precession since J2000 alone moves the star by several arcminutes
+/// assert!(stars[0].0.hypot(stars[0].1) > 60.0);
+/// ```
+pub fn star_field(
+    wcs: &TangentPlane,
+    catalog: &[CatalogStar],
+    datetime: DateTime<Utc>,
+    conditions: &SimulationConditions,
+) -> Result<Vec<(f64, f64, f64)>> {
+    if !(conditions.zero_point_flux > 0.0 && conditions.zero_point_flux.is_finite()) {
+        return Err(AstroError::OutOfRange {
+            parameter: "zero_point_flux",
+            value: conditions.zero_point_flux,
+            min: f64::MIN_POSITIVE,
+            max: f64::MAX,
+        });
+    }
+    if let Some((width, height)) = conditions.image_size {
+        for (parameter, value) in [("image_size.0", width), ("image_size.1", height)] {
+            if !(value > 0.0 && value.is_finite()) {
+                return Err(AstroError::OutOfRange {
+                    parameter,
+                    value,
+                    min: f64::MIN_POSITIVE,
+                    max: f64::MAX,
+                });
+            }
+        }
+    }
+    let site = match conditions.refraction {
+        Some((location, weather)) => Some(FixedSite::new(location)?.with_conditions(weather)?),
+        None => None,
+    };
+
+    let mut stars = Vec::with_capacity(catalog.len());
+    for star in catalog {
+        if !star.magnitude.is_finite() {
+            return Err(AstroError::OutOfRange {
+                parameter: "magnitude",
+                value: star.magnitude,
+                min: f64::MIN,
+                max: f64::MAX,
+            });
+        }
+        let (mut ra, mut dec) = if conditions.apparent_place {
+            apparent_place(star.ra, star.dec, datetime)?
+        } else {
+            validate_ra(star.ra)?;
+            validate_dec(star.dec)?;
+            (star.ra, star.dec)
+        };
+
+        if let Some(site) = &site {
+            let (altitude, azimuth) = site.ra_dec_to_alt_az(ra, dec, datetime)?;
+            if altitude < 0.0 {
+                continue;
+            }
+            let refracted = site.apparent_altitude(altitude)?.min(90.0);
+            (ra, dec) = site.alt_az_to_ra_dec(refracted, azimuth, datetime)?;
+        }
+
+        let (x, y) = match wcs.ra_dec_to_pixel(ra, dec) {
+            Ok(pixel) => pixel,
+            Err(AstroError::ProjectionError { .. }) => continue,
+            Err(e) => return Err(e),
+        };
+        if let Some((width, height)) = conditions.image_size {
+            if !(0.0..width).contains(&x) || !(0.0..height).contains(&y) {
+                continue;
+            }
+        }
+        let flux = conditions.zero_point_flux * 10f64.powf(-0.4 * star.magnitude);
+        stars.push((x, y, flux));
+    }
+    Ok(stars)
+}
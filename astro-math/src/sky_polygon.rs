@@ -0,0 +1,210 @@
+//! Spherical polygons for instrument footprints and survey coverage.
+//!
+//! A polygon is a slice of `(ra, dec)` vertices in degrees, joined in order
+//! by great-circle arcs, with the last vertex joined back to the first.
+//! Detector footprints projected on the sky are polygons of this kind:
+//! straight edges on a tangent-plane image are great circles on the sky.
+//!
+//! The polygon must be simple (edges do not cross) and fit within a
+//! hemisphere. Its interior is the smaller of the two regions it bounds,
+//! so vertices may be listed clockwise or counter-clockwise. Edges may
+//! cross RA = 0° and the polygon may enclose a pole.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::sky_polygon;
+//!
+//! // A 1°×1° detector footprint straddling RA = 0°
+//! let footprint = [(359.5, -0.5), (0.5, -0.5), (0.5, 0.5), (359.5, 0.5)];
+//!
+//! assert!(sky_polygon::contains(&footprint, 0.1, 0.2).unwrap());
+//! assert!(!sky_polygon::contains(&footprint, 1.0, 0.0).unwrap());
+//! assert!((sky_polygon::area_sq_deg(&footprint).unwrap() - 1.0).abs() < 1e-3);
+//! assert!(sky_polygon::intersects_cone(&footprint, 1.0, 0.0, 0.6).unwrap());
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::CalculationError` for polygons with fewer than three
+//!   vertices, antipodal neighbouring vertices, or that do not fit within
+//!   a hemisphere
+//! - `AstroError::OutOfRange` for a cone radius outside [0, 180] degrees
+
+use crate::error::{validate_dec, validate_ra, AstroError, Result};
+use crate::vec3::{angle_between, cross, dot, norm, radec_to_unit_vector, Vec3};
+use std::f64::consts::PI;
+
+/// Square degrees per steradian
+const SQ_DEG_PER_SR: f64 = (180.0 / PI) * (180.0 / PI);
+
+/// Tests whether a position lies inside a polygon.
+///
+/// Points exactly on an edge may be reported either way.
+///
+/// # Arguments
+/// * `polygon` - Vertices as `(ra, dec)` in degrees
+/// * `ra`, `dec` - Position to test in degrees
+///
+/// # Returns
+/// `true` if the position is inside the polygon
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if any coordinate is out of range
+/// - `AstroError::CalculationError` if the polygon is invalid
+///
+/// # Example
+/// ```
+/// use astro_math::sky_polygon;
+///
+/// // A cap around the north celestial pole
+/// let cap = [(0.0, 80.0), (90.0, 80.0), (180.0, 80.0), (270.0, 80.0)];
+/// assert!(sky_polygon::contains(&cap, 123.0, 89.0).unwrap());
+/// assert!(!sky_polygon::contains(&cap, 45.0, 75.0).unwrap());
+/// ```
+pub fn contains(polygon: &[(f64, f64)], ra: f64, dec: f64) -> Result<bool> {
+    let vertices = vertices(polygon)?;
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    let p = radec_to_unit_vector(ra, dec);
+
+    // Winding angle of the boundary around p: ±2π if p (or its antipode)
+    // is enclosed, 0 otherwise
+    let winding: f64 = edges(&vertices)
+        .map(|(a, b)| {
+            let sin = dot(p, cross(a, b));
+            let cos = dot(a, b) - dot(p, a) * dot(p, b);
+            sin.atan2(cos)
+        })
+        .sum();
+    Ok(winding.abs() > PI && dot(p, centroid(&vertices)) > 0.0)
+}
+
+/// Calculates the solid angle enclosed by a polygon.
+///
+/// # Arguments
+/// * `polygon` - Vertices as `(ra, dec)` in degrees
+///
+/// # Returns
+/// Area in square degrees
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if any coordinate is out of range
+/// - `AstroError::CalculationError` if the polygon is invalid
+///
+/// # Example
+/// ```
+/// use astro_math::sky_polygon;
+///
+/// // One octant of the sky is 41253/8 square degrees
+/// let octant = [(0.0, 0.0), (90.0, 0.0), (0.0, 90.0)];
+/// assert!((sky_polygon::area_sq_deg(&octant).unwrap() - 5156.62).abs() < 0.01);
+/// ```
+pub fn area_sq_deg(polygon: &[(f64, f64)]) -> Result<f64> {
+    let vertices = vertices(polygon)?;
+
+    // Fan of signed triangles from the first vertex (Van Oosterom & Strackee)
+    let a = vertices[0];
+    let signed: f64 = vertices[1..]
+        .windows(2)
+        .map(|pair| {
+            let (b, c) = (pair[0], pair[1]);
+            let numerator = dot(a, cross(b, c));
+            let denominator = 1.0 + dot(a, b) + dot(b, c) + dot(c, a);
+            2.0 * numerator.atan2(denominator)
+        })
+        .sum();
+    Ok(signed.abs() * SQ_DEG_PER_SR)
+}
+
+/// Tests whether a polygon overlaps a cone (a circle on the sky).
+///
+/// # Arguments
+/// * `polygon` - Vertices as `(ra, dec)` in degrees
+/// * `ra`, `dec` - Cone centre in degrees
+/// * `radius_deg` - Cone radius in degrees
+///
+/// # Returns
+/// `true` if any part of the polygon lies within `radius_deg` of the centre
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if any coordinate is out of range
+/// - `AstroError::OutOfRange` if `radius_deg` is outside [0, 180]
+/// - `AstroError::CalculationError` if the polygon is invalid
+pub fn intersects_cone(polygon: &[(f64, f64)], ra: f64, dec: f64, radius_deg: f64) -> Result<bool> {
+    if !(0.0..=180.0).contains(&radius_deg) {
+        return Err(AstroError::OutOfRange {
+            parameter: "radius_deg",
+            value: radius_deg,
+            min: 0.0,
+            max: 180.0,
+        });
+    }
+    if contains(polygon, ra, dec)? {
+        return Ok(true);
+    }
+    let vertices = vertices(polygon)?;
+    let p = radec_to_unit_vector(ra, dec);
+    let radius = radius_deg.to_radians();
+    let near_edge = edges(&vertices).any(|(a, b)| arc_distance(p, a, b) <= radius);
+    Ok(near_edge)
+}
+
+/// Validates a polygon and converts its vertices to unit vectors.
+fn vertices(polygon: &[(f64, f64)]) -> Result<Vec<Vec3>> {
+    if polygon.len() < 3 {
+        return Err(AstroError::CalculationError {
+            calculation: "sky polygon",
+            reason: format!("a polygon needs at least 3 vertices, got {}", polygon.len()),
+        });
+    }
+    let mut vertices = Vec::with_capacity(polygon.len());
+    for &(ra, dec) in polygon {
+        validate_ra(ra)?;
+        validate_dec(dec)?;
+        vertices.push(radec_to_unit_vector(ra, dec));
+    }
+
+    if edges(&vertices).any(|(a, b)| dot(a, b) < -1.0 + 1e-12) {
+        return Err(AstroError::CalculationError {
+            calculation: "sky polygon",
+            reason: "neighbouring vertices are antipodal, so the edge between them is undefined".to_string(),
+        });
+    }
+    let c = centroid(&vertices);
+    if norm(c) < 1e-12 || vertices.iter().any(|&v| dot(v, c) <= 0.0) {
+        return Err(AstroError::CalculationError {
+            calculation: "sky polygon",
+            reason: "polygon does not fit within a hemisphere".to_string(),
+        });
+    }
+    Ok(vertices)
+}
+
+/// Edges as consecutive vertex pairs, closing the polygon.
+fn edges(vertices: &[Vec3]) -> impl Iterator<Item = (Vec3, Vec3)> + '_ {
+    let n = vertices.len();
+    (0..n).map(move |i| (vertices[i], vertices[(i + 1) % n]))
+}
+
+/// Angular distance from `p` to the great-circle arc from `a` to `b`, in radians.
+fn arc_distance(p: Vec3, a: Vec3, b: Vec3) -> f64 {
+    let normal = cross(a, b);
+    let length = norm(normal);
+    if length > 1e-15 {
+        let n = normal.map(|x| x / length);
+        // Foot of the perpendicular from p onto the great circle
+        let h = dot(p, n);
+        let foot = [p[0] - h * n[0], p[1] - h * n[1], p[2] - h * n[2]];
+        if norm(foot) > 1e-15 && dot(cross(a, foot), n) >= 0.0 && dot(cross(foot, b), n) >= 0.0 {
+            return h.abs().clamp(0.0, 1.0).asin();
+        }
+    }
+    angle_between(p, a).min(angle_between(p, b))
+}
+
+fn centroid(vertices: &[Vec3]) -> Vec3 {
+    vertices.iter().fold([0.0; 3], |s, v| [s[0] + v[0], s[1] + v[1], s[2] + v[2]])
+}
+
@@ -2,9 +2,25 @@
 //!
 //! This module provides solar position calculations using ERFA's
 //! high-precision ephemerides for professional-grade accuracy.
+//!
+//! Besides the raw ecliptic and equatorial positions, it offers ready-made
+//! helpers for solar energy and shadow work: [`solar_noon`],
+//! [`subsolar_point`], [`solar_azimuth_elevation`] and [`shadow`], and
+//! [`solar_physical_ephemeris`] gives the orientation of the solar disk
+//! for solar observers.
 
+use crate::accuracy;
+use crate::almanac::{apparent_place, ecliptic_longitude_of_date, AlmanacBody};
+use crate::error::{validate_latitude, validate_longitude, AstroError, Result};
+use crate::events::sample_uniformly;
+use crate::location::Location;
+use crate::nutation::{nutation_in_longitude, true_obliquity};
+use crate::refraction::true_to_apparent_altitude;
+use crate::sidereal::apparent_sidereal_time;
 use crate::time::julian_date;
-use chrono::{DateTime, Utc};
+use crate::time_scales::utc_to_tt_jd;
+use crate::transforms::ra_dec_to_alt_az;
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use std::f64::consts::PI;
 
 /// Calculates the Sun's ecliptic longitude and latitude using ERFA.
@@ -41,7 +57,7 @@ pub fn sun_position(date: DateTime<Utc>) -> (f64, f64) {
     
     // Get Earth's heliocentric position-velocity
     // Returns position in ICRS equatorial frame
-    let (earth_h, _earth_b) = erfars::ephemerides::Epv00(jd, 0.0);
+    let (earth_h, _earth_b) = crate::backend::ephemerides::Epv00(jd, 0.0);
     
     // Sun's position is negative of Earth's heliocentric position
     let x = -earth_h[0];  // AU
@@ -49,7 +65,7 @@ pub fn sun_position(date: DateTime<Utc>) -> (f64, f64) {
     let z = -earth_h[2];  // AU
     
     // Get mean obliquity for date
-    let eps = erfars::precnutpolar::Obl06(jd, 0.0);
+    let eps = crate::backend::precnutpolar::Obl06(jd, 0.0);
     let cos_eps = eps.cos();
     let sin_eps = eps.sin();
     
@@ -75,6 +91,52 @@ pub fn sun_position(date: DateTime<Utc>) -> (f64, f64) {
     (longitude, latitude)
 }
 
+/// Calculates the Sun's right ascension and declination with the
+/// low-precision formulas of the *Astronomical Almanac*.
+///
+/// A mean longitude and a two-term equation of center on a fixed
+/// obliquity, good to about 0.01° over 1950–2050 and degrading slowly
+/// outside it. This is the model [`sun_rise_set`](crate::rise_set::sun_rise_set)
+/// uses by default; it costs a few trigonometric calls where [`sun_ra_dec`]
+/// evaluates the full `Epv00` series.
+///
+/// # Arguments
+///
+/// * `jd` - Julian Date (UTC or TT; the difference is below the model's
+///   accuracy)
+///
+/// # Returns
+///
+/// A tuple `(ra, dec)` in degrees, referred to the equinox of date, with RA
+/// in [0, 360).
+///
+/// # Example
+///
+/// ```
+/// use astro_math::sun::{sun_position_low_precision, sun_ra_dec};
+/// use astro_math::julian_date;
+/// use astro_math::precession::precess_from_j2000;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+/// let (ra, dec) = sun_position_low_precision(julian_date(dt));
+/// let (ra_j2000, dec_j2000) = sun_ra_dec(dt);
+/// let (ra_of_date, dec_of_date) = precess_from_j2000(ra_j2000, dec_j2000, dt).unwrap();
+/// assert!((ra - ra_of_date).abs() < 0.02);
+/// assert!((dec - dec_of_date).abs() < 0.02);
+/// ```
+pub fn sun_position_low_precision(jd: f64) -> (f64, f64) {
+    let n = jd - 2451545.0;
+    let l = (280.460 + 0.9856474 * n) % 360.0;
+    let g = ((357.528 + 0.9856003 * n) % 360.0).to_radians();
+    let lambda = (l + 1.915 * g.sin() + 0.020 * (2.0 * g).sin()).to_radians();
+
+    let epsilon = 23.439_f64.to_radians();
+    let ra = (epsilon.cos() * lambda.sin()).atan2(lambda.cos()).to_degrees().rem_euclid(360.0);
+    let dec = (epsilon.sin() * lambda.sin()).asin().to_degrees();
+    (ra, dec)
+}
+
 /// Calculates the Sun's right ascension and declination using ERFA.
 ///
 /// This directly computes the Sun's equatorial coordinates from ERFA's
@@ -106,7 +168,7 @@ pub fn sun_ra_dec(date: DateTime<Utc>) -> (f64, f64) {
     let jd = julian_date(date);
     
     // Get Earth's heliocentric position-velocity
-    let (earth_h, _earth_b) = erfars::ephemerides::Epv00(jd, 0.0);
+    let (earth_h, _earth_b) = crate::backend::ephemerides::Epv00(jd, 0.0);
     
     // Sun's position is negative of Earth's heliocentric position
     let x = -earth_h[0];
@@ -127,4 +189,382 @@ pub fn sun_ra_dec(date: DateTime<Utc>) -> (f64, f64) {
     let dec = dec_rad * 180.0 / PI;
     
     (ra, dec)
-}
\ No newline at end of file
+}
+
+/// Calculates the Sun's apparent ecliptic longitude of date.
+///
+/// This is the longitude of the Sun as seen from the Earth's center,
+/// measured along the ecliptic from the true equinox of date, with light
+/// time and aberration applied: the longitude that defines the equinoxes,
+/// solstices and tropical zodiac signs. [`sun_position`] instead gives the
+/// geometric longitude on the ecliptic of J2000, which differs by the
+/// accumulated precession (about 0.34° in 2024).
+///
+/// # Arguments
+///
+/// * `date` - UTC date/time
+///
+/// # Returns
+///
+/// Ecliptic longitude in degrees, in [0, 360)
+///
+/// # Example
+///
+/// ```
+/// use astro_math::sun::ecliptic_longitude_of_sun;
+/// use chrono::{TimeZone, Utc};
+///
+/// // June solstice of 2024 at 20:51 UTC
+/// let dt = Utc.with_ymd_and_hms(2024, 6, 20, 20, 51, 0).unwrap();
+/// assert!((ecliptic_longitude_of_sun(dt) - 90.0).abs() < 0.001);
+/// ```
+pub fn ecliptic_longitude_of_sun(date: DateTime<Utc>) -> f64 {
+    let place = apparent_place(AlmanacBody::Sun, date).expect("the Sun's ephemeris accepts any date");
+    ecliptic_longitude_of_date(place.ra, place.dec, date)
+}
+
+/// One sample from [`sun_positions`]: `(time, ra, dec, distance_au)`, coordinates in
+/// degrees.
+pub type SunPositionSample = (DateTime<Utc>, f64, f64, f64);
+
+/// Samples the Sun's equatorial coordinates and distance at a uniform step.
+///
+/// Gives the same coordinates as calling [`sun_ra_dec`] at each time, with
+/// the distance from the same evaluation of the Earth's ephemeris, in one
+/// call.
+///
+/// # Arguments
+///
+/// * `start`, `end` - Time span; both ends are included when `end` falls on
+///   a step
+/// * `step` - Sampling interval
+///
+/// # Returns
+///
+/// `(time, ra, dec, distance_au)` in time order, coordinates in degrees;
+/// empty if `end` is before `start`
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::OutOfRange)` if `step` is not positive.
+///
+/// # Example
+///
+/// ```
+/// use astro_math::sun::sun_positions;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// let year = sun_positions(start, start + Duration::days(365), Duration::days(1)).unwrap();
+/// assert_eq!(year.len(), 366);
+///
+/// // Closest to the Sun in early January, furthest in early July
+/// let nearest = year.iter().min_by(|a, b| a.3.total_cmp(&b.3)).unwrap();
+/// let furthest = year.iter().max_by(|a, b| a.3.total_cmp(&b.3)).unwrap();
+/// assert!(nearest.3 < 0.984 && furthest.3 > 1.016);
+/// assert_eq!(furthest.0.format("%m").to_string(), "07");
+/// ```
+pub fn sun_positions(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: Duration,
+) -> Result<Vec<SunPositionSample>> {
+    sample_uniformly(start, end, step, |time| {
+        let (earth_h, _earth_b) = crate::backend::ephemerides::Epv00(julian_date(time), 0.0);
+        let (x, y, z) = (-earth_h[0], -earth_h[1], -earth_h[2]);
+        let r = (x * x + y * y + z * z).sqrt();
+        let mut ra = y.atan2(x) * 180.0 / PI;
+        if ra < 0.0 {
+            ra += 360.0;
+        }
+        (time, ra, (z / r).asin() * 180.0 / PI, r)
+    })
+}
+
+/// Calculates the Sun's right ascension and declination with an error estimate.
+///
+/// Returns the same position as [`sun_ra_dec`] together with an estimated
+/// 1-sigma error. [`sun_ra_dec`] evaluates the ephemeris at UTC rather than
+/// TT, so the Sun is displaced along the ecliptic by its motion over TT−UTC
+/// (about 2.8″ in the present era); the ephemeris error itself is small.
+/// The position is geometric, and the estimate does not cover the ~20″ of
+/// annual aberration separating it from the apparent Sun. See
+/// [`accuracy`] for how the terms are combined.
+///
+/// # Arguments
+///
+/// * `date` - UTC date/time
+///
+/// # Returns
+///
+/// A tuple `(ra, dec, error_arcsec)` with the coordinates in degrees.
+///
+/// # Example
+///
+/// ```
+/// use astro_math::sun::sun_ra_dec_with_accuracy;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+/// let (_ra, _dec, err) = sun_ra_dec_with_accuracy(dt);
+/// assert!(err > 2.0 && err < 4.0);
+/// ```
+pub fn sun_ra_dec_with_accuracy(date: DateTime<Utc>) -> (f64, f64, f64) {
+    let (ra, dec) = sun_ra_dec(date);
+    let jd = julian_date(date);
+    let (earth_h, _earth_b) = crate::backend::ephemerides::Epv00(jd, 0.0);
+    let rate = accuracy::angular_rate_arcsec_per_second(&earth_h);
+    let error = accuracy::combine_errors(&[
+        accuracy::time_scale_sigma_arcsec(jd, jd, rate),
+        accuracy::earth_ephemeris_sigma_arcsec(jd),
+    ]);
+    (ra, dec, error)
+}
+
+/// Sun's apparent RA/Dec (true equator and equinox of date) in degrees,
+/// with light time and annual aberration.
+fn sun_apparent_ra_dec(date: DateTime<Utc>) -> (f64, f64) {
+    let place = apparent_place(AlmanacBody::Sun, date).expect("the Sun's ephemeris accepts any date");
+    (place.ra, place.dec)
+}
+
+/// Sun's local hour angle in degrees, normalized to [-180, 180).
+fn sun_hour_angle(date: DateTime<Utc>, longitude_deg: f64) -> f64 {
+    let (ra, _) = sun_apparent_ra_dec(date);
+    let last_deg = apparent_sidereal_time(julian_date(date), longitude_deg) * 15.0;
+    (last_deg - ra + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Calculates the time of solar noon (the Sun's upper meridian transit).
+///
+/// Starts from local mean noon on the calendar date of `date` and iterates
+/// on the Sun's apparent hour angle, so the equation of time is accounted
+/// for automatically. Accurate to about a second.
+///
+/// # Arguments
+/// * `date` - Date to calculate for (only the UTC calendar date is used)
+/// * `location` - Observer's location
+///
+/// # Returns
+/// UTC time of solar noon
+///
+/// # Example
+/// ```
+/// use astro_math::{Location, sun::solar_noon};
+/// use chrono::{TimeZone, Timelike, Utc};
+///
+/// let greenwich = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let date = Utc.with_ymd_and_hms(2024, 11, 3, 0, 0, 0).unwrap();
+/// let noon = solar_noon(date, &greenwich);
+/// // Early November the Sun runs ~16 minutes fast
+/// assert_eq!((noon.hour(), noon.minute()), (11, 43));
+/// ```
+pub fn solar_noon(date: DateTime<Utc>, location: &Location) -> DateTime<Utc> {
+    let midnight = Utc.from_utc_datetime(&date.date_naive().and_hms_opt(0, 0, 0).unwrap());
+    let mean_noon_hours = 12.0 - location.longitude_deg / 15.0;
+    let mut t = midnight + Duration::milliseconds((mean_noon_hours * 3_600_000.0) as i64);
+
+    for _ in 0..4 {
+        let ha = sun_hour_angle(t, location.longitude_deg);
+        // Hour angle advances at the solar rate (~15°/hour)
+        let correction_ms = ha / 15.0 * 3_600_000.0;
+        t -= Duration::milliseconds(correction_ms as i64);
+        if correction_ms.abs() < 1.0 {
+            break;
+        }
+    }
+    t
+}
+
+/// Calculates the subsolar point: where on Earth the Sun is at the zenith.
+///
+/// # Arguments
+/// * `datetime` - UTC date/time
+///
+/// # Returns
+/// `(latitude_deg, longitude_deg)` with longitude in [-180, 180), east positive
+///
+/// # Example
+/// ```
+/// use astro_math::sun::subsolar_point;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 6, 20, 12, 0, 0).unwrap();
+/// let (lat, lon) = subsolar_point(dt);
+/// // June solstice: Sun overhead near the Tropic of Cancer, close to Greenwich at 12 UTC
+/// assert!((lat - 23.44).abs() < 0.05);
+/// assert!(lon.abs() < 1.0);
+/// ```
+pub fn subsolar_point(datetime: DateTime<Utc>) -> (f64, f64) {
+    let (ra, dec) = sun_apparent_ra_dec(datetime);
+    let gast_deg = apparent_sidereal_time(julian_date(datetime), 0.0) * 15.0;
+    let lon = (ra - gast_deg + 180.0).rem_euclid(360.0) - 180.0;
+    (dec, lon)
+}
+
+/// Calculates the Sun's azimuth and elevation for an observer.
+///
+/// Uses the Sun's apparent place of date. The elevation is geometric (no
+/// atmospheric refraction); apply [`crate::refraction`] if needed.
+///
+/// # Arguments
+/// * `datetime` - UTC date/time
+/// * `location` - Observer's location
+///
+/// # Returns
+/// `(azimuth_deg, elevation_deg)` with azimuth clockwise from north
+///
+/// # Errors
+/// Propagates errors from [`ra_dec_to_alt_az`] (non-finite intermediate values).
+///
+/// # Example
+/// ```
+/// use astro_math::{Location, sun::solar_azimuth_elevation};
+/// use chrono::{TimeZone, Utc};
+///
+/// let loc = Location { latitude_deg: 40.0, longitude_deg: -105.0, altitude_m: 1600.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 6, 21, 19, 0, 0).unwrap(); // ~local noon
+/// let (az, el) = solar_azimuth_elevation(dt, &loc).unwrap();
+/// assert!(el > 70.0);
+/// assert!((az - 180.0).abs() < 20.0);
+/// ```
+pub fn solar_azimuth_elevation(datetime: DateTime<Utc>, location: &Location) -> Result<(f64, f64)> {
+    let (ra, dec) = sun_apparent_ra_dec(datetime);
+    let (alt, az) = ra_dec_to_alt_az(ra, dec, datetime, location)?;
+    Ok((az, alt))
+}
+
+/// Calculates the length and direction of the shadow cast by a vertical
+/// object.
+///
+/// The shadow falls directly away from the Sun, with length
+/// `height / tan(elevation)`. The elevation is the Sun's apparent one,
+/// refracted for standard conditions (1013.25 hPa, 10 °C), since that is
+/// the direction the light arrives from; near sunrise and sunset this
+/// lengthens the shadow noticeably compared with the geometric elevation.
+/// The ground is taken as level.
+///
+/// # Arguments
+/// * `datetime` - UTC date/time
+/// * `location` - Observer's location
+/// * `object_height_m` - Height of the object in metres
+///
+/// # Returns
+/// `Some((length_m, azimuth_deg))` with the azimuth the shadow points
+/// toward, clockwise from north, or `None` while the Sun's centre is below
+/// the horizon
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - `AstroError::OutOfRange` if `object_height_m` is negative or not finite
+///
+/// # Example
+/// ```
+/// use astro_math::{Location, sun::shadow};
+/// use chrono::{TimeZone, Utc};
+///
+/// let loc = Location { latitude_deg: 40.0, longitude_deg: -105.0, altitude_m: 1600.0 };
+///
+/// // Summer noon: a short shadow pointing north
+/// let noon = Utc.with_ymd_and_hms(2024, 6, 21, 19, 0, 0).unwrap();
+/// let (length, azimuth) = shadow(noon, &loc, 10.0).unwrap().unwrap();
+/// assert!(length > 2.5 && length < 3.5);
+/// assert!(azimuth < 20.0 || azimuth > 340.0);
+///
+/// // No shadow from the Sun at night
+/// let night = Utc.with_ymd_and_hms(2024, 6, 21, 7, 0, 0).unwrap();
+/// assert!(shadow(night, &loc, 10.0).unwrap().is_none());
+/// ```
+pub fn shadow(datetime: DateTime<Utc>, location: &Location, object_height_m: f64) -> Result<Option<(f64, f64)>> {
+    validate_latitude(location.latitude_deg)?;
+    validate_longitude(location.longitude_deg)?;
+    if !object_height_m.is_finite() || object_height_m < 0.0 {
+        return Err(AstroError::OutOfRange {
+            parameter: "object_height_m",
+            value: object_height_m,
+            min: 0.0,
+            max: f64::INFINITY,
+        });
+    }
+
+    let (azimuth, elevation) = solar_azimuth_elevation(datetime, location)?;
+    let apparent = true_to_apparent_altitude(elevation, 1013.25, 10.0)?;
+    if apparent <= 0.0 {
+        return Ok(None);
+    }
+    let length = object_height_m / apparent.to_radians().tan();
+    Ok(Some((length, (azimuth + 180.0).rem_euclid(360.0))))
+}
+
+/// Inclination of the solar equator to the ecliptic in degrees (Carrington)
+const SOLAR_EQUATOR_INCLINATION_DEG: f64 = 7.25;
+
+/// Sidereal rotation period of the Sun used for Carrington longitudes, in days
+const CARRINGTON_SIDEREAL_PERIOD_DAYS: f64 = 25.38;
+
+/// Orientation of the solar disk as seen from the Earth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarPhysicalEphemeris {
+    /// Position angle P of the northern end of the Sun's rotation axis in
+    /// degrees, measured from celestial north through east, in [-180, 180)
+    /// (within ±26.3° in practice)
+    pub p: f64,
+    /// Heliographic latitude B0 of the centre of the disk in degrees, within
+    /// ±7.25°
+    pub b0: f64,
+    /// Heliographic (Carrington) longitude L0 of the centre of the disk in
+    /// degrees, in [0, 360)
+    pub l0: f64,
+}
+
+/// Calculates the position angle of the Sun's axis and the heliographic
+/// coordinates of the centre of the disk.
+///
+/// Follows Meeus, *Astronomical Algorithms*, chapter 29, with the Sun's
+/// apparent longitude from the same ephemeris as [`ecliptic_longitude_of_sun`].
+/// Solar observers use P to orient images with celestial north and B0, L0 to
+/// put heliographic coordinates on features of the disk.
+///
+/// # Arguments
+/// * `datetime` - Observation time
+///
+/// # Returns
+/// P, B0 and L0 as seen from the Earth's centre
+///
+/// # Example
+/// ```
+/// use astro_math::sun::solar_physical_ephemeris;
+/// use chrono::{TimeZone, Utc};
+///
+/// // Early June: the solar equator passes through the centre of the disk
+/// let dt = Utc.with_ymd_and_hms(2024, 6, 6, 12, 0, 0).unwrap();
+/// let eph = solar_physical_ephemeris(dt);
+/// assert!(eph.b0.abs() < 0.1);
+/// assert!(eph.p > -14.0 && eph.p < -12.0);
+/// ```
+pub fn solar_physical_ephemeris(datetime: DateTime<Utc>) -> SolarPhysicalEphemeris {
+    let jde = utc_to_tt_jd(julian_date(datetime));
+    let incl = SOLAR_EQUATOR_INCLINATION_DEG.to_radians();
+
+    // Longitude of the ascending node of the solar equator on the ecliptic
+    let k = (73.6667 + 1.3958333 * (jde - 2396758.0) / 36525.0).to_radians();
+    // Carrington rotation angle of the prime meridian
+    let theta = (jde - 2398220.0) * 360.0 / CARRINGTON_SIDEREAL_PERIOD_DAYS;
+
+    // Apparent longitude with nutation (λ') and without it (λ)
+    let lambda_app = ecliptic_longitude_of_sun(datetime).to_radians();
+    let lambda = lambda_app - (nutation_in_longitude(jde) / 3600.0).to_radians();
+    let epsilon = true_obliquity(jde).to_radians();
+
+    let x = (-lambda_app.cos() * epsilon.tan()).atan();
+    let y = (-(lambda - k).cos() * incl.tan()).atan();
+    let b0 = ((lambda - k).sin() * incl.sin()).asin();
+    let eta = (-(lambda - k).sin() * incl.cos()).atan2(-(lambda - k).cos());
+
+    SolarPhysicalEphemeris {
+        p: ((x + y).to_degrees() + 180.0).rem_euclid(360.0) - 180.0,
+        b0: b0.to_degrees(),
+        l0: (eta.to_degrees() - theta).rem_euclid(360.0),
+    }
+}
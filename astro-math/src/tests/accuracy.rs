@@ -0,0 +1,108 @@
+use crate::accuracy::*;
+use crate::moon::{moon_equatorial, moon_equatorial_with_accuracy};
+use crate::sun::{sun_ra_dec, sun_ra_dec_with_accuracy};
+use crate::time::julian_date;
+use crate::time_scales::utc_to_tt_jd_for_date;
+use crate::transforms::*;
+use crate::Location;
+use chrono::{TimeZone, Utc};
+
+fn separation_arcsec(alt1: f64, az1: f64, alt2: f64, az2: f64) -> f64 {
+    let (a1, a2) = (alt1.to_radians(), alt2.to_radians());
+    let daz = (az2 - az1).to_radians();
+    let cos_d = a1.sin() * a2.sin() + a1.cos() * a2.cos() * daz.cos();
+    cos_d.clamp(-1.0, 1.0).acos().to_degrees() * 3600.0
+}
+
+#[test]
+fn test_combine_errors() {
+    assert_eq!(combine_errors(&[]), 0.0);
+    assert!((combine_errors(&[1.0, 2.0, 2.0]) - 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_variants_return_base_results() {
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+
+    let (alt, az) = ra_dec_to_alt_az(279.2347, 38.7837, dt, &loc).unwrap();
+    let (alt_a, az_a, _) = ra_dec_to_alt_az_with_accuracy(279.2347, 38.7837, dt, &loc).unwrap();
+    assert_eq!((alt, az), (alt_a, az_a));
+
+    let (alt, az) = ra_dec_to_alt_az_erfa(279.2347, 38.7837, dt, &loc, Some(780.0), Some(10.0), None).unwrap();
+    let (alt_a, az_a, _) =
+        ra_dec_to_alt_az_erfa_with_accuracy(279.2347, 38.7837, dt, &loc, Some(780.0), Some(10.0), None).unwrap();
+    assert_eq!((alt, az), (alt_a, az_a));
+
+    let (ra, dec) = sun_ra_dec(dt);
+    let (ra_a, dec_a, _) = sun_ra_dec_with_accuracy(dt);
+    assert_eq!((ra, dec), (ra_a, dec_a));
+
+    let (ra, dec) = moon_equatorial(dt);
+    let (ra_a, dec_a, _) = moon_equatorial_with_accuracy(dt);
+    assert_eq!((ra, dec), (ra_a, dec_a));
+
+    assert!(ra_dec_to_alt_az_with_accuracy(400.0, 0.0, dt, &loc).is_err());
+    assert!(ra_dec_to_alt_az_erfa_with_accuracy(0.0, 95.0, dt, &loc, None, None, None).is_err());
+}
+
+#[test]
+fn test_meeus_estimate_covers_difference_from_erfa() {
+    let loc = Location { latitude_deg: -30.24, longitude_deg: -70.74, altitude_m: 2200.0 };
+    let stars = [(10.68, 41.27), (88.79, 7.41), (213.92, 19.18), (279.23, 38.78), (101.29, -16.72)];
+
+    for year in [1990, 2010, 2030] {
+        let dt = Utc.with_ymd_and_hms(year, 5, 1, 3, 0, 0).unwrap();
+        for &(ra, dec) in &stars {
+            let (alt_m, az_m, err_m) = ra_dec_to_alt_az_with_accuracy(ra, dec, dt, &loc).unwrap();
+            let (alt_e, az_e, err_e) = ra_dec_to_alt_az_erfa_with_accuracy(ra, dec, dt, &loc, None, None, None).unwrap();
+            let actual = separation_arcsec(alt_m, az_m, alt_e, az_e);
+
+            assert!(err_e < err_m, "ERFA path should be the more accurate");
+            assert!(err_e < 10.0, "ERFA error {}", err_e);
+            assert!(actual < 2.0 * err_m, "{} {}: actual {}″, estimated {}″", year, ra, actual, err_m);
+            assert!(actual > 0.5 * err_m, "{} {}: actual {}″, estimated {}″", year, ra, actual, err_m);
+        }
+    }
+}
+
+#[test]
+fn test_erfa_estimate_includes_refraction() {
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+
+    let (alt, _, airless) = ra_dec_to_alt_az_erfa_with_accuracy(344.41, -29.62, dt, &loc, None, None, None).unwrap();
+    let (_, _, refracted) =
+        ra_dec_to_alt_az_erfa_with_accuracy(344.41, -29.62, dt, &loc, Some(780.0), Some(10.0), Some(0.5)).unwrap();
+    assert!(alt > 0.0 && alt < 30.0, "altitude {}", alt);
+    assert!(refracted > airless);
+}
+
+#[test]
+fn test_sun_estimate_covers_time_scale_error() {
+    for year in [1975, 2000, 2024] {
+        let dt = Utc.with_ymd_and_hms(year, 7, 1, 0, 0, 0).unwrap();
+        let (ra, dec, err) = sun_ra_dec_with_accuracy(dt);
+
+        // The same position evaluated at the correct TT
+        let tt = utc_to_tt_jd_for_date(julian_date(dt));
+        let (earth_h, _) = crate::backend::ephemerides::Epv00(tt, 0.0);
+        let ra_tt = (-earth_h[1]).atan2(-earth_h[0]).to_degrees().rem_euclid(360.0);
+        let dec_tt = (-earth_h[2]).atan2(earth_h[0].hypot(earth_h[1])).to_degrees();
+        let actual = ((ra - ra_tt) * dec.to_radians().cos()).hypot(dec - dec_tt) * 3600.0;
+
+        assert!((actual - err).abs() < 0.1, "{}: actual {}″, estimated {}″", year, actual, err);
+    }
+}
+
+#[test]
+fn test_moon_estimate_grows_with_time_scale_and_epoch() {
+    let (_, _, now) = moon_equatorial_with_accuracy(Utc.with_ymd_and_hms(2024, 1, 25, 0, 0, 0).unwrap());
+    let (_, _, past) = moon_equatorial_with_accuracy(Utc.with_ymd_and_hms(1980, 1, 25, 0, 0, 0).unwrap());
+    let (_, _, far) = moon_equatorial_with_accuracy(Utc.with_ymd_and_hms(2300, 1, 25, 0, 0, 0).unwrap());
+
+    assert!((now - MOON98_SIGMA_ARCSEC).abs() < 0.01, "now {}", now);
+    // 18 s of TT-UTC mismatch at ~0.5″/s
+    assert!(past > 8.0 && past < 13.0, "past {}", past);
+    assert!((far - 4.0 * MOON98_SIGMA_ARCSEC).abs() < 0.1, "far {}", far);
+}
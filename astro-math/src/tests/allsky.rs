@@ -0,0 +1,156 @@
+use crate::allsky::*;
+use crate::error::AstroError;
+use crate::Location;
+use chrono::{TimeZone, Utc};
+
+fn synthetic_stars(model: &AllSkyModel) -> Vec<AllSkyStar> {
+    let mut stars = Vec::new();
+    for i in 0..24 {
+        let az = i as f64 * 15.0 + 3.0;
+        let alt = 15.0 + (i % 6) as f64 * 12.0;
+        let (x, y) = model.alt_az_to_pixel(alt, az).unwrap();
+        stars.push(AllSkyStar { x, y, alt_deg: alt, az_deg: az });
+    }
+    stars
+}
+
+#[test]
+fn test_zenith_maps_to_center() {
+    let model = AllSkyModel::new(640.0, 480.0, 5.0).with_rotation(33.0);
+    let (x, y) = model.alt_az_to_pixel(90.0, 123.0).unwrap();
+    assert!((x - 640.0).abs() < 1e-12);
+    assert!((y - 480.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_pixel_round_trip() {
+    let model = AllSkyModel::new(1000.0, 1000.0, 11.0)
+        .with_rotation(-20.0)
+        .with_distortion(-0.01, -2e-5);
+
+    for &(alt, az) in &[(80.0, 10.0), (45.0, 135.0), (10.0, 270.0), (1.0, 359.0)] {
+        let (x, y) = model.alt_az_to_pixel(alt, az).unwrap();
+        let (alt2, az2) = model.pixel_to_alt_az(x, y).unwrap();
+        assert!((alt - alt2).abs() < 1e-8, "alt {} -> {}", alt, alt2);
+        assert!((az - az2).abs() < 1e-8, "az {} -> {}", az, az2);
+    }
+}
+
+#[test]
+fn test_mirrored_parity() {
+    // Mirrored view: east appears on the left when north is up
+    let model = AllSkyModel::new(0.0, 0.0, 10.0);
+    let (x, _) = model.alt_az_to_pixel(45.0, 90.0).unwrap();
+    assert!(x < 0.0);
+
+    let unmirrored = model.with_mirrored(false);
+    let (x, _) = unmirrored.alt_az_to_pixel(45.0, 90.0).unwrap();
+    assert!(x > 0.0);
+}
+
+#[test]
+fn test_fit_recovers_model() {
+    let truth = AllSkyModel::new(1024.0, 768.0, 9.0)
+        .with_rotation(200.0)
+        .with_distortion(-0.012, 1.5e-5);
+    let stars = synthetic_stars(&truth);
+
+    let guess = AllSkyModel::new(1000.0, 800.0, 8.0);
+    let fit = fit_all_sky_model(&stars, &guess).unwrap();
+
+    assert!(fit.rms_px < 1e-4, "rms {}", fit.rms_px);
+    assert!((fit.model.x0 - truth.x0).abs() < 1e-3);
+    assert!((fit.model.y0 - truth.y0).abs() < 1e-3);
+    assert!((fit.model.rotation_deg - truth.rotation_deg).abs() < 1e-4);
+    assert!((fit.model.k1 - truth.k1).abs() < 1e-4);
+    assert_eq!(fit.model.parity, truth.parity);
+    assert_eq!(fit.residuals.len(), stars.len());
+}
+
+#[test]
+fn test_fit_detects_unmirrored_camera() {
+    let truth = AllSkyModel::new(500.0, 500.0, 5.0)
+        .with_rotation(45.0)
+        .with_mirrored(false);
+    let stars = synthetic_stars(&truth);
+
+    let fit = fit_all_sky_model(&stars, &AllSkyModel::new(510.0, 490.0, 5.2)).unwrap();
+    assert_eq!(fit.model.parity, 1.0);
+    assert!(fit.rms_px < 1e-4);
+}
+
+#[test]
+fn test_fit_with_noise() {
+    let truth = AllSkyModel::new(800.0, 600.0, 6.0).with_rotation(90.0);
+    let mut stars = synthetic_stars(&truth);
+    for (i, star) in stars.iter_mut().enumerate() {
+        star.x += if i % 2 == 0 { 0.3 } else { -0.3 };
+        star.y += if i % 3 == 0 { 0.2 } else { -0.1 };
+    }
+
+    let fit = fit_all_sky_model(&stars, &AllSkyModel::new(790.0, 610.0, 6.0)).unwrap();
+    assert!(fit.rms_px < 0.5);
+    assert!((fit.model.x0 - 800.0).abs() < 0.5);
+    assert!((fit.model.y0 - 600.0).abs() < 0.5);
+}
+
+#[test]
+fn test_fit_requires_enough_stars() {
+    let model = AllSkyModel::new(0.0, 0.0, 10.0);
+    let stars = synthetic_stars(&model)[..3].to_vec();
+    let result = fit_all_sky_model(&stars, &model);
+    assert!(matches!(result, Err(AstroError::CalculationError { .. })));
+}
+
+#[test]
+fn test_star_from_ra_dec() {
+    let location = Location { latitude_deg: 40.0, longitude_deg: -105.0, altitude_m: 1600.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let star = AllSkyStar::from_ra_dec(10.0, 20.0, 279.23, 38.78, dt, &location).unwrap();
+    assert_eq!(star.x, 10.0);
+    assert!((-90.0..=90.0).contains(&star.alt_deg));
+
+    assert!(AllSkyStar::from_ra_dec(0.0, 0.0, 400.0, 0.0, dt, &location).is_err());
+}
+
+#[test]
+fn test_invalid_altitude() {
+    let model = AllSkyModel::new(0.0, 0.0, 10.0);
+    assert!(matches!(
+        model.alt_az_to_pixel(91.0, 0.0),
+        Err(AstroError::OutOfRange { .. })
+    ));
+}
+
+#[test]
+fn test_fit_rejects_unsolvable_stars() {
+    let guess = AllSkyModel::new(510.0, 490.0, 5.2);
+
+    // Stars along a line in the image cannot come from a radial projection
+    let collinear: Vec<_> = (0..8)
+        .map(|i| AllSkyStar {
+            x: 100.0 + 50.0 * i as f64,
+            y: 300.0,
+            alt_deg: 20.0 + 5.0 * i as f64,
+            az_deg: 45.0 * i as f64,
+        })
+        .collect();
+    assert!(matches!(
+        fit_all_sky_model(&collinear, &guess),
+        Err(AstroError::CalculationError { .. })
+    ));
+
+    // A ring at one altitude fixes the radius there but not the distortion
+    let truth = AllSkyModel::new(500.0, 500.0, 5.0).with_rotation(45.0);
+    let ring: Vec<_> = (0..8)
+        .map(|i| {
+            let az = 45.0 * i as f64;
+            let (x, y) = truth.alt_az_to_pixel(30.0, az).unwrap();
+            AllSkyStar { x, y, alt_deg: 30.0, az_deg: az }
+        })
+        .collect();
+    assert!(matches!(
+        fit_all_sky_model(&ring, &guess),
+        Err(AstroError::CalculationError { .. })
+    ));
+}
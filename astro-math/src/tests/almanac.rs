@@ -0,0 +1,181 @@
+use crate::almanac::*;
+use crate::error::AstroError;
+use crate::export::{almanac_to_csv, almanac_to_json};
+use crate::location::Location;
+use crate::rise_set::{moon_rise_set, sun_rise_set};
+use crate::sun::solar_noon;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+
+fn greenwich() -> Location {
+    Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 }
+}
+
+fn seconds_apart(a: DateTime<Utc>, b: DateTime<Utc>) -> i64 {
+    (a - b).num_seconds().abs()
+}
+
+#[test]
+fn test_sun_month_matches_rise_set_and_noon() {
+    let location = greenwich();
+    let month = Utc.with_ymd_and_hms(2024, 3, 17, 12, 0, 0).unwrap();
+    let table = monthly_table(AlmanacBody::Sun, month, &location).unwrap();
+    assert_eq!(table.len(), 31);
+    assert_eq!(table[0].date, Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap());
+    assert_eq!(table[30].date.day(), 31);
+    // USNO: sunrise 06:44, sunset 17:41 on March 1
+    let rise = Utc.with_ymd_and_hms(2024, 3, 1, 6, 44, 0).unwrap();
+    let set = Utc.with_ymd_and_hms(2024, 3, 1, 17, 41, 0).unwrap();
+    assert!(seconds_apart(table[0].rise.unwrap(), rise) < 60);
+    assert!(seconds_apart(table[0].set.unwrap(), set) < 60);
+
+    for row in &table {
+        let transit = row.transit.unwrap();
+        assert!(seconds_apart(transit, solar_noon(row.date, &location)) < 5);
+        // sun_rise_set holds the Sun at its midnight place, which in March
+        // costs it a few minutes
+        let (rise, set) = sun_rise_set(row.date, &location).unwrap().unwrap();
+        assert!(seconds_apart(row.rise.unwrap(), rise) < 360);
+        assert!(seconds_apart(row.set.unwrap(), set) < 360);
+        assert!((row.magnitude + 26.74).abs() < 0.05);
+        assert!((row.distance_au - 0.995).abs() < 0.01);
+    }
+}
+
+#[test]
+fn test_moon_month_matches_moon_rise_set() {
+    let location = greenwich();
+    let month = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let table = monthly_table(AlmanacBody::Moon, month, &location).unwrap();
+    assert_eq!(table.len(), 31);
+
+    for row in &table {
+        let (rise, set) = moon_rise_set(row.date, &location).unwrap();
+        assert_eq!(row.rise.is_some(), rise.is_some());
+        assert_eq!(row.set.is_some(), set.is_some());
+        if let (Some(a), Some(b)) = (row.rise, rise) {
+            assert!(seconds_apart(a, b) < 30);
+        }
+        if let (Some(a), Some(b)) = (row.set, set) {
+            assert!(seconds_apart(a, b) < 30);
+        }
+    }
+    // Once a month the Moon rises after midnight of the next day
+    assert!(table.iter().any(|row| row.rise.is_none()));
+    // Full Moon of 2024 March 25 around magnitude -12.7
+    let brightest = table.iter().map(|row| row.magnitude).fold(f64::INFINITY, f64::min);
+    assert!((-13.0..-12.3).contains(&brightest));
+}
+
+#[test]
+fn test_jupiter_at_opposition() {
+    let t = Utc.with_ymd_and_hms(2024, 12, 7, 0, 0, 0).unwrap();
+    let jupiter = apparent_place(AlmanacBody::Jupiter, t).unwrap();
+    // Astronomical Almanac: RA 5h01m, Dec +22°10', magnitude -2.8
+    assert!((jupiter.ra - 75.3).abs() < 0.5);
+    assert!((jupiter.dec - 22.2).abs() < 0.3);
+    assert!((jupiter.distance_au - 4.08).abs() < 0.02);
+    assert!((jupiter.magnitude + 2.8).abs() < 0.1);
+}
+
+#[test]
+fn test_planet_magnitudes_stay_in_range() {
+    let location = greenwich();
+    let month = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let ranges = [
+        (AlmanacBody::Mercury, -2.5, 5.5),
+        (AlmanacBody::Venus, -4.9, -3.8),
+        (AlmanacBody::Mars, -2.9, 1.9),
+        (AlmanacBody::Saturn, -0.6, 1.5),
+        (AlmanacBody::Uranus, 5.3, 6.0),
+        (AlmanacBody::Neptune, 7.7, 8.0),
+    ];
+    for (body, min, max) in ranges {
+        for row in monthly_table(body, month, &location).unwrap() {
+            assert!((min..max).contains(&row.magnitude), "{} {}", body.name(), row.magnitude);
+        }
+    }
+}
+
+#[test]
+fn test_polar_summer_sun_never_sets() {
+    let tromso = Location { latitude_deg: 69.65, longitude_deg: 18.96, altitude_m: 0.0 };
+    let june = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    let table = monthly_table(AlmanacBody::Sun, june, &tromso).unwrap();
+    assert_eq!(table.len(), 30);
+    for row in &table {
+        assert!(row.rise.is_none() && row.set.is_none());
+        assert!(row.transit.is_some());
+    }
+}
+
+#[test]
+fn test_invalid_location() {
+    let month = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let location = Location { latitude_deg: 95.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(matches!(
+        monthly_table(AlmanacBody::Mars, month, &location),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+}
+
+#[test]
+fn test_export() {
+    let month = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+    let table = monthly_table(AlmanacBody::Mars, month, &greenwich()).unwrap();
+    assert_eq!(table.len(), 29);
+
+    let csv = almanac_to_csv(&table);
+    assert!(csv.starts_with("date,rise,transit,set,ra_deg,dec_deg,distance_au,magnitude\r\n2024-02-01,"));
+    assert_eq!(csv.lines().count(), 30);
+
+    let json = almanac_to_json(&table);
+    assert!(json.starts_with("[{\"date\":\"2024-02-01\",\"rise\":\"2024-02-01T"));
+    assert_eq!(json.matches("\"magnitude\":").count(), 29);
+}
+
+#[test]
+fn test_sky_snapshot_matrix() {
+    let location = greenwich();
+    let dt = Utc.with_ymd_and_hms(2024, 12, 7, 23, 0, 0).unwrap();
+    let sky = sky_snapshot(dt, &location).unwrap();
+    assert_eq!(sky.time, dt);
+    assert_eq!(sky.bodies.len(), AlmanacBody::ALL.len());
+    for (i, entry) in sky.bodies.iter().enumerate() {
+        assert_eq!(entry.body, AlmanacBody::ALL[i]);
+        assert_eq!(sky.separations[i][i], 0.0);
+        for j in 0..sky.bodies.len() {
+            assert_eq!(sky.separations[i][j], sky.separations[j][i]);
+            let other = &sky.bodies[j];
+            let expected = crate::angles::angular_separation(entry.ra, entry.dec, other.ra, other.dec).unwrap();
+            assert!((sky.separations[i][j] - expected).abs() < 1e-6);
+        }
+    }
+
+    // Jupiter at opposition: opposite the Sun, 48" across and high at midnight
+    let jupiter = sky.body(AlmanacBody::Jupiter);
+    assert!((jupiter.diameter_arcsec - 48.1).abs() < 0.5, "{}", jupiter.diameter_arcsec);
+    assert!((jupiter.magnitude + 2.8).abs() < 0.1);
+    assert!(jupiter.altitude > 55.0);
+    assert!(sky.separation(AlmanacBody::Sun, AlmanacBody::Jupiter) > 170.0);
+
+    let sun = sky.body(AlmanacBody::Sun);
+    assert!((sun.diameter_arcsec - 1949.0).abs() < 5.0, "{}", sun.diameter_arcsec);
+    assert!(sun.altitude < -50.0);
+}
+
+#[test]
+fn test_sky_snapshot_moon_parallax() {
+    // The Moon's topocentric diameter grows as it rises towards the zenith
+    let location = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+    let sky = sky_snapshot(dt, &location).unwrap();
+    let moon = sky.body(AlmanacBody::Moon);
+    let geocentric = apparent_place(AlmanacBody::Moon, dt).unwrap();
+    let distance_km = geocentric.distance_au * 149_597_870.7;
+    let geocentric_diameter = 2.0 * (1737.4 / distance_km).asin().to_degrees() * 3600.0;
+    let expected = geocentric_diameter * (1.0 + 6378.0 / distance_km * moon.altitude.to_radians().sin());
+    assert!((moon.diameter_arcsec - expected).abs() < 1.0, "{} vs {}", moon.diameter_arcsec, expected);
+
+    let bad = Location { latitude_deg: 91.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(matches!(sky_snapshot(dt, &bad), Err(AstroError::InvalidCoordinate { .. })));
+}
@@ -0,0 +1,65 @@
+use crate::angles::*;
+use crate::error::AstroError;
+
+#[test]
+fn test_separation_keeps_precision_near_zero_and_antipode() {
+    // 1 µas, where acos of the cosine formula rounds to 0
+    let tiny = 1e-6 / 3600.0;
+    let sep = angular_separation(123.4, 0.0, 123.4, tiny).unwrap();
+    assert!((sep / tiny - 1.0).abs() < 1e-6);
+    assert_eq!(angular_separation(10.0, 20.0, 10.0, 20.0).unwrap(), 0.0);
+
+    // Near-antipodes, where the haversine loses precision
+    let sep = angular_separation(30.0, 40.0, 210.0, -40.0 + tiny).unwrap();
+    assert!(((180.0 - sep) / tiny - 1.0).abs() < 1e-3);
+    assert_eq!(angular_separation(30.0, 40.0, 210.0, -40.0).unwrap(), 180.0);
+
+    // Wraps through RA 0 and is symmetric
+    let a = angular_separation(359.5, 0.0, 0.5, 0.0).unwrap();
+    let b = angular_separation(0.5, 0.0, 359.5, 0.0).unwrap();
+    assert!((a - 1.0).abs() < 1e-12 && (a - b).abs() < 1e-15);
+}
+
+#[test]
+fn test_position_angle_directions_and_poles() {
+    assert!((position_angle(100.0, 20.0, 100.0, 19.0).unwrap() - 180.0).abs() < 1e-9);
+    assert!((position_angle(100.0, 20.0, 99.0, 20.0).unwrap() - 270.0).abs() < 0.5);
+    assert!((position_angle(359.0, 0.0, 1.0, 0.0).unwrap() - 90.0).abs() < 1e-9);
+    assert_eq!(position_angle(100.0, 20.0, 100.0, 20.0).unwrap(), 0.0);
+
+    // Everything lies south of the north pole; PA is measured from the
+    // meridian of the pole's RA
+    assert!((position_angle(0.0, 90.0, 180.0, 80.0).unwrap() - 0.0).abs() < 1e-9);
+    assert!((position_angle(0.0, 90.0, 90.0, 80.0).unwrap() - 90.0).abs() < 1e-9);
+    // ...and north of the south pole
+    assert!((position_angle(100.0, 0.0, 0.0, -90.0).unwrap() - 180.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_offset_by_round_trips() {
+    for &(ra, dec) in &[(0.0, 0.0), (359.9, 45.0), (200.0, -89.9), (10.0, 90.0), (280.0, -90.0)] {
+        for &pa in &[0.0, 33.0, 90.0, 181.0, 300.0] {
+            for &sep in &[1e-6, 0.5, 30.0, 120.0, 179.0] {
+                let (ra2, dec2) = offset_by(ra, dec, pa, sep).unwrap();
+                assert!((0.0..360.0).contains(&ra2) && (-90.0..=90.0).contains(&dec2));
+                let back = angular_separation(ra, dec, ra2, dec2).unwrap();
+                assert!((back - sep).abs() < 1e-9, "sep {} -> {}", sep, back);
+                let diff = (position_angle(ra, dec, ra2, dec2).unwrap() - pa + 180.0).rem_euclid(360.0) - 180.0;
+                assert!(diff.abs() < 1e-6, "({}, {}) pa {} sep {}: {}", ra, dec, pa, sep, diff);
+            }
+        }
+    }
+
+    // A negative separation goes the other way
+    let (ra, dec) = offset_by(50.0, 10.0, 0.0, -5.0).unwrap();
+    assert!((ra - 50.0).abs() < 1e-9 && (dec - 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_invalid_input() {
+    assert!(matches!(angular_separation(360.0, 0.0, 0.0, 0.0), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(position_angle(0.0, 0.0, 0.0, 90.5), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(offset_by(0.0, f64::NAN, 0.0, 1.0), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(offset_by(0.0, 0.0, f64::INFINITY, 1.0), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(offset_by(0.0, 0.0, 0.0, f64::NAN), Err(AstroError::CalculationError { .. })));
+}
@@ -0,0 +1,88 @@
+use crate::apparent::*;
+use crate::error::AstroError;
+use crate::precession::icrs_to_mean_of_date;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn epoch() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+}
+
+/// On-sky change (ΔRA·cos Dec, ΔDec) from `a` to `b` in mas
+fn change_mas(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let dra = (b.0 - a.0 + 180.0).rem_euclid(360.0) - 180.0;
+    let dec = ((a.1 + b.1) / 2.0).to_radians();
+    (dra * dec.cos() * 3.6e6, (b.1 - a.1) * 3.6e6)
+}
+
+#[test]
+fn test_drift_integrates_to_apparent_motion() {
+    // Summing hourly rates over a fortnight reproduces the change in apparent place
+    for (ra, dec) in [(0.0, 30.0), (83.82, -5.39), (279.23473479, 38.78368896), (37.95, 89.26)] {
+        let hours = 14 * 24;
+        let (mut sum_ra, mut sum_dec) = (0.0, 0.0);
+        for hour in 0..hours {
+            let (dra, ddec) = apparent_place_drift(ra, dec, epoch() + Duration::minutes(hour * 60 + 30)).unwrap();
+            sum_ra += dra / 24.0;
+            sum_dec += ddec / 24.0;
+        }
+        let start = apparent_place(ra, dec, epoch()).unwrap();
+        let end = apparent_place(ra, dec, epoch() + Duration::hours(hours)).unwrap();
+        let (expected_ra, expected_dec) = change_mas(start, end);
+        // The cos(Dec) of a moving position differs slightly near the pole
+        let tolerance = if dec > 89.0 { 50.0 } else { 1.0 };
+        assert!((sum_ra - expected_ra).abs() < tolerance, "RA {} vs {}", sum_ra, expected_ra);
+        assert!((sum_dec - expected_dec).abs() < 1.0, "Dec {} vs {}", sum_dec, expected_dec);
+    }
+}
+
+#[test]
+fn test_yearly_mean_is_precession() {
+    // Over a year aberration averages out, leaving precession (plus a few
+    // mas/day from the long-period nutation terms). The star is well off the
+    // ecliptic, so the Sun never passes close to it.
+    let (ra, dec) = (0.0, 30.0);
+    let (mut mean_ra, mut mean_dec) = (0.0, 0.0);
+    for day in 0..365 {
+        let (dra, ddec) = apparent_place_drift(ra, dec, epoch() + Duration::hours(day * 24 + 12)).unwrap();
+        mean_ra += dra / 365.0;
+        mean_dec += ddec / 365.0;
+    }
+    let start = icrs_to_mean_of_date(ra, dec, epoch()).unwrap();
+    let end = icrs_to_mean_of_date(ra, dec, epoch() + Duration::days(365)).unwrap();
+    let (precession_ra, precession_dec) = change_mas(start, end);
+    // 46.1″·cos(30°) and 20.0″ per year at RA 0h
+    assert!((precession_ra / 365.0 - 109.3).abs() < 1.0, "{}", precession_ra / 365.0);
+    assert!((precession_dec / 365.0 - 54.9).abs() < 1.0, "{}", precession_dec / 365.0);
+    assert!((mean_ra - precession_ra / 365.0).abs() < 20.0, "{} vs {}", mean_ra, precession_ra / 365.0);
+    assert!((mean_dec - precession_dec / 365.0).abs() < 20.0, "{} vs {}", mean_dec, precession_dec / 365.0);
+}
+
+#[test]
+fn test_aberration_cycle() {
+    // For a star at the ecliptic pole aberration traces a 20.5″ circle once
+    // a year, so the rate has a constant magnitude of ~353 mas/day on top of
+    // precession and nutation
+    let (ra, dec) = (270.0, 66.56);
+    let (mut min, mut max) = (f64::MAX, f64::MIN);
+    for day in (0..365).step_by(7) {
+        let (dra, ddec) = apparent_place_drift(ra, dec, epoch() + Duration::days(day)).unwrap();
+        let rate = dra.hypot(ddec);
+        min = min.min(rate);
+        max = max.max(rate);
+    }
+    assert!(min > 150.0 && max < 600.0, "{} .. {}", min, max);
+}
+
+#[test]
+fn test_finite_at_pole() {
+    let (dra, ddec) = apparent_place_drift(0.0, 90.0, epoch()).unwrap();
+    assert!(dra.is_finite() && ddec.is_finite());
+    assert!(dra.hypot(ddec) < 600.0);
+}
+
+#[test]
+fn test_invalid_coordinates() {
+    assert!(matches!(apparent_place_drift(360.0, 0.0, epoch()), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(apparent_place_drift(0.0, -91.0, epoch()), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(apparent_place(-1.0, 0.0, epoch()), Err(AstroError::InvalidCoordinate { .. })));
+}
@@ -0,0 +1,99 @@
+use crate::astro_time::*;
+use crate::error::AstroError;
+use crate::time::julian_date_two_part;
+use crate::time_scales::{tdb_tt_offset_seconds, tt_to_tdb_jd};
+use chrono::{TimeZone, Timelike, Utc};
+
+/// Seconds from `a` to `b`, without rounding either to a single `f64`
+fn seconds_between(a: AstroTime, b: AstroTime) -> f64 {
+    ((b.jd1() - a.jd1()) + (b.jd2() - a.jd2())) * 86400.0
+}
+
+#[test]
+fn test_scale_offsets() {
+    // 1999 had TAI−UTC = 32 s, 2024 has 37 s
+    for (iso, tai_utc) in [("1999-06-01T00:00:00", 32.0), ("2024-06-01T00:00:00", 37.0)] {
+        let utc = AstroTime::from_iso(iso).unwrap();
+        let seconds = |t: AstroTime| seconds_between(utc, t);
+        assert!((seconds(utc.to_tai()) - tai_utc).abs() < 1e-6);
+        assert!((seconds(utc.to_tt()) - tai_utc - 32.184).abs() < 1e-6);
+        let tdb = utc.to_tdb();
+        assert!((seconds(tdb) - seconds(utc.to_tt()) - tdb_tt_offset_seconds(utc.to_tt().jd())).abs() < 1e-6);
+        assert!((tdb.jd() - tt_to_tdb_jd(utc.to_tt().jd())).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_round_trips_keep_nanoseconds() {
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap().with_nanosecond(123_456_789).unwrap();
+    let t = AstroTime::from(dt);
+    for back in [
+        t.to_tai().to_utc(),
+        t.to_tt().to_utc(),
+        t.to_tdb().to_tai().to_utc(),
+        t.to_ut1(-0.123).to_tt().to_utc(),
+    ] {
+        assert_eq!(back.scale(), TimeScale::Utc);
+        assert!(seconds_between(t, back).abs() < 1e-9);
+        assert_eq!(back.to_datetime(), dt);
+    }
+    assert_eq!(t.to_tdb().to_tt().to_tdb().jd2(), t.to_tdb().jd2());
+}
+
+#[test]
+fn test_ut1() {
+    let utc = AstroTime::from_iso("2024-08-04T06:00:00").unwrap();
+    let ut1 = utc.to_ut1(0.25);
+    assert_eq!(ut1.scale(), TimeScale::Ut1);
+    assert!((seconds_between(utc, ut1) - 0.25).abs() < 1e-9);
+    // Changing DUT1 goes through UTC
+    let other = ut1.to_ut1(-0.1);
+    assert!((seconds_between(utc, other) + 0.1).abs() < 1e-9);
+
+    let given = AstroTime::from_ut1(ut1.jd1(), ut1.jd2(), 0.25);
+    assert!(seconds_between(utc, given.to_utc()).abs() < 1e-9);
+    assert_eq!(given.to_utc().to_datetime(), utc.to_datetime());
+    // Without DUT1 a UT1 date is taken as UTC
+    let assumed = AstroTime::new(2460526.5, 0.25, TimeScale::Ut1);
+    assert_eq!(assumed.to_utc().jd2(), 0.25);
+}
+
+#[test]
+fn test_leap_second() {
+    // 2016-12-31T23:59:60 UTC is 2017-01-01T00:00:36 TAI
+    let leap = AstroTime::from_iso("2016-12-31T23:59:60").unwrap();
+    assert_eq!(leap.to_tai().to_string(), "2017-01-01T00:00:36.000 TAI");
+    let before = AstroTime::from_iso("2016-12-31T23:59:59").unwrap();
+    let after = AstroTime::from_iso("2017-01-01T00:00:00").unwrap();
+    assert!((seconds_between(before.to_tai(), leap.to_tai()) - 1.0).abs() < 1e-9);
+    assert!((seconds_between(leap.to_tai(), after.to_tai()) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_iso_parsing() {
+    let utc = AstroTime::from_datetime(Utc.with_ymd_and_hms(2024, 8, 4, 6, 30, 0).unwrap());
+    for text in [
+        "2024-08-04T06:30:00",
+        "2024-08-04T06:30:00Z",
+        "2024-08-04 06:30:00.000",
+        "2024-08-04T06:30",
+        " 2024-08-04T06:30:00 UTC ",
+        "2024-08-04T01:30:00-05:00",
+    ] {
+        assert_eq!(AstroTime::from_iso(text).unwrap(), utc, "{text}");
+    }
+    let midnight = AstroTime::from_iso("2024-08-04").unwrap();
+    assert_eq!((midnight.jd1(), midnight.jd2()), julian_date_two_part(Utc.with_ymd_and_hms(2024, 8, 4, 0, 0, 0).unwrap()));
+    assert_eq!(midnight.mjd(), 60526.0);
+
+    let tt: AstroTime = "2024-08-04T06:31:09.184 tt".parse().unwrap();
+    assert_eq!(tt.scale(), TimeScale::Tt);
+    assert!(seconds_between(utc, tt.to_utc()).abs() < 1e-9);
+    assert_eq!(tt.to_string(), "2024-08-04T06:31:09.184 TT");
+
+    for bad in ["", "yesterday", "2024-13-01T00:00:00", "2024-08-04T06:30:00 GPS", "2024-08-04T06:30:00+02:00 TT"] {
+        assert!(matches!(AstroTime::from_iso(bad), Err(AstroError::InvalidFormat { .. })), "{bad}");
+    }
+    assert_eq!("tdb".parse::<TimeScale>().unwrap(), TimeScale::Tdb);
+    assert!("GPS".parse::<TimeScale>().is_err());
+}
@@ -0,0 +1,64 @@
+use crate::astrometric_context::AstrometricContext;
+use crate::eop::EarthOrientation;
+use crate::error::AstroError;
+use crate::refraction::AtmosphericConditions;
+use crate::transforms::{ra_dec_to_alt_az_batch_parallel, ra_dec_to_alt_az_erfa, ra_dec_to_alt_az_erfa_with_eop};
+use crate::Location;
+use chrono::{TimeZone, Utc};
+
+fn mauna_kea() -> Location {
+    Location { latitude_deg: 19.8207, longitude_deg: -155.4681, altitude_m: 4205.0 }
+}
+
+fn catalog() -> Vec<(f64, f64)> {
+    (0..500).map(|k| ((k as f64 * 7.31) % 360.0, (k as f64 * 3.17) % 180.0 - 90.0)).collect()
+}
+
+#[test]
+fn test_context_matches_per_star_erfa() {
+    let site = mauna_kea();
+    let dt = Utc.with_ymd_and_hms(2024, 1, 2, 6, 0, 0).unwrap();
+    let weather = AtmosphericConditions { pressure_hpa: 615.0, temperature_c: 2.0, relative_humidity: 0.1 };
+    let eop = EarthOrientation { dut1: 0.0113, xp_arcsec: 0.075, yp_arcsec: 0.217, dx_mas: Some(0.28), dy_mas: Some(-0.06) };
+
+    let context = AstrometricContext::with_eop(dt, &site, Some(weather), &eop).unwrap();
+    assert_eq!(context.datetime(), dt);
+    assert_eq!(context.location().latitude_deg, site.latitude_deg);
+    let batch = context.observed_from_icrs_many(&catalog()).unwrap();
+    for (&(ra, dec), &alt_az) in catalog().iter().zip(&batch) {
+        let expected = ra_dec_to_alt_az_erfa_with_eop(ra, dec, dt, &site, Some(615.0), Some(2.0), Some(0.1), &eop).unwrap();
+        assert_eq!(alt_az, expected, "ra {} dec {}", ra, dec);
+    }
+
+    let context = AstrometricContext::new(dt, &site, None).unwrap();
+    let (alt, az) = context.observed_from_icrs(279.2347, 38.7837).unwrap();
+    assert_eq!((alt, az), ra_dec_to_alt_az_erfa(279.2347, 38.7837, dt, &site, None, None, None).unwrap());
+}
+
+#[test]
+fn test_batch_parallel_uses_shared_context() {
+    let site = mauna_kea();
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let batch = ra_dec_to_alt_az_batch_parallel(&catalog(), dt, &site, Some(615.0), None, None).unwrap();
+    for (&(ra, dec), &alt_az) in catalog().iter().zip(&batch) {
+        assert_eq!(alt_az, ra_dec_to_alt_az_erfa(ra, dec, dt, &site, Some(615.0), None, None).unwrap());
+    }
+    assert!(ra_dec_to_alt_az_batch_parallel(&[], dt, &site, None, None, None).unwrap().is_empty());
+}
+
+#[test]
+fn test_context_errors() {
+    let site = mauna_kea();
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let wet = AtmosphericConditions { pressure_hpa: 615.0, temperature_c: 2.0, relative_humidity: 1.5 };
+    assert!(matches!(AstrometricContext::new(dt, &site, Some(wet)), Err(AstroError::OutOfRange { .. })));
+    let eop = EarthOrientation { dut1: 1.5, ..EarthOrientation::default() };
+    assert!(matches!(AstrometricContext::with_eop(dt, &site, None, &eop), Err(AstroError::OutOfRange { .. })));
+
+    let context = AstrometricContext::new(dt, &site, None).unwrap();
+    assert!(matches!(context.observed_from_icrs(360.0, 0.0), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(
+        context.observed_from_icrs_many(&[(10.0, 20.0), (10.0, -91.0)]),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+}
@@ -0,0 +1,196 @@
+//! Pure-Rust backend checked against ERFA, to the tolerances tabulated in
+//! the crate documentation ("Cargo Features").
+
+use crate::backend::pure;
+
+/// Milliarcseconds in radians
+const MAS: f64 = std::f64::consts::PI / 648000.0 / 1000.0;
+
+/// TT Julian Dates from 1950 to 2050
+const EPOCHS: [f64; 6] = [2433282.5, 2442000.5, 2451545.0, 2455197.5, 2460600.5, 2469807.5];
+
+fn angle_diff(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(std::f64::consts::TAU);
+    d.min(std::f64::consts::TAU - d)
+}
+
+#[test]
+fn test_exact_ports_match_erfa() {
+    for &jd in &EPOCHS {
+        assert!((pure::precnutpolar::Obl06(jd, 0.0) - erfars::precnutpolar::Obl06(jd, 0.0)).abs() < 1e-14);
+        assert!((pure::precnutpolar::Obl80(jd, 0.0) - erfars::precnutpolar::Obl80(jd, 0.0)).abs() < 1e-14);
+        assert!(angle_diff(pure::rotationtime::Era00(jd, 0.0), erfars::rotationtime::Era00(jd, 0.0)) < 1e-12);
+        assert!(
+            angle_diff(
+                pure::rotationtime::Gmst06(jd, 0.0, jd, 0.0),
+                erfars::rotationtime::Gmst06(jd, 0.0, jd, 0.0)
+            ) < 1e-12
+        );
+
+        let (mut a, mut b) = ([0.0; 9], [0.0; 9]);
+        pure::precnutpolar::Pmat06(jd, 0.0, &mut a);
+        erfars::precnutpolar::Pmat06(jd, 0.0, &mut b);
+        for i in 0..9 {
+            assert!((a[i] - b[i]).abs() < 1e-14, "Pmat06 element {} at {}", i, jd);
+        }
+
+        let pe = pure::precnutpolar::P06e(jd, 0.0);
+        let ee = erfars::precnutpolar::P06e(jd, 0.0);
+        assert!((pe.9 - ee.9).abs() < 1e-14 && (pe.10 - ee.10).abs() < 1e-14 && (pe.11 - ee.11).abs() < 1e-14);
+
+        for np in 1..=8 {
+            let a = pure::ephemerides::Plan94(jd, 0.0, np).unwrap();
+            let b = erfars::ephemerides::Plan94(jd, 0.0, np).unwrap();
+            for i in 0..6 {
+                assert!((a[i] - b[i]).abs() < 1e-12, "Plan94 planet {} component {}", np, i);
+            }
+        }
+
+        let a = pure::ephemerides::Moon98(jd, 0.0);
+        let b = erfars::ephemerides::Moon98(jd, 0.0);
+        for i in 0..6 {
+            assert!((a[i] - b[i]).abs() < 1e-13, "Moon98 component {} at {}", i, jd);
+        }
+    }
+
+    let (ra, dec) = (1.2, -0.4);
+    let (l, b) = pure::galacticcoordinates::Icrs2g(ra, dec);
+    let (l_ref, b_ref) = erfars::galacticcoordinates::Icrs2g(ra, dec);
+    assert!(angle_diff(l, l_ref) < 1e-14 && (b - b_ref).abs() < 1e-14);
+    let (r2, d2) = pure::galacticcoordinates::G2icrs(l, b);
+    assert!(angle_diff(r2, ra) < 1e-13 && (d2 - dec).abs() < 1e-13);
+
+    let (xi, eta) = pure::gnomonic::Tpxes(1.21, -0.38, ra, dec).unwrap();
+    let (xi_ref, eta_ref) = erfars::gnomonic::Tpxes(1.21, -0.38, ra, dec).unwrap();
+    assert!((xi - xi_ref).abs() < 1e-15 && (eta - eta_ref).abs() < 1e-15);
+    let (a, d) = pure::gnomonic::Tpsts(xi, eta, ra, dec);
+    assert!(angle_diff(a, 1.21) < 1e-14 && (d + 0.38).abs() < 1e-14);
+    assert!(pure::gnomonic::Tpxes(ra + std::f64::consts::PI, -dec, ra, dec).is_err());
+}
+
+#[test]
+fn test_long_term_precession_matches_erfa() {
+    for &epj in &[-10000.0, 1000.0, 2000.0, 2500.0, 5000.0, 100000.0] {
+        let (mut a, mut b) = ([0.0; 9], [0.0; 9]);
+        pure::precnutpolar::Ltpb(epj, &mut a);
+        erfars::precnutpolar::Ltpb(epj, &mut b);
+        for i in 0..9 {
+            assert!((a[i] - b[i]).abs() < 1e-14, "Ltpb element {} at {}", i, epj);
+        }
+        let (pe, ee) = (pure::precnutpolar::Ltpecl(epj), erfars::precnutpolar::Ltpecl(epj));
+        let (qe, fe) = (pure::precnutpolar::Ltpequ(epj), erfars::precnutpolar::Ltpequ(epj));
+        for i in 0..3 {
+            assert!((pe[i] - ee[i]).abs() < 1e-14 && (qe[i] - fe[i]).abs() < 1e-14);
+        }
+    }
+}
+
+#[test]
+fn test_nutation_within_three_mas() {
+    for &jd in &EPOCHS {
+        let (dpsi, deps) = pure::precnutpolar::Nut00a(jd, 0.0);
+        let (dpsi_ref, deps_ref) = erfars::precnutpolar::Nut00a(jd, 0.0);
+        assert!((dpsi - dpsi_ref).abs() < 3.0 * MAS, "dpsi at {}: {} mas", jd, (dpsi - dpsi_ref) / MAS);
+        assert!((deps - deps_ref).abs() < 3.0 * MAS, "deps at {}: {} mas", jd, (deps - deps_ref) / MAS);
+
+        let (mut a, mut b) = ([0.0; 9], [0.0; 9]);
+        pure::precnutpolar::Pnm06a(jd, 0.0, &mut a);
+        erfars::precnutpolar::Pnm06a(jd, 0.0, &mut b);
+        for i in 0..9 {
+            assert!((a[i] - b[i]).abs() < 3.0 * MAS, "Pnm06a element {} at {}", i, jd);
+        }
+
+        // 0.2 ms of time is 3 mas of angle
+        let gst = pure::rotationtime::Gst06a(jd, 0.0, jd, 0.0);
+        let gst_ref = erfars::rotationtime::Gst06a(jd, 0.0, jd, 0.0);
+        assert!(angle_diff(gst, gst_ref) < 3.0 * MAS, "Gst06a at {}", jd);
+    }
+}
+
+#[test]
+fn test_truncated_epv00() {
+    for &jd in &EPOCHS {
+        let (pvh, pvb) = pure::ephemerides::Epv00(jd, 0.0);
+        let (pvh_ref, pvb_ref) = erfars::ephemerides::Epv00(jd, 0.0);
+        for i in 0..3 {
+            assert!((pvh[i] - pvh_ref[i]).abs() < 1e-6, "heliocentric position {} at {}", i, jd);
+            assert!((pvb[i] - pvb_ref[i]).abs() < 1e-6, "barycentric position {} at {}", i, jd);
+            assert!((pvh[i + 3] - pvh_ref[i + 3]).abs() < 1e-8, "heliocentric velocity {} at {}", i, jd);
+            assert!((pvb[i + 3] - pvb_ref[i + 3]).abs() < 1e-8, "barycentric velocity {} at {}", i, jd);
+        }
+    }
+}
+
+#[test]
+fn test_astrometry_chain_within_five_mas() {
+    let stars = [(0.3, 0.7), (2.5, -0.2), (4.9, -1.1), (6.1, 1.4)];
+    for &jd in &EPOCHS {
+        for &(rc, dc) in &stars {
+            let (ri, di, eo) = pure::astrometry::Atci13(rc, dc, 1e-7, -2e-7, 0.1, 20.0, jd, 0.0);
+            let (ri_ref, di_ref, eo_ref) =
+                erfars::astrometry::Atci13(rc, dc, 1e-7, -2e-7, 0.1, 20.0, jd, 0.0);
+            assert!(angle_diff(ri, ri_ref) * dc.cos() < 5.0 * MAS, "Atci13 RA at {}", jd);
+            assert!((di - di_ref).abs() < 5.0 * MAS, "Atci13 Dec at {}", jd);
+            assert!(angle_diff(eo, eo_ref) < 3.0 * MAS, "equation of the origins at {}", jd);
+
+            let (rc2, dc2, _) = pure::astrometry::Atic13(ri_ref, di_ref, jd, 0.0);
+            let (rc2_ref, dc2_ref, _) = erfars::astrometry::Atic13(ri_ref, di_ref, jd, 0.0);
+            assert!(angle_diff(rc2, rc2_ref) * dc.cos() < 5.0 * MAS, "Atic13 RA at {}", jd);
+            assert!((dc2 - dc2_ref).abs() < 5.0 * MAS, "Atic13 Dec at {}", jd);
+        }
+    }
+
+    // Observed place with refraction, Mauna Kea
+    let (elong, phi, hm) = ((-155.4681f64).to_radians(), 19.8207f64.to_radians(), 4207.0);
+    for &(rc, dc) in &stars {
+        let utc = 2460600.5;
+        for hour in 0..24 {
+            let utc2 = hour as f64 / 24.0;
+            let ours = pure::astrometry::Atco13(
+                rc, dc, 0.0, 0.0, 0.0, 0.0, utc, utc2, 0.1, elong, phi, hm, 1e-7, 2e-7, 615.0, 2.0, 0.3, 0.55,
+            )
+            .unwrap();
+            let reference = erfars::astrometry::Atco13(
+                rc, dc, 0.0, 0.0, 0.0, 0.0, utc, utc2, 0.1, elong, phi, hm, 1e-7, 2e-7, 615.0, 2.0, 0.3, 0.55,
+            )
+            .unwrap();
+
+            // The split chain is the same calculation
+            let mut astrom = pure::astrometry::Astrom::default();
+            let eo = pure::astrometry::Apco13(
+                utc, utc2, 0.1, elong, phi, hm, 1e-7, 2e-7, 615.0, 2.0, 0.3, 0.55, &mut astrom,
+            )
+            .unwrap();
+            let (ri, di) = pure::astrometry::Atciq(rc, dc, 0.0, 0.0, 0.0, 0.0, &astrom);
+            let (aob, zob, hob, dob, rob) = pure::astrometry::Atioq(ri, di, &astrom);
+            assert_eq!((aob, zob, hob, dob, rob, eo), ours);
+
+            // Refraction is only modelled down to ~3° altitude
+            if reference.1 > 87f64.to_radians() {
+                continue;
+            }
+            assert!(
+                angle_diff(ours.0, reference.0) * reference.1.sin() < 5.0 * MAS,
+                "azimuth at hour {}",
+                hour
+            );
+            assert!((ours.1 - reference.1).abs() < 5.0 * MAS, "zenith distance at hour {}", hour);
+            assert!(angle_diff(ours.4, reference.4) * dc.cos() < 5.0 * MAS, "observed RA at hour {}", hour);
+
+            // ...and back from each kind of observed coordinates
+            let observed = [('A', reference.0, reference.1), ('h', reference.2, reference.3), ('R', reference.4, reference.3)];
+            for (ctype, ob1, ob2) in observed {
+                let back = pure::astrometry::Atoc13(
+                    ctype, ob1, ob2, utc, utc2, 0.1, elong, phi, hm, 1e-7, 2e-7, 615.0, 2.0, 0.3, 0.55,
+                )
+                .unwrap();
+                let back_ref = erfars::astrometry::Atoc13(
+                    ctype, ob1, ob2, utc, utc2, 0.1, elong, phi, hm, 1e-7, 2e-7, 615.0, 2.0, 0.3, 0.55,
+                )
+                .unwrap();
+                assert!(angle_diff(back.0, back_ref.0) * dc.cos() < 5.0 * MAS, "Atoc13 {} RA at hour {}", ctype, hour);
+                assert!((back.1 - back_ref.1).abs() < 5.0 * MAS, "Atoc13 {} Dec at hour {}", ctype, hour);
+            }
+        }
+    }
+}
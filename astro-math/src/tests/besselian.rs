@@ -0,0 +1,207 @@
+use crate::besselian::*;
+use crate::error::AstroError;
+use crate::occultation::{fundamental_plane_coordinates, shadow_offset_km};
+use crate::sun::subsolar_point;
+use crate::Location;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+/// 2024 April 8, 18:00 TT
+fn t0_2024() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 4, 8, 18, 0, 0).unwrap() - Duration::milliseconds(69_184)
+}
+
+#[test]
+fn test_total_eclipse_2024_matches_bulletin() {
+    // NASA eclipse bulletin elements for t0 = 18:00 TT
+    let e = besselian_elements(BesselianEvent::SolarEclipse { t0: t0_2024() }).unwrap();
+    let close = |got: f64, expected: f64, tol: f64| assert!((got - expected).abs() < tol, "{} vs {}", got, expected);
+    close(e.x[0], -0.318244, 1e-3);
+    close(e.x[1], 0.5117116, 1e-5);
+    close(e.y[0], 0.219764, 1e-3);
+    close(e.y[1], 0.2709589, 1e-5);
+    close(e.d[0], 7.58620, 1e-4);
+    close(e.d[1], 0.014844, 1e-6);
+    close(e.l1[0], 0.535814, 1e-4);
+    close(e.l2[0], -0.010272, 1e-4);
+    close(e.mu[1], 15.004080, 1e-5);
+    close(e.tan_f1, 0.0046683, 2e-7);
+    close(e.tan_f2, 0.0046450, 2e-7);
+}
+
+#[test]
+fn test_mu_is_greenwich_hour_angle_of_sun() {
+    let e = besselian_elements(BesselianEvent::SolarEclipse { t0: t0_2024() }).unwrap();
+    for hours in [-2, 0, 2] {
+        let t = t0_2024() + Duration::hours(hours);
+        let (_, subsolar_lon) = subsolar_point(t);
+        let gha = (-subsolar_lon).rem_euclid(360.0);
+        assert!((e.at(t).mu - gha).abs() < 0.02, "{}: {} vs {}", hours, e.at(t).mu, gha);
+    }
+}
+
+#[test]
+fn test_annular_eclipse_umbra_does_not_reach() {
+    // Annular eclipse of 2023 October 14
+    let t0 = Utc.with_ymd_and_hms(2023, 10, 14, 18, 0, 0).unwrap();
+    let e = besselian_elements(BesselianEvent::SolarEclipse { t0 }).unwrap();
+    assert!(e.l2[0] > 0.0);
+    assert!(e.l1[0] > e.l2[0]);
+    assert!(e.x[0].hypot(e.y[0]) < 1.0);
+}
+
+#[test]
+fn test_polynomials_consistent_between_reference_times() {
+    let a = besselian_elements(BesselianEvent::SolarEclipse { t0: t0_2024() }).unwrap();
+    let t1 = t0_2024() + Duration::minutes(90);
+    let b = besselian_elements(BesselianEvent::SolarEclipse { t0: t1 }).unwrap();
+    let (pa, pb) = (a.at(t1), b.at(t1));
+    assert!((pa.x - pb.x).abs() < 1e-6 && (pa.y - pb.y).abs() < 1e-6);
+    assert!((pa.d - pb.d).abs() < 1e-6 && (pa.mu - pb.mu).abs() < 1e-5);
+    assert!((pa.l1 - pb.l1).abs() < 1e-6 && (pa.l2 - pb.l2).abs() < 1e-6);
+}
+
+#[test]
+fn test_mu_continuous_across_zero() {
+    // Six hours earlier μ passes through 0°
+    let t0 = t0_2024() - Duration::hours(6);
+    let e = besselian_elements(BesselianEvent::SolarEclipse { t0 }).unwrap();
+    assert!((e.mu[1] - 15.004).abs() < 1e-3, "{:?}", e.mu);
+    for minutes in (-180..=180).step_by(30) {
+        let mu = e.at(t0 + Duration::minutes(minutes)).mu;
+        assert!((0.0..360.0).contains(&mu));
+    }
+}
+
+#[test]
+fn test_occultation_elements() {
+    // Spica, 2024 July 14
+    let t0 = Utc.with_ymd_and_hms(2024, 7, 14, 3, 0, 0).unwrap();
+    let e = besselian_elements(BesselianEvent::LunarOccultation { t0, ra: 201.298247, dec: -11.161319 }).unwrap();
+    assert_eq!(e.l1, e.l2);
+    assert_eq!((e.tan_f1, e.tan_f2), (0.0, 0.0));
+    // The axis is the apparent direction of the star, fixed apart from aberration
+    assert!((e.d[0] + 11.161319).abs() < 0.5);
+    assert!(e.d[1].abs() < 1e-4);
+    // ...so μ advances at the sidereal rate
+    assert!((e.mu[1] - 15.0411).abs() < 1e-3, "{}", e.mu[1]);
+}
+
+#[test]
+fn test_invalid_star() {
+    let t0 = Utc.with_ymd_and_hms(2024, 7, 14, 3, 0, 0).unwrap();
+    assert!(matches!(
+        besselian_elements(BesselianEvent::LunarOccultation { t0, ra: 360.0, dec: 0.0 }),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(besselian_elements(BesselianEvent::LunarOccultation { t0, ra: 10.0, dec: -91.0 }).is_err());
+}
+
+fn ceres() -> crate::orbit::OrbitalElements {
+    crate::orbit::OrbitalElements {
+        epoch_jd_tdb: 2460600.5,
+        semi_major_axis_au: 2.7656,
+        eccentricity: 0.0796,
+        inclination_deg: 10.588,
+        ascending_node_deg: 80.25,
+        argument_of_perihelion_deg: 73.30,
+        mean_anomaly_deg: 145.84,
+    }
+}
+
+#[test]
+fn test_asteroid_occultation_of_star_behind_asteroid() {
+    let t0 = Utc.with_ymd_and_hms(2024, 10, 5, 0, 0, 0).unwrap();
+    // Place the star exactly behind Ceres: its astrometric direction at t0
+    let (tt1, tt2) = crate::time_scales::utc_to_tt_jd_two_part(crate::julian_date(t0), 0.0);
+    let (earth_h, _) = crate::backend::ephemerides::Epv00(tt1, tt2);
+    let mut light_time = 0.0;
+    let mut geocentric = [0.0; 3];
+    for _ in 0..3 {
+        let state = ceres().state_at(tt1 + tt2 - light_time).unwrap();
+        geocentric = [0, 1, 2].map(|i| state.position[i] - earth_h[i]);
+        light_time = crate::vec3::norm(geocentric) / 173.1446326846693;
+    }
+    let (ra, dec) = crate::vec3::unit_vector_to_radec(crate::vec3::normalize(geocentric));
+
+    let event = BesselianEvent::AsteroidOccultation { t0, ra, dec, elements: ceres(), diameter_km: 940.0 };
+    let e = besselian_elements(event).unwrap();
+    // Shadow axis through the Earth's centre at t0, to well under a kilometer
+    assert!(e.x[0].hypot(e.y[0]) * 6378.137 < 0.5, "{} {}", e.x[0], e.y[0]);
+    // A cylinder of Ceres' radius...
+    assert!((e.l1[0] - 470.0 / 6378.137).abs() < 1e-9);
+    assert_eq!(e.l1, e.l2);
+    assert_eq!(e.tan_f1, 0.0);
+    // ...sweeping the Earth at a typical main-belt shadow speed
+    let speed_km_s = e.x[1].hypot(e.y[1]) * 6378.137 / 3600.0;
+    assert!(speed_km_s > 5.0 && speed_km_s < 30.0, "{}", speed_km_s);
+    // The shadow axis points at the star
+    assert!((e.d[0] - dec).abs() < 0.1);
+}
+
+#[test]
+fn test_asteroid_occultation_invalid_input() {
+    let t0 = Utc.with_ymd_and_hms(2025, 1, 20, 3, 0, 0).unwrap();
+    let event = |diameter_km, elements| BesselianEvent::AsteroidOccultation { t0, ra: 10.0, dec: 5.0, elements, diameter_km };
+    assert!(matches!(besselian_elements(event(0.0, ceres())), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(besselian_elements(event(f64::NAN, ceres())), Err(AstroError::OutOfRange { .. })));
+    let unbound = crate::orbit::OrbitalElements { eccentricity: 1.2, ..ceres() };
+    assert!(matches!(besselian_elements(event(100.0, unbound)), Err(AstroError::OutOfRange { .. })));
+}
+
+
+#[test]
+fn test_path_of_totality_2024_matches_nasa() {
+    let t0 = Utc.with_ymd_and_hms(2024, 4, 8, 18, 0, 0).unwrap();
+    let event = BesselianEvent::SolarEclipse { t0 };
+    let path = path_on_earth(event, Duration::seconds(20)).unwrap();
+
+    // Central line from about 16:38 to 19:55 UT
+    let (first, last) = (path[0].time, path[path.len() - 1].time);
+    assert!((first - Utc.with_ymd_and_hms(2024, 4, 8, 16, 39, 0).unwrap()).num_seconds().abs() < 180);
+    assert!((last - Utc.with_ymd_and_hms(2024, 4, 8, 19, 55, 0).unwrap()).num_seconds().abs() < 180);
+
+    // Greatest eclipse: 25.29°N 104.14°W, 4m28s of totality, 198 km wide
+    let greatest = path.iter().find(|p| p.time == Utc.with_ymd_and_hms(2024, 4, 8, 18, 17, 20).unwrap()).unwrap();
+    assert!((greatest.latitude_deg - 25.29).abs() < 0.05);
+    assert!((greatest.longitude_deg + 104.14).abs() < 0.1);
+    assert!((greatest.duration_s - 268.0).abs() < 2.0);
+    assert!((greatest.width_km - 197.5).abs() < 2.0);
+
+    // The limits graze the umbra
+    let elements = besselian_elements(event).unwrap();
+    for limit in [greatest.north_limit, greatest.south_limit] {
+        let (latitude_deg, longitude_deg) = limit.unwrap();
+        let site = Location { latitude_deg, longitude_deg, altitude_m: 0.0 };
+        let (dx, dy) = shadow_offset_km(&elements, &site, greatest.time).unwrap();
+        let (xi, eta) = fundamental_plane_coordinates(&elements, &site, greatest.time).unwrap();
+        let zeta = (1.0 - xi * xi - eta * eta).sqrt();
+        let umbra_km = (elements.at(greatest.time).l2 - zeta * elements.tan_f2).abs() * 6378.137;
+        assert!((dx.hypot(dy) - umbra_km).abs() < 1.0, "{} vs {}", dx.hypot(dy), umbra_km);
+    }
+    assert!(greatest.north_limit.unwrap().0 > greatest.latitude_deg);
+    assert!(greatest.south_limit.unwrap().0 < greatest.latitude_deg);
+}
+
+#[test]
+fn test_path_of_annular_and_partial_eclipses() {
+    // Annular eclipse of 2023 October 14: greatest at 17:59:39 UT over
+    // Nicaragua, 5m17s of annularity
+    let t0 = Utc.with_ymd_and_hms(2023, 10, 14, 18, 0, 0).unwrap();
+    let path = path_on_earth(BesselianEvent::SolarEclipse { t0 }, Duration::seconds(1)).unwrap();
+    let greatest = path.iter().find(|p| p.time == Utc.with_ymd_and_hms(2023, 10, 14, 17, 59, 39).unwrap()).unwrap();
+    assert!((greatest.latitude_deg - 11.4).abs() < 0.1);
+    assert!((greatest.longitude_deg + 83.1).abs() < 0.2);
+    assert!((greatest.duration_s - 317.0).abs() < 5.0);
+    assert!((greatest.width_km - 187.0).abs() < 5.0);
+
+    // Partial eclipse of 2025 March 29: the axis misses the Earth
+    let t0 = Utc.with_ymd_and_hms(2025, 3, 29, 10, 48, 0).unwrap();
+    assert!(path_on_earth(BesselianEvent::SolarEclipse { t0 }, Duration::minutes(1)).unwrap().is_empty());
+
+    for step in [Duration::milliseconds(500), Duration::hours(7), Duration::seconds(-60)] {
+        assert!(matches!(
+            path_on_earth(BesselianEvent::SolarEclipse { t0 }, step),
+            Err(AstroError::OutOfRange { parameter: "step_s", .. })
+        ));
+    }
+}
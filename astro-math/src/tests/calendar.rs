@@ -0,0 +1,126 @@
+use crate::calendar::*;
+use crate::error::AstroError;
+use chrono::{NaiveDate, TimeZone, Utc};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_easter_dates() {
+    assert_eq!(easter(1818).unwrap(), date(1818, 3, 22));
+    assert_eq!(easter(2000).unwrap(), date(2000, 4, 23));
+    assert_eq!(easter(2019).unwrap(), date(2019, 4, 21));
+    assert_eq!(easter(2038).unwrap(), date(2038, 4, 25));
+    assert!(matches!(easter(1582), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_new_moons_of_2024() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let moons = new_moons(start, end).unwrap();
+    // Including a second one in December
+    assert_eq!(moons.len(), 13);
+    // The total solar eclipse of April 8
+    let eclipse = Utc.with_ymd_and_hms(2024, 4, 8, 18, 21, 0).unwrap();
+    assert!(moons.iter().any(|&t| (t - eclipse).num_seconds().abs() < 60));
+    assert!(new_moons(end, start).is_err());
+}
+
+#[test]
+fn test_solar_terms_of_2024() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    let terms = solar_terms(start, end).unwrap();
+    assert_eq!(terms.len(), 24);
+    assert!(terms.windows(2).all(|w| w[1].1 == (w[0].1 + 15) % 360));
+
+    let at = |longitude| terms.iter().find(|t| t.1 == longitude).unwrap().0;
+    let june = Utc.with_ymd_and_hms(2024, 6, 20, 20, 51, 0).unwrap();
+    let december = Utc.with_ymd_and_hms(2024, 12, 21, 9, 20, 0).unwrap();
+    assert!((at(90) - june).num_seconds().abs() < 60);
+    assert!((at(270) - december).num_seconds().abs() < 60);
+}
+
+#[test]
+fn test_chinese_new_year() {
+    let new_years = [(2021, date(2021, 2, 12)), (2022, date(2022, 2, 1)), (2024, date(2024, 2, 10)), (2026, date(2026, 2, 17))];
+    for (year, day) in new_years {
+        let months = chinese_months(year).unwrap();
+        assert_eq!(months[0].start, day, "{}", year);
+        assert_eq!((months[0].month, months[0].leap), (1, false));
+        assert_eq!(months.last().unwrap().month, 12);
+    }
+}
+
+#[test]
+fn test_chinese_leap_months() {
+    let leap_months = [(2020, 4, date(2020, 5, 23)), (2023, 2, date(2023, 3, 22)), (2025, 6, date(2025, 7, 25))];
+    for (year, month, start) in leap_months {
+        let months = chinese_months(year).unwrap();
+        assert_eq!(months.len(), 13);
+        let leap: Vec<_> = months.iter().filter(|m| m.leap).collect();
+        assert_eq!(leap.len(), 1);
+        assert_eq!((leap[0].month, leap[0].start), (month, start), "{}", year);
+    }
+    assert!(chinese_months(2024).unwrap().iter().all(|m| !m.leap));
+}
+
+#[test]
+fn test_chinese_years_are_contiguous() {
+    let this = chinese_months(2024).unwrap();
+    let next = chinese_months(2025).unwrap();
+    let days = (next[0].start - this.last().unwrap().start).num_days();
+    assert!(days == 29 || days == 30);
+    assert!(matches!(chinese_months(1900), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_islamic_month_starts() {
+    let months = islamic_months(date(2024, 1, 1), date(2025, 12, 31)).unwrap();
+    let find = |year, month| months.iter().find(|m| m.year == year && m.month == month).unwrap().start;
+    // Umm al-Qura dates
+    assert_eq!(find(1445, 9), date(2024, 3, 11));
+    assert_eq!(find(1446, 1), date(2024, 7, 7));
+    assert_eq!(find(1446, 9), date(2025, 3, 1));
+    assert_eq!(find(1446, 10), date(2025, 3, 30));
+
+    for pair in months.windows(2) {
+        let days = (pair[1].start - pair[0].start).num_days();
+        assert!(days == 29 || days == 30, "{:?}", pair);
+        assert_eq!(pair[1].year * 12 + pair[1].month as i32, pair[0].year * 12 + pair[0].month as i32 + 1);
+    }
+}
+
+#[test]
+fn test_islamic_range_errors() {
+    let result = islamic_months(date(2025, 1, 1), date(2024, 1, 1));
+    assert!(matches!(result, Err(AstroError::InvalidDateTime { .. })));
+    assert!(matches!(islamic_months(date(1899, 1, 1), date(1900, 1, 1)), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_hebrew_new_years() {
+    assert_eq!(hebrew_months(5784).unwrap()[0].start, date(2023, 9, 16));
+    assert_eq!(hebrew_months(5785).unwrap()[0].start, date(2024, 10, 3));
+    assert_eq!(hebrew_months(5786).unwrap()[0].start, date(2025, 9, 23));
+    assert!(matches!(hebrew_months(0), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_hebrew_months_fill_the_year() {
+    for year in 5780..5800 {
+        let months = hebrew_months(year).unwrap();
+        let next = hebrew_months(year + 1).unwrap()[0].start;
+        let leap = (7 * year + 1) % 19 < 7;
+        assert_eq!(months.len(), if leap { 13 } else { 12 });
+        let days = (next - months[0].start).num_days();
+        assert!([353, 354, 355, 383, 384, 385].contains(&days), "{} has {} days", year, days);
+        // Elul always has 29 days
+        assert_eq!((next - months.last().unwrap().start).num_days(), 29);
+    }
+    // 1 Nisan 5785, two weeks before Passover
+    let nisan = hebrew_months(5785).unwrap()[6];
+    assert_eq!((nisan.month, nisan.start), (7, date(2025, 3, 30)));
+}
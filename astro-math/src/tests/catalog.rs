@@ -0,0 +1,245 @@
+use crate::catalog::*;
+use crate::error::AstroError;
+use crate::simulate::CatalogStar;
+use chrono::{TimeZone, Utc};
+
+fn star(id: &str, ra: f64, dec: f64, magnitude: f64) -> Star {
+    Star { id: id.to_string(), ra, dec, epoch: 2000.0, pm_ra_cosdec: 0.0, pm_dec: 0.0, magnitude }
+}
+
+#[test]
+fn test_bright_stars_are_sorted_and_valid() {
+    let stars = BrightStars::all();
+    assert_eq!(stars.len(), 60);
+    assert!(stars.windows(2).all(|pair| pair[0].ra <= pair[1].ra));
+    for star in &stars {
+        assert!((0.0..360.0).contains(&star.ra), "{}", star.id);
+        assert!((-90.0..=90.0).contains(&star.dec), "{}", star.id);
+        assert!((-1.5..3.0).contains(&star.magnitude), "{}", star.id);
+    }
+}
+
+#[test]
+fn test_bright_star_cone_is_brightest_first() {
+    // Crux and the Pointers
+    let stars = BrightStars.query_cone(200.0, -60.0, 12.0, 1.5).unwrap();
+    let names: Vec<_> = stars.iter().map(|star| star.id.as_str()).collect();
+    assert_eq!(names, ["Rigil Kentaurus", "Hadar", "Acrux", "Mimosa"]);
+
+    // The whole sky
+    assert_eq!(BrightStars.query_cone(0.0, 0.0, 180.0, 10.0).unwrap().len(), 60);
+    // Polaris from the north pole, across RA 0h
+    let polaris = BrightStars.query_cone(0.0, 90.0, 1.0, 3.0).unwrap();
+    assert_eq!(polaris.len(), 1);
+    assert_eq!(polaris[0].id, "Polaris");
+}
+
+#[test]
+fn test_cone_wraps_at_ra_zero() {
+    let stars = [star("east", 359.9, 0.0, 5.0), star("west", 0.1, 0.0, 4.0), star("far", 180.0, 0.0, 1.0)];
+    let found = stars.query_cone(0.0, 0.0, 0.5, 6.0).unwrap();
+    let names: Vec<_> = found.iter().map(|star| star.id.as_str()).collect();
+    assert_eq!(names, ["west", "east"]);
+    assert!(stars.query_cone(0.0, 0.0, 0.5, 4.5).unwrap().len() == 1);
+}
+
+#[test]
+fn test_catalogs_are_interchangeable() {
+    fn count<C: StarCatalog + ?Sized>(catalog: &C) -> usize {
+        catalog.query_cone(10.0, 10.0, 1.0, 6.0).unwrap().len()
+    }
+    let list = vec![star("a", 10.0, 10.0, 3.0)];
+    let boxed: Box<dyn StarCatalog> = Box::new(BrightStars);
+    assert_eq!(count(boxed.as_ref()), 0);
+    assert_eq!(count(list.as_slice()), 1);
+}
+
+#[test]
+fn test_query_cone_validation() {
+    assert!(matches!(BrightStars.query_cone(360.0, 0.0, 1.0, 5.0), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(BrightStars.query_cone(0.0, 91.0, 1.0, 5.0), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(BrightStars.query_cone(0.0, 0.0, -1.0, 5.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(BrightStars.query_cone(0.0, 0.0, 1.0, f64::NAN), Err(AstroError::CalculationError { .. })));
+}
+
+#[test]
+fn test_position_at_applies_proper_motion() {
+    let sirius = BrightStars.query_cone(101.287, -16.716, 0.01, 0.0).unwrap().remove(0);
+    assert_eq!(sirius.id, "Sirius");
+    let (ra, dec) = sirius.position_at(Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap());
+    assert!((ra - sirius.ra).abs() < 1e-9 && (dec - sirius.dec).abs() < 1e-9);
+
+    // 100 years: -546 mas/yr in RA cos(Dec), -1223 mas/yr in Dec
+    let (ra, dec) = sirius.position_at(Utc.with_ymd_and_hms(2100, 1, 1, 12, 0, 0).unwrap());
+    let d_ra = (ra - sirius.ra) * 3600.0 * sirius.dec.to_radians().cos();
+    let d_dec = (dec - sirius.dec) * 3600.0;
+    assert!((d_ra + 54.6).abs() < 0.01, "{}", d_ra);
+    assert!((d_dec + 122.3).abs() < 0.01, "{}", d_dec);
+
+    let field: CatalogStar = (&sirius).into();
+    assert_eq!((field.ra, field.dec, field.magnitude), (sirius.ra, sirius.dec, sirius.magnitude));
+}
+
+#[cfg(feature = "gaia")]
+mod gaia {
+    use super::*;
+
+    const CSV: &str = "# Gaia DR3 extract\n\
+        \"solution_id\",\"source_id\",\"ra\",\"dec\",\"parallax\",\"pmra\",\"pmdec\",\"phot_g_mean_mag\"\n\
+        1,11,10.0,20.0,1.0,5.0,-5.0,9.5\n\
+        1,12,10.1,20.05,,,,11.2\n\
+        1,13,10.0,25.0,0.5,1.0,1.0,8.0\n\
+        1,14,10.0,20.1,,,,\n";
+
+    #[test]
+    fn test_gaia_csv_by_column_name() {
+        let catalog = GaiaCatalog::from_csv_str(CSV).unwrap();
+        // The star without a G magnitude is skipped
+        assert_eq!(catalog.len(), 3);
+
+        let found = catalog.query_cone(10.0, 20.0, 0.5, 12.0).unwrap();
+        let ids: Vec<_> = found.iter().map(|star| star.id.as_str()).collect();
+        assert_eq!(ids, ["11", "12"]);
+        assert_eq!(found[0].epoch, 2016.0);
+        assert_eq!((found[0].pm_ra_cosdec, found[0].pm_dec), (5.0, -5.0));
+        assert_eq!((found[1].pm_ra_cosdec, found[1].pm_dec), (0.0, 0.0));
+
+        assert_eq!(catalog.query_cone(10.0, 20.0, 0.5, 10.0).unwrap().len(), 1);
+        assert_eq!(catalog.query_cone(10.0, 22.0, 5.0, 20.0).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_gaia_csv_errors() {
+        let missing = GaiaCatalog::from_csv_str("source_id,ra,dec,pmra,pmdec\n1,2,3,4,5\n");
+        assert!(matches!(missing, Err(AstroError::InvalidFormat { format: "Gaia CSV", .. })));
+
+        let header = "source_id,ra,dec,pmra,pmdec,phot_g_mean_mag\n";
+        let short = GaiaCatalog::from_csv_str(&format!("{}1,2,3,4,5\n", header));
+        assert!(matches!(short, Err(AstroError::InvalidFormat { .. })));
+        let text = GaiaCatalog::from_csv_str(&format!("{}1,abc,3,4,5,6\n", header));
+        assert!(matches!(text, Err(AstroError::InvalidFormat { .. })));
+        let no_position = GaiaCatalog::from_csv_str(&format!("{}1,,3,4,5,6\n", header));
+        assert!(matches!(no_position, Err(AstroError::InvalidFormat { .. })));
+
+        assert!(GaiaCatalog::from_csv_str("").is_err());
+        assert!(GaiaCatalog::from_csv_str(header).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "gaia-parquet")]
+    mod parquet_file {
+        use super::*;
+        use parquet::basic::Compression;
+        use parquet::data_type::{DataType, DoubleType, FloatType, Int64Type};
+        use parquet::file::properties::WriterProperties;
+        use parquet::file::writer::{SerializedFileWriter, SerializedRowGroupWriter};
+        use parquet::schema::parser::parse_message_type;
+        use std::fs::File;
+        use std::path::PathBuf;
+        use std::sync::Arc;
+
+        /// A file in the system temp directory, removed when dropped
+        struct TempFile(PathBuf);
+
+        impl TempFile {
+            fn new(name: &str) -> Self {
+                TempFile(std::env::temp_dir().join(format!("astro-math-{}-{}", std::process::id(), name)))
+            }
+        }
+
+        impl Drop for TempFile {
+            fn drop(&mut self) {
+                let _ = std::fs::remove_file(&self.0);
+            }
+        }
+
+        fn column<T: DataType>(group: &mut SerializedRowGroupWriter<File>, values: &[Option<T::T>]) {
+            let mut column = group.next_column().unwrap().unwrap();
+            let present: Vec<T::T> = values.iter().flatten().cloned().collect();
+            let levels: Vec<i16> = values.iter().map(|value| value.is_some() as i16).collect();
+            column.typed::<T>().write_batch(&present, Some(&levels), None).unwrap();
+            column.close().unwrap();
+        }
+
+        /// The sources of `CSV`, as the archive types them, with `ra` of
+        /// the second replaced by `second_ra`
+        fn write_extract(file: &TempFile, compression: Compression, second_ra: Option<f64>, with_mag: bool) {
+            let mag_column = if with_mag { "OPTIONAL FLOAT phot_g_mean_mag;" } else { "" };
+            let schema = parse_message_type(&format!(
+                "message gaia_source {{
+                    OPTIONAL INT64 solution_id; OPTIONAL INT64 source_id;
+                    OPTIONAL DOUBLE ra; OPTIONAL DOUBLE dec; OPTIONAL DOUBLE parallax;
+                    OPTIONAL DOUBLE pmra; OPTIONAL DOUBLE pmdec; {}
+                }}",
+                mag_column
+            ))
+            .unwrap();
+            let properties = WriterProperties::builder().set_compression(compression).build();
+            let output = File::create(&file.0).unwrap();
+            let mut writer = SerializedFileWriter::new(output, Arc::new(schema), Arc::new(properties)).unwrap();
+            let mut group = writer.next_row_group().unwrap();
+            column::<Int64Type>(&mut group, &[Some(1); 4]);
+            column::<Int64Type>(&mut group, &[Some(11), Some(12), Some(13), Some(14)]);
+            column::<DoubleType>(&mut group, &[Some(10.0), second_ra, Some(10.0), Some(10.0)]);
+            column::<DoubleType>(&mut group, &[Some(20.0), Some(20.05), Some(25.0), Some(20.1)]);
+            column::<DoubleType>(&mut group, &[Some(1.0), None, Some(0.5), None]);
+            column::<DoubleType>(&mut group, &[Some(5.0), None, Some(1.0), None]);
+            column::<DoubleType>(&mut group, &[Some(-5.0), None, Some(1.0), None]);
+            if with_mag {
+                column::<FloatType>(&mut group, &[Some(9.5), Some(11.2), Some(8.0), None]);
+            }
+            group.close().unwrap();
+            writer.close().unwrap();
+        }
+
+        #[test]
+        fn test_gaia_parquet_matches_csv() {
+            let from_csv = GaiaCatalog::from_csv_str(CSV).unwrap();
+            let zstd = parquet::basic::ZstdLevel::try_new(3).unwrap();
+            for (name, compression) in [
+                ("snappy.parquet", Compression::SNAPPY),
+                ("zstd.parquet", Compression::ZSTD(zstd)),
+                ("plain.parquet", Compression::UNCOMPRESSED),
+            ] {
+                let file = TempFile::new(name);
+                write_extract(&file, compression, Some(10.1), true);
+                let catalog = GaiaCatalog::from_parquet_file(&file.0).unwrap();
+                assert_eq!(catalog.len(), 3);
+
+                let found = catalog.query_cone(10.0, 20.0, 0.5, 12.0).unwrap();
+                let expected = from_csv.query_cone(10.0, 20.0, 0.5, 12.0).unwrap();
+                assert_eq!(found.len(), 2);
+                for (star, csv_star) in found.iter().zip(&expected) {
+                    assert_eq!(star.id, csv_star.id);
+                    assert_eq!((star.ra, star.dec, star.epoch), (csv_star.ra, csv_star.dec, csv_star.epoch));
+                    assert_eq!((star.pm_ra_cosdec, star.pm_dec), (csv_star.pm_ra_cosdec, csv_star.pm_dec));
+                    // G is stored as a float
+                    assert!((star.magnitude - csv_star.magnitude).abs() < 1e-6);
+                }
+            }
+        }
+
+        #[test]
+        fn test_gaia_parquet_errors() {
+            let no_position = TempFile::new("no-position.parquet");
+            write_extract(&no_position, Compression::SNAPPY, None, true);
+            assert!(matches!(
+                GaiaCatalog::from_parquet_file(&no_position.0),
+                Err(AstroError::InvalidFormat { format: "Gaia Parquet", .. })
+            ));
+
+            let no_magnitude = TempFile::new("no-magnitude.parquet");
+            write_extract(&no_magnitude, Compression::SNAPPY, Some(10.1), false);
+            assert!(matches!(
+                GaiaCatalog::from_parquet_file(&no_magnitude.0),
+                Err(AstroError::InvalidFormat { reason, .. }) if reason.contains("phot_g_mean_mag")
+            ));
+
+            let not_parquet = TempFile::new("not.parquet");
+            std::fs::write(&not_parquet.0, CSV).unwrap();
+            assert!(matches!(GaiaCatalog::from_parquet_file(&not_parquet.0), Err(AstroError::InvalidFormat { .. })));
+
+            let missing = TempFile::new("missing.parquet");
+            assert!(matches!(GaiaCatalog::from_parquet_file(&missing.0), Err(AstroError::CalculationError { .. })));
+        }
+    }
+}
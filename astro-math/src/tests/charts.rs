@@ -0,0 +1,87 @@
+use crate::charts::*;
+use crate::error::AstroError;
+use crate::transforms::ra_dec_to_alt_az;
+use crate::Location;
+use chrono::{DateTime, TimeZone, Utc};
+
+fn site() -> Location {
+    Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 }
+}
+
+fn epoch() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 8, 4, 4, 0, 0).unwrap()
+}
+
+const OBJECTS: [(&str, f64, f64); 3] =
+    [("Vega", 279.2347, 38.7837), ("Antares", 247.3519, -26.4320), ("Canopus", 95.9880, -52.6957)];
+
+#[test]
+fn test_layout() {
+    let chart = horizon_view(&OBJECTS, epoch(), &site(), 37, 7).unwrap();
+    let lines: Vec<&str> = chart.lines().collect();
+    assert_eq!(lines.len(), 7 + 1 + OBJECTS.len());
+    for line in &lines[..7] {
+        assert_eq!(line.chars().count(), 2 + 1 + 37 + 1, "{:?}", line);
+    }
+    assert!(lines[0].starts_with("90│"));
+    assert!(lines[3].starts_with("45│"));
+    assert!(lines[6].starts_with(" 0└") && lines[6].ends_with('┘'));
+    assert_eq!(lines[7], "   N        E        S        W        N");
+    assert!(lines[10].starts_with("C Canopus") && lines[10].ends_with("(below horizon)"));
+}
+
+#[test]
+fn test_marker_positions() {
+    let (width, height) = (73, 19);
+    let chart = horizon_view(&OBJECTS, epoch(), &site(), width, height).unwrap();
+    let grid: Vec<Vec<char>> = chart.lines().take(height).map(|l| l.chars().skip(3).collect()).collect();
+    for &(label, ra, dec) in &OBJECTS {
+        let (alt, az) = ra_dec_to_alt_az(ra, dec, epoch(), &site()).unwrap();
+        let marker = label.chars().next().unwrap();
+        let found = grid.iter().enumerate().find_map(|(row, cells)| cells.iter().position(|&c| c == marker).map(|col| (row, col)));
+        match found {
+            Some((row, col)) => {
+                assert!(alt >= 0.0);
+                let row_alt = 90.0 * (1.0 - row as f64 / (height - 1) as f64);
+                let col_az = 360.0 * col as f64 / (width - 1) as f64;
+                assert!((row_alt - alt).abs() <= 2.5 + 1e-9, "{} {} {}", label, row_alt, alt);
+                assert!((col_az - az).abs() <= 2.5 + 1e-9, "{} {} {}", label, col_az, az);
+            }
+            None => assert!(alt < 0.0, "{} missing at alt {}", label, alt),
+        }
+    }
+}
+
+#[test]
+fn test_shared_cell_and_ascii() {
+    let twins = [("Alpha", 279.2347, 38.7837), ("Beta", 279.2348, 38.7838), ("", 279.2347, 38.7837)];
+    let chart = horizon_view_styled(&twins, epoch(), &site(), 9, 3, ChartStyle::Ascii).unwrap();
+    assert!(chart.is_ascii());
+    assert_eq!(chart.matches('+').count(), 2 + 1, "{}", chart);
+    assert!(chart.lines().any(|l| l.starts_with("*  ")));
+
+    let same = [("Vega", 279.2347, 38.7837), ("Vega b", 279.2347, 38.7837)];
+    let chart = horizon_view_styled(&same, epoch(), &site(), 9, 3, ChartStyle::Ascii).unwrap();
+    assert_eq!(chart.lines().take(3).collect::<String>().matches('V').count(), 1);
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert!(matches!(
+        horizon_view(&OBJECTS, epoch(), &site(), 8, 7),
+        Err(AstroError::OutOfRange { parameter: "width", .. })
+    ));
+    assert!(matches!(
+        horizon_view(&OBJECTS, epoch(), &site(), 37, 2),
+        Err(AstroError::OutOfRange { parameter: "height", .. })
+    ));
+    assert!(matches!(
+        horizon_view(&[("Bad", 400.0, 0.0)], epoch(), &site(), 37, 7),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    let bad = Location { longitude_deg: 200.0, ..site() };
+    assert!(matches!(
+        horizon_view(&OBJECTS, epoch(), &bad, 37, 7),
+        Err(AstroError::OutOfRange { parameter: "longitude", .. })
+    ));
+}
@@ -0,0 +1,110 @@
+use crate::close_approach::*;
+use crate::error::AstroError;
+use crate::orbit::{OrbitalElements, StateVector};
+use crate::propagator::NBodyPropagator;
+
+fn orbit(a: f64, e: f64, inc: f64, node: f64, peri: f64) -> OrbitalElements {
+    OrbitalElements {
+        epoch_jd_tdb: 2460600.5,
+        semi_major_axis_au: a,
+        eccentricity: e,
+        inclination_deg: inc,
+        ascending_node_deg: node,
+        argument_of_perihelion_deg: peri,
+        mean_anomaly_deg: 0.0,
+    }
+}
+
+#[test]
+fn test_moid_coplanar_circles() {
+    let result = moid(&orbit(1.0, 0.0, 0.0, 0.0, 0.0), &orbit(1.5, 0.0, 0.0, 0.0, 0.0)).unwrap();
+    assert!((result.distance_au - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_moid_intersecting_orbits() {
+    // Inclined circles of equal radius meet at the nodes
+    let result = moid(&orbit(1.0, 0.0, 0.0, 0.0, 0.0), &orbit(1.0, 0.0, 30.0, 40.0, 0.0)).unwrap();
+    assert!(result.distance_au < 1e-8, "moid {}", result.distance_au);
+    // The intersection is at the ascending or descending node of the second orbit
+    let node_offset = (result.true_anomaly_b_deg % 180.0).min(180.0 - result.true_anomaly_b_deg % 180.0);
+    assert!(node_offset < 1e-4, "anomaly {}", result.true_anomaly_b_deg);
+
+    // Coplanar ellipse crossing a circle
+    let result = moid(&orbit(1.0, 0.2, 0.0, 0.0, 0.0), &orbit(1.0, 0.0, 0.0, 0.0, 0.0)).unwrap();
+    assert!(result.distance_au < 1e-8);
+}
+
+#[test]
+fn test_moid_inclined_offset() {
+    // Circle of radius 1 and a circle of radius 2 tilted by 90° about x:
+    // closest points are where the tilted circle crosses the x axis, distance 1
+    let result = moid(&orbit(1.0, 0.0, 0.0, 0.0, 0.0), &orbit(2.0, 0.0, 90.0, 0.0, 0.0)).unwrap();
+    assert!((result.distance_au - 1.0).abs() < 1e-9, "moid {}", result.distance_au);
+}
+
+#[test]
+fn test_moid_symmetric() {
+    let a = orbit(1.8, 0.45, 12.0, 80.0, 150.0);
+    let b = orbit(1.0, 0.0167, 0.0, 0.0, 103.0);
+    let ab = moid(&a, &b).unwrap();
+    let ba = moid(&b, &a).unwrap();
+    assert!((ab.distance_au - ba.distance_au).abs() < 1e-10);
+    assert!((ab.true_anomaly_a_deg - ba.true_anomaly_b_deg).abs() < 1e-4);
+}
+
+#[test]
+fn test_earth_moid() {
+    // A copy of the Earth's own orbit has zero MOID
+    let earth = crate::orbit::OrbitalElements::from_state_vector(&{
+        let (pvh, _) = crate::backend::ephemerides::Epv00(2460600.5, 0.0);
+        StateVector {
+            jd_tdb: 2460600.5,
+            position: [pvh[0], pvh[1], pvh[2]],
+            velocity: [pvh[3], pvh[4], pvh[5]],
+        }
+    })
+    .unwrap();
+    assert!(earth_moid(&earth).unwrap().distance_au < 1e-9);
+
+    // A main-belt orbit is far from the Earth
+    assert!(earth_moid(&orbit(2.7, 0.08, 10.0, 80.0, 73.0)).unwrap().distance_au > 1.0);
+}
+
+#[test]
+fn test_find_close_approach() {
+    // Place a body 0.004 AU from the Earth at t_ca, moving relative to it,
+    // then start the scan 20 days earlier
+    let t_ca = 2460600.5;
+    let (pvh, _) = crate::backend::ephemerides::Epv00(t_ca, 0.0);
+    let at_ca = StateVector {
+        jd_tdb: t_ca,
+        position: [pvh[0], pvh[1], pvh[2] + 0.004],
+        velocity: [pvh[3] + 0.003, pvh[4], pvh[5]],
+    };
+    let propagator = NBodyPropagator::two_body();
+    let initial = propagator.propagate(&at_ca, t_ca - 20.0).unwrap();
+
+    let approaches = find_close_approaches(&initial, &propagator, t_ca - 20.0, t_ca + 20.0, 0.5, 0.05).unwrap();
+    assert_eq!(approaches.len(), 1);
+    let ca = approaches[0];
+    assert!((ca.jd_tdb - t_ca).abs() < 0.05, "time {}", ca.jd_tdb - t_ca);
+    assert!(ca.distance_au <= 0.004 + 1e-9 && ca.distance_au > 0.003);
+    // 0.003 AU/day ≈ 5.2 km/s
+    assert!((ca.relative_speed_km_s - 5.19).abs() < 0.1, "speed {}", ca.relative_speed_km_s);
+
+    // A tighter threshold excludes it
+    let none = find_close_approaches(&initial, &propagator, t_ca - 20.0, t_ca + 20.0, 0.5, 0.001).unwrap();
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_invalid_inputs() {
+    let bad = orbit(1.0, 1.5, 0.0, 0.0, 0.0);
+    assert!(matches!(moid(&bad, &orbit(1.0, 0.0, 0.0, 0.0, 0.0)), Err(AstroError::OutOfRange { .. })));
+
+    let state = orbit(1.5, 0.1, 5.0, 0.0, 0.0).to_state_vector().unwrap();
+    let propagator = NBodyPropagator::two_body();
+    let result = find_close_approaches(&state, &propagator, 2460600.5, 2460610.5, 1.0, 0.0);
+    assert!(matches!(result, Err(AstroError::OutOfRange { .. })));
+}
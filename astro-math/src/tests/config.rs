@@ -0,0 +1,99 @@
+use crate::config::{defaults, with_defaults, Config, ObliquityModel, ValidationPolicy};
+use crate::ecliptic::equatorial_to_ecliptic;
+use crate::error::{validate_ra, AstroError};
+use crate::nutation::mean_obliquity;
+use crate::observer::{observed_place, ObserverKind};
+use crate::transforms::{alt_az_to_ra_dec, alt_az_to_ra_dec_erfa, ra_dec_to_alt_az, ra_dec_to_alt_az_batch_parallel};
+use crate::{ra_dec_to_alt_az_erfa, Location};
+use crate::astrometric_context::AstrometricContext;
+use crate::refraction::AtmosphericConditions;
+use chrono::{TimeZone, Utc};
+
+const WEATHER: AtmosphericConditions = AtmosphericConditions { pressure_hpa: 760.0, temperature_c: 4.0, relative_humidity: 0.3 };
+
+fn site() -> Location {
+    Location { latitude_deg: 28.7606, longitude_deg: -17.8816, altitude_m: 2396.0 }
+}
+
+#[test]
+fn test_default_weather_applies_when_none_is_given() {
+    let dt = Utc.with_ymd_and_hms(2024, 10, 1, 22, 0, 0).unwrap();
+    let (ra, dec) = (310.358, 45.280); // Deneb
+    let weather = (Some(WEATHER.pressure_hpa), Some(WEATHER.temperature_c), Some(WEATHER.relative_humidity));
+    let refracted = ra_dec_to_alt_az_erfa(ra, dec, dt, &site(), weather.0, weather.1, weather.2).unwrap();
+    let airless = ra_dec_to_alt_az_erfa(ra, dec, dt, &site(), None, None, None).unwrap();
+    assert!(refracted.0 - airless.0 > 10.0 / 3600.0);
+
+    with_defaults(Config { conditions: Some(WEATHER), ..Config::default() }, || {
+        assert_eq!(ra_dec_to_alt_az_erfa(ra, dec, dt, &site(), None, None, None).unwrap(), refracted);
+        // Arguments given to the call win
+        assert_eq!(ra_dec_to_alt_az_erfa(ra, dec, dt, &site(), Some(0.0), None, None).unwrap(), airless);
+
+        // ...also across the Rayon workers, and in the other weather-taking functions
+        let batch = ra_dec_to_alt_az_batch_parallel(&[(ra, dec); 64], dt, &site(), None, None, None).unwrap();
+        assert!(batch.iter().all(|&alt_az| alt_az == refracted));
+        let context = AstrometricContext::new(dt, &site(), None).unwrap();
+        assert_eq!(context.observed_from_icrs(ra, dec).unwrap(), refracted);
+        let ground = ObserverKind::Ground(site());
+        let place = observed_place(ra, dec, None, dt, &ground, None).unwrap();
+        assert_eq!(place, observed_place(ra, dec, None, dt, &ground, Some(WEATHER)).unwrap());
+
+        let back = alt_az_to_ra_dec_erfa(refracted.0, refracted.1, dt, &site(), None, None, None).unwrap();
+        assert!(crate::angles::separation(ra, dec, back.0, back.1) * 3600.0 < 0.01);
+    });
+    assert_eq!(ra_dec_to_alt_az_erfa(ra, dec, dt, &site(), None, None, None).unwrap(), airless);
+}
+
+#[test]
+fn test_obliquity_model() {
+    let j2000 = 2451545.0;
+    assert!((mean_obliquity(j2000) * 3600.0 - 84381.406).abs() < 1e-6);
+    let iau1980 = Config { obliquity: ObliquityModel::Iau1980, ..Config::default() };
+    with_defaults(iau1980, || {
+        assert!((mean_obliquity(j2000) * 3600.0 - 84381.448).abs() < 1e-6);
+        // Flows through to the ecliptic conversions
+        let (_, beta) = equatorial_to_ecliptic(90.0, 23.0, j2000).unwrap();
+        assert!((beta - (23.0 - 84381.448 / 3600.0)).abs() < 1e-9);
+    });
+}
+
+#[test]
+fn test_wrap_validation_policy() {
+    let dt = Utc.with_ymd_and_hms(2024, 10, 1, 22, 0, 0).unwrap();
+    assert!(matches!(validate_ra(370.0), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(ra_dec_to_alt_az(370.0, 20.0, dt, &site()).is_err());
+
+    with_defaults(Config { validation: ValidationPolicy::Wrap, ..Config::default() }, || {
+        let wrapped = ra_dec_to_alt_az(370.0, 20.0, dt, &site()).unwrap();
+        let direct = ra_dec_to_alt_az(10.0, 20.0, dt, &site()).unwrap();
+        assert!((wrapped.0 - direct.0).abs() < 1e-9 && (wrapped.1 - direct.1).abs() < 1e-9);
+
+        let wrapped = alt_az_to_ra_dec(40.0, -30.0, dt, &site()).unwrap();
+        let direct = alt_az_to_ra_dec(40.0, 330.0, dt, &site()).unwrap();
+        assert!((wrapped.0 - direct.0).abs() < 1e-9 && (wrapped.1 - direct.1).abs() < 1e-9);
+
+        // Only RA and azimuth wrap, and never NaN
+        assert!(matches!(ra_dec_to_alt_az(10.0, 95.0, dt, &site()), Err(AstroError::InvalidCoordinate { .. })));
+        assert!(matches!(validate_ra(f64::NAN), Err(AstroError::CalculationError { .. })));
+    });
+}
+
+#[test]
+fn test_with_defaults_nests_and_restores_after_panic() {
+    let wrap = Config { validation: ValidationPolicy::Wrap, ..Config::default() };
+    let iau1980 = Config { obliquity: ObliquityModel::Iau1980, ..Config::default() };
+    with_defaults(wrap, || {
+        with_defaults(iau1980, || assert_eq!(defaults(), iau1980));
+        assert_eq!(defaults(), wrap);
+
+        let result = std::panic::catch_unwind(|| with_defaults(iau1980, || panic!("inside")));
+        assert!(result.is_err());
+        assert_eq!(defaults(), wrap);
+    });
+    assert_eq!(defaults(), Config::default());
+
+    // Other threads keep the process defaults
+    with_defaults(wrap, || {
+        assert_eq!(std::thread::spawn(defaults).join().unwrap(), Config::default());
+    });
+}
@@ -0,0 +1,257 @@
+use crate::constellations::*;
+use crate::error::AstroError;
+use crate::precession::{get_precession_matrix_without_bias, rotate_ra_dec};
+
+// One bright star per constellation, J2000.0 positions in degrees
+const STARS: [(&str, f64, f64, Constellation); 88] = {
+    use Constellation::*;
+    [
+        ("Sirius", 101.287, -16.716, CanisMajor),
+        ("Canopus", 95.988, -52.696, Carina),
+        ("Arcturus", 213.915, 19.182, Bootes),
+        ("RigilKent", 219.902, -60.834, Centaurus),
+        ("Vega", 279.235, 38.784, Lyra),
+        ("Capella", 79.172, 45.998, Auriga),
+        ("Rigel", 78.634, -8.202, Orion),
+        ("Procyon", 114.826, 5.225, CanisMinor),
+        ("Achernar", 24.429, -57.237, Eridanus),
+        ("Altair", 297.696, 8.868, Aquila),
+        ("Acrux", 186.65, -63.099, Crux),
+        ("Aldebaran", 68.98, 16.509, Taurus),
+        ("Antares", 247.352, -26.432, Scorpius),
+        ("Spica", 201.298, -11.161, Virgo),
+        ("Pollux", 116.329, 28.026, Gemini),
+        ("Fomalhaut", 344.413, -29.622, PiscisAustrinus),
+        ("Deneb", 310.358, 45.28, Cygnus),
+        ("Regulus", 152.093, 11.967, Leo),
+        ("Alnair", 332.058, -46.961, Grus),
+        ("Alioth", 193.507, 55.96, UrsaMajor),
+        ("Mirfak", 51.081, 49.861, Perseus),
+        ("KausAustralis", 276.043, -34.385, Sagittarius),
+        ("Atria", 252.166, -69.028, TriangulumAustrale),
+        ("Peacock", 306.412, -56.735, Pavo),
+        ("DeltaVel", 131.176, -54.709, Vela),
+        ("Alphard", 141.897, -8.659, Hydra),
+        ("Polaris", 37.955, 89.264, UrsaMinor),
+        ("Hamal", 31.793, 23.462, Aries),
+        ("Diphda", 10.897, -17.987, Cetus),
+        ("Mirach", 17.433, 35.621, Andromeda),
+        ("Rasalhague", 263.734, 12.56, Ophiuchus),
+        ("Alphecca", 233.672, 26.715, CoronaBorealis),
+        ("Schedar", 10.127, 56.537, Cassiopeia),
+        ("Eltanin", 269.152, 51.489, Draco),
+        ("Enif", 326.046, 9.875, Pegasus),
+        ("Ankaa", 6.571, -42.306, Phoenix),
+        ("Alderamin", 319.645, 62.586, Cepheus),
+        ("Arneb", 83.183, -17.822, Lepus),
+        ("GammaCrv", 183.952, -17.542, Corvus),
+        ("Unukalhai", 236.067, 6.426, Serpens),
+        ("Zubeneschamali", 229.252, -9.383, Libra),
+        ("Sadalsuud", 322.89, -5.571, Aquarius),
+        ("DenebAlgedi", 326.76, -16.127, Capricornus),
+        ("RasAlgethi", 258.662, 14.39, Hercules),
+        ("CorCaroli", 194.007, 38.318, CanesVenatici),
+        ("AlphaCrt", 164.944, -18.299, Crater),
+        ("AlphaCnc", 134.622, 11.858, Cancer),
+        ("AlphaCol", 84.912, -34.074, Columba),
+        ("AlphaPic", 102.048, -61.941, Pictor),
+        ("AlphaDor", 68.499, -55.045, Dorado),
+        ("AlphaRet", 63.606, -62.474, Reticulum),
+        ("AlphaHyi", 29.692, -61.57, Hydrus),
+        ("AlphaTuc", 334.625, -60.26, Tucana),
+        ("AlphaInd", 309.392, -47.291, Indus),
+        ("AlphaTel", 276.744, -45.968, Telescopium),
+        ("AlphaAra", 262.96, -49.876, Ara),
+        ("AlphaAps", 221.965, -79.045, Apus),
+        ("AlphaCha", 124.631, -76.92, Chamaeleon),
+        ("AlphaMus", 189.296, -69.136, Musca),
+        ("AlphaCir", 220.627, -64.975, Circinus),
+        ("Gamma2Nor", 244.96, -50.155, Norma),
+        ("AlphaLup", 220.482, -47.388, Lupus),
+        ("AlphaCrA", 287.368, -37.904, CoronaAustralis),
+        ("AlphaSct", 278.802, -8.244, Scutum),
+        ("AlphaSge", 295.024, 18.014, Sagitta),
+        ("AlphaVul", 292.176, 24.665, Vulpecula),
+        ("AlphaDel", 309.91, 15.912, Delphinus),
+        ("AlphaEqu", 318.956, 5.248, Equuleus),
+        ("AlphaLac", 337.823, 50.282, Lacerta),
+        ("AlphaTri", 28.27, 29.579, Triangulum),
+        ("AlphaPsc", 30.512, 2.764, Pisces),
+        ("46LMi", 163.328, 34.215, LeoMinor),
+        ("AlphaLyn", 140.264, 34.393, Lynx),
+        ("BetaCam", 75.855, 60.442, Camelopardalis),
+        ("BetaCom", 197.968, 27.878, ComaBerenices),
+        ("AlphaSex", 151.985, -0.372, Sextans),
+        ("AlphaAnt", 156.788, -31.068, Antlia),
+        ("AlphaPyx", 130.898, -33.186, Pyxis),
+        ("ZetaPup", 120.896, -40.003, Puppis),
+        ("AlphaCae", 70.14, -41.864, Caelum),
+        ("AlphaFor", 48.019, -28.987, Fornax),
+        ("AlphaHor", 63.5, -42.294, Horologium),
+        ("AlphaScl", 14.652, -29.357, Sculptor),
+        ("BetaMon", 97.204, -7.033, Monoceros),
+        ("NuOct", 325.369, -77.39, Octans),
+        ("AlphaMen", 92.56, -74.753, Mensa),
+        ("BetaVol", 126.434, -66.137, Volans),
+        ("GammaMic", 315.323, -32.258, Microscopium),
+    ]
+};
+
+#[test]
+fn test_one_star_in_every_constellation() {
+    for (name, ra, dec, expected) in STARS {
+        assert_eq!(constellation_at(ra, dec).unwrap(), expected, "{name}");
+    }
+    let mut found: Vec<_> = STARS.iter().map(|s| s.3).collect();
+    found.sort();
+    assert_eq!(found, Constellation::ALL);
+}
+
+// Objects within half a degree of a boundary, J2000.0 positions in degrees
+const NEAR_BOUNDARY: [(&str, f64, f64, Constellation); 26] = {
+    use Constellation::*;
+    [
+        // 0.02° from Auriga
+        ("Elnath", 81.5728, 28.6075, Taurus),
+        // 0.1° from Libra and Ophiuchus
+        ("Dschubba", 240.0834, -22.6217, Scorpius),
+        ("AlphaCrv", 182.1034, -24.7289, Corvus),
+        ("M62", 255.303, -30.114, Ophiuchus),
+        ("M104", 189.998, -11.623, Virgo),
+        ("M90", 189.209, 13.163, Virgo),
+        ("NGC55", 3.723, -39.197, Sculptor),
+        ("Albireo", 292.6803, 27.9597, Cygnus),
+        ("AlphaPsc", 30.5118, 2.7638, Pisces),
+        ("Alpheratz", 2.0965, 29.0904, Andromeda),
+        // Formerly γ Scorpii
+        ("SigmaLib", 226.0176, -25.282, Libra),
+        ("RhoOph", 246.396, -23.4472, Ophiuchus),
+        ("ThetaSer", 284.0549, 4.2036, Serpens),
+        ("NuHya", 162.4062, -16.1936, Hydra),
+        ("AlphaCir", 220.6268, -64.9751, Circinus),
+        ("GammaPeg", 3.309, 15.1836, Pegasus),
+        ("M17", 275.196, -16.171, Sagittarius),
+        ("M75", 301.52, -21.921, Sagittarius),
+        ("M83", 204.254, -29.866, Hydra),
+        ("M84", 186.266, 12.887, Virgo),
+        ("M86", 186.549, 12.946, Virgo),
+        ("M101", 210.802, 54.349, UrsaMajor),
+        ("NGC253", 11.888, -25.288, Sculptor),
+        ("NGC4755", 193.413, -60.372, Crux),
+        ("NGC1365", 53.402, -36.14, Fornax),
+        ("XiSco", 241.0917, -11.3731, Scorpius),
+    ]
+};
+
+// IAU constellation areas in square degrees
+const AREAS: [(&str, f64); 88] = [
+        ("And", 722.278), ("Ant", 238.901), ("Aps", 206.327), ("Aql", 652.473), ("Aqr", 979.854),
+        ("Ara", 237.057), ("Ari", 441.395), ("Aur", 657.438), ("Boo", 906.831), ("CMa", 380.118),
+        ("CMi", 183.367), ("CVn", 465.194), ("Cae", 124.865), ("Cam", 756.828), ("Cap", 413.947),
+        ("Car", 494.184), ("Cas", 598.407), ("Cen", 1060.422), ("Cep", 587.787), ("Cet", 1231.411),
+        ("Cha", 131.592), ("Cir", 93.353), ("Cnc", 505.872), ("Col", 270.184), ("Com", 386.475),
+        ("CrA", 127.696), ("CrB", 178.710), ("Crt", 282.398), ("Cru", 68.447), ("Crv", 183.801),
+        ("Cyg", 803.983), ("Del", 188.549), ("Dor", 179.173), ("Dra", 1082.952), ("Equ", 71.641),
+        ("Eri", 1137.919), ("For", 397.502), ("Gem", 513.761), ("Gru", 365.513), ("Her", 1225.148),
+        ("Hor", 248.885), ("Hya", 1302.844), ("Hyi", 243.035), ("Ind", 294.006), ("LMi", 231.956),
+        ("Lac", 200.688), ("Leo", 946.964), ("Lep", 290.291), ("Lib", 538.052), ("Lup", 333.683),
+        ("Lyn", 545.386), ("Lyr", 286.476), ("Men", 153.484), ("Mic", 209.513), ("Mon", 481.569),
+        ("Mus", 138.355), ("Nor", 165.290), ("Oct", 291.045), ("Oph", 948.340), ("Ori", 594.120),
+        ("Pav", 377.666), ("Peg", 1120.794), ("Per", 614.997), ("Phe", 469.319), ("Pic", 246.739),
+        ("PsA", 245.375), ("Psc", 889.417), ("Pup", 673.434), ("Pyx", 220.833), ("Ret", 113.936),
+        ("Scl", 474.764), ("Sco", 496.783), ("Sct", 109.114), ("Ser", 636.928), ("Sex", 313.515),
+        ("Sge", 79.932), ("Sgr", 867.432), ("Tau", 797.249), ("Tel", 251.512), ("TrA", 109.978),
+        ("Tri", 131.847), ("Tuc", 294.557), ("UMa", 1279.660), ("UMi", 255.864), ("Vel", 499.649),
+        ("Vir", 1294.428), ("Vol", 141.354), ("Vul", 268.165),
+];
+
+#[test]
+fn test_near_boundaries() {
+    for (name, ra, dec, expected) in NEAR_BOUNDARY {
+        assert_eq!(constellation_at(ra, dec).unwrap(), expected, "{name}");
+    }
+    // The Sun's path crosses Ophiuchus
+    assert_eq!(constellation_at(255.0, -22.0).unwrap(), Constellation::Ophiuchus);
+}
+
+#[test]
+fn test_roman_test_points() {
+    // Roman (1987) test positions, equinox B1950.0, RA in hours
+    let points = [
+        (9.0, 65.0, Constellation::UrsaMajor),
+        (23.5, -20.0, Constellation::Aquarius),
+        (5.12, 9.12, Constellation::Orion),
+        (9.4555, -19.9, Constellation::Hydra),
+        (12.8888, 22.0, Constellation::ComaBerenices),
+        (15.6687, -12.1234, Constellation::Libra),
+        (19.0, -40.0, Constellation::CoronaAustralis),
+        (6.2222, -81.1234, Constellation::Mensa),
+    ];
+    let m = get_precession_matrix_without_bias(crate::time::besselian_epoch_to_jd(1950.0));
+    for (ra_hours, dec, expected) in points {
+        let (ra, dec) = rotate_ra_dec(&m, ra_hours * 15.0, dec, true);
+        assert_eq!(constellation_at(ra, dec).unwrap(), expected, "{ra_hours} {dec}");
+    }
+}
+
+#[test]
+fn test_zone_areas_match_iau() {
+    // Integrate the zones over strips between consecutive RA limits
+    let mut edges: Vec<f64> = BOUNDARIES.iter().flat_map(|z| [z.0, z.1]).collect();
+    edges.sort_by(f64::total_cmp);
+    edges.dedup();
+    let mut areas = std::collections::HashMap::new();
+    let mut used = [false; BOUNDARIES.len()];
+    for strip in edges.windows(2) {
+        let width = ((strip[1] - strip[0]) * 15.0).to_radians();
+        let mut top = 90.0_f64;
+        for (i, &(lo, hi, dec, c)) in BOUNDARIES.iter().enumerate() {
+            if lo <= strip[0] && strip[1] <= hi && dec < top {
+                let sr = width * (top.to_radians().sin() - dec.to_radians().sin());
+                *areas.entry(c).or_insert(0.0) += sr * (180.0 / std::f64::consts::PI).powi(2);
+                used[i] = true;
+                top = dec;
+            }
+        }
+        assert_eq!(top, -90.0);
+    }
+    assert!(used.iter().all(|&u| u), "every zone owns part of the sky");
+    for (abbr, iau) in AREAS {
+        let c = Constellation::from_abbreviation(abbr).unwrap();
+        // RA limits are rounded to 1e-4 h in the table
+        assert!((areas[&c] - iau).abs() < 0.02, "{abbr}: {} vs {iau}", areas[&c]);
+    }
+}
+
+#[test]
+fn test_whole_sky_is_covered() {
+    for i in 0..72 {
+        for j in 0..=36 {
+            let ra = i as f64 * 5.0;
+            let dec = -90.0 + j as f64 * 5.0;
+            assert!(constellation_at(ra, dec).is_ok(), "{ra} {dec}");
+        }
+    }
+    assert_eq!(constellation_at(180.0, 90.0).unwrap(), Constellation::UrsaMinor);
+    assert_eq!(constellation_at(359.9999, -89.9999).unwrap(), Constellation::Octans);
+}
+
+#[test]
+fn test_names_and_abbreviations() {
+    for c in Constellation::ALL {
+        assert_eq!(c.abbreviation().len(), 3);
+        assert_eq!(Constellation::from_abbreviation(c.abbreviation()), Some(c));
+        assert_eq!(c.to_string(), c.name());
+    }
+    assert_eq!(Constellation::CanesVenatici.abbreviation(), "CVn");
+    assert_eq!(Constellation::Bootes.name(), "Boötes");
+    assert_eq!(Constellation::from_abbreviation("PSA"), Some(Constellation::PiscisAustrinus));
+    assert_eq!(Constellation::from_abbreviation("Ser Cd"), None);
+}
+
+#[test]
+fn test_invalid_coordinates() {
+    assert!(matches!(constellation_at(360.0, 0.0), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(constellation_at(10.0, 90.5), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(constellation_at(f64::NAN, 0.0).is_err());
+}
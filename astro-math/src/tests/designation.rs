@@ -0,0 +1,98 @@
+use crate::designation::*;
+use crate::error::AstroError;
+
+#[test]
+fn test_round_trips() {
+    // Examples from the MPC's description of packed designations
+    let cases = [
+        ("(3202)", "03202"),
+        ("(50000)", "50000"),
+        ("(100345)", "A0345"),
+        ("(360017)", "a0017"),
+        ("(203289)", "K3289"),
+        ("(620000)", "~0000"),
+        ("(620061)", "~000z"),
+        ("(3140113)", "~AZaz"),
+        ("(15396335)", "~zzzz"),
+        ("1995 XA", "J95X00A"),
+        ("1995 XL1", "J95X01L"),
+        ("1995 FB13", "J95F13B"),
+        ("1998 SQ108", "J98SA8Q"),
+        ("1998 SV127", "J98SC7V"),
+        ("1998 SS162", "J98SG2S"),
+        ("2099 AZ193", "K99AJ3Z"),
+        ("2008 AA360", "K08Aa0A"),
+        ("2007 TA418", "K07Tf8A"),
+        ("2040 P-L", "PLS2040"),
+        ("3138 T-1", "T1S3138"),
+        ("1010 T-2", "T2S1010"),
+        ("4101 T-3", "T3S4101"),
+        ("1P", "0001P"),
+        ("354P", "0354P"),
+        ("2I", "0002I"),
+        ("C/1995 O1", "CJ95O010"),
+        ("P/1994 P1", "PJ94P010"),
+        ("C/1994 N1", "CJ94N010"),
+        ("P/1993 F2-B", "PJ93F02b"),
+        ("P/2019 LD2", "PK19L02D"),
+        ("A/2017 U1", "AK17U010"),
+    ];
+    for (unpacked, packed) in cases {
+        assert_eq!(pack(unpacked).unwrap(), packed, "packing {}", unpacked);
+        assert_eq!(unpack(packed).unwrap(), unpacked, "unpacking {}", packed);
+    }
+}
+
+#[test]
+fn test_parsed_fields() {
+    let designation: Designation = "2024 AB1".parse().unwrap();
+    assert_eq!(
+        designation,
+        Designation::Provisional(ProvisionalDesignation { year: 2024, half_month: 'A', letter: Some('B'), number: 1 })
+    );
+    assert_eq!(
+        "P/1993 F2-B".parse::<Designation>().unwrap(),
+        Designation::ProvisionalComet {
+            kind: CometKind::Periodic,
+            designation: ProvisionalDesignation { year: 1993, half_month: 'F', letter: None, number: 2 },
+            fragment: Some('B'),
+        }
+    );
+    assert_eq!(
+        "1P/Halley".parse::<Designation>().unwrap(),
+        Designation::NumberedComet { number: 1, kind: CometKind::Periodic }
+    );
+    assert_eq!(
+        "3138 T-1".parse::<Designation>().unwrap(),
+        Designation::Survey { number: 3138, survey: Survey::Trojan1 }
+    );
+    // Parentheses are optional, surrounding blanks are ignored
+    assert_eq!(" 433 ".parse::<Designation>().unwrap(), Designation::Numbered(433));
+    assert_eq!(Designation::unpack("    K24A01B").unwrap(), designation);
+}
+
+#[test]
+fn test_invalid_designations() {
+    for text in ["", "Eros", "(0)", "2024 IA", "2024 ZA", "2024 AI", "24 AB1", "2024 A", "5C", "C/1995", "P/1993 F2-b"] {
+        assert!(
+            matches!(text.parse::<Designation>(), Err(AstroError::InvalidFormat { .. })),
+            "{:?} parsed",
+            text
+        );
+    }
+    for packed in ["", "0000", "00000", "K24A01", "K24A010", "K24I01B", "!0001", "~00!0", "QJ95O010", "0000P"] {
+        assert!(
+            matches!(Designation::unpack(packed), Err(AstroError::InvalidFormat { .. })),
+            "{:?} unpacked",
+            packed
+        );
+    }
+}
+
+#[test]
+fn test_values_too_large_to_pack() {
+    assert!(matches!(Designation::Numbered(15_396_336).pack(), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(pack("2024 AB620"), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(pack("12345P"), Err(AstroError::OutOfRange { .. })));
+    assert_eq!(pack("2024 AB619").unwrap(), "K24Az9B");
+}
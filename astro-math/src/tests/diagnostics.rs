@@ -0,0 +1,63 @@
+use crate::diagnostics::*;
+use crate::time_scales::leap_second_table_expires;
+use chrono::{Duration, TimeZone, Utc};
+
+fn check<'a>(report: &'a Report, name: &str) -> &'a Check {
+    report.checks.iter().find(|c| c.name == name).unwrap()
+}
+
+#[test]
+fn test_battery_passes() {
+    let report = self_check_at(Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap());
+    assert_eq!(report.checks.len(), 9);
+    for c in &report.checks {
+        if c.name != "Earth orientation parameters" {
+            assert_eq!(c.status, Status::Pass, "{}: {} {} {}", c.name, c.value, c.unit, c.detail);
+            assert!(c.value.is_finite());
+        }
+    }
+    // The missing EOPs are the only thing to report
+    assert_eq!(report.status(), Status::Warn);
+    let problems: Vec<_> = report.problems().map(|c| c.name).collect();
+    assert_eq!(problems, vec!["Earth orientation parameters"]);
+}
+
+#[test]
+fn test_round_trips_are_tight() {
+    let report = self_check_at(Utc.with_ymd_and_hms(2031, 1, 15, 0, 0, 0).unwrap());
+    for name in ["RA/Dec <-> Alt/Az round trip", "Precession round trip", "Galactic round trip", "Tangent-plane round trip"] {
+        assert!(check(&report, name).value < 1e-4, "{}: {}", name, check(&report, name).value);
+    }
+    // Meeus' polynomial tracks IAU 2006 to milliseconds of time this century
+    assert!(check(&report, "GMST: IAU 2006 vs Meeus").value < 0.01);
+}
+
+#[test]
+fn test_leap_second_staleness() {
+    let expires = leap_second_table_expires().and_hms_opt(0, 0, 0).unwrap().and_utc();
+
+    let before = self_check_at(expires - Duration::days(90));
+    assert_eq!(check(&before, "Leap-second table").status, Status::Pass);
+    assert_eq!(check(&before, "Leap-second table").value, 90.0);
+
+    let soon = self_check_at(expires - Duration::days(10));
+    assert_eq!(check(&soon, "Leap-second table").status, Status::Warn);
+
+    let after = self_check_at(expires + Duration::days(400));
+    let leap = check(&after, "Leap-second table");
+    assert_eq!(leap.status, Status::Warn);
+    assert!(leap.value < 0.0 && leap.detail.contains("expired"));
+}
+
+#[test]
+fn test_report_display() {
+    let report = self_check_at(Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap());
+    let text = report.to_string();
+    assert!(text.starts_with(&format!("astro-math {} (", env!("CARGO_PKG_VERSION"))));
+    assert!(text.contains("2024-08-04 06:00:00 UTC: WARN"));
+    assert_eq!(text.lines().count(), 1 + report.checks.len());
+    for c in &report.checks {
+        assert!(text.contains(c.name));
+    }
+    assert!(Status::Pass < Status::Warn && Status::Warn < Status::Fail);
+}
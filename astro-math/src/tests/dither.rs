@@ -0,0 +1,75 @@
+use crate::dither::*;
+use crate::error::AstroError;
+use crate::offsets::offset_between;
+
+#[test]
+fn test_spiral_order() {
+    let spiral = pattern(DitherKind::Spiral, 1.0, 10).unwrap();
+    assert_eq!(
+        spiral,
+        vec![
+            (0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (-1.0, 1.0),
+            (-1.0, 0.0), (-1.0, -1.0), (0.0, -1.0), (1.0, -1.0), (2.0, -1.0),
+        ]
+    );
+
+    // All distinct, consecutive points one step apart
+    let spiral = pattern(DitherKind::Spiral, 3.0, 50).unwrap();
+    for pair in spiral.windows(2) {
+        let step = (pair[1].0 - pair[0].0).hypot(pair[1].1 - pair[0].1);
+        assert!((step - 3.0).abs() < 1e-12);
+    }
+    for (i, a) in spiral.iter().enumerate() {
+        assert!(spiral[i + 1..].iter().all(|b| a != b));
+    }
+}
+
+#[test]
+fn test_grid() {
+    let grid = pattern(DitherKind::Grid, 2.0, 4).unwrap();
+    assert_eq!(grid, vec![(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]);
+
+    // Partial grids keep the square layout
+    let grid = pattern(DitherKind::Grid, 1.0, 7).unwrap();
+    assert_eq!(grid.len(), 7);
+    assert!(grid.iter().all(|&(x, y)| x.abs() <= 1.0 && y.abs() <= 1.0));
+    assert!(pattern(DitherKind::Grid, 1.0, 0).unwrap().is_empty());
+}
+
+#[test]
+fn test_random_is_bounded_and_reproducible() {
+    let a = pattern(DitherKind::Random { seed: 42 }, 15.0, 200).unwrap();
+    let b = pattern(DitherKind::Random { seed: 42 }, 15.0, 200).unwrap();
+    let c = pattern(DitherKind::Random { seed: 43 }, 15.0, 200).unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert!(a.iter().all(|&(x, y)| x.abs() <= 15.0 && y.abs() <= 15.0));
+
+    // Roughly uniform: mean near zero, spread near 15/√3
+    let mean = a.iter().map(|p| p.0).sum::<f64>() / a.len() as f64;
+    let rms = (a.iter().map(|p| p.1 * p.1).sum::<f64>() / a.len() as f64).sqrt();
+    assert!(mean.abs() < 2.0, "mean {}", mean);
+    assert!((rms - 15.0 / 3f64.sqrt()).abs() < 1.5, "rms {}", rms);
+}
+
+#[test]
+fn test_apply_keeps_on_sky_size() {
+    let offsets = pattern(DitherKind::Spiral, 20.0, 9).unwrap();
+    for dec in [0.0, 60.0, 89.99, -89.999] {
+        let pointings = apply(75.0, dec, &offsets).unwrap();
+        for (&(ra, d), &(dx, dy)) in pointings.iter().zip(&offsets) {
+            let (east, north) = offset_between(75.0, dec, ra, d).unwrap();
+            assert!((east - dx).abs() < 1e-6 && (north - dy).abs() < 1e-6, "dec {}: {:?}", dec, (east, north));
+        }
+    }
+}
+
+#[test]
+fn test_errors() {
+    for scale in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(pattern(DitherKind::Grid, scale, 4), Err(AstroError::OutOfRange { .. })));
+    }
+    assert!(matches!(apply(0.0, 91.0, &[(1.0, 1.0)]), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(apply(0.0, 91.0, &[]).is_err());
+    assert!(apply(0.0, 0.0, &[(f64::NAN, 0.0)]).is_err());
+}
@@ -0,0 +1,98 @@
+use crate::drift_scan::*;
+use crate::error::AstroError;
+use crate::transforms::ra_dec_to_alt_az;
+use crate::Location;
+use chrono::{Duration, TimeZone, Utc};
+
+fn site() -> Location {
+    Location { latitude_deg: 38.43, longitude_deg: -79.84, altitude_m: 800.0 }
+}
+
+#[test]
+fn test_strip_follows_constant_declination() {
+    let t0 = Utc.with_ymd_and_hms(2024, 9, 1, 2, 0, 0).unwrap();
+    let strip = drift_scan_strip(55.0, 120.0, t0, t0 + Duration::hours(3), &site(), Duration::minutes(15)).unwrap();
+    assert_eq!(strip.track.len(), 13);
+    for &(time, ra, dec) in &strip.track {
+        assert!((dec - strip.dec).abs() < 1e-9);
+        // Every sample maps back to the fixed pointing
+        let (alt, az) = ra_dec_to_alt_az(ra, dec, time, &site()).unwrap();
+        assert!((alt - 55.0).abs() < 1e-6 && (az - 120.0).abs() < 1e-6);
+    }
+    assert_eq!(strip.track[0].0, t0);
+    assert_eq!(strip.track[12].0, t0 + Duration::hours(3));
+    assert_eq!((strip.ra_start, strip.ra_end), (strip.track[0].1, strip.track[12].1));
+}
+
+#[test]
+fn test_span_length_and_rate() {
+    let t0 = Utc.with_ymd_and_hms(2024, 9, 1, 2, 0, 0).unwrap();
+    let strip = drift_scan_strip(60.0, 180.0, t0, t0 + Duration::hours(1), &site(), Duration::minutes(5)).unwrap();
+    // One solar hour is 1.0027 sidereal hours
+    assert!((strip.ra_span - 15.041).abs() < 0.001);
+    assert!(((strip.ra_end - strip.ra_start).rem_euclid(360.0) - strip.ra_span).abs() < 1e-3);
+    let cos_dec = strip.dec.to_radians().cos();
+    assert!((strip.length - strip.ra_span * cos_dec).abs() < 1e-12);
+    assert!((strip.drift_rate - 15.041 * cos_dec).abs() < 0.001);
+}
+
+#[test]
+fn test_uneven_step_ends_on_end_time() {
+    let t0 = Utc.with_ymd_and_hms(2024, 9, 1, 2, 0, 0).unwrap();
+    let end = t0 + Duration::minutes(25);
+    let strip = drift_scan_strip(45.0, 0.0, t0, end, &site(), Duration::minutes(10)).unwrap();
+    let times: Vec<_> = strip.track.iter().map(|p| p.0).collect();
+    assert_eq!(times, vec![t0, t0 + Duration::minutes(10), t0 + Duration::minutes(20), end]);
+
+    let instant = drift_scan_strip(45.0, 0.0, t0, t0, &site(), Duration::minutes(10)).unwrap();
+    assert_eq!(instant.track.len(), 1);
+    assert_eq!(instant.ra_span, 0.0);
+}
+
+#[test]
+fn test_contains_and_dec_range() {
+    let t0 = Utc.with_ymd_and_hms(2024, 9, 1, 2, 0, 0).unwrap();
+    let strip = drift_scan_strip(70.0, 180.0, t0, t0 + Duration::hours(2), &site(), Duration::minutes(30)).unwrap();
+    let middle = (strip.ra_start + strip.ra_span / 2.0).rem_euclid(360.0);
+    assert!(strip.contains(middle, strip.dec + 0.4, 1.0).unwrap());
+    assert!(!strip.contains(middle, strip.dec + 0.6, 1.0).unwrap());
+    let before = (strip.ra_start - 1.0).rem_euclid(360.0);
+    assert!(!strip.contains(before, strip.dec, 1.0).unwrap());
+
+    // Clamped at the pole
+    let polar = drift_scan_strip(38.43, 0.0, t0, t0 + Duration::hours(1), &site(), Duration::hours(1)).unwrap();
+    assert!((polar.dec - 90.0).abs() < 1e-9);
+    assert_eq!(polar.dec_range(4.0).unwrap().1, 90.0);
+    assert!(polar.drift_rate < 1e-6);
+}
+
+#[test]
+fn test_full_day_scan_contains_every_ra() {
+    let t0 = Utc.with_ymd_and_hms(2024, 9, 1, 2, 0, 0).unwrap();
+    let strip = drift_scan_strip(80.0, 0.0, t0, t0 + Duration::hours(24), &site(), Duration::hours(1)).unwrap();
+    assert!(strip.ra_span > 360.0);
+    for ra in [0.0, 90.0, 180.0, 270.0] {
+        assert!(strip.contains(ra, strip.dec, 0.5).unwrap());
+    }
+}
+
+#[test]
+fn test_errors() {
+    let t0 = Utc.with_ymd_and_hms(2024, 9, 1, 2, 0, 0).unwrap();
+    let step = Duration::minutes(1);
+    assert!(matches!(
+        drift_scan_strip(45.0, 0.0, t0, t0 - step, &site(), step),
+        Err(AstroError::OutOfRange { .. })
+    ));
+    assert!(matches!(
+        drift_scan_strip(45.0, 0.0, t0, t0 + step, &site(), Duration::zero()),
+        Err(AstroError::OutOfRange { .. })
+    ));
+    assert!(matches!(
+        drift_scan_strip(95.0, 0.0, t0, t0 + step, &site(), step),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    let strip = drift_scan_strip(45.0, 0.0, t0, t0 + step, &site(), step).unwrap();
+    assert!(matches!(strip.dec_range(-1.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(strip.transit_duration(f64::NAN), Err(AstroError::OutOfRange { .. })));
+}
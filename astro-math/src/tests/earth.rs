@@ -0,0 +1,68 @@
+use crate::earth::*;
+
+#[test]
+fn test_perihelion_and_aphelion() {
+    // 2024 perihelion January 3, aphelion July 5
+    let mut closest = (f64::MAX, 0.0);
+    let mut farthest = (f64::MIN, 0.0);
+    for day in 0..366 {
+        let jd = 2460310.5 + day as f64;
+        let r = earth_heliocentric_distance(jd);
+        if r < closest.0 {
+            closest = (r, jd);
+        }
+        if r > farthest.0 {
+            farthest = (r, jd);
+        }
+    }
+    assert!((closest.1 - 2460313.5).abs() <= 1.0, "{:?}", closest);
+    assert!((farthest.1 - 2460496.5).abs() <= 1.0, "{:?}", farthest);
+    assert!((closest.0 - 0.98331).abs() < 1e-4 && (farthest.0 - 1.01673).abs() < 1e-4);
+
+    // True anomaly is near 0 at perihelion and 180° at aphelion; the Moon
+    // shifts Earth's centre by up to a few days' worth
+    let wrapped = |a: f64| (a + 180.0).rem_euclid(360.0) - 180.0;
+    assert!(wrapped(earth_true_anomaly(closest.1)).abs() < 3.0);
+    assert!(wrapped(earth_true_anomaly(farthest.1) - 180.0).abs() < 3.0);
+}
+
+#[test]
+fn test_velocity_consistent_with_vis_viva() {
+    // v² = GM(2/r − 1/a) with a = 1.000001 AU
+    let gm = 0.01720209895f64.powi(2); // AU³/day²
+    for day in (0..365).step_by(30) {
+        let jd = 2451545.0 + day as f64;
+        let r = earth_heliocentric_distance(jd);
+        let expected = (gm * (2.0 / r - 1.0 / 1.000001)).sqrt() * 149597870.7 / 86400.0;
+        // The Moon's pull moves Earth's centre by ±12 m/s around the barycentre
+        assert!((earth_orbital_velocity(jd) - expected).abs() < 0.05, "{} vs {}", earth_orbital_velocity(jd), expected);
+    }
+}
+
+#[test]
+fn test_true_anomaly_matches_kepler() {
+    // A Keplerian orbit with Earth's mean elements gives the same true anomaly
+    let jd = 2460526.5;
+    let e = earth_orbit_eccentricity(jd);
+    let m = earth_mean_anomaly(jd).to_radians();
+    let ea = crate::orbit::solve_kepler(m, e);
+    let nu = 2.0 * (((1.0 + e) / (1.0 - e)).sqrt() * (ea / 2.0).tan()).atan();
+    assert!((nu.to_degrees().rem_euclid(360.0) - earth_true_anomaly(jd)).abs() < 1e-3);
+    assert!((e - 0.0167).abs() < 1e-4);
+}
+
+#[test]
+fn test_barycentric_and_heliocentric_differ_by_sun_offset() {
+    let jd = 2460526.5;
+    let h = earth_heliocentric_state(jd);
+    let b = earth_barycentric_state(jd);
+    // The Sun's offset from the barycentre is at most ~0.01 AU
+    let offset: f64 = (0..3).map(|i| (b.position[i] - h.position[i]).powi(2)).sum::<f64>().sqrt();
+    assert!(offset > 1e-4 && offset < 0.011, "{}", offset);
+    assert!((earth_barycentric_distance(jd) - earth_heliocentric_distance(jd)).abs() <= offset);
+    // ...and its velocity about it at most ~15 m/s
+    let v = earth_barycentric_velocity(jd);
+    let dv: f64 = (0..3).map(|i| (v[i] - h.velocity[i] * 149597870.7 / 86400.0).powi(2)).sum::<f64>().sqrt();
+    assert!(dv < 0.02, "{}", dv);
+    assert_eq!(h.jd_tdb, jd);
+}
@@ -0,0 +1,66 @@
+use crate::ecliptic::*;
+use crate::error::AstroError;
+use crate::nutation::mean_obliquity;
+
+#[test]
+fn test_meeus_example_13a() {
+    // Pollux: α = 7h45m18.946s, δ = +28°01′34.26″. Meeus uses the IAU 1980
+    // obliquity, 0.042″ larger than the IAU 2006 value used here.
+    let (lambda, beta) = equatorial_to_ecliptic_j2000(116.328942, 28.026183).unwrap();
+    assert!((lambda - 113.215630).abs() < 2e-5);
+    assert!((beta - 6.684170).abs() < 2e-5);
+
+    let (ra, dec) = ecliptic_to_equatorial_j2000(113.215630, 6.684170).unwrap();
+    assert!((ra - 116.328942).abs() < 2e-5);
+    assert!((dec - 28.026183).abs() < 2e-5);
+}
+
+#[test]
+fn test_ecliptic_round_trip() {
+    let jd = 2460676.5;
+    for &(ra, dec) in &[(0.0, 0.0), (83.633, 22.0145), (279.234, 38.784), (201.298, -43.019), (359.99, -89.9)] {
+        let (lambda, beta) = equatorial_to_ecliptic(ra, dec, jd).unwrap();
+        assert!((0.0..360.0).contains(&lambda));
+        let (ra2, dec2) = ecliptic_to_equatorial(lambda, beta, jd).unwrap();
+        let ra_diff = (ra2 - ra + 180.0).rem_euclid(360.0) - 180.0;
+        assert!(ra_diff.abs() * dec.to_radians().cos() < 1e-9, "RA {ra} -> {ra2}");
+        assert!((dec2 - dec).abs() < 1e-9, "Dec {dec} -> {dec2}");
+    }
+}
+
+#[test]
+fn test_ecliptic_reference_points() {
+    let jd = 2451545.0 + 36525.0; // J2100.0
+    let epsilon = mean_obliquity(jd);
+
+    // Equinoxes lie on both equators
+    assert_eq!(equatorial_to_ecliptic(0.0, 0.0, jd).unwrap(), (0.0, 0.0));
+    let (lambda, beta) = equatorial_to_ecliptic(180.0, 0.0, jd).unwrap();
+    assert!((lambda - 180.0).abs() < 1e-12 && beta.abs() < 1e-12);
+
+    // The ecliptic poles sit at RA 18h/6h, Dec ±(90° − ε)
+    let (ra, dec) = ecliptic_to_equatorial(123.0, 90.0, jd).unwrap();
+    assert!((ra - 270.0).abs() < 1e-9);
+    assert!((dec - (90.0 - epsilon)).abs() < 1e-9);
+    let (ra, dec) = ecliptic_to_equatorial(0.0, -90.0, jd).unwrap();
+    assert!((ra - 90.0).abs() < 1e-9);
+    assert!((dec + 90.0 - epsilon).abs() < 1e-9);
+
+    // Longitude is normalized rather than rejected
+    let wrapped = ecliptic_to_equatorial(-30.0, 5.0, jd).unwrap();
+    let direct = ecliptic_to_equatorial(330.0, 5.0, jd).unwrap();
+    assert!((wrapped.0 - direct.0).abs() < 1e-9 && (wrapped.1 - direct.1).abs() < 1e-9);
+}
+
+#[test]
+fn test_ecliptic_invalid_inputs() {
+    assert!(matches!(equatorial_to_ecliptic(360.0, 0.0, 2451545.0), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(equatorial_to_ecliptic_j2000(10.0, 91.0), Err(AstroError::InvalidCoordinate { .. })));
+    match ecliptic_to_equatorial_j2000(10.0, -95.0) {
+        Err(AstroError::InvalidCoordinate { coord_type, value, .. }) => {
+            assert_eq!(coord_type, "Ecliptic latitude");
+            assert_eq!(value, -95.0);
+        }
+        other => panic!("expected error, got {other:?}"),
+    }
+}
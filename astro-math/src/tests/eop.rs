@@ -0,0 +1,202 @@
+use crate::eop::*;
+use crate::error::AstroError;
+use chrono::{TimeZone, Utc};
+use std::f64::consts::TAU;
+
+/// 2017-01-01, when TAI−UTC went from 36 to 37 s
+const LEAP_MJD: f64 = 57754.0;
+
+/// UT2 − UT1 as published with IERS Bulletin A
+fn seasonal(mjd: f64) -> f64 {
+    let t = TAU * (2000.0 + (mjd - 51544.03) / 365.2422);
+    0.022 * t.sin() - 0.012 * t.cos() - 0.006 * (2.0 * t).sin() + 0.007 * (2.0 * t).cos()
+}
+
+fn tai_utc(mjd: f64) -> f64 {
+    if mjd < LEAP_MJD {
+        36.0
+    } else {
+        37.0
+    }
+}
+
+/// Daily DUT1 following a Bulletin A model: linear UT2 − TAI plus the seasonal term
+fn bulletin_a(first: f64, days: usize) -> Vec<(f64, f64)> {
+    (0..days)
+        .map(|d| {
+            let mjd = first + d as f64;
+            (mjd, -36.4 - 0.0012 * (mjd - LEAP_MJD) - seasonal(mjd) + tai_utc(mjd))
+        })
+        .collect()
+}
+
+#[test]
+fn test_interpolation() {
+    let entries = [(60000.0, 0.1), (60001.0, 0.098), (60003.0, 0.090)];
+    let table = Dut1Table::new(&entries).unwrap();
+    assert_eq!(table.span(), (60000.0, 60003.0));
+    for &(mjd, dut1) in &entries {
+        assert!((table.dut1(mjd).unwrap() - dut1).abs() < 1e-12);
+        assert_eq!(table.prediction_error(mjd).unwrap(), 0.0);
+    }
+    assert!((table.dut1(60000.25).unwrap() - 0.0995).abs() < 1e-12);
+    assert!((table.dut1(60002.0).unwrap() - 0.094).abs() < 1e-12);
+
+    let noon = Utc.with_ymd_and_hms(2023, 2, 25, 12, 0, 0).unwrap(); // MJD 60000.5
+    assert!((table.dut1_for_datetime(noon).unwrap() - 0.099).abs() < 1e-12);
+}
+
+#[test]
+fn test_leap_second_in_table() {
+    let entries = bulletin_a(LEAP_MJD - 10.0, 20);
+    // DUT1 jumps by a second across the leap
+    assert!(entries[10].1 - entries[9].1 > 0.99);
+    let table = Dut1Table::new(&entries).unwrap();
+    // The last hours of 2016-12-31 interpolate in UT1 − TAI, not across the jump
+    let late = LEAP_MJD - 0.25;
+    let expected = -36.4 - 0.0012 * (late - LEAP_MJD) + 36.0;
+    let seasonal_error = (seasonal(LEAP_MJD - 1.0) - seasonal(LEAP_MJD)).abs();
+    assert!((table.dut1(late).unwrap() - (expected - seasonal(late))).abs() < seasonal_error + 1e-9);
+    assert!(table.dut1(late).unwrap() < -0.39);
+    assert!(table.dut1(LEAP_MJD + 0.25).unwrap() > 0.59);
+}
+
+#[test]
+fn test_prediction_follows_bulletin_a_model() {
+    let entries = bulletin_a(57600.0, 90);
+    let table = Dut1Table::new(&entries).unwrap();
+    let last = table.span().1;
+    // A table that exactly follows the model predicts it exactly, across the leap second
+    for days in [0.5, 10.0, 30.0, 60.0, 90.0] {
+        let mjd = last + days;
+        let model = -36.4 - 0.0012 * (mjd - LEAP_MJD) - seasonal(mjd) + tai_utc(mjd);
+        assert!((table.dut1(mjd).unwrap() - model).abs() < 1e-9, "{} {}", days, table.dut1(mjd).unwrap() - model);
+    }
+    assert!(last + 90.0 > LEAP_MJD);
+    // Continuous at the end of the table
+    assert!((table.dut1(last + 1e-6).unwrap() - entries[89].1).abs() < 1e-7);
+}
+
+#[test]
+fn test_prediction_error() {
+    let table = Dut1Table::new(&bulletin_a(60000.0, 30)).unwrap();
+    let last = table.span().1;
+    assert!((table.prediction_error(last + 30.0).unwrap() - 0.0032).abs() < 1e-4);
+    assert!((table.prediction_error(last + 90.0).unwrap() - 0.0073).abs() < 1e-4);
+    assert!(table.prediction_error(last + 91.0).is_err());
+}
+
+#[test]
+fn test_policy() {
+    let table = Dut1Table::new(&bulletin_a(60000.0, 30)).unwrap();
+    let last = table.span().1;
+    assert_eq!(table.policy(), ExtrapolationPolicy::default());
+    assert!(table.dut1(last + 90.0).is_ok());
+    assert!(matches!(
+        table.dut1(last + 90.5),
+        Err(AstroError::OutOfRange { parameter: "mjd", .. })
+    ));
+    assert!(matches!(
+        table.dut1(59999.0),
+        Err(AstroError::OutOfRange { parameter: "mjd", .. })
+    ));
+    assert!(table.dut1(f64::NAN).is_err());
+
+    let strict = table.clone().with_policy(ExtrapolationPolicy { max_days: 0.0, fit_days: 60.0 }).unwrap();
+    assert!(strict.dut1(last).is_ok());
+    assert!(strict.dut1(last + 0.01).is_err());
+
+    // A short fit window still uses at least two entries
+    let short = table.clone().with_policy(ExtrapolationPolicy { max_days: 10.0, fit_days: 0.0 }).unwrap();
+    assert!(short.dut1(last + 5.0).unwrap().is_finite());
+
+    for bad in [-1.0, f64::INFINITY, f64::NAN] {
+        assert!(matches!(
+            table.clone().with_policy(ExtrapolationPolicy { max_days: bad, fit_days: 60.0 }),
+            Err(AstroError::OutOfRange { parameter: "max_days", .. })
+        ));
+        assert!(matches!(
+            table.clone().with_policy(ExtrapolationPolicy { max_days: 30.0, fit_days: bad }),
+            Err(AstroError::OutOfRange { parameter: "fit_days", .. })
+        ));
+    }
+}
+
+#[test]
+fn test_invalid_tables() {
+    for entries in [
+        vec![],
+        vec![(60000.0, 0.1)],
+        vec![(60000.0, 0.1), (60000.0, 0.1)],
+        vec![(60001.0, 0.1), (60000.0, 0.1)],
+        vec![(60000.0, 0.1), (60001.0, f64::NAN)],
+        vec![(60000.0, 0.1), (f64::INFINITY, 0.1)],
+    ] {
+        assert!(
+            matches!(Dut1Table::new(&entries), Err(AstroError::CalculationError { .. })),
+            "{:?}",
+            entries
+        );
+    }
+}
+
+/// A `finals2000A` line with Bulletin A values in their fixed columns
+fn finals_line(mjd: f64, xp: f64, yp: f64, dut1: f64, pole_offsets: Option<(f64, f64)>) -> String {
+    let mut line = format!(
+        "24 1 1 {:8.2} I {:9.6}{:9.6} {:9.6}{:9.6}  I{:10.7}{:10.7} {:7.4}{:7.4}",
+        mjd, xp, 0.000091, yp, 0.000091, dut1, 0.0000176, 0.3418, 0.0112
+    );
+    if let Some((dx, dy)) = pole_offsets {
+        line += &format!("  I {:9.3}{:9.3} {:9.3}{:9.3}", dx, 0.3, dy, 0.3);
+    }
+    line
+}
+
+#[test]
+fn test_parse_finals2000a() {
+    let text = [
+        finals_line(60310.0, 0.074921, 0.217099, 0.0116733, Some((0.273, -0.058))),
+        finals_line(60311.0, 0.073455, 0.216712, 0.0113505, Some((0.284, -0.054))),
+        // Predictions of polar motion and UT1 only
+        finals_line(60312.0, 0.072000, 0.216300, 0.0110000, None),
+        String::new(),
+        // Past the end of the predictions
+        "24 1 4 60313.00".to_string(),
+    ]
+    .join("\n");
+    let table = EopTable::parse_finals2000a(&text).unwrap();
+    assert_eq!(table.span(), (60310.0, 60312.0));
+
+    let eop = table.earth_orientation(60310.25).unwrap();
+    assert!((eop.dut1 - (0.0116733 * 0.75 + 0.0113505 * 0.25)).abs() < 1e-9);
+    assert!((eop.yp_arcsec - (0.217099 * 0.75 + 0.216712 * 0.25)).abs() < 1e-9);
+    assert!((eop.dy_mas.unwrap() + 0.057).abs() < 1e-9);
+
+    // Pole offsets end with the observed values; polar motion is held
+    let later = table.earth_orientation(60320.0).unwrap();
+    assert_eq!((later.dx_mas, later.dy_mas), (None, None));
+    assert_eq!((later.xp_arcsec, later.yp_arcsec), (0.072, 0.2163));
+    assert_eq!(later.dut1, table.dut1_table().dut1(60320.0).unwrap());
+    assert!(matches!(table.earth_orientation(60309.0), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_parse_finals2000a_errors() {
+    let good = finals_line(60310.0, 0.07, 0.21, 0.01, None);
+    let bad = finals_line(60311.0, 0.07, 0.21, 0.01, None).replace(" 0.0100000", " 0.01x0000");
+    let result = EopTable::parse_finals2000a(&format!("{}\n{}", good, bad));
+    assert!(matches!(result, Err(AstroError::InvalidFormat { format: "IERS finals2000A", .. })));
+    assert!(matches!(EopTable::parse_finals2000a("garbage"), Err(AstroError::InvalidFormat { .. })));
+    assert!(matches!(EopTable::parse_finals2000a(&good), Err(AstroError::CalculationError { .. })));
+}
+
+#[test]
+fn test_earth_orientation_validation() {
+    assert!(EarthOrientation::default().validate().is_ok());
+    let bad_dut1 = EarthOrientation { dut1: 1.5, ..Default::default() };
+    assert!(matches!(bad_dut1.validate(), Err(AstroError::OutOfRange { parameter: "dut1", .. })));
+    let bad_dx = EarthOrientation { dx_mas: Some(f64::NAN), ..Default::default() };
+    assert!(matches!(bad_dx.validate(), Err(AstroError::OutOfRange { parameter: "dx_mas", .. })));
+    let entries = [(60000.0, EarthOrientation::default()), (60001.0, bad_dut1)];
+    assert!(matches!(EopTable::new(&entries), Err(AstroError::OutOfRange { .. })));
+}
@@ -0,0 +1,117 @@
+use crate::almanac::AlmanacBody;
+use crate::ephemeris::*;
+use crate::error::AstroError;
+use crate::events::Crossing;
+use crate::rise_set::{body_altitude_crossings, body_rise_set, moon_rise_set, sun_rise_set_with_model, SolarModel};
+use crate::satellite::{satellite_alt_az, Tle};
+use crate::transforms::body_alt_az;
+use crate::Location;
+use chrono::{Duration, TimeZone, Utc};
+
+const ISS_LINE1: &str = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+const ISS_LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+fn greenwich() -> Location {
+    Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 }
+}
+
+fn row(hours: i64, ra: f64, dec: f64, distance_au: Option<f64>) -> TabulatedPosition {
+    let t0 = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    TabulatedPosition { time: t0 + Duration::hours(hours), ra, dec, distance_au }
+}
+
+#[test]
+fn test_sun_rise_set_matches_solar_ephemeris_model() {
+    let date = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let (rise, set) = body_rise_set(&AlmanacBody::Sun, date, &greenwich()).unwrap();
+    let (expected_rise, expected_set) = sun_rise_set_with_model(date, &greenwich(), SolarModel::Ephemeris).unwrap().unwrap();
+    assert!((rise.unwrap() - expected_rise).num_seconds().abs() <= 5);
+    assert!((set.unwrap() - expected_set).num_seconds().abs() <= 5);
+}
+
+#[test]
+fn test_moon_rise_set_is_the_generic_search() {
+    let date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+    assert_eq!(moon_rise_set(date, &greenwich()).unwrap(), body_rise_set(&AlmanacBody::Moon, date, &greenwich()).unwrap());
+}
+
+#[test]
+fn test_satellite_alt_az_matches_tle_module() {
+    let tle = Tle::parse(ISS_LINE1, ISS_LINE2).unwrap();
+    let site = Location { latitude_deg: 40.0, longitude_deg: -75.0, altitude_m: 100.0 };
+    for minutes in [0, 30, 90, 240] {
+        let dt = tle.epoch + Duration::minutes(minutes);
+        let (alt, az) = body_alt_az(&tle, dt, &site).unwrap();
+        let (expected_alt, expected_az) = satellite_alt_az(&tle, dt, &site).unwrap();
+        assert!((alt - expected_alt).abs() < 0.01, "{} min: {} vs {}", minutes, alt, expected_alt);
+        let daz = (az - expected_az + 540.0).rem_euclid(360.0) - 180.0;
+        assert!(daz.abs() * expected_alt.to_radians().cos() < 0.01);
+    }
+}
+
+#[test]
+fn test_satellite_passes() {
+    let tle = Tle::parse(ISS_LINE1, ISS_LINE2).unwrap();
+    let site = Location { latitude_deg: 40.0, longitude_deg: -75.0, altitude_m: 0.0 };
+    let crossings = body_altitude_crossings(&tle, tle.epoch, &site, 10.0).unwrap();
+    assert!(!crossings.is_empty());
+    // Each pass above 10° lasts minutes, not hours
+    for pair in crossings.windows(2) {
+        if pair[0].1 == Crossing::Rising {
+            assert!((pair[1].0 - pair[0].0) < Duration::minutes(10));
+        }
+    }
+}
+
+#[test]
+fn test_tabulated_interpolation() {
+    let table = TabulatedEphemeris::new(vec![row(0, 10.0, 0.0, Some(1.0)), row(24, 12.0, 0.0, Some(1.2))]).unwrap();
+    let noon = table.rows()[0].time + Duration::hours(12);
+    let (ra, dec) = table.radec(noon).unwrap();
+    assert!((ra - 11.0).abs() < 1e-9 && dec.abs() < 1e-9);
+    assert!((table.distance(noon).unwrap().unwrap() - 1.1).abs() < 1e-12);
+    // Endpoints are exact
+    assert!((table.radec(table.rows()[1].time).unwrap().0 - 12.0).abs() < 1e-9);
+    // Across RA 0
+    let wrap = TabulatedEphemeris::new(vec![row(0, 359.0, 0.0, None), row(24, 1.0, 0.0, None)]).unwrap();
+    let ra = wrap.radec(noon).unwrap().0;
+    assert!(((ra + 180.0).rem_euclid(360.0) - 180.0).abs() < 1e-9);
+    assert_eq!(wrap.distance(noon).unwrap(), None);
+}
+
+#[test]
+fn test_tabulated_errors() {
+    assert!(matches!(TabulatedEphemeris::new(vec![row(0, 10.0, 0.0, None)]), Err(AstroError::OutOfRange { .. })));
+    let backwards = TabulatedEphemeris::new(vec![row(24, 10.0, 0.0, None), row(0, 11.0, 0.0, None)]);
+    assert!(matches!(backwards, Err(AstroError::InvalidDateTime { .. })));
+    let bad_distance = TabulatedEphemeris::new(vec![row(0, 10.0, 0.0, Some(0.0)), row(24, 11.0, 0.0, Some(1.0))]);
+    assert!(matches!(bad_distance, Err(AstroError::OutOfRange { .. })));
+    let bad_dec = TabulatedEphemeris::new(vec![row(0, 10.0, 95.0, None), row(24, 11.0, 0.0, None)]);
+    assert!(matches!(bad_dec, Err(AstroError::InvalidCoordinate { .. })));
+
+    let table = TabulatedEphemeris::new(vec![row(0, 10.0, 0.0, None), row(24, 11.0, 0.0, None)]).unwrap();
+    let late = table.rows()[1].time + Duration::hours(1);
+    assert!(matches!(table.radec(late), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(body_alt_az(&table, late, &greenwich()), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_topocentric_parallax() {
+    let dt = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+    // Nothing for a target without a distance
+    let table = TabulatedEphemeris::new(vec![row(0, 10.0, 20.0, None), row(24 * 20, 10.0, 20.0, None)]).unwrap();
+    assert_eq!(topocentric_radec(&table, dt, &greenwich()).unwrap(), (10.0, 20.0));
+
+    // The Moon is lowered by up to its horizontal parallax, about 0.95°
+    let (geo_alt, _) = {
+        let (ra, dec) = AlmanacBody::Moon.radec(dt).unwrap();
+        crate::ra_dec_to_alt_az(ra, dec, dt, &greenwich()).unwrap()
+    };
+    let (topo_alt, _) = body_alt_az(&AlmanacBody::Moon, dt, &greenwich()).unwrap();
+    let depression = geo_alt - topo_alt;
+    assert!(depression > 0.0 && depression < 1.0);
+    assert!((depression - 0.95 * geo_alt.to_radians().cos()).abs() < 0.05);
+
+    let bad = Location { latitude_deg: 91.0, ..greenwich() };
+    assert!(matches!(topocentric_radec(&AlmanacBody::Moon, dt, &bad), Err(AstroError::InvalidCoordinate { .. })));
+}
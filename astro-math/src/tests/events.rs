@@ -0,0 +1,99 @@
+use crate::events::*;
+use crate::error::AstroError;
+use crate::{Location, ra_dec_to_alt_az};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn hours_since(t0: DateTime<Utc>, t: DateTime<Utc>) -> f64 {
+    (t - t0).num_microseconds().unwrap() as f64 / 3.6e9
+}
+
+#[test]
+fn test_find_zero_linear() {
+    let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let f = |t| hours_since(t0, t) - 3.25;
+    let root = find_zero(f, t0, t0 + Duration::hours(10), Duration::milliseconds(1))
+        .unwrap()
+        .unwrap();
+    let expected = t0 + Duration::minutes(195);
+    assert!((root - expected).num_milliseconds().abs() <= 1);
+}
+
+#[test]
+fn test_find_zero_nonlinear() {
+    let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    // Root of x^3 - 2 at x = 2^(1/3) hours
+    let f = |t| hours_since(t0, t).powi(3) - 2.0;
+    let root = find_zero(f, t0, t0 + Duration::hours(5), Duration::microseconds(100))
+        .unwrap()
+        .unwrap();
+    let expected_h = 2.0_f64.cbrt();
+    assert!((hours_since(t0, root) - expected_h).abs() * 3600.0 < 1e-3);
+}
+
+#[test]
+fn test_find_zero_no_bracket() {
+    let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let result = find_zero(|_| 1.0, t0, t0 + Duration::hours(1), Duration::seconds(1)).unwrap();
+    assert!(result.is_none());
+}
+
+#[test]
+fn test_find_zero_invalid_arguments() {
+    let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let reversed = find_zero(|_| 1.0, t0, t0 - Duration::hours(1), Duration::seconds(1));
+    assert!(matches!(reversed, Err(AstroError::InvalidDateTime { .. })));
+
+    let bad_tol = find_zero(|_| 1.0, t0, t0 + Duration::hours(1), Duration::zero());
+    assert!(matches!(bad_tol, Err(AstroError::OutOfRange { parameter: "tolerance", .. })));
+
+    let bad_step = find_zeros(|_| 1.0, t0, t0 + Duration::hours(1), Duration::zero(), Duration::seconds(1));
+    assert!(matches!(bad_step, Err(AstroError::OutOfRange { parameter: "step", .. })));
+}
+
+#[test]
+fn test_find_zeros_directions() {
+    let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let f = |t| (hours_since(t0, t) * std::f64::consts::PI / 4.0).cos();
+    // cos has roots at 2h (falling), 6h (rising), 10h (falling)
+    let roots = find_zeros(f, t0, t0 + Duration::hours(11), Duration::minutes(20), Duration::milliseconds(10))
+        .unwrap();
+    assert_eq!(roots.len(), 3);
+    let expected = [(2.0, Crossing::Falling), (6.0, Crossing::Rising), (10.0, Crossing::Falling)];
+    for ((t, dir), (h, exp_dir)) in roots.iter().zip(expected.iter()) {
+        assert!((hours_since(t0, *t) - h).abs() * 3600.0 < 0.1);
+        assert_eq!(dir, exp_dir);
+    }
+}
+
+#[test]
+fn test_find_extremum() {
+    let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let f = |t| (hours_since(t0, t) - 4.5).powi(2) + 1.0;
+
+    let (t_min, v_min) = find_extremum(f, t0, t0 + Duration::hours(10), Duration::milliseconds(10), Extremum::Minimum)
+        .unwrap();
+    assert!((hours_since(t0, t_min) - 4.5).abs() * 3600.0 < 0.1);
+    assert!((v_min - 1.0).abs() < 1e-9);
+
+    // Maximum of a convex function on an interval is at a boundary
+    let (t_max, _) = find_extremum(f, t0, t0 + Duration::hours(10), Duration::seconds(1), Extremum::Maximum)
+        .unwrap();
+    assert_eq!(t_max, t0 + Duration::hours(10));
+}
+
+#[test]
+fn test_find_extremum_transit() {
+    // Maximum altitude of a star is at upper culmination
+    let loc = Location { latitude_deg: 35.0, longitude_deg: 10.0, altitude_m: 0.0 };
+    let t0 = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let dec = 20.0;
+    let altitude = |t| ra_dec_to_alt_az(150.0, dec, t, &loc).unwrap().0;
+    let (t_max, alt_max) = find_extremum(altitude, t0, t0 + Duration::hours(23), Duration::seconds(1), Extremum::Maximum)
+        .unwrap();
+    assert!((alt_max - (90.0 - (35.0 - dec))).abs() < 1e-3);
+
+    // Hour angle should be zero at that time
+    let lst = loc.local_sidereal_time(t_max);
+    let ha = ((lst * 15.0 - 150.0 + 540.0) % 360.0) - 180.0;
+    assert!(ha.abs() < 0.01, "hour angle at culmination {}", ha);
+}
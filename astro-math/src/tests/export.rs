@@ -0,0 +1,134 @@
+use crate::events::Crossing;
+use crate::export::*;
+use crate::Location;
+use chrono::{Duration, TimeZone, Utc};
+
+fn location() -> Location {
+    Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 }
+}
+
+#[test]
+fn test_visibility_curve_and_rendering() {
+    let start = Utc.with_ymd_and_hms(2024, 10, 1, 12, 0, 0).unwrap();
+    let curve = visibility_curve(10.6847, 41.2689, start, start + Duration::hours(24), Duration::hours(1), &location()).unwrap();
+    assert_eq!(curve.len(), 25);
+    assert!(curve.iter().all(|s| s.airmass.is_some() == (s.altitude_deg > 0.0)));
+    assert!(curve.iter().any(|s| s.airmass.is_none()));
+
+    let csv = visibility_to_csv(&curve);
+    let lines: Vec<&str> = csv.split("\r\n").filter(|l| !l.is_empty()).collect();
+    assert_eq!(lines[0], "time,altitude_deg,azimuth_deg,airmass");
+    assert_eq!(lines.len(), 26);
+    assert!(lines[1].starts_with("2024-10-01T12:00:00Z,"));
+    // Below-horizon rows have an empty airmass column
+    let below = curve.iter().position(|s| s.airmass.is_none()).unwrap();
+    assert!(lines[below + 1].ends_with(','));
+
+    let json = visibility_to_json(&curve);
+    assert!(json.starts_with("[{\"time\":\"2024-10-01T12:00:00Z\",\"altitude_deg\":"));
+    assert!(json.ends_with("}]"));
+    assert!(json.contains("\"airmass\":null"));
+    assert_eq!(json.matches("\"time\"").count(), 25);
+}
+
+#[test]
+fn test_visibility_curve_errors() {
+    let start = Utc.with_ymd_and_hms(2024, 10, 1, 12, 0, 0).unwrap();
+    let end = start + Duration::hours(1);
+    assert!(visibility_curve(10.0, 41.0, start, end, Duration::zero(), &location()).is_err());
+    assert!(visibility_curve(400.0, 41.0, start, end, Duration::minutes(5), &location()).is_err());
+    assert!(visibility_curve(10.0, 41.0, end, start, Duration::minutes(5), &location()).unwrap().is_empty());
+}
+
+#[test]
+fn test_rise_set_table() {
+    let start = Utc.with_ymd_and_hms(2024, 10, 1, 15, 30, 0).unwrap();
+    let table = rise_set_table(10.6847, 41.2689, start, 3, &location()).unwrap();
+    assert_eq!(table.len(), 3);
+    assert_eq!(table[0].date, Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap());
+    assert_eq!(table[2].date, Utc.with_ymd_and_hms(2024, 10, 3, 0, 0, 0).unwrap());
+    assert!(table.iter().all(|row| row.rise.is_some() && row.set.is_some()));
+
+    let csv = rise_set_to_csv(&table);
+    assert!(csv.contains("\r\n2024-10-02,2024-10-"));
+
+    // Polaris never sets from 40°N
+    let polaris = rise_set_table(37.95, 89.26, start, 2, &location()).unwrap();
+    assert!(polaris.iter().all(|row| row.rise.is_none() && row.transit.is_none()));
+    assert_eq!(rise_set_to_csv(&polaris).lines().nth(1), Some("2024-10-01,,,"));
+    assert_eq!(
+        rise_set_to_json(&polaris[..1]),
+        "[{\"date\":\"2024-10-01\",\"rise\":null,\"transit\":null,\"set\":null}]"
+    );
+    assert!(polaris[0].events("Polaris").is_empty());
+
+    assert!(rise_set_table(10.0, 95.0, start, 1, &location()).is_err());
+}
+
+#[test]
+fn test_event_csv_and_json_escaping() {
+    let t = Utc.with_ymd_and_hms(2024, 10, 1, 23, 35, 0).unwrap();
+    let events = vec![
+        AstroEvent::new(EventKind::Twilight, t, "Dusk, \"astronomical\""),
+        AstroEvent { kind: EventKind::MeridianFlip, start: t, end: Some(t + Duration::minutes(5)), summary: "Flip\nM31".into() },
+    ];
+
+    let csv = events_to_csv(&events);
+    assert_eq!(
+        csv,
+        "kind,start,end,summary\r\n\
+         twilight,2024-10-01T23:35:00Z,,\"Dusk, \"\"astronomical\"\"\"\r\n\
+         meridian_flip,2024-10-01T23:35:00Z,2024-10-01T23:40:00Z,\"Flip\nM31\"\r\n"
+    );
+
+    let json = events_to_json(&events);
+    assert_eq!(
+        json,
+        "[{\"kind\":\"twilight\",\"start\":\"2024-10-01T23:35:00Z\",\"end\":null,\"summary\":\"Dusk, \\\"astronomical\\\"\"},\
+         {\"kind\":\"meridian_flip\",\"start\":\"2024-10-01T23:35:00Z\",\"end\":\"2024-10-01T23:40:00Z\",\"summary\":\"Flip\\nM31\"}]"
+    );
+}
+
+#[test]
+fn test_events_from_crossings() {
+    let t = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+    let events = events_from_crossings("Vega", &[(t, Crossing::Falling), (t + Duration::hours(10), Crossing::Rising)]);
+    assert_eq!(events.len(), 2);
+    assert_eq!((events[0].kind, events[0].summary.as_str()), (EventKind::Set, "Vega set"));
+    assert_eq!((events[1].kind, events[1].summary.as_str()), (EventKind::Rise, "Vega rise"));
+}
+
+#[test]
+fn test_ics_structure() {
+    let start = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+    let table = rise_set_table(10.6847, 41.2689, start, 2, &location()).unwrap();
+    let mut events: Vec<AstroEvent> = table.iter().flat_map(|row| row.events("M31")).collect();
+    events.push(AstroEvent {
+        kind: EventKind::Other,
+        start,
+        end: Some(start + Duration::hours(1)),
+        summary: format!("Run; targets: {}", ["M31"; 20].join(", ")),
+    });
+
+    let ics = events_to_ics(&events, "Backyard, 2024");
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert!(ics.contains("X-WR-CALNAME:Backyard\\, 2024\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 7);
+    assert_eq!(ics.matches("CATEGORIES:TRANSIT").count(), 2);
+    assert!(ics.contains("DTEND:20241001T010000Z\r\n"));
+
+    // Every line ends in CRLF and is at most 75 octets; long lines are folded
+    assert!(!ics.replace("\r\n", "").contains('\n'));
+    assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+    let unfolded = ics.replace("\r\n ", "");
+    assert!(unfolded.contains(&format!("SUMMARY:Run\\; targets: {}\r\n", ["M31"; 20].join("\\, "))));
+    assert!(unfolded.len() < ics.len());
+
+    // UIDs are unique
+    let uids: Vec<&str> = ics.split("\r\n").filter(|l| l.starts_with("UID:")).collect();
+    let mut unique = uids.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(uids.len(), unique.len());
+}
@@ -0,0 +1,127 @@
+use crate::error::AstroError;
+use crate::fixed_site::*;
+use crate::parallax::{diurnal_parallax, geocentric_distance};
+use crate::refraction::{apparent_to_true_altitude, refraction_saemundsson, true_to_apparent_altitude, AtmosphericConditions};
+use crate::time::julian_date_two_part;
+use crate::transforms::{alt_az_to_ra_dec, ra_dec_to_alt_az, ra_dec_to_alt_az_two_part};
+use crate::Location;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn sites() -> Vec<Location> {
+    vec![
+        Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 },
+        Location { latitude_deg: -30.24, longitude_deg: -70.74, altitude_m: 2715.0 },
+        Location { latitude_deg: 78.2, longitude_deg: 15.6, altitude_m: 0.0 },
+        Location { latitude_deg: 0.0, longitude_deg: 180.0, altitude_m: -30.0 },
+    ]
+}
+
+fn epoch() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap()
+}
+
+#[test]
+fn test_transforms_match_free_functions() {
+    let targets = [(0.0, 0.0), (83.82, -5.39), (201.37, -11.16), (279.23, 38.78), (37.95, 89.26)];
+    for location in sites() {
+        let site = FixedSite::new(location).unwrap();
+        for hours in [0, 5, 13] {
+            let dt = epoch() + Duration::hours(hours);
+            let (jd1, jd2) = julian_date_two_part(dt);
+            let batch = site.ra_dec_to_alt_az_batch(&targets, dt).unwrap();
+            for (&(ra, dec), &alt_az) in targets.iter().zip(&batch) {
+                let expected = ra_dec_to_alt_az(ra, dec, dt, &location).unwrap();
+                assert_eq!(site.ra_dec_to_alt_az(ra, dec, dt).unwrap(), expected);
+                assert_eq!(alt_az, expected);
+                assert_eq!(
+                    site.ra_dec_to_alt_az_two_part(ra, dec, jd1, jd2).unwrap(),
+                    ra_dec_to_alt_az_two_part(ra, dec, jd1, jd2, &location).unwrap()
+                );
+
+                let (alt, az) = expected;
+                assert_eq!(site.alt_az_to_ra_dec(alt, az, dt).unwrap(), alt_az_to_ra_dec(alt, az, dt, &location).unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_geodetic_factors() {
+    for location in sites() {
+        let site = FixedSite::new(location).unwrap();
+        let lat = location.latitude_deg.to_radians();
+        assert_eq!(site.sin_latitude(), lat.sin());
+        assert_eq!(site.cos_latitude(), lat.cos());
+        assert!((site.geocentric_distance() - geocentric_distance(&location)).abs() < 1e-15);
+        assert_eq!(
+            site.diurnal_parallax(45.0, 20.0, 0.00257, epoch()).unwrap(),
+            diurnal_parallax(45.0, 20.0, 0.00257, epoch(), &location).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_refraction_matches_saemundsson() {
+    let location = sites()[0];
+    let standard = FixedSite::new(location).unwrap();
+    let conditions = AtmosphericConditions { pressure_hpa: 790.0, temperature_c: -5.0, relative_humidity: 0.1 };
+    let thin = standard.with_conditions(conditions).unwrap();
+    assert_eq!(thin.conditions(), &conditions);
+
+    for altitude in [-5.0, -0.5, 0.0, 5.0, 20.0, 60.0, 90.0] {
+        for (site, p, t) in [(standard, 1013.25, 10.0), (thin, 790.0, -5.0)] {
+            let expected = refraction_saemundsson(altitude, p, t).unwrap();
+            assert!((site.refraction(altitude).unwrap() - expected).abs() < 1e-15);
+            assert!((site.true_altitude(altitude).unwrap() - apparent_to_true_altitude(altitude, p, t).unwrap()).abs() < 1e-12);
+            assert!((site.apparent_altitude(altitude).unwrap() - true_to_apparent_altitude(altitude, p, t).unwrap()).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_invalid_inputs() {
+    let bad_latitude = Location { latitude_deg: 91.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(matches!(FixedSite::new(bad_latitude), Err(AstroError::OutOfRange { .. })));
+    let bad_altitude = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: f64::NAN };
+    assert!(matches!(FixedSite::new(bad_altitude), Err(AstroError::OutOfRange { .. })));
+
+    let site = FixedSite::new(sites()[0]).unwrap();
+    let humid = AtmosphericConditions { relative_humidity: 1.5, ..AtmosphericConditions::standard() };
+    assert!(site.with_conditions(humid).is_err());
+    assert!(matches!(site.ra_dec_to_alt_az(360.0, 0.0, epoch()), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(
+        site.ra_dec_to_alt_az_batch(&[(10.0, 0.0), (10.0, 95.0)], epoch()),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(site.alt_az_to_ra_dec(45.0, 360.0, epoch()), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(site.diurnal_parallax(10.0, 0.0, 0.0, epoch()), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(site.refraction(91.0), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_radian_methods_match_degrees() {
+    let targets: Vec<(f64, f64)> = (0..36).map(|k| (k as f64 * 10.0, -85.0 + k as f64 * 5.0)).collect();
+    let targets_rad: Vec<(f64, f64)> = targets.iter().map(|&(ra, dec)| (ra.to_radians(), dec.to_radians())).collect();
+    for location in sites() {
+        let site = FixedSite::new(location).unwrap();
+        let degrees = site.ra_dec_to_alt_az_batch(&targets, epoch()).unwrap();
+        let radians = site.ra_dec_to_alt_az_batch_rad(&targets_rad, epoch()).unwrap();
+        for (k, (&(alt, az), &(alt_rad, az_rad))) in degrees.iter().zip(&radians).enumerate() {
+            assert!((alt_rad.to_degrees() - alt).abs() < 1e-9, "{}", k);
+            let daz = (az_rad.to_degrees() - az + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(daz.abs() < 1e-9, "{} {} {}", k, az, az_rad.to_degrees());
+            assert_eq!((alt_rad, az_rad), site.ra_dec_to_alt_az_rad(targets_rad[k].0, targets_rad[k].1, epoch()).unwrap());
+        }
+
+        let (ra, dec) = site.alt_az_to_ra_dec(35.0, 250.0, epoch()).unwrap();
+        let (ra_rad, dec_rad) = site.alt_az_to_ra_dec_rad(35f64.to_radians(), 250f64.to_radians(), epoch()).unwrap();
+        assert!((ra_rad.to_degrees() - ra).abs() < 1e-9 && (dec_rad.to_degrees() - dec).abs() < 1e-9);
+    }
+
+    let site = FixedSite::new(sites()[0]).unwrap();
+    assert!(matches!(
+        site.ra_dec_to_alt_az_batch_rad(&[(1.0, 0.0), (1.0, 1.6)], epoch()),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(site.alt_az_to_ra_dec_rad(0.5, -0.1, epoch()), Err(AstroError::InvalidCoordinate { .. })));
+}
@@ -0,0 +1,74 @@
+use crate::error::AstroError;
+use crate::focus::*;
+
+#[test]
+fn test_linear_fit_recovers_exact_model() {
+    let samples: Vec<(f64, f64)> = [-5.0, 0.0, 3.5, 12.0, 20.0]
+        .iter()
+        .map(|&t| (t, 8000.0 - 18.0 * t))
+        .collect();
+    let model = FocusModel::fit(&samples, FocusModelKind::Linear).unwrap();
+    assert_eq!(model.kind(), FocusModelKind::Linear);
+    assert_eq!(model.sample_count(), 5);
+    assert!((model.reference_temperature() - 6.1).abs() < 1e-12);
+    assert_eq!(model.coefficients()[2], 0.0);
+    assert!(model.rms() < 1e-9);
+    assert!((model.position_at(10.0) - 7820.0).abs() < 1e-9);
+    assert!((model.slope_at(-30.0) + 18.0).abs() < 1e-9);
+    assert!((model.offset(10.0, 5.0) - 90.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_quadratic_fit_and_slope() {
+    let truth = |t: f64| 5000.0 + 12.0 * t - 0.3 * t * t;
+    let samples: Vec<(f64, f64)> = (0..8).map(|k| -10.0 + 4.0 * k as f64).map(|t| (t, truth(t))).collect();
+    let model = FocusModel::fit(&samples, FocusModelKind::Quadratic).unwrap();
+    assert!(model.rms() < 1e-8);
+    for t in [-12.0, 0.0, 7.3, 25.0] {
+        assert!((model.position_at(t) - truth(t)).abs() < 1e-8, "{}", t);
+        assert!((model.slope_at(t) - (12.0 - 0.6 * t)).abs() < 1e-9, "{}", t);
+    }
+    assert!((model.offset(0.0, 20.0) - (truth(20.0) - truth(0.0))).abs() < 1e-8);
+
+    // A straight line through curved data leaves residuals
+    let linear = FocusModel::fit(&samples, FocusModelKind::Linear).unwrap();
+    assert!(linear.rms() > 1.0);
+}
+
+#[test]
+fn test_noisy_samples_rms() {
+    // ±2 steps alternating about a line
+    let samples: Vec<(f64, f64)> = (0..10)
+        .map(|k| {
+            let t = k as f64;
+            (t, 1000.0 - 5.0 * t + if k % 2 == 0 { 2.0 } else { -2.0 })
+        })
+        .collect();
+    let model = FocusModel::fit(&samples, FocusModelKind::Linear).unwrap();
+    assert!((model.slope_at(0.0) + 5.0).abs() < 0.2);
+    assert!(model.rms() > 1.5 && model.rms() <= 2.0, "{}", model.rms());
+}
+
+#[test]
+fn test_invalid_samples() {
+    let same_temperature = [(10.0, 100.0), (10.0, 110.0), (10.0, 105.0)];
+    assert!(matches!(
+        FocusModel::fit(&same_temperature, FocusModelKind::Linear),
+        Err(AstroError::CalculationError { .. })
+    ));
+    let two = [(0.0, 100.0), (5.0, 90.0), (5.0, 91.0)];
+    assert!(FocusModel::fit(&two, FocusModelKind::Linear).is_ok());
+    assert!(matches!(
+        FocusModel::fit(&two, FocusModelKind::Quadratic),
+        Err(AstroError::CalculationError { .. })
+    ));
+    assert!(matches!(FocusModel::fit(&[], FocusModelKind::Linear), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(
+        FocusModel::fit(&[(0.0, 1.0), (f64::NAN, 2.0)], FocusModelKind::Linear),
+        Err(AstroError::CalculationError { .. })
+    ));
+    assert!(matches!(
+        FocusModel::fit(&[(0.0, 1.0), (1.0, f64::INFINITY)], FocusModelKind::Linear),
+        Err(AstroError::CalculationError { .. })
+    ));
+}
@@ -0,0 +1,94 @@
+use crate::error::AstroError;
+use crate::great_circle::*;
+use crate::vec3::{angle_between, radec_to_unit_vector};
+
+fn separation(a: (f64, f64), b: (f64, f64)) -> f64 {
+    angle_between(radec_to_unit_vector(a.0, a.1), radec_to_unit_vector(b.0, b.1)).to_degrees()
+}
+
+#[test]
+fn test_even_spacing_on_the_arc() {
+    let (a, b) = ((279.2347, 38.7837), (297.6958, 8.8683));
+    let total = separation(a, b);
+    let path = waypoints(a, b, 11).unwrap();
+    for (k, &p) in path.iter().enumerate() {
+        let along = separation(a, p);
+        assert!((along - total * k as f64 / 10.0).abs() < 1e-9, "{} {}", k, along);
+        // On the great circle: the two legs add up to the whole
+        assert!((along + separation(p, b) - total).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_interpolate_matches_waypoints() {
+    let (a, b) = ((10.0, -20.0), (40.0, 30.0));
+    let path = waypoints(a, b, 5).unwrap();
+    for (k, &p) in path.iter().enumerate() {
+        let q = interpolate(a, b, k as f64 / 4.0).unwrap();
+        assert!((p.0 - q.0).abs() < 1e-9 && (p.1 - q.1).abs() < 1e-9);
+    }
+    // Beyond the ends, along the same circle
+    let past = interpolate(a, b, 1.5).unwrap();
+    assert!((separation(a, past) - 1.5 * separation(a, b)).abs() < 1e-9);
+    let before = interpolate(a, b, -0.5).unwrap();
+    assert!((separation(before, b) - 1.5 * separation(a, b)).abs() < 1e-9);
+}
+
+#[test]
+fn test_poles_and_wrap() {
+    // Through the pole
+    let path = waypoints((0.0, 80.0), (180.0, 80.0), 5).unwrap();
+    assert!((path[2].1 - 90.0).abs() < 1e-9);
+    assert!((path[1].0 - 0.0).abs() < 1e-9 && (path[1].1 - 85.0).abs() < 1e-9);
+    assert!((path[3].0 - 180.0).abs() < 1e-9 && (path[3].1 - 85.0).abs() < 1e-9);
+
+    // From the pole itself
+    let (ra, dec) = interpolate((0.0, -90.0), (45.0, 0.0), 0.5).unwrap();
+    assert!((ra - 45.0).abs() < 1e-9 && (dec + 45.0).abs() < 1e-9);
+
+    // Across RA = 0 stays in range
+    for &(ra, _) in &waypoints((359.0, 1.0), (1.0, -1.0), 9).unwrap() {
+        assert!((0.0..360.0).contains(&ra) && !(2.0..358.0).contains(&ra), "{}", ra);
+    }
+}
+
+#[test]
+fn test_coincident_and_nearby() {
+    let a = (123.0, 45.0);
+    assert_eq!(waypoints(a, a, 4).unwrap().len(), 4);
+    for p in waypoints(a, a, 4).unwrap() {
+        assert!(separation(a, p) < 1e-9);
+    }
+    // 1 mas apart
+    let b = (123.0, 45.0 + 1.0 / 3.6e6);
+    let mid = interpolate(a, b, 0.5).unwrap();
+    assert!((mid.1 - (45.0 + 0.5 / 3.6e6)).abs() < 1e-12);
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert!(matches!(
+        interpolate((0.0, 0.0), (180.0, 0.0), 0.5),
+        Err(AstroError::CalculationError { .. })
+    ));
+    assert!(matches!(
+        waypoints((10.0, 90.0), (200.0, -90.0), 3),
+        Err(AstroError::CalculationError { .. })
+    ));
+    assert!(matches!(
+        interpolate((0.0, 0.0), (10.0, 0.0), f64::NAN),
+        Err(AstroError::OutOfRange { parameter: "fraction", .. })
+    ));
+    assert!(matches!(
+        waypoints((0.0, 0.0), (10.0, 0.0), 1),
+        Err(AstroError::OutOfRange { parameter: "n", .. })
+    ));
+    assert!(matches!(
+        waypoints((0.0, 0.0), (360.0, 0.0), 3),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(
+        interpolate((0.0, 91.0), (10.0, 0.0), 0.5),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+}
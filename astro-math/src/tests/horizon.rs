@@ -0,0 +1,111 @@
+use crate::error::AstroError;
+use crate::horizon::*;
+
+#[test]
+fn test_interpolation_and_wrap() {
+    let horizon = Horizon::new(&[(350.0, 10.0), (10.0, 30.0), (180.0, 0.0)]).unwrap();
+    assert_eq!(horizon.points()[0], (10.0, 30.0));
+    assert_eq!(horizon.altitude_at(10.0), 30.0);
+    assert_eq!(horizon.altitude_at(95.0), 15.0);
+    // Across north
+    assert_eq!(horizon.altitude_at(0.0), 20.0);
+    assert_eq!(horizon.altitude_at(355.0), 15.0);
+    assert_eq!(horizon.altitude_at(-5.0), 15.0);
+    assert_eq!(horizon.altitude_at(720.0), 20.0);
+    assert!((horizon.altitude_at(-1e-20) - 20.0).abs() < 1e-9);
+
+    assert!(horizon.is_visible(20.1, 0.0));
+    assert!(!horizon.is_visible(20.0, 0.0));
+
+    let flat = Horizon::flat(-0.5).unwrap();
+    for az in [0.0, 123.0, 359.9] {
+        assert_eq!(flat.altitude_at(az), -0.5);
+    }
+}
+
+#[test]
+fn test_vertical_step() {
+    // A wall from 90° to 100° azimuth
+    let horizon = Horizon::new(&[(0.0, 0.0), (90.0, 0.0), (90.0, 40.0), (100.0, 40.0), (100.0, 0.0)]).unwrap();
+    assert!(horizon.altitude_at(89.999) < 0.01);
+    assert_eq!(horizon.altitude_at(90.0), 40.0);
+    assert_eq!(horizon.altitude_at(95.0), 40.0);
+    assert!(horizon.altitude_at(100.001) < 0.01);
+    assert_eq!(horizon.altitude_at(200.0), 0.0);
+}
+
+#[test]
+fn test_csv() {
+    let csv = "az,alt\r\n0,5\r\n90,15\r\n\r\n# tree\r\n180,25,extra\r\n";
+    let horizon = Horizon::from_csv_str(csv).unwrap();
+    assert_eq!(horizon.points(), &[(0.0, 5.0), (90.0, 15.0), (180.0, 25.0)]);
+    // Headerless
+    assert_eq!(Horizon::from_csv_str(" 0 , 5\n90,15").unwrap().points().len(), 2);
+
+    assert!(matches!(
+        Horizon::from_csv_str("az,alt\n0,5\nninety,15\n"),
+        Err(AstroError::InvalidFormat { input, .. }) if input == "ninety,15"
+    ));
+    assert!(matches!(Horizon::from_csv_str("az,alt\n"), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(
+        Horizon::from_csv_str("0,95\n"),
+        Err(AstroError::OutOfRange { parameter: "altitude", .. })
+    ));
+}
+
+#[test]
+fn test_hrz() {
+    let hrz = "; Cartes du Ciel horizon\n0\t3.5\n  45   7\n90 12.25\n";
+    let horizon = Horizon::from_hrz_str(hrz).unwrap();
+    assert_eq!(horizon.points(), &[(0.0, 3.5), (45.0, 7.0), (90.0, 12.25)]);
+    assert!(matches!(
+        Horizon::from_hrz_str("0 3\n45\n"),
+        Err(AstroError::InvalidFormat { format: "Cartes du Ciel horizon", .. })
+    ));
+    assert!(Horizon::from_hrz_str("0,3\n").is_err());
+}
+
+#[test]
+fn test_stellarium() {
+    let list = "# horizon\n0 10\n90, 20\n180\t30\n270 20\n";
+    let plain = Horizon::from_stellarium_str(list, None).unwrap();
+    assert_eq!(plain.altitude_at(90.0), 20.0);
+
+    let ini = "[location]\npolygonal_angle_rotatez = 45\n\n[landscape]\nname = Test\npolygonal_horizon_list_mode = azDeg_altDeg\npolygonal_angle_rotatez = -90\n";
+    let rotated = Horizon::from_stellarium_str(list, Some(ini)).unwrap();
+    // Rotation from [landscape] only
+    assert_eq!(rotated.altitude_at(90.0), 30.0);
+    assert_eq!(rotated.altitude_at(270.0), 10.0);
+
+    let radians = "0 1.3962634015954636\n3.141592653589793 1.0471975511965976\n";
+    let ini = "[landscape]\npolygonal_horizon_list_mode = azRad_zdRad\n";
+    let horizon = Horizon::from_stellarium_str(radians, Some(ini)).unwrap();
+    assert!((horizon.altitude_at(0.0) - 10.0).abs() < 1e-9);
+    assert!((horizon.altitude_at(180.0) - 30.0).abs() < 1e-9);
+
+    let grads = "100 20\n";
+    let ini = "[landscape]\npolygonal_horizon_list_mode = azGrad_altGrad\n";
+    let horizon = Horizon::from_stellarium_str(grads, Some(ini)).unwrap();
+    assert_eq!(horizon.points(), &[(90.0, 18.0)]);
+
+    assert!(matches!(
+        Horizon::from_stellarium_str(list, Some("[landscape]\npolygonal_horizon_list_mode = azHours_altDeg\n")),
+        Err(AstroError::InvalidFormat { format: "Stellarium landscape", .. })
+    ));
+    assert!(matches!(
+        Horizon::from_stellarium_str(list, Some("[landscape]\npolygonal_angle_rotatez = east\n")),
+        Err(AstroError::InvalidFormat { .. })
+    ));
+    assert_eq!("azDeg_zdDeg".parse::<StellariumHorizonMode>().unwrap(), StellariumHorizonMode::AzDegZdDeg);
+}
+
+#[test]
+fn test_invalid_points() {
+    assert!(matches!(Horizon::new(&[]), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(
+        Horizon::new(&[(f64::NAN, 0.0)]),
+        Err(AstroError::OutOfRange { parameter: "azimuth", .. })
+    ));
+    assert!(Horizon::new(&[(0.0, f64::NAN)]).is_err());
+    assert!(Horizon::flat(91.0).is_err());
+}
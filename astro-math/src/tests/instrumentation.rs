@@ -0,0 +1,86 @@
+use crate::events::find_zeros;
+use crate::location::Location;
+use crate::refraction::true_to_apparent_altitude;
+use crate::transforms::{ra_dec_to_alt_az, ra_dec_to_alt_az_batch_parallel};
+use chrono::{Duration, TimeZone, Utc};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Records span names and event field names.
+#[derive(Default)]
+struct Recorder {
+    next_id: AtomicU64,
+    spans: Mutex<Vec<String>>,
+    fields: Mutex<Vec<String>>,
+}
+
+struct FieldNames<'a>(&'a mut Vec<String>);
+
+impl Visit for FieldNames<'_> {
+    fn record_debug(&mut self, field: &Field, _value: &dyn std::fmt::Debug) {
+        self.0.push(field.name().to_string());
+    }
+}
+
+#[derive(Clone)]
+struct Handle(Arc<Recorder>);
+
+impl Subscriber for Handle {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.0.spans.lock().unwrap().push(span.metadata().name().to_string());
+        Id::from_u64(self.0.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut FieldNames(&mut self.0.fields.lock().unwrap()));
+    }
+    fn enter(&self, _: &Id) {}
+    fn exit(&self, _: &Id) {}
+}
+
+fn record(f: impl FnOnce()) -> Arc<Recorder> {
+    let recorder = Arc::new(Recorder::default());
+    tracing::subscriber::with_default(Handle(recorder.clone()), f);
+    recorder
+}
+
+#[test]
+fn test_hour_angle_traced() {
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+    let recorder = record(|| {
+        ra_dec_to_alt_az(279.23473479, 38.78368896, dt, &loc).unwrap();
+    });
+    let fields = recorder.fields.lock().unwrap();
+    assert!(fields.iter().any(|f| f == "lst_hours"));
+    assert!(fields.iter().any(|f| f == "ha_hours"));
+}
+
+#[test]
+fn test_spans_around_expensive_operations() {
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+    let recorder = record(|| {
+        ra_dec_to_alt_az_batch_parallel(&[(10.0, 20.0)], dt, &loc, None, None, None).unwrap();
+        find_zeros(|t| (t - dt).num_seconds() as f64 - 90.0, dt, dt + Duration::hours(1),
+                   Duration::minutes(10), Duration::seconds(1)).unwrap();
+    });
+    let spans = recorder.spans.lock().unwrap();
+    assert!(spans.iter().any(|s| s == "ra_dec_to_alt_az_batch_parallel"));
+    assert!(spans.iter().any(|s| s == "find_zeros"));
+}
+
+#[test]
+fn test_refraction_traced() {
+    let recorder = record(|| {
+        true_to_apparent_altitude(10.0, 1013.25, 10.0).unwrap();
+    });
+    assert!(recorder.fields.lock().unwrap().iter().any(|f| f == "refraction_arcsec"));
+}
@@ -0,0 +1,104 @@
+use crate::error::AstroError;
+use crate::interference::*;
+use crate::moon::moon_equatorial;
+use crate::Location;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn site() -> Location {
+    Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 }
+}
+
+fn night(year: i32, month: u32, day: u32) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start = Utc.with_ymd_and_hms(year, month, day, 2, 0, 0).unwrap();
+    (start, start + Duration::hours(10))
+}
+
+const M31: (f64, f64) = (10.6847, 41.2689);
+
+#[test]
+fn test_hourly_samples() {
+    let (start, end) = night(2024, 10, 3);
+    let hours = interference_forecast(M31, (start, end), &site()).unwrap();
+    assert_eq!(hours.len(), 11);
+    assert_eq!(hours[0].time, start);
+    assert_eq!(hours[10].time, end);
+    for pair in hours.windows(2) {
+        assert_eq!(pair[1].time - pair[0].time, Duration::hours(1));
+    }
+
+    // A partial last hour is not sampled
+    let short = interference_forecast(M31, (start, start + Duration::minutes(90)), &site()).unwrap();
+    assert_eq!(short.len(), 2);
+    let single = interference_forecast(M31, (start, start), &site()).unwrap();
+    assert_eq!(single.len(), 1);
+}
+
+#[test]
+fn test_sky_estimate_only_when_dark_and_up() {
+    for hour in interference_forecast(M31, night(2024, 10, 17), &site()).unwrap() {
+        assert_eq!(hour.sky_estimate.is_some(), hour.target_alt > 0.0 && hour.sun_alt < -18.0, "{:?}", hour);
+        if let Some(sky) = hour.sky_estimate {
+            assert!(sky > 15.0 && sky < 22.0, "{:?}", hour);
+        }
+    }
+}
+
+#[test]
+fn test_moon_brightens_sky() {
+    // New Moon (2024-10-02) versus full Moon (2024-10-17), same target and hour
+    let dark = interference_forecast(M31, night(2024, 10, 3), &site()).unwrap();
+    let bright = interference_forecast(M31, night(2024, 10, 17), &site()).unwrap();
+    assert!(dark.iter().all(|h| h.moon_illumination < 0.05));
+    assert!(bright.iter().all(|h| h.moon_illumination > 0.95));
+
+    let mut compared = 0;
+    for (d, b) in dark.iter().zip(&bright) {
+        if let (Some(d_sky), Some(b_sky)) = (d.sky_estimate, b.sky_estimate) {
+            if b.moon_alt > 20.0 {
+                assert!(d_sky - b_sky > 1.5, "{:?} {:?}", d, b);
+                compared += 1;
+            }
+        }
+    }
+    assert!(compared > 0);
+
+    // Near the zenith with no Moon, the sky is close to the dark-sky value
+    let darkest = dark.iter().filter(|h| h.moon_alt < 0.0).filter_map(|h| h.sky_estimate).fold(0.0, f64::max);
+    assert!((darkest - 21.7).abs() < 0.3, "{}", darkest);
+}
+
+#[test]
+fn test_moon_separation_shrinks_near_moon() {
+    let time = Utc.with_ymd_and_hms(2024, 10, 17, 8, 0, 0).unwrap();
+    let (ra, dec) = moon_equatorial(time);
+    let near = interference_forecast((ra, dec), (time, time), &site()).unwrap()[0];
+    // Only topocentric parallax (< 1°) separates the target from the Moon
+    assert!(near.moon_sep < 1.1, "{:?}", near);
+    let far = interference_forecast(((ra + 180.0) % 360.0, -dec), (time, time), &site()).unwrap()[0];
+    assert!(far.moon_sep > 178.9, "{:?}", far);
+    if let (Some(near_sky), Some(far_sky)) = (near.sky_estimate, far.sky_estimate) {
+        assert!(near_sky < far_sky);
+    }
+}
+
+#[test]
+fn test_invalid_inputs() {
+    let (start, end) = night(2024, 10, 3);
+    assert!(matches!(
+        interference_forecast((360.0, 0.0), (start, end), &site()),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(
+        interference_forecast((0.0, 91.0), (start, end), &site()),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(
+        interference_forecast(M31, (end, start), &site()),
+        Err(AstroError::OutOfRange { parameter: "night", .. })
+    ));
+    let bad = Location { latitude_deg: 95.0, ..site() };
+    assert!(matches!(
+        interference_forecast(M31, (start, end), &bad),
+        Err(AstroError::OutOfRange { parameter: "latitude", .. })
+    ));
+}
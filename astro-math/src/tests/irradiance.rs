@@ -0,0 +1,87 @@
+use crate::error::AstroError;
+use crate::irradiance::*;
+use crate::location::Location;
+use chrono::{Duration, TimeZone, Utc};
+
+fn site(latitude_deg: f64, longitude_deg: f64, altitude_m: f64) -> Location {
+    Location { latitude_deg, longitude_deg, altitude_m }
+}
+
+#[test]
+fn test_clear_sky_components_are_consistent() {
+    let boulder = site(40.0, -105.25, 1650.0);
+    let start = Utc.with_ymd_and_hms(2024, 6, 21, 12, 0, 0).unwrap();
+    for hour in 0..14 {
+        let sky = clear_sky_irradiance(start + Duration::hours(hour), &boulder).unwrap();
+        if sky.elevation_deg <= 0.0 {
+            assert_eq!((sky.ghi, sky.dni, sky.dhi), (0.0, 0.0, 0.0));
+            continue;
+        }
+        let direct_horizontal = sky.dni * sky.elevation_deg.to_radians().sin();
+        assert!((sky.ghi - sky.dhi - direct_horizontal).abs() < 1e-9);
+        assert!(sky.dhi > 0.0);
+        assert!(sky.ghi < sky.extraterrestrial);
+    }
+}
+
+#[test]
+fn test_clear_sky_noon_values() {
+    // Boulder at the June solstice: clear-sky noon GHI is about
+    // 1000 W/m², with a direct beam of 900-950 W/m²
+    let boulder = site(40.0, -105.25, 1650.0);
+    let noon = Utc.with_ymd_and_hms(2024, 6, 21, 19, 0, 0).unwrap();
+    let sky = clear_sky_irradiance(noon, &boulder).unwrap();
+    assert!((sky.elevation_deg - 73.4).abs() < 0.5, "{}", sky.elevation_deg);
+    assert!((sky.ghi - 1000.0).abs() < 50.0, "{}", sky.ghi);
+    assert!((sky.dni - 920.0).abs() < 50.0, "{}", sky.dni);
+    // Extraterrestrial irradiance is near its aphelion minimum in July
+    let normal = sky.extraterrestrial / sky.elevation_deg.to_radians().sin();
+    assert!((normal - 1317.0).abs() < 3.0, "{normal}");
+
+    // Thinner air at the same Sun elevation lets more of the beam through
+    let sea_level = clear_sky_irradiance(noon, &site(40.0, -105.25, 0.0)).unwrap();
+    assert!(sea_level.dni < sky.dni);
+    let thick = ClearSkyAtmosphere { pressure_hpa: Some(1013.25), ..Default::default() };
+    let forced = clear_sky_irradiance_with_atmosphere(noon, &boulder, &thick).unwrap();
+    assert!((forced.dni - sea_level.dni).abs() < 1e-9);
+}
+
+#[test]
+fn test_daily_insolation() {
+    // Equator at the equinox: about 70% of the 10.4 kWh/m² at the top of
+    // the atmosphere reaches the ground
+    let quito_latitude = site(0.0, -78.5, 0.0);
+    let equinox = Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap();
+    let equator = daily_insolation(equinox, &quito_latitude).unwrap();
+    assert!(equator > 6.5 && equator < 8.0, "{equator}");
+
+    // The day is centred on solar noon, so far-east sites are not split
+    let fiji = daily_insolation(equinox, &site(0.0, 178.0, 0.0)).unwrap();
+    assert!((fiji - equator).abs() < 0.05, "{fiji} vs {equator}");
+
+    // Midnight sun: insolation all day at 70°N in June, none in December
+    let tromso = site(69.65, 18.96, 0.0);
+    let june = daily_insolation(Utc.with_ymd_and_hms(2024, 6, 21, 0, 0, 0).unwrap(), &tromso).unwrap();
+    let december = daily_insolation(Utc.with_ymd_and_hms(2024, 12, 21, 0, 0, 0).unwrap(), &tromso).unwrap();
+    assert!(june > 5.0, "{june}");
+    assert_eq!(december, 0.0);
+}
+
+#[test]
+fn test_irradiance_errors() {
+    let dt = Utc.with_ymd_and_hms(2024, 6, 21, 12, 0, 0).unwrap();
+    assert!(matches!(
+        clear_sky_irradiance(dt, &site(91.0, 0.0, 0.0)),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    let bright = ClearSkyAtmosphere { albedo: 1.5, ..Default::default() };
+    assert!(matches!(
+        clear_sky_irradiance_with_atmosphere(dt, &site(40.0, 0.0, 0.0), &bright),
+        Err(AstroError::OutOfRange { parameter: "albedo", .. })
+    ));
+    let hazy = ClearSkyAtmosphere { aod_500nm: -0.1, ..Default::default() };
+    assert!(matches!(
+        daily_insolation_with_atmosphere(dt, &site(40.0, 0.0, 0.0), &hazy),
+        Err(AstroError::OutOfRange { parameter: "aod_500nm", .. })
+    ));
+}
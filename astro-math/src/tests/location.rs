@@ -1,4 +1,4 @@
-use crate::location::Location;
+use crate::location::{CoordinateFormat, Location, ParseConfidence};
 use crate::error::AstroError;
 use chrono::{TimeZone, Utc};
 
@@ -242,4 +242,199 @@ fn test_parse_dms_negative_zero_degrees() {
             expected
         );
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_parse_with_diagnostics_matches_parse() {
+    let cases = [
+        ("40.7128 N", "74.0060 W"),
+        ("40°42'46.08\"N", "74°0'21.6\"W"),
+        ("51.5074 N", "0h 7m 39.84s W"),
+        ("404246N", "0740036W"),
+        ("4042.767N", "07400.36W"),
+        ("-33 52 04", "151 12 26"),
+    ];
+    for (lat, lon) in cases {
+        let plain = Location::parse(lat, lon, 5.0).unwrap();
+        let report = Location::parse_with_diagnostics(lat, lon, 5.0).unwrap();
+        assert_eq!(report.location.latitude_deg, plain.latitude_deg, "{}", lat);
+        assert_eq!(report.location.longitude_deg, plain.longitude_deg, "{}", lon);
+        assert_eq!(report.location.altitude_m, 5.0);
+    }
+}
+
+#[test]
+fn test_parse_with_diagnostics_unambiguous() {
+    let report = Location::parse_with_diagnostics("40.7128 N", "74°0'21.6\"W", 0.0).unwrap();
+    assert_eq!(report.latitude.format, CoordinateFormat::Decimal);
+    assert_eq!(report.longitude.format, CoordinateFormat::Dms);
+    assert!(report.latitude.alternatives.is_empty() && report.longitude.alternatives.is_empty());
+    assert_eq!(report.confidence(), ParseConfidence::High);
+
+    // Short digit runs are plain decimal degrees, not typos
+    let report = Location::parse_with_diagnostics("40", "-105", 0.0).unwrap();
+    assert_eq!(report.confidence(), ParseConfidence::High);
+
+    let report = Location::parse_with_diagnostics("4042.767N", "0h 7m 39.84s W", 0.0).unwrap();
+    assert_eq!(report.latitude.format, CoordinateFormat::CompactDm);
+    assert_eq!(report.longitude.format, CoordinateFormat::Hms);
+    assert_eq!(report.confidence(), ParseConfidence::High);
+}
+
+#[test]
+fn test_parse_with_diagnostics_digit_runs() {
+    let report = Location::parse_with_diagnostics("404246 S", "0", 0.0).unwrap();
+    let latitude = &report.latitude;
+    assert_eq!(latitude.format, CoordinateFormat::CompactDms);
+    assert!((latitude.value_deg + 40.712_777_8).abs() < 1e-6);
+    assert_eq!(latitude.confidence, ParseConfidence::Low);
+    let values: Vec<f64> = latitude.alternatives.iter().map(|a| a.value_deg).collect();
+    // 404.246 is out of range; the compass direction applies to every reading
+    assert_eq!(values, vec![-4.04246, -40.4246]);
+    assert!(latitude.alternatives.iter().all(|a| a.format == CoordinateFormat::MissingDecimalPoint));
+
+    let report = Location::parse_with_diagnostics("0", "0740036W", 0.0).unwrap();
+    assert!((report.longitude.value_deg + 74.01).abs() < 1e-9);
+    assert_eq!(report.longitude.confidence, ParseConfidence::Low);
+    assert!(report.longitude.alternatives.iter().any(|a| (a.value_deg + 74.0036).abs() < 1e-9));
+}
+
+#[test]
+fn test_parse_with_diagnostics_hours_or_degrees() {
+    let report = Location::parse_with_diagnostics("0", "4:56:27", 0.0).unwrap();
+    assert_eq!(report.longitude.format, CoordinateFormat::Dms);
+    assert_eq!(report.longitude.confidence, ParseConfidence::Medium);
+    let alternative = report.longitude.alternatives[0];
+    assert_eq!(alternative.format, CoordinateFormat::Hms);
+    assert!((alternative.value_deg - 74.1125).abs() < 1e-9);
+
+    // Latitude is never in hours, and over 24 the first field cannot be
+    let report = Location::parse_with_diagnostics("4:56:27", "151 12 26", 0.0).unwrap();
+    assert_eq!(report.confidence(), ParseConfidence::High);
+
+    // Explicit degree marks remove the ambiguity
+    let report = Location::parse_with_diagnostics("0", "4° 56' 27\"", 0.0).unwrap();
+    assert_eq!(report.longitude.confidence, ParseConfidence::High);
+}
+
+#[test]
+fn test_parse_with_diagnostics_errors() {
+    assert!(matches!(
+        Location::parse_with_diagnostics("foo", "0", 0.0),
+        Err(AstroError::InvalidDmsFormat { .. })
+    ));
+    assert!(matches!(
+        Location::parse_with_diagnostics("0", "4042", 0.0),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+}
+
+#[test]
+fn test_geohash_known_cells() {
+    // Reference cells from the original geohash.org encoding
+    let loc = Location::from_geohash("ezs42").unwrap();
+    assert!((loc.latitude_deg - 42.605).abs() < 1e-3);
+    assert!((loc.longitude_deg + 5.603).abs() < 1e-3);
+    assert_eq!(loc.altitude_m, 0.0);
+
+    let jutland = Location { latitude_deg: 57.64911, longitude_deg: 10.40744, altitude_m: 0.0 };
+    assert_eq!(jutland.to_geohash(11).unwrap(), "u4pruydqqvj");
+    let origin = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert_eq!(origin.to_geohash(5).unwrap(), "s0000");
+    let corner = Location { latitude_deg: -90.0, longitude_deg: -180.0, altitude_m: 0.0 };
+    assert_eq!(corner.to_geohash(4).unwrap(), "0000");
+    let far_corner = Location { latitude_deg: 90.0, longitude_deg: 180.0, altitude_m: 0.0 };
+    assert_eq!(far_corner.to_geohash(4).unwrap(), "zzzz");
+}
+
+#[test]
+fn test_geohash_round_trip() {
+    let sites = [(19.8207, -155.4681), (-30.2407, -70.7366), (28.7606, -17.8816), (-89.99, 179.99)];
+    for (lat, lon) in sites {
+        let loc = Location { latitude_deg: lat, longitude_deg: lon, altitude_m: 0.0 };
+        for precision in 1..=12 {
+            let hash = loc.to_geohash(precision).unwrap();
+            assert_eq!(hash.len(), precision);
+            let center = Location::from_geohash(&hash).unwrap();
+            // The cell is 180° × 360° halved once per bit
+            let lat_bits = (5 * precision / 2) as i32;
+            let lon_bits = (5 * precision).div_ceil(2) as i32;
+            assert!((center.latitude_deg - lat).abs() <= 90.0 / 2f64.powi(lat_bits));
+            assert!((center.longitude_deg - lon).abs() <= 180.0 / 2f64.powi(lon_bits));
+            assert_eq!(center.to_geohash(precision).unwrap(), hash);
+        }
+    }
+}
+
+#[test]
+fn test_geohash_errors() {
+    assert_eq!(Location::from_geohash("DR5REGW3").unwrap().to_geohash(8).unwrap(), "dr5regw3");
+    for bad in ["", "dr5regw3dr5re", "dr5a", "dr 5"] {
+        assert!(matches!(Location::from_geohash(bad), Err(AstroError::InvalidFormat { .. })), "{:?}", bad);
+    }
+    let loc = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    assert!(matches!(loc.to_geohash(0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(loc.to_geohash(13), Err(AstroError::OutOfRange { .. })));
+    let bad = Location { latitude_deg: f64::NAN, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(matches!(bad.to_geohash(5), Err(AstroError::InvalidCoordinate { .. })));
+}
+
+#[test]
+fn test_geodesic_reference_lines() {
+    // One degree along the equator is a/ρ of the equatorial radius
+    let origin = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let east = Location { latitude_deg: 0.0, longitude_deg: 1.0, altitude_m: 0.0 };
+    let line = origin.geodesic_to(&east).unwrap();
+    assert!((line.distance_m - 6378137.0 * 1f64.to_radians()).abs() < 1e-6);
+    assert!((line.initial_bearing_deg - 90.0).abs() < 1e-9 && (line.final_bearing_deg - 90.0).abs() < 1e-9);
+
+    // Equator to pole: the WGS84 meridian quadrant
+    let pole = Location { latitude_deg: 90.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!((origin.distance_to(&pole).unwrap() - 10_001_965.729).abs() < 0.001);
+    assert!(origin.bearing_to(&pole).unwrap().abs() < 1e-9);
+
+    // Vincenty's Flinders Peak–Buninyong line, final bearing from the
+    // published reverse azimuth 127°10′25.07″
+    let flinders = Location::parse("37 57 03.72030 S", "144 25 29.52440 E", 0.0).unwrap();
+    let buninyong = Location::parse("37 39 10.15610 S", "143 55 35.38390 E", 0.0).unwrap();
+    let final_bearing = 180.0 + 127.0 + 10.0 / 60.0 + 25.07 / 3600.0;
+    assert!((flinders.final_bearing_to(&buninyong).unwrap() - final_bearing).abs() < 0.01 / 3600.0);
+}
+
+#[test]
+fn test_geodesic_symmetry_and_altitude() {
+    let paranal = Location { latitude_deg: -24.6272, longitude_deg: -70.4042, altitude_m: 2635.0 };
+    let la_palma = Location { latitude_deg: 28.7606, longitude_deg: -17.8816, altitude_m: 2396.0 };
+    let forward = paranal.geodesic_to(&la_palma).unwrap();
+    let back = la_palma.geodesic_to(&paranal).unwrap();
+    assert!((forward.distance_m - back.distance_m).abs() < 1e-6);
+    // The reverse line starts opposite to where the forward one arrives
+    assert!(((forward.final_bearing_deg + 180.0).rem_euclid(360.0) - back.initial_bearing_deg).abs() < 1e-9);
+    // 8170 km on a sphere of the mean radius
+    assert!((forward.distance_m / 1000.0 - 8154.7).abs() < 0.1, "{}", forward.distance_m);
+
+    // Altitude does not enter; coincident points have zero length
+    let sea_level = Location { altitude_m: 0.0, ..paranal };
+    assert_eq!(sea_level.distance_to(&la_palma).unwrap(), forward.distance_m);
+    assert_eq!(paranal.geodesic_to(&sea_level).unwrap().distance_m, 0.0);
+
+    // Across the antimeridian
+    let west = Location { latitude_deg: 10.0, longitude_deg: 179.5, altitude_m: 0.0 };
+    let east = Location { latitude_deg: 10.0, longitude_deg: -179.5, altitude_m: 0.0 };
+    assert!(west.distance_to(&east).unwrap() < 110_000.0);
+    assert!((west.bearing_to(&east).unwrap() - 90.0).abs() < 0.1);
+}
+
+#[test]
+fn test_geodesic_errors() {
+    let origin = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let bad = Location { latitude_deg: 91.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(matches!(origin.distance_to(&bad), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(bad.bearing_to(&origin), Err(AstroError::InvalidCoordinate { .. })));
+    let nan = Location { latitude_deg: 0.0, longitude_deg: f64::NAN, altitude_m: 0.0 };
+    assert!(origin.final_bearing_to(&nan).is_err());
+
+    // Nearly antipodal on the equator, where Vincenty's iteration fails
+    let antipode = Location { latitude_deg: 0.5, longitude_deg: 179.7, altitude_m: 0.0 };
+    assert!(matches!(origin.geodesic_to(&antipode), Err(AstroError::CalculationError { .. })));
+}
@@ -0,0 +1,161 @@
+use crate::error::AstroError;
+use crate::location::Location;
+use crate::meteor::*;
+use crate::observer::terrestrial_position_km;
+use crate::vec3::{dot, norm, normalize, radec_to_unit_vector, Vec3};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn epoch() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 8, 12, 23, 14, 5).unwrap()
+}
+
+/// Alt/az of an ITRS position in km seen from a station
+fn observe(station: &Location, target: Vec3) -> (f64, f64) {
+    let origin = terrestrial_position_km(station);
+    let range = [0, 1, 2].map(|i| target[i] - origin[i]);
+    let (sin_lat, cos_lat) = station.latitude_deg.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = station.longitude_deg.to_radians().sin_cos();
+    let east = dot(range, [-sin_lon, cos_lon, 0.0]);
+    let north = dot(range, [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat]);
+    let up = dot(range, [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat]);
+    ((up / norm(range)).asin().to_degrees(), east.atan2(north).to_degrees().rem_euclid(360.0))
+}
+
+/// A straight meteor from `begin` along `heading` (ITRS unit vector) at
+/// `speed` km/s, sampled every 100 ms
+fn synthetic_track(
+    station: &Location,
+    begin: Vec3,
+    heading: Vec3,
+    speed: f64,
+    steps: std::ops::Range<i64>,
+) -> Vec<(f64, f64, DateTime<Utc>)> {
+    steps
+        .map(|k| {
+            let s = speed * k as f64 * 0.1;
+            let (alt, az) = observe(station, [0, 1, 2].map(|i| begin[i] + s * heading[i]));
+            (alt, az, epoch() + Duration::milliseconds(100 * k))
+        })
+        .collect()
+}
+
+fn stations() -> (Location, Location) {
+    (
+        Location { latitude_deg: 45.0, longitude_deg: 15.0, altitude_m: 300.0 },
+        Location { latitude_deg: 45.3, longitude_deg: 15.8, altitude_m: 250.0 },
+    )
+}
+
+#[test]
+fn test_recovers_synthetic_trajectory() {
+    let (a, b) = stations();
+    let begin = terrestrial_position_km(&Location { latitude_deg: 45.4, longitude_deg: 15.3, altitude_m: 105_000.0 });
+    // Heading down and to the south-west in ITRS
+    let end = terrestrial_position_km(&Location { latitude_deg: 45.2, longitude_deg: 15.1, altitude_m: 80_000.0 });
+    let heading = normalize([0, 1, 2].map(|i| end[i] - begin[i]));
+    let track_a = synthetic_track(&a, begin, heading, 40.0, 0..8);
+    // The second camera starts later and runs longer
+    let track_b = synthetic_track(&b, begin, heading, 40.0, 2..10);
+
+    let trajectory = triangulate_meteor((&a, &track_a), (&b, &track_b)).unwrap();
+    assert_eq!(trajectory.points.len(), 16);
+    assert!((trajectory.velocity_km_s - 40.0).abs() < 1e-6, "{}", trajectory.velocity_km_s);
+    assert!(trajectory.convergence_angle_deg > 10.0);
+
+    let first = trajectory.begin();
+    assert_eq!(first.time, epoch());
+    assert_eq!(first.station, 0);
+    assert!((first.location.latitude_deg - 45.4).abs() < 1e-6);
+    assert!((first.location.longitude_deg - 15.3).abs() < 1e-6);
+    assert!((first.location.altitude_m - 105_000.0).abs() < 0.1, "{}", first.location.altitude_m);
+    assert_eq!(first.distance_km, 0.0);
+
+    let last = trajectory.end();
+    assert_eq!(last.time, epoch() + Duration::milliseconds(900));
+    assert_eq!(last.station, 1);
+    assert!((last.distance_km - 36.0).abs() < 1e-6);
+    for pair in trajectory.points.windows(2) {
+        assert!(pair[0].time <= pair[1].time);
+        assert!(pair[0].location.altitude_m > pair[1].location.altitude_m - 1e-3);
+    }
+    assert!(trajectory.points.iter().all(|p| p.miss_distance_km < 1e-6));
+
+    // The radiant is where the meteor came from: far enough up the track
+    // that the station's offset from it does not matter
+    let back = [0, 1, 2].map(|i| begin[i] - 1.0e7 * heading[i]);
+    let (alt, az) = observe(&a, back);
+    let expected = crate::transforms::alt_az_to_ra_dec(alt, az, epoch(), &a).unwrap();
+    let separation = crate::vec3::angle_between(
+        radec_to_unit_vector(trajectory.radiant_ra, trajectory.radiant_dec),
+        radec_to_unit_vector(expected.0, expected.1),
+    );
+    assert!(separation.to_degrees() < 1e-3, "{}", separation.to_degrees());
+}
+
+#[test]
+fn test_station_order_and_noise() {
+    let (a, b) = stations();
+    let begin = terrestrial_position_km(&Location { latitude_deg: 45.1, longitude_deg: 15.5, altitude_m: 95_000.0 });
+    let heading = normalize([0.3, -0.5, -0.8]);
+    let track_a = synthetic_track(&a, begin, heading, 25.0, 0..6);
+    let track_b = synthetic_track(&b, begin, heading, 25.0, 0..6);
+
+    let ab = triangulate_meteor((&a, &track_a), (&b, &track_b)).unwrap();
+    let ba = triangulate_meteor((&b, &track_b), (&a, &track_a)).unwrap();
+    assert!((ab.velocity_km_s - ba.velocity_km_s).abs() < 1e-6);
+    assert!((ab.radiant_dec - ba.radiant_dec).abs() < 1e-6);
+    assert!((ab.begin().location.altitude_m - ba.begin().location.altitude_m).abs() < 1.0);
+
+    // Half-arcminute errors move the heights by well under a kilometer
+    let noisy: Vec<_> = track_a
+        .iter()
+        .enumerate()
+        .map(|(k, &(alt, az, t))| (alt + if k % 2 == 0 { 0.008 } else { -0.008 }, az, t))
+        .collect();
+    let rough = triangulate_meteor((&a, &noisy), (&b, &track_b)).unwrap();
+    assert!((rough.begin().location.altitude_m - ab.begin().location.altitude_m).abs() < 1000.0);
+    assert!(rough.points.iter().any(|p| p.miss_distance_km > 1e-4));
+    assert!((rough.velocity_km_s - 25.0).abs() < 1.0);
+}
+
+#[test]
+fn test_invalid_tracks() {
+    let (a, b) = stations();
+    let begin = terrestrial_position_km(&Location { latitude_deg: 45.1, longitude_deg: 15.5, altitude_m: 95_000.0 });
+    let heading = normalize([0.3, -0.5, -0.8]);
+    let track_a = synthetic_track(&a, begin, heading, 25.0, 0..4);
+    let track_b = synthetic_track(&b, begin, heading, 25.0, 0..4);
+
+    assert!(matches!(
+        triangulate_meteor((&a, &track_a[..1]), (&b, &track_b)),
+        Err(AstroError::CalculationError { .. })
+    ));
+    let mut bad = track_b.clone();
+    bad[1].1 = 360.0;
+    assert!(matches!(triangulate_meteor((&a, &track_a), (&b, &bad)), Err(AstroError::InvalidCoordinate { .. })));
+    let nowhere = Location { latitude_deg: 95.0, ..a };
+    assert!(matches!(
+        triangulate_meteor((&nowhere, &track_a), (&b, &track_b)),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+
+    // One instant for every point
+    let frozen: Vec<_> = track_a.iter().map(|&(alt, az, _)| (alt, az, epoch())).collect();
+    let frozen_b: Vec<_> = track_b.iter().map(|&(alt, az, _)| (alt, az, epoch())).collect();
+    assert!(matches!(
+        triangulate_meteor((&a, &frozen), (&b, &frozen_b)),
+        Err(AstroError::CalculationError { .. })
+    ));
+
+    // Both stations see the same plane: the meteor moves parallel to their
+    // baseline
+    let (origin_a, origin_b) = (terrestrial_position_km(&a), terrestrial_position_km(&b));
+    let along_baseline = normalize([0, 1, 2].map(|i| origin_b[i] - origin_a[i]));
+    let high = terrestrial_position_km(&Location { latitude_deg: 45.1, longitude_deg: 15.3, altitude_m: 90_000.0 });
+    let line_a = synthetic_track(&a, high, along_baseline, 30.0, 0..4);
+    let line_b = synthetic_track(&b, high, along_baseline, 30.0, 1..5);
+    assert!(matches!(
+        triangulate_meteor((&a, &line_a), (&b, &line_b)),
+        Err(AstroError::CalculationError { .. })
+    ));
+}
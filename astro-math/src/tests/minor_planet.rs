@@ -0,0 +1,67 @@
+use crate::error::AstroError;
+use crate::minor_planet::*;
+
+#[test]
+fn test_phase_functions_limits() {
+    let (phi1, phi2) = hg_phase_functions(0.0).unwrap();
+    assert!((phi1 - 1.0).abs() < 1e-12);
+    assert!((phi2 - 1.0).abs() < 1e-12);
+
+    assert_eq!(hg_phase_functions(180.0).unwrap(), (0.0, 0.0));
+}
+
+#[test]
+fn test_phase_functions_decrease() {
+    let mut last = (1.0, 1.0);
+    for alpha in (5..=150).step_by(5) {
+        let (phi1, phi2) = hg_phase_functions(alpha as f64).unwrap();
+        assert!(phi1 < last.0 && phi2 < last.1, "not decreasing at {}°", alpha);
+        assert!(phi1 > 0.0 && phi2 > 0.0);
+        last = (phi1, phi2);
+    }
+}
+
+#[test]
+fn test_magnitude_at_unit_distance_and_zero_phase() {
+    // At r = Δ = 1 AU and α = 0 the apparent magnitude is H
+    let v = asteroid_magnitude_hg(15.2, 0.15, 1.0, 1.0, 0.0).unwrap();
+    assert!((v - 15.2).abs() < 1e-12);
+}
+
+#[test]
+fn test_magnitude_reference_value() {
+    // Away from opposition the exact functions track the simplified
+    // exponential approximation exp(−A tan^B(α/2)) to a few tenths of a percent
+    let (phi1, phi2) = hg_phase_functions(20.0).unwrap();
+    let tan_half = 10f64.to_radians().tan();
+    let approx1 = (-3.33 * tan_half.powf(0.63)).exp();
+    let approx2 = (-1.87 * tan_half.powf(1.22)).exp();
+    assert!((phi1 - approx1).abs() < 3e-3, "phi1 = {}", phi1);
+    assert!((phi2 - approx2).abs() < 3e-3, "phi2 = {}", phi2);
+
+    let v = asteroid_magnitude_hg(7.0, 0.15, 2.5, 1.8, 20.0).unwrap();
+    let expected = 7.0 + 5.0 * (2.5f64 * 1.8).log10() - 2.5 * (0.85 * phi1 + 0.15 * phi2).log10();
+    assert!((v - expected).abs() < 1e-12);
+}
+
+#[test]
+fn test_larger_g_is_brighter_at_phase() {
+    let low = asteroid_magnitude_hg(10.0, 0.05, 2.0, 1.2, 25.0).unwrap();
+    let high = asteroid_magnitude_hg(10.0, 0.40, 2.0, 1.2, 25.0).unwrap();
+    assert!(high < low);
+}
+
+#[test]
+fn test_full_phase_is_invisible() {
+    let v = asteroid_magnitude_hg(10.0, 0.15, 1.0, 0.5, 180.0).unwrap();
+    assert!(v.is_infinite());
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert!(matches!(asteroid_magnitude_hg(10.0, 0.15, 0.0, 1.0, 10.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(asteroid_magnitude_hg(10.0, 0.15, 1.0, -1.0, 10.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(asteroid_magnitude_hg(10.0, 0.15, 1.0, 1.0, 181.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(asteroid_magnitude_hg(f64::NAN, 0.15, 1.0, 1.0, 10.0), Err(AstroError::OutOfRange { .. })));
+    assert!(hg_phase_functions(f64::NAN).is_err());
+}
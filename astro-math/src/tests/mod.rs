@@ -1,18 +1,85 @@
 pub mod aberration;
+pub mod accuracy;
 pub mod airmass;
+pub mod allsky;
+pub mod almanac;
+pub mod angles;
+pub mod apparent;
+pub mod astro_time;
+pub mod astrometric_context;
+pub mod besselian;
+pub mod catalog;
+#[cfg(feature = "calendar")]
+pub mod calendar;
+pub mod charts;
+pub mod config;
+pub mod constellations;
+#[cfg(feature = "erfa")]
+pub mod backend;
+pub mod close_approach;
+pub mod designation;
+pub mod diagnostics;
+pub mod dither;
+pub mod drift_scan;
+pub mod earth;
+pub mod ecliptic;
+pub mod eop;
+pub mod ephemeris;
 pub mod erfa;
 pub mod error_paths;
+pub mod events;
+pub mod export;
+pub mod fixed_site;
+pub mod focus;
 pub mod galactic;
+pub mod great_circle;
+pub mod horizon;
+#[cfg(feature = "tracing")]
+pub mod instrumentation;
+pub mod interference;
+#[cfg(feature = "irradiance")]
+pub mod irradiance;
 pub mod location;
+pub mod meteor;
+pub mod minor_planet;
 pub mod moon;
+pub mod mosaic;
+pub mod mount;
+pub mod moving_observer;
+pub mod night;
 pub mod nutation;
+pub mod observer;
+pub mod occultation;
+pub mod offsets;
+pub mod orbit;
 pub mod parallax;
 pub mod parsing;
+pub mod planets;
+pub mod plate_solve;
+pub mod pointing_model;
+pub mod polar_alignment;
 pub mod precession;
+pub mod prelude;
+pub mod propagator;
 pub mod proper_motion;
+pub mod provenance;
+pub mod radial_velocity;
 pub mod refraction;
 pub mod rise_set;
+pub mod satellite;
+pub mod schedule;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod sidereal;
+pub mod simulate;
+pub mod sky_polygon;
 pub mod sun;
 pub mod time;
+pub mod timing;
 pub mod transforms;
+#[cfg(feature = "units")]
+pub mod units;
+pub mod vec3;
+pub mod weather;
+pub mod zenith;
+pub mod zodiac;
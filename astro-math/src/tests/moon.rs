@@ -284,4 +284,103 @@ fn test_moon_ecliptic_inclination() {
     // Most of the time should be within orbital inclination
     assert!(count_within_orbit > total_samples * 7 / 10, 
             "Only {} of {} samples within orbital plane", count_within_orbit, total_samples);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_moon_positions_match_scalar_functions() {
+    let start = Utc.with_ymd_and_hms(2024, 3, 10, 6, 0, 0).unwrap();
+    let step = chrono::Duration::minutes(37);
+    let track = moon_positions(start, start + chrono::Duration::hours(48), step).unwrap();
+    // 48 h / 37 min = 77.8 steps, so the end itself is not sampled
+    assert_eq!(track.len(), 78);
+    for (k, &(t, ra, dec, distance_km)) in track.iter().enumerate() {
+        assert_eq!(t, start + step * k as i32);
+        assert_eq!((ra, dec), moon_equatorial(t));
+        assert_eq!(distance_km, moon_distance(t));
+    }
+}
+
+#[test]
+fn test_moon_positions_span_and_step() {
+    let start = Utc.with_ymd_and_hms(2024, 3, 10, 6, 0, 0).unwrap();
+    let hour = chrono::Duration::hours(1);
+    assert_eq!(moon_positions(start, start, hour).unwrap().len(), 1);
+    assert!(moon_positions(start, start - hour, hour).unwrap().is_empty());
+    assert!(matches!(
+        moon_positions(start, start + hour, chrono::Duration::zero()),
+        Err(crate::error::AstroError::OutOfRange { .. })
+    ));
+    assert!(moon_positions(start, start + hour, -hour).is_err());
+}
+
+#[test]
+fn test_moon_ecliptic_longitude_at_phases() {
+    use crate::sun::ecliptic_longitude_of_sun;
+    // Full moon of 2024 February 24, 12:30 UTC
+    let full = Utc.with_ymd_and_hms(2024, 2, 24, 12, 30, 0).unwrap();
+    let elongation = (moon_ecliptic_longitude(full) - ecliptic_longitude_of_sun(full)).rem_euclid(360.0);
+    assert!((elongation - 180.0).abs() < 0.02, "{}", elongation);
+    for day in 1..28 {
+        let lon = moon_ecliptic_longitude(full + chrono::Duration::days(day));
+        assert!((0.0..360.0).contains(&lon));
+    }
+}
+
+#[test]
+fn test_checked_variants_reject_far_dates() {
+    let dt = Utc.with_ymd_and_hms(2024, 3, 10, 9, 0, 0).unwrap();
+    assert_eq!(moon_phase_angle_checked(dt).unwrap(), moon_phase_angle(dt));
+    assert_eq!(moon_illumination_checked(dt).unwrap(), moon_illumination(dt));
+
+    let too_early = Utc.with_ymd_and_hms(-5000, 1, 1, 0, 0, 0).unwrap();
+    let too_late = Utc.with_ymd_and_hms(12000, 1, 1, 0, 0, 0).unwrap();
+    for dt in [too_early, too_late] {
+        assert!(matches!(moon_phase_angle_checked(dt), Err(crate::error::AstroError::OutOfRange { .. })));
+        assert!(moon_illumination_checked(dt).is_err());
+    }
+}
+
+#[test]
+fn test_libration_meeus_example() {
+    // Meeus, Astronomical Algorithms, example 53.a: 1992 April 12, 0h TD,
+    // optical libration l' = -1.206°, b' = +4.194°, P = 15.08°
+    let tt_offset = crate::time_scales::tt_utc_offset_seconds();
+    let dt = Utc.with_ymd_and_hms(1992, 4, 12, 0, 0, 0).unwrap()
+        - chrono::Duration::microseconds((tt_offset * 1e6) as i64);
+    let libration = moon_libration(dt);
+    assert!((libration.longitude + 1.206).abs() < 0.01, "l = {}", libration.longitude);
+    assert!((libration.latitude - 4.194).abs() < 0.01, "b = {}", libration.latitude);
+    assert!((libration.position_angle - 15.08).abs() < 0.03, "P = {}", libration.position_angle);
+}
+
+#[test]
+fn test_libration_ranges_over_a_month() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let (mut max_l, mut max_b) = (0.0f64, 0.0f64);
+    for hours in (0..720).step_by(12) {
+        let libration = moon_libration(start + chrono::Duration::hours(hours));
+        max_l = max_l.max(libration.longitude.abs());
+        max_b = max_b.max(libration.latitude.abs());
+        // The axis never leans more than about 25° from celestial north
+        let p = (libration.position_angle + 180.0).rem_euclid(360.0) - 180.0;
+        assert!(p.abs() < 26.0, "P = {}", libration.position_angle);
+    }
+    assert!((4.0..8.2).contains(&max_l), "max |l| = {}", max_l);
+    assert!((5.5..7.0).contains(&max_b), "max |b| = {}", max_b);
+}
+
+#[test]
+fn test_sub_observer_point_parallax() {
+    let dt = Utc.with_ymd_and_hms(2024, 1, 20, 20, 0, 0).unwrap();
+    let geocentric = moon_libration(dt);
+    let north = crate::Location { latitude_deg: 89.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let south = crate::Location { latitude_deg: -89.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let (_, b_north) = moon_sub_observer_point(dt, &north).unwrap();
+    let (_, b_south) = moon_sub_observer_point(dt, &south).unwrap();
+    // Seen from further north, more of the Moon's north is visible
+    assert!(b_north > geocentric.latitude && geocentric.latitude > b_south);
+    assert!(b_north - b_south > 1.0 && b_north - b_south < 2.1);
+
+    let bad = crate::Location { latitude_deg: 91.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(moon_sub_observer_point(dt, &bad).is_err());
+}
@@ -0,0 +1,109 @@
+use crate::error::AstroError;
+use crate::mosaic::*;
+use crate::sky_polygon;
+
+fn separation_deg(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> f64 {
+    let (d1, d2) = (dec1.to_radians(), dec2.to_radians());
+    let cos = d1.sin() * d2.sin() + d1.cos() * d2.cos() * (ra2 - ra1).to_radians().cos();
+    cos.clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+#[test]
+fn test_tile_counts() {
+    // Region smaller than the field: a single centred frame
+    let tiles = plan((100.0, 20.0), 0.5, 0.5, (1.0, 0.8), 10.0, 30.0).unwrap();
+    assert_eq!(tiles.len(), 1);
+    assert!((tiles[0].ra - 100.0).abs() < 1e-9 && (tiles[0].dec - 20.0).abs() < 1e-9);
+    assert!((tiles[0].rotation - 30.0).abs() < 1e-6);
+
+    // 3 columns of 1° with 0% overlap cover exactly 3°
+    let tiles = plan((100.0, 20.0), 3.0, 1.0, (1.0, 1.0), 0.0, 0.0).unwrap();
+    assert_eq!(tiles.len(), 3);
+    // 20% overlap: steps of 0.8° need 4 frames for 3°
+    let tiles = plan((100.0, 20.0), 3.0, 1.0, (1.0, 1.0), 20.0, 0.0).unwrap();
+    assert_eq!(tiles.len(), 4);
+    assert_eq!((tiles[3].row, tiles[3].column), (0, 3));
+}
+
+#[test]
+fn test_spacing_is_uniform_at_high_declination() {
+    // At Dec 80° a naive RA step would need to be 5.8 times the field
+    let tiles = plan((200.0, 80.0), 6.0, 2.0, (2.0, 2.0), 0.0, 0.0).unwrap();
+    assert_eq!(tiles.len(), 3);
+    for pair in tiles.windows(2) {
+        let sep = separation_deg(pair[0].ra, pair[0].dec, pair[1].ra, pair[1].dec);
+        assert!((sep - 2.0).abs() < 0.01, "separation {}", sep);
+    }
+    // Rows follow a great circle, not a parallel of declination
+    assert!((tiles[0].dec - tiles[2].dec).abs() < 1e-9);
+    assert!(tiles[0].dec < 80.0);
+    // Outer tiles are rotated in opposite directions by the meridian convergence
+    assert!(tiles[1].rotation.abs() < 1e-6);
+    assert!((tiles[0].rotation + tiles[2].rotation).abs() < 1e-6);
+    assert!(tiles[0].rotation.abs() > 5.0);
+}
+
+#[test]
+fn test_mosaic_over_pole() {
+    let tiles = plan((45.0, 90.0), 6.0, 6.0, (2.0, 2.0), 0.0, 0.0).unwrap();
+    assert_eq!(tiles.len(), 9);
+    // The central tile sits on the pole and the grid is symmetric about it
+    let centre = tiles.iter().find(|t| t.row == 1 && t.column == 1).unwrap();
+    assert!((centre.dec - 90.0).abs() < 1e-9);
+    for tile in &tiles {
+        // Grid offsets are tangent-plane distances from the pole
+        let from_pole = 90.0 - tile.dec;
+        let offset = match (tile.row, tile.column) {
+            (1, 1) => 0.0,
+            (1, _) | (_, 1) => 2.0,
+            _ => 2.0 * 2f64.sqrt(),
+        };
+        let expected = f64::to_radians(offset).atan().to_degrees();
+        assert!((from_pole - expected).abs() < 1e-6, "{:?}: {}", (tile.row, tile.column), from_pole);
+    }
+}
+
+#[test]
+fn test_tiles_cover_region_with_overlap() {
+    let tiles = plan((10.0, 85.0), 8.0, 5.0, (3.0, 2.0), 15.0, 20.0).unwrap();
+    let footprints: Vec<[(f64, f64); 4]> = tiles.iter().map(|t| t.corners().unwrap()).collect();
+
+    // Every point of the requested region lies in at least one frame
+    let region = MosaicTile { row: 0, column: 0, ra: 10.0, dec: 85.0, rotation: 20.0, width_deg: 8.0, height_deg: 5.0 };
+    let plane = region.tangent_plane(3600.0).unwrap();
+    for i in 0..=16 {
+        for j in 0..=10 {
+            let (x, y) = (-4.0 + i as f64 * 0.5, -2.5 + j as f64 * 0.5);
+            let (ra, dec) = plane.pixel_to_ra_dec(x, y).unwrap();
+            let covered = footprints.iter().any(|f| sky_polygon::contains(f, ra, dec).unwrap());
+            assert!(covered, "({}, {}) not covered", x, y);
+        }
+    }
+
+    // Each frame covers the camera field of view
+    let area = sky_polygon::area_sq_deg(&footprints[0]).unwrap();
+    assert!((area - 6.0).abs() < 0.05, "frame area {}", area);
+}
+
+#[test]
+fn test_tile_tangent_plane() {
+    let tiles = plan((150.0, 60.0), 4.0, 1.0, (2.0, 1.0), 0.0, 0.0).unwrap();
+    let plane = tiles[0].tangent_plane(1.5).unwrap();
+    assert_eq!((plane.ra0, plane.dec0, plane.rotation), (tiles[0].ra, tiles[0].dec, tiles[0].rotation));
+    assert_eq!(plane.scale, 1.5);
+
+    // The neighbouring tile lies along the frame's x axis
+    let (x, y) = plane.ra_dec_to_pixel(tiles[1].ra, tiles[1].dec).unwrap();
+    assert!((x.abs() * 1.5 / 3600.0 - 2.0).abs() < 0.01, "x {}", x);
+    assert!(y.abs() < 1.0, "y {}", y);
+}
+
+#[test]
+fn test_plan_errors() {
+    assert!(matches!(plan((400.0, 0.0), 1.0, 1.0, (1.0, 1.0), 0.0, 0.0), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(plan((0.0, 0.0), 0.0, 1.0, (1.0, 1.0), 0.0, 0.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(plan((0.0, 0.0), 120.0, 1.0, (1.0, 1.0), 0.0, 0.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(plan((0.0, 0.0), 1.0, 1.0, (f64::NAN, 1.0), 0.0, 0.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(plan((0.0, 0.0), 1.0, 1.0, (1.0, 1.0), 95.0, 0.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(plan((0.0, 0.0), 1.0, 1.0, (1.0, 1.0), 0.0, f64::INFINITY), Err(AstroError::OutOfRange { .. })));
+}
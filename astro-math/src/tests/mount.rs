@@ -0,0 +1,115 @@
+use crate::error::AstroError;
+use crate::mount::*;
+
+fn refractor() -> MountGeometry {
+    MountGeometry {
+        latitude_deg: 45.0,
+        tube_length_m: 1.2,
+        tube_diameter_m: 0.2,
+        tube_center_offset_m: 0.1,
+        dec_axis_offset_m: 0.25,
+        pier_radius_m: 0.12,
+        pier_top_m: 0.3,
+        clearance_m: 0.02,
+    }
+}
+
+#[test]
+fn test_normal_side_is_clear() {
+    let mount = refractor();
+    for dec in (-30..=89).step_by(7) {
+        for ha in (5..=175).step_by(10) {
+            let (ha, dec) = (ha as f64, dec as f64);
+            assert_eq!(PierSide::counterweight_down(ha), PierSide::East);
+            assert!(!collision_check(&mount, ha, dec, PierSide::East).unwrap(), "HA {} Dec {}", ha, dec);
+            assert_eq!(PierSide::counterweight_down(-ha), PierSide::West);
+            assert!(!collision_check(&mount, -ha, dec, PierSide::West).unwrap(), "HA {} Dec {}", -ha, dec);
+        }
+    }
+}
+
+#[test]
+fn test_sides_mirror_east_west() {
+    let mount = refractor();
+    for (ha, dec) in [(-150.0, 10.0), (-60.0, 45.0), (20.0, -20.0), (100.0, 80.0)] {
+        let east = tube_pier_gap(&mount, ha, dec, PierSide::East).unwrap();
+        let west = tube_pier_gap(&mount, -ha, dec, PierSide::West).unwrap();
+        assert!((east - west).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_counterweights_up_collides() {
+    let mount = refractor();
+    assert!(collision_check(&mount, -90.0, 60.0, PierSide::East).unwrap());
+    assert!(collision_check(&mount, 90.0, 60.0, PierSide::West).unwrap());
+    // Sliding the tube back in its rings never widens the gap
+    let gaps: Vec<f64> = [1.2, 0.9, 0.6, 0.3, 0.0]
+        .iter()
+        .map(|&offset| {
+            let geometry = MountGeometry { tube_center_offset_m: offset, ..mount };
+            tube_pier_gap(&geometry, -90.0, 60.0, PierSide::East).unwrap()
+        })
+        .collect();
+    assert!(gaps.windows(2).all(|pair| pair[1] <= pair[0] + 1e-12));
+    assert!(gaps[0] > mount.clearance_m && gaps[4] < 0.0);
+}
+
+#[test]
+fn test_gap_at_meridian() {
+    // Pointing at the zenith, the tube stands upright beside the pier, its
+    // axis 0.25 m out and its rear end 0.5 m below the axes
+    let mount = refractor();
+    let gap = tube_pier_gap(&mount, 0.0, 45.0, PierSide::East).unwrap();
+    assert!((gap - (0.25 - 0.1 - 0.12)).abs() < 1e-9);
+}
+
+#[test]
+fn test_alt_az_matches_ha_dec() {
+    let mount = refractor();
+    let cases = [
+        // Zenith
+        ((90.0, 0.0), (0.0, 45.0)),
+        // Due south at the equator's altitude
+        ((45.0, 180.0), (0.0, 0.0)),
+        // East point of the horizon
+        ((0.0, 90.0), (-90.0, 0.0)),
+        // The pole
+        ((45.0, 0.0), (0.0, 90.0)),
+    ];
+    for ((alt, az), (ha, dec)) in cases {
+        for side in [PierSide::East, PierSide::West] {
+            assert_eq!(
+                collision_check_alt_az(&mount, alt, az, side).unwrap(),
+                collision_check(&mount, ha, dec, side).unwrap()
+            );
+        }
+    }
+    assert!(collision_check_alt_az(&mount, 60.0, 10.0, PierSide::East).unwrap());
+}
+
+#[test]
+fn test_invalid_input() {
+    let mount = refractor();
+    assert!(matches!(
+        collision_check(&mount, 0.0, 95.0, PierSide::East),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(
+        collision_check(&mount, f64::NAN, 0.0, PierSide::East),
+        Err(AstroError::OutOfRange { parameter: "ha_deg", .. })
+    ));
+    assert!(matches!(
+        collision_check_alt_az(&mount, -91.0, 0.0, PierSide::East),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    let bad = MountGeometry { tube_length_m: 0.0, ..mount };
+    assert!(matches!(
+        collision_check(&bad, 0.0, 0.0, PierSide::East),
+        Err(AstroError::OutOfRange { parameter: "tube_length_m", .. })
+    ));
+    let bad = MountGeometry { clearance_m: -0.01, ..mount };
+    assert!(matches!(bad.validate(), Err(AstroError::OutOfRange { parameter: "clearance_m", .. })));
+    let bad = MountGeometry { latitude_deg: 100.0, ..mount };
+    assert!(matches!(bad.validate(), Err(AstroError::InvalidCoordinate { .. })));
+}
@@ -0,0 +1,90 @@
+use crate::error::AstroError;
+use crate::moving_observer::*;
+use crate::transforms::*;
+use crate::Location;
+use chrono::{Duration, TimeZone, Utc};
+
+fn loc(latitude_deg: f64, longitude_deg: f64, altitude_m: f64) -> Location {
+    Location { latitude_deg, longitude_deg, altitude_m }
+}
+
+#[test]
+fn test_track_validation() {
+    let t0 = Utc.with_ymd_and_hms(2024, 4, 8, 18, 0, 0).unwrap();
+    assert!(matches!(MovingObserver::from_track(vec![]), Err(AstroError::CalculationError { .. })));
+    let unordered = vec![(t0, loc(0.0, 0.0, 0.0)), (t0, loc(1.0, 0.0, 0.0))];
+    assert!(matches!(MovingObserver::from_track(unordered), Err(AstroError::CalculationError { .. })));
+
+    let single = MovingObserver::from_track(vec![(t0, loc(10.0, 20.0, 30.0))]).unwrap();
+    assert_eq!(single.time_range(), Some((t0, t0)));
+    assert_eq!(single.location_at(t0).unwrap().latitude_deg, 10.0);
+    assert!(matches!(
+        single.location_at(t0 + Duration::seconds(1)),
+        Err(AstroError::OutOfRange { parameter: "datetime", .. })
+    ));
+}
+
+#[test]
+fn test_track_interpolation() {
+    let t0 = Utc.with_ymd_and_hms(2024, 4, 8, 18, 0, 0).unwrap();
+    let track = vec![
+        (t0, loc(0.0, 179.0, 0.0)),
+        (t0 + Duration::hours(1), loc(0.0, -179.0, 1000.0)),
+        (t0 + Duration::hours(2), loc(2.0, -179.0, 1000.0)),
+    ];
+    let observer = MovingObserver::from_track(track).unwrap();
+
+    // Crossing the antimeridian takes the short way round
+    let mid = observer.location_at(t0 + Duration::minutes(30)).unwrap();
+    assert!(mid.latitude_deg.abs() < 1e-9);
+    assert!((mid.longitude_deg.abs() - 180.0).abs() < 1e-9, "longitude {}", mid.longitude_deg);
+    assert!((mid.altitude_m - 500.0).abs() < 1e-9);
+
+    // Second leg runs due north
+    let q = observer.location_at(t0 + Duration::minutes(75)).unwrap();
+    assert!((q.latitude_deg - 0.5).abs() < 1e-9);
+    assert!((q.longitude_deg + 179.0).abs() < 1e-9);
+
+    assert!(observer.location_at(t0 - Duration::seconds(1)).is_err());
+    assert!(observer.location_at(t0 + Duration::hours(3)).is_err());
+}
+
+#[test]
+fn test_function_and_stationary_observers() {
+    let t0 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let site = loc(-30.24, -70.74, 2200.0);
+    let fixed = MovingObserver::stationary(site);
+    assert_eq!(fixed.time_range(), None);
+
+    let (alt, az) = ra_dec_to_alt_az(101.29, -16.72, t0, &site).unwrap();
+    assert_eq!(ra_dec_to_alt_az_moving(101.29, -16.72, t0, &fixed).unwrap(), (alt, az));
+    assert_eq!(
+        ra_dec_to_alt_az_erfa_moving(101.29, -16.72, t0, &fixed, None, None, None).unwrap(),
+        ra_dec_to_alt_az_erfa(101.29, -16.72, t0, &site, None, None, None).unwrap()
+    );
+    assert_eq!(
+        alt_az_to_ra_dec_moving(alt, az, t0, &fixed).unwrap(),
+        alt_az_to_ra_dec(alt, az, t0, &site).unwrap()
+    );
+}
+
+#[test]
+fn test_observer_keeping_pace_with_the_sky() {
+    // Flying west along the equator at the sidereal rate keeps the local
+    // sidereal time, and so a star's position, nearly fixed
+    let t0 = Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap();
+    let jet = MovingObserver::from_fn(move |t| Location {
+        latitude_deg: 0.0,
+        longitude_deg: -15.041 * (t - t0).num_seconds() as f64 / 3600.0,
+        altitude_m: 12000.0,
+    });
+
+    let (alt0, az0) = ra_dec_to_alt_az_moving(30.0, 10.0, t0, &jet).unwrap();
+    let (alt1, az1) = ra_dec_to_alt_az_moving(30.0, 10.0, t0 + Duration::hours(1), &jet).unwrap();
+    assert!((alt0 - alt1).abs() < 0.01 && (az0 - az1).abs() < 0.01);
+
+    // A fixed observer sees the star move by ~15°
+    let still = MovingObserver::stationary(loc(0.0, 0.0, 12000.0));
+    let (alt2, _) = ra_dec_to_alt_az_moving(30.0, 10.0, t0 + Duration::hours(1), &still).unwrap();
+    assert!((alt2 - alt0).abs() > 10.0);
+}
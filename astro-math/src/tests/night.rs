@@ -0,0 +1,120 @@
+use crate::error::AstroError;
+use crate::location::Location;
+use crate::night::*;
+use crate::rise_set::sun_rise_set;
+use chrono::{Duration, NaiveDate, TimeZone, Timelike, Utc};
+
+fn kitt_peak() -> Location {
+    Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 }
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_containing_splits_at_local_noon() {
+    let site = kitt_peak();
+    let night = Night::starting_on(date(2024, 11, 3), &site).unwrap();
+    // Local mean noon at 111.6° W is 19:26:24 UTC
+    assert_eq!(night.start(), Utc.with_ymd_and_hms(2024, 11, 3, 19, 26, 24).unwrap());
+    assert_eq!(night.end(), night.next().start());
+    assert_eq!(night.previous().next().date(), night.date());
+
+    let just_before = night.start() - Duration::milliseconds(1);
+    assert_eq!(Night::containing(just_before, &site).unwrap().date(), date(2024, 11, 2));
+    assert_eq!(Night::containing(night.start(), &site).unwrap().date(), date(2024, 11, 3));
+    // Across UTC midnight and up to the next local noon
+    for hours in [4, 12, 23] {
+        let t = night.start() + Duration::hours(hours);
+        assert!(night.contains(t));
+        assert_eq!(Night::containing(t, &site).unwrap().date(), date(2024, 11, 3));
+    }
+    assert!(!night.contains(night.end()));
+}
+
+#[test]
+fn test_east_of_greenwich() {
+    // Siding Spring, 149° E: the night starts before 02:00 UTC on its date
+    let site = Location { latitude_deg: -31.2733, longitude_deg: 149.0644, altitude_m: 1165.0 };
+    let t = Utc.with_ymd_and_hms(2024, 11, 3, 1, 0, 0).unwrap();
+    assert_eq!(Night::containing(t, &site).unwrap().date(), date(2024, 11, 2));
+    let t = Utc.with_ymd_and_hms(2024, 11, 3, 14, 0, 0).unwrap();
+    assert_eq!(Night::containing(t, &site).unwrap().date(), date(2024, 11, 3));
+}
+
+#[test]
+fn test_hours() {
+    let night = Night::starting_on(date(2024, 11, 3), &kitt_peak()).unwrap();
+    let hours: Vec<_> = night.hours().collect();
+    assert_eq!(hours.len(), 24);
+    assert_eq!(hours[0], Utc.with_ymd_and_hms(2024, 11, 3, 20, 0, 0).unwrap());
+    assert!(hours.iter().all(|t| night.contains(*t) && t.minute() == 0 && t.second() == 0));
+
+    // A night starting on the hour includes its start
+    let greenwich = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+    let night = Night::starting_on(date(2024, 11, 3), &greenwich).unwrap();
+    assert_eq!(night.hours().next(), Some(night.start()));
+    assert_eq!(night.hours().count(), 24);
+}
+
+#[test]
+fn test_sun_events_in_order() {
+    let site = kitt_peak();
+    let night = Night::starting_on(date(2024, 11, 3), &site).unwrap();
+    let sunset = night.sunset().unwrap().unwrap();
+    let sunrise = night.sunrise().unwrap().unwrap();
+    let mut previous = sunset;
+    for twilight in [Twilight::Civil, Twilight::Nautical, Twilight::Astronomical] {
+        let dusk = night.dusk(twilight).unwrap().unwrap();
+        assert!(dusk > previous);
+        previous = dusk;
+    }
+    for twilight in [Twilight::Astronomical, Twilight::Nautical, Twilight::Civil] {
+        let dawn = night.dawn(twilight).unwrap().unwrap();
+        assert!(dawn > previous);
+        previous = dawn;
+    }
+    assert!(sunrise > previous);
+
+    // sun_rise_set pairs each sunset with the preceding sunrise
+    let (_, set) = sun_rise_set(Utc.with_ymd_and_hms(2024, 11, 3, 0, 0, 0).unwrap(), &site).unwrap().unwrap();
+    assert!((sunset - set).num_seconds().abs() < 240);
+    let (rise, _) = sun_rise_set(Utc.with_ymd_and_hms(2024, 11, 4, 0, 0, 0).unwrap(), &site).unwrap().unwrap();
+    assert!((sunrise - rise).num_seconds().abs() < 240);
+}
+
+#[test]
+fn test_white_nights() {
+    // Oslo around the summer solstice: the Sun sets, but it never gets
+    // astronomically dark
+    let oslo = Location { latitude_deg: 59.91, longitude_deg: 10.75, altitude_m: 0.0 };
+    let night = Night::starting_on(date(2024, 6, 21), &oslo).unwrap();
+    assert!(night.sunset().unwrap().is_some());
+    assert!(night.dusk(Twilight::Civil).unwrap().is_some());
+    assert!(night.dusk(Twilight::Nautical).unwrap().is_none());
+    assert!(night.dusk(Twilight::Astronomical).unwrap().is_none());
+    assert!(night.dawn(Twilight::Astronomical).unwrap().is_none());
+
+    // Tromsø has midnight Sun
+    let tromso = Location { latitude_deg: 69.65, longitude_deg: 18.96, altitude_m: 0.0 };
+    let night = Night::starting_on(date(2024, 6, 21), &tromso).unwrap();
+    assert!(night.sunset().unwrap().is_none() && night.sunrise().unwrap().is_none());
+}
+
+#[test]
+fn test_invalid_location() {
+    let bad = Location { latitude_deg: 0.0, longitude_deg: 200.0, altitude_m: 0.0 };
+    let t = Utc.with_ymd_and_hms(2024, 11, 3, 0, 0, 0).unwrap();
+    assert!(matches!(Night::containing(t, &bad), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(Night::starting_on(date(2024, 11, 3), &bad), Err(AstroError::InvalidCoordinate { .. })));
+}
+
+#[test]
+fn test_sunset_uses_sea_level_horizon() {
+    let sea = Location { altitude_m: 0.0, ..kitt_peak() };
+    let summit = Night::starting_on(date(2024, 11, 3), &kitt_peak()).unwrap();
+    let sea = Night::starting_on(date(2024, 11, 3), &sea).unwrap();
+    assert_eq!(summit.sunset().unwrap(), sea.sunset().unwrap());
+    assert_eq!(summit.sunrise().unwrap(), sea.sunrise().unwrap());
+}
@@ -336,4 +336,57 @@ fn test_nutation_matrix_consistency() {
     // Just verify the values are reasonable
     assert!((cos_dpsi - 1.0).abs() < 0.001, "cos(dpsi) should be ~1 for small angles");
     assert!(sin_dpsi.abs() < 0.0001, "sin(dpsi) should be small");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_two_part_nutation_matches_single() {
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let jd = julian_date(dt);
+    let a = nutation(jd);
+    let b = nutation_two_part(2451545.0, jd - 2451545.0);
+    assert!((a.longitude - b.longitude).abs() < 1e-6);
+    assert!((a.obliquity - b.obliquity).abs() < 1e-6);
+}
+
+#[test]
+fn test_apply_nutation_batch() {
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let coords = vec![(0.0, 0.0), (90.0, 23.0), (279.23, 38.78), (180.0, -60.0)];
+    let batch = apply_nutation_batch(&coords, dt).unwrap();
+
+    let nut = nutation(julian_date(dt));
+    for (&(ra, dec), &(nra, ndec)) in coords.iter().zip(&batch) {
+        let single = apply_nutation(ra, dec, dt).unwrap();
+        assert_eq!(single, (nra, ndec));
+        // On-sky shift is bounded by the nutation amplitude
+        let dra = (nra - ra + 540.0) % 360.0 - 180.0;
+        let dra = dra * dec.to_radians().cos();
+        let shift = (dra.powi(2) + (ndec - dec).powi(2)).sqrt() * 3600.0;
+        assert!(shift < 2.0 * nut.longitude.abs() + nut.obliquity.abs() + 1.0, "shift {}", shift);
+    }
+
+    // At the equinox (RA = 0, Dec = 0) the RA shift is Δψ·cos ε
+    let eps = mean_obliquity(julian_date(dt)).to_radians();
+    let dra_arcsec = batch[0].0 * 3600.0;
+    let dra_arcsec = if dra_arcsec > 648000.0 { dra_arcsec - 1296000.0 } else { dra_arcsec };
+    assert!((dra_arcsec - nut.longitude * eps.cos()).abs() < 0.01, "dra {}", dra_arcsec);
+
+    assert!(apply_nutation(400.0, 0.0, dt).is_err());
+}
+
+#[test]
+fn test_checked_variants_reject_invalid_jd() {
+    use crate::error::AstroError;
+
+    let jd = 2460526.75;
+    assert_eq!(nutation_in_longitude_checked(jd).unwrap(), nutation_in_longitude(jd));
+    assert_eq!(nutation_in_obliquity_checked(jd).unwrap(), nutation_in_obliquity(jd));
+    assert_eq!(nutation_checked(jd).unwrap(), nutation(jd));
+
+    assert!(matches!(nutation_in_longitude_checked(f64::NAN), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(nutation_in_obliquity_checked(f64::NEG_INFINITY), Err(AstroError::CalculationError { .. })));
+    match nutation_checked(-1.0) {
+        Err(AstroError::OutOfRange { parameter, .. }) => assert_eq!(parameter, "Julian Date"),
+        other => panic!("Expected OutOfRange, got {:?}", other),
+    }
+}
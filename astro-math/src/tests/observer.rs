@@ -0,0 +1,175 @@
+use crate::error::AstroError;
+use crate::location::Location;
+use crate::observer::*;
+use crate::refraction::AtmosphericConditions;
+use crate::transforms::ra_dec_to_alt_az_erfa;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn epoch() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap()
+}
+
+fn site() -> Location {
+    Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 }
+}
+
+/// Angular distance between two RA/Dec positions in arcseconds
+fn separation_arcsec(a: &ObservedPlace, b: &ObservedPlace) -> f64 {
+    let (ra1, dec1, ra2, dec2) = (a.ra.to_radians(), a.dec.to_radians(), b.ra.to_radians(), b.dec.to_radians());
+    let dra = ra2 - ra1;
+    let x = dec1.cos() * dec2.sin() - dec1.sin() * dec2.cos() * dra.cos();
+    let y = dec2.cos() * dra.sin();
+    let z = dec1.sin() * dec2.sin() + dec1.cos() * dec2.cos() * dra.cos();
+    x.hypot(y).atan2(z).to_degrees() * 3600.0
+}
+
+#[test]
+fn test_space_observer_at_rest_matches_geocentric_cirs() {
+    let observer = ObserverKind::space([7000.0, 0.0, 0.0], [0.0; 3]).unwrap();
+    let place = observed_place(101.28715533, -16.71611586, None, epoch(), &observer, None).unwrap();
+
+    let (tt1, tt2) = crate::time_scales::utc_to_tt_jd_two_part(
+        crate::time::julian_date_two_part(epoch()).0,
+        crate::time::julian_date_two_part(epoch()).1,
+    );
+    let (ri, di, _) = crate::backend::astrometry::Atci13(
+        101.28715533f64.to_radians(),
+        -16.71611586f64.to_radians(),
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        tt1,
+        tt2,
+    );
+    assert!((place.ra - ri.to_degrees()).abs() < 1e-9);
+    assert!((place.dec - di.to_degrees()).abs() < 1e-9);
+    assert!(place.alt_az.is_none());
+}
+
+#[test]
+fn test_platform_velocity_aberration() {
+    // A star on the x axis and 7.5 km/s along y: v/c = 5.16″ towards +y
+    let at_rest = ObserverKind::space([7000.0, 0.0, 0.0], [0.0; 3]).unwrap();
+    let moving = ObserverKind::space([7000.0, 0.0, 0.0], [0.0, 7.5, 0.0]).unwrap();
+    let along = ObserverKind::space([7000.0, 0.0, 0.0], [7.5, 0.0, 0.0]).unwrap();
+
+    let reference = observed_place(0.0, 0.0, None, epoch(), &at_rest, None).unwrap();
+    let shifted = observed_place(0.0, 0.0, None, epoch(), &moving, None).unwrap();
+    let unshifted = observed_place(0.0, 0.0, None, epoch(), &along, None).unwrap();
+
+    assert!((separation_arcsec(&reference, &shifted) - 5.16).abs() < 0.01);
+    assert!(separation_arcsec(&reference, &unshifted) < 1e-3);
+}
+
+#[test]
+fn test_space_parallax_of_the_moon() {
+    let distance_au = 384400.0 / 149597870.7;
+    let geocentre = observed_place(90.0, 0.0, None, epoch(), &ObserverKind::space([6400.0, 0.0, 0.0], [0.0; 3]).unwrap(), None).unwrap();
+
+    // Displaced along the line of sight: no parallax
+    let in_line = ObserverKind::space([0.0, 42164.0, 0.0], [0.0; 3]).unwrap();
+    let place = observed_place(90.0, 0.0, Some(distance_au), epoch(), &in_line, None).unwrap();
+    assert!(separation_arcsec(&geocentre, &place) < 1e-3);
+
+    // Displaced perpendicular to it: atan(42164 / 384400) = 6.26°
+    let across = ObserverKind::space([42164.0, 0.0, 0.0], [0.0; 3]).unwrap();
+    let place = observed_place(90.0, 0.0, Some(distance_au), epoch(), &across, None).unwrap();
+    assert!((separation_arcsec(&geocentre, &place) / 3600.0 - 6.26).abs() < 0.01);
+}
+
+#[test]
+fn test_ground_without_refraction_matches_transforms() {
+    let observer = ObserverKind::Ground(site());
+    for (ra, dec) in [(279.23473479, 38.78368896), (101.28715533, -16.71611586), (10.0, 60.0)] {
+        let place = observed_place(ra, dec, None, epoch(), &observer, None).unwrap();
+        let (alt, az) = place.alt_az.unwrap();
+        let (expected_alt, expected_az) =
+            ra_dec_to_alt_az_erfa(ra, dec, epoch(), &site(), Some(0.0), Some(0.0), Some(0.0)).unwrap();
+        assert!((alt - expected_alt).abs() < 1e-6, "alt {} vs {}", alt, expected_alt);
+        assert!((az - expected_az).abs() < 1e-6, "az {} vs {}", az, expected_az);
+    }
+}
+
+#[test]
+fn test_refraction_only_on_the_ground() {
+    let weather = Some(AtmosphericConditions::standard());
+    let ground = ObserverKind::Ground(site());
+    let dry = observed_place(279.23473479, 38.78368896, None, epoch(), &ground, None).unwrap();
+    let wet = observed_place(279.23473479, 38.78368896, None, epoch(), &ground, weather).unwrap();
+    assert!(wet.alt_az.unwrap().0 > dry.alt_az.unwrap().0);
+
+    // Weather is ignored above the atmosphere
+    let space = ObserverKind::space([7000.0, 0.0, 0.0], [0.0, 7.5, 0.0]).unwrap();
+    assert!(!space.has_atmosphere());
+    assert!(ground.has_atmosphere());
+    assert_eq!(
+        observed_place(279.23473479, 38.78368896, None, epoch(), &space, None).unwrap(),
+        observed_place(279.23473479, 38.78368896, None, epoch(), &space, weather).unwrap()
+    );
+}
+
+#[test]
+fn test_ground_parallax_matches_space_observer_at_site() {
+    // A ground site without weather and a space observer at the same GCRS
+    // position see the Moon with the same parallax; they differ only by
+    // diurnal aberration (< 0.32″)
+    let ground = ObserverKind::Ground(site());
+    let position = ground.gcrs_position_km(epoch());
+    let space = ObserverKind::space(position, [0.0; 3]).unwrap();
+    let distance_au = 0.00257;
+
+    let a = observed_place(200.0, -10.0, Some(distance_au), epoch(), &ground, None).unwrap();
+    let b = observed_place(200.0, -10.0, Some(distance_au), epoch(), &space, None).unwrap();
+    assert!(separation_arcsec(&a, &b) < 0.35, "{}″", separation_arcsec(&a, &b));
+
+    // Without parallax the geocentric place is tens of arcminutes away
+    let c = observed_place(200.0, -10.0, None, epoch(), &space, None).unwrap();
+    assert!(separation_arcsec(&b, &c) > 600.0);
+}
+
+#[test]
+fn test_ground_gcrs_position_rotates() {
+    let ground = ObserverKind::Ground(site());
+    let r0 = ground.gcrs_position_km(epoch());
+    let radius = (r0[0] * r0[0] + r0[1] * r0[1] + r0[2] * r0[2]).sqrt();
+    assert!((radius - 6374.0).abs() < 5.0, "{}", radius);
+    assert!((r0[2] - radius * 31.8f64.to_radians().sin()).abs() < 30.0);
+
+    // Half a sidereal day later the site is on the other side of the
+    // rotation axis, which is the pole of date rather than the GCRS z axis
+    let later = epoch() + Duration::seconds(43082);
+    let r1 = ground.gcrs_position_km(later);
+    let (jd1, jd2) = crate::time::julian_date_two_part(later);
+    let (tt1, tt2) = crate::time_scales::utc_to_tt_jd_two_part(jd1, jd2);
+    let pole = crate::erfa::bias_precession_nutation_matrix(tt1, tt2)[2];
+    let mid = [0, 1, 2].map(|i| (r0[i] + r1[i]) / 2.0);
+    let along = mid[0] * pole[0] + mid[1] * pole[1] + mid[2] * pole[2];
+    let across = (0..3).map(|i| (mid[i] - along * pole[i]).powi(2)).sum::<f64>().sqrt();
+    assert!(across < 1.0, "{} km off the axis", across);
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert!(matches!(ObserverKind::space([6000.0, 0.0, 0.0], [0.0; 3]), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(
+        ObserverKind::space([7000.0, f64::NAN, 0.0], [0.0; 3]),
+        Err(AstroError::OutOfRange { .. })
+    ));
+    assert!(matches!(
+        ObserverKind::space([7000.0, 0.0, 0.0], [300000.0, 0.0, 0.0]),
+        Err(AstroError::OutOfRange { .. })
+    ));
+
+    let space = ObserverKind::space([7000.0, 0.0, 0.0], [0.0; 3]).unwrap();
+    assert!(matches!(
+        observed_place(400.0, 0.0, None, epoch(), &space, None),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(
+        observed_place(10.0, 0.0, Some(0.0), epoch(), &space, None),
+        Err(AstroError::OutOfRange { .. })
+    ));
+    let bad_weather = AtmosphericConditions { relative_humidity: 2.0, ..AtmosphericConditions::standard() };
+    assert!(observed_place(10.0, 0.0, None, epoch(), &ObserverKind::Ground(site()), Some(bad_weather)).is_err());
+}
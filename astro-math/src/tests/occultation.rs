@@ -0,0 +1,192 @@
+use crate::besselian::BesselianElements;
+use crate::error::AstroError;
+use crate::location::Location;
+use crate::occultation::*;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+/// A 5.3 km/s shadow crossing central Europe eastwards
+fn elements() -> BesselianElements {
+    BesselianElements {
+        t0: Utc.with_ymd_and_hms(2024, 11, 3, 21, 30, 0).unwrap(),
+        delta_t_s: 69.184,
+        x: [-0.2245, 3.0, 0.0, 0.0],
+        y: [0.4935, 0.0, 0.0, 0.0],
+        d: [20.0, 0.0, 0.0, 0.0],
+        mu: [330.0, 15.041, 0.0, 0.0],
+        l1: [0.0047, 0.0, 0.0, 0.0],
+        l2: [0.0047, 0.0, 0.0, 0.0],
+        tan_f1: 0.0,
+        tan_f2: 0.0,
+    }
+}
+
+fn site(latitude_deg: f64) -> Location {
+    Location { latitude_deg, longitude_deg: 10.0, altitude_m: 350.0 }
+}
+
+/// Times the star would vanish and reappear behind a profile with
+/// `inside(x, y) < 0`, to the millisecond
+fn observe(location: Location, inside: impl Fn(f64, f64) -> f64) -> Option<OccultationTiming> {
+    let e = elements();
+    let f = |t: DateTime<Utc>| {
+        let (x, y) = shadow_offset_km(&e, &location, t).unwrap();
+        inside(x, y)
+    };
+    let refine = |mut a: DateTime<Utc>, mut b: DateTime<Utc>| {
+        while b - a > Duration::milliseconds(1) {
+            let mid = a + (b - a) / 2;
+            if (f(mid) < 0.0) == (f(a) < 0.0) {
+                a = mid;
+            } else {
+                b = mid;
+            }
+        }
+        b
+    };
+    let step = Duration::milliseconds(50);
+    let mut t = e.t0 - Duration::seconds(120);
+    let mut events = Vec::new();
+    while t < e.t0 + Duration::seconds(120) {
+        if (f(t) < 0.0) != (f(t + step) < 0.0) {
+            events.push(refine(t, t + step));
+        }
+        t += step;
+    }
+    match events[..] {
+        [disappearance, reappearance] => Some(OccultationTiming { location, disappearance, reappearance }),
+        _ => None,
+    }
+}
+
+fn circle(cx: f64, cy: f64, r: f64) -> impl Fn(f64, f64) -> f64 {
+    move |x, y| (x - cx).hypot(y - cy) - r
+}
+
+fn ellipse(cx: f64, cy: f64, a: f64, b: f64, pa_deg: f64) -> impl Fn(f64, f64) -> f64 {
+    let (s, c) = pa_deg.to_radians().sin_cos();
+    move |x, y| {
+        // Major axis along (sin PA, cos PA)
+        let (u, v) = (x - cx, y - cy);
+        let along = u * s + v * c;
+        let across = u * c - v * s;
+        (along / a).powi(2) + (across / b).powi(2) - 1.0
+    }
+}
+
+#[test]
+fn test_fundamental_plane_matches_projection() {
+    // ξ, η are the observer's geocentric position projected on the plane
+    // perpendicular to the shadow axis, x east and y north
+    let e = elements();
+    let t = e.t0 + Duration::seconds(90);
+    let location = site(49.0);
+    let (xi, eta) = fundamental_plane_coordinates(&e, &location, t).unwrap();
+
+    let plane = e.at(t);
+    let [x, y, z] = crate::observer::terrestrial_position_km(&location);
+    let hour_angle = (plane.mu.to_radians() + y.atan2(x)).rem_euclid(std::f64::consts::TAU);
+    // Observer vector in a frame whose x axis points at the shadow axis' meridian
+    let r = [x.hypot(y) * hour_angle.cos(), x.hypot(y) * hour_angle.sin(), z].map(|c| c / 6378.137);
+    let d = plane.d.to_radians();
+    let axis = [d.cos(), 0.0, d.sin()];
+    let east = [0.0, 1.0, 0.0];
+    let north = crate::vec3::cross(axis, east);
+    assert!((xi - crate::vec3::dot(r, east)).abs() < 1e-12, "{}", xi);
+    assert!((eta - crate::vec3::dot(r, north)).abs() < 1e-12, "{}", eta);
+    // Rotation under the shadow: ξ changes at about ρ cos φ' · 15°/h
+    let (later, _) = fundamental_plane_coordinates(&e, &location, t + Duration::hours(1)).unwrap();
+    assert!((later - xi).abs() > 0.1 && (later - xi).abs() < 0.3);
+}
+
+#[test]
+fn test_chords_lie_on_profile() {
+    let profile = circle(3.0, -2.0, 30.0);
+    let timings: Vec<_> = [48.85, 49.0, 49.1].iter().map(|&lat| observe(site(lat), &profile).unwrap()).collect();
+    let chords = reduce_chords(&elements(), &timings).unwrap();
+    assert_eq!(chords.len(), 3);
+    for chord in &chords {
+        for (x, y) in [chord.disappearance, chord.reappearance] {
+            assert!(profile(x, y).abs() < 0.02, "{} {}", x, y);
+        }
+        // The shadow moves east, so the observer crosses it westwards
+        assert!(chord.reappearance.0 < chord.disappearance.0);
+        let (mx, my) = chord.midpoint();
+        assert!((mx - 3.0).abs() < 0.5 && my.abs() < 30.0, "{} {}", mx, my);
+        assert!(chord.length_km() < 60.0);
+    }
+    assert_eq!(chords[1].location.latitude_deg, 49.0);
+}
+
+#[test]
+fn test_fit_circle_recovers_profile() {
+    let profile = circle(3.0, -2.0, 30.0);
+    let timings: Vec<_> = [48.85, 49.0, 49.1].iter().map(|&lat| observe(site(lat), &profile).unwrap()).collect();
+    let fit = fit_circle(&reduce_chords(&elements(), &timings).unwrap()).unwrap();
+    assert!((fit.center_km.0 - 3.0).abs() < 0.02, "{:?}", fit);
+    assert!((fit.center_km.1 + 2.0).abs() < 0.02, "{:?}", fit);
+    assert!((fit.semi_major_km - 30.0).abs() < 0.02);
+    assert_eq!(fit.semi_major_km, fit.semi_minor_km);
+    assert_eq!(fit.position_angle_deg, 0.0);
+    assert!((fit.equivalent_diameter_km() - 60.0).abs() < 0.05);
+    assert!(fit.rms_km < 0.01);
+
+    // Two chords are enough for a circle
+    let fit = fit_circle(&reduce_chords(&elements(), &timings[..2]).unwrap()).unwrap();
+    assert!((fit.semi_major_km - 30.0).abs() < 0.05);
+}
+
+#[test]
+fn test_fit_ellipse_recovers_profile() {
+    let profile = ellipse(-5.0, 4.0, 45.0, 25.0, 60.0);
+    let timings: Vec<_> = [48.8, 48.9, 49.0, 49.1, 49.2]
+        .iter()
+        .filter_map(|&lat| observe(site(lat), &profile))
+        .collect();
+    assert_eq!(timings.len(), 5);
+    let chords = reduce_chords(&elements(), &timings).unwrap();
+    let fit = fit_ellipse(&chords).unwrap();
+    assert!((fit.center_km.0 + 5.0).abs() < 0.05, "{:?}", fit);
+    assert!((fit.center_km.1 - 4.0).abs() < 0.05, "{:?}", fit);
+    assert!((fit.semi_major_km - 45.0).abs() < 0.1, "{:?}", fit);
+    assert!((fit.semi_minor_km - 25.0).abs() < 0.1, "{:?}", fit);
+    assert!((fit.position_angle_deg - 60.0).abs() < 0.2, "{:?}", fit);
+    assert!(fit.rms_km < 0.02);
+
+    // A circle through an elongated profile leaves large residuals
+    let circular = fit_circle(&chords).unwrap();
+    assert!(circular.rms_km > 1.0, "{:?}", circular);
+}
+
+#[test]
+fn test_insufficient_chords() {
+    let profile = circle(0.0, 0.0, 30.0);
+    let timings: Vec<_> = [48.9, 49.0, 49.1].iter().map(|&lat| observe(site(lat), &profile).unwrap()).collect();
+    let chords = reduce_chords(&elements(), &timings).unwrap();
+
+    let calculation_error = |r: crate::error::Result<ProfileFit>| matches!(r, Err(AstroError::CalculationError { .. }));
+    assert!(calculation_error(fit_circle(&chords[..1])));
+    assert!(calculation_error(fit_circle(&[])));
+    assert!(calculation_error(fit_ellipse(&chords[..2])));
+    // Chords along one line do not determine a circle
+    let on_line = |x0: f64, x1: f64| Chord { location: site(49.0), disappearance: (x0, 0.5 * x0), reappearance: (x1, 0.5 * x1) };
+    let collinear = [on_line(20.0, -10.0), on_line(40.0, 5.0)];
+    assert!(calculation_error(fit_circle(&collinear)));
+    let mut bad = chords.clone();
+    bad[0].reappearance.0 = f64::NAN;
+    assert!(calculation_error(fit_circle(&bad)));
+}
+
+#[test]
+fn test_invalid_timings() {
+    let e = elements();
+    let at = e.t0;
+    let reversed = OccultationTiming { location: site(49.0), disappearance: at + Duration::seconds(5), reappearance: at };
+    assert!(matches!(reduce_chords(&e, &[reversed]), Err(AstroError::InvalidDateTime { .. })));
+    let nowhere = OccultationTiming {
+        location: Location { latitude_deg: 95.0, longitude_deg: 0.0, altitude_m: 0.0 },
+        disappearance: at,
+        reappearance: at,
+    };
+    assert!(matches!(reduce_chords(&e, &[nowhere]), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(reduce_chords(&e, &[]).unwrap().is_empty());
+}
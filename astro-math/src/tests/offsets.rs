@@ -0,0 +1,53 @@
+use crate::error::AstroError;
+use crate::offsets::*;
+
+#[test]
+fn test_round_trip() {
+    let cases = [(10.0, 20.0, 30.0, -45.0), (200.0, -60.0, -600.0, 300.0), (359.999, 89.9, 500.0, 500.0)];
+    for &(ra, dec, dx, dy) in &cases {
+        let (ra2, dec2) = offset_position(ra, dec, dx, dy).unwrap();
+        let (dx2, dy2) = offset_between(ra, dec, ra2, dec2).unwrap();
+        assert!((dx - dx2).abs() < 1e-6 && (dy - dy2).abs() < 1e-6, "{:?}: {} {}", (ra, dec), dx2, dy2);
+        assert!((0.0..360.0).contains(&ra2));
+    }
+}
+
+#[test]
+fn test_small_offset_matches_cos_dec() {
+    for dec in [-70.0, -30.0, 0.0, 45.0, 80.0] {
+        let (east, north) = offset_between(120.0, dec, 120.001, dec + 0.001).unwrap();
+        let expected_east = 3.6 * f64::to_radians(dec).cos();
+        assert!((east - expected_east).abs() < 1e-3, "dec {}: {}", dec, east);
+        assert!((north - 3.6).abs() < 1e-3, "dec {}: {}", dec, north);
+    }
+}
+
+#[test]
+fn test_offset_across_pole() {
+    // Two points 36″ either side of the north pole
+    let (east, north) = offset_between(30.0, 89.995, 210.0, 89.995).unwrap();
+    assert!(east.abs() < 1e-6, "east {}", east);
+    assert!((north - 36.0).abs() < 1e-6, "north {}", north);
+
+    // Offsets from the pole itself stay finite
+    let (ra, dec) = offset_position(0.0, 90.0, 0.0, -36.0).unwrap();
+    assert!((dec - 89.99).abs() < 1e-9, "dec {}", dec);
+    assert!(ra.abs() < 1e-9, "ra {}", ra);
+}
+
+#[test]
+fn test_ra_wrap() {
+    let (ra, _) = offset_position(0.0005, 0.0, -3.6, 0.0).unwrap();
+    assert!((ra - 359.9995).abs() < 1e-9, "ra {}", ra);
+
+    let (east, _) = offset_between(0.0005, 0.0, 359.9995, 0.0).unwrap();
+    assert!((east + 3.6).abs() < 1e-6, "east {}", east);
+}
+
+#[test]
+fn test_errors() {
+    assert!(matches!(offset_between(0.0, 0.0, 180.0, 0.0), Err(AstroError::ProjectionError { .. })));
+    assert!(matches!(offset_position(0.0, 0.0, f64::NAN, 0.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(offset_position(0.0, 91.0, 0.0, 0.0), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(offset_between(400.0, 0.0, 0.0, 0.0).is_err());
+}
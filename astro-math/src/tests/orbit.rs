@@ -0,0 +1,98 @@
+use crate::error::AstroError;
+use crate::orbit::*;
+
+fn earth_elements() -> OrbitalElements {
+    // Mean elements of the Earth–Moon barycentre at J2000.0 (Standish 1992)
+    OrbitalElements {
+        epoch_jd_tdb: 2451545.0,
+        semi_major_axis_au: 1.00000261,
+        eccentricity: 0.01671123,
+        inclination_deg: 0.0,
+        ascending_node_deg: 0.0,
+        argument_of_perihelion_deg: 102.93768193,
+        mean_anomaly_deg: 100.46457166 - 102.93768193 + 360.0,
+    }
+}
+
+fn test_elements() -> OrbitalElements {
+    OrbitalElements {
+        epoch_jd_tdb: 2460600.5,
+        semi_major_axis_au: 2.3,
+        eccentricity: 0.35,
+        inclination_deg: 17.0,
+        ascending_node_deg: 123.4,
+        argument_of_perihelion_deg: 287.6,
+        mean_anomaly_deg: 45.0,
+    }
+}
+
+#[test]
+fn test_solve_kepler() {
+    for &e in &[0.0, 0.1, 0.5, 0.9, 0.99] {
+        for i in -10..=10 {
+            let m = i as f64 * 0.7;
+            let ea = solve_kepler(m, e);
+            assert!((ea - e * ea.sin() - m).abs() < 1e-12, "e={} M={}", e, m);
+        }
+    }
+}
+
+#[test]
+fn test_earth_matches_ephemeris() {
+    let state = earth_elements().to_state_vector().unwrap();
+    let (pvh, _) = crate::backend::ephemerides::Epv00(2451545.0, 0.0);
+    for i in 0..3 {
+        // Earth centre vs EMB plus mean-element error: well under 0.001 AU
+        assert!((state.position[i] - pvh[i]).abs() < 1e-3, "axis {}", i);
+        assert!((state.velocity[i] - pvh[i + 3]).abs() < 1e-5, "axis {}", i);
+    }
+}
+
+#[test]
+fn test_elements_round_trip() {
+    let elements = test_elements();
+    let state = elements.to_state_vector().unwrap();
+    let back = OrbitalElements::from_state_vector(&state).unwrap();
+
+    assert!((back.semi_major_axis_au - elements.semi_major_axis_au).abs() < 1e-12);
+    assert!((back.eccentricity - elements.eccentricity).abs() < 1e-12);
+    assert!((back.inclination_deg - elements.inclination_deg).abs() < 1e-10);
+    assert!((back.ascending_node_deg - elements.ascending_node_deg).abs() < 1e-10);
+    assert!((back.argument_of_perihelion_deg - elements.argument_of_perihelion_deg).abs() < 1e-9);
+    assert!((back.mean_anomaly_deg - elements.mean_anomaly_deg).abs() < 1e-9);
+}
+
+#[test]
+fn test_two_body_conserves_energy_and_period() {
+    let elements = test_elements();
+    let energy = |s: &StateVector| s.speed_au_per_day().powi(2) / 2.0 - GM_SUN / s.distance_au();
+
+    let s0 = elements.to_state_vector().unwrap();
+    let s1 = elements.state_at(elements.epoch_jd_tdb + 1234.5).unwrap();
+    assert!((energy(&s0) - energy(&s1)).abs() < 1e-15);
+
+    let s2 = elements.state_at(elements.epoch_jd_tdb + elements.period_days()).unwrap();
+    for i in 0..3 {
+        assert!((s0.position[i] - s2.position[i]).abs() < 1e-10);
+    }
+
+    assert!((elements.perihelion_au() - 1.495).abs() < 1e-12);
+    assert!((elements.aphelion_au() - 3.105).abs() < 1e-12);
+}
+
+#[test]
+fn test_invalid_elements() {
+    let mut elements = test_elements();
+    elements.eccentricity = 1.2;
+    assert!(matches!(elements.to_state_vector(), Err(AstroError::OutOfRange { .. })));
+
+    let mut elements = test_elements();
+    elements.semi_major_axis_au = -1.0;
+    assert!(matches!(elements.validate(), Err(AstroError::OutOfRange { .. })));
+
+    let escaping = StateVector { jd_tdb: 2451545.0, position: [1.0, 0.0, 0.0], velocity: [0.0, 0.03, 0.0] };
+    assert!(matches!(
+        OrbitalElements::from_state_vector(&escaping),
+        Err(AstroError::CalculationError { .. })
+    ));
+}
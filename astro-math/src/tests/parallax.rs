@@ -123,4 +123,45 @@ fn test_annual_parallax_wraparound_branches() {
     // Test case that results in RA > 360 needing wrap
     let (ra, _) = annual_parallax(359.999, 0.0, 100.0, dt).unwrap();
     assert!((0.0..360.0).contains(&ra), "RA should be normalized after exceeding 360");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_moon_horizontal_parallax_size() {
+    // On the equator with the Moon on the western horizon, it is displaced
+    // east by its full horizontal parallax, about 57′
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 22, 0, 0).unwrap();
+    let location = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let ra = (location.local_sidereal_time(dt) * 15.0 - 90.0).rem_euclid(360.0);
+    let (ra_topo, dec_topo) = diurnal_parallax(ra, 0.0, 0.00257, dt, &location).unwrap();
+    let shift = (ra - ra_topo + 540.0).rem_euclid(360.0) - 180.0;
+    assert!((shift - 0.951).abs() < 0.01, "{}", shift);
+    assert!(dec_topo.abs() < 1e-6);
+}
+
+#[test]
+fn test_geocentric_factors_palomar() {
+    // Meeus, Astronomical Algorithms, example 11.a: Palomar Observatory at
+    // φ = +33°21′22″, 1706 m, has ρ·sin φ′ = 0.546861 and ρ·cos φ′ = 0.836339
+    let palomar = Location {
+        latitude_deg: 33.0 + 21.0 / 60.0 + 22.0 / 3600.0,
+        longitude_deg: -116.8625,
+        altitude_m: 1706.0,
+    };
+    let (rho_sin, rho_cos) = geocentric_factors(&palomar);
+    assert!((rho_sin - 0.546861).abs() < 1e-5, "ρ·sin φ′ = {}", rho_sin);
+    assert!((rho_cos - 0.836339).abs() < 1e-5, "ρ·cos φ′ = {}", rho_cos);
+    assert!((geocentric_distance(&palomar) - 0.546861_f64.hypot(0.836339)).abs() < 1e-5);
+}
+
+#[test]
+fn test_lunar_horizontal_parallax_meeus() {
+    // Meeus, example 47.a: at 368409.7 km the Moon's equatorial horizontal
+    // parallax is 0.991990°, all of which an observer on the equator sees
+    // with the Moon on the horizon
+    let dt = Utc.with_ymd_and_hms(1992, 4, 12, 0, 0, 0).unwrap();
+    let location = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let ra = (location.local_sidereal_time(dt) * 15.0 - 90.0).rem_euclid(360.0);
+    let (ra_topo, _) = diurnal_parallax(ra, 0.0, 368_409.7 / 149_597_870.7, dt, &location).unwrap();
+    let shift = (ra - ra_topo + 540.0).rem_euclid(360.0) - 180.0;
+    assert!((shift - 0.991990).abs() < 1e-5, "{}", shift);
+}
@@ -0,0 +1,52 @@
+use crate::almanac::{apparent_place, AlmanacBody};
+use crate::ephemeris::topocentric_radec;
+use crate::error::AstroError;
+use crate::planets::*;
+use crate::Location;
+use chrono::{Duration, TimeZone, Utc};
+
+#[test]
+fn test_venus_meeus_example() {
+    // Meeus, Astronomical Algorithms, example 33.a: 1992 December 20, 0h TD
+    let dt = Utc.with_ymd_and_hms(1992, 12, 20, 0, 0, 0).unwrap() - Duration::milliseconds(59_184);
+    let venus = planet_position(Planet::Venus, dt).unwrap();
+    let expected_ra = (21.0 + 4.0 / 60.0 + 41.454 / 3600.0) * 15.0;
+    let expected_dec: f64 = -(18.0 + 53.0 / 60.0 + 16.84 / 3600.0);
+    assert!((venus.ra - expected_ra).abs() * 3600.0 * expected_dec.to_radians().cos() < 5.0);
+    assert!((venus.dec - expected_dec).abs() * 3600.0 < 5.0);
+    assert!((venus.distance_au - 0.910947).abs() < 1e-5);
+}
+
+#[test]
+fn test_matches_almanac() {
+    let dt = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    let pairs = [
+        (Planet::Mercury, AlmanacBody::Mercury),
+        (Planet::Saturn, AlmanacBody::Saturn),
+        (Planet::Neptune, AlmanacBody::Neptune),
+    ];
+    for (planet, body) in pairs {
+        assert_eq!(planet_position(planet, dt).unwrap(), apparent_place(body, dt).unwrap());
+    }
+}
+
+#[test]
+fn test_topocentric_matches_vector_parallax() {
+    let site = Location { latitude_deg: 35.0, longitude_deg: 139.0, altitude_m: 50.0 };
+    let dt = Utc.with_ymd_and_hms(2026, 10, 24, 0, 0, 0).unwrap();
+    for (planet, body) in [(Planet::Venus, AlmanacBody::Venus), (Planet::Mars, AlmanacBody::Mars)] {
+        let place = topocentric_planet_position(planet, dt, &site).unwrap();
+        let (ra, dec) = topocentric_radec(&body, dt, &site).unwrap();
+        assert!((place.ra - ra).abs() * 3600.0 < 0.05, "{:?}", planet);
+        assert!((place.dec - dec).abs() * 3600.0 < 0.05, "{:?}", planet);
+    }
+}
+
+#[test]
+fn test_errors() {
+    let dt = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    assert!(matches!(planet_position(Planet::EarthMoon, dt), Err(AstroError::CalculationError { .. })));
+    let bad = Location { latitude_deg: 0.0, longitude_deg: 200.0, altitude_m: 0.0 };
+    let result = topocentric_planet_position(Planet::Mars, dt, &bad);
+    assert!(matches!(result, Err(AstroError::InvalidCoordinate { .. })));
+}
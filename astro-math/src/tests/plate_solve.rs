@@ -0,0 +1,100 @@
+use crate::error::AstroError;
+use crate::plate_solve::*;
+use crate::projection::TangentPlane;
+
+/// Deterministic uniform numbers in [0, 1)
+fn lcg(seed: &mut u64) -> f64 {
+    *seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+    (*seed >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Stars within a degree of (ra0, dec0), brightest first
+fn field(ra0: f64, dec0: f64, count: usize, seed: &mut u64) -> Vec<(f64, f64)> {
+    (0..count)
+        .map(|_| {
+            let dec = dec0 + 2.0 * lcg(seed) - 1.0;
+            let ra = ra0 + (2.0 * lcg(seed) - 1.0) / dec0.to_radians().cos();
+            (ra.rem_euclid(360.0), dec)
+        })
+        .collect()
+}
+
+#[test]
+fn test_blind_match_of_shuffled_noisy_detections() {
+    let mut seed = 7;
+    let catalog = field(0.2, 45.0, 60, &mut seed);
+    let truth = TangentPlane::new(0.1, 45.2, 6.0)
+        .unwrap()
+        .with_reference_pixel(1024.0, 768.0)
+        .with_rotation(-125.0);
+
+    // Stars on a 2048 × 1536 frame, with 0.3 px of noise, swapped
+    // neighbours in the brightness order and two spurious detections
+    let mut detections: Vec<(f64, f64)> = catalog
+        .iter()
+        .filter_map(|&(ra, dec)| truth.ra_dec_to_pixel(ra, dec).ok())
+        .filter(|&(x, y)| (0.0..2048.0).contains(&x) && (0.0..1536.0).contains(&y))
+        .map(|(x, y)| (x + 0.6 * lcg(&mut seed) - 0.3, y + 0.6 * lcg(&mut seed) - 0.3))
+        .collect();
+    for i in (0..detections.len() - 1).step_by(3) {
+        detections.swap(i, i + 1);
+    }
+    detections.insert(2, (100.0, 1400.0));
+    detections.insert(6, (1900.0, 50.0));
+    let stars_in_frame = detections.len() - 2;
+    assert!(stars_in_frame > 25);
+
+    let candidates = match_quads(&detections, &catalog, 2.0..=20.0).unwrap();
+    let best = candidates[0];
+    assert_eq!(best.matched, stars_in_frame);
+    assert!((best.plane.scale - 6.0).abs() < 0.01, "{:?}", best.plane);
+    assert!((best.plane.rotation + 125.0).abs() < 0.05, "{:?}", best.plane);
+    for (x, y) in [(0.0, 0.0), (2048.0, 1536.0), (1024.0, 768.0)] {
+        let (ra, dec) = best.plane.pixel_to_ra_dec(x, y).unwrap();
+        let (ra_true, dec_true) = truth.pixel_to_ra_dec(x, y).unwrap();
+        assert!(crate::angles::separation(ra, dec, ra_true, dec_true) * 3600.0 < 3.0);
+    }
+    assert!(candidates.iter().skip(1).all(|other| other.matched < best.matched));
+}
+
+#[test]
+fn test_scale_range_excludes_the_solution() {
+    let mut seed = 11;
+    let catalog = field(200.0, -30.0, 20, &mut seed);
+    let truth = TangentPlane::new(200.0, -30.0, 10.0).unwrap();
+    let detections: Vec<_> = catalog.iter().map(|&(ra, dec)| truth.ra_dec_to_pixel(ra, dec).unwrap()).collect();
+
+    assert!(match_quads(&detections, &catalog, 8.0..=12.0).unwrap()[0].matched == 20);
+    assert!(match_quads(&detections, &catalog, 1.0..=5.0).unwrap().is_empty());
+}
+
+#[test]
+fn test_mirrored_frame_needs_flipping() {
+    let mut seed = 3;
+    let catalog = field(80.0, 10.0, 15, &mut seed);
+    let truth = TangentPlane::new(80.0, 10.0, 8.0).unwrap();
+    let mirrored: Vec<_> = catalog
+        .iter()
+        .map(|&(ra, dec)| {
+            let (x, y) = truth.ra_dec_to_pixel(ra, dec).unwrap();
+            (-x, y)
+        })
+        .collect();
+    assert!(match_quads(&mirrored, &catalog, 1.0..=20.0).unwrap().is_empty());
+    let flipped: Vec<_> = mirrored.iter().map(|&(x, y)| (-x, y)).collect();
+    assert_eq!(match_quads(&flipped, &catalog, 1.0..=20.0).unwrap()[0].matched, 15);
+}
+
+#[test]
+fn test_match_quads_validation() {
+    let stars = [(10.0, 10.0), (10.1, 10.0), (10.0, 10.1), (10.1, 10.1)];
+    let pixels = [(0.0, 0.0), (100.0, 0.0), (0.0, 100.0), (100.0, 100.0)];
+    assert!(matches!(match_quads(&pixels[..3], &stars, 1.0..=10.0), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(match_quads(&pixels, &stars[..3], 1.0..=10.0), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(match_quads(&pixels, &stars, 0.0..=10.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(match_quads(&pixels, &stars, 10.0..=1.0), Err(AstroError::OutOfRange { .. })));
+    let bad = [(10.0, 10.0), (10.1, 10.0), (10.0, 95.0), (10.1, 10.1)];
+    assert!(matches!(match_quads(&pixels, &bad, 1.0..=10.0), Err(AstroError::InvalidCoordinate { .. })));
+    let nan = [(f64::NAN, 0.0), (100.0, 0.0), (0.0, 100.0), (100.0, 100.0)];
+    assert!(matches!(match_quads(&nan, &stars, 1.0..=10.0), Err(AstroError::CalculationError { .. })));
+}
@@ -0,0 +1,120 @@
+use crate::pointing_model::*;
+use crate::error::AstroError;
+
+#[test]
+fn test_empty_model_is_identity() {
+    let model = PointingModel::new();
+    let (ha, dec) = model.apply(-30.0, 45.0, 40.0);
+    assert_eq!(ha, -30.0);
+    assert_eq!(dec, 45.0);
+}
+
+#[test]
+fn test_apply_remove_round_trip() {
+    let model = PointingModel::new()
+        .with_term(PointingTerm::IH, -45.2)
+        .with_term(PointingTerm::ID, 12.7)
+        .with_term(PointingTerm::CH, 30.1)
+        .with_term(PointingTerm::NP, -8.4)
+        .with_term(PointingTerm::MA, 60.0)
+        .with_term(PointingTerm::ME, -25.0)
+        .with_term(PointingTerm::TF, 15.0)
+        .with_term(PointingTerm::FO, 3.0)
+        .with_term(PointingTerm::DAF, -4.0);
+
+    for &(ha, dec) in &[(0.0, 0.0), (45.0, 60.0), (-75.0, -20.0), (120.0, 80.0)] {
+        let (mha, mdec) = model.apply(ha, dec, 35.0);
+        let (rha, rdec) = model.remove(mha, mdec, 35.0);
+        assert!((rha - ha).abs() * 3600.0 < 1e-6, "HA round trip failed at {}, {}", ha, dec);
+        assert!((rdec - dec).abs() * 3600.0 < 1e-6, "Dec round trip failed at {}, {}", ha, dec);
+    }
+}
+
+#[test]
+fn test_polar_misalignment_terms() {
+    // ME moves declination by ME on the meridian, MA by MA at HA = +6h
+    let me = PointingModel::new().with_term(PointingTerm::ME, 36.0);
+    let (_, dd) = me.correction(0.0, 20.0, 40.0);
+    assert!((dd * 3600.0 - 36.0).abs() < 1e-9);
+
+    let ma = PointingModel::new().with_term(PointingTerm::MA, 36.0);
+    let (_, dd) = ma.correction(90.0, 20.0, 40.0);
+    assert!((dd * 3600.0 - 36.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_set_term_replaces_value() {
+    let mut model = PointingModel::new().with_term(PointingTerm::IH, 10.0);
+    model.set_term(PointingTerm::IH, 20.0);
+    assert_eq!(model.terms.len(), 1);
+    assert_eq!(model.term(PointingTerm::IH), 20.0);
+    assert_eq!(model.term(PointingTerm::ID), 0.0);
+}
+
+#[test]
+fn test_tpoint_round_trip() {
+    let mut model = PointingModel::new()
+        .with_term(PointingTerm::IH, -123.4567)
+        .with_term(PointingTerm::DAF, 5.5)
+        .with_term(PointingTerm::TF, -0.25);
+    model.caption = "Test mount".to_string();
+    model.rms_arcsec = Some(8.9);
+
+    let text = model.to_tpoint_string();
+    assert!(text.starts_with("Test mount\n"));
+    assert!(text.trim_end().ends_with("END"));
+
+    let parsed = PointingModel::from_tpoint_str(&text).unwrap();
+    assert_eq!(parsed, model);
+}
+
+#[test]
+fn test_tpoint_ignores_trailing_content() {
+    let text = "caption\n  IH  1.0\nEND\n  ID  not-a-number\n";
+    let model = PointingModel::from_tpoint_str(text).unwrap();
+    assert_eq!(model.term(PointingTerm::IH), 1.0);
+}
+
+#[test]
+fn test_tpoint_errors() {
+    let unknown = PointingModel::from_tpoint_str("caption\n  HHSH2  4.0\nEND\n");
+    assert!(matches!(unknown, Err(AstroError::InvalidFormat { .. })));
+
+    let missing_value = PointingModel::from_tpoint_str("caption\n  IH\nEND\n");
+    assert!(matches!(missing_value, Err(AstroError::InvalidFormat { .. })));
+}
+
+#[test]
+fn test_term_parsing() {
+    assert_eq!("daf".parse::<PointingTerm>().unwrap(), PointingTerm::DAF);
+    assert_eq!(PointingTerm::NP.to_string(), "NP");
+    assert!("XX".parse::<PointingTerm>().is_err());
+}
+
+#[test]
+fn test_10micron_round_trip() {
+    let model = PointingModel::new()
+        .with_term(PointingTerm::MA, 40.0)
+        .with_term(PointingTerm::ME, -20.0)
+        .with_term(PointingTerm::NP, 7.0);
+
+    let info = model.to_10micron_alignment(-33.0);
+    assert!(info.ends_with('#'));
+
+    let parsed = PointingModel::from_10micron_alignment(&info, -33.0).unwrap();
+    for term in [PointingTerm::MA, PointingTerm::ME, PointingTerm::NP] {
+        assert!(
+            (parsed.term(term) - model.term(term)).abs() < 1.0,
+            "{} mismatch: {} vs {}", term, parsed.term(term), model.term(term)
+        );
+    }
+}
+
+#[test]
+fn test_10micron_errors() {
+    let short = PointingModel::from_10micron_alignment("1.0,2.0#", 40.0);
+    assert!(matches!(short, Err(AstroError::InvalidFormat { .. })));
+
+    let garbage = PointingModel::from_10micron_alignment("a,b,c,d,e#", 40.0);
+    assert!(matches!(garbage, Err(AstroError::InvalidFormat { .. })));
+}
@@ -0,0 +1,113 @@
+use crate::error::AstroError;
+use crate::location::Location;
+use crate::polar_alignment::*;
+use crate::refraction::AtmosphericConditions;
+use crate::transforms::ra_dec_to_alt_az_erfa;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn site(latitude_deg: f64) -> Location {
+    Location { latitude_deg, longitude_deg: 10.0, altitude_m: 500.0 }
+}
+
+fn epoch() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 3, 1, 22, 0, 0).unwrap()
+}
+
+#[test]
+fn test_unrefracted_pole_at_latitude() {
+    for latitude in [60.0, 35.0, 5.0, -5.0, -35.0, -60.0] {
+        let (alt, az) = refracted_pole_position(epoch(), &site(latitude), None).unwrap();
+        assert!((alt - latitude.abs()).abs() < 1e-4, "lat {}: alt {}", latitude, alt);
+        let expected_az = if latitude >= 0.0 { 0.0 } else { 180.0 };
+        let daz = (az - expected_az + 180.0).rem_euclid(360.0) - 180.0;
+        assert!(daz.abs() < 0.01, "lat {}: az {}", latitude, az);
+    }
+}
+
+#[test]
+fn test_refraction_lifts_pole() {
+    let conditions = AtmosphericConditions::standard();
+    for latitude in [20.0, 45.0, -45.0] {
+        let (alt, _) = refracted_pole_position(epoch(), &site(latitude), Some(conditions)).unwrap();
+        let lift_arcsec = (alt - latitude.abs()) * 3600.0;
+        // About 58″ tan(z) at sea level, scaled to 500 m only through the given pressure
+        let expected = 58.0 * (90.0 - latitude.abs()).to_radians().tan();
+        assert!((lift_arcsec - expected).abs() < 0.05 * expected, "lat {}: {}″ vs {}″", latitude, lift_arcsec, expected);
+    }
+}
+
+#[test]
+fn test_pole_star_separation() {
+    let polaris = pole_star_offset(epoch(), &site(45.0), None).unwrap();
+    assert_eq!(polaris.star, "Polaris");
+    assert!((polaris.separation_deg - 0.63).abs() < 0.01, "{}", polaris.separation_deg);
+
+    let sigma_oct = pole_star_offset(epoch(), &site(-45.0), None).unwrap();
+    assert_eq!(sigma_oct.star, "σ Octantis");
+    assert!((sigma_oct.separation_deg - 1.15).abs() < 0.05, "{}", sigma_oct.separation_deg);
+
+    // Polaris is still closing on the pole
+    let later = pole_star_offset(epoch() + Duration::days(365 * 50), &site(45.0), None).unwrap();
+    assert!(later.separation_deg < polaris.separation_deg);
+}
+
+#[test]
+fn test_matches_icrs_to_alt_az() {
+    let conditions = AtmosphericConditions { pressure_hpa: 950.0, temperature_c: 5.0, relative_humidity: 0.0 };
+    let location = site(52.0);
+    let offset = pole_star_offset(epoch(), &location, Some(conditions)).unwrap();
+    let (pole_alt, pole_az) = refracted_pole_position(epoch(), &location, Some(conditions)).unwrap();
+
+    // Catalogue position without space motion, so agreement to a few arcseconds
+    let (alt, az) =
+        ra_dec_to_alt_az_erfa(37.954560667, 89.264109, epoch(), &location, Some(950.0), Some(5.0), Some(0.0)).unwrap();
+    let daz = (az - pole_az + 180.0).rem_euclid(360.0) - 180.0;
+    assert!(((alt - pole_alt) - offset.delta_alt_deg).abs() * 3600.0 < 5.0);
+    assert!((daz - offset.delta_az_deg).abs() * 3600.0 * pole_alt.to_radians().cos() < 5.0);
+}
+
+#[test]
+fn test_clock_position_follows_hour_angle() {
+    for hours in 0..24 {
+        let dt = epoch() + Duration::minutes(hours * 60);
+
+        // Stars turn anticlockwise around the north pole, so the clock runs backwards
+        let north = pole_star_offset(dt, &site(45.0), None).unwrap();
+        let expected = (-north.hour_angle_hours / 2.0).rem_euclid(12.0);
+        let diff = (north.clock_position - expected + 6.0).rem_euclid(12.0) - 6.0;
+        assert!(diff.abs() < 0.05, "north HA {}: clock {}", north.hour_angle_hours, north.clock_position);
+
+        let south = pole_star_offset(dt, &site(-45.0), None).unwrap();
+        let expected = (south.hour_angle_hours / 2.0).rem_euclid(12.0);
+        let diff = (south.clock_position - expected + 6.0).rem_euclid(12.0) - 6.0;
+        assert!(diff.abs() < 0.05, "south HA {}: clock {}", south.hour_angle_hours, south.clock_position);
+    }
+}
+
+#[test]
+fn test_upper_culmination_is_twelve_o_clock() {
+    let offset = pole_star_offset(epoch(), &site(45.0), None).unwrap();
+    // Step to the moment of upper culmination
+    let seconds = -offset.hour_angle_hours * 3600.0 / 1.00273790935;
+    let dt = epoch() + Duration::milliseconds((seconds * 1000.0) as i64);
+    let culmination = pole_star_offset(dt, &site(45.0), None).unwrap();
+    assert!(culmination.delta_alt_deg > 0.6);
+    assert!(culmination.delta_az_deg.abs() < 0.05);
+    let from_twelve = (culmination.clock_position + 6.0).rem_euclid(12.0) - 6.0;
+    assert!(from_twelve.abs() < 0.01, "{}", culmination.clock_position);
+}
+
+#[test]
+fn test_invalid_inputs() {
+    let humid = AtmosphericConditions { relative_humidity: 50.0, ..AtmosphericConditions::standard() };
+    assert!(matches!(
+        refracted_pole_position(epoch(), &site(45.0), Some(humid)),
+        Err(AstroError::OutOfRange { parameter: "relative_humidity", .. })
+    ));
+    let hot = AtmosphericConditions { temperature_c: 500.0, ..AtmosphericConditions::standard() };
+    assert!(pole_star_offset(epoch(), &site(45.0), Some(hot)).is_err());
+    assert!(matches!(
+        refracted_pole_position(epoch(), &site(95.0), None),
+        Err(AstroError::OutOfRange { parameter: "latitude", .. })
+    ));
+}
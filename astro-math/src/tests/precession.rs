@@ -89,4 +89,140 @@ fn test_precession_ra_wraparound_360() {
     // Test case that results in RA >= 360 after inverse precession
     let (ra, _) = precess_to_j2000(0.1, 89.0, dt).unwrap();
     assert!((0.0..360.0).contains(&ra), "RA should be normalized when >= 360");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_two_part_precession_matches_single() {
+    use crate::time::julian_date_two_part;
+
+    let dt = Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap();
+    let (jd1, jd2) = julian_date_two_part(dt);
+
+    let (ra1, dec1) = precess_from_j2000(279.23473479, 38.78368896, dt).unwrap();
+    let (ra2, dec2) = precess_from_j2000_two_part(279.23473479, 38.78368896, jd1, jd2).unwrap();
+    assert!((ra1 - ra2).abs() < 1e-9 && (dec1 - dec2).abs() < 1e-9);
+
+    let (ra0, dec0) = precess_to_j2000_two_part(ra2, dec2, jd1, jd2).unwrap();
+    assert!((ra0 - 279.23473479).abs() < 1e-8 && (dec0 - 38.78368896).abs() < 1e-8);
+}
+
+#[test]
+fn test_precess_batch_matches_single() {
+    let dt = Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap();
+    let coords: Vec<(f64, f64)> = (0..50)
+        .map(|i| (i as f64 * 7.1, -85.0 + i as f64 * 3.4))
+        .collect();
+
+    let forward = precess_from_j2000_batch(&coords, dt).unwrap();
+    assert_eq!(forward.len(), coords.len());
+    for (&(ra, dec), &(bra, bdec)) in coords.iter().zip(&forward) {
+        let (sra, sdec) = precess_from_j2000(ra, dec, dt).unwrap();
+        assert!((sra - bra).abs() < 1e-12 && (sdec - bdec).abs() < 1e-12);
+    }
+
+    let back = precess_to_j2000_batch(&forward, dt).unwrap();
+    for (&(ra, dec), &(bra, bdec)) in coords.iter().zip(&back) {
+        let dra = (ra - bra + 540.0) % 360.0 - 180.0;
+        assert!(dra.abs() < 1e-9 && (dec - bdec).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_precess_batch_rejects_invalid() {
+    let dt = Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap();
+    assert!(precess_from_j2000_batch(&[(10.0, 10.0), (10.0, 95.0)], dt).is_err());
+    assert!(precess_to_j2000_batch(&[], dt).unwrap().is_empty());
+}
+
+#[test]
+fn test_mean_of_date_matches_precess_from_j2000() {
+    let dt = Utc.with_ymd_and_hms(2030, 6, 1, 0, 0, 0).unwrap();
+    for &(ra, dec) in &[(0.0, 0.0), (83.63, 22.01), (279.23, 38.78), (201.3, -11.16)] {
+        let (ra1, dec1) = icrs_to_mean_of_date(ra, dec, dt).unwrap();
+        let (ra2, dec2) = precess_from_j2000(ra, dec, dt).unwrap();
+        // Only TT − UTC separates the two
+        assert!((ra1 - ra2).abs() < 1e-6 && (dec1 - dec2).abs() < 1e-6);
+
+        let (ra0, dec0) = mean_of_date_to_icrs(ra1, dec1, dt).unwrap();
+        let dra = (ra0 - ra + 540.0) % 360.0 - 180.0;
+        assert!(dra.abs() < 1e-9 && (dec0 - dec).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_without_bias_differs_by_frame_bias() {
+    // Within seconds of J2000.0 there is no precession and no bias
+    let j2000 = Utc.with_ymd_and_hms(2000, 1, 1, 11, 58, 55).unwrap();
+    let (ra, dec) = precess_from_j2000_without_bias(150.0, 30.0, j2000).unwrap();
+    assert!((ra - 150.0).abs() < 1e-8 && (dec - 30.0).abs() < 1e-8);
+
+    let dt = Utc.with_ymd_and_hms(2030, 6, 1, 0, 0, 0).unwrap();
+    for &(ra, dec) in &[(0.0, 0.0), (90.0, 45.0), (200.0, -60.0)] {
+        let (ra1, dec1) = icrs_to_mean_of_date(ra, dec, dt).unwrap();
+        let (ra2, dec2) = precess_from_j2000_without_bias(ra, dec, dt).unwrap();
+        let sep = (((ra1 - ra2) * f64::to_radians(dec1).cos()).powi(2) + (dec1 - dec2).powi(2)).sqrt() * 3600.0;
+        // Frame bias is 14.6 mas in RA and up to 17 mas at the poles
+        assert!(sep > 0.005 && sep < 0.03, "{}", sep);
+    }
+}
+
+#[test]
+fn test_mean_of_date_rejects_invalid() {
+    let dt = Utc.with_ymd_and_hms(2030, 6, 1, 0, 0, 0).unwrap();
+    assert!(icrs_to_mean_of_date(360.0, 0.0, dt).is_err());
+    assert!(mean_of_date_to_icrs(10.0, -90.5, dt).is_err());
+    assert!(precess_from_j2000_without_bias(-1.0, 0.0, dt).is_err());
+}
+
+#[test]
+fn test_model_selection_by_epoch() {
+    use crate::time::julian_epoch_to_jd;
+    assert_eq!(PrecessionModel::for_epoch(julian_epoch_to_jd(2999.0)), PrecessionModel::Iau2006);
+    assert_eq!(PrecessionModel::for_epoch(julian_epoch_to_jd(1001.0)), PrecessionModel::Iau2006);
+    assert_eq!(PrecessionModel::for_epoch(julian_epoch_to_jd(3001.0)), PrecessionModel::Vondrak2011);
+    assert_eq!(PrecessionModel::for_epoch(julian_epoch_to_jd(999.0)), PrecessionModel::Vondrak2011);
+}
+
+#[test]
+fn test_long_term_model_agrees_with_iau2006_near_present() {
+    for year in [1900, 2000, 2100] {
+        let dt = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+        for &(ra, dec) in &[(0.0, 0.0), (101.287, -16.716), (279.235, 38.784), (37.95, 89.26)] {
+            let a = precess_from_j2000_with_model(ra, dec, dt, PrecessionModel::Iau2006).unwrap();
+            let b = precess_from_j2000_with_model(ra, dec, dt, PrecessionModel::Vondrak2011).unwrap();
+            let sep = crate::angles::angular_separation(a.0, a.1, b.0, b.1).unwrap() * 3600.0;
+            assert!(sep < 0.2, "{} ({}, {}): {}″", year, ra, dec, sep);
+        }
+    }
+}
+
+#[test]
+fn test_long_term_model_at_j2000_is_frame_bias() {
+    let a = get_precession_matrix_with_model(2451545.0, 0.0, PrecessionModel::Vondrak2011);
+    let b = get_precession_matrix_two_part(2451545.0, 0.0);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((a[i][j] - b[i][j]).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_automatic_switch_far_from_j2000() {
+    // Around 12000 BCE Vega was the pole star; IAU 2006 is far off by then
+    let dt = Utc.with_ymd_and_hms(-11999, 1, 1, 0, 0, 0).unwrap();
+    let auto = precess_from_j2000(279.23473479, 38.78368896, dt).unwrap();
+    let long = precess_from_j2000_with_model(279.23473479, 38.78368896, dt, PrecessionModel::Vondrak2011).unwrap();
+    assert_eq!(auto, long);
+    assert!(auto.1 > 83.0, "Vega dec in 12000 BCE: {}", auto.1);
+
+    let back = precess_to_j2000(auto.0, auto.1, dt).unwrap();
+    assert!((back.0 - 279.23473479).abs() < 1e-9 && (back.1 - 38.78368896).abs() < 1e-9);
+
+    // Within the IAU 2006 range the default is unchanged
+    let dt = Utc.with_ymd_and_hms(2500, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(
+        precess_from_j2000(10.0, 20.0, dt).unwrap(),
+        precess_from_j2000_with_model(10.0, 20.0, dt, PrecessionModel::Iau2006).unwrap()
+    );
+}
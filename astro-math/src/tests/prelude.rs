@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+#[test]
+fn test_prelude_covers_a_typical_session() {
+    let site = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+    let dt: DateTime<Utc> = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+
+    let (alt, az) = ra_dec_to_alt_az(279.23473479, 38.78368896, dt, &site).unwrap();
+    let (ra, dec) = alt_az_to_ra_dec(alt, az, dt, &site).unwrap();
+    assert!((ra - 279.23473479).abs() < 1e-6 && (dec - 38.78368896).abs() < 1e-6);
+
+    let night = Night::containing(dt, &site).unwrap();
+    assert!(night.dusk(Twilight::Astronomical).unwrap().is_some());
+
+    let ground = Observer::Ground(site);
+    let conditions = AtmosphericConditions { pressure_hpa: 790.0, temperature_c: 15.0, relative_humidity: 0.3 };
+    let refracted = observed_place(279.23473479, 38.78368896, None, dt, &ground, Some(conditions)).unwrap();
+    let vacuum = observed_place(279.23473479, 38.78368896, None, dt, &ground, None).unwrap();
+    assert!(refracted.alt_az.unwrap().0 > vacuum.alt_az.unwrap().0);
+
+    let err: Result<()> = Err(AstroError::InvalidDateTime { reason: "test".to_string() });
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_prelude_items_are_the_module_items() {
+    let dt = Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap();
+    assert_eq!(julian_date(dt), crate::time::julian_date(dt));
+    assert_eq!(sun_ra_dec(dt), crate::sun::sun_ra_dec(dt));
+    assert_eq!(moon_equatorial(dt), crate::moon::moon_equatorial(dt));
+}
@@ -0,0 +1,100 @@
+use crate::error::AstroError;
+use crate::orbit::OrbitalElements;
+use crate::propagator::*;
+
+fn neo_elements() -> OrbitalElements {
+    OrbitalElements {
+        epoch_jd_tdb: 2460600.5,
+        semi_major_axis_au: 1.458,
+        eccentricity: 0.2227,
+        inclination_deg: 10.83,
+        ascending_node_deg: 304.3,
+        argument_of_perihelion_deg: 178.9,
+        mean_anomaly_deg: 310.5,
+    }
+}
+
+#[test]
+fn test_two_body_matches_kepler() {
+    let elements = neo_elements();
+    let start = elements.to_state_vector().unwrap();
+    let target = elements.epoch_jd_tdb + 365.25;
+
+    let numeric = NBodyPropagator::two_body().propagate(&start, target).unwrap();
+    let analytic = elements.state_at(target).unwrap();
+    for i in 0..3 {
+        assert!((numeric.position[i] - analytic.position[i]).abs() < 1e-9, "axis {}", i);
+    }
+    assert_eq!(numeric.jd_tdb, target);
+}
+
+#[test]
+fn test_backward_propagation_round_trip() {
+    let start = neo_elements().to_state_vector().unwrap();
+    let propagator = NBodyPropagator::new();
+    let forward = propagator.propagate(&start, start.jd_tdb + 200.0).unwrap();
+    let back = propagator.propagate(&forward, start.jd_tdb).unwrap();
+    for i in 0..3 {
+        assert!((back.position[i] - start.position[i]).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_perturbations_change_orbit() {
+    let start = neo_elements().to_state_vector().unwrap();
+    let target = start.jd_tdb + 730.0;
+
+    let kepler = NBodyPropagator::two_body().propagate(&start, target).unwrap();
+    let perturbed = NBodyPropagator::new().propagate(&start, target).unwrap();
+    let jupiter_only = NBodyPropagator::new()
+        .with_perturbers(&[Planet::Jupiter])
+        .propagate(&start, target)
+        .unwrap();
+
+    let diff = |a: &[f64; 3], b: &[f64; 3]| {
+        ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+    };
+
+    // Over two years planetary perturbations move an NEO by 1e-5..1e-2 AU
+    let d_all = diff(&kepler.position, &perturbed.position);
+    assert!(d_all > 1e-5 && d_all < 1e-2, "perturbation {}", d_all);
+
+    // Jupiter dominates but is not the whole story
+    let d_jup = diff(&kepler.position, &jupiter_only.position);
+    assert!(d_jup > 1e-6);
+    assert!(diff(&perturbed.position, &jupiter_only.position) > 1e-8);
+}
+
+#[test]
+fn test_sample_times() {
+    let start = neo_elements().to_state_vector().unwrap();
+    let propagator = NBodyPropagator::new().with_perturbers(&[Planet::Jupiter, Planet::Saturn]);
+
+    let samples = propagator.sample(&start, start.jd_tdb, start.jd_tdb + 25.0, 10.0).unwrap();
+    let times: Vec<f64> = samples.iter().map(|s| s.jd_tdb - start.jd_tdb).collect();
+    assert_eq!(times, vec![0.0, 10.0, 20.0, 25.0]);
+
+    let backward = propagator.sample(&start, start.jd_tdb, start.jd_tdb - 20.0, 5.0).unwrap();
+    assert_eq!(backward.len(), 5);
+    assert_eq!(backward[4].jd_tdb, start.jd_tdb - 20.0);
+}
+
+#[test]
+fn test_planet_states() {
+    let earth = Planet::EarthMoon.heliocentric_state(2451545.0).unwrap();
+    assert!((earth.distance_au() - 0.983).abs() < 0.01);
+
+    let jupiter = Planet::Jupiter.heliocentric_state(2451545.0).unwrap();
+    assert!(jupiter.distance_au() > 4.9 && jupiter.distance_au() < 5.5);
+    assert!((Planet::Jupiter.gm() * 1047.3486 - crate::orbit::GM_SUN).abs() < 1e-18);
+}
+
+#[test]
+fn test_invalid_configuration() {
+    let start = neo_elements().to_state_vector().unwrap();
+    let result = NBodyPropagator::new().sample(&start, start.jd_tdb, start.jd_tdb + 1.0, 0.0);
+    assert!(matches!(result, Err(AstroError::OutOfRange { .. })));
+
+    let result = NBodyPropagator::new().with_tolerance(-1.0).propagate(&start, start.jd_tdb + 1.0);
+    assert!(matches!(result, Err(AstroError::OutOfRange { .. })));
+}
@@ -0,0 +1,110 @@
+use crate::eop::{EarthOrientation, EopTable};
+use crate::observer::{observed_place, observed_place_with_provenance, ObserverKind};
+use crate::provenance::*;
+use crate::refraction::AtmosphericConditions;
+use crate::transforms::{ra_dec_to_alt_az_erfa, ra_dec_to_alt_az_erfa_with_eop, ra_dec_to_alt_az_erfa_with_provenance};
+use crate::Location;
+use chrono::{NaiveDate, TimeZone, Utc};
+
+fn mauna_kea() -> Location {
+    Location { latitude_deg: 19.8207, longitude_deg: -155.4681, altitude_m: 4205.0 }
+}
+
+fn eop_table() -> EopTable {
+    let eop = |dut1, dx| EarthOrientation { dut1, xp_arcsec: 0.075, yp_arcsec: 0.217, dx_mas: Some(dx), dy_mas: Some(-0.06) };
+    EopTable::new(&[(60310.0, eop(0.0117, 0.27)), (60311.0, eop(0.0114, 0.28)), (60312.0, eop(0.0111, 0.29))]).unwrap()
+}
+
+#[test]
+fn test_erfa_provenance_matches_result() {
+    let site = mauna_kea();
+    let dt = Utc.with_ymd_and_hms(2024, 1, 2, 6, 0, 0).unwrap();
+    let weather = AtmosphericConditions { pressure_hpa: 615.0, temperature_c: 2.0, relative_humidity: 0.1 };
+
+    let eop = EopSource::from_table(&eop_table(), dt).unwrap();
+    let EopSource::Table { values, span_mjd, extrapolated } = eop else { panic!("expected a table source") };
+    assert_eq!(span_mjd, (60310.0, 60312.0));
+    assert!(!extrapolated);
+
+    let (alt, az, provenance) =
+        ra_dec_to_alt_az_erfa_with_provenance(279.2347, 38.7837, dt, &site, Some(weather), Some(eop)).unwrap();
+    let expected =
+        ra_dec_to_alt_az_erfa_with_eop(279.2347, 38.7837, dt, &site, Some(615.0), Some(2.0), Some(0.1), &values).unwrap();
+    assert_eq!((alt, az), expected);
+
+    assert_eq!(provenance.utc, dt);
+    assert_eq!(provenance.method, "ERFA Atco13");
+    for correction in [
+        Correction::FrameBias,
+        Correction::Nutation,
+        Correction::DiurnalAberration,
+        Correction::PolarMotion,
+        Correction::CelestialPoleOffsets,
+        Correction::Refraction,
+    ] {
+        assert!(provenance.applied(correction), "{}", correction);
+    }
+    assert!(!provenance.applied(Correction::Parallax));
+    let refraction = provenance.refraction.unwrap();
+    assert_eq!(refraction.conditions, weather);
+    assert_eq!(refraction.wavelength_um, 1.0);
+    assert_eq!(provenance.eop, Some(eop));
+    assert_eq!(provenance.tai_utc_s, 37.0);
+    assert!(!provenance.leap_seconds_may_be_stale());
+
+    let text = provenance.to_string();
+    assert!(text.contains("method: ERFA Atco13\n"), "{}", text);
+    assert!(text.contains("earth orientation: table MJD 60310.0-60312.0, DUT1 +0.0113 s"), "{}", text);
+    assert!(text.contains("refraction: ERFA Refco"), "{}", text);
+    assert_eq!(text.lines().count(), 8);
+}
+
+#[test]
+fn test_erfa_provenance_without_eop_or_weather() {
+    let site = mauna_kea();
+    let dt = Utc.with_ymd_and_hms(2024, 1, 2, 6, 0, 0).unwrap();
+    let (alt, az, provenance) = ra_dec_to_alt_az_erfa_with_provenance(279.2347, 38.7837, dt, &site, None, None).unwrap();
+    assert_eq!((alt, az), ra_dec_to_alt_az_erfa(279.2347, 38.7837, dt, &site, None, None, None).unwrap());
+    assert_eq!(provenance.eop, Some(EopSource::Zero));
+    assert!(provenance.refraction.is_none());
+    assert!(!provenance.applied(Correction::PolarMotion));
+    assert!(!provenance.applied(Correction::Refraction));
+    assert_eq!(provenance.backend, if cfg!(feature = "pure-rust") { "pure-rust" } else { "erfa" });
+    assert_eq!(provenance.precession_nutation, if cfg!(feature = "pure-rust") { "IAU 2006/2000B" } else { "IAU 2006/2000A" });
+
+    // Past the end of the table, DUT1 is predicted
+    let later = Utc.with_ymd_and_hms(2024, 1, 20, 0, 0, 0).unwrap();
+    assert!(matches!(EopSource::from_table(&eop_table(), later).unwrap(), EopSource::Table { extrapolated: true, .. }));
+    let earlier = Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap();
+    assert!(EopSource::from_table(&eop_table(), earlier).is_err());
+}
+
+#[test]
+fn test_observed_place_provenance() {
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let ground = ObserverKind::Ground(mauna_kea());
+    let weather = AtmosphericConditions::standard();
+
+    // The Moon from the ground, refracted
+    let (place, provenance) = observed_place_with_provenance(300.0, -20.0, Some(0.0026), dt, &ground, Some(weather)).unwrap();
+    assert_eq!(place, observed_place(300.0, -20.0, Some(0.0026), dt, &ground, Some(weather)).unwrap());
+    assert_eq!(provenance.corrections[0], Correction::Parallax);
+    assert!(provenance.applied(Correction::Refraction));
+    assert_eq!(provenance.refraction.unwrap().wavelength_um, 0.574);
+    assert_eq!(provenance.eop, Some(EopSource::Zero));
+
+    // A star from orbit: no refraction and no Earth orientation
+    let leo = ObserverKind::space([6778.0, 0.0, 0.0], [0.0, 7.6, 0.0]).unwrap();
+    let (_, provenance) = observed_place_with_provenance(300.0, -20.0, None, dt, &leo, Some(weather)).unwrap();
+    assert_eq!(provenance.method, "ERFA Atci13");
+    assert!(!provenance.applied(Correction::Parallax));
+    assert!(!provenance.applied(Correction::EarthRotation));
+    assert!(provenance.refraction.is_none() && provenance.eop.is_none());
+    assert!(provenance.to_string().contains("earth orientation: not applicable"));
+
+    // Far past the leap-second table
+    let future = Utc.with_ymd_and_hms(2090, 1, 1, 0, 0, 0).unwrap();
+    let (_, provenance) = observed_place_with_provenance(300.0, -20.0, None, future, &leo, None).unwrap();
+    assert!(provenance.leap_seconds_may_be_stale());
+    assert!(provenance.leap_second_table_expires < NaiveDate::from_ymd_opt(2090, 1, 1).unwrap());
+}
@@ -0,0 +1,89 @@
+use crate::error::AstroError;
+use crate::galactic::equatorial_to_galactic;
+use crate::radial_velocity::*;
+use crate::Location;
+use chrono::{Duration, TimeZone, Utc};
+
+#[test]
+fn test_kinematic_apex_galactic_coordinates() {
+    // The standard solar apex lies near l = 56°, b = +23°
+    let m = SolarMotion::KINEMATIC;
+    let (l, b) = equatorial_to_galactic(m.apex_ra_deg, m.apex_dec_deg).unwrap();
+    assert!((l - 56.16).abs() < 0.1, "l = {}", l);
+    assert!((b - 22.77).abs() < 0.1, "b = {}", b);
+}
+
+#[test]
+fn test_schoenrich_motion() {
+    let m = SolarMotion::schoenrich_2010();
+    assert!((m.speed_kms - 18.044).abs() < 0.001);
+
+    // Projection onto the Galactic centre direction recovers U
+    let (ra_gc, dec_gc) = crate::galactic::galactic_to_equatorial(0.0, 0.0).unwrap();
+    let u = lsr_correction(ra_gc, dec_gc, &m).unwrap();
+    assert!((u - 11.1).abs() < 1e-6, "U = {}", u);
+}
+
+#[test]
+fn test_lsr_correction_geometry() {
+    let m = SolarMotion::default();
+    let anti_ra = (m.apex_ra_deg + 180.0) % 360.0;
+    let v = lsr_correction(anti_ra, -m.apex_dec_deg, &m).unwrap();
+    assert!((v + 20.0).abs() < 1e-9);
+
+    let zero = SolarMotion::from_uvw(0.0, 0.0, 0.0);
+    assert_eq!(lsr_correction(10.0, 10.0, &zero).unwrap(), 0.0);
+}
+
+#[test]
+fn test_barycentric_correction_annual_amplitude() {
+    // Source on the ecliptic: the orbital term swings by ~±29.8 km/s over a year
+    let loc = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let (mut min, mut max) = (f64::MAX, f64::MIN);
+    for day in (0..366).step_by(3) {
+        let v = barycentric_correction(90.0, 23.44, start + Duration::days(day), &loc).unwrap();
+        min = min.min(v);
+        max = max.max(v);
+    }
+    assert!(max > 29.0 && max < 31.0, "max {}", max);
+    assert!(min < -29.0 && min > -31.0, "min {}", min);
+}
+
+#[test]
+fn test_barycentric_correction_earth_rotation() {
+    // Opposite sides of the equator see the rotational term with opposite sign
+    let dt = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    let east = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let west = Location { latitude_deg: 0.0, longitude_deg: 180.0, altitude_m: 0.0 };
+
+    // Hour angle +6h from the first site, −6h from the second
+    let ra = (east.local_sidereal_time(dt) * 15.0 - 90.0).rem_euclid(360.0);
+    let diff = barycentric_correction(ra, 0.0, dt, &east).unwrap()
+        - barycentric_correction(ra, 0.0, dt, &west).unwrap();
+    assert!((diff + 0.9302).abs() < 0.001, "diff {}", diff);
+}
+
+#[test]
+fn test_vlsr_is_sum_of_parts() {
+    let loc = Location { latitude_deg: 38.43, longitude_deg: -79.84, altitude_m: 807.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 3, 20, 4, 0, 0).unwrap();
+    let total = vlsr_correction(83.82, -5.39, dt, &loc).unwrap();
+    let parts = barycentric_correction(83.82, -5.39, dt, &loc).unwrap()
+        + lsr_correction(83.82, -5.39, &SolarMotion::KINEMATIC).unwrap();
+    assert!((total - parts).abs() < 1e-12);
+
+    let dyn_lsr = vlsr_correction_with(83.82, -5.39, dt, &loc, &SolarMotion::schoenrich_2010()).unwrap();
+    assert!((dyn_lsr - total).abs() > 0.1);
+}
+
+#[test]
+fn test_invalid_coordinates() {
+    let loc = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    assert!(matches!(
+        vlsr_correction(360.0, 0.0, dt, &loc),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(lsr_correction(0.0, -91.0, &SolarMotion::KINEMATIC).is_err());
+}
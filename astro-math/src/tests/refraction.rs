@@ -1,4 +1,5 @@
 use crate::refraction::*;
+use crate::error::AstroError;
 
 #[test]
 fn test_refraction_below_horizon() {
@@ -89,4 +90,172 @@ fn test_refraction_below_limit() {
     // Radio: below -1.0 degrees
     let r3 = refraction_radio(-2.0, 1013.25, 10.0, 50.0).unwrap();
     assert_eq!(r3, 0.0);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_raytrace_matches_first_order_theory() {
+    // Away from the horizon refraction depends only on the refractivity at
+    // the observer: R ≈ (n0 - 1)·tan(z), less a small tan³(z) term
+    for &(p, t, h) in &[(1013.25, 15.0, 0.0), (80.0, -60.0, 18_000.0), (11.97, -46.6, 30_000.0)] {
+        let n0_minus_1 = 78.9e-6 * p / (t + 273.15);
+        let r = refraction_raytrace(60.0, p, t, h).unwrap();
+        let expected = (n0_minus_1 * 30f64.to_radians().tan()).to_degrees();
+        assert!((r / expected - 1.0).abs() < 0.005, "{} hPa: {} vs {}", p, r, expected);
+    }
+}
+
+#[test]
+fn test_raytrace_sea_level() {
+    // Close to Saemundsson at moderate altitudes, ~34′ at the horizon
+    for alt in [5.0, 10.0, 20.0] {
+        let r = refraction_raytrace(alt, 1010.0, 10.0, 0.0).unwrap();
+        let s = refraction_saemundsson(alt, 1010.0, 10.0).unwrap();
+        assert!((r - s).abs() * 60.0 < 0.2, "{}°: {}′ vs {}′", alt, r * 60.0, s * 60.0);
+    }
+    let horizon = refraction_raytrace(0.0, 1010.0, 10.0, 0.0).unwrap() * 60.0;
+    assert!((horizon - 34.5).abs() < 1.5, "horizon {}′", horizon);
+}
+
+#[test]
+fn test_raytrace_balloon_horizon() {
+    // At 30 km the refractivity falls off nearly exponentially, for which a
+    // horizontal ray is bent by N·√(πr/2H)
+    let (p, t_k, h) = (11.97, 226.5, 30_000.0);
+    let n = 78.9e-6 * p / t_k;
+    let scale_height = t_k / (0.034163 + 0.001);
+    let expected = (n * (std::f64::consts::PI * (6_371_000.0 + h) / (2.0 * scale_height)).sqrt()).to_degrees();
+
+    let r = refraction_raytrace(0.0, p, t_k - 273.15, h).unwrap();
+    assert!((r / expected - 1.0).abs() < 0.01, "{}′ vs {}′", r * 60.0, expected * 60.0);
+
+    // Continuous through the astronomical horizon
+    let below = refraction_raytrace(-1e-4, p, t_k - 273.15, h).unwrap();
+    assert!((below - r).abs() * 3600.0 < 0.1);
+}
+
+#[test]
+fn test_raytrace_below_horizon() {
+    // Looking down from a balloon the ray passes through denser air
+    let mut previous = refraction_raytrace(0.0, 11.97, -46.6, 30_000.0).unwrap();
+    for alt in [-1.0, -3.0, -5.0] {
+        let r = refraction_raytrace(alt, 11.97, -46.6, 30_000.0).unwrap();
+        assert!(r > previous, "{}°: {}", alt, r);
+        previous = r;
+    }
+
+    // The geometric dip at 30 km is 5.56°; steeper rays meet the ground
+    assert!(matches!(
+        refraction_raytrace(-6.0, 11.97, -46.6, 30_000.0),
+        Err(AstroError::CalculationError { .. })
+    ));
+    // A ground-level observer has no sky below the horizon
+    assert!(refraction_raytrace(-0.5, 1013.25, 15.0, 0.0).is_err());
+}
+
+#[test]
+fn test_raytrace_edge_cases() {
+    assert_eq!(refraction_raytrace(10.0, 0.0, -60.0, 30_000.0).unwrap(), 0.0);
+    assert_eq!(refraction_raytrace(90.0, 1013.25, 15.0, 0.0).unwrap(), 0.0);
+    assert!(refraction_raytrace(10.0, 1013.25, 15.0, 90_000.0).is_err());
+    // Extremely cold, dense air traps horizontal rays
+    assert!(matches!(
+        refraction_raytrace(1.0, 1200.0, -150.0, 0.0),
+        Err(AstroError::CalculationError { .. })
+    ));
+}
+
+#[test]
+fn test_stratospheric_conditions_validated() {
+    // Stratospheric pressure and temperature are accepted...
+    let r = refraction_saemundsson(20.0, 50.0, -75.0).unwrap();
+    assert!(r > 0.0);
+    // ...but non-physical values are not
+    assert!(refraction_saemundsson(20.0, -1.0, 10.0).is_err());
+    assert!(refraction_saemundsson(20.0, 1013.25, -300.0).is_err());
+    assert!(refraction_radio(20.0, 1013.25, 150.0, 50.0).is_err());
+}
+
+#[test]
+fn test_refraction_model_dispatch() {
+    let (p, t) = (1013.25, 10.0);
+    assert_eq!(RefractionModel::Bennett.refraction(20.0, p, t).unwrap(), refraction_bennett(20.0).unwrap());
+    assert_eq!(
+        RefractionModel::Saemundsson.refraction(20.0, p, t).unwrap(),
+        refraction_saemundsson(20.0, p, t).unwrap()
+    );
+    assert_eq!(
+        RefractionModel::Radio { humidity_percent: 40.0 }.refraction(20.0, p, t).unwrap(),
+        refraction_radio(20.0, p, t, 40.0).unwrap()
+    );
+    assert_eq!(
+        RefractionModel::Raytrace { height_m: 2000.0 }.refraction(20.0, p, t).unwrap(),
+        refraction_raytrace(20.0, p, t, 2000.0).unwrap()
+    );
+}
+
+#[cfg(feature = "erfa")]
+#[test]
+fn test_raytrace_against_erfa_refco() {
+    // ERFA's A·tan(z) + B·tan³(z) coefficients come from ray tracing and
+    // hold to a fraction of an arcsecond down to ~15° altitude
+    for &(p, t) in &[(1013.25, 15.0), (600.0, -10.0), (80.0, -60.0)] {
+        let (a, b) = erfars::astrometry::Refco(p, t, 0.0, 0.574);
+        for alt in [15.0f64, 30.0, 60.0] {
+            let tan_z = (90.0 - alt).to_radians().tan();
+            let expected = (a * tan_z + b * tan_z.powi(3)).to_degrees() * 3600.0;
+            let r = refraction_raytrace(alt, p, t, 0.0).unwrap() * 3600.0;
+            assert!((r - expected).abs() < 0.5, "{} hPa, {}°: {}″ vs {}″", p, alt, r, expected);
+        }
+    }
+}
+
+#[test]
+fn test_refraction_table_within_reported_error() {
+    let conditions = AtmosphericConditions { pressure_hpa: 780.0, temperature_c: -5.0, relative_humidity: 0.3 };
+    for (model, range, step) in [
+        (RefractionModel::Saemundsson, 5.0..=90.0, 0.05),
+        (RefractionModel::Bennett, 0.0..=89.0, 0.1),
+        (RefractionModel::Raytrace { height_m: 2000.0 }, -1.0..=10.0, 0.1),
+    ] {
+        let table = RefractionTable::build(model, &conditions, range.clone(), step).unwrap();
+        assert_eq!(table.range(), range);
+        assert!(table.step() <= step);
+        assert!(table.max_error() > 0.0 && table.max_error() * 3600.0 < 1.0, "{:?} {}", model, table.max_error());
+
+        // Dense check, including the grid points themselves
+        let (min, max) = (*range.start(), *range.end());
+        for i in 0..=1000 {
+            let alt = min + (max - min) * i as f64 / 1000.0;
+            let exact = model.refraction(alt, conditions.pressure_hpa, conditions.temperature_c).unwrap();
+            assert!((table.lookup(alt) - exact).abs() <= table.max_error() * 1.1 + 1e-12, "{:?} {}", model, alt);
+        }
+        // Clamped outside the range
+        assert_eq!(table.lookup(min - 5.0), table.lookup(min));
+        assert_eq!(table.lookup(max + 5.0), table.lookup(max));
+    }
+
+    // A coarser grid is less accurate
+    let coarse = RefractionTable::build(RefractionModel::Bennett, &conditions, 0.0..=89.0, 1.0).unwrap();
+    let fine = RefractionTable::build(RefractionModel::Bennett, &conditions, 0.0..=89.0, 0.1).unwrap();
+    assert!(coarse.max_error() > 10.0 * fine.max_error());
+}
+
+#[test]
+fn test_refraction_table_errors() {
+    let conditions = AtmosphericConditions::standard();
+    let build = |range, step| RefractionTable::build(RefractionModel::Saemundsson, &conditions, range, step);
+    for (range, step) in [
+        (10.0..=5.0, 0.1),
+        (10.0..=10.0, 0.1),
+        (-95.0..=10.0, 0.1),
+        (f64::NAN..=10.0, 0.1),
+        (0.0..=10.0, 0.0),
+        (0.0..=10.0, f64::NAN),
+        (0.0..=10.0, 1e-9),
+    ] {
+        assert!(matches!(build(range.clone(), step), Err(AstroError::OutOfRange { .. })), "{:?} {}", range, step);
+    }
+    let storm = AtmosphericConditions { pressure_hpa: 1500.0, ..conditions };
+    let result = RefractionTable::build(RefractionModel::Bennett, &storm, 0.0..=10.0, 0.1);
+    assert!(matches!(result, Err(AstroError::OutOfRange { parameter: "pressure_hpa", .. })));
+}
@@ -215,4 +215,374 @@ fn test_next_set_no_set_within_search() {
     // Circumpolar object at this latitude - never sets
     let result = next_set(0.0, 80.0, summer, &location, None).unwrap();
     assert!(result.is_none(), "Circumpolar object should not set");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_altitude_crossings_match_threshold() {
+    use crate::events::Crossing;
+
+    let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    let start = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+    let (ra, dec) = (10.6847, 41.2689); // M31
+
+    let crossings = altitude_crossings(ra, dec, start, &location, 30.0).unwrap();
+    assert_eq!(crossings.len(), 2);
+    assert_ne!(crossings[0].1, crossings[1].1);
+    assert!(crossings[0].0 < crossings[1].0);
+
+    for (t, dir) in crossings {
+        let (alt, _) = ra_dec_to_alt_az(ra, dec, t, &location).unwrap();
+        assert!((alt - 30.0).abs() < 0.01, "Altitude at crossing {}", alt);
+
+        // Direction matches the altitude trend
+        let (later, _) = ra_dec_to_alt_az(ra, dec, t + chrono::Duration::minutes(5), &location).unwrap();
+        match dir {
+            Crossing::Rising => assert!(later > alt),
+            Crossing::Falling => assert!(later < alt),
+        }
+    }
+}
+
+#[test]
+fn test_altitude_crossings_none() {
+    let location = Location { latitude_deg: 45.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let start = Utc.with_ymd_and_hms(2024, 8, 4, 0, 0, 0).unwrap();
+
+    // Polaris never drops below 40° from latitude 45°
+    let crossings = altitude_crossings(37.95, 89.26, start, &location, 40.0).unwrap();
+    assert!(crossings.is_empty());
+
+    // Invalid threshold
+    assert!(altitude_crossings(37.95, 89.26, start, &location, 95.0).is_err());
+}
+
+#[test]
+fn test_altitude_crossings_symmetric_about_transit() {
+    let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    let date = Utc.with_ymd_and_hms(2024, 8, 4, 12, 0, 0).unwrap();
+    let (ra, dec) = (100.0, 20.0);
+
+    let (_, transit, _) = rise_transit_set(ra, dec, date, &location, None).unwrap().unwrap();
+    let crossings = altitude_crossings(ra, dec, transit - chrono::Duration::hours(12), &location, RISE_SET_ALTITUDE)
+        .unwrap();
+    assert_eq!(crossings.len(), 2);
+
+    // Rise and set are equally spaced around the meridian transit
+    let before = (transit - crossings[0].0).num_seconds();
+    let after = (crossings[1].0 - transit).num_seconds();
+    assert!((before - after).abs() < 180, "rise {}s before, set {}s after transit", before, after);
+}
+
+#[test]
+fn test_rise_transit_set_hour_angle_sign() {
+    // A star at +20° seen from 40°N is up for 2 × 108.6° of hour angle,
+    // about 14.4 hours; with the sign of cos(H0) flipped it came out as the
+    // 9.6 hours it spends below the horizon
+    let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    let date = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+    let (rise, _, set) = rise_transit_set(100.0, 20.0, date, &location, None).unwrap().unwrap();
+    let hours_up = (set - rise).num_seconds() as f64 / 3600.0;
+    assert!((hours_up - 14.44).abs() < 0.05, "up for {} h", hours_up);
+
+    for event in [rise, set] {
+        let (alt, _) = ra_dec_to_alt_az(100.0, 20.0, event, &location).unwrap();
+        assert!((alt - RISE_SET_ALTITUDE).abs() < 0.05, "altitude {} at {}", alt, event);
+    }
+}
+
+#[test]
+fn test_horizon_dip() {
+    assert_eq!(horizon_dip(0.0).unwrap(), 0.0);
+    // 1.76' per root meter: 17.6' at 100 m
+    assert!((horizon_dip(100.0).unwrap() - 17.6 / 60.0).abs() < 1e-12);
+    assert!(horizon_dip(-1.0).is_err());
+    assert!(horizon_dip(f64::NAN).is_err());
+    assert!(horizon_dip(f64::INFINITY).is_err());
+
+    assert_eq!(horizon_altitude(-400.0).unwrap(), RISE_SET_ALTITUDE);
+    assert!((horizon_altitude(100.0).unwrap() - (RISE_SET_ALTITUDE - 17.6 / 60.0)).abs() < 1e-12);
+    assert!(horizon_altitude(f64::NAN).is_err());
+}
+
+#[test]
+fn test_sun_rise_set_new_york_solstice() {
+    // Upper limb at -50' on 2024-06-21, 40°N 74°W: sunrise 09:28, sunset 00:27 UTC
+    let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    let date = Utc.with_ymd_and_hms(2024, 6, 21, 12, 0, 0).unwrap();
+    let (sunrise, sunset) = sun_rise_set(date, &location).unwrap().unwrap();
+
+    let expected_rise = Utc.with_ymd_and_hms(2024, 6, 21, 9, 28, 0).unwrap();
+    let expected_set = Utc.with_ymd_and_hms(2024, 6, 22, 0, 27, 0).unwrap();
+    assert!((sunrise - expected_rise).num_seconds().abs() < 120, "sunrise {}", sunrise);
+    assert!((sunset - expected_set).num_seconds().abs() < 120, "sunset {}", sunset);
+}
+
+#[test]
+fn test_elevated_observer_sees_longer_day() {
+    let sea = Location { latitude_deg: 19.82, longitude_deg: -155.47, altitude_m: 0.0 };
+    let summit = Location { altitude_m: 2000.0, ..sea };
+    let date = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+
+    let (rise_sea, set_sea) = sun_rise_set(date, &sea).unwrap().unwrap();
+    let (rise_summit, set_summit) = sun_rise_set_from_height(date, &summit, 2000.0).unwrap().unwrap();
+    let earlier = (rise_sea - rise_summit).num_seconds();
+    let later = (set_summit - set_sea).num_seconds();
+    assert!((300..600).contains(&earlier), "sunrise {}s earlier", earlier);
+    assert!((300..600).contains(&later), "sunset {}s later", later);
+    assert_eq!(sun_rise_set_from_height(date, &summit, 0.0).unwrap(), sun_rise_set(date, &summit).unwrap());
+    assert!(sun_rise_set_from_height(date, &sea, f64::NAN).is_err());
+
+    // The default horizon does not depend on the location's altitude_m
+    assert_eq!(sun_rise_set(date, &sea).unwrap(), sun_rise_set(date, &summit).unwrap());
+    let flat_sea = rise_transit_set(0.0, 0.0, date, &sea, None).unwrap();
+    let flat_summit = rise_transit_set(0.0, 0.0, date, &summit, None).unwrap();
+    assert_eq!(flat_sea, flat_summit);
+    assert_eq!(flat_sea, rise_transit_set(0.0, 0.0, date, &sea, Some(RISE_SET_ALTITUDE)).unwrap());
+
+    // The dip applies only when asked for
+    let horizon = horizon_altitude(2000.0).unwrap();
+    let dipped = rise_transit_set(0.0, 0.0, date, &summit, Some(horizon)).unwrap().unwrap();
+    assert!(dipped.0 < flat_summit.unwrap().0);
+}
+
+#[test]
+fn test_body_profile_altitudes() {
+    assert_eq!(BodyProfile::default(), BodyProfile::Star);
+    assert_eq!(BodyProfile::Star.standard_altitude(), RISE_SET_ALTITUDE);
+    assert_eq!(BodyProfile::Planet.standard_altitude(), RISE_SET_ALTITUDE);
+    assert_eq!(BodyProfile::SunCenter.standard_altitude(), RISE_SET_ALTITUDE);
+    assert_eq!(BodyProfile::SunUpperLimb.standard_altitude(), RISE_SET_ALTITUDE - SUN_SEMI_DIAMETER);
+    assert_eq!(BodyProfile::MoonUpperLimb.standard_altitude(), 0.125);
+
+    // The dip applies to every profile alike
+    for profile in [BodyProfile::Star, BodyProfile::SunUpperLimb, BodyProfile::MoonUpperLimb] {
+        assert!((profile.horizon_altitude(0.0).unwrap() - profile.standard_altitude()).abs() < 1e-12);
+        let dip = horizon_dip(2000.0).unwrap();
+        assert!((profile.horizon_altitude(2000.0).unwrap() - (profile.standard_altitude() - dip)).abs() < 1e-12);
+    }
+    assert!(BodyProfile::Planet.horizon_altitude(f64::NAN).is_err());
+
+    // Apogee and perigee bracket the mean value
+    let apogee = moon_standard_altitude(406_700.0).unwrap();
+    let perigee = moon_standard_altitude(356_400.0).unwrap();
+    assert!((0.07..0.09).contains(&apogee), "{}", apogee);
+    assert!((0.16..0.18).contains(&perigee), "{}", perigee);
+    assert!(matches!(moon_standard_altitude(6000.0), Err(error::AstroError::OutOfRange { .. })));
+    assert!(moon_standard_altitude(f64::INFINITY).is_err());
+}
+
+#[test]
+fn test_rise_transit_set_for_body_matches_explicit_altitude() {
+    let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 1500.0 };
+    let date = Utc.with_ymd_and_hms(2024, 8, 4, 12, 0, 0).unwrap();
+    for profile in [BodyProfile::Star, BodyProfile::SunUpperLimb, BodyProfile::MoonUpperLimb] {
+        let altitude = profile.standard_altitude();
+        assert_eq!(
+            rise_transit_set_for_body(200.0, -5.0, date, &location, profile).unwrap(),
+            rise_transit_set(200.0, -5.0, date, &location, Some(altitude)).unwrap()
+        );
+    }
+    assert_eq!(
+        rise_transit_set_for_body(200.0, -5.0, date, &location, BodyProfile::Star).unwrap(),
+        rise_transit_set(200.0, -5.0, date, &location, None).unwrap()
+    );
+
+    // Sunrise is unchanged by routing it through the profile
+    let sun = sun_rise_set(date, &location).unwrap().unwrap();
+    assert!(sun.0 < sun.1);
+}
+
+#[test]
+fn test_moon_rise_set_upper_limb_on_horizon() {
+    let locations = [
+        Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 },
+        Location { latitude_deg: -33.9, longitude_deg: 151.2, altitude_m: 0.0 },
+        Location { latitude_deg: 19.8, longitude_deg: -155.5, altitude_m: 0.0 },
+    ];
+    let mut events = 0;
+    for location in &locations {
+        for day in 1..=30 {
+            let date = Utc.with_ymd_and_hms(2024, 3, day, 0, 0, 0).unwrap();
+            let (rise, set) = moon_rise_set(date, location).unwrap();
+            for event in [rise, set].into_iter().flatten() {
+                assert_eq!(event.day(), day);
+                events += 1;
+                let (ra, dec) = moon::moon_equatorial(event);
+                let distance_km = moon::moon_distance(event);
+                let (ra, dec) = precession::precess_from_j2000(ra, dec, event).unwrap();
+                let (ra, dec) = nutation::apply_nutation(ra, dec, event).unwrap();
+                let (alt, _) = ra_dec_to_alt_az(ra, dec, event, location).unwrap();
+                // Topocentric geometric altitude of the upper limb is 34'
+                // of refraction below the horizon
+                let parallax = (6378.137 / distance_km).asin().to_degrees() * alt.to_radians().cos();
+                let semi_diameter = (1737.4 / distance_km).asin().to_degrees();
+                let upper_limb = alt - parallax + semi_diameter;
+                assert!((upper_limb - RISE_SET_ALTITUDE).abs() < 0.01, "{:?} {} {}", location, event, upper_limb);
+            }
+        }
+    }
+    // About one day a month without each event
+    assert!(events >= 3 * (60 - 4), "{}", events);
+}
+
+#[test]
+fn test_moon_rise_set_elevated_observer() {
+    let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    let date = Utc.with_ymd_and_hms(2024, 8, 4, 0, 0, 0).unwrap();
+    let (rise, set) = moon_rise_set(date, &location).unwrap();
+    let (rise, set) = (rise.unwrap(), set.unwrap());
+
+    // A high site alone moves the events only through the Moon's parallax
+    let elevated = Location { altitude_m: 2000.0, ..location };
+    let (site_rise, site_set) = moon_rise_set(date, &elevated).unwrap();
+    assert!((site_rise.unwrap() - rise).num_seconds().abs() <= 2);
+    assert!((site_set.unwrap() - set).num_seconds().abs() <= 2);
+
+    // The dipped horizon brings moonrise earlier and moonset later
+    let (high_rise, high_set) = body_rise_set_from_height(&almanac::AlmanacBody::Moon, date, &location, 2000.0).unwrap();
+    assert!(high_rise.unwrap() < rise && high_set.unwrap() > set);
+    assert_eq!(body_rise_set_from_height(&almanac::AlmanacBody::Moon, date, &location, 0.0).unwrap(), (Some(rise), Some(set)));
+
+    assert!(body_rise_set_from_height(&almanac::AlmanacBody::Moon, date, &location, f64::NAN).is_err());
+}
+
+#[test]
+fn test_moon_transit_on_meridian() {
+    let location = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+    let moon = almanac::AlmanacBody::Moon;
+    let mut transits = Vec::new();
+    for day in 1..=31 {
+        let date = Utc.with_ymd_and_hms(2024, 3, day, 0, 0, 0).unwrap();
+        let Some(transit) = moon_transit(date, &location).unwrap() else { continue };
+        assert_eq!(transit.day(), day);
+        // Due south, as the Moon never reaches 51.5° north
+        let (_, az) = crate::transforms::body_alt_az(&moon, transit, &location).unwrap();
+        assert!((az - 180.0).abs() < 0.01, "{} {}", transit, az);
+        if let (Some(rise), Some(set)) = moon_rise_set(date, &location).unwrap() {
+            if rise < set {
+                assert!(rise < transit && transit < set);
+            }
+        }
+        transits.push(transit);
+    }
+    // About 50 minutes later each day, so one day in the month has none
+    assert_eq!(transits.len(), 30);
+
+    let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+    let from_events: Vec<_> = rise_set_events(&moon, start, end, &location, 0.0)
+        .unwrap()
+        .filter(|e| e.kind == RiseSetEventKind::Transit)
+        .map(|e| e.time)
+        .collect();
+    assert_eq!(transits, from_events);
+
+    let bad = Location { latitude_deg: 95.0, ..location };
+    assert!(matches!(moon_transit(start, &bad), Err(AstroError::InvalidCoordinate { .. })));
+}
+
+#[test]
+fn test_sun_rise_set_models_against_usno() {
+    let greenwich = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+    // USNO: sunrise 06:44, sunset 17:41 UTC on 2024 March 1
+    let date = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let (rise, set) = sun_rise_set_with_model(date, &greenwich, SolarModel::Ephemeris).unwrap().unwrap();
+    assert_eq!((rise.hour(), rise.minute()), (6, 44));
+    assert_eq!((set.hour(), set.minute()), (17, 41));
+}
+
+#[test]
+fn test_sun_rise_set_models_agree_with_almanac_search() {
+    use crate::almanac::{monthly_table, AlmanacBody};
+    let greenwich = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+    for (month, day) in [(3, 1), (6, 21), (9, 22), (12, 21)] {
+        let date = Utc.with_ymd_and_hms(2024, month, day, 0, 0, 0).unwrap();
+        // The almanac follows the apparent Sun through the day
+        let table = monthly_table(AlmanacBody::Sun, date, &greenwich).unwrap();
+        let row = &table[day as usize - 1];
+        let (expected_rise, expected_set) = (row.rise.unwrap(), row.set.unwrap());
+
+        let (rise, set) = sun_rise_set_with_model(date, &greenwich, SolarModel::Ephemeris).unwrap().unwrap();
+        assert!((rise - expected_rise).num_seconds().abs() <= 5, "{} rise {}", date, rise);
+        assert!((set - expected_set).num_seconds().abs() <= 5, "{} set {}", date, set);
+
+        // The fixed low-precision Sun stays within a few minutes
+        let (rise, set) = sun_rise_set_with_model(date, &greenwich, SolarModel::LowPrecision).unwrap().unwrap();
+        assert!((rise - expected_rise).num_seconds().abs() < 360, "{} rise {}", date, rise);
+        assert!((set - expected_set).num_seconds().abs() < 360, "{} set {}", date, set);
+        assert_eq!(sun_rise_set(date, &greenwich).unwrap(), Some((rise, set)));
+    }
+}
+
+#[test]
+fn test_sun_rise_set_with_model_polar_and_errors() {
+    let tromso = Location { latitude_deg: 69.65, longitude_deg: 18.96, altitude_m: 0.0 };
+    let midsummer = Utc.with_ymd_and_hms(2024, 6, 21, 0, 0, 0).unwrap();
+    let midwinter = Utc.with_ymd_and_hms(2024, 12, 21, 0, 0, 0).unwrap();
+    for model in [SolarModel::LowPrecision, SolarModel::Ephemeris] {
+        assert_eq!(sun_rise_set_with_model(midsummer, &tromso, model).unwrap(), None);
+        assert_eq!(sun_rise_set_with_model(midwinter, &tromso, model).unwrap(), None);
+        let high = Location { altitude_m: 3000.0, ..tromso };
+        assert_eq!(sun_rise_set_with_model(midsummer, &high, model).unwrap(), None);
+    }
+    assert_eq!(SolarModel::default(), SolarModel::LowPrecision);
+}
+
+#[test]
+fn test_rise_set_events_match_daily_search() {
+    use crate::ephemeris::FixedPosition;
+    let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    let m31 = FixedPosition { ra: 10.6847, dec: 41.2689 };
+    let start = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 10, 4, 0, 0, 0).unwrap();
+
+    let events: Vec<_> = rise_set_events(&m31, start, end, &location, RISE_SET_ALTITUDE).unwrap().collect();
+    assert_eq!(events.len(), 9);
+    assert!(events.windows(2).all(|w| w[0].time < w[1].time));
+    assert!(events.iter().all(|e| e.time >= start && e.time < end));
+
+    let crossings = altitude_crossings(m31.ra, m31.dec, start, &location, RISE_SET_ALTITUDE).unwrap();
+    for (time, direction) in crossings {
+        let kind = if direction == events::Crossing::Rising { RiseSetEventKind::Rise } else { RiseSetEventKind::Set };
+        assert!(events.iter().any(|e| e.kind == kind && (e.time - time).num_seconds().abs() <= 1));
+    }
+    let transit = events.iter().find(|e| e.kind == RiseSetEventKind::Transit).unwrap();
+    // Upper transit just north of the zenith, at 90° - (41.27° - 40°)
+    assert!((transit.altitude_deg - 88.73).abs() < 0.05);
+}
+
+#[test]
+fn test_rise_set_events_are_lazy_and_follow_the_moon() {
+    let location = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+    let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    // A century, of which only the first events are computed
+    let far = Utc.with_ymd_and_hms(2124, 3, 1, 0, 0, 0).unwrap();
+    let moon = almanac::AlmanacBody::Moon;
+    let first: Vec<_> = rise_set_events(&moon, start, far, &location, 0.0).unwrap().take(3).collect();
+    assert_eq!(first.len(), 3);
+    assert!(first[2].time - start < chrono::Duration::days(2));
+
+    // About 50 minutes later each day: one day in a month has no moonrise
+    let end = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+    let rises = rise_set_events(&moon, start, end, &location, 0.0)
+        .unwrap()
+        .filter(|e| e.kind == RiseSetEventKind::Rise)
+        .count();
+    assert!((29..=31).contains(&rises), "{}", rises);
+}
+
+#[test]
+fn test_rise_set_events_errors() {
+    use crate::ephemeris::{TabulatedEphemeris, TabulatedPosition};
+    let location = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    let start = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+    let end = start + chrono::Duration::days(2);
+    let sun = almanac::AlmanacBody::Sun;
+    assert!(matches!(rise_set_events(&sun, end, start, &location, 0.0), Err(AstroError::InvalidDateTime { .. })));
+    assert!(matches!(rise_set_events(&sun, start, end, &location, 95.0), Err(AstroError::OutOfRange { .. })));
+
+    // A one-day table cannot cover two days
+    let row = |days, ra| TabulatedPosition { time: start + chrono::Duration::days(days), ra, dec: 10.0, distance_au: None };
+    let table = TabulatedEphemeris::new(vec![row(0, 100.0), row(1, 101.0)]).unwrap();
+    assert!(matches!(rise_set_events(&table, start, end, &location, 0.0), Err(AstroError::OutOfRange { .. })));
+}
@@ -0,0 +1,209 @@
+use crate::error::AstroError;
+use crate::satellite::{iss_alt_az, satellite_alt_az, Sgp4, Tle, CSS_NORAD_ID, ISS_NORAD_ID, MAX_PROPAGATION_DAYS};
+use crate::vec3::{norm, unit_vector_to_radec};
+use crate::Location;
+use chrono::{Duration, TimeZone, Utc};
+
+const ISS_LINE1: &str = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+const ISS_LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+// Test case 00005 of Vallado et al., "Revisiting Spacetrack Report #3" (2006)
+const VANGUARD_LINE1: &str = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+const VANGUARD_LINE2: &str = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+
+// A geostationary satellite, beyond the reach of near-Earth SGP4
+const GEO_LINE1: &str = "1 28626U 05019A   08264.50000000 -.00000109  00000-0  10000-3 0  9999";
+const GEO_LINE2: &str = "2 28626   0.0258 260.2934 0002491 213.0524 338.6791  1.00272015 12345";
+
+fn iss() -> Tle {
+    Tle::parse(ISS_LINE1, ISS_LINE2).unwrap()
+}
+
+#[test]
+fn test_parse_fields() {
+    let tle = iss();
+    assert_eq!(tle.norad_id, ISS_NORAD_ID);
+    assert_eq!(tle.name, None);
+    // Day 264.51782528 of 2008
+    let expected = Utc.with_ymd_and_hms(2008, 9, 20, 12, 25, 40).unwrap() + Duration::milliseconds(104);
+    assert!((tle.epoch - expected).num_milliseconds().abs() <= 1);
+    assert_eq!(tle.mean_motion_dot, -0.00002182);
+    assert!((tle.bstar + 0.11606e-4).abs() < 1e-15);
+    assert_eq!(tle.inclination_deg, 51.6416);
+    assert_eq!(tle.raan_deg, 247.4627);
+    assert!((tle.eccentricity - 0.0006703).abs() < 1e-15);
+    assert_eq!(tle.arg_perigee_deg, 130.5360);
+    assert_eq!(tle.mean_anomaly_deg, 325.0288);
+    assert_eq!(tle.mean_motion, 15.72125391);
+    assert!((tle.period_minutes() - 91.596).abs() < 0.001);
+}
+
+#[test]
+fn test_parse_epoch_century() {
+    let tle = Tle::parse(VANGUARD_LINE1, VANGUARD_LINE2).unwrap();
+    assert_eq!(tle.epoch.format("%Y-%m-%d").to_string(), "2000-06-27");
+    assert!((tle.bstar - 0.28098e-4).abs() < 1e-15);
+}
+
+#[test]
+fn test_parse_rejects_bad_checksum() {
+    let corrupted = ISS_LINE1.replace("51782528", "51782529");
+    let result = Tle::parse(&corrupted, ISS_LINE2);
+    assert!(matches!(result, Err(AstroError::InvalidFormat { ref reason, .. }) if reason.contains("checksum")));
+}
+
+#[test]
+fn test_parse_rejects_malformed_lines() {
+    assert!(matches!(Tle::parse(ISS_LINE2, ISS_LINE1), Err(AstroError::InvalidFormat { .. })));
+    assert!(matches!(Tle::parse(&ISS_LINE1[..60], ISS_LINE2), Err(AstroError::InvalidFormat { .. })));
+    // Catalog numbers from different satellites
+    assert!(matches!(Tle::parse(ISS_LINE1, VANGUARD_LINE2), Err(AstroError::InvalidFormat { .. })));
+}
+
+#[test]
+fn test_parse_all_mixed_sets() {
+    let text = format!(
+        "ISS (ZARYA)\n{}\n{}\n\n{}\n{}\n0 ISS AGAIN\n{}\n{}\n",
+        ISS_LINE1, ISS_LINE2, VANGUARD_LINE1, VANGUARD_LINE2, ISS_LINE1, ISS_LINE2
+    );
+    let sets = Tle::parse_all(&text).unwrap();
+    assert_eq!(sets.len(), 3);
+    assert_eq!(sets[0].name.as_deref(), Some("ISS (ZARYA)"));
+    assert_eq!(sets[1].name, None);
+    assert_eq!(sets[1].norad_id, 5);
+    assert_eq!(sets[2].name.as_deref(), Some("ISS AGAIN"));
+
+    let truncated = format!("ISS (ZARYA)\n{}\n", ISS_LINE1);
+    assert!(matches!(Tle::parse_all(&truncated), Err(AstroError::InvalidFormat { .. })));
+}
+
+#[test]
+fn test_iss_orbit_radius() {
+    let tle = iss();
+    for hours in [0, 6, 24, 72] {
+        let r = norm(tle.position_teme(tle.epoch + Duration::hours(hours)).unwrap());
+        // Roughly 350 km altitude in 2008, nearly circular
+        assert!((6700.0..6750.0).contains(&r), "radius {} km after {} h", r, hours);
+    }
+}
+
+#[test]
+fn test_iss_returns_after_one_period() {
+    let tle = iss();
+    let period = Duration::milliseconds((tle.period_minutes() * 60e3) as i64);
+    let start = tle.position_teme(tle.epoch).unwrap();
+    let end = tle.position_teme(tle.epoch + period).unwrap();
+    // Only the slow J2 drift of the orbit plane and perigee separates them
+    let gap = norm([0, 1, 2].map(|k| end[k] - start[k]));
+    assert!(gap < 50.0, "gap {} km", gap);
+}
+
+#[test]
+fn test_matches_sgp4_reference() {
+    let tle = Tle::parse(VANGUARD_LINE1, VANGUARD_LINE2).unwrap();
+    let sgp4 = Sgp4::new(&tle).unwrap();
+    let reference = [
+        (0.0, [7022.46529266, -1400.08296755, 0.03995155], [1.893841015, 6.405893759, 4.534807250]),
+        (360.0, [-7154.03120202, -3783.17682504, -3536.19412294], [4.741887409, -4.151817765, -2.093935425]),
+        (720.0, [-7134.59340119, 6531.68641334, 3260.27186483], [-4.113793027, -2.911922039, -2.557327851]),
+    ];
+    for (minutes, expected_r, expected_v) in reference {
+        let (position, velocity) = sgp4.propagate(minutes).unwrap();
+        let error = norm([0, 1, 2].map(|k| position[k] - expected_r[k]));
+        assert!(error < 1e-3, "{} km off after {} min", error, minutes);
+        let error = norm([0, 1, 2].map(|k| velocity[k] - expected_v[k]));
+        assert!(error < 1e-6, "{} km/s off after {} min", error, minutes);
+
+        let dt = tle.epoch + Duration::seconds((minutes * 60.0) as i64);
+        assert_eq!(tle.position_teme(dt).unwrap(), sgp4.position_velocity_teme(dt).unwrap().0);
+    }
+}
+
+#[test]
+fn test_sgp4_velocity_is_derivative_of_position() {
+    let sgp4 = Sgp4::new(&iss()).unwrap();
+    let (_, velocity) = sgp4.propagate(100.0).unwrap();
+    let (before, _) = sgp4.propagate(100.0 - 1.0 / 60.0).unwrap();
+    let (after, _) = sgp4.propagate(100.0 + 1.0 / 60.0).unwrap();
+    for k in 0..3 {
+        assert!(((after[k] - before[k]) / 2.0 - velocity[k]).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_deep_space_falls_back_to_secular_propagator() {
+    let tle = Tle::parse(GEO_LINE1, GEO_LINE2).unwrap();
+    assert!(matches!(Sgp4::new(&tle), Err(AstroError::CalculationError { .. })));
+    for hours in [0, 12, 48] {
+        let r = norm(tle.position_teme(tle.epoch + Duration::hours(hours)).unwrap());
+        assert!((42_100.0..42_250.0).contains(&r), "radius {} km after {} h", r, hours);
+    }
+}
+
+#[test]
+fn test_sgp4_rejects_stale_elements() {
+    let sgp4 = Sgp4::new(&iss()).unwrap();
+    let minutes = (MAX_PROPAGATION_DAYS + 1.0) * 1440.0;
+    assert!(matches!(sgp4.propagate(minutes), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(sgp4.propagate(f64::NAN), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_overhead_at_subsatellite_point() {
+    let tle = iss();
+    let dt = tle.epoch + Duration::hours(3);
+    let teme = tle.position_teme(dt).unwrap();
+    // Sub-satellite point: rotate the TEME longitude by GMST
+    let (jd1, jd2) = crate::time::julian_date_two_part(dt);
+    let gmst_deg = crate::sidereal::gmst_two_part(jd1, jd2) * 15.0;
+    let (ra, dec) = unit_vector_to_radec(teme);
+    let longitude = (ra - gmst_deg + 540.0).rem_euclid(360.0) - 180.0;
+    // Geocentric and geodetic latitude differ by up to 0.19°, which tilts
+    // the vertical by ~3° at the station's height
+    let below = Location { latitude_deg: dec, longitude_deg: longitude, altitude_m: 0.0 };
+    let (alt, _) = satellite_alt_az(&tle, dt, &below).unwrap();
+    assert!(alt > 85.0, "altitude {}", alt);
+
+    // From the far side of the Earth it is well below the horizon
+    let antipode = Location { latitude_deg: -dec, longitude_deg: (longitude + 360.0) % 360.0 - 180.0, altitude_m: 0.0 };
+    let (alt, _) = satellite_alt_az(&tle, dt, &antipode).unwrap();
+    assert!(alt < -80.0, "altitude {}", alt);
+}
+
+#[test]
+fn test_alt_az_method_matches_function() {
+    let tle = iss();
+    let site = Location { latitude_deg: 35.0, longitude_deg: 139.0, altitude_m: 40.0 };
+    let dt = tle.epoch + Duration::minutes(95);
+    assert_eq!(tle.alt_az(dt, &site).unwrap(), satellite_alt_az(&tle, dt, &site).unwrap());
+}
+
+#[test]
+fn test_iss_alt_az_finds_iss() {
+    let site = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+    let tle = iss();
+    let dt = tle.epoch + Duration::hours(10);
+    let text = format!("{}\n{}\nISS (ZARYA)\n{}\n{}\n", VANGUARD_LINE1, VANGUARD_LINE2, ISS_LINE1, ISS_LINE2);
+    assert_eq!(iss_alt_az(dt, &site, &text).unwrap(), satellite_alt_az(&tle, dt, &site).unwrap());
+
+    let no_iss = format!("{}\n{}\n", VANGUARD_LINE1, VANGUARD_LINE2);
+    assert!(matches!(iss_alt_az(dt, &site, &no_iss), Err(AstroError::CalculationError { .. })));
+    assert_ne!(CSS_NORAD_ID, ISS_NORAD_ID);
+}
+
+#[test]
+fn test_rejects_stale_elements() {
+    let tle = iss();
+    let site = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let late = tle.epoch + Duration::hours((MAX_PROPAGATION_DAYS * 24.0) as i64 + 1);
+    assert!(matches!(satellite_alt_az(&tle, late, &site), Err(AstroError::OutOfRange { .. })));
+    let early = tle.epoch - Duration::days(30);
+    assert!(matches!(tle.position_teme(early), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_rejects_invalid_location() {
+    let tle = iss();
+    let site = Location { latitude_deg: 95.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(matches!(satellite_alt_az(&tle, tle.epoch, &site), Err(AstroError::OutOfRange { .. })));
+}
@@ -0,0 +1,175 @@
+use crate::error::AstroError;
+use crate::schedule::*;
+use crate::transforms::ra_dec_to_alt_az;
+use crate::Location;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn site() -> Location {
+    Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 }
+}
+
+fn night() -> (DateTime<Utc>, DateTime<Utc>) {
+    (
+        Utc.with_ymd_and_hms(2024, 10, 1, 2, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2024, 10, 1, 12, 0, 0).unwrap(),
+    )
+}
+
+#[test]
+fn test_optimal_time_is_transit() {
+    let targets = [(10.6847, 41.2689), (350.0, 10.0)];
+    let groups = group_by_transit(&targets, night(), &site(), 10.0).unwrap();
+    for group in &groups {
+        for target in &group.targets {
+            assert!(target.transits);
+            let (ra, dec) = targets[target.index];
+            let (alt, az) = ra_dec_to_alt_az(ra, dec, target.optimal_time, &site()).unwrap();
+            assert!((alt - target.altitude_deg).abs() < 1e-9);
+            // On the meridian, north of the zenith for M31 at Dec +41°
+            let expected_az = if dec > site().latitude_deg { 0.0 } else { 180.0 };
+            assert!(((az - expected_az + 180.0).rem_euclid(360.0) - 180.0).abs() < 0.5, "az {}", az);
+            // Lower a minute before and after
+            for offset in [-1, 1] {
+                let (neighbour, _) =
+                    ra_dec_to_alt_az(ra, dec, target.optimal_time + Duration::minutes(offset), &site()).unwrap();
+                assert!(neighbour < alt);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_targets_outside_transit_use_nearer_end() {
+    let (start, end) = night();
+    // Transits about an hour before the night starts, and about an hour after it ends
+    let lst_start = site().local_sidereal_time(start);
+    let early_ra = ((lst_start - 1.0).rem_euclid(24.0)) * 15.0;
+    let lst_end = site().local_sidereal_time(end);
+    let late_ra = ((lst_end + 1.0).rem_euclid(24.0)) * 15.0;
+
+    let groups = group_by_transit(&[(late_ra, 30.0), (early_ra, 30.0)], night(), &site(), 30.0).unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].indices(), vec![1]);
+    assert_eq!(groups[0].targets[0].optimal_time, start);
+    assert!(!groups[0].targets[0].transits);
+    assert_eq!(groups[1].targets[0].optimal_time, end);
+
+    // Windows are clipped to the night
+    assert_eq!(groups[0].start, start);
+    assert_eq!(groups[0].end, start + Duration::minutes(15));
+    assert_eq!(groups[1].start, end - Duration::minutes(15));
+    assert_eq!(groups[1].end, end);
+}
+
+#[test]
+fn test_grouping_is_transitive_and_ordered() {
+    let lst_start = site().local_sidereal_time(night().0);
+    // Transits 2 h, 2 h 40 m, 3 h 20 m and 5 h into the night (sidereal), given out of order
+    let ra_at = |hours: f64| ((lst_start + hours).rem_euclid(24.0)) * 15.0;
+    let targets = [(ra_at(5.0), 20.0), (ra_at(3.0 + 1.0 / 3.0), 20.0), (ra_at(2.0), 20.0), (ra_at(2.0 + 2.0 / 3.0), 20.0)];
+
+    let groups = group_by_transit(&targets, night(), &site(), 45.0).unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].indices(), vec![2, 3, 1]);
+    assert_eq!(groups[1].indices(), vec![0]);
+    assert_eq!(groups[0].start, groups[0].targets[0].optimal_time - Duration::seconds(22 * 60 + 30));
+    assert_eq!(groups[0].end, groups[0].targets[2].optimal_time + Duration::seconds(22 * 60 + 30));
+
+    // A narrower window splits every target into its own group
+    let groups = group_by_transit(&targets, night(), &site(), 30.0).unwrap();
+    assert_eq!(groups.len(), 4);
+    assert!(groups.windows(2).all(|pair| pair[0].targets[0].optimal_time < pair[1].targets[0].optimal_time));
+}
+
+#[test]
+fn test_targets_below_horizon_are_left_out() {
+    // The south celestial pole region never rises at +32°
+    let groups = group_by_transit(&[(0.0, -80.0), (10.6847, 41.2689)], night(), &site(), 30.0).unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].indices(), vec![1]);
+    assert!(group_by_transit(&[], night(), &site(), 30.0).unwrap().is_empty());
+}
+
+#[test]
+fn test_invalid_inputs() {
+    let (start, end) = night();
+    assert!(matches!(
+        group_by_transit(&[(10.0, 10.0)], (end, start), &site(), 30.0),
+        Err(AstroError::OutOfRange { parameter: "night", .. })
+    ));
+    assert!(matches!(
+        group_by_transit(&[(10.0, 10.0)], night(), &site(), 0.0),
+        Err(AstroError::OutOfRange { parameter: "window_minutes", .. })
+    ));
+    assert!(matches!(
+        group_by_transit(&[(10.0, 100.0)], night(), &site(), 30.0),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    let nowhere = Location { latitude_deg: 95.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(matches!(
+        group_by_transit(&[(10.0, 10.0)], night(), &nowhere, 30.0),
+        Err(AstroError::OutOfRange { .. })
+    ));
+}
+
+#[test]
+fn test_field_observability_waits_for_every_corner() {
+    // A 4°×4° footprint on the celestial equator, rising during the night
+    let corners = vec![(348.0, -2.0), (352.0, -2.0), (352.0, 2.0), (348.0, 2.0)];
+    let field = SurveyField::Polygon(corners.clone());
+    let constraints = FieldConstraints::default();
+    let windows = field_observability(&field, night(), &site(), &constraints).unwrap();
+    assert_eq!(windows.len(), 1);
+    let (start, end) = windows[0];
+
+    // At the start the lowest corner is at 30°, and all are above it inside
+    let lowest = |t| corners.iter().map(|&(ra, dec)| ra_dec_to_alt_az(ra, dec, t, &site()).unwrap().0).fold(90.0, f64::min);
+    assert!((lowest(start) - 30.0).abs() < 1e-3, "{}", lowest(start));
+    assert!(lowest(start + Duration::minutes(30)) > 30.0);
+    if end < night().1 {
+        assert!((lowest(end) - 30.0).abs() < 1e-3);
+    }
+
+    // Half the corners is satisfied sooner than all of them
+    let half = FieldConstraints { min_fraction: 0.5, ..constraints };
+    let half_windows = field_observability(&field, night(), &site(), &half).unwrap();
+    assert!(half_windows[0].0 < start && half_windows[0].1 >= end);
+}
+
+#[test]
+fn test_field_observability_airmass_and_extremes() {
+    let field = SurveyField::Circle { ra: 20.0, dec: 20.0, radius: 2.0 };
+    // Airmass 2 is near 30° altitude, so the tighter limit wins either way
+    let by_airmass = FieldConstraints { min_altitude_deg: 0.0, max_airmass: Some(2.0), min_fraction: 1.0 };
+    let by_altitude = FieldConstraints { min_altitude_deg: 30.0, ..by_airmass };
+    let a = field_observability(&field, night(), &site(), &by_airmass).unwrap();
+    let b = field_observability(&field, night(), &site(), &by_altitude).unwrap();
+    assert_eq!(a.len(), 1);
+    assert!((a[0].0 - b[0].0).num_seconds().abs() < 300);
+    assert!(b[0].0 >= a[0].0);
+
+    // Never up from Arizona, and up all night
+    let south = SurveyField::Circle { ra: 0.0, dec: -80.0, radius: 3.0 };
+    assert!(field_observability(&south, night(), &site(), &FieldConstraints::default()).unwrap().is_empty());
+    let north = SurveyField::Circle { ra: 0.0, dec: 85.0, radius: 1.0 };
+    assert_eq!(field_observability(&north, night(), &site(), &FieldConstraints::default()).unwrap(), vec![night()]);
+}
+
+#[test]
+fn test_field_observability_errors() {
+    let field = SurveyField::Circle { ra: 20.0, dec: 20.0, radius: 2.0 };
+    let check = |constraints: FieldConstraints| field_observability(&field, night(), &site(), &constraints);
+    assert!(matches!(check(FieldConstraints { min_fraction: 0.0, ..Default::default() }), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(check(FieldConstraints { max_airmass: Some(0.9), ..Default::default() }), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(check(FieldConstraints { min_altitude_deg: 95.0, ..Default::default() }), Err(AstroError::OutOfRange { .. })));
+
+    let constraints = FieldConstraints::default();
+    let reversed = (night().1, night().0);
+    assert!(matches!(field_observability(&field, reversed, &site(), &constraints), Err(AstroError::OutOfRange { .. })));
+    let too_few = SurveyField::Polygon(vec![(0.0, 0.0), (1.0, 0.0)]);
+    assert!(matches!(field_observability(&too_few, night(), &site(), &constraints), Err(AstroError::CalculationError { .. })));
+    let bad = SurveyField::Polygon(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 95.0)]);
+    assert!(matches!(field_observability(&bad, night(), &site(), &constraints), Err(AstroError::InvalidCoordinate { .. })));
+    let wide = SurveyField::Circle { ra: 0.0, dec: 0.0, radius: 100.0 };
+    assert!(matches!(field_observability(&wide, night(), &site(), &constraints), Err(AstroError::OutOfRange { .. })));
+}
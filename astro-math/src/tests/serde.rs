@@ -0,0 +1,64 @@
+use crate::config::{Config, ObliquityModel, ValidationPolicy};
+use crate::eop::{EarthOrientation, ExtrapolationPolicy};
+use crate::nutation::nutation;
+use crate::pointing_model::{PointingModel, PointingTerm};
+use crate::{Location, TangentPlane};
+use crate::refraction::{AtmosphericConditions, RefractionModel};
+
+#[test]
+fn test_location_round_trip() {
+    let location = Location { latitude_deg: 19.8207, longitude_deg: -155.468, altitude_m: 4205.0 };
+    let json = serde_json::to_string(&location).unwrap();
+    assert_eq!(json, r#"{"latitude_deg":19.8207,"longitude_deg":-155.468,"altitude_m":4205.0}"#);
+
+    let back: Location = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.latitude_deg, location.latitude_deg);
+    assert_eq!(back.longitude_deg, location.longitude_deg);
+    assert_eq!(back.altitude_m, location.altitude_m);
+}
+
+#[test]
+fn test_projection_and_nutation_round_trip() {
+    let plane = TangentPlane::new(83.82, -5.39, 1.5).unwrap().with_rotation(12.0).with_reference_pixel(1024.0, 768.0);
+    let back: TangentPlane = serde_json::from_str(&serde_json::to_string(&plane).unwrap()).unwrap();
+    assert_eq!(back, plane);
+
+    let nut = nutation(2460526.75);
+    let back = serde_json::from_str(&serde_json::to_string(&nut).unwrap()).unwrap();
+    assert_eq!(nut, back);
+}
+
+#[test]
+fn test_eop_and_pointing_model_round_trip() {
+    let eop = EarthOrientation { dut1: -0.03, xp_arcsec: 0.12, yp_arcsec: 0.4, dx_mas: Some(0.2), dy_mas: None };
+    let back: EarthOrientation = serde_json::from_str(&serde_json::to_string(&eop).unwrap()).unwrap();
+    assert_eq!(back, eop);
+
+    let policy = ExtrapolationPolicy { max_days: 30.0, fit_days: 20.0 };
+    let back: ExtrapolationPolicy = serde_json::from_str(&serde_json::to_string(&policy).unwrap()).unwrap();
+    assert_eq!(back, policy);
+
+    let mut model = PointingModel::new();
+    model.caption = "Dome 2, 2024-03".to_string();
+    model.set_term(PointingTerm::IH, -12.5);
+    model.set_term(PointingTerm::DAF, 3.25);
+    let json = serde_json::to_string(&model).unwrap();
+    assert!(json.contains(r#"["IH",-12.5]"#), "{}", json);
+    let back: PointingModel = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, model);
+}
+
+#[test]
+fn test_config_round_trip() {
+    let config = Config {
+        conditions: Some(AtmosphericConditions { pressure_hpa: 615.0, temperature_c: 2.0, relative_humidity: 0.2 }),
+        obliquity: ObliquityModel::Iau1980,
+        validation: ValidationPolicy::Wrap,
+    };
+    let back: Config = serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+    assert_eq!(back, config);
+
+    let model = RefractionModel::Raytrace { height_m: 2400.0 };
+    let back: RefractionModel = serde_json::from_str(&serde_json::to_string(&model).unwrap()).unwrap();
+    assert_eq!(back, model);
+}
@@ -1,4 +1,7 @@
-use crate::sidereal::{apparent_sidereal_time, gmst, local_mean_sidereal_time};
+use crate::sidereal::{
+    apparent_sidereal_time, apparent_sidereal_time_checked, gmst, gmst_checked, local_mean_sidereal_time,
+    local_mean_sidereal_time_checked,
+};
 use crate::time::julian_date;
 use chrono::{DateTime, TimeZone, Utc};
 
@@ -80,3 +83,50 @@ fn test_apparent_sidereal_time_astropy_crosscheck() {
         );
     }
 }
+
+#[test]
+fn test_two_part_sidereal_matches_single() {
+    use crate::sidereal::{apparent_sidereal_time_two_part, gmst_two_part};
+    use crate::time::julian_date_two_part;
+
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let jd = julian_date(dt);
+    let (jd1, jd2) = julian_date_two_part(dt);
+
+    assert!((gmst(jd) - gmst_two_part(jd1, jd2)).abs() < 1e-8);
+    assert!(
+        (apparent_sidereal_time(jd, -111.6) - apparent_sidereal_time_two_part(jd1, jd2, -111.6)).abs()
+            < 1e-8
+    );
+}
+
+#[test]
+fn test_apparent_sidereal_time_with_eop() {
+    use crate::eop::EarthOrientation;
+    use crate::sidereal::apparent_sidereal_time_with_eop;
+    let jd = 2460310.75;
+    let none = apparent_sidereal_time_with_eop(jd, 10.0, &EarthOrientation::default());
+    assert!((none - apparent_sidereal_time(jd, 10.0)).abs() < 1e-10);
+
+    // UT1 ahead of UTC by 0.5 s: the Earth has turned 0.5 s further
+    let ahead = apparent_sidereal_time_with_eop(jd, 10.0, &EarthOrientation { dut1: 0.5, ..Default::default() });
+    assert!(((ahead - none) * 3600.0 - 0.5 * 1.00273791).abs() < 1e-4);
+}
+
+#[test]
+fn test_checked_variants_reject_invalid_input() {
+    let jd = julian_date(Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap());
+    assert_eq!(gmst_checked(jd).unwrap(), gmst(jd));
+    assert_eq!(local_mean_sidereal_time_checked(jd, -111.6).unwrap(), local_mean_sidereal_time(jd, -111.6));
+    assert_eq!(apparent_sidereal_time_checked(jd, -111.6).unwrap(), apparent_sidereal_time(jd, -111.6));
+
+    for bad_jd in [f64::NAN, f64::INFINITY, -1.0, 1.0e10] {
+        assert!(gmst_checked(bad_jd).is_err(), "accepted JD {}", bad_jd);
+        assert!(local_mean_sidereal_time_checked(bad_jd, 0.0).is_err());
+        assert!(apparent_sidereal_time_checked(bad_jd, 0.0).is_err());
+    }
+    for bad_longitude in [f64::NAN, 180.5, -360.0] {
+        assert!(local_mean_sidereal_time_checked(jd, bad_longitude).is_err());
+        assert!(apparent_sidereal_time_checked(jd, bad_longitude).is_err());
+    }
+}
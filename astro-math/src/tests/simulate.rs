@@ -0,0 +1,128 @@
+use crate::apparent::apparent_place;
+use crate::error::AstroError;
+use crate::projection::TangentPlane;
+use crate::refraction::AtmosphericConditions;
+use crate::simulate::*;
+use crate::transforms::ra_dec_to_alt_az;
+use crate::Location;
+use chrono::{DateTime, TimeZone, Utc};
+
+fn epoch() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap()
+}
+
+fn site() -> Location {
+    Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 }
+}
+
+fn grid(ra0: f64, dec0: f64) -> Vec<CatalogStar> {
+    let mut stars = Vec::new();
+    for i in -2..=2 {
+        for j in -2..=2 {
+            stars.push(CatalogStar { ra: ra0 + i as f64 * 0.1, dec: dec0 + j as f64 * 0.1, magnitude: 8.0 + (i + j) as f64 });
+        }
+    }
+    stars
+}
+
+#[test]
+fn test_no_effects_is_plain_projection() {
+    let wcs = TangentPlane::new(150.0, 40.0, 2.0).unwrap().with_reference_pixel(1000.0, 800.0).with_rotation(30.0);
+    let catalog = grid(150.0, 40.0);
+    let conditions = SimulationConditions { zero_point_flux: 1e6, ..SimulationConditions::default() };
+    let stars = star_field(&wcs, &catalog, epoch(), &conditions).unwrap();
+    assert_eq!(stars.len(), catalog.len());
+    for (star, &(x, y, flux)) in catalog.iter().zip(&stars) {
+        assert_eq!((x, y), wcs.ra_dec_to_pixel(star.ra, star.dec).unwrap());
+        assert!((flux - 1e6 * 10f64.powf(-0.4 * star.magnitude)).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_apparent_place_matches_apparent_wcs() {
+    // Pointing at the apparent place of the centre star puts it on the reference pixel
+    let (ra, dec) = apparent_place(150.0, 40.0, epoch()).unwrap();
+    let wcs = TangentPlane::new(ra, dec, 1.0).unwrap();
+    let conditions = SimulationConditions { apparent_place: true, ..SimulationConditions::default() };
+    let stars = star_field(&wcs, &grid(150.0, 40.0), epoch(), &conditions).unwrap();
+    let centre = stars[12];
+    assert!(centre.0.abs() < 1e-6 && centre.1.abs() < 1e-6);
+
+    // The same pointing without the effect leaves the field ~20′ away
+    let plain = star_field(&wcs, &grid(150.0, 40.0), epoch(), &SimulationConditions::default()).unwrap();
+    assert!(plain[12].0.hypot(plain[12].1) > 600.0);
+}
+
+#[test]
+fn test_refraction_lifts_and_stretches_field() {
+    // Orion at about 30° altitude, rising in the east
+    let time = Utc.with_ymd_and_hms(2024, 12, 1, 5, 0, 0).unwrap();
+    let (alt, _) = ra_dec_to_alt_az(83.82, -5.39, time, &site()).unwrap();
+    assert!(alt > 20.0 && alt < 40.0, "{}", alt);
+
+    let wcs = TangentPlane::new(83.82, -5.39, 1.0).unwrap();
+    let catalog = grid(83.82, -5.39);
+    let unrefracted = star_field(&wcs, &catalog, time, &SimulationConditions::default()).unwrap();
+    let refracted = star_field(
+        &wcs,
+        &catalog,
+        time,
+        &SimulationConditions { refraction: Some((site(), AtmosphericConditions::standard())), ..SimulationConditions::default() },
+    )
+    .unwrap();
+
+    // Every star moves by roughly the refraction at 30° (~1.7′ at sea level)
+    for (a, b) in unrefracted.iter().zip(&refracted) {
+        let shift = (b.0 - a.0).hypot(b.1 - a.1);
+        assert!(shift > 60.0 && shift < 180.0, "{}″", shift);
+    }
+    // Stars lower in the sky move more, stretching the field vertically
+    let shift = |k: usize| (refracted[k].0 - unrefracted[k].0).hypot(refracted[k].1 - unrefracted[k].1);
+    let (low, high) = (0..catalog.len())
+        .map(|k| (ra_dec_to_alt_az(catalog[k].ra, catalog[k].dec, time, &site()).unwrap().0, k))
+        .fold(((f64::MAX, 0), (f64::MIN, 0)), |(lo, hi), (a, k)| {
+            (if a < lo.0 { (a, k) } else { lo }, if a > hi.0 { (a, k) } else { hi })
+        });
+    assert!(shift(low.1) > shift(high.1));
+}
+
+#[test]
+fn test_culling() {
+    let wcs = TangentPlane::new(150.0, 40.0, 1.0).unwrap().with_reference_pixel(500.0, 500.0);
+    let conditions = SimulationConditions { image_size: Some((1000.0, 1000.0)), ..SimulationConditions::default() };
+    // Only the centre star and its ±0.1° neighbours (360″) fit in a 1000″ frame
+    let stars = star_field(&wcs, &grid(150.0, 40.0), epoch(), &conditions).unwrap();
+    assert!(stars.iter().all(|&(x, y, _)| (0.0..1000.0).contains(&x) && (0.0..1000.0).contains(&y)));
+    assert_eq!(stars.len(), 9);
+
+    // Stars on the far side of the sky are dropped, not an error
+    let opposite = [CatalogStar { ra: 330.0, dec: -40.0, magnitude: 1.0 }];
+    assert!(star_field(&wcs, &opposite, epoch(), &SimulationConditions::default()).unwrap().is_empty());
+
+    // So are stars below the horizon when refracting
+    let refracting = SimulationConditions { refraction: Some((site(), AtmosphericConditions::standard())), ..SimulationConditions::default() };
+    let south = [CatalogStar { ra: 150.0, dec: -85.0, magnitude: 1.0 }];
+    let wcs = TangentPlane::new(150.0, -85.0, 1.0).unwrap();
+    assert!(star_field(&wcs, &south, epoch(), &refracting).unwrap().is_empty());
+}
+
+#[test]
+fn test_invalid_inputs() {
+    let wcs = TangentPlane::new(150.0, 40.0, 1.0).unwrap();
+    let good = [CatalogStar { ra: 150.0, dec: 40.0, magnitude: 5.0 }];
+    assert!(matches!(
+        star_field(&wcs, &[CatalogStar { ra: 150.0, dec: 95.0, magnitude: 5.0 }], epoch(), &SimulationConditions::default()),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(
+        star_field(&wcs, &[CatalogStar { ra: 150.0, dec: 40.0, magnitude: f64::NAN }], epoch(), &SimulationConditions::default()),
+        Err(AstroError::OutOfRange { parameter: "magnitude", .. })
+    ));
+    let bad_zero_point = SimulationConditions { zero_point_flux: 0.0, ..SimulationConditions::default() };
+    assert!(star_field(&wcs, &good, epoch(), &bad_zero_point).is_err());
+    let bad_size = SimulationConditions { image_size: Some((100.0, -1.0)), ..SimulationConditions::default() };
+    assert!(star_field(&wcs, &good, epoch(), &bad_size).is_err());
+    let bad_weather = AtmosphericConditions { pressure_hpa: -5.0, ..AtmosphericConditions::standard() };
+    let refracting = SimulationConditions { refraction: Some((site(), bad_weather)), ..SimulationConditions::default() };
+    assert!(star_field(&wcs, &good, epoch(), &refracting).is_err());
+}
@@ -0,0 +1,93 @@
+use crate::error::AstroError;
+use crate::sky_polygon::*;
+
+const FULL_SKY_SQ_DEG: f64 = 41252.96124941928;
+
+#[test]
+fn test_area_known_shapes() {
+    let octant = [(0.0, 0.0), (90.0, 0.0), (0.0, 90.0)];
+    assert!((area_sq_deg(&octant).unwrap() - FULL_SKY_SQ_DEG / 8.0).abs() < 1e-6);
+
+    // Winding direction does not matter
+    let reversed = [(0.0, 90.0), (90.0, 0.0), (0.0, 0.0)];
+    assert!((area_sq_deg(&reversed).unwrap() - FULL_SKY_SQ_DEG / 8.0).abs() < 1e-6);
+
+    // Spherical excess of a small square is close to its flat area
+    let square = [(10.0, 20.0), (10.0 + 0.1 / 20f64.to_radians().cos(), 20.0), (10.0 + 0.1 / 20f64.to_radians().cos(), 20.1), (10.0, 20.1)];
+    assert!((area_sq_deg(&square).unwrap() - 0.01).abs() < 1e-4);
+
+    // Square around the pole: four triangles pole-(0,45)-(90,45), each with
+    // a right angle at the pole and two base angles of acos(1/√3)
+    let cap = [(0.0, 45.0), (90.0, 45.0), (180.0, 45.0), (270.0, 45.0)];
+    let area = area_sq_deg(&cap).unwrap();
+    let base = (1f64 / 3f64.sqrt()).acos();
+    let excess = std::f64::consts::FRAC_PI_2 + 2.0 * base - std::f64::consts::PI;
+    let expected = 4.0 * excess * (180.0 / std::f64::consts::PI).powi(2);
+    assert!((area - expected).abs() < 1e-6, "{} vs {}", area, expected);
+}
+
+#[test]
+fn test_contains_convex_and_concave() {
+    let octant = [(0.0, 0.0), (90.0, 0.0), (0.0, 90.0)];
+    assert!(contains(&octant, 45.0, 30.0).unwrap());
+    assert!(!contains(&octant, 135.0, 30.0).unwrap());
+    assert!(!contains(&octant, 45.0, -10.0).unwrap());
+    // The antipode of an interior point is outside
+    assert!(!contains(&octant, 225.0, -30.0).unwrap());
+
+    // L-shaped footprint
+    let l_shape = [(10.0, 0.0), (12.0, 0.0), (12.0, 1.0), (11.0, 1.0), (11.0, 2.0), (10.0, 2.0)];
+    assert!(contains(&l_shape, 10.5, 1.5).unwrap());
+    assert!(contains(&l_shape, 11.5, 0.5).unwrap());
+    assert!(!contains(&l_shape, 11.5, 1.5).unwrap());
+    assert!((area_sq_deg(&l_shape).unwrap() - 3.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_contains_across_ra_zero_and_pole() {
+    let wrap = [(358.0, -1.0), (2.0, -1.0), (2.0, 1.0), (358.0, 1.0)];
+    assert!(contains(&wrap, 0.0, 0.0).unwrap());
+    assert!(contains(&wrap, 359.0, 0.5).unwrap());
+    assert!(contains(&wrap, 1.0, -0.5).unwrap());
+    assert!(!contains(&wrap, 180.0, 0.0).unwrap());
+    assert!(!contains(&wrap, 3.0, 0.0).unwrap());
+
+    let south_cap = [(0.0, -80.0), (270.0, -80.0), (180.0, -80.0), (90.0, -80.0)];
+    for ra in [0.0, 77.0, 200.0, 359.0] {
+        assert!(contains(&south_cap, ra, -89.0).unwrap());
+    }
+    assert!(contains(&south_cap, 0.0, -90.0).unwrap());
+    assert!(!contains(&south_cap, 45.0, -78.0).unwrap());
+}
+
+#[test]
+fn test_intersects_cone() {
+    let square = [(100.0, -1.0), (102.0, -1.0), (102.0, 1.0), (100.0, 1.0)];
+    // Centre inside
+    assert!(intersects_cone(&square, 101.0, 0.0, 0.0).unwrap());
+    // Centre outside, edge within the radius
+    assert!(intersects_cone(&square, 103.0, 0.0, 1.01).unwrap());
+    assert!(!intersects_cone(&square, 103.0, 0.0, 0.99).unwrap());
+    // Nearest point is a corner
+    let corner_distance = 2f64.sqrt();
+    assert!(intersects_cone(&square, 103.0, 2.0, corner_distance + 0.01).unwrap());
+    assert!(!intersects_cone(&square, 103.0, 2.0, corner_distance - 0.01).unwrap());
+    // Cone containing the whole polygon
+    assert!(intersects_cone(&square, 280.0, 0.0, 180.0).unwrap());
+}
+
+#[test]
+fn test_invalid_polygons() {
+    assert!(matches!(area_sq_deg(&[(0.0, 0.0), (1.0, 0.0)]), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(
+        contains(&[(0.0, 0.0), (180.0, 0.0), (90.0, 45.0)], 10.0, 10.0),
+        Err(AstroError::CalculationError { .. })
+    ));
+    // Vertices spread around the whole equator do not fit in a hemisphere
+    let belt = [(0.0, 0.0), (120.0, 0.0), (240.0, 0.0)];
+    assert!(matches!(area_sq_deg(&belt), Err(AstroError::CalculationError { .. })));
+    assert!(matches!(area_sq_deg(&[(0.0, 0.0), (1.0, 95.0), (2.0, 0.0)]), Err(AstroError::InvalidCoordinate { .. })));
+    let square = [(100.0, -1.0), (102.0, -1.0), (102.0, 1.0), (100.0, 1.0)];
+    assert!(matches!(intersects_cone(&square, 0.0, 0.0, -1.0), Err(AstroError::OutOfRange { .. })));
+    assert!(contains(&square, 360.0, 0.0).is_err());
+}
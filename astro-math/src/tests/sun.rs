@@ -66,4 +66,198 @@ fn test_sun_position_continuity() {
     let daily_motion = (lon2 - lon1).abs();
     assert!(daily_motion > 0.9 && daily_motion < 1.1, 
         "Sun should move ~1° per day, got {}°", daily_motion);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_solar_noon_equation_of_time() {
+    use crate::Location;
+    use chrono::Timelike;
+
+    let greenwich = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+
+    // Equation of time is ~ +16.4 min in early November, ~ -14.2 min mid-February
+    let nov = solar_noon(Utc.with_ymd_and_hms(2024, 11, 3, 0, 0, 0).unwrap(), &greenwich);
+    let nov_min = nov.hour() as f64 * 60.0 + nov.minute() as f64 + nov.second() as f64 / 60.0;
+    assert!((nov_min - (720.0 - 16.4)).abs() < 0.5, "November solar noon at {}", nov);
+
+    let feb = solar_noon(Utc.with_ymd_and_hms(2024, 2, 11, 0, 0, 0).unwrap(), &greenwich);
+    let feb_min = feb.hour() as f64 * 60.0 + feb.minute() as f64 + feb.second() as f64 / 60.0;
+    assert!((feb_min - (720.0 + 14.2)).abs() < 0.5, "February solar noon at {}", feb);
+}
+
+#[test]
+fn test_solar_noon_is_transit() {
+    use crate::Location;
+
+    let loc = Location { latitude_deg: -33.9, longitude_deg: 151.2, altitude_m: 0.0 };
+    let date = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let noon = solar_noon(date, &loc);
+
+    // Same UTC calendar day is not guaranteed for eastern longitudes, but local date is
+    assert!((noon - date).num_hours().abs() < 24);
+
+    // Elevation peaks at solar noon
+    let (az, el) = solar_azimuth_elevation(noon, &loc).unwrap();
+    let (_, el_before) = solar_azimuth_elevation(noon - chrono::Duration::minutes(10), &loc).unwrap();
+    let (_, el_after) = solar_azimuth_elevation(noon + chrono::Duration::minutes(10), &loc).unwrap();
+    assert!(el > el_before && el > el_after);
+    // Southern hemisphere summer-ish: Sun to the north at noon
+    assert!(!(90.0..=270.0).contains(&az), "azimuth {}", az);
+}
+
+#[test]
+fn test_subsolar_point() {
+    // Equinox: subsolar latitude near zero
+    let dt = Utc.with_ymd_and_hms(2024, 3, 20, 3, 6, 0).unwrap();
+    let (lat, _) = subsolar_point(dt);
+    assert!(lat.abs() < 0.05, "Equinox subsolar latitude {}", lat);
+
+    // Longitude moves westward ~15° per hour
+    let t0 = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+    let (_, lon0) = subsolar_point(t0);
+    let (_, lon1) = subsolar_point(t0 + chrono::Duration::hours(1));
+    let delta = (lon0 - lon1 + 540.0) % 360.0 - 180.0;
+    assert!((delta - 15.0).abs() < 0.1, "Hourly longitude change {}", delta);
+    assert!((-180.0..180.0).contains(&lon0));
+}
+
+#[test]
+fn test_solar_elevation_matches_subsolar_point() {
+    use crate::Location;
+
+    // An observer at the subsolar point sees the Sun at the zenith
+    let dt = Utc.with_ymd_and_hms(2024, 9, 1, 15, 30, 0).unwrap();
+    let (lat, lon) = subsolar_point(dt);
+    let loc = Location { latitude_deg: lat, longitude_deg: lon, altitude_m: 0.0 };
+    let (_, el) = solar_azimuth_elevation(dt, &loc).unwrap();
+    assert!(el > 89.9, "Elevation at subsolar point {}", el);
+}
+
+#[test]
+fn test_sun_positions_match_scalar_functions() {
+    let start = Utc.with_ymd_and_hms(2024, 9, 20, 0, 0, 0).unwrap();
+    let step = chrono::Duration::hours(6);
+    let track = sun_positions(start, start + chrono::Duration::days(5), step).unwrap();
+    assert_eq!(track.len(), 21);
+    for (k, &(t, ra, dec, distance_au)) in track.iter().enumerate() {
+        assert_eq!(t, start + step * k as i32);
+        assert_eq!((ra, dec), sun_ra_dec(t));
+        assert!((distance_au - 1.004).abs() < 0.002, "{}", distance_au);
+    }
+    // Crossing the equator southwards at the September equinox
+    assert!(track[0].2 > 0.0 && track[20].2 < 0.0);
+}
+
+#[test]
+fn test_sun_positions_invalid_step() {
+    let start = Utc.with_ymd_and_hms(2024, 9, 20, 0, 0, 0).unwrap();
+    let day = chrono::Duration::days(1);
+    assert!(matches!(
+        sun_positions(start, start + day, chrono::Duration::zero()),
+        Err(crate::error::AstroError::OutOfRange { .. })
+    ));
+    assert!(sun_positions(start + day, start, day).unwrap().is_empty());
+}
+
+#[test]
+fn test_ecliptic_longitude_of_sun_equinoxes() {
+    // March and September equinoxes of 2024, to the minute
+    let march = Utc.with_ymd_and_hms(2024, 3, 20, 3, 6, 0).unwrap();
+    let september = Utc.with_ymd_and_hms(2024, 9, 22, 12, 44, 0).unwrap();
+    let march_lon = (ecliptic_longitude_of_sun(march) + 180.0).rem_euclid(360.0) - 180.0;
+    assert!(march_lon.abs() < 0.001, "{}", march_lon);
+    assert!((ecliptic_longitude_of_sun(september) - 180.0).abs() < 0.001);
+    // The J2000 ecliptic longitude lags the equinox of date by precession
+    let lag = ecliptic_longitude_of_sun(september) - sun_position(september).0;
+    assert!((lag - 0.34).abs() < 0.02, "{}", lag);
+}
+
+#[test]
+fn test_shadow() {
+    let loc = crate::Location { latitude_deg: 40.0, longitude_deg: -105.0, altitude_m: 1600.0 };
+    let start = Utc.with_ymd_and_hms(2024, 6, 21, 12, 0, 0).unwrap();
+    for minutes in (0..14 * 60).step_by(30) {
+        let t = start + chrono::Duration::minutes(minutes);
+        let (az, el) = solar_azimuth_elevation(t, &loc).unwrap();
+        match shadow(t, &loc, 2.0).unwrap() {
+            Some((length, shadow_az)) => {
+                // Opposite the Sun, and refraction only ever shortens the shadow
+                let away = (shadow_az - az).rem_euclid(360.0);
+                assert!((away - 180.0).abs() < 1e-9);
+                if el > 0.0 {
+                    assert!(length <= 2.0 / el.to_radians().tan());
+                }
+                if el > 30.0 {
+                    assert!((length * el.to_radians().tan() / 2.0 - 1.0).abs() < 0.002);
+                }
+            }
+            None => assert!(el < 0.0),
+        }
+    }
+
+    // Southern hemisphere summer noon: shadow points south
+    let sydney = crate::Location { latitude_deg: -33.87, longitude_deg: 151.21, altitude_m: 0.0 };
+    let noon = solar_noon(Utc.with_ymd_and_hms(2024, 12, 21, 0, 0, 0).unwrap(), &sydney);
+    let (length, azimuth) = shadow(noon, &sydney, 1.0).unwrap().unwrap();
+    assert!((azimuth - 180.0).abs() < 1.0, "{}", azimuth);
+    assert!((length - (33.87f64 - 23.44).to_radians().tan()).abs() < 0.01, "{}", length);
+}
+
+#[test]
+fn test_shadow_at_sunset() {
+    // Just after geometric sunset the refracted Sun still casts a very long shadow
+    let loc = crate::Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    let mut t = Utc.with_ymd_and_hms(2024, 3, 20, 17, 0, 0).unwrap();
+    while solar_azimuth_elevation(t, &loc).unwrap().1 > -0.2 {
+        t += chrono::Duration::seconds(10);
+    }
+    let (length, azimuth) = shadow(t, &loc, 1.0).unwrap().unwrap();
+    assert!(length > 50.0, "{}", length);
+    assert!((azimuth - 90.0).abs() < 1.0);
+    assert!(shadow(t + chrono::Duration::minutes(5), &loc, 1.0).unwrap().is_none());
+
+    assert!(matches!(
+        shadow(t, &loc, -1.0),
+        Err(crate::error::AstroError::OutOfRange { parameter: "object_height_m", .. })
+    ));
+    let bad = crate::Location { latitude_deg: 95.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(matches!(shadow(t, &bad, 1.0), Err(crate::error::AstroError::InvalidCoordinate { .. })));
+}
+
+#[test]
+fn test_solar_physical_ephemeris_meeus_example() {
+    // Meeus example 29.a: 1992 October 13.0 TD (ΔT ≈ 59 s)
+    let dt = Utc.with_ymd_and_hms(1992, 10, 12, 23, 59, 1).unwrap();
+    let eph = solar_physical_ephemeris(dt);
+    assert!((eph.p - 26.27).abs() < 0.01, "P = {}", eph.p);
+    assert!((eph.b0 - 5.99).abs() < 0.01, "B0 = {}", eph.b0);
+    assert!((eph.l0 - 238.63).abs() < 0.01, "L0 = {}", eph.l0);
+}
+
+#[test]
+fn test_solar_physical_ephemeris_annual_cycle() {
+    // B0 peaks near +7.25° in early September and -7.25° in early March
+    let sept = solar_physical_ephemeris(Utc.with_ymd_and_hms(2024, 9, 7, 0, 0, 0).unwrap());
+    let march = solar_physical_ephemeris(Utc.with_ymd_and_hms(2024, 3, 6, 0, 0, 0).unwrap());
+    assert!(sept.b0 > 7.2 && march.b0 < -7.2, "B0 {} / {}", sept.b0, march.b0);
+
+    // P passes through zero in early January and early July
+    let jan = solar_physical_ephemeris(Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap());
+    let jul = solar_physical_ephemeris(Utc.with_ymd_and_hms(2024, 7, 7, 0, 0, 0).unwrap());
+    assert!(jan.p.abs() < 1.0 && jul.p.abs() < 1.0, "P {} / {}", jan.p, jul.p);
+
+    // L0 decreases by about 13.2° a day
+    let a = solar_physical_ephemeris(Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap());
+    let b = solar_physical_ephemeris(Utc.with_ymd_and_hms(2024, 5, 2, 0, 0, 0).unwrap());
+    let daily = (a.l0 - b.l0).rem_euclid(360.0);
+    assert!((daily - 13.2).abs() < 0.2, "daily L0 change {}", daily);
+}
+
+#[test]
+fn test_subsolar_point_uses_apparent_declination() {
+    // Meeus example 25.b: 1992 October 13.0 TD, apparent δ = -7.783871°
+    // (ΔT ≈ 59 s). Without annual aberration the result is off by about 7″.
+    let dt = Utc.with_ymd_and_hms(1992, 10, 12, 23, 59, 1).unwrap();
+    let (lat, _) = subsolar_point(dt);
+    assert!((lat + 7.783871).abs() * 3600.0 < 1.0, "δ = {}", lat);
+}
@@ -125,3 +125,41 @@ fn test_julian_date_gregorian_transition() {
     assert!((jd_after - 2299162.0).abs() < EPSILON,
         "Oct 16, 1582 noon should be JD 2299162.0, got {}", jd_after);
 }
+
+#[test]
+fn test_julian_date_two_part() {
+    use crate::time::julian_date_two_part;
+
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 30, 0).unwrap();
+    let (jd1, jd2) = julian_date_two_part(dt);
+    assert_eq!(jd1, 2460526.5);
+    assert!((jd1 + jd2 - julian_date(dt)).abs() < 1e-9);
+
+    // Sub-microsecond offsets survive in the fractional part
+    let later = dt + chrono::Duration::nanoseconds(500);
+    let (_, jd2_later) = julian_date_two_part(later);
+    let dt_ns = (jd2_later - jd2) * 86400.0 * 1e9;
+    assert!((dt_ns - 500.0).abs() < 1.0, "lost precision: {} ns", dt_ns);
+}
+
+#[test]
+fn test_epoch_conversions_match_erfa() {
+    use crate::time::{besselian_epoch, besselian_epoch_to_jd, julian_epoch, julian_epoch_to_jd};
+
+    // Test values from ERFA's t_erfa_c.c
+    assert!((besselian_epoch(2415019.8135 + 30103.18648) - 1982.4184241592786).abs() < 1e-9);
+    assert!((julian_epoch(2451545.0 - 7392.5) - 1979.760438056126).abs() < 1e-9);
+    assert!((besselian_epoch_to_jd(1957.3) - 2400000.5 - 35948.1915101513).abs() < 1e-6);
+    assert!((julian_epoch_to_jd(1996.8) - 2400000.5 - 50375.7).abs() < 1e-6);
+
+    // Round trips
+    for &epoch in &[1875.0, 1950.0, 2000.0, 2016.0, 2024.58] {
+        assert!((julian_epoch(julian_epoch_to_jd(epoch)) - epoch).abs() < 1e-10);
+        assert!((besselian_epoch(besselian_epoch_to_jd(epoch)) - epoch).abs() < 1e-10);
+    }
+
+    // B1950.0 falls 0.0765 days before J1950.0
+    let b1950 = besselian_epoch_to_jd(1950.0);
+    let j1950 = julian_epoch_to_jd(1950.0);
+    assert!((j1950 - b1950 - 0.0765).abs() < 1e-4);
+}
@@ -0,0 +1,159 @@
+use crate::error::AstroError;
+use crate::radial_velocity::barycentric_correction;
+use crate::time::julian_date;
+use crate::timing::*;
+use crate::Location;
+use chrono::{TimeZone, Timelike, Utc};
+
+fn site() -> Location {
+    Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 }
+}
+
+#[test]
+fn test_midpoint_uses_offset_for_date() {
+    // TAI−UTC was 32 s throughout 2000 and 37 s since 2017
+    for (year, offset) in [(2000, 64.184), (2024, 69.184)] {
+        let start = Utc.with_ymd_and_hms(year, 6, 1, 3, 0, 0).unwrap();
+        let mid = exposure_midpoint_jd(start, 300.0).unwrap();
+        assert_eq!(mid.utc, Utc.with_ymd_and_hms(year, 6, 1, 3, 2, 30).unwrap());
+        assert!((mid.jd_utc - julian_date(mid.utc)).abs() < 1e-9);
+        assert!(((mid.jd_tt - mid.jd_utc) * 86400.0 - offset).abs() < 1e-4, "{}", (mid.jd_tt - mid.jd_utc) * 86400.0);
+        assert!((mid.jd_tdb - mid.jd_tt).abs() * 86400.0 < 1.7e-3);
+    }
+}
+
+#[test]
+fn test_midpoint_across_leap_second() {
+    let start = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 30).unwrap();
+    let before = exposure_midpoint_jd(start, 0.0).unwrap();
+
+    // The leap second adds one SI second to the civil clock
+    let mid = exposure_midpoint_jd(start, 80.0).unwrap();
+    assert_eq!(mid.utc, Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 9).unwrap());
+    assert!(((mid.jd_tt - before.jd_tt) * 86400.0 - 40.0).abs() < 1e-4);
+
+    // A midpoint inside the leap second reads 23:59:60.5
+    let mid = exposure_midpoint_jd(start, 61.0).unwrap();
+    assert_eq!((mid.utc.hour(), mid.utc.minute(), mid.utc.second()), (23, 59, 59));
+    assert_eq!(mid.utc.nanosecond(), 1_500_000_000);
+    assert!(((mid.jd_tt - before.jd_tt) * 86400.0 - 30.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_bjd_rate_matches_barycentric_velocity() {
+    // The Rømer delay changes at (v·ŝ)/c, v being the observer's barycentric velocity
+    let (ra, dec) = (83.82, -5.39);
+    let start = Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap();
+    let a = exposure_midpoint_jd(start, 0.0).unwrap();
+    // Midpoints five minutes apart
+    let b = exposure_midpoint_jd(start, 600.0).unwrap();
+    let delay = |m: &ExposureMidpoint| (m.bjd_tdb(ra, dec, &site()).unwrap() - m.jd_tdb) * 86400.0;
+    let rate = (delay(&b) - delay(&a)) / 300.0;
+
+    let mid = exposure_midpoint_jd(start, 300.0).unwrap().utc;
+    let v = barycentric_correction(ra, dec, mid, &site()).unwrap();
+    assert!((rate - v / 299792.458).abs() < 1e-7, "{} vs {}", rate, v / 299792.458);
+}
+
+#[test]
+fn test_bjd_annual_amplitude() {
+    // A star on the ecliptic sees the full ±499 s; one at the ecliptic pole almost none
+    let (mut ecliptic, mut pole) = ((f64::MAX, f64::MIN), 0.0f64);
+    for month in 1..=12 {
+        let mid = exposure_midpoint_jd(Utc.with_ymd_and_hms(2024, month, 1, 0, 0, 0).unwrap(), 0.0).unwrap();
+        let d = (mid.bjd_tdb(90.0, 23.44, &site()).unwrap() - mid.jd_tdb) * 86400.0;
+        ecliptic = (ecliptic.0.min(d), ecliptic.1.max(d));
+        pole = pole.max(((mid.bjd_tdb(270.0, 66.56, &site()).unwrap() - mid.jd_tdb) * 86400.0).abs());
+    }
+    assert!(ecliptic.0 < -450.0 && ecliptic.1 > 450.0 && ecliptic.1 < 510.0, "{:?}", ecliptic);
+    assert!(pole < 10.0, "{}", pole);
+}
+
+#[test]
+fn test_alt_az_smear() {
+    let start = Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap();
+    // Path length is 15″/s · cos(dec) of sidereal rotation
+    let trail = alt_az_smear(83.82, 60.0, start, 120.0, &site()).unwrap();
+    assert!((trail.path_arcsec - 15.04 * 120.0 * 0.5).abs() < 5.0, "{}", trail.path_arcsec);
+
+    // Near the pole the trail curves, so the average sits inside the arc:
+    // r·θ²/6 ≈ 7.6″ for a 15° arc of radius 0.74°
+    let curved = alt_az_smear(37.95, 89.26, start, 3600.0, &site()).unwrap();
+    let straight = alt_az_smear(83.82, 0.0, start, 3600.0, &site()).unwrap();
+    assert!((curved.mean_offset_arcsec - 7.6).abs() < 0.5, "{}", curved.mean_offset_arcsec);
+    assert!(curved.mean_offset_arcsec > straight.mean_offset_arcsec);
+
+    // A zero-length exposure has no smear
+    let instant = alt_az_smear(83.82, 0.0, start, 0.0, &site()).unwrap();
+    assert_eq!(instant.start, instant.end);
+    assert!(instant.path_arcsec < 1e-9 && instant.mean_offset_arcsec < 1e-6);
+}
+
+#[test]
+fn test_smear_wraps_longitude() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let trail = smear(start, 10.0, 11, |t| {
+        let s = (t - start).num_milliseconds() as f64 / 1000.0;
+        Ok(((359.5 + 0.1 * s).rem_euclid(360.0), 10.0))
+    })
+    .unwrap();
+    assert!((trail.mean.0 - 0.0).abs() < 1e-6 || (trail.mean.0 - 360.0).abs() < 1e-6, "{:?}", trail.mean);
+    assert!((trail.path_arcsec - 3600.0 * 10f64.to_radians().cos()).abs() < 0.1);
+}
+
+#[test]
+fn test_invalid_inputs() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    for duration in [-1.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(exposure_midpoint_jd(start, duration), Err(AstroError::OutOfRange { parameter: "duration", .. })));
+    }
+    assert!(matches!(smear(start, 10.0, 1, |_| Ok((0.0, 0.0))), Err(AstroError::OutOfRange { parameter: "samples", .. })));
+    let failing = smear(start, 10.0, 3, |_| Err(AstroError::ProjectionError { reason: "behind".into() }));
+    assert!(matches!(failing, Err(AstroError::ProjectionError { .. })));
+    assert!(matches!(alt_az_smear(400.0, 0.0, start, 10.0, &site()), Err(AstroError::InvalidCoordinate { .. })));
+
+    let mid = exposure_midpoint_jd(start, 10.0).unwrap();
+    assert!(matches!(mid.bjd_tdb(0.0, 91.0, &site()), Err(AstroError::InvalidCoordinate { .. })));
+    let nowhere = Location { latitude_deg: 0.0, longitude_deg: 200.0, altitude_m: 0.0 };
+    assert!(matches!(mid.bjd_tdb(0.0, 0.0, &nowhere), Err(AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_mean_parallactic_angle() {
+    let start = Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap();
+    let lst_deg = site().local_sidereal_time(start) * 15.0;
+
+    // A zero-length exposure gives the instantaneous angle
+    let ra = (lst_deg + 40.0).rem_euclid(360.0);
+    let instant = mean_parallactic_angle(ra, 5.0, start, 0.0, &site()).unwrap();
+    assert!((instant - crate::transforms::parallactic_angle(ra, 5.0, start, &site()).unwrap()).abs() < 1e-9);
+
+    // A rising target: the low-altitude start of the exposure carries more
+    // weight than in a plain time average
+    let ra = (lst_deg + 60.0).rem_euclid(360.0);
+    let weighted = mean_parallactic_angle(ra, -10.0, start, 7200.0, &site()).unwrap();
+    let mut plain = 0.0;
+    for minute in 0..=120 {
+        let t = start + chrono::Duration::minutes(minute);
+        plain += crate::transforms::parallactic_angle(ra, -10.0, t, &site()).unwrap() / 121.0;
+    }
+    let first = crate::transforms::parallactic_angle(ra, -10.0, start, &site()).unwrap();
+    assert!((weighted - plain).abs() > 0.1, "{weighted} vs {plain}");
+    assert!((weighted - first).abs() < (plain - first).abs());
+
+    // North of the zenith the angle crosses ±180° without the mean jumping
+    let ra = (lst_deg + 0.5).rem_euclid(360.0);
+    let north = mean_parallactic_angle(ra, 70.0, start, 240.0, &site()).unwrap();
+    assert!(north.abs() > 179.0, "{north}");
+
+    // Setting below the horizon part way through
+    let ra = (lst_deg - 85.0).rem_euclid(360.0);
+    assert!(matches!(
+        mean_parallactic_angle(ra, -30.0, start, 3600.0, &site()),
+        Err(AstroError::CalculationError { .. })
+    ));
+    assert!(matches!(
+        mean_parallactic_angle(0.0, 0.0, start, -1.0, &site()),
+        Err(AstroError::OutOfRange { parameter: "duration", .. })
+    ));
+}
@@ -1,5 +1,6 @@
 use crate::*;
-use crate::transforms::{ra_dec_to_alt_az_erfa, alt_az_to_ra_dec};
+use crate::time::julian_date_two_part;
+use crate::transforms::*;
 use chrono::{TimeZone, Utc};
 
 const EPSILON: f64 = 0.1; // ~6 arcminutes tolerance
@@ -834,3 +835,298 @@ fn test_alt_az_to_ra_dec_vs_known_stars() {
     }
 }
 
+
+#[test]
+fn test_two_part_transforms_match_datetime() {
+    use crate::transforms::{alt_az_to_ra_dec_two_part, ra_dec_to_alt_az_erfa_two_part};
+
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+    let (jd1, jd2) = julian_date_two_part(dt);
+
+    let (alt, az) = ra_dec_to_alt_az(279.23473479, 38.78368896, dt, &loc).unwrap();
+    let (alt2, az2) = ra_dec_to_alt_az_two_part(279.23473479, 38.78368896, jd1, jd2, &loc).unwrap();
+    assert!((alt - alt2).abs() < 1e-9 && (az - az2).abs() < 1e-9);
+
+    let (ra, dec) = alt_az_to_ra_dec_two_part(alt, az, jd1, jd2, &loc).unwrap();
+    assert!((ra - 279.23473479).abs() < 1e-6 && (dec - 38.78368896).abs() < 1e-6);
+
+    let e1 = ra_dec_to_alt_az_erfa(279.23473479, 38.78368896, dt, &loc, None, None, None).unwrap();
+    let e2 = ra_dec_to_alt_az_erfa_two_part(279.23473479, 38.78368896, jd1, jd2, &loc, None, None, None)
+        .unwrap();
+    assert!((e1.0 - e2.0).abs() < 1e-9 && (e1.1 - e2.1).abs() < 1e-9);
+}
+
+#[test]
+fn test_visible_mask_matches_full_transform() {
+    let observer = Location { latitude_deg: -30.24, longitude_deg: -70.74, altitude_m: 2700.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 6, 1, 3, 0, 0).unwrap();
+    let coords: Vec<(f64, f64)> = (0..2000)
+        .map(|k| ((k as f64 * 7.31) % 360.0, (k as f64 * 3.17) % 180.0 - 90.0))
+        .collect();
+    for min_alt in [-10.0, 0.0, 30.0] {
+        let mask = visible_mask(&coords, dt, &observer, min_alt).unwrap();
+        assert_eq!(mask.len(), coords.len());
+        for (&(ra, dec), &visible) in coords.iter().zip(&mask) {
+            let (alt, _) = ra_dec_to_alt_az(ra, dec, dt, &observer).unwrap();
+            if (alt - min_alt).abs() > 1e-9 {
+                assert_eq!(visible, alt >= min_alt, "ra {} dec {} alt {}", ra, dec, alt);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_visible_mask_limits_and_errors() {
+    let observer = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let coords = [(10.0, 20.0), (200.0, -60.0)];
+    assert_eq!(visible_mask(&coords, dt, &observer, -90.0).unwrap(), vec![true, true]);
+    assert!(visible_mask(&[], dt, &observer, 0.0).unwrap().is_empty());
+
+    assert!(matches!(visible_mask(&coords, dt, &observer, 91.0), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(visible_mask(&coords, dt, &observer, f64::NAN), Err(AstroError::OutOfRange { .. })));
+    assert!(matches!(
+        visible_mask(&[(10.0, 20.0), (360.0, 0.0)], dt, &observer, 0.0),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+}
+
+#[test]
+fn test_alt_az_for_sites_matches_per_site_erfa() {
+    let dt = Utc.with_ymd_and_hms(2024, 3, 10, 4, 0, 0).unwrap();
+    let sites: Vec<Location> = (0..300)
+        .map(|k| Location {
+            latitude_deg: (k as f64 * 0.59) % 178.0 - 89.0,
+            longitude_deg: (k as f64 * 7.3) % 360.0 - 180.0,
+            altitude_m: (k % 7) as f64 * 600.0,
+        })
+        .collect();
+    for (ra, dec) in [(84.0533, 21.1425), (37.95456067, 89.26410897), (0.0, -60.0)] {
+        let batch = alt_az_for_sites(ra, dec, dt, &sites).unwrap();
+        assert_eq!(batch.len(), sites.len());
+        for (site, &(alt, az)) in sites.iter().zip(&batch) {
+            let (alt0, az0) = ra_dec_to_alt_az_erfa(ra, dec, dt, site, None, None, None).unwrap();
+            // On-sky difference, in milliarcseconds
+            let daz = ((az - az0 + 180.0).rem_euclid(360.0) - 180.0) * alt0.to_radians().cos();
+            let diff = (alt - alt0).hypot(daz) * 3.6e6;
+            assert!(diff < 1.0, "site {:?}: {} mas", site, diff);
+        }
+    }
+
+    assert!(alt_az_for_sites(10.0, 20.0, dt, &[]).unwrap().is_empty());
+    assert!(matches!(alt_az_for_sites(10.0, 91.0, dt, &sites), Err(AstroError::InvalidCoordinate { .. })));
+}
+
+#[test]
+fn test_ra_dec_to_alt_az_j2000_tracks_full_path() {
+    let observer = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+    let stars = [(279.23473479, 38.78368896), (88.79293899, 7.40706399), (37.95456067, 89.26410897)];
+    for year in [2000, 2024, 2050] {
+        let dt = Utc.with_ymd_and_hms(year, 8, 4, 6, 0, 0).unwrap();
+        for (ra, dec) in stars {
+            let (alt, az) = ra_dec_to_alt_az_j2000(ra, dec, dt, &observer).unwrap();
+            let (alt_full, az_full) = ra_dec_to_alt_az_erfa(ra, dec, dt, &observer, None, None, None).unwrap();
+            // Only annual aberration (≤ 20.5″) separates the two
+            let cos_alt = alt.to_radians().cos();
+            let az_diff = ((az - az_full + 180.0).rem_euclid(360.0) - 180.0) * cos_alt;
+            assert!((alt - alt_full).abs() * 3600.0 < 25.0, "{} {} {}", year, ra, alt - alt_full);
+            assert!(az_diff.abs() * 3600.0 < 25.0, "{} {} {}", year, ra, az_diff);
+        }
+    }
+}
+
+#[test]
+fn test_ra_dec_to_alt_az_j2000_vs_unprecessed() {
+    let observer = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+    let dt = Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap();
+    let (alt, _) = ra_dec_to_alt_az_j2000(0.0, 0.0, dt, &observer).unwrap();
+    let (alt_naive, _) = ra_dec_to_alt_az(0.0, 0.0, dt, &observer).unwrap();
+    // Fifty years of precession: about 0.7°
+    assert!((alt - alt_naive).abs() > 0.3);
+
+    let (ra_mean, dec_mean) = precess_from_j2000(0.0, 0.0, dt).unwrap();
+    let (ra_now, dec_now) = crate::nutation::apply_nutation(ra_mean, dec_mean, dt).unwrap();
+    assert_eq!(
+        ra_dec_to_alt_az_of_date(ra_now, dec_now, dt, &observer).unwrap(),
+        ra_dec_to_alt_az_j2000(0.0, 0.0, dt, &observer).unwrap()
+    );
+    assert!(matches!(ra_dec_to_alt_az_j2000(360.0, 0.0, dt, &observer), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(ra_dec_to_alt_az_of_date(0.0, 91.0, dt, &observer), Err(AstroError::InvalidCoordinate { .. })));
+}
+
+#[test]
+fn test_radian_variants_match_degrees() {
+    let observer = Location { latitude_deg: -33.93, longitude_deg: 18.48, altitude_m: 10.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 11, 5, 21, 30, 0).unwrap();
+    for ra in (0..24).map(|h| h as f64 * 15.0 + 3.7) {
+        for dec in [-90.0, -60.0, -12.5, 0.0, 33.3, 89.0] {
+            let (alt, az) = ra_dec_to_alt_az(ra, dec, dt, &observer).unwrap();
+            let (alt_rad, az_rad) = ra_dec_to_alt_az_rad(ra.to_radians(), dec.to_radians(), dt, &observer).unwrap();
+            assert!((0.0..std::f64::consts::TAU).contains(&az_rad));
+            assert!((alt_rad.to_degrees() - alt).abs() < 1e-9, "{} {}", ra, dec);
+            let daz = (az_rad.to_degrees() - az + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(daz.abs() < 1e-9, "{} {} {} {}", ra, dec, az, az_rad.to_degrees());
+
+            // Round trip entirely in radians
+            if alt.abs() < 89.0 {
+                let (ra_back, dec_back) = alt_az_to_ra_dec_rad(alt_rad, az_rad, dt, &observer).unwrap();
+                let dra = (ra_back.to_degrees() - ra + 180.0).rem_euclid(360.0) - 180.0;
+                assert!((dec_back.to_degrees() - dec).abs() < 1e-8, "{} {}", ra, dec);
+                assert!(dra.abs() * dec.to_radians().cos() < 1e-8, "{} {}", ra, dec);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_radian_variants_validate_radian_ranges() {
+    let observer = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 0.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    // Degree-sized values are rejected rather than silently accepted
+    assert!(matches!(
+        ra_dec_to_alt_az_rad(180.0, 0.0, dt, &observer),
+        Err(AstroError::InvalidCoordinate { coord_type: "RA", .. })
+    ));
+    assert!(matches!(
+        ra_dec_to_alt_az_rad(1.0, 2.0, dt, &observer),
+        Err(AstroError::InvalidCoordinate { coord_type: "Declination", .. })
+    ));
+    assert!(matches!(
+        ra_dec_to_alt_az_rad(f64::NAN, 0.0, dt, &observer),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(
+        alt_az_to_ra_dec_rad(-1.6, 1.0, dt, &observer),
+        Err(AstroError::InvalidCoordinate { coord_type: "Altitude", .. })
+    ));
+    assert!(matches!(
+        alt_az_to_ra_dec_rad(0.5, std::f64::consts::TAU, dt, &observer),
+        Err(AstroError::InvalidCoordinate { coord_type: "Azimuth", .. })
+    ));
+}
+
+#[test]
+fn test_erfa_with_eop() {
+    use crate::eop::EarthOrientation;
+    let dt = Utc.with_ymd_and_hms(2024, 1, 1, 6, 0, 0).unwrap();
+    let loc = Location { latitude_deg: 19.8, longitude_deg: -155.5, altitude_m: 4200.0 };
+    let (ra, dec) = (279.2347, 38.7837);
+    let plain = ra_dec_to_alt_az_erfa(ra, dec, dt, &loc, None, None, None).unwrap();
+    let with = |eop: EarthOrientation| ra_dec_to_alt_az_erfa_with_eop(ra, dec, dt, &loc, None, None, None, &eop).unwrap();
+    let arcsec = |a: (f64, f64), b: (f64, f64)| {
+        ((a.0 - b.0).powi(2) + ((a.1 - b.1) * a.0.to_radians().cos()).powi(2)).sqrt() * 3600.0
+    };
+
+    assert_eq!(with(EarthOrientation::default()), plain);
+
+    // DUT1 is a shift of the Earth's rotation: the same as observing later
+    let later = ra_dec_to_alt_az_erfa(ra, dec, dt + chrono::Duration::milliseconds(500), &loc, None, None, None).unwrap();
+    assert!(arcsec(with(EarthOrientation { dut1: 0.5, ..Default::default() }), later) < 0.01);
+
+    // Polar motion and pole offsets move the place by no more than their size
+    let polar = arcsec(with(EarthOrientation { xp_arcsec: 0.2, yp_arcsec: 0.3, ..Default::default() }), plain);
+    assert!(polar > 0.05 && polar < 0.37, "{}", polar);
+    let offsets = arcsec(with(EarthOrientation { dx_mas: Some(0.3), dy_mas: Some(-0.2), ..Default::default() }), plain);
+    assert!(offsets > 1e-5 && offsets < 0.00037, "{}", offsets);
+
+    let bad = EarthOrientation { dut1: f64::NAN, ..Default::default() };
+    let result = ra_dec_to_alt_az_erfa_with_eop(ra, dec, dt, &loc, None, None, None, &bad);
+    assert!(matches!(result, Err(error::AstroError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_parallactic_angle() {
+    let site = Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap();
+    let lst_deg = site.local_sidereal_time(dt) * 15.0;
+    let ra_at = |ha_deg: f64| (lst_deg - ha_deg).rem_euclid(360.0);
+
+    // On the meridian: 0 south of the zenith, 180 north of it
+    assert!(parallactic_angle(ra_at(0.0), 10.0, dt, &site).unwrap().abs() < 1e-6);
+    assert!((parallactic_angle(ra_at(0.0), 60.0, dt, &site).unwrap().abs() - 180.0).abs() < 1e-6);
+
+    // sin q = sin H cos φ / cos h, with the sign of the hour angle
+    for &(ha, dec) in &[(-60.0f64, -20.0), (-10.0, 50.0), (30.0, 0.0), (75.0, 70.0), (150.0, 85.0)] {
+        let q = parallactic_angle(ra_at(ha), dec, dt, &site).unwrap();
+        let (alt, _) = ra_dec_to_alt_az(ra_at(ha), dec, dt, &site).unwrap();
+        let expected = ha.to_radians().sin() * site.latitude_deg.to_radians().cos() / alt.to_radians().cos();
+        assert!((q.to_radians().sin() - expected).abs() < 1e-6, "HA {ha}, Dec {dec}: {q}");
+        assert_eq!(q > 0.0, ha > 0.0);
+    }
+
+    assert!(matches!(
+        parallactic_angle(10.0, 95.0, dt, &site),
+        Err(crate::error::AstroError::InvalidCoordinate { .. })
+    ));
+}
+
+#[test]
+fn test_alt_az_rates_match_finite_differences() {
+    let site = Location { latitude_deg: -29.26, longitude_deg: -70.73, altitude_m: 2400.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 5, 20, 3, 0, 0).unwrap();
+    let lst_deg = site.local_sidereal_time(dt) * 15.0;
+    let ra_at = |ha_deg: f64| (lst_deg - ha_deg).rem_euclid(360.0);
+    let step = chrono::Duration::milliseconds(500);
+
+    for &(ha, dec) in &[(-75.0f64, -60.0), (-20.0, 10.0), (0.0, -40.0), (5.0, -25.0), (45.0, 30.0), (160.0, -80.0)] {
+        let ra = ra_at(ha);
+        let rates = alt_az_rates(ra, dec, dt, &site).unwrap();
+        let (alt, az) = ra_dec_to_alt_az(ra, dec, dt, &site).unwrap();
+        assert!((rates.alt - alt).abs() < 1e-9 && (rates.az - az).abs() < 1e-9);
+        assert_eq!(rates.parallactic_angle, parallactic_angle(ra, dec, dt, &site).unwrap());
+
+        // Symmetric differences over one second
+        let (alt0, az0) = ra_dec_to_alt_az(ra, dec, dt - step, &site).unwrap();
+        let (alt1, az1) = ra_dec_to_alt_az(ra, dec, dt + step, &site).unwrap();
+        let q0 = parallactic_angle(ra, dec, dt - step, &site).unwrap();
+        let q1 = parallactic_angle(ra, dec, dt + step, &site).unwrap();
+        let wrap = |d: f64| (d + 180.0).rem_euclid(360.0) - 180.0;
+        assert!((alt1 - alt0 - rates.alt_rate).abs() < 1e-8, "HA {ha}, Dec {dec}");
+        assert!((wrap(az1 - az0) - rates.az_rate).abs() < 1e-7, "HA {ha}, Dec {dec}");
+        assert!((wrap(q1 - q0) - rates.parallactic_angle_rate).abs() < 1e-7, "HA {ha}, Dec {dec}");
+    }
+
+    // Rising in the east, setting in the west; 15"/s at most
+    assert!(alt_az_rates(ra_at(-60.0), 0.0, dt, &site).unwrap().alt_rate > 0.0);
+    assert!(alt_az_rates(ra_at(60.0), 0.0, dt, &site).unwrap().alt_rate < 0.0);
+    assert!(alt_az_rates(ra_at(-90.0), 0.0, dt, &site).unwrap().alt_rate * 3600.0 < 15.05);
+
+    assert!(matches!(
+        alt_az_rates(ra_at(0.0), 95.0, dt, &site),
+        Err(crate::error::AstroError::InvalidCoordinate { .. })
+    ));
+}
+
+#[test]
+fn test_alt_az_to_ra_dec_erfa_inverts_erfa_forward() {
+    let site = Location { latitude_deg: -24.6275, longitude_deg: -70.4044, altitude_m: 2635.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 3, 15, 2, 0, 0).unwrap();
+    let weather = (Some(744.0), Some(12.0), Some(0.1));
+
+    let mut worst: f64 = 0.0;
+    for i in 0..72 {
+        let (ra, dec) = (i as f64 * 5.0, -85.0 + (i % 18) as f64 * 10.0);
+        let (alt, az) = ra_dec_to_alt_az_erfa(ra, dec, dt, &site, weather.0, weather.1, weather.2).unwrap();
+        if alt < 15.0 {
+            continue;
+        }
+        let (ra2, dec2) = alt_az_to_ra_dec_erfa(alt, az, dt, &site, weather.0, weather.1, weather.2).unwrap();
+        worst = worst.max(crate::angles::separation(ra, dec, ra2, dec2) * 3600.0);
+    }
+    assert!(worst > 0.0 && worst < 0.01, "worst round trip {}″", worst);
+
+    // Without the same weather the refraction is left in
+    let (alt, az) = ra_dec_to_alt_az_erfa(120.0, -40.0, dt, &site, weather.0, weather.1, weather.2).unwrap();
+    let (ra2, dec2) = alt_az_to_ra_dec_erfa(alt, az, dt, &site, None, None, None).unwrap();
+    assert!(crate::angles::separation(120.0, -40.0, ra2, dec2) * 3600.0 > 10.0);
+
+    assert!(matches!(
+        alt_az_to_ra_dec_erfa(91.0, 0.0, dt, &site, None, None, None),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+    assert!(matches!(
+        alt_az_to_ra_dec_erfa(45.0, 360.0, dt, &site, None, None, None),
+        Err(AstroError::InvalidCoordinate { .. })
+    ));
+}
@@ -0,0 +1,89 @@
+use crate::error::AstroError;
+use crate::location::Location;
+use crate::units::angle::{degree, minute, radian};
+use crate::units::length::{foot, kilometer, meter};
+use crate::units::time::{hour, second};
+use crate::units::*;
+use chrono::{TimeZone, Utc};
+
+fn kitt_peak() -> Location {
+    Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 }
+}
+
+#[test]
+fn test_matches_f64_api() {
+    let site = kitt_peak();
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+
+    let (alt, az) = ra_dec_to_alt_az(
+        Angle::new::<radian>(279.23473479f64.to_radians()),
+        Angle::new::<degree>(38.78368896),
+        dt,
+        &site,
+    )
+    .unwrap();
+    let (alt_f64, az_f64) = crate::transforms::ra_dec_to_alt_az(279.23473479, 38.78368896, dt, &site).unwrap();
+    assert!((alt.get::<degree>() - alt_f64).abs() < 1e-9);
+    assert!((az.get::<degree>() - az_f64).abs() < 1e-9);
+
+    let (ra, dec) = alt_az_to_ra_dec(alt, az, dt, &site).unwrap();
+    assert!((ra.get::<degree>() - 279.23473479).abs() < 1e-6);
+    assert!((dec.get::<degree>() - 38.78368896).abs() < 1e-6);
+
+    let q = parallactic_angle(ra, dec, dt, &site).unwrap();
+    let q_f64 = crate::transforms::parallactic_angle(279.23473479, 38.78368896, dt, &site).unwrap();
+    assert!((q.get::<degree>() - q_f64).abs() < 1e-6);
+}
+
+#[test]
+fn test_hours_and_lengths_convert_at_the_boundary() {
+    // RA 6h is 90°, not 6°
+    assert!((hours(6.0).get::<degree>() - 90.0).abs() < 1e-12);
+    assert!((to_hours(Angle::new::<minute>(15.0)) - 1.0 / 60.0).abs() < 1e-12);
+
+    let site = location(
+        Angle::new::<degree>(31.9583),
+        Angle::new::<degree>(-111.6),
+        Length::new::<foot>(2120.0 / 0.3048),
+    )
+    .unwrap();
+    assert!((site.altitude_m - 2120.0).abs() < 1e-9);
+    assert!((altitude(&site).get::<meter>() - 2120.0).abs() < 1e-9);
+    assert!((latitude(&site).get::<degree>() - 31.9583).abs() < 1e-12);
+    assert!((longitude(&site).get::<degree>() + 111.6).abs() < 1e-12);
+
+    // The Moon's distance in kilometres matches the f64 API's AU, converted
+    // with the IAU value rather than uom's rounded astronomical unit
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let (ra, dec) = (hours(16.0), Angle::new::<degree>(-20.0));
+    let (ra, dec) = diurnal_parallax(ra, dec, Length::new::<kilometer>(384_400.0), dt, &site).unwrap();
+    let (ra_f64, dec_f64) =
+        crate::parallax::diurnal_parallax(240.0, -20.0, 384_400.0 / 149_597_870.7, dt, &site).unwrap();
+    assert!((ra.get::<degree>() - ra_f64).abs() < 1e-12);
+    assert!((dec.get::<degree>() - dec_f64).abs() < 1e-12);
+
+    let r = geocentric_distance(&site).get::<kilometer>();
+    assert!(r > 6371.0 && r < 6375.0);
+}
+
+#[test]
+fn test_mean_parallactic_angle_takes_any_time_unit() {
+    let site = kitt_peak();
+    let start = Utc.with_ymd_and_hms(2024, 8, 4, 5, 0, 0).unwrap();
+    let (ra, dec) = (Angle::new::<degree>(279.23473479), Angle::new::<degree>(38.78368896));
+    let in_hours = mean_parallactic_angle(ra, dec, start, Time::new::<hour>(0.25), &site).unwrap();
+    let in_seconds = mean_parallactic_angle(ra, dec, start, Time::new::<second>(900.0), &site).unwrap();
+    assert!((in_hours - in_seconds).abs().get::<degree>() < 1e-9);
+}
+
+#[test]
+fn test_errors_pass_through() {
+    match location(Angle::new::<radian>(2.0), Angle::new::<degree>(0.0), Length::new::<meter>(0.0)) {
+        Err(AstroError::InvalidCoordinate { coord_type, .. }) => assert_eq!(coord_type, "Latitude"),
+        other => panic!("expected InvalidCoordinate, got {other:?}"),
+    }
+
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let result = annual_parallax(hours(14.5), Angle::new::<degree>(-62.7), Angle::new::<degree>(0.0), dt);
+    assert!(matches!(result, Err(AstroError::OutOfRange { parameter: "parallax_mas", .. })));
+}
@@ -0,0 +1,65 @@
+use crate::precession::{precess_from_j2000, precess_to_j2000};
+use crate::vec3::*;
+use chrono::{TimeZone, Utc};
+
+#[test]
+fn test_round_trip() {
+    for &(ra, dec) in &[(0.0, 0.0), (45.0, 30.0), (180.0, -45.0), (359.999999, 89.9999), (271.5, -89.99999)] {
+        let v = radec_to_unit_vector(ra, dec);
+        assert!((norm(v) - 1.0).abs() < 1e-15);
+        let (r, d) = unit_vector_to_radec(v);
+        assert!((r - ra).abs() < 1e-9, "{} {}", r, ra);
+        assert!((d - dec).abs() < 1e-12, "{} {}", d, dec);
+    }
+}
+
+#[test]
+fn test_ra_range() {
+    // Directions a rounding error either side of the x axis
+    for y in [-1e-20, -0.0, 0.0, 1e-20] {
+        let (ra, _) = unit_vector_to_radec([1.0, y, 0.0]);
+        assert!((0.0..360.0).contains(&ra), "{}", ra);
+    }
+    assert_eq!(unit_vector_to_radec([-1.0, -0.0, 0.0]).0, 180.0);
+    // Poles and the zero vector
+    assert_eq!(unit_vector_to_radec([0.0, 0.0, 1.0]), (0.0, 90.0));
+    assert_eq!(unit_vector_to_radec([0.0, 0.0, -3.0]), (0.0, -90.0));
+    assert_eq!(unit_vector_to_radec([0.0; 3]), (0.0, 0.0));
+}
+
+#[test]
+fn test_products() {
+    let x = [1.0, 0.0, 0.0];
+    let y = [0.0, 1.0, 0.0];
+    assert_eq!(cross(x, y), [0.0, 0.0, 1.0]);
+    assert_eq!(cross(y, x), [0.0, 0.0, -1.0]);
+    assert_eq!(dot(x, y), 0.0);
+    assert_eq!(dot([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]), 32.0);
+    assert_eq!(norm([3.0, 4.0, 12.0]), 13.0);
+    assert_eq!(normalize([0.0, 0.0, 5.0]), [0.0, 0.0, 1.0]);
+    assert_eq!(normalize([0.0; 3]), [0.0; 3]);
+}
+
+#[test]
+fn test_angle_between() {
+    let a = radec_to_unit_vector(10.0, 20.0);
+    // 1 mas: acos of the dot product would lose most digits here
+    let b = radec_to_unit_vector(10.0, 20.0 + 1.0 / 3.6e6);
+    assert!((angle_between(a, b).to_degrees() * 3.6e6 - 1.0).abs() < 1e-6);
+    let antipode = radec_to_unit_vector(190.0, -20.0);
+    assert!((angle_between(a, antipode) - std::f64::consts::PI).abs() < 1e-12);
+    // Length does not matter
+    assert!((angle_between(a.map(|c| c * 7.0), b) - angle_between(a, b)).abs() < 1e-15);
+}
+
+#[test]
+fn test_precession_ra_stays_in_range() {
+    // Positions either side of RA 0 precess across it
+    let dt = Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap();
+    for ra in [359.9999, 0.0, 1e-12] {
+        let (forward, _) = precess_from_j2000(ra, 0.0, dt).unwrap();
+        let (backward, _) = precess_to_j2000(ra, 0.0, dt).unwrap();
+        assert!((0.0..360.0).contains(&forward), "{}", forward);
+        assert!((0.0..360.0).contains(&backward), "{}", backward);
+    }
+}
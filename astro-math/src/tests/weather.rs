@@ -0,0 +1,133 @@
+use crate::error::AstroError;
+use crate::refraction::AtmosphericConditions;
+use crate::transforms::ra_dec_to_alt_az_erfa;
+use crate::weather::*;
+use crate::Location;
+use chrono::{Duration, TimeZone, Utc};
+use std::path::PathBuf;
+
+const REPORT: &str = r#"{
+  "station": "KPNO-WX1",
+  "time": "2024-08-04T06:00:00Z",
+  "pressure_hpa": 781.4,
+  "temperature_c": 12.5,
+  "relative_humidity": 0.31,
+  "wind": {"speed_kmh": 14.2, "direction": "SW", "gusts": [18.0, 21.5]},
+  "raining": false,
+  "note": "escaped \"quote\" and °C"
+}"#;
+
+/// A file in the system temp directory, removed when dropped
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, contents: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("astro-math-{}-{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        TempFile(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn test_reading_from_station_report() {
+    let reading = WeatherReading::from_json_str(REPORT).unwrap();
+    assert_eq!(
+        reading.conditions,
+        AtmosphericConditions { pressure_hpa: 781.4, temperature_c: 12.5, relative_humidity: 0.31 }
+    );
+    assert_eq!(reading.time, Some(Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap()));
+
+    let untimed = WeatherReading::from_json_str(
+        r#"{"pressure_hpa":1013.25,"temperature_c":-5,"relative_humidity":1e-1,"time":null}"#,
+    )
+    .unwrap();
+    assert_eq!(untimed.time, None);
+    assert_eq!(untimed.conditions.temperature_c, -5.0);
+}
+
+#[test]
+fn test_reading_rejects_bad_json() {
+    let bad = [
+        "",
+        "[]",
+        r#"{"pressure_hpa": 781.4, "temperature_c": 12.5}"#,
+        r#"{"pressure_hpa": "781", "temperature_c": 12.5, "relative_humidity": 0.3}"#,
+        r#"{"pressure_hpa": NaN, "temperature_c": 12.5, "relative_humidity": 0.3}"#,
+        r#"{"pressure_hpa": 781.4 "temperature_c": 12.5, "relative_humidity": 0.3}"#,
+        r#"{"pressure_hpa": 781.4, "temperature_c": 12.5, "relative_humidity": 0.3"#,
+        r#"{"pressure_hpa": 781.4, "temperature_c": 12.5, "relative_humidity": 0.3} x"#,
+        r#"{"pressure_hpa": 781.4, "temperature_c": 12.5, "relative_humidity": 0.3, "time": "yesterday"}"#,
+        r#"{"pressure_hpa": 781.4, "temperature_c": 12.5, "relative_humidity": 0.3, "wind": {"a": [1, 2}"#,
+    ];
+    for text in bad {
+        assert!(matches!(WeatherReading::from_json_str(text), Err(AstroError::InvalidFormat { .. })), "{}", text);
+    }
+    // Humidity is a fraction, not a percentage
+    assert!(matches!(
+        WeatherReading::from_json_str(r#"{"pressure_hpa": 781.4, "temperature_c": 12.5, "relative_humidity": 31}"#),
+        Err(AstroError::OutOfRange { .. })
+    ));
+}
+
+#[test]
+fn test_json_file_provider() {
+    let file = TempFile::new("weather.json", REPORT);
+    let provider = JsonFileConditions::new(&file.0);
+    assert_eq!(provider.path(), file.0.as_path());
+    assert_eq!(provider.current().unwrap().pressure_hpa, 781.4);
+
+    // The file is read again on every call
+    std::fs::write(&file.0, REPORT.replace("781.4", "779.9")).unwrap();
+    assert_eq!(provider.current().unwrap().pressure_hpa, 779.9);
+}
+
+#[test]
+fn test_json_file_provider_max_age() {
+    let file = TempFile::new("weather-age.json", REPORT);
+    let reading_time = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let provider = JsonFileConditions::new(&file.0).with_max_age(Duration::minutes(10)).unwrap();
+    assert!(provider.reading_at(reading_time + Duration::minutes(9)).is_ok());
+    assert!(matches!(
+        provider.reading_at(reading_time + Duration::minutes(11)),
+        Err(AstroError::OutOfRange { .. })
+    ));
+
+    // Without a time the age is unknown
+    let untimed = TempFile::new("weather-untimed.json", &REPORT.replace("\"time\"", "\"observed\""));
+    let provider = JsonFileConditions::new(&untimed.0).with_max_age(Duration::hours(1)).unwrap();
+    assert!(matches!(provider.reading_at(reading_time), Err(AstroError::OutOfRange { .. })));
+
+    assert!(matches!(
+        JsonFileConditions::new("x.json").with_max_age(Duration::seconds(-1)),
+        Err(AstroError::OutOfRange { .. })
+    ));
+}
+
+#[test]
+fn test_missing_file() {
+    let provider = JsonFileConditions::new(std::env::temp_dir().join("astro-math-no-such-weather.json"));
+    assert!(matches!(provider.current(), Err(AstroError::CalculationError { .. })));
+}
+
+#[test]
+fn test_alt_az_with_conditions() {
+    let site = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+    let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+    let conditions = AtmosphericConditions { pressure_hpa: 781.4, temperature_c: 12.5, relative_humidity: 0.31 };
+    let expected = ra_dec_to_alt_az_erfa(279.23473479, 38.78368896, dt, &site, Some(781.4), Some(12.5), Some(0.31))
+        .unwrap();
+    assert_eq!(alt_az_with_conditions(279.23473479, 38.78368896, dt, &site, &conditions).unwrap(), expected);
+
+    let file = TempFile::new("weather-transform.json", REPORT);
+    let from_file = alt_az_with_conditions(279.23473479, 38.78368896, dt, &site, &JsonFileConditions::new(&file.0));
+    assert_eq!(from_file.unwrap(), expected);
+
+    let impossible = AtmosphericConditions { relative_humidity: 2.0, ..conditions };
+    assert!(matches!(impossible.current(), Err(AstroError::OutOfRange { .. })));
+}
@@ -0,0 +1,79 @@
+use crate::error::AstroError;
+use crate::transforms::{alt_az_to_ra_dec, ra_dec_to_alt_az};
+use crate::zenith::*;
+use crate::Location;
+use chrono::{DateTime, TimeZone, Utc};
+
+fn site() -> Location {
+    Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 }
+}
+
+fn epoch() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap()
+}
+
+#[test]
+fn test_zenith_and_nadir() {
+    for site in [site(), Location { latitude_deg: -89.0, longitude_deg: 179.0, altitude_m: 0.0 }] {
+        let zenith = zenith_ra_dec(epoch(), &site).unwrap();
+        let (alt, _) = ra_dec_to_alt_az(zenith.0, zenith.1, epoch(), &site).unwrap();
+        assert!((alt - 90.0).abs() < 1e-4, "{}", alt);
+        let expected = alt_az_to_ra_dec(90.0, 0.0, epoch(), &site).unwrap();
+        assert!((expected.1 - zenith.1).abs() < 1e-9);
+
+        let nadir = nadir_ra_dec(epoch(), &site).unwrap();
+        let (alt, _) = ra_dec_to_alt_az(nadir.0, nadir.1, epoch(), &site).unwrap();
+        assert!((alt + 90.0).abs() < 1e-4, "{}", alt);
+    }
+}
+
+#[test]
+fn test_region_overhead_is_above_altitude_limit() {
+    let overhead = sky_region_overhead(epoch(), &site(), 20.0).unwrap();
+    let mut targets = Vec::new();
+    for i in 0..72 {
+        for j in -17..=17 {
+            targets.push((i as f64 * 5.0, j as f64 * 5.0));
+        }
+    }
+    let inside = overhead.filter(&targets).unwrap();
+    assert!(!inside.is_empty());
+    for (k, &(ra, dec)) in targets.iter().enumerate() {
+        let (alt, _) = ra_dec_to_alt_az(ra, dec, epoch(), &site()).unwrap();
+        assert_eq!(inside.contains(&k), alt >= 70.0, "{} {} alt {}", ra, dec, alt);
+    }
+    // Nearest first
+    let distances: Vec<f64> = inside.iter().map(|&k| overhead.separation(targets[k].0, targets[k].1).unwrap()).collect();
+    assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_cone_geometry() {
+    let cone = SkyCone { ra: 359.0, dec: 0.0, radius_deg: 2.0 };
+    // Across RA 0h
+    assert!(cone.contains(0.5, 0.0).unwrap());
+    assert!(!cone.contains(1.5, 0.0).unwrap());
+    assert!((cone.separation(179.0, 0.0).unwrap() - 180.0).abs() < 1e-9);
+
+    let field = [(0.5, -0.5), (1.5, -0.5), (1.5, 0.5), (0.5, 0.5)];
+    assert!(cone.intersects_polygon(&field).unwrap());
+    assert!(!SkyCone { radius_deg: 1.0, ..cone }.intersects_polygon(&field).unwrap());
+
+    // Around the pole the cone is a polar cap
+    let cap = SkyCone { ra: 0.0, dec: 90.0, radius_deg: 10.0 };
+    assert!(cap.contains(200.0, 80.5).unwrap() && !cap.contains(200.0, 79.5).unwrap());
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert!(matches!(
+        sky_region_overhead(epoch(), &site(), -1.0),
+        Err(AstroError::OutOfRange { parameter: "radius_deg", .. })
+    ));
+    assert!(sky_region_overhead(epoch(), &site(), f64::NAN).is_err());
+    let nowhere = Location { latitude_deg: 91.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    assert!(matches!(zenith_ra_dec(epoch(), &nowhere), Err(AstroError::OutOfRange { .. })));
+    assert!(nadir_ra_dec(epoch(), &nowhere).is_err());
+    let cone = SkyCone { ra: 0.0, dec: 0.0, radius_deg: 5.0 };
+    assert!(matches!(cone.filter(&[(0.0, 0.0), (0.0, 95.0)]), Err(AstroError::InvalidCoordinate { .. })));
+}
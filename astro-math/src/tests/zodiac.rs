@@ -0,0 +1,41 @@
+use crate::error::AstroError;
+use crate::zodiac::*;
+
+#[test]
+fn test_signs_in_order() {
+    for (i, sign) in ZodiacSign::ALL.iter().enumerate() {
+        assert_eq!(sign.start_longitude(), i as f64 * 30.0);
+        assert_eq!(ZodiacSign::from_longitude(sign.start_longitude() + 15.0).unwrap(), *sign);
+        assert_eq!(sign.to_string(), sign.name());
+    }
+    assert_eq!(ZodiacSign::Aries.symbol(), '♈');
+    assert_eq!(ZodiacSign::Pisces.symbol(), '♓');
+}
+
+#[test]
+fn test_sign_position_wraps() {
+    let (sign, degrees) = sign_position(390.5).unwrap();
+    assert_eq!(sign, ZodiacSign::Taurus);
+    assert!((degrees - 0.5).abs() < 1e-12);
+    // Boundaries belong to the sign that starts there
+    assert_eq!(sign_position(90.0).unwrap(), (ZodiacSign::Cancer, 0.0));
+    // A negative longitude too small to represent below 360 wraps to 0
+    assert_eq!(sign_position(-1e-18).unwrap(), (ZodiacSign::Aries, 0.0));
+}
+
+#[test]
+fn test_ecliptic_segment() {
+    assert_eq!(ecliptic_segment(0.0, 1).unwrap(), (0, 0.0));
+    assert_eq!(ecliptic_segment(359.0, 24).unwrap().0, 23);
+    let (index, degrees) = ecliptic_segment(-7.5, 24).unwrap();
+    assert_eq!(index, 23);
+    assert!((degrees - 7.5).abs() < 1e-12);
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert!(matches!(ecliptic_segment(f64::NAN, 12), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(ecliptic_segment(f64::INFINITY, 12), Err(AstroError::InvalidCoordinate { .. })));
+    assert!(matches!(ecliptic_segment(10.0, 0), Err(AstroError::OutOfRange { .. })));
+    assert!(ZodiacSign::from_longitude(f64::NAN).is_err());
+}
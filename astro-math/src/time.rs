@@ -19,6 +19,24 @@
 //! - **JD 2451545.0**: J2000.0 epoch (January 1, 2000 at noon TT)
 //! - **Modified Julian Date (MJD)**: JD - 2400000.5 (starts at midnight)
 //!
+//! # Epochs in Years
+//!
+//! Catalog epochs and equinoxes are given as fractional years. Modern ones
+//! are Julian epochs (`J2024.5`), counted in Julian years of exactly 365.25
+//! days from J2000.0; older catalogs such as the FK4 use Besselian epochs
+//! (`B1950.0`), counted in tropical years from B1900.0. Use
+//! [`julian_epoch`] and [`besselian_epoch`] and their inverses rather than
+//! dividing by a year length by hand, so the two are never mixed.
+//!
+//! # Two-Part Julian Dates
+//!
+//! A single `f64` Julian Date near the present resolves only ~20 µs, because
+//! most of its 53 bits are spent on the day count. For timing-critical work
+//! (occultations, pulsar timing) use [`julian_date_two_part`], which returns
+//! the day boundary and the fraction of day separately, following the ERFA
+//! convention. The `*_two_part` variants of the sidereal time, precession,
+//! nutation and transform functions accept these pairs directly.
+//!
 //! # Examples
 //!
 //! ```
@@ -35,7 +53,7 @@
 //! println!("Days since J2000.0: {:.5}", days);
 //! ```
 
-use chrono::{DateTime, Datelike, Timelike, Utc};
+use chrono::{DateTime, Datelike, TimeZone, Timelike, Utc};
 
 /// Julian Date (JD) of the J2000.0 epoch: 2000 January 1.5 TT.
 ///
@@ -43,6 +61,16 @@ use chrono::{DateTime, Datelike, Timelike, Utc};
 /// Most star catalogs, ephemerides, and orbital elements are referenced to this epoch.
 pub const JD2000: f64 = 2451545.0;
 
+/// Length of the Julian year in days, the unit of Julian epochs.
+pub const JULIAN_YEAR_DAYS: f64 = 365.25;
+
+/// Length of the tropical year at B1900.0 in days, the unit of Besselian
+/// epochs.
+pub const TROPICAL_YEAR_DAYS: f64 = 365.242198781;
+
+/// Julian Date of the B1900.0 epoch.
+const JD_B1900: f64 = 2415020.31352;
+
 /// Converts a UTC datetime to a Julian Date (JD).
 ///
 /// Julian Dates are a continuous count of days since noon UTC on **January 1, 4713 BCE**
@@ -137,6 +165,126 @@ pub fn j2000_days(datetime: DateTime<Utc>) -> f64 {
     julian_date(datetime) - JD2000
 }
 
+/// Converts a Julian Date to a Julian epoch in years.
+///
+/// Julian epochs count Julian years of 365.25 days from J2000.0, so
+/// `2451545.0` is `J2000.0`. They are used for modern catalog epochs and
+/// equinoxes. Matches ERFA `eraEpj`.
+///
+/// # Arguments
+///
+/// - `jd` — Julian Date (normally TT or TDB)
+///
+/// # Returns
+///
+/// The Julian epoch, e.g. `2024.5`
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time::{julian_epoch, julian_epoch_to_jd};
+///
+/// assert_eq!(julian_epoch(2451545.0), 2000.0);
+/// // Gaia DR3 reference epoch
+/// assert!((julian_epoch_to_jd(2016.0) - 2457389.0).abs() < 1e-9);
+/// ```
+pub fn julian_epoch(jd: f64) -> f64 {
+    2000.0 + (jd - JD2000) / JULIAN_YEAR_DAYS
+}
+
+/// Converts a Julian epoch in years to a Julian Date.
+///
+/// Inverse of [`julian_epoch`]. Matches ERFA `eraEpj2jd`.
+///
+/// # Arguments
+///
+/// - `epoch` — Julian epoch, e.g. `2016.0`
+///
+/// # Returns
+///
+/// The Julian Date
+pub fn julian_epoch_to_jd(epoch: f64) -> f64 {
+    JD2000 + (epoch - 2000.0) * JULIAN_YEAR_DAYS
+}
+
+/// Converts a Julian Date to a Besselian epoch in years.
+///
+/// Besselian epochs count tropical years from B1900.0 (JD 2415020.31352).
+/// They are the epochs and equinoxes of the FK4 and other pre-1984
+/// catalogs, e.g. `B1950.0`. Matches ERFA `eraEpb`.
+///
+/// # Arguments
+///
+/// - `jd` — Julian Date (normally TT or TDB)
+///
+/// # Returns
+///
+/// The Besselian epoch, e.g. `1950.0`
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time::{besselian_epoch, besselian_epoch_to_jd, julian_epoch};
+///
+/// let b1950 = besselian_epoch_to_jd(1950.0);
+/// assert!((b1950 - 2433282.4235).abs() < 1e-4);
+/// assert!((besselian_epoch(b1950) - 1950.0).abs() < 1e-12);
+/// // B1950.0 is not J1950.0
+/// assert!((julian_epoch(b1950) - 1949.99979).abs() < 1e-5);
+/// ```
+pub fn besselian_epoch(jd: f64) -> f64 {
+    1900.0 + (jd - JD_B1900) / TROPICAL_YEAR_DAYS
+}
+
+/// Converts a Besselian epoch in years to a Julian Date.
+///
+/// Inverse of [`besselian_epoch`]. Matches ERFA `eraEpb2jd`.
+///
+/// # Arguments
+///
+/// - `epoch` — Besselian epoch, e.g. `1950.0`
+///
+/// # Returns
+///
+/// The Julian Date
+pub fn besselian_epoch_to_jd(epoch: f64) -> f64 {
+    JD_B1900 + (epoch - 1900.0) * TROPICAL_YEAR_DAYS
+}
+
+/// Converts a UTC datetime to a two-part Julian Date `(jd1, jd2)`.
+///
+/// `jd1` is the Julian Date of the preceding 0h UTC (always ending in `.5`)
+/// and `jd2` the fraction of the day, including sub-second precision. The sum
+/// equals [`julian_date`], but the pair preserves nanosecond resolution.
+///
+/// # Arguments
+///
+/// - `datetime` — A UTC [`DateTime<Utc>`] to convert
+///
+/// # Returns
+///
+/// Tuple `(jd1, jd2)` with `jd1 + jd2` the Julian Date
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::time::{julian_date, julian_date_two_part};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let (jd1, jd2) = julian_date_two_part(dt);
+/// assert_eq!(jd1, 2460526.5);
+/// assert!((jd2 - 0.25).abs() < 1e-15);
+/// assert!((jd1 + jd2 - julian_date(dt)).abs() < 1e-9);
+/// ```
+pub fn julian_date_two_part(datetime: DateTime<Utc>) -> (f64, f64) {
+    let midnight = Utc.from_utc_datetime(&datetime.date_naive().and_hms_opt(0, 0, 0).unwrap());
+    let jd1 = julian_date(midnight);
+    let seconds = datetime.num_seconds_from_midnight() as f64
+        + datetime.nanosecond() as f64 * 1e-9;
+    (jd1, seconds / 86400.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
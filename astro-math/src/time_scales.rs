@@ -4,12 +4,20 @@
 //! - **UTC** (Coordinated Universal Time): Civil time with leap seconds
 //! - **TAI** (International Atomic Time): Uniform atomic time scale  
 //! - **TT** (Terrestrial Time): Theoretical uniform time scale for Earth observations
+//! - **TDB** (Barycentric Dynamical Time): TT plus periodic terms (±1.7 ms), used by ephemerides
+//! - **TCG**, **TCB** (Geocentric and Barycentric Coordinate Time): the
+//!   relativistic coordinate times that TT and TDB are scaled from
 //!
 //! # Time Scale Relationships
 //!
 //! - **TT = TAI + 32.184 seconds** (exact constant)
 //! - **TAI = UTC + leap_seconds** (varies with leap second announcements)
 //! - **TT = UTC + TAI_UTC_offset + 32.184**
+//! - **TT = TCG − L_G × (TCG − T₀)** and **TDB = TCB − L_B × (TCB − T₀) + TDB₀**
+//!   (IAU 2000 B1.9 and 2006 B3, T₀ = 1977 January 1.0 TAI)
+//!
+//! A clock away from the geoid runs at a different rate from TT; see
+//! [`proper_time_rate`].
 //!
 //! # Accuracy
 //!
@@ -17,6 +25,11 @@
 //! to the second. For applications requiring sub-second precision in time scale
 //! conversions, consider using dedicated time libraries.
 //!
+//! The `_checked` conversions ([`utc_to_tt_checked`], [`utc_to_tdb_checked`],
+//! [`utc_to_ut1_checked`] and their inverses) return a [`TimeConversion`]
+//! whose [`TimeFlags`] say when the result is degraded: a stale leap-second
+//! table, no UT1−UTC, or an instant inside a leap second.
+//!
 //! # Example
 //!
 //! ```
@@ -31,6 +44,8 @@
 //! println!("Current TT-UTC offset: {:.6} seconds", tt_utc_offset_seconds());
 //! ```
 
+use crate::eop::Dut1Table;
+use crate::location::Location;
 use chrono::{DateTime, Utc, NaiveDate};
 
 /// TT-TAI offset in seconds (exact constant defined by IAU).
@@ -39,6 +54,61 @@ use chrono::{DateTime, Utc, NaiveDate};
 /// by exactly 32.184 seconds as defined by the IAU.
 const TT_TAI_SECONDS: f64 = 32.184;
 
+/// Seconds per day
+const DAY_SECONDS: f64 = 86400.0;
+
+/// T₀ = 1977 January 1.0 TAI as a TT (and TCG, TCB) Julian Date, split in two
+const T0_JD1: f64 = 2443144.5;
+const T0_JD2: f64 = TT_TAI_SECONDS / DAY_SECONDS;
+
+/// Rate of TCG relative to TT, L_G (IAU 2000 Resolution B1.9, defining constant)
+const L_G: f64 = 6.969290134e-10;
+
+/// Rate of TCB relative to TDB, L_B (IAU 2006 Resolution B3, defining constant)
+const L_B: f64 = 1.550519768e-8;
+
+/// TDB − TCB at T₀ in seconds (IAU 2006 Resolution B3)
+const TDB0_SECONDS: f64 = -6.55e-5;
+
+/// Speed of light in m/s
+const SPEED_OF_LIGHT: f64 = 299792458.0;
+
+/// WGS84 semi-major axis in meters
+const EARTH_EQUATORIAL_RADIUS_M: f64 = 6378137.0;
+
+/// WGS84 flattening
+const EARTH_FLATTENING: f64 = 1.0 / 298.257223563;
+
+/// Geocentric gravitational constant in m³/s² (WGS84)
+const EARTH_GM: f64 = 3.986004418e14;
+
+/// Dynamical form factor J2 (WGS84)
+const EARTH_J2: f64 = 1.08262982e-3;
+
+/// Earth's rotation rate in rad/s
+const EARTH_ROTATION_RAD_S: f64 = 7.292115e-5;
+
+/// Leading terms of the Fairhead & Bretagnon (1990) TDB−TT series:
+/// (amplitude s, frequency rad per Julian millennium, phase rad), grouped by
+/// the power of time they multiply.
+const FAIRHEAD_T0: [[f64; 3]; 10] = [
+    [1656.674564e-6, 6283.075849991, 6.240054195],
+    [22.417471e-6, 5753.384884897, 4.296977442],
+    [13.839792e-6, 12566.151699983, 6.196904410],
+    [4.770086e-6, 529.690965095, 0.444401603],
+    [4.676740e-6, 6069.776754553, 4.021195093],
+    [2.256707e-6, 213.299095438, 5.543113262],
+    [1.694205e-6, -3.523118349, 5.025132748],
+    [1.554905e-6, 77713.771467920, 5.198467090],
+    [1.276839e-6, 7860.419392439, 5.988822341],
+    [1.193379e-6, 5223.693919802, 3.649823730],
+];
+const FAIRHEAD_T1: [[f64; 3]; 2] = [
+    [102.156724e-6, 6283.075849991, 4.249032005],
+    [1.706807e-6, 12566.151699983, 4.205904248],
+];
+const FAIRHEAD_T2: [[f64; 3]; 1] = [[4.322990e-6, 6283.075849991, 2.642893748]];
+
 /// Leap second table with (date, cumulative_leap_seconds) pairs.
 /// Updated from IERS Bulletin C announcements.
 /// 
@@ -76,6 +146,37 @@ static LEAP_SECOND_TABLE: &[(i32, u32, u32, f64)] = &[
     (2017,  1,  1, 37.0),  // Most recent leap second
 ];
 
+/// Date after which `LEAP_SECOND_TABLE` may be missing a leap second.
+///
+/// IERS Bulletin C 70 (July 2025) announced no leap second at the end of
+/// December 2025; the next one could be inserted at the end of June 2026.
+/// Update this together with the table when a new bulletin is published.
+const LEAP_SECOND_TABLE_EXPIRES: (i32, u32, u32) = (2026, 6, 28);
+
+/// Date after which the built-in leap-second table may be out of date.
+///
+/// IERS announces each leap second about six months ahead in Bulletin C.
+/// Up to this date the table is known to be complete; after it, a leap
+/// second may have been inserted that this build does not know about, so
+/// TAI−UTC (and everything derived from UTC) could be off by a second.
+///
+/// # Returns
+///
+/// The last date covered by the most recent Bulletin C the table reflects.
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time_scales::{leap_second_table_expires, tai_utc_offset_for_date};
+///
+/// let expires = leap_second_table_expires();
+/// assert_eq!(tai_utc_offset_for_date(expires), 37.0);
+/// ```
+pub fn leap_second_table_expires() -> NaiveDate {
+    let (year, month, day) = LEAP_SECOND_TABLE_EXPIRES;
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
 /// Get TAI-UTC offset for a specific date.
 ///
 /// Performs a lookup in the leap second table to find the correct
@@ -307,6 +408,32 @@ pub fn tt_to_utc_jd(jd_tt: f64) -> f64 {
     jd_tt - tt_utc_offset_jd()
 }
 
+/// Convert a two-part UTC Julian Date to a two-part TT Julian Date.
+///
+/// The TT-UTC offset is added to the second part only, so a fraction-of-day
+/// `jd2` keeps its full precision.
+///
+/// # Arguments
+///
+/// * `jd1`, `jd2` - UTC Julian Date split as in [`crate::time::julian_date_two_part`]
+///
+/// # Returns
+///
+/// TT Julian Date `(jd1, jd2)` with the same first part.
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time_scales::{utc_to_tt_jd, utc_to_tt_jd_two_part};
+///
+/// let (tt1, tt2) = utc_to_tt_jd_two_part(2460526.5, 0.25);
+/// assert_eq!(tt1, 2460526.5);
+/// assert!((tt1 + tt2 - utc_to_tt_jd(2460526.75)).abs() < 1e-9);
+/// ```
+pub fn utc_to_tt_jd_two_part(jd1: f64, jd2: f64) -> (f64, f64) {
+    (jd1, jd2 + tt_utc_offset_jd())
+}
+
 /// Split Julian Date into two parts for maximum precision in ERFA calls.
 ///
 /// ERFA functions expect Julian Dates to be split into two parts to
@@ -337,6 +464,485 @@ pub fn split_jd_for_erfa(jd: f64) -> (f64, f64) {
     (jd1, jd2)
 }
 
+/// TDB−TT in seconds at the geocentre.
+///
+/// Evaluates the 13 largest terms of the Fairhead & Bretagnon (1990)
+/// series, which agree with the full 787-term series (ERFA `eraDtdb`) to a
+/// few microseconds over 1600–2400. The topocentric terms (≤ 2 µs) are not
+/// included.
+///
+/// # Arguments
+///
+/// * `jd_tt` - Julian Date in TT (TDB may be used interchangeably)
+///
+/// # Returns
+///
+/// TDB−TT in seconds, within ±1.7 ms
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time_scales::tdb_tt_offset_seconds;
+///
+/// // Largest in early April and early October
+/// assert!(tdb_tt_offset_seconds(2460409.5).abs() > 1.5e-3);
+/// ```
+pub fn tdb_tt_offset_seconds(jd_tt: f64) -> f64 {
+    // Julian millennia since J2000.0
+    let t = (jd_tt - 2451545.0) / 365250.0;
+    let series = |terms: &[[f64; 3]]| terms.iter().map(|&[a, f, p]| a * (f * t + p).sin()).sum::<f64>();
+    series(&FAIRHEAD_T0) + t * (series(&FAIRHEAD_T1) + t * series(&FAIRHEAD_T2))
+}
+
+/// Convert TT Julian Date to TDB Julian Date.
+///
+/// See [`tdb_tt_offset_seconds`] for the model and its accuracy.
+///
+/// # Arguments
+///
+/// * `jd_tt` - Julian Date in TT
+///
+/// # Returns
+///
+/// Julian Date in TDB
+pub fn tt_to_tdb_jd(jd_tt: f64) -> f64 {
+    jd_tt + tdb_tt_offset_seconds(jd_tt) / DAY_SECONDS
+}
+
+/// Convert TDB Julian Date to TT Julian Date.
+///
+/// # Arguments
+///
+/// * `jd_tdb` - Julian Date in TDB
+///
+/// # Returns
+///
+/// Julian Date in TT
+pub fn tdb_to_tt_jd(jd_tdb: f64) -> f64 {
+    // The offset changes by under 1e-9 s over its own size
+    jd_tdb - tdb_tt_offset_seconds(jd_tdb) / DAY_SECONDS
+}
+
+/// Conditions that limit how far a converted time can be trusted.
+///
+/// Every flag is `false` for a conversion that is good to the accuracy of
+/// the underlying model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimeFlags {
+    /// The date is past [`leap_second_table_expires`], so TAI−UTC may be
+    /// missing a leap second and the result could be off by a second.
+    pub leap_table_stale: bool,
+    /// No UT1−UTC was available and zero was used, an error of up to 0.9 s.
+    pub dut1_missing: bool,
+    /// The UTC instant is within a second of a leap second, where a UTC
+    /// Julian Date cannot tell 23:59:60 from the seconds either side of it.
+    pub leap_second_ambiguous: bool,
+}
+
+impl TimeFlags {
+    /// Whether any flag is set.
+    pub fn is_degraded(&self) -> bool {
+        self.leap_table_stale || self.dut1_missing || self.leap_second_ambiguous
+    }
+
+}
+
+/// A converted Julian Date together with the [`TimeFlags`] picked up on
+/// the way.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeConversion {
+    /// Julian Date in the target time scale
+    pub jd: f64,
+    /// Conditions that degrade `jd`
+    pub flags: TimeFlags,
+}
+
+impl TimeConversion {
+    /// Applies a further conversion, keeping the flags.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use astro_math::time_scales::{tt_to_tdb_jd, utc_to_tdb_checked, utc_to_tt_checked};
+    ///
+    /// let tdb = utc_to_tt_checked(2460526.75).map(tt_to_tdb_jd);
+    /// assert_eq!(tdb, utc_to_tdb_checked(2460526.75));
+    /// ```
+    pub fn map(self, convert: impl FnOnce(f64) -> f64) -> TimeConversion {
+        TimeConversion { jd: convert(self.jd), flags: self.flags }
+    }
+}
+
+/// UTC calendar date containing a UTC Julian Date.
+fn utc_date_of_jd(jd_utc: f64) -> NaiveDate {
+    let days = (jd_utc - 2440587.5).floor() as i64;
+    NaiveDate::from_ymd_opt(1970, 1, 1).unwrap() + chrono::Duration::days(days)
+}
+
+/// Flags that follow from the leap-second table alone for a UTC instant.
+fn utc_flags(jd_utc: f64) -> TimeFlags {
+    let date = utc_date_of_jd(jd_utc);
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    // The first entry is the start of leap-second UTC, not an inserted second
+    let near_leap = LEAP_SECOND_TABLE[1..].iter().any(|&(year, month, day, _)| {
+        let midnight = (NaiveDate::from_ymd_opt(year, month, day).unwrap() - epoch).num_days() as f64 + 2440587.5;
+        ((jd_utc - midnight) * DAY_SECONDS).abs() < 1.0
+    });
+    TimeFlags {
+        leap_table_stale: date > leap_second_table_expires(),
+        dut1_missing: false,
+        leap_second_ambiguous: near_leap,
+    }
+}
+
+/// Convert UTC Julian Date to TT Julian Date, flagging doubtful results.
+///
+/// Uses the TAI−UTC in force on the date itself, as
+/// [`utc_to_tt_jd_for_date`] does, and reports when that value may be wrong.
+///
+/// # Arguments
+///
+/// * `jd_utc` - Julian Date in UTC
+///
+/// # Returns
+///
+/// TT Julian Date with `leap_table_stale` set past the table's expiry and
+/// `leap_second_ambiguous` set within a second of a leap second.
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time_scales::utc_to_tt_checked;
+///
+/// // 2016 December 31 23:59:59.5 UTC, half a second before a leap second
+/// let tt = utc_to_tt_checked(2457754.5 - 0.5 / 86400.0);
+/// assert!(tt.flags.leap_second_ambiguous);
+///
+/// let tt = utc_to_tt_checked(2451545.0);
+/// assert!(!tt.flags.is_degraded());
+/// assert!(((tt.jd - 2451545.0) * 86400.0 - 64.184).abs() < 1e-4);
+/// ```
+pub fn utc_to_tt_checked(jd_utc: f64) -> TimeConversion {
+    let tai_utc = tai_utc_offset_for_date(utc_date_of_jd(jd_utc));
+    TimeConversion {
+        jd: jd_utc + (tai_utc + TT_TAI_SECONDS) / DAY_SECONDS,
+        flags: utc_flags(jd_utc),
+    }
+}
+
+/// Convert TT Julian Date to UTC Julian Date, flagging doubtful results.
+///
+/// Unlike [`tt_to_utc_jd`], which applies today's offset, this uses the
+/// TAI−UTC in force at the resulting UTC instant.
+///
+/// # Arguments
+///
+/// * `jd_tt` - Julian Date in TT
+///
+/// # Returns
+///
+/// UTC Julian Date, flagged as for [`utc_to_tt_checked`]. A TT instant
+/// inside an inserted leap second has no UTC Julian Date of its own and is
+/// always flagged `leap_second_ambiguous`.
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time_scales::{tt_to_utc_checked, utc_to_tt_checked};
+///
+/// let jd_utc = 2457000.25;
+/// let back = tt_to_utc_checked(utc_to_tt_checked(jd_utc).jd);
+/// assert!((back.jd - jd_utc).abs() * 86400.0 < 1e-4);
+/// ```
+pub fn tt_to_utc_checked(jd_tt: f64) -> TimeConversion {
+    let jd_tai = jd_tt - TT_TAI_SECONDS / DAY_SECONDS;
+    // TAI is ahead of UTC by under a minute, so two lookups settle the date
+    let mut jd_utc = jd_tai - tai_utc_offset_for_date(utc_date_of_jd(jd_tai)) / DAY_SECONDS;
+    jd_utc = jd_tai - tai_utc_offset_for_date(utc_date_of_jd(jd_utc)) / DAY_SECONDS;
+    TimeConversion { jd: jd_utc, flags: utc_flags(jd_utc) }
+}
+
+/// Convert UTC Julian Date to TDB Julian Date, flagging doubtful results.
+///
+/// Chains [`utc_to_tt_checked`] and [`tt_to_tdb_jd`].
+///
+/// # Arguments
+///
+/// * `jd_utc` - Julian Date in UTC
+///
+/// # Returns
+///
+/// TDB Julian Date with the flags of the UTC→TT step
+pub fn utc_to_tdb_checked(jd_utc: f64) -> TimeConversion {
+    utc_to_tt_checked(jd_utc).map(tt_to_tdb_jd)
+}
+
+/// Convert TDB Julian Date to UTC Julian Date, flagging doubtful results.
+///
+/// Chains [`tdb_to_tt_jd`] and [`tt_to_utc_checked`].
+///
+/// # Arguments
+///
+/// * `jd_tdb` - Julian Date in TDB
+///
+/// # Returns
+///
+/// UTC Julian Date with the flags of the TT→UTC step
+pub fn tdb_to_utc_checked(jd_tdb: f64) -> TimeConversion {
+    tt_to_utc_checked(tdb_to_tt_jd(jd_tdb))
+}
+
+/// Convert UTC Julian Date to UT1 Julian Date, flagging doubtful results.
+///
+/// # Arguments
+///
+/// * `jd_utc` - Julian Date in UTC
+/// * `dut1` - UT1−UTC table, if one is loaded
+///
+/// # Returns
+///
+/// UT1 Julian Date. When there is no table, or the date is outside it and
+/// its prediction horizon, UT1−UTC is taken as zero and `dut1_missing` is
+/// set alongside the flags of [`utc_to_tt_checked`].
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time_scales::utc_to_ut1_checked;
+///
+/// let ut1 = utc_to_ut1_checked(2460526.75, None);
+/// assert!(ut1.flags.dut1_missing);
+/// assert_eq!(ut1.jd, 2460526.75);
+/// ```
+pub fn utc_to_ut1_checked(jd_utc: f64, dut1: Option<&Dut1Table>) -> TimeConversion {
+    let mut flags = utc_flags(jd_utc);
+    let seconds = match dut1.map(|table| table.dut1(jd_utc - 2400000.5)) {
+        Some(Ok(seconds)) => seconds,
+        _ => {
+            flags.dut1_missing = true;
+            0.0
+        }
+    };
+    TimeConversion { jd: jd_utc + seconds / DAY_SECONDS, flags }
+}
+
+/// Convert a two-part TT Julian Date to a two-part TCG Julian Date.
+///
+/// TCG runs faster than TT by L_G = 6.969290134e-10 (about 22 ms per
+/// year) and the two agreed at T₀ = 1977 January 1.0 TAI. The offset is
+/// added to the second part so a fraction-of-day `jd2` keeps its precision.
+///
+/// # Arguments
+///
+/// * `jd1`, `jd2` - TT Julian Date split into two parts
+///
+/// # Returns
+///
+/// TCG Julian Date `(jd1, jd2)` with the same first part
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time_scales::{tt_to_tcg_jd_two_part, tcg_to_tt_jd_two_part};
+///
+/// let (tcg1, tcg2) = tt_to_tcg_jd_two_part(2460526.5, 0.25);
+/// // TCG is about a second ahead of TT in 2024
+/// assert!(((tcg2 - 0.25) * 86400.0 - 1.047).abs() < 0.001);
+///
+/// let (tt1, tt2) = tcg_to_tt_jd_two_part(tcg1, tcg2);
+/// assert!(((tt1 - 2460526.5) + (tt2 - 0.25)).abs() < 1e-14);
+/// ```
+pub fn tt_to_tcg_jd_two_part(jd1: f64, jd2: f64) -> (f64, f64) {
+    let since_t0 = (jd1 - T0_JD1) + (jd2 - T0_JD2);
+    (jd1, jd2 + since_t0 * L_G / (1.0 - L_G))
+}
+
+/// Convert a two-part TCG Julian Date to a two-part TT Julian Date.
+///
+/// Inverse of [`tt_to_tcg_jd_two_part`].
+///
+/// # Arguments
+///
+/// * `jd1`, `jd2` - TCG Julian Date split into two parts
+///
+/// # Returns
+///
+/// TT Julian Date `(jd1, jd2)` with the same first part
+pub fn tcg_to_tt_jd_two_part(jd1: f64, jd2: f64) -> (f64, f64) {
+    let since_t0 = (jd1 - T0_JD1) + (jd2 - T0_JD2);
+    (jd1, jd2 - since_t0 * L_G)
+}
+
+/// Convert TT Julian Date to TCG Julian Date.
+///
+/// Single-value form of [`tt_to_tcg_jd_two_part`]; a single `f64` Julian
+/// Date resolves about 40 µs.
+pub fn tt_to_tcg_jd(jd_tt: f64) -> f64 {
+    let (jd1, jd2) = tt_to_tcg_jd_two_part(jd_tt, 0.0);
+    jd1 + jd2
+}
+
+/// Convert TCG Julian Date to TT Julian Date.
+///
+/// Single-value form of [`tcg_to_tt_jd_two_part`].
+pub fn tcg_to_tt_jd(jd_tcg: f64) -> f64 {
+    let (jd1, jd2) = tcg_to_tt_jd_two_part(jd_tcg, 0.0);
+    jd1 + jd2
+}
+
+/// Convert a two-part TDB Julian Date to a two-part TCB Julian Date.
+///
+/// TCB runs faster than TDB by L_B = 1.550519768e-8 (about 0.49 s per
+/// year), with TDB − TCB = TDB₀ = −65.5 µs at T₀ (IAU 2006 Resolution B3).
+///
+/// # Arguments
+///
+/// * `jd1`, `jd2` - TDB Julian Date split into two parts
+///
+/// # Returns
+///
+/// TCB Julian Date `(jd1, jd2)` with the same first part
+///
+/// # Example
+///
+/// ```
+/// use astro_math::time_scales::{tdb_to_tcb_jd_two_part, tcb_to_tdb_jd_two_part};
+///
+/// let (tcb1, tcb2) = tdb_to_tcb_jd_two_part(2451545.0, 0.0);
+/// // At J2000.0 TCB was about 11.25 s ahead of TDB
+/// assert!((tcb2 * 86400.0 - 11.25).abs() < 0.01);
+///
+/// let (tdb1, tdb2) = tcb_to_tdb_jd_two_part(tcb1, tcb2);
+/// assert!((tdb1 - 2451545.0 + tdb2).abs() < 1e-14);
+/// ```
+pub fn tdb_to_tcb_jd_two_part(jd1: f64, jd2: f64) -> (f64, f64) {
+    let tdb0 = TDB0_SECONDS / DAY_SECONDS;
+    let since_t0 = (jd1 - T0_JD1) + (jd2 - tdb0 - T0_JD2);
+    (jd1, jd2 - tdb0 + since_t0 * L_B / (1.0 - L_B))
+}
+
+/// Convert a two-part TCB Julian Date to a two-part TDB Julian Date.
+///
+/// Inverse of [`tdb_to_tcb_jd_two_part`].
+///
+/// # Arguments
+///
+/// * `jd1`, `jd2` - TCB Julian Date split into two parts
+///
+/// # Returns
+///
+/// TDB Julian Date `(jd1, jd2)` with the same first part
+pub fn tcb_to_tdb_jd_two_part(jd1: f64, jd2: f64) -> (f64, f64) {
+    let since_t0 = (jd1 - T0_JD1) + (jd2 - T0_JD2);
+    (jd1, jd2 - since_t0 * L_B + TDB0_SECONDS / DAY_SECONDS)
+}
+
+/// Convert TDB Julian Date to TCB Julian Date.
+///
+/// Single-value form of [`tdb_to_tcb_jd_two_part`].
+pub fn tdb_to_tcb_jd(jd_tdb: f64) -> f64 {
+    let (jd1, jd2) = tdb_to_tcb_jd_two_part(jd_tdb, 0.0);
+    jd1 + jd2
+}
+
+/// Convert TCB Julian Date to TDB Julian Date.
+///
+/// Single-value form of [`tcb_to_tdb_jd_two_part`].
+pub fn tcb_to_tdb_jd(jd_tcb: f64) -> f64 {
+    let (jd1, jd2) = tcb_to_tdb_jd_two_part(jd_tcb, 0.0);
+    jd1 + jd2
+}
+
+/// Fractional rate of a clock at `location` relative to TT.
+///
+/// TT is the proper time of a clock on the geoid. A clock elsewhere on the
+/// rotating Earth sits at a different combined gravitational and
+/// centrifugal potential W, and runs at `dτ/dTT = 1 + (W_geoid − W)/c²`.
+/// The potential is evaluated with the WGS84 ellipsoid and its J2 term,
+/// taking `altitude_m` as the height above the geoid; geoid-height
+/// conversions and tides are left to the caller.
+///
+/// # Arguments
+///
+/// * `location` - Clock position
+///
+/// # Returns
+///
+/// `dτ/dTT − 1`: positive for a clock that runs fast relative to TT
+/// (about +1.09e-16 per meter of height)
+///
+/// # Example
+///
+/// ```
+/// use astro_math::{Location, time_scales::proper_time_rate};
+///
+/// // A clock at Boulder, Colorado (1650 m) gains ~15.6 ns per day on TT
+/// let boulder = Location { latitude_deg: 40.0, longitude_deg: -105.27, altitude_m: 1650.0 };
+/// let gain_ns_per_day = proper_time_rate(&boulder) * 86400.0 * 1e9;
+/// assert!((gain_ns_per_day - 15.5).abs() < 0.2);
+/// ```
+pub fn proper_time_rate(location: &Location) -> f64 {
+    proper_time_rate_with_velocity(location, [0.0; 3])
+}
+
+/// Fractional rate of a moving clock relative to TT.
+///
+/// Like [`proper_time_rate`], with the additional special-relativistic
+/// slowing of a clock moving over the Earth's surface (aircraft, ships,
+/// vehicles). Sagnac and path-dependent effects on time transfer are not
+/// included.
+///
+/// # Arguments
+///
+/// * `location` - Clock position
+/// * `velocity_enu_m_s` - Velocity relative to the ground as east, north and
+///   up components in m/s
+///
+/// # Returns
+///
+/// `dτ/dTT − 1`
+///
+/// # Example
+///
+/// ```
+/// use astro_math::{Location, time_scales::{proper_time_rate, proper_time_rate_with_velocity}};
+///
+/// let cruise = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 10000.0 };
+/// // Eastbound flights move with the Earth's rotation and lose time relative to westbound ones
+/// let east = proper_time_rate_with_velocity(&cruise, [250.0, 0.0, 0.0]);
+/// let west = proper_time_rate_with_velocity(&cruise, [-250.0, 0.0, 0.0]);
+/// assert!(east < proper_time_rate(&cruise) && proper_time_rate(&cruise) < west);
+/// ```
+pub fn proper_time_rate_with_velocity(location: &Location, velocity_enu_m_s: [f64; 3]) -> f64 {
+    let phi = location.latitude_deg.to_radians();
+    let (p_geoid, z_geoid) = meridian_position(phi, 0.0);
+    let (p, z) = meridian_position(phi, location.altitude_m);
+
+    // Extra kinetic energy per unit mass over co-rotation with the Earth
+    let [ve, vn, vu] = velocity_enu_m_s;
+    let kinetic = EARTH_ROTATION_RAD_S * p * ve + (ve * ve + vn * vn + vu * vu) / 2.0;
+
+    (earth_potential(p_geoid, z_geoid) - earth_potential(p, z) - kinetic) / (SPEED_OF_LIGHT * SPEED_OF_LIGHT)
+}
+
+/// Distance from the rotation axis and height above the equator (m) of a
+/// point at geodetic latitude `phi` and ellipsoidal height `h`.
+fn meridian_position(phi: f64, h: f64) -> (f64, f64) {
+    let e2 = EARTH_FLATTENING * (2.0 - EARTH_FLATTENING);
+    let n = EARTH_EQUATORIAL_RADIUS_M / (1.0 - e2 * phi.sin().powi(2)).sqrt();
+    ((n + h) * phi.cos(), (n * (1.0 - e2) + h) * phi.sin())
+}
+
+/// Gravitational (with J2) plus centrifugal potential in m²/s², positive.
+fn earth_potential(p: f64, z: f64) -> f64 {
+    let r2 = p * p + z * z;
+    let r = r2.sqrt();
+    let sin2 = z * z / r2;
+    let a2 = EARTH_EQUATORIAL_RADIUS_M * EARTH_EQUATORIAL_RADIUS_M;
+    let gravity = EARTH_GM / r * (1.0 - EARTH_J2 * a2 / r2 * (3.0 * sin2 - 1.0) / 2.0);
+    gravity + (EARTH_ROTATION_RAD_S * p).powi(2) / 2.0
+}
+
 /// Check if the hardcoded time offset needs updating.
 ///
 /// This function helps identify when leap second tables need updating.
@@ -368,6 +974,63 @@ pub fn check_time_offset_accuracy(hardcoded_seconds: f64) -> f64 {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "erfa")]
+    #[test]
+    fn test_relativistic_scales_match_erfa() {
+        for &jd in &[2415020.5, 2443144.5, 2451545.0, 2460526.75, 2488070.5] {
+            let dtdb = erfars::timescales::Dtdb(jd, 0.0, 0.0, 0.0, 0.0, 0.0);
+            assert!((tdb_tt_offset_seconds(jd) - dtdb).abs() < 5e-6, "TDB-TT at {}", jd);
+
+            let (a1, a2) = tt_to_tcg_jd_two_part(jd, 0.125);
+            let (b1, b2) = erfars::timescales::Tttcg(jd, 0.125);
+            assert!(((a1 - b1) + (a2 - b2)).abs() * DAY_SECONDS < 1e-9, "TCG at {}", jd);
+            let (a1, a2) = tcg_to_tt_jd_two_part(jd, 0.125);
+            let (b1, b2) = erfars::timescales::Tcgtt(jd, 0.125);
+            assert!(((a1 - b1) + (a2 - b2)).abs() * DAY_SECONDS < 1e-9, "TT from TCG at {}", jd);
+
+            let (a1, a2) = tdb_to_tcb_jd_two_part(jd, 0.125);
+            let (b1, b2) = erfars::timescales::Tdbtcb(jd, 0.125);
+            assert!(((a1 - b1) + (a2 - b2)).abs() * DAY_SECONDS < 1e-9, "TCB at {}", jd);
+            let (a1, a2) = tcb_to_tdb_jd_two_part(jd, 0.125);
+            let (b1, b2) = erfars::timescales::Tcbtdb(jd, 0.125);
+            assert!(((a1 - b1) + (a2 - b2)).abs() * DAY_SECONDS < 1e-9, "TDB from TCB at {}", jd);
+        }
+    }
+
+    #[test]
+    fn test_relativistic_roundtrips() {
+        let jd = 2460526.75;
+        assert!((tdb_to_tt_jd(tt_to_tdb_jd(jd)) - jd).abs() * DAY_SECONDS < 1e-4);
+        assert!((tcg_to_tt_jd(tt_to_tcg_jd(jd)) - jd).abs() * DAY_SECONDS < 1e-4);
+        assert!((tcb_to_tdb_jd(tdb_to_tcb_jd(jd)) - jd).abs() * DAY_SECONDS < 1e-4);
+
+        // TCG and TCB agree with TT and TDB (up to TDB0) at 1977 January 1.0 TAI
+        let t0 = T0_JD1 + T0_JD2;
+        assert!((tt_to_tcg_jd(t0) - t0).abs() * DAY_SECONDS < 1e-4);
+        assert!(((tdb_to_tcb_jd(t0) - t0) * DAY_SECONDS + TDB0_SECONDS).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_proper_time_rate() {
+        let site = |latitude_deg, altitude_m| Location { latitude_deg, longitude_deg: 0.0, altitude_m };
+
+        // On the geoid a fixed clock keeps TT
+        assert_eq!(proper_time_rate(&site(0.0, 0.0)), 0.0);
+        assert!(proper_time_rate(&site(60.0, 0.0)).abs() < 1e-20);
+
+        // g·h/c²: 1.09e-13 per kilometer, a little more at the poles where g is larger
+        let equator = proper_time_rate(&site(0.0, 1000.0));
+        let pole = proper_time_rate(&site(90.0, 1000.0));
+        assert!((equator - 9.780 * 1000.0 / (SPEED_OF_LIGHT * SPEED_OF_LIGHT)).abs() < 1e-16);
+        assert!(pole > equator);
+
+        // An eastbound airliner: v²/2c² plus motion on top of the Earth's rotation
+        let still = proper_time_rate(&site(0.0, 10000.0));
+        let moving = proper_time_rate_with_velocity(&site(0.0, 10000.0), [250.0, 0.0, 0.0]);
+        let expected = (EARTH_ROTATION_RAD_S * 6388137.0 * 250.0 + 250.0 * 250.0 / 2.0) / (SPEED_OF_LIGHT * SPEED_OF_LIGHT);
+        assert!((still - moving - expected).abs() < 1e-17);
+    }
+
     #[test]
     fn test_tai_utc_offset_current() {
         // As of 2025, TAI-UTC should be 37 seconds
@@ -503,4 +1166,49 @@ mod tests {
                 "J2000.0 conversion should use correct leap second value: got {:.9}, expected {:.9}",
                 jd_j2000_tt, expected_jd_tt);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_checked_conversion_flags() {
+        // Leap second at the end of 2016 December 31
+        let midnight = 2457754.5;
+        for seconds in [-0.5, 0.0, 0.5] {
+            let tt = utc_to_tt_checked(midnight + seconds / DAY_SECONDS);
+            assert!(tt.flags.leap_second_ambiguous, "{} s from the leap second", seconds);
+        }
+        assert!(!utc_to_tt_checked(midnight - 1.5 / DAY_SECONDS).flags.is_degraded());
+
+        // TAI-UTC steps from 36 to 37 s across the leap second
+        let before = utc_to_tt_checked(midnight - 2.0 / DAY_SECONDS).jd;
+        let after = utc_to_tt_checked(midnight + 2.0 / DAY_SECONDS).jd;
+        assert!(((after - before) * DAY_SECONDS - 5.0).abs() < 1e-4);
+
+        let expires = leap_second_table_expires();
+        let jd = (expires - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as f64 + 2440587.5;
+        assert!(!utc_to_tdb_checked(jd + 0.5).flags.leap_table_stale);
+        assert!(utc_to_tdb_checked(jd + 1.5).flags.leap_table_stale);
+        assert!(tdb_to_utc_checked(jd + 1.5).flags.leap_table_stale);
+    }
+
+    #[test]
+    fn test_checked_roundtrips() {
+        for &jd_utc in &[2441317.75, 2451545.0, 2457754.5 + 2.0 / DAY_SECONDS, 2460526.75] {
+            let tt = utc_to_tt_checked(jd_utc);
+            assert!((tt.jd - utc_to_tt_jd_for_date(jd_utc)).abs() * DAY_SECONDS < 1e-4);
+            assert!((tt_to_utc_checked(tt.jd).jd - jd_utc).abs() * DAY_SECONDS < 1e-4);
+            let tdb = utc_to_tdb_checked(jd_utc);
+            assert!((tdb_to_utc_checked(tdb.jd).jd - jd_utc).abs() * DAY_SECONDS < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_checked_ut1() {
+        let table = Dut1Table::new(&[(60500.0, 0.02), (60530.0, 0.01)]).unwrap();
+        let ut1 = utc_to_ut1_checked(2400000.5 + 60515.0, Some(&table));
+        assert!(!ut1.flags.dut1_missing);
+        assert!(((ut1.jd - 2400000.5 - 60515.0) * DAY_SECONDS - 0.015).abs() < 1e-3);
+
+        // Before the table and with no table at all
+        assert!(utc_to_ut1_checked(2400000.5 + 60000.0, Some(&table)).flags.dut1_missing);
+        assert!(utc_to_ut1_checked(2400000.5 + 60515.0, None).flags.dut1_missing);
+    }
+}
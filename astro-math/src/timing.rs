@@ -0,0 +1,436 @@
+//! Exposure timestamps and the motion of coordinates during an exposure.
+//!
+//! Capture software usually records when the shutter opened and how long it
+//! stayed open, but photometry and astrometry want a single time for the
+//! frame: the midpoint, on a uniform time scale, and for timing work the
+//! moment the light would have reached the Solar System barycentre.
+//!
+//! [`exposure_midpoint_jd`] does the arithmetic in TT, which has no leap
+//! seconds, so an exposure that spans a leap second still gets the right
+//! midpoint. The TAI−UTC offset is looked up for the date of the exposure
+//! rather than taken from today's value. [`ExposureMidpoint::bjd_tdb`] then
+//! adds the Rømer delay to give BJD(TDB), the standard for transit and
+//! variable-star timing.
+//!
+//! Over a long exposure a target's coordinates change: an untracked camera
+//! sees a star trail through altitude and azimuth, and even apparent places
+//! drift. [`smear`] samples any time-dependent position across an exposure
+//! and reports how far it travelled and how far its time-averaged position
+//! lies from its position at the midpoint; [`alt_az_smear`] applies it to
+//! horizontal coordinates. When that offset is not negligible, the midpoint
+//! position is not where the light was actually collected.
+//!
+//! For spectroscopy the same applies to the slit angle:
+//! [`mean_parallactic_angle`] averages the parallactic angle over the
+//! exposure, weighted by airmass since atmospheric dispersion grows with
+//! it, which is the orientation that loses the least light overall.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::timing::exposure_midpoint_jd;
+//! use astro_math::Location;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let start = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+//! let mid = exposure_midpoint_jd(start, 120.0).unwrap();
+//! assert_eq!(mid.utc, Utc.with_ymd_and_hms(2024, 8, 4, 6, 1, 0).unwrap());
+//! // TT runs 69.184 s ahead of UTC since 2017
+//! assert!(((mid.jd_tt - mid.jd_utc) * 86400.0 - 69.184).abs() < 1e-3);
+//!
+//! let site = Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 };
+//! let bjd = mid.bjd_tdb(279.23473479, 38.78368896, &site).unwrap();
+//! // The light-travel time across Earth's orbit is at most ~8.3 minutes
+//! assert!((bjd - mid.jd_tdb).abs() * 1440.0 < 8.4);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::OutOfRange` for a negative or non-finite duration, too few
+//!   samples, or an out-of-range location
+//! - `AstroError::CalculationError` if a target sets during an exposure
+
+use crate::airmass::airmass_kasten_young;
+use crate::error::{validate_dec, validate_ra, validate_range, AstroError, Result};
+use crate::location::Location;
+use crate::observer::ObserverKind;
+use crate::time::julian_date_two_part;
+use crate::time_scales::{tai_utc_offset_for_datetime, tdb_tt_offset_seconds};
+use crate::transforms::{alt_az_from_lst, parallactic_angle_from_hour_angle, ra_dec_to_alt_az};
+use crate::vec3::{angle_between, radec_to_unit_vector, unit_vector_to_radec};
+use chrono::{DateTime, Duration, TimeZone, Timelike, Utc};
+
+/// TT−TAI in seconds
+const TT_TAI_SECONDS: f64 = 32.184;
+
+/// Seconds per day
+const DAY_SECONDS: f64 = 86400.0;
+
+/// Astronomical Unit in kilometers
+const AU_KM: f64 = 149597870.7;
+
+/// Speed of light in km/s
+const C_KM_S: f64 = 299792.458;
+
+/// The midpoint of an exposure on several time scales.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExposureMidpoint {
+    /// Civil time of the midpoint. Inside a leap second this is 23:59:60,
+    /// which chrono represents as 23:59:59 with a nanosecond field ≥ 10⁹.
+    pub utc: DateTime<Utc>,
+    /// UTC Julian Date
+    pub jd_utc: f64,
+    /// TT Julian Date
+    pub jd_tt: f64,
+    /// TDB Julian Date at the geocentre
+    pub jd_tdb: f64,
+}
+
+impl ExposureMidpoint {
+    /// Barycentric Julian Date in TDB for light arriving from a target.
+    ///
+    /// Adds the Rømer delay, the light-travel time from the Solar System
+    /// barycentre to the observer along the direction of the target, using
+    /// Earth's barycentric position (VSOP2000-based `eraEpv00`) plus the
+    /// observer's geocentric position. The Shapiro delay (tens of
+    /// microseconds at most) and the target's own parallax are not included.
+    ///
+    /// # Arguments
+    /// * `ra` - ICRS right ascension in degrees
+    /// * `dec` - ICRS declination in degrees
+    /// * `location` - Observer's location
+    ///
+    /// # Returns
+    /// BJD(TDB). A single `f64` Julian Date resolves about 40 µs.
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+    /// - `AstroError::OutOfRange` if the latitude, longitude or altitude is out of range
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::timing::exposure_midpoint_jd;
+    /// use astro_math::Location;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let site = Location { latitude_deg: 0.0, longitude_deg: 0.0, altitude_m: 0.0 };
+    /// // At opposition light from a star on the ecliptic reaches Earth
+    /// // about 8.3 minutes before it reaches the barycentre
+    /// let mid = exposure_midpoint_jd(Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap(), 0.0).unwrap();
+    /// let delay = (mid.bjd_tdb(180.0, 0.0, &site).unwrap() - mid.jd_tdb) * 86400.0;
+    /// assert!((delay - 497.0).abs() < 5.0);
+    /// ```
+    pub fn bjd_tdb(&self, ra: f64, dec: f64, location: &Location) -> Result<f64> {
+        validate_ra(ra)?;
+        validate_dec(dec)?;
+        validate_range(location.latitude_deg, -90.0, 90.0, "latitude")?;
+        validate_range(location.longitude_deg, -180.0, 180.0, "longitude")?;
+        if !location.altitude_m.is_finite() {
+            return Err(AstroError::OutOfRange {
+                parameter: "altitude_m",
+                value: location.altitude_m,
+                min: f64::MIN,
+                max: f64::MAX,
+            });
+        }
+
+        let (_earth_h, earth_b) = crate::backend::ephemerides::Epv00(self.jd_tt, 0.0);
+        let site = ObserverKind::Ground(*location).gcrs_position_km(self.utc);
+        let target = radec_to_unit_vector(ra, dec);
+        let projected_km: f64 = (0..3).map(|i| (earth_b[i] * AU_KM + site[i]) * target[i]).sum();
+        Ok(self.jd_tdb + projected_km / C_KM_S / DAY_SECONDS)
+    }
+}
+
+/// How a position moved across an exposure.
+///
+/// Positions are `(longitude, latitude)` pairs in degrees, in whatever
+/// frame the sampled function returns, e.g. `(ra, dec)` or `(azimuth, altitude)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoordinateSmear {
+    /// Position when the shutter opened
+    pub start: (f64, f64),
+    /// Position at the midpoint of the exposure
+    pub midpoint: (f64, f64),
+    /// Position when the shutter closed
+    pub end: (f64, f64),
+    /// Position averaged uniformly over the exposure
+    pub mean: (f64, f64),
+    /// Length of the path traced during the exposure, in arcseconds
+    pub path_arcsec: f64,
+    /// Separation of `mean` from `midpoint` in arcseconds; zero for motion
+    /// that is uniform along a great circle
+    pub mean_offset_arcsec: f64,
+}
+
+/// Calculates the midpoint of an exposure on the UTC, TT and TDB scales.
+///
+/// The duration is elapsed SI seconds, so it is added in TT. The TAI−UTC
+/// offset comes from the leap-second table for the date of the exposure,
+/// and changes correctly if the exposure spans a leap second.
+///
+/// # Arguments
+/// * `start` - When the shutter opened (UTC)
+/// * `duration` - Exposure time in seconds
+///
+/// # Returns
+/// The midpoint on each time scale
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if `duration` is negative or not finite.
+///
+/// # Example
+/// ```
+/// use astro_math::timing::exposure_midpoint_jd;
+/// use chrono::{TimeZone, Utc};
+///
+/// // 20 s starting ten seconds before the leap second at the end of 2016:
+/// // the midpoint is the leap second itself
+/// let start = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 50).unwrap();
+/// let mid = exposure_midpoint_jd(start, 20.0).unwrap();
+/// assert_eq!(mid.utc.format("%H:%M:%S").to_string(), "23:59:60");
+/// ```
+pub fn exposure_midpoint_jd(start: DateTime<Utc>, duration: f64) -> Result<ExposureMidpoint> {
+    validate_duration(duration)?;
+    let utc = add_elapsed_seconds(start, duration / 2.0);
+
+    let (jd1, jd2) = julian_date_two_part(start);
+    let tt_offset = (tai_utc_offset_for_datetime(start) + TT_TAI_SECONDS) / DAY_SECONDS;
+    let jd_tt = jd1 + (jd2 + tt_offset + duration / 2.0 / DAY_SECONDS);
+
+    let (utc1, utc2) = julian_date_two_part(utc);
+    Ok(ExposureMidpoint {
+        utc,
+        jd_utc: utc1 + utc2,
+        jd_tt,
+        jd_tdb: jd_tt + tdb_tt_offset_seconds(jd_tt) / DAY_SECONDS,
+    })
+}
+
+/// Samples a time-dependent position across an exposure.
+///
+/// `position` is evaluated at `samples` evenly spaced times from `start`
+/// to the end of the exposure, and once more at the midpoint. The mean
+/// position is the normalised trapezoidal average of the unit vectors, so
+/// it is well defined across RA 0h and near the poles.
+///
+/// # Arguments
+/// * `start` - When the shutter opened (UTC)
+/// * `duration` - Exposure time in seconds
+/// * `samples` - Number of samples including both ends; at least 2
+/// * `position` - Returns `(longitude, latitude)` in degrees at a given time
+///
+/// # Returns
+/// The positions at the start, midpoint and end, their mean, the path
+/// length and the offset of the mean from the midpoint
+///
+/// # Errors
+/// - `AstroError::OutOfRange` if `duration` is negative or not finite, or
+///   `samples` is less than 2
+/// - Any error returned by `position`
+///
+/// # Example
+/// ```
+/// use astro_math::timing::smear;
+/// use chrono::{TimeZone, Utc};
+///
+/// // A point moving along the equator at 1°/minute
+/// let start = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let trail = smear(start, 60.0, 7, |t| Ok(((t - start).num_milliseconds() as f64 / 60000.0, 0.0))).unwrap();
+/// assert!((trail.path_arcsec - 3600.0).abs() < 1e-6);
+/// assert!(trail.mean_offset_arcsec < 1e-6);
+/// ```
+pub fn smear<F>(start: DateTime<Utc>, duration: f64, samples: usize, mut position: F) -> Result<CoordinateSmear>
+where
+    F: FnMut(DateTime<Utc>) -> Result<(f64, f64)>,
+{
+    validate_duration(duration)?;
+    if samples < 2 {
+        return Err(AstroError::OutOfRange {
+            parameter: "samples",
+            value: samples as f64,
+            min: 2.0,
+            max: f64::MAX,
+        });
+    }
+
+    let mut sum = [0.0; 3];
+    let mut path_rad = 0.0;
+    let mut previous: Option<[f64; 3]> = None;
+    let (mut first, mut last) = ((0.0, 0.0), (0.0, 0.0));
+    for k in 0..samples {
+        let offset = duration * k as f64 / (samples - 1) as f64;
+        let point = position(add_elapsed_seconds(start, offset))?;
+        let v = radec_to_unit_vector(point.0, point.1);
+        let weight = if k == 0 || k == samples - 1 { 0.5 } else { 1.0 };
+        for i in 0..3 {
+            sum[i] += weight * v[i];
+        }
+        if let Some(p) = previous {
+            path_rad += angle_between(p, v);
+        }
+        if k == 0 {
+            first = point;
+        }
+        last = point;
+        previous = Some(v);
+    }
+
+    let midpoint = position(add_elapsed_seconds(start, duration / 2.0))?;
+    let mean = unit_vector_to_radec(sum);
+    Ok(CoordinateSmear {
+        start: first,
+        midpoint,
+        end: last,
+        mean,
+        path_arcsec: path_rad.to_degrees() * 3600.0,
+        mean_offset_arcsec: angle_between(radec_to_unit_vector(mean.0, mean.1), radec_to_unit_vector(midpoint.0, midpoint.1)).to_degrees() * 3600.0,
+    })
+}
+
+/// Samples the horizontal coordinates of a fixed target across an exposure.
+///
+/// This is the trail an untracked or alt-az camera records without
+/// derotation. Positions in the result are `(azimuth, altitude)`.
+///
+/// # Arguments
+/// * `ra` - Right ascension in degrees
+/// * `dec` - Declination in degrees
+/// * `start` - When the shutter opened (UTC)
+/// * `duration` - Exposure time in seconds
+/// * `location` - Observer's location
+///
+/// # Returns
+/// The smear of `(azimuth, altitude)`, sampled once a second (at least 3 samples)
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if `duration` is negative or not finite
+///
+/// # Example
+/// ```
+/// use astro_math::timing::alt_az_smear;
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 };
+/// let start = Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap();
+/// // A star on the celestial equator moves 15″ per second
+/// let trail = alt_az_smear(83.82, 0.0, start, 60.0, &site).unwrap();
+/// assert!((trail.path_arcsec - 900.0).abs() < 5.0);
+/// ```
+pub fn alt_az_smear(ra: f64, dec: f64, start: DateTime<Utc>, duration: f64, location: &Location) -> Result<CoordinateSmear> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    validate_duration(duration)?;
+    let samples = (duration.ceil() as usize + 1).max(3);
+    smear(start, duration, samples, |t| {
+        let (alt, az) = ra_dec_to_alt_az(ra, dec, t, location)?;
+        Ok((az, alt))
+    })
+}
+
+/// Averages the parallactic angle of a target over an exposure, weighted
+/// by airmass.
+///
+/// Differential refraction along the parallactic angle grows roughly with
+/// airmass, so the parts of the exposure taken lower in the sky count for
+/// more. The angle is sampled once a second (at least 3 samples), and the
+/// weighted mean is taken on the circle so that it stays continuous where
+/// the angle passes through ±180°, north of the zenith.
+///
+/// # Arguments
+/// * `ra` - Right ascension of date in degrees
+/// * `dec` - Declination of date in degrees
+/// * `start` - When the shutter opened (UTC)
+/// * `duration` - Exposure time in seconds
+/// * `location` - Observer's location
+///
+/// # Returns
+/// The airmass-weighted mean parallactic angle in degrees, in (−180, 180]
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if `duration` is negative or not finite
+/// - `AstroError::CalculationError` if the target is below the horizon at
+///   any time during the exposure
+///
+/// # Example
+/// ```
+/// use astro_math::timing::mean_parallactic_angle;
+/// use astro_math::Location;
+/// use astro_math::transforms::parallactic_angle;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 };
+/// let start = Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap();
+/// let ra = (site.local_sidereal_time(start) * 15.0).rem_euclid(360.0);
+///
+/// // An hour through the meridian: the angle swings from east to west, but
+/// // the exposure is symmetric, so the slit stays at the midpoint angle
+/// let before = start - Duration::minutes(30);
+/// let mean = mean_parallactic_angle(ra, -10.0, before, 3600.0, &site).unwrap();
+/// assert!(mean.abs() < 0.1);
+/// let at_start = parallactic_angle(ra, -10.0, before, &site).unwrap();
+/// assert!(at_start < -5.0);
+/// ```
+pub fn mean_parallactic_angle(ra: f64, dec: f64, start: DateTime<Utc>, duration: f64, location: &Location) -> Result<f64> {
+    validate_ra(ra)?;
+    validate_dec(dec)?;
+    validate_duration(duration)?;
+    let samples = (duration.ceil() as usize + 1).max(3);
+    let lat_rad = location.latitude_deg.to_radians();
+    let (sin_lat, cos_lat) = lat_rad.sin_cos();
+
+    let (mut sum_sin, mut sum_cos) = (0.0, 0.0);
+    for k in 0..samples {
+        let offset = duration * k as f64 / (samples - 1) as f64;
+        let lst_hours = location.local_sidereal_time(add_elapsed_seconds(start, offset));
+        let (alt, _) = alt_az_from_lst(ra, dec, lst_hours, sin_lat, cos_lat)?;
+        if alt <= 0.0 {
+            return Err(AstroError::CalculationError {
+                calculation: "mean parallactic angle",
+                reason: format!("target is below the horizon {offset:.0} s into the exposure"),
+            });
+        }
+        let ha_rad = (lst_hours * 15.0 - ra).to_radians();
+        let q = parallactic_angle_from_hour_angle(ha_rad, dec.to_radians(), lat_rad);
+        let trapezoid = if k == 0 || k == samples - 1 { 0.5 } else { 1.0 };
+        let weight = trapezoid * airmass_kasten_young(alt)?;
+        sum_sin += weight * q.sin();
+        sum_cos += weight * q.cos();
+    }
+    Ok(sum_sin.atan2(sum_cos).to_degrees())
+}
+
+fn validate_duration(duration: f64) -> Result<()> {
+    if !(duration >= 0.0 && duration.is_finite()) {
+        return Err(AstroError::OutOfRange {
+            parameter: "duration",
+            value: duration,
+            min: 0.0,
+            max: f64::MAX,
+        });
+    }
+    Ok(())
+}
+
+/// Adds elapsed SI seconds to a UTC time, allowing for any leap second in between.
+fn add_elapsed_seconds(start: DateTime<Utc>, seconds: f64) -> DateTime<Utc> {
+    let naive = start + Duration::nanoseconds((seconds * 1e9).round() as i64);
+    let inserted = tai_utc_offset_for_datetime(naive) - tai_utc_offset_for_datetime(start);
+    if inserted == 0.0 {
+        return naive;
+    }
+    let adjusted = naive - Duration::nanoseconds((inserted * 1e9).round() as i64);
+    if tai_utc_offset_for_datetime(adjusted) == tai_utc_offset_for_datetime(naive) {
+        return adjusted;
+    }
+    // Inside the leap second itself: 23:59:60 on the day before the new offset
+    let midnight = naive.date_naive().and_hms_opt(0, 0, 0).unwrap();
+    let into_day = (naive.naive_utc() - midnight).num_nanoseconds().unwrap_or(0) as u32;
+    let last_second = (midnight - Duration::seconds(1)).with_nanosecond(1_000_000_000 + into_day).unwrap();
+    Utc.from_utc_datetime(&last_second)
+}
+
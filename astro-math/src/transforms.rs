@@ -14,18 +14,53 @@
 //!   - Altitude: -90° to +90° above horizon
 //!   - Azimuth: 0° to 360° clockwise from north
 //!
+//! # Input Epoch
+//!
+//! [`ra_dec_to_alt_az`] takes RA/Dec of date (JNow). For catalog positions
+//! use [`ra_dec_to_alt_az_j2000`], which precesses and nutates them first, or
+//! the full [`ra_dec_to_alt_az_erfa`]; [`ra_dec_to_alt_az_of_date`] names the
+//! JNow case explicitly.
+//!
+//! # Units
+//!
+//! Degrees are the canonical units of the public API: every transform takes
+//! and returns degrees, and the other modules build on those. The hot-path
+//! transforms also come in radian-native `_rad` variants
+//! ([`ra_dec_to_alt_az_rad`], [`alt_az_to_ra_dec_rad`] and the matching
+//! [`FixedSite`](crate::fixed_site::FixedSite) methods) for batch loops over data that are
+//! already in radians. They share the degree functions' trigonometry, skip
+//! the conversions at either end and validate the same ranges expressed in
+//! radians.
+//!
 //! # Error Handling
 //!
 //! All functions validate their inputs and return `Result<T>` types. Common errors:
 //! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
 
+use crate::accuracy;
+use crate::astrometric_context::AstrometricContext;
+use crate::eop::EarthOrientation;
+use crate::provenance::{Correction, EopSource, Provenance, RefractionApplied};
+use crate::ephemeris::{topocentric_radec, Ephemeris};
 use crate::location::Location;
-use crate::error::{Result, validate_ra, validate_dec, validate_finite};
-use crate::time::julian_date;
+use crate::moving_observer::MovingObserver;
+use crate::nutation::apply_nutation;
+use crate::precession::precess_from_j2000;
+use crate::error::{AstroError, Result, validate_ra, validate_dec, validate_finite};
+use crate::refraction::{refraction_saemundsson, AtmosphericConditions};
+use crate::time::julian_date_two_part;
+use crate::time_scales::utc_to_tt_jd_two_part;
 use chrono::{DateTime, Utc};
-use std::f64::consts::PI;
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
 use rayon::prelude::*;
 
+/// Radians per hour of sidereal time
+pub(crate) const HOURS_TO_RAD: f64 = PI / 12.0;
+
+/// Rate of change of hour angle in degrees per second of UT1 (the IAU 2000
+/// Earth rotation angle rate, 1.00273781191135448 turns per day)
+const HOUR_ANGLE_RATE_DEG_S: f64 = 360.0 * 1.002_737_811_911_354_5 / 86_400.0;
+
 /// Sanitize coordinate transformation results to prevent NaN/Infinity propagation
 #[inline]
 fn sanitize_alt_az_result(alt: f64, az: f64) -> Result<(f64, f64)> {
@@ -39,6 +74,14 @@ fn sanitize_alt_az_result(alt: f64, az: f64) -> Result<(f64, f64)> {
     Ok((alt_clamped, az_normalized))
 }
 
+/// Sanitize Alt/Az results in radians, as [`sanitize_alt_az_result`]
+#[inline]
+fn sanitize_alt_az_result_rad(alt: f64, az: f64) -> Result<(f64, f64)> {
+    validate_finite(alt, "altitude")?;
+    validate_finite(az, "azimuth")?;
+    Ok((alt.clamp(-FRAC_PI_2, FRAC_PI_2), az.rem_euclid(TAU)))
+}
+
 /// Sanitize RA/Dec transformation results 
 #[inline]
 fn sanitize_ra_dec_result(ra: f64, dec: f64) -> Result<(f64, f64)> {
@@ -52,6 +95,14 @@ fn sanitize_ra_dec_result(ra: f64, dec: f64) -> Result<(f64, f64)> {
     Ok((ra_normalized, dec_clamped))
 }
 
+/// Sanitize RA/Dec results in radians, as [`sanitize_ra_dec_result`]
+#[inline]
+fn sanitize_ra_dec_result_rad(ra: f64, dec: f64) -> Result<(f64, f64)> {
+    validate_finite(ra, "RA")?;
+    validate_finite(dec, "declination")?;
+    Ok((ra.rem_euclid(TAU), dec.clamp(-FRAC_PI_2, FRAC_PI_2)))
+}
+
 /// Converts equatorial coordinates (RA/DEC) to horizontal coordinates (Altitude/Azimuth)
 /// for a given UTC time and observer location.
 ///
@@ -140,55 +191,594 @@ pub fn ra_dec_to_alt_az(
     // Validate inputs
     validate_ra(ra_deg)?;
     validate_dec(dec_deg)?;
-    // Convert declination and latitude to radians
+    let lst_hours = observer.local_sidereal_time(datetime);
+    let (sin_lat, cos_lat) = observer.latitude_deg.to_radians().sin_cos();
+    alt_az_from_lst(ra_deg, dec_deg, lst_hours, sin_lat, cos_lat)
+}
+
+/// Computes the altitude and azimuth of a moving target from its ephemeris.
+///
+/// Works for anything implementing [`Ephemeris`]: the Sun, Moon and planets
+/// ([`AlmanacBody`](crate::almanac::AlmanacBody)), satellites
+/// ([`Tle`](crate::satellite::Tle)) or a tabulated comet. The position is
+/// topocentric, so it includes the diurnal parallax of a nearby target (see
+/// [`topocentric_radec`]), and unrefracted.
+///
+/// # Arguments
+/// * `body` - The target
+/// * `datetime` - Time of observation
+/// * `observer` - Observer's location
+///
+/// # Returns
+/// `(altitude, azimuth)` in degrees, azimuth measured from North through East
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the latitude or longitude is out of
+///   range
+/// - Whatever the target's ephemeris returns for `datetime`
+///
+/// # Example
+/// ```
+/// use astro_math::almanac::AlmanacBody;
+/// use astro_math::transforms::body_alt_az;
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let greenwich = Location { latitude_deg: 51.4769, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let noon = Utc.with_ymd_and_hms(2024, 6, 20, 12, 0, 0).unwrap();
+/// let (alt, az) = body_alt_az(&AlmanacBody::Sun, noon, &greenwich).unwrap();
+/// assert!((alt - 61.9).abs() < 0.2);
+/// assert!((az - 180.0).abs() < 2.0);
+/// ```
+pub fn body_alt_az<E: Ephemeris + ?Sized>(
+    body: &E,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<(f64, f64)> {
+    let (ra, dec) = topocentric_radec(body, datetime, observer)?;
+    ra_dec_to_alt_az(ra, dec, datetime, observer)
+}
+
+/// Converts RA/Dec to Alt/Az for a time given as a two-part UTC Julian Date.
+///
+/// Identical to [`ra_dec_to_alt_az`] but takes the observation time as a split
+/// Julian Date (see [`julian_date_two_part`])
+/// so that timing-critical applications keep full precision end to end.
+///
+/// # Arguments
+///
+/// - `ra_deg`: Right Ascension in degrees (0° to 360°)
+/// - `dec_deg`: Declination in degrees (−90° to +90°)
+/// - `jd1`, `jd2`: UTC Julian Date split into two parts
+/// - `observer`: Observer location
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg)` in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::{Location, ra_dec_to_alt_az};
+/// use astro_math::transforms::ra_dec_to_alt_az_two_part;
+/// use astro_math::time::julian_date_two_part;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+/// let (jd1, jd2) = julian_date_two_part(dt);
+///
+/// let a = ra_dec_to_alt_az(279.23473479, 38.78368896, dt, &loc).unwrap();
+/// let b = ra_dec_to_alt_az_two_part(279.23473479, 38.78368896, jd1, jd2, &loc).unwrap();
+/// assert!((a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9);
+/// ```
+pub fn ra_dec_to_alt_az_two_part(
+    ra_deg: f64,
+    dec_deg: f64,
+    jd1: f64,
+    jd2: f64,
+    observer: &Location,
+) -> Result<(f64, f64)> {
+    validate_ra(ra_deg)?;
+    validate_dec(dec_deg)?;
+    let lst_hours = observer.local_sidereal_time_two_part(jd1, jd2);
+    let (sin_lat, cos_lat) = observer.latitude_deg.to_radians().sin_cos();
+    alt_az_from_lst(ra_deg, dec_deg, lst_hours, sin_lat, cos_lat)
+}
+
+/// Converts RA/Dec of date (JNow) to Alt/Az.
+///
+/// The same calculation as [`ra_dec_to_alt_az`], named for the epoch it
+/// expects: positions referred to the true equator and equinox of the date
+/// of observation, as reported by most mounts and planetarium programs in
+/// "JNow" mode. Use [`ra_dec_to_alt_az_j2000`] for catalog (J2000/ICRS)
+/// positions.
+///
+/// # Arguments
+///
+/// - `ra_jnow`: Right Ascension of date in degrees (0° to 360°)
+/// - `dec_jnow`: Declination of date in degrees (−90° to +90°)
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: Observer location
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg)` in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::{Location, ra_dec_to_alt_az};
+/// use astro_math::transforms::ra_dec_to_alt_az_of_date;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+/// let a = ra_dec_to_alt_az_of_date(279.5591, 38.8068, dt, &loc).unwrap();
+/// assert_eq!(a, ra_dec_to_alt_az(279.5591, 38.8068, dt, &loc).unwrap());
+/// ```
+pub fn ra_dec_to_alt_az_of_date(
+    ra_jnow: f64,
+    dec_jnow: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<(f64, f64)> {
+    ra_dec_to_alt_az(ra_jnow, dec_jnow, datetime, observer)
+}
+
+/// Converts J2000 RA/Dec to Alt/Az with the fast Meeus path.
+///
+/// [`ra_dec_to_alt_az`] measures the hour angle from apparent sidereal
+/// time, so it expects positions of date; a J2000 position fed to it
+/// directly is off by the precession since 2000 (about 0.35° by 2025).
+/// This wrapper first precesses the position to the mean equator and
+/// equinox of date (with the ICRS frame bias) and applies nutation, then
+/// converts it. Annual aberration (up to 20.5″) is not applied; use
+/// [`ra_dec_to_alt_az_erfa`] when that matters.
+///
+/// # Arguments
+///
+/// - `ra_j2000`: Right Ascension at J2000.0 (ICRS) in degrees (0° to 360°)
+/// - `dec_j2000`: Declination at J2000.0 (ICRS) in degrees (−90° to +90°)
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: Observer location
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg)` in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::{Location, ra_dec_to_alt_az_erfa};
+/// use astro_math::transforms::ra_dec_to_alt_az_j2000;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+/// // Vega from the catalog
+/// let (alt, az) = ra_dec_to_alt_az_j2000(279.23473479, 38.78368896, dt, &loc).unwrap();
+/// let (alt_full, az_full) = ra_dec_to_alt_az_erfa(279.23473479, 38.78368896, dt, &loc, None, None, None).unwrap();
+/// // Within the aberration left out
+/// assert!((alt - alt_full).abs() * 3600.0 < 30.0);
+/// ```
+pub fn ra_dec_to_alt_az_j2000(
+    ra_j2000: f64,
+    dec_j2000: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<(f64, f64)> {
+    let (ra_mean, dec_mean) = precess_from_j2000(ra_j2000, dec_j2000, datetime)?;
+    let (ra_true, dec_true) = apply_nutation(ra_mean, dec_mean, datetime)?;
+    ra_dec_to_alt_az(ra_true, dec_true, datetime, observer)
+}
+
+/// Converts RA/Dec to Alt/Az with the Meeus method and estimates its error.
+///
+/// Returns the same altitude and azimuth as [`ra_dec_to_alt_az`] together
+/// with an estimated 1-sigma on-sky error, treating the input as ICRS
+/// (J2000) catalog coordinates. The estimate is dominated by the
+/// precession, nutation and aberration this path leaves out, so it grows by
+/// about 50″ per year away from J2000; use
+/// [`ra_dec_to_alt_az_erfa_with_accuracy`] to compare. See
+/// [`accuracy`] for how the terms are combined.
+///
+/// # Arguments
+///
+/// - `ra_deg`: Right Ascension in degrees (0° to 360°)
+/// - `dec_deg`: Declination in degrees (−90° to +90°)
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: Observer location
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg, error_arcsec)`. The altitude is
+/// geometric (no refraction) and the error does not cover refraction.
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::Location;
+/// use astro_math::transforms::ra_dec_to_alt_az_with_accuracy;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+///
+/// let (_alt, _az, err) = ra_dec_to_alt_az_with_accuracy(279.23473479, 38.78368896, dt, &loc).unwrap();
+/// // A quarter century of precession since J2000
+/// assert!(err > 600.0);
+/// ```
+pub fn ra_dec_to_alt_az_with_accuracy(
+    ra_deg: f64,
+    dec_deg: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<(f64, f64, f64)> {
+    let (alt, az) = ra_dec_to_alt_az(ra_deg, dec_deg, datetime, observer)?;
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+    let error = accuracy::combine_errors(&[
+        accuracy::catalog_to_apparent_arcsec(ra_deg, dec_deg, tt1, tt2),
+        accuracy::diurnal_aberration_arcsec(observer.latitude_deg),
+        accuracy::earth_rotation_sigma_arcsec(dec_deg),
+        accuracy::terrestrial_sigma_arcsec(),
+    ]);
+    Ok((alt, az, error))
+}
+
+/// Converts RA/Dec to Alt/Az for a moving observer.
+///
+/// Looks up the observer's position at `datetime` and then behaves like
+/// [`ra_dec_to_alt_az`], so the local sidereal time and latitude follow the
+/// observer along the track.
+///
+/// # Arguments
+///
+/// - `ra_deg`: Right Ascension in degrees (0° to 360°)
+/// - `dec_deg`: Declination in degrees (−90° to +90°)
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: [`MovingObserver`] giving the position at `datetime`
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg)` in degrees
+///
+/// # Errors
+///
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if `datetime` is outside the observer's track
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use astro_math::{Location, ra_dec_to_alt_az};
+/// use astro_math::moving_observer::MovingObserver;
+/// use astro_math::transforms::ra_dec_to_alt_az_moving;
+///
+/// let t0 = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let start = Location { latitude_deg: 40.0, longitude_deg: -74.0, altitude_m: 10000.0 };
+/// let end = Location { latitude_deg: 50.0, longitude_deg: -30.0, altitude_m: 10000.0 };
+/// let flight = MovingObserver::from_track(vec![(t0, start), (t0 + Duration::hours(6), end)]).unwrap();
+///
+/// // At take-off the result matches the fixed-location transform
+/// let moving = ra_dec_to_alt_az_moving(279.2347, 38.7837, t0, &flight).unwrap();
+/// let fixed = ra_dec_to_alt_az(279.2347, 38.7837, t0, &start).unwrap();
+/// assert!((moving.0 - fixed.0).abs() < 1e-9);
+/// ```
+pub fn ra_dec_to_alt_az_moving(
+    ra_deg: f64,
+    dec_deg: f64,
+    datetime: DateTime<Utc>,
+    observer: &MovingObserver,
+) -> Result<(f64, f64)> {
+    ra_dec_to_alt_az(ra_deg, dec_deg, datetime, &observer.location_at(datetime)?)
+}
+
+/// Converts RA/Dec to Alt/Az with all angles in radians.
+///
+/// The radian-native counterpart of [`ra_dec_to_alt_az`] for callers whose
+/// data are already in radians (ERFA output, GPU buffers, vectorised
+/// pipelines). It runs the same spherical trigonometry without converting
+/// to degrees and back, and agrees with the degree function to rounding
+/// (about 1e-12 rad).
+///
+/// # Arguments
+///
+/// - `ra_rad`: Right Ascension of date in radians (0 to 2π)
+/// - `dec_rad`: Declination of date in radians (−π/2 to +π/2)
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: Observer location
+///
+/// # Returns
+///
+/// A tuple `(altitude_rad, azimuth_rad)`, with azimuth in [0, 2π) from north
+/// through east
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if `ra_rad` is outside
+/// [0, 2π) or `dec_rad` is outside [−π/2, π/2].
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::{Location, ra_dec_to_alt_az};
+/// use astro_math::transforms::ra_dec_to_alt_az_rad;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+///
+/// let (alt, az) = ra_dec_to_alt_az_rad(279.23473479_f64.to_radians(), 38.78368896_f64.to_radians(), dt, &loc).unwrap();
+/// let (alt_deg, az_deg) = ra_dec_to_alt_az(279.23473479, 38.78368896, dt, &loc).unwrap();
+/// assert!((alt.to_degrees() - alt_deg).abs() < 1e-9);
+/// assert!((az.to_degrees() - az_deg).abs() < 1e-9);
+/// ```
+pub fn ra_dec_to_alt_az_rad(
+    ra_rad: f64,
+    dec_rad: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<(f64, f64)> {
+    validate_ra_dec_rad(ra_rad, dec_rad)?;
+    let lst_rad = observer.local_sidereal_time(datetime) * HOURS_TO_RAD;
+    let (sin_lat, cos_lat) = observer.latitude_deg.to_radians().sin_cos();
+    alt_az_from_lst_rad(ra_rad, dec_rad, lst_rad, sin_lat, cos_lat)
+}
+
+/// Computes the parallactic angle of a target.
+///
+/// The parallactic angle q is the angle at the target between the
+/// direction to the celestial pole and the direction to the zenith,
+/// measured from north through east. Atmospheric dispersion spreads a
+/// star's light along the vertical, so a spectrograph slit or an ADC is
+/// aligned with it by rotating to q. It is zero on the meridian south of
+/// the zenith, 180° on the meridian north of it, negative east of the
+/// meridian and positive west of it.
+///
+/// # Arguments
+/// * `ra_deg` - Right Ascension of date in degrees
+/// * `dec_deg` - Declination of date in degrees
+/// * `datetime` - UTC datetime of observation
+/// * `observer` - Observer location
+///
+/// # Returns
+/// Parallactic angle in degrees, in (−180, 180]
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if `ra_deg` is outside
+/// [0, 360) or `dec_deg` is outside [−90, 90].
+///
+/// # Example
+/// ```
+/// use astro_math::Location;
+/// use astro_math::transforms::parallactic_angle;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: -24.63, longitude_deg: -70.4, altitude_m: 2635.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 12, 1, 3, 0, 0).unwrap();
+/// let lst_deg = site.local_sidereal_time(dt) * 15.0;
+///
+/// // From Chile the celestial equator passes north of the zenith, where the
+/// // pole and the zenith lie on opposite sides of the star: |q| is near 180°
+/// let east = parallactic_angle((lst_deg + 15.0).rem_euclid(360.0), 0.0, dt, &site).unwrap();
+/// let west = parallactic_angle((lst_deg - 15.0).rem_euclid(360.0), 0.0, dt, &site).unwrap();
+/// assert!((east + 150.6).abs() < 0.1);
+/// assert!((east + west).abs() < 1e-9);
+/// ```
+pub fn parallactic_angle(
+    ra_deg: f64,
+    dec_deg: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<f64> {
+    validate_ra(ra_deg)?;
+    validate_dec(dec_deg)?;
+    let ha_rad = (observer.local_sidereal_time(datetime) * 15.0 - ra_deg).to_radians();
+    let q = parallactic_angle_from_hour_angle(ha_rad, dec_deg.to_radians(), observer.latitude_deg.to_radians());
+    Ok(q.to_degrees())
+}
+
+/// Alt/Az of a target with their rates of change, for driving an alt-az mount.
+///
+/// All rates are in degrees per second of time; multiply by 3600 for
+/// arcseconds per second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AltAzRates {
+    /// Altitude in degrees
+    pub alt: f64,
+    /// Azimuth in degrees, from North through East
+    pub az: f64,
+    /// Rate of change of altitude
+    pub alt_rate: f64,
+    /// Rate of change of azimuth
+    pub az_rate: f64,
+    /// Parallactic angle in degrees, as from [`parallactic_angle`]
+    pub parallactic_angle: f64,
+    /// Rate of change of the parallactic angle: the field rotation rate that
+    /// an alt-az derotator has to follow, with the sign reversed
+    pub parallactic_angle_rate: f64,
+}
+
+/// Computes altitude and azimuth together with their time derivatives and
+/// the field rotation rate.
+///
+/// The rates are analytic: for a fixed RA/Dec of date only the hour angle
+/// H changes, at the Earth's rotation rate ω, and with latitude φ,
+/// altitude a and azimuth A
+///
+/// ```text
+/// da/dt = ω cos φ sin A
+/// dA/dt = ω (sin φ − cos φ cos A tan a)
+/// dq/dt = −ω cos φ cos A / cos a
+/// ```
+///
+/// The azimuth and parallactic angle rates grow as 1 / cos a near the
+/// zenith, which an alt-az mount cannot follow through; plan around a
+/// keyhole of a degree or so there. Refraction is not applied, and the
+/// target's own motion (a planet or satellite) is not included.
+///
+/// # Arguments
+/// * `ra_deg` - Right Ascension of date in degrees
+/// * `dec_deg` - Declination of date in degrees
+/// * `datetime` - UTC datetime of observation
+/// * `observer` - Observer location
+///
+/// # Returns
+/// An [`AltAzRates`] with the position in degrees and the rates in degrees
+/// per second
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if `ra_deg` is outside [0, 360) or
+///   `dec_deg` is outside [−90, 90]
+/// - `AstroError::CalculationError` if the target is at the zenith, where
+///   the azimuth rate is undefined
+///
+/// # Example
+/// ```
+/// use astro_math::{ra_dec_to_alt_az, Location};
+/// use astro_math::transforms::alt_az_rates;
+/// use chrono::{Duration, TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: 19.8207, longitude_deg: -155.468, altitude_m: 4205.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 8, 0, 0).unwrap();
+///
+/// let rates = alt_az_rates(279.2347, 38.7837, dt, &site).unwrap();
+/// // Agrees with the position a second later
+/// let (alt, az) = ra_dec_to_alt_az(279.2347, 38.7837, dt + Duration::seconds(1), &site).unwrap();
+/// assert!((alt - rates.alt - rates.alt_rate).abs() < 1e-6);
+/// assert!((az - rates.az - rates.az_rate).abs() < 1e-6);
+/// ```
+pub fn alt_az_rates(
+    ra_deg: f64,
+    dec_deg: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<AltAzRates> {
+    validate_ra(ra_deg)?;
+    validate_dec(dec_deg)?;
+    let ha_rad = (observer.local_sidereal_time(datetime) * 15.0 - ra_deg).to_radians();
     let dec_rad = dec_deg.to_radians();
     let lat_rad = observer.latitude_deg.to_radians();
+    let (sin_lat, cos_lat) = lat_rad.sin_cos();
 
+    let (alt_rad, az_rad) = horizontal_from_hour_angle(ha_rad, dec_rad, sin_lat, cos_lat);
+    let (alt, az) = sanitize_alt_az_result(alt_rad.to_degrees(), az_rad.to_degrees())?;
+    let cos_alt = alt_rad.cos();
+    if cos_alt < 1e-12 {
+        return Err(AstroError::CalculationError {
+            calculation: "alt/az rates",
+            reason: "target is at the zenith, where the azimuth rate is undefined".to_string(),
+        });
+    }
+    let (sin_az, cos_az) = az_rad.sin_cos();
+
+    Ok(AltAzRates {
+        alt,
+        az,
+        alt_rate: HOUR_ANGLE_RATE_DEG_S * cos_lat * sin_az,
+        az_rate: HOUR_ANGLE_RATE_DEG_S * (sin_lat - cos_lat * cos_az * alt_rad.tan()),
+        parallactic_angle: parallactic_angle_from_hour_angle(ha_rad, dec_rad, lat_rad).to_degrees(),
+        parallactic_angle_rate: -HOUR_ANGLE_RATE_DEG_S * cos_lat * cos_az / cos_alt,
+    })
+}
+
+/// Parallactic angle in radians from the hour angle, declination and
+/// latitude in radians (Meeus 14.1).
+pub(crate) fn parallactic_angle_from_hour_angle(ha_rad: f64, dec_rad: f64, lat_rad: f64) -> f64 {
+    let (sin_dec, cos_dec) = dec_rad.sin_cos();
+    let (sin_ha, cos_ha) = ha_rad.sin_cos();
+    sin_ha.atan2(lat_rad.tan() * cos_dec - sin_dec * cos_ha)
+}
+
+/// Alt/Az from validated RA/Dec, local apparent sidereal time in hours and
+/// the sine and cosine of the observer's latitude.
+pub(crate) fn alt_az_from_lst(
+    ra_deg: f64,
+    dec_deg: f64,
+    lst_hours: f64,
+    sin_lat: f64,
+    cos_lat: f64,
+) -> Result<(f64, f64)> {
     // Compute hour angle (in hours → degrees → radians)
-    let lst_hours = observer.local_sidereal_time(datetime);
     let ha_hours = lst_hours - ra_deg / 15.0; // signed!
+    trace_event!(debug, lst_hours, ha_hours, "hour angle from local sidereal time");
     let ha_rad = (ha_hours * 15.0).to_radians();
 
+    let (alt_rad, az_rad) = horizontal_from_hour_angle(ha_rad, dec_deg.to_radians(), sin_lat, cos_lat);
+    sanitize_alt_az_result(alt_rad.to_degrees(), az_rad.to_degrees())
+}
+
+/// Alt/Az in radians from validated RA/Dec in radians, local apparent
+/// sidereal time in radians and the sine and cosine of the observer's
+/// latitude.
+pub(crate) fn alt_az_from_lst_rad(
+    ra_rad: f64,
+    dec_rad: f64,
+    lst_rad: f64,
+    sin_lat: f64,
+    cos_lat: f64,
+) -> Result<(f64, f64)> {
+    let (alt_rad, az_rad) = horizontal_from_hour_angle(lst_rad - ra_rad, dec_rad, sin_lat, cos_lat);
+    sanitize_alt_az_result_rad(alt_rad, az_rad)
+}
+
+/// Altitude and azimuth in radians, azimuth in [0, 2π], from a signed hour
+/// angle and declination in radians (Meeus).
+#[inline]
+fn horizontal_from_hour_angle(ha_rad: f64, dec_rad: f64, sin_lat: f64, cos_lat: f64) -> (f64, f64) {
+    let (sin_dec, cos_dec) = dec_rad.sin_cos();
+    let (sin_ha, cos_ha) = ha_rad.sin_cos();
+
     // Altitude (Meeus formula)
-    let sin_alt = dec_rad.sin() * lat_rad.sin() + dec_rad.cos() * lat_rad.cos() * ha_rad.cos();
-    let alt_rad = sin_alt.asin();
+    let sin_alt = sin_dec * sin_lat + cos_dec * cos_lat * cos_ha;
+    let alt_rad = sin_alt.clamp(-1.0, 1.0).asin();
 
-    // Azimuth calculation with improved numerical stability
-    let alt_deg = alt_rad.to_degrees();
-    
     // Handle edge cases for azimuth calculation
-    let denominator = alt_rad.cos() * lat_rad.cos();
-    
-    let az_deg = if denominator.abs() < 1e-10 {
+    let denominator = alt_rad.cos() * cos_lat;
+
+    let az_rad = if denominator.abs() < 1e-10 {
         // At zenith or for polar observers, azimuth is undefined
         // Use hour angle to determine a reasonable azimuth
-        if ha_rad.sin() > 0.0 {
-            180.0 // West
+        if sin_ha > 0.0 {
+            PI // West
         } else {
-            0.0   // East (or on meridian)
+            0.0 // East (or on meridian)
         }
     } else {
         // Standard azimuth calculation
-        let numerator = dec_rad.sin() - alt_rad.sin() * lat_rad.sin();
+        let numerator = sin_dec - alt_rad.sin() * sin_lat;
         let cos_az = numerator / denominator;
-        
+
         // Clamp cos_az to [-1, 1] to handle numerical errors
-        let cos_az_clamped = cos_az.clamp(-1.0, 1.0);
-        let mut az_rad = cos_az_clamped.acos();
-        
+        let az_rad = cos_az.clamp(-1.0, 1.0).acos();
+
         // Flip azimuth if hour angle is positive (west of meridian)
-        if ha_rad.sin() > 0.0 {
-            az_rad = 2.0 * PI - az_rad;
-        }
-        
-        let mut az = az_rad.to_degrees();
-        if az < 0.0 {
-            az += 360.0;
+        if sin_ha > 0.0 {
+            2.0 * PI - az_rad
+        } else {
+            az_rad
         }
-        az
     };
-
-    sanitize_alt_az_result(alt_deg, az_deg)
+    (alt_rad, az_rad)
 }
 
 /// Converts ICRS equatorial coordinates to horizontal coordinates using ERFA.
@@ -206,101 +796,460 @@ pub fn ra_dec_to_alt_az(
 /// - `temperature_c`: Temperature in Celsius (default ~15°C)
 /// - `humidity`: Relative humidity 0-1 (default 0.5)
 ///
+/// With all three weather arguments `None`, the [default](crate::config)
+/// weather is used: no refraction unless one has been set.
+///
 /// # Returns
 ///
 /// A tuple `(altitude_deg, azimuth_deg)` in degrees
 ///
 /// # Note
 ///
-/// This function includes:
-/// - Frame bias and precession-nutation (IAU 2006)
-/// - Earth rotation and polar motion
-/// - Annual and diurnal aberration
-/// - Atmospheric refraction (if pressure > 0)
-pub fn ra_dec_to_alt_az_erfa(
+/// This function includes:
+/// - Frame bias and precession-nutation (IAU 2006)
+/// - Earth rotation and polar motion
+/// - Annual and diurnal aberration
+/// - Atmospheric refraction (if pressure > 0)
+///
+/// UT1 is taken equal to UTC and polar motion as zero, good to a few
+/// arcseconds; [`ra_dec_to_alt_az_erfa_with_eop`] applies measured values.
+pub fn ra_dec_to_alt_az_erfa(
+    ra_icrs: f64,
+    dec_icrs: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+    pressure_hpa: Option<f64>,
+    temperature_c: Option<f64>,
+    humidity: Option<f64>,
+) -> Result<(f64, f64)> {
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    ra_dec_to_alt_az_erfa_two_part(ra_icrs, dec_icrs, jd1, jd2, observer, pressure_hpa, temperature_c, humidity)
+}
+
+/// Converts ICRS coordinates to horizontal coordinates using ERFA, for a time
+/// given as a two-part UTC Julian Date.
+///
+/// Identical to [`ra_dec_to_alt_az_erfa`] but takes the observation time as a
+/// split Julian Date, which is passed straight through to ERFA.
+///
+/// # Arguments
+///
+/// - `ra_icrs`, `dec_icrs`: ICRS coordinates in degrees
+/// - `jd1`, `jd2`: UTC Julian Date split into two parts
+/// - `observer`, `pressure_hpa`, `temperature_c`, `humidity`: as for [`ra_dec_to_alt_az_erfa`]
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg)` in degrees
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(observer)))]
+pub fn ra_dec_to_alt_az_erfa_two_part(
+    ra_icrs: f64,
+    dec_icrs: f64,
+    jd1: f64,
+    jd2: f64,
+    observer: &Location,
+    pressure_hpa: Option<f64>,
+    temperature_c: Option<f64>,
+    humidity: Option<f64>,
+) -> Result<(f64, f64)> {
+    erfa_alt_az(ra_icrs, dec_icrs, jd1, jd2, observer, pressure_hpa, temperature_c, humidity, &EarthOrientation::default())
+        .map(|(alt, az, _)| (alt, az))
+}
+
+/// Converts ICRS coordinates to horizontal coordinates using ERFA, with
+/// measured Earth orientation parameters.
+///
+/// [`ra_dec_to_alt_az_erfa`] takes UT1 = UTC, no polar motion and the
+/// IAU 2006/2000A pole, which limits it to a few arcseconds. This applies
+/// DUT1 and polar motion from `eop` through ERFA, and the celestial pole
+/// offsets dX and dY when present, for sub-arcsecond pointing. Load the
+/// values from IERS data with [`EopTable`](crate::eop::EopTable).
+///
+/// # Arguments
+///
+/// - `ra_icrs`, `dec_icrs`: ICRS coordinates in degrees
+/// - `datetime`: UTC datetime of observation
+/// - `observer`, `pressure_hpa`, `temperature_c`, `humidity`: as for [`ra_dec_to_alt_az_erfa`]
+/// - `eop`: Earth orientation parameters for `datetime`
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg)` in degrees
+///
+/// # Errors
+///
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if an Earth orientation value is out of range
+///   (see [`EarthOrientation::validate`])
+///
+/// # Example
+///
+/// ```
+/// use astro_math::eop::EarthOrientation;
+/// use astro_math::transforms::{ra_dec_to_alt_az_erfa, ra_dec_to_alt_az_erfa_with_eop};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 6, 0, 0).unwrap();
+/// let loc = Location { latitude_deg: 19.8, longitude_deg: -155.5, altitude_m: 4200.0 };
+/// let eop = EarthOrientation { dut1: 0.0116, xp_arcsec: 0.0749, yp_arcsec: 0.2171, dx_mas: Some(0.27), dy_mas: Some(-0.06) };
+///
+/// let (alt, az) = ra_dec_to_alt_az_erfa_with_eop(279.2347, 38.7837, dt, &loc, None, None, None, &eop).unwrap();
+/// let (alt0, az0) = ra_dec_to_alt_az_erfa(279.2347, 38.7837, dt, &loc, None, None, None).unwrap();
+/// // 11.6 ms of Earth rotation is up to 0.17″
+/// let shift = ((alt - alt0).powi(2) + ((az - az0) * alt.to_radians().cos()).powi(2)).sqrt() * 3600.0;
+/// assert!(shift > 0.01 && shift < 0.5);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn ra_dec_to_alt_az_erfa_with_eop(
+    ra_icrs: f64,
+    dec_icrs: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+    pressure_hpa: Option<f64>,
+    temperature_c: Option<f64>,
+    humidity: Option<f64>,
+    eop: &EarthOrientation,
+) -> Result<(f64, f64)> {
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    erfa_alt_az(ra_icrs, dec_icrs, jd1, jd2, observer, pressure_hpa, temperature_c, humidity, eop)
+        .map(|(alt, az, _)| (alt, az))
+}
+
+/// Converts ICRS coordinates to horizontal coordinates using ERFA, with
+/// the provenance of the result.
+///
+/// The same calculation as [`ra_dec_to_alt_az_erfa_with_eop`], returning a
+/// [`Provenance`] that records the models and corrections applied: the
+/// precession-nutation model, refraction and the weather it was computed
+/// for, the source of the Earth orientation parameters and the leap-second
+/// table. If ERFA rejects the inputs and the Meeus transformation is used
+/// instead, the provenance says so.
+///
+/// # Arguments
+///
+/// - `ra_icrs`, `dec_icrs`: ICRS coordinates in degrees
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: Observer location
+/// - `conditions`: Weather for refraction; `None` for the [default](crate::config)
+///   weather, no refraction unless set
+/// - `eop`: Earth orientation parameters and where they came from, e.g.
+///   [`EopSource::from_table`]; `None` for UT1 = UTC and no polar motion
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg, provenance)`
+///
+/// # Errors
+///
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if a weather or Earth orientation value is out
+///   of range
+///
+/// # Example
+///
+/// ```
+/// use astro_math::eop::EarthOrientation;
+/// use astro_math::provenance::{Correction, EopSource};
+/// use astro_math::transforms::{ra_dec_to_alt_az_erfa_with_eop, ra_dec_to_alt_az_erfa_with_provenance};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 6, 0, 0).unwrap();
+/// let loc = Location { latitude_deg: 19.8, longitude_deg: -155.5, altitude_m: 4200.0 };
+/// let eop = EarthOrientation { dut1: 0.0116, xp_arcsec: 0.0749, yp_arcsec: 0.2171, dx_mas: None, dy_mas: None };
+///
+/// let (alt, az, provenance) =
+///     ra_dec_to_alt_az_erfa_with_provenance(279.2347, 38.7837, dt, &loc, None, Some(EopSource::Supplied(eop))).unwrap();
+/// assert_eq!((alt, az), ra_dec_to_alt_az_erfa_with_eop(279.2347, 38.7837, dt, &loc, None, None, None, &eop).unwrap());
+/// assert_eq!(provenance.method, "ERFA Atco13");
+/// assert!(provenance.applied(Correction::PolarMotion));
+/// assert!(!provenance.applied(Correction::CelestialPoleOffsets));
+/// assert_eq!(provenance.eop.unwrap().values().dut1, 0.0116);
+/// ```
+pub fn ra_dec_to_alt_az_erfa_with_provenance(
+    ra_icrs: f64,
+    dec_icrs: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+    conditions: Option<AtmosphericConditions>,
+    eop: Option<EopSource>,
+) -> Result<(f64, f64, Provenance)> {
+    let conditions = crate::config::conditions_or_default(conditions);
+    if let Some(conditions) = &conditions {
+        conditions.validate()?;
+    }
+    let eop = eop.unwrap_or(EopSource::Zero);
+    let values = eop.values();
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (alt, az, used_erfa) = erfa_alt_az(
+        ra_icrs,
+        dec_icrs,
+        jd1,
+        jd2,
+        observer,
+        conditions.map(|c| c.pressure_hpa),
+        conditions.map(|c| c.temperature_c),
+        conditions.map(|c| c.relative_humidity),
+        &values,
+    )?;
+
+    let provenance = if used_erfa {
+        let mut corrections = vec![
+            Correction::FrameBias,
+            Correction::Precession,
+            Correction::Nutation,
+            Correction::LightDeflection,
+            Correction::AnnualAberration,
+            Correction::DiurnalAberration,
+            Correction::EarthRotation,
+        ];
+        if eop != EopSource::Zero {
+            corrections.push(Correction::PolarMotion);
+        }
+        if values.dx_mas.is_some() && values.dy_mas.is_some() {
+            corrections.push(Correction::CelestialPoleOffsets);
+        }
+        let refraction = conditions.filter(|c| c.pressure_hpa > 0.0).map(|conditions| RefractionApplied {
+            model: "ERFA Refco (A tan z + B tan^3 z)",
+            conditions,
+            wavelength_um: ERFA_WAVELENGTH_UM,
+        });
+        if refraction.is_some() {
+            corrections.push(Correction::Refraction);
+        }
+        Provenance::new(datetime, "ERFA Atco13", corrections, refraction, Some(eop))
+    } else {
+        Provenance::new(
+            datetime,
+            "Meeus spherical trigonometry (ERFA fallback)",
+            vec![Correction::EarthRotation],
+            None,
+            Some(EopSource::Zero),
+        )
+    };
+    Ok((alt, az, provenance))
+}
+
+/// Observed altitude and azimuth from ERFA for the given Earth orientation,
+/// shared by the `_erfa` transforms, and whether ERFA produced them rather
+/// than the Meeus fallback.
+#[allow(clippy::too_many_arguments)]
+fn erfa_alt_az(
+    ra_icrs: f64,
+    dec_icrs: f64,
+    jd1: f64,
+    jd2: f64,
+    observer: &Location,
+    pressure_hpa: Option<f64>,
+    temperature_c: Option<f64>,
+    humidity: Option<f64>,
+    eop: &EarthOrientation,
+) -> Result<(f64, f64, bool)> {
+    // Validate inputs
+    validate_ra(ra_icrs)?;
+    validate_dec(dec_icrs)?;
+    eop.validate()?;
+    
+    // Convert to radians
+    let mut ra_rad = ra_icrs.to_radians();
+    let mut dec_rad = dec_icrs.to_radians();
+    if let (Some(dx), Some(dy)) = (eop.dx_mas, eop.dy_mas) {
+        (ra_rad, dec_rad) = apply_pole_offsets(ra_rad, dec_rad, mas_to_rad(dx), mas_to_rad(dy));
+    }
+    
+    // Observer location in radians
+    let elong = observer.longitude_deg.to_radians();
+    let phi = observer.latitude_deg.to_radians();
+    let hm = observer.altitude_m;
+    
+    // Atmospheric parameters (use AstroPy defaults: no refraction, unless configured)
+    let (pressure_hpa, temperature_c, humidity) = crate::config::weather_or_default(pressure_hpa, temperature_c, humidity);
+    let phpa = pressure_hpa.unwrap_or(0.0);  // AstroPy default: no refraction
+    let tc = temperature_c.unwrap_or(0.0);   // AstroPy default
+    let rh = humidity.unwrap_or(0.0);        // AstroPy default
+    let wl = ERFA_WAVELENGTH_UM;
+    
+    // Set proper motion, parallax, radial velocity to zero for stars
+    let pr = 0.0;  // proper motion in RA (rad/year)
+    let pd = 0.0;  // proper motion in Dec (rad/year)
+    let px = 0.0;  // parallax (arcsec)
+    let rv = 0.0;  // radial velocity (km/s)
+    
+    // Earth orientation parameters
+    let dut1 = eop.dut1;  // UT1-UTC in seconds
+    let xp = mas_to_rad(eop.xp_arcsec * 1000.0);  // polar motion x (radians)
+    let yp = mas_to_rad(eop.yp_arcsec * 1000.0);  // polar motion y (radians)
+    
+    // Call ERFA Atco13 for ICRS to observed transformation
+    match crate::backend::astrometry::Atco13(
+        ra_rad, dec_rad, pr, pd, px, rv,
+        jd1, jd2, dut1, elong, phi, hm,
+        xp, yp, phpa, tc, rh, wl,
+    ) {
+        Ok((aob, zob, _hob, _dob, _rob, _eo)) => {
+            trace_event!(debug, hour_angle_deg = _hob.to_degrees(), "ERFA observed hour angle");
+            let (alt, az) = alt_az_from_observed(aob, zob)?;
+            Ok((alt, az, true))
+        }
+        Err(_) => {
+            // Fall back to the original method if ERFA fails
+            trace_event!(warn, "ERFA Atco13 failed, falling back to the Meeus transformation");
+            let (alt, az) = ra_dec_to_alt_az_two_part(ra_icrs, dec_icrs, jd1, jd2, observer)?;
+            Ok((alt, az, false))
+        }
+    }
+}
+
+/// Altitude and azimuth in degrees from ERFA's observed azimuth `aob`
+/// (N = 0, E = 90°) and zenith distance `zob` in radians.
+pub(crate) fn alt_az_from_observed(aob: f64, zob: f64) -> Result<(f64, f64)> {
+    // Convert zenith distance to altitude
+    let alt_deg = (PI / 2.0 - zob).to_degrees();
+
+    // Convert azimuth to degrees and normalize
+    let mut az_deg = aob.to_degrees();
+    if az_deg < 0.0 {
+        az_deg += 360.0;
+    } else if az_deg >= 360.0 {
+        az_deg -= 360.0;
+    }
+    sanitize_alt_az_result(alt_deg, az_deg)
+}
+
+/// Wavelength for refraction in the `_erfa` transforms, in micrometers
+/// (AstroPy's default)
+pub(crate) const ERFA_WAVELENGTH_UM: f64 = 1.0;
+
+pub(crate) fn mas_to_rad(mas: f64) -> f64 {
+    (mas / 3_600_000.0).to_radians()
+}
+
+/// Shifts an ICRS direction by the celestial pole offsets dX and dY, to
+/// first order: the same change the offsets make to the celestial-to-
+/// intermediate matrix, applied before it.
+pub(crate) fn apply_pole_offsets(ra_rad: f64, dec_rad: f64, dx_rad: f64, dy_rad: f64) -> (f64, f64) {
+    let (sin_dec, cos_dec) = dec_rad.sin_cos();
+    let (sin_ra, cos_ra) = ra_rad.sin_cos();
+    let (x, y, z) = (cos_dec * cos_ra, cos_dec * sin_ra, sin_dec);
+    let (x, y, z) = (x - dx_rad * z, y - dy_rad * z, z + dx_rad * x + dy_rad * y);
+    (y.atan2(x).rem_euclid(TAU), (z / (x * x + y * y + z * z).sqrt()).asin())
+}
+
+/// Converts ICRS coordinates to horizontal coordinates using ERFA and
+/// estimates the error.
+///
+/// Returns the same altitude and azimuth as [`ra_dec_to_alt_az_erfa`]
+/// together with an estimated 1-sigma on-sky error. The full IAU 2006/2000A
+/// model is used, so the error comes from the Earth orientation parameters
+/// this function sets to zero (DUT1 and polar motion) and, when refraction
+/// is enabled, from the refraction model. See [`accuracy`]
+/// for how the terms are combined.
+///
+/// # Arguments
+///
+/// Same as [`ra_dec_to_alt_az_erfa`]
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg, error_arcsec)`
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::Location;
+/// use astro_math::transforms::ra_dec_to_alt_az_erfa_with_accuracy;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+/// let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+///
+/// let (_alt, _az, err) =
+///     ra_dec_to_alt_az_erfa_with_accuracy(279.23473479, 38.78368896, dt, &loc, None, None, None).unwrap();
+/// // Dominated by the unknown UT1-UTC
+/// assert!(err > 1.0 && err < 10.0);
+/// ```
+pub fn ra_dec_to_alt_az_erfa_with_accuracy(
+    ra_icrs: f64,
+    dec_icrs: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+    pressure_hpa: Option<f64>,
+    temperature_c: Option<f64>,
+    humidity: Option<f64>,
+) -> Result<(f64, f64, f64)> {
+    let (pressure_hpa, temperature_c, humidity) = crate::config::weather_or_default(pressure_hpa, temperature_c, humidity);
+    let (alt, az) =
+        ra_dec_to_alt_az_erfa(ra_icrs, dec_icrs, datetime, observer, pressure_hpa, temperature_c, humidity)?;
+
+    let pressure = pressure_hpa.unwrap_or(0.0);
+    let refraction_arcsec = if pressure > 0.0 {
+        refraction_saemundsson(alt, pressure, temperature_c.unwrap_or(0.0))? * 3600.0
+    } else {
+        0.0
+    };
+    let error = accuracy::combine_errors(&[
+        accuracy::earth_rotation_sigma_arcsec(dec_icrs),
+        accuracy::terrestrial_sigma_arcsec(),
+        accuracy::REFRACTION_SIGMA_FRACTION * refraction_arcsec,
+    ]);
+    Ok((alt, az, error))
+}
+
+/// Converts ICRS coordinates to horizontal coordinates using ERFA, for a
+/// moving observer.
+///
+/// Looks up the observer's position (including its height) at `datetime`
+/// and then behaves like [`ra_dec_to_alt_az_erfa`].
+///
+/// # Arguments
+///
+/// - `ra_icrs`, `dec_icrs`: ICRS coordinates in degrees
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: [`MovingObserver`] giving the position at `datetime`
+/// - `pressure_hpa`, `temperature_c`, `humidity`: as for [`ra_dec_to_alt_az_erfa`]
+///
+/// # Returns
+///
+/// A tuple `(altitude_deg, azimuth_deg)` in degrees
+///
+/// # Errors
+///
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if `datetime` is outside the observer's track
+pub fn ra_dec_to_alt_az_erfa_moving(
     ra_icrs: f64,
     dec_icrs: f64,
     datetime: DateTime<Utc>,
-    observer: &Location,
+    observer: &MovingObserver,
     pressure_hpa: Option<f64>,
     temperature_c: Option<f64>,
     humidity: Option<f64>,
 ) -> Result<(f64, f64)> {
-    // Validate inputs
-    validate_ra(ra_icrs)?;
-    validate_dec(dec_icrs)?;
-    
-    // Convert to radians
-    let ra_rad = ra_icrs.to_radians();
-    let dec_rad = dec_icrs.to_radians();
-    
-    // Get Julian Date
-    let jd_utc = julian_date(datetime);
-    
-    // Observer location in radians
-    let elong = observer.longitude_deg.to_radians();
-    let phi = observer.latitude_deg.to_radians();
-    let hm = observer.altitude_m;
-    
-    // Atmospheric parameters (use AstroPy defaults: no refraction)
-    let phpa = pressure_hpa.unwrap_or(0.0);  // AstroPy default: no refraction
-    let tc = temperature_c.unwrap_or(0.0);   // AstroPy default
-    let rh = humidity.unwrap_or(0.0);        // AstroPy default
-    let wl = 1.0;  // AstroPy default: 1.0 micron
-    
-    // Set proper motion, parallax, radial velocity to zero for stars
-    let pr = 0.0;  // proper motion in RA (rad/year)
-    let pd = 0.0;  // proper motion in Dec (rad/year)
-    let px = 0.0;  // parallax (arcsec)
-    let rv = 0.0;  // radial velocity (km/s)
-    
-    // Earth orientation parameters (using defaults for now)
-    let dut1 = 0.0;  // UT1-UTC in seconds
-    let xp = 0.0;    // polar motion x (radians)
-    let yp = 0.0;    // polar motion y (radians)
-    
-    // Call ERFA Atco13 for ICRS to observed transformation
-    match erfars::astrometry::Atco13(
-        ra_rad, dec_rad, pr, pd, px, rv,
-        jd_utc, 0.0, dut1, elong, phi, hm,
-        xp, yp, phpa, tc, rh, wl,
-    ) {
-        Ok((aob, zob, _hob, _dob, _rob, _eo)) => {
-            // aob = azimuth (radians, N=0, E=90)
-            // zob = zenith distance (radians)
-            
-            // Convert zenith distance to altitude
-            let alt_rad = PI / 2.0 - zob;
-            let alt_deg = alt_rad.to_degrees();
-            
-            // Convert azimuth to degrees and normalize
-            let mut az_deg = aob.to_degrees();
-            if az_deg < 0.0 {
-                az_deg += 360.0;
-            } else if az_deg >= 360.0 {
-                az_deg -= 360.0;
-            }
-            
-            sanitize_alt_az_result(alt_deg, az_deg)
-        }
-        Err(_) => {
-            // Fall back to the original method if ERFA fails
-            ra_dec_to_alt_az(ra_icrs, dec_icrs, datetime, observer)
-        }
-    }
+    let location = observer.location_at(datetime)?;
+    ra_dec_to_alt_az_erfa(ra_icrs, dec_icrs, datetime, &location, pressure_hpa, temperature_c, humidity)
 }
 
 /// Parallel batch conversion of equatorial coordinates to horizontal coordinates using ERFA.
 ///
 /// This function processes multiple coordinate pairs in parallel using Rayon for maximum performance.
-/// It's optimized for processing large datasets (thousands to millions of coordinates).
+/// It's optimized for processing large datasets (thousands to millions of coordinates): the
+/// star-independent part of the reduction is computed once in an
+/// [`AstrometricContext`], with the same results as
+/// [`ra_dec_to_alt_az_erfa`] per star.
 ///
 /// # Arguments
 ///
 /// - `ra_dec_pairs`: Slice of (RA, Dec) coordinate pairs in degrees
 /// - `datetime`: UTC datetime of observation
 /// - `observer`: Observer location
-/// - `pressure_hpa`: Atmospheric pressure in hPa (default 0 = no refraction, matching AstroPy)
+/// - `pressure_hpa`: Atmospheric pressure in hPa (default 0 = no refraction, matching AstroPy,
+///   unless [configured](crate::config))
 /// - `temperature_c`: Temperature in Celsius (default 0°C)
 /// - `humidity`: Relative humidity 0-1 (default 0.0)
 ///
@@ -311,7 +1260,7 @@ pub fn ra_dec_to_alt_az_erfa(
 /// # Performance
 ///
 /// This function uses Rayon for parallel processing and can achieve:
-/// - Single-threaded: ~1000-5000 coords/sec (depending on hardware)
+/// - Single-threaded: ~1-2 million coords/sec (depending on hardware)
 /// - Multi-threaded: Scales with CPU cores (e.g., 8-core = ~8x faster)
 ///
 /// # Example
@@ -331,6 +1280,7 @@ pub fn ra_dec_to_alt_az_erfa(
 /// let results = ra_dec_to_alt_az_batch_parallel(&coords, dt, &loc, None, None, None).unwrap();
 /// assert_eq!(results.len(), 3);
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(count = ra_dec_pairs.len())))]
 pub fn ra_dec_to_alt_az_batch_parallel(
     ra_dec_pairs: &[(f64, f64)],
     datetime: DateTime<Utc>,
@@ -339,11 +1289,167 @@ pub fn ra_dec_to_alt_az_batch_parallel(
     temperature_c: Option<f64>,
     humidity: Option<f64>,
 ) -> Result<Vec<(f64, f64)>> {
-    // Process coordinates in parallel using Rayon
+    // Resolved here, as the Rayon workers do not see `config::with_defaults`
+    let (pressure_hpa, temperature_c, humidity) = crate::config::weather_or_default(pressure_hpa, temperature_c, humidity);
+    let weather = (pressure_hpa.unwrap_or(0.0), temperature_c.unwrap_or(0.0), humidity.unwrap_or(0.0));
+    match AstrometricContext::build(datetime, observer, weather, &EarthOrientation::default()) {
+        Ok(context) => context.observed_from_icrs_many(ra_dec_pairs),
+        // A date ERFA rejects: per star, with the Meeus fallback
+        Err(_) => ra_dec_pairs
+            .par_iter()
+            .map(|&(ra, dec)| {
+                ra_dec_to_alt_az_erfa(ra, dec, datetime, observer, pressure_hpa, temperature_c, humidity)
+            })
+            .collect(),
+    }
+}
+
+/// Converts one ICRS position to horizontal coordinates at many sites.
+///
+/// For occultation paths and station networks, where the same target at
+/// the same time is needed from hundreds of locations. The result for each
+/// site matches [`ra_dec_to_alt_az_erfa`] without refraction, but the
+/// site-independent part of the reduction (frame bias, precession-nutation,
+/// light deflection and annual aberration, ERFA `Atci13`) is done once and
+/// only the Earth rotation, polar motion and diurnal aberration (`Atio13`)
+/// per site, in parallel. The diurnal aberration is applied in ERFA's
+/// separate approximation rather than folded into the annual term, which
+/// moves the result by well under a milliarcsecond.
+///
+/// # Arguments
+///
+/// - `ra_icrs`, `dec_icrs`: ICRS coordinates in degrees
+/// - `datetime`: UTC datetime of observation
+/// - `sites`: Observer locations
+///
+/// # Returns
+///
+/// One `(altitude_deg, azimuth_deg)` tuple per site, in input order
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::{ra_dec_to_alt_az_erfa, Location};
+/// use astro_math::transforms::alt_az_for_sites;
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 3, 10, 4, 0, 0).unwrap();
+/// // Stations along an occultation path
+/// let sites: Vec<_> = (0..100)
+///     .map(|i| Location { latitude_deg: 30.0 + 0.05 * i as f64, longitude_deg: -110.0 + 0.1 * i as f64, altitude_m: 1500.0 })
+///     .collect();
+///
+/// let alt_az = alt_az_for_sites(84.0533, 21.1425, dt, &sites).unwrap();
+/// let (alt, az) = ra_dec_to_alt_az_erfa(84.0533, 21.1425, dt, &sites[42], None, None, None).unwrap();
+/// assert!((alt_az[42].0 - alt).abs() < 1e-6 && (alt_az[42].1 - az).abs() < 1e-6);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(count = sites.len())))]
+pub fn alt_az_for_sites(
+    ra_icrs: f64,
+    dec_icrs: f64,
+    datetime: DateTime<Utc>,
+    sites: &[Location],
+) -> Result<Vec<(f64, f64)>> {
+    validate_ra(ra_icrs)?;
+    validate_dec(dec_icrs)?;
+
+    // ICRS to CIRS depends only on the time
+    let (jd1, jd2) = julian_date_two_part(datetime);
+    let (tt1, tt2) = utc_to_tt_jd_two_part(jd1, jd2);
+    let (ri, di, _) =
+        crate::backend::astrometry::Atci13(ra_icrs.to_radians(), dec_icrs.to_radians(), 0.0, 0.0, 0.0, 0.0, tt1, tt2);
+
+    sites
+        .par_iter()
+        .map(|site| {
+            match crate::backend::astrometry::Atio13(
+                ri, di, jd1, jd2, 0.0,
+                site.longitude_deg.to_radians(), site.latitude_deg.to_radians(), site.altitude_m,
+                0.0, 0.0, 0.0, 0.0, 0.0, ERFA_WAVELENGTH_UM,
+            ) {
+                Ok((aob, zob, _, _, _)) => alt_az_from_observed(aob, zob),
+                // As for the other `_erfa` transforms
+                Err(_) => ra_dec_to_alt_az_two_part(ra_icrs, dec_icrs, jd1, jd2, site),
+            }
+        })
+        .collect()
+}
+
+/// Parallel above-horizon test for large catalogs.
+///
+/// Flags which objects are at or above `min_alt_deg`, as a cheap pre-filter
+/// before per-object work such as [`ra_dec_to_alt_az_batch_parallel`]. The
+/// sidereal time and site trigonometry are computed once, and each object
+/// then costs one comparison of
+///
+/// ```text
+/// sin(Dec)·sin(Lat) + cos(Dec)·cos(Lat)·cos(HA) ≥ sin(min_alt)
+/// ```
+///
+/// with no inverse trigonometry or azimuth. The geometry is that of
+/// [`ra_dec_to_alt_az`]: no refraction, so lower `min_alt_deg` by about
+/// half a degree to keep objects that refraction lifts over the horizon.
+///
+/// # Arguments
+///
+/// - `ra_dec_pairs`: Slice of (RA, Dec) coordinate pairs in degrees
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: Observer location
+/// - `min_alt_deg`: Lowest altitude that counts as visible, in degrees
+///
+/// # Returns
+///
+/// One flag per object, in input order: `true` if its altitude is at least
+/// `min_alt_deg`
+///
+/// # Errors
+///
+/// - `AstroError::InvalidCoordinate` if any RA or Dec is out of range
+/// - `AstroError::OutOfRange` if `min_alt_deg` is outside [-90, 90]
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Utc, TimeZone};
+/// use astro_math::Location;
+/// use astro_math::transforms::visible_mask;
+///
+/// let loc = Location { latitude_deg: 52.0, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// // Polaris, and a star near the south celestial pole
+/// let mask = visible_mask(&[(37.95, 89.26), (0.0, -80.0)], dt, &loc, 20.0).unwrap();
+/// assert_eq!(mask, vec![true, false]);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(count = ra_dec_pairs.len())))]
+pub fn visible_mask(
+    ra_dec_pairs: &[(f64, f64)],
+    datetime: DateTime<Utc>,
+    observer: &Location,
+    min_alt_deg: f64,
+) -> Result<Vec<bool>> {
+    if !(-90.0..=90.0).contains(&min_alt_deg) {
+        return Err(AstroError::OutOfRange {
+            parameter: "min_alt_deg",
+            value: min_alt_deg,
+            min: -90.0,
+            max: 90.0,
+        });
+    }
+    let lst_deg = observer.local_sidereal_time(datetime) * 15.0;
+    let (sin_lat, cos_lat) = observer.latitude_deg.to_radians().sin_cos();
+    let sin_min_alt = min_alt_deg.to_radians().sin();
     ra_dec_pairs
         .par_iter()
         .map(|&(ra, dec)| {
-            ra_dec_to_alt_az_erfa(ra, dec, datetime, observer, pressure_hpa, temperature_c, humidity)
+            validate_ra(ra)?;
+            validate_dec(dec)?;
+            let (sin_dec, cos_dec) = dec.to_radians().sin_cos();
+            let cos_ha = (lst_deg - ra).to_radians().cos();
+            Ok(sin_dec * sin_lat + cos_dec * cos_lat * cos_ha >= sin_min_alt)
         })
         .collect()
 }
@@ -359,6 +1465,9 @@ pub fn ra_dec_to_alt_az_batch_parallel(
 /// 1. Convert altitude and azimuth to hour angle and declination
 /// 2. Convert hour angle to right ascension using local sidereal time
 ///
+/// The result is of date, without aberration or refraction; use
+/// [`alt_az_to_ra_dec_erfa`] to invert [`ra_dec_to_alt_az_erfa`] to ICRS.
+///
 /// # Arguments
 ///
 /// - `altitude_deg`: Elevation above horizon in degrees (−90° to +90°)
@@ -448,6 +1557,190 @@ pub fn alt_az_to_ra_dec(
     datetime: DateTime<Utc>,
     observer: &Location,
 ) -> Result<(f64, f64)> {
+    validate_alt_az(altitude_deg, azimuth_deg)?;
+    let lst_hours = observer.local_sidereal_time(datetime);
+    let (sin_lat, cos_lat) = observer.latitude_deg.to_radians().sin_cos();
+    ra_dec_from_lst(altitude_deg, azimuth_deg, lst_hours, sin_lat, cos_lat)
+}
+
+/// Converts Alt/Az to RA/Dec for a time given as a two-part UTC Julian Date.
+///
+/// Identical to [`alt_az_to_ra_dec`] but takes the observation time as a split
+/// Julian Date (see [`julian_date_two_part`]).
+///
+/// # Arguments
+///
+/// - `altitude_deg`: Altitude in degrees (−90° to +90°)
+/// - `azimuth_deg`: Azimuth in degrees (0° to 360°)
+/// - `jd1`, `jd2`: UTC Julian Date split into two parts
+/// - `observer`: Observer location
+///
+/// # Returns
+///
+/// A tuple `(ra_deg, dec_deg)` in degrees
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if altitude or azimuth is out of range.
+pub fn alt_az_to_ra_dec_two_part(
+    altitude_deg: f64,
+    azimuth_deg: f64,
+    jd1: f64,
+    jd2: f64,
+    observer: &Location,
+) -> Result<(f64, f64)> {
+    validate_alt_az(altitude_deg, azimuth_deg)?;
+    let lst_hours = observer.local_sidereal_time_two_part(jd1, jd2);
+    let (sin_lat, cos_lat) = observer.latitude_deg.to_radians().sin_cos();
+    ra_dec_from_lst(altitude_deg, azimuth_deg, lst_hours, sin_lat, cos_lat)
+}
+
+/// Converts Alt/Az to RA/Dec for a moving observer.
+///
+/// Looks up the observer's position at `datetime` and then behaves like
+/// [`alt_az_to_ra_dec`].
+///
+/// # Arguments
+///
+/// - `altitude_deg`: Altitude in degrees (−90° to +90°)
+/// - `azimuth_deg`: Azimuth in degrees (0° to 360°)
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: [`MovingObserver`] giving the position at `datetime`
+///
+/// # Returns
+///
+/// A tuple `(ra_deg, dec_deg)` in degrees
+///
+/// # Errors
+///
+/// - `AstroError::InvalidCoordinate` if altitude or azimuth is out of range
+/// - `AstroError::OutOfRange` if `datetime` is outside the observer's track
+pub fn alt_az_to_ra_dec_moving(
+    altitude_deg: f64,
+    azimuth_deg: f64,
+    datetime: DateTime<Utc>,
+    observer: &MovingObserver,
+) -> Result<(f64, f64)> {
+    alt_az_to_ra_dec(altitude_deg, azimuth_deg, datetime, &observer.location_at(datetime)?)
+}
+
+/// Converts Alt/Az to RA/Dec with all angles in radians.
+///
+/// The radian-native counterpart of [`alt_az_to_ra_dec`]; see
+/// [`ra_dec_to_alt_az_rad`].
+///
+/// # Arguments
+///
+/// - `altitude_rad`: Altitude in radians (−π/2 to +π/2)
+/// - `azimuth_rad`: Azimuth in radians (0 to 2π), from north through east
+/// - `datetime`: UTC datetime of observation
+/// - `observer`: Observer location
+///
+/// # Returns
+///
+/// A tuple `(ra_rad, dec_rad)` of date, with RA in [0, 2π)
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if `altitude_rad` is outside
+/// [−π/2, π/2] or `azimuth_rad` is outside [0, 2π).
+pub fn alt_az_to_ra_dec_rad(
+    altitude_rad: f64,
+    azimuth_rad: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<(f64, f64)> {
+    validate_alt_az_rad(altitude_rad, azimuth_rad)?;
+    let lst_rad = observer.local_sidereal_time(datetime) * HOURS_TO_RAD;
+    let (sin_lat, cos_lat) = observer.latitude_deg.to_radians().sin_cos();
+    ra_dec_from_lst_rad(altitude_rad, azimuth_rad, lst_rad, sin_lat, cos_lat)
+}
+
+/// Converts observed horizontal coordinates to ICRS using ERFA.
+///
+/// The inverse of [`ra_dec_to_alt_az_erfa`]: removes refraction for the
+/// given weather, then diurnal aberration, Earth rotation,
+/// precession-nutation, annual aberration and light deflection (ERFA
+/// `Atoc13`). [`alt_az_to_ra_dec`] only undoes Earth rotation and returns
+/// coordinates of date, which differ from ICRS by arcminutes; pass the
+/// same weather here as to the forward transform to round-trip through
+/// the accurate pipeline.
+///
+/// # Arguments
+///
+/// - `altitude_deg`: Observed altitude in degrees (−90° to +90°)
+/// - `azimuth_deg`: Observed azimuth in degrees (0° to 360°), from north through east
+/// - `datetime`: UTC datetime of observation
+/// - `observer`, `pressure_hpa`, `temperature_c`, `humidity`: as for [`ra_dec_to_alt_az_erfa`]
+///
+/// # Returns
+///
+/// A tuple `(ra_icrs, dec_icrs)` in degrees, RA in [0, 360)
+///
+/// # Errors
+///
+/// Returns `Err(AstroError::InvalidCoordinate)` if altitude or azimuth is out of range.
+///
+/// # Example
+///
+/// ```
+/// use astro_math::{alt_az_to_ra_dec, ra_dec_to_alt_az_erfa, Location};
+/// use astro_math::transforms::alt_az_to_ra_dec_erfa;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 1, 4, 0, 0).unwrap();
+/// let loc = Location { latitude_deg: 31.9583, longitude_deg: -111.5967, altitude_m: 2096.0 };
+/// let (ra, dec) = (279.23473479, 38.78368896); // Vega
+///
+/// let (alt, az) = ra_dec_to_alt_az_erfa(ra, dec, dt, &loc, Some(790.0), Some(15.0), Some(0.3)).unwrap();
+/// let (ra2, dec2) = alt_az_to_ra_dec_erfa(alt, az, dt, &loc, Some(790.0), Some(15.0), Some(0.3)).unwrap();
+/// assert!((ra2 - ra).abs() * dec.to_radians().cos() * 3600.0 < 0.01);
+/// assert!((dec2 - dec).abs() * 3600.0 < 0.01);
+///
+/// // Undoing only Earth rotation lands arcminutes away
+/// let (ra3, _) = alt_az_to_ra_dec(alt, az, dt, &loc).unwrap();
+/// assert!((ra3 - ra).abs() * 60.0 > 1.0);
+/// ```
+pub fn alt_az_to_ra_dec_erfa(
+    altitude_deg: f64,
+    azimuth_deg: f64,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+    pressure_hpa: Option<f64>,
+    temperature_c: Option<f64>,
+    humidity: Option<f64>,
+) -> Result<(f64, f64)> {
+    validate_alt_az(altitude_deg, azimuth_deg)?;
+    let (jd1, jd2) = julian_date_two_part(datetime);
+
+    // Atmospheric parameters as for the forward transform: no refraction by default
+    let (pressure_hpa, temperature_c, humidity) = crate::config::weather_or_default(pressure_hpa, temperature_c, humidity);
+    match crate::backend::astrometry::Atoc13(
+        'A',
+        azimuth_deg.to_radians(),
+        (90.0 - altitude_deg).to_radians(),
+        jd1,
+        jd2,
+        0.0,
+        observer.longitude_deg.to_radians(),
+        observer.latitude_deg.to_radians(),
+        observer.altitude_m,
+        0.0,
+        0.0,
+        pressure_hpa.unwrap_or(0.0),
+        temperature_c.unwrap_or(0.0),
+        humidity.unwrap_or(0.0),
+        ERFA_WAVELENGTH_UM,
+    ) {
+        Ok((rc, dc)) => Ok((rc.to_degrees().rem_euclid(360.0) % 360.0, dc.to_degrees())),
+        Err(_) => {
+            trace_event!(warn, "ERFA Atoc13 failed, falling back to the Meeus transformation");
+            alt_az_to_ra_dec_two_part(altitude_deg, azimuth_deg, jd1, jd2, observer)
+        }
+    }
+}
+
+pub(crate) fn validate_alt_az(altitude_deg: f64, azimuth_deg: f64) -> Result<()> {
     // Validate inputs
     if !(-90.0..=90.0).contains(&altitude_deg) {
         return Err(crate::error::AstroError::InvalidCoordinate {
@@ -457,77 +1750,134 @@ pub fn alt_az_to_ra_dec(
         });
     }
     
-    if !(0.0..360.0).contains(&azimuth_deg) {
+    if !(0.0..360.0).contains(&azimuth_deg) && !crate::config::wraps_angles() {
         return Err(crate::error::AstroError::InvalidCoordinate {
             coord_type: "Azimuth", 
             value: azimuth_deg,
             valid_range: "[0, 360)",
         });
     }
+    Ok(())
+}
+
+/// Validates RA in [0, 2π) and Dec in [−π/2, π/2], in radians.
+pub(crate) fn validate_ra_dec_rad(ra_rad: f64, dec_rad: f64) -> Result<()> {
+    if !(0.0..TAU).contains(&ra_rad) && !crate::config::wraps_angles() {
+        return Err(AstroError::InvalidCoordinate {
+            coord_type: "RA",
+            value: ra_rad,
+            valid_range: "[0, 2π) rad",
+        });
+    }
+    if !(-FRAC_PI_2..=FRAC_PI_2).contains(&dec_rad) {
+        return Err(AstroError::InvalidCoordinate {
+            coord_type: "Declination",
+            value: dec_rad,
+            valid_range: "[-π/2, π/2] rad",
+        });
+    }
+    Ok(())
+}
+
+/// Validates altitude in [−π/2, π/2] and azimuth in [0, 2π), in radians.
+pub(crate) fn validate_alt_az_rad(altitude_rad: f64, azimuth_rad: f64) -> Result<()> {
+    if !(-FRAC_PI_2..=FRAC_PI_2).contains(&altitude_rad) {
+        return Err(AstroError::InvalidCoordinate {
+            coord_type: "Altitude",
+            value: altitude_rad,
+            valid_range: "[-π/2, π/2] rad",
+        });
+    }
+    if !(0.0..TAU).contains(&azimuth_rad) && !crate::config::wraps_angles() {
+        return Err(AstroError::InvalidCoordinate {
+            coord_type: "Azimuth",
+            value: azimuth_rad,
+            valid_range: "[0, 2π) rad",
+        });
+    }
+    Ok(())
+}
+
+/// RA/Dec from validated Alt/Az, local apparent sidereal time in hours and
+/// the sine and cosine of the observer's latitude.
+pub(crate) fn ra_dec_from_lst(
+    altitude_deg: f64,
+    azimuth_deg: f64,
+    lst_hours: f64,
+    sin_lat: f64,
+    cos_lat: f64,
+) -> Result<(f64, f64)> {
+    let (ha_rad, dec_rad) =
+        equatorial_from_horizontal(altitude_deg.to_radians(), azimuth_deg.to_radians(), sin_lat, cos_lat);
+
+    // Convert hour angle to RA: RA = LST - HA
+    let ha_hours = ha_rad.to_degrees() / 15.0;
+    let mut ra_hours = lst_hours - ha_hours;
     
-    // Convert to radians
-    let alt_rad = altitude_deg.to_radians();
-    let az_rad = azimuth_deg.to_radians();
-    let lat_rad = observer.latitude_deg.to_radians();
+    // Normalize RA to [0, 24) hours
+    while ra_hours < 0.0 {
+        ra_hours += 24.0;
+    }
+    while ra_hours >= 24.0 {
+        ra_hours -= 24.0;
+    }
+    
+    // Convert to degrees
+    let ra_deg = ra_hours * 15.0;
     
+    sanitize_ra_dec_result(ra_deg, dec_rad.to_degrees())
+}
+
+/// RA/Dec in radians from validated Alt/Az in radians, local apparent
+/// sidereal time in radians and the sine and cosine of the observer's
+/// latitude.
+pub(crate) fn ra_dec_from_lst_rad(
+    altitude_rad: f64,
+    azimuth_rad: f64,
+    lst_rad: f64,
+    sin_lat: f64,
+    cos_lat: f64,
+) -> Result<(f64, f64)> {
+    let (ha_rad, dec_rad) = equatorial_from_horizontal(altitude_rad, azimuth_rad, sin_lat, cos_lat);
+    sanitize_ra_dec_result_rad(lst_rad - ha_rad, dec_rad)
+}
+
+/// Signed hour angle and declination in radians from altitude and azimuth
+/// in radians. The hour angle is zero at the celestial poles, where it is
+/// undefined.
+#[inline]
+fn equatorial_from_horizontal(alt_rad: f64, az_rad: f64, sin_lat: f64, cos_lat: f64) -> (f64, f64) {
+    let (sin_alt, cos_alt) = alt_rad.sin_cos();
+    let (sin_az, cos_az) = az_rad.sin_cos();
+
     // Calculate declination using spherical trigonometry
     // sin(Dec) = sin(Alt)·sin(Lat) + cos(Alt)·cos(Lat)·cos(Az)
-    let sin_dec = alt_rad.sin() * lat_rad.sin() + 
-                  alt_rad.cos() * lat_rad.cos() * az_rad.cos();
+    let sin_dec = sin_alt * sin_lat + cos_alt * cos_lat * cos_az;
     
     // Handle edge case where sin_dec is outside [-1, 1] due to numerical errors
-    let sin_dec_clamped = sin_dec.clamp(-1.0, 1.0);
-    let dec_rad = sin_dec_clamped.asin();
-    let dec_deg = dec_rad.to_degrees();
-    
-    // Calculate hour angle
+    let dec_rad = sin_dec.clamp(-1.0, 1.0).asin();
     let cos_dec = dec_rad.cos();
     
     // Handle edge cases where declination approaches ±90°
     if cos_dec.abs() < 1e-10 {
-        // At celestial poles, hour angle is undefined
-        // Use a reasonable default based on azimuth
-        let lst_hours = observer.local_sidereal_time(datetime);
-        let ra_deg = (lst_hours * 15.0) % 360.0;
-        return sanitize_ra_dec_result(ra_deg, dec_deg);
+        return (0.0, dec_rad);
     }
     
     // cos(HA) = (sin(Alt) - sin(Dec)·sin(Lat)) / (cos(Dec)·cos(Lat))
-    let numerator = alt_rad.sin() - dec_rad.sin() * lat_rad.sin();
-    let denominator = cos_dec * lat_rad.cos();
-    
-    let cos_ha = numerator / denominator;
-    let cos_ha_clamped = cos_ha.clamp(-1.0, 1.0);
+    let cos_ha = (sin_alt - dec_rad.sin() * sin_lat) / (cos_dec * cos_lat);
     
     // Calculate hour angle magnitude
-    let ha_rad_magnitude = cos_ha_clamped.acos();
+    let ha_rad_magnitude = cos_ha.clamp(-1.0, 1.0).acos();
     
     // Determine hour angle sign using sin(HA) = -sin(Az)·cos(Alt) / cos(Dec)
-    let sin_ha_expected = -az_rad.sin() * alt_rad.cos() / cos_dec;
+    let sin_ha_expected = -sin_az * cos_alt / cos_dec;
     
     let ha_rad = if sin_ha_expected >= 0.0 {
         ha_rad_magnitude  // Positive hour angle (west of meridian)
     } else {
         -ha_rad_magnitude // Negative hour angle (east of meridian)
     };
-    
-    // Convert hour angle to RA: RA = LST - HA
-    let lst_hours = observer.local_sidereal_time(datetime);
-    let ha_hours = ha_rad.to_degrees() / 15.0;
-    let mut ra_hours = lst_hours - ha_hours;
-    
-    // Normalize RA to [0, 24) hours
-    while ra_hours < 0.0 {
-        ra_hours += 24.0;
-    }
-    while ra_hours >= 24.0 {
-        ra_hours -= 24.0;
-    }
-    
-    // Convert to degrees
-    let ra_deg = ra_hours * 15.0;
-    
-    sanitize_ra_dec_result(ra_deg, dec_deg)
+    (ha_rad, dec_rad)
 }
 
 // Note: ERFA does not provide a direct single-function inverse transformation
@@ -0,0 +1,332 @@
+//! Dimensionally typed facade over the `f64` API, using [`uom`].
+//!
+//! The rest of the crate takes and returns bare `f64`s whose units live in
+//! the names and docs: degrees for most angles, hours for sidereal time,
+//! metres for [`Location::altitude_m`], AU for
+//! [`diurnal_parallax`](crate::parallax::diurnal_parallax) distances and
+//! milliarcseconds for stellar parallax. This module wraps the same
+//! functions with [`Angle`], [`Length`] and [`Time`] quantities, so a right
+//! ascension in hours or a distance in kilometres is converted at the call
+//! site instead of silently misread. The `f64` functions are unchanged and
+//! remain the primary API; these wrappers only convert at the boundary.
+//!
+//! `uom` has no hour-angle unit, so [`hours`] and [`to_hours`] convert
+//! between hours of right ascension or sidereal time and [`Angle`].
+//!
+//! Enabled by the `units` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::units::angle::degree;
+//! use astro_math::units::length::{foot, kilometer};
+//! use astro_math::units::{self, hours, Angle, Length};
+//! use chrono::{TimeZone, Utc};
+//!
+//! let site = units::location(
+//!     Angle::new::<degree>(19.8207),
+//!     Angle::new::<degree>(-155.4681),
+//!     Length::new::<foot>(13_796.0),
+//! )
+//! .unwrap();
+//! assert!((site.altitude_m - 4205.0).abs() < 1.0);
+//!
+//! // Vega, RA 18h 36m 56.3s
+//! let dt = Utc.with_ymd_and_hms(2024, 8, 4, 8, 0, 0).unwrap();
+//! let ra = hours(18.0 + 36.0 / 60.0 + 56.3 / 3600.0);
+//! let (alt, _az) = units::ra_dec_to_alt_az(ra, Angle::new::<degree>(38.7837), dt, &site).unwrap();
+//! assert!(alt.get::<degree>() > 30.0);
+//!
+//! // The Moon's parallax from its distance in kilometres, no AU conversion needed
+//! let moon = Length::new::<kilometer>(384_400.0);
+//! let (_, dec) = units::diurnal_parallax(ra, Angle::new::<degree>(38.7837), moon, dt, &site).unwrap();
+//! assert!((dec.get::<degree>() - 38.7837).abs() > 0.1);
+//! ```
+//!
+//! # Error Handling
+//!
+//! The wrappers return the same errors as the functions they wrap, with
+//! values reported in the units of the `f64` API:
+//! - `AstroError::InvalidCoordinate` for out-of-range coordinates
+//! - `AstroError::OutOfRange` for non-positive distances or parallaxes and
+//!   negative durations
+
+use crate::error::{validate_latitude, validate_longitude, Result};
+use crate::location::Location;
+use crate::parallax::EARTH_RADIUS_KM;
+use chrono::{DateTime, Utc};
+use uom::si::angle::{degree, second as arcsecond};
+use uom::si::length::{kilometer, meter};
+use uom::si::time::second;
+
+/// Astronomical Unit in kilometers (IAU 2012). `uom`'s `astronomical_unit`
+/// is rounded to 1.495979 × 10¹¹ m, which would shift the Moon's parallax
+/// by tens of microarcseconds.
+const AU_KM: f64 = 149597870.7;
+
+pub use uom::si::f64::{Angle, Length, Time};
+/// Units of [`Angle`], [`Length`] and [`Time`], so callers need no direct
+/// `uom` dependency.
+pub use uom::si::{angle, length, time};
+
+/// Converts hours of right ascension, hour angle or sidereal time to an
+/// angle (1h = 15°).
+///
+/// # Example
+/// ```
+/// use astro_math::units::angle::degree;
+/// use astro_math::units::hours;
+///
+/// assert_eq!(hours(6.0).get::<degree>(), 90.0);
+/// ```
+pub fn hours(hours: f64) -> Angle {
+    Angle::new::<degree>(hours * 15.0)
+}
+
+/// Converts an angle to hours of right ascension, hour angle or sidereal
+/// time (15° = 1h).
+///
+/// # Example
+/// ```
+/// use astro_math::units::angle::degree;
+/// use astro_math::units::{to_hours, Angle};
+///
+/// assert_eq!(to_hours(Angle::new::<degree>(270.0)), 18.0);
+/// ```
+pub fn to_hours(angle: Angle) -> f64 {
+    angle.get::<degree>() / 15.0
+}
+
+/// Builds a [`Location`] from typed latitude, longitude and altitude.
+///
+/// # Arguments
+/// * `latitude` - Geodetic latitude (+N)
+/// * `longitude` - Longitude (+E)
+/// * `altitude` - Height above sea level
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if the latitude is outside
+/// [-90°, 90°] or the longitude outside [-180°, 180°]
+///
+/// # Example
+/// ```
+/// use astro_math::units::angle::degree;
+/// use astro_math::units::length::kilometer;
+/// use astro_math::units::{self, Angle, Length};
+///
+/// let site = units::location(
+///     Angle::new::<degree>(-30.24),
+///     Angle::new::<degree>(-70.74),
+///     Length::new::<kilometer>(2.715),
+/// )
+/// .unwrap();
+/// assert!((site.altitude_m - 2715.0).abs() < 1e-9);
+/// assert_eq!(units::altitude(&site), Length::new::<kilometer>(2.715));
+/// ```
+pub fn location(latitude: Angle, longitude: Angle, altitude: Length) -> Result<Location> {
+    let location = Location {
+        latitude_deg: latitude.get::<degree>(),
+        longitude_deg: longitude.get::<degree>(),
+        altitude_m: altitude.get::<meter>(),
+    };
+    validate_latitude(location.latitude_deg)?;
+    validate_longitude(location.longitude_deg)?;
+    Ok(location)
+}
+
+/// The observer's geodetic latitude.
+pub fn latitude(location: &Location) -> Angle {
+    Angle::new::<degree>(location.latitude_deg)
+}
+
+/// The observer's longitude (+E).
+pub fn longitude(location: &Location) -> Angle {
+    Angle::new::<degree>(location.longitude_deg)
+}
+
+/// The observer's height above sea level.
+pub fn altitude(location: &Location) -> Length {
+    Length::new::<meter>(location.altitude_m)
+}
+
+/// Local apparent sidereal time, as an angle.
+///
+/// See [`Location::local_sidereal_time`], which returns hours.
+///
+/// # Example
+/// ```
+/// use astro_math::units::{self, to_hours};
+/// use astro_math::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+/// let lst = units::local_sidereal_time(&site, dt);
+/// assert!((to_hours(lst) - site.local_sidereal_time(dt)).abs() < 1e-12);
+/// ```
+pub fn local_sidereal_time(location: &Location, datetime: DateTime<Utc>) -> Angle {
+    hours(location.local_sidereal_time(datetime))
+}
+
+/// The observer's distance from the Earth's centre.
+///
+/// See [`parallax::geocentric_distance`](crate::parallax::geocentric_distance),
+/// which returns Earth equatorial radii.
+///
+/// # Example
+/// ```
+/// use astro_math::units::{self, length::kilometer};
+/// use astro_math::Location;
+///
+/// let pole = Location { latitude_deg: 90.0, longitude_deg: 0.0, altitude_m: 0.0 };
+/// let r = units::geocentric_distance(&pole).get::<kilometer>();
+/// assert!((r - 6356.752).abs() < 0.001);
+/// ```
+pub fn geocentric_distance(location: &Location) -> Length {
+    Length::new::<kilometer>(crate::parallax::geocentric_distance(location) * EARTH_RADIUS_KM)
+}
+
+/// Converts RA/Dec to altitude and azimuth.
+///
+/// See [`transforms::ra_dec_to_alt_az`](crate::transforms::ra_dec_to_alt_az).
+///
+/// # Returns
+/// Tuple of (altitude, azimuth), azimuth measured from north through east
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA is outside [0°, 360°)
+/// or Dec outside [-90°, 90°]
+pub fn ra_dec_to_alt_az(
+    ra: Angle,
+    dec: Angle,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<(Angle, Angle)> {
+    let (alt, az) = crate::transforms::ra_dec_to_alt_az(ra.get::<degree>(), dec.get::<degree>(), datetime, observer)?;
+    Ok((Angle::new::<degree>(alt), Angle::new::<degree>(az)))
+}
+
+/// Converts altitude and azimuth to RA/Dec.
+///
+/// See [`transforms::alt_az_to_ra_dec`](crate::transforms::alt_az_to_ra_dec).
+///
+/// # Returns
+/// Tuple of (ra, dec)
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if the altitude is outside
+/// [-90°, 90°] or the azimuth outside [0°, 360°)
+pub fn alt_az_to_ra_dec(
+    altitude: Angle,
+    azimuth: Angle,
+    datetime: DateTime<Utc>,
+    observer: &Location,
+) -> Result<(Angle, Angle)> {
+    let (ra, dec) = crate::transforms::alt_az_to_ra_dec(
+        altitude.get::<degree>(),
+        azimuth.get::<degree>(),
+        datetime,
+        observer,
+    )?;
+    Ok((Angle::new::<degree>(ra), Angle::new::<degree>(dec)))
+}
+
+/// The parallactic angle of a target.
+///
+/// See [`transforms::parallactic_angle`](crate::transforms::parallactic_angle).
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if RA, Dec or the observer's
+/// latitude or longitude is out of range
+pub fn parallactic_angle(ra: Angle, dec: Angle, datetime: DateTime<Utc>, observer: &Location) -> Result<Angle> {
+    crate::transforms::parallactic_angle(ra.get::<degree>(), dec.get::<degree>(), datetime, observer)
+        .map(Angle::new::<degree>)
+}
+
+/// The airmass-weighted mean parallactic angle over an exposure.
+///
+/// See [`timing::mean_parallactic_angle`](crate::timing::mean_parallactic_angle).
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if `duration` is negative
+/// - `AstroError::CalculationError` if the target sets during the exposure
+pub fn mean_parallactic_angle(
+    ra: Angle,
+    dec: Angle,
+    start: DateTime<Utc>,
+    duration: Time,
+    location: &Location,
+) -> Result<Angle> {
+    crate::timing::mean_parallactic_angle(
+        ra.get::<degree>(),
+        dec.get::<degree>(),
+        start,
+        duration.get::<second>(),
+        location,
+    )
+    .map(Angle::new::<degree>)
+}
+
+/// Applies diurnal parallax for a nearby object.
+///
+/// See [`parallax::diurnal_parallax`](crate::parallax::diurnal_parallax),
+/// which takes the distance in AU.
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if `distance` is not positive
+pub fn diurnal_parallax(
+    ra: Angle,
+    dec: Angle,
+    distance: Length,
+    datetime: DateTime<Utc>,
+    location: &Location,
+) -> Result<(Angle, Angle)> {
+    let (ra, dec) = crate::parallax::diurnal_parallax(
+        ra.get::<degree>(),
+        dec.get::<degree>(),
+        distance.get::<kilometer>() / AU_KM,
+        datetime,
+        location,
+    )?;
+    Ok((Angle::new::<degree>(ra), Angle::new::<degree>(dec)))
+}
+
+/// Applies annual parallax for a nearby star.
+///
+/// See [`parallax::annual_parallax`](crate::parallax::annual_parallax),
+/// which takes the parallax in milliarcseconds.
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - `AstroError::OutOfRange` if `parallax` is not positive
+///
+/// # Example
+/// ```
+/// use astro_math::units::angle::{degree, second};
+/// use astro_math::units::{self, Angle};
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 0, 0, 0).unwrap();
+/// // Proxima Centauri, parallax 0.7685″
+/// let (ra, dec) = units::annual_parallax(
+///     Angle::new::<degree>(217.42894),
+///     Angle::new::<degree>(-62.67948),
+///     Angle::new::<second>(0.7685),
+///     dt,
+/// )
+/// .unwrap();
+/// let (ra_f64, dec_f64) = astro_math::annual_parallax(217.42894, -62.67948, 768.5, dt).unwrap();
+/// assert!((ra.get::<degree>() - ra_f64).abs() < 1e-12);
+/// assert!((dec.get::<degree>() - dec_f64).abs() < 1e-12);
+/// ```
+pub fn annual_parallax(ra: Angle, dec: Angle, parallax: Angle, datetime: DateTime<Utc>) -> Result<(Angle, Angle)> {
+    let (ra, dec) = crate::parallax::annual_parallax(
+        ra.get::<degree>(),
+        dec.get::<degree>(),
+        parallax.get::<arcsecond>() * 1000.0,
+        datetime,
+    )?;
+    Ok((Angle::new::<degree>(ra), Angle::new::<degree>(dec)))
+}
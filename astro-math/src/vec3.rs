@@ -0,0 +1,109 @@
+//! Three-vector helpers for spherical ↔ Cartesian work.
+//!
+//! Many calculations in the crate are simplest on unit vectors: rotations
+//! between frames, aberration, angular separations, spherical polygons. This
+//! module is the one place they convert between `(ra, dec)` and vectors, so
+//! that every module agrees on the conventions:
+//!
+//! - Vectors are `[x, y, z]` with x towards RA 0°, Dec 0°, y towards RA 90°,
+//!   Dec 0° and z towards the north pole of the frame.
+//! - [`unit_vector_to_radec`] returns RA in [0, 360) — never 360 itself,
+//!   even for a vector a rounding error below the x axis — and takes
+//!   declination from `atan2`, so vectors need not be exactly unit length
+//!   and positions near the poles keep full precision.
+//!
+//! The same functions serve any longitude/latitude pair, e.g. galactic or
+//! ecliptic coordinates, or geographic positions.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::vec3::{angle_between, radec_to_unit_vector, unit_vector_to_radec};
+//!
+//! let vega = radec_to_unit_vector(279.2347, 38.7837);
+//! let altair = radec_to_unit_vector(297.6958, 8.8683);
+//! assert!((angle_between(vega, altair).to_degrees() - 34.2).abs() < 0.1);
+//!
+//! let (ra, dec) = unit_vector_to_radec(vega);
+//! assert!((ra - 279.2347).abs() < 1e-12 && (dec - 38.7837).abs() < 1e-12);
+//! ```
+
+/// A Cartesian three-vector.
+pub type Vec3 = [f64; 3];
+
+/// Unit vector towards a position on the sphere.
+///
+/// # Arguments
+/// * `ra_deg` - Right ascension (or longitude) in degrees
+/// * `dec_deg` - Declination (or latitude) in degrees
+///
+/// # Returns
+/// `[x, y, z]` of unit length
+pub fn radec_to_unit_vector(ra_deg: f64, dec_deg: f64) -> Vec3 {
+    let (ra, dec) = (ra_deg.to_radians(), dec_deg.to_radians());
+    [dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin()]
+}
+
+/// Position on the sphere a vector points towards.
+///
+/// # Arguments
+/// * `v` - Direction; need not be unit length
+///
+/// # Returns
+/// `(ra, dec)` in degrees with RA in [0, 360) and Dec in [-90, 90].
+/// The zero vector gives `(0, 0)`; along the z axis RA is 0.
+///
+/// # Example
+/// ```
+/// use astro_math::vec3::unit_vector_to_radec;
+///
+/// // A direction a hair below the x axis wraps to RA 0, not 360
+/// let (ra, dec) = unit_vector_to_radec([1.0, -1e-18, 0.0]);
+/// assert_eq!((ra, dec), (0.0, 0.0));
+/// assert_eq!(unit_vector_to_radec([0.0, -2.0, 0.0]), (270.0, 0.0));
+/// ```
+pub fn unit_vector_to_radec(v: Vec3) -> (f64, f64) {
+    let ra = v[1].atan2(v[0]).to_degrees().rem_euclid(360.0);
+    // rem_euclid rounds tiny negative angles up to exactly 360
+    let ra = if ra >= 360.0 { 0.0 } else { ra };
+    (ra, v[2].atan2(v[0].hypot(v[1])).to_degrees())
+}
+
+/// Dot product.
+pub fn dot(a: Vec3, b: Vec3) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Cross product `a × b`.
+pub fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+/// Euclidean length.
+pub fn norm(v: Vec3) -> f64 {
+    dot(v, v).sqrt()
+}
+
+/// Scales a vector to unit length.
+///
+/// # Returns
+/// `v / |v|`, or the zero vector unchanged
+pub fn normalize(v: Vec3) -> Vec3 {
+    let n = norm(v);
+    if n == 0.0 {
+        v
+    } else {
+        v.map(|c| c / n)
+    }
+}
+
+/// Angle between two vectors.
+///
+/// Uses `atan2(|a × b|, a · b)`, which stays accurate for tiny and nearly
+/// antipodal separations where `acos` of the dot product does not.
+///
+/// # Returns
+/// Angle in radians, in [0, π]
+pub fn angle_between(a: Vec3, b: Vec3) -> f64 {
+    norm(cross(a, b)).atan2(dot(a, b))
+}
@@ -0,0 +1,389 @@
+//! Live weather for refraction-aware transforms.
+//!
+//! Refraction depends on the pressure, temperature and humidity at the
+//! telescope, which change through the night. [`ConditionsProvider`] is the
+//! one place an application plugs in its weather source; anything that needs
+//! [`AtmosphericConditions`] can then ask the provider for current values
+//! instead of each call site holding its own copy.
+//!
+//! Two providers come with the crate:
+//!
+//! - [`AtmosphericConditions`] itself, for fixed values
+//! - [`JsonFileConditions`], which reads a small JSON file that a weather
+//!   station logger rewrites as readings arrive, and can refuse readings
+//!   that have gone stale
+//!
+//! The JSON is an object with these members; any others are ignored, so a
+//! station's full report can be used as is:
+//!
+//! ```text
+//! {
+//!   "time": "2024-08-04T06:00:00Z",
+//!   "pressure_hpa": 781.4,
+//!   "temperature_c": 12.5,
+//!   "relative_humidity": 0.31
+//! }
+//! ```
+//!
+//! `time` (RFC 3339) is optional unless the provider has a maximum age.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::weather::{alt_az_with_conditions, WeatherReading};
+//! use astro_math::Location;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let reading = WeatherReading::from_json_str(
+//!     r#"{"pressure_hpa": 781.4, "temperature_c": 12.5, "relative_humidity": 0.31, "wind_kmh": 14}"#,
+//! )
+//! .unwrap();
+//! assert_eq!(reading.conditions.pressure_hpa, 781.4);
+//!
+//! let site = Location { latitude_deg: 31.9583, longitude_deg: -111.6, altitude_m: 2120.0 };
+//! let dt = Utc.with_ymd_and_hms(2024, 8, 4, 6, 0, 0).unwrap();
+//! let (alt, _az) = alt_az_with_conditions(279.23473479, 38.78368896, dt, &site, &reading.conditions).unwrap();
+//! assert!(alt > 0.0);
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidFormat` for malformed JSON, a missing or
+//!   non-numeric member, or an unreadable time
+//! - `AstroError::OutOfRange` for values outside the ranges the refraction
+//!   models accept, or a reading older than the provider's maximum age
+//! - `AstroError::CalculationError` if the weather file cannot be read
+
+use crate::error::{AstroError, Result};
+use crate::refraction::AtmosphericConditions;
+use crate::transforms::ra_dec_to_alt_az_erfa;
+use crate::Location;
+use chrono::{DateTime, Duration, Utc};
+use std::path::{Path, PathBuf};
+
+/// A source of current weather at the observer.
+pub trait ConditionsProvider {
+    /// Returns the latest conditions.
+    ///
+    /// # Errors
+    /// Implementations return an error when no trustworthy reading is
+    /// available, rather than a guess.
+    fn current(&self) -> Result<AtmosphericConditions>;
+}
+
+impl ConditionsProvider for AtmosphericConditions {
+    /// Returns these fixed conditions after checking their ranges.
+    fn current(&self) -> Result<AtmosphericConditions> {
+        self.validate()?;
+        Ok(*self)
+    }
+}
+
+/// One weather report: the conditions and, if given, when they were read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeatherReading {
+    /// Pressure, temperature and humidity
+    pub conditions: AtmosphericConditions,
+    /// Time of the reading
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl WeatherReading {
+    /// Parses a reading from JSON.
+    ///
+    /// # Arguments
+    /// * `text` - A JSON object with numeric `pressure_hpa`, `temperature_c`
+    ///   and `relative_humidity` (0–1) members and an optional RFC 3339
+    ///   `time`; other members are ignored
+    ///
+    /// # Returns
+    /// The reading
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidFormat` if the JSON is malformed, a required
+    ///   member is missing or not a number, or `time` does not parse
+    /// - `AstroError::OutOfRange` if a value is outside the ranges the
+    ///   refraction models accept
+    pub fn from_json_str(text: &str) -> Result<Self> {
+        let members = parse_flat_object(text)?;
+        let number = |key: &str| -> Result<f64> {
+            match members.iter().find(|(k, _)| k == key) {
+                Some((_, JsonValue::Number(value))) => Ok(*value),
+                Some(_) => Err(invalid(text, format!("'{}' is not a number", key))),
+                None => Err(invalid(text, format!("missing '{}'", key))),
+            }
+        };
+        let conditions = AtmosphericConditions {
+            pressure_hpa: number("pressure_hpa")?,
+            temperature_c: number("temperature_c")?,
+            relative_humidity: number("relative_humidity")?,
+        };
+        conditions.validate()?;
+        let time = match members.iter().find(|(k, _)| k == "time") {
+            None | Some((_, JsonValue::Null)) => None,
+            Some((_, JsonValue::String(value))) => Some(
+                DateTime::parse_from_rfc3339(value)
+                    .map_err(|e| invalid(text, format!("time '{}': {}", value, e)))?
+                    .with_timezone(&Utc),
+            ),
+            Some(_) => return Err(invalid(text, "'time' is not a string".to_string())),
+        };
+        Ok(WeatherReading { conditions, time })
+    }
+}
+
+/// Reads conditions from a JSON file each time they are asked for.
+///
+/// Point it at the file a weather station logger keeps up to date; see the
+/// [module documentation](self) for the format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonFileConditions {
+    path: PathBuf,
+    max_age: Option<Duration>,
+}
+
+impl JsonFileConditions {
+    /// Creates a provider reading `path`, accepting readings of any age.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        JsonFileConditions { path: path.into(), max_age: None }
+    }
+
+    /// Refuses readings older than `max_age`, or with no `time`.
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::OutOfRange)` if `max_age` is negative.
+    pub fn with_max_age(mut self, max_age: Duration) -> Result<Self> {
+        if max_age < Duration::zero() {
+            return Err(AstroError::OutOfRange {
+                parameter: "max_age",
+                value: max_age.num_milliseconds() as f64 / 1000.0,
+                min: 0.0,
+                max: f64::MAX,
+            });
+        }
+        self.max_age = Some(max_age);
+        Ok(self)
+    }
+
+    /// The file read.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the file and checks the reading's age against `now`.
+    ///
+    /// [`current`](ConditionsProvider::current) calls this with the system
+    /// clock; pass a time explicitly for replays and tests.
+    ///
+    /// # Errors
+    /// - `AstroError::CalculationError` if the file cannot be read
+    /// - `AstroError::InvalidFormat` or `AstroError::OutOfRange` as for
+    ///   [`WeatherReading::from_json_str`]
+    /// - `AstroError::OutOfRange` if a maximum age is set and the reading is
+    ///   older, or has no time
+    pub fn reading_at(&self, now: DateTime<Utc>) -> Result<WeatherReading> {
+        let text = std::fs::read_to_string(&self.path).map_err(|e| AstroError::CalculationError {
+            calculation: "weather conditions",
+            reason: format!("cannot read {}: {}", self.path.display(), e),
+        })?;
+        let reading = WeatherReading::from_json_str(&text)?;
+        if let Some(max_age) = self.max_age {
+            let max = max_age.num_milliseconds() as f64 / 1000.0;
+            let age = reading.time.map_or(f64::INFINITY, |time| (now - time).num_milliseconds() as f64 / 1000.0);
+            if age > max {
+                return Err(AstroError::OutOfRange { parameter: "weather reading age", value: age, min: 0.0, max });
+            }
+        }
+        Ok(reading)
+    }
+}
+
+impl ConditionsProvider for JsonFileConditions {
+    fn current(&self) -> Result<AtmosphericConditions> {
+        self.reading_at(Utc::now()).map(|reading| reading.conditions)
+    }
+}
+
+/// Converts RA/Dec to refracted Alt/Az with conditions from a provider.
+///
+/// Asks `provider` for the current weather and passes it to
+/// [`ra_dec_to_alt_az_erfa`].
+///
+/// # Arguments
+/// * `ra`, `dec` - ICRS position in degrees
+/// * `datetime` - Time of observation (UTC)
+/// * `location` - Observer's location
+/// * `provider` - Source of pressure, temperature and humidity
+///
+/// # Returns
+/// `(altitude, azimuth)` in degrees, altitude including refraction
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if RA or Dec is out of range
+/// - Any error from `provider`
+pub fn alt_az_with_conditions(
+    ra: f64,
+    dec: f64,
+    datetime: DateTime<Utc>,
+    location: &Location,
+    provider: &impl ConditionsProvider,
+) -> Result<(f64, f64)> {
+    let conditions = provider.current()?;
+    ra_dec_to_alt_az_erfa(
+        ra,
+        dec,
+        datetime,
+        location,
+        Some(conditions.pressure_hpa),
+        Some(conditions.temperature_c),
+        Some(conditions.relative_humidity),
+    )
+}
+
+/// A JSON value as far as the reading needs it
+enum JsonValue {
+    Number(f64),
+    String(String),
+    Null,
+    /// Booleans, arrays and objects, which are skipped
+    Other,
+}
+
+/// Parses the members of a JSON object, keeping nested values opaque.
+fn parse_flat_object(text: &str) -> Result<Vec<(String, JsonValue)>> {
+    let mut parser = JsonParser { text, chars: text.char_indices().peekable() };
+    parser.expect('{')?;
+    let mut members = Vec::new();
+    if parser.peek() == Some('}') {
+        parser.next();
+    } else {
+        loop {
+            let key = parser.string()?;
+            parser.expect(':')?;
+            let value = parser.value()?;
+            members.push((key, value));
+            match parser.next() {
+                Some(',') => continue,
+                Some('}') => break,
+                _ => return Err(invalid(text, "expected ',' or '}'".to_string())),
+            }
+        }
+    }
+    if parser.next().is_some() {
+        return Err(invalid(text, "unexpected text after the object".to_string()));
+    }
+    Ok(members)
+}
+
+struct JsonParser<'a> {
+    text: &'a str,
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl JsonParser<'_> {
+    /// Next character that is not whitespace
+    fn next(&mut self) -> Option<char> {
+        self.peek()?;
+        self.chars.next().map(|(_, c)| c)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        while let Some(&(_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                return Some(c);
+            }
+            self.chars.next();
+        }
+        None
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(invalid(self.text, format!("expected '{}'", expected))),
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.chars.next().map(|(_, c)| c) {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.chars.next().map(|(_, c)| c) {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('r') => value.push('\r'),
+                    Some('b') => value.push('\u{8}'),
+                    Some('f') => value.push('\u{c}'),
+                    Some('u') => {
+                        let code: String = (0..4).filter_map(|_| self.chars.next().map(|(_, c)| c)).collect();
+                        let c = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32);
+                        value.push(c.unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                    Some(c) => value.push(c),
+                    None => break,
+                },
+                Some(c) => value.push(c),
+                None => break,
+            }
+        }
+        Err(invalid(self.text, "unterminated string".to_string()))
+    }
+
+    fn value(&mut self) -> Result<JsonValue> {
+        match self.peek() {
+            Some('"') => self.string().map(JsonValue::String),
+            Some('{') | Some('[') => {
+                self.skip_nested()?;
+                Ok(JsonValue::Other)
+            }
+            Some(_) => {
+                let start = self.chars.peek().map_or(self.text.len(), |&(i, _)| i);
+                while let Some(&(_, c)) = self.chars.peek() {
+                    if c == ',' || c == '}' || c == ']' || c.is_whitespace() {
+                        break;
+                    }
+                    self.chars.next();
+                }
+                let end = self.chars.peek().map_or(self.text.len(), |&(i, _)| i);
+                match &self.text[start..end] {
+                    "null" => Ok(JsonValue::Null),
+                    "true" | "false" => Ok(JsonValue::Other),
+                    // Rust also parses "inf" and "NaN", which JSON does not allow
+                    token if token.starts_with(|c: char| c == '-' || c.is_ascii_digit()) => token
+                        .parse()
+                        .map(JsonValue::Number)
+                        .map_err(|_| invalid(self.text, format!("'{}' is not a JSON value", token))),
+                    token => Err(invalid(self.text, format!("'{}' is not a JSON value", token))),
+                }
+            }
+            None => Err(invalid(self.text, "expected a value".to_string())),
+        }
+    }
+
+    /// Skips an object or array, including any strings inside it
+    fn skip_nested(&mut self) -> Result<()> {
+        let mut depth = 0;
+        while let Some(c) = self.peek() {
+            match c {
+                '"' => {
+                    self.string()?;
+                    continue;
+                }
+                '{' | '[' => depth += 1,
+                '}' | ']' => depth -= 1,
+                _ => {}
+            }
+            self.chars.next();
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+        Err(invalid(self.text, "unterminated object or array".to_string()))
+    }
+}
+
+fn invalid(text: &str, reason: String) -> AstroError {
+    AstroError::InvalidFormat { format: "weather JSON", input: text.to_string(), reason }
+}
@@ -0,0 +1,235 @@
+//! What is overhead: the zenith, the nadir and the sky around the zenith.
+//!
+//! [`zenith_ra_dec`] gives the point on the celestial sphere directly above
+//! an observer, and [`sky_region_overhead`] a cone around it. Everything in
+//! a cone of radius *r* about the zenith is at least 90° − *r* above the
+//! horizon, which makes it the natural search area for "what's overhead
+//! now" displays and for sky flats and blank fields, taken near the zenith
+//! where airmass and its gradient are smallest.
+//!
+//! Coordinates follow [`alt_az_to_ra_dec`](crate::transforms::alt_az_to_ra_dec):
+//! the zenith's right ascension is the local apparent sidereal time and its
+//! declination the observer's geodetic latitude. They refer to the equator
+//! of date, which differs from J2000 catalogue coordinates by precession
+//! (about 0.3° in 2024); for a cone of a few degrees this can be ignored,
+//! otherwise convert with [`precess_to_j2000`](crate::precession::precess_to_j2000).
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::{ra_dec_to_alt_az, Location};
+//! use astro_math::zenith::sky_region_overhead;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let site = Location { latitude_deg: 31.96, longitude_deg: -111.6, altitude_m: 2120.0 };
+//! let dt = Utc.with_ymd_and_hms(2024, 12, 1, 6, 0, 0).unwrap();
+//!
+//! let overhead = sky_region_overhead(dt, &site, 10.0).unwrap();
+//! let candidates = [(52.0, 40.0), (83.82, -5.39), (56.75, 24.12)];
+//! for i in overhead.filter(&candidates).unwrap() {
+//!     let (ra, dec) = candidates[i];
+//!     let (alt, _) = ra_dec_to_alt_az(ra, dec, dt, &site).unwrap();
+//!     assert!(alt >= 80.0);
+//! }
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for out-of-range RA or Dec values
+//! - `AstroError::OutOfRange` for an out-of-range latitude or longitude, or
+//!   a radius outside [0, 180] degrees
+//! - `AstroError::CalculationError` for an invalid polygon
+
+use crate::error::{validate_dec, validate_ra, validate_range, AstroError, Result};
+use crate::location::Location;
+use crate::sky_polygon;
+use chrono::{DateTime, Utc};
+
+/// A circular region of the sky.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkyCone {
+    /// Right ascension of the centre in degrees
+    pub ra: f64,
+    /// Declination of the centre in degrees
+    pub dec: f64,
+    /// Angular radius in degrees
+    pub radius_deg: f64,
+}
+
+impl SkyCone {
+    /// Angular distance of a position from the centre of the cone.
+    ///
+    /// # Arguments
+    /// * `ra`, `dec` - Position in degrees
+    ///
+    /// # Returns
+    /// Separation in degrees, in [0, 180]
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+    pub fn separation(&self, ra: f64, dec: f64) -> Result<f64> {
+        validate_ra(ra)?;
+        validate_dec(dec)?;
+        let (ra1, dec1) = (self.ra.to_radians(), self.dec.to_radians());
+        let (ra2, dec2) = (ra.to_radians(), dec.to_radians());
+        let dra = ra2 - ra1;
+        let y = (dec2.cos() * dra.sin()).hypot(dec1.cos() * dec2.sin() - dec1.sin() * dec2.cos() * dra.cos());
+        let x = dec1.sin() * dec2.sin() + dec1.cos() * dec2.cos() * dra.cos();
+        Ok(y.atan2(x).to_degrees())
+    }
+
+    /// Tests whether a position lies within the cone.
+    ///
+    /// # Arguments
+    /// * `ra`, `dec` - Position in degrees
+    ///
+    /// # Returns
+    /// `true` if the position is no further than `radius_deg` from the centre
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if RA or Dec is out of range.
+    pub fn contains(&self, ra: f64, dec: f64) -> Result<bool> {
+        Ok(self.separation(ra, dec)? <= self.radius_deg)
+    }
+
+    /// Selects the targets that lie within the cone.
+    ///
+    /// # Arguments
+    /// * `targets` - Positions as `(ra, dec)` in degrees
+    ///
+    /// # Returns
+    /// Indices into `targets` of those inside the cone, nearest the centre first
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if any position is out of range.
+    ///
+    /// # Example
+    /// ```
+    /// use astro_math::zenith::SkyCone;
+    ///
+    /// let cone = SkyCone { ra: 10.0, dec: 20.0, radius_deg: 5.0 };
+    /// let targets = [(10.0, 24.0), (30.0, 20.0), (10.0, 21.0)];
+    /// assert_eq!(cone.filter(&targets).unwrap(), vec![2, 0]);
+    /// ```
+    pub fn filter(&self, targets: &[(f64, f64)]) -> Result<Vec<usize>> {
+        let mut inside = Vec::new();
+        for (index, &(ra, dec)) in targets.iter().enumerate() {
+            let separation = self.separation(ra, dec)?;
+            if separation <= self.radius_deg {
+                inside.push((separation, index));
+            }
+        }
+        inside.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(inside.into_iter().map(|(_, index)| index).collect())
+    }
+
+    /// Tests whether a spherical polygon, such as a survey field or detector
+    /// footprint, overlaps the cone.
+    ///
+    /// # Arguments
+    /// * `polygon` - Vertices as `(ra, dec)` in degrees; see [`sky_polygon`]
+    ///
+    /// # Returns
+    /// `true` if any part of the polygon lies within the cone
+    ///
+    /// # Errors
+    /// - `AstroError::InvalidCoordinate` if any coordinate is out of range
+    /// - `AstroError::OutOfRange` if the cone's radius is outside [0, 180]
+    /// - `AstroError::CalculationError` if the polygon is invalid
+    pub fn intersects_polygon(&self, polygon: &[(f64, f64)]) -> Result<bool> {
+        sky_polygon::intersects_cone(polygon, self.ra, self.dec, self.radius_deg)
+    }
+}
+
+/// Calculates the RA/Dec directly overhead.
+///
+/// # Arguments
+/// * `datetime` - Time of observation (UTC)
+/// * `location` - Observer's location
+///
+/// # Returns
+/// `(ra, dec)` of the zenith in degrees: the local apparent sidereal time
+/// and the geodetic latitude
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if the latitude or longitude is out of range.
+///
+/// # Example
+/// ```
+/// use astro_math::{ra_dec_to_alt_az, Location};
+/// use astro_math::zenith::zenith_ra_dec;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: -30.24, longitude_deg: -70.74, altitude_m: 2200.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 8, 4, 3, 0, 0).unwrap();
+/// let (ra, dec) = zenith_ra_dec(dt, &site).unwrap();
+/// assert_eq!(dec, -30.24);
+/// let (alt, _) = ra_dec_to_alt_az(ra, dec, dt, &site).unwrap();
+/// assert!((alt - 90.0).abs() < 1e-4);
+/// ```
+pub fn zenith_ra_dec(datetime: DateTime<Utc>, location: &Location) -> Result<(f64, f64)> {
+    validate_location(location)?;
+    let ra = (location.local_sidereal_time(datetime) * 15.0).rem_euclid(360.0);
+    Ok((ra, location.latitude_deg))
+}
+
+/// Calculates the RA/Dec directly below the observer.
+///
+/// # Arguments
+/// * `datetime` - Time of observation (UTC)
+/// * `location` - Observer's location
+///
+/// # Returns
+/// `(ra, dec)` of the nadir in degrees, the antipode of [`zenith_ra_dec`]
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if the latitude or longitude is out of range.
+pub fn nadir_ra_dec(datetime: DateTime<Utc>, location: &Location) -> Result<(f64, f64)> {
+    let (ra, dec) = zenith_ra_dec(datetime, location)?;
+    Ok(((ra + 180.0).rem_euclid(360.0), -dec))
+}
+
+/// Returns the cone of sky within `radius_deg` of the zenith.
+///
+/// # Arguments
+/// * `datetime` - Time of observation (UTC)
+/// * `location` - Observer's location
+/// * `radius_deg` - Zenith distance of the edge of the region, in degrees
+///
+/// # Returns
+/// A [`SkyCone`] centred on the zenith; every position inside it has an
+/// altitude of at least `90 − radius_deg` (without refraction)
+///
+/// # Errors
+/// Returns `Err(AstroError::OutOfRange)` if the latitude or longitude is out
+/// of range, or `radius_deg` is outside [0, 180].
+///
+/// # Example
+/// ```
+/// use astro_math::Location;
+/// use astro_math::zenith::sky_region_overhead;
+/// use chrono::{TimeZone, Utc};
+///
+/// let site = Location { latitude_deg: 51.48, longitude_deg: 0.0, altitude_m: 46.0 };
+/// let dt = Utc.with_ymd_and_hms(2024, 1, 15, 22, 0, 0).unwrap();
+/// let overhead = sky_region_overhead(dt, &site, 15.0).unwrap();
+/// // Capella (Dec +46°) is near the zenith of London on a winter evening
+/// assert!(overhead.contains(79.17, 46.0).unwrap());
+/// ```
+pub fn sky_region_overhead(datetime: DateTime<Utc>, location: &Location, radius_deg: f64) -> Result<SkyCone> {
+    if !(0.0..=180.0).contains(&radius_deg) {
+        return Err(AstroError::OutOfRange {
+            parameter: "radius_deg",
+            value: radius_deg,
+            min: 0.0,
+            max: 180.0,
+        });
+    }
+    let (ra, dec) = zenith_ra_dec(datetime, location)?;
+    Ok(SkyCone { ra, dec, radius_deg })
+}
+
+fn validate_location(location: &Location) -> Result<()> {
+    validate_range(location.latitude_deg, -90.0, 90.0, "latitude")?;
+    validate_range(location.longitude_deg, -180.0, 180.0, "longitude")
+}
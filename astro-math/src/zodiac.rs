@@ -0,0 +1,203 @@
+//! Tropical zodiac signs and equal divisions of the ecliptic.
+//!
+//! Planetarium and teaching tools label ecliptic longitudes by sign ("the
+//! Sun is at 29° Gemini") or by equal segments, such as the 24 solar terms
+//! or the 27 lunar mansions. These helpers take a longitude in degrees,
+//! normally the apparent longitude of date from
+//! [`ecliptic_longitude_of_sun`](crate::sun::ecliptic_longitude_of_sun) or
+//! [`moon_ecliptic_longitude`](crate::moon::moon_ecliptic_longitude).
+//!
+//! The signs are the tropical ones: twelve 30° divisions counted from the
+//! March equinox. Precession has carried them about 24° west of the
+//! constellations they are named after, so the Sun "in Aries" is in fact in
+//! Pisces, a useful point for teaching.
+//!
+//! # Example
+//!
+//! ```
+//! use astro_math::sun::ecliptic_longitude_of_sun;
+//! use astro_math::zodiac::{sign_position, ZodiacSign};
+//! use chrono::{TimeZone, Utc};
+//!
+//! let dt = Utc.with_ymd_and_hms(2024, 6, 20, 12, 0, 0).unwrap();
+//! let (sign, degrees) = sign_position(ecliptic_longitude_of_sun(dt)).unwrap();
+//! assert_eq!(sign, ZodiacSign::Gemini);
+//! assert_eq!(format!("{:.0}° {} {}", degrees.floor(), sign.symbol(), sign), "29° ♊ Gemini");
+//! ```
+//!
+//! # Error Handling
+//!
+//! - `AstroError::InvalidCoordinate` for a longitude that is not finite
+//! - `AstroError::OutOfRange` for zero segments
+
+use crate::error::{AstroError, Result};
+use std::fmt;
+
+/// The twelve signs of the tropical zodiac, in order of longitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ZodiacSign {
+    /// 0°–30°, from the March equinox
+    Aries,
+    /// 30°–60°
+    Taurus,
+    /// 60°–90°
+    Gemini,
+    /// 90°–120°, from the June solstice
+    Cancer,
+    /// 120°–150°
+    Leo,
+    /// 150°–180°
+    Virgo,
+    /// 180°–210°, from the September equinox
+    Libra,
+    /// 210°–240°
+    Scorpio,
+    /// 240°–270°
+    Sagittarius,
+    /// 270°–300°, from the December solstice
+    Capricorn,
+    /// 300°–330°
+    Aquarius,
+    /// 330°–360°
+    Pisces,
+}
+
+impl ZodiacSign {
+    /// Every sign, in order of longitude.
+    pub const ALL: [ZodiacSign; 12] = [
+        ZodiacSign::Aries,
+        ZodiacSign::Taurus,
+        ZodiacSign::Gemini,
+        ZodiacSign::Cancer,
+        ZodiacSign::Leo,
+        ZodiacSign::Virgo,
+        ZodiacSign::Libra,
+        ZodiacSign::Scorpio,
+        ZodiacSign::Sagittarius,
+        ZodiacSign::Capricorn,
+        ZodiacSign::Aquarius,
+        ZodiacSign::Pisces,
+    ];
+
+    /// Returns the sign containing an ecliptic longitude.
+    ///
+    /// # Arguments
+    /// * `longitude_deg` - Ecliptic longitude in degrees, any value
+    ///
+    /// # Errors
+    /// Returns `Err(AstroError::InvalidCoordinate)` if the longitude is not
+    /// finite.
+    pub fn from_longitude(longitude_deg: f64) -> Result<ZodiacSign> {
+        Ok(sign_position(longitude_deg)?.0)
+    }
+
+    /// English name.
+    pub fn name(self) -> &'static str {
+        match self {
+            ZodiacSign::Aries => "Aries",
+            ZodiacSign::Taurus => "Taurus",
+            ZodiacSign::Gemini => "Gemini",
+            ZodiacSign::Cancer => "Cancer",
+            ZodiacSign::Leo => "Leo",
+            ZodiacSign::Virgo => "Virgo",
+            ZodiacSign::Libra => "Libra",
+            ZodiacSign::Scorpio => "Scorpio",
+            ZodiacSign::Sagittarius => "Sagittarius",
+            ZodiacSign::Capricorn => "Capricorn",
+            ZodiacSign::Aquarius => "Aquarius",
+            ZodiacSign::Pisces => "Pisces",
+        }
+    }
+
+    /// Unicode symbol, ♈ to ♓.
+    pub fn symbol(self) -> char {
+        char::from_u32('♈' as u32 + self as u32).expect("the signs are consecutive code points")
+    }
+
+    /// Ecliptic longitude at which the sign begins, in degrees.
+    pub fn start_longitude(self) -> f64 {
+        self as u32 as f64 * 30.0
+    }
+}
+
+impl fmt::Display for ZodiacSign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Splits an ecliptic longitude into its sign and the degrees into it.
+///
+/// # Arguments
+/// * `longitude_deg` - Ecliptic longitude in degrees, any value
+///
+/// # Returns
+/// The sign and the longitude past its start, in [0, 30)
+///
+/// # Errors
+/// Returns `Err(AstroError::InvalidCoordinate)` if the longitude is not
+/// finite.
+///
+/// # Example
+/// ```
+/// use astro_math::zodiac::{sign_position, ZodiacSign};
+///
+/// let (sign, degrees) = sign_position(-15.0).unwrap();
+/// assert_eq!(sign, ZodiacSign::Pisces);
+/// assert!((degrees - 15.0).abs() < 1e-12);
+/// ```
+pub fn sign_position(longitude_deg: f64) -> Result<(ZodiacSign, f64)> {
+    let (index, degrees) = ecliptic_segment(longitude_deg, 12)?;
+    Ok((ZodiacSign::ALL[index as usize], degrees))
+}
+
+/// Splits an ecliptic longitude into one of `segments` equal divisions of
+/// the ecliptic, counted from longitude 0.
+///
+/// # Arguments
+/// * `longitude_deg` - Ecliptic longitude in degrees, any value
+/// * `segments` - Number of divisions, e.g. 12 for signs, 24 for solar
+///   terms, 27 for lunar mansions
+///
+/// # Returns
+/// The index of the division, from 0, and the longitude past its start in
+/// degrees
+///
+/// # Errors
+/// - `AstroError::InvalidCoordinate` if the longitude is not finite
+/// - `AstroError::OutOfRange` if `segments` is zero
+///
+/// # Example
+/// ```
+/// use astro_math::zodiac::ecliptic_segment;
+///
+/// // The 27 nakshatras of 13°20′ each
+/// let (index, degrees) = ecliptic_segment(100.0, 27).unwrap();
+/// assert_eq!(index, 7);
+/// assert!((degrees - 6.6667).abs() < 1e-4);
+/// ```
+pub fn ecliptic_segment(longitude_deg: f64, segments: u32) -> Result<(u32, f64)> {
+    if !longitude_deg.is_finite() {
+        return Err(AstroError::InvalidCoordinate {
+            coord_type: "ecliptic longitude",
+            value: longitude_deg,
+            valid_range: "finite",
+        });
+    }
+    if segments == 0 {
+        return Err(AstroError::OutOfRange {
+            parameter: "segments",
+            value: 0.0,
+            min: 1.0,
+            max: u32::MAX as f64,
+        });
+    }
+    let width = 360.0 / segments as f64;
+    // rem_euclid rounds tiny negative longitudes up to 360
+    let longitude = match longitude_deg.rem_euclid(360.0) {
+        l if l >= 360.0 => 0.0,
+        l => l,
+    };
+    let index = ((longitude / width) as u32).min(segments - 1);
+    Ok((index, (longitude - index as f64 * width).max(0.0)))
+}